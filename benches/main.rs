@@ -8,7 +8,7 @@ use test::{black_box, Bencher};
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 
-use _pydantic_core::SchemaValidator;
+use _pydantic_core::{SchemaValidator, ValidationOptions};
 
 fn build_schema_validator_with_globals(
     py: Python,
@@ -33,7 +33,7 @@ fn ints_json(bench: &mut Bencher) {
         let validator = build_schema_validator(py, c"{'type': 'int'}");
 
         let result = validator
-            .validate_json(py, &json(py, "123"), None, None, None, None, false.into(), None, None)
+            .validate_json_with(py, &json(py, "123"), &ValidationOptions::default())
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 123);
@@ -41,7 +41,7 @@ fn ints_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, "123"), None, None, None, None, false.into(), None, None)
+                    .validate_json_with(py, &json(py, "123"), &ValidationOptions::default())
                     .unwrap(),
             )
         })
@@ -55,7 +55,7 @@ fn ints_python(bench: &mut Bencher) {
 
         let Ok(input) = 123_i64.into_pyobject(py);
         let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python_with(py, &input, &ValidationOptions::default())
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 123);
@@ -64,7 +64,7 @@ fn ints_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python_with(py, &input, &ValidationOptions::default())
                     .unwrap(),
             )
         })
@@ -83,7 +83,7 @@ fn list_int_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, &code), None, None, None, None, false.into(), None, None)
+                    .validate_json_with(py, &json(py, &code), &ValidationOptions::default())
                     .unwrap(),
             )
         })
@@ -109,7 +109,7 @@ fn list_int_python(bench: &mut Bencher) {
         let input = black_box(input.bind(py));
         bench.iter(|| {
             let v = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                .validate_python_with(py, &input, &ValidationOptions::default())
                 .unwrap();
             black_box(v)
         })
@@ -122,13 +122,13 @@ fn list_int_python_isinstance(bench: &mut Bencher) {
         let (validator, input) = list_int_input(py);
         let input = black_box(input.bind(py));
         let v = validator
-            .isinstance_python(py, &input, None, None, None, None, None, None, None)
+            .isinstance_python_with(py, &input, &ValidationOptions::default())
             .unwrap();
         assert!(v);
 
         bench.iter(|| {
             let v = validator
-                .isinstance_python(py, &input, None, None, None, None, None, None, None)
+                .isinstance_python_with(py, &input, &ValidationOptions::default())
                 .unwrap();
             black_box(v)
         })
@@ -147,7 +147,7 @@ fn list_error_json(bench: &mut Bencher) {
                 .join(", ")
         );
 
-        match validator.validate_json(py, &json(py, &code), None, None, None, None, false.into(), None, None) {
+        match validator.validate_json_with(py, &json(py, &code), &ValidationOptions::default()) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value(py);
@@ -158,12 +158,12 @@ fn list_error_json(bench: &mut Bencher) {
             }
         };
 
-        bench.iter(|| {
-            match validator.validate_json(py, &json(py, &code), None, None, None, None, false.into(), None, None) {
+        bench.iter(
+            || match validator.validate_json_with(py, &json(py, &code), &ValidationOptions::default()) {
                 Ok(_) => panic!("unexpectedly valid"),
                 Err(e) => black_box(e),
-            }
-        })
+            },
+        )
     })
 }
 
@@ -180,7 +180,7 @@ fn list_error_python_input(py: Python<'_>) -> (SchemaValidator, PyObject) {
 
     let input = py.eval(&code, None, None).unwrap().extract().unwrap();
 
-    match validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None) {
+    match validator.validate_python_with(py, &input, &ValidationOptions::default()) {
         Ok(_) => panic!("unexpectedly valid"),
         Err(e) => {
             let v = e.value(py);
@@ -200,7 +200,7 @@ fn list_error_python(bench: &mut Bencher) {
 
         let input = black_box(input.bind(py));
         bench.iter(|| {
-            let result = validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None);
+            let result = validator.validate_python_with(py, &input, &ValidationOptions::default());
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -216,14 +216,14 @@ fn list_error_python_isinstance(bench: &mut Bencher) {
         let (validator, input) = list_error_python_input(py);
         let input = black_box(input.bind(py));
         let r = validator
-            .isinstance_python(py, &input, None, None, None, None, None, None, None)
+            .isinstance_python_with(py, &input, &ValidationOptions::default())
             .unwrap();
         assert!(!r);
 
         bench.iter(|| {
             black_box(
                 validator
-                    .isinstance_python(py, &input, None, None, None, None, None, None, None)
+                    .isinstance_python_with(py, &input, &ValidationOptions::default())
                     .unwrap(),
             );
         })
@@ -242,7 +242,7 @@ fn list_any_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, &code), None, None, None, None, false.into(), None, None)
+                    .validate_json_with(py, &json(py, &code), &ValidationOptions::default())
                     .unwrap(),
             )
         })
@@ -262,7 +262,7 @@ fn list_any_python(bench: &mut Bencher) {
         let input = black_box(input);
         bench.iter(|| {
             let v = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                .validate_python_with(py, &input, &ValidationOptions::default())
                 .unwrap();
             black_box(v)
         })
@@ -296,7 +296,7 @@ fn dict_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, &code), None, None, None, None, false.into(), None, None)
+                    .validate_json_with(py, &json(py, &code), &ValidationOptions::default())
                     .unwrap(),
             )
         })
@@ -323,7 +323,7 @@ fn dict_python(bench: &mut Bencher) {
         let input = black_box(input);
         bench.iter(|| {
             let v = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                .validate_python_with(py, &input, &ValidationOptions::default())
                 .unwrap();
             black_box(v)
         })
@@ -353,7 +353,7 @@ fn dict_value_error(bench: &mut Bencher) {
 
         let input = py.eval(&code, None, None).unwrap();
 
-        match validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None) {
+        match validator.validate_python_with(py, &input, &ValidationOptions::default()) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value(py);
@@ -366,7 +366,7 @@ fn dict_value_error(bench: &mut Bencher) {
 
         let input = black_box(input);
         bench.iter(|| {
-            let result = validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None);
+            let result = validator.validate_python_with(py, &input, &ValidationOptions::default());
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -404,7 +404,7 @@ fn typed_dict_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, code), None, None, None, None, false.into(), None, None)
+                    .validate_json_with(py, &json(py, code), &ValidationOptions::default())
                     .unwrap(),
             )
         })
@@ -439,7 +439,7 @@ fn typed_dict_python(bench: &mut Bencher) {
         let input = black_box(input);
         bench.iter(|| {
             let v = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                .validate_python_with(py, &input, &ValidationOptions::default())
                 .unwrap();
             black_box(v)
         })
@@ -480,7 +480,7 @@ fn typed_dict_deep_error(bench: &mut Bencher) {
         let input = py.eval(code, None, None).unwrap();
         let input = black_box(input);
 
-        match validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None) {
+        match validator.validate_python_with(py, &input, &ValidationOptions::default()) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value(py);
@@ -492,7 +492,7 @@ fn typed_dict_deep_error(bench: &mut Bencher) {
         };
 
         bench.iter(|| {
-            let result = validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None);
+            let result = validator.validate_python_with(py, &input, &ValidationOptions::default());
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -518,7 +518,7 @@ fn complete_model(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python_with(py, &input, &ValidationOptions::default())
                     .unwrap(),
             );
         })
@@ -539,13 +539,13 @@ fn nested_model_using_definitions(bench: &mut Bencher) {
         let input = black_box(input);
 
         validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python_with(py, &input, &ValidationOptions::default())
             .unwrap();
 
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python_with(py, &input, &ValidationOptions::default())
                     .unwrap(),
             );
         })
@@ -566,13 +566,13 @@ fn nested_model_inlined(bench: &mut Bencher) {
         let input = black_box(input);
 
         validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python_with(py, &input, &ValidationOptions::default())
             .unwrap();
 
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python_with(py, &input, &ValidationOptions::default())
                     .unwrap(),
             );
         })
@@ -586,7 +586,7 @@ fn literal_ints_few_python(bench: &mut Bencher) {
 
         let Ok(input) = 4_i64.into_pyobject(py);
         let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python_with(py, &input, &ValidationOptions::default())
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 4);
@@ -595,7 +595,7 @@ fn literal_ints_few_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python_with(py, &input, &ValidationOptions::default())
                     .unwrap(),
             )
         })
@@ -610,7 +610,7 @@ fn literal_strings_few_small_python(bench: &mut Bencher) {
         let input = py.eval(c"'4'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python_with(py, &input, &ValidationOptions::default())
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -619,7 +619,7 @@ fn literal_strings_few_small_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python_with(py, &input, &ValidationOptions::default())
                     .unwrap(),
             )
         })
@@ -637,7 +637,7 @@ fn literal_strings_few_large_python(bench: &mut Bencher) {
         let input = py.eval(c"'a' * 25 + '4'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python_with(py, &input, &ValidationOptions::default())
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -646,7 +646,7 @@ fn literal_strings_few_large_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python_with(py, &input, &ValidationOptions::default())
                     .unwrap(),
             )
         })
@@ -680,7 +680,7 @@ class Foo(Enum):
 
         let input = py.eval(c"Foo.v4", Some(&globals), None).unwrap();
         let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python_with(py, &input, &ValidationOptions::default())
             .unwrap();
         assert!(input.eq(result).unwrap());
 
@@ -688,7 +688,7 @@ class Foo(Enum):
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python_with(py, &input, &ValidationOptions::default())
                     .unwrap(),
             )
         })
@@ -702,7 +702,7 @@ fn literal_ints_many_python(bench: &mut Bencher) {
 
         let Ok(input) = 99_i64.into_pyobject(py);
         let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python_with(py, &input, &ValidationOptions::default())
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 99);
@@ -711,7 +711,7 @@ fn literal_ints_many_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python_with(py, &input, &ValidationOptions::default())
                     .unwrap(),
             )
         })
@@ -727,7 +727,7 @@ fn literal_strings_many_small_python(bench: &mut Bencher) {
         let input = py.eval(c"'99'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python_with(py, &input, &ValidationOptions::default())
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -736,7 +736,7 @@ fn literal_strings_many_small_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python_with(py, &input, &ValidationOptions::default())
                     .unwrap(),
             )
         })
@@ -754,7 +754,7 @@ fn literal_strings_many_large_python(bench: &mut Bencher) {
         let input = py.eval(c"'a' * 25 + '99'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python_with(py, &input, &ValidationOptions::default())
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -763,7 +763,7 @@ fn literal_strings_many_large_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python_with(py, &input, &ValidationOptions::default())
                     .unwrap(),
             )
         })
@@ -777,7 +777,7 @@ fn literal_ints_many_json(bench: &mut Bencher) {
 
         let input_json = py.eval(c"'99'", None, None).unwrap();
         let result = validator
-            .validate_json(py, &input_json, None, None, None, None, false.into(), None, None)
+            .validate_json_with(py, &input_json, &ValidationOptions::default())
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 99);
@@ -786,7 +786,7 @@ fn literal_ints_many_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &input_json, None, None, None, None, false.into(), None, None)
+                    .validate_json_with(py, &input_json, &ValidationOptions::default())
                     .unwrap(),
             )
         })
@@ -805,7 +805,7 @@ fn literal_strings_many_large_json(bench: &mut Bencher) {
         let input_json = py.eval(c"'\"' + 'a' * 25 + '99' + '\"'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_json(py, &input_json, None, None, None, None, false.into(), None, None)
+            .validate_json_with(py, &input_json, &ValidationOptions::default())
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -814,7 +814,7 @@ fn literal_strings_many_large_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &input_json, None, None, None, None, false.into(), None, None)
+                    .validate_json_with(py, &input_json, &ValidationOptions::default())
                     .unwrap(),
             )
         })
@@ -850,7 +850,7 @@ class Foo(Enum):
             let input = py.eval(c"'null'", None, None).unwrap();
             let input_str: String = input.extract().unwrap();
             let result = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                .validate_python_with(py, &input, &ValidationOptions::default())
                 .unwrap();
             let result_str: String = result.extract(py).unwrap();
             assert_eq!(result_str, input_str);
@@ -859,7 +859,7 @@ class Foo(Enum):
             bench.iter(|| {
                 black_box(
                     validator
-                        .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                        .validate_python_with(py, &input, &ValidationOptions::default())
                         .unwrap(),
                 )
             })
@@ -870,7 +870,7 @@ class Foo(Enum):
             let input = py.eval(c"-1", None, None).unwrap();
             let input_int: i64 = input.extract().unwrap();
             let result = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                .validate_python_with(py, &input, &ValidationOptions::default())
                 .unwrap();
             let result_int: i64 = result.extract(py).unwrap();
             assert_eq!(result_int, input_int);
@@ -879,7 +879,7 @@ class Foo(Enum):
             bench.iter(|| {
                 black_box(
                     validator
-                        .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                        .validate_python_with(py, &input, &ValidationOptions::default())
                         .unwrap(),
                 )
             })
@@ -889,7 +889,7 @@ class Foo(Enum):
         {
             let input = py.eval(c"None", None, None).unwrap();
             let result = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                .validate_python_with(py, &input, &ValidationOptions::default())
                 .unwrap();
             assert!(input.eq(result).unwrap());
 
@@ -897,7 +897,7 @@ class Foo(Enum):
             bench.iter(|| {
                 black_box(
                     validator
-                        .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                        .validate_python_with(py, &input, &ValidationOptions::default())
                         .unwrap(),
                 )
             })
@@ -907,7 +907,7 @@ class Foo(Enum):
         {
             let input = py.eval(c"Foo.v4", Some(&globals), None).unwrap();
             let result = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                .validate_python_with(py, &input, &ValidationOptions::default())
                 .unwrap();
             assert!(input.eq(result).unwrap());
 
@@ -915,7 +915,7 @@ class Foo(Enum):
             bench.iter(|| {
                 black_box(
                     validator
-                        .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                        .validate_python_with(py, &input, &ValidationOptions::default())
                         .unwrap(),
                 )
             })
@@ -1,15 +1,21 @@
 from __future__ import annotations
 
 import sys as _sys
+from datetime import datetime as _datetime
 from typing import Any as _Any
+from typing import Literal as _Literal
+from uuid import UUID as _UUID
 
 from typing_extensions import Sentinel
 
 from ._pydantic_core import (
     ArgsKwargs,
+    ArrowBatch,
+    IncrementalValidation,
     MultiHostUrl,
     PydanticCustomError,
     PydanticKnownError,
+    PydanticMultipleErrors,
     PydanticOmit,
     PydanticSerializationError,
     PydanticSerializationUnexpectedValue,
@@ -17,14 +23,26 @@ from ._pydantic_core import (
     PydanticUndefinedType,
     PydanticUseDefault,
     SchemaError,
+    SchemaJsonifier,
     SchemaSerializer,
     SchemaValidator,
     Some,
     TzInfo,
+    Ulid,
     Url,
     ValidationError,
+    ValidationResult,
+    Version,
     __version__,
+    capabilities,
+    check_schema,
+    clear_string_cache,
+    compare_secrets,
     from_json,
+    register_error_type,
+    register_plugin,
+    set_string_cache_size,
+    string_cache_info,
     to_json,
     to_jsonable_python,
 )
@@ -47,30 +65,76 @@ __all__ = [
     'CoreSchema',
     'CoreSchemaType',
     'SchemaValidator',
+    'IncrementalValidation',
+    'ArrowBatch',
+    'ValidationResult',
     'SchemaSerializer',
+    'SchemaJsonifier',
     'Some',
     'Url',
     'MultiHostUrl',
+    'Version',
+    'Ulid',
+    'TimestampedUUID',
     'ArgsKwargs',
     'PydanticUndefined',
     'PydanticUndefinedType',
     'SchemaError',
     'ErrorDetails',
+    'ErrorPosition',
     'InitErrorDetails',
     'ValidationError',
     'PydanticCustomError',
     'PydanticKnownError',
+    'PydanticMultipleErrors',
     'PydanticOmit',
     'PydanticUseDefault',
     'PydanticSerializationError',
     'PydanticSerializationUnexpectedValue',
     'TzInfo',
+    'UnionAttempt',
+    'UnionTrace',
+    'PartialOmission',
+    'ValueProvenance',
+    'TimedeltaComponents',
+    'IsoPeriod',
+    'ValidatorProfile',
+    'FieldCoercion',
+    'AppliedDefault',
+    'ValidationExplanation',
+    'SchemaDiagnostic',
+    'ExampleFailure',
+    'SerializationWarningDetails',
+    'Capabilities',
+    'CapabilityLimits',
     'to_json',
     'from_json',
     'to_jsonable_python',
+    'compare_secrets',
+    'check_schema',
+    'capabilities',
+    'register_error_type',
+    'register_plugin',
+    'set_string_cache_size',
+    'clear_string_cache',
+    'string_cache_info',
 ]
 
 
+class ErrorPosition(_TypedDict):
+    """
+    Position of the JSON value an error refers to, see
+    [`CoreConfig.json_error_positions`][pydantic_core.core_schema.CoreConfig].
+    """
+
+    offset: int
+    """The 0-indexed byte offset of the value in the JSON data."""
+    line: int
+    """The 1-indexed line number of the value."""
+    column: int
+    """The 1-indexed column number of the value, counted in bytes like `offset`."""
+
+
 class ErrorDetails(_TypedDict):
     type: str
     """
@@ -79,8 +143,11 @@ class ErrorDetails(_TypedDict):
 
     `type` is unique for each error message, and can hence be used as an identifier to build custom error messages.
     """
-    loc: tuple[int | str, ...]
-    """Tuple of strings and ints identifying where in the schema the error occurred."""
+    loc: tuple[int | str, ...] | str
+    """
+    Tuple of strings and ints identifying where in the schema the error occurred, or a JSON Pointer string
+    if `loc_as_pointer=True` was passed to [`errors()`][pydantic_core.ValidationError.errors].
+    """
     msg: str
     """A human readable error message."""
     input: _Any
@@ -95,6 +162,13 @@ class ErrorDetails(_TypedDict):
     The documentation URL giving information about the error. No URL is available if
     a [`PydanticCustomError`][pydantic_core.PydanticCustomError] is used.
     """
+    position: _NotRequired[ErrorPosition]
+    """
+    The position of the offending value in the JSON input, only available when validating JSON with
+    [`CoreConfig.json_error_positions`][pydantic_core.core_schema.CoreConfig] enabled. Errors whose input isn't
+    a value in the JSON, e.g. errors from the handler of a wrap validator, are placed by following their `loc` as far
+    as it matches the JSON.
+    """
 
 
 class InitErrorDetails(_TypedDict):
@@ -109,6 +183,10 @@ class InitErrorDetails(_TypedDict):
     Values which are required to render the error message, and could hence be useful in rendering custom error messages.
     Also useful for passing custom error data forward.
     """
+    position: _NotRequired[ErrorPosition]
+    """The position of the offending value in the JSON input."""
+    msg_template: _NotRequired[str]
+    """A template to render the message with instead of the error type's, formatted with `ctx`."""
 
 
 class ErrorTypeInfo(_TypedDict):
@@ -145,6 +223,289 @@ class MultiHostHost(_TypedDict):
     """The port part of this host, or `None`."""
 
 
+class UnionAttempt(_TypedDict):
+    """
+    A union choice tried during union validation, see [`UnionTrace`][pydantic_core.UnionTrace].
+    """
+
+    choice: str
+    """The label of the choice, or the name of its validator if it has no label."""
+    valid: bool
+    """Whether the input was valid for this choice."""
+    exactness: _Literal['exact', 'strict', 'lax'] | None
+    """How exactly the input matched this choice, used to pick between valid choices, `None` if invalid."""
+    fields_set_count: int | None
+    """The number of fields set when validating a model, dataclass or typed dict choice, `None` otherwise."""
+    nested: list[UnionTrace]
+    """Traces of unions validated while validating this choice."""
+
+
+class UnionTrace(_TypedDict):
+    """
+    A record of how a union was validated, returned when validating with `trace=True`, see
+    [`SchemaValidator.validate_python`][pydantic_core.SchemaValidator.validate_python].
+    """
+
+    union: str
+    """The name of the union validator."""
+    mode: _Literal['smart', 'left_to_right']
+    """The union's validation mode."""
+    attempts: list[UnionAttempt]
+    """The choices which were tried, in order."""
+    winner: str | None
+    """The label of the chosen choice, `None` if no choice was valid."""
+    reason: _Literal[
+        'exact',
+        'only_valid_choice',
+        'most_fields_set',
+        'most_exact',
+        'first_of_equal_choices',
+        'highest_score',
+        'first_valid_choice',
+        'no_valid_choice',
+    ]
+    """
+    Why the winner was chosen:
+
+    * `'exact'`: the input was an exact match for the winner, so later choices weren't tried
+    * `'only_valid_choice'`: the winner was the only valid choice
+    * `'most_fields_set'`: the winner had more fields set than the other valid choices
+    * `'most_exact'`: the winner matched more exactly than the other valid choices
+    * `'first_of_equal_choices'`: several valid choices were equally good, so the first was chosen
+    * `'highest_score'`: the winner had the highest score from the union's `scorer`
+    * `'first_valid_choice'`: in `left_to_right` mode the first valid choice is always chosen
+    * `'no_valid_choice'`: no choice was valid
+    """
+
+
+class PartialOmission(_TypedDict):
+    """
+    An item left out of the output of partial validation, returned when validating with `allow_partial` and
+    `partial_report=True`, see [`SchemaValidator.validate_python`][pydantic_core.SchemaValidator.validate_python].
+    """
+
+    loc: tuple[int | str, ...]
+    """The location of the item, the last item of a sequence or mapping, which may be incomplete."""
+    errors: list[ErrorDetails]
+    """
+    The errors which caused the item to be left out, as they'd be reported without `allow_partial`, or a
+    `json_invalid` error if the JSON parser left the item out as it was incomplete.
+    """
+
+
+class ValueProvenance(_TypedDict):
+    """
+    Where the value of a field came from, returned when validating with `provenance=True`, see
+    [`SchemaValidator.validate_python`][pydantic_core.SchemaValidator.validate_python].
+    """
+
+    loc: tuple[str, ...]
+    """The names of the field and the fields it's nested in, from the outermost."""
+    source: _Literal['supplied', 'coerced', 'default']
+    """
+    Whether the value was supplied in the input, supplied but only valid after lax coercion, or the field's default.
+    """
+    input_type: str | None
+    """The name of the type of the input, `None` for defaults."""
+
+
+class TimedeltaComponents(_TypedDict):
+    """
+    The parts of a duration, returned when validating with
+    [`timedelta_schema(output='components')`][pydantic_core.core_schema.timedelta_schema].
+
+    The parts are all positive, and normalized so e.g. `hours` is less than 24.
+    """
+
+    days: int
+    hours: int
+    minutes: int
+    seconds: int
+    microseconds: int
+    negative: bool
+    """Whether the duration is negative."""
+
+
+class IsoPeriod(TimedeltaComponents):
+    """
+    The parts of an ISO 8601 duration as written, e.g. `P1Y2M3DT36H` has 36 hours, returned when validating with
+    [`timedelta_schema(output='period')`][pydantic_core.core_schema.timedelta_schema].
+
+    Weeks are added to days. Other inputs, and ISO 8601 durations with a fraction of a part other than seconds,
+    have no years or months and are normalized like [`TimedeltaComponents`][pydantic_core.TimedeltaComponents].
+    """
+
+    years: int
+    months: int
+
+
+class ValidatorProfile(_TypedDict):
+    """
+    The timings recorded for a node of a validator, see
+    [`SchemaValidator.get_profile`][pydantic_core.SchemaValidator.get_profile].
+    """
+
+    calls: int
+    """The number of times the node was called."""
+    total_time: float
+    """The total wall time spent in the node in seconds, including the nodes it contains."""
+
+
+class FieldCoercion(_TypedDict):
+    """
+    A field whose value was only valid in lax mode, see [`ValidationExplanation`][pydantic_core.ValidationExplanation].
+    """
+
+    loc: tuple[str, ...]
+    """The names of the field and the fields containing it, from the outermost."""
+    from_type: str
+    """The name of the type of the input value."""
+    to_type: str
+    """The name of the type of the validated value."""
+
+
+class AppliedDefault(_TypedDict):
+    """
+    A field which used its default, see [`ValidationExplanation`][pydantic_core.ValidationExplanation].
+    """
+
+    loc: tuple[str, ...]
+    """The names of the field and the fields containing it, from the outermost."""
+    value: _Any
+    """The default value used."""
+
+
+class ValidationExplanation(_TypedDict):
+    """
+    Report of a validation, see [`SchemaValidator.explain`][pydantic_core.SchemaValidator.explain].
+    """
+
+    valid: bool
+    """Whether the input was valid."""
+    coercions: list[FieldCoercion]
+    """The fields whose value was coerced from another type, these would fail validation in strict mode."""
+    defaults: list[AppliedDefault]
+    """The fields which weren't in the input and used their default."""
+    errors: list[ErrorDetails]
+    """The validation errors, empty if the input was valid."""
+
+
+class SchemaDiagnostic(_TypedDict):
+    """
+    A problem found in a core schema by [`check_schema`][pydantic_core.check_schema].
+    """
+
+    path: tuple[int | str, ...]
+    """The keys and indices leading to the problem from the outermost schema, e.g. `('schema', 'fields', 'a')`."""
+    kind: _Literal[
+        'invalid_schema',
+        'incompatible_constraint',
+        'unsatisfiable_constraint',
+        'duplicate_union_choice',
+        'unreachable_union_choice',
+        'duplicate_discriminator_tag',
+        'alias_mismatch',
+    ]
+    """The kind of problem found, designed for programmatic use."""
+    message: str
+    """A human readable description of the problem."""
+    suggestion: str | None
+    """How the problem might be fixed, if there's an obvious fix."""
+
+
+class ExampleFailure(_TypedDict):
+    """
+    An example in a core schema which failed validation, returned by
+    [`SchemaValidator.check_examples`][pydantic_core.SchemaValidator.check_examples].
+    """
+
+    path: tuple[int | str, ...]
+    """The keys and indices leading to the schema with the example from the outermost schema."""
+    index: int
+    """The index of the example in the schema's `examples`."""
+    example: _Any
+    """The example."""
+    message: str
+    """A human readable description of why the example failed."""
+    errors: list[ErrorDetails]
+    """The validation errors, empty if the schema couldn't be built on its own to validate the example."""
+
+
+class SerializationWarningDetails(_TypedDict):
+    """
+    A serializer warning returned by [`SchemaSerializer.to_python`][pydantic_core.SchemaSerializer.to_python] and
+    [`SchemaSerializer.to_json`][pydantic_core.SchemaSerializer.to_json] with `warnings='collect'`.
+    """
+
+    loc: tuple[str, ...]
+    """The name of the field containing the value, empty if the value wasn't in a field."""
+    msg: str
+    """A human readable description of the warning, as it would be shown with `warnings='warn'`."""
+    expected: str | None
+    """The name of the serializer the value didn't match, e.g. `'int'`."""
+    actual: str | None
+    """The name of the type of the value."""
+    input: _Any
+    """The value which didn't match the serializer."""
+
+
+class CapabilityLimits(_TypedDict):
+    """
+    Limits on the input pydantic-core can validate, see [`Capabilities`][pydantic_core.Capabilities].
+    """
+
+    recursion_limit: int
+    """The maximum depth of recursive schema references followed during validation or serialization."""
+    json_max_depth: int
+    """The maximum depth of nested arrays and objects when parsing JSON."""
+
+
+class Capabilities(_TypedDict):
+    """
+    The features and limits compiled into pydantic-core, returned by [`capabilities`][pydantic_core.capabilities].
+    """
+
+    version: str
+    """The version of pydantic-core, as `__version__`."""
+    build_profile: str
+    """The cargo profile pydantic-core was built with, e.g. `'release'`."""
+    pgo: bool
+    """Whether pydantic-core was built with profile guided optimization."""
+    free_threading: bool
+    """Whether pydantic-core was built for a free-threaded (no GIL) build of Python."""
+    input_formats: list[_Literal['python', 'json', 'strings']]
+    """The kinds of input which can be validated, i.e. `validate_python`, `validate_json` and `validate_strings`."""
+    output_formats: list[_Literal['python', 'json', 'jsonl', 'msgpack']]
+    """The formats values can be serialized to."""
+    compression: list[_Literal['gzip', 'zstd']]
+    """The values of `compress` supported by `to_json`, each codec can be left out of a build with its cargo feature."""
+    regex_engines: list[_Literal['rust-regex', 'python-re']]
+    """The values supported by [`CoreConfig.regex_engine`][pydantic_core.core_schema.CoreConfig]."""
+    simd: list[_Literal['json']]
+    """The parsers using SIMD instructions on this platform."""
+    limits: CapabilityLimits
+    """Limits on the input which can be validated."""
+
+
+class TimestampedUUID(_UUID):
+    """
+    A `uuid.UUID` with the time embedded in it, returned when validating with
+    [`uuid_schema(with_timestamp=True)`][pydantic_core.core_schema.uuid_schema].
+    """
+
+    __slots__ = ('timestamp',)
+
+    timestamp: _datetime
+    """When the UUID was generated, as an aware UTC datetime."""
+
+    def __getstate__(self) -> dict[str, _Any]:
+        return {**super().__getstate__(), 'timestamp': self.timestamp}
+
+    def __setstate__(self, state: dict[str, _Any]) -> None:
+        super().__setstate__(state)
+        object.__setattr__(self, 'timestamp', state['timestamp'])
+
+
 MISSING = Sentinel('MISSING')
 """A singleton indicating a field value was not provided during validation.
 
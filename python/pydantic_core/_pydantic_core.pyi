@@ -1,11 +1,26 @@
 import datetime
-from collections.abc import Mapping
-from typing import Any, Callable, Generic, Literal, TypeVar, final
+from collections.abc import Iterable, Iterator, Mapping, Sequence
+from typing import Any, Callable, Generic, Literal, TypeVar, final, overload
+from uuid import UUID
 
-from _typeshed import SupportsAllComparisons
+from _typeshed import SupportsAllComparisons, SupportsRead, SupportsWrite
 from typing_extensions import LiteralString, Self, TypeAlias
 
-from pydantic_core import ErrorDetails, ErrorTypeInfo, InitErrorDetails, MultiHostHost
+from pydantic_core import (
+    Capabilities,
+    ErrorDetails,
+    ErrorTypeInfo,
+    ExampleFailure,
+    InitErrorDetails,
+    MultiHostHost,
+    PartialOmission,
+    SchemaDiagnostic,
+    SerializationWarningDetails,
+    UnionTrace,
+    ValidationExplanation,
+    ValidatorProfile,
+    ValueProvenance,
+)
 from pydantic_core.core_schema import CoreConfig, CoreSchema, ErrorType, ExtraBehavior
 
 __all__ = [
@@ -15,13 +30,20 @@ __all__ = [
     '_recursion_limit',
     'ArgsKwargs',
     'SchemaValidator',
+    'IncrementalValidation',
+    'ArrowBatch',
+    'ValidationResult',
     'SchemaSerializer',
+    'SchemaJsonifier',
     'Url',
     'MultiHostUrl',
+    'Version',
+    'Ulid',
     'SchemaError',
     'ValidationError',
     'PydanticCustomError',
     'PydanticKnownError',
+    'PydanticMultipleErrors',
     'PydanticOmit',
     'PydanticUseDefault',
     'PydanticSerializationError',
@@ -32,7 +54,15 @@ __all__ = [
     'to_json',
     'from_json',
     'to_jsonable_python',
+    'compare_secrets',
+    'check_schema',
+    'capabilities',
+    'set_string_cache_size',
+    'clear_string_cache',
+    'string_cache_info',
     'list_all_errors',
+    'register_error_type',
+    'register_plugin',
     'TzInfo',
 ]
 __version__: str
@@ -68,6 +98,10 @@ class SchemaValidator:
     """
     `SchemaValidator` is the Python wrapper for `pydantic-core`'s Rust validation logic, internally it owns one
     `CombinedValidator` which may in turn own more `CombinedValidator`s which make up the full schema validator.
+
+    Validators pickle as their core schema and config, so they can be cached, e.g. on disk, but unpickling builds
+    the validator again. The built validator itself can't be saved, as it holds Python objects like functions and
+    classes which have no byte representation.
     """
 
     # note: pyo3 currently supports __new__, but not __init__, though we include __init__ stubs
@@ -99,6 +133,19 @@ class SchemaValidator:
         allow_partial: bool | Literal['off', 'on', 'trailing-strings'] = False,
         by_alias: bool | None = None,
         by_name: bool | None = None,
+        strict_scope: Literal['recursive', 'top-level'] = 'recursive',
+        max_errors: int | None = None,
+        fail_fast: bool = False,
+        trace: bool = False,
+        exactness: bool = False,
+        partial_report: bool = False,
+        partial_update: bool = False,
+        on_extra_ignored: Callable[[tuple[str, ...]], None] | None = None,
+        provenance: bool = False,
+        union_mode: Literal['smart', 'left_to_right'] | None = None,
+        reference_time: datetime.datetime | float | None = None,
+        identity_cache: bool = False,
+        timeout_ms: int | None = None,
     ) -> Any:
         """
         Validate a Python object against the schema and return the validated object.
@@ -107,6 +154,7 @@ class SchemaValidator:
             input: The Python object to validate.
             strict: Whether to validate the object in strict mode.
                 If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+                By default this applies to nested models and dataclasses too, see `strict_scope`.
             extra: Whether to ignore, allow, or forbid extra data during model validation.
                 If `None`, the value of [`CoreConfig.extra_fields_behavior`][pydantic_core.core_schema.CoreConfig] is used.
             from_attributes: Whether to validate objects as inputs to models by extracting attributes.
@@ -114,19 +162,73 @@ class SchemaValidator:
             context: The context to use for validation, this is passed to functional validators as
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
             self_instance: An instance of a model set attributes on from validation, this is used when running
-                validation from the `__init__` method of a model.
+                validation from the `__init__` method of a model. For a typed dict schema, a `dict` can be passed
+                to be cleared and filled with the validated fields instead of creating a new dict, so one dict can
+                be reused when validating many similar inputs, it's left empty when validation fails. When the dict
+                is also the input, it's only updated once validation succeeds.
             allow_partial: Whether to allow partial validation; if `True` errors in the last element of sequences
                 and mappings are ignored.
                 `'trailing-strings'` means any final unfinished JSON string is included in the result.
             by_alias: Whether to use the field's alias when validating against the provided input data.
             by_name: Whether to use the field's name when validating against the provided input data.
+            strict_scope: Which models `strict` applies to; with `'recursive'` it applies to all nested models and
+                dataclasses, with `'top-level'` only to the outermost model or dataclass, nested ones using their
+                own `strict` config.
+            max_errors: The maximum number of errors to collect before validation stops, the resulting
+                [`ValidationError`][pydantic_core.ValidationError] is then marked as `truncated` if errors were
+                left out.
+                If `None`, the value of [`CoreConfig.max_errors`][pydantic_core.core_schema.CoreConfig] is used.
+            fail_fast: Whether to stop validating at the first error anywhere in the input, this is faster when
+                only the validity of the input is of interest.
+            trace: Whether to record how unions were validated, for debugging union validation.
+                If `True`, a [`ValidationResult`][pydantic_core.ValidationResult] is returned, its `trace` is a
+                list of [`UnionTrace`][pydantic_core.UnionTrace]s, one for each union validated outside other
+                unions.
+            exactness: Whether to return how exactly the input matched the schema, e.g. to log or reject input
+                which was only valid after lax coercion. If `True`, a
+                [`ValidationResult`][pydantic_core.ValidationResult] is returned, its `exactness` is `'exact'`,
+                `'strict'` or `'lax'`.
+            partial_report: Whether to return what `allow_partial` left out of the validated object, e.g. so
+                consumers of streamed input know which parts aren't there yet. If `True`, a
+                [`ValidationResult`][pydantic_core.ValidationResult] is returned, its `partial_report` is a list of
+                [`PartialOmission`][pydantic_core.PartialOmission]s. Only the last item of each sequence or mapping
+                can be left out, and only if it's invalid.
+            partial_update: Whether to validate a partial update of a model, e.g. the body of an HTTP PATCH
+                request. Missing fields aren't errors and don't get their defaults, the model only has the fields
+                in the input, as listed in its `__pydantic_fields_set__`. Models nested in fields are validated in
+                full.
+            on_extra_ignored: Called with the location of each extra input left out of the output by
+                `extra_behavior='ignore'`, the names of the fields it's nested in and its key, e.g. to detect
+                inputs drifting from the schema without forbidding extra inputs. Inputs ignored within union members
+                are only reported for the member which is used.
+            provenance: Whether to return where the value of each field came from, e.g. for an audit trail of
+                which values were supplied, coerced or defaulted. If `True`, a
+                [`ValidationResult`][pydantic_core.ValidationResult] is returned, its `provenance` is a list of
+                [`ValueProvenance`][pydantic_core.ValueProvenance]s, one for each field of a model, dataclass or
+                typed dict which doesn't hold other fields.
+            union_mode: Overrides the `mode` of every `union` schema, e.g. `'left_to_right'` to use the first
+                choice which succeeds for latency-critical validation without building another validator.
+                If `None`, each union uses its own `mode`. Tagged unions aren't affected.
+            reference_time: The current time used by the `now_op` constraints of datetime and date fields, e.g. for
+                deterministic replay or backtesting, a datetime, naive datetimes being local time, or a unix
+                timestamp in seconds. It takes precedence over the validation context and the
+                [`CoreConfig.clock`][pydantic_core.core_schema.CoreConfig] config.
+            identity_cache: Whether to revalidate each model instance only once when it appears many times in the
+                input, e.g. shared references, reusing the validated instance for the other occurrences rather than
+                validating it again. Only applies to instances which would be revalidated, see
+                [`CoreConfig.revalidate_instances`][pydantic_core.core_schema.CoreConfig].
+            timeout_ms: The most milliseconds validation can take, checked for each item, field and union choice,
+                once it's exceeded validation is abandoned with a `validation_timeout` error, also when a wrap
+                validator function catches the `TimeoutError` its handler raises. A single slow step, e.g. matching
+                a pattern or calling a validator function, isn't interrupted.
 
         Raises:
             ValidationError: If validation fails.
             Exception: Other error types maybe raised if internal errors occur.
 
         Returns:
-            The validated object.
+            The validated object, or a [`ValidationResult`][pydantic_core.ValidationResult] with it if `trace`,
+                `exactness`, `partial_report` or `provenance` are `True`.
         """
     def isinstance_python(
         self,
@@ -139,6 +241,7 @@ class SchemaValidator:
         self_instance: Any | None = None,
         by_alias: bool | None = None,
         by_name: bool | None = None,
+        strict_scope: Literal['recursive', 'top-level'] = 'recursive',
     ) -> bool:
         """
         Similar to [`validate_python()`][pydantic_core.SchemaValidator.validate_python] but returns a boolean.
@@ -160,6 +263,18 @@ class SchemaValidator:
         allow_partial: bool | Literal['off', 'on', 'trailing-strings'] = False,
         by_alias: bool | None = None,
         by_name: bool | None = None,
+        strict_scope: Literal['recursive', 'top-level'] = 'recursive',
+        max_errors: int | None = None,
+        fail_fast: bool = False,
+        trace: bool = False,
+        exactness: bool = False,
+        partial_report: bool = False,
+        partial_update: bool = False,
+        on_extra_ignored: Callable[[tuple[str, ...]], None] | None = None,
+        provenance: bool = False,
+        union_mode: Literal['smart', 'left_to_right'] | None = None,
+        reference_time: datetime.datetime | float | None = None,
+        timeout_ms: int | None = None,
     ) -> Any:
         """
         Validate JSON data directly against the schema and return the validated Python object.
@@ -174,23 +289,246 @@ class SchemaValidator:
             input: The JSON data to validate.
             strict: Whether to validate the object in strict mode.
                 If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+                By default this applies to nested models and dataclasses too, see `strict_scope`.
             extra: Whether to ignore, allow, or forbid extra data during model validation.
                 If `None`, the value of [`CoreConfig.extra_fields_behavior`][pydantic_core.core_schema.CoreConfig] is used.
             context: The context to use for validation, this is passed to functional validators as
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
-            self_instance: An instance of a model set attributes on from validation.
+            self_instance: An instance of a model set attributes on from validation, or for a typed dict schema,
+                a `dict` to fill with the validated fields.
             allow_partial: Whether to allow partial validation; if `True` incomplete JSON will be parsed successfully
                 and errors in the last element of sequences and mappings are ignored.
                 `'trailing-strings'` means any final unfinished JSON string is included in the result.
             by_alias: Whether to use the field's alias when validating against the provided input data.
             by_name: Whether to use the field's name when validating against the provided input data.
+            strict_scope: Which models `strict` applies to; with `'recursive'` it applies to all nested models and
+                dataclasses, with `'top-level'` only to the outermost model or dataclass, nested ones using their
+                own `strict` config.
+            max_errors: The maximum number of errors to collect before validation stops, the resulting
+                [`ValidationError`][pydantic_core.ValidationError] is then marked as `truncated` if errors were
+                left out.
+                If `None`, the value of [`CoreConfig.max_errors`][pydantic_core.core_schema.CoreConfig] is used.
+            fail_fast: Whether to stop validating at the first error anywhere in the input, this is faster when
+                only the validity of the input is of interest.
+            trace: Whether to record how unions were validated, for debugging union validation.
+                If `True`, a [`ValidationResult`][pydantic_core.ValidationResult] is returned, its `trace` is a
+                list of [`UnionTrace`][pydantic_core.UnionTrace]s, one for each union validated outside other
+                unions.
+            exactness: Whether to return how exactly the input matched the schema, e.g. to log or reject input
+                which was only valid after lax coercion. If `True`, a
+                [`ValidationResult`][pydantic_core.ValidationResult] is returned, its `exactness` is `'exact'`,
+                `'strict'` or `'lax'`.
+            partial_report: Whether to return what `allow_partial` left out of the validated object, e.g. so
+                consumers of streamed input know which parts aren't there yet. If `True`, a
+                [`ValidationResult`][pydantic_core.ValidationResult] is returned, its `partial_report` is a list of
+                [`PartialOmission`][pydantic_core.PartialOmission]s. Only the last item of each sequence or mapping
+                can be left out, and only if it's invalid, or if it's incomplete and the JSON parser left it out.
+            partial_update: Whether to validate a partial update of a model, e.g. the body of an HTTP PATCH
+                request. Missing fields aren't errors and don't get their defaults, the model only has the fields
+                in the input, as listed in its `__pydantic_fields_set__`. Models nested in fields are validated in
+                full.
+            on_extra_ignored: Called with the location of each extra input left out of the output by
+                `extra_behavior='ignore'`, the names of the fields it's nested in and its key, e.g. to detect
+                inputs drifting from the schema without forbidding extra inputs. Inputs ignored within union members
+                are only reported for the member which is used.
+            provenance: Whether to return where the value of each field came from, e.g. for an audit trail of
+                which values were supplied, coerced or defaulted. If `True`, a
+                [`ValidationResult`][pydantic_core.ValidationResult] is returned, its `provenance` is a list of
+                [`ValueProvenance`][pydantic_core.ValueProvenance]s, one for each field of a model, dataclass or
+                typed dict which doesn't hold other fields.
+            union_mode: Overrides the `mode` of every `union` schema, e.g. `'left_to_right'` to use the first
+                choice which succeeds for latency-critical validation without building another validator.
+                If `None`, each union uses its own `mode`. Tagged unions aren't affected.
+            reference_time: The current time used by the `now_op` constraints of datetime and date fields, e.g. for
+                deterministic replay or backtesting, a datetime, naive datetimes being local time, or a unix
+                timestamp in seconds. It takes precedence over the validation context and the
+                [`CoreConfig.clock`][pydantic_core.core_schema.CoreConfig] config.
+            timeout_ms: The most milliseconds validation can take, checked for each item, field and union choice,
+                once it's exceeded validation is abandoned with a `validation_timeout` error, also when a wrap
+                validator function catches the `TimeoutError` its handler raises. A single slow step, e.g. matching
+                a pattern or calling a validator function, isn't interrupted.
 
         Raises:
             ValidationError: If validation fails or if the JSON data is invalid.
             Exception: Other error types maybe raised if internal errors occur.
 
         Returns:
-            The validated Python object.
+            The validated Python object, or a [`ValidationResult`][pydantic_core.ValidationResult] with it if
+                `trace`, `exactness`, `partial_report` or `provenance` are `True`.
+        """
+    def validate_json_items(
+        self,
+        input: str | bytes | bytearray | SupportsRead[str | bytes],
+        *,
+        strict: bool | None = None,
+        extra: ExtraBehavior | None = None,
+        context: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+        chunk_size: int = 65536,
+    ) -> Iterator[Any]:
+        """
+        Validate each item of a JSON array against the schema, yielding the validated items one at a time.
+
+        Only the item being validated is held in memory, so when `input` is a file-like object, JSON arrays
+        bigger than the memory available can be validated, e.g. `validate_json_items(open('export.json', 'rb'))`.
+
+        If an item fails validation, a [`ValidationError`][pydantic_core.ValidationError] is raised whose error
+        locations start with the item's index, iteration can then continue with the next item.
+        If the JSON is invalid, a `ValidationError` is raised and iteration stops.
+
+        Arguments:
+            input: The JSON array to validate, either in whole or as a file-like object with a `read` method
+                returning `str` or `bytes`.
+            strict: Whether to validate the items in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            extra: Whether to ignore, allow, or forbid extra data during model validation.
+                If `None`, the value of [`CoreConfig.extra_fields_behavior`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            by_alias: Whether to use the field's alias when validating against the provided input data.
+            by_name: Whether to use the field's name when validating against the provided input data.
+            chunk_size: The number of bytes, or characters for text files, to read from `input` at a time.
+
+        Raises:
+            ValidationError: If an item fails validation or if the JSON data is invalid.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            An iterator of the validated items.
+        """
+    def incremental_json(
+        self,
+        *,
+        allow_partial: Literal[True, 'on', 'trailing-strings'] = True,
+        strict: bool | None = None,
+        extra: ExtraBehavior | None = None,
+        context: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+    ) -> IncrementalValidation:
+        """
+        Start validating a JSON document which arrives in parts, e.g. streamed from an LLM.
+
+        Each part is passed to [`IncrementalValidation.resume`][pydantic_core.IncrementalValidation.resume], which
+        validates the JSON received so far as partial. Items of arrays and objects which are complete aren't
+        validated again, so validating a document in many parts doesn't validate the start of it many times.
+
+        Likewise when the document is an array or object, its complete items are only parsed once, each part only
+        parses the JSON after the last complete item.
+
+        Arguments:
+            allow_partial: How to validate the incomplete document, `'trailing-strings'` means any final
+                unfinished JSON string is included in the result.
+            strict: Whether to validate the document in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            extra: Whether to ignore, allow, or forbid extra data during model validation.
+                If `None`, the value of [`CoreConfig.extra_fields_behavior`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            by_alias: Whether to use the field's alias when validating against the provided input data.
+            by_name: Whether to use the field's name when validating against the provided input data.
+
+        Raises:
+            ValueError: If `allow_partial` is `False` or `'off'`.
+
+        Returns:
+            An [`IncrementalValidation`][pydantic_core.IncrementalValidation] with no JSON received yet.
+        """
+    def validate_many(
+        self,
+        inputs: Iterable[Any],
+        *,
+        input_type: Literal['python', 'json', 'string'] = 'python',
+        strict: bool | None = None,
+        extra: ExtraBehavior | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+    ) -> list[Any | ValidationError]:
+        """
+        Validate a batch of independent inputs against the schema, e.g. the rows of an ETL job.
+
+        This is faster than calling `validate_python`, `validate_json` or `validate_strings` for each input, and
+        an input failing validation doesn't stop the others being validated.
+        With `input_type='json'`, all the inputs are parsed with the GIL released before any are validated.
+
+        Arguments:
+            inputs: The inputs to validate.
+            input_type: How the inputs are validated, as Python objects like `validate_python`, as JSON `str`,
+                `bytes` or `bytearray` like `validate_json`, or as strings like `validate_strings`.
+            strict: Whether to validate the inputs in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            extra: Whether to ignore, allow, or forbid extra data during model validation.
+                If `None`, the value of [`CoreConfig.extra_fields_behavior`][pydantic_core.core_schema.CoreConfig] is used.
+            from_attributes: Whether to validate objects as inputs to models by extracting attributes,
+                only used when `input_type` is `'python'`.
+                If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            by_alias: Whether to use the field's alias when validating against the provided input data.
+            by_name: Whether to use the field's name when validating against the provided input data.
+
+        Raises:
+            ValueError: If `input_type` isn't one of `'python'`, `'json'` or `'string'`.
+            Exception: Other error types maybe raised if internal errors occur, these stop the batch.
+
+        Returns:
+            A list with, for each input in order, the validated Python object or the
+                [`ValidationError`][pydantic_core.ValidationError] if the input failed validation.
+        """
+    def validate_many_arrow(
+        self,
+        inputs: Iterable[Any],
+        *,
+        input_type: Literal['python', 'json', 'string'] = 'python',
+        strict: bool | None = None,
+        extra: ExtraBehavior | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+    ) -> ArrowBatch:
+        """
+        Validate a batch of inputs like [`validate_many`][pydantic_core.SchemaValidator.validate_many], collecting
+        the valid results into the columns of an Arrow record batch instead of a list.
+
+        The results must be dicts, e.g. from a typed dict schema, or models or dataclasses, the columns are the
+        fields of the first result, and each result is added to the columns as soon as it's validated, so e.g.
+        `pyarrow.record_batch(batch)` or `polars.DataFrame(batch)` don't need to convert the results to rows.
+        Fields which are missing or `None` are null, and the type of each column is that of its first value
+        which isn't `None`:
+
+        - `bool` is `bool`, `int` is `int64`, `float` is `float64`
+        - `str` is `large_string` and `bytes` is `large_binary`
+        - `datetime.date` is `date32`
+        - `datetime.datetime` is `timestamp[us]`, aware datetimes are converted to UTC and the column has the
+          `'UTC'` timezone, a column can't mix naive and aware datetimes
+
+        Arguments:
+            inputs: The inputs to validate.
+            input_type: How the inputs are validated, see `validate_many`.
+            strict: Whether to validate the inputs in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            extra: Whether to ignore, allow, or forbid extra data during model validation.
+                If `None`, the value of [`CoreConfig.extra_fields_behavior`][pydantic_core.core_schema.CoreConfig] is used.
+            from_attributes: Whether to validate objects as inputs to models by extracting attributes,
+                only used when `input_type` is `'python'`.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            by_alias: Whether to use the field's alias when validating against the provided input data.
+            by_name: Whether to use the field's name when validating against the provided input data.
+
+        Raises:
+            ValueError: If `input_type` isn't one of `'python'`, `'json'` or `'string'`.
+            TypeError: If a result isn't a dict, model or dataclass, has fields the first result doesn't have,
+                or has a value of another type than its column or which Arrow doesn't have a type for.
+            OverflowError: If an int doesn't fit in an `int64`.
+
+        Returns:
+            An [`ArrowBatch`][pydantic_core.ArrowBatch] with the valid results, and the errors of the inputs
+                which failed validation.
         """
     def validate_strings(
         self,
@@ -202,6 +540,19 @@ class SchemaValidator:
         allow_partial: bool | Literal['off', 'on', 'trailing-strings'] = False,
         by_alias: bool | None = None,
         by_name: bool | None = None,
+        strict_scope: Literal['recursive', 'top-level'] = 'recursive',
+        max_errors: int | None = None,
+        fail_fast: bool = False,
+        trace: bool = False,
+        exactness: bool = False,
+        partial_report: bool = False,
+        partial_update: bool = False,
+        on_extra_ignored: Callable[[tuple[str, ...]], None] | None = None,
+        provenance: bool = False,
+        union_mode: Literal['smart', 'left_to_right'] | None = None,
+        reference_time: datetime.datetime | float | None = None,
+        timeout_ms: int | None = None,
+        env_style: bool = False,
     ) -> Any:
         """
         Validate a string against the schema and return the validated Python object.
@@ -213,6 +564,7 @@ class SchemaValidator:
             input: The input as a string, or bytes/bytearray if `strict=False`.
             strict: Whether to validate the object in strict mode.
                 If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+                By default this applies to nested models and dataclasses too, see `strict_scope`.
             extra: Whether to ignore, allow, or forbid extra data during model validation.
                 If `None`, the value of [`CoreConfig.extra_fields_behavior`][pydantic_core.core_schema.CoreConfig] is used.
             context: The context to use for validation, this is passed to functional validators as
@@ -222,13 +574,63 @@ class SchemaValidator:
                 `'trailing-strings'` means any final unfinished JSON string is included in the result.
             by_alias: Whether to use the field's alias when validating against the provided input data.
             by_name: Whether to use the field's name when validating against the provided input data.
+            strict_scope: Which models `strict` applies to; with `'recursive'` it applies to all nested models and
+                dataclasses, with `'top-level'` only to the outermost model or dataclass, nested ones using their
+                own `strict` config.
+            max_errors: The maximum number of errors to collect before validation stops, the resulting
+                [`ValidationError`][pydantic_core.ValidationError] is then marked as `truncated` if errors were
+                left out.
+                If `None`, the value of [`CoreConfig.max_errors`][pydantic_core.core_schema.CoreConfig] is used.
+            fail_fast: Whether to stop validating at the first error anywhere in the input, this is faster when
+                only the validity of the input is of interest.
+            trace: Whether to record how unions were validated, for debugging union validation.
+                If `True`, a [`ValidationResult`][pydantic_core.ValidationResult] is returned, its `trace` is a
+                list of [`UnionTrace`][pydantic_core.UnionTrace]s, one for each union validated outside other
+                unions.
+            exactness: Whether to return how exactly the input matched the schema, e.g. to log or reject input
+                which was only valid after lax coercion. If `True`, a
+                [`ValidationResult`][pydantic_core.ValidationResult] is returned, its `exactness` is `'exact'`,
+                `'strict'` or `'lax'`.
+            partial_report: Whether to return what `allow_partial` left out of the validated object, e.g. so
+                consumers of streamed input know which parts aren't there yet. If `True`, a
+                [`ValidationResult`][pydantic_core.ValidationResult] is returned, its `partial_report` is a list of
+                [`PartialOmission`][pydantic_core.PartialOmission]s. Only the last item of each sequence or mapping
+                can be left out, and only if it's invalid.
+            partial_update: Whether to validate a partial update of a model, e.g. the body of an HTTP PATCH
+                request. Missing fields aren't errors and don't get their defaults, the model only has the fields
+                in the input, as listed in its `__pydantic_fields_set__`. Models nested in fields are validated in
+                full.
+            on_extra_ignored: Called with the location of each extra input left out of the output by
+                `extra_behavior='ignore'`, the names of the fields it's nested in and its key, e.g. to detect
+                inputs drifting from the schema without forbidding extra inputs. Inputs ignored within union members
+                are only reported for the member which is used.
+            provenance: Whether to return where the value of each field came from, e.g. for an audit trail of
+                which values were supplied, coerced or defaulted. If `True`, a
+                [`ValidationResult`][pydantic_core.ValidationResult] is returned, its `provenance` is a list of
+                [`ValueProvenance`][pydantic_core.ValueProvenance]s, one for each field of a model, dataclass or
+                typed dict which doesn't hold other fields.
+            union_mode: Overrides the `mode` of every `union` schema, e.g. `'left_to_right'` to use the first
+                choice which succeeds for latency-critical validation without building another validator.
+                If `None`, each union uses its own `mode`. Tagged unions aren't affected.
+            reference_time: The current time used by the `now_op` constraints of datetime and date fields, e.g. for
+                deterministic replay or backtesting, a datetime, naive datetimes being local time, or a unix
+                timestamp in seconds. It takes precedence over the validation context and the
+                [`CoreConfig.clock`][pydantic_core.core_schema.CoreConfig] config.
+            timeout_ms: The most milliseconds validation can take, checked for each item, field and union choice,
+                once it's exceeded validation is abandoned with a `validation_timeout` error, also when a wrap
+                validator function catches the `TimeoutError` its handler raises. A single slow step, e.g. matching
+                a pattern or calling a validator function, isn't interrupted.
+            env_style: Whether to also coerce the conventions of environment variables, e.g. for settings: `''` and
+                `'null'` are `None` where `None` is allowed, and lists, tuples and sets are comma-separated, e.g.
+                `'a, b'` is `['a', 'b']`. Bools already accept `'yes'`, `'no'`, `'on'` and `'off'`.
 
         Raises:
             ValidationError: If validation fails or if the JSON data is invalid.
             Exception: Other error types maybe raised if internal errors occur.
 
         Returns:
-            The validated Python object.
+            The validated Python object, or a [`ValidationResult`][pydantic_core.ValidationResult] with it if
+                `trace`, `exactness`, `partial_report` or `provenance` are `True`.
         """
     def validate_assignment(
         self,
@@ -241,76 +643,686 @@ class SchemaValidator:
         from_attributes: bool | None = None,
         context: Any | None = None,
         by_alias: bool | None = None,
-        by_name: bool | None = None,
-    ) -> dict[str, Any] | tuple[dict[str, Any], dict[str, Any] | None, set[str]]:
+        by_name: bool | None = None,
+        strict_scope: Literal['recursive', 'top-level'] = 'recursive',
+    ) -> dict[str, Any] | tuple[dict[str, Any], dict[str, Any] | None, set[str]]:
+        """
+        Validate an assignment to a field on a model.
+
+        Arguments:
+            obj: The model instance being assigned to.
+            field_name: The name of the field to validate assignment for.
+            field_value: The value to assign to the field.
+            strict: Whether to validate the object in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+                By default this applies to nested models and dataclasses too, see `strict_scope`.
+            extra: Whether to ignore, allow, or forbid extra data during model validation.
+                If `None`, the value of [`CoreConfig.extra_fields_behavior`][pydantic_core.core_schema.CoreConfig] is used.
+            from_attributes: Whether to validate objects as inputs to models by extracting attributes.
+                If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            by_alias: Whether to use the field's alias when validating against the provided input data.
+            by_name: Whether to use the field's name when validating against the provided input data.
+            strict_scope: Which models `strict` applies to; with `'recursive'` it applies to all nested models and
+                dataclasses, with `'top-level'` only to the outermost model or dataclass, nested ones using their
+                own `strict` config.
+
+        Raises:
+            ValidationError: If validation fails.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            Either the model dict or a tuple of `(model_data, model_extra, fields_set)`
+        """
+    def validate_into(
+        self,
+        obj: Any,
+        data: dict[str, Any],
+        *,
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+    ) -> set[str]:
+        """
+        Validate `data` and apply it onto an existing model instance, as though each item were assigned to
+        the instance in turn.
+
+        Frozen models and fields are respected, assignment validators are run and `__pydantic_fields_set__` is
+        updated. If validation of any item fails, the instance is restored to its original state.
+
+        Arguments:
+            obj: The model instance to apply the data to.
+            data: A mapping of field names to the new values for those fields.
+            strict: Whether to validate the data in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            from_attributes: Whether to validate objects as inputs to models by extracting attributes.
+                If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            by_alias: Whether to use the field's alias when validating against the provided input data.
+            by_name: Whether to use the field's name when validating against the provided input data.
+
+        Raises:
+            ValidationError: If validation of any item fails, with the errors for all invalid items.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            The names of the fields whose value changed.
+        """
+    def deep_copy(self, obj: Any, memo: dict[int, Any] | None = None) -> Any:
+        """
+        Deep copy a validated object, equivalent to `copy.deepcopy(obj, memo)` but faster, e.g. to implement
+        `model_copy(deep=True)`.
+
+        Dicts, lists, sets, tuples and model instances are copied in Rust, immutable values such as strings and
+        numbers are reused rather than copied, and any other objects are passed to `copy.deepcopy`. Nested model
+        instances whose class defines `__deepcopy__` are copied by calling it, `obj` itself is always copied
+        directly.
+
+        Arguments:
+            obj: The object to copy.
+            memo: The `copy.deepcopy` memo dict, pass this when calling `deep_copy` from `__deepcopy__`.
+
+        Returns:
+            The copied object.
+        """
+    def get_profile(self, *, reset: bool = False) -> dict[str, ValidatorProfile]:
+        """
+        Get the call counts and wall time recorded for each node of the validator, available when the validator
+        was created with [`CoreConfig.profile_validation`][pydantic_core.core_schema.CoreConfig] enabled.
+
+        Nodes are keyed by their path through the schema, e.g. `'model[Outer].model-fields.inner.model[Inner]'`,
+        the time recorded for a node includes the time spent in the nodes it contains.
+
+        Arguments:
+            reset: Whether to reset the recorded counts and times after getting them.
+
+        Raises:
+            RuntimeError: If profiling is not enabled for this validator.
+
+        Returns:
+            A dict mapping the path of each node to its [`ValidatorProfile`][pydantic_core.ValidatorProfile].
+        """
+    def clear_validation_cache(self) -> None:
+        """
+        Remove every entry from the cache of validated instances enabled by
+        [`CoreConfig.validation_cache_size`][pydantic_core.core_schema.CoreConfig], does nothing if caching isn't
+        enabled.
+        """
+    def construct(
+        self,
+        data: Any,
+        *,
+        fields_set: set[str] | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+    ) -> Any:
+        """
+        Create an instance of the model or dataclass of the schema from trusted data, without validating it,
+        like `BaseModel.model_construct`.
+
+        Values in `data` are used as they are, fields missing from it get their default, without
+        `validate_default` being applied, and fields without a default are left unset. Items of `data` which
+        aren't fields are kept as `__pydantic_extra__` if the model allows extra data, otherwise they're ignored.
+
+        The model's `post_init` is called, a dataclass's `__post_init__` isn't.
+
+        Arguments:
+            data: The values of the fields, for a root model the root value.
+            fields_set: The names of the fields to record as explicitly set, by default the fields found in `data`.
+            by_alias: Whether to find fields in `data` by their alias.
+            by_name: Whether to find fields in `data` by their name.
+
+        Raises:
+            TypeError: If the schema isn't for a model or dataclass.
+
+        Returns:
+            The constructed instance.
+        """
+    def fields(self) -> dict[str, dict[str, Any]]:
+        """
+        Describe the fields of the model, dataclass or typed dict of the schema, as found in the built validator
+        rather than the schema.
+
+        Schemas wrapped in validator functions or definition references are looked through. Dataclass fields
+        with `init=False` are left out as they can't be given.
+
+        Each field is described by a dict of:
+
+        * `required`: whether the field must be given
+        * `has_default`: whether the field has a default or a default factory
+        * `validation_alias`: the field's validation alias, a string or a list of paths, or `None`
+        * `serialization_alias`: the field's serialization alias, or `None`
+        * `type`: the name of the field's validator, e.g. `'int'` or `'nullable[str]'`
+        * `model`: the class of the model or dataclass the field holds, possibly as `None`, otherwise `None`
+
+        Raises:
+            TypeError: If the schema isn't for a model, dataclass or typed dict.
+
+        Returns:
+            The description of each field, by name.
+        """
+    def required_fields(self) -> list[str]:
+        """
+        The names of the fields which must be given, see [`fields`][pydantic_core.SchemaValidator.fields].
+        """
+    def aliases(
+        self, *, mode: Literal['validation', 'serialization'] = 'validation'
+    ) -> dict[str, str | list[list[str | int]]]:
+        """
+        The validation alias, or with `mode='serialization'` the serialization alias, of each field which has one,
+        by name, see [`fields`][pydantic_core.SchemaValidator.fields].
+
+        Raises:
+            ValueError: If `mode` isn't `'validation'` or `'serialization'`.
+        """
+    def defaults(self) -> dict[str, Any]:
+        """
+        The default of each field which has one, by name, see [`fields`][pydantic_core.SchemaValidator.fields].
+
+        Default factories aren't called, so fields with a default factory are left out.
+        """
+    def validate_merged(
+        self,
+        layers: Iterable[dict[str, Any]],
+        *,
+        strict: bool | None = None,
+        extra: ExtraBehavior | None = None,
+        context: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+    ) -> tuple[Any, dict[tuple[str | int, ...], int]]:
+        """
+        Merge layers of input, e.g. defaults, a config file and environment overrides, then validate the result.
+
+        Layers are merged in order, so later layers override earlier ones. How a field's values are combined is
+        set by the `merge_policy` of the field's schema: `'replace'` (the default) uses the value from the last
+        layer with the field, `'merge'` merges dicts key by key, applying the policies of the fields of nested
+        models and typed dicts, and `'append'` concatenates lists. A value which can't be combined, e.g. `None`
+        for a `'merge'` field, replaces the values from the layers before it. A field's values are merged whether
+        a layer has the field under its alias or its name, as allowed by `by_alias` and `by_name`, the merged input
+        has them under the key the field is looked up by first.
+
+        Arguments:
+            layers: The dicts to merge, from the lowest priority to the highest.
+            strict: Whether to validate the merged input in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            extra: Whether to ignore, allow, or forbid extra data during model validation.
+                If `None`, the value of [`CoreConfig.extra_fields_behavior`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            by_alias: Whether to use the field's alias when validating against the provided input data.
+            by_name: Whether to use the field's name when validating against the provided input data.
+
+        Raises:
+            ValidationError: If validation of the merged input fails.
+            TypeError: If a layer isn't a dict.
+
+        Returns:
+            A tuple of the validated value and a dict mapping the location of each value in the merged input
+            to the index of the layer it came from, e.g. `{('db', 'host'): 2, ('plugins', 0): 0}`.
+        """
+    def explain(
+        self,
+        input: Any,
+        *,
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+    ) -> ValidationExplanation:
+        """
+        Validate a Python object without raising, and report which fields were coerced from another type, which
+        fields used their default and which errors occurred.
+
+        A field is reported as coerced if its value was only valid in lax mode, so would fail in strict mode,
+        e.g. `'1'` for an `int` field. Only the innermost coerced fields are reported, e.g. a field holding a
+        model is only reported if none of the model's own fields are.
+
+        Arguments:
+            input: The Python object to validate.
+            strict: Whether to validate the object in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            from_attributes: Whether to validate objects as inputs to models by extracting attributes.
+                If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+
+        Raises:
+            Exception: Errors other than validation errors, e.g. from validator functions, are raised.
+
+        Returns:
+            A [`ValidationExplanation`][pydantic_core.ValidationExplanation] of the validation.
+        """
+    def check_examples(self) -> list[ExampleFailure]:
+        """
+        Validate the `examples` of the schema, and of every schema nested in it, reporting the examples which fail.
+
+        Nested schemas are validated on their own in Python mode, with the config passed to the validator and the
+        definitions of the outermost schema.
+
+        Raises:
+            TypeError: If a schema's `examples` isn't a list.
+            Exception: Errors other than validation errors, e.g. from validator functions, are raised.
+
+        Returns:
+            An [`ExampleFailure`][pydantic_core.ExampleFailure] for each example which failed, empty if they all passed.
+        """
+    def generate_example(self, *, seed: int | None = None, context: Any = None) -> Any:
+        """
+        Generate a valid instance of the schema, by sampling an input within the constraints of each validator and
+        validating it.
+
+        Bounds, lengths and patterns are respected, literals and enums are sampled from their members, models and
+        dataclasses are built from generated fields, and nullables end recursive schemas. Inputs which still fail
+        validation, e.g. because of validator functions, are replaced by new ones a few times.
+
+        Arguments:
+            seed: The seed of the random generator, the same seed generates the same example.
+                If `None`, a random seed is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+
+        Raises:
+            TypeError: If a validator can't generate inputs, e.g. a plain function validator.
+            ValueError: If no valid example was generated, the last validation error is the cause.
+
+        Returns:
+            The validated example.
+        """
+    def get_default_value(self, *, strict: bool | None = None, context: Any = None) -> Some | None:
+        """
+        Get the default value for the schema, including running default value validation.
+
+        Arguments:
+            strict: Whether to validate the default value in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+
+        Raises:
+            ValidationError: If validation fails.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            `None` if the schema has no default value, otherwise a [`Some`][pydantic_core.Some] containing the default.
+        """
+
+@final
+class ValidationResult:
+    """
+    The result of [`SchemaValidator.validate_python`][pydantic_core.SchemaValidator.validate_python],
+    `validate_json` or `validate_strings` with `trace`, `exactness`, `partial_report` or `provenance`.
+
+    The attributes of the options which weren't used are `None`.
+    """
+
+    @property
+    def output(self) -> Any:
+        """The validated object."""
+    @property
+    def trace(self) -> list[UnionTrace] | None:
+        """How each union validated outside other unions was validated, with `trace=True`."""
+    @property
+    def exactness(self) -> Literal['exact', 'strict', 'lax'] | None:
+        """How exactly the input matched the schema, with `exactness=True`."""
+    @property
+    def partial_report(self) -> list[PartialOmission] | None:
+        """The items `allow_partial` left out of the validated object, with `partial_report=True`."""
+    @property
+    def provenance(self) -> list[ValueProvenance] | None:
+        """Where the value of each field came from, with `provenance=True`."""
+
+@final
+class ArrowBatch:
+    """
+    The results of [`SchemaValidator.validate_many_arrow`][pydantic_core.SchemaValidator.validate_many_arrow],
+    exported as an Arrow record batch with the
+    [Arrow PyCapsule interface](https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html).
+
+    The columns are shared with the arrays exported, they aren't copied.
+    """
+
+    @property
+    def num_rows(self) -> int:
+        """The number of valid results."""
+    @property
+    def column_names(self) -> list[str]:
+        """The names of the columns, the fields of the first valid result."""
+    @property
+    def errors(self) -> list[tuple[int, ValidationError]]:
+        """The index of each input which failed validation, and its error, these inputs have no row."""
+    def __arrow_c_schema__(self) -> Any:
+        """The schema of the record batch, a struct with a field for each column, as an `arrow_schema` capsule."""
+    def __arrow_c_array__(self, requested_schema: Any | None = None) -> tuple[Any, Any]:
+        """
+        The schema and the struct array of the record batch, as `arrow_schema` and `arrow_array` capsules.
+
+        `requested_schema` is ignored, the columns always have the types of their values.
+        """
+    def __len__(self) -> int: ...
+
+class IncrementalValidation:
+    """
+    Validation of a JSON document as more of it arrives, see
+    [`SchemaValidator.incremental_json`][pydantic_core.SchemaValidator.incremental_json].
+
+    Lists, dicts, typed dicts and models keep the validated values of their complete items, these are reused
+    rather than validated again, and are shared between the values returned.
+    Items validated by other schemas, e.g. unions and function validators, are validated again each time.
+    """
+
+    @property
+    def value(self) -> Any | None:
+        """The last value validated, `None` if the JSON received so far hasn't been valid."""
+    def resume(self, suffix: str | bytes | bytearray) -> Any:
+        """
+        Add `suffix` to the JSON received so far and validate the incomplete document.
+
+        If validation fails, `suffix` is still kept, so validation can be resumed with the next part.
+
+        Arguments:
+            suffix: The next part of the JSON document.
+
+        Raises:
+            ValidationError: If the JSON received so far is invalid, e.g. required fields haven't arrived yet.
+
+        Returns:
+            The value validated from the JSON received so far.
+        """
+    def finish(self, suffix: str | bytes | bytearray | None = None) -> Any:
+        """
+        Add `suffix` to the JSON received so far and validate it as a complete document.
+
+        Arguments:
+            suffix: The last part of the JSON document, if it hasn't all been passed to `resume`.
+
+        Raises:
+            ValidationError: If the JSON document is invalid or incomplete.
+
+        Returns:
+            The value validated from the whole document.
+        """
+
+# In reality, `bool` should be replaced by `Literal[True]` but mypy fails to correctly apply bidirectional type inference
+# (e.g. when using `{'a': {'b': True}}`).
+_IncEx: TypeAlias = set[int] | set[str] | Mapping[int, _IncEx | bool] | Mapping[str, _IncEx | bool]
+_Fallback: TypeAlias = Callable[[Any], Any] | Sequence[Callable[[Any], Any]]
+
+@final
+class SchemaSerializer:
+    """
+    `SchemaSerializer` is the Python wrapper for `pydantic-core`'s Rust serialization logic, internally it owns one
+    `CombinedSerializer` which may in turn own more `CombinedSerializer`s which make up the full schema serializer.
+
+    Like [`SchemaValidator`][pydantic_core.SchemaValidator], serializers pickle as their core schema, config and
+    `subclass_serializers`, and are built again when unpickled.
+    """
+
+    def __init__(
+        self,
+        schema: CoreSchema,
+        config: CoreConfig | None = None,
+        subclass_serializers: Mapping[type[Any], CoreSchema] | None = None,
+    ) -> None:
+        """Initializes the `SchemaSerializer`.
+
+        Arguments:
+            schema: The `CoreSchema` to use for serialization.
+            config: Optionally a [`CoreConfig`][pydantic_core.core_schema.CoreConfig] to to configure serialization.
+            subclass_serializers: Schemas to serialize subclasses of the models and dataclasses in `schema` with,
+                by class. When a model or dataclass value is an instance of a subclass rather than the class in
+                its schema, the schema registered for its exact class is used, so its own fields are serialized.
+                Subclasses which aren't registered are serialized as before, according to `serialize_as_any`.
+        """
+
+    def __new__(cls, schema: CoreSchema, config: CoreConfig | None = None) -> Self: ...
+    def to_python(
+        self,
+        value: Any,
+        *,
+        mode: str | None = None,
+        include: _IncEx | None = None,
+        exclude: _IncEx | None = None,
+        by_alias: bool | None = None,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        exclude_computed_fields: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error', 'collect'] = True,
+        fallback: _Fallback | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+        shared_references: Literal['ref', 'error'] | None = None,
+    ) -> Any:
+        """
+        Serialize/marshal a Python object to a Python object including transforming and filtering data.
+
+        Arguments:
+            value: The Python object to serialize.
+            mode: The serialization mode to use, either `'python'` or `'json'`, defaults to `'python'`. In JSON mode,
+                all values are converted to JSON compatible types, e.g. `None`, `int`, `float`, `str`, `list`, `dict`.
+            include: A set of fields to include, if `None` all fields are included.
+            exclude: A set of fields to exclude, if `None` no fields are excluded.
+            by_alias: Whether to use the alias names of fields.
+            exclude_unset: Whether to exclude fields that are not set,
+                e.g. are not included in `__pydantic_fields_set__`.
+            exclude_defaults: Whether to exclude fields that are equal to their default value.
+            exclude_none: Whether to exclude fields that have a value of `None`.
+            exclude_computed_fields: Whether to exclude computed fields.
+            round_trip: Whether to enable serialization and validation round-trip support.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
+                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError],
+                "collect" returns the warnings alongside the serialized object, see the return value.
+            fallback: A function to call when an unknown value is encountered, or a sequence of functions tried
+                in order, where a function can raise `NotImplementedError` to pass the value on to the next one,
+                if `None` (or every function raises `NotImplementedError`) a
+                [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+            shared_references: What to do with an object which occurs more than once in `value`, if `None` it's
+                serialized each time. `'ref'` serializes it where it first occurs and replaces later occurrences with
+                `{'$ref': '#/json/pointer'}` to that output, `'error'` raises a
+                [`PydanticSerializationError`][pydantic_core.PydanticSerializationError]. Only objects serialized
+                to a non-empty dict, e.g. models, dataclasses and dicts, are considered.
+
+        Raises:
+            PydanticSerializationError: If serialization fails and no `fallback` function is provided.
+
+        Returns:
+            The serialized Python object, or with `warnings='collect'` a tuple of the serialized object and a list
+            of [`SerializationWarningDetails`][pydantic_core.SerializationWarningDetails].
+        """
+    @overload
+    def to_json(
+        self,
+        value: Any,
+        *,
+        indent: int | None = None,
+        ensure_ascii: bool = False,
+        sort_keys: bool = False,
+        include: _IncEx | None = None,
+        exclude: _IncEx | None = None,
+        by_alias: bool | None = None,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        exclude_computed_fields: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] = True,
+        fallback: _Fallback | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+        writer: None = None,
+        shared_references: Literal['ref', 'error'] | None = None,
+        compress: Literal['gzip', 'zstd'] | None = None,
+        level: int | None = None,
+    ) -> bytes:
+        """
+        Serialize a Python object to JSON including transforming and filtering data.
+
+        Arguments:
+            value: The Python object to serialize.
+            indent: If `None`, the JSON will be compact, otherwise it will be pretty-printed with the indent provided.
+            ensure_ascii: If `True`, the output is guaranteed to have all incoming non-ASCII characters escaped.
+                If `False` (the default), these characters will be output as-is.
+            sort_keys: Whether to sort the keys of every JSON object, so the output doesn't depend on the order of
+                fields or dict items.
+            include: A set of fields to include, if `None` all fields are included.
+            exclude: A set of fields to exclude, if `None` no fields are excluded.
+            by_alias: Whether to use the alias names of fields.
+            exclude_unset: Whether to exclude fields that are not set,
+                e.g. are not included in `__pydantic_fields_set__`.
+            exclude_defaults: Whether to exclude fields that are equal to their default value.
+            exclude_none: Whether to exclude fields that have a value of `None`.
+            exclude_computed_fields: Whether to exclude computed fields.
+            round_trip: Whether to enable serialization and validation round-trip support.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
+                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError],
+                "collect" returns the warnings alongside the JSON, see the return value.
+            fallback: A function to call when an unknown value is encountered, or a sequence of functions tried
+                in order, where a function can raise `NotImplementedError` to pass the value on to the next one,
+                if `None` (or every function raises `NotImplementedError`) a
+                [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+            writer: A file-like object to write the JSON to, e.g. a file opened in binary mode, anything with a
+                `write` method accepting `bytes`. The JSON is passed to `write` in chunks as it's serialized, so
+                large values can be written without holding all their JSON in memory.
+            shared_references: What to do with an object which occurs more than once in `value`, if `None` it's
+                serialized each time. `'ref'` serializes it where it first occurs and replaces later occurrences with
+                `{"$ref": "#/json/pointer"}` to that output, `'error'` raises a
+                [`PydanticSerializationError`][pydantic_core.PydanticSerializationError]. Only objects serialized
+                to a non-empty object, e.g. models, dataclasses and dicts, are considered.
+            compress: Compress the JSON with `'gzip'` or `'zstd'` as it's serialized, so the returned bytes, or
+                the chunks passed to `writer`, are compressed. Each codec is a cargo feature which builds can leave
+                out, `capabilities()['compression']` lists those available.
+            level: The compression level, 0 to 9 for gzip (default 6), or up to 22 for zstd (default 3) where
+                negative levels are faster.
+
+        Raises:
+            PydanticSerializationError: If serialization fails and no `fallback` function is provided.
+
+        Returns:
+           JSON bytes, or `None` if `writer` is provided. With `warnings='collect'`, a tuple of that and a list of
+           [`SerializationWarningDetails`][pydantic_core.SerializationWarningDetails].
+        """
+    @overload
+    def to_json(
+        self,
+        value: Any,
+        *,
+        indent: int | None = None,
+        ensure_ascii: bool = False,
+        sort_keys: bool = False,
+        include: _IncEx | None = None,
+        exclude: _IncEx | None = None,
+        by_alias: bool | None = None,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        exclude_computed_fields: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] = True,
+        fallback: _Fallback | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+        writer: SupportsWrite[bytes],
+        shared_references: Literal['ref', 'error'] | None = None,
+        compress: Literal['gzip', 'zstd'] | None = None,
+        level: int | None = None,
+    ) -> None: ...
+    @overload
+    def to_json(
+        self,
+        value: Any,
+        *,
+        indent: int | None = None,
+        ensure_ascii: bool = False,
+        sort_keys: bool = False,
+        include: _IncEx | None = None,
+        exclude: _IncEx | None = None,
+        by_alias: bool | None = None,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        exclude_computed_fields: bool = False,
+        round_trip: bool = False,
+        warnings: Literal['collect'],
+        fallback: _Fallback | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+        writer: SupportsWrite[bytes] | None = None,
+        shared_references: Literal['ref', 'error'] | None = None,
+        compress: Literal['gzip', 'zstd'] | None = None,
+        level: int | None = None,
+    ) -> tuple[bytes | None, list[SerializationWarningDetails]]: ...
+    def to_jsonl(
+        self,
+        values: Iterable[Any],
+        *,
+        ensure_ascii: bool = False,
+        include: _IncEx | None = None,
+        exclude: _IncEx | None = None,
+        by_alias: bool | None = None,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        exclude_computed_fields: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] = True,
+        fallback: _Fallback | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+    ) -> bytes:
         """
-        Validate an assignment to a field on a model.
-
-        Arguments:
-            obj: The model instance being assigned to.
-            field_name: The name of the field to validate assignment for.
-            field_value: The value to assign to the field.
-            strict: Whether to validate the object in strict mode.
-                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
-            extra: Whether to ignore, allow, or forbid extra data during model validation.
-                If `None`, the value of [`CoreConfig.extra_fields_behavior`][pydantic_core.core_schema.CoreConfig] is used.
-            from_attributes: Whether to validate objects as inputs to models by extracting attributes.
-                If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
-            context: The context to use for validation, this is passed to functional validators as
-                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
-            by_alias: Whether to use the field's alias when validating against the provided input data.
-            by_name: Whether to use the field's name when validating against the provided input data.
-
-        Raises:
-            ValidationError: If validation fails.
-            Exception: Other error types maybe raised if internal errors occur.
+        Serialize each of `values` to a line of JSON, giving newline-delimited JSON (JSON lines).
 
-        Returns:
-            Either the model dict or a tuple of `(model_data, model_extra, fields_set)`
-        """
-    def get_default_value(self, *, strict: bool | None = None, context: Any = None) -> Some | None:
-        """
-        Get the default value for the schema, including running default value validation.
+        Each value is serialized as `to_json` would serialize it, followed by a newline, so the schema
+        describes a single value, not the collection.
+        All the lines are written to the same buffer, which is faster than calling `to_json` for each value.
 
         Arguments:
-            strict: Whether to validate the default value in strict mode.
-                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
-            context: The context to use for validation, this is passed to functional validators as
-                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            values: The Python objects to serialize, any iterable.
+            ensure_ascii: If `True`, the output is guaranteed to have all incoming non-ASCII characters escaped.
+                If `False` (the default), these characters will be output as-is.
+            include: A set of fields to include in each value, if `None` all fields are included.
+            exclude: A set of fields to exclude from each value, if `None` no fields are excluded.
+            by_alias: Whether to use the alias names of fields.
+            exclude_unset: Whether to exclude fields that are not set,
+                e.g. are not included in `__pydantic_fields_set__`.
+            exclude_defaults: Whether to exclude fields that are equal to their default value.
+            exclude_none: Whether to exclude fields that have a value of `None`.
+            exclude_computed_fields: Whether to exclude computed fields.
+            round_trip: Whether to enable serialization and validation round-trip support.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
+                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
+            fallback: A function to call when an unknown value is encountered, or a sequence of functions tried
+                in order, where a function can raise `NotImplementedError` to pass the value on to the next one,
+                if `None` (or every function raises `NotImplementedError`) a
+                [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
 
         Raises:
-            ValidationError: If validation fails.
-            Exception: Other error types maybe raised if internal errors occur.
+            PydanticSerializationError: If serialization fails and no `fallback` function is provided.
 
         Returns:
-            `None` if the schema has no default value, otherwise a [`Some`][pydantic_core.Some] containing the default.
-        """
-
-# In reality, `bool` should be replaced by `Literal[True]` but mypy fails to correctly apply bidirectional type inference
-# (e.g. when using `{'a': {'b': True}}`).
-_IncEx: TypeAlias = set[int] | set[str] | Mapping[int, _IncEx | bool] | Mapping[str, _IncEx | bool]
-
-@final
-class SchemaSerializer:
-    """
-    `SchemaSerializer` is the Python wrapper for `pydantic-core`'s Rust serialization logic, internally it owns one
-    `CombinedSerializer` which may in turn own more `CombinedSerializer`s which make up the full schema serializer.
-    """
-
-    def __init__(self, schema: CoreSchema, config: CoreConfig | None = None) -> None:
-        """Initializes the `SchemaSerializer`.
-
-        Arguments:
-            schema: The `CoreSchema` to use for serialization.
-            config: Optionally a [`CoreConfig`][pydantic_core.core_schema.CoreConfig] to to configure serialization.
+           JSON lines bytes, each line ending with a newline.
         """
-
-    def __new__(cls, schema: CoreSchema, config: CoreConfig | None = None) -> Self: ...
-    def to_python(
+    def to_msgpack(
         self,
         value: Any,
         *,
-        mode: str | None = None,
+        bytes_mode: Literal['bin', 'utf8', 'base64', 'hex'] = 'bin',
+        datetime_mode: Literal['timestamp', 'iso8601', 'seconds', 'milliseconds'] = 'timestamp',
+        decimal_mode: Literal['str', 'float'] = 'str',
         include: _IncEx | None = None,
         exclude: _IncEx | None = None,
         by_alias: bool | None = None,
@@ -320,17 +1332,24 @@ class SchemaSerializer:
         exclude_computed_fields: bool = False,
         round_trip: bool = False,
         warnings: bool | Literal['none', 'warn', 'error'] = True,
-        fallback: Callable[[Any], Any] | None = None,
+        fallback: _Fallback | None = None,
         serialize_as_any: bool = False,
         context: Any | None = None,
-    ) -> Any:
+    ) -> bytes:
         """
-        Serialize/marshal a Python object to a Python object including transforming and filtering data.
+        Serialize a Python object to [MessagePack](https://msgpack.org) bytes.
+
+        Values are serialized as `to_json` would serialize them, except for bytes, datetimes and decimals,
+        which msgpack can represent natively. Dict keys are serialized as strings, as in JSON.
 
         Arguments:
             value: The Python object to serialize.
-            mode: The serialization mode to use, either `'python'` or `'json'`, defaults to `'python'`. In JSON mode,
-                all values are converted to JSON compatible types, e.g. `None`, `int`, `float`, `str`, `list`, `dict`.
+            bytes_mode: How to serialize bytes, `'bin'` (the default) writes msgpack binary data, the other
+                modes write strings as with the `ser_json_bytes` config.
+            datetime_mode: How to serialize datetimes, `'timestamp'` (the default) writes the msgpack timestamp
+                extension type, the other modes are as with the `ser_json_temporal` config.
+                Naive datetimes are handled according to `ser_naive_datetime`, and are otherwise taken to be UTC.
+            decimal_mode: How to serialize decimals, as strings (the default) or as floats.
             include: A set of fields to include, if `None` all fields are included.
             exclude: A set of fields to exclude, if `None` no fields are excluded.
             by_alias: Whether to use the alias names of fields.
@@ -342,24 +1361,26 @@ class SchemaSerializer:
             round_trip: Whether to enable serialization and validation round-trip support.
             warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
                 "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
-            fallback: A function to call when an unknown value is encountered,
-                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            fallback: A function to call when an unknown value is encountered, or a sequence of functions tried
+                in order, where a function can raise `NotImplementedError` to pass the value on to the next one,
+                if `None` (or every function raises `NotImplementedError`) a
+                [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
             serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
             context: The context to use for serialization, this is passed to functional serializers as
                 [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
 
         Raises:
-            PydanticSerializationError: If serialization fails and no `fallback` function is provided.
+            PydanticSerializationError: If serialization fails and no `fallback` function is provided,
+                or the value can't be represented in msgpack, e.g. an integer which doesn't fit in 64 bits.
 
         Returns:
-            The serialized Python object.
+           msgpack bytes.
         """
-    def to_json(
+    def hash(
         self,
         value: Any,
         *,
-        indent: int | None = None,
-        ensure_ascii: bool = False,
+        algorithm: str = 'sha256',
         include: _IncEx | None = None,
         exclude: _IncEx | None = None,
         by_alias: bool | None = None,
@@ -369,18 +1390,21 @@ class SchemaSerializer:
         exclude_computed_fields: bool = False,
         round_trip: bool = False,
         warnings: bool | Literal['none', 'warn', 'error'] = True,
-        fallback: Callable[[Any], Any] | None = None,
+        fallback: _Fallback | None = None,
         serialize_as_any: bool = False,
         context: Any | None = None,
-    ) -> bytes:
+    ) -> str:
         """
-        Serialize a Python object to JSON including transforming and filtering data.
+        Hash the canonical JSON serialization of a Python object, e.g. for use as a cache key.
+
+        The canonical serialization is compact JSON with the keys of every object sorted and integral floats
+        written as integers, so values which serialize to equal JSON always have the same hash. The JSON is
+        streamed through the hasher rather than being built in memory.
 
         Arguments:
-            value: The Python object to serialize.
-            indent: If `None`, the JSON will be compact, otherwise it will be pretty-printed with the indent provided.
-            ensure_ascii: If `True`, the output is guaranteed to have all incoming non-ASCII characters escaped.
-                If `False` (the default), these characters will be output as-is.
+            value: The Python object to hash.
+            algorithm: The name of the hash algorithm to use, any algorithm supported by
+                [`hashlib.new`][hashlib.new] may be used.
             include: A set of fields to include, if `None` all fields are included.
             exclude: A set of fields to exclude, if `None` no fields are excluded.
             by_alias: Whether to use the alias names of fields.
@@ -392,24 +1416,70 @@ class SchemaSerializer:
             round_trip: Whether to enable serialization and validation round-trip support.
             warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
                 "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
-            fallback: A function to call when an unknown value is encountered,
-                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            fallback: A function to call when an unknown value is encountered, or a sequence of functions tried
+                in order, where a function can raise `NotImplementedError` to pass the value on to the next one,
+                if `None` (or every function raises `NotImplementedError`) a
+                [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
             serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
             context: The context to use for serialization, this is passed to functional serializers as
                 [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
 
         Raises:
             PydanticSerializationError: If serialization fails and no `fallback` function is provided.
+            ValueError: If the hash algorithm is not supported.
+
+        Returns:
+           The hex digest of the hash.
+        """
+
+@final
+class SchemaJsonifier:
+    """
+    `SchemaJsonifier` generates a [JSON Schema](https://json-schema.org/draft/2020-12) document from a core schema.
+    """
+
+    def __init__(self, schema: CoreSchema, config: CoreConfig | None = None) -> None:
+        """Initializes the `SchemaJsonifier`.
+
+        Arguments:
+            schema: The `CoreSchema` to generate a JSON Schema for.
+            config: Optionally a [`CoreConfig`][pydantic_core.core_schema.CoreConfig] to configure generation,
+                e.g. `ser_json_timedelta` and `ser_json_bytes` change the JSON Schema of serialized values.
+        """
+
+    def __new__(cls, schema: CoreSchema, config: CoreConfig | None = None) -> Self: ...
+    def json_schema(
+        self,
+        *,
+        mode: Literal['validation', 'serialization'] = 'validation',
+        by_alias: bool = True,
+        ref_template: str = '#/$defs/{ref}',
+    ) -> dict[str, Any]:
+        """
+        Generate the JSON Schema of the core schema.
+
+        Schemas with a `ref` are added to `$defs` and referred to with `$ref`.
+
+        Arguments:
+            mode: Whether to describe the input accepted by validation, or the output of serialization.
+            by_alias: Whether to use the validation or serialization alias of fields as property names.
+            ref_template: The template for `$ref` values, `{ref}` is replaced with the schema's `ref`.
+
+        Raises:
+            SchemaError: If the core schema contains a schema type which has no JSON Schema equivalent.
+            ValueError: If `ref_template` doesn't contain `{ref}`.
 
         Returns:
-           JSON bytes.
+            The JSON Schema as a dict.
         """
 
+
 def to_json(
     value: Any,
     *,
     indent: int | None = None,
     ensure_ascii: bool = False,
+    sort_keys: bool = False,
     include: _IncEx | None = None,
     exclude: _IncEx | None = None,
     # Note: In Pydantic 2.11, the default value of `by_alias` on `SchemaSerializer` was changed from `True` to `None`,
@@ -423,9 +1493,11 @@ def to_json(
     bytes_mode: Literal['utf8', 'base64', 'hex'] = 'utf8',
     inf_nan_mode: Literal['null', 'constants', 'strings'] = 'constants',
     serialize_unknown: bool = False,
-    fallback: Callable[[Any], Any] | None = None,
+    fallback: _Fallback | None = None,
     serialize_as_any: bool = False,
     context: Any | None = None,
+    compress: Literal['gzip', 'zstd'] | None = None,
+    level: int | None = None,
 ) -> bytes:
     """
     Serialize a Python object to JSON including transforming and filtering data.
@@ -437,6 +1509,8 @@ def to_json(
         indent: If `None`, the JSON will be compact, otherwise it will be pretty-printed with the indent provided.
         ensure_ascii: If `True`, the output is guaranteed to have all incoming non-ASCII characters escaped.
             If `False` (the default), these characters will be output as-is.
+        sort_keys: Whether to sort the keys of every JSON object, so the output doesn't depend on the order of
+            fields or dict items.
         include: A set of fields to include, if `None` all fields are included.
         exclude: A set of fields to exclude, if `None` no fields are excluded.
         by_alias: Whether to use the alias names of fields.
@@ -450,11 +1524,15 @@ def to_json(
         inf_nan_mode: How to serialize `Infinity`, `-Infinity` and `NaN` values, either `'null'`, `'constants'`, or `'strings'`.
         serialize_unknown: Attempt to serialize unknown types, `str(value)` will be used, if that fails
             `"<Unserializable {value_type} object>"` will be used.
-        fallback: A function to call when an unknown value is encountered,
-            if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+        fallback: A function to call when an unknown value is encountered, or a sequence of functions tried
+            in order, where a function can raise `NotImplementedError` to pass the value on to the next one,
+            if `None` (or every function raises `NotImplementedError`) a
+            [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
         serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
         context: The context to use for serialization, this is passed to functional serializers as
             [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+        compress: Compress the JSON with `'gzip'` or `'zstd'` as it's serialized.
+        level: The compression level, see [`SchemaSerializer.to_json`][pydantic_core.SchemaSerializer.to_json].
 
     Raises:
         PydanticSerializationError: If serialization fails and no `fallback` function is provided.
@@ -495,6 +1573,7 @@ def from_json(
 def to_jsonable_python(
     value: Any,
     *,
+    sort_keys: bool = False,
     include: _IncEx | None = None,
     exclude: _IncEx | None = None,
     # Note: In Pydantic 2.11, the default value of `by_alias` on `SchemaSerializer` was changed from `True` to `None`,
@@ -508,7 +1587,7 @@ def to_jsonable_python(
     bytes_mode: Literal['utf8', 'base64', 'hex'] = 'utf8',
     inf_nan_mode: Literal['null', 'constants', 'strings'] = 'constants',
     serialize_unknown: bool = False,
-    fallback: Callable[[Any], Any] | None = None,
+    fallback: _Fallback | None = None,
     serialize_as_any: bool = False,
     context: Any | None = None,
 ) -> Any:
@@ -520,6 +1599,8 @@ def to_jsonable_python(
 
     Args:
         value: The Python object to serialize.
+        sort_keys: Whether to sort the keys of every dict in the output, so it doesn't depend on the order of
+            fields or dict items.
         include: A set of fields to include, if `None` all fields are included.
         exclude: A set of fields to exclude, if `None` no fields are excluded.
         by_alias: Whether to use the alias names of fields.
@@ -533,8 +1614,10 @@ def to_jsonable_python(
         inf_nan_mode: How to serialize `Infinity`, `-Infinity` and `NaN` values, either `'null'`, `'constants'`, or `'strings'`.
         serialize_unknown: Attempt to serialize unknown types, `str(value)` will be used, if that fails
             `"<Unserializable {value_type} object>"` will be used.
-        fallback: A function to call when an unknown value is encountered,
-            if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+        fallback: A function to call when an unknown value is encountered, or a sequence of functions tried
+            in order, where a function can raise `NotImplementedError` to pass the value on to the next one,
+            if `None` (or every function raises `NotImplementedError`) a
+            [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
         serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
         context: The context to use for serialization, this is passed to functional serializers as
             [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
@@ -546,14 +1629,93 @@ def to_jsonable_python(
         The serialized Python object.
     """
 
+def compare_secrets(a: str | bytes, b: str | bytes) -> bool:
+    """
+    Compare two secrets for equality in constant time, i.e. in time which depends only on their lengths and not
+    their contents, so the comparison doesn't leak how much of the secrets matched.
+
+    pydantic-core has no secret types of its own, and doesn't use this function when validating, it's a helper
+    for secret types defined elsewhere, e.g. to implement their `__eq__`.
+
+    Arguments:
+        a: The first secret.
+        b: The second secret, of the same type as `a`.
+
+    Raises:
+        TypeError: If `a` and `b` are not both `str` or both `bytes`.
+
+    Returns:
+        Whether the secrets are equal.
+    """
+
+def check_schema(schema: CoreSchema, config: CoreConfig | None = None) -> list[SchemaDiagnostic]:
+    """
+    Check a core schema for likely mistakes, e.g. union choices which are never used, duplicate discriminator tags,
+    constraints which have no effect on the schema type or can't be satisfied, and fields serialized by alias to a
+    key they can't be validated from.
+
+    Unlike building a [`SchemaValidator`][pydantic_core.SchemaValidator], every problem found is returned rather
+    than raising on the first one, errors building a validator are included as an `'invalid_schema'` diagnostic.
+
+    Arguments:
+        schema: The `CoreSchema` to check.
+        config: Optionally a [`CoreConfig`][pydantic_core.core_schema.CoreConfig] used to build the schema.
+
+    Returns:
+        A [`SchemaDiagnostic`][pydantic_core.SchemaDiagnostic] for each problem found, empty if none were found.
+    """
+
+def capabilities() -> Capabilities:
+    """
+    The features and limits compiled into this build of pydantic-core, so libraries can check whether a feature is
+    available at runtime rather than comparing versions.
+
+    Returns:
+        A [`Capabilities`][pydantic_core.Capabilities] dict.
+    """
+
+def set_string_cache_size(size: int) -> None:
+    """
+    Set the number of strings held by the cache validators use to reuse `str` objects for equal strings, see the
+    `cache_strings` config. The cache is cleared.
+
+    The cache is shared by every schema in the process, a smaller cache holds less memory, a larger one helps when
+    inputs repeat many different strings.
+
+    Arguments:
+        size: The number of strings to hold, default `16384`, `0` stops strings being cached.
+
+    Raises:
+        ValueError: If `size` is over `16777216` (`2**24`).
+    """
+
+def clear_string_cache() -> None:
+    """
+    Clear the cache of strings used by validators, and the cache of strings parsed by
+    [`from_json`][pydantic_core.from_json], releasing the strings they hold.
+    """
+
+def string_cache_info() -> dict[str, int]:
+    """
+    Returns:
+        The `size` of the cache of strings used by validators, and its `usage`, the number of strings it holds.
+    """
+
 class Url(SupportsAllComparisons):
     """
     A URL type, internal logic uses the [url rust crate](https://docs.rs/url/latest/url/) originally developed
     by Mozilla.
     """
 
-    def __init__(self, url: str) -> None: ...
-    def __new__(cls, url: str) -> Self: ...
+    def __init__(
+        self, url: str, *, preserve_empty_path: bool = False, punycode_host: bool = True, normalize_path: bool = True
+    ) -> None: ...
+    def __new__(
+        cls, url: str, *, preserve_empty_path: bool = False, punycode_host: bool = True, normalize_path: bool = True
+    ) -> Self:
+        """
+        Validates `url`, the keyword arguments are like the `url` schema's.
+        """
     @property
     def scheme(self) -> str: ...
     @property
@@ -576,6 +1738,32 @@ class Url(SupportsAllComparisons):
     def __repr__(self) -> str: ...
     def __str__(self) -> str: ...
     def __deepcopy__(self, memo: dict) -> str: ...
+    def replace(
+        self,
+        *,
+        scheme: str = ...,
+        username: str | None = ...,
+        password: str | None = ...,
+        host: str | None = ...,
+        port: int | None = ...,
+        path: str | None = ...,
+        query: str | None = ...,
+        fragment: str | None = ...,
+    ) -> Self:
+        """
+        Returns a copy of the URL with the given parts replaced, parts given as `None` are removed, e.g.
+        `url.replace(path='items', query=None)`. The host and path keep their original form, including when
+        replaced, if this URL's do, see `punycode_host` and `normalize_path`.
+
+        Raises:
+            ValueError: If the URL can't have a part, e.g. a `mailto:` URL can't have a port.
+        """
+    def join(self, relative: str) -> Self:
+        """
+        Returns the URL `relative` resolves to with this URL as its base, like a link in a document at this URL,
+        e.g. `Url('https://example.com/a/b').join('../c')` is `https://example.com/c`. The host and path keep their
+        original form if this URL's do and `relative` doesn't change them, resolving a relative path normalizes it.
+        """
     @classmethod
     def build(
         cls,
@@ -610,6 +1798,24 @@ class MultiHostUrl(SupportsAllComparisons):
     @property
     def fragment(self) -> str | None: ...
     def hosts(self) -> list[MultiHostHost]: ...
+    def with_hosts(self, hosts: list[MultiHostHost]) -> Self:
+        """
+        Returns a copy of the URL with `hosts` instead of its hosts.
+        """
+    def add_host(
+        self, *, host: str, username: str | None = None, password: str | None = None, port: int | None = None
+    ) -> Self:
+        """
+        Returns a copy of the URL with another host after its hosts.
+        """
+    def remove_host(self, host: str, port: int | None = None) -> Self:
+        """
+        Returns a copy of the URL without the hosts matching `host`, and `port` if it's given, where hosts without
+        a port have the scheme's default port.
+
+        Raises:
+            ValueError: If no host matches, or every host does.
+        """
     def unicode_string(self) -> str: ...
     def __repr__(self) -> str: ...
     def __str__(self) -> str: ...
@@ -629,6 +1835,55 @@ class MultiHostUrl(SupportsAllComparisons):
         fragment: str | None = None,
     ) -> Self: ...
 
+class Version(SupportsAllComparisons):
+    """
+    A [SemVer 2.0](https://semver.org) version, parsed in Rust.
+
+    Versions are ordered by SemVer precedence, so pre-releases come before their release, with build metadata
+    compared last.
+    """
+
+    def __init__(self, version: str) -> None: ...
+    def __new__(cls, version: str) -> Self: ...
+    @property
+    def major(self) -> int: ...
+    @property
+    def minor(self) -> int: ...
+    @property
+    def patch(self) -> int: ...
+    @property
+    def prerelease(self) -> str | None: ...
+    @property
+    def build(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __hash__(self) -> int: ...
+    def __deepcopy__(self, memo: dict) -> Self: ...
+
+class Ulid(SupportsAllComparisons):
+    """
+    A [ULID](https://github.com/ulid/spec), decoded in Rust.
+
+    ULIDs are ordered by their 128-bit value, so first by the time they were generated.
+    """
+
+    def __init__(self, ulid: str | bytes | UUID) -> None: ...
+    def __new__(cls, ulid: str | bytes | UUID) -> Self: ...
+    @property
+    def timestamp(self) -> datetime.datetime:
+        """The time the ULID was generated, as an aware UTC datetime."""
+    @property
+    def timestamp_ms(self) -> int:
+        """The time the ULID was generated, in milliseconds since the Unix epoch."""
+    @property
+    def int(self) -> int: ...
+    @property
+    def bytes(self) -> bytes: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __hash__(self) -> int: ...
+    def __deepcopy__(self, memo: dict) -> Self: ...
+
 @final
 class SchemaError(Exception):
     """
@@ -659,16 +1914,23 @@ class ValidationError(ValueError):
         line_errors: list[InitErrorDetails],
         input_type: Literal['python', 'json'] = 'python',
         hide_input: bool = False,
+        truncated: bool = False,
+        cause: BaseException | None = None,
     ) -> Self:
         """
         Python constructor for a Validation Error.
 
+        This is also how validation errors are unpickled, so they keep their title, errors, input type and cause.
+
         Arguments:
             title: The title of the error, as used in the heading of `str(validation_error)`
             line_errors: A list of [`InitErrorDetails`][pydantic_core.InitErrorDetails] which contain information
                 about errors that occurred during validation.
             input_type: Whether the error is for a Python object or JSON.
             hide_input: Whether to hide the input value in the error message.
+            truncated: Whether errors were left out because there were more than `max_errors` of them.
+            cause: The `__cause__` of the error, e.g. the group of exceptions raised by validators with
+                the `validation_error_cause` config.
         """
     @property
     def title(self) -> str:
@@ -680,8 +1942,18 @@ class ValidationError(ValueError):
         Returns:
             The number of errors in the validation error.
         """
+    @property
+    def truncated(self) -> bool:
+        """
+        Whether errors were left out because there were more than `max_errors` of them.
+        """
     def errors(
-        self, *, include_url: bool = True, include_context: bool = True, include_input: bool = True
+        self,
+        *,
+        include_url: bool = True,
+        include_context: bool = True,
+        include_input: bool = True,
+        loc_as_pointer: bool = False,
     ) -> list[ErrorDetails]:
         """
         Details about each error in the validation error.
@@ -690,6 +1962,9 @@ class ValidationError(ValueError):
             include_url: Whether to include a URL to documentation on the error each error.
             include_context: Whether to include the context of each error.
             include_input: Whether to include the input value of each error.
+            loc_as_pointer: Whether to render the `loc` of each error as an
+                [RFC 6901](https://datatracker.ietf.org/doc/html/rfc6901) JSON Pointer string, e.g. `'/foo/0'`,
+                instead of a tuple.
 
         Returns:
             A list of [`ErrorDetails`][pydantic_core.ErrorDetails] for each error in the validation error.
@@ -701,6 +1976,7 @@ class ValidationError(ValueError):
         include_url: bool = True,
         include_context: bool = True,
         include_input: bool = True,
+        loc_as_pointer: bool = False,
     ) -> str:
         """
         Same as [`errors()`][pydantic_core.ValidationError.errors] but returns a JSON string.
@@ -710,6 +1986,7 @@ class ValidationError(ValueError):
             include_url: Whether to include a URL to documentation on the error each error.
             include_context: Whether to include the context of each error.
             include_input: Whether to include the input value of each error.
+            loc_as_pointer: Whether to render the `loc` of each error as a JSON Pointer string.
 
         Returns:
             a JSON string.
@@ -803,6 +2080,38 @@ class PydanticKnownError(ValueError):
     def message(self) -> str:
         """The formatted message associated with the error. This presents as the message template with context variables appropriately injected."""
 
+@final
+class PydanticMultipleErrors(ValueError):
+    """An exception for raising several errors at once from a validator function, each at its own location.
+
+    Each error is converted as if it were raised by the validator on its own, and its `loc` is added to the
+    location of the resulting errors, after the location of the validator.
+
+    Example:
+        ```py
+        from pydantic_core import PydanticCustomError, PydanticMultipleErrors
+
+        def check_passwords(data):
+            errors = []
+            if len(data['password']) < 8:
+                errors.append(('password', ValueError('password too short')))
+            if data['password'] != data['password_repeat']:
+                errors.append(('password_repeat', PydanticCustomError('mismatch', 'passwords do not match')))
+            if errors:
+                raise PydanticMultipleErrors(errors)
+            return data
+        ```
+
+    Arguments:
+        errors: `(loc, error)` pairs, `loc` is a string, int or a tuple of strings and ints, `error` is any
+            exception which a validator function could raise. At least one error is required.
+    """
+
+    def __init__(self, errors: Iterable[tuple[str | int | tuple[str | int, ...], Exception]], /) -> None: ...
+    @property
+    def errors(self) -> list[tuple[tuple[str | int, ...], Exception]]:
+        """The `(loc, error)` pairs, with each `loc` as a tuple."""
+
 @final
 class PydanticOmit(Exception):
     """An exception to signal that a field should be omitted from a generated result.
@@ -1006,6 +2315,64 @@ def list_all_errors() -> list[ErrorTypeInfo]:
     Returns:
         A list of `ErrorTypeInfo` typed dicts.
     """
+
+def register_error_type(error_type: str, message_template: str, *, required_context: list[str] = []) -> None:
+    """
+    Register an error type, so its errors can be created by name like built-in errors, with a stable message
+    template, rather than each `PydanticCustomError` repeating it.
+
+    Once registered, the error type can be used with [`PydanticKnownError`][pydantic_core.PydanticKnownError],
+    in the line errors of [`ValidationError.from_exception_data`][pydantic_core.ValidationError.from_exception_data],
+    as `custom_error_type` in [`custom_error_schema`][pydantic_core.core_schema.custom_error_schema] and in
+    `error_messages`. Its errors are custom errors, formatted the same way as `PydanticCustomError` messages, and
+    their messages can be replaced by `error_messages` and the `error_message_templates` config like any other.
+
+    Registering an error type again replaces it.
+
+    Arguments:
+        error_type: The name of the error type, e.g. `'my_lib.invalid_sku'`.
+        message_template: The template of its messages, formatted with the error's context,
+            e.g. `'Invalid SKU {sku}'`.
+        required_context: Keys the context of its errors must have.
+
+    Raises:
+        ValueError: If `error_type` is the name of a built-in error type.
+    """
+
+def register_plugin(name: str, capsule: Any) -> None:
+    """
+    Register a plugin implemented by another compiled extension, to validate and serialize
+    [`plugin_schema`][pydantic_core.core_schema.plugin_schema] schemas naming it, e.g. numpy scalars, at the speed
+    of compiled code rather than through python functions.
+
+    The capsule must be named `'pydantic_core.plugin'` and point to a C struct, which must outlive the capsule:
+
+    ```c
+    typedef struct {
+        uint32_t abi_version;  /* must be 1 */
+        /* set `*output` to a new reference and return 0 if `input` is valid, return 1 if it's invalid for a
+           `plugin_type` error, or return -1 with an exception set, converted like those of validator functions,
+           must not be NULL */
+        int (*validate)(PyObject *options, PyObject *input, int strict, PyObject **output);
+        /* optional, return a new reference to `value` serialized, JSON compatible if `json` is 1, or NULL with
+           an exception set, values are serialized like `any` schemas if it's NULL */
+        PyObject *(*serialize)(PyObject *options, PyObject *value, int json);
+    } PydanticCorePlugin;
+    ```
+
+    `options` is the schema's `options` dict, and the functions are called with the GIL held.
+
+    The plugin must be registered before schemas naming it are built. Registering a plugin again replaces it,
+    schemas already built keep the plugin they were built with.
+
+    Arguments:
+        name: The name of the plugin, used as `plugin` in `plugin_schema`.
+        capsule: The capsule pointing to the plugin's functions.
+
+    Raises:
+        ValueError: If the capsule isn't named `'pydantic_core.plugin'`, its ABI version isn't supported or its
+            `validate` function is NULL.
+    """
 @final
 class TzInfo(datetime.tzinfo):
     """An `pydantic-core` implementation of the abstract [`datetime.tzinfo`][] class."""
@@ -26,15 +26,16 @@ else:
     from typing import Protocol, Required, TypeAlias
 
 if TYPE_CHECKING:
-    from pydantic_core import PydanticUndefined
+    from pydantic_core import PydanticUndefined, Version
 else:
     # The initial build of pydantic_core requires PydanticUndefined to generate
     # the core schema; so we need to conditionally skip it. mypy doesn't like
     # this at all, hence the TYPE_CHECKING branch above.
     try:
-        from pydantic_core import PydanticUndefined
+        from pydantic_core import PydanticUndefined, Version
     except ImportError:
         PydanticUndefined = object()
+        Version = object
 
 
 ExtraBehavior = Literal['allow', 'forbid', 'ignore']
@@ -50,6 +51,8 @@ class CoreConfig(TypedDict, total=False):
         extra_fields_behavior: The behavior for handling extra fields.
         typed_dict_total: Whether the TypedDict should be considered total. Default is `True`.
         from_attributes: Whether to use attributes for models, dataclasses, and tagged union keys.
+        empty_str_as_missing: Whether empty strings are treated as missing by model fields, so they get their default
+            or a `missing` error. Default is `False`.
         loc_by_alias: Whether to use the used alias (or first alias for "field required" errors) instead of
             `field_names` to construct error `loc`s. Default is `True`.
         revalidate_instances: Whether instances of models and dataclasses should re-validate. Default is 'never'.
@@ -60,14 +63,30 @@ class CoreConfig(TypedDict, total=False):
         str_to_lower: Whether to convert string fields to lowercase.
         str_to_upper: Whether to convert string fields to uppercase.
         allow_inf_nan: Whether to allow infinity and NaN values for float fields. Default is `True`.
+        exact_numeric_conversion: Whether `Decimal` and `Fraction` inputs to float fields are only accepted in lax
+            mode, and only if they convert to a float exactly, raising a `float_inexact` error if converting
+            would lose precision. Default is `False`, where they're accepted in strict mode too and rounded to the
+            nearest float. They're always only accepted by int fields in lax mode, if they're integral.
+        int_float_tolerance: How far from an integer a number with a fractional part can be for int fields to round
+            it to that integer in lax mode, e.g. `1e-9` accepts `2.0000000001` as `2`. Numbers further from an
+            integer raise an `int_from_float_tolerance` error. Default is `None`, where numbers must be integral.
         ser_json_timedelta: The serialization option for `timedelta` values. Default is 'iso8601'.
             Note that if ser_json_temporal is set, then this param will be ignored.
         ser_json_temporal: The serialization option for datetime like values. Default is 'iso8601'.
             The types this covers are datetime, date, time and timedelta.
             If this is set, it will take precedence over ser_json_timedelta
+        ser_naive_datetime: How to serialize naive datetimes (without a UTC offset) to JSON. Default is 'as-is'.
+            'assume-utc' serializes them as UTC, 'error' raises a `PydanticSerializationError`.
         ser_json_bytes: The serialization option for `bytes` values. Default is 'utf8'.
+            A `bytes-format` serialization schema on a field takes precedence.
         ser_json_inf_nan: The serialization option for infinity and NaN values
-            in float fields. Default is 'null'.
+            in float fields. Default is 'null', or 'constants' for fields with `allow_inf_nan=True` in their schema.
+        ser_json_float: How finite floats are written to JSON, see
+            [`FloatFormat`][pydantic_core.core_schema.FloatFormat]. By default the fewest digits which are read back
+            as the same float are used. A `float-format` serialization schema on a field takes precedence.
+        ser_json_key_fallback: How dict keys which don't match the keys serializer are serialized to JSON.
+            Default is 'infer', which warns and infers the key's type, 'str' uses `str(key)` without warning and
+            'error' raises a `PydanticSerializationError`.
         val_json_bytes: The validation option for `bytes` values, complementing ser_json_bytes. Default is 'utf8'.
         hide_input_in_errors: Whether to hide input data from `ValidationError` representation.
         validation_error_cause: Whether to add user-python excs to the __cause__ of a ValidationError.
@@ -80,6 +99,67 @@ class CoreConfig(TypedDict, total=False):
         validate_by_name: Whether to use the field's name when validating against the provided input data. Default is `False`. Replacement for `populate_by_name`.
         serialize_by_alias: Whether to serialize by alias. Default is `False`, expected to change to `True` in V3.
         url_preserve_empty_path: Whether to preserve empty URL paths when validating values for a URL type. Defaults to `False`.
+        json_error_positions: Whether to include the `position` (byte offset, line and column) of the offending
+            JSON value in each error raised by `validate_json`. Default is `False`.
+        max_errors: The maximum number of errors to collect before validation stops. By default all errors are
+            collected.
+        profile_validation: Whether to record call counts and wall time for each node of the validator, see
+            [`SchemaValidator.get_profile`][pydantic_core.SchemaValidator.get_profile]. Only applies to the config
+            passed to `SchemaValidator`. Default is `False`.
+        lazy_definitions: Whether to build the validators of `definitions` when they're first used instead of when
+            the `SchemaValidator` is created, so creating validators for large schemas is faster. Errors in a
+            definition's schema are only raised when it's first used, and lazily built definitions aren't profiled.
+            Only applies to the config passed to `SchemaValidator`. Default is `False`.
+        error_loc_mapping: Translates the `loc` of errors before a `ValidationError` is raised, e.g. to replace
+            internal field names with public names. Either a dict mapping location items (field names or indices)
+            to their replacements, or a callable taking the whole `loc` tuple and returning the new one. Only
+            applies to the config passed to `SchemaValidator`. Default is `None`.
+        error_message_templates: Replaces the message templates of errors before a `ValidationError` is raised,
+            e.g. to translate them. Either a dict mapping error types to templates, or a callable taking the error
+            type, the default template and the error context and returning a template, or `None` to keep the
+            default. Templates are formatted with the error context, e.g. `'Doit être supérieur à {gt}'`. Only
+            applies to the config passed to `SchemaValidator`. Default is `None`.
+        validation_cache_size: The maximum number of instances of a frozen model to cache, keyed by the input they
+            were validated from, so validating the same input again returns the same instance, e.g. for config
+            blobs or JWT claims validated on every request. Only immutable inputs are cached: `str`, `bytes`,
+            `int`, `float`, `bool` and `None`, and tuples and frozensets of them, keyed by the input and the types
+            of the values in it, e.g. `(1,)` and `(True,)` are cached separately. Inputs are also cached separately
+            for each combination of `strict`, `extra`, `from_attributes`, `by_alias`, `by_name` and `union_mode`,
+            and not at all with other per-call options like `context`. The oldest entry is dropped when the cache
+            is full. Other inputs, e.g. dicts, lists and objects read with `from_attributes`, are validated every
+            time without using the cache, as they may have changed. The schema must be a frozen model, but the values of its fields aren't frozen, a
+            mutable value like a list is shared by every result the cached instance is returned for. Only applies
+            to the config passed to `SchemaValidator`. By default nothing is cached.
+        lax_sequence_types: Types accepted by list, tuple, set and frozenset fields in lax mode even if they'd
+            otherwise be rejected, e.g. ORM result proxies which also implement `Mapping`. Instances are iterated
+            to get their items.
+        clock: The current time used by the `now_op` constraints of datetime and date fields instead of the system
+            clock, e.g. to validate historical payloads deterministically. Either a fixed time or a callable called
+            on each validation returning the time, the time is a datetime, naive datetimes being local time, or a
+            unix timestamp in seconds. Unless `now_utc_offset` is set, the UTC offset of an aware datetime is used
+            as the local offset, otherwise the local offset at that time. By default the system clock is used.
+        clock_context_key: A key of the validation `context` holding the current time for the `now_op` constraints
+            of datetime and date fields, as a datetime or a timestamp like `clock`, used instead of `clock` when the
+            context is a dict with the key.
+        datetime_rfc2822: Whether datetime fields accept RFC 2822 dates like HTTP `Date:` headers in lax mode, see
+            [`datetime_schema`][pydantic_core.core_schema.datetime_schema]. Default is `False`.
+        shared_defaults_check: Whether to warn (`'warn'`) or raise a `default_shared` error (`'error'`) when a field
+            gets a mutable default, i.e. one which can't be hashed, which is the same object as the previous default
+            of the field, e.g. a `default_factory` returning a module-level list, so validated results would share
+            it. The previous default is kept alive to compare with. By default defaults aren't checked.
+        validation_info_path: Whether validator functions get the location of the value they're validating as
+            [`ValidationInfo.path`][pydantic_core.core_schema.ValidationInfo.path], the location is tracked
+            through fields and items while validating. Only applies to the config passed to `SchemaValidator`.
+            Default is `False`.
+        max_input_length: The most characters of a string, or bytes of a `bytes`, anywhere in the input to
+            `validate_python`, `validate_json` and `validate_strings`, checked before the input is validated.
+            Longer inputs fail with an `input_limit_exceeded` error. JSON is checked as it's read, before it's
+            parsed, and the limit also applies to the characters of JSON numbers. Only applies to the config passed
+            to `SchemaValidator`. Default is no limit.
+        max_input_items: The most items of a list, tuple, set, frozenset or dict anywhere in the input, checked like
+            `max_input_length`. Default is no limit.
+        max_input_depth: The most containers nested in each other in the input, a list of ints has a depth of 1,
+            checked like `max_input_length`. Default is no limit.
     """
 
     title: str
@@ -89,6 +169,7 @@ class CoreConfig(TypedDict, total=False):
     typed_dict_total: bool  # default: True
     # used for models, dataclasses, and tagged union keys
     from_attributes: bool
+    empty_str_as_missing: bool  # default: False
     # whether to use the used alias (or first alias for "field required" errors) instead of field_names
     # to construct error `loc`s, default True
     loc_by_alias: bool
@@ -105,11 +186,17 @@ class CoreConfig(TypedDict, total=False):
     str_to_upper: bool
     # fields related to float fields only
     allow_inf_nan: bool  # default: True
+    exact_numeric_conversion: bool  # default: False
+    # fields related to int fields only
+    int_float_tolerance: float  # default: None
     # the config options are used to customise serialization to JSON
     ser_json_timedelta: Literal['iso8601', 'float']  # default: 'iso8601'
     ser_json_temporal: Literal['iso8601', 'seconds', 'milliseconds']  # default: 'iso8601'
+    ser_naive_datetime: Literal['as-is', 'assume-utc', 'error']  # default: 'as-is'
     ser_json_bytes: Literal['utf8', 'base64', 'hex']  # default: 'utf8'
     ser_json_inf_nan: Literal['null', 'constants', 'strings']  # default: 'null'
+    ser_json_float: FloatFormat
+    ser_json_key_fallback: Literal['infer', 'str', 'error']  # default: 'infer'
     val_json_bytes: Literal['utf8', 'base64', 'hex']  # default: 'utf8'
     # used to hide input data from ValidationError repr
     hide_input_in_errors: bool
@@ -121,6 +208,26 @@ class CoreConfig(TypedDict, total=False):
     validate_by_name: bool  # default: False
     serialize_by_alias: bool  # default: False
     url_preserve_empty_path: bool  # default: False
+    json_error_positions: bool  # default: False
+    max_errors: int
+    profile_validation: bool  # default: False
+    lazy_definitions: bool  # default: False
+    error_loc_mapping: Union[
+        dict[Union[str, int], Union[str, int]], Callable[[tuple[Union[str, int], ...]], tuple[Union[str, int], ...]]
+    ]
+    error_message_templates: Union[
+        'dict[ErrorType, str]', Callable[[str, str, Union[dict[str, Any], None]], Union[str, None]]
+    ]
+    validation_cache_size: int
+    lax_sequence_types: list[type]
+    clock: Union[datetime, float, Callable[[], Union[datetime, float]]]
+    clock_context_key: str
+    datetime_rfc2822: bool  # default: False
+    shared_defaults_check: Literal['warn', 'error']
+    validation_info_path: bool  # default: False
+    max_input_length: int
+    max_input_items: int
+    max_input_depth: int
 
 
 IncExCall: TypeAlias = 'set[int | str] | dict[int | str, IncExCall] | None'
@@ -233,6 +340,31 @@ class ValidationInfo(Protocol[ContextT]):
         """
         ...
 
+    @property
+    def parent_data(self) -> dict[str, Any] | None:
+        """
+        The `data` of the model, typed dict or dataclass enclosing the one being validated, e.g. the fields of the
+        outer model validated so far for a validator of a nested model's field, `None` at the outermost level.
+        """
+        ...
+
+    @property
+    def path(self) -> tuple[str | int, ...] | None:
+        """
+        The location of the value being validated, like the `loc` of its errors, e.g. `('items', 0, 'name')`.
+        `None` unless the `validation_info_path` config is set.
+        """
+        ...
+
+    @property
+    def scratch(self) -> dict[Any, Any]:
+        """
+        A dict shared by the validator functions of a validation, which they can write to, unlike `context`, items
+        set while validating a union choice which isn't used are rolled back. Objects in the dict which are mutated
+        in place aren't rolled back.
+        """
+        ...
+
 
 ExpectedSerializationTypes = Literal[
     'none',
@@ -500,11 +632,17 @@ class ComputedField(TypedDict, total=False):
     property_name: Required[str]
     return_schema: Required[CoreSchema]
     alias: str
+    cache: bool
     metadata: dict[str, Any]
 
 
 def computed_field(
-    property_name: str, return_schema: CoreSchema, *, alias: str | None = None, metadata: dict[str, Any] | None = None
+    property_name: str,
+    return_schema: CoreSchema,
+    *,
+    alias: str | None = None,
+    cache: bool | None = None,
+    metadata: dict[str, Any] | None = None,
 ) -> ComputedField:
     """
     ComputedFields are properties of a model or dataclass that are included in serialization.
@@ -513,10 +651,17 @@ def computed_field(
         property_name: The name of the property on the model or dataclass
         return_schema: The schema used for the type returned by the computed field
         alias: The name to use in the serialized output
+        cache: Whether to get the property only once per instance during a single `to_python` or `to_json` call,
+            reusing the result when the same instance is serialized again, defaults to `False`
         metadata: Any other information you want to include with the schema, not used by pydantic-core
     """
     return _dict_not_none(
-        type='computed-field', property_name=property_name, return_schema=return_schema, alias=alias, metadata=metadata
+        type='computed-field',
+        property_name=property_name,
+        return_schema=return_schema,
+        alias=alias,
+        cache=cache,
+        metadata=metadata,
     )
 
 
@@ -524,11 +669,16 @@ class AnySchema(TypedDict, total=False):
     type: Required[Literal['any']]
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
 def any_schema(
-    *, ref: str | None = None, metadata: dict[str, Any] | None = None, serialization: SerSchema | None = None
+    *,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
+    serialization: SerSchema | None = None,
 ) -> AnySchema:
     """
     Returns a schema that matches any value, e.g.:
@@ -544,20 +694,26 @@ def any_schema(
     Args:
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='any', ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(type='any', ref=ref, metadata=metadata, examples=examples, serialization=serialization)
 
 
 class NoneSchema(TypedDict, total=False):
     type: Required[Literal['none']]
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
 def none_schema(
-    *, ref: str | None = None, metadata: dict[str, Any] | None = None, serialization: SerSchema | None = None
+    *,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
+    serialization: SerSchema | None = None,
 ) -> NoneSchema:
     """
     Returns a schema that matches a None value, e.g.:
@@ -573,9 +729,10 @@ def none_schema(
     Args:
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='none', ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(type='none', ref=ref, metadata=metadata, examples=examples, serialization=serialization)
 
 
 class BoolSchema(TypedDict, total=False):
@@ -583,6 +740,7 @@ class BoolSchema(TypedDict, total=False):
     strict: bool
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -590,6 +748,7 @@ def bool_schema(
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> BoolSchema:
     """
@@ -607,9 +766,12 @@ def bool_schema(
         strict: Whether the value should be a bool or a value that can be converted to a bool
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='bool', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='bool', strict=strict, ref=ref, metadata=metadata, examples=examples, serialization=serialization
+    )
 
 
 class IntSchema(TypedDict, total=False):
@@ -619,9 +781,17 @@ class IntSchema(TypedDict, total=False):
     ge: int
     lt: int
     gt: int
+    interval: str
+    max_digits: int
+    allow_radix_prefix: bool  # default: False
+    digit_separators: str
+    unit: str
+    unit_conversions: dict[str, float]
     strict: bool
+    error_messages: dict[ErrorType, str]
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -632,9 +802,17 @@ def int_schema(
     ge: int | None = None,
     lt: int | None = None,
     gt: int | None = None,
+    interval: str | None = None,
+    max_digits: int | None = None,
+    allow_radix_prefix: bool | None = None,
+    digit_separators: str | None = None,
+    unit: str | None = None,
+    unit_conversions: dict[str, float] | None = None,
     strict: bool | None = None,
+    error_messages: dict[ErrorType, str] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> IntSchema:
     """
@@ -654,9 +832,23 @@ def int_schema(
         ge: The value must be greater than or equal to this number
         lt: The value must be strictly less than this number
         gt: The value must be strictly greater than this number
+        interval: The value must be within this interval, e.g. `'[0, 100)'`, can't be combined with `le`, `ge`,
+            `lt` or `gt`, a value outside it raises a single `out_of_range` error
+        max_digits: The maximum number of digits the value can have, strings with more digits are rejected before
+            they're parsed
+        allow_radix_prefix: Whether strings with a `0x`, `0o` or `0b` prefix, e.g. `'0x1F'`, are parsed in that base
+            in lax mode, default `False`
+        digit_separators: Characters besides `_` which lax mode accepts between groups of three digits of the
+            integer part of strings, e.g. `','` for `'1,000,000'` or `'\\u2009'` for a thin space
+        unit: The unit of the value, e.g. `'s'`, in lax mode strings with the unit as a suffix, e.g. `'300s'`, are
+            accepted
+        unit_conversions: Other units strings can have as a suffix, by how many of `unit` each is, e.g.
+            `{'m': 60, 'h': 3600}` so that `'5m'` is validated as `300`, `unit` itself can only be included as `1`
         strict: Whether the value should be a int or a value that can be converted to a int
+        error_messages: Messages to use instead of the default ones for errors raised by this schema, by error type
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -666,39 +858,121 @@ def int_schema(
         ge=ge,
         lt=lt,
         gt=gt,
+        interval=interval,
+        max_digits=max_digits,
+        allow_radix_prefix=allow_radix_prefix,
+        digit_separators=digit_separators,
+        unit=unit,
+        unit_conversions=unit_conversions,
         strict=strict,
+        error_messages=error_messages,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
 
+class FloatFormat(TypedDict, total=False):
+    """
+    How floats are written to JSON, used by `float_format_ser_schema` and the `ser_json_float` config.
+
+    Attributes:
+        precision: The number of digits after the decimal point, e.g. with `2`, `12.1` is written as `12.10`,
+            the float's shortest representation is rounded half away from zero, e.g. `1.005` to `1.01`
+        scientific_threshold: Floats whose decimal exponent is at least this, or at most its negation, are written
+            in scientific notation, by default scientific notation isn't used
+        decimal_point: Whether floats without a fractional part include a decimal point. Default is `True`.
+    """
+
+    precision: int
+    scientific_threshold: int
+    decimal_point: bool  # default: True
+
+
+class FloatFormatSerSchema(FloatFormat, total=False):
+    type: Required[Literal['float-format']]
+
+
+def float_format_ser_schema(
+    *, precision: int | None = None, scientific_threshold: int | None = None, decimal_point: bool | None = None
+) -> FloatFormatSerSchema:
+    """
+    Returns a schema for serializing floats to JSON with a fixed format, e.g.:
+
+    ```py
+    from pydantic_core import SchemaSerializer, core_schema
+
+    schema = core_schema.float_schema(serialization=core_schema.float_format_ser_schema(precision=2))
+    s = SchemaSerializer(schema)
+    assert s.to_json(1.005) == b'1.01'
+    ```
+
+    The float is still written as a JSON number, the format has no effect when serializing to Python.
+
+    Args:
+        precision: The number of digits after the decimal point, by default the fewest digits which are read back
+            as the same float are used, the shortest representation is rounded half away from zero, e.g. with `0`,
+            `12.5` is written as `13.0`
+        scientific_threshold: Floats whose decimal exponent is at least this, or at most its negation, are written
+            in scientific notation, e.g. with `6`, `1234567.0` is written as `1.234567e6`, by default scientific
+            notation isn't used
+        decimal_point: Whether floats without a fractional part include a decimal point, e.g. `1.0` rather
+            than `1`, default `True`
+    """
+    return _dict_not_none(
+        type='float-format',
+        precision=precision,
+        scientific_threshold=scientific_threshold,
+        decimal_point=decimal_point,
+    )
+
+
+FloatFormatOrElseSerSchema = Union[FloatFormatSerSchema, SerSchema]
+
+
 class FloatSchema(TypedDict, total=False):
     type: Required[Literal['float']]
     allow_inf_nan: bool  # whether 'NaN', '+inf', '-inf' should be forbidden. default: True
     multiple_of: float
+    multiple_of_tolerance: float  # default: 1e-9
+    multiple_of_rel_tolerance: float  # default: 0
     le: float
     ge: float
     lt: float
     gt: float
+    interval: str
+    digit_separators: str
+    unit: str
+    unit_conversions: dict[str, float]
     strict: bool
+    error_messages: dict[ErrorType, str]
     ref: str
     metadata: dict[str, Any]
-    serialization: SerSchema
+    examples: list[Any]
+    serialization: FloatFormatOrElseSerSchema
 
 
 def float_schema(
     *,
     allow_inf_nan: bool | None = None,
     multiple_of: float | None = None,
+    multiple_of_tolerance: float | None = None,
+    multiple_of_rel_tolerance: float | None = None,
     le: float | None = None,
     ge: float | None = None,
     lt: float | None = None,
     gt: float | None = None,
+    interval: str | None = None,
+    digit_separators: str | None = None,
+    unit: str | None = None,
+    unit_conversions: dict[str, float] | None = None,
     strict: bool | None = None,
+    error_messages: dict[ErrorType, str] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
-    serialization: SerSchema | None = None,
+    examples: list[Any] | None = None,
+    serialization: FloatFormatOrElseSerSchema | None = None,
 ) -> FloatSchema:
     """
     Returns a schema that matches a float value, e.g.:
@@ -712,32 +986,67 @@ def float_schema(
     ```
 
     Args:
-        allow_inf_nan: Whether to allow inf and nan values
+        allow_inf_nan: Whether to allow inf and nan values, overriding the `allow_inf_nan` config. With `True`, inf
+            and nan values are serialized to JSON as constants unless the `ser_json_inf_nan` config is set
         multiple_of: The value must be a multiple of this number
+        multiple_of_tolerance: How far a value can be from a multiple of `multiple_of`, default `1e-9`
+        multiple_of_rel_tolerance: How far a value can be from a multiple of `multiple_of` relative to its
+            magnitude, e.g. `1e-12` for large values where the absolute tolerance is smaller than their precision,
+            the larger of the two tolerances is used
         le: The value must be less than or equal to this number
         ge: The value must be greater than or equal to this number
         lt: The value must be strictly less than this number
         gt: The value must be strictly greater than this number
+        interval: The value must be within this interval, e.g. `'[0, 100)'`, can't be combined with `le`, `ge`,
+            `lt` or `gt`, a value outside it raises a single `out_of_range` error, bounds can't be NaN
+        digit_separators: Characters besides `_` which lax mode accepts between groups of three digits of the
+            integer part of strings, e.g. `','` for `'1,000,000'` or `'\\u2009'` for a thin space
+        unit: The unit of the value, e.g. `'s'`, in lax mode strings with the unit as a suffix, e.g. `'300s'`, are
+            accepted
+        unit_conversions: Other units strings can have as a suffix, by how many of `unit` each is, e.g.
+            `{'m': 60, 'h': 3600}` so that `'5m'` is validated as `300`, `unit` itself can only be included as `1`
         strict: Whether the value should be a float or a value that can be converted to a float
+        error_messages: Messages to use instead of the default ones for errors raised by this schema, by error type
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
         type='float',
         allow_inf_nan=allow_inf_nan,
         multiple_of=multiple_of,
+        multiple_of_tolerance=multiple_of_tolerance,
+        multiple_of_rel_tolerance=multiple_of_rel_tolerance,
         le=le,
         ge=ge,
         lt=lt,
         gt=gt,
+        interval=interval,
+        digit_separators=digit_separators,
+        unit=unit,
+        unit_conversions=unit_conversions,
         strict=strict,
+        error_messages=error_messages,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
 
+DecimalRounding = Literal[
+    'ROUND_HALF_EVEN',
+    'ROUND_HALF_UP',
+    'ROUND_HALF_DOWN',
+    'ROUND_UP',
+    'ROUND_DOWN',
+    'ROUND_CEILING',
+    'ROUND_FLOOR',
+    'ROUND_05UP',
+]
+
+
 class DecimalSchema(TypedDict, total=False):
     type: Required[Literal['decimal']]
     allow_inf_nan: bool  # whether 'NaN', '+inf', '-inf' should be forbidden. default: False
@@ -748,9 +1057,17 @@ class DecimalSchema(TypedDict, total=False):
     gt: Decimal
     max_digits: int
     decimal_places: int
+    rounding: DecimalRounding  # default: 'ROUND_HALF_EVEN' with `quantize_to`
+    quantize_to: Decimal
+    digit_separators: str
+    unit: str
+    unit_conversions: dict[str, Union[Decimal, float]]
     strict: bool
+    ser_mode: Literal['string', 'number']  # default: 'string'
+    error_messages: dict[ErrorType, str]
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -764,9 +1081,17 @@ def decimal_schema(
     gt: Decimal | None = None,
     max_digits: int | None = None,
     decimal_places: int | None = None,
+    rounding: DecimalRounding | None = None,
+    quantize_to: Decimal | None = None,
+    digit_separators: str | None = None,
+    unit: str | None = None,
+    unit_conversions: dict[str, Decimal | float] | None = None,
     strict: bool | None = None,
+    ser_mode: Literal['string', 'number'] | None = None,
+    error_messages: dict[ErrorType, str] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> DecimalSchema:
     """
@@ -781,18 +1106,50 @@ def decimal_schema(
     assert v.validate_python('0.5') == Decimal('0.5')
     ```
 
+    With `rounding`, inputs with more than `decimal_places` decimal places are rounded instead of raising an error:
+
+    ```py
+    from decimal import Decimal
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.decimal_schema(decimal_places=2, rounding='ROUND_HALF_UP')
+    v = SchemaValidator(schema)
+    assert v.validate_python('1.005') == Decimal('1.01')
+    assert v.validate_python('1.5') == Decimal('1.5')
+    ```
+
     Args:
-        allow_inf_nan: Whether to allow inf and nan values
+        allow_inf_nan: Whether to allow inf and nan values, overriding the `allow_inf_nan` config. With `True` and
+            `ser_mode='number'`, inf and nan values are written to JSON as constants unless `ser_json_inf_nan` is set
         multiple_of: The value must be a multiple of this number
         le: The value must be less than or equal to this number
         ge: The value must be greater than or equal to this number
         lt: The value must be strictly less than this number
-        gt: The value must be strictly greater than this number
+        gt: The value must be strictly greater than this number, decimal schemas don't take an `interval` like
+            `int_schema` and `float_schema`, use `ge`, `gt`, `le` and `lt` instead
         max_digits: The maximum number of decimal digits allowed
         decimal_places: The maximum number of decimal places allowed
+        rounding: A rounding mode of the `decimal` module, e.g. `'ROUND_HALF_UP'`, used to round inputs with more
+            than `decimal_places` decimal places, or to quantize them with `quantize_to`
+        quantize_to: Quantize every value to the exponent of this decimal, e.g. `Decimal('0.01')` rounds values
+            with more decimal places and pads those with fewer, so `'1.5'` becomes `Decimal('1.50')`. Rounded values
+            can have up to 4300 digits, regardless of the precision of the current `decimal` context
+        digit_separators: Characters besides `_` which lax mode accepts between groups of three digits of the
+            integer part of strings, e.g. `','` for `'1,000,000'` or `'\\u2009'` for a thin space
+        unit: The unit of the value, e.g. `'s'`, in lax mode strings with the unit as a suffix, e.g. `'300s'`, are
+            accepted
+        unit_conversions: Other units strings can have as a suffix, by how many of `unit` each is, e.g.
+            `{'m': 60, 'h': 3600}` so that `'5m'` is validated as `300`, `unit` itself can only be included as `1`
         strict: Whether the value should be a float or a value that can be converted to a float
+        ser_mode: How the value is written to JSON, `'string'` (the default) writes e.g. `"1.50"`, `'number'`
+            writes a JSON number with all the decimal's digits, e.g. `1.50`, infinity and NaN are written
+            according to the `ser_json_inf_nan` config. With `'number'`, the value is serialized as a float
+            with `to_python(mode='json')`. Values are rounded with `rounding` and `quantize_to` before they're
+            serialized.
+        error_messages: Messages to use instead of the default ones for errors raised by this schema, by error type
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -803,11 +1160,19 @@ def decimal_schema(
         le=le,
         max_digits=max_digits,
         decimal_places=decimal_places,
+        rounding=rounding,
+        quantize_to=quantize_to,
+        digit_separators=digit_separators,
+        unit=unit,
+        unit_conversions=unit_conversions,
         multiple_of=multiple_of,
         allow_inf_nan=allow_inf_nan,
         strict=strict,
+        ser_mode=ser_mode,
+        error_messages=error_messages,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -817,6 +1182,7 @@ class ComplexSchema(TypedDict, total=False):
     strict: bool
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -825,6 +1191,7 @@ def complex_schema(
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> ComplexSchema:
     """
@@ -843,6 +1210,7 @@ def complex_schema(
         strict: Whether the value should be a complex object instance or a value that can be converted to a complex object
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -850,6 +1218,57 @@ def complex_schema(
         strict=strict,
         ref=ref,
         metadata=metadata,
+        examples=examples,
+        serialization=serialization,
+    )
+
+
+class PluginSchema(TypedDict, total=False):
+    type: Required[Literal['plugin']]
+    plugin: Required[str]
+    options: dict[str, Any]
+    strict: bool
+    ref: str
+    metadata: dict[str, Any]
+    examples: list[Any]
+    serialization: SerSchema
+
+
+def plugin_schema(
+    plugin: str,
+    *,
+    options: dict[str, Any] | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> PluginSchema:
+    """
+    Returns a schema validated and serialized by a plugin another compiled extension registered with
+    [`register_plugin`][pydantic_core.register_plugin], e.g. `plugin_schema('numpy-int64')` once an extension
+    validating numpy scalars has registered a `'numpy-int64'` plugin, so values are validated without calling
+    python functions.
+
+    Invalid inputs the plugin rejects are reported as `plugin_type` errors.
+
+    Args:
+        plugin: The name the plugin was registered with, it must be registered before the schema is built
+        options: Passed to each of the plugin's functions, to configure the plugin
+        strict: Passed to the plugin's `validate` function, whether the input should be validated in strict mode
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='plugin',
+        plugin=plugin,
+        options=options,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -857,32 +1276,58 @@ def complex_schema(
 class StringSchema(TypedDict, total=False):
     type: Required[Literal['str']]
     pattern: Union[str, Pattern[str]]
+    pattern_not: Union[str, Pattern[str]]
     max_length: int
     min_length: int
+    length_unit: Literal['codepoints', 'graphemes', 'utf8_bytes']  # default: 'codepoints'
     strip_whitespace: bool
     to_lower: bool
     to_upper: bool
     regex_engine: Literal['rust-regex', 'python-re']  # default: 'rust-regex'
     strict: bool
     coerce_numbers_to_str: bool
+    intern: bool
+    cache_strings: bool  # default: True
+    encoding: str  # default: 'utf-8'
+    errors: Literal['strict', 'replace', 'reject']  # default: 'strict'
+    strip_bom: bool
+    normalize_newlines: Literal['\n', '\r\n']
+    unicode_normalization: Literal['NFC', 'NFKC', 'NFD', 'NFKD']
+    truncate: bool
+    truncate_suffix: str
+    error_messages: dict[ErrorType, str]
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
 def str_schema(
     *,
     pattern: str | Pattern[str] | None = None,
+    pattern_not: str | Pattern[str] | None = None,
     max_length: int | None = None,
     min_length: int | None = None,
+    length_unit: Literal['codepoints', 'graphemes', 'utf8_bytes'] | None = None,
     strip_whitespace: bool | None = None,
     to_lower: bool | None = None,
     to_upper: bool | None = None,
     regex_engine: Literal['rust-regex', 'python-re'] | None = None,
     strict: bool | None = None,
     coerce_numbers_to_str: bool | None = None,
+    intern: bool | None = None,
+    cache_strings: bool | None = None,
+    encoding: str | None = None,
+    errors: Literal['strict', 'replace', 'reject'] | None = None,
+    strip_bom: bool | None = None,
+    normalize_newlines: Literal['\n', '\r\n'] | None = None,
+    unicode_normalization: Literal['NFC', 'NFKC', 'NFD', 'NFKD'] | None = None,
+    truncate: bool | None = None,
+    truncate_suffix: str | None = None,
+    error_messages: dict[ErrorType, str] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> StringSchema:
     """
@@ -898,8 +1343,16 @@ def str_schema(
 
     Args:
         pattern: A regex pattern that the value must match
+        pattern_not: A regex pattern that the value must not match, values matching it raise a
+            `string_pattern_forbidden` error
         max_length: The value must be at most this length
         min_length: The value must be at least this length
+        length_unit: What `min_length` and `max_length` count:
+            - `codepoints` (default) counts the characters of the `str`
+            - `graphemes` counts user-perceived characters, e.g. a flag emoji or a letter with combining accents
+              is one, raising `string_too_short` and `string_too_long` errors like `codepoints`
+            - `utf8_bytes` counts the bytes of the value encoded as UTF-8, e.g. for the size of a database column,
+              raising `string_bytes_too_short` and `string_bytes_too_long` errors
         strip_whitespace: Whether to strip whitespace from the value
         to_lower: Whether to convert the value to lowercase
         to_upper: Whether to convert the value to uppercase
@@ -911,35 +1364,100 @@ def str_schema(
               which supports all regex features, but may be slower.
         strict: Whether the value should be a string or a value that can be converted to a string
         coerce_numbers_to_str: Whether to enable coercion of any `Number` type to `str` (not applicable in `strict` mode).
+        intern: Whether to reuse the same `str` object for equal validated strings, using a bounded cache of short
+            strings, to save memory when values repeat a lot
+        cache_strings: Set to `False` to never cache the validated strings, whatever the `cache_strings` config,
+            e.g. for values which rarely repeat and would only replace other strings in the cache
+        encoding: The codec used to decode `bytes` and `bytearray` inputs in lax mode, e.g. `'latin-1'`,
+            default `'utf-8'`
+        errors: What to do with `bytes` and `bytearray` inputs in lax mode which can't be decoded:
+            - `strict` (default) raises a `string_unicode` error
+            - `replace` replaces the invalid bytes with `U+FFFD`
+            - `reject` doesn't accept `bytes` or `bytearray` inputs at all, raising a `string_type` error
+        strip_bom: Whether to remove a byte order mark (`U+FEFF`) from the start of the value
+        normalize_newlines: The line ending to use throughout the value, every `\\r\\n`, `\\r` and `\\n`
+            is replaced with it, applied before `strip_whitespace` and the other constraints
+        unicode_normalization: The Unicode normalization form to convert the value to, e.g. `'NFC'` so that
+            `'e\\u0301'` becomes `'é'`, applied before `strip_whitespace`, the length and the patterns are checked
+        truncate: Whether to truncate values longer than `max_length` instead of raising an error, without
+            splitting a grapheme cluster such as a letter with combining accents or a flag emoji
+        truncate_suffix: A suffix to end truncated values with, e.g. `'…'`, which counts towards `max_length`
+        error_messages: Messages to use instead of the default ones for errors raised by this schema, by error type
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
         type='str',
         pattern=pattern,
+        pattern_not=pattern_not,
         max_length=max_length,
         min_length=min_length,
+        length_unit=length_unit,
         strip_whitespace=strip_whitespace,
         to_lower=to_lower,
         to_upper=to_upper,
         regex_engine=regex_engine,
         strict=strict,
         coerce_numbers_to_str=coerce_numbers_to_str,
+        intern=intern,
+        cache_strings=cache_strings,
+        encoding=encoding,
+        errors=errors,
+        strip_bom=strip_bom,
+        normalize_newlines=normalize_newlines,
+        unicode_normalization=unicode_normalization,
+        truncate=truncate,
+        truncate_suffix=truncate_suffix,
+        error_messages=error_messages,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
 
+class BytesFormatSerSchema(TypedDict, total=False):
+    type: Required[Literal['bytes-format']]
+    encoding: Required[Literal['base64', 'urlsafe-base64', 'base32', 'hex']]
+
+
+def bytes_format_ser_schema(encoding: Literal['base64', 'urlsafe-base64', 'base32', 'hex']) -> BytesFormatSerSchema:
+    """
+    Returns a schema for serializing bytes to JSON with a given encoding, regardless of the `ser_json_bytes`
+    config, e.g.:
+
+    ```py
+    from pydantic_core import SchemaSerializer, core_schema
+
+    schema = core_schema.bytes_schema(serialization=core_schema.bytes_format_ser_schema('hex'))
+    s = SchemaSerializer(schema)
+    assert s.to_json(b'\\xab\\xcd') == b'"abcd"'
+    ```
+
+    The encoding has no effect when serializing to Python.
+
+    Args:
+        encoding: How the bytes are encoded, `'base64'` uses the standard alphabet with padding. Note that
+            the `ser_json_bytes='base64'` config uses the URL-safe alphabet, like `'urlsafe-base64'`
+    """
+    return _dict_not_none(type='bytes-format', encoding=encoding)
+
+
+BytesFormatOrElseSerSchema = Union[BytesFormatSerSchema, SerSchema]
+
+
 class BytesSchema(TypedDict, total=False):
     type: Required[Literal['bytes']]
     max_length: int
     min_length: int
     strict: bool
+    error_messages: dict[ErrorType, str]
     ref: str
     metadata: dict[str, Any]
-    serialization: SerSchema
+    examples: list[Any]
+    serialization: BytesFormatOrElseSerSchema
 
 
 def bytes_schema(
@@ -947,9 +1465,11 @@ def bytes_schema(
     max_length: int | None = None,
     min_length: int | None = None,
     strict: bool | None = None,
+    error_messages: dict[ErrorType, str] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
-    serialization: SerSchema | None = None,
+    examples: list[Any] | None = None,
+    serialization: BytesFormatOrElseSerSchema | None = None,
 ) -> BytesSchema:
     """
     Returns a schema that matches a bytes value, e.g.:
@@ -966,8 +1486,10 @@ def bytes_schema(
         max_length: The value must be at most this length
         min_length: The value must be at least this length
         strict: Whether the value should be a bytes or a value that can be converted to a bytes
+        error_messages: Messages to use instead of the default ones for errors raised by this schema, by error type
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -975,8 +1497,10 @@ def bytes_schema(
         max_length=max_length,
         min_length=min_length,
         strict=strict,
+        error_messages=error_messages,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -992,8 +1516,10 @@ class DateSchema(TypedDict, total=False):
     # defaults to current local utc offset from `time.localtime().tm_gmtoff`
     # value is restricted to -86_400 < offset < 86_400:
     now_utc_offset: int
+    format: str
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -1006,8 +1532,10 @@ def date_schema(
     gt: date | None = None,
     now_op: Literal['past', 'future'] | None = None,
     now_utc_offset: int | None = None,
+    format: str | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> DateSchema:
     """
@@ -1030,8 +1558,11 @@ def date_schema(
         gt: The value must be strictly greater than this date
         now_op: The value must be in the past or future relative to the current date
         now_utc_offset: The value must be in the past or future relative to the current date with this utc offset
+        format: A `strptime`-style format to parse strings with instead of ISO 8601, e.g. `'%d/%m/%Y'`, supporting
+            `%Y`, `%y`, `%m`, `%b`, `%B`, `%d`, `%a`, `%A`, `%H`, `%I`, `%p`, `%M`, `%S`, `%f`, `%z` and `%%`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -1043,8 +1574,10 @@ def date_schema(
         gt=gt,
         now_op=now_op,
         now_utc_offset=now_utc_offset,
+        format=format,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -1058,8 +1591,10 @@ class TimeSchema(TypedDict, total=False):
     gt: time
     tz_constraint: Union[Literal['aware', 'naive'], int]
     microseconds_precision: Literal['truncate', 'error']
+    format: str
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -1072,8 +1607,10 @@ def time_schema(
     gt: time | None = None,
     tz_constraint: Literal['aware', 'naive'] | int | None = None,
     microseconds_precision: Literal['truncate', 'error'] = 'truncate',
+    format: str | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> TimeSchema:
     """
@@ -1096,8 +1633,11 @@ def time_schema(
         gt: The value must be strictly greater than this time
         tz_constraint: The value must be timezone aware or naive, or an int to indicate required tz offset
         microseconds_precision: The behavior when seconds have more than 6 digits or microseconds is too large
+        format: A `strptime`-style format to parse strings with instead of ISO 8601, e.g. `'%I:%M %p'`, see
+            [`date_schema`][pydantic_core.core_schema.date_schema] for the supported directives
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -1109,8 +1649,10 @@ def time_schema(
         gt=gt,
         tz_constraint=tz_constraint,
         microseconds_precision=microseconds_precision,
+        format=format,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -1123,13 +1665,17 @@ class DatetimeSchema(TypedDict, total=False):
     lt: datetime
     gt: datetime
     now_op: Literal['past', 'future']
-    tz_constraint: Union[Literal['aware', 'naive'], int]
+    tz_constraint: Union[Literal['aware', 'naive'], int, str]
+    zoneinfo: bool  # default: False
     # defaults to current local utc offset from `time.localtime().tm_gmtoff`
     # value is restricted to -86_400 < offset < 86_400 by bounds in generate_self_schema.py
     now_utc_offset: int
     microseconds_precision: Literal['truncate', 'error']  # default: 'truncate'
+    format: str
+    rfc2822: bool  # default: False
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -1141,11 +1687,15 @@ def datetime_schema(
     lt: datetime | None = None,
     gt: datetime | None = None,
     now_op: Literal['past', 'future'] | None = None,
-    tz_constraint: Literal['aware', 'naive'] | int | None = None,
+    tz_constraint: Literal['aware', 'naive'] | int | str | None = None,
+    zoneinfo: bool | None = None,
     now_utc_offset: int | None = None,
     microseconds_precision: Literal['truncate', 'error'] = 'truncate',
+    format: str | None = None,
+    rfc2822: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> DatetimeSchema:
     """
@@ -1168,12 +1718,20 @@ def datetime_schema(
         lt: The value must be strictly less than this datetime
         gt: The value must be strictly greater than this datetime
         now_op: The value must be in the past or future relative to the current datetime
-        tz_constraint: The value must be timezone aware or naive, or an int to indicate required tz offset
-            TODO: use of a tzinfo where offset changes based on the datetime is not yet supported
+        tz_constraint: The value must be timezone aware or naive, an int to indicate required tz offset, or an IANA
+            time zone name like `'Europe/Paris'` to require the zone's offset at the value's time
+        zoneinfo: Whether to attach validated datetimes to the `zoneinfo.ZoneInfo` of the `tz_constraint` zone
+            rather than a fixed offset, so arithmetic across DST transitions uses the right offsets
         now_utc_offset: The value must be in the past or future relative to the current datetime with this utc offset
         microseconds_precision: The behavior when seconds have more than 6 digits or microseconds is too large
+        format: A `strptime`-style format to parse strings with instead of ISO 8601, e.g. `'%d/%m/%Y %H:%M'`, see
+            [`date_schema`][pydantic_core.core_schema.date_schema] for the supported directives
+        rfc2822: Whether strings which aren't ISO 8601 can be RFC 2822 dates in lax mode, like the
+            `Sun, 06 Nov 1994 08:49:37 GMT` of email and HTTP `Date:` headers, defaults to the `datetime_rfc2822`
+            config setting or `False`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -1185,10 +1743,14 @@ def datetime_schema(
         gt=gt,
         now_op=now_op,
         tz_constraint=tz_constraint,
+        zoneinfo=zoneinfo,
         now_utc_offset=now_utc_offset,
         microseconds_precision=microseconds_precision,
+        format=format,
+        rfc2822=rfc2822,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -1201,8 +1763,12 @@ class TimedeltaSchema(TypedDict, total=False):
     lt: timedelta
     gt: timedelta
     microseconds_precision: Literal['truncate', 'error']
+    output: Literal['timedelta', 'components', 'period']  # default: 'timedelta'
+    allow_negative: bool  # default: True
+    sign_scope: Literal['duration', 'days']  # default: 'duration'
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -1214,8 +1780,12 @@ def timedelta_schema(
     lt: timedelta | None = None,
     gt: timedelta | None = None,
     microseconds_precision: Literal['truncate', 'error'] = 'truncate',
+    output: Literal['timedelta', 'components', 'period'] | None = None,
+    allow_negative: bool | None = None,
+    sign_scope: Literal['duration', 'days'] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> TimedeltaSchema:
     """
@@ -1237,8 +1807,19 @@ def timedelta_schema(
         lt: The value must be strictly less than this timedelta
         gt: The value must be strictly greater than this timedelta
         microseconds_precision: The behavior when seconds have more than 6 digits or microseconds is too large
+        output: What to return, the constraints are always checked on the duration as a timedelta:
+            - `timedelta` (default) returns a `datetime.timedelta`
+            - `components` returns a [`TimedeltaComponents`][pydantic_core.TimedeltaComponents] dict
+            - `period` returns an [`IsoPeriod`][pydantic_core.IsoPeriod] dict, keeping the years and months of
+              ISO 8601 durations like `P1Y2M`, which a timedelta would convert to 365 and 30 days
+        allow_negative: Whether negative durations are valid, defaults to `True`
+        sign_scope: What a leading `-` in a string negates:
+            - `duration` (default) negates the whole duration, like ISO 8601, so `'-1 day, 23:00:00'` is -47 hours
+            - `days` only negates the days, so `'-1 day, 23:00:00'`, which is how `str(timedelta)` writes -1 hour,
+              is -1 hour
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -1249,8 +1830,12 @@ def timedelta_schema(
         lt=lt,
         gt=gt,
         microseconds_precision=microseconds_precision,
+        output=output,
+        allow_negative=allow_negative,
+        sign_scope=sign_scope,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -1258,16 +1843,20 @@ def timedelta_schema(
 class LiteralSchema(TypedDict, total=False):
     type: Required[Literal['literal']]
     expected: Required[list[Any]]
+    case_insensitive: bool  # default: False
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
 def literal_schema(
     expected: list[Any],
     *,
+    case_insensitive: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> LiteralSchema:
     """
@@ -1283,236 +1872,858 @@ def literal_schema(
 
     Args:
         expected: The value must be one of these values
+        case_insensitive: Whether strings match string members ignoring case, the member is returned
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='literal',
+        expected=expected,
+        case_insensitive=case_insensitive,
+        ref=ref,
+        metadata=metadata,
+        examples=examples,
+        serialization=serialization,
+    )
+
+
+class ConstSchema(TypedDict, total=False):
+    type: Required[Literal['const']]
+    value: Required[Any]
+    ref: str
+    metadata: dict[str, Any]
+    examples: list[Any]
+    serialization: SerSchema
+
+
+def const_schema(
+    value: Any,
+    *,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> ConstSchema:
+    """
+    Returns a schema for a constant, which is both the default and the only value allowed if one is given, e.g.
+    for envelope fields like `"version": 2`:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.typed_dict_schema({'version': core_schema.typed_dict_field(core_schema.const_schema(2))})
+    v = SchemaValidator(schema)
+    assert v.validate_python({}) == {'version': 2}
+    assert v.validate_python({'version': 2}) == {'version': 2}
+    ```
+
+    Values are matched like a `literal` with a single expected value, with a `const_mismatch` error otherwise,
+    and fields using this schema behave like fields with a `default` schema. `bool`, `int` and `float` constants
+    also require the input to have the same type, so `const_schema(1)` rejects `True` and `1.0`.
+
+    Args:
+        value: The constant, used as the default and the only value allowed
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
+        serialization: Custom serialization schema
+    """
+    s = _dict_not_none(type='const', ref=ref, metadata=metadata, examples=examples, serialization=serialization)
+    # set separately, since `None` is a valid constant
+    s['value'] = value
+    return s
+
+
+class EnumSchema(TypedDict, total=False):
+    type: Required[Literal['enum']]
+    cls: Required[Any]
+    members: Required[list[Any]]
+    sub_type: Literal['str', 'int', 'float']
+    missing: Callable[[Any], Any]
+    accept_names: bool  # default: False
+    strict: bool
+    ref: str
+    metadata: dict[str, Any]
+    examples: list[Any]
+    serialization: SerSchema
+
+
+def enum_schema(
+    cls: Any,
+    members: list[Any],
+    *,
+    sub_type: Literal['str', 'int', 'float'] | None = None,
+    missing: Callable[[Any], Any] | None = None,
+    accept_names: bool | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> EnumSchema:
+    """
+    Returns a schema that matches an enum value, e.g.:
+
+    ```py
+    from enum import Enum
+    from pydantic_core import SchemaValidator, core_schema
+
+    class Color(Enum):
+        RED = 1
+        GREEN = 2
+        BLUE = 3
+
+    schema = core_schema.enum_schema(Color, list(Color.__members__.values()))
+    v = SchemaValidator(schema)
+    assert v.validate_python(2) is Color.GREEN
+    ```
+
+    Args:
+        cls: The enum class
+        members: The members of the enum, generally `list(MyEnum.__members__.values())`
+        sub_type: The type of the enum, either 'str' or 'int' or None for plain enums
+        missing: A function to use when the value is not found in the enum, from `_missing_`
+        accept_names: Whether the name of a member, e.g. `'GREEN'`, is also accepted in lax mode, defaults to False
+        strict: Whether to use strict mode, defaults to False
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='enum',
+        cls=cls,
+        members=members,
+        sub_type=sub_type,
+        missing=missing,
+        accept_names=accept_names,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        examples=examples,
+        serialization=serialization,
+    )
+
+
+class MissingSentinelSchema(TypedDict, total=False):
+    type: Required[Literal['missing-sentinel']]
+    metadata: dict[str, Any]
+    examples: list[Any]
+    serialization: SerSchema
+
+
+def missing_sentinel_schema(
+    metadata: dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+    examples: list[Any] | None = None,
+) -> MissingSentinelSchema:
+    """Returns a schema for the `MISSING` sentinel."""
+
+    return _dict_not_none(
+        type='missing-sentinel',
+        metadata=metadata,
+        examples=examples,
+        serialization=serialization,
+    )
+
+
+# must match input/parse_json.rs::JsonType::try_from
+JsonType = Literal['null', 'bool', 'int', 'float', 'str', 'list', 'dict']
+
+
+class IsInstanceSchema(TypedDict, total=False):
+    type: Required[Literal['is-instance']]
+    cls: Required[Any]
+    cls_repr: str
+    ref: str
+    metadata: dict[str, Any]
+    examples: list[Any]
+    serialization: SerSchema
+
+
+def is_instance_schema(
+    cls: Any,
+    *,
+    cls_repr: str | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> IsInstanceSchema:
+    """
+    Returns a schema that checks if a value is an instance of a class, equivalent to python's `isinstance` method, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    class A:
+        pass
+
+    schema = core_schema.is_instance_schema(cls=A)
+    v = SchemaValidator(schema)
+    v.validate_python(A())
+    ```
+
+    Args:
+        cls: The value must be an instance of this class
+        cls_repr: If provided this string is used in the validator name instead of `repr(cls)`
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='is-instance',
+        cls=cls,
+        cls_repr=cls_repr,
+        ref=ref,
+        metadata=metadata,
+        examples=examples,
+        serialization=serialization,
+    )
+
+
+class IsSubclassSchema(TypedDict, total=False):
+    type: Required[Literal['is-subclass']]
+    cls: Required[type[Any]]
+    cls_repr: str
+    ref: str
+    metadata: dict[str, Any]
+    examples: list[Any]
+    serialization: SerSchema
+
+
+def is_subclass_schema(
+    cls: type[Any],
+    *,
+    cls_repr: str | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> IsInstanceSchema:
+    """
+    Returns a schema that checks if a value is a subtype of a class, equivalent to python's `issubclass` method, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    class A:
+        pass
+
+    class B(A):
+        pass
+
+    schema = core_schema.is_subclass_schema(cls=A)
+    v = SchemaValidator(schema)
+    v.validate_python(B)
+    ```
+
+    Args:
+        cls: The value must be a subclass of this class
+        cls_repr: If provided this string is used in the validator name instead of `repr(cls)`
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='is-subclass',
+        cls=cls,
+        cls_repr=cls_repr,
+        ref=ref,
+        metadata=metadata,
+        examples=examples,
+        serialization=serialization,
+    )
+
+
+class CallableSchema(TypedDict, total=False):
+    type: Required[Literal['callable']]
+    ref: str
+    metadata: dict[str, Any]
+    examples: list[Any]
+    serialization: SerSchema
+
+
+def callable_schema(
+    *,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> CallableSchema:
+    """
+    Returns a schema that checks if a value is callable, equivalent to python's `callable` method, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.callable_schema()
+    v = SchemaValidator(schema)
+    v.validate_python(min)
+    ```
+
+    Args:
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(type='callable', ref=ref, metadata=metadata, examples=examples, serialization=serialization)
+
+
+class UuidSchema(TypedDict, total=False):
+    type: Required[Literal['uuid']]
+    version: Literal[1, 3, 4, 5, 6, 7, 8]
+    timestamp_ge: datetime
+    timestamp_lt: datetime
+    with_timestamp: bool  # default: False
+    strict: bool
+    ref: str
+    metadata: dict[str, Any]
+    examples: list[Any]
+    serialization: SerSchema
+
+
+def uuid_schema(
+    *,
+    version: Literal[1, 3, 4, 5, 6, 7, 8] | None = None,
+    timestamp_ge: datetime | None = None,
+    timestamp_lt: datetime | None = None,
+    with_timestamp: bool | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> UuidSchema:
+    """
+    Returns a schema that matches a `uuid.UUID`, e.g.:
+
+    ```py
+    from datetime import datetime, timezone
+
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.uuid_schema(version=7, with_timestamp=True)
+    v = SchemaValidator(schema)
+    uuid = v.validate_python('01890a5d-ac96-774b-bcce-b302099a8057')
+    assert uuid.timestamp == datetime(2023, 6, 30, 3, 34, 18, 518000, tzinfo=timezone.utc)
+    ```
+
+    Args:
+        version: The UUID version the value must have
+        timestamp_ge: The time embedded in the UUID must be greater than or equal to this aware datetime,
+            requires a time-based `version`, 1, 6 or 7
+        timestamp_lt: The time embedded in the UUID must be less than this aware datetime, requires a time-based
+            `version`
+        with_timestamp: Whether to return a [`TimestampedUUID`][pydantic_core.TimestampedUUID], whose `timestamp`
+            is the time embedded in the UUID, requires a time-based `version`
+        strict: Whether the value should be a `uuid.UUID` rather than a string or bytes (in Python mode)
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='uuid',
+        version=version,
+        timestamp_ge=timestamp_ge,
+        timestamp_lt=timestamp_lt,
+        with_timestamp=with_timestamp,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        examples=examples,
+        serialization=serialization,
+    )
+
+
+class SemverSchema(TypedDict, total=False):
+    type: Required[Literal['semver']]
+    ge: Union[str, Version]
+    lt: Union[str, Version]
+    strict: bool
+    ref: str
+    metadata: dict[str, Any]
+    examples: list[Any]
+    serialization: SerSchema
+
+
+def semver_schema(
+    *,
+    ge: str | Version | None = None,
+    lt: str | Version | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> SemverSchema:
+    """
+    Returns a schema that matches a [SemVer 2.0](https://semver.org) version, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, Version, core_schema
+
+    schema = core_schema.semver_schema(ge='1.0.0', lt='2.0.0')
+    v = SchemaValidator(schema)
+    version = v.validate_python('1.2.3-rc.1+build.5')
+    assert version == Version('1.2.3-rc.1+build.5')
+    assert (version.major, version.minor, version.patch) == (1, 2, 3)
+    assert (version.prerelease, version.build) == ('rc.1', 'build.5')
+    ```
+
+    Strings are parsed in Rust, in lax mode surrounding whitespace and a `v` prefix, e.g. `v1.2.3`, are removed.
+
+    Args:
+        ge: The version must be greater than or equal to this version, by SemVer precedence, so pre-releases
+            come before their release and build metadata is ignored
+        lt: The version must be less than this version, by SemVer precedence
+        strict: Whether the version string has to be exactly a SemVer version
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='semver',
+        ge=ge,
+        lt=lt,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        examples=examples,
+        serialization=serialization,
+    )
+
+
+class UlidSchema(TypedDict, total=False):
+    type: Required[Literal['ulid']]
+    strict: bool
+    ref: str
+    metadata: dict[str, Any]
+    examples: list[Any]
+    serialization: SerSchema
+
+
+def ulid_schema(
+    *,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> UlidSchema:
+    """
+    Returns a schema that matches a [ULID](https://github.com/ulid/spec), e.g.:
+
+    ```py
+    from datetime import datetime, timezone
+
+    from pydantic_core import SchemaValidator, Ulid, core_schema
+
+    schema = core_schema.ulid_schema()
+    v = SchemaValidator(schema)
+    ulid = v.validate_python('01arz3ndektsv4rrffq69g5fav')
+    assert ulid == Ulid('01ARZ3NDEKTSV4RRFFQ69G5FAV')
+    assert ulid.timestamp == datetime(2016, 7, 30, 23, 54, 10, 259000, tzinfo=timezone.utc)
+    ```
+
+    Strings are decoded case-insensitively in Rust and serialized as the canonical upper case 26 character string.
+    In lax mode, a `uuid.UUID` or the 16 bytes of the ULID are also accepted.
+
+    Args:
+        strict: Whether only strings and `Ulid` instances are accepted
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='ulid', strict=strict, ref=ref, metadata=metadata, examples=examples, serialization=serialization
+    )
+
+
+class IpV4AddressSchema(TypedDict, total=False):
+    type: Required[Literal['ip-v4-address']]
+    strict: bool
+    ref: str
+    metadata: dict[str, Any]
+    examples: list[Any]
+    serialization: SerSchema
+
+
+def ip_v4_address_schema(
+    *,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> IpV4AddressSchema:
+    """
+    Returns a schema that matches an `ipaddress.IPv4Address`, e.g.:
+
+    ```py
+    from ipaddress import IPv4Address
+
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.ip_v4_address_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('192.168.0.1') == IPv4Address('192.168.0.1')
+    ```
+
+    Strings are parsed in Rust, in lax mode Python input can also be an `int` or packed `bytes`.
+
+    Args:
+        strict: Whether only `IPv4Address` instances, or strings in JSON, are allowed
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='ip-v4-address', strict=strict, ref=ref, metadata=metadata, examples=examples, serialization=serialization
+    )
+
+
+class IpV6AddressSchema(TypedDict, total=False):
+    type: Required[Literal['ip-v6-address']]
+    strict: bool
+    ref: str
+    metadata: dict[str, Any]
+    examples: list[Any]
+    serialization: SerSchema
+
+
+def ip_v6_address_schema(
+    *,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> IpV6AddressSchema:
+    """
+    Returns a schema that matches an `ipaddress.IPv6Address`, e.g.:
+
+    ```py
+    from ipaddress import IPv6Address
+
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.ip_v6_address_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('::1') == IPv6Address('::1')
+    ```
+
+    Strings are parsed in Rust, in lax mode Python input can also be an `int` or packed `bytes`. Scoped addresses,
+    e.g. `'fe80::1%eth0'`, keep their scope ID.
+
+    Args:
+        strict: Whether only `IPv6Address` instances, or strings in JSON, are allowed
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='literal', expected=expected, ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='ip-v6-address', strict=strict, ref=ref, metadata=metadata, examples=examples, serialization=serialization
+    )
 
 
-class EnumSchema(TypedDict, total=False):
-    type: Required[Literal['enum']]
-    cls: Required[Any]
-    members: Required[list[Any]]
-    sub_type: Literal['str', 'int', 'float']
-    missing: Callable[[Any], Any]
+class IpNetworkSchema(TypedDict, total=False):
+    type: Required[Literal['ip-network']]
+    version: Literal[4, 6]
     strict: bool
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
-def enum_schema(
-    cls: Any,
-    members: list[Any],
+def ip_network_schema(
     *,
-    sub_type: Literal['str', 'int', 'float'] | None = None,
-    missing: Callable[[Any], Any] | None = None,
+    version: Literal[4, 6] | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
-) -> EnumSchema:
+) -> IpNetworkSchema:
     """
-    Returns a schema that matches an enum value, e.g.:
+    Returns a schema that matches an `ipaddress.IPv4Network` or `ipaddress.IPv6Network`, e.g.:
 
     ```py
-    from enum import Enum
-    from pydantic_core import SchemaValidator, core_schema
+    from ipaddress import IPv4Network
 
-    class Color(Enum):
-        RED = 1
-        GREEN = 2
-        BLUE = 3
+    from pydantic_core import SchemaValidator, core_schema
 
-    schema = core_schema.enum_schema(Color, list(Color.__members__.values()))
+    schema = core_schema.ip_network_schema()
     v = SchemaValidator(schema)
-    assert v.validate_python(2) is Color.GREEN
+    assert v.validate_python('10.0.0.0/8') == IPv4Network('10.0.0.0/8')
     ```
 
+    Like `ipaddress.ip_network`, the prefix can be omitted, and for IPv4 can be a netmask or hostmask,
+    networks with host bits set are invalid.
+
     Args:
-        cls: The enum class
-        members: The members of the enum, generally `list(MyEnum.__members__.values())`
-        sub_type: The type of the enum, either 'str' or 'int' or None for plain enums
-        missing: A function to use when the value is not found in the enum, from `_missing_`
-        strict: Whether to use strict mode, defaults to False
+        version: The IP version of the network, either version is allowed if omitted
+        strict: Whether only network instances, or strings in JSON, are allowed
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
-        type='enum',
-        cls=cls,
-        members=members,
-        sub_type=sub_type,
-        missing=missing,
+        type='ip-network',
+        version=version,
         strict=strict,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
 
-class MissingSentinelSchema(TypedDict, total=False):
-    type: Required[Literal['missing-sentinel']]
-    metadata: dict[str, Any]
-    serialization: SerSchema
-
-
-def missing_sentinel_schema(
-    metadata: dict[str, Any] | None = None,
-    serialization: SerSchema | None = None,
-) -> MissingSentinelSchema:
-    """Returns a schema for the `MISSING` sentinel."""
-
-    return _dict_not_none(
-        type='missing-sentinel',
-        metadata=metadata,
-        serialization=serialization,
-    )
-
-
-# must match input/parse_json.rs::JsonType::try_from
-JsonType = Literal['null', 'bool', 'int', 'float', 'str', 'list', 'dict']
-
-
-class IsInstanceSchema(TypedDict, total=False):
-    type: Required[Literal['is-instance']]
-    cls: Required[Any]
-    cls_repr: str
+class IpInterfaceSchema(TypedDict, total=False):
+    type: Required[Literal['ip-interface']]
+    version: Literal[4, 6]
+    strict: bool
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
-def is_instance_schema(
-    cls: Any,
+def ip_interface_schema(
     *,
-    cls_repr: str | None = None,
+    version: Literal[4, 6] | None = None,
+    strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
-) -> IsInstanceSchema:
+) -> IpInterfaceSchema:
     """
-    Returns a schema that checks if a value is an instance of a class, equivalent to python's `isinstance` method, e.g.:
+    Returns a schema that matches an `ipaddress.IPv4Interface` or `ipaddress.IPv6Interface`, e.g.:
 
     ```py
-    from pydantic_core import SchemaValidator, core_schema
+    from ipaddress import IPv4Interface
 
-    class A:
-        pass
+    from pydantic_core import SchemaValidator, core_schema
 
-    schema = core_schema.is_instance_schema(cls=A)
+    schema = core_schema.ip_interface_schema()
     v = SchemaValidator(schema)
-    v.validate_python(A())
+    assert v.validate_python('192.168.0.1/24') == IPv4Interface('192.168.0.1/24')
     ```
 
     Args:
-        cls: The value must be an instance of this class
-        cls_repr: If provided this string is used in the validator name instead of `repr(cls)`
+        version: The IP version of the interface, either version is allowed if omitted
+        strict: Whether only interface instances, or strings in JSON, are allowed
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
-        type='is-instance', cls=cls, cls_repr=cls_repr, ref=ref, metadata=metadata, serialization=serialization
+        type='ip-interface',
+        version=version,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        examples=examples,
+        serialization=serialization,
     )
 
 
-class IsSubclassSchema(TypedDict, total=False):
-    type: Required[Literal['is-subclass']]
-    cls: Required[type[Any]]
-    cls_repr: str
+class EmailSchema(TypedDict, total=False):
+    type: Required[Literal['email']]
+    max_length: int
+    allowed_domains: list[str]
+    strict: bool
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
-def is_subclass_schema(
-    cls: type[Any],
+def email_schema(
     *,
-    cls_repr: str | None = None,
+    max_length: int | None = None,
+    allowed_domains: list[str] | None = None,
+    strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
-) -> IsInstanceSchema:
+) -> EmailSchema:
     """
-    Returns a schema that checks if a value is a subtype of a class, equivalent to python's `issubclass` method, e.g.:
+    Returns a schema that matches an email address, returning the normalized address as a `str`, e.g.:
 
     ```py
     from pydantic_core import SchemaValidator, core_schema
 
-    class A:
-        pass
-
-    class B(A):
-        pass
-
-    schema = core_schema.is_subclass_schema(cls=A)
+    schema = core_schema.email_schema()
     v = SchemaValidator(schema)
-    v.validate_python(B)
+    assert v.validate_python('Samuel <S.Colvin@Example.COM>') == 'S.Colvin@example.com'
     ```
 
+    The part before the @-sign can include non-ASCII characters (RFC 6531) but not be quoted, the domain is
+    converted with IDNA, and is lower-cased and in its Unicode form in the normalized address. A display name
+    like `Name <address>` is allowed and dropped.
+
     Args:
-        cls: The value must be a subclass of this class
-        cls_repr: If provided this string is used in the validator name instead of `repr(cls)`
+        max_length: The maximum length of the normalized address
+        allowed_domains: The domains allowed after the @-sign, compared after IDNA conversion
+        strict: Whether to use strict mode for the `str` input
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
-        type='is-subclass', cls=cls, cls_repr=cls_repr, ref=ref, metadata=metadata, serialization=serialization
+        type='email',
+        max_length=max_length,
+        allowed_domains=allowed_domains,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        examples=examples,
+        serialization=serialization,
     )
 
 
-class CallableSchema(TypedDict, total=False):
-    type: Required[Literal['callable']]
+class NameEmailSchema(TypedDict, total=False):
+    type: Required[Literal['name-email']]
+    max_length: int
+    allowed_domains: list[str]
+    strict: bool
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
-def callable_schema(
-    *, ref: str | None = None, metadata: dict[str, Any] | None = None, serialization: SerSchema | None = None
-) -> CallableSchema:
+def name_email_schema(
+    *,
+    max_length: int | None = None,
+    allowed_domains: list[str] | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> NameEmailSchema:
     """
-    Returns a schema that checks if a value is callable, equivalent to python's `callable` method, e.g.:
+    Returns a schema that matches an email address with an optional display name, returning a
+    `(name, email)` tuple where the name defaults to the part of the address before the @-sign, e.g.:
 
     ```py
     from pydantic_core import SchemaValidator, core_schema
 
-    schema = core_schema.callable_schema()
+    schema = core_schema.name_email_schema()
     v = SchemaValidator(schema)
-    v.validate_python(min)
+    assert v.validate_python('Samuel Colvin <s@example.com>') == ('Samuel Colvin', 's@example.com')
+    assert v.validate_python('s@example.com') == ('s', 's@example.com')
     ```
 
+    The address is validated and normalized like [`email_schema`][pydantic_core.core_schema.email_schema].
+
     Args:
+        max_length: The maximum length of the normalized address
+        allowed_domains: The domains allowed after the @-sign, compared after IDNA conversion
+        strict: Whether to use strict mode for the `str` input
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='callable', ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='name-email',
+        max_length=max_length,
+        allowed_domains=allowed_domains,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        examples=examples,
+        serialization=serialization,
+    )
 
 
-class UuidSchema(TypedDict, total=False):
-    type: Required[Literal['uuid']]
-    version: Literal[1, 3, 4, 5, 7]
+class PathSchema(TypedDict, total=False):
+    type: Required[Literal['path']]
+    must_exist: bool  # default: False
+    is_file: bool  # default: False
+    is_dir: bool  # default: False
+    suffix_in: list[str]
+    resolve: bool  # default: False
     strict: bool
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
-def uuid_schema(
+def path_schema(
     *,
-    version: Literal[1, 3, 4, 5, 6, 7, 8] | None = None,
+    must_exist: bool | None = None,
+    is_file: bool | None = None,
+    is_dir: bool | None = None,
+    suffix_in: list[str] | None = None,
+    resolve: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
-) -> UuidSchema:
+) -> PathSchema:
+    """
+    Returns a schema that matches a filesystem path, returning a `pathlib.Path`, e.g.:
+
+    ```py
+    from pathlib import Path
+
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.path_schema(suffix_in=['.toml', '.yaml'])
+    v = SchemaValidator(schema)
+    assert v.validate_python('config/app.toml') == Path('config/app.toml')
+    ```
+
+    In lax mode, `bytes` and `os.PathLike` inputs are decoded with `os.fsdecode`. The filesystem is checked
+    when validating, following symlinks, and relative paths are relative to the current directory.
+
+    Args:
+        must_exist: Whether the path must exist
+        is_file: Whether the path must be an existing file
+        is_dir: Whether the path must be an existing directory
+        suffix_in: The suffixes the path can end with, e.g. `['.tar.gz', '.zip']`, compared case-sensitively
+        resolve: Whether to make the path absolute and resolve symlinks with `Path.resolve()`, the other
+            constraints are checked on the resolved path
+        strict: Whether only `pathlib.Path` instances, or strings in JSON, are allowed
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
+        serialization: Custom serialization schema
+    """
     return _dict_not_none(
-        type='uuid', version=version, strict=strict, ref=ref, metadata=metadata, serialization=serialization
+        type='path',
+        must_exist=must_exist,
+        is_file=is_file,
+        is_dir=is_dir,
+        suffix_in=suffix_in,
+        resolve=resolve,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        examples=examples,
+        serialization=serialization,
     )
 
 
@@ -1534,10 +2745,17 @@ class ListSchema(TypedDict, total=False):
     items_schema: CoreSchema
     min_length: int
     max_length: int
+    monotonic: Literal['increasing', 'decreasing']
+    max_adjacent_duplicates: int
+    unique_items: bool
+    preserve_type: bool
     fail_fast: bool
+    parallel: bool
     strict: bool
+    error_messages: dict[ErrorType, str]
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: IncExSeqOrElseSerSchema
 
 
@@ -1546,10 +2764,17 @@ def list_schema(
     *,
     min_length: int | None = None,
     max_length: int | None = None,
+    monotonic: Literal['increasing', 'decreasing'] | None = None,
+    max_adjacent_duplicates: int | None = None,
+    unique_items: bool | None = None,
+    preserve_type: bool | None = None,
     fail_fast: bool | None = None,
+    parallel: bool | None = None,
     strict: bool | None = None,
+    error_messages: dict[ErrorType, str] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
 ) -> ListSchema:
     """
@@ -1567,10 +2792,26 @@ def list_schema(
         items_schema: The value must be a list of items that match this schema
         min_length: The value must be a list with at least this many items
         max_length: The value must be a list with at most this many items
+        monotonic: Each validated item must be greater than or equal to the one before it with `'increasing'`,
+            or less than or equal to it with `'decreasing'`, e.g. the timestamps of a time series, NaN items are
+            never in order and the items after them are compared to the item before them
+        max_adjacent_duplicates: The value must have at most this many items in a row which are equal to the one
+            before them, `0` forbids adjacent duplicates, e.g. with `monotonic` to require a strict order
+        unique_items: Each validated item must be unique, a `duplicate_item` error is raised for each item equal to
+            an item before it, with the index of the first one
+        preserve_type: Return the validated items in the type of a Python input rather than a list, tuples as tuples,
+            deques as deques with the same `maxlen`, and iterators, e.g. generators, as an iterator over the
+            validated items, e.g. for `Sequence[T]`. Subclasses of tuple, e.g. named tuples, are returned as plain
+            tuples, since they can't be built from the items, other subclasses keep their type. Tuples and deques are
+            serialized like lists, keeping their type in python mode
         fail_fast: Stop validation on the first error
+        parallel: Validate items in parallel threads on free-threaded Python, only if the items schema
+            doesn't call Python functions, used for large collections of e.g. numbers or strings
         strict: The value must be a list with exactly this many items
+        error_messages: Messages to use instead of the default ones for errors raised by this schema, by error type
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -1578,10 +2819,97 @@ def list_schema(
         items_schema=items_schema,
         min_length=min_length,
         max_length=max_length,
+        monotonic=monotonic,
+        max_adjacent_duplicates=max_adjacent_duplicates,
+        unique_items=unique_items,
+        preserve_type=preserve_type,
         fail_fast=fail_fast,
+        parallel=parallel,
+        strict=strict,
+        error_messages=error_messages,
+        ref=ref,
+        metadata=metadata,
+        examples=examples,
+        serialization=serialization,
+    )
+
+
+class KeyedListSchema(TypedDict, total=False):
+    type: Required[Literal['keyed-list']]
+    items_schema: Required[CoreSchema]
+    key: Required[str]
+    duplicates: Literal['error', 'first', 'last']  # default: 'error'
+    min_length: int
+    max_length: int
+    strict: bool
+    error_messages: dict[ErrorType, str]
+    ref: str
+    metadata: dict[str, Any]
+    examples: list[Any]
+    serialization: SerSchema
+
+
+def keyed_list_schema(
+    items_schema: CoreSchema,
+    key: str,
+    *,
+    duplicates: Literal['error', 'first', 'last'] | None = None,
+    min_length: int | None = None,
+    max_length: int | None = None,
+    strict: bool | None = None,
+    error_messages: dict[ErrorType, str] | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> KeyedListSchema:
+    """
+    Returns a schema that matches a list of items, returning a dict of the validated items keyed by one of
+    their fields, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    item_schema = core_schema.typed_dict_schema(
+        {
+            'name': core_schema.typed_dict_field(core_schema.str_schema()),
+            'port': core_schema.typed_dict_field(core_schema.int_schema()),
+        }
+    )
+    schema = core_schema.keyed_list_schema(item_schema, 'name')
+    v = SchemaValidator(schema)
+    assert v.validate_python([{'name': 'http', 'port': '80'}]) == {'http': {'name': 'http', 'port': 80}}
+    ```
+
+    The key is read from the validated item, by key for dicts and by attribute otherwise, errors keep the index
+    of the item in their location. The items are serialized back to a list.
+
+    Args:
+        items_schema: The schema each item must match
+        key: The field of the validated items to use as their key
+        duplicates: What to do when two items have the same key, `'error'` raises a `keyed_list_duplicate` error,
+            `'first'` keeps the first item and `'last'` keeps the last one, in the position of the first
+        min_length: The value must be a list with at least this many items
+        max_length: The value must be a list with at most this many items
+        strict: The value must be a list, not another sequence
+        error_messages: Messages to use instead of the default ones for errors raised by this schema, by error type
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='keyed-list',
+        items_schema=items_schema,
+        key=key,
+        duplicates=duplicates,
+        min_length=min_length,
+        max_length=max_length,
         strict=strict,
+        error_messages=error_messages,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -1594,6 +2922,7 @@ def tuple_positional_schema(
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
 ) -> TupleSchema:
     """
@@ -1618,6 +2947,7 @@ def tuple_positional_schema(
         strict: The value must be a tuple with exactly this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     if extras_schema is not None:
@@ -1631,6 +2961,7 @@ def tuple_positional_schema(
         strict=strict,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -1644,6 +2975,7 @@ def tuple_variable_schema(
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
 ) -> TupleSchema:
     """
@@ -1666,6 +2998,7 @@ def tuple_variable_schema(
         strict: The value must be a tuple with exactly this many items
         ref: Optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return tuple_schema(
@@ -1676,6 +3009,7 @@ def tuple_variable_schema(
         strict=strict,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -1686,10 +3020,14 @@ class TupleSchema(TypedDict, total=False):
     variadic_item_index: int
     min_length: int
     max_length: int
+    unique_items: bool
     fail_fast: bool
+    parallel: bool
     strict: bool
+    error_messages: dict[ErrorType, str]
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: IncExSeqOrElseSerSchema
 
 
@@ -1699,10 +3037,14 @@ def tuple_schema(
     variadic_item_index: int | None = None,
     min_length: int | None = None,
     max_length: int | None = None,
+    unique_items: bool | None = None,
     fail_fast: bool | None = None,
+    parallel: bool | None = None,
     strict: bool | None = None,
+    error_messages: dict[ErrorType, str] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
 ) -> TupleSchema:
     """
@@ -1724,10 +3066,16 @@ def tuple_schema(
         variadic_item_index: The index of the schema in `items_schema` to be treated as variadic (following PEP 646)
         min_length: The value must be a tuple with at least this many items
         max_length: The value must be a tuple with at most this many items
+        unique_items: Each validated item must be unique, a `duplicate_item` error is raised for each item equal to
+            an item before it, with the index of the first one
         fail_fast: Stop validation on the first error
+        parallel: Validate items in parallel threads on free-threaded Python, only for tuples of a single variadic
+            item, e.g. `tuple[int, ...]`, whose schema doesn't call Python functions
         strict: The value must be a tuple with exactly this many items
+        error_messages: Messages to use instead of the default ones for errors raised by this schema, by error type
         ref: Optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -1736,10 +3084,14 @@ def tuple_schema(
         variadic_item_index=variadic_item_index,
         min_length=min_length,
         max_length=max_length,
+        unique_items=unique_items,
         fail_fast=fail_fast,
+        parallel=parallel,
         strict=strict,
+        error_messages=error_messages,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -1750,9 +3102,12 @@ class SetSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     fail_fast: bool
+    parallel: bool
     strict: bool
+    error_messages: dict[ErrorType, str]
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -1762,9 +3117,12 @@ def set_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     fail_fast: bool | None = None,
+    parallel: bool | None = None,
     strict: bool | None = None,
+    error_messages: dict[ErrorType, str] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> SetSchema:
     """
@@ -1785,9 +3143,13 @@ def set_schema(
         min_length: The value must be a set with at least this many items
         max_length: The value must be a set with at most this many items
         fail_fast: Stop validation on the first error
+        parallel: Validate items in parallel threads on free-threaded Python, only if the items schema
+            doesn't call Python functions, used for large collections of e.g. numbers or strings
         strict: The value must be a set with exactly this many items
+        error_messages: Messages to use instead of the default ones for errors raised by this schema, by error type
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -1796,9 +3158,12 @@ def set_schema(
         min_length=min_length,
         max_length=max_length,
         fail_fast=fail_fast,
+        parallel=parallel,
         strict=strict,
+        error_messages=error_messages,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -1809,9 +3174,12 @@ class FrozenSetSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     fail_fast: bool
+    parallel: bool
     strict: bool
+    error_messages: dict[ErrorType, str]
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -1821,9 +3189,12 @@ def frozenset_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     fail_fast: bool | None = None,
+    parallel: bool | None = None,
     strict: bool | None = None,
+    error_messages: dict[ErrorType, str] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> FrozenSetSchema:
     """
@@ -1844,9 +3215,13 @@ def frozenset_schema(
         min_length: The value must be a frozenset with at least this many items
         max_length: The value must be a frozenset with at most this many items
         fail_fast: Stop validation on the first error
+        parallel: Validate items in parallel threads on free-threaded Python, only if the items schema
+            doesn't call Python functions, used for large collections of e.g. numbers or strings
         strict: The value must be a frozenset with exactly this many items
+        error_messages: Messages to use instead of the default ones for errors raised by this schema, by error type
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -1855,9 +3230,12 @@ def frozenset_schema(
         min_length=min_length,
         max_length=max_length,
         fail_fast=fail_fast,
+        parallel=parallel,
         strict=strict,
+        error_messages=error_messages,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -1869,6 +3247,7 @@ class GeneratorSchema(TypedDict, total=False):
     max_length: int
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: IncExSeqOrElseSerSchema
 
 
@@ -1879,6 +3258,7 @@ def generator_schema(
     max_length: int | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
 ) -> GeneratorSchema:
     """
@@ -1900,12 +3280,16 @@ def generator_schema(
     but instead will raise a ValidationError when a violating value is actually read from the generator.
     This is to ensure that "validated" generators retain the benefit of lazy evaluation.
 
+    Async generators and iterators are validated the same way, their items are validated as they're consumed
+    with `async for`.
+
     Args:
         items_schema: The value must be a generator with items that match this schema
         min_length: The value must be a generator that yields at least this many items
         max_length: The value must be a generator that yields at most this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -1915,6 +3299,7 @@ def generator_schema(
         max_length=max_length,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -1939,12 +3324,20 @@ class DictSchema(TypedDict, total=False):
     type: Required[Literal['dict']]
     keys_schema: CoreSchema  # default: AnySchema
     values_schema: CoreSchema  # default: AnySchema
+    keys_ser_schema: CoreSchema
+    keys_separator: str  # default: ','
     min_length: int
     max_length: int
     fail_fast: bool
+    parallel: bool
+    from_items: bool  # default: False
+    dict_type: Literal['dict', 'ordered_dict', 'counter', 'default_dict']  # default: 'dict'
+    default_factory: Callable[[], Any]
     strict: bool
+    error_messages: dict[ErrorType, str]
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: IncExDictOrElseSerSchema
 
 
@@ -1952,12 +3345,20 @@ def dict_schema(
     keys_schema: CoreSchema | None = None,
     values_schema: CoreSchema | None = None,
     *,
+    keys_ser_schema: CoreSchema | None = None,
+    keys_separator: str | None = None,
     min_length: int | None = None,
     max_length: int | None = None,
     fail_fast: bool | None = None,
+    parallel: bool | None = None,
+    from_items: bool | None = None,
+    dict_type: Literal['dict', 'ordered_dict', 'counter', 'default_dict'] | None = None,
+    default_factory: Callable[[], Any] | None = None,
     strict: bool | None = None,
+    error_messages: dict[ErrorType, str] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> DictSchema:
     """
@@ -1976,24 +3377,45 @@ def dict_schema(
     Args:
         keys_schema: The value must be a dict with keys that match this schema
         values_schema: The value must be a dict with values that match this schema
+        keys_ser_schema: The schema used to serialize keys instead of `keys_schema`, e.g. `enum_schema` to
+            serialize enum keys as their values, keys which don't match it are serialized as
+            [`CoreConfig.ser_json_key_fallback`][pydantic_core.core_schema.CoreConfig] says
+        keys_separator: The separator joining the items of tuple keys when serializing to JSON
         min_length: The value must be a dict with at least this many items
         max_length: The value must be a dict with at most this many items
         fail_fast: Stop validation on the first error
+        parallel: Validate keys and values in parallel threads on free-threaded Python, only if the keys and values
+            schemas don't call Python functions, used for large dicts of e.g. numbers or strings
+        from_items: Whether to also accept any iterable of `(key, value)` tuples, e.g. a generator or database
+            cursor, the pairs are validated as they're iterated so no intermediate dict is built
+        dict_type: The type of the validated dict, `'ordered_dict'` for `collections.OrderedDict`, `'counter'` for
+            `collections.Counter` and `'default_dict'` for `collections.defaultdict`, also for JSON objects
+        default_factory: The `default_factory` of a `'default_dict'`, required for it
         strict: Whether the keys and values should be validated with strict mode
+        error_messages: Messages to use instead of the default ones for errors raised by this schema, by error type
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
         type='dict',
         keys_schema=keys_schema,
         values_schema=values_schema,
+        keys_ser_schema=keys_ser_schema,
+        keys_separator=keys_separator,
         min_length=min_length,
         max_length=max_length,
         fail_fast=fail_fast,
+        parallel=parallel,
+        from_items=from_items,
+        dict_type=dict_type,
+        default_factory=default_factory,
         strict=strict,
+        error_messages=error_messages,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -2025,6 +3447,7 @@ class _ValidatorFunctionSchema(TypedDict, total=False):
     schema: Required[CoreSchema]
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -2274,6 +3697,7 @@ class WrapValidatorFunctionSchema(TypedDict, total=False):
     ref: str
     json_schema_input_schema: CoreSchema
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -2284,6 +3708,7 @@ def no_info_wrap_validator_function(
     ref: str | None = None,
     json_schema_input_schema: CoreSchema | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> WrapValidatorFunctionSchema:
     """
@@ -2313,6 +3738,7 @@ def no_info_wrap_validator_function(
         ref: optional unique identifier of the schema, used to reference the schema in other places
         json_schema_input_schema: The core schema to be used to generate the corresponding JSON Schema input type
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -2322,6 +3748,7 @@ def no_info_wrap_validator_function(
         json_schema_input_schema=json_schema_input_schema,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -2334,6 +3761,7 @@ def with_info_wrap_validator_function(
     json_schema_input_schema: CoreSchema | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> WrapValidatorFunctionSchema:
     """
@@ -2365,6 +3793,7 @@ def with_info_wrap_validator_function(
         json_schema_input_schema: The core schema to be used to generate the corresponding JSON Schema input type
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     if field_name is not None:
@@ -2381,6 +3810,7 @@ def with_info_wrap_validator_function(
         json_schema_input_schema=json_schema_input_schema,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -2391,6 +3821,7 @@ class PlainValidatorFunctionSchema(TypedDict, total=False):
     ref: str
     json_schema_input_schema: CoreSchema
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -2400,6 +3831,7 @@ def no_info_plain_validator_function(
     ref: str | None = None,
     json_schema_input_schema: CoreSchema | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> PlainValidatorFunctionSchema:
     """
@@ -2422,6 +3854,7 @@ def no_info_plain_validator_function(
         ref: optional unique identifier of the schema, used to reference the schema in other places
         json_schema_input_schema: The core schema to be used to generate the corresponding JSON Schema input type
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -2430,6 +3863,7 @@ def no_info_plain_validator_function(
         ref=ref,
         json_schema_input_schema=json_schema_input_schema,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -2441,6 +3875,7 @@ def with_info_plain_validator_function(
     ref: str | None = None,
     json_schema_input_schema: CoreSchema | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> PlainValidatorFunctionSchema:
     """
@@ -2464,6 +3899,7 @@ def with_info_plain_validator_function(
         ref: optional unique identifier of the schema, used to reference the schema in other places
         json_schema_input_schema: The core schema to be used to generate the corresponding JSON Schema input type
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     if field_name is not None:
@@ -2479,6 +3915,7 @@ def with_info_plain_validator_function(
         ref=ref,
         json_schema_input_schema=json_schema_input_schema,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -2494,6 +3931,7 @@ class WithDefaultSchema(TypedDict, total=False):
     strict: bool
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -2508,6 +3946,7 @@ def with_default_schema(
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> WithDefaultSchema:
     """
@@ -2534,6 +3973,7 @@ def with_default_schema(
         strict: Whether the underlying schema should be validated with strict mode
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     s = _dict_not_none(
@@ -2546,6 +3986,7 @@ def with_default_schema(
         strict=strict,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
     if default is not PydanticUndefined:
@@ -2559,6 +4000,7 @@ class NullableSchema(TypedDict, total=False):
     strict: bool
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -2568,6 +4010,7 @@ def nullable_schema(
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> NullableSchema:
     """
@@ -2586,38 +4029,58 @@ def nullable_schema(
         strict: Whether the underlying schema should be validated with strict mode
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
-        type='nullable', schema=schema, strict=strict, ref=ref, metadata=metadata, serialization=serialization
+        type='nullable',
+        schema=schema,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        examples=examples,
+        serialization=serialization,
     )
 
 
+UnionScorerFunction = Callable[[str, Any, Literal['exact', 'strict', 'lax'], Union[int, None]], float]
+"""
+A function scoring a valid choice of a smart mode union, called with the choice's label, the validated value, how
+exactly the input matched the choice, and the number of fields set for model-like choices (`None` otherwise).
+"""
+
+
 class UnionSchema(TypedDict, total=False):
     type: Required[Literal['union']]
     choices: Required[list[Union[CoreSchema, tuple[CoreSchema, str]]]]
+    choices_strict: list[Union[bool, None]]
     # default true, whether to automatically collapse unions with one element to the inner validator
     auto_collapse: bool
     custom_error_type: str
     custom_error_message: str
     custom_error_context: dict[str, Union[str, int, float]]
     mode: Literal['smart', 'left_to_right']  # default: 'smart'
+    scorer: UnionScorerFunction
     strict: bool
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
 def union_schema(
     choices: list[CoreSchema | tuple[CoreSchema, str]],
     *,
+    choices_strict: list[bool | None] | None = None,
     auto_collapse: bool | None = None,
     custom_error_type: str | None = None,
     custom_error_message: str | None = None,
     custom_error_context: dict[str, str | int] | None = None,
     mode: Literal['smart', 'left_to_right'] | None = None,
+    scorer: UnionScorerFunction | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> UnionSchema:
     """
@@ -2633,7 +4096,13 @@ def union_schema(
     ```
 
     Args:
-        choices: The schemas to match. If a tuple, the second item is used as the label for the case.
+        choices: The schemas to match. If a tuple, the second item is used as the label for the case, the label
+            replaces the name of the case's validator in error locations, e.g. `('payment', 'card', 'number')`
+            rather than `('payment', 'function-after[<lambda>(), typed-dict]', 'number')`
+        choices_strict: The `strict` setting of each choice, in the same order as `choices`, `True` or `False`
+            override the validation time and schema `strict` settings for everything within the choice, `None`
+            leaves the choice unchanged, e.g. `[True, False]` to only accept an exact match for the first choice
+            while the second can coerce its input
         auto_collapse: whether to automatically collapse unions with one element to the inner validator, default true
         custom_error_type: The custom error type to use if the validation fails
         custom_error_message: The custom error message to use if the validation fails
@@ -2641,20 +4110,27 @@ def union_schema(
         mode: How to select which choice to return
             * `smart` (default) will try to return the choice which is the closest match to the input value
             * `left_to_right` will return the first choice in `choices` which succeeds validation
+        scorer: In `smart` mode, a function scoring each valid choice, the choice with the highest score is returned
+            and the default heuristics break ties, a NaN score is the lowest score, see
+            [`UnionScorerFunction`][pydantic_core.core_schema.UnionScorerFunction]
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
         type='union',
         choices=choices,
+        choices_strict=choices_strict,
         auto_collapse=auto_collapse,
         custom_error_type=custom_error_type,
         custom_error_message=custom_error_message,
         custom_error_context=custom_error_context,
         mode=mode,
+        scorer=scorer,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -2662,28 +4138,40 @@ def union_schema(
 class TaggedUnionSchema(TypedDict, total=False):
     type: Required[Literal['tagged-union']]
     choices: Required[dict[Hashable, CoreSchema]]
-    discriminator: Required[Union[str, list[Union[str, int]], list[list[Union[str, int]]], Callable[[Any], Hashable]]]
+    discriminator: Required[
+        Union[
+            str,
+            list[Union[str, int]],
+            tuple[Union[str, int], ...],
+            list[list[Union[str, int]]],
+            Callable[[Any], Hashable],
+        ]
+    ]
     custom_error_type: str
     custom_error_message: str
     custom_error_context: dict[str, Union[str, int, float]]
     strict: bool
     from_attributes: bool  # default: True
+    cache_discriminator: bool  # default: False
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
 def tagged_union_schema(
     choices: dict[Any, CoreSchema],
-    discriminator: str | list[str | int] | list[list[str | int]] | Callable[[Any], Any],
+    discriminator: str | list[str | int] | tuple[str | int, ...] | list[list[str | int]] | Callable[[Any], Any],
     *,
     custom_error_type: str | None = None,
     custom_error_message: str | None = None,
     custom_error_context: dict[str, int | str | float] | None = None,
     strict: bool | None = None,
     from_attributes: bool | None = None,
+    cache_discriminator: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> TaggedUnionSchema:
     """
@@ -2728,7 +4216,8 @@ def tagged_union_schema(
             (This approach is to prevent multiple ownership of a single schema in Rust)
         discriminator: The discriminator to use to determine the schema to use
             * If `discriminator` is a str, it is the name of the attribute to use as the discriminator
-            * If `discriminator` is a list of int/str, it should be used as a "path" to access the discriminator
+            * If `discriminator` is a list or tuple of int/str, it should be used as a "path" to access the
+              discriminator, e.g. `('meta', 'kind')` for a tag nested in an envelope object
             * If `discriminator` is a list of lists, each inner list is a path, and the first path that exists is used
             * If `discriminator` is a callable, it should return the discriminator when called on the value to validate;
               the callable can return `None` to indicate that there is no matching discriminator present on the input
@@ -2737,8 +4226,13 @@ def tagged_union_schema(
         custom_error_context: The custom error context to use if the validation fails
         strict: Whether the underlying schemas should be validated with strict mode
         from_attributes: Whether to use the attributes of the object to retrieve the discriminator value
+        cache_discriminator: Whether to cache the tag returned by a callable `discriminator` for each type of input,
+            so the callable is only called once per type. Only use this if the tag depends only on the type of the
+            input, e.g. when validating instances of different classes. The tags of mappings, lists and tuples, e.g.
+            JSON objects and arrays, aren't cached since they usually depend on the contents of the input.
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -2750,8 +4244,10 @@ def tagged_union_schema(
         custom_error_context=custom_error_context,
         strict=strict,
         from_attributes=from_attributes,
+        cache_discriminator=cache_discriminator,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -2761,6 +4257,7 @@ class ChainSchema(TypedDict, total=False):
     steps: Required[list[CoreSchema]]
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -2769,6 +4266,7 @@ def chain_schema(
     *,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> ChainSchema:
     """
@@ -2793,9 +4291,12 @@ def chain_schema(
         steps: The schemas to chain
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='chain', steps=steps, ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='chain', steps=steps, ref=ref, metadata=metadata, examples=examples, serialization=serialization
+    )
 
 
 class LaxOrStrictSchema(TypedDict, total=False):
@@ -2805,6 +4306,7 @@ class LaxOrStrictSchema(TypedDict, total=False):
     strict: bool
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -2815,6 +4317,7 @@ def lax_or_strict_schema(
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> LaxOrStrictSchema:
     """
@@ -2849,6 +4352,7 @@ def lax_or_strict_schema(
         strict: Whether the strict schema should be used
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -2858,6 +4362,7 @@ def lax_or_strict_schema(
         strict=strict,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -2868,6 +4373,7 @@ class JsonOrPythonSchema(TypedDict, total=False):
     python_schema: Required[CoreSchema]
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -2877,6 +4383,7 @@ def json_or_python_schema(
     *,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> JsonOrPythonSchema:
     """
@@ -2907,6 +4414,7 @@ def json_or_python_schema(
         python_schema: The schema to use for Python inputs
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -2915,10 +4423,14 @@ def json_or_python_schema(
         python_schema=python_schema,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
 
+MergePolicy = Literal['replace', 'merge', 'append']
+
+
 class TypedDictField(TypedDict, total=False):
     type: Required[Literal['typed-dict-field']]
     schema: Required[CoreSchema]
@@ -2928,6 +4440,8 @@ class TypedDictField(TypedDict, total=False):
     serialization_exclude: bool  # default: False
     metadata: dict[str, Any]
     serialization_exclude_if: Callable[[Any], bool]  # default None
+    merge_policy: MergePolicy  # default: 'replace'
+    read_only: bool  # default: False
 
 
 def typed_dict_field(
@@ -2939,6 +4453,8 @@ def typed_dict_field(
     serialization_exclude: bool | None = None,
     metadata: dict[str, Any] | None = None,
     serialization_exclude_if: Callable[[Any], bool] | None = None,
+    merge_policy: MergePolicy | None = None,
+    read_only: bool | None = None,
 ) -> TypedDictField:
     """
     Returns a schema that matches a typed dict field, e.g.:
@@ -2952,10 +4468,18 @@ def typed_dict_field(
     Args:
         schema: The schema to use for the field
         required: Whether the field is required, otherwise uses the value from `total` on the typed dict
-        validation_alias: The alias(es) to use to find the field in the validation data
+        validation_alias: The alias(es) to use to find the field in the validation data, after the first key a
+            path can use `'*'` to collect the rest of the path from every item of a dict or list into a list,
+            leaving out items without it, errors in the list are reported at the item's key or index in the input,
+            from attributes a dotted alias like `'owner.address.city'` reads each attribute in turn
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
         serialization_exclude_if: A callable that determines whether to exclude the field when serializing based on its value.
+        merge_policy: How the field's values in several layers are combined by `SchemaValidator.validate_merged`,
+            `'replace'` (the default) uses the last value, `'merge'` merges dicts key by key and `'append'`
+            concatenates lists
+        read_only: Whether the field is read-only, like a `ReadOnly` key of a typed dict, assigning it with
+            `SchemaValidator.validate_assignment` is a `read_only_field` error
         metadata: Any other information you want to include with the schema, not used by pydantic-core
     """
     return _dict_not_none(
@@ -2966,6 +4490,8 @@ def typed_dict_field(
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
         serialization_exclude_if=serialization_exclude_if,
+        merge_policy=merge_policy,
+        read_only=read_only,
         metadata=metadata,
     )
 
@@ -2978,11 +4504,16 @@ class TypedDictSchema(TypedDict, total=False):
     computed_fields: list[ComputedField]
     strict: bool
     extras_schema: CoreSchema
+    extras_key_pattern: str
+    extras_pattern_schemas: dict[str, CoreSchema]
     # all these values can be set via config, equivalent fields have `typed_dict_` prefix
     extra_behavior: ExtraBehavior
     total: bool  # default: True
+    alias_generator: Callable[[str], str]
+    derive_aliases: bool
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
     config: CoreConfig
 
@@ -2995,10 +4526,15 @@ def typed_dict_schema(
     computed_fields: list[ComputedField] | None = None,
     strict: bool | None = None,
     extras_schema: CoreSchema | None = None,
+    extras_key_pattern: str | None = None,
+    extras_pattern_schemas: dict[str, CoreSchema] | None = None,
     extra_behavior: ExtraBehavior | None = None,
     total: bool | None = None,
+    alias_generator: Callable[[str], str] | None = None,
+    derive_aliases: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
     config: CoreConfig | None = None,
 ) -> TypedDictSchema:
@@ -3028,10 +4564,19 @@ def typed_dict_schema(
         computed_fields: Computed fields to use when serializing the model, only applies when directly inside a model
         strict: Whether the typed dict is strict
         extras_schema: The extra validator to use for the typed dict
+        extras_key_pattern: A regex the keys of extra input data must match, other extra input data is forbidden
+        extras_pattern_schemas: Schemas for the values of extra input data with keys matching each regex, the first
+            match is used, extra input data with keys matching none is validated with `extras_schema` if set,
+            otherwise it's forbidden
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         extra_behavior: The extra behavior to use for the typed dict
         total: Whether the typed dict is total, otherwise uses `typed_dict_total` from config
+        alias_generator: Called with the name of each field without a `validation_alias` or `serialization_alias`
+            when the schema is built, the string it returns is used as the missing aliases
+        derive_aliases: Whether fields with only one of `validation_alias` and `serialization_alias` set use it as
+            the other too, if it's a string, so serialized data can be validated again
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -3042,10 +4587,15 @@ def typed_dict_schema(
         computed_fields=computed_fields,
         strict=strict,
         extras_schema=extras_schema,
+        extras_key_pattern=extras_key_pattern,
+        extras_pattern_schemas=extras_pattern_schemas,
         extra_behavior=extra_behavior,
         total=total,
+        alias_generator=alias_generator,
+        derive_aliases=derive_aliases,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
         config=config,
     )
@@ -3059,6 +4609,9 @@ class ModelField(TypedDict, total=False):
     serialization_exclude: bool  # default: False
     serialization_exclude_if: Callable[[Any], bool]  # default: None
     frozen: bool
+    read_only: bool
+    merge_policy: MergePolicy  # default: 'replace'
+    empty_str_as_missing: bool
     metadata: dict[str, Any]
 
 
@@ -3070,6 +4623,9 @@ def model_field(
     serialization_exclude: bool | None = None,
     serialization_exclude_if: Callable[[Any], bool] | None = None,
     frozen: bool | None = None,
+    read_only: bool | None = None,
+    merge_policy: MergePolicy | None = None,
+    empty_str_as_missing: bool | None = None,
     metadata: dict[str, Any] | None = None,
 ) -> ModelField:
     """
@@ -3083,11 +4639,21 @@ def model_field(
 
     Args:
         schema: The schema to use for the field
-        validation_alias: The alias(es) to use to find the field in the validation data
+        validation_alias: The alias(es) to use to find the field in the validation data, after the first key a
+            path can use `'*'` to collect the rest of the path from every item of a dict or list into a list,
+            leaving out items without it, errors in the list are reported at the item's key or index in the input,
+            from attributes a dotted alias like `'owner.address.city'` reads each attribute in turn
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
         serialization_exclude_if: A Callable that determines whether to exclude a field during serialization based on its value.
         frozen: Whether the field is frozen
+        read_only: Whether the field is read-only, assigning it with `SchemaValidator.validate_assignment` is a
+            `read_only_field` error
+        merge_policy: How the field's values in several layers are combined by `SchemaValidator.validate_merged`,
+            `'replace'` (the default) uses the last value, `'merge'` merges dicts key by key and `'append'`
+            concatenates lists
+        empty_str_as_missing: Whether an empty string input is treated as if the field was missing, overriding the
+            model's `empty_str_as_missing`
         metadata: Any other information you want to include with the schema, not used by pydantic-core
     """
     return _dict_not_none(
@@ -3098,10 +4664,85 @@ def model_field(
         serialization_exclude=serialization_exclude,
         serialization_exclude_if=serialization_exclude_if,
         frozen=frozen,
+        read_only=read_only,
+        merge_policy=merge_policy,
+        empty_str_as_missing=empty_str_as_missing,
         metadata=metadata,
     )
 
 
+class SumConsistencyCheck(TypedDict, total=False):
+    type: Required[Literal['sum']]
+    field: Required[str]
+    of: Required[list[list[Union[str, int]]]]
+    tolerance: float
+
+
+def sum_consistency_check(
+    field: str, of: list[list[str | int]], *, tolerance: float | None = None
+) -> SumConsistencyCheck:
+    """
+    Returns a check that a field of a model equals the sum of other values, e.g. `total == sum(items[].price)`:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    item_schema = core_schema.typed_dict_schema({'price': core_schema.typed_dict_field(core_schema.int_schema())})
+    schema = core_schema.model_fields_schema(
+        {
+            'items': core_schema.model_field(core_schema.list_schema(item_schema)),
+            'total': core_schema.model_field(core_schema.int_schema()),
+        },
+        consistency_checks=[core_schema.sum_consistency_check('total', [['items', '*', 'price']])],
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python({'items': [{'price': 10}, {'price': 5}], 'total': 15})[0]['total'] == 15
+    ```
+
+    The sum is computed in Rust for ints and floats, other numbers like decimals are added in Python. Values which
+    can't be added, e.g. strings, are a `consistency_uncomputable` error on the field rather than a `TypeError`.
+
+    Args:
+        field: The field which must equal the sum
+        of: Paths to the values to add, starting with a field name, `'*'` adds the rest of the path from every item
+            of a list or dict, values the paths don't find are left out
+        tolerance: The largest absolute difference allowed between the field and the sum, float sums are also
+            compared with a relative tolerance of `1e-9`, as with `math.isclose`
+    """
+    return _dict_not_none(type='sum', field=field, of=of, tolerance=tolerance)
+
+
+class ChecksumConsistencyCheck(TypedDict, total=False):
+    type: Required[Literal['checksum']]
+    field: Required[str]
+    of: Required[list[Union[str, int]]]
+    algorithm: Required[Literal['crc32', 'adler32']]
+
+
+def checksum_consistency_check(
+    field: str, of: list[str | int], algorithm: Literal['crc32', 'adler32']
+) -> ChecksumConsistencyCheck:
+    """
+    Returns a check that a field of a model is the checksum of another value, a `str` or `bytes`, e.g.:
+
+    ```py
+    from pydantic_core import core_schema
+
+    check = core_schema.checksum_consistency_check('crc', ['payload'], 'crc32')
+    ```
+
+    Args:
+        field: The field which must equal the checksum, as an int or a hex string like `'cbf43926'`
+        of: The path to the value to compute the checksum of, starting with a field name, `str` values are
+            encoded as UTF-8
+        algorithm: The checksum algorithm, `'crc32'` or `'adler32'`, as computed by `zlib`
+    """
+    return _dict_not_none(type='checksum', field=field, of=of, algorithm=algorithm)
+
+
+ConsistencyCheck = Union[SumConsistencyCheck, ChecksumConsistencyCheck]
+
+
 class ModelFieldsSchema(TypedDict, total=False):
     type: Required[Literal['model-fields']]
     fields: Required[dict[str, ModelField]]
@@ -3110,10 +4751,17 @@ class ModelFieldsSchema(TypedDict, total=False):
     strict: bool
     extras_schema: CoreSchema
     extras_keys_schema: CoreSchema
+    extras_key_pattern: str
+    extras_pattern_schemas: dict[str, CoreSchema]
     extra_behavior: ExtraBehavior
     from_attributes: bool
+    alias_generator: Callable[[str], str]
+    derive_aliases: bool
+    consistency_checks: list[ConsistencyCheck]
+    empty_str_as_missing: bool
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -3125,10 +4773,17 @@ def model_fields_schema(
     strict: bool | None = None,
     extras_schema: CoreSchema | None = None,
     extras_keys_schema: CoreSchema | None = None,
+    extras_key_pattern: str | None = None,
+    extras_pattern_schemas: dict[str, CoreSchema] | None = None,
     extra_behavior: ExtraBehavior | None = None,
     from_attributes: bool | None = None,
+    alias_generator: Callable[[str], str] | None = None,
+    derive_aliases: bool | None = None,
+    consistency_checks: list[ConsistencyCheck] | None = None,
+    empty_str_as_missing: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> ModelFieldsSchema:
     """
@@ -3152,10 +4807,26 @@ def model_fields_schema(
         strict: Whether the model is strict
         extras_schema: The schema to use when validating extra input data
         extras_keys_schema: The schema to use when validating the keys of extra input data
+        extras_key_pattern: A regex the keys of extra input data must match, other extra input data is forbidden
+        extras_pattern_schemas: Schemas for the values of extra input data with keys matching each regex, the first
+            match is used, extra input data with keys matching none is validated with `extras_schema` if set,
+            otherwise it's forbidden
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         extra_behavior: The extra behavior to use for the model fields
         from_attributes: Whether the model fields should be populated from attributes
+        alias_generator: Called with the name of each field without a `validation_alias` or `serialization_alias`
+            when the schema is built, the string it returns is used as the missing aliases
+        derive_aliases: Whether fields with only one of `validation_alias` and `serialization_alias` set use it as
+            the other too, if it's a string, so serialized data can be validated again
+        consistency_checks: Checks that a field equals a function of other fields, run once all the fields are
+            valid and on `validate_assignment`, a failing check is a `consistency_mismatch` error on its field,
+            see [`sum_consistency_check`][pydantic_core.core_schema.sum_consistency_check] and
+            [`checksum_consistency_check`][pydantic_core.core_schema.checksum_consistency_check]
+        empty_str_as_missing: Whether empty string inputs are treated as if their fields were missing, so they get
+            their default or a `missing` error, e.g. for the blank inputs of HTML forms, overriding the
+            `empty_str_as_missing` config
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -3166,10 +4837,17 @@ def model_fields_schema(
         strict=strict,
         extras_schema=extras_schema,
         extras_keys_schema=extras_keys_schema,
+        extras_key_pattern=extras_key_pattern,
+        extras_pattern_schemas=extras_pattern_schemas,
         extra_behavior=extra_behavior,
         from_attributes=from_attributes,
+        alias_generator=alias_generator,
+        derive_aliases=derive_aliases,
+        consistency_checks=consistency_checks,
+        empty_str_as_missing=empty_str_as_missing,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -3189,7 +4867,9 @@ class ModelSchema(TypedDict, total=False):
     config: CoreConfig
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
+    unknown_type_encoder: Callable[[Any], Any]
 
 
 def model_schema(
@@ -3207,7 +4887,9 @@ def model_schema(
     config: CoreConfig | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
+    unknown_type_encoder: Callable[[Any], Any] | None = None,
 ) -> ModelSchema:
     """
     A model schema generally contains a typed-dict schema.
@@ -3256,7 +4938,12 @@ def model_schema(
         config: The config to use for the model
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
+        unknown_type_encoder: A function called to serialize values of unknown type within the model to JSON,
+            e.g. in fields typed as `Any`, before the `fallback` passed when serializing. It can raise
+            `NotImplementedError` to pass the value on to the `fallback`, nested models use their own encoder if
+            they have one
     """
     return _dict_not_none(
         type='model',
@@ -3273,7 +4960,9 @@ def model_schema(
         config=config,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
+        unknown_type_encoder=unknown_type_encoder,
     )
 
 
@@ -3326,7 +5015,10 @@ def dataclass_field(
         kw_only: Whether the field can be set with a positional argument as well as a keyword argument
         init: Whether the field should be validated during initialization
         init_only: Whether the field should be omitted  from `__dict__` and passed to `__post_init__`
-        validation_alias: The alias(es) to use to find the field in the validation data
+        validation_alias: The alias(es) to use to find the field in the validation data, after the first key a
+            path can use `'*'` to collect the rest of the path from every item of a dict or list into a list,
+            leaving out items without it, errors in the list are reported at the item's key or index in the input,
+            from attributes a dotted alias like `'owner.address.city'` reads each attribute in turn
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
         serialization_exclude_if: A callable that determines whether to exclude the field when serializing based on its value.
@@ -3355,8 +5047,11 @@ class DataclassArgsSchema(TypedDict, total=False):
     fields: Required[list[DataclassField]]
     computed_fields: list[ComputedField]
     collect_init_only: bool  # default: False
+    alias_generator: Callable[[str], str]
+    derive_aliases: bool
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
     extra_behavior: ExtraBehavior
 
@@ -3367,8 +5062,11 @@ def dataclass_args_schema(
     *,
     computed_fields: list[ComputedField] | None = None,
     collect_init_only: bool | None = None,
+    alias_generator: Callable[[str], str] | None = None,
+    derive_aliases: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
     extra_behavior: ExtraBehavior | None = None,
 ) -> DataclassArgsSchema:
@@ -3394,8 +5092,13 @@ def dataclass_args_schema(
         fields: The fields to use for the dataclass
         computed_fields: Computed fields to use when serializing the dataclass
         collect_init_only: Whether to collect init only fields into a dict to pass to `__post_init__`
+        alias_generator: Called with the name of each field without a `validation_alias` or `serialization_alias`
+            when the schema is built, the string it returns is used as the missing aliases
+        derive_aliases: Whether fields with only one of `validation_alias` and `serialization_alias` set use it as
+            the other too, if it's a string, so serialized data can be validated again
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
         extra_behavior: How to handle extra fields
     """
@@ -3405,8 +5108,11 @@ def dataclass_args_schema(
         fields=fields,
         computed_fields=computed_fields,
         collect_init_only=collect_init_only,
+        alias_generator=alias_generator,
+        derive_aliases=derive_aliases,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
         extra_behavior=extra_behavior,
     )
@@ -3425,9 +5131,11 @@ class DataclassSchema(TypedDict, total=False):
     frozen: bool  # default False
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
     slots: bool
     config: CoreConfig
+    unknown_type_encoder: Callable[[Any], Any]
 
 
 def dataclass_schema(
@@ -3442,10 +5150,12 @@ def dataclass_schema(
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
     frozen: bool | None = None,
     slots: bool | None = None,
     config: CoreConfig | None = None,
+    unknown_type_encoder: Callable[[Any], Any] | None = None,
 ) -> DataclassSchema:
     """
     Returns a schema for a dataclass. As with `ModelSchema`, this schema can only be used as a field within
@@ -3465,10 +5175,15 @@ def dataclass_schema(
         strict: Whether to require an exact instance of `cls`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
         frozen: Whether the dataclass is frozen
         slots: Whether `slots=True` on the dataclass, means each field is assigned independently, rather than
             simply setting `__dict__`, default false
+        unknown_type_encoder: A function called to serialize values of unknown type within the dataclass to JSON,
+            e.g. in fields typed as `Any`, before the `fallback` passed when serializing. It can raise
+            `NotImplementedError` to pass the value on to the `fallback`, nested dataclasss use their own encoder if
+            they have one
     """
     return _dict_not_none(
         type='dataclass',
@@ -3482,10 +5197,12 @@ def dataclass_schema(
         strict=strict,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
         frozen=frozen,
         slots=slots,
         config=config,
+        unknown_type_encoder=unknown_type_encoder,
     )
 
 
@@ -3539,6 +5256,7 @@ class ArgumentsSchema(TypedDict, total=False):
     var_kwargs_schema: CoreSchema
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -3552,6 +5270,7 @@ def arguments_schema(
     var_kwargs_schema: CoreSchema | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> ArgumentsSchema:
     """
@@ -3582,6 +5301,7 @@ def arguments_schema(
         var_kwargs_schema: The variable kwargs schema to use for the arguments schema
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -3594,6 +5314,7 @@ def arguments_schema(
         var_kwargs_schema=var_kwargs_schema,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -3658,6 +5379,7 @@ class ArgumentsV3Schema(TypedDict, total=False):
     extra_behavior: Literal['forbid', 'ignore']  # 'allow' doesn't make sense here.
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -3669,6 +5391,7 @@ def arguments_v3_schema(
     extra_behavior: Literal['forbid', 'ignore'] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> ArgumentsV3Schema:
     """
@@ -3698,6 +5421,7 @@ def arguments_v3_schema(
         extra_behavior: The extra behavior to use.
         ref: optional unique identifier of the schema, used to reference the schema in other places.
         metadata: Any other information you want to include with the schema, not used by pydantic-core.
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema.
     """
     return _dict_not_none(
@@ -3708,6 +5432,7 @@ def arguments_v3_schema(
         extra_behavior=extra_behavior,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -3720,6 +5445,7 @@ class CallSchema(TypedDict, total=False):
     return_schema: CoreSchema
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -3731,6 +5457,7 @@ def call_schema(
     return_schema: CoreSchema | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> CallSchema:
     """
@@ -3763,6 +5490,7 @@ def call_schema(
         return_schema: The return schema to use for the call schema
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -3773,6 +5501,7 @@ def call_schema(
         return_schema=return_schema,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -3785,6 +5514,7 @@ class CustomErrorSchema(TypedDict, total=False):
     custom_error_context: dict[str, Union[str, int, float]]
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -3796,6 +5526,7 @@ def custom_error_schema(
     custom_error_context: dict[str, Any] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> CustomErrorSchema:
     """
@@ -3820,6 +5551,7 @@ def custom_error_schema(
         custom_error_context: The custom error context to use for the custom error schema
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -3830,6 +5562,7 @@ def custom_error_schema(
         custom_error_context=custom_error_context,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -3839,6 +5572,7 @@ class JsonSchema(TypedDict, total=False):
     schema: CoreSchema
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -3847,6 +5581,7 @@ def json_schema(
     *,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> JsonSchema:
     """
@@ -3883,9 +5618,12 @@ def json_schema(
         schema: The schema to use for the JSON schema
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='json', schema=schema, ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='json', schema=schema, ref=ref, metadata=metadata, examples=examples, serialization=serialization
+    )
 
 
 class UrlSchema(TypedDict, total=False):
@@ -3896,9 +5634,13 @@ class UrlSchema(TypedDict, total=False):
     default_host: str
     default_port: int
     default_path: str
+    preserve_empty_path: bool  # default False
+    punycode_host: bool  # default True
+    normalize_path: bool  # default True
     strict: bool
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -3911,9 +5653,12 @@ def url_schema(
     default_port: int | None = None,
     default_path: str | None = None,
     preserve_empty_path: bool | None = None,
+    punycode_host: bool | None = None,
+    normalize_path: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> UrlSchema:
     """
@@ -3936,9 +5681,15 @@ def url_schema(
         default_port: The default port to use if the URL does not have a port
         default_path: The default path to use if the URL does not have a path
         preserve_empty_path: Whether to preserve an empty path or convert it to '/', default False
+        punycode_host: Whether domains are punycode encoded and lowercased, default True.
+            With False, domains are kept as given in the URL's string and `host`
+        normalize_path: Whether the path is normalized, e.g. resolving `.` and `..` segments and percent-encoding
+            characters like spaces, default True. With False, the path is kept as given, together with
+            `punycode_host=False` and `preserve_empty_path=True` URLs keep their original form, e.g. for signing
         strict: Whether to use strict URL parsing
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -3950,9 +5701,12 @@ def url_schema(
         default_port=default_port,
         default_path=default_path,
         preserve_empty_path=preserve_empty_path,
+        punycode_host=punycode_host,
+        normalize_path=normalize_path,
         strict=strict,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -3968,6 +5722,7 @@ class MultiHostUrlSchema(TypedDict, total=False):
     strict: bool
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -3983,6 +5738,7 @@ def multi_host_url_schema(
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> MultiHostUrlSchema:
     """
@@ -4008,6 +5764,7 @@ def multi_host_url_schema(
         strict: Whether to use strict URL parsing
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -4022,6 +5779,7 @@ def multi_host_url_schema(
         strict=strict,
         ref=ref,
         metadata=metadata,
+        examples=examples,
         serialization=serialization,
     )
 
@@ -4031,6 +5789,7 @@ class DefinitionsSchema(TypedDict, total=False):
     schema: Required[CoreSchema]
     definitions: Required[list[CoreSchema]]
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -4062,6 +5821,7 @@ class DefinitionReferenceSchema(TypedDict, total=False):
     schema_ref: Required[str]
     ref: str
     metadata: dict[str, Any]
+    examples: list[Any]
     serialization: SerSchema
 
 
@@ -4069,6 +5829,7 @@ def definition_reference_schema(
     schema_ref: str,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    examples: list[Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> DefinitionReferenceSchema:
     """
@@ -4092,10 +5853,16 @@ def definition_reference_schema(
     Args:
         schema_ref: The schema ref to use for the definition reference schema
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        examples: Examples of valid inputs, checked by `SchemaValidator.check_examples`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
-        type='definition-ref', schema_ref=schema_ref, ref=ref, metadata=metadata, serialization=serialization
+        type='definition-ref',
+        schema_ref=schema_ref,
+        ref=ref,
+        metadata=metadata,
+        examples=examples,
+        serialization=serialization,
     )
 
 
@@ -4118,12 +5885,14 @@ if not MYPY:
         DatetimeSchema,
         TimedeltaSchema,
         LiteralSchema,
+        ConstSchema,
         MissingSentinelSchema,
         EnumSchema,
         IsInstanceSchema,
         IsSubclassSchema,
         CallableSchema,
         ListSchema,
+        KeyedListSchema,
         TupleSchema,
         SetSchema,
         FrozenSetSchema,
@@ -4155,7 +5924,17 @@ if not MYPY:
         DefinitionsSchema,
         DefinitionReferenceSchema,
         UuidSchema,
+        SemverSchema,
+        UlidSchema,
+        IpV4AddressSchema,
+        IpV6AddressSchema,
+        IpNetworkSchema,
+        IpInterfaceSchema,
+        EmailSchema,
+        NameEmailSchema,
+        PathSchema,
         ComplexSchema,
+        PluginSchema,
     ]
 elif False:
     CoreSchema: TypeAlias = Mapping[str, Any]
@@ -4177,12 +5956,14 @@ CoreSchemaType = Literal[
     'datetime',
     'timedelta',
     'literal',
+    'const',
     'missing-sentinel',
     'enum',
     'is-instance',
     'is-subclass',
     'callable',
     'list',
+    'keyed-list',
     'tuple',
     'set',
     'frozenset',
@@ -4214,7 +5995,17 @@ CoreSchemaType = Literal[
     'definitions',
     'definition-ref',
     'uuid',
+    'semver',
+    'ulid',
+    'ip-v4-address',
+    'ip-v6-address',
+    'ip-network',
+    'ip-interface',
+    'email',
+    'name-email',
+    'path',
     'complex',
+    'plugin',
 ]
 
 CoreSchemaFieldType = Literal['model-field', 'dataclass-field', 'typed-dict-field', 'computed-field']
@@ -4228,23 +6019,30 @@ ErrorType = Literal[
     'json_type',
     'needs_python_object',
     'recursion_loop',
+    'input_limit_exceeded',
+    'validation_timeout',
     'missing',
     'frozen_field',
+    'read_only_field',
     'frozen_instance',
     'extra_forbidden',
     'invalid_key',
     'get_attribute_error',
     'model_type',
     'model_attributes_type',
+    'consistency_mismatch',
+    'consistency_uncomputable',
     'dataclass_type',
     'dataclass_exact_type',
     'default_factory_not_called',
+    'default_shared',
     'none_required',
     'greater_than',
     'greater_than_equal',
     'less_than',
     'less_than_equal',
     'multiple_of',
+    'out_of_range',
     'finite_number',
     'too_short',
     'too_long',
@@ -4255,11 +6053,18 @@ ErrorType = Literal[
     'string_unicode',
     'string_too_short',
     'string_too_long',
+    'string_bytes_too_short',
+    'string_bytes_too_long',
     'string_pattern_mismatch',
+    'string_pattern_forbidden',
     'enum',
     'dict_type',
     'mapping_type',
     'list_type',
+    'list_not_monotonic',
+    'list_adjacent_duplicates',
+    'keyed_list_duplicate',
+    'duplicate_item',
     'tuple_type',
     'set_type',
     'set_item_not_hashable',
@@ -4269,8 +6074,11 @@ ErrorType = Literal[
     'int_parsing',
     'int_parsing_size',
     'int_from_float',
+    'int_from_float_tolerance',
+    'int_max_digits',
     'float_type',
     'float_parsing',
+    'float_inexact',
     'bytes_type',
     'bytes_too_short',
     'bytes_too_long',
@@ -4278,26 +6086,32 @@ ErrorType = Literal[
     'value_error',
     'assertion_error',
     'literal_error',
+    'const_mismatch',
     'missing_sentinel_error',
     'date_type',
     'date_parsing',
     'date_from_datetime_parsing',
     'date_from_datetime_inexact',
+    'date_format',
     'date_past',
     'date_future',
     'time_type',
     'time_parsing',
+    'time_format',
     'datetime_type',
     'datetime_parsing',
     'datetime_object_invalid',
     'datetime_from_date_parsing',
+    'datetime_format',
     'datetime_past',
     'datetime_future',
     'timezone_naive',
     'timezone_aware',
     'timezone_offset',
+    'timezone_name',
     'time_delta_type',
     'time_delta_parsing',
+    'time_delta_negative',
     'frozen_set_type',
     'is_instance_of',
     'is_subclass_of',
@@ -4319,6 +6133,25 @@ ErrorType = Literal[
     'uuid_type',
     'uuid_parsing',
     'uuid_version',
+    'uuid_timestamp_greater_than_equal',
+    'uuid_timestamp_less_than',
+    'semver_type',
+    'semver_parsing',
+    'semver_greater_than_equal',
+    'semver_less_than',
+    'ulid_type',
+    'ulid_parsing',
+    'email_parsing',
+    'email_too_long',
+    'email_domain',
+    'path_type',
+    'path_not_found',
+    'path_not_file',
+    'path_not_directory',
+    'path_suffix',
+    'ip_address',
+    'ip_network',
+    'ip_interface',
     'decimal_type',
     'decimal_parsing',
     'decimal_max_digits',
@@ -4326,6 +6159,7 @@ ErrorType = Literal[
     'decimal_whole_digits',
     'complex_type',
     'complex_str_parsing',
+    'plugin_type',
 ]
 
 
@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::error::Error;
 use std::fmt;
 use std::ops::Deref;
@@ -10,7 +11,7 @@ use pyo3::types::{PyDict, PyList, PyString};
 use pyo3::{intern, FromPyObject, PyErrArguments};
 
 use crate::errors::{PyLineError, ValError};
-use crate::input::InputType;
+use crate::input::{Input, InputType};
 use crate::tools::SchemaDict;
 use crate::ValidationError;
 
@@ -48,6 +49,62 @@ pub fn is_strict(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>)
     Ok(schema_or_config_same(schema, config, intern!(py, "strict"))?.unwrap_or(false))
 }
 
+/// How the aliases fields don't set are found, from the `alias_generator` and `derive_aliases` keys of a model
+/// fields, typed dict or dataclass args schema.
+pub struct FieldAliases<'py> {
+    generator: Option<Bound<'py, PyAny>>,
+    derive: bool,
+}
+
+impl<'py> FieldAliases<'py> {
+    pub fn from_schema(schema: &Bound<'py, PyDict>) -> PyResult<Self> {
+        let py = schema.py();
+        Ok(Self {
+            generator: schema.get_as(intern!(py, "alias_generator"))?,
+            derive: schema.get_as(intern!(py, "derive_aliases"))?.unwrap_or(false),
+        })
+    }
+
+    /// The `validation_alias` or `serialization_alias` of a field, `alias_key`. If it isn't set, with
+    /// `derive_aliases` the field's other alias is used if it's a string, otherwise the alias `alias_generator`
+    /// returns for the field's name.
+    pub fn get(
+        &self,
+        field: &Bound<'py, PyDict>,
+        alias_key: &Bound<'py, PyString>,
+        field_name: &str,
+    ) -> PyResult<Option<Bound<'py, PyAny>>> {
+        let py = field.py();
+        if let Some(alias) = field.get_item(alias_key)? {
+            return Ok(Some(alias));
+        }
+        if self.derive {
+            let other_key = match alias_key.to_str()? {
+                "validation_alias" => intern!(py, "serialization_alias"),
+                _ => intern!(py, "validation_alias"),
+            };
+            if let Some(alias) = field.get_item(other_key)? {
+                if alias.is_instance_of::<PyString>() {
+                    return Ok(Some(alias));
+                }
+            }
+        }
+        let Some(generator) = &self.generator else {
+            return Ok(None);
+        };
+        let alias = generator.call1((field_name,))?;
+        if alias.is_instance_of::<PyString>() {
+            Ok(Some(alias))
+        } else {
+            py_schema_err!(
+                "`alias_generator` should return a string, got `{}` for field `{}`",
+                alias.get_type().name()?,
+                field_name
+            )
+        }
+    }
+}
+
 enum SchemaErrorEnum {
     Message(String),
     ValidationError(ValidationError),
@@ -139,7 +196,7 @@ impl SchemaError {
     fn errors(&self, py: Python) -> PyResult<Py<PyList>> {
         match &self.0 {
             SchemaErrorEnum::Message(_) => Ok(PyList::empty(py).unbind()),
-            SchemaErrorEnum::ValidationError(error) => error.errors(py, false, false, true),
+            SchemaErrorEnum::ValidationError(error) => error.errors(py, false, false, true, false),
         }
     }
 
@@ -178,6 +235,140 @@ macro_rules! py_schema_err {
 }
 pub(crate) use py_schema_err;
 
+/// The bounds of an `interval` schema setting, e.g. `[0, 100)` is `ge=0, lt=100`.
+pub struct IntervalBounds<T> {
+    pub le: Option<T>,
+    pub lt: Option<T>,
+    pub ge: Option<T>,
+    pub gt: Option<T>,
+}
+
+/// Get the `interval` schema setting, which can't be combined with individual bounds.
+pub fn schema_interval(schema: &Bound<'_, PyDict>) -> PyResult<Option<String>> {
+    let py = schema.py();
+    let Some(interval) = schema.get_as::<String>(intern!(py, "interval"))? else {
+        return Ok(None);
+    };
+    for key in ["le", "lt", "ge", "gt"] {
+        if schema.contains(key)? {
+            return py_schema_err!("`interval` cannot be combined with `{}`", key);
+        }
+    }
+    Ok(Some(interval.trim().to_string()))
+}
+
+/// Parse an interval in mathematical notation, e.g. `[0, 100)` or `(0, inf)`, `-inf` and `inf` mean the
+/// interval is unbounded at that end. `parse_bound` converts each bound to its value.
+///
+/// Empty intervals, e.g. `[1, 0]` or `[0, 0)`, are rejected as no value could be valid.
+pub fn parse_interval<T: PartialOrd>(
+    interval: &str,
+    parse_bound: impl Fn(&str) -> Option<T>,
+) -> PyResult<IntervalBounds<T>> {
+    let invalid = || py_schema_err!("Invalid interval `{}`, expected an interval like `[0, 100)`", interval);
+    let trimmed = interval.trim();
+    let (Some(lower_inclusive), Some(upper_inclusive)) = (
+        match trimmed.chars().next() {
+            Some('[') => Some(true),
+            Some('(') => Some(false),
+            _ => None,
+        },
+        match trimmed.chars().last() {
+            Some(']') => Some(true),
+            Some(')') => Some(false),
+            _ => None,
+        },
+    ) else {
+        return invalid();
+    };
+    let Some((lower, upper)) = trimmed
+        .get(1..trimmed.len() - 1)
+        .and_then(|inner| inner.split_once(','))
+    else {
+        return invalid();
+    };
+    let parse = |bound: &str, unbounded: &[&str]| -> Result<Option<T>, ()> {
+        let bound = bound.trim();
+        if unbounded.contains(&bound) {
+            Ok(None)
+        } else {
+            parse_bound(bound).map(Some).ok_or(())
+        }
+    };
+    let (Ok(lower), Ok(upper)) = (parse(lower, &["-inf"]), parse(upper, &["inf", "+inf"])) else {
+        return invalid();
+    };
+    if let (Some(lower), Some(upper)) = (&lower, &upper) {
+        match lower.partial_cmp(upper) {
+            Some(Ordering::Less) => {}
+            Some(Ordering::Equal) if lower_inclusive && upper_inclusive => {}
+            None => return py_schema_err!("Invalid interval `{}`, bounds can't be NaN", interval),
+            _ => {
+                return py_schema_err!(
+                "Empty interval `{}`, the lower bound must be less than the upper bound, or equal to it if both are \
+                     included",
+                interval
+            )
+            }
+        }
+    }
+    let (ge, gt) = if lower_inclusive { (lower, None) } else { (None, lower) };
+    let (le, lt) = if upper_inclusive { (upper, None) } else { (None, upper) };
+    Ok(IntervalBounds { le, lt, ge, gt })
+}
+
+/// The `digit_separators` schema setting of numbers, characters besides `_` which lax mode accepts between groups of
+/// three digits in strings, e.g. `','` for `'1,000,000'`.
+#[derive(Debug, Clone)]
+pub struct DigitSeparators(String);
+
+impl DigitSeparators {
+    pub fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let Some(separators) = schema.get_as::<String>(intern!(py, "digit_separators"))? else {
+            return Ok(None);
+        };
+        if separators.is_empty()
+            || separators
+                .chars()
+                .any(|c| c.is_alphanumeric() || matches!(c, '.' | '+' | '-'))
+        {
+            return py_schema_err!(
+                "Invalid digit_separators: `{}`, separators can't be letters, digits, signs or '.'",
+                separators
+            );
+        }
+        Ok(Some(Self(separators)))
+    }
+
+    /// Remove the separators from a string input, `None` if it isn't a string, has no separators or they're
+    /// misplaced: they can only be between digits of the integer part, in groups of three after the first, and
+    /// there can only be one kind of separator.
+    pub fn strip<'py>(&self, input: &(impl Input<'py> + ?Sized)) -> Option<String> {
+        let either_str = input.exact_str().ok()?;
+        let s = either_str.as_cow().ok()?;
+        let s = s.trim();
+        let separator = s.chars().find(|c| self.0.contains(*c))?;
+        let (sign, unsigned) = match s.strip_prefix(['+', '-']) {
+            Some(unsigned) => (&s[..1], unsigned),
+            None => ("", s),
+        };
+        let end = unsigned
+            .find(|c: char| !c.is_ascii_digit() && c != separator)
+            .unwrap_or(unsigned.len());
+        let (integer, rest) = unsigned.split_at(end);
+        if rest.contains(|c| self.0.contains(c)) {
+            return None;
+        }
+        let mut groups = integer.split(separator);
+        let first = groups.next()?;
+        if first.is_empty() || first.len() > 3 || groups.any(|group| group.len() != 3) {
+            return None;
+        }
+        Some(format!("{sign}{}{rest}", integer.replace(separator, "")))
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum ExtraBehavior {
     Allow,
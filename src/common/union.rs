@@ -1,4 +1,5 @@
 use pyo3::prelude::*;
+use pyo3::types::{PyList, PyTuple};
 use pyo3::{PyTraverseError, PyVisit};
 
 use crate::lookup_key::LookupKey;
@@ -18,7 +19,12 @@ impl Discriminator {
             return Ok(Self::Function(raw.clone().unbind()));
         }
 
-        let lookup_key = LookupKey::from_py(py, raw, None)?;
+        let lookup_key = match raw.downcast::<PyTuple>() {
+            // a tuple of keys is the path to a tag nested in the input, e.g. `('meta', 'kind')`,
+            // equivalent to a list of keys
+            Ok(path) => LookupKey::from_py(py, PyList::new(py, [PyList::new(py, path)?])?.as_any(), None)?,
+            Err(_) => LookupKey::from_py(py, raw, None)?,
+        };
         Ok(Self::LookupKey(lookup_key))
     }
 
@@ -5,14 +5,16 @@
 /// and then get a definition from a reference using an integer id (just for performance of not using a HashMap)
 use std::{
     borrow::Borrow,
-    collections::hash_map::Entry,
+    cell::Cell,
     fmt::Debug,
     sync::{
         atomic::{AtomicBool, Ordering},
-        Arc, OnceLock, Weak,
+        Arc, Mutex, MutexGuard, OnceLock, Weak,
     },
 };
 
+use pyo3::sync::MutexExt;
+use pyo3::types::PyDict;
 use pyo3::{prelude::*, PyTraverseError, PyVisit};
 
 use ahash::AHashMap;
@@ -29,11 +31,45 @@ use crate::{build_tools::py_schema_err, py_gc::PyGcTraverse};
 /// They get indexed by a ReferenceId, which are integer identifiers
 /// that are handed out and managed by DefinitionsBuilder when the Schema{Validator,Serializer}
 /// gets build.
-pub struct Definitions<T>(AHashMap<Arc<String>, Definition<T>>);
+pub struct Definitions<T> {
+    definitions: AHashMap<Arc<String>, Definition<T>>,
+    // set when definitions are built lazily, keeps what's needed to build definitions on first use
+    lazy: Option<Arc<LazyDefinitions<T>>>,
+}
 
 struct Definition<T> {
     value: Arc<OnceLock<T>>,
     name: Arc<LazyName>,
+    pending: Arc<Mutex<Option<PendingDefinition<T>>>>,
+}
+
+// Definition can always be cloned, the clone shares its value
+impl<T> Clone for Definition<T> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            name: self.name.clone(),
+            pending: self.pending.clone(),
+        }
+    }
+}
+
+/// Builds the value of a definition from its schema.
+pub type BuildDefinition<T> =
+    fn(&Bound<'_, PyAny>, Option<&Bound<'_, PyDict>>, &mut DefinitionsBuilder<T>) -> PyResult<T>;
+
+/// The schema of a definition which hasn't been built yet, it's built on first use.
+struct PendingDefinition<T> {
+    schema: Py<PyDict>,
+    config: Option<Py<PyDict>>,
+    lazy: Weak<LazyDefinitions<T>>,
+}
+
+/// Builds definitions lazily, with a builder which shares its definitions with the builder of the outer schema
+/// so definitions can refer to each other.
+struct LazyDefinitions<T> {
+    builder: Mutex<DefinitionsBuilder<T>>,
+    build: BuildDefinition<T>,
 }
 
 /// Reference to a definition.
@@ -43,6 +79,7 @@ pub struct DefinitionRef<T> {
     // when recursive definitions are used.
     value: Weak<OnceLock<T>>,
     name: Arc<LazyName>,
+    pending: Weak<Mutex<Option<PendingDefinition<T>>>>,
 }
 
 // DefinitionRef can always be cloned (#[derive(Clone)] would require T: Clone)
@@ -52,6 +89,7 @@ impl<T> Clone for DefinitionRef<T> {
             reference: self.reference.clone(),
             value: self.value.clone(),
             name: self.name.clone(),
+            pending: self.pending.clone(),
         }
     }
 }
@@ -65,15 +103,86 @@ impl<T> DefinitionRef<T> {
         let Some(definition) = self.value.upgrade() else {
             return "...";
         };
+        if definition.get().is_none() {
+            // a definition waiting to be built lazily is built to name it, unless definitions are being built,
+            // errors are raised again when it's used
+            let _ = Python::attach(|py| self.build(py, &definition, false));
+        }
         match definition.get() {
             Some(value) => self.name.get_or_init(|| init(value)),
-            None => "...",
+            None => {
+                // names including this placeholder are formatted again once the definition is built
+                if self
+                    .pending
+                    .upgrade()
+                    .is_some_and(|pending| pending.try_lock().map_or(true, |pending| pending.is_some()))
+                {
+                    UNBUILT_DEFINITION_NAMED.set(true);
+                }
+                "..."
+            }
         }
     }
 
     pub fn read<R>(&self, f: impl FnOnce(Option<&T>) -> R) -> R {
         f(self.value.upgrade().as_ref().and_then(|value| value.get()))
     }
+
+    /// Like `read`, but builds the definition first if it's built lazily and hasn't been used yet.
+    pub fn read_or_build<R>(&self, py: Python<'_>, f: impl FnOnce(&T) -> R) -> PyResult<R> {
+        let Some(value) = self.value.upgrade() else {
+            return py_schema_err!("Definitions error: definition `{}` was dropped", self.reference);
+        };
+        if value.get().is_none() {
+            self.build(py, &value, true)?;
+        }
+        match value.get() {
+            Some(value) => Ok(f(value)),
+            None => py_schema_err!("Definitions error: definition `{}` was never filled", self.reference),
+        }
+    }
+
+    /// Build the definition if it's waiting to be built lazily, if `wait` isn't set it's only built if no other
+    /// definitions are being built.
+    fn build(&self, py: Python<'_>, value: &OnceLock<T>, wait: bool) -> PyResult<()> {
+        let Some(pending) = self.pending.upgrade() else {
+            return Ok(());
+        };
+        let Some(mut pending) = lock_or_try(py, &pending, wait) else {
+            return Ok(());
+        };
+        // another thread may have built the definition while this one waited for the lock
+        if value.get().is_some() {
+            return Ok(());
+        }
+        let Some(PendingDefinition { schema, config, lazy }) = pending.as_ref() else {
+            return Ok(());
+        };
+        let Some(lazy) = lazy.upgrade() else {
+            return Ok(());
+        };
+        let Some(mut builder) = lock_or_try(py, &lazy.builder, wait) else {
+            return Ok(());
+        };
+        let built = (lazy.build)(schema.bind(py), config.as_ref().map(|c| c.bind(py)), &mut builder)?;
+        builder.check_filled()?;
+        // set while the builder is locked, so other builds never see this definition as unfilled
+        let _ = value.set(built);
+        *pending = None;
+        Ok(())
+    }
+}
+
+fn lock_or_try<'a, T>(py: Python<'_>, mutex: &'a Mutex<T>, wait: bool) -> Option<MutexGuard<'a, T>> {
+    if wait {
+        Some(
+            mutex
+                .lock_py_attached(py)
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+        )
+    } else {
+        mutex.try_lock().ok()
+    }
 }
 
 impl<T: Debug> Debug for DefinitionRef<T> {
@@ -91,7 +200,7 @@ impl<T: Debug> Debug for Definitions<T> {
         // minor release of pydantic.
         write![f, "["]?;
         let mut first = true;
-        for def in self.0.values() {
+        for def in self.definitions.values() {
             write![f, "{sep}{def:?}", sep = if first { "" } else { ", " }]?;
             first = false;
         }
@@ -120,24 +229,86 @@ impl<T: PyGcTraverse> PyGcTraverse for DefinitionRef<T> {
 
 impl<T: PyGcTraverse> PyGcTraverse for Definitions<T> {
     fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
-        for value in self.0.values() {
-            if let Some(value) = value.value.get() {
-                value.py_gc_traverse(visit)?;
+        let Some(lazy) = &self.lazy else {
+            return traverse_definitions(&self.definitions, visit);
+        };
+        // the lazy builder has every definition, including those added when building definitions lazily,
+        // if it's in use by a build there's nothing safe to visit
+        match lazy.builder.try_lock() {
+            Ok(builder) => traverse_definitions(&builder.definitions.definitions, visit),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+fn traverse_definitions<T: PyGcTraverse>(
+    definitions: &AHashMap<Arc<String>, Definition<T>>,
+    visit: &PyVisit<'_>,
+) -> Result<(), PyTraverseError> {
+    for definition in definitions.values() {
+        if let Some(value) = definition.value.get() {
+            value.py_gc_traverse(visit)?;
+        } else if let Ok(pending) = definition.pending.try_lock() {
+            if let Some(pending) = pending.as_ref() {
+                visit.call(&pending.schema)?;
+                if let Some(config) = &pending.config {
+                    visit.call(config)?;
+                }
             }
         }
-        Ok(())
     }
+    Ok(())
 }
 
 #[derive(Debug)]
 pub struct DefinitionsBuilder<T> {
     definitions: Definitions<T>,
+    // set if definitions are built lazily, on first use
+    build_lazily: Option<BuildDefinition<T>>,
+    lazy: Weak<LazyDefinitions<T>>,
 }
 
 impl<T: std::fmt::Debug> DefinitionsBuilder<T> {
     pub fn new() -> Self {
         Self {
-            definitions: Definitions(AHashMap::new()),
+            definitions: Definitions {
+                definitions: AHashMap::new(),
+                lazy: None,
+            },
+            build_lazily: None,
+            lazy: Weak::new(),
+        }
+    }
+
+    /// A builder for definitions which are built with `build` on first use, see `add_lazy_definition`.
+    pub fn new_lazy(build: BuildDefinition<T>) -> Self {
+        Self {
+            build_lazily: Some(build),
+            ..Self::new()
+        }
+    }
+
+    pub fn is_lazy(&self) -> bool {
+        self.build_lazily.is_some()
+    }
+
+    fn entry(&mut self, reference: Arc<String>) -> &mut Definition<T> {
+        self.definitions
+            .definitions
+            .entry(reference)
+            .or_insert_with(|| Definition {
+                value: Arc::new(OnceLock::new()),
+                name: Arc::new(LazyName::new()),
+                pending: Arc::new(Mutex::new(None)),
+            })
+    }
+
+    fn definition_ref(reference: Arc<String>, definition: &Definition<T>) -> DefinitionRef<T> {
+        DefinitionRef {
+            reference,
+            value: Arc::downgrade(&definition.value),
+            name: definition.name.clone(),
+            pending: Arc::downgrade(&definition.pending),
         }
     }
 
@@ -147,54 +318,97 @@ impl<T: std::fmt::Debug> DefinitionsBuilder<T> {
         // Neither is better than the other
         // We opted for the easier outward facing API
         let reference = Arc::new(reference.to_string());
-        let value = match self.definitions.0.entry(reference.clone()) {
-            Entry::Occupied(entry) => entry.into_mut(),
-            Entry::Vacant(entry) => entry.insert(Definition {
-                value: Arc::new(OnceLock::new()),
-                name: Arc::new(LazyName::new()),
-            }),
-        };
-        DefinitionRef {
-            reference,
-            value: Arc::downgrade(&value.value),
-            name: value.name.clone(),
-        }
+        let definition = self.entry(reference.clone());
+        Self::definition_ref(reference, definition)
     }
 
     /// Add a definition, returning the ReferenceId that maps to it
     pub fn add_definition(&mut self, reference: String, value: T) -> PyResult<DefinitionRef<T>> {
         let reference = Arc::new(reference);
-        let value = match self.definitions.0.entry(reference.clone()) {
-            Entry::Occupied(entry) => {
-                let definition = entry.into_mut();
-                match definition.value.set(value) {
-                    Ok(()) => definition,
-                    Err(_) => return py_schema_err!("Duplicate ref: `{}`", reference),
+        let definition = self.entry(reference.clone());
+        if definition.value.set(value).is_err() || is_pending(definition) {
+            return py_schema_err!("Duplicate ref: `{}`", reference);
+        }
+        Ok(Self::definition_ref(reference, definition))
+    }
+
+    /// Add the schema of a definition which is built when it's first used, builders created with `new_lazy` only.
+    pub fn add_lazy_definition(
+        &mut self,
+        reference: String,
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<()> {
+        let reference = Arc::new(reference);
+        let lazy = self.lazy.clone();
+        let definition = self.entry(reference.clone());
+        if definition.value.get().is_some() || is_pending(definition) {
+            return py_schema_err!("Duplicate ref: `{}`", reference);
+        }
+        *definition
+            .pending
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(PendingDefinition {
+            schema: schema.clone().unbind(),
+            config: config.map(|config| config.clone().unbind()),
+            lazy,
+        });
+        Ok(())
+    }
+
+    /// Consume this Definitions into a vector of items, indexed by each items ReferenceId
+    pub fn finish(self) -> PyResult<Definitions<T>> {
+        self.check_filled()?;
+        let Some(build) = self.build_lazily else {
+            return Ok(self.definitions);
+        };
+        let definitions = self.definitions.definitions;
+        let lazy = Arc::new_cyclic(|lazy: &Weak<LazyDefinitions<T>>| {
+            for definition in definitions.values() {
+                let mut pending = definition
+                    .pending
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner);
+                if let Some(pending) = pending.as_mut() {
+                    pending.lazy = lazy.clone();
                 }
             }
-            Entry::Vacant(entry) => entry.insert(Definition {
-                value: Arc::new(OnceLock::from(value)),
-                name: Arc::new(LazyName::new()),
-            }),
-        };
-        Ok(DefinitionRef {
-            reference,
-            value: Arc::downgrade(&value.value),
-            name: value.name.clone(),
+            LazyDefinitions {
+                builder: Mutex::new(DefinitionsBuilder {
+                    definitions: Definitions {
+                        definitions: definitions.clone(),
+                        lazy: None,
+                    },
+                    build_lazily: Some(build),
+                    lazy: lazy.clone(),
+                }),
+                build,
+            }
+        });
+        Ok(Definitions {
+            definitions,
+            lazy: Some(lazy),
         })
     }
+}
 
-    /// Consume this Definitions into a vector of items, indexed by each items ReferenceId
-    pub fn finish(self) -> PyResult<Definitions<T>> {
-        for (reference, def) in &self.definitions.0 {
-            if def.value.get().is_none() {
+impl<T> DefinitionsBuilder<T> {
+    /// Check every definition referred to has been filled, or will be built lazily.
+    fn check_filled(&self) -> PyResult<()> {
+        for (reference, def) in &self.definitions.definitions {
+            if def.value.get().is_none() && !is_pending(def) {
                 return py_schema_err!("Definitions error: definition `{}` was never filled", reference);
             }
         }
-        Ok(self.definitions)
+        Ok(())
     }
 }
 
+fn is_pending<T>(definition: &Definition<T>) -> bool {
+    // a definition which is locked is being built, so it's still pending
+    definition.pending.try_lock().map_or(true, |pending| pending.is_some())
+}
+
 /// Because definitions can create recursive structures, we often need to be able to populate
 /// values lazily from these structures in a way that avoids infinite recursion. This structure
 /// avoids infinite recursion by returning a default value when a recursion loop is detected.
@@ -241,29 +455,105 @@ impl<T> RecursionSafeCache<T> {
         self.in_recursion.store(false, Ordering::SeqCst);
         result
     }
-
-    /// Gets the value, if it is set
-    fn get(&self) -> Option<&T> {
-        self.cache.get()
-    }
 }
 
-#[derive(Clone)]
-struct LazyName(RecursionSafeCache<String>);
+struct LazyName {
+    name: CompositeName,
+    in_recursion: AtomicBool,
+}
 
 impl LazyName {
     fn new() -> Self {
-        Self(RecursionSafeCache::new())
+        Self {
+            name: CompositeName::new(),
+            in_recursion: AtomicBool::new(false),
+        }
     }
 
     /// Gets the validator name, returning the default in the case of recursion loops
     fn get_or_init(&self, init: impl FnOnce() -> String) -> &str {
-        self.0.get_or_init(init, "...")
+        if let Some(name) = self.name.get() {
+            return name;
+        }
+        if self
+            .in_recursion
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            return "...";
+        }
+        let name = self.name.get_or_init(init);
+        self.in_recursion.store(false, Ordering::SeqCst);
+        name
     }
 }
 
 impl Debug for LazyName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.0.get().map_or("...", String::as_str).fmt(f)
+        self.name.get().unwrap_or("...").fmt(f)
+    }
+}
+
+thread_local! {
+    // set when a name is formatted with the placeholder of a definition which is still waiting to be built lazily
+    static UNBUILT_DEFINITION_NAMED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// The name of a validator formatted from the names of the validators it contains, e.g. `nullable[int]`.
+///
+/// Those validators may refer to definitions which are built lazily and haven't been built yet, so the name is
+/// only cached once every definition it names has been built, until then it's formatted again each time it's used
+/// and the first name formatted is returned. This way the name is the same whether or not definitions are built
+/// lazily.
+#[derive(Clone)]
+pub(crate) struct CompositeName {
+    name: OnceLock<String>,
+    partial: OnceLock<String>,
+}
+
+impl Debug for CompositeName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.name
+            .get()
+            .or(self.partial.get())
+            .map_or("...", String::as_str)
+            .fmt(f)
+    }
+}
+
+impl CompositeName {
+    pub(crate) fn new() -> Self {
+        Self {
+            name: OnceLock::new(),
+            partial: OnceLock::new(),
+        }
+    }
+
+    /// A name which doesn't name any definitions, so it's known up front.
+    pub(crate) fn known(name: String) -> Self {
+        Self {
+            name: OnceLock::from(name),
+            partial: OnceLock::new(),
+        }
+    }
+
+    pub(crate) fn get_or_init(&self, format: impl FnOnce() -> String) -> &str {
+        if let Some(name) = self.name.get() {
+            return name;
+        }
+        let outer_unbuilt = UNBUILT_DEFINITION_NAMED.replace(false);
+        let name = format();
+        let unbuilt = UNBUILT_DEFINITION_NAMED.get();
+        // names including this one aren't complete either
+        UNBUILT_DEFINITION_NAMED.set(outer_unbuilt || unbuilt);
+        if unbuilt {
+            self.partial.get_or_init(|| name)
+        } else {
+            self.name.get_or_init(|| name)
+        }
+    }
+
+    pub(crate) fn get(&self) -> Option<&str> {
+        self.name.get().map(String::as_str)
     }
 }
@@ -11,24 +11,53 @@ use crate::input::BorrowInput;
 use crate::input::Input;
 
 use super::location::{LocItem, Location};
+use super::position::ErrorPosition;
 use super::types::ErrorType;
 
 pub type ValResult<T> = Result<T, ValError>;
 
 pub trait ToErrorValue {
     fn to_error_value(&self) -> InputValue;
+
+    /// Where the input is in the JSON being validated, if it's part of it.
+    fn json_input(&self) -> Option<JsonInput> {
+        None
+    }
 }
 
 impl<'a, T: BorrowInput<'a>> ToErrorValue for T {
     fn to_error_value(&self) -> InputValue {
         Input::as_error_value(self.borrow_input())
     }
+
+    fn json_input(&self) -> Option<JsonInput> {
+        Input::json_input(self.borrow_input())
+    }
 }
 
 impl ToErrorValue for &'_ dyn ToErrorValue {
     fn to_error_value(&self) -> InputValue {
         (**self).to_error_value()
     }
+
+    fn json_input(&self) -> Option<JsonInput> {
+        (**self).json_input()
+    }
+}
+
+/// Where the input of an error is in the JSON being validated, so its position can be found once validation fails,
+/// see the `json_error_positions` config.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub enum JsonInput {
+    /// the position in the JSON data, once it's been found
+    Position(ErrorPosition),
+    /// the byte offset in the JSON data, e.g. where it's invalid
+    Offset(usize),
+    /// a value of the parsed JSON, by its address, which doesn't change while the parsed JSON is alive
+    Value(usize),
+    /// a key of an object of the parsed JSON, by the address of its text
+    Key(usize),
 }
 
 #[cfg_attr(debug_assertions, derive(Debug))]
@@ -82,6 +111,19 @@ impl ValError {
         Self::LineErrors(vec![ValLineError::new_custom_input(error_type, input_value)])
     }
 
+    /// Set where the input of each line error is in the JSON being validated.
+    pub fn with_json_input(self, json_input: JsonInput) -> Self {
+        match self {
+            Self::LineErrors(mut line_errors) => {
+                for line_error in &mut line_errors {
+                    line_error.json_input = Some(json_input);
+                }
+                Self::LineErrors(line_errors)
+            }
+            other => other,
+        }
+    }
+
     /// helper function to call with_outer on line items if applicable
     pub fn with_outer_location(self, into_loc_item: impl Into<LocItem>) -> Self {
         let loc_item = into_loc_item.into();
@@ -106,6 +148,8 @@ pub struct ValLineError {
     // location is reversed so that adding an "outer" location item is pushing, it's reversed before showing to the user
     pub location: Location,
     pub input_value: InputValue,
+    // recorded when the error is created, as the location may not follow the structure of the JSON
+    pub json_input: Option<JsonInput>,
 }
 
 impl ValLineError {
@@ -114,6 +158,7 @@ impl ValLineError {
             error_type,
             input_value: input.to_error_value(),
             location: Location::default(),
+            json_input: input.json_input(),
         }
     }
 
@@ -122,6 +167,7 @@ impl ValLineError {
             error_type,
             input_value: input.to_error_value(),
             location: Location::new_some(loc.into()),
+            json_input: input.json_input(),
         }
     }
 
@@ -130,6 +176,7 @@ impl ValLineError {
             error_type,
             input_value: input.to_error_value(),
             location,
+            json_input: input.json_input(),
         }
     }
 
@@ -138,6 +185,7 @@ impl ValLineError {
             error_type,
             input_value,
             location: Location::default(),
+            json_input: None,
         }
     }
 
@@ -10,7 +10,7 @@ use serde::{Serialize, Serializer};
 
 /// Used to store individual items of the error location, e.g. a string for key/field names
 /// or a number for array indices.
-#[derive(Clone, Eq, PartialEq, IntoPyObjectRef)]
+#[derive(Clone, Eq, PartialEq, Hash, IntoPyObjectRef)]
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub enum LocItem {
     /// string type key, used to identify items from a dict or anything that implements `__getitem__`
@@ -136,6 +136,47 @@ impl Location {
         Self::List(loc)
     }
 
+    /// Render the location as an RFC 6901 JSON Pointer, e.g. `["foo", 2]` becomes `/foo/2`.
+    pub fn json_pointer(&self) -> String {
+        let mut pointer = String::new();
+        if let Self::List(loc) = self {
+            for item in loc.iter().rev() {
+                pointer.push('/');
+                match item {
+                    LocItem::S(s) => {
+                        for c in s.chars() {
+                            match c {
+                                '~' => pointer.push_str("~0"),
+                                '/' => pointer.push_str("~1"),
+                                c => pointer.push(c),
+                            }
+                        }
+                    }
+                    LocItem::I(i) => pointer.push_str(&i.to_string()),
+                }
+            }
+        }
+        pointer
+    }
+
+    /// The outermost item of the location, e.g. "a" for `a.b`
+    pub fn outer(&self) -> Option<&LocItem> {
+        match self {
+            Self::List(loc) => loc.last(),
+            Self::Empty => None,
+        }
+    }
+
+    /// Remove the outermost item of the location.
+    pub fn pop_outer(&mut self) {
+        if let Self::List(loc) = self {
+            loc.pop();
+            if loc.is_empty() {
+                *self = Self::Empty;
+            }
+        }
+    }
+
     pub fn with_outer(&mut self, loc_item: LocItem) {
         match self {
             Self::List(ref mut loc) => loc.push(loc_item),
@@ -2,15 +2,19 @@ use pyo3::prelude::*;
 
 mod line_error;
 mod location;
+mod position;
 mod types;
 mod validation_exception;
 mod value_exception;
 
-pub use self::line_error::{InputValue, ToErrorValue, ValError, ValLineError, ValResult};
+pub use self::line_error::{InputValue, JsonInput, ToErrorValue, ValError, ValLineError, ValResult};
 pub use self::location::{LocItem, Location};
-pub use self::types::{list_all_errors, ErrorType, ErrorTypeDefaults, Number};
+pub use self::position::add_json_positions;
+pub use self::types::{list_all_errors, register_error_type, ErrorType, ErrorTypeDefaults, Number};
 pub use self::validation_exception::{PyLineError, ValidationError};
-pub use self::value_exception::{PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticUseDefault};
+pub use self::value_exception::{
+    PydanticCustomError, PydanticKnownError, PydanticMultipleErrors, PydanticOmit, PydanticUseDefault,
+};
 
 pub fn py_err_string(py: Python, err: PyErr) -> String {
     let value = err.value(py);
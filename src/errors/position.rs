@@ -0,0 +1,227 @@
+use std::borrow::Cow;
+
+use ahash::AHashMap;
+use jiter::{Jiter, JiterResult, JsonValue, LinePosition, Peek};
+use pyo3::exceptions::PyKeyError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+
+use super::line_error::{JsonInput, ValError};
+use super::location::{LocItem, Location};
+
+/// Position of the token in the source JSON which an error refers to.
+/// `line` and `column` are 1-indexed, `offset` is the 0-indexed byte offset.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct ErrorPosition {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl ErrorPosition {
+    fn from_offset(json_data: &[u8], offset: usize) -> Self {
+        let LinePosition { line, column } = LinePosition::find(json_data, offset);
+        Self { offset, line, column }
+    }
+}
+
+/// Find the position of the input of each error in `json_data`, from where the input was recorded to be when the
+/// error was created, `json_value` is the JSON parsed from `json_data`, if it could be parsed.
+///
+/// The values and keys of the parsed JSON which are the inputs of errors are found in one pass over the data.
+/// Errors whose input wasn't recorded, e.g. errors from the handler of a wrap validator, which validates a Python
+/// value, are found by following their location as far as it matches the JSON.
+pub fn add_json_positions(error: ValError, json_data: &[u8], json_value: Option<&JsonValue<'_>>) -> ValError {
+    let ValError::LineErrors(mut line_errors) = error else {
+        return error;
+    };
+    let mut offsets: AHashMap<JsonInput, Option<usize>> = line_errors
+        .iter()
+        .filter_map(|e| match e.json_input {
+            Some(json_input @ (JsonInput::Value(_) | JsonInput::Key(_))) => Some((json_input, None)),
+            _ => None,
+        })
+        .collect();
+    if let Some(json_value) = json_value.filter(|_| !offsets.is_empty()) {
+        let mut jiter = Jiter::new(json_data).with_allow_inf_nan();
+        // the data after an error, e.g. the end of partial JSON, has no values to find
+        let _ = jiter
+            .peek()
+            .and_then(|peek| find_offsets(&mut jiter, json_data, peek, json_value, &mut offsets));
+    }
+    for line_error in &mut line_errors {
+        let offset = match line_error.json_input {
+            Some(JsonInput::Offset(offset)) => Some(offset),
+            Some(json_input @ (JsonInput::Value(_) | JsonInput::Key(_))) => offsets.get(&json_input).copied().flatten(),
+            Some(JsonInput::Position(_)) => continue,
+            None => Some(find_by_location(json_data, &line_error.location)),
+        };
+        line_error.json_input = offset.map(|offset| JsonInput::Position(ErrorPosition::from_offset(json_data, offset)));
+    }
+    ValError::LineErrors(line_errors)
+}
+
+/// Record the offset of `value`, which starts at `peek`, and of the values and keys inside it, if they're in
+/// `offsets`.
+fn find_offsets(
+    jiter: &mut Jiter<'_>,
+    json_data: &[u8],
+    peek: Peek,
+    value: &JsonValue<'_>,
+    offsets: &mut AHashMap<JsonInput, Option<usize>>,
+) -> JiterResult<()> {
+    record_offset(
+        offsets,
+        JsonInput::Value((value as *const JsonValue<'_>) as usize),
+        jiter.current_index(),
+    );
+    match (peek, value) {
+        (Peek::Array, JsonValue::Array(items)) => {
+            let mut next = jiter.known_array()?;
+            for item in items.iter() {
+                let Some(peek) = next else { break };
+                find_offsets(jiter, json_data, peek, item, offsets)?;
+                next = jiter.array_step()?;
+            }
+        }
+        (Peek::Object, JsonValue::Object(object)) => {
+            // the items of the parsed object are in the order they're in the data, including duplicate keys
+            let mut before_key = jiter.current_index();
+            let mut next = jiter.known_object()?.is_some();
+            for (key, value) in object.as_slice() {
+                if !next {
+                    break;
+                }
+                // only `{` or `,` and whitespace come before the quote of the key
+                if let Some(quote) = json_data[before_key..].iter().position(|&b| b == b'"') {
+                    record_offset(offsets, JsonInput::Key(key.as_ptr() as usize), before_key + quote);
+                }
+                let peek = jiter.peek()?;
+                find_offsets(jiter, json_data, peek, value, offsets)?;
+                before_key = jiter.current_index();
+                next = jiter.next_key()?.is_some();
+            }
+        }
+        _ => jiter.known_skip(peek)?,
+    }
+    Ok(())
+}
+
+/// The offset of the value found by following `location` into `json_data`.
+///
+/// Location items which don't match the structure of the JSON (e.g. the name of a union member, or a missing field)
+/// are skipped, so the offset is that of the deepest value the location could be followed to.
+fn find_by_location(json_data: &[u8], location: &Location) -> usize {
+    let mut offset = json_data
+        .iter()
+        .position(|b| !matches!(b, b' ' | b'\t' | b'\n' | b'\r'))
+        .unwrap_or(0);
+    if let Location::List(loc_items) = location {
+        // location is stored in reverse
+        for loc_item in loc_items.iter().rev() {
+            if let Some(child_offset) = find_child(&json_data[offset..], loc_item) {
+                offset += child_offset;
+            }
+        }
+    }
+    offset
+}
+
+/// Offset of the value (relative to the start of `json_data`) identified by `loc_item` within the
+/// object or array at the start of `json_data`.
+fn find_child(json_data: &[u8], loc_item: &LocItem) -> Option<usize> {
+    let mut jiter = Jiter::new(json_data).with_allow_inf_nan();
+    match jiter.peek().ok()? {
+        Peek::Object => {
+            let key: Cow<'_, str> = match loc_item {
+                LocItem::S(s) => Cow::Borrowed(s),
+                LocItem::I(i) => Cow::Owned(i.to_string()),
+            };
+            // with duplicate keys the last value is kept, so that's the value the error refers to
+            let mut child_offset = None;
+            let mut found = jiter.known_object().ok()?.map(|k| k == key);
+            while let Some(matched) = found {
+                if matched {
+                    jiter.peek().ok()?;
+                    child_offset = Some(jiter.current_index());
+                }
+                if jiter.next_skip().is_err() {
+                    break;
+                }
+                found = match jiter.next_key() {
+                    Ok(found) => found.map(|k| k == key),
+                    Err(_) => break,
+                };
+            }
+            child_offset
+        }
+        Peek::Array => {
+            let LocItem::I(index) = loc_item else {
+                return None;
+            };
+            let index = usize::try_from(*index).ok()?;
+            let mut next = jiter.known_array().ok()?;
+            let mut current = 0;
+            while let Some(peek) = next {
+                if current == index {
+                    return Some(jiter.current_index());
+                }
+                jiter.known_skip(peek).ok()?;
+                next = jiter.array_step().ok()?;
+                current += 1;
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
+fn record_offset(offsets: &mut AHashMap<JsonInput, Option<usize>>, json_input: JsonInput, offset: usize) {
+    if let Some(found) = offsets.get_mut(&json_input) {
+        found.get_or_insert(offset);
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &ErrorPosition {
+    type Target = PyDict;
+    type Output = Bound<'py, PyDict>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("offset", self.offset)?;
+        dict.set_item("line", self.line)?;
+        dict.set_item("column", self.column)?;
+        Ok(dict)
+    }
+}
+
+impl FromPyObject<'_> for ErrorPosition {
+    fn extract_bound(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let dict = obj.downcast::<PyDict>()?;
+        let get = |key: &'static str| -> PyResult<usize> {
+            dict.get_item(key)?.ok_or_else(|| PyKeyError::new_err(key))?.extract()
+        };
+        Ok(Self {
+            offset: get("offset")?,
+            line: get("line")?,
+            column: get("column")?,
+        })
+    }
+}
+
+impl Serialize for ErrorPosition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(3))?;
+        map.serialize_entry("offset", &self.offset)?;
+        map.serialize_entry("line", &self.line)?;
+        map.serialize_entry("column", &self.column)?;
+        map.end()
+    }
+}
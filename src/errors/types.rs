@@ -1,8 +1,9 @@
 use std::any::type_name;
 use std::borrow::Cow;
 use std::fmt;
+use std::sync::{PoisonError, RwLock};
 
-use pyo3::exceptions::{PyKeyError, PyTypeError};
+use pyo3::exceptions::{PyKeyError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::sync::PyOnceLock;
 use pyo3::types::{PyDict, PyList};
@@ -12,6 +13,7 @@ use num_bigint::BigInt;
 use strum::{Display, EnumMessage, IntoEnumIterator};
 use strum_macros::EnumIter;
 
+use crate::build_tools::LazyLock;
 use crate::input::{InputType, Int};
 use crate::tools::{extract_i64, py_err, py_error_type};
 
@@ -42,6 +44,43 @@ pub fn list_all_errors(py: Python<'_>) -> PyResult<Bound<'_, PyList>> {
     PyList::new(py, errors)
 }
 
+/// An error type registered with `register_error_type`, its errors are custom errors with its template.
+#[derive(Debug)]
+struct RegisteredErrorType {
+    message_template: String,
+    required_context: Vec<String>,
+}
+
+/// Error types registered with `register_error_type`, by name.
+static REGISTERED_ERROR_TYPES: LazyLock<RwLock<AHashMap<String, RegisteredErrorType>>> =
+    LazyLock::new(|| RwLock::new(AHashMap::new()));
+
+#[pyfunction]
+#[pyo3(signature = (error_type, message_template, *, required_context=Vec::new()))]
+pub fn register_error_type(
+    py: Python<'_>,
+    error_type: String,
+    message_template: String,
+    required_context: Vec<String>,
+) -> PyResult<()> {
+    if ERROR_TYPE_LOOKUP
+        .get_or_init(py, ErrorType::build_lookup)
+        .contains_key(&error_type)
+    {
+        return py_err!(PyValueError; "Can't register '{}', it's a built-in error type", error_type);
+    }
+    let registered = RegisteredErrorType {
+        message_template,
+        required_context,
+    };
+    // registering a type again replaces it, e.g. when the module registering it is reloaded
+    REGISTERED_ERROR_TYPES
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(error_type, registered);
+    Ok(())
+}
+
 fn field_from_context<'py, T: FromPyObject<'py>>(
     context: Option<&Bound<'py, PyDict>>,
     field_name: &str,
@@ -102,7 +141,7 @@ macro_rules! error_types {
                 let lookup = ERROR_TYPE_LOOKUP.get_or_init(py, Self::build_lookup);
                 let error_type = match lookup.get(value) {
                     Some(error_type) => error_type.clone(),
-                    None => return py_err!(PyKeyError; "Invalid error type: '{}'", value),
+                    None => return Self::new_registered(value, context),
                 };
                 match error_type {
                     $(
@@ -172,9 +211,19 @@ error_types! {
     // recursion error
     RecursionLoop {},
     // ---------------------
+    // input limit and timeout errors
+    InputLimitExceeded {
+        limit: {ctx_type: String, ctx_fn: field_from_context},
+        max: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    ValidationTimeout {
+        timeout_ms: {ctx_type: u64, ctx_fn: field_from_context},
+    },
+    // ---------------------
     // typed dict specific errors
     Missing {},
     FrozenField {},
+    ReadOnlyField {},
     FrozenInstance {},
     ExtraForbidden {},
     InvalidKey {},
@@ -187,6 +236,14 @@ error_types! {
         class_name: {ctx_type: String, ctx_fn: field_from_context},
     },
     ModelAttributesType {},
+    ConsistencyMismatch {
+        check: {ctx_type: String, ctx_fn: field_from_context},
+        expected: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    ConsistencyUncomputable {
+        check: {ctx_type: String, ctx_fn: field_from_context},
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // dataclass errors (we don't talk about ArgsKwargs here for simplicity)
     DataclassType {
@@ -198,6 +255,8 @@ error_types! {
     // ---------------------
     // Default factory not called (happens when there's already an error and the factory takes data)
     DefaultFactoryNotCalled {},
+    // a default which is the same mutable object as one returned before, with `shared_defaults_check='error'`
+    DefaultShared {},
     // ---------------------
     // None errors
     NoneRequired {},
@@ -218,6 +277,9 @@ error_types! {
     MultipleOf {
         multiple_of: {ctx_type: Number, ctx_fn: field_from_context},
     },
+    OutOfRange {
+        interval: {ctx_type: String, ctx_fn: field_from_context},
+    },
     FiniteNumber {},
     // ---------------------
     // generic length errors - used for everything with a length except strings and bytes which need custom messages
@@ -248,9 +310,18 @@ error_types! {
     StringTooLong {
         max_length: {ctx_type: usize, ctx_fn: field_from_context},
     },
+    StringBytesTooShort {
+        min_length: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    StringBytesTooLong {
+        max_length: {ctx_type: usize, ctx_fn: field_from_context},
+    },
     StringPatternMismatch {
         pattern: {ctx_type: String, ctx_fn: field_from_context},
     },
+    StringPatternForbidden {
+        pattern: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // enum errors
     Enum {
@@ -265,6 +336,18 @@ error_types! {
     // ---------------------
     // list errors
     ListType {},
+    ListNotMonotonic {
+        monotonic: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    ListAdjacentDuplicates {
+        max_adjacent_duplicates: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    KeyedListDuplicate {
+        key_field: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    DuplicateItem {
+        first_index: {ctx_type: usize, ctx_fn: field_from_context},
+    },
     // ---------------------
     // tuple errors
     TupleType {},
@@ -282,10 +365,17 @@ error_types! {
     IntParsing {},
     IntParsingSize {},
     IntFromFloat {},
+    IntFromFloatTolerance {
+        tolerance: {ctx_type: Number, ctx_fn: field_from_context},
+    },
+    IntMaxDigits {
+        max_digits: {ctx_type: u64, ctx_fn: field_from_context},
+    },
     // ---------------------
     // float errors
     FloatType {},
     FloatParsing {},
+    FloatInexact {},
     // ---------------------
     // bytes errors
     BytesType {},
@@ -319,6 +409,11 @@ error_types! {
         expected: {ctx_type: String, ctx_fn: field_from_context},
     },
     // ---------------------
+    // constants
+    ConstMismatch {
+        expected: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    // ---------------------
     // missing sentinel
     MissingSentinelError {},
     // date errors
@@ -330,6 +425,10 @@ error_types! {
         error: {ctx_type: Cow<'static, str>, ctx_fn: cow_field_from_context<String, _>},
     },
     DateFromDatetimeInexact {},
+    DateFormat {
+        format: {ctx_type: String, ctx_fn: field_from_context},
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
     DatePast {},
     DateFuture {},
     // ---------------------
@@ -338,6 +437,10 @@ error_types! {
     TimeParsing {
         error: {ctx_type: Cow<'static, str>, ctx_fn: cow_field_from_context<String, _>},
     },
+    TimeFormat {
+        format: {ctx_type: String, ctx_fn: field_from_context},
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // datetime errors
     DatetimeType {},
@@ -350,6 +453,10 @@ error_types! {
     DatetimeFromDateParsing {
         error: {ctx_type: Cow<'static, str>, ctx_fn: cow_field_from_context<String, _>},
     },
+    DatetimeFormat {
+        format: {ctx_type: String, ctx_fn: field_from_context},
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
     DatetimePast {},
     DatetimeFuture {},
     // ---------------------
@@ -360,12 +467,18 @@ error_types! {
         tz_expected: {ctx_type: i32, ctx_fn: field_from_context},
         tz_actual: {ctx_type: i32, ctx_fn: field_from_context},
     },
+    TimezoneName {
+        tz_name: {ctx_type: String, ctx_fn: field_from_context},
+        tz_expected: {ctx_type: i32, ctx_fn: field_from_context},
+        tz_actual: {ctx_type: i32, ctx_fn: field_from_context},
+    },
     // ---------------------
     // timedelta errors
     TimeDeltaType {},
     TimeDeltaParsing {
         error: {ctx_type: Cow<'static, str>, ctx_fn: cow_field_from_context<String, _>},
     },
+    TimeDeltaNegative {},
     // ---------------------
     // frozenset errors
     FrozenSetType {},
@@ -421,6 +534,56 @@ error_types! {
     UuidVersion {
         expected_version: {ctx_type: usize, ctx_fn: field_from_context},
     },
+    UuidTimestampGreaterThanEqual {
+        ge: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    UuidTimestampLessThan {
+        lt: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    // Semantic version errors
+    SemverType {},
+    SemverParsing {
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    SemverGreaterThanEqual {
+        ge: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    SemverLessThan {
+        lt: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    // ULID errors
+    UlidType {},
+    UlidParsing {
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    // Email errors
+    EmailParsing {
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    EmailTooLong {
+        max_length: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    EmailDomain {
+        expected_domains: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    // Path errors
+    PathType {},
+    PathNotFound {},
+    PathNotFile {},
+    PathNotDirectory {},
+    PathSuffix {
+        expected_suffixes: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    // IP address errors
+    IpAddress {
+        version: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    IpNetwork {
+        version: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    IpInterface {
+        version: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // Decimal errors
     DecimalType {},
     DecimalParsing {},
@@ -436,6 +599,10 @@ error_types! {
     // Complex errors
     ComplexType {},
     ComplexStrParsing {},
+    // Plugin errors
+    PluginType {
+        plugin: {ctx_type: String, ctx_fn: field_from_context},
+    },
 }
 
 macro_rules! render {
@@ -487,23 +654,30 @@ impl ErrorType {
             Self::JsonType {..} => "JSON input should be string, bytes or bytearray",
             Self::NeedsPythonObject {..} => "Cannot check `{method_name}` when validating from json, use a JsonOrPython validator instead",
             Self::RecursionLoop {..} => "Recursion error - cyclic reference detected",
+            Self::InputLimitExceeded {..} => "Input exceeds the {limit} limit of {max}",
+            Self::ValidationTimeout {..} => "Validation didn't finish within the timeout of {timeout_ms}ms",
             Self::Missing {..} => "Field required",
             Self::FrozenField {..} => "Field is frozen",
+            Self::ReadOnlyField {..} => "Field is read-only",
             Self::FrozenInstance {..} => "Instance is frozen",
             Self::ExtraForbidden {..} => "Extra inputs are not permitted",
             Self::InvalidKey {..} => "Keys should be strings",
             Self::GetAttributeError {..} => "Error extracting attribute: {error}",
             Self::ModelType {..} => "Input should be a valid dictionary or instance of {class_name}",
             Self::ModelAttributesType {..} => "Input should be a valid dictionary or object to extract fields from",
+            Self::ConsistencyMismatch {..} => "Value should be {expected}, the {check}",
+            Self::ConsistencyUncomputable {..} => "The {check} can't be computed: {error}",
             Self::DataclassType {..} => "Input should be a dictionary or an instance of {class_name}",
             Self::DataclassExactType {..} => "Input should be an instance of {class_name}",
             Self::DefaultFactoryNotCalled {..} => "The default factory uses validated data, but at least one validation error occurred",
+            Self::DefaultShared {..} => "Default value is the same object as a default returned before, it should be a new object each time",
             Self::NoneRequired {..} => "Input should be None",
             Self::GreaterThan {..} => "Input should be greater than {gt}",
             Self::GreaterThanEqual {..} => "Input should be greater than or equal to {ge}",
             Self::LessThan {..} => "Input should be less than {lt}",
             Self::LessThanEqual {..} => "Input should be less than or equal to {le}",
             Self::MultipleOf {..} => "Input should be a multiple of {multiple_of}",
+            Self::OutOfRange {..} => "Input should be in the interval {interval}",
             Self::FiniteNumber {..} => "Input should be a finite number",
             Self::TooShort {..} => "{field_type} should have at least {min_length} item{expected_plural} after validation, not {actual_length}",
             Self::TooLong {..} => "{field_type} should have at most {max_length} item{expected_plural} after validation, not {actual_length}",
@@ -514,11 +688,18 @@ impl ErrorType {
             Self::StringUnicode {..} => "Input should be a valid string, unable to parse raw data as a unicode string",
             Self::StringTooShort {..} => "String should have at least {min_length} character{expected_plural}",
             Self::StringTooLong {..} => "String should have at most {max_length} character{expected_plural}",
+            Self::StringBytesTooShort {..} => "String should have at least {min_length} byte{expected_plural} in UTF-8",
+            Self::StringBytesTooLong {..} => "String should have at most {max_length} byte{expected_plural} in UTF-8",
             Self::StringPatternMismatch {..} => "String should match pattern '{pattern}'",
+            Self::StringPatternForbidden {..} => "String should not match pattern '{pattern}'",
             Self::Enum {..} => "Input should be {expected}",
             Self::DictType {..} => "Input should be a valid dictionary",
             Self::MappingType {..} => "Input should be a valid mapping, error: {error}",
             Self::ListType {..} => "Input should be a valid list",
+            Self::ListNotMonotonic {..} => "List items should be {monotonic}",
+            Self::ListAdjacentDuplicates {..} => "List should have at most {max_adjacent_duplicates} adjacent duplicate{expected_plural}",
+            Self::KeyedListDuplicate {..} => "Duplicate key, each item should have a unique '{key_field}'",
+            Self::DuplicateItem {..} => "Items should be unique, this item is a duplicate of the item at index {first_index}",
             Self::TupleType {..} => "Input should be a valid tuple",
             Self::SetType {..} => "Input should be a valid set",
             Self::SetItemNotHashable {..} => "Set items should be hashable",
@@ -527,9 +708,12 @@ impl ErrorType {
             Self::IntType {..} => "Input should be a valid integer",
             Self::IntParsing {..} => "Input should be a valid integer, unable to parse string as an integer",
             Self::IntFromFloat {..} => "Input should be a valid integer, got a number with a fractional part",
+            Self::IntFromFloatTolerance {..} => "Input should be a valid integer, got a number more than {tolerance} from an integer",
             Self::IntParsingSize {..} => "Unable to parse input string as an integer, exceeded maximum size",
+            Self::IntMaxDigits {..} => "Integer input should have no more than {max_digits} digit{expected_plural}",
             Self::FloatType {..} => "Input should be a valid number",
             Self::FloatParsing {..} => "Input should be a valid number, unable to parse string as a number",
+            Self::FloatInexact {..} => "Input should be a number exactly representable as a float",
             Self::BytesType {..} => "Input should be a valid bytes",
             Self::BytesTooShort {..} => "Data should have at least {min_length} byte{expected_plural}",
             Self::BytesTooLong {..} => "Data should have at most {max_length} byte{expected_plural}",
@@ -538,26 +722,32 @@ impl ErrorType {
             Self::AssertionError {..} => "Assertion failed, {error}",
             Self::CustomError {..} => "",  // custom errors are handled separately
             Self::LiteralError {..} => "Input should be {expected}",
+            Self::ConstMismatch {..} => "Input should be the constant {expected}",
             Self::MissingSentinelError { .. } => "Input should be the 'MISSING' sentinel",
             Self::DateType {..} => "Input should be a valid date",
             Self::DateParsing {..} => "Input should be a valid date in the format YYYY-MM-DD, {error}",
             Self::DateFromDatetimeParsing {..} => "Input should be a valid date or datetime, {error}",
             Self::DateFromDatetimeInexact {..} => "Datetimes provided to dates should have zero time - e.g. be exact dates",
+            Self::DateFormat {..} => "Input should be a valid date in the format '{format}', {error}",
             Self::DatePast {..} => "Date should be in the past",
             Self::DateFuture {..} => "Date should be in the future",
             Self::TimeType {..} => "Input should be a valid time",
             Self::TimeParsing {..} => "Input should be in a valid time format, {error}",
+            Self::TimeFormat {..} => "Input should be a valid time in the format '{format}', {error}",
             Self::DatetimeType {..} => "Input should be a valid datetime",
             Self::DatetimeParsing {..} => "Input should be a valid datetime, {error}",
             Self::DatetimeObjectInvalid {..} => "Invalid datetime object, got {error}",
             Self::DatetimeFromDateParsing {..} => "Input should be a valid datetime or date, {error}",
+            Self::DatetimeFormat {..} => "Input should be a valid datetime in the format '{format}', {error}",
             Self::DatetimePast {..} => "Input should be in the past",
             Self::DatetimeFuture {..} => "Input should be in the future",
             Self::TimezoneNaive {..} => "Input should not have timezone info",
             Self::TimezoneAware {..} => "Input should have timezone info",
             Self::TimezoneOffset {..} => "Timezone offset of {tz_expected} required, got {tz_actual}",
+            Self::TimezoneName {..} => "Timezone offset of {tz_expected} required for '{tz_name}', got {tz_actual}",
             Self::TimeDeltaType {..} => "Input should be a valid timedelta",
             Self::TimeDeltaParsing {..} => "Input should be a valid timedelta, {error}",
+            Self::TimeDeltaNegative {..} => "Input should be a non-negative timedelta",
             Self::FrozenSetType {..} => "Input should be a valid frozenset",
             Self::IsInstanceOf {..} => "Input should be an instance of {class}",
             Self::IsSubclassOf {..} => "Input should be a subclass of {class}",
@@ -579,6 +769,25 @@ impl ErrorType {
             Self::UuidType {..} => "UUID input should be a string, bytes or UUID object",
             Self::UuidParsing {..} => "Input should be a valid UUID, {error}",
             Self::UuidVersion {..} => "UUID version {expected_version} expected",
+            Self::UuidTimestampGreaterThanEqual {..} => "UUID timestamp should be greater than or equal to {ge}",
+            Self::UuidTimestampLessThan {..} => "UUID timestamp should be less than {lt}",
+            Self::SemverType {..} => "Version input should be a string or Version",
+            Self::SemverParsing {..} => "Input should be a valid semantic version, {error}",
+            Self::SemverGreaterThanEqual {..} => "Version should be greater than or equal to {ge}",
+            Self::SemverLessThan {..} => "Version should be less than {lt}",
+            Self::UlidType {..} => "ULID input should be a string, bytes, UUID or Ulid",
+            Self::UlidParsing {..} => "Input should be a valid ULID, {error}",
+            Self::EmailParsing {..} => "Input should be a valid email address, {error}",
+            Self::EmailTooLong {..} => "Email address should have at most {max_length} character{expected_plural}",
+            Self::EmailDomain {..} => "Email address domain should be {expected_domains}",
+            Self::PathType {..} => "Input should be a valid path",
+            Self::PathNotFound {..} => "Path does not exist",
+            Self::PathNotFile {..} => "Path does not point to a file",
+            Self::PathNotDirectory {..} => "Path does not point to a directory",
+            Self::PathSuffix {..} => "Path suffix should be {expected_suffixes}",
+            Self::IpAddress {..} => "Input is not a valid {version} address",
+            Self::IpNetwork {..} => "Input is not a valid {version} network",
+            Self::IpInterface {..} => "Input is not a valid {version} interface",
             Self::DecimalType {..} => "Decimal input should be an integer, float, string or Decimal object",
             Self::DecimalParsing {..} => "Input should be a valid decimal",
             Self::DecimalMaxDigits {..} => "Decimal input should have no more than {max_digits} digit{expected_plural} in total",
@@ -586,6 +795,7 @@ impl ErrorType {
             Self::DecimalWholeDigits {..} => "Decimal input should have no more than {whole_digits} digit{expected_plural} before the decimal point",
             Self::ComplexType {..} => "Input should be a valid python complex object, a number, or a valid complex string following the rules at https://docs.python.org/3/library/functions.html#complex",
             Self::ComplexStrParsing {..} => "Input should be a valid complex string following the rules at https://docs.python.org/3/library/functions.html#complex",
+            Self::PluginType {..} => "Input should be a valid {plugin}",
         }
     }
 
@@ -611,6 +821,28 @@ impl ErrorType {
     pub fn valid_type(py: Python, error_type: &str) -> bool {
         let lookup = ERROR_TYPE_LOOKUP.get_or_init(py, Self::build_lookup);
         lookup.contains_key(error_type)
+            || REGISTERED_ERROR_TYPES
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .contains_key(error_type)
+    }
+
+    /// An error of a type registered with `register_error_type`, `context` must have its required keys.
+    fn new_registered(error_type: &str, context: Option<Bound<'_, PyDict>>) -> PyResult<Self> {
+        let registered_types = REGISTERED_ERROR_TYPES.read().unwrap_or_else(PoisonError::into_inner);
+        let Some(registered) = registered_types.get(error_type) else {
+            return py_err!(PyKeyError; "Invalid error type: '{}'", error_type);
+        };
+        for key in &registered.required_context {
+            if !context.as_ref().map_or(Ok(false), |context| context.contains(key))? {
+                return py_err!(PyTypeError; "{}: '{}' required in context", error_type, key);
+            }
+        }
+        Ok(Self::CustomError {
+            error_type: error_type.to_string(),
+            message_template: registered.message_template.clone(),
+            context: context.map(Bound::unbind),
+        })
     }
 
     fn build_lookup() -> AHashMap<String, Self> {
@@ -631,18 +863,36 @@ impl ErrorType {
     }
 
     pub fn render_message(&self, py: Python, input_type: InputType) -> PyResult<String> {
-        let tmpl = match input_type {
-            InputType::Python => self.message_template_python(),
+        let tmpl = match (self, input_type) {
+            (Self::CustomError { message_template, .. }, _) => message_template.as_str(),
+            (_, InputType::Python) => self.message_template_python(),
             _ => self.message_template_json(),
         };
+        self.render_template(py, tmpl)
+    }
+
+    /// Format `tmpl`, the default message template or one replacing it, with the error's context, including the
+    /// placeholders computed from it like `{expected_plural}`.
+    pub fn render_template(&self, py: Python, tmpl: &str) -> PyResult<String> {
         match self {
             Self::NoSuchAttribute { attribute, .. } => render!(tmpl, attribute),
+            Self::PluginType { plugin, .. } => render!(tmpl, plugin),
             Self::JsonInvalid { error, .. }
             | Self::GetAttributeError { error, .. }
             | Self::IterationError { error, .. }
             | Self::DatetimeObjectInvalid { error, .. }
             | Self::UrlParsing { error, .. }
-            | Self::UuidParsing { error, .. } => render!(tmpl, error),
+            | Self::UuidParsing { error, .. }
+            | Self::SemverParsing { error, .. }
+            | Self::UlidParsing { error, .. }
+            | Self::EmailParsing { error, .. } => render!(tmpl, error),
+            Self::UuidTimestampGreaterThanEqual { ge, .. } | Self::SemverGreaterThanEqual { ge, .. } => {
+                render!(tmpl, ge)
+            }
+            Self::UuidTimestampLessThan { lt, .. } | Self::SemverLessThan { lt, .. } => render!(tmpl, lt),
+            Self::IpAddress { version, .. } | Self::IpNetwork { version, .. } | Self::IpInterface { version, .. } => {
+                render!(tmpl, version)
+            }
             Self::MappingType { error, .. }
             | Self::DateParsing { error, .. }
             | Self::DateFromDatetimeParsing { error, .. }
@@ -651,15 +901,24 @@ impl ErrorType {
             | Self::DatetimeFromDateParsing { error, .. }
             | Self::TimeDeltaParsing { error, .. }
             | Self::UrlSyntaxViolation { error, .. } => render!(tmpl, error),
+            Self::DateFormat { format, error, .. }
+            | Self::TimeFormat { format, error, .. }
+            | Self::DatetimeFormat { format, error, .. } => render!(tmpl, format, error),
             Self::NeedsPythonObject { method_name, .. } => render!(tmpl, method_name),
             Self::ModelType { class_name, .. }
             | Self::DataclassType { class_name, .. }
             | Self::DataclassExactType { class_name, .. } => render!(tmpl, class_name),
+            Self::ConsistencyMismatch { check, expected, .. } => render!(tmpl, expected, check),
+            Self::ConsistencyUncomputable { check, error, .. } => render!(tmpl, check, error),
             Self::GreaterThan { gt, .. } => to_string_render!(tmpl, gt),
             Self::GreaterThanEqual { ge, .. } => to_string_render!(tmpl, ge),
             Self::LessThan { lt, .. } => to_string_render!(tmpl, lt),
             Self::LessThanEqual { le, .. } => to_string_render!(tmpl, le),
             Self::MultipleOf { multiple_of, .. } => to_string_render!(tmpl, multiple_of),
+            Self::IntFromFloatTolerance { tolerance, .. } => to_string_render!(tmpl, tolerance),
+            Self::OutOfRange { interval, .. } => render!(tmpl, interval),
+            Self::InputLimitExceeded { limit, max, .. } => to_string_render!(tmpl, limit, max),
+            Self::ValidationTimeout { timeout_ms, .. } => to_string_render!(tmpl, timeout_ms),
             Self::TooShort {
                 field_type,
                 min_length,
@@ -679,17 +938,23 @@ impl ErrorType {
                 let actual_length = actual_length.map_or(Cow::Borrowed("more"), |v| Cow::Owned(v.to_string()));
                 to_string_render!(tmpl, field_type, max_length, actual_length, expected_plural,)
             }
-            Self::StringTooShort { min_length, .. } | Self::BytesTooShort { min_length, .. } => {
+            Self::StringTooShort { min_length, .. }
+            | Self::StringBytesTooShort { min_length, .. }
+            | Self::BytesTooShort { min_length, .. } => {
                 let expected_plural = plural_s(*min_length);
                 to_string_render!(tmpl, min_length, expected_plural)
             }
             Self::StringTooLong { max_length, .. }
+            | Self::StringBytesTooLong { max_length, .. }
             | Self::BytesTooLong { max_length, .. }
-            | Self::UrlTooLong { max_length, .. } => {
+            | Self::UrlTooLong { max_length, .. }
+            | Self::EmailTooLong { max_length, .. } => {
                 let expected_plural = plural_s(*max_length);
                 to_string_render!(tmpl, max_length, expected_plural)
             }
-            Self::StringPatternMismatch { pattern, .. } => render!(tmpl, pattern),
+            Self::StringPatternMismatch { pattern, .. } | Self::StringPatternForbidden { pattern, .. } => {
+                render!(tmpl, pattern)
+            }
             Self::Enum { expected, .. } => to_string_render!(tmpl, expected),
             Self::BytesInvalidEncoding {
                 encoding,
@@ -708,15 +973,19 @@ impl ErrorType {
                     .map_or(Cow::Borrowed("None"), |v| Cow::Owned(v.bind(py).to_string()));
                 render!(tmpl, error)
             }
-            Self::CustomError {
-                message_template,
-                context,
-                ..
-            } => PydanticCustomError::format_message(message_template, context.as_ref().map(|c| c.bind(py))),
-            Self::LiteralError { expected, .. } => render!(tmpl, expected),
+            Self::CustomError { context, .. } => {
+                PydanticCustomError::format_message(tmpl, context.as_ref().map(|c| c.bind(py)))
+            }
+            Self::LiteralError { expected, .. } | Self::ConstMismatch { expected, .. } => render!(tmpl, expected),
             Self::TimezoneOffset {
                 tz_expected, tz_actual, ..
             } => to_string_render!(tmpl, tz_expected, tz_actual),
+            Self::TimezoneName {
+                tz_name,
+                tz_expected,
+                tz_actual,
+                ..
+            } => to_string_render!(tmpl, tz_name, tz_expected, tz_actual),
             Self::IsInstanceOf { class, .. } | Self::IsSubclassOf { class, .. } => render!(tmpl, class),
             Self::UnionTagInvalid {
                 discriminator,
@@ -726,8 +995,20 @@ impl ErrorType {
             } => render!(tmpl, discriminator, tag, expected_tags),
             Self::UnionTagNotFound { discriminator, .. } => render!(tmpl, discriminator),
             Self::UrlScheme { expected_schemes, .. } => render!(tmpl, expected_schemes),
+            Self::EmailDomain { expected_domains, .. } => render!(tmpl, expected_domains),
+            Self::PathSuffix { expected_suffixes, .. } => render!(tmpl, expected_suffixes),
+            Self::KeyedListDuplicate { key_field, .. } => render!(tmpl, key_field),
+            Self::DuplicateItem { first_index, .. } => to_string_render!(tmpl, first_index),
+            Self::ListNotMonotonic { monotonic, .. } => render!(tmpl, monotonic),
+            Self::ListAdjacentDuplicates {
+                max_adjacent_duplicates,
+                ..
+            } => {
+                let expected_plural = plural_s(*max_adjacent_duplicates);
+                to_string_render!(tmpl, max_adjacent_duplicates, expected_plural)
+            }
             Self::UuidVersion { expected_version, .. } => to_string_render!(tmpl, expected_version),
-            Self::DecimalMaxDigits { max_digits, .. } => {
+            Self::IntMaxDigits { max_digits, .. } | Self::DecimalMaxDigits { max_digits, .. } => {
                 let expected_plural = plural_s(*max_digits);
                 to_string_render!(tmpl, max_digits, expected_plural)
             }
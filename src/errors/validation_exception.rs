@@ -20,10 +20,11 @@ use crate::errors::LocItem;
 use crate::get_pydantic_version;
 use crate::input::InputType;
 use crate::serializers::{Extra, SerMode, SerializationConfig, SerializationState, WarningsMode};
-use crate::tools::{safe_repr, write_truncated_to_limited_bytes, SchemaDict};
+use crate::tools::{write_truncated_safe_repr, SchemaDict};
 
-use super::line_error::ValLineError;
+use super::line_error::{JsonInput, ValLineError};
 use super::location::Location;
+use super::position::ErrorPosition;
 use super::types::ErrorType;
 use super::value_exception::PydanticCustomError;
 use super::{InputValue, ValError};
@@ -36,6 +37,7 @@ pub struct ValidationError {
     title: Py<PyAny>,
     input_type: InputType,
     hide_input: bool,
+    truncated: bool,
 }
 
 impl ValidationError {
@@ -45,6 +47,7 @@ impl ValidationError {
             title,
             input_type,
             hide_input,
+            truncated: false,
         }
     }
 
@@ -102,7 +105,12 @@ impl ValidationError {
             let count = self.line_errors.len();
             let plural = if count == 1 { "" } else { "s" };
             let title: &str = self.title.extract(py).unwrap();
-            format!("{count} validation error{plural} for {title}\n{line_errors}")
+            let truncated = if self.truncated {
+                " (truncated, max_errors reached)"
+            } else {
+                ""
+            };
+            format!("{count} validation error{plural} for {title}{truncated}\n{line_errors}")
         }
     }
 
@@ -114,6 +122,61 @@ impl ValidationError {
         py_schema_error_type!("Uncaught `PydanticUseDefault` exception: the error was raised in a field validator and no default value is available for that field.")
     }
 
+    /// Keep at most `max_errors` errors, the error is marked as truncated if any were left out.
+    pub fn truncate(&mut self, max_errors: usize) {
+        if self.line_errors.len() > max_errors {
+            self.line_errors.truncate(max_errors);
+            self.truncated = true;
+        }
+    }
+
+    /// Translate the location of each error with `mapping`, either a dict of location items to replace, or a
+    /// callable taking and returning a whole location tuple, see the `error_loc_mapping` config setting.
+    pub fn map_locations(&mut self, mapping: &Bound<'_, PyAny>) -> PyResult<()> {
+        let py = mapping.py();
+        for line_error in &mut self.line_errors {
+            if let Ok(dict) = mapping.downcast::<PyDict>() {
+                if let Location::List(loc_items) = &mut line_error.location {
+                    for loc_item in loc_items.iter_mut() {
+                        if let Some(new_item) = dict.get_item(&*loc_item)? {
+                            *loc_item = LocItem::from(&new_item);
+                        }
+                    }
+                }
+            } else {
+                let location = mapping.call1(((&line_error.location).into_pyobject(py)?,))?;
+                line_error.location = Location::try_from(Some(&location))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Replace the message template of errors with `templates`, either a dict of error types to templates, or
+    /// a callable taking the error type, default template and context and returning a template or `None` to
+    /// keep the default, see the `error_message_templates` config setting.
+    ///
+    /// The error type and context are unchanged, new templates are formatted with the error's context.
+    pub fn translate_messages(&mut self, templates: &Bound<'_, PyAny>) -> PyResult<()> {
+        let py = templates.py();
+        for line_error in &mut self.line_errors {
+            let error_type = line_error.error_type.type_string();
+            line_error.message_template = if let Ok(dict) = templates.downcast::<PyDict>() {
+                dict.get_item(&error_type)?
+                    .map(|template| template.extract())
+                    .transpose()?
+            } else {
+                let default_template = match (&line_error.error_type, self.input_type) {
+                    (ErrorType::CustomError { message_template, .. }, _) => message_template.as_str(),
+                    (other, InputType::Python) => other.message_template_python(),
+                    (other, _) => other.message_template_json(),
+                };
+                let context = line_error.error_type.py_dict(py)?;
+                templates.call1((&error_type, default_template, context))?.extract()?
+            };
+        }
+        Ok(())
+    }
+
     fn maybe_add_cause(self_: PyRef<'_, Self>, py: Python) -> Option<PyErr> {
         let mut user_py_errs = vec![];
         for line_error in &self_.line_errors {
@@ -254,26 +317,36 @@ impl ValidationError {
 #[pymethods]
 impl ValidationError {
     #[new]
-    #[pyo3(signature = (title, line_errors, input_type="python", hide_input=false))]
-    fn py_new(title: Py<PyAny>, line_errors: Vec<PyLineError>, input_type: &str, hide_input: bool) -> PyResult<Self> {
+    #[pyo3(signature = (title, line_errors, input_type="python", hide_input=false, truncated=false))]
+    fn py_new(
+        title: Py<PyAny>,
+        line_errors: Vec<PyLineError>,
+        input_type: &str,
+        hide_input: bool,
+        truncated: bool,
+    ) -> PyResult<Self> {
         Ok(Self {
             line_errors,
             title,
             input_type: InputType::try_from(input_type)?,
             hide_input,
+            truncated,
         })
     }
 
     #[classmethod]
-    #[pyo3(signature = (title, line_errors, input_type="python", hide_input=false))]
+    #[pyo3(signature = (title, line_errors, input_type="python", hide_input=false, truncated=false, cause=None))]
     fn from_exception_data<'py>(
         cls: &Bound<'py, PyType>,
         title: Py<PyAny>,
         line_errors: Bound<'_, PyList>,
         input_type: &str,
         hide_input: bool,
+        truncated: bool,
+        cause: Option<&Bound<'_, PyAny>>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        cls.call1((
+        let py = cls.py();
+        let err = cls.call1((
             title,
             line_errors
                 .iter()
@@ -281,7 +354,12 @@ impl ValidationError {
                 .collect::<PyResult<Vec<PyLineError>>>()?,
             InputType::try_from(input_type)?,
             hide_input,
-        ))
+            truncated,
+        ))?;
+        if let Some(cause) = cause {
+            err.setattr(intern!(py, "__cause__"), cause)?;
+        }
+        Ok(err)
     }
 
     #[getter]
@@ -293,13 +371,19 @@ impl ValidationError {
         self.line_errors.len()
     }
 
-    #[pyo3(signature = (*, include_url = true, include_context = true, include_input = true))]
+    #[getter]
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    #[pyo3(signature = (*, include_url = true, include_context = true, include_input = true, loc_as_pointer = false))]
     pub fn errors(
         &self,
         py: Python,
         include_url: bool,
         include_context: bool,
         include_input: bool,
+        loc_as_pointer: bool,
     ) -> PyResult<Py<PyList>> {
         let url_prefix = get_url_prefix(py, include_url);
         let mut iteration_error = None;
@@ -312,14 +396,21 @@ impl ValidationError {
                 if iteration_error.is_some() {
                     return py.None();
                 }
-                e.as_dict(py, url_prefix, include_context, self.input_type, include_input)
-                    .map_or_else(
-                        |err| {
-                            iteration_error = Some(err);
-                            py.None()
-                        },
-                        Into::into,
-                    )
+                e.as_dict(
+                    py,
+                    url_prefix,
+                    include_context,
+                    self.input_type,
+                    include_input,
+                    loc_as_pointer,
+                )
+                .map_or_else(
+                    |err| {
+                        iteration_error = Some(err);
+                        py.None()
+                    },
+                    Into::into,
+                )
             }),
         )?;
         if let Some(err) = iteration_error {
@@ -329,7 +420,7 @@ impl ValidationError {
         }
     }
 
-    #[pyo3(signature = (*, indent = None, include_url = true, include_context = true, include_input = true))]
+    #[pyo3(signature = (*, indent = None, include_url = true, include_context = true, include_input = true, loc_as_pointer = false))]
     pub fn json<'py>(
         &self,
         py: Python<'py>,
@@ -337,6 +428,7 @@ impl ValidationError {
         include_url: bool,
         include_context: bool,
         include_input: bool,
+        loc_as_pointer: bool,
     ) -> PyResult<Bound<'py, PyString>> {
         let config = SerializationConfig::from_args("iso8601", "iso8601", "utf8", "constants")?;
         let extra = Extra::new(
@@ -360,6 +452,7 @@ impl ValidationError {
             url_prefix: get_url_prefix(py, include_url),
             include_context,
             include_input,
+            loc_as_pointer,
             state: &mut state,
             input_type: &self.input_type,
         };
@@ -395,11 +488,18 @@ impl ValidationError {
         let py = slf.py();
         let callable = slf.getattr("from_exception_data")?;
         let borrow = slf.try_borrow()?;
+        let line_errors = borrow
+            .line_errors
+            .iter()
+            .map(|line_error| line_error.as_full_dict(py, borrow.input_type))
+            .collect::<PyResult<Vec<_>>>()?;
         let args = (
             &borrow.title,
-            borrow.errors(py, include_url_env(py), true, true)?,
+            line_errors,
             borrow.input_type,
             borrow.hide_input,
+            borrow.truncated,
+            slf.getattr(intern!(py, "__cause__"))?,
         )
             .into_pyobject(py)?;
         Ok((callable, args))
@@ -428,6 +528,11 @@ pub struct PyLineError {
     error_type: ErrorType,
     location: Location,
     input_value: Py<PyAny>,
+    position: Option<ErrorPosition>,
+    // kept so the position can still be found if the error is raised again by a wrap validator
+    json_input: Option<JsonInput>,
+    // replaces the template of `error_type` when rendering the message, see `error_message_templates`
+    message_template: Option<String>,
 }
 
 impl From<PyLineError> for ValLineError {
@@ -437,6 +542,7 @@ impl From<PyLineError> for ValLineError {
             error_type: other.error_type,
             location: other.location,
             input_value: InputValue::Python(other.input_value),
+            json_input: other.json_input,
         }
     }
 }
@@ -470,10 +576,16 @@ impl TryFrom<&Bound<'_, PyAny>> for PyLineError {
             None => py.None(),
         };
 
+        let position = dict.get_as(intern!(py, "position"))?;
+        let message_template = dict.get_as(intern!(py, "msg_template"))?;
+
         Ok(Self {
             error_type,
             location,
             input_value,
+            position,
+            json_input: position.map(JsonInput::Position),
+            message_template,
         })
     }
 }
@@ -484,9 +596,22 @@ impl PyLineError {
             error_type: error.error_type,
             location: error.location,
             input_value: error.input_value.into_pyobject(py)?.unbind(),
+            position: match error.json_input {
+                Some(JsonInput::Position(position)) => Some(position),
+                _ => None,
+            },
+            json_input: error.json_input,
+            message_template: None,
         })
     }
 
+    fn render_message(&self, py: Python, input_type: InputType) -> PyResult<String> {
+        match self.message_template {
+            Some(ref message_template) => self.error_type.render_template(py, message_template),
+            None => self.error_type.render_message(py, input_type),
+        }
+    }
+
     fn get_error_url(&self, url_prefix: &str) -> String {
         format!("{url_prefix}{}", self.error_type.type_string())
     }
@@ -498,11 +623,16 @@ impl PyLineError {
         include_context: bool,
         input_type: InputType,
         include_input: bool,
+        loc_as_pointer: bool,
     ) -> PyResult<Bound<'py, PyDict>> {
         let dict = PyDict::new(py);
         dict.set_item("type", self.error_type.type_string())?;
-        dict.set_item("loc", &self.location)?;
-        dict.set_item("msg", self.error_type.render_message(py, input_type)?)?;
+        if loc_as_pointer {
+            dict.set_item("loc", self.location.json_pointer())?;
+        } else {
+            dict.set_item("loc", &self.location)?;
+        }
+        dict.set_item("msg", self.render_message(py, input_type)?)?;
         if include_input {
             dict.set_item("input", &self.input_value)?;
         }
@@ -521,6 +651,32 @@ impl PyLineError {
                 }
             }
         }
+        if let Some(position) = &self.position {
+            dict.set_item("position", position)?;
+        }
+        Ok(dict)
+    }
+
+    /// Everything about the error, read back by `PyLineError::try_from`, so `ValidationError` can be pickled.
+    ///
+    /// Unlike `as_dict`, custom errors keep their message template, as a `PydanticCustomError` in `type`, and
+    /// templates replaced by `error_message_templates` are included as `msg_template`.
+    fn as_full_dict<'py>(&self, py: Python<'py>, input_type: InputType) -> PyResult<Bound<'py, PyDict>> {
+        let dict = self.as_dict(py, None, true, input_type, true, false)?;
+        if let ErrorType::CustomError {
+            error_type,
+            message_template,
+            context,
+        } = &self.error_type
+        {
+            let custom_error = py
+                .get_type::<PydanticCustomError>()
+                .call1((error_type, message_template, context))?;
+            dict.set_item(intern!(py, "type"), custom_error)?;
+        }
+        if let Some(message_template) = &self.message_template {
+            dict.set_item(intern!(py, "msg_template"), message_template)?;
+        }
         Ok(dict)
     }
 
@@ -534,7 +690,7 @@ impl PyLineError {
         let mut output = String::with_capacity(200);
         write!(output, "{}", self.location)?;
 
-        let message = match self.error_type.render_message(py, input_type) {
+        let message = match self.render_message(py, input_type) {
             Ok(message) => message,
             Err(err) => format!("(error rendering message: {err})"),
         };
@@ -543,9 +699,8 @@ impl PyLineError {
         // special case: don't show input for DefaultFactoryNotCalled errors - there is no valid input
         if !hide_input && !matches!(self.error_type, ErrorType::DefaultFactoryNotCalled { .. }) {
             let input_value = self.input_value.bind(py);
-            let input_str = safe_repr(input_value);
             write!(output, ", input_value=")?;
-            write_truncated_to_limited_bytes(&mut output, &input_str.to_string(), 50)?;
+            write_truncated_safe_repr(&mut output, input_value, 50)?;
 
             if let Ok(type_) = input_value.get_type().qualname() {
                 write!(output, ", input_type={type_}")?;
@@ -589,6 +744,7 @@ struct ValidationErrorSerializer<'slf, 'a, 'py> {
     url_prefix: Option<&'py str>,
     include_context: bool,
     include_input: bool,
+    loc_as_pointer: bool,
     state: &'slf mut SerializationState<'a, 'py>,
     input_type: &'py InputType,
 }
@@ -606,6 +762,7 @@ impl ValidationErrorSerializer<'_, '_, '_> {
                 url_prefix: self.url_prefix,
                 include_context: self.include_context,
                 include_input: self.include_input,
+                loc_as_pointer: self.loc_as_pointer,
                 state: RefCell::new(self.state),
                 input_type: self.input_type,
             };
@@ -621,6 +778,7 @@ struct PyLineErrorSerializer<'slf, 'a, 'py> {
     url_prefix: Option<&'py str>,
     include_context: bool,
     include_input: bool,
+    loc_as_pointer: bool,
     state: RefCell<&'slf mut SerializationState<'a, 'py>>,
     input_type: &'py InputType,
 }
@@ -631,20 +789,28 @@ impl Serialize for PyLineErrorSerializer<'_, '_, '_> {
         S: Serializer,
     {
         let py = self.py;
-        let size = 3 + [self.url_prefix.is_some(), self.include_context, self.include_input]
-            .into_iter()
-            .filter(|b| *b)
-            .count();
+        let size = 3 + [
+            self.url_prefix.is_some(),
+            self.include_context,
+            self.include_input,
+            self.line_error.position.is_some(),
+        ]
+        .into_iter()
+        .filter(|b| *b)
+        .count();
         let mut map = serializer.serialize_map(Some(size))?;
         let mut state = self.state.borrow_mut();
 
         map.serialize_entry("type", &self.line_error.error_type.type_string())?;
 
-        map.serialize_entry("loc", &self.line_error.location)?;
+        if self.loc_as_pointer {
+            map.serialize_entry("loc", &self.line_error.location.json_pointer())?;
+        } else {
+            map.serialize_entry("loc", &self.line_error.location)?;
+        }
 
         let msg = self
             .line_error
-            .error_type
             .render_message(py, *self.input_type)
             .map_err(py_err_json::<S>)?;
         map.serialize_entry("msg", &msg)?;
@@ -661,6 +827,9 @@ impl Serialize for PyLineErrorSerializer<'_, '_, '_> {
         if let Some(url_prefix) = self.url_prefix {
             map.serialize_entry("url", &self.line_error.get_error_url(url_prefix))?;
         }
+        if let Some(position) = &self.line_error.position {
+            map.serialize_entry("position", position)?;
+        }
         map.end()
     }
 }
@@ -1,12 +1,12 @@
-use pyo3::exceptions::{PyException, PyValueError};
+use pyo3::exceptions::{PyBaseException, PyException, PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyDict, PyInt, PyList, PyString, PyTuple};
 
 use crate::input::InputType;
 use crate::tools::extract_i64;
 
 use super::line_error::ToErrorValue;
-use super::{ErrorType, ValError};
+use super::{ErrorType, Location, ValError};
 
 #[pyclass(extends=PyException, module="pydantic_core._pydantic_core")]
 #[derive(Debug, Clone)]
@@ -152,12 +152,15 @@ impl PydanticKnownError {
 
     #[getter(r#type)]
     pub fn error_type(&self) -> String {
-        self.error_type.to_string()
+        self.error_type.type_string()
     }
 
     #[getter]
-    pub fn message_template(&self) -> &'static str {
-        self.error_type.message_template_python()
+    pub fn message_template(&self) -> &str {
+        match &self.error_type {
+            ErrorType::CustomError { message_template, .. } => message_template,
+            error_type => error_type.message_template_python(),
+        }
     }
 
     #[getter]
@@ -187,3 +190,88 @@ impl PydanticKnownError {
         ValError::new(self.error_type, input)
     }
 }
+
+#[pyclass(extends=PyValueError, module="pydantic_core._pydantic_core")]
+#[derive(Debug)]
+pub struct PydanticMultipleErrors {
+    // `(loc, error)` pairs, `loc` is always a tuple
+    errors: Vec<(Py<PyTuple>, Py<PyBaseException>)>,
+}
+
+#[pymethods]
+impl PydanticMultipleErrors {
+    #[new]
+    pub fn py_new(errors: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let py = errors.py();
+        let errors = errors
+            .try_iter()?
+            .map(|item| {
+                let item = item?;
+                let (loc, error): (Bound<'_, PyAny>, Bound<'_, PyAny>) = item.extract().map_err(|_| {
+                    PyTypeError::new_err("PydanticMultipleErrors expects an iterable of `(loc, error)` tuples")
+                })?;
+                let loc = if loc.is_instance_of::<PyString>() || loc.is_instance_of::<PyInt>() {
+                    PyTuple::new(py, [loc])?
+                } else if let Ok(tuple) = loc.downcast::<PyTuple>() {
+                    tuple.clone()
+                } else if let Ok(list) = loc.downcast::<PyList>() {
+                    list.to_tuple()
+                } else {
+                    return Err(PyTypeError::new_err(
+                        "PydanticMultipleErrors `loc` must be a string, int, or tuple of strings and ints",
+                    ));
+                };
+                let error = error.downcast_into::<PyBaseException>().map_err(|_| {
+                    PyTypeError::new_err("PydanticMultipleErrors `error` must be an exception instance")
+                })?;
+                Ok((loc.unbind(), error.unbind()))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        if errors.is_empty() {
+            return Err(PyValueError::new_err(
+                "PydanticMultipleErrors requires at least one error",
+            ));
+        }
+        Ok(Self { errors })
+    }
+
+    #[getter]
+    pub fn errors<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        PyList::new(
+            py,
+            self.errors.iter().map(|(loc, error)| (loc.bind(py), error.bind(py))),
+        )
+    }
+
+    fn __str__(&self, py: Python) -> PyResult<String> {
+        let errors = self
+            .errors
+            .iter()
+            .map(|(loc, error)| {
+                let location = Location::try_from(Some(loc.bind(py).as_any()))?;
+                Ok(format!("{}  {}", location, error.bind(py).str()?))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        let count = self.errors.len();
+        let plural = if count == 1 { "" } else { "s" };
+        Ok(format!("{count} error{plural}\n{}", errors.join("\n")))
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        Ok(format!("PydanticMultipleErrors({})", self.errors(py)?.repr()?))
+    }
+}
+
+impl PydanticMultipleErrors {
+    pub fn errors_iter<'a, 'py>(
+        &'a self,
+        py: Python<'py>,
+    ) -> impl Iterator<Item = (&'a Bound<'py, PyTuple>, &'a Bound<'py, PyBaseException>)> + 'a
+    where
+        'py: 'a,
+    {
+        self.errors
+            .iter()
+            .map(move |(loc, error)| (loc.bind(py), error.bind(py)))
+    }
+}
@@ -5,10 +5,10 @@ use pyo3::exceptions::PyValueError;
 use pyo3::types::{PyDict, PyList, PyString};
 use pyo3::{intern, prelude::*, IntoPyObjectExt};
 
-use crate::errors::{ErrorTypeDefaults, InputValue, LocItem, ValError, ValResult};
-use crate::lookup_key::{LookupKey, LookupPath};
+use crate::errors::{ErrorTypeDefaults, InputValue, JsonInput, LocItem, ValError, ValResult};
+use crate::lookup_key::{FoundPath, LookupKey};
 use crate::tools::py_err;
-use crate::validators::{TemporalUnitMode, ValBytesMode};
+use crate::validators::{LaxSequenceTypes, TemporalUnitMode, ValBytesMode};
 
 use super::datetime::{EitherDate, EitherDateTime, EitherTime, EitherTimedelta};
 use super::return_enums::{EitherBytes, EitherComplex, EitherInt, EitherString};
@@ -65,6 +65,11 @@ pub trait Input<'py>: fmt::Debug {
 
     fn as_error_value(&self) -> InputValue;
 
+    /// Where the input is in the JSON being validated, see `ToErrorValue::json_input`.
+    fn json_input(&self) -> Option<JsonInput> {
+        None
+    }
+
     fn is_none(&self) -> bool {
         false
     }
@@ -140,21 +145,21 @@ pub trait Input<'py>: fmt::Debug {
     where
         Self: 'a;
 
-    fn validate_list(&self, strict: bool) -> ValMatch<Self::List<'_>>;
+    fn validate_list(&self, strict: bool, lax_types: &LaxSequenceTypes) -> ValMatch<Self::List<'_>>;
 
     type Tuple<'a>: ValidatedTuple<'py>
     where
         Self: 'a;
 
-    fn validate_tuple(&self, strict: bool) -> ValMatch<Self::Tuple<'_>>;
+    fn validate_tuple(&self, strict: bool, lax_types: &LaxSequenceTypes) -> ValMatch<Self::Tuple<'_>>;
 
     type Set<'a>: ValidatedSet<'py>
     where
         Self: 'a;
 
-    fn validate_set(&self, strict: bool) -> ValMatch<Self::Set<'_>>;
+    fn validate_set(&self, strict: bool, lax_types: &LaxSequenceTypes) -> ValMatch<Self::Set<'_>>;
 
-    fn validate_frozenset(&self, strict: bool) -> ValMatch<Self::Set<'_>>;
+    fn validate_frozenset(&self, strict: bool, lax_types: &LaxSequenceTypes) -> ValMatch<Self::Set<'_>>;
 
     fn validate_iter(&self) -> ValResult<GenericIterator<'static>>;
 
@@ -223,7 +228,7 @@ pub trait KeywordArgs<'py> {
     where
         Self: 'a;
     fn len(&self) -> usize;
-    fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(&'k LookupPath, Self::Item<'_>)>>;
+    fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(FoundPath<'k>, Self::Item<'_>)>>;
     fn iter(&self) -> impl Iterator<Item = ValResult<(Self::Key<'_>, Self::Item<'_>)>>;
 }
 
@@ -243,7 +248,7 @@ pub trait ValidatedDict<'py> {
     type Item<'a>: BorrowInput<'py>
     where
         Self: 'a;
-    fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(&'k LookupPath, Self::Item<'_>)>>;
+    fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(FoundPath<'k>, Self::Item<'_>)>>;
     // FIXME this is a bit of a leaky abstraction
     fn is_py_get_attr(&self) -> bool {
         false
@@ -285,7 +290,7 @@ pub enum Never {}
 impl<'py> ValidatedDict<'py> for Never {
     type Key<'a> = Bound<'py, PyAny>;
     type Item<'a> = Bound<'py, PyAny>;
-    fn get_item<'k>(&self, _key: &'k LookupKey) -> ValResult<Option<(&'k LookupPath, Self::Item<'_>)>> {
+    fn get_item<'k>(&self, _key: &'k LookupKey) -> ValResult<Option<(FoundPath<'k>, Self::Item<'_>)>> {
         unreachable!()
     }
     fn iterate<'a, R>(
@@ -371,7 +376,7 @@ impl<'py> KeywordArgs<'py> for Never {
     fn len(&self) -> usize {
         unreachable!()
     }
-    fn get_item<'k>(&self, _key: &'k LookupKey) -> ValResult<Option<(&'k LookupPath, Self::Item<'_>)>> {
+    fn get_item<'k>(&self, _key: &'k LookupKey) -> ValResult<Option<(FoundPath<'k>, Self::Item<'_>)>> {
         unreachable!()
     }
     fn iter(&self) -> impl Iterator<Item = ValResult<(Self::Key<'_>, Self::Item<'_>)>> {
@@ -8,12 +8,12 @@ use pyo3::types::{PyDict, PyList, PyString};
 use speedate::MicrosecondsPrecisionOverflowBehavior;
 use strum::EnumMessage;
 
-use crate::errors::{ErrorType, ErrorTypeDefaults, InputValue, LocItem, ValError, ValResult};
+use crate::errors::{ErrorType, ErrorTypeDefaults, InputValue, JsonInput, LocItem, ValError, ValResult};
 use crate::input::return_enums::EitherComplex;
-use crate::lookup_key::{LookupKey, LookupPath};
+use crate::lookup_key::{FoundPath, LookupKey};
 use crate::validators::complex::string_to_complex;
 use crate::validators::decimal::create_decimal;
-use crate::validators::{TemporalUnitMode, ValBytesMode};
+use crate::validators::{LaxSequenceTypes, TemporalUnitMode, ValBytesMode};
 
 use super::datetime::{
     bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, float_as_datetime, float_as_duration,
@@ -55,6 +55,10 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
         InputValue::Json(self.to_static())
     }
 
+    fn json_input(&self) -> Option<JsonInput> {
+        Some(JsonInput::Value((self as *const Self) as usize))
+    }
+
     fn is_none(&self) -> bool {
         matches!(self, JsonValue::Null)
     }
@@ -238,7 +242,7 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
     where
         Self: 'a;
 
-    fn validate_list(&self, _strict: bool) -> ValMatch<&JsonArray<'data>> {
+    fn validate_list(&self, _strict: bool, _lax_types: &LaxSequenceTypes) -> ValMatch<&JsonArray<'data>> {
         match self {
             JsonValue::Array(a) => Ok(ValidationMatch::exact(a)),
             _ => Err(ValError::new(ErrorTypeDefaults::ListType, self)),
@@ -250,7 +254,7 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
     where
         Self: 'a;
 
-    fn validate_tuple(&self, _strict: bool) -> ValMatch<&JsonArray<'data>> {
+    fn validate_tuple(&self, _strict: bool, _lax_types: &LaxSequenceTypes) -> ValMatch<&JsonArray<'data>> {
         // just as in set's case, List has to be allowed
         match self {
             JsonValue::Array(a) => Ok(ValidationMatch::strict(a)),
@@ -263,7 +267,7 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
     where
         Self: 'a;
 
-    fn validate_set(&self, _strict: bool) -> ValMatch<&JsonArray<'data>> {
+    fn validate_set(&self, _strict: bool, _lax_types: &LaxSequenceTypes) -> ValMatch<&JsonArray<'data>> {
         // we allow a list here since otherwise it would be impossible to create a set from JSON
         match self {
             JsonValue::Array(a) => Ok(ValidationMatch::strict(a)),
@@ -271,7 +275,7 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
         }
     }
 
-    fn validate_frozenset(&self, _strict: bool) -> ValMatch<&JsonArray<'data>> {
+    fn validate_frozenset(&self, _strict: bool, _lax_types: &LaxSequenceTypes) -> ValMatch<&JsonArray<'data>> {
         // we allow a list here since otherwise it would be impossible to create a frozenset from JSON
         match self {
             JsonValue::Array(a) => Ok(ValidationMatch::strict(a)),
@@ -404,6 +408,10 @@ impl<'py> Input<'py> for str {
         InputValue::Json(JsonValue::Str(self.to_owned().into()))
     }
 
+    fn json_input(&self) -> Option<JsonInput> {
+        Some(JsonInput::Key(self.as_ptr() as usize))
+    }
+
     fn as_kwargs(&self, _py: Python<'py>) -> Option<Bound<'py, PyDict>> {
         None
     }
@@ -481,26 +489,26 @@ impl<'py> Input<'py> for str {
 
     type List<'a> = Never;
 
-    fn validate_list(&self, _strict: bool) -> ValMatch<Never> {
+    fn validate_list(&self, _strict: bool, _lax_types: &LaxSequenceTypes) -> ValMatch<Never> {
         Err(ValError::new(ErrorTypeDefaults::ListType, self))
     }
 
     type Tuple<'a> = Never;
 
     #[cfg_attr(has_coverage_attribute, coverage(off))]
-    fn validate_tuple(&self, _strict: bool) -> ValMatch<Never> {
+    fn validate_tuple(&self, _strict: bool, _lax_types: &LaxSequenceTypes) -> ValMatch<Never> {
         Err(ValError::new(ErrorTypeDefaults::TupleType, self))
     }
 
     type Set<'a> = Never;
 
     #[cfg_attr(has_coverage_attribute, coverage(off))]
-    fn validate_set(&self, _strict: bool) -> ValMatch<Never> {
+    fn validate_set(&self, _strict: bool, _lax_types: &LaxSequenceTypes) -> ValMatch<Never> {
         Err(ValError::new(ErrorTypeDefaults::SetType, self))
     }
 
     #[cfg_attr(has_coverage_attribute, coverage(off))]
-    fn validate_frozenset(&self, _strict: bool) -> ValMatch<Never> {
+    fn validate_frozenset(&self, _strict: bool, _lax_types: &LaxSequenceTypes) -> ValMatch<Never> {
         Err(ValError::new(ErrorTypeDefaults::SetType, self))
     }
 
@@ -566,6 +574,14 @@ impl<'data> BorrowInput<'_> for JsonValue<'data> {
     }
 }
 
+// values found by lookup paths with wildcards are collected into a new array
+impl<'data> BorrowInput<'_> for Cow<'_, JsonValue<'data>> {
+    type Input = JsonValue<'data>;
+    fn borrow_input(&self) -> &Self::Input {
+        self
+    }
+}
+
 fn string_to_vec(s: &str) -> JsonArray<'static> {
     JsonArray::new(s.chars().map(|c| JsonValue::Str(c.to_string().into())).collect())
 }
@@ -577,11 +593,11 @@ impl<'data> ValidatedDict<'_> for &'_ JsonObject<'data> {
         Self: 'a;
 
     type Item<'a>
-        = &'a JsonValue<'data>
+        = Cow<'a, JsonValue<'data>>
     where
         Self: 'a;
 
-    fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(&'k LookupPath, Self::Item<'_>)>> {
+    fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(FoundPath<'k>, Self::Item<'_>)>> {
         key.json_get(self)
     }
 
@@ -589,7 +605,7 @@ impl<'data> ValidatedDict<'_> for &'_ JsonObject<'data> {
         &'a self,
         consumer: impl ConsumeIterator<ValResult<(Self::Key<'a>, Self::Item<'a>)>, Output = R>,
     ) -> ValResult<R> {
-        Ok(consumer.consume_iterator(self.as_slice().iter().map(|(k, v)| Ok((k.as_ref(), v)))))
+        Ok(consumer.consume_iterator(self.as_slice().iter().map(|(k, v)| Ok((k.as_ref(), Cow::Borrowed(v))))))
     }
 
     fn last_key(&self) -> Option<Self::Key<'_>> {
@@ -684,17 +700,17 @@ impl<'data> KeywordArgs<'_> for JsonObject<'data> {
     where
         Self: 'a;
     type Item<'a>
-        = &'a JsonValue<'data>
+        = Cow<'a, JsonValue<'data>>
     where
         Self: 'a;
 
     fn len(&self) -> usize {
         Vec::len(self)
     }
-    fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(&'k LookupPath, Self::Item<'_>)>> {
+    fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(FoundPath<'k>, Self::Item<'_>)>> {
         key.json_get(self)
     }
     fn iter(&self) -> impl Iterator<Item = ValResult<(Self::Key<'_>, Self::Item<'_>)>> {
-        self.as_slice().iter().map(|(k, v)| Ok((k.as_ref(), v)))
+        self.as_slice().iter().map(|(k, v)| Ok((k.as_ref(), Cow::Borrowed(v))))
     }
 }
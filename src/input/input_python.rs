@@ -7,8 +7,10 @@ use pyo3::sync::PyOnceLock;
 use pyo3::types::PyType;
 use pyo3::types::{
     PyBool, PyByteArray, PyBytes, PyComplex, PyDate, PyDateTime, PyDict, PyFloat, PyFrozenSet, PyInt, PyIterator,
-    PyList, PyMapping, PySet, PyString, PyTime, PyTuple,
+    PyList, PyMapping, PyRange, PySet, PyString, PyTime, PyTuple,
 };
+#[cfg(not(any(PyPy, GraalPy)))]
+use pyo3::types::{PyDictItems, PyDictKeys, PyDictValues};
 
 use pyo3::PyTypeCheck;
 use pyo3::PyTypeInfo;
@@ -19,6 +21,7 @@ use crate::tools::{extract_i64, safe_repr};
 use crate::validators::complex::string_to_complex;
 use crate::validators::decimal::{create_decimal, get_decimal_type};
 use crate::validators::Exactness;
+use crate::validators::LaxSequenceTypes;
 use crate::validators::TemporalUnitMode;
 use crate::validators::ValBytesMode;
 use crate::ArgsKwargs;
@@ -453,11 +456,15 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
     where
         Self: 'a;
 
-    fn validate_list<'a>(&'a self, strict: bool) -> ValMatch<PySequenceIterable<'a, 'py>> {
+    fn validate_list<'a>(
+        &'a self,
+        strict: bool,
+        lax_types: &LaxSequenceTypes,
+    ) -> ValMatch<PySequenceIterable<'a, 'py>> {
         if let Ok(list) = self.downcast::<PyList>() {
             return Ok(ValidationMatch::exact(PySequenceIterable::List(list)));
         } else if !strict {
-            if let Ok(other) = extract_sequence_iterable(self) {
+            if let Ok(other) = extract_sequence_iterable(self, lax_types) {
                 return Ok(ValidationMatch::lax(other));
             }
         }
@@ -470,11 +477,15 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
     where
         Self: 'a;
 
-    fn validate_tuple<'a>(&'a self, strict: bool) -> ValMatch<PySequenceIterable<'a, 'py>> {
+    fn validate_tuple<'a>(
+        &'a self,
+        strict: bool,
+        lax_types: &LaxSequenceTypes,
+    ) -> ValMatch<PySequenceIterable<'a, 'py>> {
         if let Ok(tup) = self.downcast::<PyTuple>() {
             return Ok(ValidationMatch::exact(PySequenceIterable::Tuple(tup)));
         } else if !strict {
-            if let Ok(other) = extract_sequence_iterable(self) {
+            if let Ok(other) = extract_sequence_iterable(self, lax_types) {
                 return Ok(ValidationMatch::lax(other));
             }
         }
@@ -487,11 +498,11 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
     where
         Self: 'a;
 
-    fn validate_set<'a>(&'a self, strict: bool) -> ValMatch<PySequenceIterable<'a, 'py>> {
+    fn validate_set<'a>(&'a self, strict: bool, lax_types: &LaxSequenceTypes) -> ValMatch<PySequenceIterable<'a, 'py>> {
         if let Ok(set) = self.downcast::<PySet>() {
             return Ok(ValidationMatch::exact(PySequenceIterable::Set(set)));
         } else if !strict {
-            if let Ok(other) = extract_sequence_iterable(self) {
+            if let Ok(other) = extract_sequence_iterable(self, lax_types) {
                 return Ok(ValidationMatch::lax(other));
             }
         }
@@ -499,11 +510,15 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
         Err(ValError::new(ErrorTypeDefaults::SetType, self))
     }
 
-    fn validate_frozenset<'a>(&'a self, strict: bool) -> ValMatch<PySequenceIterable<'a, 'py>> {
+    fn validate_frozenset<'a>(
+        &'a self,
+        strict: bool,
+        lax_types: &LaxSequenceTypes,
+    ) -> ValMatch<PySequenceIterable<'a, 'py>> {
         if let Ok(frozenset) = self.downcast::<PyFrozenSet>() {
             return Ok(ValidationMatch::exact(PySequenceIterable::FrozenSet(frozenset)));
         } else if !strict {
-            if let Ok(other) = extract_sequence_iterable(self) {
+            if let Ok(other) = extract_sequence_iterable(self, lax_types) {
                 return Ok(ValidationMatch::lax(other));
             }
         }
@@ -832,7 +847,7 @@ impl<'py> KeywordArgs<'py> for PyKwargs<'py> {
     fn get_item<'k>(
         &self,
         key: &'k crate::lookup_key::LookupKey,
-    ) -> ValResult<Option<(&'k crate::lookup_key::LookupPath, Self::Item<'_>)>> {
+    ) -> ValResult<Option<(crate::lookup_key::FoundPath<'k>, Self::Item<'_>)>> {
         key.py_get_dict_item(&self.0).map_err(Into::into)
     }
 
@@ -862,7 +877,7 @@ impl<'py> ValidatedDict<'py> for GenericPyMapping<'_, 'py> {
     fn get_item<'k>(
         &self,
         key: &'k crate::lookup_key::LookupKey,
-    ) -> ValResult<Option<(&'k crate::lookup_key::LookupPath, Self::Item<'_>)>> {
+    ) -> ValResult<Option<(crate::lookup_key::FoundPath<'k>, Self::Item<'_>)>> {
         match self {
             Self::Dict(dict) => key.py_get_dict_item(dict).map_err(Into::into),
             Self::Mapping(mapping) => key.py_get_mapping_item(mapping).map_err(Into::into),
@@ -910,11 +925,28 @@ pub enum PySequenceIterable<'a, 'py> {
     Set(&'a Bound<'py, PySet>),
     FrozenSet(&'a Bound<'py, PyFrozenSet>),
     Iterator(Bound<'py, PyIterator>),
+    // an iterator over a container of known length which isn't one of the types above, e.g. a dict view
+    SizedIterator(Bound<'py, PyIterator>, usize),
+}
+
+/// Whether `obj` is a dict view or a `range`, which are sized and never strings or mappings.
+fn is_dict_view_or_range(obj: &Bound<'_, PyAny>) -> bool {
+    #[cfg(not(any(PyPy, GraalPy)))]
+    if obj.is_exact_instance_of::<PyDictKeys>()
+        || obj.is_exact_instance_of::<PyDictValues>()
+        || obj.is_exact_instance_of::<PyDictItems>()
+    {
+        return true;
+    }
+    obj.is_exact_instance_of::<PyRange>()
 }
 
 /// Extract types which can be iterated to produce a sequence-like container like a list, tuple, set
 /// or frozenset
-fn extract_sequence_iterable<'a, 'py>(obj: &'a Bound<'py, PyAny>) -> ValResult<PySequenceIterable<'a, 'py>> {
+fn extract_sequence_iterable<'a, 'py>(
+    obj: &'a Bound<'py, PyAny>,
+    lax_types: &LaxSequenceTypes,
+) -> ValResult<PySequenceIterable<'a, 'py>> {
     // Handle concrete non-overlapping types first, then abstract types
     if let Ok(iterable) = obj.downcast::<PyList>() {
         Ok(PySequenceIterable::List(iterable))
@@ -924,6 +956,13 @@ fn extract_sequence_iterable<'a, 'py>(obj: &'a Bound<'py, PyAny>) -> ValResult<P
         Ok(PySequenceIterable::Set(iterable))
     } else if let Ok(iterable) = obj.downcast::<PyFrozenSet>() {
         Ok(PySequenceIterable::FrozenSet(iterable))
+    } else if is_dict_view_or_range(obj) || lax_types.contains(obj) {
+        // these skip the checks below, so allowed types are accepted even if they're e.g. mappings
+        let iter = obj.try_iter()?;
+        match obj.len() {
+            Ok(len) => Ok(PySequenceIterable::SizedIterator(iter, len)),
+            Err(_) => Ok(PySequenceIterable::Iterator(iter)),
+        }
     } else {
         // Try to get this as a generable iterable thing, but exclude string and mapping types
         if !(obj.is_instance_of::<PyString>()
@@ -949,6 +988,7 @@ impl<'py> PySequenceIterable<'_, 'py> {
             PySequenceIterable::Set(iter) => Some(iter.len()),
             PySequenceIterable::FrozenSet(iter) => Some(iter.len()),
             PySequenceIterable::Iterator(iter) => iter.len().ok(),
+            PySequenceIterable::SizedIterator(_, len) => Some(*len),
         }
     }
     fn generic_try_for_each(self, f: impl FnMut(PyResult<Bound<'py, PyAny>>) -> ValResult<()>) -> ValResult<()> {
@@ -957,7 +997,9 @@ impl<'py> PySequenceIterable<'_, 'py> {
             PySequenceIterable::Tuple(iter) => iter.iter().map(Ok).try_for_each(f),
             PySequenceIterable::Set(iter) => iter.iter().map(Ok).try_for_each(f),
             PySequenceIterable::FrozenSet(iter) => iter.iter().map(Ok).try_for_each(f),
-            PySequenceIterable::Iterator(mut iter) => iter.try_for_each(f),
+            PySequenceIterable::Iterator(mut iter) | PySequenceIterable::SizedIterator(mut iter, _) => {
+                iter.try_for_each(f)
+            }
         }
     }
     fn generic_iterate<R>(
@@ -969,7 +1011,9 @@ impl<'py> PySequenceIterable<'_, 'py> {
             PySequenceIterable::Tuple(iter) => Ok(consumer.consume_iterator(iter.iter().map(Ok))),
             PySequenceIterable::Set(iter) => Ok(consumer.consume_iterator(iter.iter().map(Ok))),
             PySequenceIterable::FrozenSet(iter) => Ok(consumer.consume_iterator(iter.iter().map(Ok))),
-            PySequenceIterable::Iterator(iter) => Ok(consumer.consume_iterator(iter.try_iter()?)),
+            PySequenceIterable::Iterator(iter) | PySequenceIterable::SizedIterator(iter, _) => {
+                Ok(consumer.consume_iterator(iter.try_iter()?))
+            }
         }
     }
 }
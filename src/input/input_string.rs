@@ -1,15 +1,15 @@
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyDict, PyList, PyString};
 
 use speedate::MicrosecondsPrecisionOverflowBehavior;
 
-use crate::errors::{ErrorTypeDefaults, InputValue, LocItem, ValError, ValResult};
+use crate::errors::{ErrorType, ErrorTypeDefaults, InputValue, LocItem, ValError, ValResult};
 use crate::input::py_string_str;
-use crate::lookup_key::{LookupKey, LookupPath};
+use crate::lookup_key::{FoundPath, LookupKey};
 use crate::tools::safe_repr;
 use crate::validators::complex::string_to_complex;
 use crate::validators::decimal::create_decimal;
-use crate::validators::{TemporalUnitMode, ValBytesMode};
+use crate::validators::{LaxSequenceTypes, TemporalUnitMode, ValBytesMode};
 
 use super::datetime::{
     bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, EitherDate, EitherDateTime, EitherTime,
@@ -18,20 +18,23 @@ use super::input_abstract::{Never, ValMatch};
 use super::return_enums::EitherComplex;
 use super::shared::{str_as_bool, str_as_float, str_as_int};
 use super::{
-    Arguments, BorrowInput, EitherBytes, EitherFloat, EitherInt, EitherString, EitherTimedelta, GenericIterator, Input,
-    KeywordArgs, ValidatedDict, ValidationMatch,
+    Arguments, BorrowInput, ConsumeIterator, EitherBytes, EitherFloat, EitherInt, EitherString, EitherTimedelta,
+    GenericIterator, Input, KeywordArgs, ValidatedDict, ValidatedList, ValidatedSet, ValidatedTuple, ValidationMatch,
 };
 
-#[derive(Debug, Clone, IntoPyObject, IntoPyObjectRef)]
+/// The input of `validate_strings`, a string or a dict of them. The `bool` is whether the conventions of
+/// environment variables are coerced too, from `env_style`: `""` and `"null"` are `None`, and lists, tuples and sets
+/// are comma-separated.
+#[derive(Debug, Clone)]
 pub enum StringMapping<'py> {
-    String(Bound<'py, PyString>),
-    Mapping(Bound<'py, PyDict>),
+    String(Bound<'py, PyString>, bool),
+    Mapping(Bound<'py, PyDict>, bool),
 }
 
 impl<'py> StringMapping<'py> {
-    pub fn new_key(py_key: Bound<'py, PyAny>) -> ValResult<Self> {
+    pub fn new_key(py_key: Bound<'py, PyAny>, env_style: bool) -> ValResult<Self> {
         match py_key.downcast_into::<PyString>() {
-            Ok(value) => Ok(Self::String(value)),
+            Ok(value) => Ok(Self::String(value, env_style)),
             Err(downcast_error) => Err(ValError::new(
                 ErrorTypeDefaults::StringType,
                 downcast_error.into_inner(),
@@ -39,11 +42,11 @@ impl<'py> StringMapping<'py> {
         }
     }
 
-    pub fn new_value(py_value: Bound<'py, PyAny>) -> ValResult<Self> {
+    pub fn new_value(py_value: Bound<'py, PyAny>, env_style: bool) -> ValResult<Self> {
         match py_value.downcast_into::<PyString>() {
-            Ok(py_str) => Ok(Self::String(py_str)),
+            Ok(py_str) => Ok(Self::String(py_str, env_style)),
             Err(downcast_error) => match downcast_error.into_inner().downcast_into::<PyDict>() {
-                Ok(value) => Ok(Self::Mapping(value)),
+                Ok(value) => Ok(Self::Mapping(value, env_style)),
                 Err(downcast_error) => Err(ValError::new(
                     ErrorTypeDefaults::StringType,
                     downcast_error.into_inner(),
@@ -51,13 +54,33 @@ impl<'py> StringMapping<'py> {
             },
         }
     }
+
+    /// The items of a comma-separated string with `env_style`, stripped of whitespace, `""` has no items,
+    /// otherwise `error_type`.
+    fn comma_separated(&self, error_type: ErrorType) -> ValMatch<StringMappingItems<'py>> {
+        match self {
+            Self::String(s, true) => {
+                let py = s.py();
+                let str = py_string_str(s)?;
+                let items = if str.trim().is_empty() {
+                    Vec::new()
+                } else {
+                    str.split(',')
+                        .map(|item| Self::String(PyString::new(py, item.trim()), true))
+                        .collect()
+                };
+                Ok(ValidationMatch::strict(StringMappingItems(items)))
+            }
+            _ => Err(ValError::new(error_type, self)),
+        }
+    }
 }
 
 impl From<StringMapping<'_>> for LocItem {
     fn from(string_mapping: StringMapping<'_>) -> Self {
         match string_mapping {
-            StringMapping::String(s) => s.to_string_lossy().as_ref().into(),
-            StringMapping::Mapping(d) => safe_repr(&d).to_string().into(),
+            StringMapping::String(s, _) => s.to_string_lossy().as_ref().into(),
+            StringMapping::Mapping(d, _) => safe_repr(&d).to_string().into(),
         }
     }
 }
@@ -65,13 +88,23 @@ impl From<StringMapping<'_>> for LocItem {
 impl<'py> Input<'py> for StringMapping<'py> {
     #[inline]
     fn py_converter(&self) -> impl IntoPyObject<'py> + '_ {
-        self
+        match self {
+            Self::String(s, _) => s.as_any(),
+            Self::Mapping(d, _) => d.as_any(),
+        }
+    }
+
+    fn is_none(&self) -> bool {
+        match self {
+            Self::String(s, true) => matches!(s.to_str(), Ok("" | "null")),
+            _ => false,
+        }
     }
 
     fn as_error_value(&self) -> InputValue {
         match self {
-            Self::String(s) => s.as_error_value(),
-            Self::Mapping(d) => d.as_error_value(),
+            Self::String(s, _) => s.as_error_value(),
+            Self::Mapping(d, _) => d.as_error_value(),
         }
     }
 
@@ -96,8 +129,8 @@ impl<'py> Input<'py> for StringMapping<'py> {
 
     fn validate_dataclass_args<'a>(&'a self, _dataclass_name: &str) -> ValResult<StringMappingDict<'py>> {
         match self {
-            StringMapping::String(_) => Err(ValError::new(ErrorTypeDefaults::ArgumentsType, self)),
-            StringMapping::Mapping(m) => Ok(StringMappingDict(m.clone())),
+            StringMapping::String(..) => Err(ValError::new(ErrorTypeDefaults::ArgumentsType, self)),
+            StringMapping::Mapping(m, env_style) => Ok(StringMappingDict(m.clone(), *env_style)),
         }
     }
 
@@ -107,8 +140,8 @@ impl<'py> Input<'py> for StringMapping<'py> {
         _coerce_numbers_to_str: bool,
     ) -> ValResult<ValidationMatch<EitherString<'_, 'py>>> {
         match self {
-            Self::String(s) => Ok(ValidationMatch::strict(s.clone().into())),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::StringType, self)),
+            Self::String(s, _) => Ok(ValidationMatch::strict(s.clone().into())),
+            Self::Mapping(..) => Err(ValError::new(ErrorTypeDefaults::StringType, self)),
         }
     }
 
@@ -118,39 +151,39 @@ impl<'py> Input<'py> for StringMapping<'py> {
         mode: ValBytesMode,
     ) -> ValResult<ValidationMatch<EitherBytes<'a, 'py>>> {
         match self {
-            Self::String(s) => py_string_str(s).and_then(|b| match mode.deserialize_string(b) {
+            Self::String(s, _) => py_string_str(s).and_then(|b| match mode.deserialize_string(b) {
                 Ok(b) => Ok(ValidationMatch::strict(b)),
                 Err(e) => Err(ValError::new(e, self)),
             }),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::BytesType, self)),
+            Self::Mapping(..) => Err(ValError::new(ErrorTypeDefaults::BytesType, self)),
         }
     }
 
     fn validate_bool(&self, _strict: bool) -> ValResult<ValidationMatch<bool>> {
         match self {
-            Self::String(s) => str_as_bool(self, py_string_str(s)?).map(ValidationMatch::strict),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::BoolType, self)),
+            Self::String(s, _) => str_as_bool(self, py_string_str(s)?).map(ValidationMatch::strict),
+            Self::Mapping(..) => Err(ValError::new(ErrorTypeDefaults::BoolType, self)),
         }
     }
 
     fn validate_int(&self, _strict: bool) -> ValResult<ValidationMatch<EitherInt<'_>>> {
         match self {
-            Self::String(s) => str_as_int(self, py_string_str(s)?).map(ValidationMatch::strict),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::IntType, self)),
+            Self::String(s, _) => str_as_int(self, py_string_str(s)?).map(ValidationMatch::strict),
+            Self::Mapping(..) => Err(ValError::new(ErrorTypeDefaults::IntType, self)),
         }
     }
 
     fn validate_float(&self, _strict: bool) -> ValResult<ValidationMatch<EitherFloat<'_>>> {
         match self {
-            Self::String(s) => str_as_float(self, py_string_str(s)?).map(ValidationMatch::strict),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::FloatType, self)),
+            Self::String(s, _) => str_as_float(self, py_string_str(s)?).map(ValidationMatch::strict),
+            Self::Mapping(..) => Err(ValError::new(ErrorTypeDefaults::FloatType, self)),
         }
     }
 
     fn validate_decimal(&self, _strict: bool, _py: Python<'py>) -> ValMatch<Bound<'py, PyAny>> {
         match self {
-            Self::String(s) => create_decimal(s, self).map(ValidationMatch::strict),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::DecimalType, self)),
+            Self::String(s, _) => create_decimal(s, self).map(ValidationMatch::strict),
+            Self::Mapping(..) => Err(ValError::new(ErrorTypeDefaults::DecimalType, self)),
         }
     }
 
@@ -161,40 +194,40 @@ impl<'py> Input<'py> for StringMapping<'py> {
 
     fn strict_dict(&self) -> ValResult<StringMappingDict<'py>> {
         match self {
-            Self::String(_) => Err(ValError::new(ErrorTypeDefaults::DictType, self)),
-            Self::Mapping(d) => Ok(StringMappingDict(d.clone())),
+            Self::String(..) => Err(ValError::new(ErrorTypeDefaults::DictType, self)),
+            Self::Mapping(d, env_style) => Ok(StringMappingDict(d.clone(), *env_style)),
         }
     }
 
     type List<'a>
-        = Never
+        = StringMappingItems<'py>
     where
         Self: 'a;
 
-    fn validate_list(&self, _strict: bool) -> ValMatch<Never> {
-        Err(ValError::new(ErrorTypeDefaults::ListType, self))
+    fn validate_list(&self, _strict: bool, _lax_types: &LaxSequenceTypes) -> ValMatch<StringMappingItems<'py>> {
+        self.comma_separated(ErrorTypeDefaults::ListType)
     }
 
     type Tuple<'a>
-        = Never
+        = StringMappingItems<'py>
     where
         Self: 'a;
 
-    fn validate_tuple(&self, _strict: bool) -> ValMatch<Never> {
-        Err(ValError::new(ErrorTypeDefaults::TupleType, self))
+    fn validate_tuple(&self, _strict: bool, _lax_types: &LaxSequenceTypes) -> ValMatch<StringMappingItems<'py>> {
+        self.comma_separated(ErrorTypeDefaults::TupleType)
     }
 
     type Set<'a>
-        = Never
+        = StringMappingItems<'py>
     where
         Self: 'a;
 
-    fn validate_set(&self, _strict: bool) -> ValMatch<Never> {
-        Err(ValError::new(ErrorTypeDefaults::SetType, self))
+    fn validate_set(&self, _strict: bool, _lax_types: &LaxSequenceTypes) -> ValMatch<StringMappingItems<'py>> {
+        self.comma_separated(ErrorTypeDefaults::SetType)
     }
 
-    fn validate_frozenset(&self, _strict: bool) -> ValMatch<Never> {
-        Err(ValError::new(ErrorTypeDefaults::FrozenSetType, self))
+    fn validate_frozenset(&self, _strict: bool, _lax_types: &LaxSequenceTypes) -> ValMatch<StringMappingItems<'py>> {
+        self.comma_separated(ErrorTypeDefaults::FrozenSetType)
     }
 
     fn validate_iter(&self) -> ValResult<GenericIterator<'static>> {
@@ -203,8 +236,8 @@ impl<'py> Input<'py> for StringMapping<'py> {
 
     fn validate_date(&self, _strict: bool, mode: TemporalUnitMode) -> ValResult<ValidationMatch<EitherDate<'py>>> {
         match self {
-            Self::String(s) => bytes_as_date(self, py_string_str(s)?.as_bytes(), mode).map(ValidationMatch::strict),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::DateType, self)),
+            Self::String(s, _) => bytes_as_date(self, py_string_str(s)?.as_bytes(), mode).map(ValidationMatch::strict),
+            Self::Mapping(..) => Err(ValError::new(ErrorTypeDefaults::DateType, self)),
         }
     }
 
@@ -214,9 +247,9 @@ impl<'py> Input<'py> for StringMapping<'py> {
         microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
     ) -> ValResult<ValidationMatch<EitherTime<'py>>> {
         match self {
-            Self::String(s) => bytes_as_time(self, py_string_str(s)?.as_bytes(), microseconds_overflow_behavior)
+            Self::String(s, _) => bytes_as_time(self, py_string_str(s)?.as_bytes(), microseconds_overflow_behavior)
                 .map(ValidationMatch::strict),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::TimeType, self)),
+            Self::Mapping(..) => Err(ValError::new(ErrorTypeDefaults::TimeType, self)),
         }
     }
 
@@ -227,11 +260,11 @@ impl<'py> Input<'py> for StringMapping<'py> {
         mode: TemporalUnitMode,
     ) -> ValResult<ValidationMatch<EitherDateTime<'py>>> {
         match self {
-            Self::String(s) => {
+            Self::String(s, _) => {
                 bytes_as_datetime(self, py_string_str(s)?.as_bytes(), microseconds_overflow_behavior, mode)
                     .map(ValidationMatch::strict)
             }
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::DatetimeType, self)),
+            Self::Mapping(..) => Err(ValError::new(ErrorTypeDefaults::DatetimeType, self)),
         }
     }
 
@@ -241,16 +274,18 @@ impl<'py> Input<'py> for StringMapping<'py> {
         microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
     ) -> ValResult<ValidationMatch<EitherTimedelta<'py>>> {
         match self {
-            Self::String(s) => bytes_as_timedelta(self, py_string_str(s)?.as_bytes(), microseconds_overflow_behavior)
-                .map(ValidationMatch::strict),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::TimeDeltaType, self)),
+            Self::String(s, _) => {
+                bytes_as_timedelta(self, py_string_str(s)?.as_bytes(), microseconds_overflow_behavior)
+                    .map(ValidationMatch::strict)
+            }
+            Self::Mapping(..) => Err(ValError::new(ErrorTypeDefaults::TimeDeltaType, self)),
         }
     }
 
     fn validate_complex(&self, _strict: bool, _py: Python<'py>) -> ValResult<ValidationMatch<EitherComplex<'py>>> {
         match self {
-            Self::String(s) => Ok(ValidationMatch::strict(EitherComplex::Py(string_to_complex(s, self)?))),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::ComplexType, self)),
+            Self::String(s, _) => Ok(ValidationMatch::strict(EitherComplex::Py(string_to_complex(s, self)?))),
+            Self::Mapping(..) => Err(ValError::new(ErrorTypeDefaults::ComplexType, self)),
         }
     }
 }
@@ -262,7 +297,50 @@ impl<'py> BorrowInput<'py> for StringMapping<'py> {
     }
 }
 
-pub struct StringMappingDict<'py>(Bound<'py, PyDict>);
+/// The items of a comma-separated string, see `StringMapping::comma_separated`.
+pub struct StringMappingItems<'py>(Vec<StringMapping<'py>>);
+
+impl<'py> ValidatedList<'py> for StringMappingItems<'py> {
+    type Item = StringMapping<'py>;
+
+    fn len(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+
+    fn as_py_list(&self) -> Option<&Bound<'py, PyList>> {
+        None
+    }
+
+    fn iterate<R>(self, consumer: impl ConsumeIterator<PyResult<Self::Item>, Output = R>) -> ValResult<R> {
+        Ok(consumer.consume_iterator(self.0.into_iter().map(Ok)))
+    }
+}
+
+impl<'py> ValidatedTuple<'py> for StringMappingItems<'py> {
+    type Item = StringMapping<'py>;
+
+    fn len(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+
+    fn try_for_each(self, f: impl FnMut(PyResult<Self::Item>) -> ValResult<()>) -> ValResult<()> {
+        self.0.into_iter().map(Ok).try_for_each(f)
+    }
+
+    fn iterate<R>(self, consumer: impl ConsumeIterator<PyResult<Self::Item>, Output = R>) -> ValResult<R> {
+        Ok(consumer.consume_iterator(self.0.into_iter().map(Ok)))
+    }
+}
+
+impl<'py> ValidatedSet<'py> for StringMappingItems<'py> {
+    type Item = StringMapping<'py>;
+
+    fn iterate<R>(self, consumer: impl ConsumeIterator<PyResult<Self::Item>, Output = R>) -> ValResult<R> {
+        Ok(consumer.consume_iterator(self.0.into_iter().map(Ok)))
+    }
+}
+
+pub struct StringMappingDict<'py>(Bound<'py, PyDict>, bool);
 
 impl<'py> Arguments<'py> for StringMappingDict<'py> {
     type Args = Never;
@@ -292,14 +370,17 @@ impl<'py> KeywordArgs<'py> for StringMappingDict<'py> {
         self.0.len()
     }
 
-    fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(&'k LookupPath, Self::Item<'_>)>> {
-        key.py_get_string_mapping_item(&self.0)
+    fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(FoundPath<'k>, Self::Item<'_>)>> {
+        key.py_get_string_mapping_item(&self.0, self.1)
     }
 
     fn iter(&self) -> impl Iterator<Item = ValResult<(Self::Key<'_>, Self::Item<'_>)>> {
-        self.0
-            .iter()
-            .map(|(key, val)| Ok((StringMapping::new_key(key)?, StringMapping::new_value(val)?)))
+        self.0.iter().map(|(key, val)| {
+            Ok((
+                StringMapping::new_key(key, self.1)?,
+                StringMapping::new_value(val, self.1)?,
+            ))
+        })
     }
 }
 
@@ -313,18 +394,19 @@ impl<'py> ValidatedDict<'py> for StringMappingDict<'py> {
         = StringMapping<'py>
     where
         Self: 'a;
-    fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(&'k LookupPath, Self::Item<'_>)>> {
-        key.py_get_string_mapping_item(&self.0)
+    fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(FoundPath<'k>, Self::Item<'_>)>> {
+        key.py_get_string_mapping_item(&self.0, self.1)
     }
     fn iterate<'a, R>(
         &'a self,
         consumer: impl super::ConsumeIterator<ValResult<(Self::Key<'a>, Self::Item<'a>)>, Output = R>,
     ) -> ValResult<R> {
-        Ok(consumer.consume_iterator(
-            self.0
-                .iter()
-                .map(|(key, val)| Ok((StringMapping::new_key(key)?, StringMapping::new_value(val)?))),
-        ))
+        Ok(consumer.consume_iterator(self.0.iter().map(|(key, val)| {
+            Ok((
+                StringMapping::new_key(key, self.1)?,
+                StringMapping::new_value(val, self.1)?,
+            ))
+        })))
     }
 
     fn last_key(&self) -> Option<Self::Key<'_>> {
@@ -332,6 +414,6 @@ impl<'py> ValidatedDict<'py> for StringMappingDict<'py> {
             .keys()
             .iter()
             .last()
-            .and_then(|key| StringMapping::new_key(key).ok())
+            .and_then(|key| StringMapping::new_key(key, self.1).ok())
     }
 }
@@ -12,17 +12,17 @@ use pyo3::ffi;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyFunction;
-use pyo3::types::{PyBytes, PyComplex, PyFloat, PyFrozenSet, PyIterator, PyMapping, PySet, PyString};
+use pyo3::types::{PyByteArray, PyBytes, PyComplex, PyFloat, PyFrozenSet, PyIterator, PyMapping, PySet, PyString};
 
 use pyo3::IntoPyObjectExt;
 use serde::{ser::Error, Serialize, Serializer};
 
 use crate::errors::{
-    py_err_string, ErrorType, ErrorTypeDefaults, InputValue, ToErrorValue, ValError, ValLineError, ValResult,
+    py_err_string, ErrorType, ErrorTypeDefaults, InputValue, Location, ToErrorValue, ValError, ValLineError, ValResult,
 };
 use crate::py_gc::PyGcTraverse;
 use crate::tools::{extract_i64, extract_int, new_py_string, py_err};
-use crate::validators::{CombinedValidator, Exactness, ValidationState, Validator};
+use crate::validators::{CombinedValidator, Exactness, ResumeNode, ValidationState, Validator};
 
 use super::{py_error_on_minusone, BorrowInput, Input};
 
@@ -126,27 +126,46 @@ pub(crate) fn validate_iter_to_vec<'py>(
     validator: &CombinedValidator,
     state: &mut ValidationState<'_, 'py>,
     fail_fast: bool,
+    mut resume: Option<&mut ResumeNode>,
 ) -> ValResult<Vec<Py<PyAny>>> {
     let mut output: Vec<Py<PyAny>> = Vec::with_capacity(capacity);
     let mut errors: Vec<ValLineError> = Vec::new();
     let allow_partial = state.allow_partial;
 
     for (index, is_last_partial, item_result) in state.enumerate_last_partial(iter) {
+        state.check_deadline()?;
         state.allow_partial = match is_last_partial {
             true => allow_partial,
             false => PartialMode::Off,
         };
         let item = item_result.map_err(|e| any_next_error!(py, e, max_length_check.input, index))?;
-        match validator.validate(py, item.borrow_input(), state) {
+        let partial_mark = state.partial_mark();
+        let result = state.with_path_item(
+            || index,
+            |state| match resume {
+                Some(ref mut resume) => {
+                    resume.validate_item(py, index.into(), is_last_partial, validator, state, |state| {
+                        validator.validate(py, item.borrow_input(), state)
+                    })
+                }
+                None => validator.validate(py, item.borrow_input(), state),
+            },
+        );
+        match result {
             Ok(item) => {
                 max_length_check.incr()?;
                 output.push(item);
+                if is_last_partial {
+                    state.record_partial_item(partial_mark, &Location::new_some(index.into()), None);
+                }
             }
             Err(ValError::LineErrors(line_errors)) => {
                 max_length_check.incr()?;
-                if !is_last_partial {
+                if is_last_partial {
+                    state.record_partial_item(partial_mark, &Location::new_some(index.into()), Some(line_errors));
+                } else {
                     errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
-                    if fail_fast {
+                    if state.stop_collecting(fail_fast, &errors) {
                         return Err(ValError::LineErrors(errors));
                     }
                 }
@@ -229,13 +248,18 @@ pub(crate) fn validate_iter_to_set<'py>(
     let allow_partial = state.allow_partial;
 
     for (index, is_last_partial, item_result) in state.enumerate_last_partial(iter) {
+        state.check_deadline()?;
         state.allow_partial = match is_last_partial {
             true => allow_partial,
             false => PartialMode::Off,
         };
         let item = item_result.map_err(|e| any_next_error!(py, e, input, index))?;
-        match validate_add(py, set, item, state, validator) {
+        let partial_mark = state.partial_mark();
+        match state.with_path_item(|| index, |state| validate_add(py, set, item, state, validator)) {
             Ok(()) => {
+                if is_last_partial {
+                    state.record_partial_item(partial_mark, &Location::new_some(index.into()), None);
+                }
                 if let Some(max_length) = max_length {
                     if set.build_len() > max_length {
                         return Err(ValError::new(
@@ -254,14 +278,16 @@ pub(crate) fn validate_iter_to_set<'py>(
                 }
             }
             Err(ValError::LineErrors(line_errors)) => {
-                if !is_last_partial {
+                if is_last_partial {
+                    state.record_partial_item(partial_mark, &Location::new_some(index.into()), Some(line_errors));
+                } else {
                     errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
                 }
             }
             Err(ValError::Omit) => (),
             Err(err) => return Err(err),
         }
-        if fail_fast && !errors.is_empty() {
+        if state.stop_collecting(fail_fast, &errors) {
             return Err(ValError::LineErrors(errors));
         }
     }
@@ -323,6 +349,38 @@ fn mapping_err<'py>(err: PyErr, py: Python<'py>, input: &'py (impl Input<'py> +
 
 const MAPPING_TUPLE_ERROR: &str = "Mapping items must be tuples of (key, value) pairs";
 
+/// Iterate over the `(key, value)` pairs of an iterable which isn't a mapping, e.g. a generator or database
+/// cursor, `None` if `object` isn't such an iterable.
+pub(crate) fn iterate_items<'py>(
+    object: &Bound<'py, PyAny>,
+) -> Option<impl Iterator<Item = ValResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)>> + 'py> {
+    if object.is_instance_of::<PyString>()
+        || object.is_instance_of::<PyBytes>()
+        || object.is_instance_of::<PyByteArray>()
+        || object.downcast::<PyMapping>().is_ok()
+    {
+        return None;
+    }
+    let py = object.py();
+    let input = object.clone();
+    let iterator = object.try_iter().ok()?;
+    Some(iterator.enumerate().map(move |(index, item)| {
+        let item = item.map_err(|err| any_next_error!(py, err, &input, index))?;
+        item.extract().map_err(|_| {
+            ValError::new_with_loc(
+                ErrorType::MappingType {
+                    error: ITEMS_TUPLE_ERROR.into(),
+                    context: None,
+                },
+                &input,
+                index,
+            )
+        })
+    }))
+}
+
+const ITEMS_TUPLE_ERROR: &str = "Items must be tuples of (key, value) pairs";
+
 /// Iterate over attributes of an object
 pub(crate) fn iterate_attributes<'a, 'py>(
     object: &'a Bound<'py, PyAny>,
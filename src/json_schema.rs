@@ -0,0 +1,845 @@
+use ahash::AHashSet;
+use pyo3::exceptions::PyValueError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyList, PyString, PyTuple, PyType};
+use pyo3::{PyTraverseError, PyVisit};
+
+use crate::build_tools::{parse_interval, py_schema_err, FieldAliases, IntervalBounds};
+use crate::input::Int;
+use crate::py_gc::PyGcTraverse;
+use crate::tools::SchemaDict;
+
+/// Whether a JSON Schema describes the input accepted by validation or the output of serialization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonSchemaMode {
+    Validation,
+    Serialization,
+}
+
+impl FromPyObject<'_> for JsonSchemaMode {
+    fn extract_bound(ob: &Bound<'_, PyAny>) -> PyResult<Self> {
+        match ob.extract::<&str>()? {
+            "validation" => Ok(Self::Validation),
+            "serialization" => Ok(Self::Serialization),
+            s => Err(PyValueError::new_err(format!(
+                "Invalid mode: `{s}`, expected 'validation' or 'serialization'"
+            ))),
+        }
+    }
+}
+
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+#[derive(Debug)]
+pub struct SchemaJsonifier {
+    py_schema: Py<PyDict>,
+    py_config: Option<Py<PyDict>>,
+}
+
+impl_py_gc_traverse!(SchemaJsonifier { py_schema, py_config });
+
+#[pymethods]
+impl SchemaJsonifier {
+    #[new]
+    #[pyo3(signature = (schema, config=None))]
+    pub fn py_new(schema: Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> Self {
+        Self {
+            py_schema: schema.unbind(),
+            py_config: config.filter(|c| !c.is_empty()).map(|c| c.clone().unbind()),
+        }
+    }
+
+    #[pyo3(signature = (*, mode = JsonSchemaMode::Validation, by_alias = true, ref_template = "#/$defs/{ref}"))]
+    pub fn json_schema<'py>(
+        &self,
+        py: Python<'py>,
+        mode: JsonSchemaMode,
+        by_alias: bool,
+        ref_template: &str,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        if !ref_template.contains("{ref}") {
+            return Err(PyValueError::new_err("`ref_template` must contain `{ref}`"));
+        }
+        let config = self.py_config.as_ref().map(|c| c.bind(py));
+        let mut generator = JsonSchemaGenerator {
+            py,
+            mode,
+            by_alias,
+            ref_template,
+            ser_json_timedelta: config
+                .map(|c| c.get_as::<String>(intern!(py, "ser_json_timedelta")))
+                .transpose()?
+                .flatten(),
+            ser_json_bytes: config
+                .map(|c| c.get_as::<String>(intern!(py, "ser_json_bytes")))
+                .transpose()?
+                .flatten(),
+            defs: PyDict::new(py),
+            building: AHashSet::new(),
+        };
+        let json_schema = generator.generate(self.py_schema.bind(py))?;
+        if !generator.defs.is_empty() {
+            json_schema.set_item(intern!(py, "$defs"), &generator.defs)?;
+        }
+        Ok(json_schema)
+    }
+
+    pub fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        let init_args = (&slf.get().py_schema, &slf.get().py_config).into_pyobject(slf.py())?;
+        Ok((slf.get_type(), init_args))
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        format!("SchemaJsonifier(schema={})", self.py_schema.bind(py))
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.py_gc_traverse(&visit)
+    }
+}
+
+/// Walks a core schema to build the equivalent JSON Schema, schemas with a `ref` are added to `$defs`.
+struct JsonSchemaGenerator<'a, 'py> {
+    py: Python<'py>,
+    mode: JsonSchemaMode,
+    by_alias: bool,
+    ref_template: &'a str,
+    ser_json_timedelta: Option<String>,
+    ser_json_bytes: Option<String>,
+    defs: Bound<'py, PyDict>,
+    // refs whose JSON Schema is being built, so recursive schemas refer to themselves rather than looping
+    building: AHashSet<String>,
+}
+
+impl<'py> JsonSchemaGenerator<'_, 'py> {
+    fn generate(&mut self, schema: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let py = self.py;
+        if let Some(schema_ref) = schema.get_as::<String>(intern!(py, "ref"))? {
+            if !self.defs.contains(&schema_ref)? && !self.building.contains(&schema_ref) {
+                self.building.insert(schema_ref.clone());
+                let json_schema = self.generate_type(schema);
+                self.building.remove(&schema_ref);
+                self.defs.set_item(&schema_ref, json_schema?)?;
+            }
+            return self.ref_schema(&schema_ref);
+        }
+        self.generate_type(schema)
+    }
+
+    fn generate_type(&mut self, schema: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let py = self.py;
+        if self.mode == JsonSchemaMode::Serialization {
+            if let Some(json_schema) = self.generate_serialization(schema)? {
+                return Ok(json_schema);
+            }
+        }
+        let type_: String = schema.get_as_req(intern!(py, "type"))?;
+        match type_.as_str() {
+            "any" => Ok(PyDict::new(py)),
+            "none" => self.typed("null"),
+            "bool" => self.typed("boolean"),
+            "int" => {
+                let json_schema = self.typed("integer")?;
+                self.add_number_constraints(schema, &json_schema, |bound| {
+                    bound
+                        .parse()
+                        .map(Int::I64)
+                        .or_else(|_| bound.parse().map(Int::Big))
+                        .ok()
+                })?;
+                Ok(json_schema)
+            }
+            "float" => {
+                let json_schema = self.typed("number")?;
+                self.add_number_constraints(schema, &json_schema, |bound| bound.parse::<f64>().ok())?;
+                Ok(json_schema)
+            }
+            "decimal" => match self.mode {
+                JsonSchemaMode::Validation => {
+                    let number = self.typed("number")?;
+                    self.add_number_constraints(schema, &number, |_| None::<f64>)?;
+                    let string = self.typed("string")?;
+                    self.any_of(vec![number, string])
+                }
+                JsonSchemaMode::Serialization => self.typed("string"),
+            },
+            "str" => {
+                let json_schema = self.typed("string")?;
+                // `minLength` and `maxLength` count code points, so lengths in other units have no equivalent
+                let length_unit = schema.get_as::<String>(intern!(py, "length_unit"))?;
+                if matches!(length_unit.as_deref(), None | Some("codepoints")) {
+                    self.copy_items(schema, &json_schema, &[("min_length", "minLength")])?;
+                    // longer inputs are accepted and truncated, so `maxLength` only applies to the output
+                    let truncate = schema.get_as(intern!(py, "truncate"))?.unwrap_or(false);
+                    if !truncate || self.mode == JsonSchemaMode::Serialization {
+                        self.copy_items(schema, &json_schema, &[("max_length", "maxLength")])?;
+                    }
+                }
+                if let Some(pattern) = schema.get_item(intern!(py, "pattern"))? {
+                    let pattern = match pattern.downcast::<PyString>() {
+                        Ok(_) => pattern,
+                        // a compiled `re.Pattern`
+                        Err(_) => pattern.getattr(intern!(py, "pattern"))?,
+                    };
+                    json_schema.set_item(intern!(py, "pattern"), pattern)?;
+                }
+                if let Some(pattern_not) = schema.get_item(intern!(py, "pattern_not"))? {
+                    let pattern_not = match pattern_not.downcast::<PyString>() {
+                        Ok(_) => pattern_not,
+                        Err(_) => pattern_not.getattr(intern!(py, "pattern"))?,
+                    };
+                    let not = PyDict::new(py);
+                    not.set_item(intern!(py, "pattern"), pattern_not)?;
+                    json_schema.set_item(intern!(py, "not"), not)?;
+                }
+                Ok(json_schema)
+            }
+            "bytes" => {
+                let format = match self.ser_json_bytes.as_deref() {
+                    Some("base64") => "base64url",
+                    _ => "binary",
+                };
+                let json_schema = self.formatted_string(format)?;
+                self.copy_items(
+                    schema,
+                    &json_schema,
+                    &[("min_length", "minLength"), ("max_length", "maxLength")],
+                )?;
+                Ok(json_schema)
+            }
+            "date" => self.temporal_string(schema, "date"),
+            "time" => self.temporal_string(schema, "time"),
+            "datetime" => self.temporal_string(schema, "date-time"),
+            "timedelta" => match (self.mode, self.ser_json_timedelta.as_deref()) {
+                // `output='components'` or `output='period'` returns a dict of the parts of the duration
+                (JsonSchemaMode::Serialization, _)
+                    if schema
+                        .get_as::<String>(intern!(py, "output"))?
+                        .is_some_and(|output| output != "timedelta") =>
+                {
+                    self.typed("object")
+                }
+                (JsonSchemaMode::Serialization, Some("float")) => self.typed("number"),
+                _ => self.formatted_string("duration"),
+            },
+            "uuid" => self.formatted_string("uuid"),
+            "semver" => self.formatted_string("semver"),
+            "ulid" => self.formatted_string("ulid"),
+            "email" | "name-email" => self.formatted_string(&type_),
+            "path" => self.formatted_string("path"),
+            "ip-v4-address" => self.formatted_string("ipv4"),
+            "ip-v6-address" => self.formatted_string("ipv6"),
+            "ip-network" | "ip-interface" => {
+                // the same formats as pydantic, e.g. `ipv4network` or `ipvanyinterface`
+                let version = match schema.get_as::<u8>(intern!(py, "version"))? {
+                    Some(4) => "v4",
+                    Some(6) => "v6",
+                    _ => "vany",
+                };
+                let kind = if type_ == "ip-network" { "network" } else { "interface" };
+                self.formatted_string(&format!("ip{version}{kind}"))
+            }
+            "url" | "multi-host-url" => {
+                let json_schema = self.formatted_string(if type_ == "url" { "uri" } else { "multi-host-uri" })?;
+                json_schema.set_item(intern!(py, "minLength"), 1)?;
+                self.copy_items(schema, &json_schema, &[("max_length", "maxLength")])?;
+                Ok(json_schema)
+            }
+            "complex" => self.typed("string"),
+            "literal" => self.literal(&schema.get_as_req(intern!(py, "expected"))?),
+            "const" => {
+                let value = schema.get_as_req::<Bound<'py, PyAny>>(intern!(py, "value"))?;
+                let json_schema = self.literal(&PyList::new(py, [&value])?)?;
+                if is_json_value(&value) {
+                    json_schema.set_item(intern!(py, "default"), value)?;
+                }
+                Ok(json_schema)
+            }
+            "enum" => self.enum_(schema),
+            "list" | "set" | "frozenset" | "generator" => {
+                let json_schema = self.typed("array")?;
+                json_schema.set_item(intern!(py, "items"), self.optional_schema(schema, "items_schema")?)?;
+                if type_ == "set" || type_ == "frozenset" {
+                    json_schema.set_item(intern!(py, "uniqueItems"), true)?;
+                }
+                self.copy_items(
+                    schema,
+                    &json_schema,
+                    &[("min_length", "minItems"), ("max_length", "maxItems")],
+                )?;
+                Ok(json_schema)
+            }
+            "keyed-list" => {
+                // the list of items is validated and serialized, the dict is only the validated Python value
+                let json_schema = self.typed("array")?;
+                json_schema.set_item(intern!(py, "items"), self.inner(schema, "items_schema")?)?;
+                self.copy_items(
+                    schema,
+                    &json_schema,
+                    &[("min_length", "minItems"), ("max_length", "maxItems")],
+                )?;
+                Ok(json_schema)
+            }
+            "tuple" => self.tuple(schema),
+            "dict" => {
+                let json_schema = self.typed("object")?;
+                let keys_schema = self.optional_schema(schema, "keys_schema")?;
+                // keys are always strings in JSON, so only constraints on them are worth including
+                let keys_type = keys_schema.get_item(intern!(py, "type"))?;
+                if keys_schema.len() > usize::from(keys_type.is_some()) {
+                    json_schema.set_item(intern!(py, "propertyNames"), keys_schema)?;
+                }
+                json_schema.set_item(
+                    intern!(py, "additionalProperties"),
+                    self.optional_schema(schema, "values_schema")?,
+                )?;
+                self.copy_items(
+                    schema,
+                    &json_schema,
+                    &[("min_length", "minProperties"), ("max_length", "maxProperties")],
+                )?;
+                Ok(json_schema)
+            }
+            "nullable" => {
+                let inner = self.inner(schema, "schema")?;
+                let null = self.typed("null")?;
+                self.any_of(vec![inner, null])
+            }
+            "union" => {
+                let choices: Bound<'py, PyList> = schema.get_as_req(intern!(py, "choices"))?;
+                let mut json_choices = Vec::with_capacity(choices.len());
+                for choice in choices {
+                    // choices can be `(schema, label)` tuples
+                    let choice = match choice.downcast::<PyTuple>() {
+                        Ok(tuple) => tuple.get_item(0)?,
+                        Err(_) => choice,
+                    };
+                    json_choices.push(self.generate(choice.downcast()?)?);
+                }
+                self.any_of(json_choices)
+            }
+            "tagged-union" => self.tagged_union(schema),
+            "default" => {
+                let json_schema = self.inner(schema, "schema")?;
+                if let Some(default) = schema.get_item(intern!(py, "default"))? {
+                    if is_json_value(&default) {
+                        json_schema.set_item(intern!(py, "default"), default)?;
+                    }
+                }
+                Ok(json_schema)
+            }
+            "model" | "dataclass" => {
+                let json_schema = self.inner(schema, "schema")?;
+                self.set_title(schema, &json_schema)?;
+                Ok(json_schema)
+            }
+            "model-fields" | "typed-dict" => {
+                let json_schema = self.fields_object(schema)?;
+                if type_ == "typed-dict" {
+                    self.set_title(schema, &json_schema)?;
+                }
+                Ok(json_schema)
+            }
+            "dataclass-args" => self.fields_object(schema),
+            "definitions" => {
+                let definitions: Bound<'py, PyList> = schema.get_as_req(intern!(py, "definitions"))?;
+                for definition in definitions {
+                    self.generate(definition.downcast()?)?;
+                }
+                self.inner(schema, "schema")
+            }
+            "definition-ref" => self.ref_schema(&schema.get_as_req::<String>(intern!(py, "schema_ref"))?),
+            "function-before" | "function-wrap" | "function-plain" => {
+                let input_schema = match self.mode {
+                    JsonSchemaMode::Validation => schema.get_as(intern!(py, "json_schema_input_schema"))?,
+                    JsonSchemaMode::Serialization => None,
+                };
+                match input_schema {
+                    Some(input_schema) => self.generate(&input_schema),
+                    None if type_ == "function-plain" => Ok(PyDict::new(py)),
+                    None => self.inner(schema, "schema"),
+                }
+            }
+            "function-after" | "custom-error" => self.inner(schema, "schema"),
+            "chain" => {
+                let steps: Bound<'py, PyList> = schema.get_as_req(intern!(py, "steps"))?;
+                let step = match self.mode {
+                    JsonSchemaMode::Validation => steps.get_item(0)?,
+                    JsonSchemaMode::Serialization => steps.get_item(steps.len().saturating_sub(1))?,
+                };
+                self.generate(step.downcast()?)
+            }
+            "lax-or-strict" => match self.mode {
+                JsonSchemaMode::Validation => self.inner(schema, "lax_schema"),
+                JsonSchemaMode::Serialization => self.inner(schema, "strict_schema"),
+            },
+            "json-or-python" => self.inner(schema, "json_schema"),
+            "json" => {
+                let inner = self.optional_schema(schema, "schema")?;
+                match self.mode {
+                    JsonSchemaMode::Validation => {
+                        let json_schema = self.typed("string")?;
+                        json_schema.set_item(intern!(py, "contentMediaType"), "application/json")?;
+                        json_schema.set_item(intern!(py, "contentSchema"), inner)?;
+                        Ok(json_schema)
+                    }
+                    JsonSchemaMode::Serialization => Ok(inner),
+                }
+            }
+            _ => py_schema_err!("Cannot generate a JSON Schema for `{}` schemas", type_),
+        }
+    }
+
+    /// The JSON Schema of the serialized value if the schema has a custom serializer which changes it.
+    fn generate_serialization(&mut self, schema: &Bound<'py, PyDict>) -> PyResult<Option<Bound<'py, PyDict>>> {
+        let py = self.py;
+        let Some(serialization) = schema.get_as::<Bound<'py, PyDict>>(intern!(py, "serialization"))? else {
+            return Ok(None);
+        };
+        if let Some(return_schema) = serialization.get_as::<Bound<'py, PyDict>>(intern!(py, "return_schema"))? {
+            return self.generate(&return_schema).map(Some);
+        }
+        let ser_type: String = serialization.get_as_req(intern!(py, "type"))?;
+        match ser_type.as_str() {
+            "function-plain" => Ok(Some(PyDict::new(py))),
+            "to-string" | "format" => self.typed("string").map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    fn inner(&mut self, schema: &Bound<'py, PyDict>, key: &str) -> PyResult<Bound<'py, PyDict>> {
+        self.generate(&schema.get_as_req(&PyString::new(self.py, key))?)
+    }
+
+    /// The JSON Schema of an optional nested schema, which accepts anything if it's missing.
+    fn optional_schema(&mut self, schema: &Bound<'py, PyDict>, key: &str) -> PyResult<Bound<'py, PyDict>> {
+        match schema.get_as::<Bound<'py, PyDict>>(&PyString::new(self.py, key))? {
+            Some(inner) => self.generate(&inner),
+            None => Ok(PyDict::new(self.py)),
+        }
+    }
+
+    fn ref_schema(&self, schema_ref: &str) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = PyDict::new(self.py);
+        json_schema.set_item(intern!(self.py, "$ref"), self.ref_template.replace("{ref}", schema_ref))?;
+        Ok(json_schema)
+    }
+
+    fn typed(&self, json_type: &str) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = PyDict::new(self.py);
+        json_schema.set_item(intern!(self.py, "type"), json_type)?;
+        Ok(json_schema)
+    }
+
+    fn formatted_string(&self, format: &str) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = self.typed("string")?;
+        json_schema.set_item(intern!(self.py, "format"), format)?;
+        Ok(json_schema)
+    }
+
+    fn temporal_string(&self, schema: &Bound<'py, PyDict>, format: &str) -> PyResult<Bound<'py, PyDict>> {
+        // inputs in a custom `format` aren't ISO 8601, outputs still are
+        if self.mode == JsonSchemaMode::Validation && schema.contains(intern!(self.py, "format"))? {
+            return self.typed("string");
+        }
+        self.formatted_string(format)
+    }
+
+    fn any_of(&self, choices: Vec<Bound<'py, PyDict>>) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = PyDict::new(self.py);
+        json_schema.set_item(intern!(self.py, "anyOf"), PyList::new(self.py, choices)?)?;
+        Ok(json_schema)
+    }
+
+    /// Copy each of `keys` which is set on `schema` to `json_schema`, as `(schema key, JSON Schema key)` pairs.
+    fn copy_items(
+        &self,
+        schema: &Bound<'py, PyDict>,
+        json_schema: &Bound<'py, PyDict>,
+        keys: &[(&str, &str)],
+    ) -> PyResult<()> {
+        for (key, json_key) in keys {
+            if let Some(value) = schema.get_item(key)? {
+                json_schema.set_item(json_key, value)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn add_number_constraints<T: PartialOrd + IntoPyObject<'py>>(
+        &self,
+        schema: &Bound<'py, PyDict>,
+        json_schema: &Bound<'py, PyDict>,
+        parse_bound: impl Fn(&str) -> Option<T>,
+    ) -> PyResult<()> {
+        let py = self.py;
+        self.copy_items(
+            schema,
+            json_schema,
+            &[
+                ("multiple_of", "multipleOf"),
+                ("le", "maximum"),
+                ("lt", "exclusiveMaximum"),
+                ("ge", "minimum"),
+                ("gt", "exclusiveMinimum"),
+            ],
+        )?;
+        if let Some(interval) = schema.get_as::<String>(intern!(py, "interval"))? {
+            let IntervalBounds { le, lt, ge, gt } = parse_interval(&interval, parse_bound)?;
+            for (json_key, bound) in [
+                ("maximum", le),
+                ("exclusiveMaximum", lt),
+                ("minimum", ge),
+                ("exclusiveMinimum", gt),
+            ] {
+                if let Some(bound) = bound {
+                    json_schema.set_item(json_key, bound)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn literal(&self, expected: &Bound<'py, PyList>) -> PyResult<Bound<'py, PyDict>> {
+        let py = self.py;
+        let values = expected
+            .iter()
+            .map(|value| enum_value(&value))
+            .collect::<PyResult<Vec<_>>>()?;
+        let json_schema = match values.as_slice() {
+            [value] => {
+                let json_schema = PyDict::new(py);
+                json_schema.set_item(intern!(py, "const"), value)?;
+                json_schema
+            }
+            _ => {
+                let json_schema = PyDict::new(py);
+                json_schema.set_item(intern!(py, "enum"), PyList::new(py, &values)?)?;
+                json_schema
+            }
+        };
+        // include the type if all values have the same one
+        let mut types = values.iter().map(json_type);
+        if let Some(Some(first)) = types.next() {
+            if types.all(|json_type| json_type == Some(first)) {
+                json_schema.set_item(intern!(py, "type"), first)?;
+            }
+        }
+        Ok(json_schema)
+    }
+
+    fn enum_(&self, schema: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let py = self.py;
+        let members: Bound<'py, PyList> = schema.get_as_req(intern!(py, "members"))?;
+        let values = members
+            .iter()
+            .map(|member| member.getattr(intern!(py, "value")))
+            .collect::<PyResult<Vec<_>>>()?;
+        let json_schema = PyDict::new(py);
+        json_schema.set_item(intern!(py, "enum"), PyList::new(py, values)?)?;
+        let sub_type: Option<String> = schema.get_as(intern!(py, "sub_type"))?;
+        match sub_type.as_deref() {
+            Some("str") => json_schema.set_item(intern!(py, "type"), "string")?,
+            Some("int") => json_schema.set_item(intern!(py, "type"), "integer")?,
+            Some("float") => json_schema.set_item(intern!(py, "type"), "number")?,
+            _ => {}
+        }
+        self.set_title(schema, &json_schema)?;
+        Ok(json_schema)
+    }
+
+    fn tuple(&mut self, schema: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let py = self.py;
+        let items_schema: Bound<'py, PyList> = schema.get_as_req(intern!(py, "items_schema"))?;
+        let mut items = items_schema
+            .iter()
+            .map(|item| self.generate(item.downcast()?))
+            .collect::<PyResult<Vec<_>>>()?;
+        let variadic_item_index: Option<usize> = schema.get_as(intern!(py, "variadic_item_index"))?;
+        let json_schema = self.typed("array")?;
+        match variadic_item_index {
+            Some(index) if index + 1 == items.len() => {
+                let variadic = items.pop();
+                json_schema.set_item(intern!(py, "items"), variadic)?;
+                json_schema.set_item(intern!(py, "minItems"), items.len())?;
+            }
+            Some(_) => {
+                return py_schema_err!("Cannot generate a JSON Schema for tuples with items after a variadic item")
+            }
+            None => {
+                json_schema.set_item(intern!(py, "minItems"), items.len())?;
+                json_schema.set_item(intern!(py, "maxItems"), items.len())?;
+                // `prefixItems` alone would allow extra items
+                json_schema.set_item(intern!(py, "items"), false)?;
+            }
+        }
+        if !items.is_empty() {
+            json_schema.set_item(intern!(py, "prefixItems"), PyList::new(py, items)?)?;
+        }
+        self.copy_items(
+            schema,
+            &json_schema,
+            &[("min_length", "minItems"), ("max_length", "maxItems")],
+        )?;
+        Ok(json_schema)
+    }
+
+    fn tagged_union(&mut self, schema: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let py = self.py;
+        let choices: Bound<'py, PyDict> = schema.get_as_req(intern!(py, "choices"))?;
+        let mut json_choices: Vec<Bound<'py, PyDict>> = Vec::with_capacity(choices.len());
+        let mapping = PyDict::new(py);
+        for (tag, choice) in &choices {
+            let json_choice = self.generate(choice.downcast()?)?;
+            if let Some(json_ref) = json_choice.get_item(intern!(py, "$ref"))? {
+                let tag = enum_value(&tag)?;
+                if tag.is_instance_of::<PyString>() {
+                    mapping.set_item(tag, &json_ref)?;
+                }
+                // several tags can map to the same choice
+                let mut duplicate = false;
+                for existing in &json_choices {
+                    if let Some(existing_ref) = existing.get_item(intern!(py, "$ref"))? {
+                        duplicate |= existing_ref.eq(&json_ref)?;
+                    }
+                }
+                if duplicate {
+                    continue;
+                }
+            }
+            json_choices.push(json_choice);
+        }
+        let json_schema = PyDict::new(py);
+        let all_refs = mapping.len() == choices.len();
+        json_schema.set_item(intern!(py, "oneOf"), PyList::new(py, json_choices)?)?;
+        if let Ok(discriminator) = schema.get_as_req::<Bound<'py, PyString>>(intern!(py, "discriminator")) {
+            if all_refs {
+                let json_discriminator = PyDict::new(py);
+                json_discriminator.set_item(intern!(py, "propertyName"), discriminator)?;
+                json_discriminator.set_item(intern!(py, "mapping"), mapping)?;
+                json_schema.set_item(intern!(py, "discriminator"), json_discriminator)?;
+            }
+        }
+        Ok(json_schema)
+    }
+
+    /// An object with the fields of a model, typed dict or dataclass as its properties.
+    fn fields_object(&mut self, schema: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let py = self.py;
+        let properties = PyDict::new(py);
+        let required = PyList::empty(py);
+        let total: bool = schema.get_as(intern!(py, "total"))?.unwrap_or(true);
+        let aliases = FieldAliases::from_schema(schema)?;
+
+        let fields = schema.get_as_req::<Bound<'py, PyAny>>(intern!(py, "fields"))?;
+        let fields: Vec<(Bound<'py, PyAny>, Bound<'py, PyDict>)> = if let Ok(fields) = fields.downcast::<PyDict>() {
+            fields
+                .iter()
+                .map(|(name, field)| Ok((name, field.downcast_into::<PyDict>()?)))
+                .collect::<PyResult<_>>()?
+        } else {
+            fields
+                .try_iter()?
+                .map(|field| {
+                    let field = field?.downcast_into::<PyDict>()?;
+                    Ok((field.get_as_req(intern!(py, "name"))?, field))
+                })
+                .collect::<PyResult<_>>()?
+        };
+
+        for (name, field) in fields {
+            let name: String = name.extract()?;
+            match self.mode {
+                JsonSchemaMode::Validation if field.get_as(intern!(py, "init"))? == Some(false) => continue,
+                JsonSchemaMode::Serialization
+                    if field.get_as(intern!(py, "serialization_exclude"))? == Some(true)
+                        || field.get_as(intern!(py, "init_only"))? == Some(true) =>
+                {
+                    continue
+                }
+                _ => {}
+            }
+            let field_schema: Bound<'py, PyDict> = field.get_as_req(intern!(py, "schema"))?;
+            let has_default = matches!(
+                field_schema.get_as::<String>(intern!(py, "type"))?.as_deref(),
+                Some("default" | "const")
+            );
+            let is_required = match field.get_as::<bool>(intern!(py, "required"))? {
+                Some(required) => required,
+                None => total && !has_default,
+            };
+            let json_field = self.generate(&field_schema)?;
+            if !json_field.contains(intern!(py, "$ref"))? {
+                json_field.set_item(intern!(py, "title"), field_title(&name))?;
+            }
+            let property_name = self.property_name(&field, name, &aliases)?;
+            if is_required {
+                required.append(&property_name)?;
+            }
+            properties.set_item(property_name, json_field)?;
+        }
+
+        if self.mode == JsonSchemaMode::Serialization {
+            if let Some(computed_fields) = schema.get_as::<Bound<'py, PyList>>(intern!(py, "computed_fields"))? {
+                for computed_field in computed_fields {
+                    let computed_field = computed_field.downcast::<PyDict>()?;
+                    let name: String = computed_field.get_as_req(intern!(py, "property_name"))?;
+                    let json_field = self.inner(computed_field, "return_schema")?;
+                    json_field.set_item(intern!(py, "readOnly"), true)?;
+                    if !json_field.contains(intern!(py, "$ref"))? {
+                        json_field.set_item(intern!(py, "title"), field_title(&name))?;
+                    }
+                    let property_name = match computed_field.get_as::<String>(intern!(py, "alias"))? {
+                        Some(alias) if self.by_alias => alias,
+                        _ => name,
+                    };
+                    required.append(&property_name)?;
+                    properties.set_item(property_name, json_field)?;
+                }
+            }
+        }
+
+        let json_schema = self.typed("object")?;
+        json_schema.set_item(intern!(py, "properties"), properties)?;
+        if !required.is_empty() {
+            json_schema.set_item(intern!(py, "required"), required)?;
+        }
+        let extra_behavior = match schema.get_as::<String>(intern!(py, "extra_behavior"))? {
+            Some(extra_behavior) => Some(extra_behavior),
+            None => schema
+                .get_as::<Bound<'py, PyDict>>(intern!(py, "config"))?
+                .map(|config| config.get_as::<String>(intern!(py, "extra_fields_behavior")))
+                .transpose()?
+                .flatten(),
+        };
+        match extra_behavior.as_deref() {
+            Some("forbid") => json_schema.set_item(intern!(py, "additionalProperties"), false)?,
+            Some("allow") => {
+                let extras = match schema.get_as::<Bound<'py, PyDict>>(intern!(py, "extras_schema"))? {
+                    Some(extras_schema) => self.generate(&extras_schema)?.into_any(),
+                    None => PyBool::new(py, true).to_owned().into_any(),
+                };
+                json_schema.set_item(intern!(py, "additionalProperties"), extras)?;
+            }
+            _ => {}
+        }
+        Ok(json_schema)
+    }
+
+    /// The name of a field in the JSON Schema, its alias for the current mode if `by_alias` is set.
+    fn property_name(&self, field: &Bound<'py, PyDict>, name: String, aliases: &FieldAliases<'py>) -> PyResult<String> {
+        let py = self.py;
+        if !self.by_alias {
+            return Ok(name);
+        }
+        let alias = match self.mode {
+            JsonSchemaMode::Validation => {
+                match aliases.get(field, intern!(py, "validation_alias"), &name)? {
+                    // for alias paths or choices of paths, use the first item of the first path if it's a string
+                    Some(alias) => first_alias(&alias)?,
+                    None => None,
+                }
+            }
+            JsonSchemaMode::Serialization => match aliases.get(field, intern!(py, "serialization_alias"), &name)? {
+                Some(alias) => Some(alias.extract()?),
+                None => None,
+            },
+        };
+        Ok(alias.unwrap_or(name))
+    }
+
+    /// Set the title of a model, typed dict, dataclass or enum, from its config or class name.
+    fn set_title(&self, schema: &Bound<'py, PyDict>, json_schema: &Bound<'py, PyDict>) -> PyResult<()> {
+        let py = self.py;
+        if json_schema.contains(intern!(py, "title"))? {
+            return Ok(());
+        }
+        let config_title = schema
+            .get_as::<Bound<'py, PyDict>>(intern!(py, "config"))?
+            .map(|config| config.get_as::<String>(intern!(py, "title")))
+            .transpose()?
+            .flatten();
+        let title = match config_title {
+            Some(title) => Some(title),
+            None => schema
+                .get_as::<Bound<'py, PyType>>(intern!(py, "cls"))?
+                .map(|cls| cls.name().map(|name| name.to_string()))
+                .transpose()?,
+        };
+        if let Some(title) = title {
+            json_schema.set_item(intern!(py, "title"), title)?;
+        }
+        Ok(())
+    }
+}
+
+fn first_alias(alias: &Bound<'_, PyAny>) -> PyResult<Option<String>> {
+    if let Ok(alias) = alias.downcast::<PyString>() {
+        return Ok(Some(alias.to_string()));
+    }
+    match alias.downcast::<PyList>() {
+        Ok(items) if !items.is_empty() => first_alias(&items.get_item(0)?),
+        _ => Ok(None),
+    }
+}
+
+/// The value of an enum member, other values are returned unchanged.
+fn enum_value<'py>(value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let py = value.py();
+    let enum_type = py.import(intern!(py, "enum"))?.getattr(intern!(py, "Enum"))?;
+    if value.is_instance(&enum_type)? {
+        value.getattr(intern!(py, "value"))
+    } else {
+        Ok(value.clone())
+    }
+}
+
+fn json_type(value: &Bound<'_, PyAny>) -> Option<&'static str> {
+    if value.is_none() {
+        Some("null")
+    } else if value.is_instance_of::<PyBool>() {
+        Some("boolean")
+    } else if value.is_instance_of::<PyInt>() {
+        Some("integer")
+    } else if value.is_instance_of::<PyFloat>() {
+        Some("number")
+    } else if value.is_instance_of::<PyString>() {
+        Some("string")
+    } else {
+        None
+    }
+}
+
+/// Whether a value can be included as-is in a JSON Schema, e.g. as a default.
+fn is_json_value(value: &Bound<'_, PyAny>) -> bool {
+    if json_type(value).is_some() {
+        true
+    } else if let Ok(list) = value.downcast::<PyList>() {
+        list.iter().all(|item| is_json_value(&item))
+    } else if let Ok(dict) = value.downcast::<PyDict>() {
+        dict.iter()
+            .all(|(key, value)| key.is_instance_of::<PyString>() && is_json_value(&value))
+    } else {
+        false
+    }
+}
+
+/// The default title of a field, e.g. `'First Name'` for `first_name`, the same as `name.title().replace('_', ' ')`.
+fn field_title(name: &str) -> String {
+    let mut title = String::with_capacity(name.len());
+    let mut previous_cased = false;
+    for c in name.chars() {
+        if c.is_alphabetic() {
+            if previous_cased {
+                title.extend(c.to_lowercase());
+            } else {
+                title.extend(c.to_uppercase());
+            }
+            previous_cased = true;
+        } else {
+            title.push(if c == '_' { ' ' } else { c });
+            previous_cased = false;
+        }
+    }
+    title
+}
@@ -6,6 +6,7 @@ use std::sync::OnceLock;
 
 use jiter::{map_json_error, FloatMode, PartialMode, PythonParse, StringCacheMode};
 use pyo3::exceptions::PyTypeError;
+use pyo3::types::{PyBytes, PyDict, PyString};
 use pyo3::{prelude::*, sync::PyOnceLock};
 use serializers::BytesMode;
 use validators::ValBytesMode;
@@ -20,26 +21,41 @@ mod common;
 mod definitions;
 mod errors;
 mod input;
+mod json_schema;
 mod lookup_key;
+mod plugin;
 mod recursion_guard;
+mod schema_check;
+mod semver;
 mod serializers;
+mod string_cache;
 mod tools;
+mod ulid;
 mod url;
 mod validators;
 
 // required for benchmarks
 pub use self::input::TzInfo;
+pub use self::semver::PyVersion;
+pub use self::ulid::PyUlid;
 pub use self::url::{PyMultiHostUrl, PyUrl};
 pub use argument_markers::{ArgsKwargs, PydanticUndefinedType};
 pub use build_tools::SchemaError;
 pub use errors::{
-    list_all_errors, PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticUseDefault, ValidationError,
+    list_all_errors, register_error_type, PydanticCustomError, PydanticKnownError, PydanticMultipleErrors,
+    PydanticOmit, PydanticUseDefault, ValidationError,
 };
+pub use json_schema::{JsonSchemaMode, SchemaJsonifier};
+pub use plugin::register_plugin;
+pub use schema_check::check_schema;
 pub use serializers::{
     to_json, to_jsonable_python, PydanticSerializationError, PydanticSerializationUnexpectedValue, SchemaSerializer,
     WarningsArg,
 };
-pub use validators::{PySome, SchemaValidator};
+pub use string_cache::{clear_string_cache, set_string_cache_size, string_cache_info};
+pub use validators::{
+    ArrowBatch, IncrementalValidation, PySome, SchemaValidator, StrictScope, ValidationOptions, ValidationResult,
+};
 
 use crate::input::Input;
 
@@ -68,6 +84,21 @@ pub fn from_json<'py>(
         .map_err(|e| map_json_error(json_bytes, &e))
 }
 
+#[pyfunction]
+pub fn compare_secrets(a: &Bound<'_, PyAny>, b: &Bound<'_, PyAny>) -> PyResult<bool> {
+    if let (Ok(a), Ok(b)) = (a.downcast::<PyString>(), b.downcast::<PyString>()) {
+        Ok(tools::constant_time_eq(a.to_str()?.as_bytes(), b.to_str()?.as_bytes()))
+    } else if let (Ok(a), Ok(b)) = (a.downcast::<PyBytes>(), b.downcast::<PyBytes>()) {
+        Ok(tools::constant_time_eq(a.as_bytes(), b.as_bytes()))
+    } else {
+        Err(PyTypeError::new_err(format!(
+            "unsupported operand types for compare_secrets: '{}' and '{}', expected two `str` or two `bytes`",
+            a.get_type().name()?,
+            b.get_type().name()?
+        )))
+    }
+}
+
 pub fn get_pydantic_core_version() -> &'static str {
     static PYDANTIC_CORE_VERSION: OnceLock<String> = OnceLock::new();
 
@@ -106,6 +137,30 @@ pub fn build_info() -> String {
     )
 }
 
+/// The features and limits compiled into this build, see `capabilities` in `_pydantic_core.pyi`.
+#[pyfunction]
+pub fn capabilities(py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+    let capabilities = PyDict::new(py);
+    capabilities.set_item("version", get_pydantic_core_version())?;
+    capabilities.set_item("build_profile", env!("PROFILE"))?;
+    capabilities.set_item("pgo", cfg!(specified_profile_use))?;
+    capabilities.set_item("free_threading", cfg!(Py_GIL_DISABLED))?;
+    capabilities.set_item("input_formats", ["python", "json", "strings"])?;
+    capabilities.set_item("output_formats", ["python", "json", "jsonl", "msgpack"])?;
+    capabilities.set_item("compression", serializers::COMPRESSION_CODECS)?;
+    capabilities.set_item("regex_engines", ["rust-regex", "python-re"])?;
+    // jiter only has SIMD number and string parsing on aarch64
+    let simd: &[&str] = if cfg!(target_arch = "aarch64") { &["json"] } else { &[] };
+    capabilities.set_item("simd", simd)?;
+
+    let limits = PyDict::new(py);
+    limits.set_item("recursion_limit", recursion_guard::RECURSION_GUARD_LIMIT)?;
+    // jiter's `DEFAULT_RECURSION_LIMIT`, it isn't public
+    limits.set_item("json_max_depth", 200)?;
+    capabilities.set_item("limits", limits)?;
+    Ok(capabilities)
+}
+
 #[pymodule(gil_used = false)]
 pub mod _pydantic_core {
     #[allow(clippy::wildcard_imports)]
@@ -113,10 +168,12 @@ pub mod _pydantic_core {
 
     #[pymodule_export]
     use crate::{
-        from_json, list_all_errors, to_json, to_jsonable_python, ArgsKwargs, PyMultiHostUrl, PySome, PyUrl,
-        PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticSerializationError,
-        PydanticSerializationUnexpectedValue, PydanticUndefinedType, PydanticUseDefault, SchemaError, SchemaSerializer,
-        SchemaValidator, TzInfo, ValidationError,
+        capabilities, check_schema, clear_string_cache, compare_secrets, from_json, list_all_errors,
+        register_error_type, register_plugin, set_string_cache_size, string_cache_info, to_json, to_jsonable_python,
+        ArgsKwargs, ArrowBatch, IncrementalValidation, PyMultiHostUrl, PySome, PyUlid, PyUrl, PyVersion,
+        PydanticCustomError, PydanticKnownError, PydanticMultipleErrors, PydanticOmit, PydanticSerializationError,
+        PydanticSerializationUnexpectedValue, PydanticUndefinedType, PydanticUseDefault, SchemaError, SchemaJsonifier,
+        SchemaSerializer, SchemaValidator, TzInfo, ValidationError, ValidationResult,
     };
 
     #[pymodule_init]
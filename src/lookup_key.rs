@@ -1,27 +1,32 @@
+use std::borrow::Cow;
 use std::convert::Infallible;
 use std::fmt;
+use std::sync::{Mutex, PoisonError};
 
 use pyo3::exceptions::{PyAttributeError, PyTypeError};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyMapping, PyString};
+use pyo3::types::{PyDict, PyList, PyMapping, PyString, PyTuple, PyType};
 use pyo3::IntoPyObjectExt;
+#[cfg(not(any(PyPy, GraalPy, Py_LIMITED_API, Py_GIL_DISABLED)))]
+use pyo3::{ffi, intern, sync::PyOnceLock};
 
-use jiter::{JsonObject, JsonValue};
+use jiter::{JsonArray, JsonObject, JsonValue};
 
 use crate::build_tools::py_schema_err;
 use crate::errors::{py_err_string, ErrorType, LocItem, Location, ToErrorValue, ValError, ValLineError, ValResult};
 use crate::input::StringMapping;
-use crate::tools::{extract_i64, mapping_get, py_err};
+use crate::tools::{extract_i64, mapping_get, py_err, safe_repr};
 
 /// Used for getting items from python dicts, python objects, or JSON objects, in different ways
 #[derive(Debug)]
-pub(crate) enum LookupKey {
+pub enum LookupKey {
     /// simply look up a key in a dict, equivalent to `d.get(key)`
     Simple(LookupPath),
     /// look up a key by either string, equivalent to `d.get(choice1, d.get(choice2))`
     Choice { path1: LookupPath, path2: LookupPath },
     /// look up keys by one or more "paths" a path might be `['foo', 'bar']` to get `d.?foo.?bar`
-    /// ints are also supported to index arrays/lists/tuples and dicts with int keys
+    /// ints are also supported to index arrays/lists/tuples and dicts with int keys, and `'*'` after the first
+    /// item to collect the rest of the path from every item of a dict or list into a list
     /// we reuse Location as the enum is the same, and the meaning is the same
     PathChoices(Vec<LookupPath>),
 }
@@ -46,6 +51,15 @@ impl fmt::Display for LookupKey {
 }
 
 impl LookupKey {
+    /// The first key of each of the paths, the keys the value can be under in a dict
+    pub fn first_keys(&self) -> Vec<&str> {
+        match self {
+            Self::Simple(path) => vec![path.first_key()],
+            Self::Choice { path1, path2 } => vec![path1.first_key(), path2.first_key()],
+            Self::PathChoices(paths) => paths.iter().map(LookupPath::first_key).collect(),
+        }
+    }
+
     pub fn from_py(py: Python, value: &Bound<'_, PyAny>, alt_alias: Option<&str>) -> PyResult<Self> {
         if let Ok(alias_py) = value.downcast::<PyString>() {
             let alias: String = alias_py.extract()?;
@@ -89,7 +103,7 @@ impl LookupKey {
     pub fn py_get_dict_item<'py, 's>(
         &'s self,
         dict: &Bound<'py, PyDict>,
-    ) -> PyResult<Option<(&'s LookupPath, Bound<'py, PyAny>)>> {
+    ) -> PyResult<Option<(FoundPath<'s>, Bound<'py, PyAny>)>> {
         self.get_impl(
             dict,
             |dict, path| dict.get_item(&path.py_key),
@@ -100,9 +114,10 @@ impl LookupKey {
     pub fn py_get_string_mapping_item<'py, 's>(
         &'s self,
         dict: &Bound<'py, PyDict>,
-    ) -> ValResult<Option<(&'s LookupPath, StringMapping<'py>)>> {
+        env_style: bool,
+    ) -> ValResult<Option<(FoundPath<'s>, StringMapping<'py>)>> {
         if let Some((path, py_any)) = self.py_get_dict_item(dict)? {
-            let value = StringMapping::new_value(py_any)?;
+            let value = StringMapping::new_value(py_any, env_style)?;
             Ok(Some((path, value)))
         } else {
             Ok(None)
@@ -112,7 +127,7 @@ impl LookupKey {
     pub fn py_get_mapping_item<'py, 's>(
         &'s self,
         dict: &Bound<'py, PyMapping>,
-    ) -> PyResult<Option<(&'s LookupPath, Bound<'py, PyAny>)>> {
+    ) -> PyResult<Option<(FoundPath<'s>, Bound<'py, PyAny>)>> {
         self.get_impl(
             dict,
             |dict, path| mapping_get(dict, &path.py_key),
@@ -123,19 +138,15 @@ impl LookupKey {
     pub fn simple_py_get_attr<'py, 's>(
         &'s self,
         obj: &Bound<'py, PyAny>,
-    ) -> PyResult<Option<(&'s LookupPath, Bound<'py, PyAny>)>> {
-        self.get_impl(
-            obj,
-            |obj, path| py_get_attrs(obj, &path.py_key),
-            |d, loc| loc.py_get_attrs(&d),
-        )
+    ) -> PyResult<Option<(FoundPath<'s>, Bound<'py, PyAny>)>> {
+        self.get_impl(obj, |obj, path| path.getattr(obj), |d, loc| loc.py_get_attrs(&d))
     }
 
     pub fn py_get_attr<'py, 's>(
         &'s self,
         obj: &Bound<'py, PyAny>,
         kwargs: Option<&Bound<'py, PyDict>>,
-    ) -> ValResult<Option<(&'s LookupPath, Bound<'py, PyAny>)>> {
+    ) -> ValResult<Option<(FoundPath<'s>, Bound<'py, PyAny>)>> {
         if let Some(dict) = kwargs {
             if let Ok(Some(item)) = self.py_get_dict_item(dict) {
                 return Ok(Some(item));
@@ -157,7 +168,7 @@ impl LookupKey {
     pub fn json_get<'a, 'data, 's>(
         &'s self,
         dict: &'a JsonObject<'data>,
-    ) -> ValResult<Option<(&'s LookupPath, &'a JsonValue<'data>)>> {
+    ) -> ValResult<Option<(FoundPath<'s>, Cow<'a, JsonValue<'data>>)>> {
         // FIXME: use of find_map in here probably leads to quadratic complexity
         match self {
             Self::Simple(path) => match dict
@@ -167,7 +178,10 @@ impl LookupKey {
             {
                 Some(value) => {
                     debug_assert!(path.rest.is_empty());
-                    Ok(Some((path, value)))
+                    Ok(Some((
+                        FoundPath::new(path, WildcardPositions::default()),
+                        Cow::Borrowed(value),
+                    )))
                 }
                 None => Ok(None),
             },
@@ -178,7 +192,10 @@ impl LookupKey {
             {
                 Some(value) => {
                     debug_assert!(path1.rest.is_empty());
-                    Ok(Some((path1, value)))
+                    Ok(Some((
+                        FoundPath::new(path1, WildcardPositions::default()),
+                        Cow::Borrowed(value),
+                    )))
                 }
                 None => match dict
                     .iter()
@@ -187,7 +204,10 @@ impl LookupKey {
                 {
                     Some(value) => {
                         debug_assert!(path2.rest.is_empty());
-                        Ok(Some((path2, value)))
+                        Ok(Some((
+                            FoundPath::new(path2, WildcardPositions::default()),
+                            Cow::Borrowed(value),
+                        )))
                     }
                     None => Ok(None),
                 },
@@ -205,14 +225,12 @@ impl LookupKey {
                         None => continue,
                     };
 
-                    let mut path_iter = path.rest.iter();
-
                     // similar to above
                     // iterate over the path and plug each value into the JsonValue from the last step, starting with v
-                    // from the first step, this could just be a loop but should be somewhat faster with a functional design
-                    if let Some(v) = path_iter.try_fold(v, |d, loc| loc.json_get(d)) {
+                    // from the first step
+                    if let Some((v, positions)) = json_get_rest(v, &path.rest) {
                         // Successfully found an item, return it
-                        return Ok(Some((path, v)));
+                        return Ok(Some((FoundPath::new(path, positions), v)));
                     }
                 }
                 // got to the end of path_choices, without a match, return None
@@ -221,50 +239,45 @@ impl LookupKey {
         }
     }
 
-    fn get_impl<'s, 'a, SourceT, OutputT: 'a>(
+    fn get_impl<'s, 'a, 'py, SourceT>(
         &'s self,
         source: &'a SourceT,
-        lookup: impl Fn(&'a SourceT, &'s PathItemString) -> PyResult<Option<OutputT>>,
-        nested_lookup: impl Fn(OutputT, &'s PathItem) -> PyResult<Option<OutputT>>,
-    ) -> PyResult<Option<(&'s LookupPath, OutputT)>> {
+        lookup: impl Fn(&'a SourceT, &'s PathItemString) -> PyResult<Option<Bound<'py, PyAny>>>,
+        nested_lookup: impl Fn(Bound<'py, PyAny>, &'s PathItem) -> PyResult<Option<Bound<'py, PyAny>>>,
+    ) -> PyResult<Option<(FoundPath<'s>, Bound<'py, PyAny>)>> {
         match self {
             Self::Simple(path) => match lookup(source, &path.first_item)? {
                 Some(value) => {
                     debug_assert!(path.rest.is_empty());
-                    Ok(Some((path, value)))
+                    Ok(Some((FoundPath::new(path, WildcardPositions::default()), value)))
                 }
                 None => Ok(None),
             },
             Self::Choice { path1, path2, .. } => match lookup(source, &path1.first_item)? {
                 Some(value) => {
                     debug_assert!(path1.rest.is_empty());
-                    Ok(Some((path1, value)))
+                    Ok(Some((FoundPath::new(path1, WildcardPositions::default()), value)))
                 }
                 None => match lookup(source, &path2.first_item)? {
                     Some(value) => {
                         debug_assert!(path2.rest.is_empty());
-                        Ok(Some((path2, value)))
+                        Ok(Some((FoundPath::new(path2, WildcardPositions::default()), value)))
                     }
                     None => Ok(None),
                 },
             },
             Self::PathChoices(path_choices) => {
-                'choices: for path in path_choices {
-                    let Some(mut value) = lookup(source, &path.first_item)? else {
+                for path in path_choices {
+                    let Some(value) = lookup(source, &path.first_item)? else {
                         continue;
                     };
 
                     // iterate over the path and plug each value into the value from the last step
-                    for loc in &path.rest {
-                        value = match nested_lookup(value, loc) {
-                            Ok(Some(v)) => v,
-                            // this choice did not match, try the next one
-                            Ok(None) => continue 'choices,
-                            Err(e) => return Err(e),
-                        }
+                    if let Some((value, positions)) = py_get_rest(value, &path.rest, &nested_lookup)? {
+                        // Successfully found an item, return it
+                        return Ok(Some((FoundPath::new(path, positions), value)));
                     }
-                    // Successfully found an item, return it
-                    return Ok(Some((path, value)));
+                    // this choice did not match, try the next one
                 }
                 // got to the end of path_choices, without a match, return None
                 Ok(None)
@@ -300,7 +313,7 @@ impl LookupKey {
 }
 
 #[derive(Debug)]
-pub(crate) struct LookupPath {
+pub struct LookupPath {
     /// All paths must start with a string key
     first_item: PathItemString,
     /// Most paths will have no extra items, though some do so we encode this here
@@ -324,15 +337,12 @@ impl LookupPath {
             None => PyString::new(py, key),
         };
         Self {
-            first_item: PathItemString {
-                key: key.to_string(),
-                py_key: py_key.clone().unbind(),
-            },
+            first_item: PathItemString::new(key.to_string(), py_key),
             rest: Vec::new(),
         }
     }
 
-    fn from_list(obj: &Bound<'_, PyAny>) -> PyResult<LookupPath> {
+    pub(crate) fn from_list(obj: &Bound<'_, PyAny>) -> PyResult<LookupPath> {
         let mut iter = obj.downcast::<PyList>()?.iter();
 
         let Some(first_item) = iter.next() else {
@@ -343,10 +353,7 @@ impl LookupPath {
             return py_err!(PyTypeError; "The first item in an alias path should be a string");
         };
 
-        let first_item = PathItemString {
-            key: first_item_py_str.to_str()?.to_owned(),
-            py_key: first_item_py_str.clone().unbind(),
-        };
+        let first_item = PathItemString::new(first_item_py_str.to_str()?.to_owned(), first_item_py_str);
 
         let rest = iter.map(PathItem::from_py).collect::<PyResult<_>>()?;
 
@@ -365,11 +372,51 @@ impl LookupPath {
         }
     }
 
+    /// The location `apply_error_loc` adds to errors of the value found by this path.
+    pub fn loc(&self, loc_by_alias: bool, field_name: &str) -> Location {
+        let mut loc = Location::default();
+        if loc_by_alias {
+            for path_item in self.rest.iter().rev() {
+                loc.with_outer(path_item.to_loc_item());
+            }
+            loc.with_outer(self.first_item.key.as_str().into());
+        } else {
+            loc.with_outer(field_name.into());
+        }
+        loc
+    }
+
+    fn to_py_list<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        let list = PyList::new(py, [&self.first_item])?;
+        for item in &self.rest {
+            list.append(item)?;
+        }
+        Ok(list)
+    }
+
     /// get the `str` from the first item in the path, note paths always have length > 0, and the first item
     /// is always a string
     pub fn first_key(&self) -> &str {
         &self.first_item.key
     }
+
+    /// The number of wildcards in the path, each nests the value found in another list.
+    pub fn wildcards(&self) -> usize {
+        self.rest
+            .iter()
+            .filter(|item| matches!(item, PathItem::Wildcard))
+            .count()
+    }
+
+    /// Look up the path in validated data, e.g. the fields of a model, reading attributes of values which aren't
+    /// dicts, like models nested in fields.
+    pub fn py_get_validated<'py>(&self, data: &Bound<'py, PyDict>) -> PyResult<Option<Bound<'py, PyAny>>> {
+        let Some(value) = data.get_item(&self.first_item.py_key)? else {
+            return Ok(None);
+        };
+        let found = py_get_rest(value, &self.rest, &|value, item: &PathItem| item.py_get_attrs(&value))?;
+        Ok(found.map(|(value, _)| value))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -378,14 +425,31 @@ pub(crate) enum PathItem {
     /// integer key, used to get items from a list, tuple OR a dict with int keys `dict[int, ...]` (python only)
     Pos(usize),
     Neg(usize),
+    /// `'*'`, every item of a dict or list
+    Wildcard,
 }
 
 /// string type key, used to get or identify items from a dict or anything that implements `__getitem__`
 /// we store both the string and pystring to save creating the pystring for python
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub(crate) struct PathItemString {
     key: String,
     py_key: Py<PyString>,
+    attribute: AttributeCache,
+    /// the parts of a dotted key like `'owner.address.city'`, read as successive attributes of objects
+    /// without an attribute named by the whole key
+    dotted: Option<Box<[PathItemString]>>,
+}
+
+// the attribute cache is left out so it doesn't clutter the validator repr
+#[allow(clippy::missing_fields_in_debug)]
+impl fmt::Debug for PathItemString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PathItemString")
+            .field("key", &self.key)
+            .field("py_key", &self.py_key)
+            .finish()
+    }
 }
 
 impl fmt::Display for PathItem {
@@ -394,6 +458,7 @@ impl fmt::Display for PathItem {
             Self::S(key) => key.fmt(f),
             Self::Pos(key) => write!(f, "{key}"),
             Self::Neg(key) => write!(f, "-{key}"),
+            Self::Wildcard => write!(f, "*"),
         }
     }
 }
@@ -417,6 +482,7 @@ impl<'py> IntoPyObject<'py> for &'_ PathItem {
                 let neg_value = -(*val as i64);
                 neg_value.into_bound_py_any(py)
             }
+            PathItem::Wildcard => WILDCARD.into_bound_py_any(py),
         }
     }
 }
@@ -436,10 +502,10 @@ impl PathItem {
         let obj = match obj.downcast_into::<PyString>() {
             Ok(py_str_key) => {
                 let str_key = py_str_key.to_str()?.to_string();
-                return Ok(Self::S(PathItemString {
-                    key: str_key,
-                    py_key: py_str_key.unbind(),
-                }));
+                if str_key == WILDCARD {
+                    return Ok(Self::Wildcard);
+                }
+                return Ok(Self::S(PathItemString::new(str_key, py_str_key)));
             }
             Err(e) => e.into_inner(),
         };
@@ -483,7 +549,7 @@ impl PathItem {
                         None
                     }
                 }
-                Self::S(..) => None,
+                Self::S(..) | Self::Wildcard => None,
             },
             _ => None,
         }
@@ -501,18 +567,216 @@ impl PathItem {
             Self::S(PathItemString { key, .. }) => LocItem::from(key),
             Self::Pos(index) => LocItem::from(*index),
             Self::Neg(index) => LocItem::from(-(*index as i64)),
+            Self::Wildcard => LocItem::from(WILDCARD),
+        }
+    }
+}
+
+/// The path item matching every item of a dict or list, only after the first item of a path.
+const WILDCARD: &str = "*";
+
+/// Look up the rest of a path in `value`, a wildcard collects the rest of the path from each (value of a) item of a
+/// dict, mapping, list or tuple into a list, leaving out items it isn't found in. Where the collected items were is
+/// returned too, so errors can be reported at their position in the input.
+fn py_get_rest<'s, 'py>(
+    mut value: Bound<'py, PyAny>,
+    rest: &'s [PathItem],
+    nested_lookup: &impl Fn(Bound<'py, PyAny>, &'s PathItem) -> PyResult<Option<Bound<'py, PyAny>>>,
+) -> PyResult<Option<(Bound<'py, PyAny>, WildcardPositions)>> {
+    for (index, loc) in rest.iter().enumerate() {
+        if let PathItem::Wildcard = loc {
+            let items: Vec<(LocItem, Bound<'py, PyAny>)> = if let Ok(dict) = value.downcast::<PyDict>() {
+                dict.iter().map(|(k, v)| (py_key_loc_item(&k), v)).collect()
+            } else if let Ok(list) = value.downcast::<PyList>() {
+                list.iter().enumerate().map(|(i, v)| (i.into(), v)).collect()
+            } else if let Ok(tuple) = value.downcast::<PyTuple>() {
+                tuple.iter().enumerate().map(|(i, v)| (i.into(), v)).collect()
+            } else if let Ok(mapping) = value.downcast::<PyMapping>() {
+                mapping
+                    .items()?
+                    .iter()
+                    .map(|item| {
+                        let (k, v) = item.extract::<(Bound<'py, PyAny>, Bound<'py, PyAny>)>()?;
+                        Ok((py_key_loc_item(&k), v))
+                    })
+                    .collect::<PyResult<_>>()?
+            } else {
+                return Ok(None);
+            };
+            let collected = PyList::empty(value.py());
+            let mut positions = Vec::new();
+            for (loc_item, item) in items {
+                if let Some((item, nested)) = py_get_rest(item, &rest[index + 1..], nested_lookup)? {
+                    collected.append(item)?;
+                    positions.push((loc_item, nested));
+                }
+            }
+            return Ok(Some((collected.into_any(), WildcardPositions(positions))));
+        }
+        value = match nested_lookup(value, loc)? {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+    }
+    Ok(Some((value, WildcardPositions::default())))
+}
+
+/// The location of an item found under a key of a dict by a wildcard.
+fn py_key_loc_item(key: &Bound<'_, PyAny>) -> LocItem {
+    if let Ok(key) = key.downcast::<PyString>() {
+        key.to_string_lossy().into()
+    } else if let Some(key) = extract_i64(key) {
+        key.into()
+    } else {
+        safe_repr(key).to_string().into()
+    }
+}
+
+/// Look up the rest of a path in a JSON value, like `py_get_rest`.
+fn json_get_rest<'a, 'data>(
+    value: &'a JsonValue<'data>,
+    rest: &[PathItem],
+) -> Option<(Cow<'a, JsonValue<'data>>, WildcardPositions)> {
+    let mut value = value;
+    for (index, loc) in rest.iter().enumerate() {
+        if let PathItem::Wildcard = loc {
+            let rest = &rest[index + 1..];
+            let found: Vec<(LocItem, JsonValue<'data>, WildcardPositions)> = match value {
+                JsonValue::Object(object) => object
+                    .iter()
+                    .filter_map(|(key, item)| {
+                        json_get_rest(item, rest).map(|(item, nested)| (key.as_ref().into(), item.into_owned(), nested))
+                    })
+                    .collect(),
+                JsonValue::Array(array) => array
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, item)| {
+                        json_get_rest(item, rest).map(|(item, nested)| (i.into(), item.into_owned(), nested))
+                    })
+                    .collect(),
+                _ => return None,
+            };
+            let (collected, positions) = found
+                .into_iter()
+                .map(|(loc_item, item, nested)| (item, (loc_item, nested)))
+                .unzip();
+            return Some((
+                Cow::Owned(JsonValue::Array(JsonArray::new(collected))),
+                WildcardPositions(positions),
+            ));
+        }
+        value = loc.json_get(value)?;
+    }
+    Some((Cow::Borrowed(value), WildcardPositions::default()))
+}
+
+/// Where each item a wildcard collected into a list was in the input, with the positions of the items collected
+/// by the wildcards after it in the path.
+#[derive(Default)]
+pub struct WildcardPositions(Vec<(LocItem, WildcardPositions)>);
+
+/// The path a value was found by, with where the items collected by its wildcards were found.
+pub struct FoundPath<'s> {
+    path: &'s LookupPath,
+    positions: WildcardPositions,
+}
+
+impl<'s> FoundPath<'s> {
+    fn new(path: &'s LookupPath, positions: WildcardPositions) -> Self {
+        Self { path, positions }
+    }
+
+    /// Like `LookupPath::first_key`, borrowed for as long as the path.
+    pub fn first_key(&self) -> &'s str {
+        self.path.first_key()
+    }
+
+    /// Like `LookupPath::apply_error_loc`, by alias each wildcard is replaced by the position of the item the error
+    /// is in, e.g. `items.2.id` rather than `items.*.id.0` if the path skipped two items without an `id`.
+    pub fn apply_error_loc(&self, mut line_error: ValLineError, loc_by_alias: bool, field_name: &str) -> ValLineError {
+        if !loc_by_alias || self.path.wildcards() == 0 {
+            return self.path.apply_error_loc(line_error, loc_by_alias, field_name);
+        }
+        let mut loc_items = Vec::with_capacity(1 + self.path.rest.len());
+        loc_items.push(LocItem::from(&self.path.first_item.key));
+        let mut positions = Some(&self.positions);
+        for path_item in &self.path.rest {
+            if let (PathItem::Wildcard, Some(WildcardPositions(items))) = (path_item, positions) {
+                // the outermost location of the error is the index in the list collected by this wildcard
+                let position = match line_error.location.outer() {
+                    Some(LocItem::I(index)) => usize::try_from(*index).ok().and_then(|index| items.get(index)),
+                    _ => None,
+                };
+                if let Some((loc_item, nested)) = position {
+                    line_error.location.pop_outer();
+                    loc_items.push(loc_item.clone());
+                    positions = Some(nested);
+                    continue;
+                }
+                positions = None;
+            }
+            loc_items.push(path_item.to_loc_item());
         }
+        for loc_item in loc_items.into_iter().rev() {
+            line_error = line_error.with_outer_location(loc_item);
+        }
+        line_error
+    }
+}
+
+impl std::ops::Deref for FoundPath<'_> {
+    type Target = LookupPath;
+
+    fn deref(&self) -> &LookupPath {
+        self.path
     }
 }
 
 impl PathItemString {
+    fn new(key: String, py_key: Bound<'_, PyString>) -> Self {
+        let py = py_key.py();
+        let dotted = if key.contains('.') && key.split('.').all(|part| !part.is_empty()) {
+            let parts = key
+                .split('.')
+                .map(|part| Self::new(part.to_string(), PyString::new(py, part)))
+                .collect();
+            Some(parts)
+        } else {
+            None
+        };
+        Self {
+            key,
+            py_key: py_key.unbind(),
+            attribute: AttributeCache::default(),
+            dotted,
+        }
+    }
+
     fn py_get_attrs<'py>(&self, obj: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyAny>>> {
         // if obj is a dict, we want to use get_item, not getattr
         if obj.downcast::<PyDict>().is_ok() {
             Ok(py_get_item(obj, self))
         } else {
-            py_get_attrs(obj, &self.py_key)
+            self.getattr(obj)
+        }
+    }
+
+    /// Get the attribute named by the key, or for a dotted key which doesn't name an attribute, get each part of the
+    /// key from the value of the last, like the items of a path.
+    fn getattr<'py>(&self, obj: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyAny>>> {
+        let attr = self.attribute.getattr(obj, &self.py_key)?;
+        let (None, Some(parts)) = (&attr, &self.dotted) else {
+            return Ok(attr);
+        };
+        let mut value = obj.clone();
+        for part in parts {
+            value = match part.py_get_attrs(&value)? {
+                Some(v) => v,
+                None => return Ok(None),
+            };
         }
+        Ok(Some(value))
     }
 }
 
@@ -542,6 +806,113 @@ fn py_get_attrs<'py>(obj: &Bound<'py, PyAny>, attr_name: &Py<PyString>) -> PyRes
     }
 }
 
+/// How an attribute is read from objects of the type it was last read from, so attributes stored in
+/// `__slots__` can be read directly, without the `AttributeError` raised by `getattr` for unset slots.
+#[derive(Default)]
+#[cfg_attr(any(PyPy, GraalPy, Py_LIMITED_API, Py_GIL_DISABLED), allow(dead_code))]
+// boxed to keep lookup keys, and so validators, small
+struct AttributeCache(Box<Mutex<Option<CachedAttribute>>>);
+
+#[cfg_attr(any(PyPy, GraalPy, Py_LIMITED_API, Py_GIL_DISABLED), allow(dead_code))]
+struct CachedAttribute {
+    type_: Py<PyType>,
+    // the type's version tag, which changes when the type or its bases are modified
+    version_tag: u32,
+    // the offset of the slot in the object, `None` if the attribute is read with `getattr`,
+    // e.g. because it's a property
+    slot_offset: Option<isize>,
+}
+
+impl Clone for AttributeCache {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl AttributeCache {
+    #[cfg(not(any(PyPy, GraalPy, Py_LIMITED_API, Py_GIL_DISABLED)))]
+    fn getattr<'py>(&self, obj: &Bound<'py, PyAny>, attr_name: &Py<PyString>) -> PyResult<Option<Bound<'py, PyAny>>> {
+        let type_ = obj.get_type();
+        let version_tag = || unsafe { (*type_.as_type_ptr()).tp_version_tag };
+        let cached = match &*self.0.lock().unwrap_or_else(PoisonError::into_inner) {
+            Some(cached) if cached.type_.is(&type_) && cached.version_tag == version_tag() => Some(cached.slot_offset),
+            _ => None,
+        };
+        match cached {
+            // SAFETY: the offset is of an object slot of `type_`, which hasn't been modified since
+            Some(Some(offset)) => Ok(unsafe { read_slot(obj, offset) }),
+            Some(None) => py_get_attrs(obj, attr_name),
+            None => {
+                // looking up the attribute assigns the type a version tag if it doesn't have one
+                let attr = py_get_attrs(obj, attr_name)?;
+                if version_tag() != 0 {
+                    let slot_offset = find_slot(&type_, attr_name.bind(obj.py()))?;
+                    *self.0.lock().unwrap_or_else(PoisonError::into_inner) = Some(CachedAttribute {
+                        type_: type_.clone().unbind(),
+                        version_tag: version_tag(),
+                        slot_offset,
+                    });
+                }
+                Ok(attr)
+            }
+        }
+    }
+
+    #[cfg(any(PyPy, GraalPy, Py_LIMITED_API, Py_GIL_DISABLED))]
+    fn getattr<'py>(&self, obj: &Bound<'py, PyAny>, attr_name: &Py<PyString>) -> PyResult<Option<Bound<'py, PyAny>>> {
+        py_get_attrs(obj, attr_name)
+    }
+}
+
+#[cfg(not(any(PyPy, GraalPy, Py_LIMITED_API, Py_GIL_DISABLED)))]
+static MEMBER_DESCRIPTOR_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+
+/// The offset of the object slot `attr_name` is stored in, if it's stored in `__slots__` of `type_` or one of its
+/// bases and `type_` uses the default `__getattribute__` without `__getattr__`.
+#[cfg(not(any(PyPy, GraalPy, Py_LIMITED_API, Py_GIL_DISABLED)))]
+fn find_slot(type_: &Bound<'_, PyType>, attr_name: &Bound<'_, PyString>) -> PyResult<Option<isize>> {
+    let py = type_.py();
+    let getattro = unsafe { (*type_.as_type_ptr()).tp_getattro };
+    if getattro.map(|f| f as *const () as usize) != Some(ffi::PyObject_GenericGetAttr as *const () as usize) {
+        return Ok(None);
+    }
+    for base in type_.mro() {
+        let base_dict = base.getattr(intern!(py, "__dict__"))?;
+        let descriptor = base_dict.call_method1(intern!(py, "get"), (attr_name,))?;
+        if descriptor.is_none() {
+            continue;
+        }
+        // the first attribute found in the MRO is the one `getattr` uses, slots are data descriptors so they
+        // take precedence over the instance `__dict__`
+        if !descriptor
+            .get_type()
+            .is(MEMBER_DESCRIPTOR_TYPE.import(py, "types", "MemberDescriptorType")?)
+        {
+            return Ok(None);
+        }
+        let descriptor = unsafe { &*descriptor.as_ptr().cast::<ffi::PyMemberDescrObject>() };
+        // the descriptor could have been copied from an unrelated class, where the offset means something else
+        if unsafe { ffi::PyType_IsSubtype(type_.as_type_ptr(), descriptor.d_common.d_type) } == 0 {
+            return Ok(None);
+        }
+        let member = unsafe { &*descriptor.d_member.cast::<ffi::PyMemberDef>() };
+        let is_object_slot = member.type_code == ffi::Py_T_OBJECT_EX
+            && member.flags & (ffi::Py_AUDIT_READ | ffi::Py_RELATIVE_OFFSET) == 0;
+        return Ok(is_object_slot.then_some(member.offset));
+    }
+    Ok(None)
+}
+
+/// Read the object slot at `offset`, `None` if it's unset.
+///
+/// # Safety
+/// `offset` must be the offset of an object slot of the type of `obj`.
+#[cfg(not(any(PyPy, GraalPy, Py_LIMITED_API, Py_GIL_DISABLED)))]
+unsafe fn read_slot<'py>(obj: &Bound<'py, PyAny>, offset: isize) -> Option<Bound<'py, PyAny>> {
+    let slot = obj.as_ptr().cast::<*mut ffi::PyObject>().byte_offset(offset);
+    Bound::from_borrowed_ptr_or_opt(obj.py(), *slot)
+}
+
 #[derive(Debug)]
 #[allow(clippy::struct_field_names)]
 pub struct LookupKeyCollection {
@@ -571,6 +942,23 @@ impl LookupKeyCollection {
         }
     }
 
+    /// The field's `validation_alias`, a string or a list of paths, `None` if it has no alias.
+    pub fn validation_alias<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyAny>>> {
+        match &self.by_alias {
+            Some(LookupKey::Simple(path) | LookupKey::Choice { path1: path, .. }) => {
+                Ok(Some(path.first_item.py_key.bind(py).clone().into_any()))
+            }
+            Some(LookupKey::PathChoices(paths)) => {
+                let paths = paths
+                    .iter()
+                    .map(|path| path.to_py_list(py))
+                    .collect::<PyResult<Vec<_>>>()?;
+                Ok(Some(PyList::new(py, paths)?.into_any()))
+            }
+            None => Ok(None),
+        }
+    }
+
     pub fn select(&self, validate_by_alias: bool, validate_by_name: bool) -> PyResult<&LookupKey> {
         let lookup_key_selection = match (validate_by_alias, validate_by_name) {
             (true, true) => self.by_alias_then_name.as_ref().unwrap_or(&self.by_name),
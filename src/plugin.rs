@@ -0,0 +1,177 @@
+use std::ffi::{c_int, CStr};
+use std::ptr;
+use std::sync::{PoisonError, RwLock};
+
+use ahash::AHashMap;
+use pyo3::exceptions::{PySystemError, PyValueError};
+use pyo3::ffi;
+use pyo3::prelude::*;
+use pyo3::types::{PyCapsule, PyDict};
+
+use crate::build_tools::{py_schema_err, LazyLock};
+use crate::py_gc::PyGcTraverse;
+use crate::tools::py_err;
+
+/// The name of the capsules `register_plugin` takes.
+pub const PLUGIN_CAPSULE_NAME: &CStr = c"pydantic_core.plugin";
+
+/// The version of `PydanticCorePlugin` this build of pydantic-core uses.
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+/// `validate` found a valid input and set its output.
+pub const PLUGIN_VALID: c_int = 0;
+/// `validate` found an invalid input, reported as a `plugin_type` error.
+pub const PLUGIN_INVALID: c_int = 1;
+/// `validate` failed with a python exception set, which is converted like the exceptions of validator functions.
+pub const PLUGIN_ERROR: c_int = -1;
+
+/// The functions another compiled extension implements to validate and serialize `plugin` schemas, pointed to by
+/// a `pydantic_core.plugin` capsule, so types like numpy scalars can be validated without calling python functions.
+///
+/// All functions are called with the GIL held, `options` is the `options` dict of the schema.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct PydanticCorePlugin {
+    /// must be `PLUGIN_ABI_VERSION`
+    pub abi_version: u32,
+    /// validate `input`, on success set `*output` to a new reference to the value and return `PLUGIN_VALID`,
+    /// otherwise return `PLUGIN_INVALID` or `PLUGIN_ERROR`, must not be `NULL`
+    pub validate: Option<
+        unsafe extern "C" fn(
+            options: *mut ffi::PyObject,
+            input: *mut ffi::PyObject,
+            strict: c_int,
+            output: *mut *mut ffi::PyObject,
+        ) -> c_int,
+    >,
+    /// serialize `value`, to a JSON compatible python value when `json` is `1`, returning a new reference, or
+    /// `NULL` with a python exception set, values are serialized like `any` schemas without this function
+    pub serialize: Option<
+        unsafe extern "C" fn(options: *mut ffi::PyObject, value: *mut ffi::PyObject, json: c_int) -> *mut ffi::PyObject,
+    >,
+}
+
+/// A registered plugin, copied out of its capsule.
+#[derive(Clone)]
+pub(crate) struct Plugin {
+    name: String,
+    functions: PydanticCorePlugin,
+    // keeps the extension which registered the plugin alive while it's used
+    capsule: Py<PyCapsule>,
+}
+
+impl std::fmt::Debug for Plugin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Plugin")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Plugins registered with `register_plugin`, by name.
+static REGISTERED_PLUGINS: LazyLock<RwLock<AHashMap<String, Plugin>>> = LazyLock::new(|| RwLock::new(AHashMap::new()));
+
+#[pyfunction]
+pub fn register_plugin(name: String, capsule: &Bound<'_, PyCapsule>) -> PyResult<()> {
+    if capsule.name()? != Some(PLUGIN_CAPSULE_NAME) {
+        let capsule_name = PLUGIN_CAPSULE_NAME.to_string_lossy();
+        return py_err!(PyValueError; "Plugins must be registered with a '{}' capsule", capsule_name);
+    }
+    let functions = capsule.pointer().cast::<PydanticCorePlugin>();
+    if functions.is_null() {
+        return py_err!(PyValueError; "The capsule of plugin '{}' is empty", name);
+    }
+    // SAFETY: the capsule's name promises it points to a `PydanticCorePlugin`, the version is read first as the
+    // layout of the rest may differ between versions
+    let abi_version = unsafe { ptr::addr_of!((*functions).abi_version).read() };
+    if abi_version != PLUGIN_ABI_VERSION {
+        return py_err!(
+            PyValueError;
+            "Plugin '{}' has ABI version {}, this version of pydantic-core supports version {}",
+            name,
+            abi_version,
+            PLUGIN_ABI_VERSION
+        );
+    }
+    // SAFETY: as above, the version matches so the rest of the layout does too
+    let functions = unsafe { functions.read() };
+    if functions.validate.is_none() {
+        return py_err!(PyValueError; "Plugin '{}' doesn't have a validate function", name);
+    }
+    let plugin = Plugin {
+        name: name.clone(),
+        functions,
+        capsule: capsule.clone().unbind(),
+    };
+    // registering a plugin again replaces it, schemas already built keep using the one they were built with
+    REGISTERED_PLUGINS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(name, plugin);
+    Ok(())
+}
+
+impl Plugin {
+    /// The plugin registered as `name`, a schema error if there isn't one.
+    pub fn get(name: &str) -> PyResult<Self> {
+        let plugins = REGISTERED_PLUGINS.read().unwrap_or_else(PoisonError::into_inner);
+        match plugins.get(name) {
+            Some(plugin) => Ok(plugin.clone()),
+            None => py_schema_err!("Plugin '{}' isn't registered, see `register_plugin`", name),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Validate `input` with the plugin, `None` if it's invalid.
+    pub fn validate<'py>(
+        &self,
+        options: &Bound<'py, PyDict>,
+        input: &Bound<'py, PyAny>,
+        strict: bool,
+    ) -> PyResult<Option<Bound<'py, PyAny>>> {
+        let Some(validate) = self.functions.validate else {
+            // `register_plugin` doesn't store plugins without a validate function
+            return py_err!(PySystemError; "Plugin '{}' doesn't have a validate function", self.name);
+        };
+        let py = input.py();
+        let mut output: *mut ffi::PyObject = ptr::null_mut();
+        let status = unsafe { validate(options.as_ptr(), input.as_ptr(), c_int::from(strict), &mut output) };
+        match status {
+            PLUGIN_VALID if output.is_null() => {
+                py_err!(PySystemError; "Plugin '{}' returned a valid input without a value", self.name)
+            }
+            PLUGIN_VALID => Ok(Some(unsafe { Bound::from_owned_ptr(py, output) })),
+            PLUGIN_INVALID => Ok(None),
+            PLUGIN_ERROR => Err(PyErr::fetch(py)),
+            status => py_err!(PySystemError; "Plugin '{}' returned the unknown status {}", self.name, status),
+        }
+    }
+
+    /// Serialize `value` with the plugin, `None` if the plugin doesn't serialize values.
+    pub fn serialize<'py>(
+        &self,
+        options: &Bound<'py, PyDict>,
+        value: &Bound<'py, PyAny>,
+        json: bool,
+    ) -> PyResult<Option<Bound<'py, PyAny>>> {
+        let Some(serialize) = self.functions.serialize else {
+            return Ok(None);
+        };
+        let py = value.py();
+        let output = unsafe { serialize(options.as_ptr(), value.as_ptr(), c_int::from(json)) };
+        if output.is_null() {
+            Err(PyErr::fetch(py))
+        } else {
+            Ok(Some(unsafe { Bound::from_owned_ptr(py, output) }))
+        }
+    }
+}
+
+impl PyGcTraverse for Plugin {
+    fn py_gc_traverse(&self, visit: &pyo3::PyVisit<'_>) -> Result<(), pyo3::PyTraverseError> {
+        self.capsule.py_gc_traverse(visit)
+    }
+}
@@ -0,0 +1,799 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
+
+use crate::build_tools::{FieldAliases, SchemaError};
+use crate::semver::{PyVersion, SemVer};
+use crate::tools::SchemaDict;
+use crate::validators::SchemaValidator;
+use crate::ValidationError;
+
+const NUMERIC_TYPES: &[&str] = &["int", "float", "decimal"];
+const BOUNDED_TYPES: &[&str] = &["int", "float", "decimal", "date", "time", "datetime", "timedelta"];
+// `semver` schemas only have inclusive lower and exclusive upper bounds
+const VERSION_BOUNDED_TYPES: &[&str] = &[
+    "int",
+    "float",
+    "decimal",
+    "date",
+    "time",
+    "datetime",
+    "timedelta",
+    "semver",
+];
+const SIZED_TYPES: &[&str] = &[
+    "str",
+    "bytes",
+    "list",
+    "keyed-list",
+    "tuple",
+    "set",
+    "frozenset",
+    "dict",
+    "generator",
+    "url",
+    "multi-host-url",
+];
+
+/// Constraints and the schema types they apply to.
+const CONSTRAINTS: &[(&str, &[&str])] = &[
+    ("gt", BOUNDED_TYPES),
+    ("ge", VERSION_BOUNDED_TYPES),
+    ("lt", VERSION_BOUNDED_TYPES),
+    ("le", BOUNDED_TYPES),
+    ("multiple_of", NUMERIC_TYPES),
+    ("multiple_of_tolerance", &["float"]),
+    ("multiple_of_rel_tolerance", &["float"]),
+    ("interval", &["int", "float"]),
+    ("allow_inf_nan", &["float", "decimal"]),
+    ("max_digits", &["int", "decimal"]),
+    ("allow_radix_prefix", &["int"]),
+    ("digit_separators", NUMERIC_TYPES),
+    ("unit", NUMERIC_TYPES),
+    ("unit_conversions", NUMERIC_TYPES),
+    ("decimal_places", &["decimal"]),
+    ("rounding", &["decimal"]),
+    ("quantize_to", &["decimal"]),
+    ("min_length", SIZED_TYPES),
+    ("max_length", SIZED_TYPES),
+    ("monotonic", &["list"]),
+    ("max_adjacent_duplicates", &["list"]),
+    ("unique_items", &["list", "tuple"]),
+    ("preserve_type", &["list"]),
+    ("dict_type", &["dict"]),
+    ("default_factory", &["dict", "default"]),
+    ("pattern", &["str"]),
+    ("pattern_not", &["str"]),
+    ("unicode_normalization", &["str"]),
+    ("length_unit", &["str"]),
+    ("strip_whitespace", &["str"]),
+    ("to_lower", &["str"]),
+    ("to_upper", &["str"]),
+    ("truncate", &["str"]),
+    ("truncate_suffix", &["str"]),
+    ("format", &["date", "time", "datetime"]),
+    ("rfc2822", &["datetime"]),
+    ("zoneinfo", &["datetime"]),
+    ("allow_negative", &["timedelta"]),
+    ("case_insensitive", &["literal"]),
+    ("accept_names", &["enum"]),
+];
+
+// keys whose values are data rather than nested schemas
+const DATA_KEYS: &[&str] = &["metadata", "default", "expected", "value", "members", "context"];
+
+/// Check a core schema for likely mistakes, returning a diagnostic for each problem found rather than raising
+/// on the first one.
+///
+/// Errors raised when building a validator from the schema are included as an `invalid_schema` diagnostic.
+#[pyfunction]
+#[pyo3(signature = (schema, config=None))]
+pub fn check_schema<'py>(
+    py: Python<'py>,
+    schema: &Bound<'py, PyAny>,
+    config: Option<&Bound<'py, PyDict>>,
+) -> PyResult<Bound<'py, PyList>> {
+    let mut checker = SchemaChecker {
+        py,
+        path: Vec::new(),
+        diagnostics: Vec::new(),
+        invalid: false,
+        validate_by_name: validate_by_name(config)?,
+    };
+    if let Err(err) = SchemaValidator::py_new(py, schema, config) {
+        if !err.is_instance_of::<SchemaError>(py) {
+            return Err(err);
+        }
+        checker.report(&[], "invalid_schema", err.value(py).str()?.to_string(), None);
+        checker.invalid = true;
+    }
+    checker.walk(schema)?;
+    PyList::new(py, checker.diagnostics)
+}
+
+#[derive(Clone)]
+enum PathItem {
+    Key(String),
+    Index(usize),
+}
+
+impl<'py> IntoPyObject<'py> for &PathItem {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            PathItem::Key(key) => Ok(PyString::new(py, key).into_any()),
+            PathItem::Index(index) => Ok(index.into_pyobject(py)?.into_any()),
+        }
+    }
+}
+
+struct Diagnostic {
+    path: Vec<PathItem>,
+    kind: &'static str,
+    message: String,
+    suggestion: Option<String>,
+}
+
+impl<'py> IntoPyObject<'py> for Diagnostic {
+    type Target = PyDict;
+    type Output = Bound<'py, PyDict>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item(intern!(py, "path"), PyTuple::new(py, &self.path)?)?;
+        dict.set_item(intern!(py, "kind"), self.kind)?;
+        dict.set_item(intern!(py, "message"), self.message)?;
+        dict.set_item(intern!(py, "suggestion"), self.suggestion)?;
+        Ok(dict)
+    }
+}
+
+struct SchemaChecker<'py> {
+    py: Python<'py>,
+    // keys and indices from the outermost schema to the value being checked
+    path: Vec<PathItem>,
+    diagnostics: Vec<Diagnostic>,
+    // whether building a validator from the schema failed
+    invalid: bool,
+    // whether fields can be validated by name, from the innermost config
+    validate_by_name: bool,
+}
+
+impl<'py> SchemaChecker<'py> {
+    fn report(&mut self, sub_path: &[PathItem], kind: &'static str, message: String, suggestion: Option<String>) {
+        let mut path = self.path.clone();
+        path.extend_from_slice(sub_path);
+        self.diagnostics.push(Diagnostic {
+            path,
+            kind,
+            message,
+            suggestion,
+        });
+    }
+
+    /// Check every schema nested in `value`.
+    fn walk(&mut self, value: &Bound<'py, PyAny>) -> PyResult<()> {
+        if let Ok(dict) = value.downcast::<PyDict>() {
+            let outer_validate_by_name = self.validate_by_name;
+            let result = self.walk_dict(dict);
+            self.validate_by_name = outer_validate_by_name;
+            result?;
+        } else if value.is_instance_of::<PyList>() || value.is_instance_of::<PyTuple>() {
+            for (index, item) in value.try_iter()?.enumerate() {
+                self.path.push(PathItem::Index(index));
+                let result = self.walk(&item?);
+                self.path.pop();
+                result?;
+            }
+        }
+        Ok(())
+    }
+
+    fn walk_dict(&mut self, dict: &Bound<'py, PyDict>) -> PyResult<()> {
+        if let Some(type_) = dict.get_as::<String>(intern!(self.py, "type")).ok().flatten() {
+            if let Ok(Some(config)) = dict.get_as::<Bound<'py, PyDict>>(intern!(self.py, "config")) {
+                self.validate_by_name |= validate_by_name(Some(&config)).unwrap_or(false);
+            }
+            // parts of an invalid schema may not have the expected structure, that's already been reported
+            if let Err(err) = self.check_node(dict, &type_) {
+                if !self.invalid {
+                    return Err(err);
+                }
+            }
+        }
+        for (key, item) in dict {
+            let Ok(key) = key.extract::<String>() else {
+                continue;
+            };
+            if DATA_KEYS.contains(&key.as_str()) {
+                continue;
+            }
+            self.path.push(PathItem::Key(key));
+            let result = self.walk(&item);
+            self.path.pop();
+            result?;
+        }
+        Ok(())
+    }
+
+    fn check_node(&mut self, schema: &Bound<'py, PyDict>, type_: &str) -> PyResult<()> {
+        self.check_constraints(schema, type_)?;
+        match type_ {
+            "union" => self.check_union(schema),
+            "tagged-union" => self.check_tagged_union(schema),
+            "model-fields" | "typed-dict" | "dataclass-args" => self.check_aliases(schema),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_constraints(&mut self, schema: &Bound<'py, PyDict>, type_: &str) -> PyResult<()> {
+        // only validation schemas take constraints, serialization schemas also have a `type`
+        let takes_constraints = CONSTRAINTS.iter().any(|(_, types)| types.contains(&type_));
+        if !takes_constraints && !is_core_schema_type(type_) {
+            return Ok(());
+        }
+        for (key, types) in CONSTRAINTS {
+            if !types.contains(&type_) && schema.contains(*key)? {
+                self.report(
+                    &[PathItem::Key((*key).to_string())],
+                    "incompatible_constraint",
+                    format!("`{key}` has no effect on `{type_}` schemas"),
+                    Some(format!(
+                        "remove `{key}`, it only applies to {} schemas",
+                        types.iter().map(|t| format!("`{t}`")).collect::<Vec<_>>().join(", ")
+                    )),
+                );
+            }
+        }
+
+        self.check_bounds(schema, type_, "min_length", "max_length", false)?;
+        for lower in ["ge", "gt"] {
+            for upper in ["le", "lt"] {
+                self.check_bounds(schema, type_, lower, upper, lower == "gt" || upper == "lt")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Report a lower bound which is greater than the upper bound, or equal to it if either is exclusive.
+    fn check_bounds(
+        &mut self,
+        schema: &Bound<'py, PyDict>,
+        type_: &str,
+        lower: &str,
+        upper: &str,
+        exclusive: bool,
+    ) -> PyResult<()> {
+        let (Some(lower_value), Some(upper_value)) = (schema.get_item(lower)?, schema.get_item(upper)?) else {
+            return Ok(());
+        };
+        let unsatisfiable = if type_ == "semver" {
+            // versions given as strings have to be compared by precedence rather than as strings
+            match (as_semver(&lower_value), as_semver(&upper_value)) {
+                (Some(lower_version), Some(upper_version)) => Ok(if exclusive {
+                    lower_version.cmp_precedence(&upper_version).is_ge()
+                } else {
+                    lower_version.cmp_precedence(&upper_version).is_gt()
+                }),
+                _ => Ok(false),
+            }
+        } else if exclusive {
+            // bounds of different types can't always be compared, e.g. a `date` and an `int`
+            lower_value.ge(&upper_value)
+        } else {
+            lower_value.gt(&upper_value)
+        };
+        if unsatisfiable.unwrap_or(false) {
+            self.report(
+                &[PathItem::Key(lower.to_string())],
+                "unsatisfiable_constraint",
+                format!(
+                    "`{lower}={lower_value}` and `{upper}={upper_value}` can't both be satisfied, no input is valid"
+                ),
+                Some(format!("check the values of `{lower}` and `{upper}`")),
+            );
+        }
+        Ok(())
+    }
+
+    fn check_union(&mut self, schema: &Bound<'py, PyDict>) -> PyResult<()> {
+        let py = self.py;
+        let Some(choices) = schema.get_as::<Bound<'py, PyList>>(intern!(py, "choices"))? else {
+            return Ok(());
+        };
+        let left_to_right = schema.get_as::<String>(intern!(py, "mode"))?.as_deref() == Some("left_to_right");
+        let choices = choices
+            .iter()
+            .map(|choice| match choice.downcast::<PyTuple>() {
+                // `(schema, label)` choices
+                Ok(tuple) => tuple.get_item(0),
+                Err(_) => Ok(choice),
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let mut any_index: Option<usize> = None;
+        for (index, choice) in choices.iter().enumerate() {
+            let choice_path = [PathItem::Key("choices".to_string()), PathItem::Index(index)];
+            if let Some(any_index) = any_index {
+                self.report(
+                    &choice_path,
+                    "unreachable_union_choice",
+                    format!(
+                        "Union choice {index} is never used, choice {any_index} is an `any` schema which accepts \
+                         every input in `left_to_right` mode"
+                    ),
+                    Some(format!("move choice {any_index} to the end of the union")),
+                );
+                continue;
+            }
+            let mut duplicate_of = None;
+            for (previous_index, previous) in choices[..index].iter().enumerate() {
+                if previous.eq(choice)? {
+                    duplicate_of = Some(previous_index);
+                    break;
+                }
+            }
+            if let Some(previous_index) = duplicate_of {
+                self.report(
+                    &choice_path,
+                    "duplicate_union_choice",
+                    format!("Union choice {index} is the same as choice {previous_index}, so it's never used"),
+                    Some(format!("remove choice {index}")),
+                );
+            }
+            if left_to_right && schema_type(choice)?.as_deref() == Some("any") {
+                any_index = Some(index);
+            }
+        }
+        Ok(())
+    }
+
+    fn check_tagged_union(&mut self, schema: &Bound<'py, PyDict>) -> PyResult<()> {
+        let py = self.py;
+        let Some(choices) = schema.get_as::<Bound<'py, PyDict>>(intern!(py, "choices"))? else {
+            return Ok(());
+        };
+        let discriminator = schema.get_as::<String>(intern!(py, "discriminator")).ok().flatten();
+        let enum_type = py.import(intern!(py, "enum"))?.getattr(intern!(py, "Enum"))?;
+
+        let mut tags: Vec<Bound<'py, PyAny>> = Vec::with_capacity(choices.len());
+        for (tag, choice) in &choices {
+            // enum members are looked up by their value too, so an enum tag and its value clash
+            let tag_value = if tag.is_instance(&enum_type)? {
+                tag.getattr(intern!(py, "value"))?
+            } else {
+                tag.clone()
+            };
+            let choice_path = [
+                PathItem::Key("choices".to_string()),
+                PathItem::Key(tag.str()?.to_string()),
+            ];
+            for previous in &tags {
+                if previous.eq(&tag_value)? {
+                    self.report(
+                        &choice_path,
+                        "duplicate_discriminator_tag",
+                        format!("Tag {} is the same as another tag in the tagged union", tag.repr()?),
+                        Some("use a distinct tag for each choice".to_string()),
+                    );
+                }
+            }
+
+            if let Some(discriminator) = &discriminator {
+                if let Some(expected) = discriminator_literal(&choice, discriminator)? {
+                    let mut matches = false;
+                    for value in &expected {
+                        matches |= value.eq(&tag)? || value.eq(&tag_value)?;
+                    }
+                    if !matches {
+                        self.report(
+                            &choice_path,
+                            "unreachable_union_choice",
+                            format!(
+                                "Choice {} is never valid, its `{discriminator}` field only allows {}",
+                                tag.repr()?,
+                                expected.repr()?
+                            ),
+                            Some(format!(
+                                "change the tag or allow it in the choice's `{discriminator}` field"
+                            )),
+                        );
+                    }
+                }
+            }
+            tags.push(tag_value);
+        }
+        Ok(())
+    }
+
+    /// Report fields whose serialized key isn't one of the keys they're validated from, so serialized data
+    /// can't be validated again, assuming both use aliases.
+    fn check_aliases(&mut self, schema: &Bound<'py, PyDict>) -> PyResult<()> {
+        let py = self.py;
+        let aliases = FieldAliases::from_schema(schema)?;
+        let fields = schema.get_as_req::<Bound<'py, PyAny>>(intern!(py, "fields"))?;
+        let fields: Vec<(PathItem, String, Bound<'py, PyDict>)> = if let Ok(fields) = fields.downcast::<PyDict>() {
+            fields
+                .iter()
+                .map(|(name, field)| {
+                    let name: String = name.extract()?;
+                    Ok((PathItem::Key(name.clone()), name, field.downcast_into::<PyDict>()?))
+                })
+                .collect::<PyResult<_>>()?
+        } else {
+            fields
+                .try_iter()?
+                .enumerate()
+                .map(|(index, field)| {
+                    let field = field?.downcast_into::<PyDict>()?;
+                    Ok((PathItem::Index(index), field.get_as_req(intern!(py, "name"))?, field))
+                })
+                .collect::<PyResult<_>>()?
+        };
+
+        for (path_item, name, field) in fields {
+            // fields which aren't both serialized and validated can't be round-tripped anyway
+            if field.get_as(intern!(py, "serialization_exclude"))? == Some(true)
+                || field.get_as(intern!(py, "init"))? == Some(false)
+                || field.get_as(intern!(py, "init_only"))? == Some(true)
+            {
+                continue;
+            }
+            let serialized_key = match aliases.get(&field, intern!(py, "serialization_alias"), &name)? {
+                Some(alias) => alias.extract::<String>()?,
+                None => name.clone(),
+            };
+            let validation_alias = aliases.get(&field, intern!(py, "validation_alias"), &name)?;
+            let mut validated_keys = match &validation_alias {
+                Some(alias) => alias_keys(alias)?,
+                None => vec![name.clone()],
+            };
+            if self.validate_by_name && !validated_keys.contains(&name) {
+                validated_keys.push(name.clone());
+            }
+            if validated_keys.contains(&serialized_key) {
+                continue;
+            }
+
+            let explicit_validation_alias = field.get_item(intern!(py, "validation_alias"))?;
+            let explicit_serialization_alias = field.get_item(intern!(py, "serialization_alias"))?;
+            let suggestion = match (&explicit_validation_alias, explicit_serialization_alias) {
+                (Some(alias), None) if alias.is_instance_of::<PyString>() => {
+                    format!(
+                        "set `serialization_alias` to {}, or `derive_aliases=True` on the schema",
+                        alias.repr()?
+                    )
+                }
+                (None, Some(alias)) => {
+                    format!(
+                        "set `validation_alias` to {}, or `derive_aliases=True` on the schema",
+                        alias.repr()?
+                    )
+                }
+                _ => "use the same alias for validation and serialization".to_string(),
+            };
+            let validated_from = match validated_keys.as_slice() {
+                [] => "only validated from nested data".to_string(),
+                keys => format!(
+                    "validated from {}",
+                    keys.iter()
+                        .map(|key| format!("`{key}`"))
+                        .collect::<Vec<_>>()
+                        .join(" or ")
+                ),
+            };
+            self.report(
+                &[PathItem::Key("fields".to_string()), path_item],
+                "alias_mismatch",
+                format!(
+                    "Field `{name}` is serialized as `{serialized_key}` but {validated_from}, so serialized data \
+                     can't be validated again"
+                ),
+                Some(suggestion),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Validate the `examples` of every schema nested in `schema`, see `SchemaValidator.check_examples`.
+///
+/// Nested schemas are validated on their own, with the definitions of the outermost schema so references to them
+/// can be resolved.
+pub(crate) fn check_examples<'py>(
+    py: Python<'py>,
+    schema: &Bound<'py, PyAny>,
+    config: Option<&Bound<'py, PyDict>>,
+) -> PyResult<Bound<'py, PyList>> {
+    let definitions = match schema.downcast::<PyDict>() {
+        Ok(dict) if dict.get_as::<String>(intern!(py, "type"))?.as_deref() == Some("definitions") => {
+            dict.get_as::<Bound<'py, PyList>>(intern!(py, "definitions"))?
+        }
+        _ => None,
+    };
+    let mut checker = ExamplesChecker {
+        py,
+        config,
+        definitions,
+        path: Vec::new(),
+        failures: PyList::empty(py),
+    };
+    checker.walk(schema)?;
+    Ok(checker.failures)
+}
+
+struct ExamplesChecker<'a, 'py> {
+    py: Python<'py>,
+    config: Option<&'a Bound<'py, PyDict>>,
+    // the definitions of the outermost schema, if it's a `definitions` schema
+    definitions: Option<Bound<'py, PyList>>,
+    path: Vec<PathItem>,
+    failures: Bound<'py, PyList>,
+}
+
+impl<'py> ExamplesChecker<'_, 'py> {
+    fn walk(&mut self, value: &Bound<'py, PyAny>) -> PyResult<()> {
+        if let Ok(dict) = value.downcast::<PyDict>() {
+            if dict.contains(intern!(self.py, "type"))? {
+                if let Some(examples) = dict.get_item(intern!(self.py, "examples"))? {
+                    self.check(dict, &examples)?;
+                }
+            }
+            for (key, item) in dict {
+                let Ok(key) = key.extract::<String>() else {
+                    continue;
+                };
+                // serialization schemas don't validate, so can't have examples
+                if DATA_KEYS.contains(&key.as_str()) || key == "examples" || key == "serialization" {
+                    continue;
+                }
+                self.path.push(PathItem::Key(key));
+                let result = self.walk(&item);
+                self.path.pop();
+                result?;
+            }
+        } else if value.is_instance_of::<PyList>() || value.is_instance_of::<PyTuple>() {
+            for (index, item) in value.try_iter()?.enumerate() {
+                self.path.push(PathItem::Index(index));
+                let result = self.walk(&item?);
+                self.path.pop();
+                result?;
+            }
+        }
+        Ok(())
+    }
+
+    fn check(&mut self, schema: &Bound<'py, PyDict>, examples: &Bound<'py, PyAny>) -> PyResult<()> {
+        let py = self.py;
+        if !(examples.is_instance_of::<PyList>() || examples.is_instance_of::<PyTuple>()) {
+            return Err(PyTypeError::new_err(format!(
+                "`examples` should be a list, got {}",
+                examples.get_type().name()?
+            )));
+        }
+        let validator = match SchemaValidator::py_new(py, &self.standalone_schema(schema)?, self.config) {
+            Ok(validator) => Ok(Bound::new(py, validator)?),
+            Err(err) if err.is_instance_of::<SchemaError>(py) => Err(format!(
+                "Schema can't be built on its own to check examples: {}",
+                err.value(py).str()?
+            )),
+            Err(err) => return Err(err),
+        };
+        for (index, example) in examples.try_iter()?.enumerate() {
+            let example = example?;
+            let (message, errors) = match &validator {
+                Ok(validator) => match validator.call_method1(intern!(py, "validate_python"), (&example,)) {
+                    Ok(_) => continue,
+                    Err(err) if err.is_instance_of::<ValidationError>(py) => {
+                        let err = err.value(py);
+                        (err.str()?.to_string(), err.call_method0(intern!(py, "errors"))?)
+                    }
+                    Err(err) => return Err(err),
+                },
+                Err(message) => (message.clone(), PyList::empty(py).into_any()),
+            };
+            let failure = PyDict::new(py);
+            failure.set_item(intern!(py, "path"), PyTuple::new(py, &self.path)?)?;
+            failure.set_item(intern!(py, "index"), index)?;
+            failure.set_item(intern!(py, "example"), example)?;
+            failure.set_item(intern!(py, "message"), message)?;
+            failure.set_item(intern!(py, "errors"), errors)?;
+            self.failures.append(failure)?;
+        }
+        Ok(())
+    }
+
+    /// The schema to validate the examples of `schema` with, nested schemas are wrapped with the definitions.
+    fn standalone_schema(&self, schema: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyAny>> {
+        let py = self.py;
+        let Some(definitions) = self.definitions.as_ref().filter(|_| !self.path.is_empty()) else {
+            return Ok(schema.clone().into_any());
+        };
+        // a definition is referred to rather than repeated, which would define its `ref` twice
+        let mut inner = schema.clone();
+        if let Some(schema_ref) = schema.get_as::<String>(intern!(py, "ref"))? {
+            if is_defined(definitions, &schema_ref) {
+                inner = PyDict::new(py);
+                inner.set_item(intern!(py, "type"), "definition-ref")?;
+                inner.set_item(intern!(py, "schema_ref"), schema_ref)?;
+            }
+        }
+        let wrapped = PyDict::new(py);
+        wrapped.set_item(intern!(py, "type"), "definitions")?;
+        wrapped.set_item(intern!(py, "schema"), inner)?;
+        wrapped.set_item(intern!(py, "definitions"), definitions)?;
+        Ok(wrapped.into_any())
+    }
+}
+
+fn is_defined(definitions: &Bound<'_, PyList>, schema_ref: &str) -> bool {
+    definitions.iter().any(|definition| {
+        definition
+            .downcast::<PyDict>()
+            .ok()
+            .and_then(|definition| {
+                definition
+                    .get_as::<String>(intern!(definition.py(), "ref"))
+                    .ok()
+                    .flatten()
+            })
+            .is_some_and(|definition_ref| definition_ref == schema_ref)
+    })
+}
+
+fn validate_by_name(config: Option<&Bound<'_, PyDict>>) -> PyResult<bool> {
+    match config {
+        Some(config) => Ok(config
+            .get_as(intern!(config.py(), "validate_by_name"))?
+            .unwrap_or(false)),
+        None => Ok(false),
+    }
+}
+
+/// The top level keys a `validation_alias` looks up, ignoring paths into nested data.
+fn alias_keys(alias: &Bound<'_, PyAny>) -> PyResult<Vec<String>> {
+    if let Ok(key) = alias.extract::<String>() {
+        return Ok(vec![key]);
+    }
+    let Ok(alias) = alias.downcast::<PyList>() else {
+        return Ok(Vec::new());
+    };
+    // a single path, or a list of paths to choose from
+    let paths: Vec<Bound<'_, PyAny>> = match alias.get_item(0) {
+        Ok(first) if first.is_instance_of::<PyList>() => alias.iter().collect(),
+        _ => vec![alias.clone().into_any()],
+    };
+    let mut keys = Vec::new();
+    for path in paths {
+        if let Ok(path) = path.downcast::<PyList>() {
+            if path.len() == 1 {
+                if let Ok(key) = path.get_item(0)?.extract::<String>() {
+                    if !keys.contains(&key) {
+                        keys.push(key);
+                    }
+                }
+            }
+        }
+    }
+    Ok(keys)
+}
+
+fn is_core_schema_type(type_: &str) -> bool {
+    matches!(
+        type_,
+        "any"
+            | "none"
+            | "bool"
+            | "literal"
+            | "const"
+            | "enum"
+            | "missing-sentinel"
+            | "is-instance"
+            | "is-subclass"
+            | "callable"
+            | "complex"
+            | "uuid"
+            | "semver"
+            | "ulid"
+            | "email"
+            | "name-email"
+            | "ip-v4-address"
+            | "ip-v6-address"
+            | "ip-network"
+            | "ip-interface"
+            | "path"
+            | "keyed-list"
+            | "plugin"
+            | "nullable"
+            | "union"
+            | "tagged-union"
+            | "model"
+            | "model-fields"
+            | "typed-dict"
+            | "dataclass"
+            | "dataclass-args"
+            | "arguments"
+            | "arguments-v3"
+            | "call"
+            | "chain"
+            | "lax-or-strict"
+            | "json-or-python"
+            | "custom-error"
+            | "json"
+            | "definitions"
+            | "definition-ref"
+            | "default"
+            | "function-before"
+            | "function-after"
+            | "function-wrap"
+            | "function-plain"
+    )
+}
+
+fn as_semver(value: &Bound<'_, PyAny>) -> Option<SemVer> {
+    match value.downcast::<PyVersion>() {
+        Ok(version) => Some(version.get().version().clone()),
+        Err(_) => SemVer::parse(value.extract::<&str>().ok()?).ok(),
+    }
+}
+
+fn schema_type(schema: &Bound<'_, PyAny>) -> PyResult<Option<String>> {
+    match schema.downcast::<PyDict>() {
+        Ok(schema) => schema.get_as(intern!(schema.py(), "type")),
+        Err(_) => Ok(None),
+    }
+}
+
+/// The values allowed by the discriminator field of a tagged union choice, if it's a `literal` or `const` schema.
+fn discriminator_literal<'py>(choice: &Bound<'py, PyAny>, discriminator: &str) -> PyResult<Option<Bound<'py, PyList>>> {
+    let py = choice.py();
+    let Ok(mut schema) = choice.downcast::<PyDict>().cloned() else {
+        return Ok(None);
+    };
+    // find the fields of models and dataclasses
+    while matches!(schema_type(&schema)?.as_deref(), Some("model" | "dataclass")) {
+        schema = schema.get_as_req(intern!(py, "schema"))?;
+    }
+    let field = match schema_type(&schema)?.as_deref() {
+        Some("model-fields" | "typed-dict") => schema
+            .get_as::<Bound<'py, PyDict>>(intern!(py, "fields"))?
+            .and_then(|fields| fields.get_item(discriminator).ok().flatten()),
+        Some("dataclass-args") => {
+            let mut found = None;
+            if let Some(fields) = schema.get_as::<Bound<'py, PyList>>(intern!(py, "fields"))? {
+                for field in fields {
+                    if let Ok(field_dict) = field.downcast::<PyDict>() {
+                        if field_dict.get_as::<String>(intern!(py, "name"))?.as_deref() == Some(discriminator) {
+                            found = Some(field);
+                            break;
+                        }
+                    }
+                }
+            }
+            found
+        }
+        _ => None,
+    };
+    let Some(field) = field.and_then(|field| field.downcast_into::<PyDict>().ok()) else {
+        return Ok(None);
+    };
+    let Some(mut field_schema) = field.get_as::<Bound<'py, PyDict>>(intern!(py, "schema"))? else {
+        return Ok(None);
+    };
+    if schema_type(&field_schema)?.as_deref() == Some("default") {
+        field_schema = field_schema.get_as_req(intern!(py, "schema"))?;
+    }
+    match schema_type(&field_schema)?.as_deref() {
+        Some("literal") => field_schema.get_as(intern!(py, "expected")),
+        Some("const") => Ok(Some(PyList::new(
+            py,
+            [field_schema.get_as_req::<Bound<'py, PyAny>>(intern!(py, "value"))?],
+        )?)),
+        _ => Ok(None),
+    }
+}
@@ -0,0 +1,276 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::{self, Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+use jiter::{PartialMode, StringCacheMode};
+use pyo3::pyclass::CompareOp;
+use pyo3::{prelude::*, IntoPyObjectExt};
+
+use crate::input::InputType;
+use crate::recursion_guard::RecursionState;
+use crate::validators::semver::SemverValidator;
+use crate::validators::{Extra, ValidationOptions, ValidationState, Validator};
+use crate::ValidationError;
+
+/// An identifier of the pre-release part of a version, numeric identifiers have lower precedence than
+/// alphanumeric ones and are compared numerically.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Identifier {
+    Numeric(u64),
+    AlphaNumeric(String),
+}
+
+impl Display for Identifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Numeric(n) => write!(f, "{n}"),
+            Self::AlphaNumeric(s) => f.write_str(s),
+        }
+    }
+}
+
+/// A [SemVer 2.0](https://semver.org) version.
+///
+/// Versions are ordered by their precedence, with build metadata, which doesn't affect precedence, compared
+/// last so the ordering is consistent with equality.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SemVer {
+    major: u64,
+    minor: u64,
+    patch: u64,
+    pre: Vec<Identifier>,
+    build: Vec<String>,
+}
+
+impl SemVer {
+    pub fn parse(s: &str) -> Result<Self, &'static str> {
+        let (s, build) = match s.split_once('+') {
+            Some((s, build)) => (s, Some(build)),
+            None => (s, None),
+        };
+        let (s, pre) = match s.split_once('-') {
+            Some((s, pre)) => (s, Some(pre)),
+            None => (s, None),
+        };
+
+        let mut parts = s.split('.');
+        let (Some(major), Some(minor), Some(patch), None) = (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err("it should have major, minor and patch versions separated by periods");
+        };
+        let number = |part: &str, leading_zero_error: &'static str| -> Result<u64, &'static str> {
+            match parse_numeric(part) {
+                Some(Ok(n)) => Ok(n),
+                Some(Err(NumericError::LeadingZero)) => Err(leading_zero_error),
+                Some(Err(NumericError::TooLarge)) => Err("a version number is too large"),
+                None => Err("the major, minor and patch versions should be numbers"),
+            }
+        };
+        let major = number(major, "the major version has a leading zero")?;
+        let minor = number(minor, "the minor version has a leading zero")?;
+        let patch = number(patch, "the patch version has a leading zero")?;
+
+        let pre = match pre {
+            Some(pre) => pre
+                .split('.')
+                .map(|identifier| {
+                    if !is_identifier(identifier) {
+                        return Err("the pre-release should be dot-separated alphanumeric identifiers");
+                    }
+                    match parse_numeric(identifier) {
+                        Some(Ok(n)) => Ok(Identifier::Numeric(n)),
+                        Some(Err(NumericError::LeadingZero)) => {
+                            Err("a numeric pre-release identifier has a leading zero")
+                        }
+                        Some(Err(NumericError::TooLarge)) => Err("a numeric pre-release identifier is too large"),
+                        None => Ok(Identifier::AlphaNumeric(identifier.to_string())),
+                    }
+                })
+                .collect::<Result<_, _>>()?,
+            None => Vec::new(),
+        };
+        let build = match build {
+            Some(build) => build
+                .split('.')
+                .map(|identifier| match is_identifier(identifier) {
+                    true => Ok(identifier.to_string()),
+                    false => Err("the build metadata should be dot-separated alphanumeric identifiers"),
+                })
+                .collect::<Result<_, _>>()?,
+            None => Vec::new(),
+        };
+
+        Ok(Self {
+            major,
+            minor,
+            patch,
+            pre,
+            build,
+        })
+    }
+
+    /// The SemVer precedence of two versions, which ignores build metadata.
+    pub fn cmp_precedence(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (self.pre.is_empty(), other.pre.is_empty()) {
+                // a pre-release has lower precedence than the release
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => self.pre.cmp(&other.pre),
+            })
+    }
+}
+
+impl Ord for SemVer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_precedence(other).then_with(|| self.build.cmp(&other.build))
+    }
+}
+
+impl PartialOrd for SemVer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for SemVer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if !self.pre.is_empty() {
+            write!(f, "-{}", join(&self.pre))?;
+        }
+        if !self.build.is_empty() {
+            write!(f, "+{}", self.build.join("."))?;
+        }
+        Ok(())
+    }
+}
+
+enum NumericError {
+    LeadingZero,
+    TooLarge,
+}
+
+/// Parse a numeric identifier, `None` if it's not all digits.
+fn parse_numeric(s: &str) -> Option<Result<u64, NumericError>> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    if s.len() > 1 && s.starts_with('0') {
+        return Some(Err(NumericError::LeadingZero));
+    }
+    Some(s.parse().map_err(|_| NumericError::TooLarge))
+}
+
+fn is_identifier(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+}
+
+fn join(identifiers: &[Identifier]) -> String {
+    identifiers
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+#[pyclass(name = "Version", module = "pydantic_core._pydantic_core", subclass, frozen)]
+#[derive(Clone)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct PyVersion {
+    version: SemVer,
+}
+
+impl PyVersion {
+    pub fn new(version: SemVer) -> Self {
+        Self { version }
+    }
+
+    pub fn version(&self) -> &SemVer {
+        &self.version
+    }
+}
+
+#[pymethods]
+impl PyVersion {
+    #[new]
+    pub fn py_new(py: Python, version: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let validator = SemverValidator::get_simple();
+        let version_obj = validator
+            .validate(
+                py,
+                version,
+                &mut ValidationState::new(
+                    Extra::new(&ValidationOptions::default(), InputType::Python, StringCacheMode::None),
+                    &mut RecursionState::default(),
+                    PartialMode::Off,
+                ),
+            )
+            .map_err(|e| {
+                let name = match validator.get_name().into_py_any(py) {
+                    Ok(name) => name,
+                    Err(e) => return e,
+                };
+                ValidationError::from_val_error(py, name, InputType::Python, e, None, false, false)
+            })?
+            .downcast_bound::<Self>(py)?
+            .get()
+            .clone();
+        Ok(version_obj)
+    }
+
+    #[getter]
+    pub fn major(&self) -> u64 {
+        self.version.major
+    }
+
+    #[getter]
+    pub fn minor(&self) -> u64 {
+        self.version.minor
+    }
+
+    #[getter]
+    pub fn patch(&self) -> u64 {
+        self.version.patch
+    }
+
+    #[getter]
+    pub fn prerelease(&self) -> Option<String> {
+        (!self.version.pre.is_empty()).then(|| join(&self.version.pre))
+    }
+
+    #[getter]
+    pub fn build(&self) -> Option<String> {
+        (!self.version.build.is_empty()).then(|| self.version.build.join("."))
+    }
+
+    pub fn __str__(&self, _py: Python<'_>) -> String {
+        self.version.to_string()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("Version('{}')", self.version)
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+        op.matches(self.version.cmp(&other.version))
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.version.hash(&mut s);
+        s.finish()
+    }
+
+    #[pyo3(signature = (_memo, /))]
+    pub fn __deepcopy__(&self, py: Python, _memo: Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        self.clone().into_py_any(py)
+    }
+
+    fn __getnewargs__(&self) -> (String,) {
+        (self.version.to_string(),)
+    }
+}
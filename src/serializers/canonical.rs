@@ -0,0 +1,107 @@
+use std::io::{self, Write};
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use serde::Serialize;
+use serde_json::ser::{CompactFormatter, Formatter};
+
+use super::errors::se_err_py_err;
+use super::extra::SerializationState;
+use super::ser::PythonSerializer;
+use super::shared::{CombinedSerializer, PydanticSerializer};
+use super::sort_keys::SortKeysFormatter;
+
+/// Largest float below which every integral value is exactly representable as an integer, larger
+/// values are written as floats so their output stays short.
+const MAX_EXACT_INT_FLOAT: f64 = 9_007_199_254_740_992.0;
+
+const HASH_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Serialize `value` to canonical JSON, writing it to `hasher` (a `hashlib` hash object) in chunks, then
+/// return the hex digest.
+pub(super) fn to_json_hash<'py>(
+    value: &Bound<'py, PyAny>,
+    serializer: &CombinedSerializer,
+    state: &mut SerializationState<'_, 'py>,
+    hasher: &Bound<'py, PyAny>,
+) -> PyResult<String> {
+    let serializer = PydanticSerializer::new(value, serializer, state);
+    let writer = HashWriter {
+        hasher,
+        buffer: Vec::with_capacity(HASH_CHUNK_SIZE),
+        error: None,
+    };
+    let mut ser = PythonSerializer::with_formatter(writer, SortKeysFormatter::new(CanonicalFormatter));
+    let result = serializer.serialize(&mut ser);
+    let mut writer = ser.into_inner();
+    if let Some(err) = writer.error.take() {
+        return Err(err);
+    }
+    result.map_err(se_err_py_err)?;
+    writer.flush_to_hasher()?;
+    hasher.call_method0(intern!(hasher.py(), "hexdigest"))?.extract()
+}
+
+struct HashWriter<'a, 'py> {
+    hasher: &'a Bound<'py, PyAny>,
+    buffer: Vec<u8>,
+    // `io::Error` can't hold a `PyErr`, so it's kept here to be raised once serialization has stopped
+    error: Option<PyErr>,
+}
+
+impl HashWriter<'_, '_> {
+    fn flush_to_hasher(&mut self) -> PyResult<()> {
+        if !self.buffer.is_empty() {
+            let py = self.hasher.py();
+            self.hasher
+                .call_method1(intern!(py, "update"), (PyBytes::new(py, &self.buffer),))?;
+            self.buffer.clear();
+        }
+        Ok(())
+    }
+}
+
+impl Write for HashWriter<'_, '_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        if self.buffer.len() >= HASH_CHUNK_SIZE {
+            self.flush_to_hasher().map_err(|err| {
+                let io_err = io::Error::other(err.to_string());
+                self.error = Some(err);
+                io_err
+            })?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Formatter for compact JSON with integral floats written as integers, used with `SortKeysFormatter` so
+/// equal values always produce the same output.
+struct CanonicalFormatter;
+
+impl Formatter for CanonicalFormatter {
+    fn write_f32<W>(&mut self, writer: &mut W, value: f32) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.write_f64(writer, f64::from(value))
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn write_f64<W>(&mut self, writer: &mut W, value: f64) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        if value.fract() == 0.0 && value.abs() < MAX_EXACT_INT_FLOAT {
+            // this also normalizes `-0.0` to `0`
+            CompactFormatter.write_i64(writer, value as i64)
+        } else {
+            CompactFormatter.write_f64(writer, value)
+        }
+    }
+}
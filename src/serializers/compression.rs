@@ -0,0 +1,147 @@
+use std::io::{self, Write};
+
+#[cfg(feature = "gzip")]
+use flate2::write::GzEncoder;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// The values of `compress` this build supports, each codec is behind the cargo feature of the same name.
+pub(crate) const CODECS: &[&str] = &[
+    #[cfg(feature = "gzip")]
+    "gzip",
+    #[cfg(feature = "zstd")]
+    "zstd",
+];
+
+/// How JSON is compressed, from the `compress` and `level` arguments of `to_json`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Compression {
+    #[cfg(feature = "gzip")]
+    Gzip(u32),
+    #[cfg(feature = "zstd")]
+    Zstd(i32),
+}
+
+impl Compression {
+    pub fn from_args(compress: Option<&str>, level: Option<i32>) -> PyResult<Option<Self>> {
+        let Some(compress) = compress else {
+            return match level {
+                Some(_) => Err(PyValueError::new_err("`level` requires `compress`")),
+                None => Ok(None),
+            };
+        };
+        let compression = match compress {
+            "gzip" => Self::gzip(level)?,
+            "zstd" => Self::zstd(level)?,
+            s => {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid compress: `{s}`, expected 'gzip' or 'zstd'"
+                )))
+            }
+        };
+        Ok(Some(compression))
+    }
+
+    #[cfg(feature = "gzip")]
+    fn gzip(level: Option<i32>) -> PyResult<Self> {
+        match level {
+            None => Ok(Self::Gzip(flate2::Compression::default().level())),
+            Some(level @ 0..=9) => Ok(Self::Gzip(level.unsigned_abs())),
+            Some(level) => Err(PyValueError::new_err(format!(
+                "Invalid gzip level: {level}, expected 0 to 9"
+            ))),
+        }
+    }
+
+    #[cfg(not(feature = "gzip"))]
+    fn gzip(_level: Option<i32>) -> PyResult<Self> {
+        Err(codec_not_built("gzip"))
+    }
+
+    #[cfg(feature = "zstd")]
+    fn zstd(level: Option<i32>) -> PyResult<Self> {
+        let range = zstd::compression_level_range();
+        match level {
+            None => Ok(Self::Zstd(zstd::DEFAULT_COMPRESSION_LEVEL)),
+            Some(level) if range.contains(&level) => Ok(Self::Zstd(level)),
+            Some(level) => Err(PyValueError::new_err(format!(
+                "Invalid zstd level: {level}, expected {} to {}",
+                range.start(),
+                range.end()
+            ))),
+        }
+    }
+
+    #[cfg(not(feature = "zstd"))]
+    fn zstd(_level: Option<i32>) -> PyResult<Self> {
+        Err(codec_not_built("zstd"))
+    }
+
+    /// Compress what's written to the encoder, writing the compressed bytes to `writer`.
+    #[cfg_attr(not(any(feature = "gzip", feature = "zstd")), allow(unused_variables))]
+    pub fn encoder<W: Write>(self, writer: W) -> io::Result<Encoder<W>> {
+        match self {
+            #[cfg(feature = "gzip")]
+            Self::Gzip(level) => Ok(Encoder::Gzip(GzEncoder::new(writer, flate2::Compression::new(level)))),
+            #[cfg(feature = "zstd")]
+            Self::Zstd(level) => Ok(Encoder::Zstd(zstd::Encoder::new(writer, level)?)),
+        }
+    }
+}
+
+#[cfg(not(all(feature = "gzip", feature = "zstd")))]
+fn codec_not_built(codec: &str) -> PyErr {
+    PyValueError::new_err(format!(
+        "`compress='{codec}'` isn't available, pydantic-core was built without the `{codec}` feature"
+    ))
+}
+
+pub(crate) enum Encoder<W: Write> {
+    #[cfg(feature = "gzip")]
+    Gzip(GzEncoder<W>),
+    #[cfg(feature = "zstd")]
+    Zstd(zstd::Encoder<'static, W>),
+    // without any codecs there are no encoders, this only uses `W`
+    #[cfg(not(any(feature = "gzip", feature = "zstd")))]
+    #[allow(dead_code)]
+    None(std::convert::Infallible, std::marker::PhantomData<W>),
+}
+
+impl<W: Write> Encoder<W> {
+    /// Write the end of the compressed stream, returning the writer.
+    pub fn finish(self) -> io::Result<W> {
+        match self {
+            #[cfg(feature = "gzip")]
+            Self::Gzip(encoder) => encoder.finish(),
+            #[cfg(feature = "zstd")]
+            Self::Zstd(encoder) => encoder.finish(),
+            #[cfg(not(any(feature = "gzip", feature = "zstd")))]
+            Self::None(never, _) => match never {},
+        }
+    }
+}
+
+impl<W: Write> Write for Encoder<W> {
+    #[cfg_attr(not(any(feature = "gzip", feature = "zstd")), allow(unused_variables))]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            #[cfg(feature = "gzip")]
+            Self::Gzip(encoder) => encoder.write(buf),
+            #[cfg(feature = "zstd")]
+            Self::Zstd(encoder) => encoder.write(buf),
+            #[cfg(not(any(feature = "gzip", feature = "zstd")))]
+            Self::None(never, _) => match *never {},
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            #[cfg(feature = "gzip")]
+            Self::Gzip(encoder) => encoder.flush(),
+            #[cfg(feature = "zstd")]
+            Self::Zstd(encoder) => encoder.flush(),
+            #[cfg(not(any(feature = "gzip", feature = "zstd")))]
+            Self::None(never, _) => match *never {},
+        }
+    }
+}
@@ -1,7 +1,7 @@
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyString};
+use pyo3::types::{PyDict, PyInt, PyList, PyString, PyTuple};
 use pyo3::{intern, PyTraverseError, PyVisit};
 use serde::ser::SerializeMap;
 
@@ -119,7 +119,7 @@ impl ComputedFields {
                 Err(e) => return Err(convert_error(e)),
             };
 
-            let value = match model.getattr(property_name_py) {
+            let value = match computed_field.get_value(model, state) {
                 Ok(field_value) => field_value,
                 Err(e) => {
                     return Err(convert_error(e));
@@ -160,6 +160,7 @@ struct ComputedField {
     alias: String,
     alias_py: Py<PyString>,
     serialize_by_alias: Option<bool>,
+    cache: bool,
 }
 
 impl ComputedField {
@@ -184,8 +185,74 @@ impl ComputedField {
             alias: alias_py.extract()?,
             alias_py: alias_py.into(),
             serialize_by_alias: config.get_as(intern!(py, "serialize_by_alias"))?,
+            cache: schema.get_as(intern!(py, "cache"))?.unwrap_or(false),
         })
     }
+
+    fn get_value<'py>(
+        &self,
+        model: &Bound<'py, PyAny>,
+        state: &SerializationState<'_, 'py>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let property_name_py = self.property_name_py.bind(model.py());
+        if !self.cache {
+            return model.getattr(property_name_py);
+        }
+        if let Some(value) = state.computed_field_cache.get(model, property_name_py)? {
+            return Ok(value);
+        }
+        let value = model.getattr(property_name_py)?;
+        state.computed_field_cache.insert(model, property_name_py, &value)?;
+        Ok(value)
+    }
+}
+
+/// The values of computed fields with `cache` set, for the duration of a single serialization call.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ComputedFieldCache(Arc<OnceLock<Py<PyDict>>>);
+
+impl ComputedFieldCache {
+    fn get<'py>(
+        &self,
+        model: &Bound<'py, PyAny>,
+        property_name: &Bound<'py, PyString>,
+    ) -> PyResult<Option<Bound<'py, PyAny>>> {
+        let Some(entries) = self.0.get() else {
+            return Ok(None);
+        };
+        match entries.bind(model.py()).get_item(Self::key(model, property_name)?)? {
+            Some(entry) => entry.get_item(1).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn insert<'py>(
+        &self,
+        model: &Bound<'py, PyAny>,
+        property_name: &Bound<'py, PyString>,
+        value: &Bound<'py, PyAny>,
+    ) -> PyResult<()> {
+        let py = model.py();
+        let entries = self.0.get_or_init(|| PyDict::new(py).unbind());
+        // the model is kept alive by the entry, so its id can't be reused by another model during serialization
+        let entry = PyTuple::new(py, [model, value])?;
+        entries.bind(py).set_item(Self::key(model, property_name)?, entry)
+    }
+
+    // keyed on the identity of the model, models needn't be hashable
+    fn key<'py>(model: &Bound<'py, PyAny>, property_name: &Bound<'py, PyString>) -> PyResult<Bound<'py, PyTuple>> {
+        let id = PyInt::new(model.py(), model.as_ptr() as usize);
+        PyTuple::new(model.py(), [id.as_any(), property_name.as_any()])
+    }
+}
+
+impl PyGcTraverse for ComputedFieldCache {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        match self.0.get() {
+            Some(entries) => visit.call(entries),
+            None => Ok(()),
+        }
+    }
 }
 
 impl_py_gc_traverse!(ComputedField { serializer });
@@ -3,7 +3,7 @@ use std::str::{from_utf8, FromStr, Utf8Error};
 
 use base64::Engine;
 use pyo3::prelude::*;
-use pyo3::types::{PyDate, PyDateTime, PyDict, PyString, PyTime};
+use pyo3::types::{PyDate, PyDateTime, PyDict, PyString, PyTime, PyTzInfo, PyTzInfoAccess};
 use pyo3::{intern, IntoPyObjectExt};
 
 use serde::ser::Error;
@@ -14,16 +14,19 @@ use crate::serializers::type_serializers::datetime_etc::{
     date_to_milliseconds, date_to_seconds, date_to_string, datetime_to_milliseconds, datetime_to_seconds,
     datetime_to_string, time_to_milliseconds, time_to_seconds, time_to_string,
 };
+use crate::serializers::type_serializers::float::FloatFormat;
 use crate::tools::SchemaDict;
 
-use super::errors::py_err_se_err;
+use super::errors::{py_err_se_err, PydanticSerializationError, SERIALIZATION_ERR_MARKER};
 
 #[derive(Debug, Clone, Copy)]
 #[allow(clippy::struct_field_names)]
 pub(crate) struct SerializationConfig {
     pub temporal_mode: TemporalMode,
+    pub naive_datetime_mode: NaiveDatetimeMode,
     pub bytes_mode: BytesMode,
     pub inf_nan_mode: InfNanMode,
+    pub float_format: Option<FloatFormat>,
 }
 
 impl SerializationConfig {
@@ -36,12 +39,16 @@ impl SerializationConfig {
         } else {
             TimedeltaMode::from_config(config)?.into()
         };
+        let naive_datetime_mode = NaiveDatetimeMode::from_config(config)?;
         let bytes_mode = BytesMode::from_config(config)?;
         let inf_nan_mode = InfNanMode::from_config(config)?;
+        let float_format = FloatFormat::from_config(config)?;
         Ok(Self {
             temporal_mode,
+            naive_datetime_mode,
             bytes_mode,
             inf_nan_mode,
+            float_format,
         })
     }
 
@@ -58,10 +65,19 @@ impl SerializationConfig {
         };
         Ok(Self {
             temporal_mode: resolved_temporal_mode,
+            naive_datetime_mode: NaiveDatetimeMode::default(),
             bytes_mode: BytesMode::from_str(bytes_mode)?,
             inf_nan_mode: InfNanMode::from_str(inf_nan_mode)?,
+            float_format: None,
         })
     }
+
+    pub fn datetime_mode(self) -> DatetimeMode {
+        DatetimeMode {
+            temporal_mode: self.temporal_mode,
+            naive_datetime_mode: self.naive_datetime_mode,
+        }
+    }
 }
 
 pub trait FromConfig {
@@ -120,6 +136,14 @@ serialization_mode! {
     Milliseconds => "milliseconds"
 }
 
+serialization_mode! {
+    NaiveDatetimeMode,
+    "ser_naive_datetime",
+    AsIs => "as-is",
+    AssumeUtc => "assume-utc",
+    Error => "error",
+}
+
 serialization_mode! {
     BytesMode,
     "ser_json_bytes",
@@ -136,6 +160,14 @@ serialization_mode! {
     Strings => "strings",
 }
 
+serialization_mode! {
+    KeyFallbackMode,
+    "ser_json_key_fallback",
+    Infer => "infer",
+    Str => "str",
+    Error => "error",
+}
+
 impl TimedeltaMode {}
 
 impl From<TimedeltaMode> for TemporalMode {
@@ -315,6 +347,86 @@ impl TemporalMode {
     }
 }
 
+impl NaiveDatetimeMode {
+    /// Apply the policy to a datetime which is about to be serialized to JSON, aware datetimes are
+    /// always returned unchanged.
+    pub fn apply<'a, 'py>(self, datetime: &'a Bound<'py, PyDateTime>) -> PyResult<Cow<'a, Bound<'py, PyDateTime>>> {
+        if self == Self::AsIs || !is_naive(datetime)? {
+            return Ok(Cow::Borrowed(datetime));
+        }
+        let py = datetime.py();
+        match self {
+            Self::AsIs => unreachable!(),
+            Self::AssumeUtc => {
+                let kwargs = PyDict::new(py);
+                kwargs.set_item(intern!(py, "tzinfo"), PyTzInfo::utc(py)?)?;
+                let aware = datetime.call_method(intern!(py, "replace"), (), Some(&kwargs))?;
+                Ok(Cow::Owned(aware.downcast_into()?))
+            }
+            Self::Error => Err(PydanticSerializationError::new_err(format!(
+                "Cannot serialize naive datetime `{}` when `ser_naive_datetime` is 'error'",
+                datetime_to_string(datetime)?
+            ))),
+        }
+    }
+}
+
+/// As per the python docs, a datetime is naive if `tzinfo` is `None` or `utcoffset()` returns `None`.
+fn is_naive(datetime: &Bound<'_, PyDateTime>) -> PyResult<bool> {
+    if datetime.get_tzinfo().is_none() {
+        return Ok(true);
+    }
+    Ok(datetime.call_method0(intern!(datetime.py(), "utcoffset"))?.is_none())
+}
+
+/// Serialization mode for `datetime` values, the temporal mode plus the policy for naive datetimes.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DatetimeMode {
+    temporal_mode: TemporalMode,
+    naive_datetime_mode: NaiveDatetimeMode,
+}
+
+impl FromConfig for DatetimeMode {
+    fn from_config(config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        Ok(Self {
+            temporal_mode: TemporalMode::from_config(config)?,
+            naive_datetime_mode: NaiveDatetimeMode::from_config(config)?,
+        })
+    }
+}
+
+impl DatetimeMode {
+    pub fn naive_datetime_mode(self) -> NaiveDatetimeMode {
+        self.naive_datetime_mode
+    }
+
+    pub fn with_temporal_mode(self, temporal_mode: TemporalMode) -> Self {
+        Self { temporal_mode, ..self }
+    }
+
+    pub fn datetime_to_json(self, py: Python, datetime: &Bound<'_, PyDateTime>) -> PyResult<Py<PyAny>> {
+        let datetime = self.naive_datetime_mode.apply(datetime)?;
+        self.temporal_mode.datetime_to_json(py, &datetime)
+    }
+
+    pub fn datetime_json_key<'py>(self, datetime: &Bound<'_, PyDateTime>) -> PyResult<Cow<'py, str>> {
+        let datetime = self.naive_datetime_mode.apply(datetime)?;
+        self.temporal_mode.datetime_json_key(&datetime)
+    }
+
+    pub fn datetime_serialize<S: serde::ser::Serializer>(
+        self,
+        datetime: &Bound<'_, PyDateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let datetime = self
+            .naive_datetime_mode
+            .apply(datetime)
+            .map_err(|err| S::Error::custom(format!("{SERIALIZATION_ERR_MARKER}{}", err.value(datetime.py()))))?;
+        self.temporal_mode.datetime_serialize(&datetime, serializer)
+    }
+}
+
 impl BytesMode {
     pub fn bytes_to_string<'a>(self, py: Python, bytes: &'a [u8]) -> PyResult<Cow<'a, str>> {
         match self {
@@ -3,7 +3,7 @@ use std::fmt::Write;
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::PyString;
+use pyo3::types::{PyDict, PyString, PyTuple};
 
 use crate::tools::truncate_safe_repr;
 
@@ -162,6 +162,25 @@ impl PydanticSerializationUnexpectedValue {
             self.input_value.clone(),
         ))
     }
+
+    /// The warning as a dict, for `warnings='collect'`.
+    pub(super) fn to_record<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let record = PyDict::new(py);
+        let loc = match &self.field_name {
+            Some(field_name) => PyTuple::new(py, [field_name])?,
+            None => PyTuple::empty(py),
+        };
+        record.set_item("loc", loc)?;
+        record.set_item("msg", self.__str__(py))?;
+        record.set_item("expected", &self.field_type)?;
+        let input_value = self.input_value.as_ref().map(|input_value| input_value.bind(py));
+        let actual = input_value
+            .map(|input_value| input_value.get_type().name())
+            .transpose()?;
+        record.set_item("actual", actual)?;
+        record.set_item("input", input_value)?;
+        Ok(record)
+    }
 }
 
 #[pymethods]
@@ -3,17 +3,22 @@ use std::ffi::CString;
 use std::fmt;
 use std::ops::{Deref, DerefMut};
 use std::string::ToString;
+use std::sync::Arc;
 
 use pyo3::exceptions::{PyTypeError, PyUserWarning, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{PyBool, PyString};
+use pyo3::types::{PyBool, PyList, PyString, PyType};
 use pyo3::{intern, PyTypeInfo};
 
 use serde::ser::Error;
 
+use super::computed_fields::ComputedFieldCache;
 use super::config::SerializationConfig;
 use super::errors::{PydanticSerializationUnexpectedValue, UNEXPECTED_TYPE_SER_MARKER};
+use super::msgpack::MsgpackConfig;
 use super::ob_type::ObTypeLookup;
+use super::shared_refs::SharedReferences;
+use super::subclass::SubclassSerializers;
 use crate::recursion_guard::ContainsRecursionState;
 use crate::recursion_guard::RecursionError;
 use crate::recursion_guard::RecursionGuard;
@@ -33,6 +38,19 @@ pub(crate) struct SerializationState<'a, 'py> {
     /// Inside unions, checks are applied to attempt to select a preferred branch
     pub check: SerCheck,
     pub include_exclude: (Option<Bound<'py, PyAny>>, Option<Bound<'py, PyAny>>),
+    /// The separator joining the items of tuple dict keys, if not `,`
+    pub key_separator: Option<Arc<str>>,
+    /// The `unknown_type_encoder` of the innermost model or dataclass being serialized which has one, tried
+    /// before the `fallback` on values of unknown type
+    pub unknown_type_encoder: Option<Bound<'py, PyAny>>,
+    /// How types msgpack represents natively are serialized, set when serializing to msgpack
+    pub msgpack: Option<MsgpackConfig>,
+    /// The `subclass_serializers` of the `SchemaSerializer` being used, if it has any
+    pub subclass_serializers: Option<Arc<SubclassSerializers>>,
+    /// The values of computed fields with `cache` set, shared by every state derived from this one
+    pub computed_field_cache: ComputedFieldCache,
+    /// The values serialized so far, set if `shared_references` is used
+    pub shared_references: Option<Arc<SharedReferences>>,
     /// Global settings for the serialization process
     pub extra: Extra<'a, 'py>,
 }
@@ -76,6 +94,12 @@ impl<'a, 'py> SerializationState<'a, 'py> {
             field_name: None,
             check: SerCheck::None,
             include_exclude: (include, exclude),
+            key_separator: None,
+            unknown_type_encoder: None,
+            msgpack: None,
+            subclass_serializers: None,
+            computed_field_cache: ComputedFieldCache::default(),
+            shared_references: None,
             extra,
         })
     }
@@ -141,6 +165,27 @@ impl<'a, 'py> SerializationState<'a, 'py> {
         self.scoped_set(SerializationState::include_exclude_mut, (next_include, next_exclude))
     }
 
+    /// Use a model or dataclass's own `unknown_type_encoder` while serializing its fields, keeping the
+    /// encoder of the enclosing model if it doesn't have one.
+    pub fn scoped_unknown_type_encoder<'scope>(
+        &'scope mut self,
+        encoder: Option<&Bound<'py, PyAny>>,
+    ) -> ScopedUnknownTypeEncoderState<'scope, 'a, 'py> {
+        let encoder = encoder.cloned().or_else(|| self.unknown_type_encoder.clone());
+        self.scoped_set(SerializationState::unknown_type_encoder_mut, encoder)
+    }
+
+    /// The serializer registered in `subclass_serializers` for the class of `value`, if it's a subclass of `class`.
+    pub fn subclass_serializer(
+        &self,
+        value: &Bound<'_, PyAny>,
+        class: &Bound<'_, PyType>,
+    ) -> Option<Arc<super::shared::CombinedSerializer>> {
+        self.subclass_serializers
+            .as_ref()
+            .and_then(|subclass_serializers| subclass_serializers.lookup(value, class))
+    }
+
     pub fn include(&self) -> Option<&Bound<'py, PyAny>> {
         self.include_exclude.0.as_ref()
     }
@@ -156,6 +201,10 @@ impl<'a, 'py> SerializationState<'a, 'py> {
         super::infer::SerializeInfer::new(value, self)
     }
 
+    fn unknown_type_encoder_mut(&mut self) -> &mut Option<Bound<'py, PyAny>> {
+        &mut self.unknown_type_encoder
+    }
+
     fn include_exclude_mut(&mut self) -> &mut (Option<Bound<'py, PyAny>>, Option<Bound<'py, PyAny>>) {
         &mut self.include_exclude
     }
@@ -255,6 +304,12 @@ pub(crate) struct ExtraOwned {
     pub context: Option<Py<PyAny>>,
     include: Option<Py<PyAny>>,
     exclude: Option<Py<PyAny>>,
+    key_separator: Option<Arc<str>>,
+    unknown_type_encoder: Option<Py<PyAny>>,
+    msgpack: Option<MsgpackConfig>,
+    subclass_serializers: Option<Arc<SubclassSerializers>>,
+    computed_field_cache: ComputedFieldCache,
+    shared_references: Option<Arc<SharedReferences>>,
 }
 
 impl_py_gc_traverse!(ExtraOwned {
@@ -263,6 +318,9 @@ impl_py_gc_traverse!(ExtraOwned {
     context,
     include,
     exclude,
+    unknown_type_encoder,
+    computed_field_cache,
+    shared_references,
 });
 
 #[derive(Clone)]
@@ -306,6 +364,12 @@ impl ExtraOwned {
             context: extra.context.map(|model| model.clone().into()),
             include: state.include().map(|m| m.clone().into()),
             exclude: state.exclude().map(|m| m.clone().into()),
+            key_separator: state.key_separator.clone(),
+            unknown_type_encoder: state.unknown_type_encoder.as_ref().map(|m| m.clone().into()),
+            msgpack: state.msgpack,
+            subclass_serializers: state.subclass_serializers.clone(),
+            computed_field_cache: state.computed_field_cache.clone(),
+            shared_references: state.shared_references.clone(),
         }
     }
 
@@ -343,6 +407,12 @@ impl ExtraOwned {
                 self.include.as_ref().map(|m| m.bind(py).clone()),
                 self.exclude.as_ref().map(|m| m.bind(py).clone()),
             ),
+            key_separator: self.key_separator.clone(),
+            unknown_type_encoder: self.unknown_type_encoder.as_ref().map(|m| m.bind(py).clone()),
+            msgpack: self.msgpack,
+            subclass_serializers: self.subclass_serializers.clone(),
+            computed_field_cache: self.computed_field_cache.clone(),
+            shared_references: self.shared_references.clone(),
             extra,
         }
     }
@@ -401,6 +471,8 @@ pub enum WarningsMode {
     None,
     Warn,
     Error,
+    // warnings are returned alongside the serialized value
+    Collect,
 }
 
 impl<'py> FromPyObject<'py> for WarningsMode {
@@ -412,13 +484,14 @@ impl<'py> FromPyObject<'py> for WarningsMode {
                 "none" => Ok(Self::None),
                 "warn" => Ok(Self::Warn),
                 "error" => Ok(Self::Error),
+                "collect" => Ok(Self::Collect),
                 _ => Err(PyValueError::new_err(
-                    "Invalid warnings parameter, should be `'none'`, `'warn'`, `'error'` or a `bool`",
+                    "Invalid warnings parameter, should be `'none'`, `'warn'`, `'error'`, `'collect'` or a `bool`",
                 )),
             }
         } else {
             Err(PyTypeError::new_err(
-                "Invalid warnings parameter, should be `'none'`, `'warn'`, `'error'` or a `bool`",
+                "Invalid warnings parameter, should be `'none'`, `'warn'`, `'error'`, `'collect'` or a `bool`",
             ))
         }
     }
@@ -510,7 +583,7 @@ impl CollectWarnings {
     }
 
     pub fn final_check(&self, py: Python) -> PyResult<()> {
-        if self.mode == WarningsMode::None {
+        if matches!(self.mode, WarningsMode::None | WarningsMode::Collect) {
             return Ok(());
         }
 
@@ -528,6 +601,19 @@ impl CollectWarnings {
             Err(PydanticSerializationError::new_err(message))
         }
     }
+
+    /// The warnings as a list of records if they're being collected, otherwise `None`.
+    pub fn collected<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyList>>> {
+        if self.mode != WarningsMode::Collect {
+            return Ok(None);
+        }
+        let records = self
+            .warnings
+            .iter()
+            .map(|warning| warning.to_record(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        PyList::new(py, records).map(Some)
+    }
 }
 
 impl ContainsRecursionState for SerializationState<'_, '_> {
@@ -584,3 +670,11 @@ type ScopedIncludeExcludeState<'scope, 'a, 'py> = ScopedSetState<
     for<'s> fn(&'s mut SerializationState<'a, 'py>) -> &'s mut (Option<Bound<'py, PyAny>>, Option<Bound<'py, PyAny>>),
     (Option<Bound<'py, PyAny>>, Option<Bound<'py, PyAny>>),
 >;
+
+type ScopedUnknownTypeEncoderState<'scope, 'a, 'py> = ScopedSetState<
+    'scope,
+    'a,
+    'py,
+    for<'s> fn(&'s mut SerializationState<'a, 'py>) -> &'s mut Option<Bound<'py, PyAny>>,
+    Option<Bound<'py, PyAny>>,
+>;
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 use std::cell::RefCell;
 
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyNotImplementedError, PyTypeError};
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyComplex;
@@ -27,13 +27,16 @@ use super::extra::SerMode;
 use super::filter::{AnyFilter, SchemaFilter};
 use super::ob_type::ObType;
 use super::shared::any_dataclass_iter;
+use super::shared_refs::track_to_python;
 use super::SchemaSerializer;
 
 pub(crate) fn infer_to_python<'py>(
     value: &Bound<'py, PyAny>,
     state: &mut SerializationState<'_, 'py>,
 ) -> PyResult<Py<PyAny>> {
-    infer_to_python_known(state.extra.ob_type_lookup.get_type(value), value, state)
+    track_to_python(value, state, |state| {
+        infer_to_python_known(state.extra.ob_type_lookup.get_type(value), value, state)
+    })
 }
 
 // arbitrary ids to identify that we recursed through infer_to_{python,json}_known
@@ -151,7 +154,7 @@ pub(crate) fn infer_to_python_known<'py>(
             ObType::Datetime => {
                 let datetime = state
                     .config
-                    .temporal_mode
+                    .datetime_mode()
                     .datetime_to_json(value.py(), value.downcast()?)?;
                 datetime.into_py_any(py)?
             }
@@ -169,6 +172,8 @@ pub(crate) fn infer_to_python_known<'py>(
             }
             ObType::Url
             | ObType::MultiHostUrl
+            | ObType::Version
+            | ObType::Ulid
             | ObType::Path
             | ObType::Ipv4Address
             | ObType::Ipv6Address
@@ -208,8 +213,7 @@ pub(crate) fn infer_to_python_known<'py>(
             }
             ObType::Pattern => serialize_pattern(value, serialize_to_python())?,
             ObType::Unknown => {
-                if let Some(fallback) = state.extra.fallback {
-                    let next_value = fallback.call1((value,))?;
+                if let Some(next_value) = call_fallback(value, state)? {
                     let next_result = infer_to_python(&next_value, state);
                     return next_result;
                 } else if state.extra.serialize_unknown {
@@ -256,8 +260,7 @@ pub(crate) fn infer_to_python_known<'py>(
                 v.into_py_any(py)?
             }
             ObType::Unknown => {
-                if let Some(fallback) = state.extra.fallback {
-                    let next_value = fallback.call1((value,))?;
+                if let Some(next_value) = call_fallback(value, state)? {
                     let next_result = infer_to_python(&next_value, state);
                     return next_result;
                 }
@@ -370,9 +373,17 @@ pub(crate) fn infer_serialize_known<'py, S: Serializer>(
         }
         ObType::Float | ObType::FloatSubclass => {
             let v = value.extract::<f64>().map_err(py_err_se_err)?;
-            type_serializers::float::serialize_f64(v, serializer, state.config.inf_nan_mode)
-        }
-        ObType::Decimal => value.to_string().serialize(serializer),
+            type_serializers::float::serialize_f64(
+                v,
+                serializer,
+                state.config.inf_nan_mode,
+                state.config.float_format.as_ref(),
+            )
+        }
+        ObType::Decimal => match state.msgpack {
+            Some(msgpack) => msgpack.serialize_decimal(value, serializer),
+            None => value.to_string().serialize(serializer),
+        },
         ObType::Str | ObType::StrSubclass => {
             let py_str = value.downcast::<PyString>().map_err(py_err_se_err)?;
             serialize_to_json(serializer)
@@ -381,7 +392,10 @@ pub(crate) fn infer_serialize_known<'py, S: Serializer>(
         }
         ObType::Bytes => {
             let py_bytes = value.downcast::<PyBytes>().map_err(py_err_se_err)?;
-            state.config.bytes_mode.serialize_bytes(py_bytes.as_bytes(), serializer)
+            match state.msgpack {
+                Some(msgpack) => msgpack.serialize_bytes(py_bytes.as_bytes(), serializer),
+                None => state.config.bytes_mode.serialize_bytes(py_bytes.as_bytes(), serializer),
+            }
         }
         ObType::Bytearray => {
             let py_byte_array = value.downcast::<PyByteArray>().map_err(py_err_se_err)?;
@@ -391,7 +405,10 @@ pub(crate) fn infer_serialize_known<'py, S: Serializer>(
                 // run any code which could cause the critical section to be
                 // released.
                 let bytes = unsafe { py_byte_array.as_bytes() };
-                state.config.bytes_mode.serialize_bytes(bytes, serializer)
+                match state.msgpack {
+                    Some(msgpack) => msgpack.serialize_bytes(bytes, serializer),
+                    None => state.config.bytes_mode.serialize_bytes(bytes, serializer),
+                }
             })
         }
         ObType::Dict => {
@@ -404,7 +421,10 @@ pub(crate) fn infer_serialize_known<'py, S: Serializer>(
         ObType::Frozenset => serialize_seq!(PyFrozenSet),
         ObType::Datetime => {
             let py_datetime = value.downcast().map_err(py_err_se_err)?;
-            state.config.temporal_mode.datetime_serialize(py_datetime, serializer)
+            match state.msgpack {
+                Some(msgpack) => msgpack.serialize_datetime(py_datetime, state.config.datetime_mode(), serializer),
+                None => state.config.datetime_mode().datetime_serialize(py_datetime, serializer),
+            }
         }
         ObType::Date => {
             let py_date = value.downcast().map_err(py_err_se_err)?;
@@ -420,6 +440,8 @@ pub(crate) fn infer_serialize_known<'py, S: Serializer>(
         }
         ObType::Url
         | ObType::MultiHostUrl
+        | ObType::Version
+        | ObType::Ulid
         | ObType::Path
         | ObType::Ipv4Address
         | ObType::Ipv6Address
@@ -457,8 +479,7 @@ pub(crate) fn infer_serialize_known<'py, S: Serializer>(
         }
         ObType::Pattern => serialize_pattern(value, serialize_to_json(serializer)).map_err(unwrap_ser_error),
         ObType::Unknown => {
-            if let Some(fallback) = state.extra.fallback {
-                let next_value = fallback.call1((value,)).map_err(py_err_se_err)?;
+            if let Some(next_value) = call_fallback(value, state).map_err(py_err_se_err)? {
                 let next_result = infer_serialize(&next_value, serializer, state);
                 return next_result;
             } else if state.extra.serialize_unknown {
@@ -544,7 +565,7 @@ pub(crate) fn infer_json_key_known<'a, 'py>(
             })
             .map(|cow| Cow::Owned(cow.into_owned()))
         }
-        ObType::Datetime => state.config.temporal_mode.datetime_json_key(key.downcast()?),
+        ObType::Datetime => state.config.datetime_mode().datetime_json_key(key.downcast()?),
         ObType::Date => state.config.temporal_mode.date_json_key(key.downcast()?),
         ObType::Time => state.config.temporal_mode.time_json_key(key.downcast()?),
         ObType::Uuid => {
@@ -557,6 +578,8 @@ pub(crate) fn infer_json_key_known<'a, 'py>(
         }
         ObType::Url
         | ObType::MultiHostUrl
+        | ObType::Version
+        | ObType::Ulid
         | ObType::Path
         | ObType::Ipv4Address
         | ObType::Ipv6Address
@@ -566,7 +589,7 @@ pub(crate) fn infer_json_key_known<'a, 'py>(
             Ok(Cow::Owned(key.str()?.to_string_lossy().into_owned()))
         }
         ObType::Tuple => {
-            let mut key_build = super::type_serializers::tuple::KeyBuilder::new();
+            let mut key_build = super::type_serializers::tuple::KeyBuilder::new(state.key_separator.clone());
             for element in key.downcast::<PyTuple>()?.iter_borrowed() {
                 key_build.push(&infer_json_key(&element, state)?);
             }
@@ -596,8 +619,7 @@ pub(crate) fn infer_json_key_known<'a, 'py>(
                 .into_owned(),
         )),
         ObType::Unknown => {
-            if let Some(fallback) = state.extra.fallback {
-                let next_key = fallback.call1((key,))?;
+            if let Some(next_key) = call_fallback(key, state)? {
                 infer_json_key(&next_key, state).map(|cow| Cow::Owned(cow.into_owned()))
             } else if state.extra.serialize_unknown {
                 Ok(serialize_unknown(key))
@@ -608,6 +630,43 @@ pub(crate) fn infer_json_key_known<'a, 'py>(
     }
 }
 
+/// Call the `unknown_type_encoder` of the enclosing model (outside python mode), then the `fallback`, on a
+/// value of unknown type.
+/// `fallback` is either a function or a sequence of functions which are tried in order, where a function
+/// (including the `unknown_type_encoder`) can raise `NotImplementedError` to pass the value on to the next.
+///
+/// Returns `None` if there's no encoder or fallback, or every function raised `NotImplementedError`.
+fn call_fallback<'py>(
+    value: &Bound<'py, PyAny>,
+    state: &SerializationState<'_, 'py>,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    if let Some(encoder) = state
+        .unknown_type_encoder
+        .as_ref()
+        .filter(|_| !matches!(state.extra.mode, SerMode::Python))
+    {
+        match encoder.call1((value,)) {
+            Ok(next_value) => return Ok(Some(next_value)),
+            Err(err) if err.is_instance_of::<PyNotImplementedError>(value.py()) => {}
+            Err(err) => return Err(err),
+        }
+    }
+    let Some(fallback) = state.extra.fallback else {
+        return Ok(None);
+    };
+    if fallback.is_callable() {
+        return fallback.call1((value,)).map(Some);
+    }
+    for function in fallback.try_iter()? {
+        match function?.call1((value,)) {
+            Ok(next_value) => return Ok(Some(next_value)),
+            Err(err) if err.is_instance_of::<PyNotImplementedError>(value.py()) => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(None)
+}
+
 /// Serialize `value` as if it had a `__pydantic_serializer__` attribute
 ///
 /// `do_serialize` should be a closure which performs serialization without type inference
@@ -627,6 +686,12 @@ pub(crate) fn call_pydantic_serializer<'py, T, E: From<PyErr>>(
         field_name: state.field_name.clone(),
         include_exclude: state.include_exclude.clone(),
         check: state.check,
+        key_separator: state.key_separator.clone(),
+        unknown_type_encoder: state.unknown_type_encoder.clone(),
+        msgpack: state.msgpack,
+        subclass_serializers: state.subclass_serializers.clone(),
+        computed_field_cache: state.computed_field_cache.clone(),
+        shared_references: state.shared_references.clone(),
         extra: state.extra.clone(),
     };
 
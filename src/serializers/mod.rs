@@ -2,20 +2,29 @@ use std::fmt::Debug;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
+use pyo3::exceptions::PyValueError;
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyBytes, PyDict, PyTuple, PyType};
-use pyo3::{PyTraverseError, PyVisit};
+use pyo3::{IntoPyObjectExt, PyTraverseError, PyVisit};
 use type_serializers::any::AnySerializer;
 
 use crate::definitions::{Definitions, DefinitionsBuilder};
 use crate::py_gc::PyGcTraverse;
 
+use compression::Compression;
+pub(crate) use compression::CODECS as COMPRESSION_CODECS;
 pub(crate) use config::{BytesMode, SerializationConfig};
 pub use errors::{PydanticSerializationError, PydanticSerializationUnexpectedValue};
 pub(crate) use extra::{Extra, SerMode, SerializationState, WarningsMode};
-use shared::to_json_bytes;
+use msgpack::{to_msgpack_bytes, MsgpackConfig};
 pub use shared::CombinedSerializer;
+use shared::{to_json_bytes, to_json_compressed_bytes, to_json_file, to_jsonl_bytes};
+use shared_refs::{SharedReferences, SharedReferencesMode};
+use subclass::SubclassSerializers;
 
+mod canonical;
+mod compression;
 mod computed_fields;
 mod config;
 mod errors;
@@ -23,10 +32,14 @@ mod extra;
 mod fields;
 mod filter;
 mod infer;
+mod msgpack;
 mod ob_type;
 mod prebuilt;
 pub mod ser;
 mod shared;
+mod shared_refs;
+mod sort_keys;
+mod subclass;
 mod type_serializers;
 
 #[derive(FromPyObject)]
@@ -35,6 +48,37 @@ pub enum WarningsArg {
     Literal(WarningsMode),
 }
 
+impl WarningsArg {
+    fn mode(self) -> WarningsMode {
+        match self {
+            WarningsArg::Bool(b) => b.into(),
+            WarningsArg::Literal(mode) => mode,
+        }
+    }
+
+    /// The mode for methods which can't return collected warnings alongside their output.
+    fn mode_without_collect(self, method: &str) -> PyResult<WarningsMode> {
+        match self.mode() {
+            WarningsMode::Collect => Err(PyValueError::new_err(format!(
+                "`warnings='collect'` is not supported by `{method}`, only by `to_python` and `to_json`"
+            ))),
+            mode => Ok(mode),
+        }
+    }
+}
+
+/// Pair `value` with the warnings collected while serializing it, if `warnings='collect'` was used.
+fn with_collected_warnings(
+    py: Python<'_>,
+    state: &SerializationState<'_, '_>,
+    value: Py<PyAny>,
+) -> PyResult<Py<PyAny>> {
+    match state.warnings.collected(py)? {
+        Some(warnings) => (value, warnings).into_py_any(py),
+        None => Ok(value),
+    }
+}
+
 #[pyclass(module = "pydantic_core._pydantic_core", frozen)]
 #[derive(Debug)]
 pub struct SchemaSerializer {
@@ -42,43 +86,58 @@ pub struct SchemaSerializer {
     definitions: Definitions<Arc<CombinedSerializer>>,
     expected_json_size: AtomicUsize,
     config: SerializationConfig,
+    subclass_serializers: Option<Arc<SubclassSerializers>>,
     // References to the Python schema and config objects are saved to enable
     // reconstructing the object for pickle support (see `__reduce__`).
     py_schema: Py<PyDict>,
     py_config: Option<Py<PyDict>>,
+    py_subclass_serializers: Option<Py<PyDict>>,
 }
 
 impl_py_gc_traverse!(SchemaSerializer {
     serializer,
     definitions,
+    subclass_serializers,
     py_schema,
     py_config,
+    py_subclass_serializers,
 });
 
 #[pymethods]
 impl SchemaSerializer {
     #[new]
-    #[pyo3(signature = (schema, config=None))]
-    pub fn py_new(schema: Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+    #[pyo3(signature = (schema, config=None, subclass_serializers=None))]
+    pub fn py_new(
+        schema: Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        subclass_serializers: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Self> {
         let mut definitions_builder = DefinitionsBuilder::new();
         let serializer = CombinedSerializer::build_base(schema.downcast()?, config, &mut definitions_builder)?;
+        let py_subclass_serializers = subclass_serializers.filter(|s| !s.is_empty());
+        let subclass_serializers = py_subclass_serializers
+            .map(|s| SubclassSerializers::build(s, config, &mut definitions_builder).map(Arc::new))
+            .transpose()?;
         Ok(Self {
             serializer,
             definitions: definitions_builder.finish()?,
             expected_json_size: AtomicUsize::new(1024),
             config: SerializationConfig::from_config(config)?,
+            subclass_serializers,
             py_schema: schema.into(),
             py_config: match config {
                 Some(c) if !c.is_empty() => Some(c.clone().into()),
                 _ => None,
             },
+            py_subclass_serializers: py_subclass_serializers.map(|s| s.clone().unbind()),
         })
     }
 
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (value, *, mode = None, include = None, exclude = None, by_alias = None,
         exclude_unset = false, exclude_defaults = false, exclude_none = false, exclude_computed_fields = false,
-        round_trip = false, warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None))]
+        round_trip = false, warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None,
+        shared_references = None))]
     pub fn to_python(
         &self,
         py: Python,
@@ -96,12 +155,11 @@ impl SchemaSerializer {
         fallback: Option<&Bound<'_, PyAny>>,
         serialize_as_any: bool,
         context: Option<&Bound<'_, PyAny>>,
+        shared_references: Option<&str>,
     ) -> PyResult<Py<PyAny>> {
         let mode: SerMode = mode.into();
-        let warnings_mode = match warnings {
-            WarningsArg::Bool(b) => b.into(),
-            WarningsArg::Literal(mode) => mode,
-        };
+        let warnings_mode = warnings.mode();
+        let shared_references = SharedReferencesMode::from_arg(shared_references)?;
         let extra = Extra::new(
             py,
             &mode,
@@ -117,21 +175,29 @@ impl SchemaSerializer {
             context,
         );
         let mut state = SerializationState::new(self.config, warnings_mode, include, exclude, extra)?;
+        state.subclass_serializers.clone_from(&self.subclass_serializers);
+        state.shared_references = shared_references.map(|mode| SharedReferences::new(py, mode));
         let v = self.serializer.to_python(value, &mut state)?;
+        if let Some(shared_references) = &state.shared_references {
+            shared_references.resolve(v.bind(py))?;
+        }
         state.warnings.final_check(py)?;
-        Ok(v)
+        with_collected_warnings(py, &state, v)
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (value, *, indent = None, ensure_ascii = false, include = None, exclude = None, by_alias = None,
-        exclude_unset = false, exclude_defaults = false, exclude_none = false, exclude_computed_fields = false,
-        round_trip = false, warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None))]
+    #[pyo3(signature = (value, *, indent = None, ensure_ascii = false, sort_keys = false, include = None,
+        exclude = None, by_alias = None, exclude_unset = false, exclude_defaults = false, exclude_none = false,
+        exclude_computed_fields = false, round_trip = false, warnings = WarningsArg::Bool(true), fallback = None,
+        serialize_as_any = false, context = None, writer = None, shared_references = None, compress = None,
+        level = None))]
     pub fn to_json(
         &self,
         py: Python,
         value: &Bound<'_, PyAny>,
         indent: Option<usize>,
         ensure_ascii: Option<bool>,
+        sort_keys: bool,
         include: Option<Bound<'_, PyAny>>,
         exclude: Option<Bound<'_, PyAny>>,
         by_alias: Option<bool>,
@@ -144,11 +210,14 @@ impl SchemaSerializer {
         fallback: Option<&Bound<'_, PyAny>>,
         serialize_as_any: bool,
         context: Option<&Bound<'_, PyAny>>,
+        writer: Option<&Bound<'_, PyAny>>,
+        shared_references: Option<&str>,
+        compress: Option<&str>,
+        level: Option<i32>,
     ) -> PyResult<Py<PyAny>> {
-        let warnings_mode = match warnings {
-            WarningsArg::Bool(b) => b.into(),
-            WarningsArg::Literal(mode) => mode,
-        };
+        let warnings_mode = warnings.mode();
+        let shared_references = SharedReferencesMode::from_arg(shared_references)?;
+        let compression = Compression::from_args(compress, level)?;
         let extra = Extra::new(
             py,
             &SerMode::Json,
@@ -164,24 +233,231 @@ impl SchemaSerializer {
             context,
         );
         let mut state = SerializationState::new(self.config, warnings_mode, include, exclude, extra)?;
+        state.subclass_serializers.clone_from(&self.subclass_serializers);
+        state.shared_references = shared_references.map(|mode| SharedReferences::new(py, mode));
+
+        // references can only be resolved once the whole value is serialized, so with `shared_references`
+        // the value is serialized to JSON compatible python objects, which are then written as JSON
+        let resolved;
+        let json_mode = SerMode::Json;
+        let mut resolved_state;
+        let (value, serializer, json_state) = match state.shared_references.clone() {
+            Some(shared_references) => {
+                resolved = self.serializer.to_python(value, &mut state)?.into_bound(py);
+                shared_references.resolve(&resolved)?;
+                let extra = Extra::new(
+                    py, &json_mode, None, false, false, false, false, false, false, None, false, None,
+                );
+                resolved_state = SerializationState::new(self.config, WarningsMode::None, None, None, extra)?;
+                (&resolved, AnySerializer::get(), &mut resolved_state)
+            }
+            None => (value, &self.serializer, &mut state),
+        };
+        if let Some(writer) = writer {
+            to_json_file(
+                value,
+                serializer,
+                json_state,
+                indent,
+                ensure_ascii.unwrap_or(false),
+                sort_keys,
+                compression,
+                writer,
+            )?;
+            state.warnings.final_check(py)?;
+            return with_collected_warnings(py, &state, py.None());
+        }
+        if let Some(compression) = compression {
+            let bytes = to_json_compressed_bytes(
+                value,
+                serializer,
+                json_state,
+                indent,
+                ensure_ascii.unwrap_or(false),
+                sort_keys,
+                compression,
+            )?;
+            state.warnings.final_check(py)?;
+            return with_collected_warnings(py, &state, PyBytes::new(py, &bytes).into());
+        }
         let bytes = to_json_bytes(
             value,
-            &self.serializer,
-            &mut state,
+            serializer,
+            json_state,
             indent,
             ensure_ascii.unwrap_or(false),
+            sort_keys,
             self.expected_json_size.load(Ordering::Relaxed),
         )?;
 
         state.warnings.final_check(py)?;
 
         self.expected_json_size.store(bytes.len(), Ordering::Relaxed);
+        let py_bytes = PyBytes::new(py, &bytes);
+        with_collected_warnings(py, &state, py_bytes.into())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (values, *, ensure_ascii = false, include = None, exclude = None, by_alias = None,
+        exclude_unset = false, exclude_defaults = false, exclude_none = false, exclude_computed_fields = false,
+        round_trip = false, warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None))]
+    pub fn to_jsonl(
+        &self,
+        py: Python,
+        values: &Bound<'_, PyAny>,
+        ensure_ascii: Option<bool>,
+        include: Option<Bound<'_, PyAny>>,
+        exclude: Option<Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        exclude_computed_fields: bool,
+        round_trip: bool,
+        warnings: WarningsArg,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Py<PyAny>> {
+        let warnings_mode = warnings.mode_without_collect("to_jsonl")?;
+        let extra = Extra::new(
+            py,
+            &SerMode::Json,
+            by_alias,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            exclude_computed_fields,
+            round_trip,
+            false,
+            fallback,
+            serialize_as_any,
+            context,
+        );
+        let mut state = SerializationState::new(self.config, warnings_mode, include, exclude, extra)?;
+        state.subclass_serializers.clone_from(&self.subclass_serializers);
+        let bytes = to_jsonl_bytes(
+            values,
+            &self.serializer,
+            &mut state,
+            ensure_ascii.unwrap_or(false),
+            self.expected_json_size.load(Ordering::Relaxed),
+        )?;
+
+        state.warnings.final_check(py)?;
+
         let py_bytes = PyBytes::new(py, &bytes);
         Ok(py_bytes.into())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, *, bytes_mode = "bin", datetime_mode = "timestamp", decimal_mode = "str", include = None,
+        exclude = None, by_alias = None, exclude_unset = false, exclude_defaults = false, exclude_none = false,
+        exclude_computed_fields = false, round_trip = false, warnings = WarningsArg::Bool(true), fallback = None,
+        serialize_as_any = false, context = None))]
+    pub fn to_msgpack(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        bytes_mode: &str,
+        datetime_mode: &str,
+        decimal_mode: &str,
+        include: Option<Bound<'_, PyAny>>,
+        exclude: Option<Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        exclude_computed_fields: bool,
+        round_trip: bool,
+        warnings: WarningsArg,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Py<PyAny>> {
+        let msgpack_config = MsgpackConfig::from_args(bytes_mode, datetime_mode, decimal_mode)?;
+        let warnings_mode = warnings.mode_without_collect("to_msgpack")?;
+        let extra = Extra::new(
+            py,
+            &SerMode::Json,
+            by_alias,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            exclude_computed_fields,
+            round_trip,
+            false,
+            fallback,
+            serialize_as_any,
+            context,
+        );
+        let mut state = SerializationState::new(self.config, warnings_mode, include, exclude, extra)?;
+        state.subclass_serializers.clone_from(&self.subclass_serializers);
+        state.msgpack = Some(msgpack_config);
+        let bytes = to_msgpack_bytes(
+            value,
+            &self.serializer,
+            &mut state,
+            self.expected_json_size.load(Ordering::Relaxed),
+        )?;
+
+        state.warnings.final_check(py)?;
+
+        let py_bytes = PyBytes::new(py, &bytes);
+        Ok(py_bytes.into())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, *, algorithm = "sha256", include = None, exclude = None, by_alias = None,
+        exclude_unset = false, exclude_defaults = false, exclude_none = false, exclude_computed_fields = false,
+        round_trip = false, warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None))]
+    pub fn hash(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        algorithm: &str,
+        include: Option<Bound<'_, PyAny>>,
+        exclude: Option<Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        exclude_computed_fields: bool,
+        round_trip: bool,
+        warnings: WarningsArg,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<String> {
+        let hasher = py
+            .import(intern!(py, "hashlib"))?
+            .call_method1(intern!(py, "new"), (algorithm,))?;
+        let warnings_mode = warnings.mode_without_collect("hash")?;
+        let extra = Extra::new(
+            py,
+            &SerMode::Json,
+            by_alias,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            exclude_computed_fields,
+            round_trip,
+            false,
+            fallback,
+            serialize_as_any,
+            context,
+        );
+        let mut state = SerializationState::new(self.config, warnings_mode, include, exclude, extra)?;
+        state.subclass_serializers.clone_from(&self.subclass_serializers);
+        let digest = canonical::to_json_hash(value, &self.serializer, &mut state, &hasher)?;
+        state.warnings.final_check(py)?;
+        Ok(digest)
+    }
+
     pub fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
-        let init_args = (&slf.get().py_schema, &slf.get().py_config).into_pyobject(slf.py())?;
+        let slf_ref = slf.get();
+        let init_args =
+            (&slf_ref.py_schema, &slf_ref.py_config, &slf_ref.py_subclass_serializers).into_pyobject(slf.py())?;
         Ok((slf.get_type(), init_args))
     }
 
@@ -199,15 +475,16 @@ impl SchemaSerializer {
 
 #[allow(clippy::too_many_arguments)]
 #[pyfunction]
-#[pyo3(signature = (value, *, indent = None, ensure_ascii = false, include = None, exclude = None, by_alias = true,
-    exclude_none = false, round_trip = false, timedelta_mode = "iso8601", temporal_mode = "iso8601",
+#[pyo3(signature = (value, *, indent = None, ensure_ascii = false, sort_keys = false, include = None, exclude = None,
+    by_alias = true, exclude_none = false, round_trip = false, timedelta_mode = "iso8601", temporal_mode = "iso8601",
     bytes_mode = "utf8",  inf_nan_mode = "constants", serialize_unknown = false, fallback = None,
-    serialize_as_any = false, context = None))]
+    serialize_as_any = false, context = None, compress = None, level = None))]
 pub fn to_json(
     py: Python,
     value: &Bound<'_, PyAny>,
     indent: Option<usize>,
     ensure_ascii: Option<bool>,
+    sort_keys: bool,
     include: Option<Bound<'_, PyAny>>,
     exclude: Option<Bound<'_, PyAny>>,
     by_alias: bool,
@@ -221,8 +498,11 @@ pub fn to_json(
     fallback: Option<&Bound<'_, PyAny>>,
     serialize_as_any: bool,
     context: Option<&Bound<'_, PyAny>>,
+    compress: Option<&str>,
+    level: Option<i32>,
 ) -> PyResult<Py<PyAny>> {
     let config = SerializationConfig::from_args(timedelta_mode, temporal_mode, bytes_mode, inf_nan_mode)?;
+    let compression = Compression::from_args(compress, level)?;
     let extra = Extra::new(
         py,
         &SerMode::Json,
@@ -238,14 +518,27 @@ pub fn to_json(
         context,
     );
     let mut state = SerializationState::new(config, WarningsMode::None, include, exclude, extra)?;
-    let bytes = to_json_bytes(
-        value,
-        AnySerializer::get(),
-        &mut state,
-        indent,
-        ensure_ascii.unwrap_or(false),
-        1024,
-    )?;
+    let ensure_ascii = ensure_ascii.unwrap_or(false);
+    let bytes = match compression {
+        Some(compression) => to_json_compressed_bytes(
+            value,
+            AnySerializer::get(),
+            &mut state,
+            indent,
+            ensure_ascii,
+            sort_keys,
+            compression,
+        )?,
+        None => to_json_bytes(
+            value,
+            AnySerializer::get(),
+            &mut state,
+            indent,
+            ensure_ascii,
+            sort_keys,
+            1024,
+        )?,
+    };
     state.final_check(py)?;
     let py_bytes = PyBytes::new(py, &bytes);
     Ok(py_bytes.into())
@@ -253,12 +546,13 @@ pub fn to_json(
 
 #[allow(clippy::too_many_arguments)]
 #[pyfunction]
-#[pyo3(signature = (value, *, include = None, exclude = None, by_alias = true, exclude_none = false, round_trip = false,
-    timedelta_mode = "iso8601", temporal_mode = "iso8601", bytes_mode = "utf8", inf_nan_mode = "constants",
-    serialize_unknown = false, fallback = None, serialize_as_any = false, context = None))]
+#[pyo3(signature = (value, *, sort_keys = false, include = None, exclude = None, by_alias = true, exclude_none = false,
+    round_trip = false, timedelta_mode = "iso8601", temporal_mode = "iso8601", bytes_mode = "utf8",
+    inf_nan_mode = "constants", serialize_unknown = false, fallback = None, serialize_as_any = false, context = None))]
 pub fn to_jsonable_python(
     py: Python,
     value: &Bound<'_, PyAny>,
+    sort_keys: bool,
     include: Option<Bound<'_, PyAny>>,
     exclude: Option<Bound<'_, PyAny>>,
     by_alias: bool,
@@ -289,7 +583,10 @@ pub fn to_jsonable_python(
         context,
     );
     let mut state = SerializationState::new(config, WarningsMode::None, include, exclude, extra)?;
-    let v = infer::infer_to_python(value, &mut state)?;
+    let mut v = infer::infer_to_python(value, &mut state)?;
+    if sort_keys {
+        v = sort_keys::sort_python_keys(v.bind(py))?.unbind();
+    }
     state.final_check(py)?;
     Ok(v)
 }
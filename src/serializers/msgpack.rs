@@ -0,0 +1,627 @@
+use std::str::FromStr;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDateTime;
+use serde::ser::{self, Error, Serialize};
+
+use crate::input::pydatetime_as_datetime;
+
+use super::config::{BytesMode, DatetimeMode, TemporalMode};
+use super::errors::{py_err_se_err, se_err_py_err, PythonSerializerError, SERIALIZATION_ERR_MARKER};
+use super::extra::SerializationState;
+use super::shared::{CombinedSerializer, PydanticSerializer};
+
+// matches the token `serde_json` uses for arbitrary precision numbers, see `ser::RawNumber`
+const NUMBER_TOKEN: &str = "$serde_json::private::Number";
+// marks the bytes of a msgpack timestamp extension, see `MsgpackConfig::serialize_datetime`
+const TIMESTAMP_TOKEN: &str = "$pydantic_core::msgpack::Timestamp";
+const TIMESTAMP_EXT_TYPE: i8 = -1;
+
+/// How `SchemaSerializer.to_msgpack` serializes the types msgpack can represent other than as strings.
+#[derive(Debug, Clone, Copy)]
+#[allow(clippy::struct_field_names)]
+pub(crate) struct MsgpackConfig {
+    bytes_mode: MsgpackBytesMode,
+    datetime_mode: MsgpackDatetimeMode,
+    decimal_mode: MsgpackDecimalMode,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum MsgpackBytesMode {
+    Bin,
+    Str(BytesMode),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum MsgpackDatetimeMode {
+    Timestamp,
+    Temporal(TemporalMode),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum MsgpackDecimalMode {
+    Str,
+    Float,
+}
+
+impl MsgpackConfig {
+    pub fn from_args(bytes_mode: &str, datetime_mode: &str, decimal_mode: &str) -> PyResult<Self> {
+        let bytes_mode = match bytes_mode {
+            "bin" => MsgpackBytesMode::Bin,
+            "utf8" | "base64" | "hex" => MsgpackBytesMode::Str(BytesMode::from_str(bytes_mode)?),
+            s => {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid msgpack bytes mode: `{s}`, expected 'bin', 'utf8', 'base64' or 'hex'"
+                )))
+            }
+        };
+        let datetime_mode = match datetime_mode {
+            "timestamp" => MsgpackDatetimeMode::Timestamp,
+            "iso8601" | "seconds" | "milliseconds" => {
+                MsgpackDatetimeMode::Temporal(TemporalMode::from_str(datetime_mode)?)
+            }
+            s => {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid msgpack datetime mode: `{s}`, expected 'timestamp', 'iso8601', 'seconds' or 'milliseconds'"
+                )))
+            }
+        };
+        let decimal_mode = match decimal_mode {
+            "str" => MsgpackDecimalMode::Str,
+            "float" => MsgpackDecimalMode::Float,
+            s => {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid msgpack decimal mode: `{s}`, expected 'str' or 'float'"
+                )))
+            }
+        };
+        Ok(Self {
+            bytes_mode,
+            datetime_mode,
+            decimal_mode,
+        })
+    }
+
+    pub fn serialize_bytes<S: serde::ser::Serializer>(self, bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        match self.bytes_mode {
+            MsgpackBytesMode::Bin => serializer.serialize_bytes(bytes),
+            MsgpackBytesMode::Str(bytes_mode) => bytes_mode.serialize_bytes(bytes, serializer),
+        }
+    }
+
+    /// Serialize a datetime as a msgpack timestamp, naive datetimes are handled as configured by
+    /// `ser_naive_datetime` and are otherwise taken to be UTC.
+    pub fn serialize_datetime<S: serde::ser::Serializer>(
+        self,
+        datetime: &Bound<'_, PyDateTime>,
+        datetime_mode: DatetimeMode,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self.datetime_mode {
+            MsgpackDatetimeMode::Timestamp => {
+                let datetime = datetime_mode.naive_datetime_mode().apply(datetime).map_err(|err| {
+                    S::Error::custom(format!("{SERIALIZATION_ERR_MARKER}{}", err.value(datetime.py())))
+                })?;
+                let dt = pydatetime_as_datetime(&datetime).map_err(py_err_se_err)?;
+                let payload = timestamp_payload(dt.timestamp_tz(), dt.time.microsecond * 1000);
+                serializer.serialize_newtype_struct(TIMESTAMP_TOKEN, &ExtPayload(&payload))
+            }
+            MsgpackDatetimeMode::Temporal(temporal_mode) => datetime_mode
+                .with_temporal_mode(temporal_mode)
+                .datetime_serialize(datetime, serializer),
+        }
+    }
+
+    pub fn serialize_decimal<S: serde::ser::Serializer>(
+        self,
+        decimal: &Bound<'_, PyAny>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self.decimal_mode {
+            MsgpackDecimalMode::Str => serializer.collect_str(decimal),
+            MsgpackDecimalMode::Float => serializer.serialize_f64(decimal.extract().map_err(py_err_se_err)?),
+        }
+    }
+}
+
+/// The smallest of the msgpack timestamp formats which can hold `seconds` and `nanoseconds`.
+fn timestamp_payload(seconds: i64, nanoseconds: u32) -> Vec<u8> {
+    if seconds >> 34 == 0 {
+        let value = (u64::from(nanoseconds) << 34) | seconds as u64;
+        if value >> 32 == 0 {
+            (value as u32).to_be_bytes().to_vec()
+        } else {
+            value.to_be_bytes().to_vec()
+        }
+    } else {
+        let mut payload = nanoseconds.to_be_bytes().to_vec();
+        payload.extend_from_slice(&seconds.to_be_bytes());
+        payload
+    }
+}
+
+struct ExtPayload<'a>(&'a [u8]);
+
+impl Serialize for ExtPayload<'_> {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+pub(super) fn to_msgpack_bytes<'py>(
+    value: &Bound<'py, PyAny>,
+    serializer: &CombinedSerializer,
+    state: &mut SerializationState<'_, 'py>,
+    expected_size: usize,
+) -> PyResult<Vec<u8>> {
+    let serializer = PydanticSerializer::new(value, serializer, state);
+    let mut ser = MsgpackSerializer {
+        output: Vec::with_capacity(expected_size),
+        ext_type: None,
+    };
+    serializer.serialize(&mut ser).map_err(se_err_py_err)?;
+    Ok(ser.output)
+}
+
+type Result<T, E = PythonSerializerError> = std::result::Result<T, E>;
+
+fn msgpack_error(msg: impl std::fmt::Display) -> PythonSerializerError {
+    PythonSerializerError::custom(format!("{SERIALIZATION_ERR_MARKER}{msg}"))
+}
+
+/// A `serde` serializer writing msgpack.
+///
+/// Arrays and maps are written before their length is known, since items can be excluded after the length was
+/// given, the header is inserted before the items when the array or map ends.
+struct MsgpackSerializer {
+    output: Vec<u8>,
+    // set by `TIMESTAMP_TOKEN`, the next bytes are written as an extension of this type
+    ext_type: Option<i8>,
+}
+
+impl MsgpackSerializer {
+    fn write_uint(&mut self, value: u64) {
+        if value < 0x80 {
+            self.output.push(value as u8);
+        } else if let Ok(v) = u8::try_from(value) {
+            self.output.extend_from_slice(&[0xcc, v]);
+        } else if let Ok(v) = u16::try_from(value) {
+            self.output.push(0xcd);
+            self.output.extend_from_slice(&v.to_be_bytes());
+        } else if let Ok(v) = u32::try_from(value) {
+            self.output.push(0xce);
+            self.output.extend_from_slice(&v.to_be_bytes());
+        } else {
+            self.output.push(0xcf);
+            self.output.extend_from_slice(&value.to_be_bytes());
+        }
+    }
+
+    fn write_int(&mut self, value: i64) {
+        if let Ok(v) = u64::try_from(value) {
+            self.write_uint(v);
+        } else if value >= -32 {
+            self.output.push(value as u8);
+        } else if let Ok(v) = i8::try_from(value) {
+            self.output.push(0xd0);
+            self.output.extend_from_slice(&v.to_be_bytes());
+        } else if let Ok(v) = i16::try_from(value) {
+            self.output.push(0xd1);
+            self.output.extend_from_slice(&v.to_be_bytes());
+        } else if let Ok(v) = i32::try_from(value) {
+            self.output.push(0xd2);
+            self.output.extend_from_slice(&v.to_be_bytes());
+        } else {
+            self.output.push(0xd3);
+            self.output.extend_from_slice(&value.to_be_bytes());
+        }
+    }
+
+    /// Write a header with a length, `fix` is the marker of the format holding small lengths in its low bits.
+    fn write_header(output: &mut Vec<u8>, len: usize, fix: Option<(u8, usize)>, markers: [u8; 3]) -> Result<()> {
+        match (fix, len) {
+            (Some((marker, max)), len) if len <= max => output.push(marker | len as u8),
+            (_, len) if markers[0] != 0 && len <= 0xff => output.extend_from_slice(&[markers[0], len as u8]),
+            (_, len) if len <= 0xffff => {
+                output.push(markers[1]);
+                output.extend_from_slice(&(len as u16).to_be_bytes());
+            }
+            (_, len) => {
+                let len = u32::try_from(len).map_err(|_| msgpack_error("Value is too long for msgpack"))?;
+                output.push(markers[2]);
+                output.extend_from_slice(&len.to_be_bytes());
+            }
+        }
+        Ok(())
+    }
+
+    fn write_str(&mut self, value: &str) -> Result<()> {
+        Self::write_header(&mut self.output, value.len(), Some((0xa0, 31)), [0xd9, 0xda, 0xdb])?;
+        self.output.extend_from_slice(value.as_bytes());
+        Ok(())
+    }
+
+    fn write_ext(&mut self, ext_type: i8, value: &[u8]) -> Result<()> {
+        match value.len() {
+            1 => self.output.push(0xd4),
+            2 => self.output.push(0xd5),
+            4 => self.output.push(0xd6),
+            8 => self.output.push(0xd7),
+            16 => self.output.push(0xd8),
+            len => Self::write_header(&mut self.output, len, None, [0xc7, 0xc8, 0xc9])?,
+        }
+        self.output.extend_from_slice(&ext_type.to_be_bytes());
+        self.output.extend_from_slice(value);
+        Ok(())
+    }
+
+    fn compound(&mut self, kind: CompoundKind) -> Compound<'_> {
+        let start = self.output.len();
+        Compound {
+            ser: self,
+            kind,
+            start,
+            len: 0,
+        }
+    }
+
+    /// The number serialized by `serde_json` as a string, see `NUMBER_TOKEN`.
+    fn write_number(&mut self, number: &str) -> Result<()> {
+        if let Ok(v) = number.parse::<i64>() {
+            self.write_int(v);
+        } else if let Ok(v) = number.parse::<u64>() {
+            self.write_uint(v);
+        } else if number.bytes().all(|b| b.is_ascii_digit() || b == b'-') {
+            return Err(msgpack_error(format!("Integer `{number}` is too large for msgpack")));
+        } else {
+            let v = number.parse::<f64>().map_err(msgpack_error)?;
+            ser::Serializer::serialize_f64(self, v)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum CompoundKind {
+    Array,
+    Map,
+    Number,
+}
+
+struct Compound<'a> {
+    ser: &'a mut MsgpackSerializer,
+    kind: CompoundKind,
+    // where the items start in the output, the header is inserted here
+    start: usize,
+    len: usize,
+}
+
+impl Compound<'_> {
+    fn element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.len += 1;
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<()> {
+        let mut header = Vec::with_capacity(5);
+        match self.kind {
+            CompoundKind::Array => {
+                MsgpackSerializer::write_header(&mut header, self.len, Some((0x90, 15)), [0, 0xdc, 0xdd])?;
+            }
+            CompoundKind::Map => {
+                MsgpackSerializer::write_header(&mut header, self.len, Some((0x80, 15)), [0, 0xde, 0xdf])?;
+            }
+            CompoundKind::Number => return Ok(()),
+        }
+        self.ser.output.splice(self.start..self.start, header);
+        Ok(())
+    }
+}
+
+impl<'a> ser::Serializer for &'a mut MsgpackSerializer {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    fn serialize_bool(self, value: bool) -> Result<()> {
+        self.output.push(if value { 0xc3 } else { 0xc2 });
+        Ok(())
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<()> {
+        self.write_int(value.into());
+        Ok(())
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<()> {
+        self.write_int(value.into());
+        Ok(())
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<()> {
+        self.write_int(value.into());
+        Ok(())
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<()> {
+        self.write_int(value);
+        Ok(())
+    }
+
+    fn serialize_i128(self, value: i128) -> Result<()> {
+        if let Ok(v) = i64::try_from(value) {
+            self.write_int(v);
+        } else if let Ok(v) = u64::try_from(value) {
+            self.write_uint(v);
+        } else {
+            return Err(msgpack_error(format!("Integer `{value}` is too large for msgpack")));
+        }
+        Ok(())
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<()> {
+        self.write_uint(value.into());
+        Ok(())
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<()> {
+        self.write_uint(value.into());
+        Ok(())
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<()> {
+        self.write_uint(value.into());
+        Ok(())
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<()> {
+        self.write_uint(value);
+        Ok(())
+    }
+
+    fn serialize_u128(self, value: u128) -> Result<()> {
+        let v =
+            u64::try_from(value).map_err(|_| msgpack_error(format!("Integer `{value}` is too large for msgpack")))?;
+        self.write_uint(v);
+        Ok(())
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<()> {
+        self.output.push(0xca);
+        self.output.extend_from_slice(&value.to_be_bytes());
+        Ok(())
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<()> {
+        self.output.push(0xcb);
+        self.output.extend_from_slice(&value.to_be_bytes());
+        Ok(())
+    }
+
+    fn serialize_char(self, value: char) -> Result<()> {
+        self.write_str(value.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<()> {
+        self.write_str(value)
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<()> {
+        if let Some(ext_type) = self.ext_type.take() {
+            return self.write_ext(ext_type, value);
+        }
+        MsgpackSerializer::write_header(&mut self.output, value.len(), None, [0xc4, 0xc5, 0xc6])?;
+        self.output.extend_from_slice(value);
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        self.output.push(0xc0);
+        Ok(())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        self.serialize_none()
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, variant: &'static str) -> Result<()> {
+        self.write_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(self, name: &'static str, value: &T) -> Result<()> {
+        if name == TIMESTAMP_TOKEN {
+            self.ext_type = Some(TIMESTAMP_EXT_TYPE);
+        }
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.output.push(0x81);
+        self.write_str(variant)?;
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Ok(self.compound(CompoundKind::Array))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeTupleStruct> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        self.output.push(0x81);
+        self.write_str(variant)?;
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Ok(self.compound(CompoundKind::Map))
+    }
+
+    fn serialize_struct(self, name: &'static str, len: usize) -> Result<Self::SerializeStruct> {
+        if name == NUMBER_TOKEN {
+            Ok(self.compound(CompoundKind::Number))
+        } else {
+            self.serialize_map(Some(len))
+        }
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        self.output.push(0x81);
+        self.write_str(variant)?;
+        self.serialize_map(Some(len))
+    }
+}
+
+impl ser::SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<()> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<()> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<()> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<()> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<()> {
+        self.element(key)
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<()> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, key: &'static str, value: &T) -> Result<()> {
+        if self.kind == CompoundKind::Number {
+            // the number is serialized as a string, which is taken back off the output to write the number
+            let start = self.ser.output.len();
+            value.serialize(&mut *self.ser)?;
+            let number = self.ser.output.split_off(start);
+            let header_len = match number.first() {
+                Some(0xd9) => 2,
+                Some(0xda) => 3,
+                Some(0xdb) => 5,
+                _ => 1,
+            };
+            let number = std::str::from_utf8(&number[header_len..]).map_err(msgpack_error)?;
+            return self.ser.write_number(number);
+        }
+        self.element(key)?;
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<()> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, key: &'static str, value: &T) -> Result<()> {
+        ser::SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Result<()> {
+        Compound::end(self)
+    }
+}
@@ -9,6 +9,8 @@ use pyo3::{intern, PyTypeInfo};
 use strum::Display;
 use strum_macros::EnumString;
 
+use crate::semver::PyVersion;
+use crate::ulid::PyUlid;
 use crate::url::{PyMultiHostUrl, PyUrl};
 
 #[derive(Debug, Clone)]
@@ -38,6 +40,8 @@ pub struct ObTypeLookup {
     // types from this package
     url: usize,
     multi_host_url: usize,
+    version: usize,
+    ulid: usize,
     // enum type
     enum_object: Py<PyAny>,
     // generator
@@ -88,6 +92,8 @@ impl ObTypeLookup {
             timedelta: PyDelta::type_object_raw(py) as usize,
             url: PyUrl::type_object_raw(py) as usize,
             multi_host_url: PyMultiHostUrl::type_object_raw(py) as usize,
+            version: PyVersion::type_object_raw(py) as usize,
+            ulid: PyUlid::type_object_raw(py) as usize,
             enum_object: py.import("enum").unwrap().getattr("Enum").unwrap().unbind(),
             generator_object: py.import("types").unwrap().getattr("GeneratorType").unwrap().unbind(),
             path_object: py.import("pathlib").unwrap().getattr("Path").unwrap().unbind(),
@@ -160,6 +166,8 @@ impl ObTypeLookup {
             ObType::Bytearray => self.bytearray == ob_type,
             ObType::Url => self.url == ob_type,
             ObType::MultiHostUrl => self.multi_host_url == ob_type,
+            ObType::Version => self.version == ob_type,
+            ObType::Ulid => self.ulid == ob_type,
             ObType::Dataclass => is_dataclass(op_value),
             ObType::PydanticSerializable => is_pydantic_serializable(op_value),
             ObType::Enum => self.enum_object.as_ptr() as usize == ob_type,
@@ -251,6 +259,10 @@ impl ObTypeLookup {
             ObType::Url
         } else if ob_type == self.multi_host_url {
             ObType::MultiHostUrl
+        } else if ob_type == self.version {
+            ObType::Version
+        } else if ob_type == self.ulid {
+            ObType::Ulid
         } else if ob_type == self.complex {
             ObType::Complex
         } else if ob_type == self.uuid_object.as_ptr() as usize {
@@ -339,6 +351,10 @@ impl ObTypeLookup {
             ObType::Url
         } else if value.is_instance_of::<PyMultiHostUrl>() {
             ObType::MultiHostUrl
+        } else if value.is_instance_of::<PyVersion>() {
+            ObType::Version
+        } else if value.is_instance_of::<PyUlid>() {
+            ObType::Ulid
         } else if value.is_instance(self.decimal_object.bind(py)).unwrap_or(false) {
             ObType::Decimal
         } else if value.is_instance(self.uuid_object.bind(py)).unwrap_or(false) {
@@ -428,6 +444,8 @@ pub enum ObType {
     // types from this package
     Url,
     MultiHostUrl,
+    Version,
+    Ulid,
     // anything with __pydantic_serializer__, including BaseModel and pydantic dataclasses
     PydanticSerializable,
     // vanilla dataclasses
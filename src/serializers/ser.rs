@@ -1,6 +1,9 @@
 use std::{io, num::FpCategory};
 
-use serde::{ser::Impossible, Serialize, Serializer};
+use serde::{
+    ser::{Impossible, SerializeStruct},
+    Serialize, Serializer,
+};
 use serde_json::ser::{CompactFormatter, Formatter, PrettyFormatter, State};
 
 use super::errors::PythonSerializerError;
@@ -62,6 +65,17 @@ where
     }
 }
 
+/// A number which is written to JSON as is, `PythonSerializer` writes the string without quotes.
+pub(crate) struct RawNumber<'a>(pub &'a str);
+
+impl Serialize for RawNumber<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut number = serializer.serialize_struct(TOKEN, 1)?;
+        number.serialize_field(TOKEN, self.0)?;
+        number.end()
+    }
+}
+
 impl<'a, W, F> Serializer for &'a mut PythonSerializer<W, F>
 where
     W: io::Write,
@@ -1,23 +1,24 @@
 use std::borrow::Cow;
 use std::cell::RefCell;
 use std::fmt::Debug;
-use std::io::{self, Write};
+use std::io::{self, BufWriter, Write};
 use std::sync::Arc;
 
 use pyo3::exceptions::PyTypeError;
 use pyo3::sync::PyOnceLock;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyBytes, PyDict, PyString};
 use pyo3::{intern, PyTraverseError, PyVisit};
 use pyo3::{prelude::*, IntoPyObjectExt};
 
 use enum_dispatch::enum_dispatch;
 use serde::{Serialize, Serializer};
-use serde_json::ser::{Formatter, PrettyFormatter};
+use serde_json::ser::{CompactFormatter, Formatter, PrettyFormatter};
 
 use crate::build_tools::py_schema_err;
 use crate::build_tools::py_schema_error_type;
 use crate::definitions::DefinitionsBuilder;
 use crate::py_gc::PyGcTraverse;
+use crate::serializers::compression::Compression;
 use crate::serializers::errors::WrappedSerError;
 use crate::serializers::ser::PythonSerializer;
 use crate::serializers::type_serializers::any::AnySerializer;
@@ -27,6 +28,8 @@ use super::errors::se_err_py_err;
 use super::extra::SerializationState;
 use super::infer::{infer_json_key, infer_serialize, infer_to_python};
 use super::ob_type::{IsType, ObType};
+use super::shared_refs::track_to_python;
+use super::sort_keys::SortKeysFormatter;
 
 pub(crate) trait BuildSerializer: Sized {
     const EXPECTED_TYPE: &'static str;
@@ -103,6 +106,13 @@ combined_serializer! {
         super::type_serializers::other::IsInstanceBuilder;
         super::type_serializers::other::IsSubclassBuilder;
         super::type_serializers::other::CallableBuilder;
+        super::type_serializers::other::EmailBuilder;
+        super::type_serializers::other::NameEmailBuilder;
+        super::type_serializers::other::IpV4AddressBuilder;
+        super::type_serializers::other::IpV6AddressBuilder;
+        super::type_serializers::other::IpNetworkBuilder;
+        super::type_serializers::other::IpInterfaceBuilder;
+        super::type_serializers::other::PathBuilder;
         super::type_serializers::definitions::DefinitionsSerializerBuilder;
         super::type_serializers::dataclass::DataclassArgsBuilder;
         super::type_serializers::function::FunctionBeforeSerializerBuilder;
@@ -110,6 +120,7 @@ combined_serializer! {
         super::type_serializers::function::FunctionPlainSerializerBuilder;
         super::type_serializers::function::FunctionWrapSerializerBuilder;
         super::type_serializers::model::ModelFieldsBuilder;
+        super::type_serializers::with_default::ConstBuilder;
     }
     // `both` means the struct is added to both the `CombinedSerializer` enum and the match statement in
     // `find_serializer` so they can be used via a `type` str.
@@ -127,6 +138,7 @@ combined_serializer! {
         Date: super::type_serializers::datetime_etc::DateSerializer;
         Time: super::type_serializers::datetime_etc::TimeSerializer;
         List: super::type_serializers::list::ListSerializer;
+        KeyedList: super::type_serializers::keyed_list::KeyedListSerializer;
         Set: super::type_serializers::set_frozenset::SetSerializer;
         FrozenSet: super::type_serializers::set_frozenset::FrozenSetSerializer;
         Generator: super::type_serializers::generator::GeneratorSerializer;
@@ -135,6 +147,8 @@ combined_serializer! {
         Dataclass: super::type_serializers::dataclass::DataclassSerializer;
         Url: super::type_serializers::url::UrlSerializer;
         MultiHostUrl: super::type_serializers::url::MultiHostUrlSerializer;
+        Semver: super::type_serializers::url::SemverSerializer;
+        Ulid: super::type_serializers::url::UlidSerializer;
         Uuid: super::type_serializers::uuid::UuidSerializer;
         Any: super::type_serializers::any::AnySerializer;
         Format: super::type_serializers::format::FormatSerializer;
@@ -151,6 +165,7 @@ combined_serializer! {
         Tuple: super::type_serializers::tuple::TupleSerializer;
         Complex: super::type_serializers::complex::ComplexSerializer;
         TypedDict: super::type_serializers::typed_dict::TypedDictSerializer;
+        Plugin: super::type_serializers::plugin::PluginSerializer;
     }
 }
 
@@ -203,7 +218,9 @@ impl CombinedSerializer {
                     // applies to lists tuples and dicts, does not override the main schema `type`
                     "include-exclude-sequence" | "include-exclude-dict"
                     // applies specifically to bytes, does not override the main schema `type`
-                    | "base64"
+                    | "base64" | "bytes-format"
+                    // applies specifically to floats, does not override the main schema `type`
+                    | "float-format"
                 )
                 // if `schema.serialization.type` is None, fall back to `schema.type`
                 | None => (),
@@ -251,7 +268,7 @@ impl CombinedSerializer {
         value: &Bound<'py, PyAny>,
         state: &mut SerializationState<'_, 'py>,
     ) -> PyResult<Py<PyAny>> {
-        TypeSerializer::to_python(self, value, state)
+        track_to_python(value, state, |state| TypeSerializer::to_python(self, value, state))
     }
 
     pub fn json_key<'a, 'py>(
@@ -333,6 +350,7 @@ impl PyGcTraverse for CombinedSerializer {
             CombinedSerializer::Date(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Time(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::List(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::KeyedList(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Set(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::FrozenSet(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Generator(inner) => inner.py_gc_traverse(visit),
@@ -341,6 +359,8 @@ impl PyGcTraverse for CombinedSerializer {
             CombinedSerializer::Dataclass(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Url(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::MultiHostUrl(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Semver(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Ulid(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Any(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Format(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::ToString(inner) => inner.py_gc_traverse(visit),
@@ -357,6 +377,7 @@ impl PyGcTraverse for CombinedSerializer {
             CombinedSerializer::Uuid(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Complex(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::TypedDict(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Plugin(inner) => inner.py_gc_traverse(visit),
         }
     }
 }
@@ -541,40 +562,204 @@ pub(crate) fn to_json_bytes<'py>(
     state: &mut SerializationState<'_, 'py>,
     indent: Option<usize>,
     ensure_ascii: bool,
+    sort_keys: bool,
     expected_json_size: usize,
 ) -> PyResult<Vec<u8>> {
-    let serializer = PydanticSerializer::new(value, serializer, state);
-
     let writer: Vec<u8> = Vec::with_capacity(expected_json_size);
+    write_json(value, serializer, state, indent, ensure_ascii, sort_keys, writer)
+}
 
-    let bytes = match (indent, ensure_ascii) {
+/// Serialize `value` as JSON compressed with `compression`, the JSON is compressed as it's serialized so the whole
+/// uncompressed JSON is never held in memory.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn to_json_compressed_bytes<'py>(
+    value: &Bound<'py, PyAny>,
+    serializer: &CombinedSerializer,
+    state: &mut SerializationState<'_, 'py>,
+    indent: Option<usize>,
+    ensure_ascii: bool,
+    sort_keys: bool,
+    compression: Compression,
+) -> PyResult<Vec<u8>> {
+    write_json_compressed(
+        value,
+        serializer,
+        state,
+        indent,
+        ensure_ascii,
+        sort_keys,
+        compression,
+        Vec::new(),
+    )
+}
+
+/// Serialize `value` as JSON to the file-like object `file`, in chunks so the whole JSON is never held in
+/// memory.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn to_json_file<'py>(
+    value: &Bound<'py, PyAny>,
+    serializer: &CombinedSerializer,
+    state: &mut SerializationState<'_, 'py>,
+    indent: Option<usize>,
+    ensure_ascii: bool,
+    sort_keys: bool,
+    compression: Option<Compression>,
+    file: &Bound<'py, PyAny>,
+) -> PyResult<()> {
+    let mut writer = PyFileWriter {
+        file,
+        buffer: Vec::with_capacity(PyFileWriter::CHUNK_SIZE),
+        error: None,
+    };
+    let result = match compression {
+        Some(compression) => write_json_compressed(
+            value,
+            serializer,
+            state,
+            indent,
+            ensure_ascii,
+            sort_keys,
+            compression,
+            &mut writer,
+        ),
+        None => write_json(value, serializer, state, indent, ensure_ascii, sort_keys, &mut writer),
+    };
+    // an error writing to the file is re-raised rather than the serialization error it causes
+    let result = result.and_then(|writer| writer.flush().map_err(PyErr::from));
+    match writer.error.take() {
+        Some(err) => Err(err),
+        None => result,
+    }
+}
+
+fn write_json<'py, W: Write>(
+    value: &Bound<'py, PyAny>,
+    serializer: &CombinedSerializer,
+    state: &mut SerializationState<'_, 'py>,
+    indent: Option<usize>,
+    ensure_ascii: bool,
+    sort_keys: bool,
+    writer: W,
+) -> PyResult<W> {
+    // JSON nested in msgpack, e.g. by a `json` schema, is serialized as it would be on its own
+    let state = &mut state.scoped_set(|s| &mut s.msgpack, None);
+    let serializer = PydanticSerializer::new(value, serializer, state);
+
+    match (indent, ensure_ascii) {
         (Some(indent), true) => {
             let indent = vec![b' '; indent];
             let formatter = EscapeNonAsciiPrettyFormatter::with_indent(&indent);
-            let mut ser = PythonSerializer::with_formatter(writer, formatter);
-            serializer.serialize(&mut ser).map_err(se_err_py_err)?;
-            ser.into_inner()
+            format_json(&serializer, writer, formatter, sort_keys)
         }
         (Some(indent), false) => {
             let indent = vec![b' '; indent];
             let formatter = PrettyFormatter::with_indent(&indent);
-            let mut ser = PythonSerializer::with_formatter(writer, formatter);
-            serializer.serialize(&mut ser).map_err(se_err_py_err)?;
-            ser.into_inner()
+            format_json(&serializer, writer, formatter, sort_keys)
+        }
+        (None, true) => format_json(&serializer, writer, EscapeNonAsciiFormatter, sort_keys),
+        (None, false) => format_json(&serializer, writer, CompactFormatter, sort_keys),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn write_json_compressed<'py, W: Write>(
+    value: &Bound<'py, PyAny>,
+    serializer: &CombinedSerializer,
+    state: &mut SerializationState<'_, 'py>,
+    indent: Option<usize>,
+    ensure_ascii: bool,
+    sort_keys: bool,
+    compression: Compression,
+    writer: W,
+) -> PyResult<W> {
+    // the JSON is written in many small pieces, which are buffered so they're compressed together
+    let encoder = BufWriter::with_capacity(PyFileWriter::CHUNK_SIZE, compression.encoder(writer)?);
+    let encoder = write_json(value, serializer, state, indent, ensure_ascii, sort_keys, encoder)?;
+    let encoder = encoder.into_inner().map_err(io::IntoInnerError::into_error)?;
+    Ok(encoder.finish()?)
+}
+
+fn format_json<W: Write, F: Formatter>(
+    serializer: &PydanticSerializer<'_, '_, '_>,
+    writer: W,
+    formatter: F,
+    sort_keys: bool,
+) -> PyResult<W> {
+    if sort_keys {
+        let mut ser = PythonSerializer::with_formatter(writer, SortKeysFormatter::new(formatter));
+        serializer.serialize(&mut ser).map_err(se_err_py_err)?;
+        Ok(ser.into_inner())
+    } else {
+        let mut ser = PythonSerializer::with_formatter(writer, formatter);
+        serializer.serialize(&mut ser).map_err(se_err_py_err)?;
+        Ok(ser.into_inner())
+    }
+}
+
+/// Buffers JSON and passes it to the `write` method of a Python file-like object in chunks.
+struct PyFileWriter<'a, 'py> {
+    file: &'a Bound<'py, PyAny>,
+    buffer: Vec<u8>,
+    // the error raised by `write`, which can't be returned through `io::Write`
+    error: Option<PyErr>,
+}
+
+impl PyFileWriter<'_, '_> {
+    const CHUNK_SIZE: usize = 65536;
+}
+
+impl Write for PyFileWriter<'_, '_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        if self.buffer.len() >= Self::CHUNK_SIZE {
+            self.flush()?;
         }
-        (None, true) => {
-            let mut ser = PythonSerializer::with_formatter(writer, EscapeNonAsciiFormatter);
-            serializer.serialize(&mut ser).map_err(se_err_py_err)?;
-            ser.into_inner()
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
         }
-        (None, false) => {
-            let mut ser = PythonSerializer::new(writer);
-            serializer.serialize(&mut ser).map_err(se_err_py_err)?;
-            ser.into_inner()
+        let py = self.file.py();
+        let chunk = PyBytes::new(py, &self.buffer);
+        match self.file.call_method1(intern!(py, "write"), (chunk,)) {
+            Ok(_) => {
+                self.buffer.clear();
+                Ok(())
+            }
+            Err(err) => {
+                self.error = Some(err);
+                Err(io::Error::other("error writing JSON to file"))
+            }
         }
-    };
+    }
+}
 
-    Ok(bytes)
+/// Serialize each item of `values` as a line of JSON, all into the same buffer.
+pub(crate) fn to_jsonl_bytes<'py>(
+    values: &Bound<'py, PyAny>,
+    serializer: &CombinedSerializer,
+    state: &mut SerializationState<'_, 'py>,
+    ensure_ascii: bool,
+    expected_json_size: usize,
+) -> PyResult<Vec<u8>> {
+    let mut writer: Vec<u8> = Vec::with_capacity(expected_json_size);
+    for value in values.try_iter()? {
+        let value = value?;
+        let serializer = PydanticSerializer::new(&value, serializer, state);
+        if ensure_ascii {
+            serializer.serialize(&mut PythonSerializer::with_formatter(
+                &mut writer,
+                EscapeNonAsciiFormatter,
+            ))
+        } else {
+            serializer.serialize(&mut PythonSerializer::new(&mut writer))
+        }
+        .map_err(se_err_py_err)?;
+        writer.push(b'\n');
+    }
+    Ok(writer)
 }
 
 #[allow(clippy::type_complexity)]
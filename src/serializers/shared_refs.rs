@@ -0,0 +1,188 @@
+use std::sync::Arc;
+
+use ahash::AHashMap;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyInt, PyList, PyTuple};
+use pyo3::{intern, PyTraverseError, PyVisit};
+
+use crate::py_gc::PyGcTraverse;
+use crate::PydanticSerializationError;
+
+use super::extra::SerializationState;
+
+/// What to do with a value which is serialized more than once, see the `shared_references` argument of
+/// `to_python` and `to_json`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SharedReferencesMode {
+    /// the value is only serialized where it's first found, later occurrences are `{"$ref": "#/json/pointer"}`
+    Ref,
+    /// serialization fails
+    Error,
+}
+
+impl SharedReferencesMode {
+    pub fn from_arg(shared_references: Option<&str>) -> PyResult<Option<Self>> {
+        match shared_references {
+            None => Ok(None),
+            Some("ref") => Ok(Some(Self::Ref)),
+            Some("error") => Ok(Some(Self::Error)),
+            Some(other) => Err(PyValueError::new_err(format!(
+                "Invalid shared_references: `{other}`, expected 'ref' or 'error'"
+            ))),
+        }
+    }
+}
+
+/// The values serialized so far, to find values which are serialized more than once.
+///
+/// Only values serialized to a non-empty dict, e.g. models, dataclasses and dicts, are tracked.
+#[derive(Debug)]
+pub(crate) struct SharedReferences {
+    mode: SharedReferencesMode,
+    // `id(value)` -> `(value, output)`, the value is kept so its id can't be reused during serialization
+    seen: Py<PyDict>,
+    // `(placeholder, output)` pairs, for each later occurrence of a value the placeholder output in its place
+    // and the output of its first occurrence
+    placeholders: Py<PyList>,
+}
+
+impl SharedReferences {
+    pub fn new(py: Python<'_>, mode: SharedReferencesMode) -> Arc<Self> {
+        Arc::new(Self {
+            mode,
+            seen: PyDict::new(py).unbind(),
+            placeholders: PyList::empty(py).unbind(),
+        })
+    }
+
+    /// Replace the placeholders in `output`, the output of serializing the root value, with references to the
+    /// first occurrence of their value, or raise an error if references aren't allowed.
+    pub fn resolve(&self, output: &Bound<'_, PyAny>) -> PyResult<()> {
+        let py = output.py();
+        let placeholders = self.placeholders.bind(py);
+        let mut pending: Vec<(Bound<'_, PyDict>, Bound<'_, PyDict>)> = placeholders
+            .iter()
+            .map(|item| item.extract())
+            .collect::<PyResult<_>>()?;
+        while !pending.is_empty() {
+            let paths = dict_paths(output)?;
+            let path_of = |dict: &Bound<'_, PyDict>| paths.get(&(dict.as_ptr() as usize));
+
+            if self.mode == SharedReferencesMode::Error {
+                let (placeholder, target) = &pending[0];
+                let placeholder_path = path_of(placeholder).map_or("?", |(_, path)| path.as_str());
+                let target_path = path_of(target).map_or("?", |(_, path)| path.as_str());
+                return Err(PydanticSerializationError::new_err(format!(
+                    "The value at `{placeholder_path}` is the same object as the value at `{target_path}`, \
+                     shared references aren't allowed"
+                )));
+            }
+
+            let mut unresolved = Vec::new();
+            for (placeholder, target) in pending {
+                match path_of(&target) {
+                    Some((_, path)) => placeholder.set_item(intern!(py, "$ref"), path)?,
+                    None => unresolved.push((placeholder, target)),
+                }
+            }
+            // the output of a value's first occurrence can be missing if a serializer function replaced it,
+            // then its first placeholder in the output takes its place
+            let first = unresolved
+                .iter()
+                .enumerate()
+                .filter_map(|(i, (placeholder, _))| path_of(placeholder).map(|(position, _)| (*position, i)))
+                .min();
+            match first {
+                Some((_, i)) => {
+                    let (placeholder, target) = unresolved.swap_remove(i);
+                    placeholder.clear();
+                    placeholder.update(target.as_mapping())?;
+                    // later placeholders for the value now refer to this one
+                    for (_, other_target) in &mut unresolved {
+                        if other_target.is(&target) {
+                            *other_target = placeholder.clone();
+                        }
+                    }
+                }
+                // placeholders which aren't in the output don't need resolving
+                None => unresolved.clear(),
+            }
+            pending = unresolved;
+        }
+        Ok(())
+    }
+}
+
+impl PyGcTraverse for SharedReferences {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.seen)?;
+        visit.call(&self.placeholders)
+    }
+}
+
+/// Serialize `value` with `serialize`, unless it's already been serialized to a non-empty dict, in which
+/// case a placeholder for a reference to that output is returned.
+pub(crate) fn track_to_python<'py>(
+    value: &Bound<'py, PyAny>,
+    state: &mut SerializationState<'_, 'py>,
+    serialize: impl FnOnce(&mut SerializationState<'_, 'py>) -> PyResult<Py<PyAny>>,
+) -> PyResult<Py<PyAny>> {
+    // outputs are discarded when union variants don't match, so they can't be referred to
+    let shared_references = match &state.shared_references {
+        Some(shared_references) if !state.check.enabled() => shared_references.clone(),
+        _ => return serialize(state),
+    };
+    let py = value.py();
+    let seen = shared_references.seen.bind(py);
+    let id = PyInt::new(py, value.as_ptr() as usize);
+    if let Some(first) = seen.get_item(&id)? {
+        let output = first.downcast::<PyTuple>()?.get_item(1)?;
+        let placeholder = PyDict::new(py);
+        placeholder.set_item(intern!(py, "$ref"), py.None())?;
+        shared_references.placeholders.bind(py).append((&placeholder, output))?;
+        return Ok(placeholder.into_any().unbind());
+    }
+
+    let output = serialize(state)?;
+    let output_bound = output.bind(py);
+    if output_bound.downcast::<PyDict>().is_ok_and(|dict| !dict.is_empty()) && !seen.contains(&id)? {
+        seen.set_item(id, (value, output_bound))?;
+    }
+    Ok(output)
+}
+
+/// The position and JSON pointer of every dict in `output`, keyed by the dict's address, positions are the
+/// order dicts are found in.
+fn dict_paths(output: &Bound<'_, PyAny>) -> PyResult<AHashMap<usize, (usize, String)>> {
+    let mut paths = AHashMap::new();
+    let mut stack = vec![(output.clone(), "#".to_string())];
+    while let Some((value, path)) = stack.pop() {
+        let mut children = Vec::new();
+        if let Ok(dict) = value.downcast::<PyDict>() {
+            for (key, child) in dict {
+                children.push((
+                    child,
+                    format!("{path}/{}", escape_pointer(&key.str()?.to_string_lossy())),
+                ));
+            }
+            let position = paths.len();
+            paths.entry(dict.as_ptr() as usize).or_insert((position, path));
+        } else if let Ok(list) = value.downcast::<PyList>() {
+            for (index, child) in list.iter().enumerate() {
+                children.push((child, format!("{path}/{index}")));
+            }
+        } else if let Ok(tuple) = value.downcast::<PyTuple>() {
+            for (index, child) in tuple.iter().enumerate() {
+                children.push((child, format!("{path}/{index}")));
+            }
+        }
+        // pushed in reverse so children are visited in order
+        stack.extend(children.into_iter().rev());
+    }
+    Ok(paths)
+}
+
+fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
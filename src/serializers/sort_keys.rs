@@ -0,0 +1,221 @@
+use std::borrow::Cow;
+use std::io::{self, Write};
+
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use serde_json::ser::{CharEscape, Formatter};
+
+/// Formatter which writes the entries of every object sorted by key, otherwise formatting JSON as `F` does.
+///
+/// Objects are buffered until they end so their entries can be sorted, everything else is written straight
+/// through to the writer.
+pub(super) struct SortKeysFormatter<F> {
+    inner: F,
+    objects: Vec<ObjectBuffer>,
+}
+
+#[derive(Default)]
+struct ObjectBuffer {
+    // `(key, value)` pairs of serialized JSON
+    entries: Vec<(Vec<u8>, Vec<u8>)>,
+    in_key: bool,
+}
+
+impl<F: Formatter> SortKeysFormatter<F> {
+    pub fn new(inner: F) -> Self {
+        Self {
+            inner,
+            objects: Vec::new(),
+        }
+    }
+}
+
+/// Where output should currently go, the entry of the innermost object being written, or the writer
+/// if we're not within an object.
+fn sink<'a, W: ?Sized + Write>(objects: &'a mut [ObjectBuffer], writer: &'a mut W) -> Sink<'a, W> {
+    match objects.last_mut() {
+        Some(object) => match object.entries.last_mut() {
+            Some((key, _)) if object.in_key => Sink::Buffer(key),
+            Some((_, value)) => Sink::Buffer(value),
+            None => Sink::Writer(writer),
+        },
+        None => Sink::Writer(writer),
+    }
+}
+
+enum Sink<'a, W: ?Sized> {
+    Writer(&'a mut W),
+    Buffer(&'a mut Vec<u8>),
+}
+
+impl<W: ?Sized + Write> Write for Sink<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Writer(writer) => writer.write(buf),
+            Self::Buffer(buffer) => buffer.write(buf),
+        }
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        match self {
+            Self::Writer(writer) => writer.write_all(buf),
+            Self::Buffer(buffer) => buffer.write_all(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Writer(writer) => writer.flush(),
+            Self::Buffer(_) => Ok(()),
+        }
+    }
+}
+
+/// The text of a serialized key, so keys are ordered by their value rather than how they were escaped.
+fn unescaped_key(key: &[u8]) -> Cow<'_, [u8]> {
+    if key.contains(&b'\\') {
+        if let Ok(key) = serde_json::from_slice::<String>(key) {
+            return Cow::Owned(key.into_bytes());
+        }
+    }
+    let key = key.strip_prefix(b"\"").unwrap_or(key);
+    Cow::Borrowed(key.strip_suffix(b"\"").unwrap_or(key))
+}
+
+macro_rules! redirect {
+    ($fun:ident) => {
+        fn $fun<W>(&mut self, writer: &mut W) -> io::Result<()>
+        where
+            W: ?Sized + io::Write,
+        {
+            self.inner.$fun(&mut sink(&mut self.objects, writer))
+        }
+    };
+    ($fun:ident, $val:ty) => {
+        fn $fun<W>(&mut self, writer: &mut W, val: $val) -> io::Result<()>
+        where
+            W: ?Sized + io::Write,
+        {
+            self.inner.$fun(&mut sink(&mut self.objects, writer), val)
+        }
+    };
+}
+
+impl<F: Formatter> Formatter for SortKeysFormatter<F> {
+    redirect!(write_null);
+    redirect!(write_bool, bool);
+    redirect!(write_i8, i8);
+    redirect!(write_i16, i16);
+    redirect!(write_i32, i32);
+    redirect!(write_i64, i64);
+    redirect!(write_i128, i128);
+    redirect!(write_u8, u8);
+    redirect!(write_u16, u16);
+    redirect!(write_u32, u32);
+    redirect!(write_u64, u64);
+    redirect!(write_u128, u128);
+    redirect!(write_f32, f32);
+    redirect!(write_f64, f64);
+    redirect!(write_number_str, &str);
+    redirect!(begin_string);
+    redirect!(end_string);
+    redirect!(write_string_fragment, &str);
+    redirect!(write_char_escape, CharEscape);
+    redirect!(write_byte_array, &[u8]);
+    redirect!(begin_array);
+    redirect!(end_array);
+    redirect!(begin_array_value, bool);
+    redirect!(end_array_value);
+    redirect!(write_raw_fragment, &str);
+
+    fn begin_object<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        // nothing else is written to the current sink until the object ends, so the opening can be
+        // written straight away
+        self.inner.begin_object(&mut sink(&mut self.objects, writer))?;
+        self.objects.push(ObjectBuffer::default());
+        Ok(())
+    }
+
+    fn end_object<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        let mut object = self.objects.pop().unwrap_or_default();
+        object
+            .entries
+            .sort_by(|(a, _), (b, _)| unescaped_key(a).cmp(&unescaped_key(b)));
+        let mut sink = sink(&mut self.objects, writer);
+        for (index, (key, value)) in object.entries.iter().enumerate() {
+            self.inner.begin_object_key(&mut sink, index == 0)?;
+            sink.write_all(key)?;
+            self.inner.end_object_key(&mut sink)?;
+            self.inner.begin_object_value(&mut sink)?;
+            sink.write_all(value)?;
+            self.inner.end_object_value(&mut sink)?;
+        }
+        self.inner.end_object(&mut sink)
+    }
+
+    fn begin_object_key<W>(&mut self, _writer: &mut W, _first: bool) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        if let Some(object) = self.objects.last_mut() {
+            object.entries.push((Vec::new(), Vec::new()));
+            object.in_key = true;
+        }
+        Ok(())
+    }
+
+    fn end_object_key<W>(&mut self, _writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        if let Some(object) = self.objects.last_mut() {
+            object.in_key = false;
+        }
+        Ok(())
+    }
+
+    fn begin_object_value<W>(&mut self, _writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        Ok(())
+    }
+
+    fn end_object_value<W>(&mut self, _writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        Ok(())
+    }
+}
+
+/// Sort the keys of every dict in `value`, the output of serializing in JSON mode, so dicts and lists
+/// are the only containers.
+pub(super) fn sort_python_keys<'py>(value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let py = value.py();
+    if let Ok(dict) = value.downcast::<PyDict>() {
+        let keys = dict.keys();
+        keys.sort()?;
+        let sorted = PyDict::new(py);
+        for key in keys {
+            if let Some(item) = dict.get_item(&key)? {
+                sorted.set_item(key, sort_python_keys(&item)?)?;
+            }
+        }
+        Ok(sorted.into_any())
+    } else if let Ok(list) = value.downcast::<PyList>() {
+        let items = list
+            .iter()
+            .map(|item| sort_python_keys(&item))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new(py, items)?.into_any())
+    } else {
+        Ok(value.clone())
+    }
+}
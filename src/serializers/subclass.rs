@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyType};
+use pyo3::{PyTraverseError, PyVisit};
+
+use crate::build_tools::py_schema_err;
+use crate::definitions::DefinitionsBuilder;
+use crate::py_gc::PyGcTraverse;
+
+use super::shared::CombinedSerializer;
+
+/// Serializers for subclasses of the classes in a schema, see the `subclass_serializers` argument
+/// of `SchemaSerializer`.
+///
+/// When a model or dataclass value is an instance of a subclass of the class in its schema, the serializer
+/// registered for its exact class is used instead of the schema's serializer.
+#[derive(Debug, Default)]
+pub(crate) struct SubclassSerializers {
+    // a few classes are expected, so a linear scan is fine
+    entries: Vec<(Py<PyType>, Arc<CombinedSerializer>)>,
+}
+
+impl SubclassSerializers {
+    pub fn build(
+        subclass_serializers: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<Arc<CombinedSerializer>>,
+    ) -> PyResult<Self> {
+        let mut entries = Vec::with_capacity(subclass_serializers.len());
+        for (cls, schema) in subclass_serializers {
+            let Ok(cls) = cls.downcast_into::<PyType>() else {
+                return py_schema_err!("`subclass_serializers` keys must be types");
+            };
+            let serializer = CombinedSerializer::build_base(schema.downcast()?, config, definitions)?;
+            // a model or dataclass serializer for another class would look itself up again, forever
+            if let Some(serializer_class) = serializer_class(&serializer) {
+                if !serializer_class.is(&cls) {
+                    return py_schema_err!(
+                        "The serializer for `{}` in `subclass_serializers` is for a different class, `{}`",
+                        cls.qualname()?,
+                        serializer_class.bind(cls.py()).qualname()?
+                    );
+                }
+            }
+            entries.push((cls.unbind(), serializer));
+        }
+        Ok(Self { entries })
+    }
+
+    /// The serializer registered for the class of `value`, if it's a subclass of `class` rather than `class` itself.
+    pub fn lookup(&self, value: &Bound<'_, PyAny>, class: &Bound<'_, PyType>) -> Option<Arc<CombinedSerializer>> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let value_type = value.get_type();
+        if value_type.is(class) || !value_type.is_subclass(class).unwrap_or(false) {
+            return None;
+        }
+        self.entries
+            .iter()
+            .find(|(cls, _)| value_type.is(cls))
+            .map(|(_, serializer)| serializer.clone())
+    }
+}
+
+fn serializer_class(serializer: &CombinedSerializer) -> Option<&Py<PyType>> {
+    match serializer {
+        CombinedSerializer::Model(model) => Some(model.class()),
+        CombinedSerializer::Dataclass(dataclass) => Some(dataclass.class()),
+        _ => None,
+    }
+}
+
+impl PyGcTraverse for SubclassSerializers {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for (cls, serializer) in &self.entries {
+            visit.call(cls)?;
+            serializer.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+}
@@ -1,13 +1,15 @@
 use std::borrow::Cow;
 use std::sync::Arc;
 
+use base64::Engine;
 use pyo3::types::{PyBytes, PyDict};
-use pyo3::{prelude::*, IntoPyObjectExt};
+use pyo3::{intern, prelude::*, IntoPyObjectExt};
 
-use crate::build_tools::LazyLock;
+use crate::build_tools::{py_schema_err, LazyLock};
 use crate::definitions::DefinitionsBuilder;
 use crate::serializers::config::{BytesMode, FromConfig};
 use crate::serializers::SerializationState;
+use crate::tools::SchemaDict;
 
 use super::{
     infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, SerMode, TypeSerializer,
@@ -16,12 +18,77 @@ use super::{
 #[derive(Debug)]
 pub struct BytesSerializer {
     bytes_mode: BytesMode,
+    // from a `bytes-format` serialization schema, takes precedence over `bytes_mode`
+    format: Option<BytesFormat>,
+}
+
+/// How a field's bytes are written to JSON, from a `bytes-format` serialization schema.
+#[derive(Debug, Clone, Copy)]
+enum BytesFormat {
+    Base64,
+    UrlsafeBase64,
+    Base32,
+    Hex,
+}
+
+impl BytesFormat {
+    fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let Some(ser) = schema.get_as::<Bound<'_, PyDict>>(intern!(py, "serialization"))? else {
+            return Ok(None);
+        };
+        if ser.get_as::<String>(intern!(py, "type"))?.as_deref() != Some("bytes-format") {
+            return Ok(None);
+        }
+        let encoding: String = ser.get_as_req(intern!(py, "encoding"))?;
+        match encoding.as_str() {
+            "base64" => Ok(Some(Self::Base64)),
+            "urlsafe-base64" => Ok(Some(Self::UrlsafeBase64)),
+            "base32" => Ok(Some(Self::Base32)),
+            "hex" => Ok(Some(Self::Hex)),
+            s => py_schema_err!(
+                "Invalid bytes encoding: `{}`, expected 'base64', 'urlsafe-base64', 'base32' or 'hex'",
+                s
+            ),
+        }
+    }
+
+    fn encode(self, bytes: &[u8]) -> String {
+        match self {
+            Self::Base64 => base64::engine::general_purpose::STANDARD.encode(bytes),
+            Self::UrlsafeBase64 => base64::engine::general_purpose::URL_SAFE.encode(bytes),
+            Self::Base32 => base32_encode(bytes),
+            Self::Hex => hex::encode(bytes),
+        }
+    }
+}
+
+/// RFC 4648 base32 with padding.
+fn base32_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(5) * 8);
+    for chunk in bytes.chunks(5) {
+        // each group of 5 bytes is written as 8 characters of 5 bits each
+        let group = (0..5).fold(0u64, |acc, i| {
+            (acc << 8) | u64::from(chunk.get(i).copied().unwrap_or(0))
+        });
+        let chars = (chunk.len() * 8).div_ceil(5);
+        for i in 0..8 {
+            if i < chars {
+                encoded.push(ALPHABET[((group >> (35 - i * 5)) & 0x1f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
 }
 
 static BYTES_SERIALIZER_UTF8: LazyLock<Arc<CombinedSerializer>> = LazyLock::new(|| {
     Arc::new(
         BytesSerializer {
             bytes_mode: BytesMode::Utf8,
+            format: None,
         }
         .into(),
     )
@@ -31,6 +98,7 @@ static BYTES_SERIALIZER_BASE64: LazyLock<Arc<CombinedSerializer>> = LazyLock::ne
     Arc::new(
         BytesSerializer {
             bytes_mode: BytesMode::Base64,
+            format: None,
         }
         .into(),
     )
@@ -40,6 +108,7 @@ static BYTES_SERIALIZER_HEX: LazyLock<Arc<CombinedSerializer>> = LazyLock::new(|
     Arc::new(
         BytesSerializer {
             bytes_mode: BytesMode::Hex,
+            format: None,
         }
         .into(),
     )
@@ -49,11 +118,20 @@ impl BuildSerializer for BytesSerializer {
     const EXPECTED_TYPE: &'static str = "bytes";
 
     fn build(
-        _schema: &Bound<'_, PyDict>,
+        schema: &Bound<'_, PyDict>,
         config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<Arc<CombinedSerializer>>,
     ) -> PyResult<Arc<CombinedSerializer>> {
         let bytes_mode = BytesMode::from_config(config)?;
+        if let Some(format) = BytesFormat::from_schema(schema)? {
+            return Ok(Arc::new(
+                Self {
+                    bytes_mode,
+                    format: Some(format),
+                }
+                .into(),
+            ));
+        }
         match bytes_mode {
             BytesMode::Utf8 => Ok(BYTES_SERIALIZER_UTF8.clone()),
             BytesMode::Base64 => Ok(BYTES_SERIALIZER_BASE64.clone()),
@@ -62,6 +140,22 @@ impl BuildSerializer for BytesSerializer {
     }
 }
 
+impl BytesSerializer {
+    fn bytes_to_string<'a>(&self, py: Python, bytes: &'a [u8]) -> PyResult<Cow<'a, str>> {
+        match self.format {
+            Some(format) => Ok(Cow::Owned(format.encode(bytes))),
+            None => self.bytes_mode.bytes_to_string(py, bytes),
+        }
+    }
+
+    fn serialize_bytes<S: serde::ser::Serializer>(&self, bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        match self.format {
+            Some(format) => serializer.serialize_str(&format.encode(bytes)),
+            None => self.bytes_mode.serialize_bytes(bytes, serializer),
+        }
+    }
+}
+
 impl_py_gc_traverse!(BytesSerializer {});
 
 impl TypeSerializer for BytesSerializer {
@@ -73,10 +167,7 @@ impl TypeSerializer for BytesSerializer {
         let py = value.py();
         match value.downcast::<PyBytes>() {
             Ok(py_bytes) => match state.extra.mode {
-                SerMode::Json => self
-                    .bytes_mode
-                    .bytes_to_string(py, py_bytes.as_bytes())?
-                    .into_py_any(py),
+                SerMode::Json => self.bytes_to_string(py, py_bytes.as_bytes())?.into_py_any(py),
                 _ => Ok(value.clone().unbind()),
             },
             Err(_) => {
@@ -92,7 +183,7 @@ impl TypeSerializer for BytesSerializer {
         state: &mut SerializationState<'_, 'py>,
     ) -> PyResult<Cow<'a, str>> {
         match key.downcast::<PyBytes>() {
-            Ok(py_bytes) => self.bytes_mode.bytes_to_string(key.py(), py_bytes.as_bytes()),
+            Ok(py_bytes) => self.bytes_to_string(key.py(), py_bytes.as_bytes()),
             Err(_) => {
                 state.warn_fallback_py(self.get_name(), key)?;
                 infer_json_key(key, state)
@@ -107,7 +198,10 @@ impl TypeSerializer for BytesSerializer {
         state: &mut SerializationState<'_, 'py>,
     ) -> Result<S::Ok, S::Error> {
         match value.downcast::<PyBytes>() {
-            Ok(py_bytes) => self.bytes_mode.serialize_bytes(py_bytes.as_bytes(), serializer),
+            Ok(py_bytes) => match state.msgpack {
+                Some(msgpack) => msgpack.serialize_bytes(py_bytes.as_bytes(), serializer),
+                None => self.serialize_bytes(py_bytes.as_bytes(), serializer),
+            },
             Err(_) => {
                 state.warn_fallback_ser::<S>(self.get_name(), value)?;
                 infer_serialize(value, serializer, state)
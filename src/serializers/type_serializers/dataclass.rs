@@ -7,7 +7,7 @@ use std::sync::Arc;
 use ahash::AHashMap;
 use serde::ser::SerializeMap;
 
-use crate::build_tools::{py_schema_error_type, ExtraBehavior};
+use crate::build_tools::{py_schema_error_type, ExtraBehavior, FieldAliases};
 use crate::definitions::DefinitionsBuilder;
 use crate::serializers::SerializationState;
 use crate::tools::SchemaDict;
@@ -32,6 +32,7 @@ impl BuildSerializer for DataclassArgsBuilder {
 
         let fields_list: Bound<'_, PyList> = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: AHashMap<String, SerField> = AHashMap::with_capacity(fields_list.len());
+        let aliases = FieldAliases::from_schema(schema)?;
 
         let fields_mode = match ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Ignore)? {
             ExtraBehavior::Allow => FieldsMode::TypedDictAllow,
@@ -56,7 +57,10 @@ impl BuildSerializer for DataclassArgsBuilder {
                     let serializer = CombinedSerializer::build(&schema, config, definitions)
                         .map_err(|e| py_schema_error_type!("Field `{}`:\n  {}", index, e))?;
 
-                    let alias = field_info.get_as(intern!(py, "serialization_alias"))?;
+                    let alias = aliases
+                        .get(field_info, intern!(py, "serialization_alias"), &name)?
+                        .map(|alias| alias.extract())
+                        .transpose()?;
                     let serialization_exclude_if: Option<Py<PyAny>> =
                         field_info.get_as(intern!(py, "serialization_exclude_if"))?;
                     fields.insert(
@@ -86,6 +90,7 @@ pub struct DataclassSerializer {
     serializer: Arc<CombinedSerializer>,
     fields: Vec<Py<PyString>>,
     name: String,
+    unknown_type_encoder: Option<Py<PyAny>>,
 }
 
 impl BuildSerializer for DataclassSerializer {
@@ -116,6 +121,7 @@ impl BuildSerializer for DataclassSerializer {
             serializer,
             fields,
             name: class.getattr(intern!(py, "__name__"))?.extract()?,
+            unknown_type_encoder: schema.get_as(intern!(py, "unknown_type_encoder"))?,
         })
         .into())
     }
@@ -130,6 +136,14 @@ impl DataclassSerializer {
         }
     }
 
+    pub(crate) fn class(&self) -> &Py<PyType> {
+        &self.class
+    }
+
+    fn unknown_type_encoder<'a, 'py>(&'a self, py: Python<'py>) -> Option<&'a Bound<'py, PyAny>> {
+        self.unknown_type_encoder.as_ref().map(|encoder| encoder.bind(py))
+    }
+
     fn get_inner_value<'py>(&self, value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyDict>> {
         let py = value.py();
         let dict = PyDict::new(py);
@@ -142,7 +156,11 @@ impl DataclassSerializer {
     }
 }
 
-impl_py_gc_traverse!(DataclassSerializer { class, serializer });
+impl_py_gc_traverse!(DataclassSerializer {
+    class,
+    serializer,
+    unknown_type_encoder
+});
 
 impl TypeSerializer for DataclassSerializer {
     fn to_python<'py>(
@@ -151,8 +169,12 @@ impl TypeSerializer for DataclassSerializer {
         state: &mut SerializationState<'_, 'py>,
     ) -> PyResult<Py<PyAny>> {
         if self.allow_value(value, state)? {
+            if let Some(serializer) = state.subclass_serializer(value, self.class.bind(value.py())) {
+                return serializer.to_python(value, state);
+            }
             let model = value;
             let state = &mut state.scoped_set(|s| &mut s.model, Some(value.clone()));
+            let state = &mut state.scoped_unknown_type_encoder(self.unknown_type_encoder(value.py()));
             let py = value.py();
             if let CombinedSerializer::Fields(ref fields_serializer) = *self.serializer {
                 let output_dict: Bound<PyDict> =
@@ -190,7 +212,11 @@ impl TypeSerializer for DataclassSerializer {
         state: &mut SerializationState<'_, 'py>,
     ) -> Result<S::Ok, S::Error> {
         if self.allow_value(value, state).map_err(py_err_se_err)? {
+            if let Some(subclass_serializer) = state.subclass_serializer(value, self.class.bind(value.py())) {
+                return subclass_serializer.serde_serialize(value, serializer, state);
+            }
             let state = &mut state.scoped_set(|s| &mut s.model, Some(value.clone()));
+            let state = &mut state.scoped_unknown_type_encoder(self.unknown_type_encoder(value.py()));
             if let CombinedSerializer::Fields(ref fields_serializer) = *self.serializer {
                 let expected_len = self.fields.len() + fields_serializer.computed_field_count();
                 let mut map = fields_serializer.main_serde_serialize(
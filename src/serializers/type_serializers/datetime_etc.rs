@@ -9,7 +9,7 @@ use super::{
 };
 use crate::definitions::DefinitionsBuilder;
 use crate::input::{pydate_as_date, pydatetime_as_datetime, pytime_as_time};
-use crate::serializers::config::{FromConfig, TemporalMode};
+use crate::serializers::config::{DatetimeMode, FromConfig, TemporalMode};
 use crate::serializers::SerializationState;
 use crate::PydanticSerializationUnexpectedValue;
 
@@ -85,15 +85,17 @@ fn downcast_date_reject_datetime<'a, 'py>(py_date: &'a Bound<'py, PyAny>) -> PyR
 macro_rules! build_temporal_serializer {
     (
         $Struct:ident,
+        $Mode:ident,
         $expected_type:literal,
         $downcast:path,
         $to_json:ident,
         $json_key_fn:ident,
         $serialize_fn:ident
+        $(, msgpack = $msgpack_fn:ident)?
     ) => {
         #[derive(Debug)]
         pub struct $Struct {
-            temporal_mode: TemporalMode,
+            mode: $Mode,
         }
 
         impl BuildSerializer for $Struct {
@@ -104,8 +106,8 @@ macro_rules! build_temporal_serializer {
                 config: Option<&Bound<'_, PyDict>>,
                 _definitions: &mut DefinitionsBuilder<Arc<CombinedSerializer>>,
             ) -> PyResult<Arc<CombinedSerializer>> {
-                let temporal_mode = TemporalMode::from_config(config)?;
-                Ok(Arc::new(Self { temporal_mode }.into()))
+                let mode = $Mode::from_config(config)?;
+                Ok(Arc::new(Self { mode }.into()))
             }
         }
 
@@ -119,7 +121,7 @@ macro_rules! build_temporal_serializer {
             ) -> PyResult<Py<PyAny>> {
                 match $downcast(value) {
                     Ok(py_value) => match state.extra.mode {
-                        SerMode::Json => Ok(self.temporal_mode.$to_json(value.py(), py_value)?),
+                        SerMode::Json => Ok(self.mode.$to_json(value.py(), py_value)?),
                         _ => Ok(value.clone().unbind()),
                     },
                     _ => {
@@ -135,7 +137,7 @@ macro_rules! build_temporal_serializer {
                 state: &mut SerializationState<'_, 'py>,
             ) -> PyResult<Cow<'a, str>> {
                 match $downcast(key) {
-                    Ok(py_value) => Ok(self.temporal_mode.$json_key_fn(py_value)?),
+                    Ok(py_value) => Ok(self.mode.$json_key_fn(py_value)?),
                     Err(_) => {
                         state.warn_fallback_py(self.get_name(), key)?;
                         infer_json_key(key, state)
@@ -150,7 +152,12 @@ macro_rules! build_temporal_serializer {
                 state: &mut SerializationState<'_, 'py>,
             ) -> Result<S::Ok, S::Error> {
                 match $downcast(value) {
-                    Ok(py_value) => self.temporal_mode.$serialize_fn(py_value, serializer),
+                    Ok(py_value) => {
+                        $(if let Some(msgpack) = state.msgpack {
+                            return msgpack.$msgpack_fn(py_value, self.mode, serializer);
+                        })?
+                        self.mode.$serialize_fn(py_value, serializer)
+                    }
                     Err(_) => {
                         state.warn_fallback_ser::<S>(self.get_name(), value)?;
                         infer_serialize(value, serializer, state)
@@ -167,15 +174,18 @@ macro_rules! build_temporal_serializer {
 
 build_temporal_serializer!(
     DatetimeSerializer,
+    DatetimeMode,
     "datetime",
     PyAnyMethods::downcast::<PyDateTime>,
     datetime_to_json,
     datetime_json_key,
-    datetime_serialize
+    datetime_serialize,
+    msgpack = serialize_datetime
 );
 
 build_temporal_serializer!(
     DateSerializer,
+    TemporalMode,
     "date",
     downcast_date_reject_datetime,
     date_to_json,
@@ -185,6 +195,7 @@ build_temporal_serializer!(
 
 build_temporal_serializer!(
     TimeSerializer,
+    TemporalMode,
     "time",
     PyAnyMethods::downcast::<PyTime>,
     time_to_json,
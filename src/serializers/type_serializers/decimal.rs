@@ -1,35 +1,100 @@
 use std::borrow::Cow;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyString};
+use pyo3::{intern, IntoPyObjectExt};
+use serde::Serialize;
 
-use crate::build_tools::LazyLock;
+use crate::build_tools::{py_schema_err, LazyLock};
 use crate::definitions::DefinitionsBuilder;
+use crate::serializers::config::InfNanMode;
+use crate::serializers::errors::py_err_se_err;
 use crate::serializers::infer::{infer_json_key_known, infer_serialize_known, infer_to_python_known};
 use crate::serializers::ob_type::{IsType, ObType};
+use crate::serializers::ser::RawNumber;
 use crate::serializers::SerializationState;
+use crate::tools::SchemaDict;
+use crate::validators::decimal::Quantize;
 
-use super::{infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, TypeSerializer};
+use super::float::{schema_inf_nan_mode, serialize_f64};
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, SerMode, TypeSerializer,
+};
 
 #[derive(Debug)]
-pub struct DecimalSerializer {}
+pub struct DecimalSerializer {
+    // `None` to write decimals to JSON as strings, otherwise how infinite and NaN decimals are written when
+    // writing decimals as numbers
+    number: Option<InfNanMode>,
+    // rounding from the `rounding` and `quantize_to` keys, so values are written as they'd be validated
+    quantize: Option<Quantize>,
+}
+
+/// How decimals are written to JSON, from the `ser_mode` of a decimal schema.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum DecimalSerMode {
+    #[default]
+    String,
+    Number,
+}
 
-static DECIMAL_SERIALIZER: LazyLock<Arc<CombinedSerializer>> = LazyLock::new(|| Arc::new(DecimalSerializer {}.into()));
+impl FromStr for DecimalSerMode {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "string" => Ok(Self::String),
+            "number" => Ok(Self::Number),
+            s => py_schema_err!("Invalid decimal ser_mode: `{}`, expected `string` or `number`", s),
+        }
+    }
+}
+
+static DECIMAL_SERIALIZER: LazyLock<Arc<CombinedSerializer>> = LazyLock::new(|| {
+    Arc::new(
+        DecimalSerializer {
+            number: None,
+            quantize: None,
+        }
+        .into(),
+    )
+});
 
 impl BuildSerializer for DecimalSerializer {
     const EXPECTED_TYPE: &'static str = "decimal";
 
     fn build(
-        _schema: &Bound<'_, PyDict>,
-        _config: Option<&Bound<'_, PyDict>>,
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<Arc<CombinedSerializer>>,
     ) -> PyResult<Arc<CombinedSerializer>> {
-        Ok(DECIMAL_SERIALIZER.clone())
+        let py = schema.py();
+        let ser_mode = match schema.get_as::<Bound<'_, PyString>>(intern!(py, "ser_mode"))? {
+            Some(ser_mode) => DecimalSerMode::from_str(ser_mode.to_str()?)?,
+            None => DecimalSerMode::default(),
+        };
+        let quantize = Quantize::from_schema(schema)?;
+        let number = match ser_mode {
+            DecimalSerMode::String if quantize.is_none() => return Ok(DECIMAL_SERIALIZER.clone()),
+            DecimalSerMode::String => None,
+            DecimalSerMode::Number => Some(schema_inf_nan_mode(schema, config)?),
+        };
+        Ok(Arc::new(Self { number, quantize }.into()))
     }
 }
 
-impl_py_gc_traverse!(DecimalSerializer {});
+impl_py_gc_traverse!(DecimalSerializer { quantize });
+
+impl DecimalSerializer {
+    fn quantize<'py>(&self, value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        match &self.quantize {
+            Some(quantize) => quantize.apply(value),
+            None => Ok(value.clone()),
+        }
+    }
+}
 
 impl TypeSerializer for DecimalSerializer {
     fn to_python<'py>(
@@ -37,9 +102,15 @@ impl TypeSerializer for DecimalSerializer {
         value: &Bound<'py, PyAny>,
         state: &mut SerializationState<'_, 'py>,
     ) -> PyResult<Py<PyAny>> {
-        let _py = value.py();
+        let py = value.py();
         match state.extra.ob_type_lookup.is_type(value, ObType::Decimal) {
-            IsType::Exact | IsType::Subclass => infer_to_python_known(ObType::Decimal, value, state),
+            IsType::Exact | IsType::Subclass => {
+                let value = &self.quantize(value)?;
+                match (state.extra.mode, self.number) {
+                    (SerMode::Json, Some(_)) => value.extract::<f64>()?.into_py_any(py),
+                    _ => infer_to_python_known(ObType::Decimal, value, state),
+                }
+            }
             IsType::False => {
                 state.warn_fallback_py(self.get_name(), value)?;
                 infer_to_python(value, state)
@@ -68,7 +139,13 @@ impl TypeSerializer for DecimalSerializer {
         state: &mut SerializationState<'_, 'py>,
     ) -> Result<S::Ok, S::Error> {
         match state.extra.ob_type_lookup.is_type(value, ObType::Decimal) {
-            IsType::Exact | IsType::Subclass => infer_serialize_known(ObType::Decimal, value, serializer, state),
+            IsType::Exact | IsType::Subclass => {
+                let value = &self.quantize(value).map_err(py_err_se_err)?;
+                match (state.msgpack, self.number) {
+                    (None, Some(inf_nan_mode)) => serialize_decimal_number(value, serializer, inf_nan_mode),
+                    _ => infer_serialize_known(ObType::Decimal, value, serializer, state),
+                }
+            }
             IsType::False => {
                 state.warn_fallback_ser::<S>(self.get_name(), value)?;
                 infer_serialize(value, serializer, state)
@@ -80,3 +157,24 @@ impl TypeSerializer for DecimalSerializer {
         Self::EXPECTED_TYPE
     }
 }
+
+/// Write a decimal as a JSON number with all its digits, infinite and NaN decimals are written as floats would be.
+fn serialize_decimal_number<S: serde::ser::Serializer>(
+    value: &Bound<'_, PyAny>,
+    serializer: S,
+    inf_nan_mode: InfNanMode,
+) -> Result<S::Ok, S::Error> {
+    let s = value.str().map_err(py_err_se_err)?;
+    let s = s.to_str().map_err(py_err_se_err)?;
+    // finite decimals always end in a digit, e.g. `1.5` or `1E+2`, unlike `Infinity` or `NaN`
+    if s.ends_with(|c: char| c.is_ascii_digit()) {
+        RawNumber(s).serialize(serializer)
+    } else {
+        let v = match s.strip_prefix('-').unwrap_or(s) {
+            "Infinity" if s.starts_with('-') => f64::NEG_INFINITY,
+            "Infinity" => f64::INFINITY,
+            _ => f64::NAN,
+        };
+        serialize_f64(v, serializer, inf_nan_mode, None)
+    }
+}
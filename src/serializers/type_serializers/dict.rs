@@ -3,13 +3,16 @@ use std::sync::Arc;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyString};
 
 use pyo3::IntoPyObjectExt;
 use serde::ser::SerializeMap;
 
 use crate::definitions::DefinitionsBuilder;
-use crate::serializers::SerializationState;
+use crate::serializers::config::{FromConfig, KeyFallbackMode};
+use crate::serializers::errors::PydanticSerializationError;
+use crate::serializers::extra::SerCheck;
+use crate::serializers::{PydanticSerializationUnexpectedValue, SerializationState};
 use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
@@ -24,6 +27,10 @@ pub struct DictSerializer {
     value_serializer: Arc<CombinedSerializer>,
     // isize because we look up include exclude via `.hash()` which returns an isize
     filter: SchemaFilter<isize>,
+    // joins the items of tuple keys in JSON, `None` to use `,`
+    key_separator: Option<Arc<str>>,
+    // what to do with keys which don't match `key_serializer` in JSON
+    key_fallback: KeyFallbackMode,
     name: String,
 }
 
@@ -36,7 +43,11 @@ impl BuildSerializer for DictSerializer {
         definitions: &mut DefinitionsBuilder<Arc<CombinedSerializer>>,
     ) -> PyResult<Arc<CombinedSerializer>> {
         let py = schema.py();
-        let key_serializer = match schema.get_as(intern!(py, "keys_schema"))? {
+        let keys_schema = match schema.get_as(intern!(py, "keys_ser_schema"))? {
+            Some(keys_ser_schema) => Some(keys_ser_schema),
+            None => schema.get_as::<Bound<'_, PyDict>>(intern!(py, "keys_schema"))?,
+        };
+        let key_serializer = match keys_schema {
             Some(items_schema) => CombinedSerializer::build(&items_schema, config, definitions)?,
             None => AnySerializer::build(schema, config, definitions)?,
         };
@@ -62,6 +73,11 @@ impl BuildSerializer for DictSerializer {
             key_serializer,
             value_serializer,
             filter,
+            key_separator: schema
+                .get_as::<Bound<'_, PyString>>(intern!(py, "keys_separator"))?
+                .map(|separator| separator.to_str().map(Arc::from))
+                .transpose()?,
+            key_fallback: KeyFallbackMode::from_config(config)?,
             name,
         })
         .into())
@@ -73,6 +89,35 @@ impl_py_gc_traverse!(DictSerializer {
     value_serializer
 });
 
+impl DictSerializer {
+    /// The JSON object key for `key`, keys which don't match `key_serializer` are handled as
+    /// `ser_json_key_fallback` says.
+    fn json_object_key<'a, 'py>(
+        &self,
+        key: &'a Bound<'py, PyAny>,
+        state: &mut SerializationState<'_, 'py>,
+    ) -> PyResult<Cow<'a, str>> {
+        let state = &mut state.scoped_set(|s| &mut s.key_separator, self.key_separator.clone());
+        if self.key_fallback == KeyFallbackMode::Infer || state.check.enabled() {
+            return self.key_serializer.json_key(key, state);
+        }
+        // with checks enabled, keys which don't match raise an error instead of falling back to inference
+        let result = self
+            .key_serializer
+            .json_key(key, &mut state.scoped_set(|s| &mut s.check, SerCheck::Lax));
+        match result {
+            Err(err) if !err.is_instance_of::<PydanticSerializationUnexpectedValue>(key.py()) => Err(err),
+            Ok(json_key) => Ok(json_key),
+            Err(_) if self.key_fallback == KeyFallbackMode::Str => Ok(Cow::Owned(key.str()?.to_string())),
+            Err(_) => Err(PydanticSerializationError::new_err(format!(
+                "Dict key {} doesn't match `{}`",
+                key.repr()?,
+                self.key_serializer.get_name()
+            ))),
+        }
+    }
+}
+
 impl TypeSerializer for DictSerializer {
     fn to_python<'py>(
         &self,
@@ -92,7 +137,7 @@ impl TypeSerializer for DictSerializer {
                             // disable include/exclude for keys
                             let state = &mut state.scoped_include_exclude(None, None);
                             match state.extra.mode {
-                                SerMode::Json => self.key_serializer.json_key(&key, state)?.into_py_any(py)?,
+                                SerMode::Json => self.json_object_key(&key, state)?.into_py_any(py)?,
                                 _ => self.key_serializer.to_python(&key, state)?,
                             }
                         };
@@ -127,14 +172,13 @@ impl TypeSerializer for DictSerializer {
         match value.downcast::<PyDict>() {
             Ok(py_dict) => {
                 let mut map = serializer.serialize_map(Some(py_dict.len()))?;
-                let key_serializer = self.key_serializer.as_ref();
                 let value_serializer = self.value_serializer.as_ref();
 
                 for (key, value) in py_dict.iter() {
                     let op_next = self.filter.key_filter(&key, state).map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
                         let state = &mut state.scoped_include_exclude(next_include, next_exclude);
-                        let key = key_serializer.json_key(&key, state).map_err(py_err_se_err)?;
+                        let key = self.json_object_key(&key, state).map_err(py_err_se_err)?;
                         let value_serialize = PydanticSerializer::new(&value, value_serializer, state);
                         map.serialize_entry(&key, &value_serialize)?;
                     }
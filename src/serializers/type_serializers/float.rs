@@ -4,11 +4,12 @@ use pyo3::{intern, prelude::*, IntoPyObjectExt};
 use std::borrow::Cow;
 use std::sync::Arc;
 
-use serde::Serializer;
+use serde::{Serialize, Serializer};
 
-use crate::build_tools::LazyLock;
+use crate::build_tools::{py_schema_err, LazyLock};
 use crate::definitions::DefinitionsBuilder;
 use crate::serializers::config::InfNanMode;
+use crate::serializers::ser::RawNumber;
 use crate::serializers::SerializationState;
 use crate::tools::SchemaDict;
 
@@ -22,43 +23,240 @@ use crate::serializers::errors::PydanticSerializationUnexpectedValue;
 #[derive(Debug)]
 pub struct FloatSerializer {
     inf_nan_mode: InfNanMode,
+    format: Option<FloatFormat>,
+}
+
+/// How finite floats are written to JSON, from a `float-format` serialization schema or the
+/// `ser_json_float` config.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FloatFormat {
+    // digits after the decimal point, `None` for the fewest digits which read back as the same float
+    precision: Option<usize>,
+    // floats whose decimal exponent is at least this, or at most its negation, use scientific notation
+    scientific_threshold: Option<i32>,
+    // whether to add `.0` to floats written without a decimal point, e.g. `1.0` rather than `1`
+    decimal_point: bool,
+}
+
+impl FloatFormat {
+    fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let Some(ser) = schema.get_as::<Bound<'_, PyDict>>(intern!(py, "serialization"))? else {
+            return Ok(None);
+        };
+        if ser.get_as::<String>(intern!(py, "type"))?.as_deref() != Some("float-format") {
+            return Ok(None);
+        }
+        Self::from_dict(&ser).map(Some)
+    }
+
+    pub fn from_config(config: Option<&Bound<'_, PyDict>>) -> PyResult<Option<Self>> {
+        let Some(config) = config else {
+            return Ok(None);
+        };
+        config
+            .get_as::<Bound<'_, PyDict>>(intern!(config.py(), "ser_json_float"))?
+            .map(|format| Self::from_dict(&format))
+            .transpose()
+    }
+
+    fn from_dict(format: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let py = format.py();
+        let scientific_threshold: Option<i32> = format.get_as(intern!(py, "scientific_threshold"))?;
+        if scientific_threshold.is_some_and(|threshold| threshold < 1) {
+            return py_schema_err!("`scientific_threshold` must be greater than 0");
+        }
+        Ok(Self {
+            precision: format.get_as(intern!(py, "precision"))?,
+            scientific_threshold,
+            decimal_point: format.get_as(intern!(py, "decimal_point"))?.unwrap_or(true),
+        })
+    }
+
+    fn format(&self, v: f64) -> String {
+        let scientific = self.scientific_threshold.is_some_and(|threshold| {
+            v != 0.0 && {
+                let exponent = decimal_exponent(v);
+                exponent >= threshold || exponent <= -threshold
+            }
+        });
+        let mut s = match (scientific, self.precision) {
+            (true, Some(precision)) => round_scientific(v, precision),
+            (true, None) => format!("{v:e}"),
+            (false, Some(precision)) => round_fixed(v, precision),
+            (false, None) => format!("{v}"),
+        };
+        if self.decimal_point {
+            let mantissa_end = s.find('e').unwrap_or(s.len());
+            if !s[..mantissa_end].contains('.') {
+                s.insert_str(mantissa_end, ".0");
+            }
+        }
+        s
+    }
+}
+
+// Floats are rounded to a precision from their shortest representation, half away from zero, so the digits
+// written are the ones `repr(v)` shows rounded as a person would, e.g. `12.5` to `13` and `1.005` to `1.01`,
+// rather than rounding the exact binary value, which is slightly below `1.005`.
+
+/// `v` with `precision` digits after the decimal point, e.g. `12.10` for `12.1` with 2
+fn round_fixed(v: f64, precision: usize) -> String {
+    let (mut digits, exponent) = shortest_digits(v);
+    // digits before the decimal point and `precision` after it, the first digit is at 10^exponent
+    let keep = i64::from(exponent) + 1 + i64::try_from(precision).unwrap_or(i64::MAX);
+    match usize::try_from(keep) {
+        Ok(keep) => {
+            round_digits(&mut digits, keep);
+        }
+        Err(_) => digits.clear(),
+    }
+    // `digits` is now the value times 10^precision, pad it so there's a digit before the decimal point
+    if digits.len() <= precision {
+        digits.splice(0..0, std::iter::repeat(b'0').take(precision + 1 - digits.len()));
+    }
+    let mut s = String::with_capacity(digits.len() + 2);
+    if v.is_sign_negative() {
+        s.push('-');
+    }
+    let point = digits.len() - precision;
+    s.extend(digits[..point].iter().map(|d| char::from(*d)));
+    if precision > 0 {
+        s.push('.');
+        s.extend(digits[point..].iter().map(|d| char::from(*d)));
+    }
+    s
+}
+
+/// `v` in scientific notation with `precision` digits after the decimal point, e.g. `1.23e4` for `12345.0` with 2
+fn round_scientific(v: f64, precision: usize) -> String {
+    let (mut digits, mut exponent) = shortest_digits(v);
+    if round_digits(&mut digits, precision + 1) {
+        // e.g. 9.999 rounded to 10.00, which is 1.000 with the next exponent
+        digits.pop();
+        exponent += 1;
+    }
+    let mut s = String::with_capacity(digits.len() + 6);
+    if v.is_sign_negative() {
+        s.push('-');
+    }
+    s.push(char::from(digits[0]));
+    if precision > 0 {
+        s.push('.');
+        s.extend(digits[1..].iter().map(|d| char::from(*d)));
+    }
+    s.push('e');
+    s.push_str(&exponent.to_string());
+    s
+}
+
+/// The digits of the shortest representation of `|v|` which reads back as `v`, and the decimal exponent of the
+/// first digit, e.g. `("12345", 4)` for `12345.0`
+fn shortest_digits(v: f64) -> (Vec<u8>, i32) {
+    let s = format!("{:e}", v.abs());
+    let (mantissa, exponent) = s.split_once('e').unwrap_or((&s, "0"));
+    let digits = mantissa.bytes().filter(u8::is_ascii_digit).collect();
+    (digits, exponent.parse().unwrap_or(0))
+}
+
+/// Keep the first `keep` digits, rounding half away from zero and padding with zeros, returns whether rounding
+/// carried into a new leading digit, e.g. `99` to `100`.
+fn round_digits(digits: &mut Vec<u8>, keep: usize) -> bool {
+    if keep >= digits.len() {
+        digits.resize(keep, b'0');
+        return false;
+    }
+    let round_up = digits[keep] >= b'5';
+    digits.truncate(keep);
+    if round_up {
+        for digit in digits.iter_mut().rev() {
+            if *digit == b'9' {
+                *digit = b'0';
+            } else {
+                *digit += 1;
+                return false;
+            }
+        }
+        digits.insert(0, b'1');
+        return true;
+    }
+    false
+}
+
+/// The exponent of `v` in scientific notation, e.g. 3 for 1234.5
+fn decimal_exponent(v: f64) -> i32 {
+    let s = format!("{v:e}");
+    s.rsplit_once('e')
+        .and_then(|(_, exponent)| exponent.parse().ok())
+        .unwrap_or(0)
 }
 
 static FLOAT_SERIALIZER_NULL: LazyLock<Arc<CombinedSerializer>> = LazyLock::new(|| {
     Arc::new(CombinedSerializer::Float(FloatSerializer {
         inf_nan_mode: InfNanMode::Null,
+        format: None,
     }))
 });
 
 static FLOAT_SERIALIZER_CONSTANTS: LazyLock<Arc<CombinedSerializer>> = LazyLock::new(|| {
     Arc::new(CombinedSerializer::Float(FloatSerializer {
         inf_nan_mode: InfNanMode::Constants,
+        format: None,
     }))
 });
 
 static FLOAT_SERIALIZER_STRINGS: LazyLock<Arc<CombinedSerializer>> = LazyLock::new(|| {
     Arc::new(CombinedSerializer::Float(FloatSerializer {
         inf_nan_mode: InfNanMode::Strings,
+        format: None,
     }))
 });
 
 impl FloatSerializer {
     pub fn get(py: Python, config: Option<&Bound<'_, PyDict>>) -> PyResult<&'static Arc<CombinedSerializer>> {
-        let inf_nan_mode = config
-            .and_then(|c| c.get_as(intern!(py, "ser_json_inf_nan")).transpose())
-            .transpose()?
-            .unwrap_or_default();
-
-        match inf_nan_mode {
+        match Self::inf_nan_mode(py, config)? {
             InfNanMode::Null => Ok(&FLOAT_SERIALIZER_NULL),
             InfNanMode::Constants => Ok(&FLOAT_SERIALIZER_CONSTANTS),
             InfNanMode::Strings => Ok(&FLOAT_SERIALIZER_STRINGS),
         }
     }
+
+    fn inf_nan_mode(py: Python, config: Option<&Bound<'_, PyDict>>) -> PyResult<InfNanMode> {
+        Ok(config
+            .and_then(|c| c.get_as(intern!(py, "ser_json_inf_nan")).transpose())
+            .transpose()?
+            .unwrap_or_default())
+    }
 }
 
-pub fn serialize_f64<S: Serializer>(v: f64, serializer: S, inf_nan_mode: InfNanMode) -> Result<S::Ok, S::Error> {
-    if v.is_nan() || v.is_infinite() {
+/// How infinite and NaN values of a float or decimal schema are written to JSON, the `ser_json_inf_nan` config if
+/// it's set, otherwise as constants if the schema sets `allow_inf_nan=True`, so they're valid input for the schema.
+pub(crate) fn schema_inf_nan_mode(
+    schema: &Bound<'_, PyDict>,
+    config: Option<&Bound<'_, PyDict>>,
+) -> PyResult<InfNanMode> {
+    let py = schema.py();
+    if let Some(inf_nan_mode) = config
+        .and_then(|c| c.get_as(intern!(py, "ser_json_inf_nan")).transpose())
+        .transpose()?
+    {
+        return Ok(inf_nan_mode);
+    }
+    match schema.get_as::<bool>(intern!(py, "allow_inf_nan"))? {
+        Some(true) => Ok(InfNanMode::Constants),
+        _ => Ok(InfNanMode::default()),
+    }
+}
+
+pub fn serialize_f64<S: Serializer>(
+    v: f64,
+    serializer: S,
+    inf_nan_mode: InfNanMode,
+    format: Option<&FloatFormat>,
+) -> Result<S::Ok, S::Error> {
+    if let Some(format) = format.filter(|_| v.is_finite()) {
+        RawNumber(&format.format(v)).serialize(serializer)
+    } else if v.is_nan() || v.is_infinite() {
         match inf_nan_mode {
             InfNanMode::Null => serializer.serialize_none(),
             InfNanMode::Constants => serializer.serialize_f64(v),
@@ -83,7 +281,22 @@ impl BuildSerializer for FloatSerializer {
         config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<Arc<CombinedSerializer>>,
     ) -> PyResult<Arc<CombinedSerializer>> {
-        Self::get(schema.py(), config).cloned()
+        let format = match FloatFormat::from_schema(schema)? {
+            Some(format) => Some(format),
+            None => FloatFormat::from_config(config)?,
+        };
+        let inf_nan_mode = schema_inf_nan_mode(schema, config)?;
+        match format {
+            Some(format) => Ok(Arc::new(CombinedSerializer::Float(Self {
+                inf_nan_mode,
+                format: Some(format),
+            }))),
+            None => match inf_nan_mode {
+                InfNanMode::Null => Ok(FLOAT_SERIALIZER_NULL.clone()),
+                InfNanMode::Constants => Ok(FLOAT_SERIALIZER_CONSTANTS.clone()),
+                InfNanMode::Strings => Ok(FLOAT_SERIALIZER_STRINGS.clone()),
+            },
+        }
     }
 }
 
@@ -133,7 +346,7 @@ impl TypeSerializer for FloatSerializer {
         state: &mut SerializationState<'_, 'py>,
     ) -> Result<S::Ok, S::Error> {
         match value.extract::<f64>() {
-            Ok(v) => serialize_f64(v, serializer, self.inf_nan_mode),
+            Ok(v) => serialize_f64(v, serializer, self.inf_nan_mode, self.format.as_ref()),
             Err(_) => {
                 state.warn_fallback_ser::<S>(self.get_name(), value)?;
                 infer_serialize(value, serializer, state)
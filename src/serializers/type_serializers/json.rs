@@ -50,7 +50,7 @@ impl TypeSerializer for JsonSerializer {
         state: &mut SerializationState<'_, 'py>,
     ) -> PyResult<Py<PyAny>> {
         if state.extra.round_trip {
-            let bytes = to_json_bytes(value, &self.serializer, state, None, false, 0)?;
+            let bytes = to_json_bytes(value, &self.serializer, state, None, false, false, 0)?;
             let py = value.py();
             let s = from_utf8(&bytes).map_err(|e| utf8_py_error(py, e, &bytes))?;
             Ok(PyString::new(py, s).into())
@@ -65,7 +65,7 @@ impl TypeSerializer for JsonSerializer {
         state: &mut SerializationState<'_, 'py>,
     ) -> PyResult<Cow<'a, str>> {
         if state.extra.round_trip {
-            let bytes = to_json_bytes(key, &self.serializer, state, None, false, 0)?;
+            let bytes = to_json_bytes(key, &self.serializer, state, None, false, false, 0)?;
             let py = key.py();
             let s = from_utf8(&bytes).map_err(|e| utf8_py_error(py, e, &bytes))?;
             Ok(Cow::Owned(s.to_string()))
@@ -81,7 +81,7 @@ impl TypeSerializer for JsonSerializer {
         state: &mut SerializationState<'_, 'py>,
     ) -> Result<S::Ok, S::Error> {
         if state.extra.round_trip {
-            let bytes = to_json_bytes(value, &self.serializer, state, None, false, 0).map_err(py_err_se_err)?;
+            let bytes = to_json_bytes(value, &self.serializer, state, None, false, false, 0).map_err(py_err_se_err)?;
             match from_utf8(&bytes) {
                 Ok(s) => serializer.serialize_str(s),
                 Err(e) => Err(Error::custom(e.to_string())),
@@ -0,0 +1,83 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::definitions::DefinitionsBuilder;
+use crate::serializers::SerializationState;
+
+use super::list::ListSerializer;
+use super::{infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, TypeSerializer};
+
+/// Serializes the dict returned by a `keyed-list` validator back to a list of its values.
+#[derive(Debug)]
+pub struct KeyedListSerializer {
+    list_serializer: Arc<CombinedSerializer>,
+    name: String,
+}
+
+impl BuildSerializer for KeyedListSerializer {
+    const EXPECTED_TYPE: &'static str = "keyed-list";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<Arc<CombinedSerializer>>,
+    ) -> PyResult<Arc<CombinedSerializer>> {
+        let list_serializer = ListSerializer::build(schema, config, definitions)?;
+        let name = format!("keyed-{}", list_serializer.get_name());
+        Ok(Arc::new(Self { list_serializer, name }.into()))
+    }
+}
+
+impl_py_gc_traverse!(KeyedListSerializer { list_serializer });
+
+impl TypeSerializer for KeyedListSerializer {
+    fn to_python<'py>(
+        &self,
+        value: &Bound<'py, PyAny>,
+        state: &mut SerializationState<'_, 'py>,
+    ) -> PyResult<Py<PyAny>> {
+        match value.downcast::<PyDict>() {
+            Ok(py_dict) => self.list_serializer.to_python(&py_dict.values(), state),
+            Err(_) => {
+                state.warn_fallback_py(self.get_name(), value)?;
+                infer_to_python(value, state)
+            }
+        }
+    }
+
+    fn json_key<'a, 'py>(
+        &self,
+        key: &'a Bound<'py, PyAny>,
+        state: &mut SerializationState<'_, 'py>,
+    ) -> PyResult<Cow<'a, str>> {
+        self.invalid_as_json_key(key, state, Self::EXPECTED_TYPE)
+    }
+
+    fn serde_serialize<'py, S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'py, PyAny>,
+        serializer: S,
+        state: &mut SerializationState<'_, 'py>,
+    ) -> Result<S::Ok, S::Error> {
+        match value.downcast::<PyDict>() {
+            Ok(py_dict) => self
+                .list_serializer
+                .serde_serialize(&py_dict.values(), serializer, state),
+            Err(_) => {
+                state.warn_fallback_ser::<S>(self.get_name(), value)?;
+                infer_serialize(value, serializer, state)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn retry_with_lax_check(&self) -> bool {
+        self.list_serializer.retry_with_lax_check()
+    }
+}
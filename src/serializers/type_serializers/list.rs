@@ -3,7 +3,8 @@ use std::sync::Arc;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
+use pyo3::sync::PyOnceLock;
+use pyo3::types::{PyDict, PyList, PyTuple, PyType};
 
 use pyo3::IntoPyObjectExt;
 use serde::ser::SerializeSeq;
@@ -15,16 +16,20 @@ use crate::tools::SchemaDict;
 use super::any::AnySerializer;
 use super::{
     infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, PydanticSerializer,
-    SchemaFilter, TypeSerializer,
+    SchemaFilter, SerMode, TypeSerializer,
 };
 
 #[derive(Debug)]
 pub struct ListSerializer {
     item_serializer: Arc<CombinedSerializer>,
     filter: SchemaFilter<usize>,
+    // with `preserve_type`, validated values can also be tuples and deques
+    preserve_type: bool,
     name: String,
 }
 
+static DEQUE_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+
 impl BuildSerializer for ListSerializer {
     const EXPECTED_TYPE: &'static str = "list";
 
@@ -43,6 +48,7 @@ impl BuildSerializer for ListSerializer {
             Self {
                 item_serializer,
                 filter: SchemaFilter::from_schema(schema)?,
+                preserve_type: schema.get_as(intern!(py, "preserve_type"))?.unwrap_or(false),
                 name,
             }
             .into(),
@@ -52,32 +58,91 @@ impl BuildSerializer for ListSerializer {
 
 impl_py_gc_traverse!(ListSerializer { item_serializer });
 
+impl ListSerializer {
+    fn items_to_python<'py>(
+        &self,
+        py_items: impl ExactSizeIterator<Item = Bound<'py, PyAny>>,
+        state: &mut SerializationState<'_, 'py>,
+    ) -> PyResult<Vec<Py<PyAny>>> {
+        let item_serializer = self.item_serializer.as_ref();
+        let len = py_items.len();
+        let mut items = Vec::with_capacity(len);
+        for (index, element) in py_items.enumerate() {
+            let op_next = self.filter.index_filter(index, state, Some(len))?;
+            if let Some((next_include, next_exclude)) = op_next {
+                let state = &mut state.scoped_include_exclude(next_include, next_exclude);
+                items.push(item_serializer.to_python(&element, state)?);
+            }
+        }
+        Ok(items)
+    }
+
+    fn serialize_items<'py, S: serde::ser::Serializer>(
+        &self,
+        py_items: impl ExactSizeIterator<Item = Bound<'py, PyAny>>,
+        serializer: S,
+        state: &mut SerializationState<'_, 'py>,
+    ) -> Result<S::Ok, S::Error> {
+        let len = py_items.len();
+        let mut seq = serializer.serialize_seq(Some(len))?;
+        let item_serializer = self.item_serializer.as_ref();
+
+        for (index, element) in py_items.enumerate() {
+            let op_next = self
+                .filter
+                .index_filter(index, state, Some(len))
+                .map_err(py_err_se_err)?;
+            if let Some((next_include, next_exclude)) = op_next {
+                let state = &mut state.scoped_include_exclude(next_include, next_exclude);
+                let item_serialize = PydanticSerializer::new(&element, item_serializer, state);
+                seq.serialize_element(&item_serialize)?;
+            }
+        }
+        seq.end()
+    }
+}
+
+/// The items of a deque, for lists with `preserve_type`, `None` if the value isn't a deque.
+fn deque_items<'py>(value: &Bound<'py, PyAny>) -> PyResult<Option<Vec<Bound<'py, PyAny>>>> {
+    if value.is_instance(DEQUE_TYPE.import(value.py(), "collections", "deque")?)? {
+        Ok(Some(value.try_iter()?.collect::<PyResult<_>>()?))
+    } else {
+        Ok(None)
+    }
+}
+
 impl TypeSerializer for ListSerializer {
     fn to_python<'py>(
         &self,
         value: &Bound<'py, PyAny>,
         state: &mut SerializationState<'_, 'py>,
     ) -> PyResult<Py<PyAny>> {
-        match value.downcast::<PyList>() {
-            Ok(py_list) => {
-                let py = value.py();
-                let item_serializer = self.item_serializer.as_ref();
-
-                let mut items = Vec::with_capacity(py_list.len());
-                for (index, element) in py_list.iter().enumerate() {
-                    let op_next = self.filter.index_filter(index, state, value.len().ok())?;
-                    if let Some((next_include, next_exclude)) = op_next {
-                        let state = &mut state.scoped_include_exclude(next_include, next_exclude);
-                        items.push(item_serializer.to_python(&element, state)?);
-                    }
-                }
-                items.into_py_any(py)
+        let py = value.py();
+        if let Ok(py_list) = value.downcast::<PyList>() {
+            return self.items_to_python(py_list.iter(), state)?.into_py_any(py);
+        }
+        if self.preserve_type {
+            if let Ok(py_tuple) = value.downcast::<PyTuple>() {
+                let items = self.items_to_python(py_tuple.iter(), state)?;
+                return match state.extra.mode {
+                    SerMode::Json => items.into_py_any(py),
+                    _ => PyTuple::new(py, items)?.into_py_any(py),
+                };
             }
-            Err(_) => {
-                state.warn_fallback_py(self.get_name(), value)?;
-                infer_to_python(value, state)
+            if let Some(deque_items) = deque_items(value)? {
+                let items = self.items_to_python(deque_items.into_iter(), state)?;
+                return match state.extra.mode {
+                    SerMode::Json => items.into_py_any(py),
+                    // with the same type and `maxlen` as the value
+                    _ => value
+                        .get_type()
+                        .call1((items, value.getattr(intern!(py, "maxlen"))?))?
+                        .into_py_any(py),
+                };
             }
         }
+        state.warn_fallback_py(self.get_name(), value)?;
+        infer_to_python(value, state)
     }
 
     fn json_key<'a, 'py>(
@@ -94,29 +159,19 @@ impl TypeSerializer for ListSerializer {
         serializer: S,
         state: &mut SerializationState<'_, 'py>,
     ) -> Result<S::Ok, S::Error> {
-        match value.downcast::<PyList>() {
-            Ok(py_list) => {
-                let mut seq = serializer.serialize_seq(Some(py_list.len()))?;
-                let item_serializer = self.item_serializer.as_ref();
-
-                for (index, element) in py_list.iter().enumerate() {
-                    let op_next = self
-                        .filter
-                        .index_filter(index, state, Some(py_list.len()))
-                        .map_err(py_err_se_err)?;
-                    if let Some((next_include, next_exclude)) = op_next {
-                        let state = &mut state.scoped_include_exclude(next_include, next_exclude);
-                        let item_serialize = PydanticSerializer::new(&element, item_serializer, state);
-                        seq.serialize_element(&item_serialize)?;
-                    }
-                }
-                seq.end()
+        if let Ok(py_list) = value.downcast::<PyList>() {
+            return self.serialize_items(py_list.iter(), serializer, state);
+        }
+        if self.preserve_type {
+            if let Ok(py_tuple) = value.downcast::<PyTuple>() {
+                return self.serialize_items(py_tuple.iter(), serializer, state);
             }
-            Err(_) => {
-                state.warn_fallback_ser::<S>(self.get_name(), value)?;
-                infer_serialize(value, serializer, state)
+            if let Some(deque_items) = deque_items(value).map_err(py_err_se_err)? {
+                return self.serialize_items(deque_items.into_iter(), serializer, state);
             }
         }
+        state.warn_fallback_ser::<S>(self.get_name(), value)?;
+        infer_serialize(value, serializer, state)
     }
 
     fn get_name(&self) -> &str {
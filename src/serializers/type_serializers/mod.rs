@@ -13,12 +13,14 @@ pub mod function;
 pub mod generator;
 pub mod json;
 pub mod json_or_python;
+pub mod keyed_list;
 pub mod list;
 pub mod literal;
 pub mod missing_sentinel;
 pub mod model;
 pub mod nullable;
 pub mod other;
+pub mod plugin;
 pub mod set_frozenset;
 pub mod simple;
 pub mod string;
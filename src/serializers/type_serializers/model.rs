@@ -13,7 +13,7 @@ use super::{
     GeneralFieldsSerializer, ObType, SerCheck, SerField, TypeSerializer,
 };
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{py_schema_error_type, ExtraBehavior};
+use crate::build_tools::{py_schema_error_type, ExtraBehavior, FieldAliases};
 use crate::definitions::DefinitionsBuilder;
 use crate::serializers::extra::FieldName;
 use crate::serializers::shared::serialize_to_json;
@@ -46,6 +46,7 @@ impl BuildSerializer for ModelFieldsBuilder {
 
         let fields_dict: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: AHashMap<String, SerField> = AHashMap::with_capacity(fields_dict.len());
+        let aliases = FieldAliases::from_schema(schema)?;
 
         let extra_serializer = match (schema.get_item(intern!(py, "extras_schema"))?, &fields_mode) {
             (Some(v), FieldsMode::ModelExtra) => Some(CombinedSerializer::build(&v.extract()?, config, definitions)?),
@@ -68,7 +69,10 @@ impl BuildSerializer for ModelFieldsBuilder {
                     SerField::new(py, key_py, None, None, true, serialize_by_alias, None),
                 );
             } else {
-                let alias: Option<String> = field_info.get_as(intern!(py, "serialization_alias"))?;
+                let alias: Option<String> = aliases
+                    .get(field_info, intern!(py, "serialization_alias"), &key)?
+                    .map(|alias| alias.extract())
+                    .transpose()?;
                 let serialization_exclude_if: Option<Py<PyAny>> =
                     field_info.get_as(intern!(py, "serialization_exclude_if"))?;
                 let schema = field_info.get_as_req(intern!(py, "schema"))?;
@@ -105,6 +109,7 @@ pub struct ModelSerializer {
     has_extra: bool,
     root_model: bool,
     name: String,
+    unknown_type_encoder: Option<Py<PyAny>>,
 }
 
 impl BuildSerializer for ModelSerializer {
@@ -132,6 +137,7 @@ impl BuildSerializer for ModelSerializer {
             has_extra: has_extra(schema, config.as_ref())?,
             root_model,
             name,
+            unknown_type_encoder: schema.get_as(intern!(py, "unknown_type_encoder"))?,
         })
         .into())
     }
@@ -179,10 +185,14 @@ impl ModelSerializer {
         if !self.allow_value(value, state.check)? {
             return do_serialize.serialize_fallback(self.get_name(), value, state);
         }
+        if let Some(serializer) = state.subclass_serializer(value, self.class.bind(value.py())) {
+            return do_serialize.serialize_no_infer(&serializer, value, state);
+        }
 
         let inner_value = self.get_inner_value(value, &state.extra)?;
 
         let state = &mut state.scoped_set(|s| &mut s.model, Some(value.clone()));
+        let state = &mut state.scoped_unknown_type_encoder(self.unknown_type_encoder(value.py()));
         do_serialize.serialize_no_infer(&self.serializer, &inner_value, state)
     }
 
@@ -195,6 +205,9 @@ impl ModelSerializer {
         if !self.allow_value_root_model(value, state.check)? {
             return do_serialize.serialize_fallback(self.get_name(), value, state);
         }
+        if let Some(serializer) = state.subclass_serializer(value, self.class.bind(value.py())) {
+            return do_serialize.serialize_no_infer(&serializer, value, state);
+        }
 
         let root = value.getattr(intern!(value.py(), ROOT_FIELD))?;
 
@@ -217,9 +230,18 @@ impl ModelSerializer {
 
         let state = &mut state.scoped_set(|s| &mut s.field_name, Some(FieldName::Root));
         let state = &mut state.scoped_set(|s| &mut s.model, Some(value.clone()));
+        let state = &mut state.scoped_unknown_type_encoder(self.unknown_type_encoder(value.py()));
         do_serialize.serialize_no_infer(serializer, &root, state)
     }
 
+    pub(crate) fn class(&self) -> &Py<PyType> {
+        &self.class
+    }
+
+    fn unknown_type_encoder<'a, 'py>(&'a self, py: Python<'py>) -> Option<&'a Bound<'py, PyAny>> {
+        self.unknown_type_encoder.as_ref().map(|encoder| encoder.bind(py))
+    }
+
     fn get_inner_value<'py>(&self, model: &Bound<'py, PyAny>, extra: &Extra) -> PyResult<Bound<'py, PyAny>> {
         let py: Python<'_> = model.py();
         let mut attrs = model.getattr(intern!(py, "__dict__"))?.downcast_into::<PyDict>()?;
@@ -247,7 +269,11 @@ impl ModelSerializer {
     }
 }
 
-impl_py_gc_traverse!(ModelSerializer { class, serializer });
+impl_py_gc_traverse!(ModelSerializer {
+    class,
+    serializer,
+    unknown_type_encoder
+});
 
 impl TypeSerializer for ModelSerializer {
     fn to_python<'py>(
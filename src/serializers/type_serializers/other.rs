@@ -113,3 +113,10 @@ macro_rules! any_build_serializer {
 any_build_serializer!(IsInstanceBuilder, "is-instance");
 any_build_serializer!(IsSubclassBuilder, "is-subclass");
 any_build_serializer!(CallableBuilder, "callable");
+any_build_serializer!(EmailBuilder, "email");
+any_build_serializer!(NameEmailBuilder, "name-email");
+any_build_serializer!(IpV4AddressBuilder, "ip-v4-address");
+any_build_serializer!(IpV6AddressBuilder, "ip-v6-address");
+any_build_serializer!(IpNetworkBuilder, "ip-network");
+any_build_serializer!(IpInterfaceBuilder, "ip-interface");
+any_build_serializer!(PathBuilder, "path");
@@ -0,0 +1,101 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use crate::definitions::DefinitionsBuilder;
+use crate::plugin::Plugin;
+use crate::serializers::SerializationState;
+use crate::tools::SchemaDict;
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer,
+    TypeSerializer,
+};
+
+/// Serializes values with a plugin registered by another compiled extension with `register_plugin`, values are
+/// serialized like `any` schemas if the plugin doesn't serialize them.
+#[derive(Debug)]
+pub struct PluginSerializer {
+    plugin: Plugin,
+    options: Py<PyDict>,
+    name: String,
+}
+
+impl BuildSerializer for PluginSerializer {
+    const EXPECTED_TYPE: &'static str = "plugin";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        _config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<Arc<CombinedSerializer>>,
+    ) -> PyResult<Arc<CombinedSerializer>> {
+        let py = schema.py();
+        let plugin_name: Bound<'_, PyString> = schema.get_as_req(intern!(py, "plugin"))?;
+        let plugin = Plugin::get(plugin_name.to_str()?)?;
+        let options = schema
+            .get_as::<Bound<'_, PyDict>>(intern!(py, "options"))?
+            .unwrap_or_else(|| PyDict::new(py));
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, plugin.name());
+        Ok(Arc::new(
+            Self {
+                plugin,
+                options: options.unbind(),
+                name,
+            }
+            .into(),
+        ))
+    }
+}
+
+impl_py_gc_traverse!(PluginSerializer { plugin, options });
+
+impl TypeSerializer for PluginSerializer {
+    fn to_python<'py>(
+        &self,
+        value: &Bound<'py, PyAny>,
+        state: &mut SerializationState<'_, 'py>,
+    ) -> PyResult<Py<PyAny>> {
+        let py = value.py();
+        match self
+            .plugin
+            .serialize(self.options.bind(py), value, state.extra.mode.is_json())?
+        {
+            Some(output) => Ok(output.unbind()),
+            None => infer_to_python(value, state),
+        }
+    }
+
+    fn json_key<'a, 'py>(
+        &self,
+        key: &'a Bound<'py, PyAny>,
+        state: &mut SerializationState<'_, 'py>,
+    ) -> PyResult<Cow<'a, str>> {
+        match self.plugin.serialize(self.options.bind(key.py()), key, true)? {
+            Some(output) => Ok(Cow::Owned(infer_json_key(&output, state)?.into_owned())),
+            None => infer_json_key(key, state),
+        }
+    }
+
+    fn serde_serialize<'py, S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'py, PyAny>,
+        serializer: S,
+        state: &mut SerializationState<'_, 'py>,
+    ) -> Result<S::Ok, S::Error> {
+        let output = self
+            .plugin
+            .serialize(self.options.bind(value.py()), value, true)
+            .map_err(py_err_se_err)?;
+        match output {
+            Some(output) => infer_serialize(&output, serializer, state),
+            None => infer_serialize(value, serializer, state),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
@@ -8,7 +8,9 @@ use crate::definitions::DefinitionsBuilder;
 use crate::input::EitherTimedelta;
 use crate::serializers::config::{FromConfig, TemporalMode, TimedeltaMode};
 use crate::serializers::SerializationState;
+use crate::tools::SchemaDict;
 
+use super::any::AnySerializer;
 use super::{
     infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, SerMode, TypeSerializer,
 };
@@ -22,10 +24,15 @@ impl BuildSerializer for TimeDeltaSerializer {
     const EXPECTED_TYPE: &'static str = "timedelta";
 
     fn build(
-        _schema: &Bound<'_, PyDict>,
+        schema: &Bound<'_, PyDict>,
         config: Option<&Bound<'_, PyDict>>,
-        _definitions: &mut DefinitionsBuilder<Arc<CombinedSerializer>>,
+        definitions: &mut DefinitionsBuilder<Arc<CombinedSerializer>>,
     ) -> PyResult<Arc<CombinedSerializer>> {
+        // the validator returns dicts rather than timedeltas when `output` is set
+        let output = schema.get_as::<String>(intern!(schema.py(), "output"))?;
+        if output.as_deref().is_some_and(|output| output != "timedelta") {
+            return AnySerializer::build(schema, config, definitions);
+        }
         let temporal_set = config
             .and_then(|cfg| cfg.contains(intern!(cfg.py(), "ser_json_temporal")).ok())
             .unwrap_or(false);
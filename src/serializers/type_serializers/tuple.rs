@@ -100,7 +100,7 @@ impl TypeSerializer for TupleSerializer {
     ) -> PyResult<Cow<'a, str>> {
         match key.downcast::<PyTuple>() {
             Ok(py_tuple) => {
-                let mut key_builder = KeyBuilder::new();
+                let mut key_builder = KeyBuilder::new(state.key_separator.clone());
 
                 let state = &mut state.scoped_include_exclude(None, None);
                 self.for_each_tuple_item_and_serializer(py_tuple, state, |entry| {
@@ -247,13 +247,15 @@ impl TupleSerializer {
 pub(crate) struct KeyBuilder {
     key: String,
     first: bool,
+    separator: Option<Arc<str>>,
 }
 
 impl KeyBuilder {
-    pub fn new() -> Self {
+    pub fn new(separator: Option<Arc<str>>) -> Self {
         Self {
             key: String::with_capacity(31),
             first: true,
+            separator,
         }
     }
 
@@ -261,7 +263,7 @@ impl KeyBuilder {
         if self.first {
             self.first = false;
         } else {
-            self.key.push(',');
+            self.key.push_str(self.separator.as_deref().unwrap_or(","));
         }
         self.key.push_str(key);
     }
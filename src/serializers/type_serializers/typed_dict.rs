@@ -8,7 +8,7 @@ use pyo3::types::{PyDict, PyString};
 use ahash::AHashMap;
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{py_schema_error_type, schema_or_config, ExtraBehavior};
+use crate::build_tools::{py_schema_error_type, schema_or_config, ExtraBehavior, FieldAliases};
 use crate::definitions::DefinitionsBuilder;
 use crate::serializers::shared::TypeSerializer;
 use crate::serializers::SerializationState;
@@ -45,6 +45,7 @@ impl BuildSerializer for TypedDictSerializer {
 
         let fields_dict: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: AHashMap<String, SerField> = AHashMap::with_capacity(fields_dict.len());
+        let aliases = FieldAliases::from_schema(schema)?;
 
         let extra_serializer = match (schema.get_item(intern!(py, "extras_schema"))?, &fields_mode) {
             (Some(v), FieldsMode::TypedDictAllow) => {
@@ -68,7 +69,10 @@ impl BuildSerializer for TypedDictSerializer {
                     SerField::new(py, key_py, None, None, required, serialize_by_alias, None),
                 );
             } else {
-                let alias: Option<String> = field_info.get_as(intern!(py, "serialization_alias"))?;
+                let alias: Option<String> = aliases
+                    .get(field_info, intern!(py, "serialization_alias"), &key)?
+                    .map(|alias| alias.extract())
+                    .transpose()?;
                 let serialization_exclude_if: Option<Py<PyAny>> =
                     field_info.get_as(intern!(py, "serialization_exclude_if"))?;
                 let schema = field_info.get_as_req(intern!(py, "schema"))?;
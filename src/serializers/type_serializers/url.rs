@@ -7,7 +7,9 @@ use pyo3::IntoPyObjectExt;
 
 use crate::build_tools::LazyLock;
 use crate::definitions::DefinitionsBuilder;
+use crate::semver::PyVersion;
 use crate::serializers::SerializationState;
+use crate::ulid::PyUlid;
 use crate::url::{PyMultiHostUrl, PyUrl};
 
 use super::{
@@ -91,3 +93,6 @@ macro_rules! build_serializer {
 }
 build_serializer!(UrlSerializer, "url", PyUrl);
 build_serializer!(MultiHostUrlSerializer, "multi-host-url", PyMultiHostUrl);
+// versions are also serialized as their string in JSON mode
+build_serializer!(SemverSerializer, "semver", PyVersion);
+build_serializer!(UlidSerializer, "ulid", PyUlid);
@@ -3,13 +3,14 @@ use std::sync::Arc;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyList};
 
 use crate::definitions::DefinitionsBuilder;
 use crate::serializers::SerializationState;
 use crate::tools::SchemaDict;
 use crate::validators::DefaultType;
 
+use super::literal::LiteralSerializer;
 use super::{BuildSerializer, CombinedSerializer, TypeSerializer};
 
 #[derive(Debug)]
@@ -38,6 +39,28 @@ impl BuildSerializer for WithDefaultSerializer {
 
 impl_py_gc_traverse!(WithDefaultSerializer { default, serializer });
 
+/// `const` schemas are serialized like a `literal` of their value, which is also their default.
+pub struct ConstBuilder;
+
+impl BuildSerializer for ConstBuilder {
+    const EXPECTED_TYPE: &'static str = "const";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<Arc<CombinedSerializer>>,
+    ) -> PyResult<Arc<CombinedSerializer>> {
+        let py = schema.py();
+        let value: Bound<'_, PyAny> = schema.get_as_req(intern!(py, "value"))?;
+        let literal_schema = PyDict::new(py);
+        literal_schema.set_item(intern!(py, "type"), intern!(py, "literal"))?;
+        literal_schema.set_item(intern!(py, "expected"), PyList::new(py, [&value])?)?;
+        let serializer = LiteralSerializer::build(&literal_schema, config, definitions)?;
+        let default = DefaultType::Default(value.unbind());
+        Ok(Arc::new(WithDefaultSerializer { default, serializer }.into()))
+    }
+}
+
 impl TypeSerializer for WithDefaultSerializer {
     fn to_python<'py>(
         &self,
@@ -0,0 +1,117 @@
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use ahash::RandomState;
+use pyo3::exceptions::PyValueError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use crate::build_tools::LazyLock;
+
+/// The number of strings the cache holds until `set_string_cache_size` is called, the same as jiter's cache.
+const DEFAULT_SIZE: usize = 16_384;
+
+/// The largest size `set_string_cache_size` accepts, the entries of a cache this size take 256MB.
+const MAX_SIZE: usize = 1 << 24;
+
+type Entry = Option<(u64, Py<PyString>)>;
+
+/// The cache of python strings created by validators, so inputs repeating the same strings, e.g. the keys of many
+/// dicts, share string objects rather than creating them again.
+///
+/// This is like jiter's cache of strings parsed from JSON, with the same placement and replacement of entries, but
+/// with a size which can be changed, as validating many unique strings replaces entries without ever reusing them.
+struct PyStringCache {
+    // allocated when the first string is cached
+    entries: Vec<Entry>,
+    size: usize,
+    hash_builder: RandomState,
+}
+
+static STRING_CACHE: LazyLock<Mutex<PyStringCache>> = LazyLock::new(|| {
+    Mutex::new(PyStringCache {
+        entries: Vec::new(),
+        size: DEFAULT_SIZE,
+        hash_builder: RandomState::new(),
+    })
+});
+
+impl PyStringCache {
+    fn get_or_insert<'py>(&mut self, py: Python<'py>, s: &str) -> Bound<'py, PyString> {
+        if self.size == 0 {
+            return PyString::new(py, s);
+        }
+        if self.entries.is_empty() {
+            self.entries.resize_with(self.size, || None);
+        }
+        let hash = self.hash_builder.hash_one(s);
+        let hash_index = (hash % self.size as u64) as usize;
+
+        // up to 5 contiguous entries are tried for a match or an empty entry, otherwise the first is replaced
+        let mut replace_index = hash_index;
+        for index in hash_index..(hash_index + 5).min(self.size) {
+            match &self.entries[index] {
+                Some((entry_hash, py_str)) if *entry_hash == hash && py_str.bind(py) == s => {
+                    return py_str.bind(py).clone();
+                }
+                Some(_) => {}
+                None => {
+                    replace_index = index;
+                    break;
+                }
+            }
+        }
+        let py_str = PyString::new(py, s);
+        self.entries[replace_index] = Some((hash, py_str.clone().unbind()));
+        py_str
+    }
+
+    fn usage(&self) -> usize {
+        self.entries.iter().filter(|entry| entry.is_some()).count()
+    }
+}
+
+fn string_cache() -> MutexGuard<'static, PyStringCache> {
+    STRING_CACHE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Create a python string, from the cache if it holds `s`.
+pub(crate) fn cached_py_string<'py>(py: Python<'py>, s: &str) -> Bound<'py, PyString> {
+    // from jiter's tests, 0 and 1 character strings are faster not cached, and long strings are rarely repeated
+    if (2..64).contains(&s.len()) {
+        string_cache().get_or_insert(py, s)
+    } else {
+        PyString::new(py, s)
+    }
+}
+
+/// Set the number of strings the cache holds, `0` to stop caching strings, the cache is cleared.
+#[pyfunction]
+pub fn set_string_cache_size(size: usize) -> PyResult<()> {
+    if size > MAX_SIZE {
+        return Err(PyValueError::new_err(format!(
+            "String cache size {size} is too large, the maximum is {MAX_SIZE}"
+        )));
+    }
+    let mut cache = string_cache();
+    cache.entries = Vec::new();
+    cache.size = size;
+    Ok(())
+}
+
+/// Clear the cache of strings created by validators, and jiter's cache of strings parsed by `from_json`.
+#[pyfunction]
+pub fn clear_string_cache() {
+    string_cache().entries = Vec::new();
+    jiter::cache_clear();
+}
+
+/// The size of the cache and the number of strings it holds.
+#[pyfunction]
+pub fn string_cache_info(py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+    let cache = string_cache();
+    let info = PyDict::new(py);
+    info.set_item(intern!(py, "size"), cache.size)?;
+    info.set_item(intern!(py, "usage"), cache.usage())?;
+    Ok(info)
+}
@@ -4,12 +4,13 @@ use num_bigint::BigInt;
 
 use pyo3::exceptions::PyKeyError;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyMapping, PyString};
+use pyo3::types::{PyDict, PyList, PyMapping, PyString, PyTuple};
 use pyo3::{intern, FromPyObject};
 
 use crate::input::Int;
+use crate::string_cache::cached_py_string;
 use crate::PydanticUndefinedType;
-use jiter::{cached_py_string, StringCacheMode};
+use jiter::StringCacheMode;
 
 pub trait SchemaDict<'py> {
     fn get_as<T>(&self, key: &Bound<'py, PyString>) -> PyResult<Option<T>>
@@ -119,16 +120,191 @@ pub fn safe_repr<'py>(v: &Bound<'py, PyAny>) -> ReprOutput<'py> {
     }
 }
 
-// warning: this function can be incredibly slow, so use with caution
 pub fn truncate_safe_repr(v: &Bound<'_, PyAny>, max_len: Option<usize>) -> String {
     let max_len = max_len.unwrap_or(50); // default to 100 bytes
-    let input_str = safe_repr(v);
     let mut limited_str = String::with_capacity(max_len);
-    write_truncated_to_limited_bytes(&mut limited_str, &input_str.to_string(), max_len)
-        .expect("Writing to a `String` failed");
+    write_truncated_safe_repr(&mut limited_str, v, max_len).expect("Writing to a `String` failed");
     limited_str
 }
 
+/// Write the repr of `v` truncated as by `write_truncated_to_limited_bytes`.
+///
+/// Exact lists, tuples and dicts are rendered from both ends only as far as the truncated output needs,
+/// so a huge (or deeply nested) container costs no more than a small one.
+pub fn write_truncated_safe_repr<F: fmt::Write>(f: &mut F, v: &Bound<'_, PyAny>, max_len: usize) -> fmt::Result {
+    let ends = repr_head(v, max_len + 1, &mut Vec::new())
+        .and_then(|head| Ok((head, repr_tail(v, max_len + 1, &mut Vec::new())?)));
+    match ends {
+        Ok(((full, true), _) | (_, (full, true))) => write_truncated_to_limited_bytes(f, &full, max_len),
+        // neither end reached the other, so the repr is longer than `max_len + 1` and truncation keeps
+        // just the first and last `mid_point` bytes, which the ends hold
+        Ok(((head, false), (tail, false))) => {
+            let mid_point = max_len.div_ceil(2);
+            write!(
+                f,
+                "{}...{}",
+                &head[0..floor_char_boundary(&head, mid_point)],
+                &tail[ceil_char_boundary(&tail, tail.len() - (mid_point - 1))..]
+            )
+        }
+        Err(_) => write_truncated_to_limited_bytes(f, &safe_repr(v).to_string(), max_len),
+    }
+}
+
+/// Containers whose repr is rendered piecewise, all others use their full repr.
+enum ReprContainer<'py> {
+    List(Bound<'py, PyList>),
+    Tuple(Bound<'py, PyTuple>),
+    Dict(Bound<'py, PyDict>),
+}
+
+impl<'py> ReprContainer<'py> {
+    fn new(v: &Bound<'py, PyAny>) -> Option<Self> {
+        if let Ok(list) = v.downcast_exact::<PyList>() {
+            Some(Self::List(list.clone()))
+        } else if let Ok(tuple) = v.downcast_exact::<PyTuple>() {
+            Some(Self::Tuple(tuple.clone()))
+        } else if let Ok(dict) = v.downcast_exact::<PyDict>() {
+            Some(Self::Dict(dict.clone()))
+        } else {
+            None
+        }
+    }
+
+    fn delimiters(&self) -> (&'static str, &'static str) {
+        match self {
+            Self::List(_) => ("[", "]"),
+            Self::Tuple(tuple) if tuple.len() == 1 => ("(", ",)"),
+            Self::Tuple(_) => ("(", ")"),
+            Self::Dict(_) => ("{", "}"),
+        }
+    }
+
+    /// What python shows for a container which (indirectly) contains itself
+    fn recursive_repr(&self) -> &'static str {
+        match self {
+            Self::List(_) => "[...]",
+            Self::Tuple(_) => "(...)",
+            Self::Dict(_) => "{...}",
+        }
+    }
+}
+
+/// At least the first `n` bytes of the repr of `v`, and whether that's the whole repr.
+fn repr_head(v: &Bound<'_, PyAny>, n: usize, seen: &mut Vec<*mut pyo3::ffi::PyObject>) -> PyResult<(String, bool)> {
+    let Some(container) = ReprContainer::new(v) else {
+        return Ok((v.repr()?.to_str()?.to_owned(), true));
+    };
+    if seen.contains(&v.as_ptr()) {
+        return Ok((container.recursive_repr().to_owned(), true));
+    }
+    seen.push(v.as_ptr());
+    let (open, close) = container.delimiters();
+    let mut out = open.to_owned();
+    let complete = match &container {
+        ReprContainer::List(list) => items_head(&mut out, list.iter().map(|item| (None, item)), n, seen)?,
+        ReprContainer::Tuple(tuple) => items_head(&mut out, tuple.iter().map(|item| (None, item)), n, seen)?,
+        ReprContainer::Dict(dict) => items_head(&mut out, dict.iter().map(|(k, v)| (Some(k), v)), n, seen)?,
+    };
+    seen.pop();
+    if complete {
+        out.push_str(close);
+    }
+    Ok((out, complete))
+}
+
+fn items_head<'py>(
+    out: &mut String,
+    items: impl Iterator<Item = (Option<Bound<'py, PyAny>>, Bound<'py, PyAny>)>,
+    n: usize,
+    seen: &mut Vec<*mut pyo3::ffi::PyObject>,
+) -> PyResult<bool> {
+    for (index, (key, value)) in items.enumerate() {
+        if out.len() >= n {
+            return Ok(false);
+        }
+        if index > 0 {
+            out.push_str(", ");
+        }
+        let parts = key.iter().chain(std::iter::once(&value));
+        for (part_index, part) in parts.enumerate() {
+            if part_index > 0 {
+                out.push_str(": ");
+            }
+            let (s, complete) = repr_head(part, n.saturating_sub(out.len()), seen)?;
+            out.push_str(&s);
+            if !complete {
+                return Ok(false);
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// At least the last `n` bytes of the repr of `v`, and whether that's the whole repr.
+fn repr_tail(v: &Bound<'_, PyAny>, n: usize, seen: &mut Vec<*mut pyo3::ffi::PyObject>) -> PyResult<(String, bool)> {
+    let Some(container) = ReprContainer::new(v) else {
+        return Ok((v.repr()?.to_str()?.to_owned(), true));
+    };
+    if seen.contains(&v.as_ptr()) {
+        return Ok((container.recursive_repr().to_owned(), true));
+    }
+    seen.push(v.as_ptr());
+    let (open, close) = container.delimiters();
+    // pieces are collected from the end backwards
+    let mut pieces = vec![close.to_owned()];
+    let complete = match &container {
+        ReprContainer::List(list) => items_tail(&mut pieces, list.iter().rev().map(|item| (None, item)), n, seen)?,
+        ReprContainer::Tuple(tuple) => items_tail(&mut pieces, tuple.iter().rev().map(|item| (None, item)), n, seen)?,
+        ReprContainer::Dict(dict) => {
+            let items = dict.items();
+            let items = items.iter().rev().map(|item| {
+                let (k, v) = item.extract::<(Bound<'_, PyAny>, Bound<'_, PyAny>)>()?;
+                Ok((Some(k), v))
+            });
+            items_tail(&mut pieces, items.collect::<PyResult<Vec<_>>>()?.into_iter(), n, seen)?
+        }
+    };
+    seen.pop();
+    if complete {
+        pieces.push(open.to_owned());
+    }
+    pieces.reverse();
+    Ok((pieces.concat(), complete))
+}
+
+fn items_tail<'py>(
+    pieces: &mut Vec<String>,
+    items: impl Iterator<Item = (Option<Bound<'py, PyAny>>, Bound<'py, PyAny>)>,
+    n: usize,
+    seen: &mut Vec<*mut pyo3::ffi::PyObject>,
+) -> PyResult<bool> {
+    let mut len = pieces.iter().map(String::len).sum::<usize>();
+    for (index, (key, value)) in items.enumerate() {
+        if len >= n {
+            return Ok(false);
+        }
+        if index > 0 {
+            pieces.push(", ".to_owned());
+            len += 2;
+        }
+        let parts = std::iter::once(&value).chain(key.iter());
+        for (part_index, part) in parts.enumerate() {
+            if part_index > 0 {
+                pieces.push(": ".to_owned());
+                len += 2;
+            }
+            let (s, complete) = repr_tail(part, n.saturating_sub(len), seen)?;
+            len += s.len();
+            pieces.push(s);
+            if !complete {
+                return Ok(false);
+            }
+        }
+    }
+    Ok(true)
+}
+
 pub fn extract_i64(v: &Bound<'_, PyAny>) -> Option<i64> {
     v.extract().ok()
 }
@@ -202,3 +378,15 @@ pub fn mapping_get<'py>(
         .call_method1(intern!(mapping.py(), "get"), (key, undefined))
         .map(|value| if value.is(undefined) { None } else { Some(value) })
 }
+
+/// Compare two byte strings in time which depends only on their lengths, not their contents, so comparing
+/// secrets doesn't leak how much of them matched, only used by `compare_secrets` for now.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let diff = a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y));
+    // best-effort optimization barrier, so the compiler is less likely to short-circuit the fold once a difference
+    // is found, `black_box` doesn't guarantee it
+    std::hint::black_box(diff) == 0
+}
@@ -0,0 +1,163 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::{self, Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+use jiter::{PartialMode, StringCacheMode};
+use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+use pyo3::types::{PyBytes, PyDateTime, PyTzInfo};
+use pyo3::IntoPyObjectExt;
+
+use crate::input::InputType;
+use crate::recursion_guard::RecursionState;
+use crate::validators::ulid::UlidValidator;
+use crate::validators::{Extra, ValidationOptions, ValidationState, Validator};
+use crate::ValidationError;
+
+/// Crockford's base32 alphabet, which excludes `I`, `L`, `O` and `U`.
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const ULID_LENGTH: usize = 26;
+
+/// A [ULID](https://github.com/ulid/spec), a 128-bit identifier made of a 48-bit millisecond timestamp and 80 random
+/// bits, written as 26 Crockford base32 characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Ulid(u128);
+
+impl Ulid {
+    pub fn parse(s: &str) -> Result<Self, &'static str> {
+        if s.len() != ULID_LENGTH {
+            return Err("it should have 26 characters");
+        }
+        let mut value: u128 = 0;
+        for (index, b) in s.bytes().enumerate() {
+            // decoding is case-insensitive, the canonical form is upper case
+            let b = b.to_ascii_uppercase();
+            let Some(digit) = ALPHABET.iter().position(|c| *c == b) else {
+                return Err("it should only contain Crockford base32 characters");
+            };
+            // 26 characters hold 130 bits, so the first can be at most `7`
+            if index == 0 && digit > 7 {
+                return Err("it is too large, the first character should be at most 7");
+            }
+            value = (value << 5) | digit as u128;
+        }
+        Ok(Self(value))
+    }
+
+    pub fn from_u128(value: u128) -> Self {
+        Self(value)
+    }
+
+    pub fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self(u128::from_be_bytes(bytes))
+    }
+
+    /// Milliseconds since the Unix epoch, from the first 48 bits.
+    pub fn timestamp_ms(self) -> u64 {
+        // the shifted value is at most 48 bits so always fits
+        (self.0 >> 80) as u64
+    }
+}
+
+impl Display for Ulid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut chars = [0; ULID_LENGTH];
+        for (index, c) in chars.iter_mut().enumerate() {
+            let shift = 5 * (ULID_LENGTH - 1 - index);
+            *c = ALPHABET[((self.0 >> shift) & 0x1f) as usize];
+        }
+        // the alphabet is ASCII
+        f.write_str(std::str::from_utf8(&chars).map_err(|_| fmt::Error)?)
+    }
+}
+
+#[pyclass(name = "Ulid", module = "pydantic_core._pydantic_core", subclass, frozen)]
+#[derive(Clone)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct PyUlid {
+    ulid: Ulid,
+}
+
+impl PyUlid {
+    pub fn new(ulid: Ulid) -> Self {
+        Self { ulid }
+    }
+}
+
+#[pymethods]
+impl PyUlid {
+    #[new]
+    pub fn py_new(py: Python, ulid: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let validator = UlidValidator::get_simple();
+        let ulid_obj = validator
+            .validate(
+                py,
+                ulid,
+                &mut ValidationState::new(
+                    Extra::new(&ValidationOptions::default(), InputType::Python, StringCacheMode::None),
+                    &mut RecursionState::default(),
+                    PartialMode::Off,
+                ),
+            )
+            .map_err(|e| {
+                let name = match validator.get_name().into_py_any(py) {
+                    Ok(name) => name,
+                    Err(e) => return e,
+                };
+                ValidationError::from_val_error(py, name, InputType::Python, e, None, false, false)
+            })?
+            .downcast_bound::<Self>(py)?
+            .get()
+            .clone();
+        Ok(ulid_obj)
+    }
+
+    /// The time the ULID was generated, as an aware UTC datetime.
+    #[getter]
+    pub fn timestamp<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDateTime>> {
+        let seconds = self.ulid.timestamp_ms() as f64 / 1000.0;
+        PyDateTime::from_timestamp(py, seconds, Some(&PyTzInfo::utc(py)?.to_owned()))
+    }
+
+    #[getter]
+    pub fn timestamp_ms(&self) -> u64 {
+        self.ulid.timestamp_ms()
+    }
+
+    #[getter]
+    pub fn int(&self) -> u128 {
+        self.ulid.0
+    }
+
+    #[getter]
+    pub fn bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.ulid.0.to_be_bytes())
+    }
+
+    pub fn __str__(&self, _py: Python<'_>) -> String {
+        self.ulid.to_string()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("Ulid('{}')", self.ulid)
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+        op.matches(self.ulid.cmp(&other.ulid))
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.ulid.hash(&mut s);
+        s.finish()
+    }
+
+    #[pyo3(signature = (_memo, /))]
+    pub fn __deepcopy__(&self, py: Python, _memo: Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        self.clone().into_py_any(py)
+    }
+
+    fn __getnewargs__(&self) -> (String,) {
+        (self.ulid.to_string(),)
+    }
+}
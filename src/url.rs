@@ -8,18 +8,18 @@ use std::sync::OnceLock;
 use idna::punycode::decode_to_string;
 use jiter::{PartialMode, StringCacheMode};
 use percent_encoding::{percent_encode, AsciiSet, CONTROLS};
-use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::pyclass::CompareOp;
 use pyo3::sync::OnceLockExt;
-use pyo3::types::{PyDict, PyType};
+use pyo3::types::{PyDict, PyString, PyType};
 use pyo3::{intern, prelude::*, IntoPyObjectExt};
-use url::Url;
+use url::{Position, Url};
 
 use crate::input::InputType;
 use crate::recursion_guard::RecursionState;
 use crate::tools::SchemaDict;
 use crate::validators::url::{MultiHostUrlValidator, UrlValidator};
-use crate::validators::{Extra, ValidationState, Validator};
+use crate::validators::{Extra, ValidationOptions, ValidationState, Validator};
 use crate::ValidationError;
 
 #[pyclass(name = "Url", module = "pydantic_core._pydantic_core", subclass, frozen)]
@@ -30,16 +30,33 @@ pub struct PyUrl {
     /// Override to treat the path as empty when it is `/`. The `url` crate always normalizes an empty path to `/`,
     /// but users may want to preserve the empty path when round-tripping.
     path_is_empty: bool,
+    /// The host and path as given, when the `url` schema's `punycode_host` or `normalize_path` is false, used
+    /// instead of `lib_url`'s in the serialized representation.
+    original: Option<Box<OriginalParts>>,
+    /// The `url` schema's `punycode_host` and `normalize_path`, so URLs built from this one by `replace`, `join`
+    /// and unpickling keep their original form too.
+    punycode_host: bool,
+    normalize_path: bool,
     /// Cache for the serialized representation where this diverges from `lib_url.as_str()`
     /// (i.e. when trailing slash was added to the empty path, but user didn't want that)
     serialized: OnceLock<String>,
 }
 
+/// Parts of a URL kept in the form they were given, see `PyUrl::with_original`.
+#[derive(Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct OriginalParts {
+    pub host: Option<String>,
+    pub path: Option<String>,
+}
+
 impl Hash for PyUrl {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.lib_url.hash(state);
         self.path_is_empty.hash(state);
-        // no need to hash `serialized` as it's derived from the other two fields
+        self.original.hash(state);
+        // no need to hash `serialized` as it's derived from the other fields, nor `punycode_host` and
+        // `normalize_path` as they're not part of the URL
     }
 }
 
@@ -48,10 +65,24 @@ impl PyUrl {
         Self {
             lib_url,
             path_is_empty,
+            original: None,
+            punycode_host: true,
+            normalize_path: true,
             serialized: OnceLock::new(),
         }
     }
 
+    /// Keep the host and path in the form they were given, the parts which are `None` come from `lib_url`.
+    #[must_use]
+    pub fn with_original(mut self, original: OriginalParts, punycode_host: bool, normalize_path: bool) -> Self {
+        if original.host.is_some() || original.path.is_some() {
+            self.original = Some(Box::new(original));
+        }
+        self.punycode_host = punycode_host;
+        self.normalize_path = normalize_path;
+        self
+    }
+
     pub fn url(&self) -> &Url {
         &self.lib_url
     }
@@ -61,37 +92,71 @@ impl PyUrl {
     }
 
     fn serialized(&self, py: Python<'_>) -> &str {
-        if self.path_is_empty {
+        if let Some(original) = &self.original {
+            self.serialized.get_or_init_py_attached(py, || {
+                serialize_url_with_original(&self.lib_url, self.path_is_empty, original)
+            })
+        } else if self.path_is_empty {
             self.serialized
                 .get_or_init_py_attached(py, || serialize_url_without_path_slash(&self.lib_url))
         } else {
             self.lib_url.as_str()
         }
     }
+
+    fn cmp_key(&self) -> (&Url, &Option<Box<OriginalParts>>) {
+        (&self.lib_url, &self.original)
+    }
+
+    /// The keyword arguments to `Url(..)` to validate URLs like this one.
+    fn new_kwargs<'py>(&self, py: Python<'py>, preserve_empty_path: bool) -> PyResult<Option<Bound<'py, PyDict>>> {
+        if !preserve_empty_path && self.punycode_host && self.normalize_path {
+            return Ok(None);
+        }
+        let kwargs = PyDict::new(py);
+        if preserve_empty_path {
+            kwargs.set_item(intern!(py, "preserve_empty_path"), true)?;
+        }
+        if !self.punycode_host {
+            kwargs.set_item(intern!(py, "punycode_host"), false)?;
+        }
+        if !self.normalize_path {
+            kwargs.set_item(intern!(py, "normalize_path"), false)?;
+        }
+        Ok(Some(kwargs))
+    }
+
+    /// A URL of the same type as `slf` from `url_str`, keeping its host and path as given if `slf` does.
+    fn rebuild<'py>(slf: &Bound<'py, Self>, url_str: &str) -> PyResult<Bound<'py, PyAny>> {
+        let kwargs = slf.get().new_kwargs(slf.py(), false)?;
+        slf.get_type().call((url_str,), kwargs.as_ref())
+    }
 }
 
 #[pymethods]
 impl PyUrl {
     #[new]
-    #[pyo3(signature = (url, *, preserve_empty_path=false))]
-    pub fn py_new(py: Python, url: &Bound<'_, PyAny>, preserve_empty_path: bool) -> PyResult<Self> {
-        let validator = UrlValidator::get_simple(false, preserve_empty_path);
+    #[pyo3(signature = (url, *, preserve_empty_path=false, punycode_host=true, normalize_path=true))]
+    pub fn py_new(
+        py: Python,
+        url: &Bound<'_, PyAny>,
+        preserve_empty_path: bool,
+        punycode_host: bool,
+        normalize_path: bool,
+    ) -> PyResult<Self> {
+        let original_form;
+        let validator = if punycode_host && normalize_path {
+            UrlValidator::get_simple(false, preserve_empty_path)
+        } else {
+            original_form = UrlValidator::get_original_form(preserve_empty_path, punycode_host, normalize_path);
+            &original_form
+        };
         let url_obj = validator
             .validate(
                 py,
                 url,
                 &mut ValidationState::new(
-                    Extra::new(
-                        None,
-                        None,
-                        None,
-                        None,
-                        None,
-                        InputType::Python,
-                        StringCacheMode::None,
-                        None,
-                        None,
-                    ),
+                    Extra::new(&ValidationOptions::default(), InputType::Python, StringCacheMode::None),
                     &mut RecursionState::default(),
                     PartialMode::Off,
                 ),
@@ -129,11 +194,17 @@ impl PyUrl {
 
     #[getter]
     pub fn host(&self) -> Option<&str> {
-        self.lib_url.host_str()
+        match self.original.as_ref().and_then(|original| original.host.as_deref()) {
+            Some(host) => Some(host),
+            None => self.lib_url.host_str(),
+        }
     }
 
     // string representation of the host, with punycode decoded when appropriate
     pub fn unicode_host(&self) -> Option<String> {
+        if let Some(host) = self.original.as_ref().and_then(|original| original.host.as_ref()) {
+            return Some(host.clone());
+        }
         match self.lib_url.host() {
             Some(url::Host::Domain(domain)) if is_punnycode_domain(&self.lib_url, domain) => decode_punycode(domain),
             _ => self.lib_url.host_str().map(ToString::to_string),
@@ -147,6 +218,9 @@ impl PyUrl {
 
     #[getter]
     pub fn path(&self) -> Option<&str> {
+        if let Some(path) = self.original.as_ref().and_then(|original| original.path.as_deref()) {
+            return Some(path);
+        }
         match self.lib_url.path() {
             "" => None,
             "/" if self.path_is_empty => None,
@@ -175,6 +249,10 @@ impl PyUrl {
 
     // string representation of the URL, with punycode decoded when appropriate
     pub fn unicode_string(&self, py: Python<'_>) -> Cow<'_, str> {
+        if self.original.as_ref().is_some_and(|original| original.host.is_some()) {
+            // the host isn't punycode encoded
+            return Cow::Borrowed(self.serialized(py));
+        }
         unicode_url(self.serialized(py), &self.lib_url)
     }
 
@@ -188,12 +266,12 @@ impl PyUrl {
 
     fn __richcmp__(&self, other: &Self, op: CompareOp) -> PyResult<bool> {
         match op {
-            CompareOp::Lt => Ok(self.lib_url < other.lib_url),
-            CompareOp::Le => Ok(self.lib_url <= other.lib_url),
-            CompareOp::Eq => Ok(self.lib_url == other.lib_url),
-            CompareOp::Ne => Ok(self.lib_url != other.lib_url),
-            CompareOp::Gt => Ok(self.lib_url > other.lib_url),
-            CompareOp::Ge => Ok(self.lib_url >= other.lib_url),
+            CompareOp::Lt => Ok(self.cmp_key() < other.cmp_key()),
+            CompareOp::Le => Ok(self.cmp_key() <= other.cmp_key()),
+            CompareOp::Eq => Ok(self.cmp_key() == other.cmp_key()),
+            CompareOp::Ne => Ok(self.cmp_key() != other.cmp_key()),
+            CompareOp::Gt => Ok(self.cmp_key() > other.cmp_key()),
+            CompareOp::Ge => Ok(self.cmp_key() >= other.cmp_key()),
         }
     }
 
@@ -212,8 +290,101 @@ impl PyUrl {
         self.clone().into_py_any(py)
     }
 
-    fn __getnewargs__(&self, py: Python<'_>) -> (&str,) {
-        (self.__str__(py),)
+    fn __getnewargs_ex__<'py>(&self, py: Python<'py>) -> PyResult<((&str,), Bound<'py, PyDict>)> {
+        let kwargs = self
+            .new_kwargs(py, self.path_is_empty)?
+            .unwrap_or_else(|| PyDict::new(py));
+        Ok(((self.__str__(py),), kwargs))
+    }
+
+    /// A copy of the URL with the given parts replaced, parts given as `None` are removed. The host and path keep
+    /// their original form, including when replaced, if this URL's do.
+    #[pyo3(signature = (**parts))]
+    pub fn replace<'py>(slf: &Bound<'py, Self>, parts: Option<&Bound<'py, PyDict>>) -> PyResult<Bound<'py, PyAny>> {
+        let py = slf.py();
+        let this = slf.get();
+        let mut url = this.lib_url.clone();
+        let mut original = this.original.as_deref().cloned().unwrap_or_default();
+        if let Some(parts) = parts {
+            for key in parts.keys() {
+                let key = key.downcast::<PyString>()?.to_str()?;
+                if !REPLACE_PARTS.contains(&key) {
+                    return Err(PyTypeError::new_err(format!(
+                        "replace() got an unexpected keyword argument '{key}'"
+                    )));
+                }
+            }
+            // `Some(None)` if the part is given as `None`
+            let part = |name: &Bound<'py, PyString>| -> PyResult<Option<Option<String>>> {
+                parts.get_item(name)?.map(|value| value.extract()).transpose()
+            };
+            let cannot_have = |url: &Url, part: &str| PyValueError::new_err(format!("'{url}' can't have a {part}"));
+            // parts are replaced in order, as e.g. whether a URL can have a port depends on its scheme and host
+            if let Some(scheme) = parts.get_as::<String>(intern!(py, "scheme"))? {
+                if url.set_scheme(&scheme).is_err() {
+                    return Err(PyValueError::new_err(format!(
+                        "Can't change the scheme of '{url}' to '{scheme}'"
+                    )));
+                }
+            }
+            if let Some(host) = part(intern!(py, "host"))? {
+                url.set_host(host.as_deref())
+                    .map_err(|e| PyValueError::new_err(format!("Invalid host: {e}")))?;
+                original.host = host.filter(|_| !this.punycode_host);
+            }
+            if let Some(username) = part(intern!(py, "username"))? {
+                if url.set_username(username.as_deref().unwrap_or_default()).is_err() {
+                    return Err(cannot_have(&url, "username"));
+                }
+            }
+            if let Some(password) = part(intern!(py, "password"))? {
+                if url.set_password(password.as_deref()).is_err() {
+                    return Err(cannot_have(&url, "password"));
+                }
+            }
+            if let Some(port) = parts.get_item(intern!(py, "port"))? {
+                if url.set_port(port.extract()?).is_err() {
+                    return Err(cannot_have(&url, "port"));
+                }
+            }
+            if let Some(path) = part(intern!(py, "path"))? {
+                url.set_path(path.as_deref().unwrap_or_default());
+                // a path without the leading `/` is only valid once normalized
+                original.path = path.filter(|path| !this.normalize_path && path.starts_with('/'));
+            }
+            if let Some(query) = part(intern!(py, "query"))? {
+                url.set_query(query.as_deref());
+            }
+            if let Some(fragment) = part(intern!(py, "fragment"))? {
+                url.set_fragment(fragment.as_deref());
+            }
+        }
+        Self::rebuild(slf, &serialize_url_with_original(&url, false, &original))
+    }
+
+    /// The URL `relative` resolves to with this URL as the base, like a link in a page at this URL. The host and
+    /// path keep their original form if this URL's do and `relative` doesn't change them, resolving a relative
+    /// path normalizes it.
+    pub fn join<'py>(slf: &Bound<'py, Self>, relative: &str) -> PyResult<Bound<'py, PyAny>> {
+        if Url::parse(relative).is_ok() {
+            // an absolute URL doesn't depend on this one
+            return Self::rebuild(slf, relative);
+        }
+        let this = slf.get();
+        let url = this
+            .lib_url
+            .join(relative)
+            .map_err(|e| PyValueError::new_err(format!("Can't join '{relative}' to the URL: {e}")))?;
+        let mut original = OriginalParts::default();
+        if let Some(base) = &this.original {
+            if url.host_str() == this.lib_url.host_str() {
+                original.host.clone_from(&base.host);
+            }
+            if url.path() == this.lib_url.path() {
+                original.path.clone_from(&base.path);
+            }
+        }
+        Self::rebuild(slf, &serialize_url_with_original(&url, false, &original))
     }
 
     #[classmethod]
@@ -310,17 +481,7 @@ impl PyMultiHostUrl {
                 py,
                 url,
                 &mut ValidationState::new(
-                    Extra::new(
-                        None,
-                        None,
-                        None,
-                        None,
-                        None,
-                        InputType::Python,
-                        StringCacheMode::None,
-                        None,
-                        None,
-                    ),
+                    Extra::new(&ValidationOptions::default(), InputType::Python, StringCacheMode::None),
                     &mut RecursionState::default(),
                     PartialMode::Off,
                 ),
@@ -485,9 +646,85 @@ impl PyMultiHostUrl {
             false, // TODO: re-enable this
         )
     }
+
+    /// A copy of the URL with `hosts` instead of its hosts.
+    fn with_hosts<'py>(slf: &Bound<'py, Self>, hosts: Vec<UrlHostParts>) -> PyResult<Bound<'py, PyAny>> {
+        slf.get().rebuild(&slf.get_type(), hosts)
+    }
+
+    /// A copy of the URL with another host after its hosts.
+    #[pyo3(signature = (*, host, username=None, password=None, port=None))]
+    fn add_host<'py>(
+        slf: &Bound<'py, Self>,
+        host: String,
+        username: Option<String>,
+        password: Option<String>,
+        port: Option<u16>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let mut hosts = slf.get().host_parts();
+        hosts.push(UrlHostParts {
+            username,
+            password,
+            host: Some(host),
+            port,
+        });
+        slf.get().rebuild(&slf.get_type(), hosts)
+    }
+
+    /// A copy of the URL without the hosts matching `host`, and `port` if it's given.
+    #[pyo3(signature = (host, port=None))]
+    fn remove_host<'py>(slf: &Bound<'py, Self>, host: &str, port: Option<u16>) -> PyResult<Bound<'py, PyAny>> {
+        let url = slf.get();
+        let urls: Vec<&Url> = url.host_urls().collect();
+        let hosts: Vec<UrlHostParts> = urls
+            .iter()
+            .filter(|url| {
+                // like `hosts()`, ports which aren't given are the scheme's default port
+                url.host_str() != Some(host) || port.is_some_and(|port| url.port_or_known_default() != Some(port))
+            })
+            .map(|url| UrlHostParts::from_url(url))
+            .collect();
+        if hosts.len() == urls.len() {
+            let host = port.map_or_else(|| host.to_string(), |port| format!("{host}:{port}"));
+            return Err(PyValueError::new_err(format!("'{host}' isn't a host of the URL")));
+        }
+        if hosts.is_empty() {
+            return Err(PyValueError::new_err("Can't remove every host of the URL"));
+        }
+        url.rebuild(&slf.get_type(), hosts)
+    }
 }
 
 impl PyMultiHostUrl {
+    /// The URL of each host, in the order of `hosts`.
+    fn host_urls(&self) -> impl Iterator<Item = &Url> {
+        self.extra_urls
+            .iter()
+            .flatten()
+            .chain(std::iter::once(&self.ref_url.lib_url).filter(|url| url.has_host()))
+    }
+
+    fn host_parts(&self) -> Vec<UrlHostParts> {
+        self.host_urls().map(UrlHostParts::from_url).collect()
+    }
+
+    /// Build a URL of the type `cls` with the scheme, path, query and fragment of this URL and `hosts`.
+    fn rebuild<'py>(&self, cls: &Bound<'py, PyType>, hosts: Vec<UrlHostParts>) -> PyResult<Bound<'py, PyAny>> {
+        Self::build_inner(
+            cls,
+            self.scheme(),
+            Some(hosts),
+            self.path().map(|path| path.strip_prefix('/').unwrap_or(path)),
+            self.query(),
+            self.fragment(),
+            None,
+            None,
+            None,
+            None,
+            false,
+        )
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn build_inner<'py>(
         cls: &Bound<'py, PyType>,
@@ -510,6 +747,9 @@ impl PyMultiHostUrl {
                 "expected one of `hosts` or singular values to be set.",
             ));
         } else if let Some(hosts) = hosts {
+            if hosts.is_empty() {
+                return Err(PyValueError::new_err("expected at least one host in `hosts`"));
+            }
             // check all of host / user / password / port empty
             // build multi-host url
             let len = hosts.len();
@@ -576,8 +816,17 @@ impl fmt::Display for MaybeEncoded<'_> {
 }
 
 impl UrlHostParts {
+    fn from_url(url: &Url) -> Self {
+        Self {
+            username: Some(url.username()).filter(|s| !s.is_empty()).map(Into::into),
+            password: url.password().map(Into::into),
+            host: url.host_str().map(Into::into),
+            port: url.port(),
+        }
+    }
+
     fn is_empty(&self) -> bool {
-        self.host.is_none() && self.password.is_none() && self.host.is_none() && self.port.is_none()
+        self.username.is_none() && self.password.is_none() && self.host.is_none() && self.port.is_none()
     }
 
     fn to_writer(&self, mut w: impl Write, encode_credentials: bool) -> fmt::Result {
@@ -606,11 +855,12 @@ impl FromPyObject<'_> for UrlHostParts {
     fn extract_bound(ob: &Bound<'_, PyAny>) -> PyResult<Self> {
         let py = ob.py();
         let dict = ob.downcast::<PyDict>()?;
+        // parts can be `None`, like in the dicts returned by `hosts()`
         Ok(UrlHostParts {
-            username: dict.get_as(intern!(py, "username"))?,
-            password: dict.get_as(intern!(py, "password"))?,
-            host: dict.get_as(intern!(py, "host"))?,
-            port: dict.get_as(intern!(py, "port"))?,
+            username: dict.get_as::<Option<_>>(intern!(py, "username"))?.flatten(),
+            password: dict.get_as::<Option<_>>(intern!(py, "password"))?.flatten(),
+            host: dict.get_as::<Option<_>>(intern!(py, "host"))?.flatten(),
+            port: dict.get_as::<Option<_>>(intern!(py, "port"))?.flatten(),
         })
     }
 }
@@ -656,6 +906,11 @@ fn decode_punycode(domain: &str) -> Option<String> {
 
 static PUNYCODE_PREFIX: &str = "xn--";
 
+/// The parts of a URL `Url.replace` can replace.
+const REPLACE_PARTS: &[&str] = &[
+    "scheme", "username", "password", "host", "port", "path", "query", "fragment",
+];
+
 fn is_punnycode_domain(lib_url: &Url, domain: &str) -> bool {
     scheme_is_special(lib_url.scheme()) && domain.split('.').any(|part| part.starts_with(PUNYCODE_PREFIX))
 }
@@ -720,3 +975,21 @@ fn serialize_url_without_path_slash(url: &Url) -> String {
 
     format!("{prefix}{suffix}")
 }
+
+fn serialize_url_with_original(url: &Url, path_is_empty: bool, original: &OriginalParts) -> String {
+    let host = original
+        .host
+        .as_deref()
+        .unwrap_or(&url[Position::BeforeHost..Position::AfterHost]);
+    let path = match &original.path {
+        Some(path) => path.as_str(),
+        None if path_is_empty => "",
+        None => url.path(),
+    };
+    format!(
+        "{}{host}{}{path}{}",
+        &url[..Position::BeforeHost],
+        &url[Position::AfterHost..Position::BeforePath],
+        &url[Position::AfterPath..]
+    )
+}
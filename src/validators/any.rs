@@ -7,7 +7,8 @@ use crate::input::Input;
 use crate::{build_tools::LazyLock, errors::ValResult};
 
 use super::{
-    validation_state::Exactness, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator,
+    validation_state::Exactness, BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator,
+    ValidationState, Validator,
 };
 
 /// This might seem useless, but it's useful in DictValidator to avoid Option<Validator> a lot
@@ -42,6 +43,10 @@ impl Validator for AnyValidator {
         Ok(input.to_object(py)?.unbind())
     }
 
+    fn example<'py>(&self, py: Python<'py>, _generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        Ok(py.None().into_bound(py))
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
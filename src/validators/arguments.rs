@@ -1,6 +1,7 @@
 use std::str::FromStr;
 use std::sync::Arc;
 
+use pyo3::exceptions::PyTypeError;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString, PyTuple};
@@ -15,6 +16,7 @@ use crate::input::{Arguments, BorrowInput, Input, KeywordArgs, PositionalArgs, V
 use crate::lookup_key::LookupKeyCollection;
 use crate::tools::SchemaDict;
 
+use super::profile;
 use super::validation_state::ValidationState;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
 
@@ -43,16 +45,20 @@ impl FromStr for VarKwargsMode {
 struct Parameter {
     positional: bool,
     name: String,
+    py_name: Py<PyString>,
     kwarg_key: Option<Py<PyString>>,
     validator: Arc<CombinedValidator>,
     lookup_key_collection: LookupKeyCollection,
-    mode: String,
 }
 
 #[derive(Debug)]
 pub struct ArgumentsValidator {
     parameters: Vec<Parameter>,
     positional_params_count: usize,
+    // calls with no keyword arguments and at least this many positional arguments bind every parameter from its
+    // positional slot or its default, so can skip looking up keyword arguments; `None` if some parameter without
+    // a default can only be passed by keyword, or keyword arguments are always validated
+    min_positional_only_call: Option<usize>,
     var_args_validator: Option<Arc<CombinedValidator>>,
     var_kwargs_mode: VarKwargsMode,
     var_kwargs_validator: Option<Arc<CombinedValidator>>,
@@ -78,6 +84,9 @@ impl BuildValidator for ArgumentsValidator {
         let mut positional_params_count = 0;
         let mut had_default_arg = false;
         let mut had_keyword_only = false;
+        // index after the last parameter without a default, and whether all such parameters are positional
+        let mut required_params_end = 0;
+        let mut required_all_positional = true;
 
         for (arg_index, arg) in arguments_schema.iter().enumerate() {
             let arg = arg.downcast::<PyDict>()?;
@@ -100,14 +109,14 @@ impl BuildValidator for ArgumentsValidator {
             }
 
             let kwarg_key = if matches!(mode, "keyword_only" | "positional_or_keyword") {
-                Some(py_name.unbind())
+                Some(py_name.clone().unbind())
             } else {
                 None
             };
 
             let schema = arg.get_as_req(intern!(py, "schema"))?;
 
-            let validator = match build_validator(&schema, config, definitions) {
+            let validator = match profile::with_path_segment(&name, || build_validator(&schema, config, definitions)) {
                 Ok(v) => v,
                 Err(err) => return py_schema_err!("Parameter '{}':\n  {}", name, err),
             };
@@ -127,6 +136,10 @@ impl BuildValidator for ArgumentsValidator {
             } else if has_default {
                 had_default_arg = true;
             }
+            if !has_default {
+                required_params_end = arg_index + 1;
+                required_all_positional &= positional;
+            }
 
             let validation_alias = arg.get_item(intern!(py, "alias"))?;
             let lookup_key_collection = LookupKeyCollection::new(py, validation_alias, name.as_str())?;
@@ -134,10 +147,10 @@ impl BuildValidator for ArgumentsValidator {
             parameters.push(Parameter {
                 positional,
                 name,
+                py_name: py_name.unbind(),
                 kwarg_key,
                 validator,
                 lookup_key_collection,
-                mode: mode.to_string(),
             });
         }
 
@@ -157,9 +170,15 @@ impl BuildValidator for ArgumentsValidator {
             );
         }
 
+        let positional_slots_valid = parameters[..positional_params_count].iter().all(|p| p.positional);
+        let min_positional_only_call =
+            (required_all_positional && positional_slots_valid && var_kwargs_mode == VarKwargsMode::Uniform)
+                .then_some(required_params_end);
+
         Ok(CombinedValidator::Arguments(Self {
             parameters,
             positional_params_count,
+            min_positional_only_call,
             var_args_validator: match schema.get_item(intern!(py, "var_args_schema"))? {
                 Some(v) => Some(build_validator(&v, config, definitions)?),
                 None => None,
@@ -198,13 +217,21 @@ impl Validator for ArgumentsValidator {
         let mut output_args: Vec<Py<PyAny>> = Vec::with_capacity(self.positional_params_count);
         let output_kwargs = PyDict::new(py);
         let mut errors: Vec<ValLineError> = Vec::new();
-        let mut used_kwargs: AHashSet<&str> = AHashSet::with_capacity(self.parameters.len());
+        let mut used_kwargs: AHashSet<&str> = AHashSet::new();
 
         let validate_by_alias = state.validate_by_alias_or(self.validate_by_alias);
         let validate_by_name = state.validate_by_name_or(self.validate_by_name);
 
+        let positional_only_call = args.kwargs().map_or(true, |kwargs| kwargs.len() == 0)
+            && self
+                .min_positional_only_call
+                .is_some_and(|min| args.args().map_or(0, PositionalArgs::len) >= min);
+
         // go through arguments getting the value from args or kwargs and validating it
         for (index, parameter) in self.parameters.iter().enumerate() {
+            if state.stop_collecting(false, &errors) {
+                break;
+            }
             let mut pos_value = None;
             if let Some(args) = args.args() {
                 if parameter.positional {
@@ -213,7 +240,9 @@ impl Validator for ArgumentsValidator {
             }
             let mut kw_value = None;
             let mut kw_lookup_key = None;
-            if matches!(parameter.mode.as_str(), "keyword_only" | "positional_or_keyword") {
+            // with a positional only call, parameters not in a positional slot have defaults, so there's no need
+            // to look them up by keyword
+            if parameter.kwarg_key.is_some() && !positional_only_call {
                 kw_lookup_key = Some(
                     parameter
                         .lookup_key_collection
@@ -230,8 +259,7 @@ impl Validator for ArgumentsValidator {
                 }
             }
 
-            let state =
-                &mut state.rebind_extra(|extra| extra.field_name = Some(PyString::new(py, parameter.name.as_str())));
+            let state = &mut state.rebind_extra(|extra| extra.field_name = Some(parameter.py_name.bind(py).clone()));
 
             match (pos_value, kw_value) {
                 (Some(_), Some((_, kw_value))) => {
@@ -241,7 +269,10 @@ impl Validator for ArgumentsValidator {
                         parameter.name.clone(),
                     ));
                 }
-                (Some(pos_value), None) => match parameter.validator.validate(py, pos_value.borrow_input(), state) {
+                (Some(pos_value), None) => match state.with_path_item(
+                    || index,
+                    |state| parameter.validator.validate(py, pos_value.borrow_input(), state),
+                ) {
                     Ok(value) => output_args.push(value),
                     Err(ValError::LineErrors(line_errors)) => {
                         errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
@@ -249,7 +280,10 @@ impl Validator for ArgumentsValidator {
                     Err(err) => return Err(err),
                 },
                 (None, Some((lookup_path, kw_value))) => {
-                    match parameter.validator.validate(py, kw_value.borrow_input(), state) {
+                    let loc = || lookup_path.loc(self.loc_by_alias, &parameter.name);
+                    match state.with_path_location(loc, |state| {
+                        parameter.validator.validate(py, kw_value.borrow_input(), state)
+                    }) {
                         Ok(value) => output_kwargs.set_item(parameter.kwarg_key.as_ref().unwrap(), value)?,
                         Err(ValError::LineErrors(line_errors)) => {
                             errors.extend(
@@ -294,7 +328,11 @@ impl Validator for ArgumentsValidator {
             if len > self.positional_params_count {
                 if let Some(ref validator) = self.var_args_validator {
                     for (index, item) in args.iter().enumerate().skip(self.positional_params_count) {
-                        match validator.validate(py, item.borrow_input(), state) {
+                        if state.stop_collecting(false, &errors) {
+                            break;
+                        }
+                        match state.with_path_item(|| index, |state| validator.validate(py, item.borrow_input(), state))
+                        {
                             Ok(value) => output_args.push(value),
                             Err(ValError::LineErrors(line_errors)) => {
                                 errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
@@ -304,6 +342,9 @@ impl Validator for ArgumentsValidator {
                     }
                 } else {
                     for (index, item) in args.iter().enumerate().skip(self.positional_params_count) {
+                        if state.stop_collecting(false, &errors) {
+                            break;
+                        }
                         errors.push(ValLineError::new_with_loc(
                             ErrorTypeDefaults::UnexpectedPositionalArgument,
                             item,
@@ -314,12 +355,18 @@ impl Validator for ArgumentsValidator {
             }
         }
 
-        let remaining_kwargs = PyDict::new(py);
+        let remaining_kwargs = match self.var_kwargs_mode {
+            VarKwargsMode::UnpackedTypedDict => Some(PyDict::new(py)),
+            VarKwargsMode::Uniform => None,
+        };
 
         // if there are kwargs check any that haven't been processed yet
         if let Some(kwargs) = args.kwargs() {
             if kwargs.len() > used_kwargs.len() {
                 for result in kwargs.iter() {
+                    if state.stop_collecting(false, &errors) {
+                        break;
+                    }
                     let (raw_key, value) = result?;
                     let either_str = match raw_key
                         .borrow_input()
@@ -341,7 +388,10 @@ impl Validator for ArgumentsValidator {
                     if !used_kwargs.contains(either_str.as_cow()?.as_ref()) {
                         match self.var_kwargs_mode {
                             VarKwargsMode::Uniform => match &self.var_kwargs_validator {
-                                Some(validator) => match validator.validate(py, value.borrow_input(), state) {
+                                Some(validator) => match state.with_path_item(
+                                    || raw_key.clone(),
+                                    |state| validator.validate(py, value.borrow_input(), state),
+                                ) {
                                     Ok(value) => {
                                         output_kwargs
                                             .set_item(either_str.as_py_string(py, state.cache_str()), value)?;
@@ -365,7 +415,7 @@ impl Validator for ArgumentsValidator {
                             },
                             VarKwargsMode::UnpackedTypedDict => {
                                 // Save to the remaining kwargs, we will validate as a single dict:
-                                remaining_kwargs.set_item(
+                                remaining_kwargs.as_ref().unwrap().set_item(
                                     either_str.as_py_string(py, state.cache_str()),
                                     value.borrow_input().to_object(py)?,
                                 )?;
@@ -376,7 +426,7 @@ impl Validator for ArgumentsValidator {
             }
         }
 
-        if self.var_kwargs_mode == VarKwargsMode::UnpackedTypedDict {
+        if let Some(remaining_kwargs) = remaining_kwargs.filter(|_| !state.stop_collecting(false, &errors)) {
             // `var_kwargs_validator` is guaranteed to be `Some`:
             match self
                 .var_kwargs_validator
@@ -385,7 +435,7 @@ impl Validator for ArgumentsValidator {
                 .validate(py, remaining_kwargs.as_any(), state)
             {
                 Ok(value) => {
-                    output_kwargs.update(value.downcast_bound::<PyDict>(py).unwrap().as_mapping())?;
+                    update_unpacked_kwargs(&output_kwargs, value.bind(py))?;
                 }
                 Err(ValError::LineErrors(line_errors)) => {
                     errors.extend(line_errors);
@@ -405,3 +455,15 @@ impl Validator for ArgumentsValidator {
         Self::EXPECTED_TYPE
     }
 }
+
+/// Add the output of an unpacked typed dict's validator to the keyword arguments, function validators wrapping the
+/// typed dict could return anything.
+pub(super) fn update_unpacked_kwargs(output_kwargs: &Bound<'_, PyDict>, value: &Bound<'_, PyAny>) -> PyResult<()> {
+    match value.downcast::<PyDict>() {
+        Ok(value) => output_kwargs.update(value.as_mapping()),
+        Err(_) => Err(PyTypeError::new_err(format!(
+            "Unpacked typed dict validator should return a dict of keyword arguments, not {}",
+            value.get_type().name()?
+        ))),
+    }
+}
@@ -19,8 +19,10 @@ use crate::input::{
 use crate::lookup_key::LookupKeyCollection;
 use crate::tools::SchemaDict;
 
+use super::arguments::update_unpacked_kwargs;
+use super::profile;
 use super::validation_state::ValidationState;
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, LaxSequenceTypes, Validator};
 
 #[derive(Debug, PartialEq)]
 enum ParameterMode {
@@ -163,7 +165,7 @@ impl BuildValidator for ArgumentsV3Validator {
 
             let schema = arg.get_as_req(intern!(py, "schema"))?;
 
-            let validator = match build_validator(&schema, config, definitions) {
+            let validator = match profile::with_path_segment(&name, || build_validator(&schema, config, definitions)) {
                 Ok(v) => v,
                 Err(err) => return py_schema_err!("Parameter '{}':\n  {}", name, err),
             };
@@ -253,6 +255,9 @@ impl ArgumentsV3Validator {
             };
 
         for parameter in &self.parameters {
+            if state.stop_collecting(false, &errors) {
+                break;
+            }
             let lookup_key = parameter
                 .lookup_key_collection
                 .select(validate_by_alias, validate_by_name)?;
@@ -279,10 +284,16 @@ impl ArgumentsV3Validator {
                             Err(err) => return Err(err),
                         }
                     }
-                    ParameterMode::VarArgs => match dict_value.borrow_input().validate_tuple(false) {
+                    ParameterMode::VarArgs => match dict_value
+                        .borrow_input()
+                        .validate_tuple(false, &LaxSequenceTypes::default())
+                    {
                         Ok(tuple) => {
                             let mut i: i64 = 0;
                             tuple.unpack(state).try_for_each(|v| {
+                                if state.stop_collecting(false, &errors) {
+                                    return Ok(());
+                                }
                                 match parameter.validator.validate(py, v.unwrap().borrow_input(), state) {
                                     Ok(tuple_value) => {
                                         output_args.push(tuple_value);
@@ -331,6 +342,9 @@ impl ArgumentsV3Validator {
                         // We will validate that keys are strings, and values match the validator:
                         Some(value) => {
                             for (dict_key, dict_value) in value {
+                                if state.stop_collecting(false, &errors) {
+                                    break;
+                                }
                                 // Validate keys are strings:
                                 match dict_key.validate_str(true, false).map(ValidationMatch::into_inner) {
                                     Ok(_) => (),
@@ -370,7 +384,7 @@ impl ArgumentsV3Validator {
                     ParameterMode::VarKwargsUnpackedTypedDict => {
                         match parameter.validator.validate(py, dict_value.borrow_input(), state) {
                             Ok(value) => {
-                                output_kwargs.update(value.downcast_bound::<PyDict>(py).unwrap().as_mapping())?;
+                                update_unpacked_kwargs(&output_kwargs, value.bind(py))?;
                             }
                             Err(ValError::LineErrors(line_errors)) => {
                                 errors.extend(
@@ -421,7 +435,7 @@ impl ArgumentsV3Validator {
                     ParameterMode::VarKwargsUnpackedTypedDict => {
                         match parameter.validator.validate(py, PyDict::new(py).borrow_input(), state) {
                             Ok(value) => {
-                                output_kwargs.update(value.downcast_bound::<PyDict>(py).unwrap().as_mapping())?;
+                                update_unpacked_kwargs(&output_kwargs, value.bind(py))?;
                             }
                             Err(ValError::LineErrors(line_errors)) => {
                                 errors.extend(
@@ -440,13 +454,14 @@ impl ArgumentsV3Validator {
         }
 
         if let Some(used_keys) = used_keys {
-            struct ValidateExtra<'a> {
+            struct ValidateExtra<'a, 's, 'py> {
                 used_keys: AHashSet<&'a str>,
                 errors: &'a mut Vec<ValLineError>,
+                state: &'a ValidationState<'s, 'py>,
                 extra_behavior: ExtraBehavior,
             }
 
-            impl<'py, Key, Value> ConsumeIterator<ValResult<(Key, Value)>> for ValidateExtra<'_>
+            impl<'py, Key, Value> ConsumeIterator<ValResult<(Key, Value)>> for ValidateExtra<'_, '_, 'py>
             where
                 Key: BorrowInput<'py> + Clone + Into<LocItem>,
                 Value: BorrowInput<'py>,
@@ -454,6 +469,9 @@ impl ArgumentsV3Validator {
                 type Output = ValResult<()>;
                 fn consume_iterator(self, iterator: impl Iterator<Item = ValResult<(Key, Value)>>) -> Self::Output {
                     for item_result in iterator {
+                        if self.state.stop_collecting(false, self.errors) {
+                            break;
+                        }
                         let (raw_key, value) = item_result?;
                         let either_str = match raw_key
                             .borrow_input()
@@ -495,6 +513,7 @@ impl ArgumentsV3Validator {
             mapping.iterate(ValidateExtra {
                 used_keys,
                 errors: &mut errors,
+                state,
                 extra_behavior,
             })??;
         }
@@ -531,6 +550,9 @@ impl ArgumentsV3Validator {
 
         // go through non variadic parameters, getting the value from args or kwargs and validating it
         for (index, parameter) in self.parameters.iter().filter(|p| !p.is_variadic()).enumerate() {
+            if state.stop_collecting(false, &errors) {
+                break;
+            }
             let lookup_key = parameter
                 .lookup_key_collection
                 .select(validate_by_alias, validate_by_name)?;
@@ -637,6 +659,9 @@ impl ArgumentsV3Validator {
             if len > self.positional_params_count {
                 if let Some(var_args_param) = self.parameters.iter().find(|p| p.mode == ParameterMode::VarArgs) {
                     for (index, item) in args.iter().enumerate().skip(self.positional_params_count) {
+                        if state.stop_collecting(false, &errors) {
+                            break;
+                        }
                         match var_args_param.validator.validate(py, item.borrow_input(), state) {
                             Ok(value) => output_args.push(value),
                             Err(ValError::LineErrors(line_errors)) => {
@@ -647,6 +672,9 @@ impl ArgumentsV3Validator {
                     }
                 } else {
                     for (index, item) in args.iter().enumerate().skip(self.positional_params_count) {
+                        if state.stop_collecting(false, &errors) {
+                            break;
+                        }
                         errors.push(ValLineError::new_with_loc(
                             ErrorTypeDefaults::UnexpectedPositionalArgument,
                             item,
@@ -663,6 +691,9 @@ impl ArgumentsV3Validator {
         if let Some(kwargs) = args_kwargs.kwargs() {
             if kwargs.len() > used_kwargs.len() {
                 for result in kwargs.iter() {
+                    if state.stop_collecting(false, &errors) {
+                        break;
+                    }
                     let (raw_key, value) = result?;
                     let either_str = match raw_key
                         .borrow_input()
@@ -736,13 +767,15 @@ impl ArgumentsV3Validator {
             .iter()
             .find(|p| p.mode == ParameterMode::VarKwargsUnpackedTypedDict);
 
-        if let Some(var_kwargs_parameter) = maybe_var_kwargs_parameter {
+        if let Some(var_kwargs_parameter) =
+            maybe_var_kwargs_parameter.filter(|_| !state.stop_collecting(false, &errors))
+        {
             match var_kwargs_parameter
                 .validator
                 .validate(py, remaining_kwargs.as_any(), state)
             {
                 Ok(value) => {
-                    output_kwargs.update(value.downcast_bound::<PyDict>(py).unwrap().as_mapping())?;
+                    update_unpacked_kwargs(&output_kwargs, value.bind(py))?;
                 }
                 Err(ValError::LineErrors(line_errors)) => {
                     errors.extend(line_errors);
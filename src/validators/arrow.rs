@@ -0,0 +1,555 @@
+//! Export of validated results as an Arrow record batch, with the
+//! [C data interface](https://arrow.apache.org/docs/format/CDataInterface.html) and the
+//! [PyCapsule interface](https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html), so libraries
+//! like pyarrow and polars can use the columns without converting each result to a row themselves.
+
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::ptr;
+use std::sync::Arc;
+
+use pyo3::exceptions::{PyOverflowError, PyTypeError};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyBytes, PyCapsule, PyDate, PyDateTime, PyDict, PyFloat, PyInt, PyList, PyString, PyTuple};
+use pyo3::{PyTraverseError, PyVisit};
+
+use crate::input::{pydate_as_date, pydatetime_as_datetime};
+
+/// The `ArrowSchema` struct of the C data interface.
+#[repr(C)]
+struct ArrowSchema {
+    format: *const c_char,
+    name: *const c_char,
+    metadata: *const c_char,
+    flags: i64,
+    n_children: i64,
+    children: *mut *mut ArrowSchema,
+    dictionary: *mut ArrowSchema,
+    release: Option<unsafe extern "C" fn(*mut ArrowSchema)>,
+    private_data: *mut c_void,
+}
+
+/// The `ArrowArray` struct of the C data interface.
+#[repr(C)]
+struct ArrowArray {
+    length: i64,
+    null_count: i64,
+    offset: i64,
+    n_buffers: i64,
+    n_children: i64,
+    buffers: *mut *const c_void,
+    children: *mut *mut ArrowArray,
+    dictionary: *mut ArrowArray,
+    release: Option<unsafe extern "C" fn(*mut ArrowArray)>,
+    private_data: *mut c_void,
+}
+
+// the structs are moved into capsules, which may be dropped on any thread, and what they point to is only freed by
+// their `release` callbacks
+unsafe impl Send for ArrowSchema {}
+unsafe impl Send for ArrowArray {}
+
+const ARROW_FLAG_NULLABLE: i64 = 2;
+
+struct SchemaPrivate {
+    format: CString,
+    name: CString,
+    children: Vec<*mut ArrowSchema>,
+}
+
+struct ArrayPrivate {
+    buffers: Vec<*const c_void>,
+    children: Vec<*mut ArrowArray>,
+    // the columns the buffers point into, kept alive until the consumer releases the array
+    _columns: Arc<Vec<Column>>,
+}
+
+unsafe extern "C" fn release_schema(schema: *mut ArrowSchema) {
+    let schema = unsafe { &mut *schema };
+    let private = unsafe { Box::from_raw(schema.private_data.cast::<SchemaPrivate>()) };
+    for child in private.children {
+        // children moved by the consumer have had their `release` cleared
+        if let Some(release) = unsafe { (*child).release } {
+            unsafe { release(child) };
+        }
+        drop(unsafe { Box::from_raw(child) });
+    }
+    schema.release = None;
+}
+
+unsafe extern "C" fn release_array(array: *mut ArrowArray) {
+    let array = unsafe { &mut *array };
+    let private = unsafe { Box::from_raw(array.private_data.cast::<ArrayPrivate>()) };
+    for child in private.children {
+        if let Some(release) = unsafe { (*child).release } {
+            unsafe { release(child) };
+        }
+        drop(unsafe { Box::from_raw(child) });
+    }
+    array.release = None;
+}
+
+impl ArrowSchema {
+    fn new(format: &str, name: &str, flags: i64, children: Vec<ArrowSchema>) -> PyResult<Self> {
+        let mut private = Box::new(SchemaPrivate {
+            format: CString::new(format)?,
+            name: CString::new(name)?,
+            children: children
+                .into_iter()
+                .map(|child| Box::into_raw(Box::new(child)))
+                .collect(),
+        });
+        Ok(Self {
+            format: private.format.as_ptr(),
+            name: private.name.as_ptr(),
+            metadata: ptr::null(),
+            flags,
+            n_children: private.children.len() as i64,
+            children: private.children.as_mut_ptr(),
+            dictionary: ptr::null_mut(),
+            release: Some(release_schema),
+            private_data: Box::into_raw(private).cast(),
+        })
+    }
+}
+
+impl ArrowArray {
+    fn new(
+        length: usize,
+        null_count: usize,
+        buffers: Vec<*const c_void>,
+        children: Vec<ArrowArray>,
+        columns: &Arc<Vec<Column>>,
+    ) -> Self {
+        let mut private = Box::new(ArrayPrivate {
+            buffers,
+            children: children
+                .into_iter()
+                .map(|child| Box::into_raw(Box::new(child)))
+                .collect(),
+            _columns: columns.clone(),
+        });
+        Self {
+            length: length as i64,
+            null_count: null_count as i64,
+            offset: 0,
+            n_buffers: private.buffers.len() as i64,
+            n_children: private.children.len() as i64,
+            buffers: private.buffers.as_mut_ptr(),
+            children: private.children.as_mut_ptr(),
+            dictionary: ptr::null_mut(),
+            release: Some(release_array),
+            private_data: Box::into_raw(private).cast(),
+        }
+    }
+}
+
+/// Wrap `value` in a capsule named `name`, releasing it when the capsule is dropped unless a consumer moved it out.
+fn capsule<'py, T: Send + 'static>(
+    py: Python<'py>,
+    value: T,
+    name: &CStr,
+    release: fn(&mut T),
+) -> PyResult<Bound<'py, PyCapsule>> {
+    PyCapsule::new_with_destructor(py, value, Some(name.to_owned()), move |mut value, _| {
+        release(&mut value);
+    })
+}
+
+/// The values of a column, with a default value for each null row.
+#[derive(Debug)]
+enum Values {
+    // only `None` so far, so the type isn't known yet
+    Null,
+    Boolean(Vec<u8>),
+    Int64(Vec<i64>),
+    Float64(Vec<f64>),
+    // `offsets` has the start of each value followed by the end of the last one
+    Utf8 { offsets: Vec<i64>, data: Vec<u8> },
+    Binary { offsets: Vec<i64>, data: Vec<u8> },
+    // days since the unix epoch
+    Date32(Vec<i32>),
+    // microseconds since the unix epoch, `utc` for aware datetimes, which are converted to UTC
+    Timestamp { values: Vec<i64>, utc: bool },
+}
+
+impl Values {
+    /// Empty values of the type of `value`.
+    fn for_value(value: &Bound<'_, PyAny>, name: &str) -> PyResult<Self> {
+        if value.is_instance_of::<PyBool>() {
+            Ok(Self::Boolean(Vec::new()))
+        } else if value.is_instance_of::<PyInt>() {
+            Ok(Self::Int64(Vec::new()))
+        } else if value.is_instance_of::<PyFloat>() {
+            Ok(Self::Float64(Vec::new()))
+        } else if value.is_instance_of::<PyString>() {
+            Ok(Self::Utf8 {
+                offsets: vec![0],
+                data: Vec::new(),
+            })
+        } else if value.is_instance_of::<PyBytes>() {
+            Ok(Self::Binary {
+                offsets: vec![0],
+                data: Vec::new(),
+            })
+        } else if value.is_instance_of::<PyDateTime>() {
+            let utc = pydatetime_as_datetime(value)?.time.tz_offset.is_some();
+            Ok(Self::Timestamp {
+                values: Vec::new(),
+                utc,
+            })
+        } else if value.is_instance_of::<PyDate>() {
+            Ok(Self::Date32(Vec::new()))
+        } else {
+            Err(PyTypeError::new_err(format!(
+                "Field `{name}` has a value of type `{}`, which can't be exported to Arrow",
+                value.get_type().qualname()?
+            )))
+        }
+    }
+
+    fn format(&self) -> &'static str {
+        match self {
+            Self::Null => "n",
+            Self::Boolean(_) => "b",
+            Self::Int64(_) => "l",
+            Self::Float64(_) => "g",
+            Self::Utf8 { .. } => "U",
+            Self::Binary { .. } => "Z",
+            Self::Date32(_) => "tdD",
+            Self::Timestamp { utc: false, .. } => "tsu:",
+            Self::Timestamp { utc: true, .. } => "tsu:UTC",
+        }
+    }
+
+    /// Append `value` as the value at `index`, returning `false` if it isn't of the type of the column.
+    fn push(&mut self, index: usize, value: &Bound<'_, PyAny>, name: &str) -> PyResult<bool> {
+        match self {
+            Self::Null => unreachable!("the type of a column is set by its first value"),
+            Self::Boolean(bits) => {
+                let Ok(value) = value.downcast::<PyBool>() else {
+                    return Ok(false);
+                };
+                set_bit(bits, index, value.is_true());
+            }
+            Self::Int64(values) => {
+                if value.is_instance_of::<PyBool>() || !value.is_instance_of::<PyInt>() {
+                    return Ok(false);
+                }
+                let Ok(value) = value.extract() else {
+                    return Err(PyOverflowError::new_err(format!(
+                        "Field `{name}` has an int which doesn't fit in an int64, it can't be exported to Arrow"
+                    )));
+                };
+                values.push(value);
+            }
+            Self::Float64(values) => {
+                let Ok(value) = value.downcast::<PyFloat>() else {
+                    return Ok(false);
+                };
+                values.push(value.value());
+            }
+            Self::Utf8 { offsets, data } => {
+                let Ok(value) = value.downcast::<PyString>() else {
+                    return Ok(false);
+                };
+                data.extend_from_slice(value.to_str()?.as_bytes());
+                offsets.push(data.len() as i64);
+            }
+            Self::Binary { offsets, data } => {
+                let Ok(value) = value.downcast::<PyBytes>() else {
+                    return Ok(false);
+                };
+                data.extend_from_slice(value.as_bytes());
+                offsets.push(data.len() as i64);
+            }
+            Self::Date32(values) => {
+                if value.is_instance_of::<PyDateTime>() || !value.is_instance_of::<PyDate>() {
+                    return Ok(false);
+                }
+                values.push(pydate_as_date(value)?.timestamp().div_euclid(86_400) as i32);
+            }
+            Self::Timestamp { values, utc } => {
+                if !value.is_instance_of::<PyDateTime>() {
+                    return Ok(false);
+                }
+                let datetime = pydatetime_as_datetime(value)?;
+                if datetime.time.tz_offset.is_some() != *utc {
+                    return Err(PyTypeError::new_err(format!(
+                        "Field `{name}` mixes naive and aware datetimes, which can't be exported to Arrow"
+                    )));
+                }
+                values.push(datetime.timestamp_tz() * 1_000_000 + i64::from(datetime.time.microsecond));
+            }
+        }
+        Ok(true)
+    }
+
+    /// Append the default value of the type at `index`, for a null row.
+    fn push_default(&mut self, index: usize) {
+        match self {
+            Self::Null => {}
+            Self::Boolean(bits) => set_bit(bits, index, false),
+            Self::Int64(values) | Self::Timestamp { values, .. } => values.push(0),
+            Self::Float64(values) => values.push(0.0),
+            Self::Utf8 { offsets, data } | Self::Binary { offsets, data } => offsets.push(data.len() as i64),
+            Self::Date32(values) => values.push(0),
+        }
+    }
+
+    fn buffers(&self) -> Vec<*const c_void> {
+        match self {
+            Self::Null => Vec::new(),
+            Self::Boolean(bits) => vec![bits.as_ptr().cast()],
+            Self::Int64(values) | Self::Timestamp { values, .. } => vec![values.as_ptr().cast()],
+            Self::Float64(values) => vec![values.as_ptr().cast()],
+            Self::Utf8 { offsets, data } | Self::Binary { offsets, data } => {
+                vec![offsets.as_ptr().cast(), data.as_ptr().cast()]
+            }
+            Self::Date32(values) => vec![values.as_ptr().cast()],
+        }
+    }
+}
+
+/// Set the bit at `index` of a bitmap which has all the bits before `index`, in Arrow's least significant bit
+/// order.
+fn set_bit(bits: &mut Vec<u8>, index: usize, value: bool) {
+    if index % 8 == 0 {
+        bits.push(0);
+    }
+    if value {
+        bits[index / 8] |= 1 << (index % 8);
+    }
+}
+
+#[derive(Debug)]
+struct Column {
+    name: String,
+    values: Values,
+    // a bit per row, set for rows which aren't null
+    validity: Vec<u8>,
+    null_count: usize,
+    len: usize,
+}
+
+impl Column {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            values: Values::Null,
+            validity: Vec::new(),
+            null_count: 0,
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, value: Option<&Bound<'_, PyAny>>) -> PyResult<()> {
+        match value.filter(|value| !value.is_none()) {
+            None => {
+                self.values.push_default(self.len);
+                set_bit(&mut self.validity, self.len, false);
+                self.null_count += 1;
+            }
+            Some(value) => {
+                if matches!(self.values, Values::Null) {
+                    self.values = Values::for_value(value, &self.name)?;
+                    for index in 0..self.len {
+                        self.values.push_default(index);
+                    }
+                }
+                if !self.values.push(self.len, value, &self.name)? {
+                    return Err(PyTypeError::new_err(format!(
+                        "Field `{}` has values of different types, `{}` isn't `{}`, which can't be exported to Arrow",
+                        self.name,
+                        value.get_type().qualname()?,
+                        self.values.format()
+                    )));
+                }
+                set_bit(&mut self.validity, self.len, true);
+            }
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    fn schema(&self) -> PyResult<ArrowSchema> {
+        ArrowSchema::new(self.values.format(), &self.name, ARROW_FLAG_NULLABLE, Vec::new())
+    }
+
+    fn array(&self, columns: &Arc<Vec<Column>>) -> ArrowArray {
+        if matches!(self.values, Values::Null) {
+            // the null type has no buffers, not even a validity bitmap
+            return ArrowArray::new(self.len, self.null_count, Vec::new(), Vec::new(), columns);
+        }
+        let validity = if self.null_count == 0 {
+            ptr::null()
+        } else {
+            self.validity.as_ptr().cast()
+        };
+        let mut buffers = vec![validity];
+        buffers.extend(self.values.buffers());
+        ArrowArray::new(self.len, self.null_count, buffers, Vec::new(), columns)
+    }
+}
+
+/// Builds the columns of a record batch from validated results, one row at a time.
+///
+/// The columns are the fields of the first result, later results may lack some of them, which are null.
+#[derive(Debug, Default)]
+pub(super) struct BatchBuilder {
+    columns: Vec<Column>,
+    num_rows: usize,
+    errors: Vec<(usize, Py<PyAny>)>,
+}
+
+impl BatchBuilder {
+    /// Add the result of the input at `index`, a row if it's valid, with an error otherwise.
+    pub fn push_result(&mut self, py: Python<'_>, index: usize, result: Result<Py<PyAny>, Py<PyAny>>) -> PyResult<()> {
+        match result {
+            Ok(value) => self.push_row(value.bind(py)),
+            Err(error) => {
+                self.errors.push((index, error));
+                Ok(())
+            }
+        }
+    }
+
+    fn push_row(&mut self, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        let fields = fields_of(value)?;
+        if self.num_rows == 0 {
+            for key in fields.keys() {
+                self.columns
+                    .push(Column::new(key.downcast_into::<PyString>()?.to_str()?.to_string()));
+            }
+        }
+        let mut found = 0;
+        for column in &mut self.columns {
+            let value = fields.get_item(&column.name)?;
+            found += usize::from(value.is_some());
+            column.push(value.as_ref())?;
+        }
+        if found < fields.len() {
+            return Err(PyTypeError::new_err(
+                "Results have different fields, only the fields of the first result can be exported to Arrow",
+            ));
+        }
+        self.num_rows += 1;
+        Ok(())
+    }
+
+    pub fn finish(self) -> ArrowBatch {
+        ArrowBatch {
+            columns: Arc::new(self.columns),
+            num_rows: self.num_rows,
+            errors: self.errors,
+        }
+    }
+}
+
+/// The fields of a validated result, which is a dict, e.g. from a typed dict, or an object with a `__dict__`, e.g.
+/// a model or a dataclass.
+fn fields_of<'py>(value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyDict>> {
+    if let Ok(dict) = value.downcast::<PyDict>() {
+        return Ok(dict.clone());
+    }
+    match value.getattr(intern!(value.py(), "__dict__")) {
+        Ok(dict) => Ok(dict.downcast_into::<PyDict>()?),
+        Err(_) => Err(PyTypeError::new_err(format!(
+            "Only dicts, models and dataclasses can be exported to Arrow, not `{}`",
+            value.get_type().qualname()?
+        ))),
+    }
+}
+
+/// The results of `SchemaValidator.validate_many_arrow`, the valid results as the columns of an Arrow record batch
+/// and the errors of the inputs which failed validation.
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+#[derive(Debug)]
+pub struct ArrowBatch {
+    columns: Arc<Vec<Column>>,
+    num_rows: usize,
+    errors: Vec<(usize, Py<PyAny>)>,
+}
+
+impl ArrowBatch {
+    fn schema(&self) -> PyResult<ArrowSchema> {
+        let children = self.columns.iter().map(Column::schema).collect::<PyResult<_>>()?;
+        // a record batch is exported as a struct array with a child for each column
+        ArrowSchema::new("+s", "", 0, children)
+    }
+}
+
+#[pymethods]
+impl ArrowBatch {
+    #[getter]
+    fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    #[getter]
+    fn column_names(&self) -> Vec<&str> {
+        self.columns.iter().map(|column| column.name.as_str()).collect()
+    }
+
+    /// The index of each input which failed validation, with its `ValidationError`.
+    #[getter]
+    fn errors<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        let errors = self
+            .errors
+            .iter()
+            .map(|(index, error)| PyTuple::new(py, [index.into_pyobject(py)?.into_any(), error.bind(py).clone()]))
+            .collect::<PyResult<Vec<_>>>()?;
+        PyList::new(py, errors)
+    }
+
+    fn __arrow_c_schema__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyCapsule>> {
+        capsule(py, self.schema()?, c"arrow_schema", release_capsule_schema)
+    }
+
+    /// The schema and the array of the record batch, `requested_schema` is ignored, which the protocol allows.
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_array__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<&Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyTuple>> {
+        let _ = requested_schema;
+        let children = self.columns.iter().map(|column| column.array(&self.columns)).collect();
+        let array = ArrowArray::new(self.num_rows, 0, vec![ptr::null()], children, &self.columns);
+        let schema = capsule(py, self.schema()?, c"arrow_schema", release_capsule_schema)?;
+        let array = capsule(py, array, c"arrow_array", release_capsule_array)?;
+        PyTuple::new(py, [schema, array])
+    }
+
+    fn __len__(&self) -> usize {
+        self.num_rows
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ArrowBatch(num_rows={}, num_columns={}, errors={})",
+            self.num_rows,
+            self.columns.len(),
+            self.errors.len()
+        )
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for (_, error) in &self.errors {
+            visit.call(error)?;
+        }
+        Ok(())
+    }
+}
+
+fn release_capsule_schema(schema: &mut ArrowSchema) {
+    if let Some(release) = schema.release {
+        unsafe { release(schema) };
+    }
+}
+
+fn release_capsule_array(array: &mut ArrowArray) {
+    if let Some(release) = array.release {
+        unsafe { release(array) };
+    }
+}
@@ -7,7 +7,7 @@ use crate::build_tools::{is_strict, LazyLock};
 use crate::errors::ValResult;
 use crate::input::Input;
 
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
 pub struct BoolValidator {
@@ -52,6 +52,10 @@ impl Validator for BoolValidator {
             .and_then(|val_match| Ok(val_match.unpack(state).into_py_any(py)?))
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        generator.bool().into_bound_py_any(py)
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyBytes, PyDict};
 use pyo3::IntoPyObjectExt;
 
 use crate::build_tools::is_strict;
@@ -12,7 +12,7 @@ use crate::input::Input;
 use crate::tools::SchemaDict;
 
 use super::config::ValBytesMode;
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
 pub struct BytesValidator {
@@ -57,6 +57,10 @@ impl Validator for BytesValidator {
             .and_then(|m| Ok(m.unpack(state).into_py_any(py)?))
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        Ok(PyBytes::new(py, generator.word(None, None).as_bytes()).into_any())
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -109,6 +113,11 @@ impl Validator for BytesConstrainedValidator {
         Ok(either_bytes.into_py_any(py)?)
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        let word = generator.word(self.min_length, self.max_length);
+        Ok(PyBytes::new(py, word.as_bytes()).into_any())
+    }
+
     fn get_name(&self) -> &'static str {
         "constrained-bytes"
     }
@@ -4,7 +4,9 @@ use pyo3::exceptions::PyTypeError;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyString;
-use pyo3::types::{PyDict, PyTuple};
+use pyo3::types::{PyDict, PyTuple, PyType};
+#[cfg(not(any(PyPy, GraalPy, Py_LIMITED_API)))]
+use pyo3::PyTypeInfo;
 
 use crate::errors::ValResult;
 use crate::input::Input;
@@ -19,6 +21,7 @@ pub struct CallValidator {
     function: Py<PyAny>,
     arguments_validator: Arc<CombinedValidator>,
     return_validator: Option<Arc<CombinedValidator>>,
+    named_tuple: Option<NamedTupleFields>,
     name: String,
 }
 
@@ -61,6 +64,7 @@ impl BuildValidator for CallValidator {
         let name = format!("{}[{function_name}]", Self::EXPECTED_TYPE);
 
         Ok(CombinedValidator::FunctionCall(Self {
+            named_tuple: NamedTupleFields::from_function(&function)?,
             function: function.unbind(),
             arguments_validator,
             return_validator,
@@ -73,7 +77,8 @@ impl BuildValidator for CallValidator {
 impl_py_gc_traverse!(CallValidator {
     function,
     arguments_validator,
-    return_validator
+    return_validator,
+    named_tuple
 });
 
 impl Validator for CallValidator {
@@ -86,9 +91,9 @@ impl Validator for CallValidator {
         let args = self.arguments_validator.validate(py, input, state)?.into_bound(py);
 
         let return_value = if let Ok((args, kwargs)) = args.extract::<(Bound<PyTuple>, Bound<PyDict>)>() {
-            self.function.call(py, args, Some(&kwargs))?
+            self.call(py, &args, &kwargs)?
         } else if let Ok(kwargs) = args.downcast::<PyDict>() {
-            self.function.call(py, (), Some(kwargs))?
+            self.call(py, &PyTuple::empty(py), kwargs)?
         } else {
             let msg = "Arguments validator should return a tuple of (args, kwargs) or a dict of kwargs";
             return Err(PyTypeError::new_err(msg).into());
@@ -107,3 +112,121 @@ impl Validator for CallValidator {
         &self.name
     }
 }
+
+impl CallValidator {
+    fn call<'py>(
+        &self,
+        py: Python<'py>,
+        args: &Bound<'py, PyTuple>,
+        kwargs: &Bound<'py, PyDict>,
+    ) -> PyResult<Py<PyAny>> {
+        if let Some(named_tuple) = &self.named_tuple {
+            if let Some(items) = named_tuple.items(args, kwargs)? {
+                return new_named_tuple(self.function.bind(py).downcast()?, items).map(Bound::unbind);
+            }
+        }
+        self.function.call(py, args, Some(kwargs))
+    }
+}
+
+/// The fields of a `collections.namedtuple` class, so it's constructed from its items without calling its
+/// `__new__`, which parses the arguments in Python.
+#[derive(Debug)]
+struct NamedTupleFields {
+    fields: Vec<Py<PyString>>,
+    defaults: Vec<Option<Py<PyAny>>>,
+}
+
+impl NamedTupleFields {
+    /// The fields of `function` if it's a namedtuple class, or a subclass of one which doesn't override
+    /// `__new__` or `__init__`.
+    fn from_function(function: &Bound<'_, PyAny>) -> PyResult<Option<Self>> {
+        let py = function.py();
+        let Ok(cls) = function.downcast::<PyType>() else {
+            return Ok(None);
+        };
+        if !cls.is_subclass_of::<PyTuple>()? {
+            return Ok(None);
+        }
+        // the class defining `__new__` must be the one generated by `namedtuple`
+        let mut generated = None;
+        for base in cls.mro() {
+            let base_dict = base.getattr(intern!(py, "__dict__"))?;
+            if base_dict.contains(intern!(py, "__new__"))? {
+                generated = Some(base_dict);
+                break;
+            }
+        }
+        let Some(generated) = generated else {
+            return Ok(None);
+        };
+        if !generated.contains(intern!(py, "_fields"))? || !generated.contains(intern!(py, "_make"))? {
+            return Ok(None);
+        }
+        let tuple_init = py.get_type::<PyTuple>().getattr(intern!(py, "__init__"))?;
+        if !cls.getattr(intern!(py, "__init__"))?.is(&tuple_init) {
+            return Ok(None);
+        }
+        let Ok(fields) = cls.getattr(intern!(py, "_fields"))?.extract::<Vec<Py<PyString>>>() else {
+            return Ok(None);
+        };
+        let field_defaults = cls.getattr(intern!(py, "_field_defaults"))?;
+        let field_defaults = field_defaults.downcast::<PyDict>()?;
+        let defaults = fields
+            .iter()
+            .map(|field| Ok(field_defaults.get_item(field)?.map(Bound::unbind)))
+            .collect::<PyResult<_>>()?;
+        Ok(Some(Self { fields, defaults }))
+    }
+
+    /// The items of the namedtuple, `None` if the arguments don't match its fields, so calling it raises the error.
+    fn items<'py>(
+        &self,
+        args: &Bound<'py, PyTuple>,
+        kwargs: &Bound<'py, PyDict>,
+    ) -> PyResult<Option<Bound<'py, PyTuple>>> {
+        let py = args.py();
+        if args.len() > self.fields.len() {
+            return Ok(None);
+        }
+        let mut items = Vec::with_capacity(self.fields.len());
+        let mut used_kwargs = 0;
+        for (index, (field, default)) in self.fields.iter().zip(&self.defaults).enumerate() {
+            let kwarg = kwargs.get_item(field)?;
+            used_kwargs += usize::from(kwarg.is_some());
+            let item = match (args.get_item(index).ok(), kwarg) {
+                (Some(_), Some(_)) => return Ok(None),
+                (Some(arg), None) => arg,
+                (None, Some(kwarg)) => kwarg,
+                (None, None) => match default {
+                    Some(default) => default.bind(py).clone(),
+                    None => return Ok(None),
+                },
+            };
+            items.push(item);
+        }
+        if used_kwargs != kwargs.len() {
+            return Ok(None);
+        }
+        PyTuple::new(py, items).map(Some)
+    }
+}
+
+impl_py_gc_traverse!(NamedTupleFields { fields, defaults });
+
+/// Create an instance of the namedtuple class `cls` from its items, like `tuple.__new__(cls, items)`.
+#[cfg(not(any(PyPy, GraalPy, Py_LIMITED_API)))]
+fn new_named_tuple<'py>(cls: &Bound<'py, PyType>, items: Bound<'py, PyTuple>) -> PyResult<Bound<'py, PyAny>> {
+    let py = cls.py();
+    let args = PyTuple::new(py, [items])?;
+    let tp_new = unsafe { (*PyTuple::type_object(py).as_type_ptr()).tp_new };
+    let tp_new = tp_new.expect("tuple defines tp_new");
+    unsafe { Bound::from_owned_ptr_or_err(py, tp_new(cls.as_type_ptr(), args.as_ptr(), std::ptr::null_mut())) }
+}
+
+#[cfg(any(PyPy, GraalPy, Py_LIMITED_API))]
+fn new_named_tuple<'py>(cls: &Bound<'py, PyType>, items: Bound<'py, PyTuple>) -> PyResult<Bound<'py, PyAny>> {
+    let py = cls.py();
+    py.get_type::<PyTuple>()
+        .call_method1(intern!(py, "__new__"), (cls, items))
+}
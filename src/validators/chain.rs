@@ -5,17 +5,18 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 
 use crate::build_tools::py_schema_err;
+use crate::definitions::CompositeName;
 use crate::errors::ValResult;
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
 use super::validation_state::ValidationState;
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, Validator};
 
 #[derive(Debug)]
 pub struct ChainValidator {
     steps: Vec<Arc<CombinedValidator>>,
-    name: String,
+    name: CompositeName,
 }
 
 impl BuildValidator for ChainValidator {
@@ -41,15 +42,11 @@ impl BuildValidator for ChainValidator {
                 let step = steps.into_iter().next().unwrap();
                 Ok(step)
             }
-            _ => {
-                let descr = steps.iter().map(|v| v.get_name()).collect::<Vec<_>>().join(",");
-
-                Ok(CombinedValidator::Chain(Self {
-                    steps,
-                    name: format!("{}[{descr}]", Self::EXPECTED_TYPE),
-                })
-                .into())
-            }
+            _ => Ok(CombinedValidator::Chain(Self {
+                steps,
+                name: CompositeName::new(),
+            })
+            .into()),
         }
     }
 }
@@ -85,7 +82,14 @@ impl Validator for ChainValidator {
         steps_iter.try_fold(value, |v, step| step.validate(py, v.bind(py), state))
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        self.steps[0].example(py, generator)
+    }
+
     fn get_name(&self) -> &str {
-        &self.name
+        self.name.get_or_init(|| {
+            let descr = self.steps.iter().map(|v| v.get_name()).collect::<Vec<_>>().join(",");
+            format!("{}[{descr}]", Self::EXPECTED_TYPE)
+        })
     }
 }
@@ -4,13 +4,14 @@ use std::str::FromStr;
 use crate::build_tools::py_schema_err;
 use crate::errors::ErrorType;
 use crate::input::EitherBytes;
+use crate::py_gc::PyGcTraverse;
 use crate::serializers::BytesMode;
 use crate::tools::SchemaDict;
 use base64::engine::general_purpose::GeneralPurpose;
 use base64::engine::{DecodePaddingMode, GeneralPurposeConfig};
 use base64::{alphabet, DecodeError, Engine};
-use pyo3::types::{PyDict, PyString};
-use pyo3::{intern, prelude::*};
+use pyo3::types::{PyDict, PyList, PyString, PyTuple, PyType};
+use pyo3::{intern, prelude::*, PyTraverseError, PyVisit};
 use speedate::TimestampUnit;
 
 const URL_SAFE_OPTIONAL_PADDING: GeneralPurpose = GeneralPurpose::new(
@@ -71,6 +72,39 @@ impl From<TemporalUnitMode> for TimestampUnit {
     }
 }
 
+/// Types accepted as sequences in lax mode from the `lax_sequence_types` config, even if they'd otherwise be
+/// rejected, e.g. result proxies which also implement `Mapping`.
+#[derive(Default, Debug, Clone)]
+pub struct LaxSequenceTypes(Option<Py<PyTuple>>);
+
+impl LaxSequenceTypes {
+    pub fn from_config(config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let Some(config_dict) = config else {
+            return Ok(Self::default());
+        };
+        let Some(types) = config_dict.get_as::<Bound<'_, PyList>>(intern!(config_dict.py(), "lax_sequence_types"))?
+        else {
+            return Ok(Self::default());
+        };
+        if !types.iter().all(|item| item.is_instance_of::<PyType>()) {
+            return py_schema_err!("`lax_sequence_types` must be a list of types");
+        }
+        Ok(Self((!types.is_empty()).then(|| types.to_tuple().unbind())))
+    }
+
+    pub fn contains(&self, obj: &Bound<'_, PyAny>) -> bool {
+        self.0
+            .as_ref()
+            .is_some_and(|types| obj.is_instance(types.bind(obj.py())).unwrap_or(false))
+    }
+}
+
+impl PyGcTraverse for LaxSequenceTypes {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.0.py_gc_traverse(visit)
+    }
+}
+
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ValBytesMode {
     pub ser: BytesMode,
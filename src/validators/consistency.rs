@@ -0,0 +1,323 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyFloat, PyInt, PyList, PyString};
+use pyo3::IntoPyObjectExt;
+
+use crate::build_tools::py_schema_err;
+use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
+use crate::lookup_key::LookupPath;
+use crate::tools::SchemaDict;
+
+/// A check that a field equals a function of other fields, from the `consistency_checks` of a model-fields schema,
+/// run once all the fields are valid.
+#[derive(Debug)]
+pub(super) struct ConsistencyCheck {
+    field: Py<PyString>,
+    kind: CheckKind,
+    // e.g. `sum of items.*.price`, for errors
+    check: String,
+}
+
+#[derive(Debug)]
+enum CheckKind {
+    Sum { paths: Vec<LookupPath>, tolerance: f64 },
+    Checksum { path: LookupPath, algorithm: Checksum },
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Checksum {
+    Crc32,
+    Adler32,
+}
+
+impl Checksum {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Crc32 => "crc32",
+            Self::Adler32 => "adler32",
+        }
+    }
+
+    fn compute(self, data: &[u8]) -> u32 {
+        match self {
+            Self::Crc32 => {
+                let mut crc = !0u32;
+                for byte in data {
+                    crc ^= u32::from(*byte);
+                    for _ in 0..8 {
+                        // the reversed IEEE polynomial, as used by zlib
+                        crc = (crc >> 1) ^ (0xEDB8_8320 & (crc & 1).wrapping_neg());
+                    }
+                }
+                !crc
+            }
+            Self::Adler32 => {
+                let (mut a, mut b) = (1u32, 0u32);
+                for byte in data {
+                    a = (a + u32::from(*byte)) % 65521;
+                    b = (b + a) % 65521;
+                }
+                (b << 16) | a
+            }
+        }
+    }
+}
+
+impl ConsistencyCheck {
+    pub fn build_all(schema: &Bound<'_, PyDict>, field_names: &[&str]) -> PyResult<Vec<Self>> {
+        let py = schema.py();
+        let Some(checks) = schema.get_as::<Bound<'_, PyList>>(intern!(py, "consistency_checks"))? else {
+            return Ok(Vec::new());
+        };
+        let check_field = |name: &str| -> PyResult<()> {
+            if field_names.contains(&name) {
+                Ok(())
+            } else {
+                py_schema_err!("Consistency check field `{}` is not a field of the model", name)
+            }
+        };
+        checks
+            .iter()
+            .map(|check| {
+                let check = check.downcast::<PyDict>()?;
+                let field: Bound<'_, PyString> = check.get_as_req(intern!(py, "field"))?;
+                check_field(field.to_str()?)?;
+                let path = |path: &Bound<'_, PyAny>| -> PyResult<(LookupPath, String)> {
+                    let lookup_path = LookupPath::from_list(path)?;
+                    check_field(lookup_path.first_key())?;
+                    let items = path
+                        .downcast::<PyList>()?
+                        .iter()
+                        .map(|item| Ok(item.str()?.to_string()))
+                        .collect::<PyResult<Vec<_>>>()?;
+                    Ok((lookup_path, items.join(".")))
+                };
+                let type_: String = check.get_as_req(intern!(py, "type"))?;
+                let (kind, check) = match type_.as_str() {
+                    "sum" => {
+                        let of: Bound<'_, PyList> = check.get_as_req(intern!(py, "of"))?;
+                        let (paths, descriptions): (Vec<_>, Vec<_>) = of
+                            .iter()
+                            .map(|item| path(&item))
+                            .collect::<PyResult<Vec<_>>>()?
+                            .into_iter()
+                            .unzip();
+                        let tolerance = check.get_as(intern!(py, "tolerance"))?.unwrap_or(0.0);
+                        (
+                            CheckKind::Sum { paths, tolerance },
+                            format!("sum of {}", descriptions.join(", ")),
+                        )
+                    }
+                    "checksum" => {
+                        let algorithm = match check.get_as_req::<String>(intern!(py, "algorithm"))?.as_str() {
+                            "crc32" => Checksum::Crc32,
+                            "adler32" => Checksum::Adler32,
+                            other => {
+                                return py_schema_err!(
+                                    "Invalid checksum algorithm: `{}`, expected 'crc32' or 'adler32'",
+                                    other
+                                );
+                            }
+                        };
+                        let (path, description) = path(&check.get_as_req(intern!(py, "of"))?)?;
+                        if path.wildcards() > 0 {
+                            return py_schema_err!("A checksum can't be computed over a path with '*'");
+                        }
+                        (
+                            CheckKind::Checksum { path, algorithm },
+                            format!("{} of {description}", algorithm.as_str()),
+                        )
+                    }
+                    other => {
+                        return py_schema_err!(
+                            "Invalid consistency check type: `{}`, expected 'sum' or 'checksum'",
+                            other
+                        )
+                    }
+                };
+                Ok(Self {
+                    field: field.unbind(),
+                    kind,
+                    check,
+                })
+            })
+            .collect()
+    }
+
+    /// Run the checks on the validated fields, with an error on the field of each check which fails.
+    ///
+    /// Checks of fields which aren't set, e.g. with `partial_update`, are skipped, as are the values their paths
+    /// don't find.
+    pub fn check_all(checks: &[Self], data: &Bound<'_, PyDict>) -> ValResult<()> {
+        let mut errors = Vec::new();
+        for check in checks {
+            let py = data.py();
+            let field = check.field.bind(py);
+            let Some(value) = data.get_item(field)? else {
+                continue;
+            };
+            let expected = match &check.kind {
+                CheckKind::Sum { paths, tolerance } => match sum_mismatch(paths, *tolerance, data, &value) {
+                    Ok(Some(total)) => total,
+                    Ok(None) => continue,
+                    // values which can't be added, e.g. strings
+                    Err(err) if err.is_instance_of::<PyTypeError>(py) => {
+                        errors.push(ValLineError::new_with_loc(
+                            ErrorType::ConsistencyUncomputable {
+                                check: check.check.clone(),
+                                error: err.value(py).str()?.to_string(),
+                                context: None,
+                            },
+                            &value,
+                            field.to_str()?,
+                        ));
+                        continue;
+                    }
+                    Err(err) => return Err(err.into()),
+                },
+                CheckKind::Checksum { path, algorithm } => {
+                    let Some(data_value) = path.py_get_validated(data)? else {
+                        continue;
+                    };
+                    let checksum = if let Ok(bytes) = data_value.downcast::<PyBytes>() {
+                        algorithm.compute(bytes.as_bytes())
+                    } else {
+                        algorithm.compute(data_value.downcast::<PyString>()?.to_str()?.as_bytes())
+                    };
+                    // a checksum is also given as a hex string, e.g. `'cbf43926'`
+                    let matches = match value.extract::<String>() {
+                        Ok(hex) => hex.eq_ignore_ascii_case(&format!("{checksum:08x}")),
+                        Err(_) => value.extract::<u32>().is_ok_and(|v| v == checksum),
+                    };
+                    if matches {
+                        continue;
+                    }
+                    checksum.into_bound_py_any(py)?
+                }
+            };
+            errors.push(ValLineError::new_with_loc(
+                ErrorType::ConsistencyMismatch {
+                    check: check.check.clone(),
+                    expected: expected.str()?.to_string(),
+                    context: None,
+                },
+                &value,
+                field.to_str()?,
+            ));
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+}
+
+impl_py_gc_traverse!(ConsistencyCheck { field });
+
+/// The sum of the values found by `paths` if it doesn't match `value`, `None` if it does or none were found.
+fn sum_mismatch<'py>(
+    paths: &[LookupPath],
+    tolerance: f64,
+    data: &Bound<'py, PyDict>,
+    value: &Bound<'py, PyAny>,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let mut total = Total::Int(0);
+    let mut found = false;
+    for path in paths {
+        let Some(found_value) = path.py_get_validated(data)? else {
+            continue;
+        };
+        found = true;
+        for item in flatten(found_value, path.wildcards())? {
+            total = total.add(&item)?;
+        }
+    }
+    if !found || total.matches(value, tolerance)? {
+        Ok(None)
+    } else {
+        total.into_py(data.py()).map(Some)
+    }
+}
+
+/// The values found by a path, unnested from the lists collected by its wildcards.
+fn flatten(value: Bound<'_, PyAny>, depth: usize) -> PyResult<Vec<Bound<'_, PyAny>>> {
+    let mut values = vec![value];
+    for _ in 0..depth {
+        let mut items = Vec::new();
+        for value in values {
+            items.extend(value.downcast::<PyList>()?.iter());
+        }
+        values = items;
+    }
+    Ok(values)
+}
+
+/// A sum computed in Rust while its values are ints which fit in an `i64` or floats, falling back to Python for
+/// other numbers, e.g. decimals.
+enum Total<'py> {
+    Int(i64),
+    Float(f64),
+    Py(Bound<'py, PyAny>),
+}
+
+impl<'py> Total<'py> {
+    fn add(self, value: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let int = || {
+            value
+                .downcast_exact::<PyInt>()
+                .ok()
+                .and_then(|v| v.extract::<i64>().ok())
+        };
+        let float = || value.downcast_exact::<PyFloat>().ok().map(PyFloatMethods::value);
+        match self {
+            Self::Int(total) => {
+                if let Some(sum) = int().and_then(|v| total.checked_add(v)) {
+                    Ok(Self::Int(sum))
+                } else if let Some(v) = float() {
+                    Ok(Self::Float(total as f64 + v))
+                } else {
+                    Ok(Self::Py(total.into_bound_py_any(value.py())?.add(value)?))
+                }
+            }
+            Self::Float(total) => {
+                if let Some(v) = float() {
+                    Ok(Self::Float(total + v))
+                } else if let Some(v) = int() {
+                    Ok(Self::Float(total + v as f64))
+                } else {
+                    Ok(Self::Py(total.into_bound_py_any(value.py())?.add(value)?))
+                }
+            }
+            Self::Py(total) => Ok(Self::Py(total.add(value)?)),
+        }
+    }
+
+    fn matches(&self, value: &Bound<'py, PyAny>, tolerance: f64) -> PyResult<bool> {
+        match self {
+            Self::Int(total) if tolerance == 0.0 => match value.downcast_exact::<PyInt>() {
+                Ok(v) => Ok(v.extract::<i64>().is_ok_and(|v| v == *total)),
+                Err(_) => value.eq(*total),
+            },
+            Self::Int(total) => Ok(value
+                .extract::<f64>()
+                .is_ok_and(|v| (v - *total as f64).abs() <= tolerance)),
+            Self::Float(total) => Ok(value.extract::<f64>().is_ok_and(|v| {
+                // like `math.isclose`, float sums are rarely exact
+                let relative = 1e-9 * v.abs().max(total.abs());
+                (v - total).abs() <= tolerance.max(relative)
+            })),
+            Self::Py(total) if tolerance == 0.0 => value.eq(total),
+            Self::Py(total) => total.sub(value)?.abs()?.le(tolerance),
+        }
+    }
+
+    fn into_py(self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            Self::Int(total) => total.into_bound_py_any(py),
+            Self::Float(total) => total.into_bound_py_any(py),
+            Self::Py(total) => Ok(total),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyType};
+
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::literal::LiteralLookup;
+use super::with_default::WithDefaultValidator;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState, Validator};
+
+/// Validator for `const` schemas, a field which defaults to a constant and only accepts that value if it's
+/// given, e.g. `"version": 2` in an envelope.
+///
+/// The schema is built as a `WithDefaultValidator` around this validator, so a `const` behaves like any other
+/// field with a default.
+#[derive(Debug, Clone)]
+pub struct ConstValidator {
+    lookup: LiteralLookup<Py<PyAny>>,
+    // the type inputs must also have for `bool`, `int` and `float` constants, since they compare equal to
+    // each other, e.g. `True == 1 == 1.0`
+    expected_type: Option<Py<PyType>>,
+    expected_repr: String,
+    name: String,
+}
+
+impl BuildValidator for ConstValidator {
+    const EXPECTED_TYPE: &'static str = "const";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        _config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<Arc<CombinedValidator>>,
+    ) -> PyResult<Arc<CombinedValidator>> {
+        let py = schema.py();
+        let value: Bound<'_, PyAny> = schema.get_as_req(intern!(py, "value"))?;
+        let expected_repr: String = value.repr()?.extract()?;
+        let numeric = value.is_exact_instance_of::<PyBool>()
+            || value.is_exact_instance_of::<PyInt>()
+            || value.is_exact_instance_of::<PyFloat>();
+        let validator = CombinedValidator::Const(Self {
+            lookup: LiteralLookup::new(py, std::iter::once((value.clone(), value.clone().unbind())))?,
+            expected_type: numeric.then(|| value.get_type().unbind()),
+            name: format!("{}[{expected_repr}]", Self::EXPECTED_TYPE),
+            expected_repr,
+        });
+        Ok(WithDefaultValidator::with_const_default(&value, validator.into()))
+    }
+}
+
+impl_py_gc_traverse!(ConstValidator { lookup, expected_type });
+
+impl ConstValidator {
+    fn same_type<'py>(&self, py: Python<'py>, input: &(impl Input<'py> + ?Sized)) -> PyResult<bool> {
+        match &self.expected_type {
+            Some(expected_type) => Ok(input.to_object(py)?.get_type().is(expected_type)),
+            None => Ok(true),
+        }
+    }
+}
+
+impl Validator for ConstValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        _state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        match self.lookup.validate(py, input)? {
+            Some((_, value)) if self.same_type(py, input)? => Ok(value.clone_ref(py)),
+            _ => Err(ValError::new(
+                ErrorType::ConstMismatch {
+                    expected: self.expected_repr.clone(),
+                    context: None,
+                },
+                input,
+            )),
+        }
+    }
+
+    fn example<'py>(&self, py: Python<'py>, _generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        Ok(self.lookup.values[0].bind(py).clone())
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
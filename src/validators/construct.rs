@@ -0,0 +1,102 @@
+use ahash::AHashSet;
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PySet, PyString};
+
+use crate::lookup_key::LookupKeyCollection;
+
+use super::CombinedValidator;
+
+/// How fields are found in the data, see `SchemaValidator.construct`.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ConstructOptions {
+    pub by_alias: Option<bool>,
+    pub by_name: Option<bool>,
+}
+
+/// A field of a model or dataclass, as seen when constructing an instance without validation.
+pub(super) struct FieldToConstruct<'v> {
+    pub name_py: &'v Py<PyString>,
+    pub lookup_key_collection: &'v LookupKeyCollection,
+    pub validator: &'v CombinedValidator,
+    /// whether the field's value can come from the data, otherwise it always gets its default
+    pub from_data: bool,
+}
+
+/// The attributes of an instance constructed from data without validation.
+pub(super) struct ConstructedFields<'py> {
+    pub dict: Bound<'py, PyDict>,
+    pub fields_set: Bound<'py, PySet>,
+    /// the items of the data which aren't the value of a field
+    pub extra: Bound<'py, PyDict>,
+}
+
+/// Construct an instance of the model or dataclass `validator` validates from `data` without validating it.
+pub(super) fn construct<'py>(
+    py: Python<'py>,
+    validator: &CombinedValidator,
+    data: &Bound<'py, PyAny>,
+    fields_set: Option<&Bound<'py, PyAny>>,
+    options: ConstructOptions,
+) -> PyResult<Bound<'py, PyAny>> {
+    match validator {
+        CombinedValidator::Model(model) => model.construct(py, data, fields_set, options),
+        CombinedValidator::Dataclass(dataclass) => dataclass.construct(py, data, options),
+        CombinedValidator::DefinitionRef(definition_ref) => {
+            definition_ref.read_definition(py, |validator| construct(py, validator, data, fields_set, options))?
+        }
+        _ => Err(PyTypeError::new_err(
+            "`construct` is only supported for model and dataclass schemas",
+        )),
+    }
+}
+
+/// Find the value of each field in `data`, fields missing from it get their default, if they have one.
+///
+/// Values are used as they are, neither validators nor `validate_default` are applied.
+pub(super) fn construct_fields<'v, 'py>(
+    py: Python<'py>,
+    data: &Bound<'py, PyDict>,
+    fields: impl IntoIterator<Item = FieldToConstruct<'v>>,
+    validate_by_alias: bool,
+    validate_by_name: bool,
+) -> PyResult<ConstructedFields<'py>> {
+    let dict = PyDict::new(py);
+    let fields_set = PySet::empty(py)?;
+    let mut used_keys: AHashSet<String> = AHashSet::new();
+
+    for field in fields {
+        let name_py = field.name_py.bind(py);
+        if field.from_data {
+            let lookup_key = field
+                .lookup_key_collection
+                .select(validate_by_alias, validate_by_name)?;
+            if let Some((lookup_path, value)) = lookup_key.py_get_dict_item(data)? {
+                used_keys.insert(lookup_path.first_key().to_string());
+                dict.set_item(name_py, value)?;
+                fields_set.add(name_py)?;
+                continue;
+            }
+        }
+        if let CombinedValidator::WithDefault(with_default) = field.validator {
+            if let Some(default) = with_default.construct_default(py, &dict)? {
+                dict.set_item(name_py, default)?;
+            }
+        }
+    }
+
+    let extra = PyDict::new(py);
+    for (key, value) in data {
+        let used = key
+            .downcast::<PyString>()
+            .is_ok_and(|key| key.to_str().is_ok_and(|key| used_keys.contains(key)));
+        if !used {
+            extra.set_item(key, value)?;
+        }
+    }
+    Ok(ConstructedFields {
+        dict,
+        fields_set,
+        extra,
+    })
+}
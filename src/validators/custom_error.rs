@@ -5,13 +5,14 @@ use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
 use crate::build_tools::py_schema_err;
+use crate::definitions::CompositeName;
 use crate::errors::ToErrorValue;
 use crate::errors::{ErrorType, PydanticCustomError, PydanticKnownError, ValError, ValResult};
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
 use super::validation_state::ValidationState;
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, Validator};
 
 #[derive(Debug, Clone)]
 pub enum CustomError {
@@ -63,7 +64,7 @@ impl CustomError {
 pub struct CustomErrorValidator {
     validator: Arc<CombinedValidator>,
     custom_error: CustomError,
-    name: String,
+    name: CompositeName,
 }
 
 impl BuildValidator for CustomErrorValidator {
@@ -77,11 +78,10 @@ impl BuildValidator for CustomErrorValidator {
         let custom_error = CustomError::build(schema, config, definitions)?.unwrap();
         let schema = schema.get_as_req(intern!(schema.py(), "schema"))?;
         let validator = build_validator(&schema, config, definitions)?;
-        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
         Ok(CombinedValidator::CustomError(Self {
             validator,
             custom_error,
-            name,
+            name: CompositeName::new(),
         })
         .into())
     }
@@ -101,7 +101,12 @@ impl Validator for CustomErrorValidator {
             .map_err(|_| self.custom_error.as_val_error(input))
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        self.validator.example(py, generator)
+    }
+
     fn get_name(&self) -> &str {
-        &self.name
+        self.name
+            .get_or_init(|| format!("{}[{}]", Self::EXPECTED_TYPE, self.validator.get_name()))
     }
 }
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use pyo3::exceptions::PyKeyError;
+use pyo3::exceptions::{PyKeyError, PyTypeError};
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString, PyTuple, PyType};
@@ -9,7 +9,7 @@ use ahash::AHashSet;
 use pyo3::IntoPyObjectExt;
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{is_strict, schema_or_config_same, ExtraBehavior};
+use crate::build_tools::{is_strict, schema_or_config_same, ExtraBehavior, FieldAliases};
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::{
     input_as_python_instance, Arguments, BorrowInput, Input, InputType, KeywordArgs, PositionalArgs, ValidationMatch,
@@ -18,9 +18,18 @@ use crate::lookup_key::LookupKeyCollection;
 use crate::tools::SchemaDict;
 use crate::validators::function::convert_err;
 
+use super::construct::{construct_fields, ConstructOptions, FieldToConstruct};
+use super::explain;
+use super::ignored_extras;
+use super::introspect::{has_default, FieldToInspect};
 use super::model::{create_class, force_setattr, Revalidate};
+use super::profile;
+use super::provenance;
 use super::validation_state::Exactness;
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState,
+    Validator,
+};
 
 #[derive(Debug)]
 struct Field {
@@ -32,6 +41,8 @@ struct Field {
     lookup_key_collection: LookupKeyCollection,
     validator: Arc<CombinedValidator>,
     frozen: bool,
+    // only used by introspection, the serializer has its own
+    serialization_alias: Option<String>,
 }
 
 #[derive(Debug)]
@@ -68,6 +79,7 @@ impl BuildValidator for DataclassArgsValidator {
 
         let fields_schema: Bound<'_, PyList> = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: Vec<Field> = Vec::with_capacity(fields_schema.len());
+        let aliases = FieldAliases::from_schema(schema)?;
 
         let mut positional_count = 0;
 
@@ -79,7 +91,7 @@ impl BuildValidator for DataclassArgsValidator {
 
             let schema = field.get_as_req(intern!(py, "schema"))?;
 
-            let validator = match build_validator(&schema, config, definitions) {
+            let validator = match profile::with_path_segment(&name, || build_validator(&schema, config, definitions)) {
                 Ok(v) => v,
                 Err(err) => return py_schema_err!("Field '{}':\n  {}", name, err),
             };
@@ -95,8 +107,12 @@ impl BuildValidator for DataclassArgsValidator {
                 positional_count += 1;
             }
 
-            let validation_alias = field.get_item(intern!(py, "validation_alias"))?;
+            let validation_alias = aliases.get(field, intern!(py, "validation_alias"), &name)?;
             let lookup_key_collection = LookupKeyCollection::new(py, validation_alias, name.as_str())?;
+            let serialization_alias = aliases
+                .get(field, intern!(py, "serialization_alias"), &name)?
+                .map(|alias| alias.extract())
+                .transpose()?;
 
             fields.push(Field {
                 kw_only,
@@ -107,6 +123,7 @@ impl BuildValidator for DataclassArgsValidator {
                 init: field.get_as(intern!(py, "init"))?.unwrap_or(true),
                 init_only: field.get_as(intern!(py, "init_only"))?.unwrap_or(false),
                 frozen: field.get_as::<bool>(intern!(py, "frozen"))?.unwrap_or(false),
+                serialization_alias,
             });
         }
 
@@ -138,6 +155,53 @@ impl_py_gc_traverse!(Field { validator });
 
 impl_py_gc_traverse!(DataclassArgsValidator { fields });
 
+impl DataclassArgsValidator {
+    /// The fields which can be given when creating the dataclass, fields with `init=False` are left out.
+    pub(super) fn inspect_fields(&self) -> Vec<FieldToInspect<'_>> {
+        self.fields
+            .iter()
+            .filter(|field| field.init || field.init_only)
+            .map(|field| FieldToInspect {
+                name: &field.name,
+                lookup_key_collection: &field.lookup_key_collection,
+                validator: &field.validator,
+                required: !has_default(&field.validator),
+                serialization_alias: field.serialization_alias.as_deref(),
+            })
+            .collect()
+    }
+
+    /// The `__dict__` of an instance constructed from `data` without validation, init-only fields are left out.
+    fn construct<'py>(
+        &self,
+        py: Python<'py>,
+        data: &Bound<'py, PyDict>,
+        options: ConstructOptions,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let fields = self
+            .fields
+            .iter()
+            .filter(|field| !field.init_only)
+            .map(|field| FieldToConstruct {
+                name_py: &field.name_py,
+                lookup_key_collection: &field.lookup_key_collection,
+                validator: &field.validator,
+                from_data: field.init,
+            });
+        let constructed = construct_fields(
+            py,
+            data,
+            fields,
+            options.by_alias.or(self.validate_by_alias).unwrap_or(true),
+            options.by_name.or(self.validate_by_name).unwrap_or(false),
+        )?;
+        if self.extra_behavior == ExtraBehavior::Allow {
+            constructed.dict.update(constructed.extra.as_mapping())?;
+        }
+        Ok(constructed.dict)
+    }
+}
+
 impl Validator for DataclassArgsValidator {
     fn validate<'py>(
         &self,
@@ -156,7 +220,7 @@ impl Validator for DataclassArgsValidator {
         let mut errors: Vec<ValLineError> = Vec::new();
         let mut used_keys: AHashSet<&str> = AHashSet::with_capacity(self.fields.len());
 
-        let state = &mut state.rebind_extra(|extra| extra.data = Some(output_dict.clone()));
+        let state = &mut state.rebind_extra(|extra| extra.set_data(output_dict.clone()));
         let state = &mut state.scoped_set(|state| &mut state.has_field_error, false);
 
         let extra_behavior = state.extra_behavior_or(self.extra_behavior);
@@ -181,6 +245,10 @@ impl Validator for DataclassArgsValidator {
 
         // go through fields getting the value from args or kwargs and validating it
         for (index, field) in self.fields.iter().enumerate() {
+            if state.stop_collecting(false, &errors) {
+                break;
+            }
+            state.check_deadline()?;
             if !field.init {
                 match field.validator.default_value(py, Some(field.name.as_str()), state) {
                     Ok(Some(value)) => {
@@ -231,38 +299,68 @@ impl Validator for DataclassArgsValidator {
                     ));
                 }
                 // found a positional argument, validate it
-                (Some(pos_value), None) => match field.validator.validate(py, pos_value.borrow_input(), state) {
-                    Ok(value) => {
-                        set_item!(field, value);
-                        fields_set_count += 1;
-                    }
-                    Err(ValError::LineErrors(line_errors)) => {
-                        state.has_field_error = true;
-                        errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                (Some(pos_value), None) => {
+                    match state.with_path_item(
+                        || index,
+                        |state| {
+                            ignored_extras::validate_field(state, &field.name, |state| {
+                                explain::validate_field(py, state, &field.name, pos_value.borrow_input(), |state| {
+                                    provenance::validate_field(
+                                        py,
+                                        state,
+                                        &field.name,
+                                        pos_value.borrow_input(),
+                                        |state| field.validator.validate(py, pos_value.borrow_input(), state),
+                                    )
+                                })
+                            })
+                        },
+                    ) {
+                        Ok(value) => {
+                            set_item!(field, value);
+                            fields_set_count += 1;
+                        }
+                        Err(ValError::LineErrors(line_errors)) => {
+                            state.has_field_error = true;
+                            errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                        }
+                        Err(err) => return Err(err),
                     }
-                    Err(err) => return Err(err),
-                },
+                }
                 // found a keyword argument, validate it
-                (None, Some((lookup_path, kw_value))) => match field.validator.validate(py, kw_value, state) {
-                    Ok(value) => {
-                        set_item!(field, value);
-                        fields_set_count += 1;
-                    }
-                    Err(ValError::LineErrors(line_errors)) => {
-                        state.has_field_error = true;
-                        errors.extend(
-                            line_errors
-                                .into_iter()
-                                .map(|err| lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name)),
-                        );
+                (None, Some((lookup_path, kw_value))) => {
+                    let loc = || lookup_path.loc(self.loc_by_alias, &field.name);
+                    match state.with_path_location(loc, |state| {
+                        ignored_extras::validate_field(state, &field.name, |state| {
+                            explain::validate_field(py, state, &field.name, kw_value, |state| {
+                                provenance::validate_field(py, state, &field.name, kw_value, |state| {
+                                    field.validator.validate(py, kw_value, state)
+                                })
+                            })
+                        })
+                    }) {
+                        Ok(value) => {
+                            set_item!(field, value);
+                            fields_set_count += 1;
+                        }
+                        Err(ValError::LineErrors(line_errors)) => {
+                            state.has_field_error = true;
+                            errors.extend(
+                                line_errors
+                                    .into_iter()
+                                    .map(|err| lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name)),
+                            );
+                        }
+                        Err(err) => return Err(err),
                     }
-                    Err(err) => return Err(err),
-                },
+                }
                 // found neither, check if there is a default value, otherwise error
                 (None, None) => {
                     match field.validator.default_value(py, Some(field.name.as_str()), state) {
                         Ok(Some(value)) => {
                             // Default value exists, and passed validation if required
+                            explain::record_default(py, state, &field.name, &value);
+                            provenance::record_default(state, &field.name);
                             set_item!(field, value);
                         }
                         Ok(None) => {
@@ -307,6 +405,10 @@ impl Validator for DataclassArgsValidator {
         if let Some(kwargs) = args.kwargs() {
             if kwargs.len() != used_keys.len() {
                 for result in kwargs.iter() {
+                    if state.stop_collecting(false, &errors) {
+                        break;
+                    }
+                    state.check_deadline()?;
                     let (raw_key, value) = result?;
                     match raw_key
                         .borrow_input()
@@ -324,10 +426,15 @@ impl Validator for DataclassArgsValidator {
                                             raw_key.clone(),
                                         ));
                                     }
-                                    ExtraBehavior::Ignore => {}
+                                    ExtraBehavior::Ignore => {
+                                        ignored_extras::report(state, &either_str.as_cow()?)?;
+                                    }
                                     ExtraBehavior::Allow => {
                                         if let Some(ref validator) = self.extras_validator {
-                                            match validator.validate(py, value.borrow_input(), state) {
+                                            match state.with_path_item(
+                                                || raw_key.clone(),
+                                                |state| validator.validate(py, value.borrow_input(), state),
+                                            ) {
                                                 Ok(value) => {
                                                     output_dict.set_item(
                                                         either_str.as_py_string(py, state.cache_str()),
@@ -416,11 +523,11 @@ impl Validator for DataclassArgsValidator {
             }
 
             let state = &mut state.rebind_extra(|extra| {
-                extra.data = Some(data_dict.clone());
+                extra.set_data(data_dict.clone());
                 extra.field_name = Some(field.name_py.bind(py).clone());
             });
 
-            match field.validator.validate(py, field_value, state) {
+            match state.with_path_item(|| field_name, |state| field.validator.validate(py, field_value, state)) {
                 Ok(output) => ok(output),
                 Err(ValError::LineErrors(line_errors)) => {
                     let errors = line_errors
@@ -451,6 +558,16 @@ impl Validator for DataclassArgsValidator {
         }
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        let dict = PyDict::new(py);
+        for field in &self.fields {
+            if field.init && !generator.skip_field(&field.validator, true) {
+                dict.set_item(&field.name_py, field.validator.example(py, generator)?)?;
+            }
+        }
+        Ok(dict.into_any())
+    }
+
     fn get_name(&self) -> &str {
         &self.validator_name
     }
@@ -534,6 +651,44 @@ impl Validator for DataclassValidator {
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        state.with_model_strict_scope(|state| self.validate_scoped(py, input, state))
+    }
+
+    fn validate_assignment<'py>(
+        &self,
+        py: Python<'py>,
+        obj: &Bound<'py, PyAny>,
+        field_name: &str,
+        field_value: &Bound<'py, PyAny>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        state.with_model_strict_scope(|state| self.validate_assignment_scoped(py, obj, field_name, field_value, state))
+    }
+
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        self.validator.example(py, generator)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl DataclassValidator {
+    pub(super) fn validator(&self) -> &CombinedValidator {
+        &self.validator
+    }
+
+    pub(super) fn class(&self) -> &Py<PyType> {
+        &self.class
+    }
+
+    fn validate_scoped<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
         if let Some(self_instance) = state.extra().self_instance {
             // in the case that self_instance is Some, we're calling validation from within `BaseModel.__init__`
@@ -589,7 +744,7 @@ impl Validator for DataclassValidator {
         }
     }
 
-    fn validate_assignment<'py>(
+    fn validate_assignment_scoped<'py>(
         &self,
         py: Python<'py>,
         obj: &Bound<'py, PyAny>,
@@ -623,12 +778,6 @@ impl Validator for DataclassValidator {
         Ok(obj.clone().unbind())
     }
 
-    fn get_name(&self) -> &str {
-        &self.name
-    }
-}
-
-impl DataclassValidator {
     /// here we just call the inner validator, then set attributes on `self_instance`
     fn validate_init<'py>(
         &self,
@@ -647,6 +796,31 @@ impl DataclassValidator {
         Ok(self_instance.clone().unbind())
     }
 
+    /// Create an instance from `data` without validating it, `__post_init__` isn't called.
+    pub(super) fn construct<'py>(
+        &self,
+        py: Python<'py>,
+        data: &Bound<'py, PyAny>,
+        options: ConstructOptions,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let CombinedValidator::DataclassArgs(dataclass_args) = self.validator.as_ref() else {
+            return Err(PyTypeError::new_err(format!(
+                "`construct` requires the fields of `{}` to have a `dataclass-args` schema",
+                self.name
+            )));
+        };
+        let dc_dict = dataclass_args.construct(py, data.downcast()?, options)?;
+        let dc = create_class(self.class.bind(py))?;
+        if self.slots {
+            for (key, value) in dc_dict {
+                force_setattr(py, &dc, key, value)?;
+            }
+        } else {
+            force_setattr(py, &dc, intern!(py, "__dict__"), dc_dict)?;
+        }
+        Ok(dc)
+    }
+
     fn dataclass_to_dict<'py>(&self, dc: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyDict>> {
         let py = dc.py();
         let dict = PyDict::new(py);
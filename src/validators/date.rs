@@ -3,17 +3,19 @@ use std::sync::Arc;
 use pyo3::exceptions::PyValueError;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
-use speedate::{Date, Time};
-use strum::EnumMessage;
+use pyo3::types::{PyDate, PyDict, PyString};
+use speedate::{Date, DateConfig, Time};
 
-use crate::build_tools::{is_strict, py_schema_error_type};
+use crate::build_tools::is_strict;
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::input::{EitherDate, Input};
 
 use crate::validators::datetime::{NowConstraint, NowOp};
 
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::datetime_format::{DateTimeFormat, FormatKind};
+
+use super::example;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState, Validator};
 use super::{Exactness, TemporalUnitMode};
 
 #[derive(Debug, Clone)]
@@ -21,6 +23,7 @@ pub struct DateValidator {
     strict: bool,
     constraints: Option<DateConstraints>,
     val_temporal_unit: TemporalUnitMode,
+    format: Option<DateTimeFormat>,
 }
 
 impl BuildValidator for DateValidator {
@@ -33,14 +36,15 @@ impl BuildValidator for DateValidator {
     ) -> PyResult<Arc<CombinedValidator>> {
         Ok(CombinedValidator::Date(Self {
             strict: is_strict(schema, config)?,
-            constraints: DateConstraints::from_py(schema)?,
+            constraints: DateConstraints::from_py(schema, config)?,
             val_temporal_unit: TemporalUnitMode::from_config(config)?,
+            format: DateTimeFormat::from_py(schema, FormatKind::Date)?,
         })
         .into())
     }
 }
 
-impl_py_gc_traverse!(DateValidator {});
+impl_py_gc_traverse!(DateValidator { constraints });
 
 impl Validator for DateValidator {
     fn validate<'py>(
@@ -50,14 +54,22 @@ impl Validator for DateValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
         let strict = state.strict_or(self.strict);
-        let date = match input.validate_date(strict, self.val_temporal_unit) {
-            Ok(val_match) => val_match.unpack(state),
-            // if the error was a parsing error, in lax mode we allow datetimes at midnight
-            Err(line_errors @ ValError::LineErrors(..)) if !strict => {
-                state.floor_exactness(Exactness::Lax);
-                date_from_datetime(input, self.val_temporal_unit)?.ok_or(line_errors)?
+        let formatted = match &self.format {
+            Some(format) => format.validate(input, strict, state)?,
+            None => None,
+        };
+        let date = if let Some(datetime) = formatted {
+            EitherDate::Raw(datetime.date)
+        } else {
+            match input.validate_date(strict, self.val_temporal_unit) {
+                Ok(val_match) => val_match.unpack(state),
+                // if the error was a parsing error, in lax mode we allow datetimes at midnight
+                Err(line_errors @ ValError::LineErrors(..)) if !strict => {
+                    state.floor_exactness(Exactness::Lax);
+                    date_from_datetime(input, self.val_temporal_unit)?.ok_or(line_errors)?
+                }
+                Err(otherwise) => return Err(otherwise),
             }
-            Err(otherwise) => return Err(otherwise),
         };
         if let Some(constraints) = &self.constraints {
             let raw_date = date.as_raw()?;
@@ -84,10 +96,7 @@ impl Validator for DateValidator {
             check_constraint!(gt, GreaterThan);
 
             if let Some(ref today_constraint) = constraints.today {
-                let offset = today_constraint.utc_offset(py)?;
-                let today = Date::today(offset).map_err(|e| {
-                    py_schema_error_type!("Date::today() error: {}", e.get_documentation().unwrap_or("unknown"))
-                })?;
+                let today = today_constraint.now(py, state)?.date;
                 // `if let Some(c)` to match behaviour of gt/lt/le/ge
                 if let Some(c) = raw_date.partial_cmp(&today) {
                     let date_compliant = today_constraint.op.compare(c);
@@ -104,6 +113,34 @@ impl Validator for DateValidator {
         date.try_into_py(py, input)
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        // days since the epoch, from 2000 to 2030 unless the constraints say otherwise
+        let days = |date: &Date| i128::from(date.timestamp().div_euclid(86_400));
+        let (ge, le) = match &self.constraints {
+            Some(c) => {
+                let bounds = example::int_bounds(
+                    c.ge.as_ref().map(days),
+                    c.gt.as_ref().map(days),
+                    c.le.as_ref().map(days),
+                    c.lt.as_ref().map(days),
+                );
+                match &c.today {
+                    Some(today) => {
+                        let today_days = days(&today.example_now(py, generator)?.date);
+                        example::now_bounds(bounds, &today.op, today_days, 1)
+                    }
+                    None => bounds,
+                }
+            }
+            None => (None, None),
+        };
+        let days = generator.int_within(ge, le, (10_957, 22_279));
+        let timestamp = i64::try_from(days * 86_400).map_err(|_| PyValueError::new_err("Date out of range"))?;
+        let date = Date::from_timestamp(timestamp, false, &DateConfig::default())
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(PyDate::new(py, date.year.into(), date.month, date.day)?.into_any())
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -165,15 +202,17 @@ struct DateConstraints {
     today: Option<NowConstraint>,
 }
 
+impl_py_gc_traverse!(DateConstraints { today });
+
 impl DateConstraints {
-    fn from_py(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+    fn from_py(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Option<Self>> {
         let py = schema.py();
         let c = Self {
             le: convert_pydate(schema, intern!(py, "le"))?,
             lt: convert_pydate(schema, intern!(py, "lt"))?,
             ge: convert_pydate(schema, intern!(py, "ge"))?,
             gt: convert_pydate(schema, intern!(py, "gt"))?,
-            today: NowConstraint::from_py(schema)?,
+            today: NowConstraint::from_py(schema, config)?,
         };
         if c.le.is_some() || c.lt.is_some() || c.ge.is_some() || c.gt.is_some() || c.today.is_some() {
             Ok(Some(c))
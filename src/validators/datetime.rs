@@ -1,21 +1,23 @@
-use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::sync::PyOnceLock;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyDateTime, PyDelta, PyDeltaAccess, PyDict, PyString, PyType, PyTzInfo};
 use speedate::{DateTime, MicrosecondsPrecisionOverflowBehavior, Time};
 use std::cmp::Ordering;
 use std::sync::Arc;
 use strum::EnumMessage;
 
 use crate::build_tools::{is_strict, py_schema_error_type};
-use crate::build_tools::{py_schema_err, schema_or_config_same};
+use crate::build_tools::{py_schema_err, schema_or_config, schema_or_config_same};
 use crate::errors::ToErrorValue;
 use crate::errors::{py_err_string, ErrorType, ErrorTypeDefaults, ValError, ValResult};
-use crate::input::{EitherDateTime, Input};
+use crate::input::{EitherDateTime, Input, TzInfo};
 
+use super::datetime_format::{parse_rfc2822, DateTimeFormat, FormatKind};
+use super::example;
 use super::Exactness;
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState, Validator};
 use crate::tools::SchemaDict;
 use crate::validators::config::TemporalUnitMode;
 
@@ -25,6 +27,10 @@ pub struct DateTimeValidator {
     constraints: Option<DateTimeConstraints>,
     microseconds_precision: speedate::MicrosecondsPrecisionOverflowBehavior,
     val_temporal_unit: TemporalUnitMode,
+    format: Option<DateTimeFormat>,
+    rfc2822: bool,
+    // the `tz_constraint` zone to attach aware datetimes to, if `zoneinfo` is set
+    zoneinfo: Option<Py<PyTzInfo>>,
 }
 
 pub(crate) fn extract_microseconds_precision(
@@ -49,17 +55,35 @@ impl BuildValidator for DateTimeValidator {
         config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<Arc<CombinedValidator>>,
     ) -> PyResult<Arc<CombinedValidator>> {
+        let constraints = DateTimeConstraints::from_py(schema, config)?;
+        let zoneinfo = if schema.get_as(intern!(schema.py(), "zoneinfo"))?.unwrap_or(false) {
+            match constraints.as_ref().and_then(|c| c.zone.as_ref()) {
+                Some(zone) => Some(zone.zone.clone_ref(schema.py())),
+                None => return py_schema_err!("`zoneinfo` requires `tz_constraint` to be an IANA time zone name"),
+            }
+        } else {
+            None
+        };
         Ok(CombinedValidator::Datetime(Self {
             strict: is_strict(schema, config)?,
-            constraints: DateTimeConstraints::from_py(schema)?,
+            constraints,
             microseconds_precision: extract_microseconds_precision(schema, config)?,
             val_temporal_unit: TemporalUnitMode::from_config(config)?,
+            format: DateTimeFormat::from_py(schema, FormatKind::Datetime)?,
+            rfc2822: schema_or_config(
+                schema,
+                config,
+                intern!(schema.py(), "rfc2822"),
+                intern!(schema.py(), "datetime_rfc2822"),
+            )?
+            .unwrap_or(false),
+            zoneinfo,
         })
         .into())
     }
 }
 
-impl_py_gc_traverse!(DateTimeValidator {});
+impl_py_gc_traverse!(DateTimeValidator { constraints, zoneinfo });
 
 impl Validator for DateTimeValidator {
     fn validate<'py>(
@@ -69,14 +93,31 @@ impl Validator for DateTimeValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
         let strict = state.strict_or(self.strict);
-        let datetime = match input.validate_datetime(strict, self.microseconds_precision, self.val_temporal_unit) {
-            Ok(val_match) => val_match.unpack(state),
-            // if the error was a parsing error, in lax mode we allow dates and add the time 00:00:00
-            Err(line_errors @ ValError::LineErrors(..)) if !strict => {
-                state.floor_exactness(Exactness::Lax);
-                datetime_from_date(input)?.ok_or(line_errors)?
+        let formatted = match &self.format {
+            Some(format) => format.validate(input, strict, state)?,
+            None => None,
+        };
+        let datetime = if let Some(datetime) = formatted {
+            EitherDateTime::Raw(datetime)
+        } else {
+            match input.validate_datetime(strict, self.microseconds_precision, self.val_temporal_unit) {
+                Ok(val_match) => val_match.unpack(state),
+                // if the error was a parsing error, in lax mode we allow RFC 2822 dates if enabled, and dates
+                // with the time 00:00:00 added
+                Err(line_errors @ ValError::LineErrors(..)) if !strict => {
+                    state.floor_exactness(Exactness::Lax);
+                    let rfc2822 = if self.rfc2822 {
+                        datetime_from_rfc2822(input)?
+                    } else {
+                        None
+                    };
+                    match rfc2822 {
+                        Some(datetime) => EitherDateTime::Raw(datetime),
+                        None => datetime_from_date(input)?.ok_or(line_errors)?,
+                    }
+                }
+                Err(otherwise) => return Err(otherwise),
             }
-            Err(otherwise) => return Err(otherwise),
         };
         if let Some(constraints) = &self.constraints {
             // if we get an error from as_speedate, it's probably because the input datetime was invalid
@@ -113,10 +154,7 @@ impl Validator for DateTimeValidator {
             check_constraint!(gt, GreaterThan);
 
             if let Some(ref now_constraint) = constraints.now {
-                let offset = now_constraint.utc_offset(py)?;
-                let now = DateTime::now(offset).map_err(|e| {
-                    py_schema_error_type!("DateTime::now() error: {}", e.get_documentation().unwrap_or("unknown"))
-                })?;
+                let now = now_constraint.now(py, state)?;
                 // `if let Some(c)` to match behaviour of gt/lt/le/ge
                 if let Some(c) = speedate_dt.partial_cmp(&now) {
                     let dt_compliant = now_constraint.op.compare(c);
@@ -133,8 +171,67 @@ impl Validator for DateTimeValidator {
             if let Some(ref tz_constraint) = constraints.tz {
                 tz_constraint.tz_check(speedate_dt.time.tz_offset, input)?;
             }
+            if let Some(ref zone) = constraints.zone {
+                zone.check(py, &speedate_dt, input)?;
+            }
         }
-        datetime.try_into_py(py, input)
+        let output = datetime.try_into_py(py, input)?;
+        match &self.zoneinfo {
+            // the offset has been checked, so this keeps the wall time
+            Some(zone) => Ok(output.call_method1(py, intern!(py, "astimezone"), (zone,))?),
+            None => Ok(output),
+        }
+    }
+
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        // seconds since the epoch, from 2000 to 2030 unless the constraints say otherwise
+        let seconds = |dt: &DateTime| i128::from(dt.timestamp_tz());
+        let (ge, le, offset) = match &self.constraints {
+            Some(c) => {
+                let bounds = example::int_bounds(
+                    c.ge.as_ref().map(seconds),
+                    c.gt.as_ref().map(seconds),
+                    c.le.as_ref().map(seconds),
+                    c.lt.as_ref().map(seconds),
+                );
+                let (ge, le) = match &c.now {
+                    // a day either side, so naive datetimes are before or after now in any timezone
+                    Some(now) => {
+                        example::now_bounds(bounds, &now.op, seconds(&now.example_now(py, generator)?), 86_400)
+                    }
+                    None => bounds,
+                };
+                let offset = match c.tz {
+                    Some(TZConstraint::Aware(offset)) => Some(offset.unwrap_or(0)),
+                    _ => None,
+                };
+                (ge, le, offset)
+            }
+            None => (None, None, None),
+        };
+        let timestamp = generator.int_within(ge, le, (946_684_800, 1_924_991_999)) + i128::from(offset.unwrap_or(0));
+        let timestamp = i64::try_from(timestamp).map_err(|_| PyValueError::new_err("Datetime out of range"))?;
+        let dt = DateTime::from_timestamp(timestamp, 0).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let tzinfo = match offset {
+            Some(offset) => Some(
+                Bound::new(py, TzInfo::try_from(offset)?)?
+                    .into_any()
+                    .downcast_into::<PyTzInfo>()?,
+            ),
+            None => None,
+        };
+        let py_dt = PyDateTime::new(
+            py,
+            dt.date.year.into(),
+            dt.date.month,
+            dt.date.day,
+            dt.time.hour,
+            dt.time.minute,
+            dt.time.second,
+            0,
+            tzinfo.as_ref(),
+        )?;
+        Ok(py_dt.into_any())
     }
 
     fn get_name(&self) -> &str {
@@ -142,6 +239,14 @@ impl Validator for DateTimeValidator {
     }
 }
 
+/// In lax mode, with `rfc2822` set, a string which isn't ISO 8601 can be an RFC 2822 date like HTTP `Date:` headers.
+fn datetime_from_rfc2822<'py>(input: &(impl Input<'py> + ?Sized)) -> ValResult<Option<DateTime>> {
+    match input.validate_str(false, false) {
+        Ok(either_str) => Ok(parse_rfc2822(either_str.into_inner().as_cow()?.as_ref())),
+        Err(_) => Ok(None),
+    }
+}
+
 /// In lax mode, if the input is not a datetime, we try parsing the input as a date and add the "00:00:00" time.
 /// Ok(None) means that this is not relevant to datetimes (the input was not a date nor a string)
 fn datetime_from_date<'py>(input: &(impl Input<'py> + ?Sized)) -> Result<Option<EitherDateTime<'py>>, ValError> {
@@ -191,20 +296,36 @@ struct DateTimeConstraints {
     gt: Option<DateTime>,
     now: Option<NowConstraint>,
     tz: Option<TZConstraint>,
+    zone: Option<ZoneConstraint>,
 }
 
+impl_py_gc_traverse!(DateTimeConstraints { now, zone });
+
 impl DateTimeConstraints {
-    fn from_py(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+    fn from_py(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Option<Self>> {
         let py = schema.py();
+        let zone = ZoneConstraint::from_py(schema)?;
         let c = Self {
             le: py_datetime_as_datetime(schema, intern!(py, "le"))?,
             lt: py_datetime_as_datetime(schema, intern!(py, "lt"))?,
             ge: py_datetime_as_datetime(schema, intern!(py, "ge"))?,
             gt: py_datetime_as_datetime(schema, intern!(py, "gt"))?,
-            now: NowConstraint::from_py(schema)?,
-            tz: TZConstraint::from_py(schema)?,
+            now: NowConstraint::from_py(schema, config)?,
+            // a zone name is checked by `zone` instead
+            tz: match zone {
+                Some(_) => None,
+                None => TZConstraint::from_py(schema)?,
+            },
+            zone,
         };
-        if c.le.is_some() || c.lt.is_some() || c.ge.is_some() || c.gt.is_some() || c.now.is_some() || c.tz.is_some() {
+        if c.le.is_some()
+            || c.lt.is_some()
+            || c.ge.is_some()
+            || c.gt.is_some()
+            || c.now.is_some()
+            || c.tz.is_some()
+            || c.zone.is_some()
+        {
             Ok(Some(c))
         } else {
             Ok(None)
@@ -256,33 +377,189 @@ impl NowOp {
 pub struct NowConstraint {
     pub op: NowOp,
     utc_offset: Option<i32>,
+    clock: Option<Clock>,
+    // the key of the validation context holding the current time, from the `clock_context_key` config
+    clock_context_key: Option<String>,
 }
 
 static TIME_LOCALTIME: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
 
 impl NowConstraint {
-    /// Get the UTC offset in seconds either from the utc_offset field or by calling `time.localtime().tm_gmtoff`.
-    /// Note: although the attribute is called "gmtoff", it is actually the offset in the UTC direction,
-    /// hence no need to negate it.
-    pub fn utc_offset(&self, py: Python) -> PyResult<i32> {
-        if let Some(utc_offset) = self.utc_offset {
-            Ok(utc_offset)
-        } else {
-            let localtime = TIME_LOCALTIME.import(py, "time", "localtime")?;
-            localtime.call0()?.getattr(intern!(py, "tm_gmtoff"))?.extract()
-        }
+    /// The current time, from the `reference_time` validation argument, the validation context or the `clock`
+    /// config if they're set, in that order, in the UTC offset from the `now_utc_offset` field, or the offset of
+    /// an aware datetime from the clock, or the local offset at that time.
+    pub fn now(&self, py: Python, state: &ValidationState<'_, '_>) -> PyResult<DateTime> {
+        let reference_time = match state.reference_time {
+            Some(reference_time) => Some(reference_time),
+            None => self.context_time(state.extra().context)?,
+        };
+        self.now_at(py, reference_time)
+    }
+
+    /// The current time used to generate examples, like `now` without a `reference_time`.
+    pub fn example_now(&self, py: Python, generator: &ExampleGenerator) -> PyResult<DateTime> {
+        let context = generator.context(py);
+        self.now_at(py, self.context_time(context.as_ref())?)
     }
 
-    pub fn from_py(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+    fn now_at(&self, py: Python, reference_time: Option<(DateTime, Option<i32>)>) -> PyResult<DateTime> {
+        // whether the time is from the system clock, so the local offset is the current one
+        let (now, clock_offset, system_clock) = match reference_time {
+            Some((now, offset)) => (now, offset, false),
+            None => match &self.clock {
+                Some(clock) => {
+                    let (now, offset) = clock.now(py)?;
+                    (now, offset, false)
+                }
+                None => {
+                    let now = DateTime::now(0).map_err(|e| {
+                        py_schema_error_type!("DateTime::now() error: {}", e.get_documentation().unwrap_or("unknown"))
+                    })?;
+                    (now, None, true)
+                }
+            },
+        };
+        let offset = match self.utc_offset.or(clock_offset) {
+            Some(utc_offset) => utc_offset,
+            None => local_utc_offset(py, (!system_clock).then(|| now.timestamp()))?,
+        };
+        now.in_timezone(offset).map_err(|e| {
+            py_schema_error_type!(
+                "Invalid UTC offset {}: {}",
+                offset,
+                e.get_documentation().unwrap_or("unknown")
+            )
+        })
+    }
+
+    pub fn from_py(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Option<Self>> {
         let py = schema.py();
         match schema.get_as::<Bound<'_, PyString>>(intern!(py, "now_op"))? {
             Some(op) => Ok(Some(Self {
                 op: NowOp::from_str(op.to_str()?)?,
                 utc_offset: schema.get_as(intern!(py, "now_utc_offset"))?,
+                clock: Clock::from_config(py, config)?,
+                clock_context_key: config.get_as(intern!(py, "clock_context_key"))?,
             })),
             None => Ok(None),
         }
     }
+
+    /// The time in the validation context at the `clock_context_key` config, if there is one.
+    fn context_time(&self, context: Option<&Bound<'_, PyAny>>) -> PyResult<Option<(DateTime, Option<i32>)>> {
+        let (Some(key), Some(context)) = (&self.clock_context_key, context) else {
+            return Ok(None);
+        };
+        let Ok(context) = context.downcast::<PyDict>() else {
+            return Ok(None);
+        };
+        match context.get_item(key.as_str())? {
+            Some(time) => Ok(Some(clock_time(&time).map_err(|err| {
+                PyErr::from_type(
+                    err.get_type(time.py()),
+                    format!(
+                        "Invalid time in the validation context at '{key}': {}",
+                        err.value(time.py())
+                    ),
+                )
+            })?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// The time from the `reference_time` validation argument, used as the current time by `now_op` constraints.
+pub(super) fn reference_time(time: &Bound<'_, PyAny>) -> PyResult<(DateTime, Option<i32>)> {
+    clock_time(time).map_err(|err| {
+        PyErr::from_type(
+            err.get_type(time.py()),
+            format!("Invalid `reference_time`: {}", err.value(time.py())),
+        )
+    })
+}
+
+impl_py_gc_traverse!(NowConstraint { clock });
+
+/// Get the local UTC offset in seconds at `timestamp`, or now, by calling `time.localtime().tm_gmtoff`.
+/// Note: although the attribute is called "gmtoff", it is actually the offset in the UTC direction,
+/// hence no need to negate it.
+fn local_utc_offset(py: Python, timestamp: Option<i64>) -> PyResult<i32> {
+    let localtime = TIME_LOCALTIME.import(py, "time", "localtime")?;
+    let struct_time = match timestamp {
+        Some(timestamp) => localtime.call1((timestamp,))?,
+        None => localtime.call0()?,
+    };
+    struct_time.getattr(intern!(py, "tm_gmtoff"))?.extract()
+}
+
+/// The `clock` config, which replaces the system clock for `now_op` constraints, either a fixed time or a
+/// callable returning the time, the time is a datetime or a unix timestamp in seconds.
+#[derive(Debug, Clone)]
+enum Clock {
+    // the time in UTC, and the UTC offset of an aware datetime
+    Fixed(DateTime, Option<i32>),
+    Callable(Py<PyAny>),
+}
+
+impl Clock {
+    fn from_config(py: Python, config: Option<&Bound<'_, PyDict>>) -> PyResult<Option<Self>> {
+        match config.get_as::<Bound<'_, PyAny>>(intern!(py, "clock"))? {
+            None => Ok(None),
+            Some(clock) if clock.is_callable() => Ok(Some(Self::Callable(clock.unbind()))),
+            Some(clock) => {
+                let (now, offset) =
+                    clock_time(&clock).map_err(|err| py_schema_error_type!("Invalid `clock` config: {}", err))?;
+                Ok(Some(Self::Fixed(now, offset)))
+            }
+        }
+    }
+
+    fn now(&self, py: Python) -> PyResult<(DateTime, Option<i32>)> {
+        match self {
+            Self::Fixed(now, offset) => Ok((*now, *offset)),
+            Self::Callable(clock) => clock_time(&clock.bind(py).call0()?),
+        }
+    }
+}
+
+impl crate::py_gc::PyGcTraverse for Clock {
+    fn py_gc_traverse(&self, visit: &pyo3::PyVisit<'_>) -> Result<(), pyo3::PyTraverseError> {
+        match self {
+            Self::Fixed(..) => Ok(()),
+            Self::Callable(clock) => visit.call(clock),
+        }
+    }
+}
+
+/// The time in UTC from a datetime, naive datetimes are local time like `datetime.timestamp()`, or a timestamp,
+/// and the UTC offset of aware datetimes.
+fn clock_time(time: &Bound<'_, PyAny>) -> PyResult<(DateTime, Option<i32>)> {
+    let py = time.py();
+    let (timestamp, offset) = if let Ok(datetime) = time.downcast::<PyDateTime>() {
+        let offset: Option<Bound<'_, PyDelta>> = datetime.call_method0(intern!(py, "utcoffset"))?.extract()?;
+        let offset = offset.map(|offset| offset.get_days() * 86_400 + offset.get_seconds());
+        (
+            datetime.call_method0(intern!(py, "timestamp"))?.extract::<f64>()?,
+            offset,
+        )
+    } else if let Ok(timestamp) = time.extract::<f64>() {
+        (timestamp, None)
+    } else {
+        return Err(PyTypeError::new_err(format!(
+            "the clock should be a datetime or a timestamp, got {}",
+            time.get_type().name()?
+        )));
+    };
+    let seconds = timestamp.floor();
+    let microseconds = (((timestamp - seconds) * 1_000_000.0).round() as u32).min(999_999);
+    let mut now = DateTime::from_timestamp(seconds as i64, microseconds).map_err(|e| {
+        PyValueError::new_err(format!(
+            "invalid clock time: {}",
+            e.get_documentation().unwrap_or("unknown")
+        ))
+    })?;
+    now.time.tz_offset = Some(0);
+    Ok((now, offset))
 }
 
 #[derive(Debug, Clone)]
@@ -336,3 +613,60 @@ impl TZConstraint {
         Ok(())
     }
 }
+
+static ZONE_INFO_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+
+/// A `tz_constraint` naming an IANA time zone, aware datetimes must have the zone's UTC offset at their time, so
+/// offsets from either side of a DST transition are accepted at the right times.
+#[derive(Debug, Clone)]
+struct ZoneConstraint {
+    name: String,
+    zone: Py<PyTzInfo>,
+}
+
+impl ZoneConstraint {
+    fn from_py(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let name = match schema.get_as::<String>(intern!(py, "tz_constraint")) {
+            Ok(Some(name)) if name != "naive" && name != "aware" => name,
+            _ => return Ok(None),
+        };
+        let zone = ZONE_INFO_TYPE
+            .import(py, "zoneinfo", "ZoneInfo")?
+            .call1((&name,))
+            .map_err(|err| {
+                py_schema_error_type!(
+                    "Invalid tz_constraint {:?}, expected 'aware', 'naive', an offset or an IANA time zone name: {}",
+                    name,
+                    py_err_string(py, err)
+                )
+            })?;
+        Ok(Some(Self {
+            name,
+            zone: zone.downcast_into::<PyTzInfo>()?.unbind(),
+        }))
+    }
+
+    fn check(&self, py: Python, datetime: &DateTime, input: impl ToErrorValue) -> ValResult<()> {
+        let Some(tz_actual) = datetime.time.tz_offset else {
+            return Err(ValError::new(ErrorTypeDefaults::TimezoneAware, input));
+        };
+        let local = PyDateTime::from_timestamp(py, datetime.timestamp_tz() as f64, Some(self.zone.bind(py)))?;
+        let offset: Bound<'_, PyDelta> = local.call_method0(intern!(py, "utcoffset"))?.extract()?;
+        let tz_expected = offset.get_days() * 86_400 + offset.get_seconds();
+        if tz_expected != tz_actual {
+            return Err(ValError::new(
+                ErrorType::TimezoneName {
+                    tz_name: self.name.clone(),
+                    tz_expected,
+                    tz_actual,
+                    context: None,
+                },
+                input,
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl_py_gc_traverse!(ZoneConstraint { zone });
@@ -0,0 +1,504 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use speedate::{Date, DateTime, Time};
+
+use crate::build_tools::py_schema_err;
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::{Input, InputType};
+use crate::tools::SchemaDict;
+
+use super::{Exactness, ValidationState};
+
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+const WEEKDAYS: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+/// The schema a format is used by, which decides the directives it can contain and the error type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum FormatKind {
+    Date,
+    Time,
+    Datetime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Year,
+    Month,
+    Day,
+    Weekday,
+    Hour,
+    AmPm,
+    Minute,
+    Second,
+    Fraction,
+    Offset,
+}
+
+impl Field {
+    fn from_directive(directive: char) -> Option<Self> {
+        match directive {
+            'Y' | 'y' => Some(Self::Year),
+            'm' | 'b' | 'B' => Some(Self::Month),
+            'd' => Some(Self::Day),
+            'a' | 'A' => Some(Self::Weekday),
+            'H' | 'I' => Some(Self::Hour),
+            'p' => Some(Self::AmPm),
+            'M' => Some(Self::Minute),
+            'S' => Some(Self::Second),
+            'f' => Some(Self::Fraction),
+            'z' => Some(Self::Offset),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Year => "year",
+            Self::Month => "month",
+            Self::Day => "day",
+            Self::Weekday => "weekday",
+            Self::Hour => "hour",
+            Self::AmPm => "AM/PM",
+            Self::Minute => "minute",
+            Self::Second => "second",
+            Self::Fraction => "fractional second",
+            Self::Offset => "timezone offset",
+        }
+    }
+
+    fn is_date(self) -> bool {
+        matches!(self, Self::Year | Self::Month | Self::Day | Self::Weekday)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Item {
+    Literal(char),
+    // any whitespace in the format matches one or more whitespace characters, like `strptime`
+    Whitespace,
+    Directive(char),
+}
+
+/// A `strptime`-style format used to parse strings instead of ISO 8601.
+///
+/// The supported directives are `%Y`, `%y`, `%m`, `%b`, `%B`, `%d`, `%a`, `%A`, `%H`, `%I`, `%p`, `%M`, `%S`,
+/// `%f`, `%z` and `%%`. Month and weekday names are English and matched case-insensitively, weekdays aren't checked
+/// against the date.
+#[derive(Debug, Clone)]
+pub(super) struct DateTimeFormat {
+    format: String,
+    kind: FormatKind,
+    items: Vec<Item>,
+}
+
+impl DateTimeFormat {
+    pub fn from_py(schema: &Bound<'_, PyDict>, kind: FormatKind) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let Some(format) = schema.get_as::<String>(intern!(py, "format"))? else {
+            return Ok(None);
+        };
+        let mut items = Vec::new();
+        let mut fields: Vec<Field> = Vec::new();
+        let mut chars = format.chars();
+        while let Some(c) = chars.next() {
+            if c == '%' {
+                let Some(directive) = chars.next() else {
+                    return py_schema_err!("`format` can't end with a single '%'");
+                };
+                if directive == '%' {
+                    items.push(Item::Literal('%'));
+                    continue;
+                }
+                let Some(field) = Field::from_directive(directive) else {
+                    return py_schema_err!(
+                        "Invalid directive '%{}' in `format`, expected one of %Y, %y, %m, %b, %B, %d, %a, %A, %H, \
+                         %I, %p, %M, %S, %f, %z or %%",
+                        directive
+                    );
+                };
+                match kind {
+                    FormatKind::Date if !field.is_date() => {
+                        return py_schema_err!("`format` can't contain '%{}' for a date", directive);
+                    }
+                    FormatKind::Time if field.is_date() => {
+                        return py_schema_err!("`format` can't contain '%{}' for a time", directive);
+                    }
+                    _ => (),
+                }
+                if fields.contains(&field) {
+                    return py_schema_err!("`format` has more than one directive for the {}", field.name());
+                }
+                fields.push(field);
+                items.push(Item::Directive(directive));
+            } else if c.is_whitespace() {
+                if !matches!(items.last(), Some(Item::Whitespace)) {
+                    items.push(Item::Whitespace);
+                }
+            } else {
+                items.push(Item::Literal(c));
+            }
+        }
+
+        let required: &[Field] = match kind {
+            FormatKind::Date | FormatKind::Datetime => &[Field::Year, Field::Month, Field::Day],
+            FormatKind::Time => &[Field::Hour],
+        };
+        for field in required {
+            if !fields.contains(field) {
+                return py_schema_err!("`format` should contain a directive for the {}", field.name());
+            }
+        }
+        let twelve_hour = items.iter().any(|item| matches!(item, Item::Directive('I')));
+        if twelve_hour && !fields.contains(&Field::AmPm) {
+            return py_schema_err!("`format` can't contain '%I' without '%p'");
+        } else if !twelve_hour && fields.contains(&Field::AmPm) {
+            return py_schema_err!("`format` can't contain '%p' without '%I'");
+        }
+        Ok(Some(Self { format, kind, items }))
+    }
+
+    /// Parse a string input with the format, `Ok(None)` means the input isn't a string, or strings aren't allowed
+    /// in strict mode, so it should be validated as usual.
+    pub fn validate<'py>(
+        &self,
+        input: &(impl Input<'py> + ?Sized),
+        strict: bool,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Option<DateTime>> {
+        let input_type = state.extra().input_type;
+        // like ISO 8601 strings, a Python `str` is only a valid datetime in lax mode
+        if strict && input_type == InputType::Python {
+            return Ok(None);
+        }
+        let Ok(either_str) = input.validate_str(strict, false) else {
+            return Ok(None);
+        };
+        let either_str = either_str.into_inner();
+        let datetime = self.parse(either_str.as_cow()?.as_ref()).map_err(|error| {
+            let format = self.format.clone();
+            let error_type = match self.kind {
+                FormatKind::Date => ErrorType::DateFormat {
+                    format,
+                    error,
+                    context: None,
+                },
+                FormatKind::Time => ErrorType::TimeFormat {
+                    format,
+                    error,
+                    context: None,
+                },
+                FormatKind::Datetime => ErrorType::DatetimeFormat {
+                    format,
+                    error,
+                    context: None,
+                },
+            };
+            ValError::new(error_type, input)
+        })?;
+        if input_type != InputType::Json {
+            state.floor_exactness(Exactness::Lax);
+        }
+        Ok(Some(datetime))
+    }
+
+    fn parse(&self, s: &str) -> Result<DateTime, String> {
+        let position = |rest: &str| s[..s.len() - rest.len()].chars().count();
+        let mut rest = s;
+        let mut date = Date {
+            year: 1900,
+            month: 1,
+            day: 1,
+        };
+        let mut time = Time {
+            hour: 0,
+            minute: 0,
+            second: 0,
+            microsecond: 0,
+            tz_offset: None,
+        };
+        let mut pm = false;
+
+        for item in &self.items {
+            match item {
+                Item::Literal(c) => match rest.strip_prefix(*c) {
+                    Some(r) => rest = r,
+                    None => return Err(format!("expected '{c}' at position {}", position(rest))),
+                },
+                Item::Whitespace => {
+                    let r = rest.trim_start();
+                    if r.len() == rest.len() {
+                        return Err(format!("expected whitespace at position {}", position(rest)));
+                    }
+                    rest = r;
+                }
+                Item::Directive(directive) => {
+                    let Some((value, r)) = parse_directive(*directive, rest) else {
+                        return Err(format!(
+                            "expected {} at position {}",
+                            describe_directive(*directive),
+                            position(rest)
+                        ));
+                    };
+                    match directive {
+                        'Y' => date.year = value as u16,
+                        // like `strptime`, 69 to 99 are in the 1900s and 0 to 68 in the 2000s
+                        'y' => date.year = (if value >= 69 { 1900 + value } else { 2000 + value }) as u16,
+                        'm' | 'b' | 'B' => date.month = value as u8,
+                        'd' => date.day = value as u8,
+                        'H' | 'I' => time.hour = value as u8,
+                        'p' => pm = value == 1,
+                        'M' => time.minute = value as u8,
+                        'S' => time.second = value as u8,
+                        'f' => time.microsecond = value as u32,
+                        'z' => time.tz_offset = Some(value),
+                        _ => (),
+                    }
+                    rest = r;
+                }
+            }
+        }
+        if !rest.is_empty() {
+            return Err(format!("unexpected extra characters at position {}", position(rest)));
+        }
+
+        if date.year == 0 {
+            return Err("year 0 is out of range".to_string());
+        }
+        if !(1..=12).contains(&date.month) {
+            return Err("month value is outside expected range".to_string());
+        }
+        if !(1..=days_in_month(date.year, date.month)).contains(&date.day) {
+            return Err("day value is outside expected range".to_string());
+        }
+        if self.items.iter().any(|item| matches!(item, Item::Directive('I'))) {
+            if !(1..=12).contains(&time.hour) {
+                return Err("hour value is outside expected range".to_string());
+            }
+            time.hour = time.hour % 12 + if pm { 12 } else { 0 };
+        } else if time.hour > 23 {
+            return Err("hour value is outside expected range".to_string());
+        }
+        if time.minute > 59 {
+            return Err("minute value is outside expected range".to_string());
+        }
+        if time.second > 59 {
+            return Err("second value is outside expected range".to_string());
+        }
+        Ok(DateTime { date, time })
+    }
+}
+
+/// Parse the value of a directive from the start of `s`, returning it and the rest of the string.
+///
+/// Names are returned as numbers, e.g. months from 1, AM as 0 and PM as 1, and timezone offsets in seconds.
+fn parse_directive(directive: char, s: &str) -> Option<(i32, &str)> {
+    match directive {
+        'Y' => parse_digits(s, 4, 4),
+        'y' => parse_digits(s, 2, 2),
+        'm' | 'd' | 'H' | 'I' | 'M' | 'S' => parse_digits(s, 1, 2),
+        'b' => parse_name(s, &MONTHS, true).map(|(index, rest)| (index + 1, rest)),
+        'B' => parse_name(s, &MONTHS, false).map(|(index, rest)| (index + 1, rest)),
+        'a' => parse_name(s, &WEEKDAYS, true),
+        'A' => parse_name(s, &WEEKDAYS, false),
+        'p' => parse_name(s, &["am", "pm"], false),
+        'f' => {
+            let (value, rest) = parse_digits(s, 1, 6)?;
+            let digits = s.len() - rest.len();
+            Some((value * 10_i32.pow(6 - digits as u32), rest))
+        }
+        'z' => parse_offset(s),
+        _ => None,
+    }
+}
+
+fn describe_directive(directive: char) -> &'static str {
+    match directive {
+        'Y' => "a 4 digit year",
+        'y' => "a 2 digit year",
+        'm' => "a month",
+        'b' => "an abbreviated month name",
+        'B' => "a month name",
+        'd' => "a day",
+        'a' => "an abbreviated weekday name",
+        'A' => "a weekday name",
+        'H' | 'I' => "an hour",
+        'p' => "AM or PM",
+        'M' => "a minute",
+        'S' => "a second",
+        'f' => "fractional seconds",
+        _ => "a timezone offset",
+    }
+}
+
+/// Parse between `min` and `max` ASCII digits from the start of `s`.
+fn parse_digits(s: &str, min: usize, max: usize) -> Option<(i32, &str)> {
+    let count = s.bytes().take(max).take_while(u8::is_ascii_digit).count();
+    if count < min {
+        return None;
+    }
+    let value = s[..count].bytes().fold(0, |value, b| value * 10 + i32::from(b - b'0'));
+    Some((value, &s[count..]))
+}
+
+/// Match one of the lower case `names`, or their first three letters when `abbreviated`, case-insensitively,
+/// returning its index.
+fn parse_name<'s>(s: &'s str, names: &[&str], abbreviated: bool) -> Option<(i32, &'s str)> {
+    names.iter().enumerate().find_map(|(index, name)| {
+        let name = if abbreviated { &name[..3] } else { name };
+        let prefix = s.get(..name.len())?;
+        prefix
+            .eq_ignore_ascii_case(name)
+            .then(|| (index as i32, &s[name.len()..]))
+    })
+}
+
+/// Parse `Z`, `±HH:MM` or `±HHMM` as an offset in seconds.
+fn parse_offset(s: &str) -> Option<(i32, &str)> {
+    if let Some(rest) = s.strip_prefix('Z') {
+        return Some((0, rest));
+    }
+    let sign = match s.as_bytes().first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let (hours, rest) = parse_digits(&s[1..], 2, 2)?;
+    let rest = rest.strip_prefix(':').unwrap_or(rest);
+    let (minutes, rest) = parse_digits(rest, 2, 2)?;
+    if hours >= 24 || minutes >= 60 {
+        return None;
+    }
+    Some((sign * (hours * 3600 + minutes * 60), rest))
+}
+
+/// Parse an RFC 2822 date like `Tue, 1 Jul 2003 10:52:37 +0200`, which includes the IMF-fixdate of HTTP headers,
+/// `Sun, 06 Nov 1994 08:49:37 GMT`.
+///
+/// The obsolete syntax is accepted too, two and three digit years, leaving out the seconds and zone names like
+/// `EST`, but not military zones or comments. The weekday, if given, should be the weekday of the date.
+pub(super) fn parse_rfc2822(s: &str) -> Option<DateTime> {
+    let mut rest = s.trim();
+    let mut weekday = None;
+    if let Some((index, r)) = parse_name(rest, &WEEKDAYS, true) {
+        weekday = Some(index);
+        rest = r.trim_start().strip_prefix(',')?.trim_start();
+    }
+    let (day, r) = parse_digits(rest, 1, 2)?;
+    let (month, r) = parse_name(skip_whitespace(r)?, &MONTHS, true)?;
+    let year_str = skip_whitespace(r)?;
+    let (year, r) = parse_digits(year_str, 2, 4)?;
+    let year = match year_str.len() - r.len() {
+        // like `strptime`, 0 to 49 are in the 2000s and 50 to 99 in the 1900s
+        2 if year < 50 => 2000 + year,
+        2 | 3 => 1900 + year,
+        _ => year,
+    };
+    let (hour, r) = parse_digits(skip_whitespace(r)?, 2, 2)?;
+    let (minute, r) = parse_digits(r.strip_prefix(':')?, 2, 2)?;
+    let (second, r) = match r.strip_prefix(':') {
+        Some(r) => parse_digits(r, 2, 2)?,
+        None => (0, r),
+    };
+    let (tz_offset, r) = parse_zone(skip_whitespace(r)?)?;
+    if !r.trim_end().is_empty() {
+        return None;
+    }
+
+    let date = Date {
+        year: year as u16,
+        month: month as u8 + 1,
+        day: day as u8,
+    };
+    if year == 0 || !(1..=days_in_month(date.year, date.month)).contains(&date.day) {
+        return None;
+    }
+    if weekday.is_some_and(|weekday| weekday != weekday_from_monday(date)) || hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+    let time = Time {
+        hour: hour as u8,
+        minute: minute as u8,
+        second: second as u8,
+        microsecond: 0,
+        tz_offset: Some(tz_offset),
+    };
+    Some(DateTime { date, time })
+}
+
+/// Skip at least one whitespace character.
+fn skip_whitespace(s: &str) -> Option<&str> {
+    let rest = s.trim_start();
+    (rest.len() < s.len()).then_some(rest)
+}
+
+/// Parse an RFC 2822 zone, `±HHMM` or one of the obsolete names, as an offset in seconds.
+fn parse_zone(s: &str) -> Option<(i32, &str)> {
+    const NAMES: [(&str, i32); 11] = [
+        ("gmt", 0),
+        ("ut", 0),
+        ("z", 0),
+        ("edt", -4),
+        ("est", -5),
+        ("cdt", -5),
+        ("cst", -6),
+        ("mdt", -6),
+        ("mst", -7),
+        ("pdt", -7),
+        ("pst", -8),
+    ];
+    let sign = match s.as_bytes().first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => {
+            let (index, rest) = parse_name(s, &NAMES.map(|(name, _)| name), false)?;
+            return Some((NAMES[index as usize].1 * 3600, rest));
+        }
+    };
+    let (hours, rest) = parse_digits(&s[1..], 2, 2)?;
+    let (minutes, rest) = parse_digits(rest, 2, 2)?;
+    if hours >= 24 || minutes >= 60 {
+        return None;
+    }
+    Some((sign * (hours * 3600 + minutes * 60), rest))
+}
+
+/// The weekday of a date, from 0 for Monday, using Sakamoto's method.
+fn weekday_from_monday(date: Date) -> i32 {
+    const MONTH_OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let year = i32::from(date.year) - i32::from(date.month < 3);
+    let from_sunday =
+        (year + year / 4 - year / 100 + year / 400 + MONTH_OFFSETS[usize::from(date.month - 1)] + i32::from(date.day))
+            % 7;
+    (from_sunday + 6) % 7
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
@@ -6,15 +6,16 @@ use pyo3::sync::PyOnceLock;
 use pyo3::types::{IntoPyDict, PyDict, PyString, PyTuple, PyType};
 use pyo3::{prelude::*, PyTypeInfo};
 
-use crate::build_tools::{is_strict, schema_or_config_same};
+use crate::build_tools::{is_strict, py_schema_err, schema_or_config_same, DigitSeparators};
 use crate::errors::ErrorType;
 use crate::errors::ValResult;
 use crate::errors::{ErrorTypeDefaults, Number};
-use crate::errors::{ToErrorValue, ValError};
-use crate::input::Input;
+use crate::errors::{ToErrorValue, ValError, ValLineError};
+use crate::input::{Input, ValidationMatch};
 use crate::tools::SchemaDict;
 
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::units::Units;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState, Validator};
 
 static DECIMAL_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
 
@@ -46,6 +47,119 @@ fn validate_as_decimal(
     }
 }
 
+/// The rounding modes of the `decimal` module, whose constants are these strings.
+const ROUNDING_MODES: &[&str] = &[
+    "ROUND_HALF_EVEN",
+    "ROUND_HALF_UP",
+    "ROUND_HALF_DOWN",
+    "ROUND_UP",
+    "ROUND_DOWN",
+    "ROUND_CEILING",
+    "ROUND_FLOOR",
+    "ROUND_05UP",
+];
+
+/// The most digits a quantized decimal can have, the same as the default limit on converting ints to strings;
+/// values which would have more fail to quantize instead of being built up to any size.
+const MAX_QUANTIZED_DIGITS: u64 = 4300;
+
+/// Rounding of decimals, from the `rounding` and `quantize_to` keys of a decimal schema, shared by the validator
+/// and the serializer.
+#[derive(Debug, Clone)]
+pub(crate) struct Quantize {
+    exp: Py<PyAny>,
+    // the exponent of `exp`, values with a smaller exponent have too many decimal places and are rounded
+    exponent: i64,
+    rounding: Py<PyString>,
+    // a context with the precision of `MAX_QUANTIZED_DIGITS`, so rounding isn't limited by the current context's
+    context: Py<PyAny>,
+    // whether values with fewer decimal places are also quantized, i.e. padded with zeros, as with `quantize_to`
+    pad: bool,
+}
+
+impl Quantize {
+    pub fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let rounding = schema.get_as::<Bound<'_, PyString>>(intern!(py, "rounding"))?;
+        if let Some(rounding) = &rounding {
+            if !ROUNDING_MODES.contains(&rounding.to_str()?) {
+                return py_schema_err!(
+                    "Invalid rounding: `{}`, expected one of {}",
+                    rounding,
+                    ROUNDING_MODES.join(", ")
+                );
+            }
+        }
+        let rounding = rounding
+            .unwrap_or_else(|| intern!(py, "ROUND_HALF_EVEN").clone())
+            .unbind();
+        let context = py
+            .import(intern!(py, "decimal"))?
+            .getattr(intern!(py, "Context"))?
+            .call(
+                (),
+                Some(&[(intern!(py, "prec"), MAX_QUANTIZED_DIGITS)].into_py_dict(py)?),
+            )?
+            .unbind();
+
+        if let Some(exp) = validate_as_decimal(py, schema, intern!(py, "quantize_to"))? {
+            let exponent = exp
+                .bind(py)
+                .call_method0(intern!(py, "as_tuple"))?
+                .getattr(intern!(py, "exponent"))?;
+            let Ok(exponent) = exponent.extract() else {
+                return py_schema_err!("'quantize_to' must be a finite Decimal");
+            };
+            return Ok(Some(Self {
+                exp,
+                exponent,
+                rounding,
+                context,
+                pad: true,
+            }));
+        }
+        if !schema.contains(intern!(py, "rounding"))? {
+            return Ok(None);
+        }
+        let Some(decimal_places) = schema.get_as::<i64>(intern!(py, "decimal_places"))? else {
+            return py_schema_err!("`rounding` requires `decimal_places` or `quantize_to`");
+        };
+        let exp = get_decimal_type(py)
+            .call1((1,))?
+            .call_method1(intern!(py, "scaleb"), (-decimal_places,))?;
+        Ok(Some(Self {
+            exp: exp.unbind(),
+            exponent: -decimal_places,
+            rounding,
+            context,
+            pad: false,
+        }))
+    }
+
+    /// Round the decimal, infinite and NaN decimals are returned unchanged. Fails if the result would have more
+    /// than `MAX_QUANTIZED_DIGITS` digits.
+    pub fn apply<'py>(&self, decimal: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let py = decimal.py();
+        let exponent = decimal
+            .call_method0(intern!(py, "as_tuple"))?
+            .getattr(intern!(py, "exponent"))?;
+        let Ok(exponent) = exponent.extract::<i64>() else {
+            return Ok(decimal.clone());
+        };
+        if !self.pad && exponent >= self.exponent {
+            return Ok(decimal.clone());
+        }
+        let kwargs = [
+            (intern!(py, "rounding"), self.rounding.bind(py).as_any()),
+            (intern!(py, "context"), self.context.bind(py)),
+        ]
+        .into_py_dict(py)?;
+        decimal.call_method(intern!(py, "quantize"), (self.exp.bind(py),), Some(&kwargs))
+    }
+}
+
+impl_py_gc_traverse!(Quantize { exp, rounding, context });
+
 #[derive(Debug, Clone)]
 pub struct DecimalValidator {
     strict: bool,
@@ -58,6 +172,9 @@ pub struct DecimalValidator {
     gt: Option<Py<PyAny>>,
     max_digits: Option<u64>,
     decimal_places: Option<u64>,
+    quantize: Option<Quantize>,
+    digit_separators: Option<DigitSeparators>,
+    units: Option<Units>,
 }
 
 impl BuildValidator for DecimalValidator {
@@ -89,17 +206,45 @@ impl BuildValidator for DecimalValidator {
             ge: validate_as_decimal(py, schema, intern!(py, "ge"))?,
             gt: validate_as_decimal(py, schema, intern!(py, "gt"))?,
             max_digits,
+            quantize: Quantize::from_schema(schema)?,
+            digit_separators: DigitSeparators::from_schema(schema)?,
+            units: Units::from_schema(schema, true)?,
         })
         .into())
     }
 }
 
+impl DecimalValidator {
+    /// Parse a string which isn't a valid decimal with `digit_separators` or `unit`, returning `error` if neither
+    /// applies.
+    fn parse_lax_str<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        error: ValError,
+    ) -> ValResult<Bound<'py, PyAny>> {
+        if let Some(stripped) = self
+            .digit_separators
+            .as_ref()
+            .and_then(|separators| separators.strip(input))
+        {
+            return create_decimal(PyString::new(py, &stripped).as_any(), input);
+        }
+        match &self.units {
+            Some(units) => units.convert_decimal(py, input, error),
+            None => Err(error),
+        }
+    }
+}
+
 impl_py_gc_traverse!(DecimalValidator {
     multiple_of,
     le,
     lt,
     ge,
-    gt
+    gt,
+    quantize,
+    units
 });
 
 fn extract_decimal_digits_info(decimal: &Bound<'_, PyAny>, normalized: bool) -> ValResult<(u64, u64)> {
@@ -142,7 +287,28 @@ impl Validator for DecimalValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        let decimal = input.validate_decimal(state.strict_or(self.strict), py)?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        let is_parsing_error = |errors: &[ValLineError]| match errors {
+            [error] => matches!(error.error_type, ErrorType::DecimalParsing { .. }),
+            _ => false,
+        };
+        let mut decimal = match input.validate_decimal(strict, py) {
+            Err(ValError::LineErrors(errors)) if !strict && is_parsing_error(&errors) => {
+                ValidationMatch::lax(self.parse_lax_str(py, input, ValError::LineErrors(errors))?).unpack(state)
+            }
+            result => result?.unpack(state),
+        };
+        if let Some(quantize) = &self.quantize {
+            decimal = quantize.apply(&decimal).map_err(|_| {
+                ValError::new(
+                    ErrorType::DecimalMaxDigits {
+                        max_digits: MAX_QUANTIZED_DIGITS,
+                        context: None,
+                    },
+                    input,
+                )
+            })?;
+        }
 
         if !self.allow_inf_nan || self.check_digits {
             if !decimal.call_method0(intern!(py, "is_finite"))?.extract()? {
@@ -153,7 +319,7 @@ impl Validator for DecimalValidator {
                 if let Ok((normalized_decimals, normalized_digits)) = extract_decimal_digits_info(&decimal, true) {
                     if let Ok((decimals, digits)) = extract_decimal_digits_info(&decimal, false) {
                         if let Some(max_digits) = self.max_digits {
-                            if (digits > max_digits) & (normalized_digits > max_digits) {
+                            if (digits > max_digits) && (normalized_digits > max_digits) {
                                 return Err(ValError::new(
                                     ErrorType::DecimalMaxDigits {
                                         max_digits,
@@ -165,7 +331,7 @@ impl Validator for DecimalValidator {
                         }
 
                         if let Some(decimal_places) = self.decimal_places {
-                            if (decimals > decimal_places) & (normalized_decimals > decimal_places) {
+                            if (decimals > decimal_places) && (normalized_decimals > decimal_places) {
                                 return Err(ValError::new(
                                     ErrorType::DecimalMaxPlaces {
                                         decimal_places,
@@ -183,7 +349,7 @@ impl Validator for DecimalValidator {
                                 let normalized_max_whole_digits = max_digits.saturating_sub(decimal_places);
 
                                 if (whole_digits > max_whole_digits)
-                                    & (normalized_whole_digits > normalized_max_whole_digits)
+                                    && (normalized_whole_digits > normalized_max_whole_digits)
                                 {
                                     return Err(ValError::new(
                                         ErrorType::DecimalWholeDigits {
@@ -274,6 +440,51 @@ impl Validator for DecimalValidator {
         Ok(decimal.into())
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        let bound = |bound: &Option<Py<PyAny>>| -> PyResult<Option<f64>> {
+            bound.as_ref().map(|b| b.bind(py).extract::<f64>()).transpose()
+        };
+        let tighter = |a: Option<f64>, b: Option<f64>, a_tighter: fn(f64, f64) -> bool| match (a, b) {
+            (Some(a), Some(b)) if !a_tighter(a, b) => Some((b, true)),
+            (Some(a), _) => Some((a, false)),
+            (None, b) => b.map(|b| (b, true)),
+        };
+        let mut lower = tighter(bound(&self.ge)?, bound(&self.gt)?, |ge, gt| ge > gt);
+        let mut upper = tighter(bound(&self.le)?, bound(&self.lt)?, |le, lt| le < lt);
+        // two decimal places, unless `decimal_places` or `max_digits` say otherwise
+        let places = match (self.decimal_places, self.max_digits) {
+            (Some(places), _) => Some(places),
+            (None, Some(max_digits)) => Some(max_digits.saturating_sub(1).min(2)),
+            (None, None) => None,
+        };
+        if let Some(max_digits) = self.max_digits {
+            // the largest value with `places` decimal places within `max_digits`
+            let places = i32::try_from(places.unwrap_or(0)).unwrap_or(i32::MAX);
+            let whole = i32::try_from(max_digits).unwrap_or(i32::MAX).saturating_sub(places);
+            let largest = 10f64.powi(whole) - 10f64.powi(-places);
+            if upper.map_or(true, |(upper, _)| upper > largest) {
+                upper = Some((largest, false));
+            }
+            if lower.map_or(true, |(lower, _)| lower < -largest) {
+                lower = Some((-largest, false));
+            }
+        }
+        let decimal_type = get_decimal_type(py);
+        if let Some(multiple_of) = &self.multiple_of {
+            // a multiple of the decimal itself, so e.g. multiples of `0.1` are exact
+            let multiple_of = multiple_of.bind(py);
+            let value = generator.float(lower, upper, Some(multiple_of.extract()?));
+            let factor = (value / multiple_of.extract::<f64>()?).round() as i64;
+            return decimal_type.call1((factor,))?.mul(multiple_of);
+        }
+        let value = generator.float(lower, upper, None);
+        let value = match places {
+            Some(places) => format!("{value:.*}", places as usize),
+            None => value.to_string(),
+        };
+        decimal_type.call1((value,))
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -0,0 +1,175 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::sync::PyOnceLock;
+use pyo3::types::{
+    PyBool, PyBytes, PyComplex, PyDate, PyDateTime, PyDelta, PyDict, PyFloat, PyFrozenSet, PyInt, PyList, PySet,
+    PyString, PyTime, PyTuple, PyType,
+};
+
+use crate::recursion_guard::RECURSION_GUARD_LIMIT;
+
+use super::model::{
+    create_class, force_setattr, DUNDER_DICT, DUNDER_FIELDS_SET_KEY, DUNDER_MODEL_EXTRA_KEY, DUNDER_MODEL_PRIVATE_KEY,
+};
+
+static COPY_DEEPCOPY: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+
+/// Deep copy `obj`, with the same semantics as `copy.deepcopy(obj, memo)`.
+///
+/// Containers and model instances are copied in Rust, immutable leaves are reused rather than copied,
+/// anything else (and anything nested deeper than the recursion limit) is passed to `copy.deepcopy`.
+/// Nested model instances whose class defines `__deepcopy__` are copied by calling it, `obj` itself is always
+/// copied directly so `deep_copy` can be called from its own `__deepcopy__`.
+/// `memo` is a `copy.deepcopy` memo dict, mapping `id()` of the original objects to their copies.
+pub(super) fn deep_copy<'py>(obj: &Bound<'py, PyAny>, memo: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyAny>> {
+    DeepCopier { memo, depth: 0 }.copy(obj)
+}
+
+struct DeepCopier<'a, 'py> {
+    memo: &'a Bound<'py, PyDict>,
+    depth: u8,
+}
+
+impl<'py> DeepCopier<'_, 'py> {
+    fn copy(&mut self, obj: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        if is_immutable_leaf(obj) {
+            return Ok(obj.clone());
+        }
+        let id = obj.as_ptr() as usize;
+        if let Some(copied) = self.memo.get_item(id)? {
+            return Ok(copied);
+        }
+        if self.depth >= RECURSION_GUARD_LIMIT {
+            return self.python_deep_copy(obj);
+        }
+
+        self.depth += 1;
+        let result = self.copy_container(obj, id);
+        self.depth -= 1;
+        result
+    }
+
+    fn copy_container(&mut self, obj: &Bound<'py, PyAny>, id: usize) -> PyResult<Bound<'py, PyAny>> {
+        let py = obj.py();
+        if let Ok(dict) = obj.downcast_exact::<PyDict>() {
+            let new_dict = PyDict::new(py);
+            self.memo.set_item(id, &new_dict)?;
+            for (key, value) in dict {
+                new_dict.set_item(self.copy(&key)?, self.copy(&value)?)?;
+            }
+            Ok(new_dict.into_any())
+        } else if let Ok(list) = obj.downcast_exact::<PyList>() {
+            let new_list = PyList::empty(py);
+            self.memo.set_item(id, &new_list)?;
+            for item in list {
+                new_list.append(self.copy(&item)?)?;
+            }
+            Ok(new_list.into_any())
+        } else if let Ok(set) = obj.downcast_exact::<PySet>() {
+            let new_set = PySet::empty(py)?;
+            self.memo.set_item(id, &new_set)?;
+            for item in set {
+                new_set.add(self.copy(&item)?)?;
+            }
+            Ok(new_set.into_any())
+        } else if let Ok(tuple) = obj.downcast_exact::<PyTuple>() {
+            let items = self.copy_items(tuple.iter())?;
+            self.memoize_immutable(obj, id, items.map(|items| PyTuple::new(py, items)).transpose()?)
+        } else if let Ok(frozen_set) = obj.downcast_exact::<PyFrozenSet>() {
+            let items = self.copy_items(frozen_set.iter())?;
+            self.memoize_immutable(obj, id, items.map(|items| PyFrozenSet::new(py, &items)).transpose()?)
+        } else if is_model_instance(obj)? {
+            // `depth` is 1 for `obj` itself
+            if self.depth > 1 && obj.get_type().hasattr(intern!(py, "__deepcopy__"))? {
+                let copied = obj.call_method1(intern!(py, "__deepcopy__"), (self.memo,))?;
+                self.memo.set_item(id, &copied)?;
+                Ok(copied)
+            } else {
+                self.copy_model(obj, id)
+            }
+        } else {
+            self.python_deep_copy(obj)
+        }
+    }
+
+    /// Copy the items of an immutable container, `None` if every item is unchanged by copying so the
+    /// original container can be reused.
+    fn copy_items(
+        &mut self,
+        iter: impl Iterator<Item = Bound<'py, PyAny>>,
+    ) -> PyResult<Option<Vec<Bound<'py, PyAny>>>> {
+        let mut changed = false;
+        let items = iter
+            .map(|item| {
+                let copied = self.copy(&item)?;
+                changed |= !copied.is(&item);
+                Ok(copied)
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(changed.then_some(items))
+    }
+
+    fn memoize_immutable<T>(
+        &self,
+        obj: &Bound<'py, PyAny>,
+        id: usize,
+        new_obj: Option<Bound<'py, T>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        // copying an item may have already copied this container via a reference cycle
+        if let Some(copied) = self.memo.get_item(id)? {
+            return Ok(copied);
+        }
+        let copied = new_obj.map_or_else(|| obj.clone(), Bound::into_any);
+        self.memo.set_item(id, &copied)?;
+        Ok(copied)
+    }
+
+    fn copy_model(&mut self, obj: &Bound<'py, PyAny>, id: usize) -> PyResult<Bound<'py, PyAny>> {
+        let py = obj.py();
+        let new_obj = create_class(&obj.get_type())?;
+        self.memo.set_item(id, &new_obj)?;
+        if let Some(dict) = obj.getattr_opt(intern!(py, DUNDER_DICT))? {
+            force_setattr(py, &new_obj, intern!(py, DUNDER_DICT), self.copy(&dict)?)?;
+        }
+        for key in [DUNDER_MODEL_EXTRA_KEY, DUNDER_MODEL_PRIVATE_KEY] {
+            if let Some(value) = obj.getattr_opt(key)? {
+                force_setattr(py, &new_obj, key, self.copy(&value)?)?;
+            }
+        }
+        if let Some(fields_set) = obj.getattr_opt(intern!(py, DUNDER_FIELDS_SET_KEY))? {
+            // field names are strings, so a shallow copy is enough
+            let fields_set = PySet::new(py, fields_set.try_iter()?.collect::<PyResult<Vec<_>>>()?)?;
+            force_setattr(py, &new_obj, intern!(py, DUNDER_FIELDS_SET_KEY), fields_set)?;
+        }
+        Ok(new_obj)
+    }
+
+    fn python_deep_copy(&self, obj: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let py = obj.py();
+        let deepcopy = COPY_DEEPCOPY.get_or_try_init(py, || -> PyResult<Py<PyAny>> {
+            Ok(py.import("copy")?.getattr("deepcopy")?.unbind())
+        })?;
+        deepcopy.bind(py).call1((obj, self.memo))
+    }
+}
+
+fn is_immutable_leaf(obj: &Bound<'_, PyAny>) -> bool {
+    obj.is_exact_instance_of::<PyString>()
+        || obj.is_exact_instance_of::<PyInt>()
+        || obj.is_exact_instance_of::<PyBool>()
+        || obj.is_exact_instance_of::<PyFloat>()
+        || obj.is_none()
+        || obj.is_exact_instance_of::<PyBytes>()
+        || obj.is_exact_instance_of::<PyComplex>()
+        || obj.is_exact_instance_of::<PyDateTime>()
+        || obj.is_exact_instance_of::<PyDate>()
+        || obj.is_exact_instance_of::<PyTime>()
+        || obj.is_exact_instance_of::<PyDelta>()
+        || obj.is_exact_instance_of::<PyType>()
+}
+
+/// Whether `obj` is an instance of a pydantic model, i.e. its class defines a `__pydantic_fields_set__` slot.
+fn is_model_instance(obj: &Bound<'_, PyAny>) -> PyResult<bool> {
+    let py = obj.py();
+    obj.get_type().hasattr(intern!(py, DUNDER_FIELDS_SET_KEY))
+}
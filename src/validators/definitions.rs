@@ -12,7 +12,10 @@ use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState,
+    Validator,
+};
 
 #[derive(Debug, Clone)]
 pub struct DefinitionsValidatorBuilder;
@@ -30,9 +33,12 @@ impl BuildValidator for DefinitionsValidatorBuilder {
         let schema_definitions: Bound<'_, PyList> = schema.get_as_req(intern!(py, "definitions"))?;
 
         for schema_definition in schema_definitions {
-            let reference = schema_definition
-                .extract::<Bound<'_, PyDict>>()?
-                .get_as_req::<String>(intern!(py, "ref"))?;
+            let schema_definition = schema_definition.extract::<Bound<'_, PyDict>>()?;
+            let reference = schema_definition.get_as_req::<String>(intern!(py, "ref"))?;
+            if definitions.is_lazy() {
+                definitions.add_lazy_definition(reference, &schema_definition, config)?;
+                continue;
+            }
             let validator = build_validator(&schema_definition, config, definitions)?;
             definitions.add_definition(reference, validator)?;
         }
@@ -51,6 +57,20 @@ impl DefinitionRefValidator {
     pub fn new(definition: DefinitionRef<Arc<CombinedValidator>>) -> Self {
         Self { definition }
     }
+
+    /// Build the definition now if it's built lazily, so it can be named.
+    pub fn build_definition(&self, py: Python<'_>) -> PyResult<()> {
+        self.definition.read_or_build(py, |_| ())
+    }
+
+    /// Read the definition's validator, building it first if it's built lazily.
+    pub fn read_definition<R>(&self, py: Python<'_>, f: impl FnOnce(&CombinedValidator) -> R) -> PyResult<R> {
+        self.definition.read_or_build(py, |validator| f(validator))
+    }
+
+    fn read<R>(&self, py: Python<'_>, f: impl FnOnce(&Arc<CombinedValidator>) -> ValResult<R>) -> ValResult<R> {
+        self.definition.read_or_build(py, f).map_err(ValError::InternalErr)?
+    }
 }
 
 impl BuildValidator for DefinitionRefValidator {
@@ -79,9 +99,9 @@ impl Validator for DefinitionRefValidator {
     ) -> ValResult<Py<PyAny>> {
         // this validator does not yet support partial validation, disable it to avoid incorrect results
         state.allow_partial = false.into();
+        state.check_deadline()?;
 
-        self.definition.read(|validator| {
-            let validator = validator.unwrap();
+        self.read(py, |validator| {
             if let Some(id) = input.as_python().map(py_identity) {
                 // Python objects can be cyclic, so need recursion guard
                 let Ok(mut guard) = RecursionGuard::new(state, id, self.definition.id()) else {
@@ -96,14 +116,11 @@ impl Validator for DefinitionRefValidator {
 
     fn default_value<'py>(
         &self,
-        _py: Python<'py>,
-        _outer_loc: Option<impl Into<crate::errors::LocItem>>,
-        _state: &mut ValidationState<'_, 'py>,
+        py: Python<'py>,
+        outer_loc: Option<impl Into<crate::errors::LocItem>>,
+        state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Option<Py<PyAny>>> {
-        self.definition.read(|validator| {
-            let validator = validator.unwrap();
-            validator.default_value(_py, _outer_loc, _state)
-        })
+        self.read(py, |validator| validator.default_value(py, outer_loc, state))
     }
 
     fn validate_assignment<'py>(
@@ -114,8 +131,7 @@ impl Validator for DefinitionRefValidator {
         field_value: &Bound<'py, PyAny>,
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        self.definition.read(|validator| {
-            let validator = validator.unwrap();
+        self.read(py, |validator| {
             let Ok(mut guard) = RecursionGuard::new(state, py_identity(obj), self.definition.id()) else {
                 return Err(ValError::new(ErrorTypeDefaults::RecursionLoop, obj));
             };
@@ -123,6 +139,12 @@ impl Validator for DefinitionRefValidator {
         })
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        let name = self.get_name();
+        self.definition
+            .read_or_build(py, |validator| generator.nested(name, |g| validator.example(py, g)))?
+    }
+
     fn get_name(&self) -> &str {
         self.definition.get_or_init_name(|v| v.get_name().into())
     }
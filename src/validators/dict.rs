@@ -2,19 +2,27 @@ use std::sync::Arc;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::sync::PyOnceLock;
+use pyo3::types::{PyDict, PyType};
+use pyo3::{PyTraverseError, PyVisit};
 
-use crate::build_tools::is_strict;
-use crate::errors::{LocItem, ValError, ValLineError, ValResult};
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::definitions::CompositeName;
+use crate::errors::{LocItem, Location, ValError, ValLineError, ValResult};
 use crate::input::BorrowInput;
 use crate::input::ConsumeIterator;
-use crate::input::{Input, ValidatedDict};
+use crate::input::{iterate_items, Input, ValidatedDict};
+use crate::py_gc::PyGcTraverse;
 
 use crate::tools::SchemaDict;
 
 use super::any::AnyValidator;
+use super::example;
 use super::list::length_check;
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{
+    build_validator, parallel, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ExampleGenerator,
+    ResumeNode, ValidationState, Validator,
+};
 
 #[derive(Debug)]
 pub struct DictValidator {
@@ -24,7 +32,80 @@ pub struct DictValidator {
     min_length: Option<usize>,
     max_length: Option<usize>,
     fail_fast: bool,
-    name: String,
+    parallel: bool,
+    // whether iterables of `(key, value)` pairs are accepted as well as mappings
+    from_items: bool,
+    dict_type: DictType,
+    name: CompositeName,
+}
+
+static ORDERED_DICT_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+static COUNTER_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+static DEFAULT_DICT_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+
+/// The `dict_type` of the validated dict, e.g. `Counter`, the validated items are copied into it.
+#[derive(Debug)]
+enum DictType {
+    Dict,
+    OrderedDict,
+    Counter,
+    DefaultDict(Py<PyAny>),
+}
+
+impl DictType {
+    fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let py = schema.py();
+        let default_factory: Option<Bound<'_, PyAny>> = schema.get_as(intern!(py, "default_factory"))?;
+        let dict_type = match schema.get_as::<String>(intern!(py, "dict_type"))?.as_deref() {
+            None | Some("dict") => Self::Dict,
+            Some("ordered_dict") => Self::OrderedDict,
+            Some("counter") => Self::Counter,
+            Some("default_dict") => match default_factory {
+                Some(default_factory) if default_factory.is_callable() => {
+                    return Ok(Self::DefaultDict(default_factory.unbind()))
+                }
+                Some(_) => return py_schema_err!("`default_factory` must be callable"),
+                None => return py_schema_err!("`dict_type='default_dict'` requires `default_factory`"),
+            },
+            Some(other) => {
+                return py_schema_err!(
+                    "Invalid dict_type: `{}`, expected 'dict', 'ordered_dict', 'counter' or 'default_dict'",
+                    other
+                )
+            }
+        };
+        match default_factory {
+            Some(_) => py_schema_err!("`default_factory` requires `dict_type='default_dict'`"),
+            None => Ok(dict_type),
+        }
+    }
+
+    fn convert(&self, py: Python<'_>, dict: Py<PyAny>) -> PyResult<Py<PyAny>> {
+        match self {
+            Self::Dict => Ok(dict),
+            Self::OrderedDict => Ok(ORDERED_DICT_TYPE
+                .import(py, "collections", "OrderedDict")?
+                .call1((dict,))?
+                .unbind()),
+            Self::Counter => Ok(COUNTER_TYPE
+                .import(py, "collections", "Counter")?
+                .call1((dict,))?
+                .unbind()),
+            Self::DefaultDict(default_factory) => Ok(DEFAULT_DICT_TYPE
+                .import(py, "collections", "defaultdict")?
+                .call1((default_factory, dict))?
+                .unbind()),
+        }
+    }
+}
+
+impl PyGcTraverse for DictType {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        match self {
+            Self::DefaultDict(default_factory) => visit.call(default_factory),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl BuildValidator for DictValidator {
@@ -44,12 +125,6 @@ impl BuildValidator for DictValidator {
             Some(d) => build_validator(&d, config, definitions)?,
             None => AnyValidator::build(schema, config, definitions)?,
         };
-        let name = format!(
-            "{}[{},{}]",
-            Self::EXPECTED_TYPE,
-            key_validator.get_name(),
-            value_validator.get_name()
-        );
         Ok(CombinedValidator::Dict(Self {
             strict: is_strict(schema, config)?,
             key_validator,
@@ -57,7 +132,10 @@ impl BuildValidator for DictValidator {
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
             fail_fast: schema.get_as(intern!(py, "fail_fast"))?.unwrap_or(false),
-            name,
+            parallel: schema.get_as(intern!(py, "parallel"))?.unwrap_or(false),
+            from_items: schema.get_as(intern!(py, "from_items"))?.unwrap_or(false),
+            dict_type: DictType::from_schema(schema)?,
+            name: CompositeName::new(),
         })
         .into())
     }
@@ -65,7 +143,8 @@ impl BuildValidator for DictValidator {
 
 impl_py_gc_traverse!(DictValidator {
     key_validator,
-    value_validator
+    value_validator,
+    dict_type
 });
 
 impl Validator for DictValidator {
@@ -74,10 +153,73 @@ impl Validator for DictValidator {
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        let dict = self.validate_dict(py, input, state)?;
+        Ok(self.dict_type.convert(py, dict)?)
+    }
+
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        let length = generator.length(self.min_length, self.max_length);
+        let dict = PyDict::new(py);
+        // like sets, duplicate keys are replaced
+        for _ in 0..length + example::ATTEMPTS {
+            if dict.len() >= length {
+                break;
+            }
+            let key = self.key_validator.example(py, generator)?;
+            dict.set_item(key, self.value_validator.example(py, generator)?)?;
+        }
+        Ok(dict.into_any())
+    }
+
+    fn get_name(&self) -> &str {
+        self.name.get_or_init(|| {
+            format!(
+                "{}[{},{}]",
+                Self::EXPECTED_TYPE,
+                self.key_validator.get_name(),
+                self.value_validator.get_name()
+            )
+        })
+    }
+}
+
+impl DictValidator {
+    fn validate_dict<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
         let strict = state.strict_or(self.strict);
-        let dict = input.validate_dict(strict)?;
-        dict.iterate(ValidateToDict {
+        let dict = match input.validate_dict(strict) {
+            Ok(dict) => dict,
+            Err(err) => {
+                let items = input.as_python().filter(|_| self.from_items).and_then(iterate_items);
+                return match items {
+                    // the pairs are validated as they're iterated, without collecting them into a dict first
+                    Some(items) => self.validate_items(py, input, items, state),
+                    None => Err(err),
+                };
+            }
+        };
+        if let Some(py_dict) = self.parallel_dict(input, state) {
+            return self.validate_parallel(py, input, py_dict, state);
+        }
+        let mut resume = state.resume.take();
+        let output = dict.iterate(self.to_dict(py, input, state, resume.as_mut()))?;
+        state.resume = resume;
+        output
+    }
+
+    fn to_dict<'a, 's, 'py, I: Input<'py> + ?Sized>(
+        &'a self,
+        py: Python<'py>,
+        input: &'a I,
+        state: &'a mut ValidationState<'s, 'py>,
+        resume: Option<&'a mut ResumeNode>,
+    ) -> ValidateToDict<'a, 's, 'py, I> {
+        ValidateToDict {
             py,
             input,
             min_length: self.min_length,
@@ -86,11 +228,95 @@ impl Validator for DictValidator {
             key_validator: &self.key_validator,
             value_validator: &self.value_validator,
             state,
-        })?
+            resume,
+        }
     }
 
-    fn get_name(&self) -> &str {
-        &self.name
+    fn validate_items<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        items: impl Iterator<Item = ValResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)>>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        state.floor_exactness(Exactness::Lax);
+        let mut resume = state.resume.take();
+        let output = self.to_dict(py, input, state, resume.as_mut()).consume_iterator(items);
+        state.resume = resume;
+        output
+    }
+
+    /// The input dict if `parallel` is set and its keys and values can be validated in parallel.
+    fn parallel_dict<'a, 'py>(
+        &self,
+        input: &'a (impl Input<'py> + ?Sized),
+        state: &ValidationState<'_, 'py>,
+    ) -> Option<&'a Bound<'py, PyDict>> {
+        let py_dict = input
+            .as_python()
+            .filter(|_| self.parallel)?
+            .downcast_exact::<PyDict>()
+            .ok()?;
+        (parallel::can_validate_in_parallel(&self.key_validator, py_dict.len(), state)
+            && parallel::can_validate_in_parallel(&self.value_validator, py_dict.len(), state))
+        .then_some(py_dict)
+    }
+
+    /// Validate the keys and then the values of `py_dict` in parallel, errors are collected as `ValidateToDict`
+    /// collects them.
+    fn validate_parallel<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        py_dict: &Bound<'py, PyDict>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        let keys: Vec<Py<PyAny>> = py_dict.keys().iter().map(Bound::unbind).collect();
+        let values: Vec<Py<PyAny>> = py_dict.values().iter().map(Bound::unbind).collect();
+        let key_results = parallel::validate_items(py, &keys, &self.key_validator, self.fail_fast, state);
+        let value_results = parallel::validate_items(py, &values, &self.value_validator, self.fail_fast, state);
+
+        let output = PyDict::new(py);
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for ((key, key_result), value_result) in keys.iter().zip(key_results).zip(value_results) {
+            let key = key.bind(py);
+            let output_key = match key_result {
+                Ok(value) => Some(value),
+                Err(ValError::LineErrors(line_errors)) => {
+                    for err in line_errors {
+                        errors.push(err.with_outer_location("[key]").with_outer_location(key.clone()));
+                    }
+                    None
+                }
+                Err(ValError::Omit) => continue,
+                Err(err) => return Err(err),
+            };
+            if state.stop_collecting(self.fail_fast, &errors) {
+                break;
+            }
+            let output_value = match value_result {
+                Ok(value) => value,
+                Err(ValError::LineErrors(line_errors)) => {
+                    errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(key.clone())));
+                    continue;
+                }
+                Err(ValError::Omit) => continue,
+                Err(err) => return Err(err),
+            };
+            if state.stop_collecting(self.fail_fast, &errors) {
+                break;
+            }
+            if let Some(key) = output_key {
+                output.set_item(key, output_value)?;
+            }
+        }
+
+        if errors.is_empty() {
+            length_check!(input, "Dictionary", self.min_length, self.max_length, output);
+            Ok(output.into())
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
     }
 }
 
@@ -103,6 +329,7 @@ struct ValidateToDict<'a, 's, 'py, I: Input<'py> + ?Sized> {
     key_validator: &'a CombinedValidator,
     value_validator: &'a CombinedValidator,
     state: &'a mut ValidationState<'s, 'py>,
+    resume: Option<&'a mut ResumeNode>,
 }
 
 impl<'py, Key, Value, I: Input<'py> + ?Sized> ConsumeIterator<ValResult<(Key, Value)>>
@@ -112,21 +339,30 @@ where
     Value: BorrowInput<'py>,
 {
     type Output = ValResult<Py<PyAny>>;
-    fn consume_iterator(self, iterator: impl Iterator<Item = ValResult<(Key, Value)>>) -> ValResult<Py<PyAny>> {
+    fn consume_iterator(mut self, iterator: impl Iterator<Item = ValResult<(Key, Value)>>) -> ValResult<Py<PyAny>> {
         let output = PyDict::new(self.py);
         let mut errors: Vec<ValLineError> = Vec::new();
         let allow_partial = self.state.allow_partial;
 
         macro_rules! should_fail_fast {
             () => {
-                self.fail_fast && !errors.is_empty()
+                self.state.stop_collecting(self.fail_fast, &errors)
             };
         }
 
         for (_, is_last_partial, item_result) in self.state.enumerate_last_partial(iterator) {
+            self.state.check_deadline()?;
             self.state.allow_partial = false.into();
             let (key, value) = item_result?;
-            let output_key = match self.key_validator.validate(self.py, key.borrow_input(), self.state) {
+            let output_key = match self.state.with_path_item(
+                || key.clone(),
+                |state| {
+                    state.with_path_item(
+                        || "[key]",
+                        |state| self.key_validator.validate(self.py, key.borrow_input(), state),
+                    )
+                },
+            ) {
                 Ok(value) => Some(value),
                 Err(ValError::LineErrors(line_errors)) => {
                     for err in line_errors {
@@ -147,10 +383,34 @@ where
                 break;
             }
 
-            let output_value = match self.value_validator.validate(self.py, value.borrow_input(), self.state) {
-                Ok(value) => value,
+            let partial_mark = self.state.partial_mark();
+            let value_result = self.state.with_path_item(
+                || key.clone(),
+                |state| match self.resume {
+                    Some(ref mut resume) => resume.validate_item(
+                        self.py,
+                        key.clone().into(),
+                        is_last_partial,
+                        self.value_validator,
+                        state,
+                        |state| self.value_validator.validate(self.py, value.borrow_input(), state),
+                    ),
+                    None => self.value_validator.validate(self.py, value.borrow_input(), state),
+                },
+            );
+            let output_value = match value_result {
+                Ok(value) => {
+                    if is_last_partial {
+                        let loc = Location::new_some(key.clone().into());
+                        self.state.record_partial_item(partial_mark, &loc, None);
+                    }
+                    value
+                }
                 Err(ValError::LineErrors(line_errors)) => {
-                    if !is_last_partial {
+                    if is_last_partial {
+                        let loc = Location::new_some(key.clone().into());
+                        self.state.record_partial_item(partial_mark, &loc, Some(line_errors));
+                    } else {
                         errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(key.clone())));
                     }
                     continue;
@@ -0,0 +1,242 @@
+use std::sync::Arc;
+
+use ahash::AHashSet;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use pyo3::IntoPyObjectExt;
+
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::literal::expected_repr_name;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+/// Longer inputs are rejected before parsing, the same limit as pydantic's `EmailStr`.
+const MAX_INPUT_LENGTH: usize = 2048;
+/// RFC 5321 limits on the ASCII form of the address.
+const MAX_LOCAL_PART_LENGTH: usize = 64;
+const MAX_ADDRESS_LENGTH: usize = 254;
+
+/// Validates email addresses, returning the normalized address as a `str`, or for `name-email` schemas a
+/// `(name, email)` tuple.
+///
+/// The syntax is checked in Rust: the part before the @-sign is a dot-atom which can include non-ASCII
+/// characters (RFC 6531), and the domain is converted with IDNA, then lower-cased and given in Unicode form in
+/// the normalized address. Inputs can be a `Name <address>` "display name" form.
+#[derive(Debug, Clone)]
+pub struct EmailValidator {
+    strict: bool,
+    with_name: bool,
+    max_length: Option<usize>,
+    // the allowed domains in their ASCII form, and their repr for errors
+    allowed_domains: Option<(AHashSet<String>, String)>,
+    name: String,
+}
+
+impl BuildValidator for EmailValidator {
+    const EXPECTED_TYPE: &'static str = "email";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<Arc<CombinedValidator>>,
+    ) -> PyResult<Arc<CombinedValidator>> {
+        Self::build_with_name(schema, config, false, Self::EXPECTED_TYPE)
+    }
+}
+
+pub struct NameEmailBuilder;
+
+impl BuildValidator for NameEmailBuilder {
+    const EXPECTED_TYPE: &'static str = "name-email";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<Arc<CombinedValidator>>,
+    ) -> PyResult<Arc<CombinedValidator>> {
+        EmailValidator::build_with_name(schema, config, true, Self::EXPECTED_TYPE)
+    }
+}
+
+impl EmailValidator {
+    fn build_with_name(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        with_name: bool,
+        base_name: &'static str,
+    ) -> PyResult<Arc<CombinedValidator>> {
+        let py = schema.py();
+        let (allowed_domains, name) = match schema.get_as::<Bound<'_, PyList>>(intern!(py, "allowed_domains"))? {
+            Some(list) => {
+                if list.is_empty() {
+                    return py_schema_err!("`allowed_domains` should have length > 0");
+                }
+                let mut domains = AHashSet::new();
+                let mut repr_args = Vec::new();
+                for item in list {
+                    let domain: String = item.extract()?;
+                    match idna::domain_to_ascii_strict(&domain) {
+                        Ok(ascii) => domains.insert(ascii),
+                        Err(_) => return py_schema_err!("Invalid domain in `allowed_domains`: '{}'", domain),
+                    };
+                    repr_args.push(format!("'{domain}'"));
+                }
+                let (repr, name) = expected_repr_name(repr_args, base_name);
+                (Some((domains, repr)), name)
+            }
+            None => (None, base_name.to_string()),
+        };
+        Ok(CombinedValidator::Email(Self {
+            strict: is_strict(schema, config)?,
+            with_name,
+            max_length: schema.get_as(intern!(py, "max_length"))?,
+            allowed_domains,
+            name,
+        })
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(EmailValidator {});
+
+impl Validator for EmailValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        let either_str = input.validate_str(state.strict_or(self.strict), false)?.unpack(state);
+        let cow = either_str.as_cow()?;
+        let parsing_error = |error: &str| {
+            ValError::new(
+                ErrorType::EmailParsing {
+                    error: error.to_string(),
+                    context: None,
+                },
+                input,
+            )
+        };
+
+        let (display_name, address) = split_display_name(cow.as_ref()).map_err(parsing_error)?;
+        let email = parse_address(address).map_err(parsing_error)?;
+
+        if let Some(max_length) = self.max_length {
+            if email.normalized.chars().count() > max_length {
+                return Err(ValError::new(
+                    ErrorType::EmailTooLong {
+                        max_length,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some((ref allowed_domains, ref expected_domains)) = self.allowed_domains {
+            if !allowed_domains.contains(&email.ascii_domain) {
+                return Err(ValError::new(
+                    ErrorType::EmailDomain {
+                        expected_domains: expected_domains.clone(),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+
+        if self.with_name {
+            // like pydantic's `NameEmail`, the name defaults to the part before the @-sign
+            let name = display_name.unwrap_or(email.local_part);
+            Ok((name, email.normalized).into_py_any(py)?)
+        } else {
+            Ok(email.normalized.into_py_any(py)?)
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+struct Email<'a> {
+    local_part: &'a str,
+    ascii_domain: String,
+    normalized: String,
+}
+
+/// Split `Name <address>` into its name and address, the name can be quoted, other inputs are just an address.
+fn split_display_name(input: &str) -> Result<(Option<&str>, &str), &'static str> {
+    if input.len() > MAX_INPUT_LENGTH {
+        return Err("the input is too long");
+    }
+    let trimmed = input.trim_end();
+    let Some(without_gt) = trimmed.strip_suffix('>') else {
+        return Ok((None, input));
+    };
+    let Some((name, address)) = without_gt.rsplit_once('<') else {
+        return Err("the display name has an unmatched '>'");
+    };
+    let name = name.trim();
+    let name = name
+        .strip_prefix('"')
+        .and_then(|name| name.strip_suffix('"'))
+        .unwrap_or(name);
+    if name.contains(['<', '>', '"']) {
+        return Err("the display name contains invalid characters");
+    }
+    Ok(((!name.is_empty()).then_some(name), address))
+}
+
+fn parse_address(address: &str) -> Result<Email<'_>, &'static str> {
+    let Some((local_part, domain)) = address.rsplit_once('@') else {
+        return Err("an email address must have an @-sign");
+    };
+    if local_part.is_empty() {
+        return Err("there must be something before the @-sign");
+    }
+    if domain.is_empty() {
+        return Err("there must be something after the @-sign");
+    }
+    if local_part.len() > MAX_LOCAL_PART_LENGTH {
+        return Err("the part before the @-sign is too long");
+    }
+    if !local_part.chars().all(is_atext) {
+        return Err("the part before the @-sign contains invalid characters");
+    }
+    if local_part.split('.').any(str::is_empty) {
+        return Err("the part before the @-sign can't start or end with a period, or have two periods in a row");
+    }
+
+    let ascii_domain =
+        idna::domain_to_ascii_strict(domain).map_err(|_| "the part after the @-sign is not a valid domain")?;
+    let Some((_, tld)) = ascii_domain.rsplit_once('.') else {
+        return Err("the part after the @-sign must contain a period");
+    };
+    if tld.bytes().all(|b| b.is_ascii_digit()) {
+        return Err("the part after the @-sign is not a valid domain");
+    }
+    if local_part.len() + 1 + ascii_domain.len() > MAX_ADDRESS_LENGTH {
+        return Err("the email address is too long");
+    }
+
+    let (unicode_domain, _) = idna::domain_to_unicode(&ascii_domain);
+    Ok(Email {
+        local_part,
+        normalized: format!("{local_part}@{unicode_domain}"),
+        ascii_domain,
+    })
+}
+
+/// Characters allowed in a dot-atom other than periods, RFC 5321 `atext` plus any non-ASCII character except
+/// whitespace and controls (RFC 6531).
+fn is_atext(c: char) -> bool {
+    if c.is_ascii() {
+        c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~.".contains(c)
+    } else {
+        !c.is_whitespace() && !c.is_control()
+    }
+}
@@ -12,9 +12,12 @@ use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::{Input, InputType};
 use crate::tools::{safe_repr, SchemaDict};
 
+use super::example;
 use super::is_instance::class_repr;
 use super::literal::{expected_repr_name, LiteralLookup};
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+use super::{
+    BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ExampleGenerator, ValidationState, Validator,
+};
 
 #[derive(Debug, Clone)]
 pub struct BuildEnumValidator;
@@ -39,11 +42,29 @@ impl BuildValidator for BuildEnumValidator {
             .map(|v| Ok((v.getattr(value_str)?, v.into())))
             .collect::<PyResult<_>>()?;
 
-        let repr_args: Vec<String> = expected
+        let mut repr_args: Vec<String> = expected
             .iter()
             .map(|(k, _)| k.repr()?.extract())
             .collect::<PyResult<_>>()?;
 
+        // with `accept_names`, member names are also accepted in lax mode, e.g. `'RED'` for `Color.RED`
+        let names = if schema.get_as(intern!(py, "accept_names"))?.unwrap_or(false) {
+            let name_str = intern!(py, "name");
+            let names: Vec<(Bound<'_, PyAny>, Py<PyAny>)> = members
+                .iter()
+                .map(|v| Ok((v.getattr(name_str)?, v.into())))
+                .collect::<PyResult<_>>()?;
+            for (name, _) in &names {
+                let name_repr: String = name.repr()?.extract()?;
+                if !repr_args.contains(&name_repr) {
+                    repr_args.push(name_repr);
+                }
+            }
+            Some(LiteralLookup::new(py, names.into_iter())?)
+        } else {
+            None
+        };
+
         let class: Bound<PyType> = schema.get_as_req(intern!(py, "cls"))?;
         let class_repr = class_repr(schema, &class)?;
 
@@ -55,6 +76,7 @@ impl BuildValidator for BuildEnumValidator {
                     phantom: PhantomData::<$vv>,
                     class: class.clone().into(),
                     lookup,
+                    names,
                     missing: schema.get_as(intern!(py, "missing"))?,
                     expected_repr: expected_repr_name(repr_args, "").0,
                     strict: is_strict(schema, config)?,
@@ -89,6 +111,7 @@ pub struct EnumValidator<T: EnumValidateValue> {
     phantom: PhantomData<T>,
     class: Py<PyType>,
     lookup: LiteralLookup<Py<PyAny>>,
+    names: Option<LiteralLookup<Py<PyAny>>>,
     missing: Option<Py<PyAny>>,
     expected_repr: String,
     strict: bool,
@@ -123,6 +146,8 @@ impl<T: EnumValidateValue> Validator for EnumValidator<T> {
 
         if let Some(v) = T::validate_value(py, input, &self.lookup, strict)? {
             return Ok(v);
+        } else if let Some(v) = self.validate_name(input, strict)? {
+            return Ok(v.clone_ref(py));
         } else if let Ok(res) = class.as_unbound().call1(py, (input.as_python(),)) {
             return Ok(res);
         } else if let Some(ref missing) = self.missing {
@@ -161,11 +186,29 @@ impl<T: EnumValidateValue> Validator for EnumValidator<T> {
         ))
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        match generator.choose(&self.lookup.values) {
+            Some(member) => Ok(member.bind(py).clone()),
+            // an enum without members only accepts what `_missing_` accepts
+            None => Err(example::unsupported(&self.name)),
+        }
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
 }
 
+impl<T: EnumValidateValue> EnumValidator<T> {
+    /// The member whose name is the input, values are looked up first so they take precedence over names.
+    fn validate_name<'py>(&self, input: &(impl Input<'py> + ?Sized), strict: bool) -> ValResult<Option<&Py<PyAny>>> {
+        match &self.names {
+            Some(names) if !strict => names.validate_str(input, true),
+            _ => Ok(None),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PlainEnumValidator;
 
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use ahash::AHashMap;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::py_schema_err;
+use crate::errors::{ErrorType, LocItem, Location, ValError, ValLineError, ValResult};
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::{CombinedValidator, ExampleGenerator, ValidationState, Validator};
+
+/// Wrap the validator built for `schema` in an `ErrorMessagesValidator` if the schema has `error_messages`.
+pub(super) fn build_with_error_messages(
+    schema: &Bound<'_, PyDict>,
+    build: impl FnOnce() -> PyResult<Arc<CombinedValidator>>,
+) -> PyResult<Arc<CombinedValidator>> {
+    let py = schema.py();
+    let Some(error_messages) = schema.get_as::<Bound<'_, PyDict>>(intern!(py, "error_messages"))? else {
+        return build();
+    };
+    let mut messages = AHashMap::with_capacity(error_messages.len());
+    for (error_type, message) in error_messages {
+        let error_type: String = error_type.extract()?;
+        if !ErrorType::valid_type(py, &error_type) {
+            return py_schema_err!("Invalid error type in `error_messages`: '{}'", error_type);
+        }
+        messages.insert(error_type, message.extract::<String>()?);
+    }
+    let validator = build()?;
+    Ok(Arc::new(CombinedValidator::ErrorMessages(ErrorMessagesValidator {
+        validator,
+        messages,
+    })))
+}
+
+/// Replaces the message of errors raised directly by the validator it wraps (i.e. not by nested validators)
+/// with the message for their type in the schema's `error_messages`.
+///
+/// The error type and context are kept, so the message can refer to the context, e.g. `'must be > {gt}'`.
+#[derive(Debug)]
+pub struct ErrorMessagesValidator {
+    validator: Arc<CombinedValidator>,
+    // error type to message template
+    messages: AHashMap<String, String>,
+}
+
+impl_py_gc_traverse!(ErrorMessagesValidator { validator });
+
+impl ErrorMessagesValidator {
+    fn map_error(&self, py: Python<'_>, err: ValError) -> ValError {
+        match err {
+            ValError::LineErrors(line_errors) => match line_errors
+                .into_iter()
+                .map(|line_error| self.map_line_error(py, line_error))
+                .collect()
+            {
+                Ok(line_errors) => ValError::LineErrors(line_errors),
+                Err(py_err) => ValError::InternalErr(py_err),
+            },
+            other => other,
+        }
+    }
+
+    fn map_line_error(&self, py: Python<'_>, mut line_error: ValLineError) -> PyResult<ValLineError> {
+        if !matches!(line_error.location, Location::Empty) {
+            return Ok(line_error);
+        }
+        let error_type = line_error.error_type.type_string();
+        if let Some(message) = self.messages.get(&error_type) {
+            line_error.error_type = ErrorType::CustomError {
+                context: line_error.error_type.py_dict(py)?,
+                error_type,
+                message_template: message.clone(),
+            };
+        }
+        Ok(line_error)
+    }
+}
+
+impl Validator for ErrorMessagesValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        self.validator
+            .validate(py, input, state)
+            .map_err(|err| self.map_error(py, err))
+    }
+
+    fn default_value<'py>(
+        &self,
+        py: Python<'py>,
+        outer_loc: Option<impl Into<LocItem>>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Option<Py<PyAny>>> {
+        self.validator.default_value(py, outer_loc, state)
+    }
+
+    fn validate_assignment<'py>(
+        &self,
+        py: Python<'py>,
+        obj: &Bound<'py, PyAny>,
+        field_name: &str,
+        field_value: &Bound<'py, PyAny>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        self.validator
+            .validate_assignment(py, obj, field_name, field_value, state)
+            .map_err(|err| self.map_error(py, err))
+    }
+
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        self.validator.example(py, generator)
+    }
+
+    fn get_name(&self) -> &str {
+        self.validator.get_name()
+    }
+}
@@ -0,0 +1,280 @@
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use regex_syntax::hir::{Class, Hir, HirKind};
+
+use super::datetime::NowOp;
+use super::CombinedValidator;
+
+/// Samples the inputs returned by the validators' `example`, for `SchemaValidator.generate_example`.
+///
+/// Inputs are sampled within the constraints the validators know about, the example is the result of validating
+/// the input, so e.g. a model's example is built from a dict of its fields' examples.
+#[derive(Debug)]
+pub struct ExampleGenerator {
+    // splitmix64, so the same seed generates the same example on every platform
+    state: u64,
+    depth: usize,
+    // the validation context the examples are validated with, e.g. for the current time of `now_op` constraints
+    context: Option<Py<PyAny>>,
+}
+
+/// Beyond this depth, containers are as small as their constraints allow and nullables are `None`, so
+/// examples of recursive schemas end.
+const SHALLOW_DEPTH: usize = 4;
+/// The depth of a recursive schema whose examples can't end, e.g. a required field of its own type.
+const MAX_DEPTH: usize = 64;
+/// The inputs tried by `generate_example` before giving up, in case validation rejects them.
+pub const ATTEMPTS: usize = 20;
+/// The most items in a container, or repetitions in a pattern, beyond the minimum.
+const MAX_EXTRA_ITEMS: usize = 3;
+
+impl ExampleGenerator {
+    pub fn new(seed: u64, context: Option<&Bound<'_, PyAny>>) -> Self {
+        Self {
+            state: seed,
+            depth: 0,
+            context: context.map(|context| context.clone().unbind()),
+        }
+    }
+
+    pub fn context<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyAny>> {
+        self.context.as_ref().map(|context| context.bind(py).clone())
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number from `0` to `n - 1`, `n` must be positive.
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    /// A float from `0` up to, but not including, `1`.
+    fn unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            None
+        } else {
+            Some(&items[self.below(items.len() as u64) as usize])
+        }
+    }
+
+    /// Whether the example is nested deeply enough that it should end.
+    pub fn shallow(&self) -> bool {
+        self.depth >= SHALLOW_DEPTH
+    }
+
+    /// Generate the example of a definition, one level deeper, recursive schemas always go through definitions.
+    pub fn nested<R>(&mut self, name: &str, f: impl FnOnce(&mut Self) -> PyResult<R>) -> PyResult<R> {
+        if self.depth >= MAX_DEPTH {
+            return Err(PyValueError::new_err(format!(
+                "Can't generate an example for `{name}`, its recursion doesn't end"
+            )));
+        }
+        self.depth += 1;
+        let result = f(self);
+        self.depth -= 1;
+        result
+    }
+
+    /// Whether to leave a field out of an example, optional fields and fields with defaults are left out half the
+    /// time, and always once shallow.
+    pub fn skip_field(&mut self, validator: &CombinedValidator, required: bool) -> bool {
+        let optional = !required || matches!(validator, CombinedValidator::WithDefault(v) if v.has_default());
+        optional && (self.shallow() || self.bool())
+    }
+
+    /// The length of a container, a few items more than `min_length`, but as few as possible once shallow.
+    pub fn length(&mut self, min_length: Option<usize>, max_length: Option<usize>) -> usize {
+        let min = min_length.unwrap_or(0);
+        if self.shallow() {
+            return min;
+        }
+        let max = max_length.unwrap_or(usize::MAX).min(min + MAX_EXTRA_ITEMS).max(min);
+        min + self.below((max - min + 1) as u64) as usize
+    }
+
+    /// An int from `ge` to `le`, a multiple of `multiple_of` if possible.
+    pub fn int(&mut self, ge: Option<i128>, le: Option<i128>, multiple_of: Option<i128>) -> i128 {
+        let lo = ge.unwrap_or_else(|| le.map_or(0, |le| le.min(0) - 100));
+        let hi = le.unwrap_or(lo.saturating_add(100));
+        let (lo, hi) = match multiple_of {
+            Some(m) if m != 0 => {
+                let m = m.abs();
+                (lo.div_euclid(m) + i128::from(lo.rem_euclid(m) != 0), hi.div_euclid(m))
+            }
+            _ => (lo, hi),
+        };
+        let value = if lo >= hi {
+            lo
+        } else {
+            lo + (u128::from(self.next_u64()) % hi.abs_diff(lo).saturating_add(1)) as i128
+        };
+        match multiple_of {
+            Some(m) if m != 0 => value * m.abs(),
+            _ => value,
+        }
+    }
+
+    /// An int from `ge` to `le`, within `default` where the bounds allow it, e.g. for dates in a recent range.
+    pub fn int_within(&mut self, ge: Option<i128>, le: Option<i128>, default: (i128, i128)) -> i128 {
+        let ge = ge.unwrap_or_else(|| le.map_or(default.0, |le| le.min(default.0)));
+        let le = le.unwrap_or_else(|| ge.max(default.1));
+        self.int(Some(ge), Some(le), None)
+    }
+
+    /// A float between `lower` and `upper`, given as the bound and whether it's exclusive, a multiple of
+    /// `multiple_of` if it's given, otherwise rounded to two decimal places if that's still within the bounds.
+    pub fn float(&mut self, lower: Option<(f64, bool)>, upper: Option<(f64, bool)>, multiple_of: Option<f64>) -> f64 {
+        let lower = lower.filter(|(v, _)| v.is_finite());
+        let upper = upper.filter(|(v, _)| v.is_finite());
+        let lo = lower.map_or_else(|| upper.map_or(0.0, |(u, _)| u.min(0.0) - 100.0), |(l, _)| l);
+        let hi = upper.map_or(lo + 100.0, |(u, _)| u);
+        let within = |v: f64| {
+            lower.map_or(true, |(l, exclusive)| if exclusive { v > l } else { v >= l })
+                && upper.map_or(true, |(u, exclusive)| if exclusive { v < u } else { v <= u })
+        };
+        if let Some(m) = multiple_of.filter(|m| *m != 0.0 && m.is_finite()) {
+            let m = m.abs();
+            let lo_k = (lo / m).ceil() as i128;
+            let hi_k = (hi / m).floor() as i128;
+            let candidates: Vec<f64> = (lo_k..=hi_k.min(lo_k + 100))
+                .map(|k| k as f64 * m)
+                .filter(|v| within(*v))
+                .collect();
+            return self.choose(&candidates).copied().unwrap_or(lo_k as f64 * m);
+        }
+        let value = lo + (hi - lo) * self.unit();
+        let rounded = (value * 100.0).round() / 100.0;
+        if within(rounded) {
+            rounded
+        } else if within(value) {
+            value
+        } else {
+            (lo + hi) / 2.0
+        }
+    }
+
+    /// A word of lowercase letters, of a length from `min_length` to `max_length`.
+    pub fn word(&mut self, min_length: Option<usize>, max_length: Option<usize>) -> String {
+        let max_length = max_length.unwrap_or(usize::MAX);
+        let min = min_length.unwrap_or(3.min(max_length));
+        let max = max_length.min(min.max(8)).max(min);
+        let length = min + self.below((max - min + 1) as u64) as usize;
+        (0..length).map(|_| char::from(b'a' + self.below(26) as u8)).collect()
+    }
+
+    /// A string matching the regex `pattern`, lookarounds and anchors are ignored.
+    pub fn matching(&mut self, pattern: &str) -> PyResult<String> {
+        let hir = regex_syntax::parse(pattern).map_err(|e| {
+            PyValueError::new_err(format!(
+                "Can't generate an example matching the pattern {pattern:?}: {e}"
+            ))
+        })?;
+        let mut bytes = Vec::new();
+        self.write_matching(&hir, &mut bytes);
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    fn write_matching(&mut self, hir: &Hir, out: &mut Vec<u8>) {
+        match hir.kind() {
+            HirKind::Empty | HirKind::Look(_) => {}
+            HirKind::Literal(literal) => out.extend_from_slice(&literal.0),
+            HirKind::Class(Class::Unicode(class)) => {
+                // printable ASCII characters are preferred, so e.g. `\w` gives readable examples
+                let printable: Vec<char> = class
+                    .ranges()
+                    .iter()
+                    .flat_map(|range| range.start().max(' ')..=range.end().min('~'))
+                    .collect();
+                let c = match self.choose(&printable) {
+                    Some(c) => *c,
+                    None => match self.choose(class.ranges()) {
+                        Some(range) => {
+                            let (start, end) = (u32::from(range.start()), u32::from(range.end()));
+                            let offset = self.below(u64::from(end - start) + 1) as u32;
+                            char::from_u32(start + offset).unwrap_or(range.start())
+                        }
+                        None => return,
+                    },
+                };
+                out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+            }
+            HirKind::Class(Class::Bytes(class)) => {
+                let printable: Vec<u8> = class
+                    .ranges()
+                    .iter()
+                    .flat_map(|range| range.start().max(b' ')..=range.end().min(b'~'))
+                    .collect();
+                if let Some(b) = self.choose(&printable) {
+                    out.push(*b);
+                }
+            }
+            HirKind::Repetition(repetition) => {
+                let min = repetition.min as usize;
+                let max = repetition.max.map(|max| max as usize);
+                let count = {
+                    let max = max.unwrap_or(usize::MAX).min(min + MAX_EXTRA_ITEMS).max(min);
+                    min + self.below((max - min + 1) as u64) as usize
+                };
+                for _ in 0..count {
+                    self.write_matching(&repetition.sub, out);
+                }
+            }
+            HirKind::Capture(capture) => self.write_matching(&capture.sub, out),
+            HirKind::Concat(hirs) => {
+                for hir in hirs {
+                    self.write_matching(hir, out);
+                }
+            }
+            HirKind::Alternation(hirs) => {
+                if let Some(hir) = self.choose(hirs) {
+                    self.write_matching(hir, out);
+                }
+            }
+        }
+    }
+}
+
+/// The inclusive bounds of an int from the `ge`, `gt`, `le` and `lt` constraints.
+pub fn int_bounds(
+    ge: Option<i128>,
+    gt: Option<i128>,
+    le: Option<i128>,
+    lt: Option<i128>,
+) -> (Option<i128>, Option<i128>) {
+    let ge = [ge, gt.map(|gt| gt.saturating_add(1))].into_iter().flatten().max();
+    let le = [le, lt.map(|lt| lt.saturating_sub(1))].into_iter().flatten().min();
+    (ge, le)
+}
+
+/// Narrow the inclusive bounds of an int to before or after `now` by at least `margin`, for `now_op`
+/// constraints.
+pub fn now_bounds(
+    (ge, le): (Option<i128>, Option<i128>),
+    op: &NowOp,
+    now: i128,
+    margin: i128,
+) -> (Option<i128>, Option<i128>) {
+    match op {
+        NowOp::Past => (ge, Some(le.map_or(now - margin, |le| le.min(now - margin)))),
+        NowOp::Future => (Some(ge.map_or(now + margin, |ge| ge.max(now + margin))), le),
+    }
+}
+
+/// The error from the `example` of validators which can't generate examples, e.g. plain function validators.
+pub fn unsupported(name: &str) -> PyErr {
+    PyTypeError::new_err(format!("Can't generate an example for `{name}`"))
+}
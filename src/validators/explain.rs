@@ -0,0 +1,112 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple};
+
+use crate::errors::ValResult;
+use crate::input::Input;
+
+use super::{Exactness, ValidationState};
+
+/// The coercions and defaults found while validating with `SchemaValidator.explain`.
+#[derive(Debug, Default)]
+pub struct Explanation {
+    // names of the fields being validated, from the outermost
+    path: Vec<String>,
+    coercions: Vec<Coercion>,
+    defaults: Vec<AppliedDefault>,
+}
+
+#[derive(Debug)]
+struct Coercion {
+    loc: Vec<String>,
+    from_type: String,
+    to_type: String,
+}
+
+#[derive(Debug)]
+struct AppliedDefault {
+    loc: Vec<String>,
+    value: Py<PyAny>,
+}
+
+impl Explanation {
+    pub fn coercions_to_py<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        let coercions = PyList::empty(py);
+        for coercion in &self.coercions {
+            let dict = PyDict::new(py);
+            dict.set_item(intern!(py, "loc"), PyTuple::new(py, &coercion.loc)?)?;
+            dict.set_item(intern!(py, "from_type"), &coercion.from_type)?;
+            dict.set_item(intern!(py, "to_type"), &coercion.to_type)?;
+            coercions.append(dict)?;
+        }
+        Ok(coercions)
+    }
+
+    pub fn defaults_to_py<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        let defaults = PyList::empty(py);
+        for default in &self.defaults {
+            let dict = PyDict::new(py);
+            dict.set_item(intern!(py, "loc"), PyTuple::new(py, &default.loc)?)?;
+            dict.set_item(intern!(py, "value"), &default.value)?;
+            defaults.append(dict)?;
+        }
+        Ok(defaults)
+    }
+}
+
+/// Validate the value of the field `name` with `validate`, when explaining the field is recorded as coerced if
+/// its value was only valid in lax mode.
+///
+/// Only the innermost coerced fields are recorded, e.g. a field holding a model is only recorded if none of
+/// the model's fields were.
+pub(super) fn validate_field<'py>(
+    py: Python<'py>,
+    state: &mut ValidationState<'_, 'py>,
+    name: &str,
+    input: &(impl Input<'py> + ?Sized),
+    validate: impl FnOnce(&mut ValidationState<'_, 'py>) -> ValResult<Py<PyAny>>,
+) -> ValResult<Py<PyAny>> {
+    let Some(explanation) = state.explanation.as_mut() else {
+        return validate(state);
+    };
+    explanation.path.push(name.to_string());
+    let coercions_before = explanation.coercions.len();
+    let outer_exactness = state.exactness.replace(Exactness::Exact);
+
+    let result = validate(state);
+
+    let field_exactness = std::mem::replace(&mut state.exactness, outer_exactness);
+    if let Some(field_exactness) = field_exactness {
+        state.floor_exactness(field_exactness);
+    }
+    if let Some(explanation) = state.explanation.as_mut() {
+        if let (Ok(output), Some(Exactness::Lax)) = (&result, field_exactness) {
+            if explanation.coercions.len() == coercions_before {
+                explanation.coercions.push(Coercion {
+                    loc: explanation.path.clone(),
+                    from_type: input.to_object(py)?.get_type().name()?.to_string(),
+                    to_type: output.bind(py).get_type().name()?.to_string(),
+                });
+            }
+        }
+        explanation.path.pop();
+    }
+    result
+}
+
+/// Record that the default of the field `name` was used, when explaining.
+pub(super) fn record_default<'py>(
+    py: Python<'py>,
+    state: &mut ValidationState<'_, 'py>,
+    name: &str,
+    value: &Py<PyAny>,
+) {
+    if let Some(explanation) = state.explanation.as_mut() {
+        let mut loc = explanation.path.clone();
+        loc.push(name.to_string());
+        explanation.defaults.push(AppliedDefault {
+            loc,
+            value: value.clone_ref(py),
+        });
+    }
+}
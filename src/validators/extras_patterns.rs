@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use crate::build_tools::{py_schema_err, ExtraBehavior};
+use crate::tools::SchemaDict;
+
+use super::string::{Pattern, RegexEngine};
+use super::{build_validator, CombinedValidator, DefinitionsBuilder};
+
+/// Restrictions on the keys of extra items with `extra_behavior='allow'`, from the `extras_key_pattern` and
+/// `extras_pattern_schemas` schema keys.
+#[derive(Debug)]
+pub(super) struct ExtrasPatterns {
+    key_pattern: Option<Pattern>,
+    // the values of extra items are validated by the validator of the first pattern their key matches
+    pattern_validators: Vec<PatternValidator>,
+}
+
+#[derive(Debug)]
+struct PatternValidator {
+    pattern: Pattern,
+    validator: Arc<CombinedValidator>,
+}
+
+impl_py_gc_traverse!(PatternValidator { validator });
+
+impl_py_gc_traverse!(ExtrasPatterns { pattern_validators });
+
+/// Whether an extra item is allowed, and if so the validator for its value.
+pub(super) enum ExtraValidator<'a> {
+    Forbidden,
+    Allowed(Option<&'a CombinedValidator>),
+}
+
+impl ExtrasPatterns {
+    pub fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        extra_behavior: ExtraBehavior,
+        definitions: &mut DefinitionsBuilder<Arc<CombinedValidator>>,
+    ) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let key_pattern = schema.get_item(intern!(py, "extras_key_pattern"))?;
+        let pattern_schemas: Option<Bound<'_, PyDict>> = schema.get_as(intern!(py, "extras_pattern_schemas"))?;
+        if key_pattern.is_none() && pattern_schemas.is_none() {
+            return Ok(None);
+        }
+        if extra_behavior != ExtraBehavior::Allow {
+            return py_schema_err!(
+                "extras_key_pattern and extras_pattern_schemas can only be used if extra_behavior=allow"
+            );
+        }
+
+        let regex_engine: Option<Bound<'_, PyString>> = config.get_as(intern!(py, "regex_engine"))?;
+        let regex_engine = regex_engine
+            .as_ref()
+            .map(|s| s.to_str())
+            .transpose()?
+            .unwrap_or(RegexEngine::RUST_REGEX);
+
+        let key_pattern = key_pattern.map(|p| Pattern::compile(p, regex_engine)).transpose()?;
+        let mut pattern_validators = Vec::new();
+        for (pattern, schema) in pattern_schemas.iter().flatten() {
+            pattern_validators.push(PatternValidator {
+                pattern: Pattern::compile(pattern, regex_engine)?,
+                validator: build_validator(&schema, config, definitions)?,
+            });
+        }
+        Ok(Some(Self {
+            key_pattern,
+            pattern_validators,
+        }))
+    }
+
+    /// Whether the extra item with `key` is allowed, and the validator for its value, `extras_validator` is used
+    /// for keys which don't match any pattern of `extras_pattern_schemas`, if there isn't one they're forbidden.
+    pub fn select<'a>(
+        this: Option<&'a Self>,
+        py: Python<'_>,
+        key: &str,
+        extras_validator: Option<&'a CombinedValidator>,
+    ) -> PyResult<ExtraValidator<'a>> {
+        let Some(this) = this else {
+            return Ok(ExtraValidator::Allowed(extras_validator));
+        };
+        if let Some(key_pattern) = &this.key_pattern {
+            if !key_pattern.is_match(py, key)? {
+                return Ok(ExtraValidator::Forbidden);
+            }
+        }
+        for pattern_validator in &this.pattern_validators {
+            if pattern_validator.pattern.is_match(py, key)? {
+                return Ok(ExtraValidator::Allowed(Some(&pattern_validator.validator)));
+            }
+        }
+        match extras_validator {
+            None if !this.pattern_validators.is_empty() => Ok(ExtraValidator::Forbidden),
+            validator => Ok(ExtraValidator::Allowed(validator)),
+        }
+    }
+}
@@ -3,15 +3,20 @@ use std::sync::Arc;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyString};
 use pyo3::IntoPyObjectExt;
 
-use crate::build_tools::{is_strict, schema_or_config_same};
+use crate::build_tools::{
+    is_strict, parse_interval, py_schema_err, schema_interval, schema_or_config_same, DigitSeparators, IntervalBounds,
+};
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{get_fraction_type, str_as_float, EitherFloat, Input, ValidationMatch};
 use crate::tools::SchemaDict;
 
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::decimal::get_decimal_type;
+use super::int::bound_error;
+use super::units::Units;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState, Validator};
 
 pub struct FloatBuilder;
 
@@ -24,26 +29,81 @@ impl BuildValidator for FloatBuilder {
     ) -> PyResult<Arc<CombinedValidator>> {
         let py = schema.py();
         let use_constrained = schema.get_item(intern!(py, "multiple_of"))?.is_some()
+            || schema.get_item(intern!(py, "multiple_of_tolerance"))?.is_some()
+            || schema.get_item(intern!(py, "multiple_of_rel_tolerance"))?.is_some()
             || schema.get_item(intern!(py, "le"))?.is_some()
             || schema.get_item(intern!(py, "lt"))?.is_some()
             || schema.get_item(intern!(py, "ge"))?.is_some()
-            || schema.get_item(intern!(py, "gt"))?.is_some();
+            || schema.get_item(intern!(py, "gt"))?.is_some()
+            || schema.get_item(intern!(py, "interval"))?.is_some();
         if use_constrained {
             ConstrainedFloatValidator::build(schema, config, definitions)
         } else {
             Ok(CombinedValidator::Float(FloatValidator {
                 strict: is_strict(schema, config)?,
                 allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+                exact_numeric_conversion: config.get_as(intern!(py, "exact_numeric_conversion"))?.unwrap_or(false),
+                digit_separators: DigitSeparators::from_schema(schema)?,
+                units: Units::from_schema(schema, false)?,
             })
             .into())
         }
     }
 }
 
+/// Validate a float, with `exact_numeric_conversion` `Decimal` and `Fraction` inputs are only accepted in lax mode,
+/// and only if they're exactly equal to the float they convert to, with `digit_separators` strings like `'1,000.5'`
+/// are accepted, and with `unit` strings with a unit suffix like `'5m'` are converted to the schema's unit.
+fn validate_float<'a, 'py: 'a>(
+    py: Python<'py>,
+    input: &'a (impl Input<'py> + ?Sized),
+    strict: bool,
+    exact_numeric_conversion: bool,
+    digit_separators: Option<&DigitSeparators>,
+    units: Option<&Units>,
+) -> ValResult<ValidationMatch<EitherFloat<'a>>> {
+    let number = input.as_python().filter(|obj| {
+        exact_numeric_conversion
+            && (obj.is_instance(get_decimal_type(obj.py())).unwrap_or(false)
+                || obj.is_instance(get_fraction_type(obj.py())).unwrap_or(false))
+    });
+    let Some(number) = number else {
+        let result = input.validate_float(strict);
+        let Err(ValError::LineErrors(errors)) = &result else {
+            return result;
+        };
+        if !matches!(errors.as_slice(), [error] if matches!(error.error_type, ErrorType::FloatParsing { .. })) {
+            return result;
+        }
+        if let Some(stripped) = digit_separators.and_then(|separators| separators.strip(input)) {
+            return str_as_float(input, &stripped).map(ValidationMatch::lax);
+        }
+        return match (units, result) {
+            (Some(units), Err(error)) => units.convert_float(py, input, error).map(ValidationMatch::lax),
+            (_, result) => result,
+        };
+    };
+    if strict {
+        return Err(ValError::new(ErrorTypeDefaults::FloatType, input));
+    }
+    // e.g. a signalling NaN can't be converted
+    let Ok(float) = number.extract::<f64>() else {
+        return Err(ValError::new(ErrorTypeDefaults::FloatType, input));
+    };
+    // comparing a decimal or fraction with a float is exact, NaN is never equal to itself
+    if !float.is_nan() && !number.eq(float)? {
+        return Err(ValError::new(ErrorTypeDefaults::FloatInexact, input));
+    }
+    Ok(ValidationMatch::lax(EitherFloat::F64(float)))
+}
+
 #[derive(Debug, Clone)]
 pub struct FloatValidator {
     strict: bool,
     allow_inf_nan: bool,
+    exact_numeric_conversion: bool,
+    digit_separators: Option<DigitSeparators>,
+    units: Option<Units>,
 }
 
 impl BuildValidator for FloatValidator {
@@ -58,12 +118,15 @@ impl BuildValidator for FloatValidator {
         Ok(CombinedValidator::Float(Self {
             strict: is_strict(schema, config)?,
             allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+            exact_numeric_conversion: config.get_as(intern!(py, "exact_numeric_conversion"))?.unwrap_or(false),
+            digit_separators: DigitSeparators::from_schema(schema)?,
+            units: Units::from_schema(schema, false)?,
         })
         .into())
     }
 }
 
-impl_py_gc_traverse!(FloatValidator {});
+impl_py_gc_traverse!(FloatValidator { units });
 
 impl Validator for FloatValidator {
     fn validate<'py>(
@@ -72,30 +135,59 @@ impl Validator for FloatValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        let either_float = input.validate_float(state.strict_or(self.strict))?.unpack(state);
+        let either_float = validate_float(
+            py,
+            input,
+            state.strict_or(self.strict),
+            self.exact_numeric_conversion,
+            self.digit_separators.as_ref(),
+            self.units.as_ref(),
+        )?
+        .unpack(state);
         if !self.allow_inf_nan && !either_float.as_f64().is_finite() {
             return Err(ValError::new(ErrorTypeDefaults::FiniteNumber, input));
         }
         Ok(either_float.into_py_any(py)?)
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        generator.float(None, None, None).into_bound_py_any(py)
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
 }
 
+/// A `multiple_of` tolerance, which must be a non-negative number.
+fn tolerance(schema: &Bound<'_, PyDict>, key: &Bound<'_, PyString>) -> PyResult<Option<f64>> {
+    match schema.get_as::<f64>(key)? {
+        Some(tolerance) if !(tolerance >= 0.0 && tolerance.is_finite()) => {
+            py_schema_err!("'{}' must be a non-negative number", key)
+        }
+        tolerance => Ok(tolerance),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ConstrainedFloatValidator {
     strict: bool,
     allow_inf_nan: bool,
+    exact_numeric_conversion: bool,
+    digit_separators: Option<DigitSeparators>,
+    units: Option<Units>,
     multiple_of: Option<f64>,
+    multiple_of_tolerance: f64,
+    multiple_of_rel_tolerance: f64,
     le: Option<f64>,
     lt: Option<f64>,
     ge: Option<f64>,
     gt: Option<f64>,
+    // if the bounds came from an `interval`, it's used in the error when they're violated
+    interval: Option<String>,
 }
 
-impl_py_gc_traverse!(ConstrainedFloatValidator {});
+impl_py_gc_traverse!(ConstrainedFloatValidator { units });
 
 impl Validator for ConstrainedFloatValidator {
     fn validate<'py>(
@@ -104,13 +196,24 @@ impl Validator for ConstrainedFloatValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        let either_float = input.validate_float(state.strict_or(self.strict))?.unpack(state);
+        let either_float = validate_float(
+            py,
+            input,
+            state.strict_or(self.strict),
+            self.exact_numeric_conversion,
+            self.digit_separators.as_ref(),
+            self.units.as_ref(),
+        )?
+        .unpack(state);
         let float: f64 = either_float.as_f64();
         if !self.allow_inf_nan && !float.is_finite() {
             return Err(ValError::new(ErrorTypeDefaults::FiniteNumber, input));
         }
         if let Some(multiple_of) = self.multiple_of {
-            let tolerance = 1e-9;
+            // like `math.isclose`, the larger of the absolute tolerance and the relative one scaled by the value
+            let tolerance = self
+                .multiple_of_tolerance
+                .max(self.multiple_of_rel_tolerance * float.abs());
             let rounded_div = (float / multiple_of).round();
             let diff = (float - (rounded_div * multiple_of)).abs();
             if diff > tolerance {
@@ -126,10 +229,13 @@ impl Validator for ConstrainedFloatValidator {
         if let Some(le) = self.le {
             if !matches!(float.partial_cmp(&le), Some(Ordering::Less | Ordering::Equal)) {
                 return Err(ValError::new(
-                    ErrorType::LessThanEqual {
-                        le: le.into(),
-                        context: None,
-                    },
+                    bound_error(
+                        self.interval.as_deref(),
+                        ErrorType::LessThanEqual {
+                            le: le.into(),
+                            context: None,
+                        },
+                    ),
                     input,
                 ));
             }
@@ -137,10 +243,13 @@ impl Validator for ConstrainedFloatValidator {
         if let Some(lt) = self.lt {
             if !matches!(float.partial_cmp(&lt), Some(Ordering::Less)) {
                 return Err(ValError::new(
-                    ErrorType::LessThan {
-                        lt: lt.into(),
-                        context: None,
-                    },
+                    bound_error(
+                        self.interval.as_deref(),
+                        ErrorType::LessThan {
+                            lt: lt.into(),
+                            context: None,
+                        },
+                    ),
                     input,
                 ));
             }
@@ -148,10 +257,13 @@ impl Validator for ConstrainedFloatValidator {
         if let Some(ge) = self.ge {
             if !matches!(float.partial_cmp(&ge), Some(Ordering::Greater | Ordering::Equal)) {
                 return Err(ValError::new(
-                    ErrorType::GreaterThanEqual {
-                        ge: ge.into(),
-                        context: None,
-                    },
+                    bound_error(
+                        self.interval.as_deref(),
+                        ErrorType::GreaterThanEqual {
+                            ge: ge.into(),
+                            context: None,
+                        },
+                    ),
                     input,
                 ));
             }
@@ -159,10 +271,13 @@ impl Validator for ConstrainedFloatValidator {
         if let Some(gt) = self.gt {
             if !matches!(float.partial_cmp(&gt), Some(Ordering::Greater)) {
                 return Err(ValError::new(
-                    ErrorType::GreaterThan {
-                        gt: gt.into(),
-                        context: None,
-                    },
+                    bound_error(
+                        self.interval.as_deref(),
+                        ErrorType::GreaterThan {
+                            gt: gt.into(),
+                            context: None,
+                        },
+                    ),
                     input,
                 ));
             }
@@ -170,6 +285,21 @@ impl Validator for ConstrainedFloatValidator {
         Ok(either_float.into_py_any(py)?)
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        // the tighter of each pair of bounds, with whether it's exclusive
+        let lower = match (self.ge, self.gt) {
+            (Some(ge), Some(gt)) if gt < ge => Some((ge, false)),
+            (_, Some(gt)) => Some((gt, true)),
+            (ge, None) => ge.map(|ge| (ge, false)),
+        };
+        let upper = match (self.le, self.lt) {
+            (Some(le), Some(lt)) if lt > le => Some((le, false)),
+            (_, Some(lt)) => Some((lt, true)),
+            (le, None) => le.map(|le| (le, false)),
+        };
+        generator.float(lower, upper, self.multiple_of).into_bound_py_any(py)
+    }
+
     fn get_name(&self) -> &'static str {
         "constrained-float"
     }
@@ -183,14 +313,44 @@ impl BuildValidator for ConstrainedFloatValidator {
         _definitions: &mut DefinitionsBuilder<Arc<CombinedValidator>>,
     ) -> PyResult<Arc<CombinedValidator>> {
         let py = schema.py();
+        let interval = schema_interval(schema)?;
+        let IntervalBounds { le, lt, ge, gt } = match interval {
+            Some(ref interval) => parse_interval(interval, |bound| bound.parse().ok())?,
+            None => IntervalBounds {
+                le: schema.get_as(intern!(py, "le"))?,
+                lt: schema.get_as(intern!(py, "lt"))?,
+                ge: schema.get_as(intern!(py, "ge"))?,
+                gt: schema.get_as(intern!(py, "gt"))?,
+            },
+        };
+        for (key, bound) in [("le", le), ("lt", lt), ("ge", ge), ("gt", gt)] {
+            if bound.is_some_and(f64::is_nan) {
+                return match interval {
+                    Some(ref interval) => py_schema_err!("Invalid interval `{}`, bounds can't be NaN", interval),
+                    None => py_schema_err!("`{}` can't be NaN", key),
+                };
+            }
+        }
+        let multiple_of = schema.get_as(intern!(py, "multiple_of"))?;
+        let multiple_of_tolerance = tolerance(schema, intern!(py, "multiple_of_tolerance"))?;
+        let multiple_of_rel_tolerance = tolerance(schema, intern!(py, "multiple_of_rel_tolerance"))?;
+        if multiple_of.is_none() && (multiple_of_tolerance.is_some() || multiple_of_rel_tolerance.is_some()) {
+            return py_schema_err!("`multiple_of_tolerance` and `multiple_of_rel_tolerance` require `multiple_of`");
+        }
         Ok(CombinedValidator::ConstrainedFloat(Self {
             strict: is_strict(schema, config)?,
             allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
-            multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
-            le: schema.get_as(intern!(py, "le"))?,
-            lt: schema.get_as(intern!(py, "lt"))?,
-            ge: schema.get_as(intern!(py, "ge"))?,
-            gt: schema.get_as(intern!(py, "gt"))?,
+            exact_numeric_conversion: config.get_as(intern!(py, "exact_numeric_conversion"))?.unwrap_or(false),
+            digit_separators: DigitSeparators::from_schema(schema)?,
+            units: Units::from_schema(schema, false)?,
+            multiple_of,
+            multiple_of_tolerance: multiple_of_tolerance.unwrap_or(1e-9),
+            multiple_of_rel_tolerance: multiple_of_rel_tolerance.unwrap_or(0.0),
+            le,
+            lt,
+            ge,
+            gt,
+            interval,
         })
         .into())
     }
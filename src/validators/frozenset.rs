@@ -3,14 +3,16 @@ use std::sync::Arc;
 use pyo3::types::{PyDict, PyFrozenSet};
 use pyo3::{prelude::*, IntoPyObjectExt};
 
+use crate::definitions::CompositeName;
 use crate::errors::ValResult;
 use crate::input::{validate_iter_to_set, BorrowInput, ConsumeIterator, Input, ValidatedSet};
 use crate::tools::SchemaDict;
 
 use super::list::min_length_check;
-use super::set::set_build;
+use super::set::{set_build, set_example};
 use super::validation_state::ValidationState;
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
+use super::LaxSequenceTypes;
+use super::{parallel, BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, Validator};
 
 #[derive(Debug)]
 pub struct FrozenSetValidator {
@@ -18,8 +20,10 @@ pub struct FrozenSetValidator {
     item_validator: Arc<CombinedValidator>,
     min_length: Option<usize>,
     max_length: Option<usize>,
-    name: String,
+    name: CompositeName,
     fail_fast: bool,
+    parallel: bool,
+    lax_sequence_types: LaxSequenceTypes,
 }
 
 impl BuildValidator for FrozenSetValidator {
@@ -27,7 +31,10 @@ impl BuildValidator for FrozenSetValidator {
     set_build!();
 }
 
-impl_py_gc_traverse!(FrozenSetValidator { item_validator });
+impl_py_gc_traverse!(FrozenSetValidator {
+    item_validator,
+    lax_sequence_types
+});
 
 impl Validator for FrozenSetValidator {
     fn validate<'py>(
@@ -36,23 +43,40 @@ impl Validator for FrozenSetValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        let collection = input.validate_frozenset(state.strict_or(self.strict))?.unpack(state);
-        let f_set = PyFrozenSet::empty(py)?;
-        collection.iterate(ValidateToFrozenSet {
-            py,
-            input,
-            f_set: &f_set,
-            max_length: self.max_length,
-            item_validator: &self.item_validator,
-            state,
-            fail_fast: self.fail_fast,
-        })??;
+        let collection = input
+            .validate_frozenset(state.strict_or(self.strict), &self.lax_sequence_types)?
+            .unpack(state);
+        let f_set = match parallel::parallel_items(self.parallel, input, &self.item_validator, self.max_length, state) {
+            Some(items) => {
+                let results = parallel::validate_items(py, &items, &self.item_validator, self.fail_fast, state);
+                PyFrozenSet::new(py, parallel::collect_results(results, state, self.fail_fast)?)?
+            }
+            None => {
+                let f_set = PyFrozenSet::empty(py)?;
+                collection.iterate(ValidateToFrozenSet {
+                    py,
+                    input,
+                    f_set: &f_set,
+                    max_length: self.max_length,
+                    item_validator: &self.item_validator,
+                    state,
+                    fail_fast: self.fail_fast,
+                })??;
+                f_set
+            }
+        };
         min_length_check!(input, "Frozenset", self.min_length, f_set);
         Ok(f_set.into_py_any(py)?)
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        let set = set_example(py, generator, &self.item_validator, self.min_length, self.max_length)?;
+        Ok(PyFrozenSet::new(py, set.iter())?.into_any())
+    }
+
     fn get_name(&self) -> &str {
-        &self.name
+        self.name
+            .get_or_init(|| format!("{}[{}]", Self::EXPECTED_TYPE, self.item_validator.get_name()))
     }
 }
 
@@ -2,12 +2,13 @@ use std::sync::Arc;
 
 use pyo3::exceptions::{PyAssertionError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyDict, PyString};
+use pyo3::types::{PyAny, PyDict, PyString, PyTuple};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
+use crate::definitions::CompositeName;
 use crate::errors::{
-    ErrorType, PydanticCustomError, PydanticKnownError, PydanticOmit, ToErrorValue, ValError, ValResult,
-    ValidationError,
+    ErrorType, LocItem, PydanticCustomError, PydanticKnownError, PydanticMultipleErrors, PydanticOmit, ToErrorValue,
+    ValError, ValResult, ValidationError,
 };
 use crate::input::Input;
 use crate::py_gc::PyGcTraverse;
@@ -16,8 +17,8 @@ use crate::PydanticUseDefault;
 
 use super::generator::InternalValidator;
 use super::{
-    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Extra, InputType, ValidationState,
-    Validator,
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, InputType,
+    ValidationState, Validator,
 };
 
 struct FunctionInfo {
@@ -56,12 +57,7 @@ macro_rules! impl_build {
                 let py = schema.py();
                 let validator = build_validator(&schema.get_as_req(intern!(py, "schema"))?, config, definitions)?;
                 let func_info = destructure_function_schema(schema)?;
-                let name = format!(
-                    "{}[{}(), {}]",
-                    $name,
-                    function_name(func_info.function.bind(py))?,
-                    validator.get_name()
-                );
+                let func_name = function_name(func_info.function.bind(py))?;
                 Ok(Arc::new(
                     Self {
                         validator,
@@ -70,7 +66,8 @@ macro_rules! impl_build {
                             Some(c) => c.clone().into(),
                             None => py.None(),
                         },
-                        name,
+                        func_name,
+                        name: CompositeName::new(),
                         field_name: func_info.field_name,
                         info_arg: func_info.info_arg,
                     }
@@ -86,7 +83,8 @@ pub struct FunctionBeforeValidator {
     validator: Arc<CombinedValidator>,
     func: Py<PyAny>,
     config: Py<PyAny>,
-    name: String,
+    func_name: String,
+    name: CompositeName,
     field_name: Option<Py<PyString>>,
     info_arg: bool,
 }
@@ -94,6 +92,10 @@ pub struct FunctionBeforeValidator {
 impl_build!(FunctionBeforeValidator, "function-before");
 
 impl FunctionBeforeValidator {
+    pub(super) fn validator(&self) -> &CombinedValidator {
+        &self.validator
+    }
+
     fn _validate<'s, 'py>(
         &'s self,
         call: impl FnOnce(Bound<'py, PyAny>, &mut ValidationState<'_, 'py>) -> ValResult<Py<PyAny>>,
@@ -108,7 +110,7 @@ impl FunctionBeforeValidator {
                 .clone()
                 .map(Bound::unbind)
                 .or_else(|| self.field_name.clone());
-            let info = ValidationInfo::new(py, state.extra(), &self.config, field_name);
+            let info = ValidationInfo::new(py, state, &self.config, field_name)?;
             self.func.call1(py, (input.to_object(py)?, info))
         } else {
             self.func.call1(py, (input.to_object(py)?,))
@@ -150,8 +152,19 @@ impl Validator for FunctionBeforeValidator {
         self._validate(validate, py, obj, state)
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        self.validator.example(py, generator)
+    }
+
     fn get_name(&self) -> &str {
-        &self.name
+        self.name.get_or_init(|| {
+            format!(
+                "{}[{}(), {}]",
+                Self::EXPECTED_TYPE,
+                self.func_name,
+                self.validator.get_name()
+            )
+        })
     }
 }
 
@@ -160,7 +173,8 @@ pub struct FunctionAfterValidator {
     validator: Arc<CombinedValidator>,
     func: Py<PyAny>,
     config: Py<PyAny>,
-    name: String,
+    func_name: String,
+    name: CompositeName,
     field_name: Option<Py<PyString>>,
     info_arg: bool,
 }
@@ -168,6 +182,10 @@ pub struct FunctionAfterValidator {
 impl_build!(FunctionAfterValidator, "function-after");
 
 impl FunctionAfterValidator {
+    pub(super) fn validator(&self) -> &CombinedValidator {
+        &self.validator
+    }
+
     fn _validate<'py, I: Input<'py> + ?Sized>(
         &self,
         call: impl FnOnce(&I, &mut ValidationState<'_, 'py>) -> ValResult<Py<PyAny>>,
@@ -183,7 +201,7 @@ impl FunctionAfterValidator {
                 .clone()
                 .map(Bound::unbind)
                 .or_else(|| self.field_name.clone());
-            let info = ValidationInfo::new(py, state.extra(), &self.config, field_name);
+            let info = ValidationInfo::new(py, state, &self.config, field_name)?;
             self.func.call1(py, (v, info))
         } else {
             self.func.call1(py, (v,))
@@ -224,8 +242,19 @@ impl Validator for FunctionAfterValidator {
         self._validate(validate, py, obj, state)
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        self.validator.example(py, generator)
+    }
+
     fn get_name(&self) -> &str {
-        &self.name
+        self.name.get_or_init(|| {
+            format!(
+                "{}[{}(), {}]",
+                Self::EXPECTED_TYPE,
+                self.func_name,
+                self.validator.get_name()
+            )
+        })
     }
 }
 
@@ -278,7 +307,7 @@ impl Validator for FunctionPlainValidator {
                 .clone()
                 .map(Bound::unbind)
                 .or_else(|| self.field_name.clone());
-            let info = ValidationInfo::new(py, state.extra(), &self.config, field_name);
+            let info = ValidationInfo::new(py, state, &self.config, field_name)?;
             self.func.call1(py, (input.to_object(py)?, info))
         } else {
             self.func.call1(py, (input.to_object(py)?,))
@@ -334,6 +363,10 @@ impl BuildValidator for FunctionWrapValidator {
 }
 
 impl FunctionWrapValidator {
+    pub(super) fn validator(&self) -> &CombinedValidator {
+        &self.validator
+    }
+
     fn _validate<'py>(
         &self,
         handler: &Bound<'_, PyAny>,
@@ -348,7 +381,7 @@ impl FunctionWrapValidator {
                 .clone()
                 .map(Bound::unbind)
                 .or_else(|| self.field_name.clone());
-            let info = ValidationInfo::new(py, state.extra(), &self.config, field_name);
+            let info = ValidationInfo::new(py, state, &self.config, field_name)?;
             self.func.call1(py, (input.to_object(py)?, handler, info))
         } else {
             self.func.call1(py, (input.to_object(py)?, handler))
@@ -370,6 +403,10 @@ impl Validator for FunctionWrapValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
+        if self.info_arg {
+            // the handler gets the scratch context, so it has to exist before the function's `ValidationInfo`
+            state.scratch(py);
+        }
         let handler = ValidatorCallable {
             validator: InternalValidator::new(
                 "ValidatorCallable",
@@ -385,6 +422,8 @@ impl Validator for FunctionWrapValidator {
         let handler = handler.borrow();
         state.exactness = handler.validator.exactness;
         state.fields_set_count = handler.validator.fields_set_count;
+        state.scratch.clone_from(&handler.validator.scratch);
+        state.timed_out |= handler.validator.timed_out;
         result
     }
 
@@ -396,6 +435,9 @@ impl Validator for FunctionWrapValidator {
         field_value: &Bound<'py, PyAny>,
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
+        if self.info_arg {
+            state.scratch(py);
+        }
         let handler = AssignmentValidatorCallable {
             validator: InternalValidator::new(
                 "AssignmentValidatorCallable",
@@ -407,8 +449,15 @@ impl Validator for FunctionWrapValidator {
             updated_field_name: field_name.to_string(),
             updated_field_value: field_value.clone().into(),
         };
+        let handler = Bound::new(py, handler)?;
         #[allow(clippy::used_underscore_items)]
-        self._validate(Bound::new(py, handler)?.as_any(), py, obj, state)
+        let result = self._validate(handler.as_any(), py, obj, state);
+        state.timed_out |= handler.borrow().validator.timed_out;
+        result
+    }
+
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        self.validator.example(py, generator)
     }
 
     fn get_name(&self) -> &str {
@@ -507,7 +556,9 @@ macro_rules! py_err_string {
 pub fn convert_err(py: Python<'_>, err: PyErr, input: impl ToErrorValue) -> ValError {
     if err.is_instance_of::<PyValueError>(py) {
         let error_value = err.value(py);
-        if let Ok(pydantic_value_error) = error_value.extract::<PydanticCustomError>() {
+        if let Ok(multiple_errors) = error_value.downcast::<PydanticMultipleErrors>() {
+            convert_multiple_errors(py, &multiple_errors.borrow(), &input)
+        } else if let Ok(pydantic_value_error) = error_value.extract::<PydanticCustomError>() {
             pydantic_value_error.into_val_error(input)
         } else if let Ok(pydantic_error_type) = error_value.extract::<PydanticKnownError>() {
             pydantic_error_type.into_val_error(input)
@@ -527,31 +578,75 @@ pub fn convert_err(py: Python<'_>, err: PyErr, input: impl ToErrorValue) -> ValE
     }
 }
 
+/// Expand each error of a `PydanticMultipleErrors` into line errors, with its `loc` added to their location.
+fn convert_multiple_errors(
+    py: Python<'_>,
+    multiple_errors: &PydanticMultipleErrors,
+    input: &dyn ToErrorValue,
+) -> ValError {
+    let mut line_errors = Vec::new();
+    for (loc, error) in multiple_errors.errors_iter(py) {
+        match convert_err(py, PyErr::from_value(error.clone().into_any()), input) {
+            ValError::LineErrors(errors) => line_errors.extend(errors.into_iter().map(|mut line_error| {
+                for loc_item in loc.iter().rev() {
+                    line_error = line_error.with_outer_location(LocItem::from(loc_item));
+                }
+                line_error
+            })),
+            otherwise => return otherwise,
+        }
+    }
+    ValError::LineErrors(line_errors)
+}
+
 #[pyclass(module = "pydantic_core._pydantic_core", get_all)]
 pub struct ValidationInfo {
     config: Py<PyAny>,
     context: Option<Py<PyAny>>,
     data: Option<Py<PyDict>>,
+    // the `data` of the model, typed dict or dataclass enclosing the one being validated
+    parent_data: Option<Py<PyDict>>,
     field_name: Option<Py<PyString>>,
     mode: InputType,
+    // the location being validated, the `loc` errors would have, only with the `validation_info_path` config
+    path: Option<Py<PyTuple>>,
+    // shared by the validator functions of a validation, writes in union choices which aren't used are rolled back
+    scratch: Py<PyDict>,
 }
 
 impl_py_gc_traverse!(ValidationInfo {
     config,
     context,
     data,
-    field_name
+    parent_data,
+    field_name,
+    path,
+    scratch
 });
 
 impl ValidationInfo {
-    fn new(py: Python, extra: &Extra<'_, '_>, config: &Py<PyAny>, field_name: Option<Py<PyString>>) -> Self {
-        Self {
+    fn new<'py>(
+        py: Python<'py>,
+        state: &mut ValidationState<'_, 'py>,
+        config: &Py<PyAny>,
+        field_name: Option<Py<PyString>>,
+    ) -> PyResult<Self> {
+        let scratch = state.scratch(py).clone_ref(py);
+        let extra = state.extra();
+        let path = match &state.path {
+            Some(path) => Some(PyTuple::new(py, path)?.unbind()),
+            None => None,
+        };
+        Ok(Self {
             config: config.clone_ref(py),
             context: extra.context.map(|ctx| ctx.clone().into()),
             field_name,
             data: extra.data.as_ref().map(|data| data.clone().into()),
+            parent_data: extra.parent_data.as_ref().map(|data| data.clone().into()),
             mode: extra.input_type,
-        }
+            path,
+            scratch,
+        })
     }
 
     fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
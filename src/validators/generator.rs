@@ -1,10 +1,14 @@
 use std::fmt;
+use std::num::NonZeroUsize;
 use std::sync::Arc;
+use std::time::Instant;
 
-use pyo3::types::{PyDict, PyString};
-use pyo3::{prelude::*, IntoPyObjectExt, PyTraverseError, PyVisit};
+use pyo3::exceptions::{PyStopAsyncIteration, PyStopIteration};
+use pyo3::types::{PyDict, PyString, PyTuple};
+use pyo3::{intern, prelude::*, IntoPyObjectExt, PyTraverseError, PyVisit};
 
 use crate::build_tools::ExtraBehavior;
+use crate::definitions::CompositeName;
 use crate::errors::{ErrorType, LocItem, ValError, ValResult};
 use crate::input::{BorrowInput, GenericIterator, Input};
 use crate::py_gc::PyGcTraverse;
@@ -14,7 +18,8 @@ use crate::ValidationError;
 
 use super::list::get_items_schema;
 use super::{
-    BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, Extra, InputType, ValidationState, Validator,
+    BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, Extra, InputType, StrictScope, ValidationState,
+    Validator,
 };
 
 #[derive(Debug, Clone)]
@@ -22,7 +27,7 @@ pub struct GeneratorValidator {
     item_validator: Option<Arc<CombinedValidator>>,
     min_length: Option<usize>,
     max_length: Option<usize>,
-    name: String,
+    name: CompositeName,
     hide_input_in_errors: bool,
     validation_error_cause: bool,
 }
@@ -36,10 +41,6 @@ impl BuildValidator for GeneratorValidator {
         definitions: &mut DefinitionsBuilder<Arc<CombinedValidator>>,
     ) -> PyResult<Arc<CombinedValidator>> {
         let item_validator = get_items_schema(schema, config, definitions)?;
-        let name = match item_validator {
-            Some(ref v) => format!("{}[{}]", Self::EXPECTED_TYPE, v.get_name()),
-            None => format!("{}[any]", Self::EXPECTED_TYPE),
-        };
         let hide_input_in_errors: bool = config
             .get_as(pyo3::intern!(schema.py(), "hide_input_in_errors"))?
             .unwrap_or(false);
@@ -48,7 +49,7 @@ impl BuildValidator for GeneratorValidator {
             .unwrap_or(false);
         Ok(CombinedValidator::Generator(Self {
             item_validator,
-            name,
+            name: CompositeName::new(),
             min_length: schema.get_as(pyo3::intern!(schema.py(), "min_length"))?,
             max_length: schema.get_as(pyo3::intern!(schema.py(), "max_length"))?,
             hide_input_in_errors,
@@ -70,20 +71,22 @@ impl Validator for GeneratorValidator {
         // this validator does not yet support partial validation, disable it to avoid incorrect results
         state.allow_partial = false.into();
 
-        let iterator = input.validate_iter()?.into_static();
-        let validator = self.item_validator.as_ref().map(|v| {
-            InternalValidator::new(
-                "ValidatorIterator",
-                v.clone(),
-                state,
-                self.hide_input_in_errors,
-                self.validation_error_cause,
-            )
-        });
+        let iterator = match input.validate_iter() {
+            Ok(iterator) => iterator.into_static(),
+            Err(err) => {
+                // async iterables aren't iterable, they're validated as they're consumed with `async for`
+                return match input.as_python() {
+                    Some(py_input) if py_input.hasattr(intern!(py, "__aiter__"))? => {
+                        self.validate_async(py, py_input, state)
+                    }
+                    _ => Err(err),
+                };
+            }
+        };
 
         let v_iterator = ValidatorIterator {
             iterator,
-            validator,
+            validator: self.internal_validator(state),
             min_length: self.min_length,
             max_length: self.max_length,
             hide_input_in_errors: self.hide_input_in_errors,
@@ -93,7 +96,49 @@ impl Validator for GeneratorValidator {
     }
 
     fn get_name(&self) -> &str {
-        &self.name
+        self.name.get_or_init(|| {
+            format!(
+                "{}[{}]",
+                Self::EXPECTED_TYPE,
+                self.item_validator.as_ref().map_or("any", |v| v.get_name())
+            )
+        })
+    }
+}
+
+impl GeneratorValidator {
+    fn internal_validator(&self, state: &ValidationState<'_, '_>) -> Option<InternalValidator> {
+        self.item_validator.as_ref().map(|v| {
+            let mut validator = InternalValidator::new(
+                "ValidatorIterator",
+                v.clone(),
+                state,
+                self.hide_input_in_errors,
+                self.validation_error_cause,
+            );
+            // items are validated as the generator is consumed, after `timeout_ms` is over
+            validator.deadline = None;
+            validator
+        })
+    }
+
+    fn validate_async<'py>(
+        &self,
+        py: Python<'py>,
+        input: &Bound<'py, PyAny>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        let v_iterator = AsyncValidatorIterator {
+            aiterator: input.call_method0(intern!(py, "__aiter__"))?.unbind(),
+            input: input.clone().unbind(),
+            validator: self.internal_validator(state),
+            index: 0,
+            min_length: self.min_length,
+            max_length: self.max_length,
+            hide_input_in_errors: self.hide_input_in_errors,
+            validation_error_cause: self.validation_error_cause,
+        };
+        Ok(v_iterator.into_py_any(py)?)
     }
 }
 
@@ -213,6 +258,177 @@ impl ValidatorIterator {
     }
 }
 
+/// The async analog of `ValidatorIterator`, items are validated as they're awaited from the async iterator.
+#[pyclass(module = "pydantic_core._pydantic_core")]
+#[derive(Debug)]
+struct AsyncValidatorIterator {
+    aiterator: Py<PyAny>,
+    // the async iterable, for errors
+    input: Py<PyAny>,
+    validator: Option<InternalValidator>,
+    index: usize,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    hide_input_in_errors: bool,
+    validation_error_cause: bool,
+}
+
+#[pymethods]
+impl AsyncValidatorIterator {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__(slf: Bound<'_, Self>) -> PyResult<ValidatorAnext> {
+        let py = slf.py();
+        let awaitable = slf.borrow().aiterator.bind(py).call_method0(intern!(py, "__anext__"))?;
+        let awaiting = awaitable.call_method0(intern!(py, "__await__"))?;
+        Ok(ValidatorAnext {
+            iterator: slf.unbind(),
+            awaiting: awaiting.unbind(),
+        })
+    }
+
+    #[getter]
+    fn index(&self) -> usize {
+        self.index
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "AsyncValidatorIterator(index={}, schema={:?})",
+            self.index, self.validator
+        )
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.aiterator)?;
+        visit.call(&self.input)?;
+        self.validator.py_gc_traverse(&visit)?;
+        Ok(())
+    }
+}
+
+impl AsyncValidatorIterator {
+    fn validate_item(&mut self, py: Python<'_>, item: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        if let Some(max_length) = self.max_length {
+            if self.index >= max_length {
+                let error_type = ErrorType::TooLong {
+                    field_type: "Generator".to_string(),
+                    max_length,
+                    actual_length: None,
+                    context: None,
+                };
+                return Err(self.length_error(py, error_type));
+            }
+        }
+        let index = self.index;
+        self.index += 1;
+        match self.validator {
+            Some(ref mut validator) => validator.validate(py, item, Some(index.into())),
+            None => Ok(item.clone().unbind()),
+        }
+    }
+
+    fn validate_end(&self, py: Python<'_>) -> PyResult<()> {
+        match self.min_length {
+            Some(min_length) if self.index < min_length => {
+                let error_type = ErrorType::TooShort {
+                    field_type: "Generator".to_string(),
+                    min_length,
+                    actual_length: self.index,
+                    context: None,
+                };
+                Err(self.length_error(py, error_type))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn length_error(&self, py: Python<'_>, error_type: ErrorType) -> PyErr {
+        let val_error = ValError::new(error_type, self.input.bind(py));
+        ValidationError::from_val_error(
+            py,
+            PyString::new(py, "ValidatorIterator").into(),
+            InputType::Python,
+            val_error,
+            None,
+            self.hide_input_in_errors,
+            self.validation_error_cause,
+        )
+    }
+}
+
+/// The awaitable returned by `AsyncValidatorIterator.__anext__`, it drives the awaitable of the async iterator's
+/// `__anext__`, validating the item it returns.
+#[pyclass(module = "pydantic_core._pydantic_core")]
+struct ValidatorAnext {
+    iterator: Py<AsyncValidatorIterator>,
+    // the iterator from `__await__` of the async iterator's `__anext__`
+    awaiting: Py<PyAny>,
+}
+
+#[pymethods]
+impl ValidatorAnext {
+    fn __await__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        self.resume(py, self.awaiting.bind(py).call_method0(intern!(py, "__next__")))
+    }
+
+    fn send(&self, py: Python<'_>, value: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        self.resume(py, self.awaiting.bind(py).call_method1(intern!(py, "send"), (value,)))
+    }
+
+    #[pyo3(signature = (*args))]
+    fn throw(&self, py: Python<'_>, args: &Bound<'_, PyTuple>) -> PyResult<Py<PyAny>> {
+        self.resume(py, self.awaiting.bind(py).call_method1(intern!(py, "throw"), args))
+    }
+
+    fn close(&self, py: Python<'_>) -> PyResult<()> {
+        let awaiting = self.awaiting.bind(py);
+        if awaiting.hasattr(intern!(py, "close"))? {
+            awaiting.call_method0(intern!(py, "close"))?;
+        }
+        Ok(())
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.iterator)?;
+        visit.call(&self.awaiting)?;
+        Ok(())
+    }
+}
+
+impl ValidatorAnext {
+    /// Pass on what the awaitable yields to the event loop, validating the item it returns.
+    fn resume(&self, py: Python<'_>, result: PyResult<Bound<'_, PyAny>>) -> PyResult<Py<PyAny>> {
+        match result {
+            Ok(yielded) => Ok(yielded.unbind()),
+            Err(err) if err.is_instance_of::<PyStopIteration>(py) => {
+                let item = err.value(py).getattr(intern!(py, "value"))?;
+                let validated = self.iterator.bind(py).borrow_mut().validate_item(py, &item)?;
+                Err(PyStopIteration::new_err((validated,)))
+            }
+            Err(err) if err.is_instance_of::<PyStopAsyncIteration>(py) => {
+                self.iterator.bind(py).borrow().validate_end(py)?;
+                Err(err)
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
 /// Owned validator wrapper for use in generators in functions, this can be passed back to python
 /// mid-validation
 pub struct InternalValidator {
@@ -220,7 +436,9 @@ pub struct InternalValidator {
     validator: Arc<CombinedValidator>,
     // TODO, do we need data?
     data: Option<Py<PyDict>>,
+    parent_data: Option<Py<PyDict>>,
     strict: Option<bool>,
+    strict_scope: StrictScope,
     extra_behavior: Option<ExtraBehavior>,
     from_attributes: Option<bool>,
     context: Option<Py<PyAny>>,
@@ -229,10 +447,16 @@ pub struct InternalValidator {
     recursion_guard: RecursionState,
     pub(crate) exactness: Option<Exactness>,
     pub(crate) fields_set_count: Option<usize>,
+    max_errors: Option<NonZeroUsize>,
+    fail_fast: bool,
     validation_mode: InputType,
     hide_input_in_errors: bool,
     validation_error_cause: bool,
     cache_str: jiter::StringCacheMode,
+    path: Option<Vec<LocItem>>,
+    pub(crate) scratch: Option<Py<PyDict>>,
+    deadline: Option<Instant>,
+    pub(crate) timed_out: bool,
 }
 
 impl fmt::Debug for InternalValidator {
@@ -254,7 +478,9 @@ impl InternalValidator {
             name: name.to_string(),
             validator,
             data: extra.data.as_ref().map(|d| d.clone().into()),
+            parent_data: extra.parent_data.as_ref().map(|d| d.clone().into()),
             strict: extra.strict,
+            strict_scope: extra.strict_scope,
             extra_behavior: extra.extra_behavior,
             from_attributes: extra.from_attributes,
             context: extra.context.map(|d| d.clone().unbind()),
@@ -263,13 +489,26 @@ impl InternalValidator {
             recursion_guard: state.recursion_guard.clone(),
             exactness: state.exactness,
             fields_set_count: state.fields_set_count,
+            max_errors: state.max_errors,
+            fail_fast: state.fail_fast,
             validation_mode: extra.input_type,
             hide_input_in_errors,
             validation_error_cause,
             cache_str: extra.cache_str,
+            path: state.path.clone(),
+            scratch: state.scratch.clone(),
+            deadline: state.deadline,
+            timed_out: state.timed_out,
         }
     }
 
+    /// The path of the items validated, within the location of the validator.
+    fn path(&self, outer_location: Option<&LocItem>) -> Option<Vec<LocItem>> {
+        let mut path = self.path.clone()?;
+        path.extend(outer_location.cloned());
+        Some(path)
+    }
+
     pub fn validate_assignment<'py>(
         &mut self,
         py: Python<'py>,
@@ -281,7 +520,9 @@ impl InternalValidator {
         let extra = Extra {
             input_type: self.validation_mode,
             data: self.data.as_ref().map(|data| data.bind(py).clone()),
+            parent_data: self.parent_data.as_ref().map(|data| data.bind(py).clone()),
             strict: self.strict,
+            strict_scope: self.strict_scope,
             extra_behavior: self.extra_behavior,
             from_attributes: self.from_attributes,
             field_name: Some(PyString::new(py, field_name)),
@@ -291,8 +532,15 @@ impl InternalValidator {
             by_alias: None,
             by_name: None,
         };
+        let path = self.path(outer_location.as_ref());
         let mut state = ValidationState::new(extra, &mut self.recursion_guard, false.into());
         state.exactness = self.exactness;
+        state.max_errors = self.max_errors;
+        state.fail_fast = self.fail_fast;
+        state.path = path;
+        state.scratch.clone_from(&self.scratch);
+        state.deadline = self.deadline;
+        state.timed_out = self.timed_out;
         let result = self
             .validator
             .validate_assignment(py, model, field_name, field_value, &mut state)
@@ -308,6 +556,8 @@ impl InternalValidator {
                 )
             });
         self.exactness = state.exactness;
+        self.scratch = state.scratch.take();
+        self.timed_out |= state.timed_out;
         result
     }
 
@@ -320,7 +570,9 @@ impl InternalValidator {
         let extra = Extra {
             input_type: self.validation_mode,
             data: self.data.as_ref().map(|data| data.bind(py).clone()),
+            parent_data: self.parent_data.as_ref().map(|data| data.bind(py).clone()),
             strict: self.strict,
+            strict_scope: self.strict_scope,
             extra_behavior: self.extra_behavior,
             from_attributes: self.from_attributes,
             field_name: self.field_name.as_ref().map(|d| d.bind(py).clone()),
@@ -330,9 +582,16 @@ impl InternalValidator {
             by_alias: None,
             by_name: None,
         };
+        let path = self.path(outer_location.as_ref());
         let mut state = ValidationState::new(extra, &mut self.recursion_guard, false.into());
         state.exactness = self.exactness;
+        state.max_errors = self.max_errors;
+        state.fail_fast = self.fail_fast;
+        state.path = path;
         state.fields_set_count = self.fields_set_count;
+        state.scratch.clone_from(&self.scratch);
+        state.deadline = self.deadline;
+        state.timed_out = self.timed_out;
         let result = self.validator.validate(py, input, &mut state).map_err(|e| {
             ValidationError::from_val_error(
                 py,
@@ -346,6 +605,8 @@ impl InternalValidator {
         });
         self.exactness = state.exactness;
         self.fields_set_count = state.fields_set_count;
+        self.scratch = state.scratch.take();
+        self.timed_out |= state.timed_out;
         result
     }
 }
@@ -353,6 +614,8 @@ impl InternalValidator {
 impl_py_gc_traverse!(InternalValidator {
     validator,
     data,
+    parent_data,
     context,
-    self_instance
+    self_instance,
+    scratch
 });
@@ -0,0 +1,106 @@
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+
+use super::ValidationState;
+
+/// Reports the extra items left out of the output because of `extra_behavior='ignore'`, set by
+/// `on_extra_ignored` at validation time.
+pub struct IgnoredExtras<'py> {
+    callback: Bound<'py, PyAny>,
+    // names of the fields being validated, from the outermost
+    path: Vec<String>,
+    // locations of the items ignored while validating union choices, only reported if the choice is used
+    pending: Vec<Vec<String>>,
+    // the number of union choices being validated
+    choices: usize,
+}
+
+impl<'py> IgnoredExtras<'py> {
+    pub fn new(callback: Bound<'py, PyAny>) -> Self {
+        Self {
+            callback,
+            path: Vec::new(),
+            pending: Vec::new(),
+            choices: 0,
+        }
+    }
+
+    fn call(&self, loc: &[String]) -> PyResult<()> {
+        let py = self.callback.py();
+        self.callback.call1((PyTuple::new(py, loc)?,))?;
+        Ok(())
+    }
+}
+
+impl ValidationState<'_, '_> {
+    /// Start holding back the ignored items reported while validating a union choice, the mark is passed to
+    /// `take_ignored_extras` once the choice has been validated.
+    pub fn ignored_extras_mark(&mut self) -> usize {
+        match &mut self.ignored_extras {
+            Some(ignored_extras) => {
+                ignored_extras.choices += 1;
+                ignored_extras.pending.len()
+            }
+            None => 0,
+        }
+    }
+
+    /// Remove the ignored items reported since `mark`, e.g. while validating a union choice which may not be used.
+    pub fn take_ignored_extras(&mut self, mark: usize) -> Vec<Vec<String>> {
+        match &mut self.ignored_extras {
+            Some(ignored_extras) => {
+                ignored_extras.choices -= 1;
+                ignored_extras.pending.split_off(mark)
+            }
+            None => Vec::new(),
+        }
+    }
+
+    /// Restore ignored items taken by `take_ignored_extras`, they're reported once they're not within any union
+    /// choice.
+    pub fn restore_ignored_extras(&mut self, taken: Vec<Vec<String>>) -> PyResult<()> {
+        if let Some(ignored_extras) = &mut self.ignored_extras {
+            if ignored_extras.choices == 0 {
+                for loc in &taken {
+                    ignored_extras.call(loc)?;
+                }
+            } else {
+                ignored_extras.pending.extend(taken);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Validate the value of the field `name` with `validate`, extra items ignored within it are reported with the
+/// field in their location.
+pub(super) fn validate_field<'py, R>(
+    state: &mut ValidationState<'_, 'py>,
+    name: &str,
+    validate: impl FnOnce(&mut ValidationState<'_, 'py>) -> R,
+) -> R {
+    let Some(ignored_extras) = state.ignored_extras.as_mut() else {
+        return validate(state);
+    };
+    ignored_extras.path.push(name.to_string());
+    let result = validate(state);
+    if let Some(ignored_extras) = state.ignored_extras.as_mut() {
+        ignored_extras.path.pop();
+    }
+    result
+}
+
+/// Report that the extra item with `key` was ignored, `on_extra_ignored` is called with its location, or once the
+/// union choice it's within is used.
+pub(super) fn report(state: &mut ValidationState<'_, '_>, key: &str) -> PyResult<()> {
+    if let Some(ignored_extras) = &mut state.ignored_extras {
+        let mut loc = ignored_extras.path.clone();
+        loc.push(key.to_string());
+        if ignored_extras.choices == 0 {
+            ignored_extras.call(&loc)?;
+        } else {
+            ignored_extras.pending.push(loc);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,382 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use ahash::AHashMap;
+use jiter::{Jiter, JsonValue, PartialMode};
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyByteArray, PyBytes, PyString};
+use pyo3::{PyTraverseError, PyVisit};
+
+use crate::build_tools::ExtraBehavior;
+use crate::errors::{LocItem, ValResult};
+use crate::input::InputType;
+use crate::recursion_guard::RecursionState;
+
+use super::{json, CombinedValidator, Extra, SchemaValidator, ValidationOptions, ValidationState, Validator};
+
+/// The outputs of the items of an array or object validated so far, so they aren't validated again when
+/// validation is resumed with more of the JSON.
+///
+/// Only items before the last item of a partial document are complete, more input can't change them.
+#[derive(Default)]
+pub struct ResumeNode {
+    complete: AHashMap<LocItem, Py<PyAny>>,
+    // the last item, which more input may continue, with the node for its own items
+    last: Option<(LocItem, Box<ResumeNode>)>,
+}
+
+impl ResumeNode {
+    /// Validate the item at `key` with `validate`, reusing the item's output if it was complete and valid when
+    /// last validated.
+    ///
+    /// `validator` is the item's validator, the item's own node is only passed on if it can use it.
+    pub fn validate_item<'s, 'py>(
+        &mut self,
+        py: Python<'py>,
+        key: LocItem,
+        is_last: bool,
+        validator: &CombinedValidator,
+        state: &mut ValidationState<'s, 'py>,
+        validate: impl FnOnce(&mut ValidationState<'s, 'py>) -> ValResult<Py<PyAny>>,
+    ) -> ValResult<Py<PyAny>> {
+        if !is_last {
+            if let Some(output) = self.complete.get(&key) {
+                return Ok(output.clone_ref(py));
+            }
+        }
+        let node = match self.last.take() {
+            Some((last_key, node)) if last_key == key => *node,
+            last => {
+                self.last = last;
+                Self::default()
+            }
+        };
+        if supports_resume(validator) {
+            state.resume = Some(node);
+        }
+        let result = validate(state);
+        let node = state.resume.take();
+        if is_last {
+            self.last = node.map(|node| (key, Box::new(node)));
+        } else if let Ok(output) = &result {
+            self.complete.insert(key, output.clone_ref(py));
+        }
+        result
+    }
+
+    fn traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for output in self.complete.values() {
+            visit.call(output)?;
+        }
+        if let Some((_, node)) = &self.last {
+            node.traverse(visit)?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether `validator` validates arrays or objects reusing the outputs in a `ResumeNode`.
+///
+/// Validators which may validate the same input more than once, or change it before validating it, don't.
+fn supports_resume(validator: &CombinedValidator) -> bool {
+    match validator {
+        CombinedValidator::List(_)
+        | CombinedValidator::Dict(_)
+        | CombinedValidator::TypedDict(_)
+        | CombinedValidator::ModelFields(_) => true,
+        CombinedValidator::Model(model) => supports_resume(model.validator()),
+        CombinedValidator::WithDefault(with_default) => supports_resume(with_default.validator()),
+        CombinedValidator::Nullable(nullable) => supports_resume(nullable.validator()),
+        _ => false,
+    }
+}
+
+/// The complete items at the start of a document whose top-level value is an array or object, each parsed once,
+/// so resuming only parses the JSON after them.
+///
+/// An item is complete once the comma after it has arrived, more input can't change it.
+struct ParsedPrefix {
+    // the offset in the buffer after the opening bracket, or after the comma following the last complete item
+    offset: usize,
+    // the number of complete items, `value` also has the items after them from the last parse
+    complete: usize,
+    // an array or object
+    value: JsonValue<'static>,
+}
+
+impl ParsedPrefix {
+    /// The prefix of the document in `data`, `None` if it doesn't start with an array or object (yet).
+    fn start(data: &[u8]) -> Option<Self> {
+        let offset = skip_whitespace(data, 0);
+        let value = match data.get(offset)? {
+            b'[' => JsonValue::Array(Arc::default()),
+            b'{' => JsonValue::Object(Arc::default()),
+            _ => return None,
+        };
+        Some(Self {
+            offset: offset + 1,
+            complete: 0,
+            value,
+        })
+    }
+
+    /// Parse the document in `data`, `value` is then the whole document.
+    ///
+    /// The items completed since the last parse are added to the prefix, the rest is parsed with `allow_partial`.
+    /// Returns `false` if the document has to be parsed as a whole instead, e.g. it's invalid, so errors are
+    /// reported at their position in the whole document.
+    fn parse(&mut self, data: &[u8], allow_partial: PartialMode) -> bool {
+        let (open, close, is_object) = match &self.value {
+            JsonValue::Object(_) => (b'{', b'}', true),
+            _ => (b'[', b']', false),
+        };
+        self.truncate();
+
+        let end = complete_items_end(data, self.offset, is_object);
+        if end > self.offset {
+            // the items without the comma after the last one, parsed in brackets at the same depth as in the document
+            let items = [&[open], &data[self.offset..end - 1], &[close]].concat();
+            let Ok(items) = JsonValue::parse_owned(&items, true, PartialMode::Off) else {
+                return false;
+            };
+            self.complete += self.append(items);
+            self.offset = end;
+        }
+
+        let rest = &data[self.offset..];
+        // a comma before the closing bracket is invalid, but wouldn't be after the opening bracket added below
+        if self.complete > 0 && rest.get(skip_whitespace(rest, 0)) == Some(&close) {
+            return false;
+        }
+        match JsonValue::parse_owned(&[&[open], rest].concat(), true, allow_partial) {
+            Ok(items) => {
+                self.append(items);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Add the items of `items`, an array or object like `value`, returning how many there were.
+    fn append(&mut self, items: JsonValue<'static>) -> usize {
+        match (&mut self.value, items) {
+            (JsonValue::Array(array), JsonValue::Array(items)) => {
+                Arc::make_mut(array).extend(Arc::try_unwrap(items).unwrap_or_else(|items| (*items).clone()));
+                array.len() - self.complete
+            }
+            (JsonValue::Object(object), JsonValue::Object(items)) => {
+                Arc::make_mut(object).extend(Arc::try_unwrap(items).unwrap_or_else(|items| (*items).clone()));
+                object.len() - self.complete
+            }
+            _ => unreachable!("the prefix and its items are both arrays or both objects"),
+        }
+    }
+
+    /// Remove the items after the complete items.
+    fn truncate(&mut self) {
+        match &mut self.value {
+            JsonValue::Array(array) => Arc::make_mut(array).truncate(self.complete),
+            JsonValue::Object(object) => Arc::make_mut(object).truncate(self.complete),
+            _ => {}
+        }
+    }
+}
+
+/// The offset after the comma following the last complete item of an array or object, scanning the items from
+/// `offset`, which is after the opening bracket or a comma. `offset` if no item after it is complete.
+///
+/// The items are only checked as far as skipping them needs, they're parsed once the end of them is found.
+fn complete_items_end(data: &[u8], mut offset: usize, is_object: bool) -> usize {
+    loop {
+        let mut index = offset;
+        if is_object {
+            let mut jiter = Jiter::new(&data[index..]);
+            if jiter.next_str().is_err() {
+                return offset;
+            }
+            index = skip_whitespace(data, index + jiter.current_index());
+            if data.get(index) != Some(&b':') {
+                return offset;
+            }
+            index += 1;
+        }
+        let mut jiter = Jiter::new(&data[index..]).with_allow_inf_nan();
+        if jiter.next_skip().is_err() {
+            return offset;
+        }
+        index = skip_whitespace(data, index + jiter.current_index());
+        if data.get(index) != Some(&b',') {
+            return offset;
+        }
+        offset = index + 1;
+    }
+}
+
+/// The offset of the first byte from `offset` which isn't JSON whitespace.
+fn skip_whitespace(data: &[u8], offset: usize) -> usize {
+    data[offset..]
+        .iter()
+        .position(|b| !matches!(b, b' ' | b'\t' | b'\n' | b'\r'))
+        .map_or(data.len(), |position| offset + position)
+}
+
+/// Validation of a JSON document as more of it arrives, see `SchemaValidator.incremental_json`.
+///
+/// The outputs of complete items are kept, so resuming validation with more of the document only validates
+/// the items which are new or were incomplete. Likewise the complete items of a top-level array or object are
+/// kept parsed, so only the JSON after them is parsed again.
+#[pyclass(module = "pydantic_core._pydantic_core")]
+pub struct IncrementalValidation {
+    validator: Py<SchemaValidator>,
+    buffer: Vec<u8>,
+    // `None` until the document is known to be an array or object, and if it isn't
+    prefix: Option<ParsedPrefix>,
+    // `None` if the validator can't reuse outputs, the whole document is validated each time
+    resume: Option<ResumeNode>,
+    // the last value validated, `None` until the document is first valid
+    value: Option<Py<PyAny>>,
+    allow_partial: PartialMode,
+    strict: Option<bool>,
+    extra_behavior: Option<ExtraBehavior>,
+    context: Option<Py<PyAny>>,
+    by_alias: Option<bool>,
+    by_name: Option<bool>,
+}
+
+impl IncrementalValidation {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn new(
+        validator: Py<SchemaValidator>,
+        allow_partial: PartialMode,
+        strict: Option<bool>,
+        extra_behavior: Option<ExtraBehavior>,
+        context: Option<&Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> Self {
+        let resume = supports_resume(&validator.get().validator).then(ResumeNode::default);
+        Self {
+            validator,
+            buffer: Vec::new(),
+            prefix: None,
+            resume,
+            value: None,
+            allow_partial,
+            strict,
+            extra_behavior,
+            context: context.map(|c| c.clone().unbind()),
+            by_alias,
+            by_name,
+        }
+    }
+
+    fn extend(&mut self, input: &Bound<'_, PyAny>) -> PyResult<()> {
+        if let Ok(py_str) = input.downcast::<PyString>() {
+            self.buffer.extend_from_slice(py_str.to_str()?.as_bytes());
+        } else if let Ok(py_bytes) = input.downcast::<PyBytes>() {
+            self.buffer.extend_from_slice(py_bytes.as_bytes());
+        } else if let Ok(py_byte_array) = input.downcast::<PyByteArray>() {
+            self.buffer.extend_from_slice(&py_byte_array.to_vec());
+        } else {
+            return Err(PyTypeError::new_err("JSON input should be a str, bytes or bytearray"));
+        }
+        Ok(())
+    }
+
+    /// Validate the JSON received so far, keeping the value if it's valid.
+    ///
+    /// Only the JSON after the parsed prefix is parsed, and only items which weren't complete are validated.
+    fn validate(&mut self, py: Python<'_>, allow_partial: PartialMode) -> PyResult<Py<PyAny>> {
+        let validator = self.validator.get();
+        let supports_resume = self.resume.is_some();
+        if self.prefix.is_none() {
+            self.prefix = ParsedPrefix::start(&self.buffer);
+        }
+        let prefix_parsed = self
+            .prefix
+            .as_mut()
+            .is_some_and(|prefix| prefix.parse(&self.buffer, allow_partial));
+        let parsed = match &self.prefix {
+            Some(prefix) if prefix_parsed => Ok(Cow::Borrowed(&prefix.value)),
+            _ => JsonValue::parse_with_config(&self.buffer, true, allow_partial).map(Cow::Owned),
+        };
+        let result = parsed
+            .map_err(|e| {
+                let error = json::map_json_data_err(PyBytes::new(py, &self.buffer).as_any(), e, &self.buffer);
+                validator.add_json_positions(error, &self.buffer, None)
+            })
+            .and_then(|json_value| {
+                let mut recursion_guard = RecursionState::default();
+                let options = ValidationOptions {
+                    strict: self.strict,
+                    extra_behavior: self.extra_behavior,
+                    context: self.context.as_ref().map(|c| c.bind(py)),
+                    by_alias: self.by_alias,
+                    by_name: self.by_name,
+                    ..ValidationOptions::default()
+                };
+                let mut state = ValidationState::new(
+                    Extra::new(&options, InputType::Json, validator.cache_str),
+                    &mut recursion_guard,
+                    allow_partial,
+                );
+                state.max_errors = validator.max_errors;
+                state.resume = self.resume.take();
+                let result = validator.validator.validate(py, json_value.as_ref(), &mut state);
+                // the node is dropped if validation fails part way through
+                self.resume = state
+                    .resume
+                    .take()
+                    .or_else(|| supports_resume.then(ResumeNode::default));
+                result.map_err(|e| validator.add_json_positions(e, &self.buffer, Some(json_value.as_ref())))
+            });
+        let value = result.map_err(|err| {
+            validator.prepare_collected_validation_err(py, err, InputType::Json, validator.max_errors)
+        })?;
+        self.value = Some(value.clone_ref(py));
+        Ok(value)
+    }
+}
+
+#[pymethods]
+impl IncrementalValidation {
+    /// Add `suffix` to the JSON and validate it as partial, returning the new value.
+    fn resume(&mut self, py: Python<'_>, suffix: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        self.extend(suffix)?;
+        self.validate(py, self.allow_partial)
+    }
+
+    /// Add `suffix` to the JSON and validate it as a complete document, returning the final value.
+    #[pyo3(signature = (suffix=None))]
+    fn finish(&mut self, py: Python<'_>, suffix: Option<&Bound<'_, PyAny>>) -> PyResult<Py<PyAny>> {
+        if let Some(suffix) = suffix {
+            self.extend(suffix)?;
+        }
+        self.validate(py, PartialMode::Off)
+    }
+
+    /// The value validated from the JSON so far.
+    #[getter]
+    fn value(&self, py: Python<'_>) -> Option<Py<PyAny>> {
+        self.value.as_ref().map(|value| value.clone_ref(py))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("IncrementalValidation(length={})", self.buffer.len())
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.validator)?;
+        if let Some(value) = &self.value {
+            visit.call(value)?;
+        }
+        if let Some(context) = &self.context {
+            visit.call(context)?;
+        }
+        if let Some(resume) = &self.resume {
+            resume.traverse(&visit)?;
+        }
+        Ok(())
+    }
+}
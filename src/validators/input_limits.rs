@@ -0,0 +1,412 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use jiter::{Jiter, JiterError, JsonValue, Peek};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PyFrozenSet, PyList, PySet, PyString, PyTuple};
+
+use crate::errors::{ErrorType, JsonInput, LocItem, ToErrorValue, ValError, ValResult};
+use crate::recursion_guard::RECURSION_GUARD_LIMIT;
+use crate::tools::SchemaDict;
+
+/// Limits on the size of the input to `validate_python`, `validate_json` and `validate_strings`, from the
+/// `max_input_length`, `max_input_items` and `max_input_depth` config.
+///
+/// The whole input is checked before it's validated, so e.g. a huge list is rejected without validating any of its
+/// items. Only strings, bytes and the builtin containers are checked, other objects aren't looked into. JSON is
+/// checked as it's read, before it's parsed, and the length limit also applies to the digits of JSON numbers.
+#[derive(Debug, Clone, Copy)]
+pub struct InputLimits {
+    length: Option<usize>,
+    items: Option<usize>,
+    depth: Option<usize>,
+}
+
+impl InputLimits {
+    /// The limits from the config, `None` if no limit is set.
+    pub fn from_config(py: Python, config: Option<&Bound<'_, PyDict>>) -> PyResult<Option<Self>> {
+        let limits = Self {
+            length: config.get_as(intern!(py, "max_input_length"))?,
+            items: config.get_as(intern!(py, "max_input_items"))?,
+            depth: config.get_as(intern!(py, "max_input_depth"))?,
+        };
+        let any_limit = limits.length.is_some() || limits.items.is_some() || limits.depth.is_some();
+        Ok(any_limit.then_some(limits))
+    }
+
+    /// The input is walked with a stack rather than recursion, so however deep it is it can't overflow the stack.
+    pub fn check_python(&self, input: &Bound<'_, PyAny>) -> ValResult<()> {
+        let mut seen = HashMap::new();
+        // the items left to check of each container being checked, with where the container is in its parent
+        let mut stack: Vec<(Option<Position<'_>>, std::vec::IntoIter<Item<'_>>)> = Vec::new();
+        let mut next = Some((None, input.clone()));
+        loop {
+            let (position, item) = match next.take() {
+                Some(next) => next,
+                None => match stack.last_mut() {
+                    Some((_, items)) => match items.next() {
+                        Some((position, item)) => (Some(position), item),
+                        None => {
+                            stack.pop();
+                            continue;
+                        }
+                    },
+                    None => return Ok(()),
+                },
+            };
+            match self.check_python_item(&item, stack.len(), &mut seen) {
+                Ok(Some(items)) => stack.push((position, items.into_iter())),
+                Ok(None) => {}
+                Err(error) => {
+                    let positions = position
+                        .into_iter()
+                        .chain(stack.into_iter().rev().filter_map(|(p, _)| p));
+                    return Err(positions.fold(error, |error, position| position.add_to(error)));
+                }
+            }
+        }
+    }
+
+    /// Check JSON data before it's parsed, so values over the limits are never allocated. Invalid JSON is left
+    /// for the parser to report, the data is only checked up to where it's invalid.
+    pub fn check_json_data(&self, json_data: &[u8]) -> ValResult<()> {
+        let mut jiter = Jiter::new(json_data).with_allow_inf_nan();
+        let result = jiter.peek().map_err(JsonScanError::from).and_then(|peek| {
+            let start = jiter.current_index();
+            self.scan_json(&mut jiter, json_data, peek, 0)
+                .map_err(|e| e.map(|e| e.with_start(start)))
+        });
+        match result {
+            Ok(()) | Err(JsonScanError::Json) => Ok(()),
+            Err(JsonScanError::Limit(error)) => Err(error.into_val_error(json_data)),
+        }
+    }
+
+    /// Check a parsed JSON value, used for partial JSON where the end of the data may not have been checked.
+    pub fn check_json(&self, input: &JsonValue<'_>) -> ValResult<()> {
+        self.check_json_at(input, 0)
+    }
+
+    /// Check the JSON value starting at `peek`, `depth` is the number of containers around it.
+    fn scan_json(&self, jiter: &mut Jiter<'_>, data: &[u8], peek: Peek, depth: usize) -> Result<(), JsonScanError> {
+        let start = jiter.current_index();
+        match peek {
+            Peek::String => {
+                let len = jiter.known_str()?.chars().count();
+                self.scan_length(len)
+            }
+            Peek::Array => {
+                self.scan_depth(depth + 1)?;
+                let mut next = jiter.known_array()?;
+                let mut index = 0;
+                while let Some(peek) = next {
+                    self.scan_items(index + 1)?;
+                    self.scan_child(jiter, data, peek, depth)
+                        .map_err(|e| e.map(|e| e.with_outer_location(index)))?;
+                    next = jiter.array_step()?;
+                    index += 1;
+                }
+                Ok(())
+            }
+            Peek::Object => {
+                self.scan_depth(depth + 1)?;
+                let mut before_key = jiter.current_index();
+                let mut next_key = jiter.known_object()?.map(str::to_owned);
+                let mut count = 0;
+                while let Some(key) = next_key {
+                    count += 1;
+                    self.scan_items(count)?;
+                    self.scan_length(key.chars().count())
+                        .map_err(|e| e.map(|e| e.with_key(&key, before_key)))?;
+                    let peek = jiter.peek()?;
+                    self.scan_child(jiter, data, peek, depth)
+                        .map_err(|e| e.map(|e| e.with_outer_location(key.as_str())))?;
+                    before_key = jiter.current_index();
+                    next_key = jiter.next_key()?.map(str::to_owned);
+                }
+                Ok(())
+            }
+            peek if peek.is_num() => {
+                // counted before the number is parsed, the parser rejects ints of more than 4300 digits
+                self.scan_length(number_len(&data[start..]))?;
+                Ok(jiter.known_skip(peek)?)
+            }
+            peek => Ok(jiter.known_skip(peek)?),
+        }
+    }
+
+    /// Check an item of a container, the errors of the item's own value get its input.
+    fn scan_child(&self, jiter: &mut Jiter<'_>, data: &[u8], peek: Peek, depth: usize) -> Result<(), JsonScanError> {
+        // deeper JSON is left to the parser, which has a lower recursion limit, even with a higher depth limit
+        if depth >= usize::from(RECURSION_GUARD_LIMIT) {
+            return Ok(jiter.known_skip(peek)?);
+        }
+        let start = jiter.current_index();
+        self.scan_json(jiter, data, peek, depth + 1)
+            .map_err(|e| e.map(|e| e.with_start(start)))
+    }
+
+    fn scan_length(&self, len: usize) -> Result<(), JsonScanError> {
+        match self.length {
+            Some(max) if len > max => Err(JsonLimitError::new("length", max)),
+            _ => Ok(()),
+        }
+    }
+
+    fn scan_depth(&self, depth: usize) -> Result<(), JsonScanError> {
+        match self.depth {
+            Some(max) if depth > max => Err(JsonLimitError::new("depth", max)),
+            _ => Ok(()),
+        }
+    }
+
+    fn scan_items(&self, len: usize) -> Result<(), JsonScanError> {
+        match self.items {
+            Some(max) if len > max => Err(JsonLimitError::new("items", max)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check `input`, returning the items to check next if it's a container. `depth` is the number of containers
+    /// around `input`, `seen` is the deepest each container has been checked at, so containers included more than
+    /// once, or in themselves, aren't checked again.
+    fn check_python_item<'py>(
+        &self,
+        input: &Bound<'py, PyAny>,
+        depth: usize,
+        seen: &mut HashMap<usize, usize>,
+    ) -> ValResult<Option<Vec<Item<'py>>>> {
+        if input.is_instance_of::<PyString>()
+            || input.is_instance_of::<PyBytes>()
+            || input.is_instance_of::<PyByteArray>()
+        {
+            self.check_length(input.len()?, input)?;
+            return Ok(None);
+        }
+        if let Ok(dict) = input.downcast::<PyDict>() {
+            if !self.enter_container(input, dict.len(), depth, seen)? {
+                return Ok(None);
+            }
+            let mut items = Vec::with_capacity(dict.len() * 2);
+            for (key, value) in dict.iter() {
+                items.push((Position::Key(key.clone()), key.clone()));
+                items.push((Position::Value(key), value));
+            }
+            return Ok(Some(items));
+        }
+        let items = if let Ok(list) = input.downcast::<PyList>() {
+            list.iter().collect::<Vec<_>>()
+        } else if let Ok(tuple) = input.downcast::<PyTuple>() {
+            tuple.iter().collect()
+        } else if let Ok(set) = input.downcast::<PySet>() {
+            set.iter().collect()
+        } else if let Ok(frozenset) = input.downcast::<PyFrozenSet>() {
+            frozenset.iter().collect()
+        } else {
+            return Ok(None);
+        };
+        if !self.enter_container(input, items.len(), depth, seen)? {
+            return Ok(None);
+        }
+        Ok(Some(
+            items
+                .into_iter()
+                .enumerate()
+                .map(|(index, item)| (Position::Index(index), item))
+                .collect(),
+        ))
+    }
+
+    /// Check the items and depth of a container, returning whether its items should be checked.
+    fn enter_container(
+        &self,
+        input: &Bound<'_, PyAny>,
+        len: usize,
+        depth: usize,
+        seen: &mut HashMap<usize, usize>,
+    ) -> ValResult<bool> {
+        self.check_container(len, depth + 1, input)?;
+        // without a depth limit, cyclic containers are only followed as deep as validation could go
+        if self.depth.is_none() && depth >= usize::from(RECURSION_GUARD_LIMIT) {
+            return Ok(false);
+        }
+        match seen.entry(input.as_ptr() as usize) {
+            Entry::Occupied(entry) if *entry.get() >= depth => Ok(false),
+            Entry::Occupied(mut entry) => {
+                entry.insert(depth);
+                Ok(true)
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(depth);
+                Ok(true)
+            }
+        }
+    }
+
+    fn check_json_at(&self, input: &JsonValue<'_>, depth: usize) -> ValResult<()> {
+        match input {
+            JsonValue::Str(s) => self.check_length(s.chars().count(), input),
+            JsonValue::Array(array) => {
+                self.check_container(array.len(), depth + 1, input)?;
+                for (index, item) in array.iter().enumerate() {
+                    self.check_json_at(item, depth + 1)
+                        .map_err(|e| e.with_outer_location(index))?;
+                }
+                Ok(())
+            }
+            JsonValue::Object(object) => {
+                self.check_container(object.len(), depth + 1, input)?;
+                for (key, value) in object.iter() {
+                    self.check_length(key.chars().count(), key.as_ref())
+                        .map_err(|e| e.with_outer_location("[key]").with_outer_location(key.as_ref()))?;
+                    self.check_json_at(value, depth + 1)
+                        .map_err(|e| e.with_outer_location(key.as_ref()))?;
+                }
+                Ok(())
+            }
+            JsonValue::BigInt(int) => self.check_length(int.to_string().len(), input),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_length(&self, len: usize, input: impl ToErrorValue) -> ValResult<()> {
+        match self.length {
+            Some(max) if len > max => Err(exceeded("length", max, input)),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_container(&self, len: usize, depth: usize, input: impl ToErrorValue) -> ValResult<()> {
+        if let Some(max) = self.depth.filter(|&max| depth > max) {
+            return Err(exceeded("depth", max, input));
+        }
+        match self.items {
+            Some(max) if len > max => Err(exceeded("items", max, input)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// An item of a Python container, and where it is in the container.
+type Item<'py> = (Position<'py>, Bound<'py, PyAny>);
+
+/// Where an item is in the Python container it's checked in.
+enum Position<'py> {
+    Index(usize),
+    Key(Bound<'py, PyAny>),
+    Value(Bound<'py, PyAny>),
+}
+
+impl Position<'_> {
+    fn add_to(self, error: ValError) -> ValError {
+        match self {
+            Self::Index(index) => error.with_outer_location(index),
+            Self::Key(key) => error.with_outer_location("[key]").with_outer_location(&key),
+            Self::Value(key) => error.with_outer_location(&key),
+        }
+    }
+}
+
+/// Why checking JSON data stopped.
+enum JsonScanError {
+    // the data is invalid, which the parser reports
+    Json,
+    Limit(JsonLimitError),
+}
+
+impl From<JiterError> for JsonScanError {
+    fn from(_: JiterError) -> Self {
+        Self::Json
+    }
+}
+
+impl JsonScanError {
+    fn map(self, f: impl FnOnce(JsonLimitError) -> JsonLimitError) -> Self {
+        match self {
+            Self::Limit(error) => Self::Limit(f(error)),
+            Self::Json => Self::Json,
+        }
+    }
+}
+
+/// A limit exceeded by JSON data, the input of the error is the JSON text of the value exceeding it.
+struct JsonLimitError {
+    limit: &'static str,
+    max: usize,
+    // innermost first
+    location: Vec<LocItem>,
+    // set for keys, otherwise the input is the value at `start`
+    key: Option<String>,
+    start: Option<usize>,
+}
+
+impl JsonLimitError {
+    #[allow(clippy::new_ret_no_self)]
+    fn new(limit: &'static str, max: usize) -> JsonScanError {
+        JsonScanError::Limit(Self {
+            limit,
+            max,
+            location: Vec::new(),
+            key: None,
+            start: None,
+        })
+    }
+
+    fn with_outer_location(mut self, loc: impl Into<LocItem>) -> Self {
+        self.location.push(loc.into());
+        self
+    }
+
+    /// The error of a key, whose input is the key, which is read from `before_key`.
+    fn with_key(mut self, key: &str, before_key: usize) -> Self {
+        self.key = Some(key.to_owned());
+        self.start = Some(before_key);
+        self.with_outer_location("[key]").with_outer_location(key)
+    }
+
+    /// The value exceeding the limit starts at `start`, unless it's already known.
+    fn with_start(mut self, start: usize) -> Self {
+        self.start.get_or_insert(start);
+        self
+    }
+
+    fn into_val_error(self, json_data: &[u8]) -> ValError {
+        let start = self.start.unwrap_or(0);
+        // only `{` or `,` and whitespace come before the quote of a key
+        let offset = match self.key {
+            Some(_) => start + json_data[start..].iter().position(|&b| b == b'"').unwrap_or(0),
+            None => start,
+        };
+        let input = self.key.unwrap_or_else(|| {
+            let mut jiter = Jiter::new(&json_data[start..]).with_allow_inf_nan();
+            // invalid JSON after the start of the value ends its text where it's invalid
+            let len = match jiter.next_skip() {
+                Ok(()) => jiter.current_index(),
+                Err(error) => error.index.max(number_len(&json_data[start..])),
+            };
+            String::from_utf8_lossy(&json_data[start..start + len]).into_owned()
+        });
+        let error =
+            exceeded(self.limit, self.max, JsonValue::Str(input.into())).with_json_input(JsonInput::Offset(offset));
+        self.location.into_iter().fold(error, ValError::with_outer_location)
+    }
+}
+
+/// The length of the JSON number at the start of `data`.
+fn number_len(data: &[u8]) -> usize {
+    data.iter()
+        .take_while(|b| matches!(b, b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E'))
+        .count()
+}
+
+fn exceeded(limit: &str, max: usize, input: impl ToErrorValue) -> ValError {
+    ValError::new(
+        ErrorType::InputLimitExceeded {
+            limit: limit.to_string(),
+            max,
+            context: None,
+        },
+        input,
+    )
+}
@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use pyo3::prelude::*;
+
+use crate::errors::ValResult;
+
+use super::{Exactness, ValidationState};
+
+/// Outputs of model instances revalidated so far in one validation, collected when validating with
+/// `identity_cache=True`, so an instance referenced many times in the input is only revalidated once.
+#[derive(Debug, Default)]
+pub struct InstanceCache {
+    // keyed by the address of the validator, the address of the input and the validation time `strict`
+    entries: HashMap<(usize, usize, Option<bool>), Entry>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    // held so the address of the input can't be reused by another object during validation
+    _input: Py<PyAny>,
+    output: Py<PyAny>,
+    exactness: Option<Exactness>,
+    fields_set_count: Option<usize>,
+}
+
+/// Revalidate the instance `input` with `validate`, or reuse the output of revalidating it with the same validator
+/// earlier in the validation, errors aren't cached.
+pub(super) fn validate_cached<'py, T>(
+    py: Python<'py>,
+    validator: &T,
+    input: &Bound<'py, PyAny>,
+    state: &mut ValidationState<'_, 'py>,
+    validate: impl FnOnce(&mut ValidationState<'_, 'py>) -> ValResult<Py<PyAny>>,
+) -> ValResult<Py<PyAny>> {
+    let key = (
+        (validator as *const T) as usize,
+        input.as_ptr() as usize,
+        state.extra().strict,
+    );
+    let Some(cache) = state.instance_cache.as_ref() else {
+        return validate(state);
+    };
+    if let Some(entry) = cache.entries.get(&key) {
+        let (output, exactness, fields_set_count) =
+            (entry.output.clone_ref(py), entry.exactness, entry.fields_set_count);
+        if let Some(exactness) = exactness {
+            state.floor_exactness(exactness);
+        }
+        state.fields_set_count = fields_set_count;
+        return Ok(output);
+    }
+
+    // the exactness of this instance alone, so it can be applied again when the output is reused
+    let outer_exactness = state.exactness.map(|_| state.exactness.replace(Exactness::Exact));
+    let result = validate(state);
+    let exactness = match outer_exactness {
+        Some(outer_exactness) => std::mem::replace(&mut state.exactness, outer_exactness),
+        None => None,
+    };
+    if let Some(exactness) = exactness {
+        state.floor_exactness(exactness);
+    }
+
+    let output = result?;
+    let fields_set_count = state.fields_set_count;
+    if let Some(cache) = state.instance_cache.as_mut() {
+        cache.entries.insert(
+            key,
+            Entry {
+                _input: input.clone().unbind(),
+                output: output.clone_ref(py),
+                exactness,
+                fields_set_count,
+            },
+        );
+    }
+    Ok(output)
+}
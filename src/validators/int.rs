@@ -1,18 +1,20 @@
 use std::sync::Arc;
 
-use num_bigint::BigInt;
+use num_bigint::{BigInt, Sign};
 use pyo3::exceptions::PyValueError;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 use pyo3::IntoPyObjectExt;
 
-use crate::build_tools::is_strict;
 use crate::build_tools::LazyLock;
+use crate::build_tools::{is_strict, parse_interval, schema_interval, DigitSeparators, IntervalBounds};
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::{Input, Int};
+use crate::input::{float_as_int, str_as_int, EitherInt, Input, Int, ValidationMatch};
+use crate::tools::SchemaDict;
 
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::units::Units;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState, Validator};
 
 fn validate_as_int(schema: &Bound<'_, PyDict>, key: &Bound<'_, PyString>) -> PyResult<Option<Int>> {
     match schema.get_item(key)? {
@@ -31,16 +33,144 @@ fn validate_as_int(schema: &Bound<'_, PyDict>, key: &Bound<'_, PyString>) -> PyR
     }
 }
 
+/// The `int_float_tolerance` config, must be a non-negative number.
+fn float_tolerance(py: Python<'_>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Option<f64>> {
+    let tolerance: Option<f64> = config.get_as(intern!(py, "int_float_tolerance"))?;
+    match tolerance {
+        Some(tolerance) if !(tolerance >= 0.0 && tolerance.is_finite()) => Err(PyValueError::new_err(
+            "'int_float_tolerance' must be a non-negative number",
+        )),
+        tolerance => Ok(tolerance),
+    }
+}
+
+/// Validate an int, with `int_float_tolerance` numbers with a fractional part are rounded to the nearest integer in
+/// lax mode if they're within the tolerance of it, with `allow_radix_prefix` strings like `'0x1F'` are parsed
+/// in the base given by their prefix, with `digit_separators` strings like `'1,000'` are accepted, and with `unit`
+/// strings with a unit suffix like `'5m'` are converted to the schema's unit.
+fn validate_int<'a, 'py: 'a>(
+    py: Python<'py>,
+    input: &'a (impl Input<'py> + ?Sized),
+    strict: bool,
+    float_tolerance: Option<f64>,
+    allow_radix_prefix: bool,
+    digit_separators: Option<&DigitSeparators>,
+    units: Option<&Units>,
+) -> ValResult<ValidationMatch<EitherInt<'a>>> {
+    let result = input.validate_int(strict);
+    let Err(ValError::LineErrors(errors)) = &result else {
+        return result;
+    };
+    let [error] = errors.as_slice() else {
+        return result;
+    };
+    if matches!(error.error_type, ErrorType::IntParsing { .. }) {
+        if allow_radix_prefix {
+            if let Some(int) = input
+                .exact_str()
+                .ok()
+                .and_then(|either_str| either_str.as_cow().ok().and_then(|s| radix_prefixed_int(&s)))
+            {
+                return Ok(ValidationMatch::lax(int));
+            }
+        }
+        if let Some(stripped) = digit_separators.and_then(|separators| separators.strip(input)) {
+            return str_as_int(input, &stripped).map(ValidationMatch::lax);
+        }
+        return match (units, result) {
+            (Some(units), Err(error)) => units.convert_int(py, input, error).map(ValidationMatch::lax),
+            (_, result) => result,
+        };
+    }
+    let Some(tolerance) = float_tolerance else {
+        return result;
+    };
+    if !matches!(error.error_type, ErrorType::IntFromFloat { .. }) {
+        return result;
+    }
+    // the input is a finite number with a fractional part, so it's also a valid float
+    let float = input.validate_float(false)?.into_inner().as_f64();
+    let rounded = float.round();
+    if (float - rounded).abs() <= tolerance {
+        float_as_int(input, rounded).map(ValidationMatch::lax)
+    } else {
+        Err(ValError::new(
+            ErrorType::IntFromFloatTolerance {
+                tolerance: tolerance.into(),
+                context: None,
+            },
+            input,
+        ))
+    }
+}
+
+/// Parse a string with a `0x`, `0o` or `0b` prefix, and optionally a sign, like `int(value, 0)` in Python.
+fn radix_prefixed_int(s: &str) -> Option<EitherInt<'static>> {
+    let s = s.trim();
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let (radix, digits) = match s.get(..2)? {
+        "0x" | "0X" => (16, &s[2..]),
+        "0o" | "0O" => (8, &s[2..]),
+        "0b" | "0B" => (2, &s[2..]),
+        _ => return None,
+    };
+    // as in Python, underscores can follow the prefix and separate digits, e.g. `0x_ff_ff`
+    let digits = digits.strip_prefix('_').unwrap_or(digits);
+    if digits.is_empty() || digits.starts_with('_') || digits.ends_with('_') || digits.contains("__") {
+        return None;
+    }
+    // `from_str_radix` also accepts a sign, which isn't valid after the prefix
+    if !digits.bytes().all(|b| b == b'_' || char::from(b).is_digit(radix)) {
+        return None;
+    }
+    let digits = digits.replace('_', "");
+    let mut int = BigInt::parse_bytes(digits.as_bytes(), radix)?;
+    if negative {
+        int = -int;
+    }
+    Some(match i64::try_from(&int) {
+        Ok(i) => EitherInt::I64(i),
+        Err(_) => EitherInt::BigInt(int),
+    })
+}
+
 #[derive(Debug, Clone)]
 pub struct IntValidator {
     strict: bool,
+    float_tolerance: Option<f64>,
+    allow_radix_prefix: bool,
+    digit_separators: Option<DigitSeparators>,
+    units: Option<Units>,
 }
 
-static STRICT_INT_VALIDATOR: LazyLock<Arc<CombinedValidator>> =
-    LazyLock::new(|| Arc::new(IntValidator { strict: true }.into()));
+static STRICT_INT_VALIDATOR: LazyLock<Arc<CombinedValidator>> = LazyLock::new(|| {
+    Arc::new(
+        IntValidator {
+            strict: true,
+            float_tolerance: None,
+            allow_radix_prefix: false,
+            digit_separators: None,
+            units: None,
+        }
+        .into(),
+    )
+});
 
-static LAX_INT_VALIDATOR: LazyLock<Arc<CombinedValidator>> =
-    LazyLock::new(|| Arc::new(IntValidator { strict: false }.into()));
+static LAX_INT_VALIDATOR: LazyLock<Arc<CombinedValidator>> = LazyLock::new(|| {
+    Arc::new(
+        IntValidator {
+            strict: false,
+            float_tolerance: None,
+            allow_radix_prefix: false,
+            digit_separators: None,
+            units: None,
+        }
+        .into(),
+    )
+});
 
 impl BuildValidator for IntValidator {
     const EXPECTED_TYPE: &'static str = "int";
@@ -55,10 +185,28 @@ impl BuildValidator for IntValidator {
             || schema.get_item(intern!(py, "le"))?.is_some()
             || schema.get_item(intern!(py, "lt"))?.is_some()
             || schema.get_item(intern!(py, "ge"))?.is_some()
-            || schema.get_item(intern!(py, "gt"))?.is_some();
+            || schema.get_item(intern!(py, "gt"))?.is_some()
+            || schema.get_item(intern!(py, "interval"))?.is_some()
+            || schema.get_item(intern!(py, "max_digits"))?.is_some();
+
+        let float_tolerance = float_tolerance(py, config)?;
+        let allow_radix_prefix = schema.get_as(intern!(py, "allow_radix_prefix"))?.unwrap_or(false);
+        let digit_separators = DigitSeparators::from_schema(schema)?;
+        let units = Units::from_schema(schema, false)?;
 
         if use_constrained {
             ConstrainedIntValidator::build(schema, config)
+        } else if float_tolerance.is_some() || allow_radix_prefix || digit_separators.is_some() || units.is_some() {
+            Ok(Arc::new(
+                IntValidator {
+                    strict: is_strict(schema, config)?,
+                    float_tolerance,
+                    allow_radix_prefix,
+                    digit_separators,
+                    units,
+                }
+                .into(),
+            ))
         } else if is_strict(schema, config)? {
             Ok(STRICT_INT_VALIDATOR.clone())
         } else {
@@ -67,7 +215,7 @@ impl BuildValidator for IntValidator {
     }
 }
 
-impl_py_gc_traverse!(IntValidator {});
+impl_py_gc_traverse!(IntValidator { units });
 
 impl Validator for IntValidator {
     fn validate<'py>(
@@ -76,9 +224,20 @@ impl Validator for IntValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        input
-            .validate_int(state.strict_or(self.strict))
-            .and_then(|val_match| Ok(val_match.unpack(state).into_py_any(py)?))
+        validate_int(
+            py,
+            input,
+            state.strict_or(self.strict),
+            self.float_tolerance,
+            self.allow_radix_prefix,
+            self.digit_separators.as_ref(),
+            self.units.as_ref(),
+        )
+        .and_then(|val_match| Ok(val_match.unpack(state).into_py_any(py)?))
+    }
+
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        generator.int(None, None, None).into_bound_py_any(py)
     }
 
     fn get_name(&self) -> &str {
@@ -89,29 +248,145 @@ impl Validator for IntValidator {
 #[derive(Debug, Clone)]
 pub struct ConstrainedIntValidator {
     strict: bool,
+    float_tolerance: Option<f64>,
+    allow_radix_prefix: bool,
+    digit_separators: Option<DigitSeparators>,
+    units: Option<Units>,
     multiple_of: Option<Int>,
     le: Option<Int>,
     lt: Option<Int>,
     ge: Option<Int>,
     gt: Option<Int>,
+    // if the bounds came from an `interval`, it's used in the error when they're violated
+    interval: Option<String>,
+    max_digits: Option<DigitLimit>,
+}
+
+/// Limit on the number of digits of an integer.
+#[derive(Debug, Clone)]
+struct DigitLimit {
+    max_digits: u64,
+}
+
+impl DigitLimit {
+    fn new(max_digits: u64) -> Self {
+        Self { max_digits }
+    }
+
+    /// The largest absolute value allowed, saturating at `i128::MAX`.
+    fn max_abs(&self) -> i128 {
+        u32::try_from(self.max_digits)
+            .ok()
+            .and_then(|exp| 10_i128.checked_pow(exp))
+            .map_or(i128::MAX, |limit| limit - 1)
+    }
+
+    fn error(&self) -> ErrorType {
+        ErrorType::IntMaxDigits {
+            max_digits: self.max_digits,
+            context: None,
+        }
+    }
+
+    /// Check the number of digits in a string before it's parsed, since parsing very long strings as
+    /// integers is slow, this is an upper bound as the string might not be a valid integer.
+    fn check_str<'py>(&self, input: &(impl Input<'py> + ?Sized)) -> ValResult<()> {
+        if let Ok(either_str) = input.exact_str() {
+            let str = either_str.as_cow()?;
+            let digits = str
+                .trim()
+                .trim_start_matches(['+', '-'])
+                .split('.')
+                .next()
+                .unwrap_or_default()
+                .trim_start_matches(['0', '_'])
+                .bytes()
+                .filter(u8::is_ascii_digit)
+                .count();
+            if digits as u64 > self.max_digits {
+                return Err(ValError::new(self.error(), input));
+            }
+        }
+        Ok(())
+    }
+
+    fn check_int<'py>(&self, input: &(impl Input<'py> + ?Sized), int_value: &Int) -> ValResult<()> {
+        if int_digits(int_value) > self.max_digits {
+            Err(ValError::new(self.error(), input))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The number of decimal digits of an integer, ignoring its sign.
+fn int_digits(int_value: &Int) -> u64 {
+    match int_value {
+        Int::I64(i) => i.unsigned_abs().checked_ilog10().map_or(1, |log| u64::from(log) + 1),
+        Int::Big(big) => {
+            // a number with `bits` bits has either `floor((bits - 1) * log10(2)) + 1` or one more digits, so the
+            // digits only need counting to tell them apart
+            let bits = big.bits();
+            let fewest = ((bits.saturating_sub(1)) as f64 * std::f64::consts::LOG10_2) as u64 + 1;
+            let most = (bits as f64 * std::f64::consts::LOG10_2).ceil() as u64;
+            if fewest >= most {
+                fewest
+            } else {
+                big.magnitude().to_str_radix(10).len() as u64
+            }
+        }
+    }
 }
 
 impl ConstrainedIntValidator {
     fn build(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Arc<CombinedValidator>> {
         let py = schema.py();
+        let interval = schema_interval(schema)?;
+        let IntervalBounds { le, lt, ge, gt } = match interval {
+            Some(ref interval) => parse_interval(interval, |bound| {
+                bound
+                    .parse()
+                    .map(Int::I64)
+                    .or_else(|_| bound.parse().map(Int::Big))
+                    .ok()
+            })?,
+            None => IntervalBounds {
+                le: validate_as_int(schema, intern!(py, "le"))?,
+                lt: validate_as_int(schema, intern!(py, "lt"))?,
+                ge: validate_as_int(schema, intern!(py, "ge"))?,
+                gt: validate_as_int(schema, intern!(py, "gt"))?,
+            },
+        };
         Ok(CombinedValidator::ConstrainedInt(Self {
             strict: is_strict(schema, config)?,
+            float_tolerance: float_tolerance(py, config)?,
+            allow_radix_prefix: schema.get_as(intern!(py, "allow_radix_prefix"))?.unwrap_or(false),
+            digit_separators: DigitSeparators::from_schema(schema)?,
+            units: Units::from_schema(schema, false)?,
             multiple_of: validate_as_int(schema, intern!(py, "multiple_of"))?,
-            le: validate_as_int(schema, intern!(py, "le"))?,
-            lt: validate_as_int(schema, intern!(py, "lt"))?,
-            ge: validate_as_int(schema, intern!(py, "ge"))?,
-            gt: validate_as_int(schema, intern!(py, "gt"))?,
+            le,
+            lt,
+            ge,
+            gt,
+            interval,
+            max_digits: schema.get_as::<u64>(intern!(py, "max_digits"))?.map(DigitLimit::new),
         })
         .into())
     }
 }
 
-impl_py_gc_traverse!(ConstrainedIntValidator {});
+impl_py_gc_traverse!(ConstrainedIntValidator { units });
+
+/// The error for a violated bound, `out_of_range` if the bounds came from an `interval`.
+pub(super) fn bound_error(interval: Option<&str>, error_type: ErrorType) -> ErrorType {
+    match interval {
+        Some(interval) => ErrorType::OutOfRange {
+            interval: interval.to_string(),
+            context: None,
+        },
+        None => error_type,
+    }
+}
 
 impl Validator for ConstrainedIntValidator {
     fn validate<'py>(
@@ -120,9 +395,25 @@ impl Validator for ConstrainedIntValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        let either_int = input.validate_int(state.strict_or(self.strict))?.unpack(state);
+        if let Some(ref max_digits) = self.max_digits {
+            max_digits.check_str(input)?;
+        }
+        let either_int = validate_int(
+            py,
+            input,
+            state.strict_or(self.strict),
+            self.float_tolerance,
+            self.allow_radix_prefix,
+            self.digit_separators.as_ref(),
+            self.units.as_ref(),
+        )?
+        .unpack(state);
         let int_value = either_int.as_int()?;
 
+        if let Some(ref max_digits) = self.max_digits {
+            max_digits.check_int(input, &int_value)?;
+        }
+
         if let Some(ref multiple_of) = self.multiple_of {
             if &int_value % multiple_of != Int::Big(BigInt::from(0)) {
                 return Err(ValError::new(
@@ -137,10 +428,13 @@ impl Validator for ConstrainedIntValidator {
         if let Some(ref le) = self.le {
             if &int_value > le {
                 return Err(ValError::new(
-                    ErrorType::LessThanEqual {
-                        le: le.clone().into(),
-                        context: None,
-                    },
+                    bound_error(
+                        self.interval.as_deref(),
+                        ErrorType::LessThanEqual {
+                            le: le.clone().into(),
+                            context: None,
+                        },
+                    ),
                     input,
                 ));
             }
@@ -148,10 +442,13 @@ impl Validator for ConstrainedIntValidator {
         if let Some(ref lt) = self.lt {
             if &int_value >= lt {
                 return Err(ValError::new(
-                    ErrorType::LessThan {
-                        lt: lt.clone().into(),
-                        context: None,
-                    },
+                    bound_error(
+                        self.interval.as_deref(),
+                        ErrorType::LessThan {
+                            lt: lt.clone().into(),
+                            context: None,
+                        },
+                    ),
                     input,
                 ));
             }
@@ -159,10 +456,13 @@ impl Validator for ConstrainedIntValidator {
         if let Some(ref ge) = self.ge {
             if &int_value < ge {
                 return Err(ValError::new(
-                    ErrorType::GreaterThanEqual {
-                        ge: ge.clone().into(),
-                        context: None,
-                    },
+                    bound_error(
+                        self.interval.as_deref(),
+                        ErrorType::GreaterThanEqual {
+                            ge: ge.clone().into(),
+                            context: None,
+                        },
+                    ),
                     input,
                 ));
             }
@@ -170,10 +470,13 @@ impl Validator for ConstrainedIntValidator {
         if let Some(ref gt) = self.gt {
             if &int_value <= gt {
                 return Err(ValError::new(
-                    ErrorType::GreaterThan {
-                        gt: gt.clone().into(),
-                        context: None,
-                    },
+                    bound_error(
+                        self.interval.as_deref(),
+                        ErrorType::GreaterThan {
+                            gt: gt.clone().into(),
+                            context: None,
+                        },
+                    ),
                     input,
                 ));
             }
@@ -181,6 +484,36 @@ impl Validator for ConstrainedIntValidator {
         Ok(either_int.into_py_any(py)?)
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        // ints beyond `i128` saturate, examples that large would be unhelpful anyway
+        let bound = |int: &Int| match int {
+            Int::I64(i) => i128::from(*i),
+            Int::Big(big) => i128::try_from(big).unwrap_or(if big.sign() == Sign::Minus {
+                i128::MIN
+            } else {
+                i128::MAX
+            }),
+        };
+        let digits = self.max_digits.as_ref().map(DigitLimit::max_abs);
+        let ge = [
+            self.ge.as_ref().map(bound),
+            self.gt.as_ref().map(|gt| bound(gt).saturating_add(1)),
+            digits.map(|d| -d),
+        ];
+        let le = [
+            self.le.as_ref().map(bound),
+            self.lt.as_ref().map(|lt| bound(lt).saturating_sub(1)),
+            digits,
+        ];
+        let multiple_of = self.multiple_of.as_ref().map(bound);
+        let value = generator.int(
+            ge.into_iter().flatten().max(),
+            le.into_iter().flatten().min(),
+            multiple_of,
+        );
+        value.into_bound_py_any(py)
+    }
+
     fn get_name(&self) -> &'static str {
         "constrained-int"
     }
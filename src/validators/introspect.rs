@@ -0,0 +1,164 @@
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyType};
+
+use crate::lookup_key::LookupKeyCollection;
+
+use super::with_default::DefaultType;
+use super::{CombinedValidator, Validator};
+
+/// A field of a model, dataclass or typed dict, as seen by `SchemaValidator.fields` and co.
+pub(super) struct FieldToInspect<'v> {
+    pub name: &'v str,
+    pub lookup_key_collection: &'v LookupKeyCollection,
+    pub validator: &'v CombinedValidator,
+    pub required: bool,
+    pub serialization_alias: Option<&'v str>,
+}
+
+/// What the built validator knows about a field, owned so it can be taken out of a definition.
+struct InspectedField {
+    name: String,
+    required: bool,
+    has_default: bool,
+    /// the default, when it's a value rather than a factory
+    default: Option<Py<PyAny>>,
+    validation_alias: Option<Py<PyAny>>,
+    serialization_alias: Option<String>,
+    type_name: String,
+    model: Option<Py<PyType>>,
+}
+
+impl InspectedField {
+    fn new(py: Python, field: FieldToInspect) -> PyResult<Self> {
+        let (validator, has_default, default) = match field.validator {
+            CombinedValidator::WithDefault(with_default) => {
+                let default = match with_default.default() {
+                    DefaultType::Default(default) => Some(default.clone_ref(py)),
+                    _ => None,
+                };
+                (with_default.validator(), with_default.has_default(), default)
+            }
+            validator => (validator, false, None),
+        };
+        Ok(Self {
+            name: field.name.to_string(),
+            required: field.required,
+            has_default,
+            default,
+            validation_alias: field.lookup_key_collection.validation_alias(py)?.map(Bound::unbind),
+            serialization_alias: field.serialization_alias.map(str::to_string),
+            type_name: validator.get_name().to_string(),
+            model: model_class(py, validator)?,
+        })
+    }
+}
+
+/// `SchemaValidator.fields`, what's known about each field, by name.
+pub(super) fn fields<'py>(py: Python<'py>, validator: &CombinedValidator) -> PyResult<Bound<'py, PyDict>> {
+    let fields = PyDict::new(py);
+    for field in inspect_fields(py, validator)? {
+        let dict = PyDict::new(py);
+        dict.set_item(intern!(py, "required"), field.required)?;
+        dict.set_item(intern!(py, "has_default"), field.has_default)?;
+        dict.set_item(intern!(py, "validation_alias"), field.validation_alias)?;
+        dict.set_item(intern!(py, "serialization_alias"), field.serialization_alias)?;
+        dict.set_item(intern!(py, "type"), field.type_name)?;
+        dict.set_item(intern!(py, "model"), field.model)?;
+        fields.set_item(field.name, dict)?;
+    }
+    Ok(fields)
+}
+
+/// `SchemaValidator.required_fields`, the names of the fields which must be given.
+pub(super) fn required_fields<'py>(py: Python<'py>, validator: &CombinedValidator) -> PyResult<Bound<'py, PyList>> {
+    let names = inspect_fields(py, validator)?
+        .into_iter()
+        .filter(|field| field.required)
+        .map(|field| field.name)
+        .collect::<Vec<_>>();
+    PyList::new(py, names)
+}
+
+/// `SchemaValidator.aliases`, the `validation_alias` or `serialization_alias` of each field which has one, by name.
+pub(super) fn aliases<'py>(py: Python<'py>, validator: &CombinedValidator, mode: &str) -> PyResult<Bound<'py, PyDict>> {
+    let serialization = match mode {
+        "validation" => false,
+        "serialization" => true,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Invalid mode: `{mode}`, expected 'validation' or 'serialization'"
+            )))
+        }
+    };
+    let aliases = PyDict::new(py);
+    for field in inspect_fields(py, validator)? {
+        if serialization {
+            if let Some(alias) = field.serialization_alias {
+                aliases.set_item(field.name, alias)?;
+            }
+        } else if let Some(alias) = field.validation_alias {
+            aliases.set_item(field.name, alias)?;
+        }
+    }
+    Ok(aliases)
+}
+
+/// `SchemaValidator.defaults`, the default of each field which has one, by name. Default factories aren't called,
+/// so fields with a factory are left out.
+pub(super) fn defaults<'py>(py: Python<'py>, validator: &CombinedValidator) -> PyResult<Bound<'py, PyDict>> {
+    let defaults = PyDict::new(py);
+    for field in inspect_fields(py, validator)? {
+        if let Some(default) = field.default {
+            defaults.set_item(field.name, default)?;
+        }
+    }
+    Ok(defaults)
+}
+
+/// The fields of the model, dataclass or typed dict `validator` validates, looking through references to
+/// definitions and validator functions wrapping it.
+fn inspect_fields(py: Python, validator: &CombinedValidator) -> PyResult<Vec<InspectedField>> {
+    let fields = match validator {
+        CombinedValidator::ModelFields(model_fields) => model_fields.inspect_fields(),
+        CombinedValidator::DataclassArgs(dataclass_args) => dataclass_args.inspect_fields(),
+        CombinedValidator::TypedDict(typed_dict) => typed_dict.inspect_fields(),
+        CombinedValidator::Model(model) if !model.is_root_model() => return inspect_fields(py, model.validator()),
+        CombinedValidator::Dataclass(dataclass) => return inspect_fields(py, dataclass.validator()),
+        CombinedValidator::FunctionBefore(function) => return inspect_fields(py, function.validator()),
+        CombinedValidator::FunctionAfter(function) => return inspect_fields(py, function.validator()),
+        CombinedValidator::FunctionWrap(function) => return inspect_fields(py, function.validator()),
+        CombinedValidator::DefinitionRef(definition_ref) => {
+            return definition_ref.read_definition(py, |validator| inspect_fields(py, validator))?
+        }
+        _ => {
+            return Err(PyTypeError::new_err(
+                "Field introspection is only supported for model, dataclass and typed-dict schemas",
+            ))
+        }
+    };
+    fields.into_iter().map(|field| InspectedField::new(py, field)).collect()
+}
+
+/// The class of the model or dataclass a field holds, if it holds one, possibly `None` or with validator functions.
+fn model_class(py: Python, validator: &CombinedValidator) -> PyResult<Option<Py<PyType>>> {
+    match validator {
+        CombinedValidator::Model(model) => Ok(Some(model.class().clone_ref(py))),
+        CombinedValidator::Dataclass(dataclass) => Ok(Some(dataclass.class().clone_ref(py))),
+        CombinedValidator::WithDefault(with_default) => model_class(py, with_default.validator()),
+        CombinedValidator::Nullable(nullable) => model_class(py, nullable.validator()),
+        CombinedValidator::FunctionBefore(function) => model_class(py, function.validator()),
+        CombinedValidator::FunctionAfter(function) => model_class(py, function.validator()),
+        CombinedValidator::FunctionWrap(function) => model_class(py, function.validator()),
+        CombinedValidator::DefinitionRef(definition_ref) => {
+            definition_ref.read_definition(py, |validator| model_class(py, validator))?
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Whether a field gets a default when it's left out.
+pub(super) fn has_default(validator: &CombinedValidator) -> bool {
+    matches!(validator, CombinedValidator::WithDefault(with_default) if with_default.has_default())
+}
@@ -0,0 +1,350 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::Arc;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyType};
+
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::{input_as_python_instance, Input, InputType, Int};
+use crate::tools::SchemaDict;
+
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IpVersion {
+    V4,
+    V6,
+}
+
+impl IpVersion {
+    fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        match schema.get_as::<u8>(intern!(schema.py(), "version"))? {
+            None => Ok(None),
+            Some(4) => Ok(Some(Self::V4)),
+            Some(6) => Ok(Some(Self::V6)),
+            Some(version) => py_schema_err!("IP version should be 4 or 6, got {}", version),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IpKind {
+    Address,
+    Network,
+    Interface,
+}
+
+impl IpKind {
+    fn class_name(self, version: IpVersion) -> &'static str {
+        match (self, version) {
+            (Self::Address, IpVersion::V4) => "IPv4Address",
+            (Self::Address, IpVersion::V6) => "IPv6Address",
+            (Self::Network, IpVersion::V4) => "IPv4Network",
+            (Self::Network, IpVersion::V6) => "IPv6Network",
+            (Self::Interface, IpVersion::V4) => "IPv4Interface",
+            (Self::Interface, IpVersion::V6) => "IPv6Interface",
+        }
+    }
+}
+
+/// An address and prefix length parsed from the input, the prefix length of an address is its number of bits.
+#[derive(Debug, Clone)]
+struct IpValue {
+    addr: IpAddr,
+    prefix: u8,
+    // the zone of a scoped IPv6 address, e.g. `eth0` in `fe80::1%eth0`
+    scope_id: Option<String>,
+}
+
+fn max_prefix(addr: &IpAddr) -> u8 {
+    match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+/// Validates IP addresses, networks and interfaces, the input is parsed and checked in Rust, and only the
+/// resulting `ipaddress` object is created in Python.
+#[derive(Debug, Clone)]
+pub struct IpValidator {
+    strict: bool,
+    kind: IpKind,
+    version: Option<IpVersion>,
+    v4_class: Option<Py<PyType>>,
+    v6_class: Option<Py<PyType>>,
+    name: &'static str,
+}
+
+// addresses have a fixed version, otherwise it's the optional `version` of the schema
+macro_rules! ip_builder {
+    ($struct_name:ident, $expected_type:literal, $kind:expr, $version:expr) => {
+        pub struct $struct_name;
+
+        impl BuildValidator for $struct_name {
+            const EXPECTED_TYPE: &'static str = $expected_type;
+
+            fn build(
+                schema: &Bound<'_, PyDict>,
+                config: Option<&Bound<'_, PyDict>>,
+                _definitions: &mut DefinitionsBuilder<Arc<CombinedValidator>>,
+            ) -> PyResult<Arc<CombinedValidator>> {
+                let version = match $version {
+                    Some(version) => Some(version),
+                    None => IpVersion::from_schema(schema)?,
+                };
+                IpValidator::build(schema, config, $kind, version, Self::EXPECTED_TYPE)
+            }
+        }
+    };
+}
+
+ip_builder!(
+    IpV4AddressBuilder,
+    "ip-v4-address",
+    IpKind::Address,
+    Some(IpVersion::V4)
+);
+ip_builder!(
+    IpV6AddressBuilder,
+    "ip-v6-address",
+    IpKind::Address,
+    Some(IpVersion::V6)
+);
+ip_builder!(IpNetworkBuilder, "ip-network", IpKind::Network, None);
+ip_builder!(IpInterfaceBuilder, "ip-interface", IpKind::Interface, None);
+
+impl IpValidator {
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        kind: IpKind,
+        version: Option<IpVersion>,
+        name: &'static str,
+    ) -> PyResult<Arc<CombinedValidator>> {
+        let py = schema.py();
+        let ipaddress = py.import(intern!(py, "ipaddress"))?;
+        let import_class = |class_version: IpVersion| -> PyResult<Option<Py<PyType>>> {
+            if version.is_some_and(|version| version != class_version) {
+                return Ok(None);
+            }
+            Ok(Some(ipaddress.getattr(kind.class_name(class_version))?.extract()?))
+        };
+        Ok(CombinedValidator::Ip(Self {
+            strict: is_strict(schema, config)?,
+            kind,
+            version,
+            v4_class: import_class(IpVersion::V4)?,
+            v6_class: import_class(IpVersion::V6)?,
+            name,
+        })
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(IpValidator { v4_class, v6_class });
+
+impl Validator for IpValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        for class in [&self.v4_class, &self.v6_class].into_iter().flatten() {
+            if let Some(py_input) = input_as_python_instance(input, class.bind(py)) {
+                return Ok(py_input.clone().unbind());
+            }
+        }
+
+        let strict = state.strict_or(self.strict);
+        if state.extra().input_type == InputType::Python {
+            if strict {
+                return Err(ValError::new(
+                    ErrorType::IsInstanceOf {
+                        class: self.class_names(),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+            state.floor_exactness(Exactness::Lax);
+        }
+
+        let value = match input.validate_str(true, false) {
+            Ok(either_str) => self.parse_str(either_str.into_inner().as_cow()?.as_ref()),
+            Err(_) if !strict => self.parse_int(input).or_else(|| self.parse_packed(input)),
+            Err(_) => None,
+        };
+        match value {
+            Some(value) => self.create_py_object(py, value),
+            None => Err(ValError::new(self.error_type(), input)),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        self.name
+    }
+}
+
+impl IpValidator {
+    fn class_names(&self) -> String {
+        [IpVersion::V4, IpVersion::V6]
+            .into_iter()
+            .filter(|version| self.allows(*version))
+            .map(|version| self.kind.class_name(version))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+
+    fn allows(&self, version: IpVersion) -> bool {
+        self.version.map_or(true, |expected| expected == version)
+    }
+
+    fn error_type(&self) -> ErrorType {
+        let version = match self.version {
+            Some(IpVersion::V4) => "IPv4",
+            Some(IpVersion::V6) => "IPv6",
+            None => "IPv4 or IPv6",
+        }
+        .to_string();
+        match self.kind {
+            IpKind::Address => ErrorType::IpAddress { version, context: None },
+            IpKind::Network => ErrorType::IpNetwork { version, context: None },
+            IpKind::Interface => ErrorType::IpInterface { version, context: None },
+        }
+    }
+
+    /// Parse an address, or for networks and interfaces an address with an optional `/prefix`, where the prefix
+    /// can be a prefix length or for IPv4 a netmask or hostmask, like the `ipaddress` module.
+    ///
+    /// IPv6 addresses can have a scope ID after a `%`, e.g. `fe80::1%eth0` or `fe80::1%1/64`.
+    fn parse_str(&self, s: &str) -> Option<IpValue> {
+        let (addr, prefix) = match self.kind {
+            IpKind::Address => (s, None),
+            IpKind::Network | IpKind::Interface => match s.split_once('/') {
+                Some((addr, prefix)) => (addr, Some(prefix)),
+                None => (s, None),
+            },
+        };
+        let addr = match addr.split_once('%') {
+            // like `ipaddress`, the scope ID can be anything but empty or containing another `%`
+            Some((addr, scope_id)) if self.allows(IpVersion::V6) && !scope_id.is_empty() && !scope_id.contains('%') => {
+                let prefix = match prefix {
+                    Some(prefix) => parse_prefix(prefix, &IpAddr::V6(Ipv6Addr::UNSPECIFIED))?,
+                    None => 128,
+                };
+                return self.check(IpValue {
+                    addr: IpAddr::V6(addr.parse().ok()?),
+                    prefix,
+                    scope_id: Some(scope_id.to_string()),
+                });
+            }
+            Some(_) => return None,
+            None => match self.version {
+                Some(IpVersion::V4) => IpAddr::V4(addr.parse().ok()?),
+                Some(IpVersion::V6) => IpAddr::V6(addr.parse().ok()?),
+                None => addr.parse().ok()?,
+            },
+        };
+        let prefix = match prefix {
+            Some(prefix) => parse_prefix(prefix, &addr)?,
+            None => max_prefix(&addr),
+        };
+        self.check(IpValue {
+            addr,
+            prefix,
+            scope_id: None,
+        })
+    }
+
+    /// An address from its integer value, preferring IPv4 when either version is allowed like `ipaddress`.
+    fn parse_int<'py>(&self, input: &(impl Input<'py> + ?Sized)) -> Option<IpValue> {
+        let int = input.validate_int(true).ok()?.into_inner().as_int().ok()?;
+        let int = match int {
+            Int::I64(i) => u128::try_from(i).ok()?,
+            Int::Big(b) => u128::try_from(&b).ok()?,
+        };
+        let addr = match u32::try_from(int) {
+            Ok(v4) if self.allows(IpVersion::V4) => IpAddr::V4(Ipv4Addr::from(v4)),
+            _ if self.allows(IpVersion::V6) => IpAddr::V6(Ipv6Addr::from(int)),
+            _ => return None,
+        };
+        let prefix = max_prefix(&addr);
+        self.check(IpValue {
+            addr,
+            prefix,
+            scope_id: None,
+        })
+    }
+
+    /// An address from its packed bytes, only for Python input.
+    fn parse_packed<'py>(&self, input: &(impl Input<'py> + ?Sized)) -> Option<IpValue> {
+        let bytes = input.as_python()?.downcast::<PyBytes>().ok()?.as_bytes();
+        let addr = match bytes.len() {
+            4 if self.allows(IpVersion::V4) => IpAddr::V4(<[u8; 4]>::try_from(bytes).ok()?.into()),
+            16 if self.allows(IpVersion::V6) => IpAddr::V6(<[u8; 16]>::try_from(bytes).ok()?.into()),
+            _ => return None,
+        };
+        let prefix = max_prefix(&addr);
+        self.check(IpValue {
+            addr,
+            prefix,
+            scope_id: None,
+        })
+    }
+
+    /// Networks can't have host bits set, e.g. `192.168.0.1/24` is an interface but not a network.
+    fn check(&self, value: IpValue) -> Option<IpValue> {
+        if self.kind != IpKind::Network {
+            return Some(value);
+        }
+        let host_bits = match value.addr {
+            IpAddr::V4(addr) => u128::from(u32::from(addr) & u32::MAX.checked_shr(value.prefix.into()).unwrap_or(0)),
+            IpAddr::V6(addr) => u128::from(addr) & u128::MAX.checked_shr(value.prefix.into()).unwrap_or(0),
+        };
+        (host_bits == 0).then_some(value)
+    }
+
+    fn create_py_object(&self, py: Python<'_>, value: IpValue) -> ValResult<Py<PyAny>> {
+        let (class, int) = match value.addr {
+            IpAddr::V4(addr) => (&self.v4_class, u128::from(u32::from(addr))),
+            IpAddr::V6(addr) => (&self.v6_class, u128::from(addr)),
+        };
+        // the version of the value is always one of those allowed, so its class has been imported
+        let class = class.as_ref().expect("class of an allowed IP version").bind(py);
+        // a scope ID can only be given in the string of the address
+        if let Some(scope_id) = &value.scope_id {
+            let addr = format!("{}%{scope_id}", value.addr);
+            let object = match self.kind {
+                IpKind::Address => class.call1((addr,))?,
+                IpKind::Network | IpKind::Interface => class.call1(((addr, value.prefix),))?,
+            };
+            return Ok(object.unbind());
+        }
+        let object = match self.kind {
+            IpKind::Address => class.call1((int,))?,
+            IpKind::Network | IpKind::Interface => class.call1(((int, value.prefix),))?,
+        };
+        Ok(object.unbind())
+    }
+}
+
+fn parse_prefix(prefix: &str, addr: &IpAddr) -> Option<u8> {
+    let max = max_prefix(addr);
+    if !prefix.is_empty() && prefix.bytes().all(|b| b.is_ascii_digit()) {
+        return prefix.parse::<u8>().ok().filter(|prefix| *prefix <= max);
+    }
+    // IPv4 prefixes can also be given as a netmask, e.g. `255.255.0.0`, or a hostmask, e.g. `0.0.255.255`
+    if addr.is_ipv4() {
+        let mask = u32::from(prefix.parse::<Ipv4Addr>().ok()?);
+        if mask.leading_ones() + mask.trailing_zeros() == 32 {
+            return u8::try_from(mask.leading_ones()).ok();
+        }
+        if mask.leading_zeros() + mask.trailing_ones() == 32 {
+            return u8::try_from(mask.leading_zeros()).ok();
+        }
+    }
+    None
+}
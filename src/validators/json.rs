@@ -6,7 +6,8 @@ use pyo3::types::PyDict;
 
 use jiter::{FloatMode, JsonValue, PythonParse};
 
-use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
+use crate::definitions::CompositeName;
+use crate::errors::{ErrorType, ErrorTypeDefaults, JsonInput, ValError, ValLineError, ValResult};
 use crate::input::{EitherBytes, Input, InputType, ValidationMatch};
 use crate::serializers::BytesMode;
 use crate::tools::SchemaDict;
@@ -17,7 +18,7 @@ use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuild
 #[derive(Debug)]
 pub struct JsonValidator {
     validator: Option<Arc<CombinedValidator>>,
-    name: String,
+    name: CompositeName,
 }
 
 impl BuildValidator for JsonValidator {
@@ -38,12 +39,11 @@ impl BuildValidator for JsonValidator {
             }
             None => None,
         };
-        let name = format!(
-            "{}[{}]",
-            Self::EXPECTED_TYPE,
-            validator.as_ref().map_or("any", |v| v.get_name())
-        );
-        Ok(CombinedValidator::Json(Self { validator, name }).into())
+        Ok(CombinedValidator::Json(Self {
+            validator,
+            name: CompositeName::new(),
+        })
+        .into())
     }
 }
 
@@ -85,7 +85,13 @@ impl Validator for JsonValidator {
     }
 
     fn get_name(&self) -> &str {
-        &self.name
+        self.name.get_or_init(|| {
+            format!(
+                "{}[{}]",
+                Self::EXPECTED_TYPE,
+                self.validator.as_ref().map_or("any", |v| v.get_name())
+            )
+        })
     }
 }
 
@@ -119,3 +125,13 @@ pub fn map_json_err<'py>(input: &(impl Input<'py> + ?Sized), error: jiter::JsonE
         input,
     )
 }
+
+/// Like `map_json_err` for the JSON data being validated, whose errors are positioned where the data is invalid.
+pub fn map_json_data_err<'py>(
+    input: &(impl Input<'py> + ?Sized),
+    error: jiter::JsonError,
+    json_data: &[u8],
+) -> ValError {
+    let offset = error.index;
+    map_json_err(input, error, json_data).with_json_input(JsonInput::Offset(offset))
+}
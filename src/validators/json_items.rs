@@ -0,0 +1,265 @@
+use std::fmt::Display;
+
+use jiter::{Jiter, JsonErrorType};
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyByteArray, PyBytes, PyString};
+use pyo3::{intern, PyTraverseError, PyVisit};
+
+use crate::build_tools::ExtraBehavior;
+use crate::errors::{ErrorType, ValError};
+use crate::input::InputType;
+
+use super::{SchemaValidator, ValidationOptions};
+
+/// Validates the items of a JSON array one at a time, see `SchemaValidator.validate_json_items`.
+///
+/// Only the item being validated is held in memory, so arrays read from a file-like object can be bigger than
+/// the memory available.
+#[pyclass(module = "pydantic_core._pydantic_core")]
+pub struct JsonItemsIterator {
+    validator: Py<SchemaValidator>,
+    input: Py<PyAny>,
+    // the file-like object the JSON is read from, `None` if the JSON was passed in whole or has been read
+    reader: Option<Py<PyAny>>,
+    chunk_size: usize,
+    buffer: Vec<u8>,
+    // index in `buffer` of the first byte not yet consumed
+    position: usize,
+    // number of bytes dropped from the start of `buffer`, so errors can refer to a position in the whole JSON
+    dropped: usize,
+    step: Step,
+    index: usize,
+    strict: Option<bool>,
+    extra_behavior: Option<ExtraBehavior>,
+    context: Option<Py<PyAny>>,
+    by_alias: Option<bool>,
+    by_name: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Start,
+    FirstItem,
+    NextItem,
+    Done,
+}
+
+impl JsonItemsIterator {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn new(
+        validator: Py<SchemaValidator>,
+        input: &Bound<'_, PyAny>,
+        chunk_size: usize,
+        strict: Option<bool>,
+        extra_behavior: Option<ExtraBehavior>,
+        context: Option<&Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> PyResult<Self> {
+        let (buffer, reader) = if let Ok(py_str) = input.downcast::<PyString>() {
+            (py_str.to_str()?.as_bytes().to_vec(), None)
+        } else if let Ok(py_bytes) = input.downcast::<PyBytes>() {
+            (py_bytes.as_bytes().to_vec(), None)
+        } else if let Ok(py_byte_array) = input.downcast::<PyByteArray>() {
+            (py_byte_array.to_vec(), None)
+        } else if input.hasattr(intern!(input.py(), "read"))? {
+            (Vec::new(), Some(input.clone().unbind()))
+        } else {
+            return Err(PyTypeError::new_err(
+                "JSON input should be a str, bytes, bytearray or a file-like object with a `read` method",
+            ));
+        };
+        Ok(Self {
+            validator,
+            input: input.clone().unbind(),
+            reader,
+            chunk_size,
+            buffer,
+            position: 0,
+            dropped: 0,
+            step: Step::Start,
+            index: 0,
+            strict,
+            extra_behavior,
+            context: context.map(|c| c.clone().unbind()),
+            by_alias,
+            by_name,
+        })
+    }
+
+    /// Read the next chunk from the reader, returns `false` if there's nothing more to read.
+    ///
+    /// At least as many bytes as are buffered are read, so an item spanning many chunks is parsed a
+    /// logarithmic number of times.
+    fn read_more(&mut self, py: Python<'_>) -> PyResult<bool> {
+        let Some(reader) = self.reader.as_ref().map(|r| r.clone_ref(py)) else {
+            return Ok(false);
+        };
+        let size = self.chunk_size.max(self.buffer.len() - self.position);
+        let chunk = reader.bind(py).call_method1(intern!(py, "read"), (size,))?;
+        let chunk_len = if let Ok(py_str) = chunk.downcast::<PyString>() {
+            let data = py_str.to_str()?.as_bytes();
+            self.extend(data);
+            data.len()
+        } else {
+            let data = chunk.downcast::<PyBytes>()?.as_bytes();
+            self.extend(data);
+            data.len()
+        };
+        if chunk_len == 0 {
+            self.reader = None;
+        }
+        Ok(chunk_len > 0)
+    }
+
+    fn extend(&mut self, data: &[u8]) {
+        // drop what's been consumed before buffering more
+        self.buffer.drain(..self.position);
+        self.dropped += self.position;
+        self.position = 0;
+        self.buffer.extend_from_slice(data);
+    }
+
+    /// Skip whitespace and return the next byte without consuming it.
+    fn peek(&mut self, py: Python<'_>) -> PyResult<Option<u8>> {
+        loop {
+            while let Some(byte) = self.buffer.get(self.position) {
+                if matches!(byte, b' ' | b'\t' | b'\n' | b'\r') {
+                    self.position += 1;
+                } else {
+                    return Ok(Some(*byte));
+                }
+            }
+            if !self.read_more(py)? {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Find the length of the next item, reading more of the JSON until the whole item is buffered.
+    fn next_item(&mut self, py: Python<'_>) -> PyResult<usize> {
+        loop {
+            let data = &self.buffer[self.position..];
+            let mut jiter = Jiter::new(data).with_allow_inf_nan();
+            match jiter.next_skip() {
+                // an item at the end of the buffer may continue in the next chunk, e.g. a number
+                Ok(()) if jiter.current_index() < data.len() || self.reader.is_none() => {
+                    return Ok(jiter.current_index());
+                }
+                Ok(()) => {}
+                // the buffer may end part way through the item, or part way through a UTF-8 character
+                Err(err) if self.reader.is_some() && err.index + 4 >= data.len() => {}
+                Err(err) => return Err(self.invalid(py, err.error_type, self.position + err.index)),
+            }
+            self.read_more(py)?;
+        }
+    }
+
+    /// A `json_invalid` validation error, the iterator doesn't yield any more items after it.
+    fn invalid(&mut self, py: Python<'_>, error: impl Display, index: usize) -> PyErr {
+        self.step = Step::Done;
+        let error = ValError::new(
+            ErrorType::JsonInvalid {
+                error: format!("{error} at byte {}", self.dropped + index),
+                context: None,
+            },
+            self.input.bind(py),
+        );
+        self.validator
+            .get()
+            .prepare_collected_validation_err(py, error, InputType::Json, None)
+    }
+
+    fn validate_item(&mut self, py: Python<'_>, length: usize) -> PyResult<Py<PyAny>> {
+        let index = self.index;
+        self.index += 1;
+        let start = self.position;
+        self.position += length;
+        let validator = self.validator.get();
+        let item = PyBytes::new(py, &self.buffer[start..start + length]);
+        let options = ValidationOptions {
+            strict: self.strict,
+            extra_behavior: self.extra_behavior,
+            context: self.context.as_ref().map(|c| c.bind(py)),
+            by_alias: self.by_alias,
+            by_name: self.by_name,
+            max_errors: validator.max_errors,
+            ..ValidationOptions::default()
+        };
+        #[allow(clippy::used_underscore_items)]
+        validator
+            ._validate_json(py, item.as_any(), item.as_bytes(), &options)
+            .map_err(|err| {
+                let err = err.with_outer_location(index);
+                validator.prepare_collected_validation_err(py, err, InputType::Json, validator.max_errors)
+            })
+    }
+}
+
+#[pymethods]
+impl JsonItemsIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        loop {
+            let next = self.peek(py)?;
+            let position = self.position;
+            match (self.step, next) {
+                (Step::Done, _) => return Ok(None),
+                (Step::Start, Some(b'[')) => {
+                    self.position += 1;
+                    self.step = Step::FirstItem;
+                }
+                (Step::Start, _) => return Err(self.invalid(py, "expected a JSON array", position)),
+                (Step::FirstItem | Step::NextItem, Some(b']')) => {
+                    self.position += 1;
+                    self.step = Step::Done;
+                    if self.peek(py)?.is_some() {
+                        return Err(self.invalid(py, JsonErrorType::TrailingCharacters, self.position));
+                    }
+                    return Ok(None);
+                }
+                (Step::FirstItem, _) => break,
+                (Step::NextItem, Some(b',')) => {
+                    self.position += 1;
+                    break;
+                }
+                (Step::NextItem, None) => {
+                    return Err(self.invalid(py, JsonErrorType::EofWhileParsingList, position));
+                }
+                (Step::NextItem, _) => {
+                    return Err(self.invalid(py, JsonErrorType::ExpectedListCommaOrEnd, position));
+                }
+            }
+        }
+        self.peek(py)?;
+        let length = self.next_item(py)?;
+        self.step = Step::NextItem;
+        self.validate_item(py, length).map(Some)
+    }
+
+    /// The number of items read so far.
+    #[getter]
+    fn index(&self) -> usize {
+        self.index
+    }
+
+    fn __repr__(&self) -> String {
+        format!("JsonItemsIterator(index={})", self.index)
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.validator)?;
+        visit.call(&self.input)?;
+        if let Some(reader) = &self.reader {
+            visit.call(reader)?;
+        }
+        if let Some(context) = &self.context {
+            visit.call(context)?;
+        }
+        Ok(())
+    }
+}
@@ -4,18 +4,20 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::definitions::DefinitionsBuilder;
+use crate::definitions::{CompositeName, DefinitionsBuilder};
 use crate::errors::ValResult;
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
-use super::{build_validator, BuildValidator, CombinedValidator, InputType, ValidationState, Validator};
+use super::{
+    build_validator, BuildValidator, CombinedValidator, ExampleGenerator, InputType, ValidationState, Validator,
+};
 
 #[derive(Debug)]
 pub struct JsonOrPython {
     json: Arc<CombinedValidator>,
     python: Arc<CombinedValidator>,
-    name: String,
+    name: CompositeName,
 }
 
 impl BuildValidator for JsonOrPython {
@@ -33,13 +35,12 @@ impl BuildValidator for JsonOrPython {
         let json = build_validator(&json_schema, config, definitions)?;
         let python = build_validator(&python_schema, config, definitions)?;
 
-        let name = format!(
-            "{}[json={},python={}]",
-            Self::EXPECTED_TYPE,
-            json.get_name(),
-            python.get_name(),
-        );
-        Ok(CombinedValidator::JsonOrPython(Self { json, python, name }).into())
+        Ok(CombinedValidator::JsonOrPython(Self {
+            json,
+            python,
+            name: CompositeName::new(),
+        })
+        .into())
     }
 }
 
@@ -58,7 +59,18 @@ impl Validator for JsonOrPython {
         }
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        self.python.example(py, generator)
+    }
+
     fn get_name(&self) -> &str {
-        &self.name
+        self.name.get_or_init(|| {
+            format!(
+                "{}[json={},python={}]",
+                Self::EXPECTED_TYPE,
+                self.json.get_name(),
+                self.python.get_name(),
+            )
+        })
     }
 }
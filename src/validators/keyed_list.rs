@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use pyo3::exceptions::PyAttributeError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyString};
+
+use crate::build_tools::py_schema_err;
+use crate::definitions::CompositeName;
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::list::ListValidator;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState, Validator};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Duplicates {
+    Error,
+    First,
+    Last,
+}
+
+/// Validates a list of items, e.g. the `containers` of a Kubernetes pod, then returns them in a dict keyed by one of
+/// their fields, keeping the index of each item in the location of its errors.
+#[derive(Debug)]
+pub struct KeyedListValidator {
+    list_validator: Arc<CombinedValidator>,
+    key: Py<PyString>,
+    key_field: String,
+    duplicates: Duplicates,
+    name: CompositeName,
+}
+
+impl BuildValidator for KeyedListValidator {
+    const EXPECTED_TYPE: &'static str = "keyed-list";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<Arc<CombinedValidator>>,
+    ) -> PyResult<Arc<CombinedValidator>> {
+        let py = schema.py();
+        let key: Bound<'_, PyString> = schema.get_as_req(intern!(py, "key"))?;
+        let duplicates = match schema.get_as::<String>(intern!(py, "duplicates"))?.as_deref() {
+            None | Some("error") => Duplicates::Error,
+            Some("first") => Duplicates::First,
+            Some("last") => Duplicates::Last,
+            Some(other) => {
+                return py_schema_err!("Invalid duplicates: `{}`, expected 'error', 'first' or 'last'", other);
+            }
+        };
+        Ok(CombinedValidator::KeyedList(Self {
+            list_validator: ListValidator::build(schema, config, definitions)?,
+            key_field: key.to_str()?.to_string(),
+            key: key.unbind(),
+            duplicates,
+            name: CompositeName::new(),
+        })
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(KeyedListValidator { list_validator, key });
+
+impl Validator for KeyedListValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        let items = self.list_validator.validate(py, input, state)?;
+        let items = items.downcast_bound::<PyList>(py)?;
+        let key = self.key.bind(py);
+
+        let output = PyDict::new(py);
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for (index, item) in items.iter().enumerate() {
+            let Some(item_key) = self.item_key(&item)? else {
+                errors.push(
+                    ValLineError::new_with_loc(ErrorTypeDefaults::Missing, &item, key.to_str()?)
+                        .with_outer_location(index),
+                );
+                continue;
+            };
+            if output.contains(&item_key)? {
+                match self.duplicates {
+                    Duplicates::Error => errors.push(
+                        ValLineError::new_with_loc(
+                            ErrorType::KeyedListDuplicate {
+                                key_field: self.key_field.clone(),
+                                context: None,
+                            },
+                            &item_key,
+                            key.to_str()?,
+                        )
+                        .with_outer_location(index),
+                    ),
+                    Duplicates::First => {}
+                    // replacing the value keeps the position of the first item with the key
+                    Duplicates::Last => output.set_item(item_key, item)?,
+                }
+            } else {
+                output.set_item(item_key, item)?;
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(output.into_any().unbind())
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        self.list_validator.example(py, generator)
+    }
+
+    fn get_name(&self) -> &str {
+        let items_name = || self.list_validator.get_name().strip_prefix("list");
+        if self.name.get().is_none() && matches!(items_name(), Some("[...]") | None) {
+            // when inner name is not initialized yet, don't cache it here
+            return "keyed-list[...]";
+        }
+        self.name
+            .get_or_init(|| format!("keyed-list{}", items_name().unwrap_or("[...]")))
+    }
+}
+
+impl KeyedListValidator {
+    /// The key of a validated item, by key for dicts and by attribute for other types, e.g. models and dataclasses.
+    fn item_key<'py>(&self, item: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyAny>>> {
+        let key = self.key.bind(item.py());
+        match item.downcast::<PyDict>() {
+            Ok(dict) => dict.get_item(key),
+            Err(_) => match item.getattr(key) {
+                Ok(value) => Ok(Some(value)),
+                Err(err) if err.is_instance_of::<PyAttributeError>(item.py()) => Ok(None),
+                Err(err) => Err(err),
+            },
+        }
+    }
+}
@@ -5,20 +5,21 @@ use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
 use crate::build_tools::is_strict;
+use crate::definitions::CompositeName;
 use crate::errors::ValResult;
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
 use super::Exactness;
 use super::ValidationState;
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, Validator};
 
 #[derive(Debug)]
 pub struct LaxOrStrictValidator {
     strict: bool,
     lax_validator: Arc<CombinedValidator>,
     strict_validator: Arc<CombinedValidator>,
-    name: String,
+    name: CompositeName,
 }
 
 impl BuildValidator for LaxOrStrictValidator {
@@ -36,17 +37,11 @@ impl BuildValidator for LaxOrStrictValidator {
         let strict_schema = schema.get_as_req(intern!(py, "strict_schema"))?;
         let strict_validator = build_validator(&strict_schema, config, definitions)?;
 
-        let name = format!(
-            "{}[lax={},strict={}]",
-            Self::EXPECTED_TYPE,
-            lax_validator.get_name(),
-            strict_validator.get_name()
-        );
         Ok(CombinedValidator::LaxOrStrict(Self {
             strict: is_strict(schema, config)?,
             lax_validator,
             strict_validator,
-            name,
+            name: CompositeName::new(),
         })
         .into())
     }
@@ -80,7 +75,22 @@ impl Validator for LaxOrStrictValidator {
         }
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        if self.strict {
+            self.strict_validator.example(py, generator)
+        } else {
+            self.lax_validator.example(py, generator)
+        }
+    }
+
     fn get_name(&self) -> &str {
-        &self.name
+        self.name.get_or_init(|| {
+            format!(
+                "{}[lax={},strict={}]",
+                Self::EXPECTED_TYPE,
+                self.lax_validator.get_name(),
+                self.strict_validator.get_name()
+            )
+        })
     }
 }
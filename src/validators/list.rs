@@ -1,15 +1,22 @@
-use std::sync::{Arc, OnceLock};
+use std::sync::Arc;
 
-use pyo3::types::PyDict;
+use pyo3::pyclass::CompareOp;
+use pyo3::sync::PyOnceLock;
+use pyo3::types::{PyDict, PyList, PyTuple, PyType};
 use pyo3::{prelude::*, IntoPyObjectExt};
 
-use crate::errors::ValResult;
+use crate::build_tools::py_schema_err;
+use crate::definitions::CompositeName;
+use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
 use crate::input::{
     no_validator_iter_to_vec, validate_iter_to_vec, BorrowInput, ConsumeIterator, Input, MaxLengthCheck, ValidatedList,
 };
 use crate::tools::SchemaDict;
 
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{
+    build_validator, parallel, BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator,
+    LaxSequenceTypes, ResumeNode, ValidationState, Validator,
+};
 
 #[derive(Debug)]
 pub struct ListValidator {
@@ -17,8 +24,50 @@ pub struct ListValidator {
     item_validator: Option<Arc<CombinedValidator>>,
     min_length: Option<usize>,
     max_length: Option<usize>,
-    name: OnceLock<String>,
+    name: CompositeName,
     fail_fast: bool,
+    parallel: bool,
+    lax_sequence_types: LaxSequenceTypes,
+    monotonic: Option<Monotonic>,
+    max_adjacent_duplicates: Option<usize>,
+    unique_items: bool,
+    // whether tuples, deques and iterators are returned as such rather than as lists
+    preserve_type: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Monotonic {
+    Increasing,
+    Decreasing,
+}
+
+impl Monotonic {
+    fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        match schema
+            .get_as::<String>(pyo3::intern!(schema.py(), "monotonic"))?
+            .as_deref()
+        {
+            None => Ok(None),
+            Some("increasing") => Ok(Some(Self::Increasing)),
+            Some("decreasing") => Ok(Some(Self::Decreasing)),
+            Some(other) => py_schema_err!("Invalid monotonic: `{}`, expected 'increasing' or 'decreasing'", other),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Increasing => "increasing",
+            Self::Decreasing => "decreasing",
+        }
+    }
+
+    /// How an item compares to the one before it when it breaks the order.
+    fn out_of_order_op(self) -> CompareOp {
+        match self {
+            Self::Increasing => CompareOp::Lt,
+            Self::Decreasing => CompareOp::Gt,
+        }
+    }
 }
 
 pub fn get_items_schema(
@@ -109,14 +158,23 @@ impl BuildValidator for ListValidator {
             item_validator,
             min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
             max_length: schema.get_as(pyo3::intern!(py, "max_length"))?,
-            name: OnceLock::new(),
+            name: CompositeName::new(),
             fail_fast: schema.get_as(pyo3::intern!(py, "fail_fast"))?.unwrap_or(false),
+            parallel: schema.get_as(pyo3::intern!(py, "parallel"))?.unwrap_or(false),
+            lax_sequence_types: LaxSequenceTypes::from_config(config)?,
+            monotonic: Monotonic::from_schema(schema)?,
+            max_adjacent_duplicates: schema.get_as(pyo3::intern!(py, "max_adjacent_duplicates"))?,
+            unique_items: schema.get_as(pyo3::intern!(py, "unique_items"))?.unwrap_or(false),
+            preserve_type: schema.get_as(pyo3::intern!(py, "preserve_type"))?.unwrap_or(false),
         })
         .into())
     }
 }
 
-impl_py_gc_traverse!(ListValidator { item_validator });
+impl_py_gc_traverse!(ListValidator {
+    item_validator,
+    lax_sequence_types
+});
 
 impl Validator for ListValidator {
     fn validate<'py>(
@@ -125,24 +183,38 @@ impl Validator for ListValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        let seq = input.validate_list(state.strict_or(self.strict))?.unpack(state);
+        let seq = input
+            .validate_list(state.strict_or(self.strict), &self.lax_sequence_types)?
+            .unpack(state);
+        let mut resume = state.resume.take();
 
         let actual_length = seq.len();
         let output = match self.item_validator {
-            Some(ref v) => seq.iterate(ValidateToVec {
-                py,
-                input,
-                actual_length,
-                max_length: self.max_length,
-                field_type: "List",
-                item_validator: v,
-                state,
-                fail_fast: self.fail_fast,
-            })??,
+            Some(ref v) => match parallel::parallel_items(self.parallel, input, v, self.max_length, state) {
+                Some(items) => {
+                    let results = parallel::validate_items(py, &items, v, self.fail_fast, state);
+                    parallel::collect_results(results, state, self.fail_fast)?
+                }
+                None => seq.iterate(ValidateToVec {
+                    py,
+                    input,
+                    actual_length,
+                    max_length: self.max_length,
+                    field_type: "List",
+                    item_validator: v,
+                    state,
+                    fail_fast: self.fail_fast,
+                    resume: resume.as_mut(),
+                })??,
+            },
             None => {
                 if let Some(py_list) = seq.as_py_list() {
                     length_check!(input, "List", self.min_length, self.max_length, py_list);
                     let list_copy = py_list.get_slice(0, usize::MAX);
+                    self.check_order(list_copy.iter())?;
+                    if self.unique_items {
+                        check_unique_items(py, list_copy.iter(), state, self.fail_fast)?;
+                    }
                     return Ok(list_copy.into());
                 }
 
@@ -156,7 +228,28 @@ impl Validator for ListValidator {
             }
         };
         min_length_check!(input, "List", self.min_length, output);
-        Ok(output.into_py_any(py)?)
+        self.check_order(output.iter().map(|item| item.bind(py).clone()))?;
+        if self.unique_items {
+            check_unique_items(
+                py,
+                output.iter().map(|item| item.bind(py).clone()),
+                state,
+                self.fail_fast,
+            )?;
+        }
+        state.resume = resume;
+        Ok(self.preserve_type(py, input, output)?)
+    }
+
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        let length = generator.length(self.min_length, self.max_length);
+        let items = (0..length)
+            .map(|_| match &self.item_validator {
+                Some(validator) => validator.example(py, generator),
+                None => Ok(py.None().into_bound(py)),
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new(py, items)?.into_any())
     }
 
     fn get_name(&self) -> &str {
@@ -164,17 +257,180 @@ impl Validator for ListValidator {
         // while also trying to render definitions correctly when possible.
         //
         // Probably an opportunity for a future refactor
-        match self.name.get() {
-            Some(s) => s.as_str(),
-            None => {
-                let name = self.item_validator.as_ref().map_or("any", |v| v.get_name());
-                if name == "..." {
-                    // when inner name is not initialized yet, don't cache it here
-                    "list[...]"
+        let item_name = || self.item_validator.as_ref().map_or("any", |v| v.get_name());
+        if self.name.get().is_none() && item_name() == "..." {
+            // when inner name is not initialized yet, don't cache it here
+            return "list[...]";
+        }
+        self.name.get_or_init(|| format!("list[{}]", item_name()))
+    }
+}
+
+/// Check the `unique_items` constraint of lists and tuples, with an error for each validated item which is equal to
+/// an item before it.
+///
+/// Hashable items are looked up by hash, unhashable ones are compared to every item before them.
+pub(super) fn check_unique_items<'py>(
+    py: Python<'py>,
+    items: impl Iterator<Item = Bound<'py, PyAny>>,
+    state: &ValidationState<'_, 'py>,
+    fail_fast: bool,
+) -> ValResult<()> {
+    // hashable items by the index of their first occurrence
+    let first_indexes = PyDict::new(py);
+    let mut unhashable: Vec<(usize, Bound<'py, PyAny>)> = Vec::new();
+    let mut errors: Vec<ValLineError> = Vec::new();
+    for (index, item) in items.enumerate() {
+        let first_index = match first_indexes.get_item(&item) {
+            Ok(Some(first_index)) => Some(first_index.extract()?),
+            Ok(None) => {
+                let first_index = first_equal(&item, unhashable.iter().map(|(i, other)| (*i, other.clone())))?;
+                if first_index.is_none() {
+                    first_indexes.set_item(&item, index)?;
+                }
+                first_index
+            }
+            Err(_) => {
+                let hashable = first_indexes
+                    .iter()
+                    .map(|(other, i)| Ok((i.extract()?, other)))
+                    .collect::<PyResult<Vec<(usize, Bound<'py, PyAny>)>>>()?;
+                let unhashable_items = unhashable.iter().map(|(i, other)| (*i, other.clone()));
+                let first_index = first_equal(&item, unhashable_items.chain(hashable))?;
+                if first_index.is_none() {
+                    unhashable.push((index, item.clone()));
+                }
+                first_index
+            }
+        };
+        if let Some(first_index) = first_index {
+            errors.push(ValLineError::new_with_loc(
+                ErrorType::DuplicateItem {
+                    first_index,
+                    context: None,
+                },
+                &item,
+                index,
+            ));
+            if state.stop_collecting(fail_fast, &errors) {
+                break;
+            }
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValError::LineErrors(errors))
+    }
+}
+
+/// The lowest index of the items equal to `item`, errors raised by `__eq__` are propagated.
+fn first_equal<'py>(
+    item: &Bound<'py, PyAny>,
+    others: impl Iterator<Item = (usize, Bound<'py, PyAny>)>,
+) -> PyResult<Option<usize>> {
+    let mut first_index: Option<usize> = None;
+    for (index, other) in others {
+        if first_index.map_or(true, |first| index < first) && item.eq(&other)? {
+            first_index = Some(index);
+        }
+    }
+    Ok(first_index)
+}
+
+static DEQUE_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+
+impl ListValidator {
+    /// The validated items in a list, or with `preserve_type`, in the type of a Python input, tuples as tuples,
+    /// deques as deques with the same `maxlen` and iterators, e.g. generators, as an iterator over the items.
+    fn preserve_type<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        items: Vec<Py<PyAny>>,
+    ) -> PyResult<Py<PyAny>> {
+        let Some(py_input) = input.as_python().filter(|_| self.preserve_type) else {
+            return items.into_py_any(py);
+        };
+        if py_input.is_instance_of::<PyTuple>() {
+            // not the tuple's own type, as subclasses, e.g. named tuples, can't be built from the items
+            return PyTuple::new(py, items)?.into_py_any(py);
+        }
+        if py_input.is_instance(DEQUE_TYPE.import(py, "collections", "deque")?)? {
+            let maxlen = py_input.getattr(pyo3::intern!(py, "maxlen"))?;
+            return py_input.get_type().call1((items, maxlen))?.into_py_any(py);
+        }
+        let list = PyList::new(py, items)?;
+        // iterators are their own iterators, the input can't be iterated again
+        if py_input.try_iter().is_ok_and(|iter| iter.is(py_input)) {
+            return list.try_iter()?.into_py_any(py);
+        }
+        list.into_py_any(py)
+    }
+
+    /// Check the `monotonic` and `max_adjacent_duplicates` constraints, comparing each validated item to the one
+    /// before it, with an error for each item which breaks them.
+    fn check_order<'py>(&self, items: impl Iterator<Item = Bound<'py, PyAny>>) -> ValResult<()> {
+        if self.monotonic.is_none() && self.max_adjacent_duplicates.is_none() {
+            return Ok(());
+        }
+        let mut errors: Vec<ValLineError> = Vec::new();
+        let mut previous: Option<Bound<'py, PyAny>> = None;
+        // the last item which isn't NaN, for `monotonic`
+        let mut previous_ordered: Option<Bound<'py, PyAny>> = None;
+        // the number of items equal to the one before them in the current run
+        let mut duplicates = 0;
+        for (index, item) in items.enumerate() {
+            if let Some(monotonic) = self.monotonic {
+                // NaN isn't equal to itself and compares false to every item, so it's never in order
+                let out_of_order = if item.ne(&item).unwrap_or(false) {
+                    true
                 } else {
-                    self.name.get_or_init(|| format!("list[{name}]")).as_str()
+                    let out_of_order = previous_ordered.as_ref().is_some_and(|previous_ordered| {
+                        // items which can't be compared can't be in order
+                        item.rich_compare(previous_ordered, monotonic.out_of_order_op())
+                            .and_then(|result| result.is_truthy())
+                            .unwrap_or(true)
+                    });
+                    previous_ordered = Some(item.clone());
+                    out_of_order
+                };
+                if out_of_order {
+                    errors.push(ValLineError::new_with_loc(
+                        ErrorType::ListNotMonotonic {
+                            monotonic: monotonic.as_str().to_string(),
+                            context: None,
+                        },
+                        &item,
+                        index,
+                    ));
                 }
             }
+            if let Some(previous) = &previous {
+                if let Some(max_adjacent_duplicates) = self.max_adjacent_duplicates {
+                    if item.eq(previous).unwrap_or(false) {
+                        duplicates += 1;
+                        if duplicates > max_adjacent_duplicates {
+                            errors.push(ValLineError::new_with_loc(
+                                ErrorType::ListAdjacentDuplicates {
+                                    max_adjacent_duplicates,
+                                    context: None,
+                                },
+                                &item,
+                                index,
+                            ));
+                        }
+                    } else {
+                        duplicates = 0;
+                    }
+                }
+            }
+            previous = Some(item);
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValError::LineErrors(errors))
         }
     }
 }
@@ -188,6 +444,7 @@ struct ValidateToVec<'a, 's, 'py, I: Input<'py> + ?Sized> {
     item_validator: &'a CombinedValidator,
     state: &'a mut ValidationState<'s, 'py>,
     fail_fast: bool,
+    resume: Option<&'a mut ResumeNode>,
 }
 
 // pretty arbitrary default capacity when creating vecs from iteration
@@ -209,6 +466,7 @@ where
             self.item_validator,
             self.state,
             self.fail_fast,
+            self.resume,
         )
     }
 }
@@ -16,7 +16,7 @@ use crate::input::{Input, ValidationMatch};
 use crate::py_gc::PyGcTraverse;
 use crate::tools::SchemaDict;
 
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState, Validator};
 
 #[derive(Debug, Clone, Default)]
 struct BoolLiteral {
@@ -33,6 +33,8 @@ pub struct LiteralLookup<T: Debug> {
     expected_bool: Option<BoolLiteral>,
     expected_int: Option<AHashMap<i64, usize>>,
     expected_str: Option<AHashMap<String, usize>>,
+    // `expected_str` by casefolded string, for `case_insensitive` literals
+    expected_str_folded: Option<AHashMap<String, usize>>,
     // Catch all for hashable types like Enum and bytes (the latter only because it is seldom used)
     expected_py_dict: Option<Py<PyDict>>,
     // Catch all for unhashable types like list
@@ -90,6 +92,7 @@ impl<T: Debug> LiteralLookup<T> {
                 .then_some(expected_bool),
             expected_int: (!expected_int.is_empty()).then_some(expected_int),
             expected_str: (!expected_str.is_empty()).then_some(expected_str),
+            expected_str_folded: None,
             expected_py_dict: (!expected_py_dict.is_empty()).then_some(expected_py_dict.into()),
             expected_py_values: (!expected_py_values.is_empty()).then_some(expected_py_values),
             expected_py_primitives: (!expected_py_primitives.is_empty()).then_some(expected_py_primitives.into()),
@@ -97,6 +100,26 @@ impl<T: Debug> LiteralLookup<T> {
         })
     }
 
+    /// Also match strings which only differ by case from a string member, which is still the value returned.
+    pub fn ignore_case(&mut self) -> PyResult<()> {
+        // in the order of `expected`, for errors
+        let mut members: Vec<(&String, usize)> = self.expected_str.iter().flatten().map(|(s, id)| (s, *id)).collect();
+        members.sort_by_key(|(_, id)| *id);
+        let mut folded: AHashMap<String, (&String, usize)> = AHashMap::new();
+        for (s, id) in members {
+            if let Some((other, _)) = folded.insert(fold_case(s), (s, id)) {
+                return py_schema_err!(
+                    "Literal members `{}` and `{}` only differ by case, so `case_insensitive` can't tell them apart",
+                    other,
+                    s
+                );
+            }
+        }
+        let folded: AHashMap<String, usize> = folded.into_iter().map(|(f, (_, id))| (f, id)).collect();
+        self.expected_str_folded = (!folded.is_empty()).then_some(folded);
+        Ok(())
+    }
+
     pub fn validate<'a, 'py, I: Input<'py> + ?Sized>(
         &self,
         py: Python<'py>,
@@ -138,6 +161,9 @@ impl<T: Debug> LiteralLookup<T> {
                 if let Some(id) = expected_strings.get(cow.as_ref()) {
                     return Ok(Some((input, &self.values[*id])));
                 }
+                if let Some(id) = self.expected_str_folded.as_ref().and_then(|f| f.get(&fold_case(&cow))) {
+                    return Ok(Some((input, &self.values[*id])));
+                }
             }
         }
         // cache py_input if needed, since we might need it for multiple lookups
@@ -268,7 +294,10 @@ impl BuildValidator for LiteralValidator {
             repr_args.push(item.repr()?.extract()?);
         }
         let (expected_repr, name) = expected_repr_name(repr_args, "literal");
-        let lookup = LiteralLookup::new(py, expected.into_iter().map(|v| (v.clone(), v.into())))?;
+        let mut lookup = LiteralLookup::new(py, expected.into_iter().map(|v| (v.clone(), v.into())))?;
+        if schema.get_as(intern!(py, "case_insensitive"))?.unwrap_or(false) {
+            lookup.ignore_case()?;
+        }
         Ok(CombinedValidator::Literal(Self {
             lookup,
             expected_repr,
@@ -299,11 +328,23 @@ impl Validator for LiteralValidator {
         }
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        let value = generator
+            .choose(&self.lookup.values)
+            .expect("literals have at least one value");
+        Ok(value.bind(py).clone())
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
 }
 
+/// Close to Python's `str.casefold`, e.g. `'ß'` and `'SS'` are both `'ss'`.
+fn fold_case(s: &str) -> String {
+    s.to_uppercase().to_lowercase()
+}
+
 pub fn expected_repr_name(mut repr_args: Vec<String>, base_name: &'static str) -> (String, String) {
     let name = format!("{base_name}[{}]", repr_args.join(","));
     // unwrap is okay since we check the length in build at the top of this file
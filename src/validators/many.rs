@@ -0,0 +1,151 @@
+use jiter::{JsonValue, PartialMode};
+use pyo3::prelude::*;
+use pyo3::types::PyList;
+
+use crate::build_tools::ExtraBehavior;
+use crate::errors::{ValError, ValResult, ValidationError};
+use crate::input::{EitherBytes, Input, InputType, StringMapping, ValidationMatch};
+
+use super::{json, SchemaValidator, ValidationOptions};
+
+/// The settings shared by every input of `SchemaValidator.validate_many`.
+pub(super) struct BatchSettings<'a, 'py> {
+    pub input_type: InputType,
+    pub strict: Option<bool>,
+    pub extra_behavior: Option<ExtraBehavior>,
+    pub from_attributes: Option<bool>,
+    pub context: Option<&'a Bound<'py, PyAny>>,
+    pub by_alias: Option<bool>,
+    pub by_name: Option<bool>,
+}
+
+/// Validate each of `inputs`, returning a list with the validated value or the `ValidationError` for each.
+pub(super) fn validate_many<'py>(
+    validator: &SchemaValidator,
+    py: Python<'py>,
+    inputs: &[Bound<'py, PyAny>],
+    settings: &BatchSettings<'_, 'py>,
+) -> PyResult<Bound<'py, PyList>> {
+    let results = PyList::empty(py);
+    for_each_result(validator, py, inputs, settings, |_, result| match result {
+        Ok(value) | Err(value) => results.append(value),
+    })?;
+    Ok(results)
+}
+
+/// Validate each of `inputs`, calling `on_result` with the index of each input and either its validated value
+/// or its `ValidationError`, other errors are raised.
+///
+/// JSON inputs are all parsed with the GIL released before any of them are validated.
+pub(super) fn for_each_result<'py>(
+    validator: &SchemaValidator,
+    py: Python<'py>,
+    inputs: &[Bound<'py, PyAny>],
+    settings: &BatchSettings<'_, 'py>,
+    mut on_result: impl FnMut(usize, Result<Py<PyAny>, Py<PyAny>>) -> PyResult<()>,
+) -> PyResult<()> {
+    let mut on_result = |index, result| {
+        let result = match result {
+            Ok(value) => Ok(value),
+            Err(err) => Err(validation_error(validator, py, err, settings.input_type)?),
+        };
+        on_result(index, result)
+    };
+    match settings.input_type {
+        InputType::Python => {
+            for (index, input) in inputs.iter().enumerate() {
+                on_result(index, validate(validator, py, input, settings))?;
+            }
+        }
+        InputType::String => {
+            for (index, input) in inputs.iter().enumerate() {
+                let result = StringMapping::new_value(input.clone(), false)
+                    .and_then(|string_mapping| validate(validator, py, &string_mapping, settings));
+                on_result(index, result)?;
+            }
+        }
+        InputType::Json => {
+            let mut errors = Vec::with_capacity(inputs.len());
+            let mut json_bytes = Vec::with_capacity(inputs.len());
+            for input in inputs {
+                match json::validate_json_bytes(input).map(ValidationMatch::into_inner) {
+                    Ok(bytes) => {
+                        errors.push(None);
+                        json_bytes.push(Some(bytes));
+                    }
+                    Err(err) => {
+                        errors.push(Some(err));
+                        json_bytes.push(None);
+                    }
+                }
+            }
+            let json_data: Vec<Option<&[u8]>> = json_bytes
+                .iter()
+                .map(|b| b.as_ref().map(EitherBytes::as_slice))
+                .collect();
+
+            // parsing doesn't need the GIL, so other threads can run while the whole batch is parsed
+            let json_values: Vec<_> = py.detach(|| {
+                json_data
+                    .iter()
+                    .map(|data| data.map(|data| JsonValue::parse_with_config(data, true, PartialMode::Off)))
+                    .collect()
+            });
+
+            let results = inputs.iter().zip(errors).zip(json_data).zip(json_values).enumerate();
+            for (index, (((input, error), data), json_value)) in results {
+                let result = match (error, data, json_value) {
+                    (Some(err), _, _) => Err(err),
+                    (None, Some(data), Some(Ok(json_value))) => validate(validator, py, &json_value, settings)
+                        .map_err(|e| validator.add_json_positions(e, data, Some(&json_value))),
+                    (None, Some(data), Some(Err(err))) => {
+                        Err(validator.add_json_positions(json::map_json_data_err(input, err, data), data, None))
+                    }
+                    (None, _, _) => unreachable!("JSON is parsed for every input without an error"),
+                };
+                on_result(index, result)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate<'py>(
+    validator: &SchemaValidator,
+    py: Python<'py>,
+    input: &(impl Input<'py> + ?Sized),
+    settings: &BatchSettings<'_, 'py>,
+) -> ValResult<Py<PyAny>> {
+    let options = ValidationOptions {
+        strict: settings.strict,
+        extra_behavior: settings.extra_behavior,
+        from_attributes: settings.from_attributes,
+        context: settings.context,
+        by_alias: settings.by_alias,
+        by_name: settings.by_name,
+        max_errors: validator.max_errors,
+        ..ValidationOptions::default()
+    };
+    #[allow(clippy::used_underscore_items)]
+    validator._validate(py, input, settings.input_type, &options)
+}
+
+/// The `ValidationError` for an input which failed validation, other errors are raised.
+fn validation_error(
+    validator: &SchemaValidator,
+    py: Python<'_>,
+    err: ValError,
+    input_type: InputType,
+) -> PyResult<Py<PyAny>> {
+    match err {
+        err @ ValError::LineErrors(_) => {
+            let py_err = validator.prepare_collected_validation_err(py, err, input_type, validator.max_errors);
+            if py_err.is_instance_of::<ValidationError>(py) {
+                Ok(py_err.into_value(py).into_any())
+            } else {
+                Err(py_err)
+            }
+        }
+        err => Err(validator.prepare_validation_err(py, err, input_type)),
+    }
+}
@@ -0,0 +1,209 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
+
+use crate::build_tools::py_schema_err;
+use crate::tools::SchemaDict;
+
+use super::CombinedValidator;
+
+/// How the values a field has in several layers are combined by `SchemaValidator.validate_merged`, set by
+/// the `merge_policy` of the field's schema.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(super) enum MergePolicy {
+    /// the value from the last layer with the field is used
+    #[default]
+    Replace,
+    /// dicts are merged key by key, using the policies of the fields of the value's schema
+    Merge,
+    /// lists are concatenated
+    Append,
+}
+
+impl MergePolicy {
+    pub fn from_field_schema(field_schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let py = field_schema.py();
+        let Some(policy) = field_schema.get_as::<Bound<'_, PyString>>(intern!(py, "merge_policy"))? else {
+            return Ok(Self::default());
+        };
+        match policy.to_str()? {
+            "replace" => Ok(Self::Replace),
+            "merge" => Ok(Self::Merge),
+            "append" => Ok(Self::Append),
+            other => py_schema_err!(
+                "Invalid merge_policy: `{}`, expected 'replace', 'merge' or 'append'",
+                other
+            ),
+        }
+    }
+}
+
+/// A field of a model or typed dict, as seen when merging layers.
+pub(super) struct MergeField<'v> {
+    /// keys the field can have in a layer, the first key of each path its value is looked up by, in the order
+    /// they're looked up, given `by_alias` and `by_name`
+    pub keys: Vec<&'v str>,
+    pub policy: MergePolicy,
+    pub validator: &'v CombinedValidator,
+}
+
+/// Merge `layers`, oldest first, into one dict to validate with `validator`.
+///
+/// The values of a field are merged whichever of its keys they have in each layer, e.g. its alias in one layer
+/// and its name in another, under the key it's first looked up by, `by_alias` and `by_name` being those of
+/// `validate_merged`.
+///
+/// The location of each value in the merged dict, e.g. `('db', 'host')` or `('plugins', 0)` for an item of
+/// an appended list, is recorded in `sources` with the index of the layer it came from.
+pub(super) fn merge_layers<'py>(
+    py: Python<'py>,
+    validator: &CombinedValidator,
+    layers: &Bound<'py, PyAny>,
+    sources: &Bound<'py, PyDict>,
+    by_alias: Option<bool>,
+    by_name: Option<bool>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let mut dicts = Vec::new();
+    for (index, layer) in layers.try_iter()?.enumerate() {
+        let layer = layer?;
+        let Ok(layer) = layer.downcast_into::<PyDict>() else {
+            return Err(PyTypeError::new_err(format!(
+                "Each layer must be a dict, layer {index} isn't"
+            )));
+        };
+        dicts.push((index, layer));
+    }
+    let lookup = Lookup { by_alias, by_name };
+    merge_dicts(py, validator, dicts, &mut Vec::new(), sources, lookup)
+}
+
+/// `by_alias` and `by_name` of `validate_merged`, which keys of a layer are fields.
+#[derive(Clone, Copy)]
+struct Lookup {
+    by_alias: Option<bool>,
+    by_name: Option<bool>,
+}
+
+fn merge_dicts<'py>(
+    py: Python<'py>,
+    validator: &CombinedValidator,
+    dicts: Vec<(usize, Bound<'py, PyDict>)>,
+    loc: &mut Vec<Bound<'py, PyAny>>,
+    sources: &Bound<'py, PyDict>,
+    lookup: Lookup,
+) -> PyResult<Bound<'py, PyDict>> {
+    with_merge_fields(py, validator, lookup, |fields| {
+        let field_of = |key: &Bound<'py, PyAny>| {
+            let key = key.downcast::<PyString>().ok()?.to_str().ok()?;
+            fields.iter().find(|field| field.keys.contains(&key))
+        };
+
+        // the values of each field, under the key it's first looked up by, and of each key which isn't a field,
+        // with the index of their layer, in the order they first appear
+        let values_by_key = PyDict::new(py);
+        for (index, dict) in &dicts {
+            for (key, value) in dict {
+                let key = match field_of(&key) {
+                    Some(field) => PyString::new(py, field.keys[0]).into_any(),
+                    None => key,
+                };
+                let values = match values_by_key.get_item(&key)? {
+                    Some(values) => values.downcast_into::<PyList>()?,
+                    None => {
+                        let values = PyList::empty(py);
+                        values_by_key.set_item(&key, &values)?;
+                        values
+                    }
+                };
+                values.append((index, value))?;
+            }
+        }
+
+        let merged = PyDict::new(py);
+        for (key, values) in values_by_key {
+            let values = values
+                .downcast::<PyList>()?
+                .iter()
+                .map(|item| item.extract::<(usize, Bound<'py, PyAny>)>())
+                .collect::<PyResult<Vec<_>>>()?;
+            loc.push(key.clone());
+            let value = match field_of(&key) {
+                Some(field) => merge_values(py, field.policy, field.validator, values, loc, sources, lookup),
+                // keys which aren't fields don't have a policy, nor a schema for their items
+                None => merge_values(py, MergePolicy::Replace, validator, values, loc, sources, lookup),
+            };
+            loc.pop();
+            merged.set_item(key, value?)?;
+        }
+        Ok(merged)
+    })?
+}
+
+/// Merge the values a field has in each layer it's in, `values` is never empty.
+fn merge_values<'py>(
+    py: Python<'py>,
+    policy: MergePolicy,
+    validator: &CombinedValidator,
+    mut values: Vec<(usize, Bound<'py, PyAny>)>,
+    loc: &mut Vec<Bound<'py, PyAny>>,
+    sources: &Bound<'py, PyDict>,
+    lookup: Lookup,
+) -> PyResult<Bound<'py, PyAny>> {
+    // a value which can't be combined replaces the values before it
+    let can_combine = |value: &Bound<'py, PyAny>| match policy {
+        MergePolicy::Replace => false,
+        MergePolicy::Merge => value.is_instance_of::<PyDict>(),
+        MergePolicy::Append => value.is_instance_of::<PyList>() || value.is_instance_of::<PyTuple>(),
+    };
+    let values = match values.iter().rposition(|(_, value)| !can_combine(value)) {
+        Some(last) if last == values.len() - 1 => {
+            let (index, value) = values.swap_remove(last);
+            sources.set_item(PyTuple::new(py, loc.iter())?, index)?;
+            return Ok(value);
+        }
+        Some(last) => values.split_off(last + 1),
+        None => values,
+    };
+
+    if policy == MergePolicy::Merge {
+        let dicts = values
+            .into_iter()
+            .map(|(index, value)| Ok((index, value.downcast_into::<PyDict>()?)))
+            .collect::<PyResult<Vec<_>>>()?;
+        return merge_dicts(py, validator, dicts, loc, sources, lookup).map(Bound::into_any);
+    }
+
+    let items = PyList::empty(py);
+    for (index, value) in values {
+        for item in value.try_iter()? {
+            loc.push(items.len().into_pyobject(py)?.into_any());
+            sources.set_item(PyTuple::new(py, loc.iter())?, index)?;
+            loc.pop();
+            items.append(item?)?;
+        }
+    }
+    Ok(items.into_any())
+}
+
+/// Call `f` with the fields of the model or typed dict `validator` validates, no fields if it validates
+/// something else.
+fn with_merge_fields<R>(
+    py: Python<'_>,
+    validator: &CombinedValidator,
+    lookup: Lookup,
+    f: impl FnOnce(&[MergeField<'_>]) -> R,
+) -> PyResult<R> {
+    let Lookup { by_alias, by_name } = lookup;
+    match validator {
+        CombinedValidator::ModelFields(model_fields) => Ok(f(&model_fields.merge_fields(by_alias, by_name)?)),
+        CombinedValidator::TypedDict(typed_dict) => Ok(f(&typed_dict.merge_fields(by_alias, by_name)?)),
+        CombinedValidator::Model(model) => with_merge_fields(py, model.validator(), lookup, f),
+        CombinedValidator::WithDefault(with_default) => with_merge_fields(py, with_default.validator(), lookup, f),
+        CombinedValidator::Nullable(nullable) => with_merge_fields(py, nullable.validator(), lookup, f),
+        CombinedValidator::DefinitionRef(definition_ref) => {
+            definition_ref.read_definition(py, |validator| with_merge_fields(py, validator, lookup, f))?
+        }
+        _ => Ok(f(&[])),
+    }
+}
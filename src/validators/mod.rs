@@ -1,27 +1,35 @@
 use std::fmt::Debug;
+use std::hash::{BuildHasher, Hasher};
+use std::num::NonZeroUsize;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use enum_dispatch::enum_dispatch;
 use jiter::{PartialMode, StringCacheMode};
 
-use pyo3::exceptions::{PyTypeError, PyValueError};
-use pyo3::types::{PyAny, PyDict, PyString, PyTuple, PyType};
+use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
+use pyo3::types::{PyAny, PyDict, PyList, PySet, PyString, PyTuple, PyType};
 use pyo3::{intern, PyTraverseError, PyVisit};
 use pyo3::{prelude::*, IntoPyObjectExt};
 
 use crate::build_tools::{py_schema_err, py_schema_error_type, ExtraBehavior};
 use crate::definitions::{Definitions, DefinitionsBuilder};
-use crate::errors::{LocItem, ValError, ValResult, ValidationError};
+use crate::errors::{add_json_positions, ErrorType, LocItem, ValError, ValLineError, ValResult, ValidationError};
 use crate::input::{Input, InputType, StringMapping};
 use crate::py_gc::PyGcTraverse;
 use crate::recursion_guard::RecursionState;
 use crate::tools::SchemaDict;
-pub(crate) use config::{TemporalUnitMode, ValBytesMode};
+pub(crate) use config::{LaxSequenceTypes, TemporalUnitMode, ValBytesMode};
+use example::ExampleGenerator;
+use ignored_extras::IgnoredExtras;
+use model::InstanceSnapshot;
+use validation_cache::{CacheLookup, ValidationCache};
 
 mod any;
 mod arguments;
 mod arguments_v3;
+mod arrow;
 mod bool;
 mod bytes;
 mod call;
@@ -29,44 +37,80 @@ mod callable;
 mod chain;
 pub(crate) mod complex;
 mod config;
+mod consistency;
+mod const_;
+mod construct;
 mod custom_error;
 mod dataclass;
 mod date;
 mod datetime;
+mod datetime_format;
 pub(crate) mod decimal;
+mod deep_copy;
 mod definitions;
 mod dict;
+mod email;
 mod enum_;
+mod error_messages;
+mod example;
+mod explain;
+mod extras_patterns;
 mod float;
 mod frozenset;
 mod function;
 mod generator;
+mod grapheme;
+mod ignored_extras;
+mod incremental;
+mod input_limits;
+mod instance_cache;
 mod int;
+mod introspect;
+mod ip;
 mod is_instance;
 mod is_subclass;
 mod json;
+mod json_items;
 mod json_or_python;
+mod keyed_list;
 mod lax_or_strict;
 mod list;
 mod literal;
+mod many;
+mod merge;
 mod missing_sentinel;
 mod model;
 mod model_fields;
 mod none;
 mod nullable;
+mod parallel;
+mod partial_report;
+mod path;
+mod plugin;
 mod prebuilt;
+mod profile;
+mod provenance;
+pub(crate) mod semver;
 mod set;
 mod string;
 mod time;
 mod timedelta;
 mod tuple;
 mod typed_dict;
+pub(crate) mod ulid;
 mod union;
+mod units;
 pub(crate) mod url;
 mod uuid;
+mod validation_cache;
+mod validation_options;
 mod validation_state;
 mod with_default;
 
+pub use self::arrow::ArrowBatch;
+pub use self::incremental::{IncrementalValidation, ResumeNode};
+pub use self::union::UnionMode;
+pub use self::validation_options::ValidationOptions;
 pub use self::validation_state::{Exactness, ValidationState};
 pub use with_default::DefaultType;
 
@@ -85,7 +129,7 @@ impl PySome {
 #[pymethods]
 impl PySome {
     pub fn __repr__(&self, py: Python) -> PyResult<String> {
-        Ok(format!("Some({})", self.value.bind(py).repr()?,))
+        Ok(format!("Some({})", self.value.bind(py).repr()?))
     }
 
     #[new]
@@ -105,6 +149,45 @@ impl PySome {
     }
 }
 
+/// The result of validating with `trace`, `exactness`, `partial_report` or `provenance`, the validated output and
+/// the results of those options, `None` for the options not used.
+#[pyclass(module = "pydantic_core._pydantic_core", frozen, get_all)]
+pub struct ValidationResult {
+    output: Py<PyAny>,
+    trace: Option<Py<PyAny>>,
+    exactness: Option<Py<PyAny>>,
+    partial_report: Option<Py<PyAny>>,
+    provenance: Option<Py<PyAny>>,
+}
+
+#[pymethods]
+impl ValidationResult {
+    pub fn __repr__(&self, py: Python) -> PyResult<String> {
+        let repr = |value: &Option<Py<PyAny>>| -> PyResult<String> {
+            match value {
+                Some(value) => Ok(value.bind(py).repr()?.to_string()),
+                None => Ok("None".to_string()),
+            }
+        };
+        Ok(format!(
+            "ValidationResult(output={}, trace={}, exactness={}, partial_report={}, provenance={})",
+            self.output.bind(py).repr()?,
+            repr(&self.trace)?,
+            repr(&self.exactness)?,
+            repr(&self.partial_report)?,
+            repr(&self.provenance)?,
+        ))
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.output)?;
+        for value in [&self.trace, &self.exactness, &self.partial_report, &self.provenance] {
+            visit.call(value)?;
+        }
+        Ok(())
+    }
+}
+
 #[pyclass(module = "pydantic_core._pydantic_core", frozen)]
 #[derive(Debug)]
 pub struct SchemaValidator {
@@ -114,11 +197,19 @@ pub struct SchemaValidator {
     // reconstructing the object for cloudpickle support (see `__reduce__`).
     py_schema: Py<PyAny>,
     py_config: Option<Py<PyDict>>,
-    #[pyo3(get)]
-    title: Py<PyAny>,
+    // `title` from the config, errors are titled with the name of the validator without one
+    config_title: Option<Py<PyAny>>,
     hide_input_in_errors: bool,
     validation_error_cause: bool,
+    json_error_positions: bool,
+    max_errors: Option<NonZeroUsize>,
     cache_str: StringCacheMode,
+    profile: Option<profile::Profile>,
+    error_loc_mapping: Option<Py<PyAny>>,
+    error_message_templates: Option<Py<PyAny>>,
+    validation_cache: Option<ValidationCache>,
+    validation_info_path: bool,
+    input_limits: Option<input_limits::InputLimits>,
 }
 
 impl_py_gc_traverse!(SchemaValidator {
@@ -126,6 +217,9 @@ impl_py_gc_traverse!(SchemaValidator {
     definitions,
     py_schema,
     py_config,
+    error_loc_mapping,
+    error_message_templates,
+    validation_cache,
 });
 
 #[pymethods]
@@ -133,42 +227,83 @@ impl SchemaValidator {
     #[new]
     #[pyo3(signature = (schema, config=None))]
     pub fn py_new(py: Python, schema: &Bound<'_, PyAny>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
-        let mut definitions_builder = DefinitionsBuilder::new();
+        let lazy_definitions: bool = config.get_as(intern!(py, "lazy_definitions"))?.unwrap_or(false);
+        let mut definitions_builder = if lazy_definitions {
+            DefinitionsBuilder::new_lazy(build_lazy_definition)
+        } else {
+            DefinitionsBuilder::new()
+        };
 
-        let validator = build_validator_base(schema, config, &mut definitions_builder)?;
+        let profile_validation: bool = config.get_as(intern!(py, "profile_validation"))?.unwrap_or(false);
+        let (validator, profile) = profile::build_profiled(profile_validation, || {
+            string::with_pattern_cache(|| build_validator_base(schema, config, &mut definitions_builder))
+        })?;
         let definitions = definitions_builder.finish()?;
+        // the outermost definition is always used, so build it now to name the validator
+        if let CombinedValidator::DefinitionRef(definition_ref) = validator.as_ref() {
+            definition_ref.build_definition(py)?;
+        }
         let py_schema = schema.clone().unbind();
         let py_config = match config {
             Some(c) if !c.is_empty() => Some(c.clone().into()),
             _ => None,
         };
         let config_title = match config {
-            Some(c) => c.get_item("title")?,
+            Some(c) => c.get_item("title")?.map(Bound::unbind),
             None => None,
         };
-        let title = match config_title {
-            Some(t) => t.unbind(),
-            None => validator.get_name().into_py_any(py)?,
-        };
         let hide_input_in_errors: bool = config.get_as(intern!(py, "hide_input_in_errors"))?.unwrap_or(false);
         let validation_error_cause: bool = config.get_as(intern!(py, "validation_error_cause"))?.unwrap_or(false);
+        let json_error_positions: bool = config.get_as(intern!(py, "json_error_positions"))?.unwrap_or(false);
+        let max_errors = match config.get_as::<usize>(intern!(py, "max_errors"))? {
+            Some(0) => return py_schema_err!("`max_errors` must be at least 1"),
+            max_errors => max_errors.and_then(NonZeroUsize::new),
+        };
         let cache_str: StringCacheMode = config
             .get_as(intern!(py, "cache_strings"))?
             .unwrap_or(StringCacheMode::All);
+        let error_loc_mapping: Option<Bound<'_, PyAny>> = config.get_as(intern!(py, "error_loc_mapping"))?;
+        if let Some(ref mapping) = error_loc_mapping {
+            if !mapping.is_instance_of::<PyDict>() && !mapping.is_callable() {
+                return py_schema_err!("`error_loc_mapping` must be a dict or a callable");
+            }
+        }
+        let error_message_templates: Option<Bound<'_, PyAny>> =
+            config.get_as(intern!(py, "error_message_templates"))?;
+        if let Some(ref templates) = error_message_templates {
+            if !templates.is_instance_of::<PyDict>() && !templates.is_callable() {
+                return py_schema_err!("`error_message_templates` must be a dict or a callable");
+            }
+        }
+        let validation_cache = config
+            .get_as::<usize>(intern!(py, "validation_cache_size"))?
+            .filter(|&size| size > 0)
+            .map(|size| ValidationCache::new(py, &validator, size))
+            .transpose()?;
+        let validation_info_path: bool = config.get_as(intern!(py, "validation_info_path"))?.unwrap_or(false);
+        let input_limits = input_limits::InputLimits::from_config(py, config)?;
         Ok(Self {
             validator,
             definitions,
             py_schema,
             py_config,
-            title,
+            config_title,
             hide_input_in_errors,
             validation_error_cause,
+            json_error_positions,
+            max_errors,
             cache_str,
+            profile,
+            error_loc_mapping: error_loc_mapping.map(Bound::unbind),
+            error_message_templates: error_message_templates.map(Bound::unbind),
+            validation_cache,
+            validation_info_path,
+            input_limits,
         })
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (input, *, strict=None, extra=None, from_attributes=None, context=None, self_instance=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None))]
+    #[pyo3(signature = (input, *, strict=None, extra=None, from_attributes=None, context=None, self_instance=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None, strict_scope=StrictScope::Recursive, max_errors=None, fail_fast=false, trace=false, exactness=false, partial_report=false, partial_update=false, on_extra_ignored=None, provenance=false, union_mode=None, reference_time=None, identity_cache=false, timeout_ms=None))]
     pub fn validate_python(
         &self,
         py: Python,
@@ -181,17 +316,26 @@ impl SchemaValidator {
         allow_partial: PartialMode,
         by_alias: Option<bool>,
         by_name: Option<bool>,
+        strict_scope: StrictScope,
+        max_errors: Option<usize>,
+        fail_fast: bool,
+        trace: bool,
+        exactness: bool,
+        partial_report: bool,
+        partial_update: bool,
+        on_extra_ignored: Option<&Bound<'_, PyAny>>,
+        provenance: bool,
+        union_mode: Option<UnionMode>,
+        reference_time: Option<&Bound<'_, PyAny>>,
+        identity_cache: bool,
+        timeout_ms: Option<u64>,
     ) -> PyResult<Py<PyAny>> {
         let extra_behavior = extra
             .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
             .transpose()?;
-
-        #[allow(clippy::used_underscore_items)]
-        self._validate(
-            py,
-            input,
-            InputType::Python,
+        let options = ValidationOptions {
             strict,
+            strict_scope,
             extra_behavior,
             from_attributes,
             context,
@@ -199,12 +343,24 @@ impl SchemaValidator {
             allow_partial,
             by_alias,
             by_name,
-        )
-        .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
+            max_errors: non_zero_max_errors(max_errors)?,
+            fail_fast,
+            trace,
+            exactness,
+            partial_report,
+            partial_update,
+            on_extra_ignored,
+            provenance,
+            union_mode,
+            reference_time,
+            identity_cache,
+            timeout_ms,
+        };
+        self.validate_python_with(py, input, &options)
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (input, *, strict=None, extra=None, from_attributes=None, context=None, self_instance=None, by_alias=None, by_name=None))]
+    #[pyo3(signature = (input, *, strict=None, extra=None, from_attributes=None, context=None, self_instance=None, by_alias=None, by_name=None, strict_scope=StrictScope::Recursive))]
     pub fn isinstance_python(
         &self,
         py: Python,
@@ -216,35 +372,27 @@ impl SchemaValidator {
         self_instance: Option<&Bound<'_, PyAny>>,
         by_alias: Option<bool>,
         by_name: Option<bool>,
+        strict_scope: StrictScope,
     ) -> PyResult<bool> {
         let extra_behavior = extra
             .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
             .transpose()?;
-
-        #[allow(clippy::used_underscore_items)]
-        match self._validate(
-            py,
-            input,
-            InputType::Python,
+        let options = ValidationOptions {
             strict,
+            strict_scope,
             extra_behavior,
             from_attributes,
             context,
             self_instance,
-            false.into(),
             by_alias,
             by_name,
-        ) {
-            Ok(_) => Ok(true),
-            Err(ValError::InternalErr(err)) => Err(err),
-            Err(ValError::Omit) => Err(ValidationError::omit_error()),
-            Err(ValError::UseDefault) => Err(ValidationError::use_default_error()),
-            Err(ValError::LineErrors(_)) => Ok(false),
-        }
+            ..ValidationOptions::default()
+        };
+        self.isinstance_python_with(py, input, &options)
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (input, *, strict=None, extra=None, context=None, self_instance=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None))]
+    #[pyo3(signature = (input, *, strict=None, extra=None, context=None, self_instance=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None, strict_scope=StrictScope::Recursive, max_errors=None, fail_fast=false, trace=false, exactness=false, partial_report=false, partial_update=false, on_extra_ignored=None, provenance=false, union_mode=None, reference_time=None, timeout_ms=None))]
     pub fn validate_json(
         &self,
         py: Python,
@@ -256,32 +404,49 @@ impl SchemaValidator {
         allow_partial: PartialMode,
         by_alias: Option<bool>,
         by_name: Option<bool>,
+        strict_scope: StrictScope,
+        max_errors: Option<usize>,
+        fail_fast: bool,
+        trace: bool,
+        exactness: bool,
+        partial_report: bool,
+        partial_update: bool,
+        on_extra_ignored: Option<&Bound<'_, PyAny>>,
+        provenance: bool,
+        union_mode: Option<UnionMode>,
+        reference_time: Option<&Bound<'_, PyAny>>,
+        timeout_ms: Option<u64>,
     ) -> PyResult<Py<PyAny>> {
         let extra_behavior = extra
             .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
             .transpose()?;
-
-        let r = match json::validate_json_bytes(input) {
-            #[allow(clippy::used_underscore_items)]
-            Ok(v_match) => self._validate_json(
-                py,
-                input,
-                v_match.into_inner().as_slice(),
-                strict,
-                extra_behavior,
-                context,
-                self_instance,
-                allow_partial,
-                by_alias,
-                by_name,
-            ),
-            Err(err) => Err(err),
+        let options = ValidationOptions {
+            strict,
+            strict_scope,
+            extra_behavior,
+            context,
+            self_instance,
+            allow_partial,
+            by_alias,
+            by_name,
+            max_errors: non_zero_max_errors(max_errors)?,
+            fail_fast,
+            trace,
+            exactness,
+            partial_report,
+            partial_update,
+            on_extra_ignored,
+            provenance,
+            union_mode,
+            reference_time,
+            timeout_ms,
+            ..ValidationOptions::default()
         };
-        r.map_err(|e| self.prepare_validation_err(py, e, InputType::Json))
+        self.validate_json_with(py, input, &options)
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (input, *, strict=None, extra=None, context=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None))]
+    #[pyo3(signature = (input, *, strict=None, extra=None, context=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None, strict_scope=StrictScope::Recursive, max_errors=None, fail_fast=false, trace=false, exactness=false, partial_report=false, partial_update=false, on_extra_ignored=None, provenance=false, union_mode=None, reference_time=None, timeout_ms=None, env_style=false))]
     pub fn validate_strings(
         &self,
         py: Python,
@@ -292,34 +457,49 @@ impl SchemaValidator {
         allow_partial: PartialMode,
         by_alias: Option<bool>,
         by_name: Option<bool>,
+        strict_scope: StrictScope,
+        max_errors: Option<usize>,
+        fail_fast: bool,
+        trace: bool,
+        exactness: bool,
+        partial_report: bool,
+        partial_update: bool,
+        on_extra_ignored: Option<&Bound<'_, PyAny>>,
+        provenance: bool,
+        union_mode: Option<UnionMode>,
+        reference_time: Option<&Bound<'_, PyAny>>,
+        timeout_ms: Option<u64>,
+        env_style: bool,
     ) -> PyResult<Py<PyAny>> {
-        let t = InputType::String;
-        let string_mapping = StringMapping::new_value(input).map_err(|e| self.prepare_validation_err(py, e, t))?;
         let extra_behavior = extra
             .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
             .transpose()?;
-
-        #[allow(clippy::used_underscore_items)]
-        match self._validate(
-            py,
-            &string_mapping,
-            t,
+        let options = ValidationOptions {
             strict,
+            strict_scope,
             extra_behavior,
-            None,
             context,
-            None,
             allow_partial,
             by_alias,
             by_name,
-        ) {
-            Ok(r) => Ok(r),
-            Err(e) => Err(self.prepare_validation_err(py, e, t)),
-        }
+            max_errors: non_zero_max_errors(max_errors)?,
+            fail_fast,
+            trace,
+            exactness,
+            partial_report,
+            partial_update,
+            on_extra_ignored,
+            provenance,
+            union_mode,
+            reference_time,
+            timeout_ms,
+            ..ValidationOptions::default()
+        };
+        self.validate_strings_with(py, input, env_style, &options)
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (obj, field_name, field_value, *, strict=None, extra=None,from_attributes=None, context=None, by_alias=None, by_name=None))]
+    #[pyo3(signature = (obj, field_name, field_value, *, strict=None, extra=None,from_attributes=None, context=None, by_alias=None, by_name=None, strict_scope=StrictScope::Recursive))]
     pub fn validate_assignment(
         &self,
         py: Python,
@@ -332,6 +512,7 @@ impl SchemaValidator {
         context: Option<&Bound<'_, PyAny>>,
         by_alias: Option<bool>,
         by_name: Option<bool>,
+        strict_scope: StrictScope,
     ) -> PyResult<Py<PyAny>> {
         let extra_behavior = extra
             .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
@@ -340,7 +521,9 @@ impl SchemaValidator {
         let extra = Extra {
             input_type: InputType::Python,
             data: None,
+            parent_data: None,
             strict,
+            strict_scope,
             extra_behavior,
             from_attributes,
             field_name: Some(PyString::new(py, field_name)),
@@ -353,11 +536,397 @@ impl SchemaValidator {
 
         let guard = &mut RecursionState::default();
         let mut state = ValidationState::new(extra, guard, false.into());
+        state.path = self.validation_info_path.then(Vec::new);
         self.validator
             .validate_assignment(py, &obj, field_name, &field_value, &mut state)
             .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
     }
 
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (obj, data, *, strict=None, from_attributes=None, context=None, by_alias=None, by_name=None))]
+    pub fn validate_into<'py>(
+        &self,
+        py: Python<'py>,
+        obj: Bound<'py, PyAny>,
+        data: &Bound<'py, PyDict>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'py, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> PyResult<Bound<'py, PySet>> {
+        let mut snapshot = InstanceSnapshot::take(&obj)?;
+        let changed = PySet::empty(py)?;
+        let mut errors: Vec<ValLineError> = Vec::new();
+
+        for (key, field_value) in data {
+            let field_name_py = key.downcast::<PyString>()?;
+            let field_name = field_name_py.to_str()?;
+            let old_value = obj.getattr_opt(field_name_py)?;
+            snapshot.record_field(&obj, field_name_py)?;
+
+            let extra = Extra {
+                input_type: InputType::Python,
+                data: None,
+                parent_data: None,
+                strict,
+                strict_scope: StrictScope::Recursive,
+                extra_behavior: None,
+                from_attributes,
+                field_name: Some(field_name_py.clone()),
+                context,
+                self_instance: None,
+                cache_str: self.cache_str,
+                by_alias,
+                by_name,
+            };
+            let guard = &mut RecursionState::default();
+            let mut state = ValidationState::new(extra, guard, false.into());
+            state.path = self.validation_info_path.then(Vec::new);
+            match self
+                .validator
+                .validate_assignment(py, &obj, field_name, &field_value, &mut state)
+            {
+                Ok(_) => {
+                    let unchanged = match old_value {
+                        Some(old_value) => old_value.eq(obj.getattr(field_name_py)?)?,
+                        None => false,
+                    };
+                    if !unchanged {
+                        changed.add(field_name_py)?;
+                    }
+                }
+                Err(ValError::LineErrors(line_errors)) => errors.extend(line_errors),
+                Err(err) => {
+                    snapshot.restore(&obj)?;
+                    return Err(self.prepare_validation_err(py, err, InputType::Python));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(changed)
+        } else {
+            // apply all of the data or none of it
+            snapshot.restore(&obj)?;
+            Err(self.prepare_validation_err(py, ValError::LineErrors(errors), InputType::Python))
+        }
+    }
+
+    #[pyo3(signature = (obj, memo=None))]
+    pub fn deep_copy<'py>(
+        &self,
+        py: Python<'py>,
+        obj: &Bound<'py, PyAny>,
+        memo: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match memo {
+            Some(memo) => deep_copy::deep_copy(obj, memo),
+            None => deep_copy::deep_copy(obj, &PyDict::new(py)),
+        }
+    }
+
+    #[pyo3(signature = (*, reset=false))]
+    pub fn get_profile<'py>(&self, py: Python<'py>, reset: bool) -> PyResult<Bound<'py, PyDict>> {
+        let Some(profile) = &self.profile else {
+            return Err(PyRuntimeError::new_err(
+                "Validation profiling is not enabled, set `profile_validation` in the config to enable it",
+            ));
+        };
+        let profile_dict = profile.to_py(py)?;
+        if reset {
+            profile.reset();
+        }
+        Ok(profile_dict)
+    }
+
+    pub fn clear_validation_cache(&self, py: Python<'_>) {
+        if let Some(cache) = &self.validation_cache {
+            cache.clear(py);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, extra=None, context=None, by_alias=None, by_name=None, chunk_size=65536))]
+    pub fn validate_json_items(
+        slf: &Bound<'_, Self>,
+        input: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        extra: Option<&Bound<'_, PyString>>,
+        context: Option<&Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+        chunk_size: usize,
+    ) -> PyResult<json_items::JsonItemsIterator> {
+        if chunk_size == 0 {
+            return Err(PyValueError::new_err("`chunk_size` must be greater than 0"));
+        }
+        let extra_behavior = extra
+            .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
+            .transpose()?;
+        json_items::JsonItemsIterator::new(
+            slf.clone().unbind(),
+            input,
+            chunk_size,
+            strict,
+            extra_behavior,
+            context,
+            by_alias,
+            by_name,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (*, allow_partial=PartialMode::On, strict=None, extra=None, context=None, by_alias=None, by_name=None))]
+    pub fn incremental_json(
+        slf: &Bound<'_, Self>,
+        allow_partial: PartialMode,
+        strict: Option<bool>,
+        extra: Option<&Bound<'_, PyString>>,
+        context: Option<&Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> PyResult<incremental::IncrementalValidation> {
+        if !allow_partial.is_active() {
+            return Err(PyValueError::new_err(
+                "`allow_partial` must be `True` or 'trailing-strings'",
+            ));
+        }
+        let extra_behavior = extra
+            .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
+            .transpose()?;
+        Ok(incremental::IncrementalValidation::new(
+            slf.clone().unbind(),
+            allow_partial,
+            strict,
+            extra_behavior,
+            context,
+            by_alias,
+            by_name,
+        ))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (inputs, *, input_type="python", strict=None, extra=None, from_attributes=None, context=None, by_alias=None, by_name=None))]
+    pub fn validate_many<'py>(
+        &self,
+        py: Python<'py>,
+        inputs: &Bound<'py, PyAny>,
+        input_type: &str,
+        strict: Option<bool>,
+        extra: Option<&Bound<'_, PyString>>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'py, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> PyResult<Bound<'py, PyList>> {
+        let extra_behavior = extra
+            .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
+            .transpose()?;
+        let inputs = inputs.try_iter()?.collect::<PyResult<Vec<_>>>()?;
+        let settings = many::BatchSettings {
+            input_type: InputType::try_from(input_type)?,
+            strict,
+            extra_behavior,
+            from_attributes,
+            context,
+            by_alias,
+            by_name,
+        };
+        many::validate_many(self, py, &inputs, &settings)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (inputs, *, input_type="python", strict=None, extra=None, from_attributes=None, context=None, by_alias=None, by_name=None))]
+    pub fn validate_many_arrow<'py>(
+        &self,
+        py: Python<'py>,
+        inputs: &Bound<'py, PyAny>,
+        input_type: &str,
+        strict: Option<bool>,
+        extra: Option<&Bound<'_, PyString>>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'py, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> PyResult<ArrowBatch> {
+        let extra_behavior = extra
+            .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
+            .transpose()?;
+        let inputs = inputs.try_iter()?.collect::<PyResult<Vec<_>>>()?;
+        let settings = many::BatchSettings {
+            input_type: InputType::try_from(input_type)?,
+            strict,
+            extra_behavior,
+            from_attributes,
+            context,
+            by_alias,
+            by_name,
+        };
+        let mut builder = arrow::BatchBuilder::default();
+        many::for_each_result(self, py, &inputs, &settings, |index, result| {
+            builder.push_result(py, index, result)
+        })?;
+        Ok(builder.finish())
+    }
+
+    #[pyo3(signature = (data, *, fields_set=None, by_alias=None, by_name=None))]
+    pub fn construct<'py>(
+        &self,
+        py: Python<'py>,
+        data: &Bound<'py, PyAny>,
+        fields_set: Option<&Bound<'py, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let options = construct::ConstructOptions { by_alias, by_name };
+        construct::construct(py, &self.validator, data, fields_set, options)
+    }
+
+    pub fn fields<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        introspect::fields(py, &self.validator)
+    }
+
+    pub fn required_fields<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        introspect::required_fields(py, &self.validator)
+    }
+
+    #[pyo3(signature = (*, mode = "validation"))]
+    pub fn aliases<'py>(&self, py: Python<'py>, mode: &str) -> PyResult<Bound<'py, PyDict>> {
+        introspect::aliases(py, &self.validator, mode)
+    }
+
+    pub fn defaults<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        introspect::defaults(py, &self.validator)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (layers, *, strict=None, extra=None, context=None, by_alias=None, by_name=None))]
+    pub fn validate_merged<'py>(
+        &self,
+        py: Python<'py>,
+        layers: &Bound<'py, PyAny>,
+        strict: Option<bool>,
+        extra: Option<&Bound<'_, PyString>>,
+        context: Option<&Bound<'py, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> PyResult<Bound<'py, PyTuple>> {
+        let extra_behavior = extra
+            .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
+            .transpose()?;
+        let sources = PyDict::new(py);
+        let merged = merge::merge_layers(py, &self.validator, layers, &sources, by_alias, by_name)?;
+
+        let options = ValidationOptions {
+            strict,
+            extra_behavior,
+            context,
+            by_alias,
+            by_name,
+            max_errors: self.max_errors,
+            ..ValidationOptions::default()
+        };
+        #[allow(clippy::used_underscore_items)]
+        let output = self
+            ._validate(py, merged.as_any(), InputType::Python, &options)
+            .map_err(|e| self.prepare_collected_validation_err(py, e, InputType::Python, self.max_errors))?;
+        PyTuple::new(py, [output.into_bound(py), sources.into_any()])
+    }
+
+    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None))]
+    pub fn explain<'py>(
+        &self,
+        py: Python<'py>,
+        input: &Bound<'py, PyAny>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let mut recursion_guard = RecursionState::default();
+        let options = ValidationOptions {
+            strict,
+            from_attributes,
+            context,
+            ..ValidationOptions::default()
+        };
+        let mut state = ValidationState::new(
+            Extra::new(&options, InputType::Python, self.cache_str),
+            &mut recursion_guard,
+            false.into(),
+        );
+        state.exactness = Some(Exactness::Exact);
+        state.explanation = Some(explain::Explanation::default());
+        let result = self.validator.validate(py, input, &mut state);
+        let explanation = state.explanation.take().unwrap_or_default();
+
+        let errors = match result {
+            Ok(_) => PyList::empty(py).into_any(),
+            Err(error) => {
+                let err = self.prepare_collected_validation_err(py, error, InputType::Python, self.max_errors);
+                if !err.is_instance_of::<ValidationError>(py) {
+                    return Err(err);
+                }
+                err.value(py).call_method0(intern!(py, "errors"))?
+            }
+        };
+        let report = PyDict::new(py);
+        report.set_item(intern!(py, "valid"), errors.len()? == 0)?;
+        report.set_item(intern!(py, "coercions"), explanation.coercions_to_py(py)?)?;
+        report.set_item(intern!(py, "defaults"), explanation.defaults_to_py(py)?)?;
+        report.set_item(intern!(py, "errors"), errors)?;
+        Ok(report)
+    }
+
+    #[pyo3(signature = (*, seed=None, context=None))]
+    pub fn generate_example(
+        &self,
+        py: Python<'_>,
+        seed: Option<u64>,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Py<PyAny>> {
+        let seed = seed.unwrap_or_else(|| std::collections::hash_map::RandomState::new().build_hasher().finish());
+        let mut generator = ExampleGenerator::new(seed, context);
+        // inputs are sampled within the constraints validators know about, others, e.g. of functions, can reject
+        // them, so a few inputs are tried
+        let mut error = None;
+        for _ in 0..example::ATTEMPTS {
+            let input = self.validator.example(py, &mut generator)?;
+            let mut recursion_guard = RecursionState::default();
+            // fields are generated by name, as they might not have an alias
+            let options = ValidationOptions {
+                context,
+                by_name: Some(true),
+                ..ValidationOptions::default()
+            };
+            let extra = Extra::new(&options, InputType::Python, self.cache_str);
+            let mut state = ValidationState::new(extra, &mut recursion_guard, false.into());
+            state.path = self.validation_info_path.then(Vec::new);
+            match self.validator.validate(py, &input, &mut state) {
+                Ok(example) => return Ok(example),
+                Err(e) => {
+                    let err = self.prepare_validation_err(py, e, InputType::Python);
+                    // errors other than validation errors, e.g. from validator functions, are raised
+                    if !err.is_instance_of::<ValidationError>(py) {
+                        return Err(err);
+                    }
+                    error = Some(err);
+                }
+            }
+        }
+        let err = PyValueError::new_err(format!(
+            "Couldn't generate a valid example in {} attempts, the last example was invalid",
+            example::ATTEMPTS
+        ));
+        err.set_cause(py, error);
+        Err(err)
+    }
+
+    pub fn check_examples<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        let config = self.py_config.as_ref().map(|config| config.bind(py));
+        crate::schema_check::check_examples(py, self.py_schema.bind(py), config)
+    }
+
     #[pyo3(signature = (*, strict=None, context=None))]
     pub fn get_default_value(
         &self,
@@ -368,7 +937,9 @@ impl SchemaValidator {
         let extra = Extra {
             input_type: InputType::Python,
             data: None,
+            parent_data: None,
             strict,
+            strict_scope: StrictScope::Recursive,
             extra_behavior: None,
             from_attributes: None,
             field_name: None,
@@ -380,6 +951,7 @@ impl SchemaValidator {
         };
         let recursion_guard = &mut RecursionState::default();
         let mut state = ValidationState::new(extra, recursion_guard, false.into());
+        state.path = self.validation_info_path.then(Vec::new);
         let r = self.validator.default_value(py, None::<i64>, &mut state);
         match r {
             Ok(maybe_default) => match maybe_default {
@@ -395,10 +967,20 @@ impl SchemaValidator {
         Ok((slf.get_type(), init_args))
     }
 
+    /// The title of validation errors, the name of the validator is only known once the definitions it
+    /// names have been built, which may be after the validator is built with `lazy_definitions`.
+    #[getter]
+    pub fn title(&self, py: Python) -> Py<PyAny> {
+        match &self.config_title {
+            Some(title) => title.clone_ref(py),
+            None => PyString::new(py, self.validator.get_name()).into_any().unbind(),
+        }
+    }
+
     pub fn __repr__(&self, py: Python) -> String {
         format!(
             "SchemaValidator(title={:?}, validator={:#?}, definitions={:#?}, cache_strings={})",
-            self.title.extract::<&str>(py).unwrap(),
+            self.title(py).extract::<&str>(py).unwrap(),
             self.validator,
             self.definitions,
             match self.cache_str {
@@ -415,82 +997,313 @@ impl SchemaValidator {
 }
 
 impl SchemaValidator {
-    #[allow(clippy::too_many_arguments)]
+    /// Validate a python object with `options`, like `validate_python`.
+    pub fn validate_python_with<'py>(
+        &self,
+        py: Python<'py>,
+        input: &Bound<'py, PyAny>,
+        options: &ValidationOptions<'_, 'py>,
+    ) -> PyResult<Py<PyAny>> {
+        let options = &self.with_config_max_errors(options);
+        let cached = match self.cache_lookup(ValidationCache::python_kind(py), input, options)? {
+            CacheLookup::Hit(output) => return Ok(output),
+            cached => cached,
+        };
+
+        self.check_input_limits(input)
+            .and_then(|()| {
+                #[allow(clippy::used_underscore_items)]
+                self._validate(py, input, InputType::Python, options)
+            })
+            .map_err(|e| self.prepare_collected_validation_err(py, e, InputType::Python, options.max_errors))
+            .and_then(|output| cached.insert(output))
+    }
+
+    /// Whether a python object is valid with `options`, like `isinstance_python`.
+    pub fn isinstance_python_with<'py>(
+        &self,
+        py: Python<'py>,
+        input: &Bound<'py, PyAny>,
+        options: &ValidationOptions<'_, 'py>,
+    ) -> PyResult<bool> {
+        let options = ValidationOptions {
+            max_errors: None,
+            // any error is enough to know the input is invalid
+            fail_fast: true,
+            ..options.clone()
+        };
+        #[allow(clippy::used_underscore_items)]
+        match self._validate(py, input, InputType::Python, &options) {
+            Ok(_) => Ok(true),
+            Err(ValError::InternalErr(err)) => Err(err),
+            Err(ValError::Omit) => Err(ValidationError::omit_error()),
+            Err(ValError::UseDefault) => Err(ValidationError::use_default_error()),
+            Err(ValError::LineErrors(_)) => Ok(false),
+        }
+    }
+
+    /// Validate a JSON string with `options`, like `validate_json`.
+    pub fn validate_json_with<'py>(
+        &self,
+        py: Python<'py>,
+        input: &Bound<'py, PyAny>,
+        options: &ValidationOptions<'_, 'py>,
+    ) -> PyResult<Py<PyAny>> {
+        let options = &self.with_config_max_errors(options);
+        let cached = match self.cache_lookup(ValidationCache::json_kind(py), input, options)? {
+            CacheLookup::Hit(output) => return Ok(output),
+            cached => cached,
+        };
+
+        match json::validate_json_bytes(input) {
+            Ok(v_match) => {
+                let json_either_bytes = v_match.into_inner();
+                let json_data = json_either_bytes.as_slice();
+                #[allow(clippy::used_underscore_items)]
+                self._validate_json(py, input, json_data, options)
+                    .map_err(|e| self.prepare_collected_validation_err(py, e, InputType::Json, options.max_errors))
+                    .and_then(|output| cached.insert(output))
+            }
+            Err(err) => Err(self.prepare_validation_err(py, err, InputType::Json)),
+        }
+    }
+
+    /// Validate a string or a mapping of strings with `options`, like `validate_strings`.
+    pub fn validate_strings_with<'py>(
+        &self,
+        py: Python<'py>,
+        input: Bound<'py, PyAny>,
+        env_style: bool,
+        options: &ValidationOptions<'_, 'py>,
+    ) -> PyResult<Py<PyAny>> {
+        let t = InputType::String;
+        let options = &self.with_config_max_errors(options);
+        self.check_input_limits(&input)
+            .map_err(|e| self.prepare_collected_validation_err(py, e, t, options.max_errors))?;
+        let string_mapping =
+            StringMapping::new_value(input, env_style).map_err(|e| self.prepare_validation_err(py, e, t))?;
+
+        #[allow(clippy::used_underscore_items)]
+        self._validate(py, &string_mapping, t, options)
+            .map_err(|e| self.prepare_collected_validation_err(py, e, t, options.max_errors))
+    }
+
+    /// `options` with `max_errors` of the config if they don't set it.
+    fn with_config_max_errors<'a, 'py>(&self, options: &ValidationOptions<'a, 'py>) -> ValidationOptions<'a, 'py> {
+        ValidationOptions {
+            max_errors: options.max_errors.or(self.max_errors),
+            ..options.clone()
+        }
+    }
+
+    /// Look up `input` in the `validation_cache`, inputs are cached separately for each combination of the options
+    /// which change the output, and not at all with options like `context`.
+    fn cache_lookup<'a, 'py>(
+        &'a self,
+        input_kind: &Bound<'py, PyString>,
+        input: &Bound<'py, PyAny>,
+        options: &ValidationOptions<'_, 'py>,
+    ) -> PyResult<CacheLookup<'a, 'py>> {
+        match (&self.validation_cache, options.cache_key()) {
+            (Some(cache), Some(options_key)) => cache.lookup(input_kind, &options_key, input),
+            _ => Ok(CacheLookup::Skip),
+        }
+    }
+
+    /// Check the `max_input_*` limits of the config before validating Python input.
+    fn check_input_limits(&self, input: &Bound<'_, PyAny>) -> ValResult<()> {
+        match &self.input_limits {
+            Some(input_limits) => input_limits.check_python(input),
+            None => Ok(()),
+        }
+    }
+
     fn _validate<'py>(
         &self,
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
         input_type: InputType,
-        strict: Option<bool>,
-        extra_behavior: Option<ExtraBehavior>,
-        from_attributes: Option<bool>,
-        context: Option<&Bound<'py, PyAny>>,
-        self_instance: Option<&Bound<'py, PyAny>>,
-        allow_partial: PartialMode,
-        by_alias: Option<bool>,
-        by_name: Option<bool>,
+        options: &ValidationOptions<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        #[allow(clippy::used_underscore_items)]
+        self._validate_truncated(py, input, input_type, options, None)
+    }
+
+    /// Like `_validate`, `json_truncation` is what the JSON parser left out of partial JSON, for `partial_report`.
+    fn _validate_truncated<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        input_type: InputType,
+        options: &ValidationOptions<'_, 'py>,
+        json_truncation: Option<partial_report::PartialOmission>,
     ) -> ValResult<Py<PyAny>> {
         let mut recursion_guard = RecursionState::default();
         let mut state = ValidationState::new(
-            Extra::new(
-                strict,
-                extra_behavior,
-                from_attributes,
-                context,
-                self_instance,
-                input_type,
-                self.cache_str,
-                by_alias,
-                by_name,
-            ),
+            Extra::new(options, input_type, self.cache_str),
             &mut recursion_guard,
-            allow_partial,
+            options.allow_partial,
         );
-        self.validator.validate(py, input, &mut state)
+        state.max_errors = options.max_errors;
+        state.fail_fast = options.fail_fast;
+        state.partial_update = options.partial_update;
+        state.union_trace = options.trace.then(Vec::new);
+        state.partial_omissions = options.partial_report.then(Vec::new);
+        state.ignored_extras = options
+            .on_extra_ignored
+            .map(|callback| IgnoredExtras::new(callback.clone()));
+        state.provenance = options.provenance.then(provenance::Provenance::default);
+        state.union_mode = options.union_mode;
+        state.reference_time = options.reference_time.map(datetime::reference_time).transpose()?;
+        state.instance_cache = options.identity_cache.then(instance_cache::InstanceCache::default);
+        state.path = self.validation_info_path.then(Vec::new);
+        state.deadline = options.timeout_ms.map(|ms| Instant::now() + Duration::from_millis(ms));
+        if options.exactness {
+            // track exactness as union validation does, lowered by any coercion during validation
+            state.exactness = Some(Exactness::Exact);
+        }
+        let result = self.validator.validate(py, input, &mut state);
+        if let (true, Some(timeout_ms)) = (state.timed_out, options.timeout_ms) {
+            // whatever the validation returned once it was abandoned, it's replaced by the timeout error
+            return Err(ValError::new(
+                ErrorType::ValidationTimeout {
+                    timeout_ms,
+                    context: None,
+                },
+                input,
+            ));
+        }
+        let output = result?;
+        if let Some(json_truncation) = json_truncation {
+            state.record_json_truncation(json_truncation);
+        }
+        let exactness = match state.exactness {
+            Some(state_exactness) if options.exactness => Some(union::exactness_str(state_exactness)),
+            _ => None,
+        };
+        let partial_report = state
+            .partial_omissions
+            .map(|omissions| {
+                partial_report::partial_omissions_to_py(py, omissions, input_type, !self.hide_input_in_errors)
+            })
+            .transpose()?;
+        if !(options.trace || options.exactness || options.partial_report || options.provenance) {
+            return Ok(output);
+        }
+        let trace = state
+            .union_trace
+            .map(|union_trace| union::union_traces_to_py(py, &union_trace))
+            .transpose()?;
+        let provenance = state
+            .provenance
+            .map(|provenance| provenance.leaves_to_py(py))
+            .transpose()?;
+        let result = ValidationResult {
+            output,
+            trace: trace.map(|trace| trace.into_any().unbind()),
+            exactness: exactness.map(|exactness| exactness.into_py_any(py)).transpose()?,
+            partial_report: partial_report.map(|report| report.into_any().unbind()),
+            provenance: provenance.map(|provenance| provenance.into_any().unbind()),
+        };
+        Ok(Bound::new(py, result)?.into_any().unbind())
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn _validate_json(
+    fn _validate_json<'py>(
         &self,
-        py: Python,
-        input: &Bound<'_, PyAny>,
+        py: Python<'py>,
+        input: &Bound<'py, PyAny>,
         json_data: &[u8],
-        strict: Option<bool>,
-        extra_behavior: Option<ExtraBehavior>,
-        context: Option<&Bound<'_, PyAny>>,
-        self_instance: Option<&Bound<'_, PyAny>>,
-        allow_partial: PartialMode,
-        by_alias: Option<bool>,
-        by_name: Option<bool>,
+        options: &ValidationOptions<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        let json_value = jiter::JsonValue::parse_with_config(json_data, true, allow_partial)
-            .map_err(|e| json::map_json_err(input, e, json_data))?;
+        if let Some(input_limits) = &self.input_limits {
+            input_limits
+                .check_json_data(json_data)
+                .map_err(|e| self.add_json_positions(e, json_data, None))?;
+        }
+        let json_value = jiter::JsonValue::parse_with_config(json_data, true, options.allow_partial)
+            .map_err(|e| self.add_json_positions(json::map_json_data_err(input, e, json_data), json_data, None))?;
+        self.validate_parsed_json(py, json_data, &json_value, options)
+            // the inputs of errors are found in the parsed JSON, so before it's dropped
+            .map_err(|e| self.add_json_positions(e, json_data, Some(&json_value)))
+    }
+
+    fn validate_parsed_json<'py>(
+        &self,
+        py: Python<'py>,
+        json_data: &[u8],
+        json_value: &jiter::JsonValue<'_>,
+        options: &ValidationOptions<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        if let (Some(input_limits), PartialMode::On | PartialMode::TrailingStrings) =
+            (&self.input_limits, options.allow_partial)
+        {
+            // the end of partial JSON isn't valid JSON, so it's checked once it's parsed
+            input_limits.check_json(json_value)?;
+        }
+        let json_truncation = match options.allow_partial {
+            PartialMode::On | PartialMode::TrailingStrings if options.partial_report => {
+                partial_report::json_truncation(json_data, json_value)
+            }
+            _ => None,
+        };
         #[allow(clippy::used_underscore_items)]
-        self._validate(
-            py,
-            &json_value,
-            InputType::Json,
-            strict,
-            extra_behavior,
-            None,
-            context,
-            self_instance,
-            allow_partial,
-            by_alias,
-            by_name,
-        )
+        self._validate_truncated(py, json_value, InputType::Json, options, json_truncation)
     }
 
-    fn prepare_validation_err(&self, py: Python, error: ValError, input_type: InputType) -> PyErr {
-        ValidationError::from_val_error(
+    /// Find the position in `json_data` of the input of each error if `json_error_positions` is set, `json_value`
+    /// is the JSON parsed from it, if it could be parsed.
+    pub(crate) fn add_json_positions(
+        &self,
+        error: ValError,
+        json_data: &[u8],
+        json_value: Option<&jiter::JsonValue<'_>>,
+    ) -> ValError {
+        if self.json_error_positions {
+            add_json_positions(error, json_data, json_value)
+        } else {
+            error
+        }
+    }
+
+    /// Like `prepare_validation_err`, but also truncates the errors to `max_errors`.
+    /// Error locations and messages are translated with `error_loc_mapping` and `error_message_templates`.
+    fn prepare_collected_validation_err(
+        &self,
+        py: Python,
+        error: ValError,
+        input_type: InputType,
+        max_errors: Option<NonZeroUsize>,
+    ) -> PyErr {
+        let py_err = ValidationError::from_val_error(
             py,
-            self.title.clone_ref(py),
+            self.title(py),
             input_type,
             error,
             None,
             self.hide_input_in_errors,
             self.validation_error_cause,
-        )
+        );
+        if let Ok(validation_error) = py_err.value(py).downcast::<ValidationError>() {
+            let mut validation_error = validation_error.borrow_mut();
+            if let Some(max_errors) = max_errors {
+                validation_error.truncate(max_errors.get());
+            }
+            if let Some(mapping) = &self.error_loc_mapping {
+                if let Err(err) = validation_error.map_locations(mapping.bind(py)) {
+                    return err;
+                }
+            }
+            if let Some(templates) = &self.error_message_templates {
+                if let Err(err) = validation_error.translate_messages(templates.bind(py)) {
+                    return err;
+                }
+            }
+        }
+        py_err
+    }
+
+    fn prepare_validation_err(&self, py: Python, error: ValError, input_type: InputType) -> PyErr {
+        self.prepare_collected_validation_err(py, error, input_type, None)
     }
 }
 
@@ -506,6 +1319,15 @@ pub trait BuildValidator: Sized {
     ) -> PyResult<Arc<CombinedValidator>>;
 }
 
+/// `max_errors` of a validation call, with a clearer error than `NonZeroUsize`'s for `0`.
+fn non_zero_max_errors(max_errors: Option<usize>) -> PyResult<Option<NonZeroUsize>> {
+    max_errors
+        .map(|max_errors| {
+            NonZeroUsize::new(max_errors).ok_or_else(|| PyValueError::new_err("`max_errors` must be at least 1"))
+        })
+        .transpose()
+}
+
 /// Logic to create a particular validator, called in the `validator_match` macro, then in turn by `build_validator`
 fn build_specific_validator<T: BuildValidator>(
     val_type: &str,
@@ -548,6 +1370,15 @@ pub fn build_validator(
     build_validator_inner(schema, config, definitions, true)
 }
 
+/// Build a definition of a schema built with `lazy_definitions`, when it's first used.
+fn build_lazy_definition(
+    schema: &Bound<'_, PyAny>,
+    config: Option<&Bound<'_, PyDict>>,
+    definitions: &mut DefinitionsBuilder<Arc<CombinedValidator>>,
+) -> PyResult<Arc<CombinedValidator>> {
+    string::with_pattern_cache(|| build_validator(schema, config, definitions))
+}
+
 fn build_validator_inner(
     schema: &Bound<'_, PyAny>,
     config: Option<&Bound<'_, PyDict>>,
@@ -559,109 +1390,138 @@ fn build_validator_inner(
     let type_: Bound<'_, PyString> = dict.get_as_req(intern!(py, "type"))?;
     let type_ = type_.to_str()?;
 
-    if use_prebuilt {
+    // when profiling, nested models are built here so their nodes are profiled too
+    if use_prebuilt && !profile::is_profiling() {
         // if we have a SchemaValidator on the type already, use it
         if let Ok(Some(prebuilt_validator)) = prebuilt::PrebuiltValidator::try_get_from_schema(type_, dict) {
             return Ok(Arc::new(prebuilt_validator));
         }
     }
 
-    validator_match!(
-        type_,
-        dict,
-        config,
-        definitions,
-        // typed dict e.g. heterogeneous dicts or simply a model
-        typed_dict::TypedDictValidator,
-        // unions
-        union::UnionValidator,
-        union::TaggedUnionValidator,
-        // nullables
-        nullable::NullableValidator,
-        // model classes
-        model::ModelValidator,
-        model_fields::ModelFieldsValidator,
-        // dataclasses
-        dataclass::DataclassArgsValidator,
-        dataclass::DataclassValidator,
-        // strings
-        string::StrValidator,
-        // integers
-        int::IntValidator,
-        // boolean
-        bool::BoolValidator,
-        // floats
-        float::FloatBuilder,
-        // decimals
-        decimal::DecimalValidator,
-        // tuples
-        tuple::TupleValidator,
-        // list/arrays
-        list::ListValidator,
-        // sets - unique lists
-        set::SetValidator,
-        // dicts/objects (recursive)
-        dict::DictValidator,
-        // None/null
-        none::NoneValidator,
-        // functions - before, after, plain & wrap
-        function::FunctionAfterValidator,
-        function::FunctionBeforeValidator,
-        function::FunctionPlainValidator,
-        function::FunctionWrapValidator,
-        // function call - validation around a function call
-        call::CallValidator,
-        // literals
-        literal::LiteralValidator,
-        // missing sentinel
-        missing_sentinel::MissingSentinelValidator,
-        // enums
-        enum_::BuildEnumValidator,
-        // any
-        any::AnyValidator,
-        // bytes
-        bytes::BytesValidator,
-        // dates
-        date::DateValidator,
-        // times
-        time::TimeValidator,
-        // datetimes
-        datetime::DateTimeValidator,
-        // frozensets
-        frozenset::FrozenSetValidator,
-        // timedelta
-        timedelta::TimeDeltaValidator,
-        // introspection types
-        is_instance::IsInstanceValidator,
-        is_subclass::IsSubclassValidator,
-        callable::CallableValidator,
-        // arguments
-        arguments::ArgumentsValidator,
-        arguments_v3::ArgumentsV3Validator,
-        // default value
-        with_default::WithDefaultValidator,
-        // chain validators
-        chain::ChainValidator,
-        // lax or strict
-        lax_or_strict::LaxOrStrictValidator,
-        // json or python
-        json_or_python::JsonOrPython,
-        // generator validators
-        generator::GeneratorValidator,
-        // custom error
-        custom_error::CustomErrorValidator,
-        // json data
-        json::JsonValidator,
-        // url types
-        url::UrlValidator,
-        url::MultiHostUrlValidator,
-        // uuid types
-        uuid::UuidValidator,
-        // recursive (self-referencing) models
-        definitions::DefinitionRefValidator,
-        definitions::DefinitionsValidatorBuilder,
-        complex::ComplexValidator,
-    )
+    let validator = profile::build_node(type_, dict, || {
+        error_messages::build_with_error_messages(dict, || {
+            validator_match!(
+                type_,
+                dict,
+                config,
+                definitions,
+                // typed dict e.g. heterogeneous dicts or simply a model
+                typed_dict::TypedDictValidator,
+                // unions
+                union::UnionValidator,
+                union::TaggedUnionValidator,
+                // nullables
+                nullable::NullableValidator,
+                // model classes
+                model::ModelValidator,
+                model_fields::ModelFieldsValidator,
+                // dataclasses
+                dataclass::DataclassArgsValidator,
+                dataclass::DataclassValidator,
+                // strings
+                string::StrValidator,
+                // integers
+                int::IntValidator,
+                // boolean
+                bool::BoolValidator,
+                // floats
+                float::FloatBuilder,
+                // decimals
+                decimal::DecimalValidator,
+                // tuples
+                tuple::TupleValidator,
+                // list/arrays
+                list::ListValidator,
+                // lists of items keyed by one of their fields
+                keyed_list::KeyedListValidator,
+                // sets - unique lists
+                set::SetValidator,
+                // dicts/objects (recursive)
+                dict::DictValidator,
+                // None/null
+                none::NoneValidator,
+                // functions - before, after, plain & wrap
+                function::FunctionAfterValidator,
+                function::FunctionBeforeValidator,
+                function::FunctionPlainValidator,
+                function::FunctionWrapValidator,
+                // function call - validation around a function call
+                call::CallValidator,
+                // literals
+                literal::LiteralValidator,
+                // constants
+                const_::ConstValidator,
+                // missing sentinel
+                missing_sentinel::MissingSentinelValidator,
+                // enums
+                enum_::BuildEnumValidator,
+                // any
+                any::AnyValidator,
+                // bytes
+                bytes::BytesValidator,
+                // dates
+                date::DateValidator,
+                // times
+                time::TimeValidator,
+                // datetimes
+                datetime::DateTimeValidator,
+                // frozensets
+                frozenset::FrozenSetValidator,
+                // timedelta
+                timedelta::TimeDeltaValidator,
+                // introspection types
+                is_instance::IsInstanceValidator,
+                is_subclass::IsSubclassValidator,
+                callable::CallableValidator,
+                // arguments
+                arguments::ArgumentsValidator,
+                arguments_v3::ArgumentsV3Validator,
+                // default value
+                with_default::WithDefaultValidator,
+                // chain validators
+                chain::ChainValidator,
+                // lax or strict
+                lax_or_strict::LaxOrStrictValidator,
+                // json or python
+                json_or_python::JsonOrPython,
+                // generator validators
+                generator::GeneratorValidator,
+                // custom error
+                custom_error::CustomErrorValidator,
+                // json data
+                json::JsonValidator,
+                // url types
+                url::UrlValidator,
+                url::MultiHostUrlValidator,
+                // semantic versions
+                semver::SemverValidator,
+                // uuid types
+                uuid::UuidValidator,
+                // ulid types
+                ulid::UlidValidator,
+                // email types
+                email::EmailValidator,
+                email::NameEmailBuilder,
+                // ip address types
+                ip::IpV4AddressBuilder,
+                ip::IpV6AddressBuilder,
+                ip::IpNetworkBuilder,
+                ip::IpInterfaceBuilder,
+                // filesystem paths
+                path::PathValidator,
+                // recursive (self-referencing) models
+                definitions::DefinitionRefValidator,
+                definitions::DefinitionsValidatorBuilder,
+                complex::ComplexValidator,
+                // validators of other compiled extensions
+                plugin::PluginValidator,
+            )
+        })
+    })?;
+    // name the validator as it's built, so definitions which haven't been filled yet are named `...` as they
+    // always have been, names including definitions waiting to be built lazily are formatted again when used
+    validator.get_name();
+    Ok(validator)
 }
 
 /// More (mostly immutable) data to pass between validators, should probably be class `Context`,
@@ -672,8 +1532,13 @@ pub struct Extra<'a, 'py> {
     pub input_type: InputType,
     /// This is used as the `data` kwargs to validator functions and default factories (if they accept the argument)
     pub data: Option<Bound<'py, PyDict>>,
+    /// The `data` of the model, typed dict or dataclass enclosing the one whose fields are being validated, used as
+    /// `ValidationInfo.parent_data`
+    pub parent_data: Option<Bound<'py, PyDict>>,
     /// whether we're in strict or lax mode
     pub strict: Option<bool>,
+    /// Which models the validation time `strict` applies to
+    pub strict_scope: StrictScope,
     /// Whether to ignore, allow, or forbid extra data during model validation
     #[allow(clippy::struct_field_names)]
     pub extra_behavior: Option<ExtraBehavior>,
@@ -694,40 +1559,33 @@ pub struct Extra<'a, 'py> {
 }
 
 impl<'a, 'py> Extra<'a, 'py> {
-    #[allow(clippy::too_many_arguments)]
-    pub fn new(
-        strict: Option<bool>,
-        extra_behavior: Option<ExtraBehavior>,
-        from_attributes: Option<bool>,
-        context: Option<&'a Bound<'py, PyAny>>,
-        self_instance: Option<&'a Bound<'py, PyAny>>,
-        input_type: InputType,
-        cache_str: StringCacheMode,
-        by_alias: Option<bool>,
-        by_name: Option<bool>,
-    ) -> Self {
+    pub fn new(options: &ValidationOptions<'a, 'py>, input_type: InputType, cache_str: StringCacheMode) -> Self {
         Extra {
             input_type,
             data: None,
-            strict,
-            extra_behavior,
-            from_attributes,
+            parent_data: None,
+            strict: options.strict,
+            strict_scope: options.strict_scope,
+            extra_behavior: options.extra_behavior,
+            from_attributes: options.from_attributes,
             field_name: None,
-            context,
-            self_instance,
+            context: options.context,
+            self_instance: options.self_instance,
             cache_str,
-            by_alias,
-            by_name,
+            by_alias: options.by_alias,
+            by_name: options.by_name,
         }
     }
 }
 
-impl Extra<'_, '_> {
+impl<'py> Extra<'_, 'py> {
     pub fn as_strict(&self) -> Self {
         Self {
             input_type: self.input_type,
             data: self.data.clone(),
+            parent_data: self.parent_data.clone(),
             strict: Some(true),
+            strict_scope: StrictScope::Recursive,
             extra_behavior: self.extra_behavior,
             from_attributes: self.from_attributes,
             field_name: self.field_name.clone(),
@@ -738,6 +1596,35 @@ impl Extra<'_, '_> {
             by_name: self.by_name,
         }
     }
+
+    /// Set the `data` of the fields being validated, keeping the enclosing data as `parent_data`.
+    pub fn set_data(&mut self, data: Bound<'py, PyDict>) {
+        self.parent_data = self.data.replace(data);
+    }
+}
+
+/// Which models a validation time `strict` setting applies to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StrictScope {
+    /// `strict` applies to all nested models and dataclasses
+    #[default]
+    Recursive,
+    /// `strict` applies only to the outermost model or dataclass, nested ones use their own config
+    TopLevel,
+    /// set once the outermost model or dataclass of a `TopLevel` scope has been entered
+    Nested,
+}
+
+impl FromPyObject<'_> for StrictScope {
+    fn extract_bound(ob: &Bound<'_, PyAny>) -> PyResult<Self> {
+        match ob.extract::<&str>()? {
+            "recursive" => Ok(Self::Recursive),
+            "top-level" => Ok(Self::TopLevel),
+            s => Err(PyValueError::new_err(format!(
+                "Invalid strict_scope: `{s}`, expected 'recursive' or 'top-level'"
+            ))),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -771,6 +1658,8 @@ pub enum CombinedValidator {
     Decimal(decimal::DecimalValidator),
     // lists
     List(list::ListValidator),
+    // lists of items keyed by one of their fields
+    KeyedList(keyed_list::KeyedListValidator),
     // sets - unique lists
     Set(set::SetValidator),
     // tuples
@@ -788,6 +1677,8 @@ pub enum CombinedValidator {
     FunctionCall(call::CallValidator),
     // literals
     Literal(literal::LiteralValidator),
+    // constants
+    Const(const_::ConstValidator),
     // Missing sentinel
     MissingSentinel(missing_sentinel::MissingSentinelValidator),
     // enums
@@ -832,15 +1723,31 @@ pub enum CombinedValidator {
     // url types
     Url(url::UrlValidator),
     MultiHostUrl(url::MultiHostUrlValidator),
+    // semantic versions
+    Semver(semver::SemverValidator),
     // uuid types
     Uuid(uuid::UuidValidator),
+    // ulid types
+    Ulid(ulid::UlidValidator),
+    // email types
+    Email(email::EmailValidator),
+    // ip address types
+    Ip(ip::IpValidator),
+    // filesystem paths
+    Path(path::PathValidator),
     // reference to definition, useful for recursive (self-referencing) models
     DefinitionRef(definitions::DefinitionRefValidator),
     // input dependent
     JsonOrPython(json_or_python::JsonOrPython),
     Complex(complex::ComplexValidator),
+    // validates with a plugin registered by another compiled extension
+    Plugin(plugin::PluginValidator),
     // uses a reference to an existing SchemaValidator to reduce memory usage
     Prebuilt(prebuilt::PrebuiltValidator),
+    // records timings of the validator it wraps, when `profile_validation` is enabled
+    Profile(profile::ProfileValidator),
+    // overrides the messages of errors from the validator it wraps, when the schema has `error_messages`
+    ErrorMessages(error_messages::ErrorMessagesValidator),
 }
 
 /// This trait must be implemented by all validators, it allows various validators to be accessed consistently,
@@ -879,6 +1786,11 @@ pub trait Validator: Send + Sync + Debug {
         Err(py_err.into())
     }
 
+    /// An input this validator accepts, sampled by `generator`, used by `SchemaValidator.generate_example`
+    fn example<'py>(&self, _py: Python<'py>, _generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        Err(example::unsupported(self.get_name()))
+    }
+
     /// `get_name` generally returns `Self::EXPECTED_TYPE` or some other clear identifier of the validator
     /// this is used in the error location in unions, and in the top level message in `ValidationError`
     fn get_name(&self) -> &str;
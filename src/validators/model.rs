@@ -6,10 +6,13 @@ use pyo3::types::{PyDict, PySet, PyString, PyTuple, PyType};
 use pyo3::{ffi, BoundObject, IntoPyObjectExt};
 use pyo3::{intern, prelude::*};
 
+use super::construct::ConstructOptions;
 use super::function::convert_err;
+use super::instance_cache;
 use super::validation_state::Exactness;
 use super::{
-    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Extra, ValidationState, Validator,
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, Extra, ValidationState,
+    Validator,
 };
 use crate::build_tools::py_schema_err;
 use crate::build_tools::schema_or_config_same;
@@ -19,10 +22,10 @@ use crate::tools::{py_err, SchemaDict};
 use crate::PydanticUndefinedType;
 
 const ROOT_FIELD: &str = "root";
-const DUNDER_DICT: &str = "__dict__";
-const DUNDER_FIELDS_SET_KEY: &str = "__pydantic_fields_set__";
-const DUNDER_MODEL_EXTRA_KEY: &str = "__pydantic_extra__";
-const DUNDER_MODEL_PRIVATE_KEY: &str = "__pydantic_private__";
+pub(super) const DUNDER_DICT: &str = "__dict__";
+pub(super) const DUNDER_FIELDS_SET_KEY: &str = "__pydantic_fields_set__";
+pub(super) const DUNDER_MODEL_EXTRA_KEY: &str = "__pydantic_extra__";
+pub(super) const DUNDER_MODEL_PRIVATE_KEY: &str = "__pydantic_private__";
 
 #[derive(Debug, Clone)]
 pub(super) enum Revalidate {
@@ -120,6 +123,89 @@ impl Validator for ModelValidator {
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        state.with_model_strict_scope(|state| self.validate_scoped(py, input, state))
+    }
+
+    fn validate_assignment<'py>(
+        &self,
+        py: Python<'py>,
+        model: &Bound<'py, PyAny>,
+        field_name: &str,
+        field_value: &Bound<'py, PyAny>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        state
+            .with_model_strict_scope(|state| self.validate_assignment_scoped(py, model, field_name, field_value, state))
+    }
+
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        self.validator.example(py, generator)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl ModelValidator {
+    pub(super) fn validator(&self) -> &CombinedValidator {
+        &self.validator
+    }
+
+    pub(super) fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    pub(super) fn class(&self) -> &Py<PyType> {
+        &self.class
+    }
+
+    pub(super) fn is_root_model(&self) -> bool {
+        self.root_model
+    }
+
+    /// Create an instance from `data` without validating it, like `BaseModel.model_construct`.
+    pub(super) fn construct<'py>(
+        &self,
+        py: Python<'py>,
+        data: &Bound<'py, PyAny>,
+        fields_set: Option<&Bound<'py, PyAny>>,
+        options: ConstructOptions,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let instance = create_class(self.class.bind(py))?;
+        if self.root_model {
+            let root_fields_set = PySet::new(py, [ROOT_FIELD])?;
+            let fields_set = fields_set.unwrap_or(&root_fields_set);
+            force_setattr(py, &instance, intern!(py, DUNDER_FIELDS_SET_KEY), fields_set)?;
+            force_setattr(py, &instance, intern!(py, ROOT_FIELD), data)?;
+        } else {
+            let CombinedValidator::ModelFields(model_fields) = self.validator.as_ref() else {
+                return Err(PyTypeError::new_err(format!(
+                    "`construct` requires the fields of `{}` to have a `model-fields` schema",
+                    self.name
+                )));
+            };
+            let constructed = model_fields.construct(py, data.downcast()?, options)?;
+            let model_extra = if model_fields.allows_extra() {
+                constructed.extra.into_any()
+            } else {
+                py.None().into_bound(py)
+            };
+            let fields_set = fields_set.unwrap_or(&constructed.fields_set);
+            set_model_attrs(&instance, &constructed.dict, &model_extra, fields_set)?;
+        }
+        if let Some(ref post_init) = self.post_init {
+            instance.call_method1(post_init.bind(py), (py.None(),))?;
+        }
+        Ok(instance)
+    }
+
+    fn validate_scoped<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
         if let Some(self_instance) = state.extra().self_instance {
             // in the case that self_instance is Some, we're calling validation from within `BaseModel.__init__`
@@ -153,24 +239,9 @@ impl Validator for ModelValidator {
 
         if let Some(py_input) = py_instance_input {
             if self.revalidate.should_revalidate(py_input, class) || force_revalidate {
-                let fields_set = py_input.getattr(intern!(py, DUNDER_FIELDS_SET_KEY))?;
-                if self.root_model {
-                    let inner_input = py_input.getattr(intern!(py, ROOT_FIELD))?;
-                    self.validate_construct(py, &inner_input, Some(&fields_set), state)
-                } else {
-                    // get dict here so from_attributes logic doesn't apply
-                    let dict = py_input.getattr(intern!(py, DUNDER_DICT))?;
-                    let model_extra = py_input.getattr(intern!(py, DUNDER_MODEL_EXTRA_KEY))?;
-
-                    let inner_input = if PyAnyMethods::is_none(&model_extra) {
-                        dict
-                    } else {
-                        let full_model_dict = dict.downcast::<PyDict>()?.copy()?;
-                        full_model_dict.update(model_extra.downcast()?)?;
-                        full_model_dict.into_any()
-                    };
-                    self.validate_construct(py, &inner_input, Some(&fields_set), state)
-                }
+                instance_cache::validate_cached(py, self, py_input, state, |state| {
+                    self.revalidate_instance(py, py_input, state)
+                })
             } else {
                 Ok(input.to_object(py)?.unbind())
             }
@@ -181,7 +252,33 @@ impl Validator for ModelValidator {
         }
     }
 
-    fn validate_assignment<'py>(
+    fn revalidate_instance<'py>(
+        &self,
+        py: Python<'py>,
+        py_input: &Bound<'py, PyAny>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        let fields_set = py_input.getattr(intern!(py, DUNDER_FIELDS_SET_KEY))?;
+        if self.root_model {
+            let inner_input = py_input.getattr(intern!(py, ROOT_FIELD))?;
+            self.validate_construct(py, &inner_input, Some(&fields_set), state)
+        } else {
+            // get dict here so from_attributes logic doesn't apply
+            let dict = py_input.getattr(intern!(py, DUNDER_DICT))?;
+            let model_extra = py_input.getattr(intern!(py, DUNDER_MODEL_EXTRA_KEY))?;
+
+            let inner_input = if PyAnyMethods::is_none(&model_extra) {
+                dict
+            } else {
+                let full_model_dict = dict.downcast::<PyDict>()?.copy()?;
+                full_model_dict.update(model_extra.downcast()?)?;
+                full_model_dict.into_any()
+            };
+            self.validate_construct(py, &inner_input, Some(&fields_set), state)
+        }
+    }
+
+    fn validate_assignment_scoped<'py>(
         &self,
         py: Python<'py>,
         model: &Bound<'py, PyAny>,
@@ -239,12 +336,6 @@ impl Validator for ModelValidator {
         Ok(model.into_py_any(py)?)
     }
 
-    fn get_name(&self) -> &str {
-        &self.name
-    }
-}
-
-impl ModelValidator {
     /// here we just call the inner validator, then set attributes on `self_instance`
     fn validate_init<'py>(
         &self,
@@ -376,6 +467,59 @@ fn set_model_attrs(
     Ok(())
 }
 
+/// The state of a model or dataclass instance which assignment validation may replace or mutate,
+/// used to roll back a partially applied `validate_into`.
+pub(super) struct InstanceSnapshot<'py> {
+    dict: Option<Bound<'py, PyAny>>,
+    model_extra: Option<Bound<'py, PyAny>>,
+    fields_set: Option<Bound<'py, PySet>>,
+    // attribute values of instances without a `__dict__`, e.g. dataclasses with `slots=True`
+    slot_values: Vec<(Bound<'py, PyString>, Bound<'py, PyAny>)>,
+}
+
+impl<'py> InstanceSnapshot<'py> {
+    pub fn take(instance: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let py = instance.py();
+        let fields_set = match instance.getattr_opt(intern!(py, DUNDER_FIELDS_SET_KEY))? {
+            Some(fields_set) => Some(PySet::new(py, fields_set.try_iter()?.collect::<PyResult<Vec<_>>>()?)?),
+            None => None,
+        };
+        Ok(Self {
+            dict: instance.getattr_opt(intern!(py, DUNDER_DICT))?,
+            model_extra: instance.getattr_opt(intern!(py, DUNDER_MODEL_EXTRA_KEY))?,
+            fields_set,
+            slot_values: Vec::new(),
+        })
+    }
+
+    /// Record the current value of `field_name` before it is assigned, if the instance has no `__dict__`.
+    pub fn record_field(&mut self, instance: &Bound<'py, PyAny>, field_name: &Bound<'py, PyString>) -> PyResult<()> {
+        if self.dict.is_none() {
+            if let Some(value) = instance.getattr_opt(field_name)? {
+                self.slot_values.push((field_name.clone(), value));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn restore(self, instance: &Bound<'py, PyAny>) -> PyResult<()> {
+        let py = instance.py();
+        if let Some(dict) = self.dict {
+            force_setattr(py, instance, intern!(py, DUNDER_DICT), dict)?;
+        }
+        if let Some(model_extra) = self.model_extra {
+            force_setattr(py, instance, intern!(py, DUNDER_MODEL_EXTRA_KEY), model_extra)?;
+        }
+        if let Some(fields_set) = self.fields_set {
+            force_setattr(py, instance, intern!(py, DUNDER_FIELDS_SET_KEY), fields_set)?;
+        }
+        for (field_name, value) in self.slot_values.into_iter().rev() {
+            force_setattr(py, instance, field_name, value)?;
+        }
+        Ok(())
+    }
+}
+
 pub(super) fn force_setattr<'py, N, V>(py: Python<'py>, obj: &Bound<'py, PyAny>, attr_name: N, value: V) -> PyResult<()>
 where
     N: IntoPyObject<'py>,
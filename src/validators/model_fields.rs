@@ -6,18 +6,31 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PySet, PyString, PyType};
 
 use ahash::AHashSet;
+use jiter::PartialMode;
 use pyo3::IntoPyObjectExt;
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{is_strict, schema_or_config_same, ExtraBehavior};
-use crate::errors::LocItem;
+use crate::build_tools::{is_strict, schema_or_config_same, ExtraBehavior, FieldAliases};
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
+use crate::errors::{LocItem, Location};
 use crate::input::ConsumeIterator;
 use crate::input::{BorrowInput, Input, ValidatedDict, ValidationMatch};
 use crate::lookup_key::LookupKeyCollection;
 use crate::tools::SchemaDict;
 
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::consistency::ConsistencyCheck;
+use super::construct::{construct_fields, ConstructOptions, ConstructedFields, FieldToConstruct};
+use super::explain;
+use super::extras_patterns::{ExtraValidator, ExtrasPatterns};
+use super::ignored_extras;
+use super::introspect::{has_default, FieldToInspect};
+use super::merge::{MergeField, MergePolicy};
+use super::profile;
+use super::provenance;
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState,
+    Validator,
+};
 
 #[derive(Debug)]
 struct Field {
@@ -26,6 +39,12 @@ struct Field {
     name_py: Py<PyString>,
     validator: Arc<CombinedValidator>,
     frozen: bool,
+    read_only: bool,
+    merge_policy: MergePolicy,
+    // an empty string is treated as if the field was missing, e.g. for blank HTML form inputs
+    empty_str_as_missing: bool,
+    // only used by introspection, the serializer has its own
+    serialization_alias: Option<String>,
 }
 
 impl_py_gc_traverse!(Field { validator });
@@ -37,11 +56,13 @@ pub struct ModelFieldsValidator {
     extra_behavior: ExtraBehavior,
     extras_validator: Option<Arc<CombinedValidator>>,
     extras_keys_validator: Option<Arc<CombinedValidator>>,
+    extras_patterns: Option<ExtrasPatterns>,
     strict: bool,
     from_attributes: bool,
     loc_by_alias: bool,
     validate_by_alias: Option<bool>,
     validate_by_name: Option<bool>,
+    consistency_checks: Vec<ConsistencyCheck>,
 }
 
 impl BuildValidator for ModelFieldsValidator {
@@ -70,12 +91,16 @@ impl BuildValidator for ModelFieldsValidator {
             (Some(_), _) => return py_schema_err!("extras_keys_schema can only be used if extra_behavior=allow"),
             (_, _) => None,
         };
+        let extras_patterns = ExtrasPatterns::build(schema, config, extra_behavior, definitions)?;
         let model_name: String = schema
             .get_as(intern!(py, "model_name"))?
             .unwrap_or_else(|| "Model".to_string());
 
         let fields_dict: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: Vec<Field> = Vec::with_capacity(fields_dict.len());
+        let aliases = FieldAliases::from_schema(schema)?;
+        let empty_str_as_missing =
+            schema_or_config_same(schema, config, intern!(py, "empty_str_as_missing"))?.unwrap_or(false);
 
         for (key, value) in fields_dict {
             let field_info = value.downcast::<PyDict>()?;
@@ -84,13 +109,18 @@ impl BuildValidator for ModelFieldsValidator {
 
             let schema = field_info.get_as_req(intern!(py, "schema"))?;
 
-            let validator = match build_validator(&schema, config, definitions) {
-                Ok(v) => v,
-                Err(err) => return py_schema_err!("Field \"{}\":\n  {}", field_name, err),
-            };
+            let validator =
+                match profile::with_path_segment(field_name, || build_validator(&schema, config, definitions)) {
+                    Ok(v) => v,
+                    Err(err) => return py_schema_err!("Field \"{}\":\n  {}", field_name, err),
+                };
 
-            let validation_alias = field_info.get_item(intern!(py, "validation_alias"))?;
+            let validation_alias = aliases.get(field_info, intern!(py, "validation_alias"), field_name)?;
             let lookup_key_collection = LookupKeyCollection::new(py, validation_alias, field_name)?;
+            let serialization_alias = aliases
+                .get(field_info, intern!(py, "serialization_alias"), field_name)?
+                .map(|alias| alias.extract())
+                .transpose()?;
 
             fields.push(Field {
                 name: field_name.to_string(),
@@ -98,20 +128,31 @@ impl BuildValidator for ModelFieldsValidator {
                 name_py: field_name_py.into(),
                 validator,
                 frozen: field_info.get_as::<bool>(intern!(py, "frozen"))?.unwrap_or(false),
+                read_only: field_info.get_as::<bool>(intern!(py, "read_only"))?.unwrap_or(false),
+                merge_policy: MergePolicy::from_field_schema(field_info)?,
+                empty_str_as_missing: field_info
+                    .get_as(intern!(py, "empty_str_as_missing"))?
+                    .unwrap_or(empty_str_as_missing),
+                serialization_alias,
             });
         }
 
+        let field_names: Vec<&str> = fields.iter().map(|field| field.name.as_str()).collect();
+        let consistency_checks = ConsistencyCheck::build_all(schema, &field_names)?;
+
         Ok(CombinedValidator::ModelFields(Self {
             fields,
             model_name,
             extra_behavior,
             extras_validator,
             extras_keys_validator,
+            extras_patterns,
             strict,
             from_attributes,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
             validate_by_alias: config.get_as(intern!(py, "validate_by_alias"))?,
             validate_by_name: config.get_as(intern!(py, "validate_by_name"))?,
+            consistency_checks,
         })
         .into())
     }
@@ -119,9 +160,66 @@ impl BuildValidator for ModelFieldsValidator {
 
 impl_py_gc_traverse!(ModelFieldsValidator {
     fields,
-    extras_validator
+    extras_validator,
+    extras_patterns,
+    consistency_checks
 });
 
+impl ModelFieldsValidator {
+    pub(super) fn inspect_fields(&self) -> Vec<FieldToInspect<'_>> {
+        self.fields
+            .iter()
+            .map(|field| FieldToInspect {
+                name: &field.name,
+                lookup_key_collection: &field.lookup_key_collection,
+                validator: &field.validator,
+                required: !has_default(&field.validator),
+                serialization_alias: field.serialization_alias.as_deref(),
+            })
+            .collect()
+    }
+
+    pub(super) fn merge_fields(&self, by_alias: Option<bool>, by_name: Option<bool>) -> PyResult<Vec<MergeField<'_>>> {
+        let by_alias = by_alias.or(self.validate_by_alias).unwrap_or(true);
+        let by_name = by_name.or(self.validate_by_name).unwrap_or(false);
+        self.fields
+            .iter()
+            .map(|field| {
+                Ok(MergeField {
+                    keys: field.lookup_key_collection.select(by_alias, by_name)?.first_keys(),
+                    policy: field.merge_policy,
+                    validator: &field.validator,
+                })
+            })
+            .collect()
+    }
+
+    pub(super) fn construct<'py>(
+        &self,
+        py: Python<'py>,
+        data: &Bound<'py, PyDict>,
+        options: ConstructOptions,
+    ) -> PyResult<ConstructedFields<'py>> {
+        let fields = self.fields.iter().map(|field| FieldToConstruct {
+            name_py: &field.name_py,
+            lookup_key_collection: &field.lookup_key_collection,
+            validator: &field.validator,
+            from_data: true,
+        });
+        construct_fields(
+            py,
+            data,
+            fields,
+            options.by_alias.or(self.validate_by_alias).unwrap_or(true),
+            options.by_name.or(self.validate_by_name).unwrap_or(false),
+        )
+    }
+
+    pub(super) fn allows_extra(&self) -> bool {
+        self.extra_behavior == ExtraBehavior::Allow
+    }
+}
+
 impl Validator for ModelFieldsValidator {
     fn validate<'py>(
         &self,
@@ -129,9 +227,6 @@ impl Validator for ModelFieldsValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        // this validator does not yet support partial validation, disable it to avoid incorrect results
-        state.allow_partial = false.into();
-
         let strict = state.strict_or(self.strict);
         let extra_behavior = state.extra_behavior_or(self.extra_behavior);
         let from_attributes = state.extra().from_attributes.unwrap_or(self.from_attributes);
@@ -165,29 +260,48 @@ impl Validator for ModelFieldsValidator {
         let mut fields_set_vec: Vec<Py<PyString>> = Vec::with_capacity(self.fields.len());
         let mut fields_set_count: usize = 0;
 
+        let partial_last_key = if state.allow_partial.is_active() {
+            dict.last_key().map(Into::into)
+        } else {
+            None
+        };
+        let allow_partial = state.allow_partial;
+
         let validate_by_alias = state.validate_by_alias_or(self.validate_by_alias);
         let validate_by_name = state.validate_by_name_or(self.validate_by_name);
 
         // we only care about which keys have been used if we're iterating over the object for extra after
         // the first pass
-        let mut used_keys: Option<AHashSet<&str>> = if extra_behavior == ExtraBehavior::Ignore || dict.is_py_get_attr()
-        {
+        let ignore_extras = extra_behavior == ExtraBehavior::Ignore && state.ignored_extras.is_none();
+        let mut used_keys: Option<AHashSet<&str>> = if ignore_extras || dict.is_py_get_attr() {
             None
         } else {
             Some(AHashSet::with_capacity(self.fields.len()))
         };
 
+        let mut resume = state.resume.take();
+
         {
-            let state = &mut state.rebind_extra(|extra| extra.data = Some(model_dict.clone()));
+            let state = &mut state.rebind_extra(|extra| extra.set_data(model_dict.clone()));
             let state = &mut state.scoped_set(|state| &mut state.has_field_error, false);
+            // models nested in fields are validated in full
+            let partial_update = state.partial_update;
+            let state = &mut state.scoped_set(|state| &mut state.partial_update, false);
+            // outputs are only reused while the fields before are complete, as validators may use their data
+            let mut reuse_outputs = true;
 
             for field in &self.fields {
+                if state.stop_collecting(false, &errors) {
+                    break;
+                }
+                state.check_deadline()?;
                 let lookup_key = field
                     .lookup_key_collection
                     .select(validate_by_alias, validate_by_name)?;
                 let op_key_value = match dict.get_item(lookup_key) {
                     Ok(v) => v,
                     Err(ValError::LineErrors(line_errors)) => {
+                        reuse_outputs = false;
                         for err in line_errors {
                             errors.push(err.with_outer_location(&field.name));
                         }
@@ -195,6 +309,15 @@ impl Validator for ModelFieldsValidator {
                     }
                     Err(err) => return Err(err),
                 };
+                let op_key_value = match op_key_value {
+                    Some((lookup_path, value)) if field.empty_str_as_missing && is_empty_str(value.borrow_input()) => {
+                        if let Some(ref mut used_keys) = used_keys {
+                            used_keys.insert(lookup_path.first_key());
+                        }
+                        None
+                    }
+                    op_key_value => op_key_value,
+                };
 
                 let state = &mut state.rebind_extra(|extra| extra.field_name = Some(field.name_py.bind(py).clone()));
 
@@ -204,17 +327,78 @@ impl Validator for ModelFieldsValidator {
                         // extra logic either way
                         used_keys.insert(lookup_path.first_key());
                     }
+                    let is_last_partial = if let Some(ref last_key) = partial_last_key {
+                        let first_key_loc: LocItem = lookup_path.first_key().into();
+                        &first_key_loc == last_key
+                    } else {
+                        false
+                    };
+                    state.allow_partial = match is_last_partial {
+                        true => allow_partial,
+                        false => false.into(),
+                    };
 
-                    match field.validator.validate(py, value.borrow_input(), state) {
+                    let validate = |state: &mut ValidationState<'_, 'py>| {
+                        let loc = || lookup_path.loc(self.loc_by_alias, &field.name);
+                        state.with_path_location(loc, |state| {
+                            ignored_extras::validate_field(state, &field.name, |state| {
+                                explain::validate_field(py, state, &field.name, value.borrow_input(), |state| {
+                                    provenance::validate_field(py, state, &field.name, value.borrow_input(), |state| {
+                                        field.validator.validate(py, value.borrow_input(), state)
+                                    })
+                                })
+                            })
+                        })
+                    };
+                    let partial_mark = state.partial_mark();
+                    let result = match resume.as_mut().filter(|_| reuse_outputs) {
+                        Some(resume) => resume.validate_item(
+                            py,
+                            field.name.clone().into(),
+                            is_last_partial,
+                            &field.validator,
+                            state,
+                            validate,
+                        ),
+                        None => validate(state),
+                    };
+                    reuse_outputs &= !is_last_partial;
+                    match result {
                         Ok(value) => {
                             model_dict.set_item(&field.name_py, value)?;
                             fields_set_vec.push(field.name_py.clone_ref(py));
                             fields_set_count += 1;
+                            if is_last_partial {
+                                let loc = lookup_path.loc(self.loc_by_alias, &field.name);
+                                state.record_partial_item(partial_mark, &loc, None);
+                            }
                         }
                         Err(e) => {
                             state.has_field_error = true;
                             match e {
                                 ValError::Omit => continue,
+                                // an incomplete last field falls back to its default, if it has one
+                                ValError::LineErrors(line_errors) if is_last_partial => {
+                                    state.allow_partial = false.into();
+                                    match field.validator.default_value(py, Some(field.name.as_str()), state)? {
+                                        Some(value) => {
+                                            explain::record_default(py, state, &field.name, &value);
+                                            provenance::record_default(state, &field.name);
+                                            model_dict.set_item(&field.name_py, value)?;
+                                            let loc = lookup_path.loc(self.loc_by_alias, &field.name);
+                                            state.record_partial_item(partial_mark, &loc, Some(line_errors));
+                                        }
+                                        None => {
+                                            for err in line_errors {
+                                                errors.push(lookup_path.apply_error_loc(
+                                                    err,
+                                                    self.loc_by_alias,
+                                                    &field.name,
+                                                ));
+                                            }
+                                        }
+                                    }
+                                }
                                 ValError::LineErrors(line_errors) => {
                                     for err in line_errors {
                                         errors.push(lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name));
@@ -227,9 +411,16 @@ impl Validator for ModelFieldsValidator {
                     continue;
                 }
 
+                reuse_outputs = false;
+                if partial_update {
+                    // missing fields are left out, neither defaulted nor errors
+                    continue;
+                }
                 match field.validator.default_value(py, Some(field.name.as_str()), state) {
                     Ok(Some(value)) => {
                         // Default value exists, and passed validation if required
+                        explain::record_default(py, state, &field.name, &value);
+                        provenance::record_default(state, &field.name);
                         model_dict.set_item(&field.name_py, value)?;
                     }
                     Ok(None) => {
@@ -266,7 +457,10 @@ impl Validator for ModelFieldsValidator {
                 extra_behavior: ExtraBehavior,
                 extras_validator: Option<&'a CombinedValidator>,
                 extras_keys_validator: Option<&'a CombinedValidator>,
+                extras_patterns: Option<&'a ExtrasPatterns>,
                 state: &'a mut ValidationState<'s, 'py>,
+                partial_last_key: Option<LocItem>,
+                allow_partial: PartialMode,
             }
 
             impl<'py, Key, Value> ConsumeIterator<ValResult<(Key, Value)>> for ValidateToModelExtra<'_, '_, 'py>
@@ -281,6 +475,10 @@ impl Validator for ModelFieldsValidator {
                 ) -> ValResult<Bound<'py, PyDict>> {
                     let model_extra_dict = PyDict::new(self.py);
                     for item_result in iterator {
+                        if self.state.stop_collecting(false, self.errors) {
+                            break;
+                        }
+                        self.state.check_deadline()?;
                         let (raw_key, value) = item_result?;
                         let either_str = match raw_key
                             .borrow_input()
@@ -314,8 +512,24 @@ impl Validator for ModelFieldsValidator {
                                     raw_key.clone(),
                                 ));
                             }
-                            ExtraBehavior::Ignore => {}
+                            ExtraBehavior::Ignore => ignored_extras::report(self.state, &cow)?,
                             ExtraBehavior::Allow => {
+                                let extras_validator = match ExtrasPatterns::select(
+                                    self.extras_patterns,
+                                    self.py,
+                                    &cow,
+                                    self.extras_validator,
+                                )? {
+                                    ExtraValidator::Allowed(validator) => validator,
+                                    ExtraValidator::Forbidden => {
+                                        self.errors.push(ValLineError::new_with_loc(
+                                            ErrorTypeDefaults::ExtraForbidden,
+                                            value,
+                                            raw_key.clone(),
+                                        ));
+                                        continue;
+                                    }
+                                };
                                 let py_key = match self.extras_keys_validator {
                                     Some(validator) => {
                                         match validator.validate(self.py, raw_key.borrow_input(), self.state) {
@@ -332,15 +546,33 @@ impl Validator for ModelFieldsValidator {
                                     None => either_str.as_py_string(self.py, self.state.cache_str()),
                                 };
 
-                                if let Some(validator) = self.extras_validator {
-                                    match validator.validate(self.py, value, self.state) {
+                                if let Some(validator) = extras_validator {
+                                    let last_partial = self.partial_last_key.as_ref() == Some(&raw_key.clone().into());
+                                    self.state.allow_partial = match last_partial {
+                                        true => self.allow_partial,
+                                        false => false.into(),
+                                    };
+                                    let partial_mark = self.state.partial_mark();
+                                    match self.state.with_path_item(
+                                        || raw_key.clone(),
+                                        |state| validator.validate(self.py, value, state),
+                                    ) {
                                         Ok(value) => {
                                             model_extra_dict.set_item(&py_key, value)?;
                                             self.fields_set_vec.push(py_key.into());
+                                            if last_partial {
+                                                let loc = Location::new_some(raw_key.clone().into());
+                                                self.state.record_partial_item(partial_mark, &loc, None);
+                                            }
                                         }
                                         Err(ValError::LineErrors(line_errors)) => {
-                                            for err in line_errors {
-                                                self.errors.push(err.with_outer_location(raw_key.clone()));
+                                            if last_partial {
+                                                let loc = Location::new_some(raw_key.clone().into());
+                                                self.state.record_partial_item(partial_mark, &loc, Some(line_errors));
+                                            } else {
+                                                for err in line_errors {
+                                                    self.errors.push(err.with_outer_location(raw_key.clone()));
+                                                }
                                             }
                                         }
                                         Err(err) => return Err(err),
@@ -364,7 +596,10 @@ impl Validator for ModelFieldsValidator {
                 extra_behavior,
                 extras_validator: self.extras_validator.as_deref(),
                 extras_keys_validator: self.extras_keys_validator.as_deref(),
+                extras_patterns: self.extras_patterns.as_ref(),
                 state,
+                partial_last_key,
+                allow_partial,
             })??;
 
             if matches!(extra_behavior, ExtraBehavior::Allow) {
@@ -375,6 +610,8 @@ impl Validator for ModelFieldsValidator {
         if !errors.is_empty() {
             Err(ValError::LineErrors(errors))
         } else {
+            ConsistencyCheck::check_all(&self.consistency_checks, &model_dict)?;
+            state.resume = resume;
             let fields_set = PySet::new(py, &fields_set_vec)?;
             state.add_fields_set(fields_set_count);
 
@@ -426,7 +663,7 @@ impl Validator for ModelFieldsValidator {
         }
 
         let new_data = {
-            let state = &mut state.rebind_extra(move |extra| extra.data = Some(data_dict));
+            let state = &mut state.rebind_extra(move |extra| extra.set_data(data_dict));
 
             if let Some(field) = self.fields.iter().find(|f| f.name == field_name) {
                 if field.frozen {
@@ -436,22 +673,40 @@ impl Validator for ModelFieldsValidator {
                         &field.name,
                     ));
                 }
+                if field.read_only {
+                    return Err(ValError::new_with_loc(
+                        ErrorTypeDefaults::ReadOnlyField,
+                        field_value,
+                        &field.name,
+                    ));
+                }
 
                 let state = &mut state.rebind_extra(|extra| extra.field_name = Some(field.name_py.bind(py).clone()));
 
-                prepare_result(field.validator.validate(py, field_value, state))?
+                prepare_result(
+                    state.with_path_item(|| field_name, |state| field.validator.validate(py, field_value, state)),
+                )?
             } else {
                 // Handle extra (unknown) field
                 // We partially use the extra_behavior for initialization / validation
                 // to determine how to handle assignment
                 // For models / typed dicts we forbid assigning extra attributes
                 // unless the user explicitly set extra_behavior to 'allow'
-                match extra_behavior {
-                    ExtraBehavior::Allow => match self.extras_validator {
-                        Some(ref validator) => prepare_result(validator.validate(py, field_value, state))?,
-                        None => get_updated_dict(field_value)?,
-                    },
-                    ExtraBehavior::Forbid | ExtraBehavior::Ignore => {
+                let extra_validator = match extra_behavior {
+                    ExtraBehavior::Allow => ExtrasPatterns::select(
+                        self.extras_patterns.as_ref(),
+                        py,
+                        field_name,
+                        self.extras_validator.as_deref(),
+                    )?,
+                    ExtraBehavior::Forbid | ExtraBehavior::Ignore => ExtraValidator::Forbidden,
+                };
+                match extra_validator {
+                    ExtraValidator::Allowed(Some(validator)) => prepare_result(
+                        state.with_path_item(|| field_name, |state| validator.validate(py, field_value, state)),
+                    )?,
+                    ExtraValidator::Allowed(None) => get_updated_dict(field_value)?,
+                    ExtraValidator::Forbidden => {
                         return Err(ValError::new_with_loc(
                             ErrorType::NoSuchAttribute {
                                 attribute: field_name.to_string(),
@@ -465,6 +720,8 @@ impl Validator for ModelFieldsValidator {
             }
         };
 
+        ConsistencyCheck::check_all(&self.consistency_checks, new_data)?;
+
         let new_extra = match &extra_behavior {
             ExtraBehavior::Allow => {
                 let non_extra_data = PyDict::new(py);
@@ -490,7 +747,24 @@ impl Validator for ModelFieldsValidator {
         Ok((new_data, new_extra, fields_set).into_py_any(py)?)
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        let dict = PyDict::new(py);
+        for field in &self.fields {
+            if !generator.skip_field(&field.validator, true) {
+                dict.set_item(&field.name_py, field.validator.example(py, generator)?)?;
+            }
+        }
+        Ok(dict.into_any())
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
 }
+
+fn is_empty_str<'py>(input: &(impl Input<'py> + ?Sized)) -> bool {
+    // strict rather than exact, as the strings of `validate_strings` inputs are only a strict match
+    input
+        .validate_str(true, false)
+        .is_ok_and(|val_match| val_match.into_inner().as_cow().is_ok_and(|s| s.is_empty()))
+}
@@ -7,7 +7,7 @@ use crate::build_tools::LazyLock;
 use crate::errors::{ErrorTypeDefaults, ValError, ValResult};
 use crate::input::Input;
 
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
 pub struct NoneValidator;
@@ -41,6 +41,10 @@ impl Validator for NoneValidator {
         }
     }
 
+    fn example<'py>(&self, py: Python<'py>, _generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        Ok(py.None().into_bound(py))
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
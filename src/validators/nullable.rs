@@ -4,17 +4,24 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
+use crate::definitions::CompositeName;
 use crate::errors::ValResult;
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
 use super::ValidationState;
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, Validator};
 
 #[derive(Debug)]
 pub struct NullableValidator {
     validator: Arc<CombinedValidator>,
-    name: String,
+    name: CompositeName,
+}
+
+impl NullableValidator {
+    pub(super) fn validator(&self) -> &CombinedValidator {
+        &self.validator
+    }
 }
 
 impl BuildValidator for NullableValidator {
@@ -27,8 +34,11 @@ impl BuildValidator for NullableValidator {
     ) -> PyResult<Arc<CombinedValidator>> {
         let schema = schema.get_as_req(intern!(schema.py(), "schema"))?;
         let validator = build_validator(&schema, config, definitions)?;
-        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
-        Ok(CombinedValidator::Nullable(Self { validator, name }).into())
+        Ok(CombinedValidator::Nullable(Self {
+            validator,
+            name: CompositeName::new(),
+        })
+        .into())
     }
 }
 
@@ -47,7 +57,17 @@ impl Validator for NullableValidator {
         }
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        // `None` once shallow, so e.g. optional fields of a model's own type end
+        if generator.shallow() || generator.bool() {
+            Ok(py.None().into_bound(py))
+        } else {
+            self.validator.example(py, generator)
+        }
+    }
+
     fn get_name(&self) -> &str {
-        &self.name
+        self.name
+            .get_or_init(|| format!("{}[{}]", Self::EXPECTED_TYPE, self.validator.get_name()))
     }
 }
@@ -0,0 +1,254 @@
+use std::cell::Cell;
+use std::num::NonZeroUsize;
+use std::time::Instant;
+
+use jiter::{PartialMode, StringCacheMode};
+use pyo3::prelude::*;
+use pyo3::types::{PyFrozenSet, PyList, PySet, PyTuple};
+
+use crate::errors::{ValError, ValLineError, ValResult};
+use crate::input::{Input, InputType};
+use crate::recursion_guard::RecursionState;
+
+use super::{CombinedValidator, Exactness, Extra, StrictScope, ValidationOptions, ValidationState, Validator};
+
+/// Collections with fewer items than this are validated serially, as starting threads would take longer.
+const MIN_ITEMS: usize = 4096;
+
+thread_local! {
+    // set on the threads validating items, so collections nested in the items are validated serially
+    static IN_WORKER: Cell<bool> = const { Cell::new(false) };
+}
+
+/// The items of `input` if `parallel` is set and it's a Python list, tuple, set or frozenset whose items can be
+/// validated with `validator` in parallel. Collections longer than `max_length` are validated serially, so the
+/// error is the same.
+pub(super) fn parallel_items<'py>(
+    parallel: bool,
+    input: &(impl Input<'py> + ?Sized),
+    validator: &CombinedValidator,
+    max_length: Option<usize>,
+    state: &ValidationState<'_, 'py>,
+) -> Option<Vec<Py<PyAny>>> {
+    let input = input.as_python().filter(|_| parallel)?;
+    let count = if let Ok(py_list) = input.downcast::<PyList>() {
+        py_list.len()
+    } else if let Ok(py_tuple) = input.downcast::<PyTuple>() {
+        py_tuple.len()
+    } else if let Ok(py_set) = input.downcast::<PySet>() {
+        py_set.len()
+    } else if let Ok(py_frozen_set) = input.downcast::<PyFrozenSet>() {
+        py_frozen_set.len()
+    } else {
+        return None;
+    };
+    if max_length.is_some_and(|max_length| count > max_length) || !can_validate_in_parallel(validator, count, state) {
+        return None;
+    }
+    input
+        .try_iter()
+        .and_then(|iter| iter.map(|item| item.map(Bound::unbind)).collect())
+        .ok()
+}
+
+/// Whether `count` items can be validated with `validator` in parallel.
+///
+/// Only free-threaded Python can run validators on several threads at once, and only validators which never
+/// call Python functions are run in parallel, as those functions may not be thread-safe.
+pub(super) fn can_validate_in_parallel(
+    validator: &CombinedValidator,
+    count: usize,
+    state: &ValidationState<'_, '_>,
+) -> bool {
+    cfg!(Py_GIL_DISABLED)
+        && count >= MIN_ITEMS
+        && matches!(state.allow_partial, PartialMode::Off)
+        && state.union_trace.is_none()
+        && state.explanation.is_none()
+        && state.provenance.is_none()
+        && !IN_WORKER.get()
+        && is_callback_free(validator)
+}
+
+fn is_callback_free(validator: &CombinedValidator) -> bool {
+    match validator {
+        CombinedValidator::Int(_)
+        | CombinedValidator::ConstrainedInt(_)
+        | CombinedValidator::Float(_)
+        | CombinedValidator::ConstrainedFloat(_)
+        | CombinedValidator::Str(_)
+        | CombinedValidator::StrConstrained(_)
+        | CombinedValidator::Bool(_)
+        | CombinedValidator::None(_)
+        | CombinedValidator::Bytes(_)
+        | CombinedValidator::ConstrainedBytes(_) => true,
+        CombinedValidator::Nullable(nullable) => is_callback_free(nullable.validator()),
+        _ => false,
+    }
+}
+
+/// The settings of the outer validation which apply to validating items, `Extra` can't be sent to other threads.
+#[derive(Clone, Copy)]
+struct ItemSettings {
+    input_type: InputType,
+    strict: Option<bool>,
+    strict_scope: StrictScope,
+    from_attributes: Option<bool>,
+    cache_str: StringCacheMode,
+    by_alias: Option<bool>,
+    by_name: Option<bool>,
+    exactness: bool,
+    // a chunk stops validating items once the deadline passes, or as collecting its errors would stop
+    deadline: Option<Instant>,
+    fail_fast: bool,
+    max_errors: Option<NonZeroUsize>,
+}
+
+/// Validate `items` with `validator`, split across a thread per CPU, returning the result for each item in order.
+///
+/// The outer exactness is lowered to the lowest exactness of the items. The results end early once the deadline
+/// passes, or once `fail_fast` (of the collection or the whole validation) or `max_errors` would stop collecting
+/// errors, as `collect_results` stops before reaching the items left out.
+pub(super) fn validate_items<'py>(
+    py: Python<'py>,
+    items: &[Py<PyAny>],
+    validator: &CombinedValidator,
+    fail_fast: bool,
+    state: &mut ValidationState<'_, 'py>,
+) -> Vec<ValResult<Py<PyAny>>> {
+    let extra = state.extra();
+    let settings = ItemSettings {
+        input_type: extra.input_type,
+        strict: extra.strict,
+        strict_scope: extra.strict_scope,
+        from_attributes: extra.from_attributes,
+        cache_str: extra.cache_str,
+        by_alias: extra.by_alias,
+        by_name: extra.by_name,
+        exactness: state.exactness.is_some(),
+        deadline: state.deadline,
+        fail_fast: fail_fast || state.fail_fast,
+        max_errors: state.max_errors,
+    };
+    let threads = std::thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    let chunk_size = items.len().div_ceil(threads).max(1);
+
+    // detach while waiting for the threads, so they can attach to the interpreter
+    let chunks = py.detach(|| {
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = items
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || Python::attach(|py| validate_chunk(py, chunk, validator, settings))))
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+                .collect::<Vec<_>>()
+        })
+    });
+
+    let mut results = Vec::with_capacity(items.len());
+    for (chunk, chunk_results) in items.chunks(chunk_size).zip(chunks) {
+        if let Some(exactness) = chunk_results.exactness {
+            state.floor_exactness(exactness);
+        }
+        state.timed_out |= chunk_results.timed_out;
+        let stopped = chunk_results.results.len() < chunk.len();
+        results.extend(chunk_results.results);
+        if stopped {
+            break;
+        }
+    }
+    results
+}
+
+/// The results of validating a chunk of items on one thread.
+struct ChunkResults {
+    results: Vec<ValResult<Py<PyAny>>>,
+    exactness: Option<Exactness>,
+    timed_out: bool,
+}
+
+fn validate_chunk(
+    py: Python<'_>,
+    items: &[Py<PyAny>],
+    validator: &CombinedValidator,
+    settings: ItemSettings,
+) -> ChunkResults {
+    IN_WORKER.set(true);
+    let mut recursion_guard = RecursionState::default();
+    let options = ValidationOptions {
+        strict: settings.strict,
+        strict_scope: settings.strict_scope,
+        from_attributes: settings.from_attributes,
+        by_alias: settings.by_alias,
+        by_name: settings.by_name,
+        ..ValidationOptions::default()
+    };
+    let extra = Extra::new(&options, settings.input_type, settings.cache_str);
+    let mut state = ValidationState::new(extra, &mut recursion_guard, PartialMode::Off);
+    if settings.exactness {
+        state.exactness = Some(Exactness::Exact);
+    }
+    state.deadline = settings.deadline;
+    state.fail_fast = settings.fail_fast;
+    state.max_errors = settings.max_errors;
+    let mut results = Vec::with_capacity(items.len());
+    let mut error_count = 0;
+    for item in items {
+        let result = state
+            .check_deadline()
+            .and_then(|()| validator.validate(py, item.bind(py), &mut state));
+        // the same condition as `ValidationState::stop_collecting`, counting this chunk's errors
+        let stop = match &result {
+            Ok(_) | Err(ValError::Omit) => false,
+            Err(ValError::LineErrors(line_errors)) => {
+                error_count += line_errors.len();
+                state.fail_fast
+                    || state
+                        .max_errors
+                        .is_some_and(|max_errors| error_count > max_errors.get())
+            }
+            Err(_) => true,
+        };
+        results.push(result);
+        if stop {
+            break;
+        }
+    }
+    IN_WORKER.set(false);
+    ChunkResults {
+        results,
+        exactness: state.exactness,
+        timed_out: state.timed_out,
+    }
+}
+
+/// Collect the results of `validate_items` as validating the items serially would, errors are located by the
+/// item's index and collection stops when `fail_fast` or `max_errors` say so.
+pub(super) fn collect_results(
+    results: Vec<ValResult<Py<PyAny>>>,
+    state: &ValidationState<'_, '_>,
+    fail_fast: bool,
+) -> ValResult<Vec<Py<PyAny>>> {
+    let mut output = Vec::with_capacity(results.len());
+    let mut errors: Vec<ValLineError> = Vec::new();
+    for (index, result) in results.into_iter().enumerate() {
+        match result {
+            Ok(item) => output.push(item),
+            Err(ValError::LineErrors(line_errors)) => {
+                errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                if state.stop_collecting(fail_fast, &errors) {
+                    break;
+                }
+            }
+            Err(ValError::Omit) => (),
+            Err(err) => return Err(err),
+        }
+    }
+    if errors.is_empty() {
+        Ok(output)
+    } else {
+        Err(ValError::LineErrors(errors))
+    }
+}
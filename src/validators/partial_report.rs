@@ -0,0 +1,232 @@
+use jiter::{Jiter, JiterResult, JsonValue, Peek};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::errors::{ErrorType, LocItem, Location, PyLineError, ValLineError};
+use crate::input::InputType;
+
+use super::ValidationState;
+
+/// An item left out of the output of validation with `allow_partial`, because it was the last item of its
+/// container, possibly incomplete, and invalid, or because the JSON parser left it out as it was incomplete.
+/// Collected when validating with `partial_report=True`.
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct PartialOmission {
+    // location of the item, reversed like the location of errors
+    loc: Location,
+    // why the item was left out, with locations relative to the item
+    errors: Vec<ValLineError>,
+}
+
+impl ValidationState<'_, '_> {
+    /// The number of omitted items recorded so far, to pass to `record_partial_item` once the last item of a
+    /// container has been validated.
+    pub fn partial_mark(&self) -> usize {
+        self.partial_omissions.as_ref().map_or(0, Vec::len)
+    }
+
+    /// Record the outcome of validating the last item of a container, at `loc` within the container.
+    ///
+    /// Items omitted within it get its location added, unless the item itself is omitted because of `errors`,
+    /// in which case it replaces them.
+    pub fn record_partial_item(&mut self, mark: usize, loc: &Location, errors: Option<Vec<ValLineError>>) {
+        let Some(omissions) = &mut self.partial_omissions else {
+            return;
+        };
+        match errors {
+            Some(errors) => {
+                omissions.truncate(mark);
+                omissions.push(PartialOmission {
+                    loc: loc.clone(),
+                    errors,
+                });
+            }
+            None => {
+                for omission in &mut omissions[mark..] {
+                    add_outer_location(&mut omission.loc, loc);
+                }
+            }
+        }
+    }
+
+    /// Remove the items omitted since `mark`, e.g. while validating a union choice which may not be used.
+    pub fn take_partial_omissions(&mut self, mark: usize) -> Vec<PartialOmission> {
+        match &mut self.partial_omissions {
+            Some(omissions) => omissions.split_off(mark),
+            None => Vec::new(),
+        }
+    }
+
+    /// Restore items taken by `take_partial_omissions`.
+    pub fn restore_partial_omissions(&mut self, taken: Vec<PartialOmission>) {
+        if let Some(omissions) = &mut self.partial_omissions {
+            omissions.extend(taken);
+        }
+    }
+
+    /// Record the item the JSON parser left out, see `json_truncation`, unless validation left out an item
+    /// containing it.
+    pub fn record_json_truncation(&mut self, truncation: PartialOmission) {
+        let Some(omissions) = &mut self.partial_omissions else {
+            return;
+        };
+        if !omissions
+            .iter()
+            .any(|omission| contains_location(&omission.loc, &truncation.loc))
+        {
+            omissions.push(truncation);
+        }
+    }
+}
+
+/// The item the parser left out of partial JSON as it was incomplete, e.g. `b` of `{"a": 1, "b": tr`, with a
+/// `json_invalid` error, `None` if the parser kept all of the JSON, e.g. a trailing string with
+/// `allow_partial='trailing-strings'`.
+pub fn json_truncation(json_data: &[u8], parsed: &JsonValue<'_>) -> Option<PartialOmission> {
+    let mut jiter = Jiter::new(json_data).with_allow_inf_nan();
+    let mut path = Vec::new();
+    let mut start = 0;
+    let error = jiter
+        .peek()
+        .and_then(|peek| find_end(&mut jiter, json_data, peek, &mut path, &mut start))
+        .err()?;
+    if path.is_empty() || contains_path(parsed, &path) {
+        return None;
+    }
+    let mut loc = Location::default();
+    for item in path.into_iter().rev() {
+        loc.with_outer(item);
+    }
+    let input = String::from_utf8_lossy(&json_data[start.min(json_data.len())..]).into_owned();
+    let error = ValLineError::new(
+        ErrorType::JsonInvalid {
+            error: error.error_type.to_string(),
+            context: None,
+        },
+        JsonValue::Str(input.into()),
+    );
+    Some(PartialOmission {
+        loc,
+        errors: vec![error],
+    })
+}
+
+/// Skip the JSON value starting at `peek`, on an error `path` is the location of the innermost item which
+/// started, and `start` is where it starts.
+fn find_end(
+    jiter: &mut Jiter<'_>,
+    data: &[u8],
+    peek: Peek,
+    path: &mut Vec<LocItem>,
+    start: &mut usize,
+) -> JiterResult<()> {
+    match peek {
+        Peek::Array => {
+            let mut next = jiter.known_array()?;
+            let mut index: usize = 0;
+            while let Some(peek) = next {
+                path.push(index.into());
+                *start = jiter.current_index();
+                find_end(jiter, data, peek, path, start)?;
+                path.pop();
+                next = jiter.array_step()?;
+                index += 1;
+            }
+            Ok(())
+        }
+        Peek::Object => {
+            let mut before = jiter.current_index();
+            let mut next_key = jiter
+                .known_object()
+                .map_err(|e| partial_key(data, before, path, start, e))?
+                .map(str::to_owned);
+            while let Some(key) = next_key {
+                path.push(key.into());
+                *start = data.len();
+                let peek = jiter.peek()?;
+                *start = jiter.current_index();
+                find_end(jiter, data, peek, path, start)?;
+                path.pop();
+                before = jiter.current_index();
+                next_key = jiter
+                    .next_key()
+                    .map_err(|e| partial_key(data, before, path, start, e))?
+                    .map(str::to_owned);
+            }
+            Ok(())
+        }
+        peek => jiter.known_skip(peek),
+    }
+}
+
+/// Add the key the JSON ends in, from `before` the key, to `path`, if the key started.
+fn partial_key<E>(data: &[u8], before: usize, path: &mut Vec<LocItem>, start: &mut usize, error: E) -> E {
+    let data = String::from_utf8_lossy(data);
+    let after = data.get(before..).unwrap_or_default();
+    let rest = after.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '{' || c == ',');
+    if let Some(key) = rest.strip_prefix('"') {
+        path.push(key.split('"').next().unwrap_or_default().into());
+        *start = before + after.len() - rest.len();
+    }
+    error
+}
+
+/// Whether the item at `path` within `value` is there.
+fn contains_path(value: &JsonValue<'_>, path: &[LocItem]) -> bool {
+    let Some((first, rest)) = path.split_first() else {
+        return true;
+    };
+    let item = match (value, first) {
+        (JsonValue::Array(array), LocItem::I(index)) => usize::try_from(*index).ok().and_then(|i| array.get(i)),
+        (JsonValue::Object(object), LocItem::S(key)) => object
+            .iter()
+            .rev()
+            .find(|(item_key, _)| item_key == key)
+            .map(|(_, item)| item),
+        _ => None,
+    };
+    item.is_some_and(|item| contains_path(item, rest))
+}
+
+/// Whether `inner` is within `outer`, both reversed.
+fn contains_location(outer: &Location, inner: &Location) -> bool {
+    match (outer, inner) {
+        (Location::Empty, _) => true,
+        (Location::List(outer), Location::List(inner)) => inner.ends_with(outer),
+        (Location::List(_), Location::Empty) => false,
+    }
+}
+
+fn add_outer_location(loc: &mut Location, outer: &Location) {
+    if let Location::List(outer_items) = outer {
+        // locations are reversed, so outer items go at the end
+        for item in outer_items {
+            loc.with_outer(item.clone());
+        }
+    }
+}
+
+/// The report returned by `partial_report=True`, a dict for each omitted item with its location and the errors
+/// which caused it to be left out.
+pub fn partial_omissions_to_py(
+    py: Python<'_>,
+    omissions: Vec<PartialOmission>,
+    input_type: InputType,
+    include_input: bool,
+) -> PyResult<Bound<'_, PyList>> {
+    let report = PyList::empty(py);
+    for omission in omissions {
+        let errors = PyList::empty(py);
+        for mut error in omission.errors {
+            add_outer_location(&mut error.location, &omission.loc);
+            let error = PyLineError::from_val_line_error(py, error)?;
+            errors.append(error.as_dict(py, None, true, input_type, include_input, false)?)?;
+        }
+        let dict = PyDict::new(py);
+        dict.set_item(intern!(py, "loc"), &omission.loc)?;
+        dict.set_item(intern!(py, "errors"), errors)?;
+        report.append(dict)?;
+    }
+    Ok(report)
+}
@@ -0,0 +1,213 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::sync::PyOnceLock;
+use pyo3::types::{PyBytes, PyDict, PyList, PyType};
+
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::{input_as_python_instance, Input, InputType};
+use crate::tools::SchemaDict;
+
+use super::literal::expected_repr_name;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+
+static PATH_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+
+fn get_path_type(py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
+    PATH_TYPE.import(py, "pathlib", "Path")
+}
+
+/// Validates filesystem paths, returning a `pathlib.Path`.
+///
+/// In lax mode, `bytes` and `os.PathLike` inputs are decoded with `os.fsdecode`. The filesystem constraints are
+/// checked in Rust, following symlinks, on the path after it's resolved when `resolve` is set.
+#[derive(Debug, Clone)]
+pub struct PathValidator {
+    strict: bool,
+    must_exist: bool,
+    is_file: bool,
+    is_dir: bool,
+    // the allowed suffixes, and their repr for errors
+    suffix_in: Option<(Vec<String>, String)>,
+    resolve: bool,
+    name: String,
+}
+
+impl BuildValidator for PathValidator {
+    const EXPECTED_TYPE: &'static str = "path";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<Arc<CombinedValidator>>,
+    ) -> PyResult<Arc<CombinedValidator>> {
+        let py = schema.py();
+        let is_file = schema.get_as(intern!(py, "is_file"))?.unwrap_or(false);
+        let is_dir = schema.get_as(intern!(py, "is_dir"))?.unwrap_or(false);
+        if is_file && is_dir {
+            return py_schema_err!("`is_file` and `is_dir` can't both be set");
+        }
+        let (suffix_in, name) = match schema.get_as::<Bound<'_, PyList>>(intern!(py, "suffix_in"))? {
+            Some(list) => {
+                if list.is_empty() {
+                    return py_schema_err!("`suffix_in` should have length > 0");
+                }
+                let mut suffixes = Vec::new();
+                let mut repr_args = Vec::new();
+                for item in list {
+                    let suffix: String = item.extract()?;
+                    if !suffix.starts_with('.') || suffix.len() == 1 {
+                        return py_schema_err!(
+                            "Invalid suffix in `suffix_in`: '{}', expected a suffix like '.txt'",
+                            suffix
+                        );
+                    }
+                    repr_args.push(format!("'{suffix}'"));
+                    suffixes.push(suffix);
+                }
+                let (repr, name) = expected_repr_name(repr_args, Self::EXPECTED_TYPE);
+                (Some((suffixes, repr)), name)
+            }
+            None => (None, Self::EXPECTED_TYPE.to_string()),
+        };
+        Ok(CombinedValidator::Path(Self {
+            strict: is_strict(schema, config)?,
+            must_exist: schema.get_as(intern!(py, "must_exist"))?.unwrap_or(false),
+            is_file,
+            is_dir,
+            suffix_in,
+            resolve: schema.get_as(intern!(py, "resolve"))?.unwrap_or(false),
+            name,
+        })
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(PathValidator {});
+
+impl Validator for PathValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        let class = get_path_type(py)?;
+        let mut path = if let Some(py_path) = input_as_python_instance(input, class) {
+            py_path.clone()
+        } else {
+            let strict = state.strict_or(self.strict);
+            if strict && state.extra().input_type == InputType::Python {
+                return Err(ValError::new(ErrorTypeDefaults::PathType, input));
+            }
+            let path_str = match input.validate_str(true, false) {
+                Ok(either_str) => either_str.into_inner().as_py_string(py, state.cache_str()).into_any(),
+                Err(_) => match input.as_python() {
+                    Some(py_input) if !strict && is_fs_path_input(py_input) => fsdecode(py_input)?,
+                    _ => return Err(ValError::new(ErrorTypeDefaults::PathType, input)),
+                },
+            };
+            // like uuids, a string is a lax match in Python mode
+            if state.extra().input_type == InputType::Python {
+                state.floor_exactness(Exactness::Lax);
+            }
+            class.call1((path_str,))?
+        };
+        if self.resolve {
+            path = path.call_method0(intern!(py, "resolve"))?;
+        }
+        self.check_constraints(&path, input)?;
+        Ok(path.unbind())
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl PathValidator {
+    fn check_constraints<'py>(&self, path: &Bound<'py, PyAny>, input: &(impl Input<'py> + ?Sized)) -> ValResult<()> {
+        if !(self.must_exist || self.is_file || self.is_dir || self.suffix_in.is_some()) {
+            return Ok(());
+        }
+        // paths which can't be encoded as UTF-8 are checked by Python, they're rare enough not to need a fast path
+        let path_string: String = match path.str()?.extract() {
+            Ok(path_string) => path_string,
+            Err(_) => return self.check_constraints_py(path, input),
+        };
+        let path = Path::new(&path_string);
+        if self.must_exist || self.is_file || self.is_dir {
+            let Ok(metadata) = path.metadata() else {
+                return Err(ValError::new(ErrorTypeDefaults::PathNotFound, input));
+            };
+            if self.is_file && !metadata.is_file() {
+                return Err(ValError::new(ErrorTypeDefaults::PathNotFile, input));
+            }
+            if self.is_dir && !metadata.is_dir() {
+                return Err(ValError::new(ErrorTypeDefaults::PathNotDirectory, input));
+            }
+        }
+        if let Some((suffixes, expected_suffixes)) = &self.suffix_in {
+            let file_name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+            if !has_suffix(file_name, suffixes) {
+                return Err(self.suffix_error(expected_suffixes, input));
+            }
+        }
+        Ok(())
+    }
+
+    fn check_constraints_py<'py>(&self, path: &Bound<'py, PyAny>, input: &(impl Input<'py> + ?Sized)) -> ValResult<()> {
+        let py = path.py();
+        if (self.must_exist || self.is_file || self.is_dir) && !path.call_method0(intern!(py, "exists"))?.is_truthy()? {
+            return Err(ValError::new(ErrorTypeDefaults::PathNotFound, input));
+        }
+        if self.is_file && !path.call_method0(intern!(py, "is_file"))?.is_truthy()? {
+            return Err(ValError::new(ErrorTypeDefaults::PathNotFile, input));
+        }
+        if self.is_dir && !path.call_method0(intern!(py, "is_dir"))?.is_truthy()? {
+            return Err(ValError::new(ErrorTypeDefaults::PathNotDirectory, input));
+        }
+        if let Some((suffixes, expected_suffixes)) = &self.suffix_in {
+            let file_name = path.getattr(intern!(py, "name"))?;
+            let file_name = file_name.str()?;
+            if !has_suffix(&file_name.to_string_lossy(), suffixes) {
+                return Err(self.suffix_error(expected_suffixes, input));
+            }
+        }
+        Ok(())
+    }
+
+    fn suffix_error<'py>(&self, expected_suffixes: &str, input: &(impl Input<'py> + ?Sized)) -> ValError {
+        ValError::new(
+            ErrorType::PathSuffix {
+                expected_suffixes: expected_suffixes.to_string(),
+                context: None,
+            },
+            input,
+        )
+    }
+}
+
+/// Whether the file name ends with one of the suffixes, which can have several parts like `.tar.gz`, after
+/// at least one other character, so `.toml` isn't a `.toml` file but a hidden file without a suffix.
+fn has_suffix(file_name: &str, suffixes: &[String]) -> bool {
+    suffixes
+        .iter()
+        .any(|suffix| file_name.len() > suffix.len() && file_name.ends_with(suffix.as_str()))
+}
+
+fn is_fs_path_input(input: &Bound<'_, PyAny>) -> bool {
+    input.is_instance_of::<PyBytes>() || input.hasattr(intern!(input.py(), "__fspath__")).unwrap_or(false)
+}
+
+fn fsdecode<'py>(input: &Bound<'py, PyAny>) -> ValResult<Bound<'py, PyAny>> {
+    let py = input.py();
+    let fsdecode = py.import(intern!(py, "os"))?.getattr(intern!(py, "fsdecode"))?;
+    // `__fspath__` can return something other than a `str` or `bytes`, or raise
+    fsdecode
+        .call1((input,))
+        .map_err(|_| ValError::new(ErrorTypeDefaults::PathType, input))
+}
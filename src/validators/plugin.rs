@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use crate::build_tools::is_strict;
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::Input;
+use crate::plugin::Plugin;
+use crate::tools::SchemaDict;
+
+use super::function::convert_err;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+/// Validates inputs with a plugin registered by another compiled extension with `register_plugin`.
+#[derive(Debug, Clone)]
+pub struct PluginValidator {
+    plugin: Plugin,
+    options: Py<PyDict>,
+    strict: bool,
+    name: String,
+}
+
+impl BuildValidator for PluginValidator {
+    const EXPECTED_TYPE: &'static str = "plugin";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<Arc<CombinedValidator>>,
+    ) -> PyResult<Arc<CombinedValidator>> {
+        let py = schema.py();
+        let plugin_name: Bound<'_, PyString> = schema.get_as_req(intern!(py, "plugin"))?;
+        let plugin = Plugin::get(plugin_name.to_str()?)?;
+        let options = schema
+            .get_as::<Bound<'_, PyDict>>(intern!(py, "options"))?
+            .unwrap_or_else(|| PyDict::new(py));
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, plugin.name());
+        Ok(CombinedValidator::Plugin(Self {
+            plugin,
+            options: options.unbind(),
+            strict: is_strict(schema, config)?,
+            name,
+        })
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(PluginValidator { plugin, options });
+
+impl Validator for PluginValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        let strict = state.strict_or(self.strict);
+        let value = input.to_object(py)?;
+        match self.plugin.validate(self.options.bind(py), &value, strict) {
+            Ok(Some(output)) => Ok(output.unbind()),
+            Ok(None) => Err(ValError::new(
+                ErrorType::PluginType {
+                    plugin: self.plugin.name().to_string(),
+                    context: None,
+                },
+                input,
+            )),
+            Err(err) => Err(convert_err(py, err, input)),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
@@ -6,7 +6,7 @@ use crate::errors::ValResult;
 use crate::input::Input;
 
 use super::ValidationState;
-use super::{CombinedValidator, SchemaValidator, Validator};
+use super::{CombinedValidator, ExampleGenerator, SchemaValidator, Validator};
 
 #[derive(Debug)]
 pub struct PrebuiltValidator {
@@ -40,6 +40,10 @@ impl Validator for PrebuiltValidator {
         self.schema_validator.get().validator.validate(py, input, state)
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        self.schema_validator.get().validator.example(py, generator)
+    }
+
     fn get_name(&self) -> &str {
         self.schema_validator.get().validator.get_name()
     }
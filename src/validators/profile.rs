@@ -0,0 +1,245 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use ahash::AHashMap;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString, PyType};
+
+use crate::errors::{LocItem, ValResult};
+use crate::input::Input;
+
+use super::with_default::WithDefaultValidator;
+use super::{BuildValidator, CombinedValidator, ExampleGenerator, ValidationState, Validator};
+
+thread_local! {
+    // profile of the validator currently being built, `None` unless `profile_validation` is enabled
+    static PROFILE_BUILDER: RefCell<Option<ProfileBuilder>> = const { RefCell::new(None) };
+}
+
+/// Call count and total wall time of a single validator node.
+#[derive(Debug, Default)]
+struct ProfileStats {
+    calls: AtomicU64,
+    nanos: AtomicU64,
+}
+
+impl ProfileStats {
+    fn record(&self, elapsed: Duration) {
+        let nanos = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        self.nanos.fetch_add(nanos, Ordering::Relaxed);
+    }
+}
+
+/// Timings of every node of a validator built with `profile_validation` enabled, keyed by schema path.
+#[derive(Debug, Default)]
+pub(super) struct Profile {
+    // in the order the nodes were built, so outer nodes come before the nodes they contain
+    entries: Vec<(String, Arc<ProfileStats>)>,
+}
+
+impl Profile {
+    pub fn to_py<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let profile = PyDict::new(py);
+        for (path, stats) in &self.entries {
+            let nanos = stats.nanos.load(Ordering::Relaxed);
+            let entry = PyDict::new(py);
+            entry.set_item(intern!(py, "calls"), stats.calls.load(Ordering::Relaxed))?;
+            #[allow(clippy::cast_precision_loss)]
+            entry.set_item(intern!(py, "total_time"), nanos as f64 / 1e9)?;
+            profile.set_item(path, entry)?;
+        }
+        Ok(profile)
+    }
+
+    pub fn reset(&self) {
+        for (_, stats) in &self.entries {
+            stats.calls.store(0, Ordering::Relaxed);
+            stats.nanos.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+#[derive(Default)]
+struct ProfileBuilder {
+    path: Vec<String>,
+    profile: Profile,
+    // index into `profile.entries` for each path, nodes with the same path share their stats
+    indices: AHashMap<String, usize>,
+}
+
+impl ProfileBuilder {
+    fn stats(&mut self) -> Arc<ProfileStats> {
+        let path = self.path.join(".");
+        let entries = &mut self.profile.entries;
+        let index = *self.indices.entry(path).or_insert_with_key(|path| {
+            entries.push((path.clone(), Arc::default()));
+            entries.len() - 1
+        });
+        entries[index].1.clone()
+    }
+}
+
+/// Build a validator with `build`, wrapping every node in a `ProfileValidator` if `enabled`, returns the
+/// validator and its profile.
+pub(super) fn build_profiled<T>(enabled: bool, build: impl FnOnce() -> PyResult<T>) -> PyResult<(T, Option<Profile>)> {
+    if !enabled {
+        return build().map(|validator| (validator, None));
+    }
+    let previous = PROFILE_BUILDER.with_borrow_mut(|builder| builder.replace(ProfileBuilder::default()));
+    let result = build();
+    let builder = PROFILE_BUILDER.with_borrow_mut(|builder| std::mem::replace(builder, previous));
+    result.map(|validator| (validator, builder.map(|builder| builder.profile)))
+}
+
+/// Whether the validator being built is being profiled.
+pub(super) fn is_profiling() -> bool {
+    PROFILE_BUILDER.with_borrow(Option::is_some)
+}
+
+/// Build the validator for a field (or other named item), `name` is added to the schema path of the
+/// nodes built within it.
+pub(super) fn with_path_segment<T>(name: &str, build: impl FnOnce() -> PyResult<T>) -> PyResult<T> {
+    if !is_profiling() {
+        return build();
+    }
+    push_segment(name.to_string());
+    let result = build();
+    pop_segment();
+    result
+}
+
+fn push_segment(segment: String) {
+    PROFILE_BUILDER.with_borrow_mut(|builder| {
+        if let Some(builder) = builder {
+            builder.path.push(segment);
+        }
+    });
+}
+
+fn pop_segment() {
+    PROFILE_BUILDER.with_borrow_mut(|builder| {
+        if let Some(builder) = builder {
+            builder.path.pop();
+        }
+    });
+}
+
+/// Build the validator for a schema node with `build`, wrapping it in a `ProfileValidator` if the validator
+/// being built is being profiled.
+///
+/// `default` nodes aren't wrapped since validators of fields and arguments check for them, their inner
+/// validator is profiled instead.
+pub(super) fn build_node(
+    type_: &str,
+    schema: &Bound<'_, PyDict>,
+    build: impl FnOnce() -> PyResult<Arc<CombinedValidator>>,
+) -> PyResult<Arc<CombinedValidator>> {
+    if type_ == WithDefaultValidator::EXPECTED_TYPE || !is_profiling() {
+        return build();
+    }
+    push_segment(node_segment(type_, schema)?);
+    // reserve the entry before building inner nodes, so outer nodes come first in the profile
+    let stats = PROFILE_BUILDER.with_borrow_mut(|builder| builder.as_mut().map(ProfileBuilder::stats));
+    let result = build();
+    pop_segment();
+    let validator = result?;
+    Ok(match stats {
+        Some(stats) => Arc::new(CombinedValidator::Profile(ProfileValidator { validator, stats })),
+        None => validator,
+    })
+}
+
+/// The schema type, followed by the name of the class, function or definition it refers to if there is one,
+/// e.g. `model[Foo]`.
+fn node_segment(type_: &str, schema: &Bound<'_, PyDict>) -> PyResult<String> {
+    let py = schema.py();
+    let name = if let Some(cls) = schema.get_item(intern!(py, "cls"))? {
+        match cls.downcast::<PyType>() {
+            Ok(cls) => Some(cls.name()?.to_string()),
+            Err(_) => None,
+        }
+    } else if let Some(function) = schema.get_item(intern!(py, "function"))? {
+        // function-before/after/wrap store the function under `function.function`
+        let function = match function.downcast::<PyDict>() {
+            Ok(info) => info.get_item(intern!(py, "function"))?,
+            Err(_) => Some(function),
+        };
+        match function {
+            Some(function) => function
+                .getattr_opt(intern!(py, "__name__"))?
+                .and_then(|name| name.downcast_into::<PyString>().ok())
+                .map(|name| name.to_string()),
+            None => None,
+        }
+    } else {
+        schema
+            .get_item(intern!(py, "schema_ref"))?
+            .and_then(|schema_ref| schema_ref.downcast_into::<PyString>().ok())
+            .map(|schema_ref| schema_ref.to_string())
+    };
+    Ok(match name {
+        Some(name) => format!("{type_}[{name}]"),
+        None => type_.to_string(),
+    })
+}
+
+/// Wraps a validator to record how many times it's called and how long it takes, including the time
+/// spent in the validators it contains.
+#[derive(Debug)]
+pub struct ProfileValidator {
+    validator: Arc<CombinedValidator>,
+    stats: Arc<ProfileStats>,
+}
+
+impl_py_gc_traverse!(ProfileValidator { validator });
+
+impl Validator for ProfileValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        let start = Instant::now();
+        let result = self.validator.validate(py, input, state);
+        self.stats.record(start.elapsed());
+        result
+    }
+
+    fn default_value<'py>(
+        &self,
+        py: Python<'py>,
+        outer_loc: Option<impl Into<LocItem>>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Option<Py<PyAny>>> {
+        self.validator.default_value(py, outer_loc, state)
+    }
+
+    fn validate_assignment<'py>(
+        &self,
+        py: Python<'py>,
+        obj: &Bound<'py, PyAny>,
+        field_name: &str,
+        field_value: &Bound<'py, PyAny>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        let start = Instant::now();
+        let result = self
+            .validator
+            .validate_assignment(py, obj, field_name, field_value, state);
+        self.stats.record(start.elapsed());
+        result
+    }
+
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        self.validator.example(py, generator)
+    }
+
+    fn get_name(&self) -> &str {
+        self.validator.get_name()
+    }
+}
@@ -0,0 +1,133 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple};
+
+use crate::errors::ValResult;
+use crate::input::Input;
+
+use super::{Exactness, ValidationState};
+
+/// Where the values of fields came from, collected when validating with `provenance=True`.
+#[derive(Debug, Default)]
+pub struct Provenance {
+    // names of the fields being validated, from the outermost
+    path: Vec<String>,
+    leaves: Vec<Leaf>,
+}
+
+/// A field which doesn't hold other fields, e.g. one with a nested model isn't a leaf, the model's fields are.
+#[derive(Debug)]
+pub struct Leaf {
+    loc: Vec<String>,
+    source: Source,
+    // the type of the input, `None` for defaults
+    input_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Source {
+    Supplied,
+    Coerced,
+    Default,
+}
+
+impl Source {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Supplied => "supplied",
+            Self::Coerced => "coerced",
+            Self::Default => "default",
+        }
+    }
+}
+
+impl Provenance {
+    /// The list returned by `provenance=True`, a dict for each leaf in the order they were validated.
+    pub fn leaves_to_py<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        let leaves = PyList::empty(py);
+        for leaf in &self.leaves {
+            let dict = PyDict::new(py);
+            dict.set_item(intern!(py, "loc"), PyTuple::new(py, &leaf.loc)?)?;
+            dict.set_item(intern!(py, "source"), leaf.source.as_str())?;
+            dict.set_item(intern!(py, "input_type"), &leaf.input_type)?;
+            leaves.append(dict)?;
+        }
+        Ok(leaves)
+    }
+}
+
+impl ValidationState<'_, '_> {
+    /// The number of leaves recorded so far, to pass to `take_provenance`.
+    pub fn provenance_mark(&self) -> usize {
+        self.provenance.as_ref().map_or(0, |provenance| provenance.leaves.len())
+    }
+
+    /// Remove the leaves recorded since `mark`, e.g. while validating a union choice which may not be used.
+    pub fn take_provenance(&mut self, mark: usize) -> Vec<Leaf> {
+        match &mut self.provenance {
+            Some(provenance) => provenance.leaves.split_off(mark),
+            None => Vec::new(),
+        }
+    }
+
+    /// Restore leaves taken by `take_provenance`.
+    pub fn restore_provenance(&mut self, taken: Vec<Leaf>) {
+        if let Some(provenance) = &mut self.provenance {
+            provenance.leaves.extend(taken);
+        }
+    }
+}
+
+/// Validate the value of the field `name` with `validate`, recording it as supplied, or coerced if its value was
+/// only valid in lax mode, when it's a leaf.
+pub(super) fn validate_field<'py>(
+    py: Python<'py>,
+    state: &mut ValidationState<'_, 'py>,
+    name: &str,
+    input: &(impl Input<'py> + ?Sized),
+    validate: impl FnOnce(&mut ValidationState<'_, 'py>) -> ValResult<Py<PyAny>>,
+) -> ValResult<Py<PyAny>> {
+    let Some(provenance) = state.provenance.as_mut() else {
+        return validate(state);
+    };
+    provenance.path.push(name.to_string());
+    let mark = provenance.leaves.len();
+    let outer_exactness = state.exactness.replace(Exactness::Exact);
+
+    let result = validate(state);
+
+    let field_exactness = std::mem::replace(&mut state.exactness, outer_exactness);
+    if let Some(field_exactness) = field_exactness {
+        state.floor_exactness(field_exactness);
+    }
+    if let Some(provenance) = state.provenance.as_mut() {
+        if result.is_err() {
+            provenance.leaves.truncate(mark);
+        } else if provenance.leaves.len() == mark {
+            let source = match field_exactness {
+                Some(Exactness::Lax) => Source::Coerced,
+                _ => Source::Supplied,
+            };
+            provenance.leaves.push(Leaf {
+                loc: provenance.path.clone(),
+                source,
+                input_type: Some(input.to_object(py)?.get_type().name()?.to_string()),
+            });
+        }
+        provenance.path.pop();
+    }
+    result
+}
+
+/// Record that the default of the field `name` was used.
+pub(super) fn record_default(state: &mut ValidationState<'_, '_>, name: &str) {
+    if let Some(provenance) = state.provenance.as_mut() {
+        let mut loc = provenance.path.clone();
+        loc.push(name.to_string());
+        provenance.leaves.push(Leaf {
+            loc,
+            source: Source::Default,
+            input_type: None,
+        });
+    }
+}
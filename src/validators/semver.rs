@@ -0,0 +1,141 @@
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use crate::build_tools::{is_strict, py_schema_err, LazyLock};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::{downcast_python_input, Input};
+use crate::semver::{PyVersion, SemVer};
+
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+
+/// Validates [SemVer 2.0](https://semver.org) versions, parsed in Rust to a `Version`.
+///
+/// In lax mode, surrounding whitespace and a `v` prefix, e.g. `v1.2.3`, are removed before parsing.
+#[derive(Debug, Clone)]
+pub struct SemverValidator {
+    strict: bool,
+    ge: Option<SemVer>,
+    lt: Option<SemVer>,
+}
+
+static SIMPLE_SEMVER_VALIDATOR: LazyLock<Arc<CombinedValidator>> = LazyLock::new(|| {
+    Arc::new(CombinedValidator::Semver(SemverValidator {
+        strict: false,
+        ge: None,
+        lt: None,
+    }))
+});
+
+impl BuildValidator for SemverValidator {
+    const EXPECTED_TYPE: &'static str = "semver";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<Arc<CombinedValidator>>,
+    ) -> PyResult<Arc<CombinedValidator>> {
+        let py = schema.py();
+        Ok(CombinedValidator::Semver(Self {
+            strict: is_strict(schema, config)?,
+            ge: constraint(schema, intern!(py, "ge"))?,
+            lt: constraint(schema, intern!(py, "lt"))?,
+        })
+        .into())
+    }
+}
+
+/// A `ge` or `lt` constraint, given as a `Version` or a string.
+fn constraint(schema: &Bound<'_, PyDict>, key: &Bound<'_, PyString>) -> PyResult<Option<SemVer>> {
+    let Some(value) = schema.get_item(key)? else {
+        return Ok(None);
+    };
+    if let Ok(version) = value.downcast::<PyVersion>() {
+        return Ok(Some(version.get().version().clone()));
+    }
+    let version: String = value.extract()?;
+    match SemVer::parse(&version) {
+        Ok(version) => Ok(Some(version)),
+        Err(error) => py_schema_err!("Invalid `{}` version '{}': {}", key, version, error),
+    }
+}
+
+impl_py_gc_traverse!(SemverValidator {});
+
+impl Validator for SemverValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        if let Some(py_version) = downcast_python_input::<PyVersion>(input) {
+            self.check_constraints(py_version.get().version(), input)?;
+            return Ok(py_version.clone().into_any().unbind());
+        }
+
+        let strict = state.strict_or(self.strict);
+        let Ok(either_str) = input.validate_str(strict, false) else {
+            return Err(ValError::new(ErrorTypeDefaults::SemverType, input));
+        };
+        let either_str = either_str.into_inner();
+        let cow = either_str.as_cow()?;
+        let mut version_str = cow.as_ref();
+        if !strict {
+            version_str = version_str.trim();
+            version_str = version_str.strip_prefix(['v', 'V']).unwrap_or(version_str);
+        }
+        let version = SemVer::parse(version_str).map_err(|error| {
+            ValError::new(
+                ErrorType::SemverParsing {
+                    error: error.to_string(),
+                    context: None,
+                },
+                input,
+            )
+        })?;
+        self.check_constraints(&version, input)?;
+        // like URLs, a string is a lax match so `str` wins over `semver` in smart unions
+        state.floor_exactness(Exactness::Lax);
+        Ok(Bound::new(py, PyVersion::new(version))?.into_any().unbind())
+    }
+
+    fn get_name(&self) -> &'static str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+impl SemverValidator {
+    pub(crate) fn get_simple() -> &'static Arc<CombinedValidator> {
+        &SIMPLE_SEMVER_VALIDATOR
+    }
+
+    fn check_constraints<'py>(&self, version: &SemVer, input: &(impl Input<'py> + ?Sized)) -> ValResult<()> {
+        if let Some(ge) = &self.ge {
+            if version.cmp_precedence(ge) == Ordering::Less {
+                return Err(ValError::new(
+                    ErrorType::SemverGreaterThanEqual {
+                        ge: ge.to_string(),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(lt) = &self.lt {
+            if version.cmp_precedence(lt) != Ordering::Less {
+                return Err(ValError::new(
+                    ErrorType::SemverLessThan {
+                        lt: lt.to_string(),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        Ok(())
+    }
+}
@@ -3,12 +3,17 @@ use std::sync::Arc;
 use pyo3::types::{PyDict, PySet};
 use pyo3::{prelude::*, IntoPyObjectExt};
 
+use crate::definitions::CompositeName;
 use crate::errors::ValResult;
 use crate::input::{validate_iter_to_set, BorrowInput, ConsumeIterator, Input, ValidatedSet};
 use crate::tools::SchemaDict;
 
+use super::example;
 use super::list::min_length_check;
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::LaxSequenceTypes;
+use super::{
+    parallel, BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState, Validator,
+};
 
 #[derive(Debug)]
 pub struct SetValidator {
@@ -16,8 +21,10 @@ pub struct SetValidator {
     item_validator: Arc<CombinedValidator>,
     min_length: Option<usize>,
     max_length: Option<usize>,
-    name: String,
+    name: CompositeName,
     fail_fast: bool,
+    parallel: bool,
+    lax_sequence_types: LaxSequenceTypes,
 }
 
 macro_rules! set_build {
@@ -32,17 +39,17 @@ macro_rules! set_build {
                 Some(d) => crate::validators::build_validator(&d, config, definitions)?,
                 None => crate::validators::any::AnyValidator::build(schema, config, definitions)?,
             };
-            let inner_name = item_validator.get_name();
             let max_length = schema.get_as(pyo3::intern!(py, "max_length"))?;
-            let name = format!("{}[{}]", Self::EXPECTED_TYPE, inner_name);
             Ok(Arc::new(
                 Self {
                     strict: crate::build_tools::is_strict(schema, config)?,
                     item_validator,
                     min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
                     max_length,
-                    name,
+                    name: crate::definitions::CompositeName::new(),
                     fail_fast: schema.get_as(pyo3::intern!(py, "fail_fast"))?.unwrap_or(false),
+                    parallel: schema.get_as(pyo3::intern!(py, "parallel"))?.unwrap_or(false),
+                    lax_sequence_types: crate::validators::LaxSequenceTypes::from_config(config)?,
                 }
                 .into(),
             ))
@@ -51,12 +58,34 @@ macro_rules! set_build {
 }
 pub(crate) use set_build;
 
+/// A set of examples of `item_validator`, duplicates are replaced so e.g. sets of bools can reach `min_length`.
+pub(super) fn set_example<'py>(
+    py: Python<'py>,
+    generator: &mut ExampleGenerator,
+    item_validator: &CombinedValidator,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+) -> PyResult<Bound<'py, PySet>> {
+    let length = generator.length(min_length, max_length);
+    let set = PySet::empty(py)?;
+    for _ in 0..length + example::ATTEMPTS {
+        if set.len() >= length {
+            break;
+        }
+        set.add(item_validator.example(py, generator)?)?;
+    }
+    Ok(set)
+}
+
 impl BuildValidator for SetValidator {
     const EXPECTED_TYPE: &'static str = "set";
     set_build!();
 }
 
-impl_py_gc_traverse!(SetValidator { item_validator });
+impl_py_gc_traverse!(SetValidator {
+    item_validator,
+    lax_sequence_types
+});
 
 impl Validator for SetValidator {
     fn validate<'py>(
@@ -65,23 +94,39 @@ impl Validator for SetValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        let collection = input.validate_set(state.strict_or(self.strict))?.unpack(state);
-        let set = PySet::empty(py)?;
-        collection.iterate(ValidateToSet {
-            py,
-            input,
-            set: &set,
-            max_length: self.max_length,
-            item_validator: &self.item_validator,
-            state,
-            fail_fast: self.fail_fast,
-        })??;
+        let collection = input
+            .validate_set(state.strict_or(self.strict), &self.lax_sequence_types)?
+            .unpack(state);
+        let set = match parallel::parallel_items(self.parallel, input, &self.item_validator, self.max_length, state) {
+            Some(items) => {
+                let results = parallel::validate_items(py, &items, &self.item_validator, self.fail_fast, state);
+                PySet::new(py, parallel::collect_results(results, state, self.fail_fast)?)?
+            }
+            None => {
+                let set = PySet::empty(py)?;
+                collection.iterate(ValidateToSet {
+                    py,
+                    input,
+                    set: &set,
+                    max_length: self.max_length,
+                    item_validator: &self.item_validator,
+                    state,
+                    fail_fast: self.fail_fast,
+                })??;
+                set
+            }
+        };
         min_length_check!(input, "Set", self.min_length, set);
         Ok(set.into_py_any(py)?)
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        Ok(set_example(py, generator, &self.item_validator, self.min_length, self.max_length)?.into_any())
+    }
+
     fn get_name(&self) -> &str {
-        &self.name
+        self.name
+            .get_or_init(|| format!("{}[{}]", Self::EXPECTED_TYPE, self.item_validator.get_name()))
     }
 }
 
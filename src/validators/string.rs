@@ -1,18 +1,26 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
 use std::sync::Arc;
 
+use ahash::AHashMap;
+
+use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer};
+use jiter::StringCacheMode;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PyString};
 use pyo3::IntoPyObjectExt;
 use regex::Regex;
 
 use crate::build_tools::LazyLock;
-use crate::build_tools::{is_strict, py_schema_error_type, schema_or_config, schema_or_config_same};
-use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::Input;
-use crate::tools::SchemaDict;
+use crate::build_tools::{is_strict, py_schema_err, py_schema_error_type, schema_or_config, schema_or_config_same};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::{EitherString, Input, ValidationMatch};
+use crate::tools::{new_py_string, SchemaDict};
 
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::example;
+use super::grapheme;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState, Validator};
 
 #[derive(Debug)]
 pub struct StrValidator {
@@ -83,6 +91,10 @@ impl Validator for StrValidator {
             })
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        generator.word(None, None).into_bound_py_any(py)
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -93,12 +105,23 @@ impl Validator for StrValidator {
 pub struct StrConstrainedValidator {
     strict: bool,
     pattern: Option<Pattern>,
+    pattern_not: Option<Pattern>,
     max_length: Option<usize>,
     min_length: Option<usize>,
+    length_unit: LengthUnit,
     strip_whitespace: bool,
     to_lower: bool,
     to_upper: bool,
     coerce_numbers_to_str: bool,
+    intern: bool,
+    // `false` if the schema opts out of caching its strings, e.g. for values which rarely repeat
+    cache_strings: bool,
+    bytes_decoding: Option<BytesDecoding>,
+    strip_bom: bool,
+    normalize_newlines: Option<&'static str>,
+    unicode_normalization: Option<UnicodeNormalization>,
+    // the suffix to end strings truncated to `max_length` with, `None` if they're not truncated
+    truncate: Option<String>,
 }
 
 impl_py_gc_traverse!(StrConstrainedValidator {});
@@ -110,43 +133,86 @@ impl Validator for StrConstrainedValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        let either_str = input
-            .validate_str(state.strict_or(self.strict), self.coerce_numbers_to_str)?
-            .unpack(state);
+        let strict = state.strict_or(self.strict);
+        let decoded = match &self.bytes_decoding {
+            Some(bytes_decoding) if !strict => bytes_decoding.decode(input)?,
+            _ => None,
+        };
+        let either_str = match decoded {
+            Some(decoded) => decoded,
+            None => input.validate_str(strict, self.coerce_numbers_to_str)?,
+        }
+        .unpack(state);
         let cow = either_str.as_cow()?;
         let mut str = cow.as_ref();
+        if self.strip_bom {
+            str = str.strip_prefix('\u{feff}').unwrap_or(str);
+        }
+        let normalized;
+        if let Some(newline) = self.normalize_newlines {
+            normalized = normalize_newlines(str, newline);
+            str = normalized.as_ref();
+        }
+        let unicode_normalized;
+        if let Some(form) = self.unicode_normalization {
+            unicode_normalized = form.normalize(str);
+            str = unicode_normalized.as_ref();
+        }
         if self.strip_whitespace {
             str = str.trim();
         }
 
-        let str_len: Option<usize> = if self.min_length.is_some() | self.max_length.is_some() {
-            Some(str.chars().count())
+        let mut str_len: Option<usize> = if self.min_length.is_some() | self.max_length.is_some() {
+            Some(self.length_unit.len(str))
         } else {
             None
         };
+        let truncated;
+        if let (Some(max_length), Some(suffix)) = (self.max_length, &self.truncate) {
+            if str_len.unwrap() > max_length {
+                let kept = self
+                    .length_unit
+                    .truncate(str, max_length - self.length_unit.len(suffix));
+                truncated = format!("{kept}{suffix}");
+                str = &truncated;
+                str_len = Some(self.length_unit.len(str));
+            }
+        }
         if let Some(min_length) = self.min_length {
             if str_len.unwrap() < min_length {
-                return Err(ValError::new(
-                    ErrorType::StringTooShort {
+                let error_type = match self.length_unit {
+                    LengthUnit::Utf8Bytes => ErrorType::StringBytesTooShort {
                         min_length,
                         context: None,
                     },
-                    input,
-                ));
+                    _ => ErrorType::StringTooShort {
+                        min_length,
+                        context: None,
+                    },
+                };
+                return Err(ValError::new(error_type, input));
             }
         }
         if let Some(max_length) = self.max_length {
             if str_len.unwrap() > max_length {
-                return Err(ValError::new(
-                    ErrorType::StringTooLong {
+                let error_type = match self.length_unit {
+                    LengthUnit::Utf8Bytes => ErrorType::StringBytesTooLong {
                         max_length,
                         context: None,
                     },
-                    input,
-                ));
+                    _ => ErrorType::StringTooLong {
+                        max_length,
+                        context: None,
+                    },
+                };
+                return Err(ValError::new(error_type, input));
             }
         }
 
+        if self.pattern.is_some() || self.pattern_not.is_some() {
+            // matching a pattern can be slow, e.g. with the `python-re` engine
+            state.check_deadline()?;
+        }
         if let Some(pattern) = &self.pattern {
             if !pattern.is_match(py, str)? {
                 return Err(ValError::new(
@@ -158,20 +224,60 @@ impl Validator for StrConstrainedValidator {
                 ));
             }
         }
+        if let Some(pattern_not) = &self.pattern_not {
+            if pattern_not.is_match(py, str)? {
+                return Err(ValError::new(
+                    ErrorType::StringPatternForbidden {
+                        pattern: pattern_not.pattern.clone(),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
 
         let py_string = if self.to_lower {
-            state.maybe_cached_str(py, &str.to_lowercase())
+            self.new_py_string(py, &str.to_lowercase(), state)
         } else if self.to_upper {
-            state.maybe_cached_str(py, &str.to_uppercase())
-        } else if self.strip_whitespace {
-            state.maybe_cached_str(py, str)
+            self.new_py_string(py, &str.to_uppercase(), state)
+        } else if self.strip_whitespace
+            || self.intern
+            || self.strip_bom
+            || self.normalize_newlines.is_some()
+            || self.unicode_normalization.is_some()
+            || self.truncate.is_some()
+        {
+            self.new_py_string(py, str, state)
         } else {
             // we haven't modified the string, return the original as it might be a PyString
-            either_str.as_py_string(py, state.cache_str())
+            either_str.as_py_string(py, self.cache_str(state))
         };
         Ok(py_string.into_py_any(py)?)
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        // strings generated from the pattern can have the wrong length, or match `pattern_not`, so a few are tried
+        let mut example = String::new();
+        for _ in 0..example::ATTEMPTS {
+            example = match &self.pattern {
+                Some(pattern) => generator.matching(&pattern.pattern)?,
+                None => generator.word(self.min_length, self.max_length),
+            };
+            let len = self.length_unit.len(&example);
+            if self.min_length.map_or(true, |min| len >= min)
+                && self.max_length.map_or(true, |max| len <= max)
+                && self.pattern.as_ref().map_or(Ok(true), |p| p.is_match(py, &example))?
+                && !self
+                    .pattern_not
+                    .as_ref()
+                    .map_or(Ok(false), |p| p.is_match(py, &example))?
+            {
+                break;
+            }
+        }
+        example.into_bound_py_any(py)
+    }
+
     fn get_name(&self) -> &'static str {
         "constrained-str"
     }
@@ -181,27 +287,42 @@ impl StrConstrainedValidator {
     fn build(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
         let py = schema.py();
 
-        let pattern = schema
-            .get_as(intern!(py, "pattern"))?
-            .map(|s| {
-                let regex_engine = schema_or_config::<Bound<'_, PyString>>(
-                    schema,
-                    config,
-                    intern!(py, "regex_engine"),
-                    intern!(py, "regex_engine"),
-                )?;
-                let regex_engine = regex_engine
-                    .as_ref()
-                    .map(|s| s.to_str())
-                    .transpose()?
-                    .unwrap_or(RegexEngine::RUST_REGEX);
-                Pattern::compile(s, regex_engine)
-            })
-            .transpose()?;
+        let compile = |key: &Bound<'_, PyString>| {
+            schema
+                .get_as(key)?
+                .map(|s| {
+                    let regex_engine = schema_or_config::<Bound<'_, PyString>>(
+                        schema,
+                        config,
+                        intern!(py, "regex_engine"),
+                        intern!(py, "regex_engine"),
+                    )?;
+                    let regex_engine = regex_engine
+                        .as_ref()
+                        .map(|s| s.to_str())
+                        .transpose()?
+                        .unwrap_or(RegexEngine::RUST_REGEX);
+                    Pattern::compile(s, regex_engine)
+                })
+                .transpose()
+        };
+        let pattern = compile(intern!(py, "pattern"))?;
+        let pattern_not = compile(intern!(py, "pattern_not"))?;
         let min_length: Option<usize> =
             schema_or_config(schema, config, intern!(py, "min_length"), intern!(py, "str_min_length"))?;
         let max_length: Option<usize> =
             schema_or_config(schema, config, intern!(py, "max_length"), intern!(py, "str_max_length"))?;
+        let length_unit = match schema.get_as::<String>(intern!(py, "length_unit"))?.as_deref() {
+            None | Some("codepoints") => LengthUnit::Codepoints,
+            Some("graphemes") => LengthUnit::Graphemes,
+            Some("utf8_bytes") => LengthUnit::Utf8Bytes,
+            Some(other) => {
+                return py_schema_err!(
+                    "Invalid length_unit: `{}`, expected 'codepoints', 'graphemes' or 'utf8_bytes'",
+                    other
+                )
+            }
+        };
 
         let strip_whitespace: bool = schema_or_config(
             schema,
@@ -217,46 +338,316 @@ impl StrConstrainedValidator {
 
         let coerce_numbers_to_str: bool =
             schema_or_config_same(schema, config, intern!(py, "coerce_numbers_to_str"))?.unwrap_or(false);
+        let intern = schema.get_as(intern!(py, "intern"))?.unwrap_or(false);
+        let cache_strings = schema.get_as(intern!(py, "cache_strings"))?.unwrap_or(true);
+        if intern && !cache_strings {
+            return py_schema_err!("`intern` can't be used with `cache_strings=False`");
+        }
+        let bytes_decoding = BytesDecoding::from_schema(schema)?;
+        let strip_bom = schema.get_as(intern!(py, "strip_bom"))?.unwrap_or(false);
+        let normalize_newlines = match schema
+            .get_as::<Bound<'_, PyString>>(intern!(py, "normalize_newlines"))?
+            .as_ref()
+            .map(|newline| newline.to_str())
+            .transpose()?
+        {
+            None => None,
+            Some("\n") => Some("\n"),
+            Some("\r\n") => Some("\r\n"),
+            Some(newline) => {
+                return py_schema_err!("Invalid normalize_newlines: {:?}, expected '\\n' or '\\r\\n'", newline)
+            }
+        };
+
+        let unicode_normalization = match schema
+            .get_as::<Bound<'_, PyString>>(intern!(py, "unicode_normalization"))?
+            .as_ref()
+            .map(|form| form.to_str())
+            .transpose()?
+        {
+            None => None,
+            Some("NFC") => Some(UnicodeNormalization::Nfc),
+            Some("NFKC") => Some(UnicodeNormalization::Nfkc),
+            Some("NFD") => Some(UnicodeNormalization::Nfd),
+            Some("NFKD") => Some(UnicodeNormalization::Nfkd),
+            Some(form) => {
+                return py_schema_err!(
+                    "Invalid unicode_normalization: `{}`, expected 'NFC', 'NFKC', 'NFD' or 'NFKD'",
+                    form
+                )
+            }
+        };
+
+        let truncate = match (
+            schema.get_as(intern!(py, "truncate"))?.unwrap_or(false),
+            schema.get_as::<String>(intern!(py, "truncate_suffix"))?,
+        ) {
+            (false, None) => None,
+            (false, Some(_)) => return py_schema_err!("`truncate_suffix` requires `truncate=True`"),
+            (true, suffix) => {
+                let suffix = suffix.unwrap_or_default();
+                match max_length {
+                    None => return py_schema_err!("`truncate` requires `max_length`"),
+                    Some(max_length) if length_unit.len(&suffix) > max_length => {
+                        return py_schema_err!("`truncate_suffix` should be at most `max_length` characters")
+                    }
+                    Some(_) => Some(suffix),
+                }
+            }
+        };
 
         Ok(Self {
             strict: is_strict(schema, config)?,
             pattern,
+            pattern_not,
             min_length,
             max_length,
+            length_unit,
             strip_whitespace,
             to_lower,
             to_upper,
             coerce_numbers_to_str,
+            intern,
+            cache_strings,
+            bytes_decoding,
+            strip_bom,
+            normalize_newlines,
+            unicode_normalization,
+            truncate,
         })
     }
 
+    fn new_py_string<'py>(&self, py: Python<'py>, s: &str, state: &ValidationState<'_, 'py>) -> Bound<'py, PyString> {
+        new_py_string(py, s, self.cache_str(state))
+    }
+
+    fn cache_str(&self, state: &ValidationState) -> StringCacheMode {
+        if self.intern {
+            // the string cache is bounded, so unlike `sys.intern` this can't grow without limit
+            StringCacheMode::All
+        } else if self.cache_strings {
+            state.cache_str()
+        } else {
+            StringCacheMode::None
+        }
+    }
+
     // whether any of the constraints/customisations are actually enabled
     // except strict and coerce_numbers_to_str which can be set on StrValidator
     fn has_constraints_set(&self) -> bool {
         self.pattern.is_some()
+            || self.pattern_not.is_some()
             || self.max_length.is_some()
             || self.min_length.is_some()
             || self.strip_whitespace
             || self.to_lower
             || self.to_upper
+            || self.intern
+            || !self.cache_strings
+            || self.bytes_decoding.is_some()
+            || self.strip_bom
+            || self.normalize_newlines.is_some()
+            || self.unicode_normalization.is_some()
+    }
+}
+
+/// Replace every `\r\n`, `\r` and `\n` in `s` with `newline`.
+fn normalize_newlines<'s>(s: &'s str, newline: &str) -> Cow<'s, str> {
+    if !s.contains('\r') && (newline == "\n" || !s.contains('\n')) {
+        return Cow::Borrowed(s);
+    }
+    let mut normalized = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(index) = rest.find(['\r', '\n']) {
+        normalized.push_str(&rest[..index]);
+        normalized.push_str(newline);
+        let line_ending_len = if rest[index..].starts_with("\r\n") { 2 } else { 1 };
+        rest = &rest[index + line_ending_len..];
+    }
+    normalized.push_str(rest);
+    Cow::Owned(normalized)
+}
+
+/// What `min_length` and `max_length` count, from the `length_unit` key of the schema.
+#[derive(Debug, Clone, Copy, Default)]
+enum LengthUnit {
+    #[default]
+    Codepoints,
+    // user-perceived characters, e.g. a flag emoji or a letter with combining accents is one
+    Graphemes,
+    // e.g. for the size of a database column
+    Utf8Bytes,
+}
+
+impl LengthUnit {
+    fn len(self, s: &str) -> usize {
+        match self {
+            Self::Codepoints => s.chars().count(),
+            Self::Graphemes => grapheme::count(s),
+            Self::Utf8Bytes => s.len(),
+        }
+    }
+
+    /// The longest prefix of `s` of at most `max_length`, without splitting a grapheme cluster.
+    fn truncate(self, s: &str, max_length: usize) -> &str {
+        match self {
+            Self::Codepoints => grapheme::truncate(s, max_length),
+            Self::Graphemes => grapheme::truncate_graphemes(s, max_length),
+            Self::Utf8Bytes => grapheme::truncate_bytes(s, max_length),
+        }
+    }
+}
+
+/// A Unicode normalization form, from the `unicode_normalization` key of the schema.
+#[derive(Debug, Clone, Copy)]
+enum UnicodeNormalization {
+    Nfc,
+    Nfkc,
+    Nfd,
+    Nfkd,
+}
+
+impl UnicodeNormalization {
+    fn normalize(self, s: &str) -> Cow<'_, str> {
+        // most strings are already normalized, e.g. ASCII in every form, so they're checked before copying them
+        match self {
+            Self::Nfc => compose(&ComposingNormalizer::new_nfc(), s),
+            Self::Nfkc => compose(&ComposingNormalizer::new_nfkc(), s),
+            Self::Nfd => decompose(&DecomposingNormalizer::new_nfd(), s),
+            Self::Nfkd => decompose(&DecomposingNormalizer::new_nfkd(), s),
+        }
+    }
+}
+
+fn compose<'s>(normalizer: &ComposingNormalizer, s: &'s str) -> Cow<'s, str> {
+    if normalizer.is_normalized(s) {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(normalizer.normalize(s))
     }
 }
 
+fn decompose<'s>(normalizer: &DecomposingNormalizer, s: &'s str) -> Cow<'s, str> {
+    if normalizer.is_normalized(s) {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(normalizer.normalize(s))
+    }
+}
+
+/// How `bytes` and `bytearray` inputs are decoded in lax mode, from the `encoding` and `errors` keys of the schema.
+///
+/// `None` from `from_schema` means strict UTF-8 decoding, which `Input::validate_str` already does.
 #[derive(Debug, Clone)]
-struct Pattern {
+struct BytesDecoding {
+    // the canonical name of the codec, e.g. `iso8859-1` for `latin-1`
+    encoding: String,
+    errors: BytesDecodeErrors,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BytesDecodeErrors {
+    // bytes which can't be decoded are a validation error
+    Strict,
+    // bytes which can't be decoded are replaced with U+FFFD
+    Replace,
+    // bytes inputs aren't accepted at all
+    Reject,
+}
+
+impl BytesDecoding {
+    fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let encoding = match schema.get_as::<Bound<'_, PyString>>(intern!(py, "encoding"))? {
+            Some(encoding) => {
+                let codecs = py.import(intern!(py, "codecs"))?;
+                match codecs.call_method1(intern!(py, "lookup"), (&encoding,)) {
+                    Ok(codec_info) => codec_info.getattr(intern!(py, "name"))?.extract()?,
+                    Err(_) => return py_schema_err!("Unknown encoding: `{}`", encoding),
+                }
+            }
+            None => "utf-8".to_string(),
+        };
+        let errors = match schema
+            .get_as::<Bound<'_, PyString>>(intern!(py, "errors"))?
+            .as_ref()
+            .map(|errors| errors.to_str())
+            .transpose()?
+        {
+            None | Some("strict") => BytesDecodeErrors::Strict,
+            Some("replace") => BytesDecodeErrors::Replace,
+            Some("reject") => BytesDecodeErrors::Reject,
+            Some(errors) => {
+                return py_schema_err!("Invalid errors: `{}`, expected 'strict', 'replace' or 'reject'", errors)
+            }
+        };
+        if encoding == "utf-8" && errors == BytesDecodeErrors::Strict {
+            Ok(None)
+        } else {
+            Ok(Some(Self { encoding, errors }))
+        }
+    }
+
+    /// Decode `input` if it's `bytes` or `bytearray`, returning `None` for other inputs.
+    fn decode<'py>(
+        &self,
+        input: &(impl Input<'py> + ?Sized),
+    ) -> ValResult<Option<ValidationMatch<EitherString<'static, 'py>>>> {
+        let Some(obj) = input.as_python() else {
+            return Ok(None);
+        };
+        if !obj.is_instance_of::<PyBytes>() && !obj.is_instance_of::<PyByteArray>() {
+            return Ok(None);
+        }
+        let py = obj.py();
+        let errors = match self.errors {
+            BytesDecodeErrors::Strict => intern!(py, "strict"),
+            BytesDecodeErrors::Replace => intern!(py, "replace"),
+            BytesDecodeErrors::Reject => return Err(ValError::new(ErrorTypeDefaults::StringType, input)),
+        };
+        match obj.call_method1(intern!(py, "decode"), (self.encoding.as_str(), errors)) {
+            Ok(decoded) => Ok(Some(ValidationMatch::lax(decoded.downcast_into::<PyString>()?.into()))),
+            Err(_) => Err(ValError::new(ErrorTypeDefaults::StringUnicode, input)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Pattern {
     pattern: String,
     engine: RegexEngine,
 }
 
 #[derive(Debug, Clone)]
-enum RegexEngine {
+pub(super) enum RegexEngine {
     RustRegex(Regex),
     PythonRe(Py<PyAny>),
 }
 
 impl RegexEngine {
-    const RUST_REGEX: &'static str = "rust-regex";
+    pub(super) const RUST_REGEX: &'static str = "rust-regex";
     const PYTHON_RE: &'static str = "python-re";
+
+    fn clone_ref(&self, py: Python<'_>) -> Self {
+        match self {
+            Self::RustRegex(regex) => Self::RustRegex(regex.clone()),
+            Self::PythonRe(py_regex) => Self::PythonRe(py_regex.clone_ref(py)),
+        }
+    }
+}
+
+thread_local! {
+    // compiled patterns of the schema being built by engine and pattern, `None` outside `with_pattern_cache`
+    static PATTERN_CACHE: RefCell<Option<AHashMap<(&'static str, String), RegexEngine>>> = const { RefCell::new(None) };
+}
+
+/// Build a schema with `build`, so the many fields of the schema which use the same pattern share one compiled
+/// regex, the compiled patterns are dropped once it's built. Clones of a `Regex` share its compiled program.
+pub(super) fn with_pattern_cache<T>(build: impl FnOnce() -> T) -> T {
+    let previous = PATTERN_CACHE.with_borrow_mut(|cache| cache.replace(AHashMap::new()));
+    let result = build();
+    PATTERN_CACHE.set(previous);
+    result
 }
 
 impl Pattern {
@@ -271,7 +662,7 @@ impl Pattern {
         }
     }
 
-    fn compile(pattern: Bound<'_, PyAny>, engine: &str) -> PyResult<Self> {
+    pub(super) fn compile(pattern: Bound<'_, PyAny>, engine: &str) -> PyResult<Self> {
         let pattern_str = Self::extract_pattern_str(&pattern)?;
 
         let py = pattern.py();
@@ -289,21 +680,38 @@ impl Pattern {
             })
         } else {
             let engine = match engine {
-                RegexEngine::RUST_REGEX => {
-                    RegexEngine::RustRegex(Regex::new(&pattern_str).map_err(|e| py_schema_error_type!("{}", e))?)
-                }
-                RegexEngine::PYTHON_RE => RegexEngine::PythonRe(re_compile.call1((pattern,))?.into()),
+                RegexEngine::RUST_REGEX => RegexEngine::RUST_REGEX,
+                RegexEngine::PYTHON_RE => RegexEngine::PYTHON_RE,
                 _ => return Err(py_schema_error_type!("Invalid regex engine: {}", engine)),
             };
+            let key = (engine, pattern_str);
+            let cached = PATTERN_CACHE.with_borrow(|cache| cache.as_ref()?.get(&key).map(|e| e.clone_ref(py)));
+            let compiled = match cached {
+                Some(compiled) => compiled,
+                None => {
+                    // compiled without the cache borrowed, `re.compile` can run python code
+                    let compiled = if engine == RegexEngine::RUST_REGEX {
+                        RegexEngine::RustRegex(Regex::new(&key.1).map_err(|e| py_schema_error_type!("{}", e))?)
+                    } else {
+                        RegexEngine::PythonRe(re_compile.call1((pattern,))?.into())
+                    };
+                    PATTERN_CACHE.with_borrow_mut(|cache| {
+                        if let Some(cache) = cache {
+                            cache.insert(key.clone(), compiled.clone_ref(py));
+                        }
+                    });
+                    compiled
+                }
+            };
 
             Ok(Self {
-                pattern: pattern_str,
-                engine,
+                pattern: key.1,
+                engine: compiled,
             })
         }
     }
 
-    fn is_match(&self, py: Python<'_>, target: &str) -> PyResult<bool> {
+    pub(super) fn is_match(&self, py: Python<'_>, target: &str) -> PyResult<bool> {
         match &self.engine {
             RegexEngine::RustRegex(regex) => Ok(regex.is_match(target)),
             RegexEngine::PythonRe(py_regex) => {
@@ -10,17 +10,20 @@ use speedate::{MicrosecondsPrecisionOverflowBehavior, Time};
 
 use crate::build_tools::is_strict;
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{EitherTime, Input};
 
 use super::datetime::extract_microseconds_precision;
 use super::datetime::TZConstraint;
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::datetime_format::{DateTimeFormat, FormatKind};
+use super::example;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
 pub struct TimeValidator {
     strict: bool,
     constraints: Option<TimeConstraints>,
     microseconds_precision: MicrosecondsPrecisionOverflowBehavior,
+    format: Option<DateTimeFormat>,
 }
 
 impl BuildValidator for TimeValidator {
@@ -35,6 +38,7 @@ impl BuildValidator for TimeValidator {
             strict: is_strict(schema, config)?,
             constraints: TimeConstraints::from_py(schema)?,
             microseconds_precision: extract_microseconds_precision(schema, config)?,
+            format: DateTimeFormat::from_py(schema, FormatKind::Time)?,
         };
         Ok(Arc::new(s.into()))
     }
@@ -49,9 +53,16 @@ impl Validator for TimeValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        let time = input
-            .validate_time(state.strict_or(self.strict), self.microseconds_precision)?
-            .unpack(state);
+        let strict = state.strict_or(self.strict);
+        let formatted = match &self.format {
+            Some(format) => format.validate(input, strict, state)?,
+            None => None,
+        };
+        let time = if let Some(datetime) = formatted {
+            EitherTime::Raw(datetime.time)
+        } else {
+            input.validate_time(strict, self.microseconds_precision)?.unpack(state)
+        };
         if let Some(constraints) = &self.constraints {
             let raw_time = time.as_raw()?;
 
@@ -83,6 +94,40 @@ impl Validator for TimeValidator {
         Ok(time.into_py_any(py)?)
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        let seconds = |time: &Time| i128::from(time.total_seconds());
+        let (ge, le, tz_offset) = match &self.constraints {
+            Some(c) => {
+                let (ge, le) = example::int_bounds(
+                    c.ge.as_ref().map(seconds),
+                    c.gt.as_ref().map(seconds),
+                    c.le.as_ref().map(seconds),
+                    c.lt.as_ref().map(seconds),
+                );
+                let tz_offset = match c.tz {
+                    Some(TZConstraint::Aware(offset)) => Some(offset.unwrap_or(0)),
+                    _ => None,
+                };
+                (ge, le, tz_offset)
+            }
+            None => (None, None, None),
+        };
+        let seconds = generator.int(
+            Some(ge.unwrap_or(0).max(0)),
+            Some(le.unwrap_or(86_399).min(86_399)),
+            None,
+        );
+        let seconds = u32::try_from(seconds).unwrap_or(0);
+        let time = Time {
+            hour: (seconds / 3600) as u8,
+            minute: (seconds / 60 % 60) as u8,
+            second: (seconds % 60) as u8,
+            microsecond: 0,
+            tz_offset,
+        };
+        Ok(EitherTime::Raw(time).into_pyobject(py)?.into_any())
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -6,18 +6,43 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDelta, PyDeltaAccess, PyDict, PyString};
 use speedate::{Duration, MicrosecondsPrecisionOverflowBehavior};
 
-use crate::build_tools::is_strict;
-use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::{duration_as_pytimedelta, Input};
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::{duration_as_pytimedelta, EitherTimedelta, Input};
+use crate::tools::SchemaDict;
 
 use super::datetime::extract_microseconds_precision;
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::example;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
 pub struct TimeDeltaValidator {
     strict: bool,
     constraints: Option<TimedeltaConstraints>,
     microseconds_precision: speedate::MicrosecondsPrecisionOverflowBehavior,
+    output: TimedeltaOutput,
+    allow_negative: bool,
+    sign_scope: SignScope,
+}
+
+/// What a leading `-` in a string negates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SignScope {
+    // the whole duration, like ISO 8601
+    Duration,
+    // only the days, like `str(timedelta)` which writes -1 hour as `-1 day, 23:00:00`
+    Days,
+}
+
+/// What the validator returns, `Components` and `Period` are dicts for durations `timedelta` can't represent
+/// the way callers need.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TimedeltaOutput {
+    Timedelta,
+    // days, hours, minutes, seconds and microseconds
+    Components,
+    // like components, with the years and months of ISO 8601 durations kept rather than converted to days
+    Period,
 }
 
 #[derive(Debug, Clone)]
@@ -64,6 +89,35 @@ impl BuildValidator for TimeDeltaValidator {
                 || constraints.gt.is_some())
             .then_some(constraints),
             microseconds_precision: extract_microseconds_precision(schema, config)?,
+            output: match schema
+                .get_as::<Bound<'_, PyString>>(intern!(py, "output"))?
+                .as_ref()
+                .map(|output| output.to_str())
+                .transpose()?
+            {
+                None | Some("timedelta") => TimedeltaOutput::Timedelta,
+                Some("components") => TimedeltaOutput::Components,
+                Some("period") => TimedeltaOutput::Period,
+                Some(output) => {
+                    return py_schema_err!(
+                        "Invalid output: {:?}, expected 'timedelta', 'components' or 'period'",
+                        output
+                    )
+                }
+            },
+            allow_negative: schema.get_as(intern!(py, "allow_negative"))?.unwrap_or(true),
+            sign_scope: match schema
+                .get_as::<Bound<'_, PyString>>(intern!(py, "sign_scope"))?
+                .as_ref()
+                .map(|sign_scope| sign_scope.to_str())
+                .transpose()?
+            {
+                None | Some("duration") => SignScope::Duration,
+                Some("days") => SignScope::Days,
+                Some(sign_scope) => {
+                    return py_schema_err!("Invalid sign_scope: {:?}, expected 'duration' or 'days'", sign_scope)
+                }
+            },
         })
         .into())
     }
@@ -78,9 +132,22 @@ impl Validator for TimeDeltaValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        let timedelta = input
+        let mut timedelta = input
             .validate_timedelta(state.strict_or(self.strict), self.microseconds_precision)?
             .unpack(state);
+        if self.sign_scope == SignScope::Days {
+            if let Ok(either_str) = input.validate_str(true, false) {
+                if let Some(duration) = parse_days_signed(either_str.into_inner().as_cow()?.as_ref()) {
+                    timedelta = EitherTimedelta::Raw(duration);
+                }
+            }
+        }
+        if !self.allow_negative {
+            let duration = timedelta.to_duration()?;
+            if !duration.positive && (duration.day, duration.second, duration.microsecond) != (0, 0, 0) {
+                return Err(ValError::new(ErrorTypeDefaults::TimeDeltaNegative, input));
+            }
+        }
         let py_timedelta = timedelta.clone().into_pyobject(py)?;
         if let Some(constraints) = &self.constraints {
             let raw_timedelta = timedelta.to_duration()?;
@@ -107,13 +174,192 @@ impl Validator for TimeDeltaValidator {
             check_constraint!(ge, GreaterThanEqual);
             check_constraint!(gt, GreaterThan);
         }
-        Ok(py_timedelta.into())
+        let period = match self.output {
+            TimedeltaOutput::Timedelta => return Ok(py_timedelta.into()),
+            TimedeltaOutput::Components => None,
+            TimedeltaOutput::Period => match input.validate_str(true, false) {
+                Ok(either_str) => IsoPeriod::parse(either_str.into_inner().as_cow()?.as_ref()),
+                Err(_) => None,
+            },
+        };
+        let period = match period {
+            Some(period) => period,
+            None => IsoPeriod::from_duration(&timedelta.to_duration()?),
+        };
+        Ok(period.to_dict(py, self.output == TimedeltaOutput::Period)?.into())
+    }
+
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        // seconds, up to a few days unless the constraints say otherwise
+        let seconds = |duration: &Duration| i128::from(duration.signed_total_seconds());
+        let (ge, le) = match &self.constraints {
+            Some(c) => example::int_bounds(
+                c.ge.as_ref().map(seconds),
+                c.gt.as_ref().map(seconds),
+                c.le.as_ref().map(seconds),
+                c.lt.as_ref().map(seconds),
+            ),
+            None => (None, None),
+        };
+        let ge = if self.allow_negative {
+            ge
+        } else {
+            Some(ge.unwrap_or(0).max(0))
+        };
+        let seconds = generator.int_within(ge, le, (0, 3 * 86_400));
+        let total =
+            u64::try_from(seconds.unsigned_abs()).map_err(|_| PyValueError::new_err("Timedelta out of range"))?;
+        let day = u32::try_from(total / 86_400).map_err(|_| PyValueError::new_err("Timedelta out of range"))?;
+        let duration = Duration::new(seconds >= 0, day, (total % 86_400) as u32, 0)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(duration_as_pytimedelta(py, &duration)?.into_any())
     }
 
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
 }
+
+/// The parts of a duration, all positive with `negative` set for negative durations.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct IsoPeriod {
+    negative: bool,
+    years: u64,
+    months: u64,
+    days: u64,
+    hours: u64,
+    minutes: u64,
+    seconds: u64,
+    microseconds: u64,
+}
+
+impl IsoPeriod {
+    fn from_duration(duration: &Duration) -> Self {
+        Self {
+            negative: !duration.positive,
+            days: u64::from(duration.day),
+            hours: u64::from(duration.second / 3600),
+            minutes: u64::from(duration.second % 3600 / 60),
+            seconds: u64::from(duration.second % 60),
+            microseconds: u64::from(duration.microsecond),
+            ..Self::default()
+        }
+    }
+
+    /// Parse an ISO 8601 duration like `P1Y2M3DT4H5M6.7S`, keeping its parts as written, weeks are added to days.
+    ///
+    /// Only seconds can have a fraction, `None` is returned for other strings so they're broken down from the
+    /// parsed `Duration` instead.
+    fn parse(s: &str) -> Option<Self> {
+        let mut period = Self::default();
+        let s = match s.as_bytes().first() {
+            Some(b'-') => {
+                period.negative = true;
+                &s[1..]
+            }
+            Some(b'+') => &s[1..],
+            _ => s,
+        };
+        let mut rest = s.strip_prefix('P')?;
+        let mut in_time = false;
+        // the order of the last part, parts must be in the order they're listed in ISO 8601
+        let mut last_order = 0;
+        let mut time_parts = 0;
+        while !rest.is_empty() {
+            if let Some(after_t) = rest.strip_prefix('T') {
+                if in_time {
+                    return None;
+                }
+                in_time = true;
+                rest = after_t;
+                continue;
+            }
+            let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+            let number: u64 = rest[..digits].parse().ok()?;
+            rest = &rest[digits..];
+            let mut microseconds = 0;
+            if let Some(fraction) = rest.strip_prefix(['.', ',']) {
+                let fraction_digits = fraction.bytes().take_while(u8::is_ascii_digit).count();
+                if fraction_digits == 0 || fraction_digits > 6 || !fraction[fraction_digits..].starts_with('S') {
+                    return None;
+                }
+                microseconds = fraction[..fraction_digits].parse::<u64>().ok()? * 10u64.pow(6 - fraction_digits as u32);
+                rest = &fraction[fraction_digits..];
+            }
+            let designator = rest.bytes().next()?;
+            rest = &rest[1..];
+            let (order, part) = match (in_time, designator) {
+                (false, b'Y') => (1, &mut period.years),
+                (false, b'M') => (2, &mut period.months),
+                (false, b'W') => (3, &mut period.days),
+                (false, b'D') => (4, &mut period.days),
+                (true, b'H') => (5, &mut period.hours),
+                (true, b'M') => (6, &mut period.minutes),
+                (true, b'S') => (7, &mut period.seconds),
+                _ => return None,
+            };
+            if order <= last_order {
+                return None;
+            }
+            last_order = order;
+            time_parts += usize::from(in_time);
+            *part = part.checked_add(if designator == b'W' {
+                number.checked_mul(7)?
+            } else {
+                number
+            })?;
+            period.microseconds = microseconds;
+        }
+        // `P` and `PT` alone aren't durations
+        match (last_order, in_time, time_parts) {
+            (0, _, _) | (_, true, 0) => None,
+            _ => Some(period),
+        }
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>, with_calendar: bool) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        if with_calendar {
+            dict.set_item(intern!(py, "years"), self.years)?;
+            dict.set_item(intern!(py, "months"), self.months)?;
+        }
+        dict.set_item(intern!(py, "days"), self.days)?;
+        dict.set_item(intern!(py, "hours"), self.hours)?;
+        dict.set_item(intern!(py, "minutes"), self.minutes)?;
+        dict.set_item(intern!(py, "seconds"), self.seconds)?;
+        dict.set_item(intern!(py, "microseconds"), self.microseconds)?;
+        dict.set_item(intern!(py, "negative"), self.negative)?;
+        Ok(dict)
+    }
+}
+
+/// Parse a string like `-1 day, 23:00:00`, which has already been parsed as a whole, with the `-` only negating
+/// the days, `None` if it isn't negative or doesn't have both days and a time.
+fn parse_days_signed(s: &str) -> Option<Duration> {
+    const DAY_MICROSECONDS: i64 = 86_400_000_000;
+    let rest = s.strip_prefix('-')?;
+    let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+    let days: i64 = rest[..digits].parse().ok()?;
+    let rest = rest[digits..].trim_start();
+    let rest = ["days", "day", "d"].iter().find_map(|unit| rest.strip_prefix(unit))?;
+    let time = rest.strip_prefix(',').unwrap_or(rest).trim_start();
+    if time.is_empty() {
+        return None;
+    }
+    let time = Duration::parse_str(time).ok()?;
+    let micros =
+        i64::from(time.day) * DAY_MICROSECONDS + i64::from(time.second) * 1_000_000 + i64::from(time.microsecond)
+            - days * DAY_MICROSECONDS;
+    let abs_micros = micros.unsigned_abs();
+    Duration::new(
+        micros >= 0,
+        u32::try_from(abs_micros / DAY_MICROSECONDS as u64).ok()?,
+        (abs_micros / 1_000_000 % 86_400) as u32,
+        (abs_micros % 1_000_000) as u32,
+    )
+    .ok()
+}
+
 fn pydelta_to_human_readable(py_delta: Bound<'_, PyDelta>) -> String {
     let total_seconds = py_delta.get_seconds();
     let hours = total_seconds / 3600;
@@ -6,12 +6,17 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyTuple};
 
 use crate::build_tools::is_strict;
+use crate::definitions::CompositeName;
 use crate::errors::{py_err_string, ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::ConsumeIterator;
 use crate::input::{BorrowInput, Input, ValidatedTuple};
 use crate::tools::SchemaDict;
 
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::list::check_unique_items;
+use super::{
+    build_validator, parallel, BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator,
+    LaxSequenceTypes, ValidationState, Validator,
+};
 
 #[derive(Debug)]
 pub struct TupleValidator {
@@ -20,8 +25,11 @@ pub struct TupleValidator {
     variadic_item_index: Option<usize>,
     min_length: Option<usize>,
     max_length: Option<usize>,
-    name: String,
+    name: CompositeName,
     fail_fast: bool,
+    parallel: bool,
+    lax_sequence_types: LaxSequenceTypes,
+    unique_items: bool,
 }
 
 impl BuildValidator for TupleValidator {
@@ -38,13 +46,8 @@ impl BuildValidator for TupleValidator {
             .map(|item| build_validator(&item, config, definitions))
             .collect::<PyResult<_>>()?;
 
-        let mut validator_names = validators.iter().map(|v| v.get_name()).collect::<Vec<_>>();
-        let variadic_item_index: Option<usize> = schema.get_as(intern!(py, "variadic_item_index"))?;
         // FIXME add friendly schema error if item out of bounds
-        if let Some(variadic_item_index) = variadic_item_index {
-            validator_names.insert(variadic_item_index + 1, "...");
-        }
-        let name = format!("tuple[{}]", validator_names.join(", "));
+        let variadic_item_index: Option<usize> = schema.get_as(intern!(py, "variadic_item_index"))?;
 
         Ok(CombinedValidator::Tuple(Self {
             strict: is_strict(schema, config)?,
@@ -52,14 +55,20 @@ impl BuildValidator for TupleValidator {
             variadic_item_index,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
-            name,
+            name: CompositeName::new(),
             fail_fast: schema.get_as(intern!(py, "fail_fast"))?.unwrap_or(false),
+            parallel: schema.get_as(intern!(py, "parallel"))?.unwrap_or(false),
+            lax_sequence_types: LaxSequenceTypes::from_config(config)?,
+            unique_items: schema.get_as(intern!(py, "unique_items"))?.unwrap_or(false),
         })
         .into())
     }
 }
 
-impl_py_gc_traverse!(TupleValidator { validators });
+impl_py_gc_traverse!(TupleValidator {
+    validators,
+    lax_sequence_types
+});
 
 impl TupleValidator {
     #[allow(clippy::too_many_arguments)]
@@ -77,8 +86,12 @@ impl TupleValidator {
     ) -> ValResult<()> {
         // Validate the head:
         for validator in item_validators {
+            state.check_deadline()?;
             match collection_iter.next() {
-                Some((index, input_item)) => match validator.validate(py, input_item.borrow_input(), state) {
+                Some((index, input_item)) => match state.with_path_item(
+                    || index,
+                    |state| validator.validate(py, input_item.borrow_input(), state),
+                ) {
                     Ok(item) => self.push_output_item(input, output, item, actual_length)?,
                     Err(ValError::LineErrors(line_errors)) => {
                         errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
@@ -95,7 +108,7 @@ impl TupleValidator {
                     }
                 }
             }
-            if fail_fast && !errors.is_empty() {
+            if state.stop_collecting(fail_fast, errors) {
                 return Ok(());
             }
         }
@@ -139,14 +152,18 @@ impl TupleValidator {
                 self.fail_fast,
             )?;
 
-            if self.fail_fast && !errors.is_empty() {
+            if state.stop_collecting(self.fail_fast, errors) {
                 return Ok(output);
             }
 
             let n_tail_validators = tail_validators.len();
             if n_tail_validators == 0 {
                 for (index, input_item) in collection_iter {
-                    match variable_validator.validate(py, input_item.borrow_input(), state) {
+                    state.check_deadline()?;
+                    match state.with_path_item(
+                        || index,
+                        |state| variable_validator.validate(py, input_item.borrow_input(), state),
+                    ) {
                         Ok(item) => self.push_output_item(input, &mut output, item, actual_length)?,
                         Err(ValError::LineErrors(line_errors)) => {
                             errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
@@ -155,7 +172,7 @@ impl TupleValidator {
                         Err(err) => return Err(err),
                     }
 
-                    if self.fail_fast && !errors.is_empty() {
+                    if state.stop_collecting(self.fail_fast, errors) {
                         return Ok(output);
                     }
                 }
@@ -171,13 +188,17 @@ impl TupleValidator {
 
                 // Iterate over all remaining collection items, validating as items "leave" the buffer
                 for (buffer_item_index, input_item) in collection_iter {
+                    state.check_deadline()?;
                     index = buffer_item_index;
                     // This `unwrap` is safe because you can only get here
                     // if there were at least `n_tail_validators` (> 0) items in the iterator
                     let buffered_item = tail_buffer.pop_front().unwrap();
                     tail_buffer.push_back(input_item);
 
-                    match variable_validator.validate(py, buffered_item.borrow_input(), state) {
+                    match state.with_path_item(
+                        || buffer_item_index,
+                        |state| variable_validator.validate(py, buffered_item.borrow_input(), state),
+                    ) {
                         Ok(item) => self.push_output_item(input, &mut output, item, actual_length)?,
                         Err(ValError::LineErrors(line_errors)) => {
                             errors.extend(
@@ -190,7 +211,7 @@ impl TupleValidator {
                         Err(err) => return Err(err),
                     }
 
-                    if self.fail_fast && !errors.is_empty() {
+                    if state.stop_collecting(self.fail_fast, errors) {
                         return Ok(output);
                     }
                 }
@@ -222,7 +243,7 @@ impl TupleValidator {
                 self.fail_fast,
             )?;
 
-            if self.fail_fast && !errors.is_empty() {
+            if state.stop_collecting(self.fail_fast, errors) {
                 return Ok(output);
             }
 
@@ -277,19 +298,39 @@ impl Validator for TupleValidator {
         // this validator does not yet support partial validation, disable it to avoid incorrect results
         state.allow_partial = false.into();
 
-        let collection = input.validate_tuple(state.strict_or(self.strict))?.unpack(state);
+        let collection = input
+            .validate_tuple(state.strict_or(self.strict), &self.lax_sequence_types)?
+            .unpack(state);
         let actual_length = collection.len();
 
         let mut errors: Vec<ValLineError> = Vec::new();
 
-        let output = collection.iterate(ValidateToTuple {
-            py,
-            input,
-            actual_length,
-            validator: self,
-            errors: &mut errors,
-            state,
-        })??;
+        // only tuples of a single variadic item, e.g. `tuple[int, ...]`, are validated in parallel
+        let parallel_items = match (self.validators.as_slice(), self.variadic_item_index) {
+            ([validator], Some(0)) => parallel::parallel_items(self.parallel, input, validator, self.max_length, state),
+            _ => None,
+        };
+        let output = match parallel_items {
+            Some(items) => {
+                let results = parallel::validate_items(py, &items, &self.validators[0], self.fail_fast, state);
+                match parallel::collect_results(results, state, self.fail_fast) {
+                    Ok(output) => output,
+                    Err(ValError::LineErrors(line_errors)) => {
+                        errors.extend(line_errors);
+                        Vec::new()
+                    }
+                    Err(err) => return Err(err),
+                }
+            }
+            None => collection.iterate(ValidateToTuple {
+                py,
+                input,
+                actual_length,
+                validator: self,
+                errors: &mut errors,
+                state,
+            })??,
+        };
 
         if let Some(min_length) = self.min_length {
             let actual_length = output.len();
@@ -307,14 +348,48 @@ impl Validator for TupleValidator {
         }
 
         if errors.is_empty() {
+            if self.unique_items {
+                check_unique_items(
+                    py,
+                    output.iter().map(|item| item.bind(py).clone()),
+                    state,
+                    self.fail_fast,
+                )?;
+            }
             Ok(PyTuple::new(py, output)?.into())
         } else {
             Err(ValError::LineErrors(errors))
         }
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        let mut items = Vec::new();
+        for (index, validator) in self.validators.iter().enumerate() {
+            if self.variadic_item_index == Some(index) {
+                // the length constraints include the other items
+                let others = self.validators.len() - 1;
+                let count = generator.length(
+                    self.min_length.map(|min| min.saturating_sub(others)),
+                    self.max_length.map(|max| max.saturating_sub(others)),
+                );
+                for _ in 0..count {
+                    items.push(validator.example(py, generator)?);
+                }
+            } else {
+                items.push(validator.example(py, generator)?);
+            }
+        }
+        Ok(PyTuple::new(py, items)?.into_any())
+    }
+
     fn get_name(&self) -> &str {
-        &self.name
+        self.name.get_or_init(|| {
+            let mut validator_names = self.validators.iter().map(|v| v.get_name()).collect::<Vec<_>>();
+            if let Some(variadic_item_index) = self.variadic_item_index {
+                validator_names.insert((variadic_item_index + 1).min(validator_names.len()), "...");
+            }
+            format!("tuple[{}]", validator_names.join(", "))
+        })
     }
 }
 
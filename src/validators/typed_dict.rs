@@ -5,9 +5,9 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString, PyType};
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{is_strict, schema_or_config, ExtraBehavior};
-use crate::errors::LocItem;
-use crate::errors::{ErrorTypeDefaults, ValError, ValLineError, ValResult};
+use crate::build_tools::{is_strict, schema_or_config, ExtraBehavior, FieldAliases};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
+use crate::errors::{LocItem, Location};
 use crate::input::BorrowInput;
 use crate::input::ConsumeIterator;
 use crate::input::ValidationMatch;
@@ -17,7 +17,17 @@ use crate::tools::SchemaDict;
 use ahash::AHashSet;
 use jiter::PartialMode;
 
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::explain;
+use super::extras_patterns::{ExtraValidator, ExtrasPatterns};
+use super::ignored_extras;
+use super::introspect::{has_default, FieldToInspect};
+use super::merge::{MergeField, MergePolicy};
+use super::profile;
+use super::provenance;
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState,
+    Validator,
+};
 
 #[derive(Debug)]
 struct TypedDictField {
@@ -25,7 +35,11 @@ struct TypedDictField {
     lookup_key_collection: LookupKeyCollection,
     name_py: Py<PyString>,
     required: bool,
+    read_only: bool,
     validator: Arc<CombinedValidator>,
+    merge_policy: MergePolicy,
+    // only used by introspection, the serializer has its own
+    serialization_alias: Option<String>,
 }
 
 impl_py_gc_traverse!(TypedDictField { validator });
@@ -35,6 +49,7 @@ pub struct TypedDictValidator {
     fields: Vec<TypedDictField>,
     extra_behavior: ExtraBehavior,
     extras_validator: Option<Arc<CombinedValidator>>,
+    extras_patterns: Option<ExtrasPatterns>,
     strict: bool,
     loc_by_alias: bool,
     validate_by_alias: Option<bool>,
@@ -68,9 +83,11 @@ impl BuildValidator for TypedDictValidator {
             (Some(_), _) => return py_schema_err!("extras_schema can only be used if extra_behavior=allow"),
             (_, _) => None,
         };
+        let extras_patterns = ExtrasPatterns::build(schema, config, extra_behavior, definitions)?;
 
         let fields_dict: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: Vec<TypedDictField> = Vec::with_capacity(fields_dict.len());
+        let aliases = FieldAliases::from_schema(schema)?;
 
         let cls_name: Option<String> = match schema.get_as_req::<String>(intern!(py, "cls_name")) {
             Ok(name) => Some(name),
@@ -87,10 +104,11 @@ impl BuildValidator for TypedDictValidator {
 
             let schema = field_info.get_as_req(intern!(py, "schema"))?;
 
-            let validator = match build_validator(&schema, config, definitions) {
-                Ok(v) => v,
-                Err(err) => return py_schema_err!("Field \"{}\":\n  {}", field_name, err),
-            };
+            let validator =
+                match profile::with_path_segment(field_name, || build_validator(&schema, config, definitions)) {
+                    Ok(v) => v,
+                    Err(err) => return py_schema_err!("Field \"{}\":\n  {}", field_name, err),
+                };
 
             let required = match field_info.get_as::<bool>(intern!(py, "required"))? {
                 Some(required) => {
@@ -120,8 +138,12 @@ impl BuildValidator for TypedDictValidator {
                 }
             }
 
-            let validation_alias = field_info.get_item(intern!(py, "validation_alias"))?;
+            let validation_alias = aliases.get(field_info, intern!(py, "validation_alias"), field_name)?;
             let lookup_key_collection = LookupKeyCollection::new(py, validation_alias, field_name)?;
+            let serialization_alias = aliases
+                .get(field_info, intern!(py, "serialization_alias"), field_name)?
+                .map(|alias| alias.extract())
+                .transpose()?;
 
             fields.push(TypedDictField {
                 name: field_name.to_string(),
@@ -129,12 +151,16 @@ impl BuildValidator for TypedDictValidator {
                 name_py: field_name_py.into(),
                 validator,
                 required,
+                read_only: field_info.get_as(intern!(py, "read_only"))?.unwrap_or(false),
+                merge_policy: MergePolicy::from_field_schema(field_info)?,
+                serialization_alias,
             });
         }
         Ok(CombinedValidator::TypedDict(Self {
             fields,
             extra_behavior,
             extras_validator,
+            extras_patterns,
             strict,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
             validate_by_alias: config.get_as(intern!(py, "validate_by_alias"))?,
@@ -147,9 +173,40 @@ impl BuildValidator for TypedDictValidator {
 
 impl_py_gc_traverse!(TypedDictValidator {
     fields,
-    extras_validator
+    extras_validator,
+    extras_patterns
 });
 
+impl TypedDictValidator {
+    pub(super) fn inspect_fields(&self) -> Vec<FieldToInspect<'_>> {
+        self.fields
+            .iter()
+            .map(|field| FieldToInspect {
+                name: &field.name,
+                lookup_key_collection: &field.lookup_key_collection,
+                validator: &field.validator,
+                required: field.required && !has_default(&field.validator),
+                serialization_alias: field.serialization_alias.as_deref(),
+            })
+            .collect()
+    }
+
+    pub(super) fn merge_fields(&self, by_alias: Option<bool>, by_name: Option<bool>) -> PyResult<Vec<MergeField<'_>>> {
+        let by_alias = by_alias.or(self.validate_by_alias).unwrap_or(true);
+        let by_name = by_name.or(self.validate_by_name).unwrap_or(false);
+        self.fields
+            .iter()
+            .map(|field| {
+                Ok(MergeField {
+                    keys: field.lookup_key_collection.select(by_alias, by_name)?.first_keys(),
+                    policy: field.merge_policy,
+                    validator: &field.validator,
+                })
+            })
+            .collect()
+    }
+}
+
 impl Validator for TypedDictValidator {
     fn validate<'py>(
         &self,
@@ -161,7 +218,25 @@ impl Validator for TypedDictValidator {
         let extra_behavior = state.extra_behavior_or(self.extra_behavior);
         let dict = input.validate_dict(strict)?;
 
-        let output_dict = PyDict::new(py);
+        // validating into an existing dict lets callers reuse one result dict for many similar inputs
+        let recycled_dict = state
+            .extra()
+            .self_instance
+            .and_then(|i| i.downcast_exact::<PyDict>().ok())
+            .cloned();
+        // the input can't be cleared before it's validated, so it's only updated once validation succeeds
+        let recycled_input = recycled_dict
+            .as_ref()
+            .is_some_and(|recycled_dict| input.as_python().is_some_and(|input| input.is(recycled_dict)));
+        let output_dict = match &recycled_dict {
+            Some(recycled_dict) if !recycled_input => {
+                recycled_dict.clear();
+                recycled_dict.clone()
+            }
+            _ => PyDict::new(py),
+        };
+        // nested validators shouldn't operate on `self_instance`
+        let state = &mut state.rebind_extra(|extra| extra.self_instance = None);
         let mut errors: Vec<ValLineError> = Vec::with_capacity(self.fields.len());
 
         let partial_last_key = if state.allow_partial.is_active() {
@@ -176,26 +251,35 @@ impl Validator for TypedDictValidator {
 
         // we only care about which keys have been used if we're iterating over the object for extra after
         // the first pass
-        let mut used_keys: Option<AHashSet<&str>> = if extra_behavior == ExtraBehavior::Ignore || dict.is_py_get_attr()
-        {
+        let ignore_extras = extra_behavior == ExtraBehavior::Ignore && state.ignored_extras.is_none();
+        let mut used_keys: Option<AHashSet<&str>> = if ignore_extras || dict.is_py_get_attr() {
             None
         } else {
             Some(AHashSet::with_capacity(self.fields.len()))
         };
 
+        let mut resume = state.resume.take();
+
         {
-            let state = &mut state.rebind_extra(|extra| extra.data = Some(output_dict.clone()));
+            let state = &mut state.rebind_extra(|extra| extra.set_data(output_dict.clone()));
             let state = &mut state.scoped_set(|state| &mut state.has_field_error, false);
 
             let mut fields_set_count: usize = 0;
+            // outputs are only reused while the fields before are complete, as validators may use their data
+            let mut reuse_outputs = true;
 
             for field in &self.fields {
+                if state.stop_collecting(false, &errors) {
+                    break;
+                }
+                state.check_deadline()?;
                 let lookup_key = field
                     .lookup_key_collection
                     .select(validate_by_alias, validate_by_name)?;
                 let op_key_value = match dict.get_item(lookup_key) {
                     Ok(v) => v,
                     Err(ValError::LineErrors(line_errors)) => {
+                        reuse_outputs = false;
                         let field_loc: LocItem = field.name.clone().into();
                         if partial_last_key.as_ref() == Some(&field_loc) {
                             for err in line_errors {
@@ -225,10 +309,39 @@ impl Validator for TypedDictValidator {
                     let state =
                         &mut state.rebind_extra(|extra| extra.field_name = Some(field.name_py.bind(py).clone()));
 
-                    match field.validator.validate(py, value.borrow_input(), state) {
+                    let validate = |state: &mut ValidationState<'_, 'py>| {
+                        let loc = || lookup_path.loc(self.loc_by_alias, &field.name);
+                        state.with_path_location(loc, |state| {
+                            ignored_extras::validate_field(state, &field.name, |state| {
+                                explain::validate_field(py, state, &field.name, value.borrow_input(), |state| {
+                                    provenance::validate_field(py, state, &field.name, value.borrow_input(), |state| {
+                                        field.validator.validate(py, value.borrow_input(), state)
+                                    })
+                                })
+                            })
+                        })
+                    };
+                    let partial_mark = state.partial_mark();
+                    let result = match resume.as_mut().filter(|_| reuse_outputs) {
+                        Some(resume) => resume.validate_item(
+                            py,
+                            field.name.clone().into(),
+                            is_last_partial,
+                            &field.validator,
+                            state,
+                            validate,
+                        ),
+                        None => validate(state),
+                    };
+                    reuse_outputs &= !is_last_partial;
+                    match result {
                         Ok(value) => {
                             output_dict.set_item(&field.name_py, value)?;
                             fields_set_count += 1;
+                            if is_last_partial {
+                                let loc = lookup_path.loc(self.loc_by_alias, &field.name);
+                                state.record_partial_item(partial_mark, &loc, None);
+                            }
                         }
                         Err(e) => {
                             state.has_field_error = true;
@@ -243,6 +356,9 @@ impl Validator for TypedDictValidator {
                                                 &field.name,
                                             ));
                                         }
+                                    } else {
+                                        let loc = lookup_path.loc(self.loc_by_alias, &field.name);
+                                        state.record_partial_item(partial_mark, &loc, Some(line_errors));
                                     }
                                 }
                                 err => return Err(err),
@@ -252,9 +368,12 @@ impl Validator for TypedDictValidator {
                     continue;
                 }
 
+                reuse_outputs = false;
                 match field.validator.default_value(py, Some(field.name.as_str()), state) {
                     Ok(Some(value)) => {
                         // Default value exists, and passed validation if required
+                        explain::record_default(py, state, &field.name, &value);
+                        provenance::record_default(state, &field.name);
                         output_dict.set_item(&field.name_py, value)?;
                     }
                     Ok(None) => {
@@ -292,6 +411,7 @@ impl Validator for TypedDictValidator {
                 used_keys: AHashSet<&'a str>,
                 errors: &'a mut Vec<ValLineError>,
                 extras_validator: Option<&'a CombinedValidator>,
+                extras_patterns: Option<&'a ExtrasPatterns>,
                 output_dict: &'a Bound<'py, PyDict>,
                 state: &'a mut ValidationState<'s, 'py>,
                 extra_behavior: ExtraBehavior,
@@ -307,6 +427,10 @@ impl Validator for TypedDictValidator {
                 type Output = ValResult<()>;
                 fn consume_iterator(self, iterator: impl Iterator<Item = ValResult<(Key, Value)>>) -> ValResult<()> {
                     for item_result in iterator {
+                        if self.state.stop_collecting(false, self.errors) {
+                            break;
+                        }
+                        self.state.check_deadline()?;
                         let (raw_key, value) = item_result?;
                         let either_str = match raw_key
                             .borrow_input()
@@ -340,21 +464,48 @@ impl Validator for TypedDictValidator {
                                     raw_key.clone(),
                                 ));
                             }
-                            ExtraBehavior::Ignore => {}
+                            ExtraBehavior::Ignore => ignored_extras::report(self.state, &cow)?,
                             ExtraBehavior::Allow => {
+                                let extras_validator = match ExtrasPatterns::select(
+                                    self.extras_patterns,
+                                    self.py,
+                                    &cow,
+                                    self.extras_validator,
+                                )? {
+                                    ExtraValidator::Allowed(validator) => validator,
+                                    ExtraValidator::Forbidden => {
+                                        self.errors.push(ValLineError::new_with_loc(
+                                            ErrorTypeDefaults::ExtraForbidden,
+                                            value,
+                                            raw_key.clone(),
+                                        ));
+                                        continue;
+                                    }
+                                };
                                 let py_key = either_str.as_py_string(self.py, self.state.cache_str());
-                                if let Some(validator) = self.extras_validator {
+                                if let Some(validator) = extras_validator {
                                     let last_partial = self.partial_last_key.as_ref() == Some(&raw_key.clone().into());
                                     self.state.allow_partial = match last_partial {
                                         true => self.allow_partial,
                                         false => false.into(),
                                     };
-                                    match validator.validate(self.py, value, self.state) {
+                                    let partial_mark = self.state.partial_mark();
+                                    match self.state.with_path_item(
+                                        || raw_key.clone(),
+                                        |state| validator.validate(self.py, value, state),
+                                    ) {
                                         Ok(value) => {
                                             self.output_dict.set_item(py_key, value)?;
+                                            if last_partial {
+                                                let loc = Location::new_some(raw_key.clone().into());
+                                                self.state.record_partial_item(partial_mark, &loc, None);
+                                            }
                                         }
                                         Err(ValError::LineErrors(line_errors)) => {
-                                            if !last_partial {
+                                            if last_partial {
+                                                let loc = Location::new_some(raw_key.clone().into());
+                                                self.state.record_partial_item(partial_mark, &loc, Some(line_errors));
+                                            } else {
                                                 for err in line_errors {
                                                     self.errors.push(err.with_outer_location(raw_key.clone()));
                                                 }
@@ -378,6 +529,7 @@ impl Validator for TypedDictValidator {
                 py,
                 errors: &mut errors,
                 extras_validator: self.extras_validator.as_deref(),
+                extras_patterns: self.extras_patterns.as_ref(),
                 output_dict: &output_dict,
                 state,
                 extra_behavior,
@@ -386,11 +538,101 @@ impl Validator for TypedDictValidator {
             })??;
         }
 
-        if errors.is_empty() {
-            Ok(output_dict.into())
+        if !errors.is_empty() {
+            // don't leave a reused dict half filled
+            output_dict.clear();
+            return Err(ValError::LineErrors(errors));
+        }
+        state.resume = resume;
+        if recycled_input {
+            let input_dict = recycled_dict.expect("the input is a recycled dict");
+            input_dict.clear();
+            input_dict.update(output_dict.as_mapping())?;
+            return Ok(input_dict.into());
+        }
+        Ok(output_dict.into())
+    }
+
+    /// Validate `field_value` and set it as `field_name` of the typed dict `obj`, which is returned.
+    fn validate_assignment<'py>(
+        &self,
+        py: Python<'py>,
+        obj: &Bound<'py, PyAny>,
+        field_name: &str,
+        field_value: &Bound<'py, PyAny>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        let dict = obj.downcast::<PyDict>()?;
+        let extra_behavior = state.extra_behavior_or(self.extra_behavior);
+
+        // other fields are available to validators as `info.data`
+        let data_dict = dict.copy()?;
+        if data_dict.contains(field_name)? {
+            data_dict.del_item(field_name)?;
+        }
+        let state = &mut state.rebind_extra(move |extra| extra.set_data(data_dict));
+
+        let result = if let Some(field) = self.fields.iter().find(|f| f.name == field_name) {
+            if field.read_only {
+                return Err(ValError::new_with_loc(
+                    ErrorTypeDefaults::ReadOnlyField,
+                    field_value,
+                    &field.name,
+                ));
+            }
+            let state = &mut state.rebind_extra(|extra| extra.field_name = Some(field.name_py.bind(py).clone()));
+            state.with_path_item(|| field_name, |state| field.validator.validate(py, field_value, state))
         } else {
-            Err(ValError::LineErrors(errors))
+            let extra_validator = match extra_behavior {
+                ExtraBehavior::Allow => ExtrasPatterns::select(
+                    self.extras_patterns.as_ref(),
+                    py,
+                    field_name,
+                    self.extras_validator.as_deref(),
+                )?,
+                ExtraBehavior::Forbid | ExtraBehavior::Ignore => ExtraValidator::Forbidden,
+            };
+            match extra_validator {
+                ExtraValidator::Allowed(Some(validator)) => {
+                    state.with_path_item(|| field_name, |state| validator.validate(py, field_value, state))
+                }
+                ExtraValidator::Allowed(None) => Ok(field_value.clone().unbind()),
+                ExtraValidator::Forbidden => {
+                    return Err(ValError::new_with_loc(
+                        ErrorType::NoSuchAttribute {
+                            attribute: field_name.to_string(),
+                            context: None,
+                        },
+                        field_value,
+                        field_name.to_string(),
+                    ))
+                }
+            }
+        };
+
+        match result {
+            Ok(output) => {
+                dict.set_item(field_name, output)?;
+                Ok(dict.clone().into_any().unbind())
+            }
+            Err(ValError::LineErrors(line_errors)) => Err(ValError::LineErrors(
+                line_errors
+                    .into_iter()
+                    .map(|e| e.with_outer_location(field_name))
+                    .collect(),
+            )),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        let dict = PyDict::new(py);
+        for field in &self.fields {
+            if !generator.skip_field(&field.validator, field.required) {
+                dict.set_item(&field.name_py, field.validator.example(py, generator)?)?;
+            }
         }
+        Ok(dict.into_any())
     }
 
     fn get_name(&self) -> &str {
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::{is_strict, LazyLock};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::{downcast_python_input, input_as_python_instance, Input};
+use crate::serializers::BytesMode;
+use crate::ulid::{PyUlid, Ulid};
+
+use super::config::ValBytesMode;
+use super::uuid::get_uuid_type;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+
+/// Validates [ULIDs](https://github.com/ulid/spec), decoded in Rust to a `Ulid`.
+///
+/// Strings are decoded case-insensitively. In lax mode, a `uuid.UUID` with the same 128 bits, the 16 bytes of
+/// the ULID, or its 26 characters as bytes, are also accepted.
+#[derive(Debug, Clone)]
+pub struct UlidValidator {
+    strict: bool,
+}
+
+static SIMPLE_ULID_VALIDATOR: LazyLock<Arc<CombinedValidator>> =
+    LazyLock::new(|| Arc::new(CombinedValidator::Ulid(UlidValidator { strict: false })));
+
+impl BuildValidator for UlidValidator {
+    const EXPECTED_TYPE: &'static str = "ulid";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<Arc<CombinedValidator>>,
+    ) -> PyResult<Arc<CombinedValidator>> {
+        Ok(CombinedValidator::Ulid(Self {
+            strict: is_strict(schema, config)?,
+        })
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(UlidValidator {});
+
+impl Validator for UlidValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        if let Some(py_ulid) = downcast_python_input::<PyUlid>(input) {
+            return Ok(py_ulid.clone().into_any().unbind());
+        }
+
+        let parse = |s: &str| {
+            Ulid::parse(s).map_err(|error| {
+                ValError::new(
+                    ErrorType::UlidParsing {
+                        error: error.to_string(),
+                        context: None,
+                    },
+                    input,
+                )
+            })
+        };
+        let ulid = if let Ok(either_str) = input.validate_str(true, false) {
+            let either_str = either_str.into_inner();
+            parse(either_str.as_cow()?.as_ref())?
+        } else if state.strict_or(self.strict) {
+            return Err(ValError::new(ErrorTypeDefaults::UlidType, input));
+        } else if let Some(uuid) = input_as_python_instance(input, get_uuid_type(py)?) {
+            Ulid::from_u128(uuid.getattr(intern!(py, "int"))?.extract()?)
+        } else {
+            let either_bytes = input
+                .validate_bytes(true, ValBytesMode { ser: BytesMode::Utf8 })
+                .map_err(|_| ValError::new(ErrorTypeDefaults::UlidType, input))?
+                .into_inner();
+            let bytes = either_bytes.as_slice();
+            match <[u8; 16]>::try_from(bytes) {
+                Ok(bytes) => Ulid::from_bytes(bytes),
+                Err(_) => parse(&String::from_utf8_lossy(bytes))?,
+            }
+        };
+        // like URLs, a string is a lax match so `str` wins over `ulid` in smart unions
+        state.floor_exactness(Exactness::Lax);
+        Ok(Bound::new(py, PyUlid::new(ulid))?.into_any().unbind())
+    }
+
+    fn get_name(&self) -> &'static str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+impl UlidValidator {
+    pub(crate) fn get_simple() -> &'static Arc<CombinedValidator> {
+        &SIMPLE_ULID_VALIDATOR
+    }
+}
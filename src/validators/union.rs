@@ -1,32 +1,48 @@
 use std::fmt::Write;
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, PoisonError};
 
 use crate::py_gc::PyGcTraverse;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyString, PyTuple};
+use pyo3::types::{PyDict, PyList, PyMapping, PyString, PyTuple, PyType};
 use pyo3::{intern, PyTraverseError, PyVisit};
 use smallvec::SmallVec;
 
 use crate::build_tools::py_schema_err;
 use crate::build_tools::schema_or_config;
 use crate::common::union::{Discriminator, SMALL_UNION_THRESHOLD};
+use crate::definitions::CompositeName;
 use crate::errors::{ErrorType, ToErrorValue, ValError, ValLineError, ValResult};
 use crate::input::{BorrowInput, Input, ValidatedDict};
 use crate::tools::SchemaDict;
 
 use super::custom_error::CustomError;
+use super::example;
 use super::literal::LiteralLookup;
 use super::{
-    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator,
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ExampleGenerator, StrictScope,
+    ValidationState, Validator,
 };
 
-#[derive(Debug)]
-enum UnionMode {
+#[derive(Debug, Clone, Copy)]
+pub enum UnionMode {
     Smart,
     LeftToRight,
 }
 
+impl FromPyObject<'_> for UnionMode {
+    fn extract_bound(ob: &Bound<'_, PyAny>) -> PyResult<Self> {
+        match ob.extract::<&str>()? {
+            "smart" => Ok(Self::Smart),
+            "left_to_right" => Ok(Self::LeftToRight),
+            s => Err(PyValueError::new_err(format!(
+                "Invalid union_mode: `{s}`, expected 'smart' or 'left_to_right'"
+            ))),
+        }
+    }
+}
+
 impl FromStr for UnionMode {
     type Err = PyErr;
 
@@ -42,9 +58,12 @@ impl FromStr for UnionMode {
 #[derive(Debug)]
 pub struct UnionValidator {
     mode: UnionMode,
-    choices: Vec<(Arc<CombinedValidator>, Option<String>)>,
+    // each choice with its label, and its `strict` setting from `choices_strict` overriding the call-level one
+    choices: Vec<(Arc<CombinedValidator>, Option<String>, Option<bool>)>,
     custom_error: Option<CustomError>,
-    name: String,
+    // in smart mode, a function scoring each valid choice, the choice with the highest score wins
+    scorer: Option<Py<PyAny>>,
+    name: CompositeName,
 }
 
 impl BuildValidator for UnionValidator {
@@ -56,10 +75,22 @@ impl BuildValidator for UnionValidator {
         definitions: &mut DefinitionsBuilder<Arc<CombinedValidator>>,
     ) -> PyResult<Arc<CombinedValidator>> {
         let py = schema.py();
-        let choices: Vec<(Arc<CombinedValidator>, Option<String>)> = schema
-            .get_as_req::<Bound<'_, PyList>>(intern!(py, "choices"))?
+        let py_choices = schema.get_as_req::<Bound<'_, PyList>>(intern!(py, "choices"))?;
+        let choices_strict: Vec<Option<bool>> = match schema.get_as(intern!(py, "choices_strict"))? {
+            Some(choices_strict) => choices_strict,
+            None => vec![None; py_choices.len()],
+        };
+        if choices_strict.len() != py_choices.len() {
+            return py_schema_err!(
+                "`choices_strict` should have the same length as `choices`, got {} and {}",
+                choices_strict.len(),
+                py_choices.len()
+            );
+        }
+        let choices: Vec<(Arc<CombinedValidator>, Option<String>, Option<bool>)> = py_choices
             .iter()
-            .map(|choice| {
+            .zip(choices_strict)
+            .map(|(choice, strict)| {
                 let mut label: Option<String> = None;
                 let choice = match choice.downcast::<PyTuple>() {
                     Ok(py_tuple) => {
@@ -69,7 +100,7 @@ impl BuildValidator for UnionValidator {
                     }
                     Err(_) => choice,
                 };
-                Ok((build_validator(&choice, config, definitions)?, label))
+                Ok((build_validator(&choice, config, definitions)?, label, strict))
             })
             .collect::<PyResult<_>>()?;
 
@@ -77,24 +108,22 @@ impl BuildValidator for UnionValidator {
         let mode = schema
             .get_as::<Bound<'_, PyString>>(intern!(py, "mode"))?
             .map_or(Ok(UnionMode::Smart), |mode| mode.to_str().and_then(UnionMode::from_str))?;
+        let scorer: Option<Bound<'_, PyAny>> = schema.get_as(intern!(py, "scorer"))?;
+        if scorer.is_some() && matches!(mode, UnionMode::LeftToRight) {
+            return py_schema_err!("`scorer` can only be used with `smart` mode unions");
+        }
         match choices.len() {
             0 => py_schema_err!("One or more union choices required"),
-            1 if auto_collapse() => Ok(choices.into_iter().next().unwrap().0),
-            _ => {
-                let descr = choices
-                    .iter()
-                    .map(|(choice, label)| label.as_deref().unwrap_or(choice.get_name()))
-                    .collect::<Vec<_>>()
-                    .join(",");
-
-                Ok(CombinedValidator::Union(Self {
-                    mode,
-                    choices,
-                    custom_error: CustomError::build(schema, config, definitions)?,
-                    name: format!("{}[{descr}]", Self::EXPECTED_TYPE),
-                })
-                .into())
-            }
+            // a choice with its own strictness still needs the union to apply it
+            1 if choices[0].2.is_none() && auto_collapse() => Ok(choices.into_iter().next().unwrap().0),
+            _ => Ok(CombinedValidator::Union(Self {
+                mode,
+                choices,
+                custom_error: CustomError::build(schema, config, definitions)?,
+                scorer: scorer.map(Bound::unbind),
+                name: CompositeName::new(),
+            })
+            .into()),
         }
     }
 }
@@ -110,21 +139,48 @@ impl UnionValidator {
         let old_fields_set_count = state.fields_set_count;
 
         let mut errors = MaybeErrors::new(self.custom_error.as_ref());
+        let mut tracer = UnionTracer::start(self, "smart", state);
 
-        let mut best_match: Option<(Py<PyAny>, Exactness, Option<usize>)> = None;
+        let mut best_match: Option<BestMatch<'_>> = None;
+        let mut best_partial_omissions = Vec::new();
+        let mut best_provenance = Vec::new();
+        let mut best_ignored_extras = Vec::new();
+        let mut best_scratch = None;
+        let mut reason = UnionTraceReason::OnlyValidChoice;
 
-        for (choice, label) in &self.choices {
+        for (choice, label, strict) in &self.choices {
+            state.check_deadline()?;
             state.exactness = Some(Exactness::Exact);
             state.fields_set_count = None;
-            let result = choice.validate(py, input, state);
+            let partial_mark = state.partial_mark();
+            let provenance_mark = state.provenance_mark();
+            let ignored_extras_mark = state.ignored_extras_mark();
+            let scratch_mark = state.scratch_mark(py)?;
+            let result = validate_choice(py, choice, *strict, input, state);
+            // items omitted by `allow_partial`, fields validated and extra items ignored within a choice only count
+            // if the choice is used
+            let partial_omissions = state.take_partial_omissions(partial_mark);
+            let provenance = state.take_provenance(provenance_mark);
+            let ignored_extras = state.take_ignored_extras(ignored_extras_mark);
+            let choice_name = label.as_deref().unwrap_or(choice.get_name());
+            if let Some(tracer) = &mut tracer {
+                tracer.record(state, choice_name, result.is_ok());
+            }
             match result {
                 Ok(new_success) => match (state.exactness, state.fields_set_count) {
-                    (Some(Exactness::Exact), None) => {
+                    // with a scorer every choice has to be scored, so exact matches can't return early
+                    (Some(Exactness::Exact), None) if self.scorer.is_none() => {
                         // exact match with no fields set data, return immediately
                         return {
                             // exact match, return, restore any previous exactness
                             state.exactness = old_exactness;
                             state.fields_set_count = old_fields_set_count;
+                            state.restore_partial_omissions(partial_omissions);
+                            state.restore_provenance(provenance);
+                            state.restore_ignored_extras(ignored_extras)?;
+                            if let Some(tracer) = tracer {
+                                tracer.finish(state, Some(choice_name), UnionTraceReason::Exact);
+                            }
                             Ok(new_success)
                         };
                     }
@@ -134,35 +190,82 @@ impl UnionValidator {
 
                         let new_exactness = state.exactness.unwrap_or(Exactness::Lax);
                         let new_fields_set_count = state.fields_set_count;
+                        let new_score =
+                            match self.score(py, choice_name, &new_success, new_exactness, new_fields_set_count) {
+                                Ok(score) => score,
+                                Err(err) => {
+                                    // leave the state as it was before the union, in case the error is handled:
+                                    // the partial omissions, provenance and ignored extras of this choice and the
+                                    // best one so far were taken from the state at their marks, and are dropped
+                                    drop((partial_omissions, provenance, ignored_extras));
+                                    debug_assert_eq!(state.partial_mark(), partial_mark);
+                                    debug_assert_eq!(state.provenance_mark(), provenance_mark);
+                                    state.exactness = old_exactness;
+                                    state.fields_set_count = old_fields_set_count;
+                                    state.restore_scratch(py, scratch_mark.as_ref())?;
+                                    if let Some(tracer) = tracer {
+                                        tracer.finish(state, None, UnionTraceReason::NoValidChoice);
+                                    }
+                                    return Err(err.into());
+                                }
+                            };
 
+                        // if the union has a scorer, the score takes precedence over everything below.
                         // we use both the exactness and the fields_set_count to determine the best union member match
                         // if fields_set_count is available for the current best match and the new candidate, we use this
                         // as the primary metric. If the new fields_set_count is greater, the new candidate is better.
                         // if the fields_set_count is the same, we use the exactness as a tie breaker to determine the best match.
                         // if the fields_set_count is not available for either the current best match or the new candidate,
                         // we use the exactness to determine the best match.
-                        let new_success_is_best_match: bool =
-                            best_match
-                                .as_ref()
-                                .map_or(true, |(_, cur_exactness, cur_fields_set_count)| {
-                                    match (*cur_fields_set_count, new_fields_set_count) {
-                                        (Some(cur), Some(new)) if cur != new => cur < new,
-                                        _ => *cur_exactness < new_exactness,
+                        let new_success_is_best_match: bool = best_match.as_ref().map_or(
+                            true,
+                            |(_, cur_exactness, cur_fields_set_count, _, cur_score)| {
+                                let (is_better, comparison) = match (*cur_fields_set_count, new_fields_set_count) {
+                                    _ if cur_score != &new_score => {
+                                        (cur_score < &new_score, UnionTraceReason::HighestScore)
                                     }
-                                });
+                                    (Some(cur), Some(new)) if cur != new => {
+                                        (cur < new, UnionTraceReason::MostFieldsSet)
+                                    }
+                                    _ if *cur_exactness != new_exactness => {
+                                        (*cur_exactness < new_exactness, UnionTraceReason::MostExact)
+                                    }
+                                    _ => (false, UnionTraceReason::FirstOfEqualChoices),
+                                };
+                                // the reason is why the current best won, so it only changes with the best choice,
+                                // or when the first valid choice is compared for the first time
+                                if is_better || matches!(reason, UnionTraceReason::OnlyValidChoice) {
+                                    reason = comparison;
+                                }
+                                is_better
+                            },
+                        );
 
                         if new_success_is_best_match {
-                            best_match = Some((new_success, new_exactness, new_fields_set_count));
+                            best_match =
+                                Some((new_success, new_exactness, new_fields_set_count, choice_name, new_score));
+                            best_partial_omissions = partial_omissions;
+                            best_provenance = provenance;
+                            best_ignored_extras = ignored_extras;
+                            best_scratch = state.scratch_mark(py)?;
                         }
+                        // writes to the scratch context only count if the choice is used
+                        state.restore_scratch(py, scratch_mark.as_ref())?;
                     }
                 },
                 Err(ValError::LineErrors(lines)) => {
+                    state.restore_scratch(py, scratch_mark.as_ref())?;
                     // if we don't yet know this validation will succeed, record the error
                     if best_match.is_none() {
                         errors.push(choice, label.as_deref(), lines);
                     }
                 }
-                otherwise => return otherwise,
+                otherwise => {
+                    if let Some(tracer) = tracer {
+                        tracer.finish(state, None, UnionTraceReason::NoValidChoice);
+                    }
+                    return otherwise;
+                }
             }
         }
 
@@ -170,18 +273,47 @@ impl UnionValidator {
         state.exactness = old_exactness;
         state.fields_set_count = old_fields_set_count;
 
-        if let Some((best_match, exactness, fields_set_count)) = best_match {
+        if let Some((best_match, exactness, fields_set_count, choice_name, _)) = best_match {
             state.floor_exactness(exactness);
             if let Some(count) = fields_set_count {
                 state.add_fields_set(count);
             }
+            state.restore_partial_omissions(best_partial_omissions);
+            state.restore_provenance(best_provenance);
+            state.restore_ignored_extras(best_ignored_extras)?;
+            state.restore_scratch(py, best_scratch.as_ref())?;
+            if let Some(tracer) = tracer {
+                tracer.finish(state, Some(choice_name), reason);
+            }
             return Ok(best_match);
         }
 
+        if let Some(tracer) = tracer {
+            tracer.finish(state, None, UnionTraceReason::NoValidChoice);
+        }
         // no matches, build errors
         Err(errors.into_val_error(input))
     }
 
+    /// Score a valid choice with the union's `scorer`, `None` if there isn't one.
+    fn score(
+        &self,
+        py: Python<'_>,
+        choice_name: &str,
+        output: &Py<PyAny>,
+        exactness: Exactness,
+        fields_set_count: Option<usize>,
+    ) -> PyResult<Option<f64>> {
+        match &self.scorer {
+            Some(scorer) => scorer
+                .call1(py, (choice_name, output, exactness_str(exactness), fields_set_count))?
+                .extract::<f64>(py)
+                // NaN can't be compared, so it's the lowest score
+                .map(|score| Some(if score.is_nan() { f64::NEG_INFINITY } else { score })),
+            None => Ok(None),
+        }
+    }
+
     fn validate_left_to_right<'py>(
         &self,
         py: Python<'py>,
@@ -189,21 +321,77 @@ impl UnionValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
         let mut errors = MaybeErrors::new(self.custom_error.as_ref());
-
-        for (validator, label) in &self.choices {
-            match validator.validate(py, input, state) {
-                Err(ValError::LineErrors(lines)) => errors.push(validator, label.as_deref(), lines),
-                otherwise => return otherwise,
+        let mut tracer = UnionTracer::start(self, "left_to_right", state);
+
+        for (validator, label, strict) in &self.choices {
+            state.check_deadline()?;
+            let partial_mark = state.partial_mark();
+            let provenance_mark = state.provenance_mark();
+            let ignored_extras_mark = state.ignored_extras_mark();
+            let scratch_mark = state.scratch_mark(py)?;
+            let result = validate_choice(py, validator, *strict, input, state);
+            let ignored_extras = state.take_ignored_extras(ignored_extras_mark);
+            let choice_name = label.as_deref().unwrap_or(validator.get_name());
+            if let Some(tracer) = &mut tracer {
+                tracer.record(state, choice_name, result.is_ok());
+            }
+            match result {
+                Err(ValError::LineErrors(lines)) => {
+                    state.take_partial_omissions(partial_mark);
+                    state.take_provenance(provenance_mark);
+                    state.restore_scratch(py, scratch_mark.as_ref())?;
+                    errors.push(validator, label.as_deref(), lines);
+                }
+                otherwise => {
+                    state.restore_ignored_extras(ignored_extras)?;
+                    if let Some(tracer) = tracer {
+                        match otherwise {
+                            Ok(_) => tracer.finish(state, Some(choice_name), UnionTraceReason::FirstValidChoice),
+                            Err(_) => tracer.finish(state, None, UnionTraceReason::NoValidChoice),
+                        }
+                    }
+                    return otherwise;
+                }
             }
         }
 
+        if let Some(tracer) = tracer {
+            tracer.finish(state, None, UnionTraceReason::NoValidChoice);
+        }
         Err(errors.into_val_error(input))
     }
 }
 
+/// Validate a choice, with its `strict` setting from `choices_strict` if it has one, which applies to everything
+/// within the choice regardless of the call-level `strict` and `strict_scope`.
+fn validate_choice<'py>(
+    py: Python<'py>,
+    choice: &CombinedValidator,
+    strict: Option<bool>,
+    input: &(impl Input<'py> + ?Sized),
+    state: &mut ValidationState<'_, 'py>,
+) -> ValResult<Py<PyAny>> {
+    match strict {
+        Some(strict) => {
+            let state = &mut state.rebind_extra(|extra| {
+                extra.strict = Some(strict);
+                extra.strict_scope = StrictScope::Recursive;
+            });
+            choice.validate(py, input, state)
+        }
+        None => choice.validate(py, input, state),
+    }
+}
+
+/// The output, exactness, fields set count, name and score of the best choice found so far in a smart union.
+type BestMatch<'a> = (Py<PyAny>, Exactness, Option<usize>, &'a str, Option<f64>);
+
 impl PyGcTraverse for UnionValidator {
     fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
-        self.choices.iter().try_for_each(|(v, _)| v.py_gc_traverse(visit))?;
+        self.choices.iter().try_for_each(|(v, _, _)| v.py_gc_traverse(visit))?;
+        if let Some(scorer) = &self.scorer {
+            visit.call(scorer)?;
+        }
         Ok(())
     }
 }
@@ -215,14 +403,173 @@ impl Validator for UnionValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
-        match self.mode {
+        match state.union_mode.unwrap_or(self.mode) {
             UnionMode::Smart => self.validate_smart(py, input, state),
             UnionMode::LeftToRight => self.validate_left_to_right(py, input, state),
         }
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        // the first choice once shallow, recursive unions usually start with the choice that ends
+        let choice = if generator.shallow() {
+            self.choices.first()
+        } else {
+            generator.choose(&self.choices)
+        };
+        match choice {
+            Some((validator, _, _)) => validator.example(py, generator),
+            None => Err(example::unsupported(self.get_name())),
+        }
+    }
+
     fn get_name(&self) -> &str {
-        &self.name
+        self.name.get_or_init(|| {
+            let descr = self
+                .choices
+                .iter()
+                .map(|(choice, label, _)| label.as_deref().unwrap_or(choice.get_name()))
+                .collect::<Vec<_>>()
+                .join(",");
+            format!("{}[{descr}]", Self::EXPECTED_TYPE)
+        })
+    }
+}
+
+/// Record of a union validation, collected when validating with `trace=True`.
+#[derive(Debug)]
+pub struct UnionTrace {
+    union: String,
+    mode: &'static str,
+    attempts: Vec<UnionAttempt>,
+    winner: Option<String>,
+    reason: UnionTraceReason,
+}
+
+#[derive(Debug)]
+struct UnionAttempt {
+    choice: String,
+    valid: bool,
+    exactness: Option<Exactness>,
+    fields_set_count: Option<usize>,
+    // traces of unions validated within this choice
+    nested: Vec<UnionTrace>,
+}
+
+/// Why the winning choice of a union was chosen.
+#[derive(Debug, Clone, Copy)]
+enum UnionTraceReason {
+    /// An exact match was found, so no further choices were tried
+    Exact,
+    /// Only one choice was valid
+    OnlyValidChoice,
+    /// The winner had more fields set than the other valid choices
+    MostFieldsSet,
+    /// The winner had a higher exactness than the other valid choices
+    MostExact,
+    /// Several valid choices were equally good, so the first was chosen
+    FirstOfEqualChoices,
+    /// The winner had the highest score from the union's `scorer`
+    HighestScore,
+    /// In `left_to_right` mode, the first valid choice is used
+    FirstValidChoice,
+    NoValidChoice,
+}
+
+impl UnionTraceReason {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Exact => "exact",
+            Self::OnlyValidChoice => "only_valid_choice",
+            Self::MostFieldsSet => "most_fields_set",
+            Self::MostExact => "most_exact",
+            Self::FirstOfEqualChoices => "first_of_equal_choices",
+            Self::HighestScore => "highest_score",
+            Self::FirstValidChoice => "first_valid_choice",
+            Self::NoValidChoice => "no_valid_choice",
+        }
+    }
+}
+
+pub(super) fn exactness_str(exactness: Exactness) -> &'static str {
+    match exactness {
+        Exactness::Lax => "lax",
+        Exactness::Strict => "strict",
+        Exactness::Exact => "exact",
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &UnionTrace {
+    type Target = PyDict;
+    type Output = Bound<'py, PyDict>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("union", &self.union)?;
+        dict.set_item("mode", self.mode)?;
+        let attempts = PyList::empty(py);
+        for attempt in &self.attempts {
+            let attempt_dict = PyDict::new(py);
+            attempt_dict.set_item("choice", &attempt.choice)?;
+            attempt_dict.set_item("valid", attempt.valid)?;
+            attempt_dict.set_item("exactness", attempt.exactness.map(exactness_str))?;
+            attempt_dict.set_item("fields_set_count", attempt.fields_set_count)?;
+            attempt_dict.set_item("nested", union_traces_to_py(py, &attempt.nested)?)?;
+            attempts.append(attempt_dict)?;
+        }
+        dict.set_item("attempts", attempts)?;
+        dict.set_item("winner", &self.winner)?;
+        dict.set_item("reason", self.reason.as_str())?;
+        Ok(dict)
+    }
+}
+
+pub(super) fn union_traces_to_py<'py>(py: Python<'py>, traces: &[UnionTrace]) -> PyResult<Bound<'py, PyList>> {
+    PyList::new(py, traces)
+}
+
+/// Collects the trace of a union validation while tracing is enabled.
+///
+/// While each choice is validated, `state.union_trace` holds the traces of unions nested within that choice,
+/// the traces from outside this union are kept in `outer` until it's finished.
+struct UnionTracer {
+    union: String,
+    mode: &'static str,
+    outer: Vec<UnionTrace>,
+    attempts: Vec<UnionAttempt>,
+}
+
+impl UnionTracer {
+    fn start(validator: &UnionValidator, mode: &'static str, state: &mut ValidationState) -> Option<Self> {
+        state.union_trace.as_mut().map(|traces| Self {
+            union: validator.get_name().to_string(),
+            mode,
+            outer: std::mem::take(traces),
+            attempts: Vec::with_capacity(validator.choices.len()),
+        })
+    }
+
+    fn record(&mut self, state: &mut ValidationState, choice: &str, valid: bool) {
+        let nested = state.union_trace.as_mut().map(std::mem::take).unwrap_or_default();
+        self.attempts.push(UnionAttempt {
+            choice: choice.to_string(),
+            valid,
+            exactness: if valid { state.exactness } else { None },
+            fields_set_count: if valid { state.fields_set_count } else { None },
+            nested,
+        });
+    }
+
+    fn finish(self, state: &mut ValidationState, winner: Option<&str>, reason: UnionTraceReason) {
+        let mut traces = self.outer;
+        traces.push(UnionTrace {
+            union: self.union,
+            mode: self.mode,
+            attempts: self.attempts,
+            winner: winner.map(ToString::to_string),
+            reason,
+        });
+        state.union_trace = Some(traces);
     }
 }
 
@@ -286,9 +633,11 @@ pub struct TaggedUnionValidator {
     lookup: LiteralLookup<Arc<CombinedValidator>>,
     from_attributes: bool,
     custom_error: Option<CustomError>,
+    // tags returned by a callable discriminator, cached by input type, if `cache_discriminator` is set
+    tag_cache: Option<Box<TagCache>>,
     tags_repr: String,
     discriminator_repr: String,
-    name: String,
+    name: CompositeName,
 }
 
 impl BuildValidator for TaggedUnionValidator {
@@ -305,7 +654,6 @@ impl BuildValidator for TaggedUnionValidator {
 
         let choices = PyDict::new(py);
         let mut tags_repr = String::with_capacity(50);
-        let mut descr = String::with_capacity(50);
         let mut first = true;
         let schema_choices: Bound<PyDict> = schema.get_as_req(intern!(py, "choices"))?;
         let mut lookup_map = Vec::with_capacity(choices.len());
@@ -315,11 +663,8 @@ impl BuildValidator for TaggedUnionValidator {
             if first {
                 first = false;
                 write!(tags_repr, "{tag_repr}").unwrap();
-                descr.push_str(validator.get_name());
             } else {
                 write!(tags_repr, ", {tag_repr}").unwrap();
-                // no spaces in get_name() output to make loc easy to read
-                write!(descr, ",{}", validator.get_name()).unwrap();
             }
             lookup_map.push((choice_key, validator));
         }
@@ -329,20 +674,33 @@ impl BuildValidator for TaggedUnionValidator {
         let key = intern!(py, "from_attributes");
         let from_attributes = schema_or_config(schema, config, key, key)?.unwrap_or(true);
 
+        let tag_cache = match schema.get_as(intern!(py, "cache_discriminator"))?.unwrap_or(false) {
+            true if !matches!(discriminator, Discriminator::Function(_)) => {
+                return py_schema_err!("`cache_discriminator` can only be used with a callable discriminator");
+            }
+            true => Some(Box::default()),
+            false => None,
+        };
+
         Ok(CombinedValidator::TaggedUnion(Self {
             discriminator,
             lookup,
             from_attributes,
             custom_error: CustomError::build(schema, config, definitions)?,
+            tag_cache,
             tags_repr,
             discriminator_repr,
-            name: format!("{}[{descr}]", Self::EXPECTED_TYPE),
+            name: CompositeName::new(),
         })
         .into())
     }
 }
 
-impl_py_gc_traverse!(TaggedUnionValidator { discriminator, lookup });
+impl_py_gc_traverse!(TaggedUnionValidator {
+    discriminator,
+    lookup,
+    tag_cache
+});
 
 impl Validator for TaggedUnionValidator {
     fn validate<'py>(
@@ -363,7 +721,11 @@ impl Validator for TaggedUnionValidator {
                 self.find_call_validator(py, &tag.borrow_input().to_object(py)?, input, state)
             }
             Discriminator::Function(func) => {
-                let tag: Py<PyAny> = func.call1(py, (input.to_object(py)?,))?;
+                let input_obj = input.to_object(py)?;
+                let tag = match &self.tag_cache {
+                    Some(tag_cache) => tag_cache.get_or_call(func, &input_obj)?,
+                    None => func.call1(py, (input_obj,))?,
+                };
                 if tag.is_none(py) {
                     Err(self.tag_not_found(input))
                 } else {
@@ -373,8 +735,31 @@ impl Validator for TaggedUnionValidator {
         }
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        // the chosen choice's example has its tag, assuming the tag is a field with a literal schema
+        let choice = if generator.shallow() {
+            self.lookup.values.first()
+        } else {
+            generator.choose(&self.lookup.values)
+        };
+        match choice {
+            Some(validator) => validator.example(py, generator),
+            None => Err(example::unsupported(self.get_name())),
+        }
+    }
+
     fn get_name(&self) -> &str {
-        &self.name
+        self.name.get_or_init(|| {
+            // no spaces in get_name() output to make loc easy to read
+            let descr = self
+                .lookup
+                .values
+                .iter()
+                .map(|v| v.get_name())
+                .collect::<Vec<_>>()
+                .join(",");
+            format!("{}[{descr}]", Self::EXPECTED_TYPE)
+        })
     }
 }
 
@@ -387,7 +772,7 @@ impl TaggedUnionValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
         if let Ok(Some((tag, validator))) = self.lookup.validate(py, tag) {
-            return match validator.validate(py, input, state) {
+            return match state.with_path_item(|| tag, |state| validator.validate(py, input, state)) {
                 Ok(res) => Ok(res),
                 Err(err) => Err(err.with_outer_location(tag)),
             };
@@ -419,3 +804,57 @@ impl TaggedUnionValidator {
         }
     }
 }
+
+/// Maximum number of input types to cache the tags of, so unions validating inputs of many different
+/// types don't keep growing the cache.
+const TAG_CACHE_SIZE: usize = 32;
+
+/// Tags returned by a callable discriminator for each exact input type, so the discriminator is only called
+/// once per type, used when the tag depends only on the type of the input.
+#[derive(Debug, Default)]
+struct TagCache(Mutex<Vec<(Py<PyType>, Py<PyAny>)>>);
+
+impl TagCache {
+    fn get_or_call(&self, func: &Py<PyAny>, input: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        let py = input.py();
+        // the tags of mappings, lists and tuples usually depend on their contents rather than their type, e.g. a
+        // `{'kind': ...}` key, so if they were cached all dicts would get the tag of the first dict
+        if input.downcast::<PyMapping>().is_ok()
+            || input.is_instance_of::<PyList>()
+            || input.is_instance_of::<PyTuple>()
+        {
+            return func.call1(py, (input,));
+        }
+        let input_type = input.get_type();
+        if let Some(tag) = self.get(&input_type) {
+            return Ok(tag);
+        }
+        // the lock isn't held while calling the discriminator, since it could validate with this union again
+        let tag = func.call1(py, (input,))?;
+        let mut cache = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        if cache.len() < TAG_CACHE_SIZE && !cache.iter().any(|(t, _)| t.is(&input_type)) {
+            cache.push((input_type.unbind(), tag.clone_ref(py)));
+        }
+        Ok(tag)
+    }
+
+    fn get(&self, input_type: &Bound<'_, PyType>) -> Option<Py<PyAny>> {
+        let cache = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        cache
+            .iter()
+            .find(|(t, _)| t.is(input_type))
+            .map(|(_, tag)| tag.clone_ref(input_type.py()))
+    }
+}
+
+impl PyGcTraverse for TagCache {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        if let Ok(cache) = self.0.try_lock() {
+            for (input_type, tag) in cache.iter() {
+                visit.call(input_type)?;
+                visit.call(tag)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+use std::cmp::Reverse;
+
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyString};
+use pyo3::{intern, IntoPyObjectExt, PyTraverseError, PyVisit};
+
+use crate::build_tools::py_schema_err;
+use crate::errors::{ValError, ValResult};
+use crate::input::{float_as_int, str_as_float, str_as_int, EitherFloat, EitherInt, Input};
+use crate::py_gc::PyGcTraverse;
+use crate::tools::SchemaDict;
+
+use super::decimal::{create_decimal, get_decimal_type};
+
+/// The `unit` and `unit_conversions` of a number schema, in lax mode strings with a unit suffix, e.g. `'5m'`, are
+/// converted to the schema's unit by multiplying them by the suffix's factor.
+#[derive(Debug, Clone)]
+pub(super) struct Units {
+    // suffixes and their factors, longest first so `'ms'` is tried before `'s'`
+    suffixes: Vec<(String, Py<PyAny>)>,
+}
+
+impl PyGcTraverse for Units {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.suffixes.iter().try_for_each(|(_, factor)| visit.call(factor))
+    }
+}
+
+impl Units {
+    /// With `decimal`, factors are converted to decimals so they can be multiplied exactly.
+    pub fn from_schema(schema: &Bound<'_, PyDict>, decimal: bool) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let conversions: Option<Bound<'_, PyDict>> = schema.get_as(intern!(py, "unit_conversions"))?;
+        let Some(unit) = schema.get_as::<String>(intern!(py, "unit"))? else {
+            return match conversions {
+                Some(_) => py_schema_err!("`unit_conversions` requires `unit`"),
+                None => Ok(None),
+            };
+        };
+        let mut suffixes = vec![(unit.clone(), 1.into_bound_py_any(py)?)];
+        for (suffix, factor) in conversions.iter().flat_map(PyDictMethods::iter) {
+            let suffix: String = suffix.extract()?;
+            let is_number = !factor.is_instance_of::<PyBool>()
+                && (factor.is_instance_of::<PyInt>()
+                    || factor.is_instance_of::<PyFloat>()
+                    || factor.is_instance(get_decimal_type(py))?);
+            // `unit` itself may be listed, e.g. so one table of conversions can be shared, but only as `1` of itself
+            if is_number && suffix == unit && factor.eq(1)? {
+                continue;
+            }
+            if suffix.is_empty() || !is_number || !factor.gt(0)? || suffix == unit {
+                return py_schema_err!(
+                    "Invalid unit conversion `{}: {}`, expected a new unit and a positive factor",
+                    suffix,
+                    factor
+                );
+            }
+            suffixes.push((suffix, factor));
+        }
+        if decimal {
+            for (_, factor) in &mut suffixes {
+                // `str` so that e.g. `0.001` is exactly `Decimal('0.001')`
+                *factor = get_decimal_type(py).call1((factor.str()?,))?;
+            }
+        }
+        suffixes.sort_by_key(|(suffix, _)| Reverse(suffix.len()));
+        Ok(Some(Self {
+            suffixes: suffixes
+                .into_iter()
+                .map(|(suffix, factor)| (suffix, factor.unbind()))
+                .collect(),
+        }))
+    }
+
+    /// The number before a unit suffix in a string input, and the suffix's factor.
+    fn split<'py>(&self, input: &(impl Input<'py> + ?Sized)) -> Option<(String, &Py<PyAny>)> {
+        let either_str = input.exact_str().ok()?;
+        let s = either_str.as_cow().ok()?;
+        let s = s.trim();
+        self.suffixes.iter().find_map(|(suffix, factor)| {
+            let number = s.strip_suffix(suffix.as_str())?.trim_end();
+            (!number.is_empty()).then(|| (number.to_string(), factor))
+        })
+    }
+
+    /// Convert an input which failed to validate as an int, returning `error` if it doesn't have a unit suffix.
+    pub fn convert_int<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        error: ValError,
+    ) -> ValResult<EitherInt<'py>> {
+        let Some((number, factor)) = self.split(input) else {
+            return Err(error);
+        };
+        let number = match str_as_int(input, &number) {
+            Ok(int) => int.into_pyobject(py)?,
+            Err(_) => match str_as_float(input, &number) {
+                Ok(float) => float.as_f64().into_pyobject(py)?.into_any(),
+                Err(_) => return Err(error),
+            },
+        };
+        let value = number.mul(factor.bind(py))?;
+        match value.downcast_into::<PyInt>() {
+            Ok(int) => EitherInt::upcast(int.as_any()),
+            Err(err) => float_as_int(input, err.into_inner().extract()?),
+        }
+    }
+
+    /// Convert an input which failed to validate as a float, returning `error` if it doesn't have a unit suffix.
+    pub fn convert_float<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        error: ValError,
+    ) -> ValResult<EitherFloat<'py>> {
+        let Some((number, factor)) = self.split(input) else {
+            return Err(error);
+        };
+        let Ok(number) = str_as_float(input, &number) else {
+            return Err(error);
+        };
+        let factor: f64 = factor.bind(py).extract()?;
+        Ok(EitherFloat::F64(number.as_f64() * factor))
+    }
+
+    /// Convert an input which failed to validate as a decimal, returning `error` if it doesn't have a unit suffix.
+    pub fn convert_decimal<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        error: ValError,
+    ) -> ValResult<Bound<'py, PyAny>> {
+        let Some((number, factor)) = self.split(input) else {
+            return Err(error);
+        };
+        let Ok(number) = create_decimal(PyString::new(py, &number).as_any(), input) else {
+            return Err(error);
+        };
+        Ok(number.mul(factor.bind(py))?)
+    }
+}
@@ -19,7 +19,7 @@ use crate::input::downcast_python_input;
 use crate::input::Input;
 use crate::input::ValidationMatch;
 use crate::tools::SchemaDict;
-use crate::url::{scheme_is_special, PyMultiHostUrl, PyUrl};
+use crate::url::{scheme_is_special, OriginalParts, PyMultiHostUrl, PyUrl};
 
 use super::literal::expected_repr_name;
 use super::Exactness;
@@ -38,6 +38,10 @@ pub struct UrlValidator {
     default_path: Option<String>,
     name: String,
     preserve_empty_path: bool,
+    // when false, domains are kept as given instead of punycode encoded
+    punycode_host: bool,
+    // when false, the path is kept as given instead of normalized
+    normalize_path: bool,
 }
 
 static SIMPLE_URL_VALIDATOR: LazyLock<Arc<CombinedValidator>> = LazyLock::new(|| {
@@ -51,6 +55,8 @@ static SIMPLE_URL_VALIDATOR: LazyLock<Arc<CombinedValidator>> = LazyLock::new(||
         default_path: None,
         name: "url".to_string(),
         preserve_empty_path: false,
+        punycode_host: true,
+        normalize_path: true,
     }))
 });
 
@@ -65,6 +71,8 @@ static SIMPLE_URL_VALIDATOR_STRICT: LazyLock<Arc<CombinedValidator>> = LazyLock:
         default_path: None,
         name: "url".to_string(),
         preserve_empty_path: false,
+        punycode_host: true,
+        normalize_path: true,
     }))
 });
 
@@ -79,6 +87,8 @@ static SIMPLE_URL_VALIDATOR_PRESERVE_EMPTY_PATH: LazyLock<Arc<CombinedValidator>
         default_path: None,
         name: "url".to_string(),
         preserve_empty_path: true,
+        punycode_host: true,
+        normalize_path: true,
     }))
 });
 
@@ -93,6 +103,8 @@ static SIMPLE_URL_VALIDATOR_STRICT_PRESERVE_EMPTY_PATH: LazyLock<Arc<CombinedVal
         default_path: None,
         name: "url".to_string(),
         preserve_empty_path: true,
+        punycode_host: true,
+        normalize_path: true,
     }))
 });
 
@@ -126,6 +138,8 @@ impl BuildValidator for UrlValidator {
             allowed_schemes,
             name,
             preserve_empty_path: get_preserve_empty_path(schema, config)?,
+            punycode_host: schema.get_as(intern!(schema.py(), "punycode_host"))?.unwrap_or(true),
+            normalize_path: schema.get_as(intern!(schema.py(), "normalize_path"))?.unwrap_or(true),
         };
 
         // if no defaults, the prebuilt simple validator will do
@@ -135,6 +149,8 @@ impl BuildValidator for UrlValidator {
             && validator.default_host.is_none()
             && validator.default_port.is_none()
             && validator.default_path.is_none()
+            && validator.punycode_host
+            && validator.normalize_path
         {
             return Ok(UrlValidator::get_simple(validator.strict, validator.preserve_empty_path).clone());
         }
@@ -198,6 +214,28 @@ impl UrlValidator {
         }
     }
 
+    /// Like `get_simple`, but keeping the host or path as given, for `Url(..)` with `punycode_host=False` or
+    /// `normalize_path=False`.
+    pub(crate) fn get_original_form(
+        preserve_empty_path: bool,
+        punycode_host: bool,
+        normalize_path: bool,
+    ) -> Arc<CombinedValidator> {
+        Arc::new(CombinedValidator::Url(UrlValidator {
+            strict: false,
+            max_length: None,
+            allowed_schemes: None,
+            host_required: false,
+            default_host: None,
+            default_port: None,
+            default_path: None,
+            name: "url".to_string(),
+            preserve_empty_path,
+            punycode_host,
+            normalize_path,
+        }))
+    }
+
     fn get_url<'py>(
         &self,
         py: Python<'py>,
@@ -225,7 +263,16 @@ impl UrlValidator {
         self.check_length(input, url_str)?;
         let url = parse_url(url_str, input, strict)?;
         let path_is_empty = need_to_preserve_empty_path(&url, url_str, self.preserve_empty_path);
-        Ok(EitherUrl::Rust(PyUrl::new(url, path_is_empty)))
+        let py_url = PyUrl::new(url, path_is_empty);
+        if self.punycode_host && self.normalize_path {
+            return Ok(EitherUrl::Rust(py_url));
+        }
+        let original = original_parts(py_url.url(), url_str, self.punycode_host, self.normalize_path);
+        Ok(EitherUrl::Rust(py_url.with_original(
+            original,
+            self.punycode_host,
+            self.normalize_path,
+        )))
     }
 
     fn check_length<'py>(&self, input: &(impl Input<'py> + ?Sized), url_str: &str) -> ValResult<()> {
@@ -741,6 +788,62 @@ fn need_to_preserve_empty_path(url: &Url, url_str: &str, preserve_empty_path: bo
     true
 }
 
+/// The host and path of `url_str` as given, for the parts which shouldn't be normalized, and which differ from
+/// `url`'s. Only domains of special schemes are kept, other hosts are kept as given anyway, or normalized
+/// structurally, like IP addresses.
+///
+/// The original parts are only kept where they're the same as `url`'s but for case, punycode, percent-encoding and
+/// dot segments, otherwise `url`'s are used, so e.g. control characters and backslashes never get into the URL.
+fn original_parts(url: &Url, url_str: &str, punycode_host: bool, normalize_path: bool) -> OriginalParts {
+    let special = scheme_is_special(url.scheme());
+    // the `url` crate strips leading and trailing C0 controls and spaces, and removes tabs and newlines anywhere
+    let url_str: String = url_str
+        .trim_matches(|c: char| c <= ' ')
+        .chars()
+        .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+        .collect();
+    let (_, rest) = url_str.split_once(':').expect("url has a scheme");
+    let rest = &rest[..rest.find(['?', '#']).unwrap_or(rest.len())];
+    let is_separator = |c: char| c == '/' || (special && c == '\\');
+
+    let (authority, path) = if special && url.scheme() != "file" {
+        // any number of slashes can come before the authority
+        let rest = rest.trim_start_matches(is_separator);
+        rest.split_at(rest.find(is_separator).unwrap_or(rest.len()))
+    } else {
+        let mut chars = rest.chars();
+        match (chars.next(), chars.next()) {
+            (Some(a), Some(b)) if is_separator(a) && is_separator(b) => {
+                let rest = &rest[2..];
+                rest.split_at(rest.find(is_separator).unwrap_or(rest.len()))
+            }
+            _ => ("", rest),
+        }
+    };
+    let is_unsafe = |c: char| c.is_ascii_control() || c == '\\';
+
+    let host = match url.host() {
+        Some(url::Host::Domain(domain)) if special && !punycode_host => {
+            let host = authority.rsplit_once('@').map_or(authority, |(_, host)| host);
+            let host = host.split_once(':').map_or(host, |(host, _)| host);
+            Some(host.to_string()).filter(|host| {
+                host != domain
+                    && !host.contains(|c: char| is_unsafe(c) || c == '%')
+                    && matches!(url::Host::parse(host), Ok(url::Host::Domain(parsed)) if parsed == domain)
+            })
+        }
+        _ => None,
+    };
+    let path = Some(path.to_string()).filter(|path| {
+        !normalize_path && !path.is_empty() && url.path() != path && !path.contains(is_unsafe) && {
+            let mut reparsed = url.clone();
+            reparsed.set_path(path);
+            reparsed.path() == url.path()
+        }
+    });
+    OriginalParts { host, path }
+}
+
 /// check host_required and substitute `default_host`, `default_port` & `default_path` if they aren't set
 fn check_sub_defaults(
     url: &mut impl CopyFromPyUrl,
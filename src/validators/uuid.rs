@@ -4,11 +4,11 @@ use std::sync::Arc;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::sync::PyOnceLock;
-use pyo3::types::{PyDict, PyType};
+use pyo3::types::{PyDateTime, PyDict, PyString, PyType, PyTzInfo, PyTzInfoAccess};
 use uuid::Uuid;
 use uuid::Variant;
 
-use crate::build_tools::is_strict;
+use crate::build_tools::{is_strict, py_schema_err};
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::input::input_as_python_instance;
 use crate::input::Input;
@@ -20,23 +20,32 @@ use crate::tools::SchemaDict;
 use super::config::ValBytesMode;
 use super::model::create_class;
 use super::model::force_setattr;
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+use super::{
+    BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ExampleGenerator, ValidationState, Validator,
+};
 
 const UUID_INT: &str = "int";
 const UUID_IS_SAFE: &str = "is_safe";
 
 static UUID_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+static TIMESTAMPED_UUID_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
 
 fn import_type(py: Python, module: &str, attr: &str) -> PyResult<Py<PyType>> {
     py.import(module)?.getattr(attr)?.extract()
 }
 
-fn get_uuid_type(py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
+pub(super) fn get_uuid_type(py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
     Ok(UUID_TYPE
         .get_or_init(py, || import_type(py, "uuid", "UUID").unwrap())
         .bind(py))
 }
 
+fn get_timestamped_uuid_type(py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
+    Ok(TIMESTAMPED_UUID_TYPE
+        .get_or_init(py, || import_type(py, "pydantic_core", "TimestampedUUID").unwrap())
+        .bind(py))
+}
+
 #[derive(Debug, Clone, Copy)]
 enum Version {
     UUIDv1 = 1,
@@ -54,18 +63,47 @@ impl From<Version> for usize {
     }
 }
 
-impl From<u8> for Version {
-    fn from(u: u8) -> Self {
+impl TryFrom<u8> for Version {
+    type Error = PyErr;
+
+    fn try_from(u: u8) -> PyResult<Self> {
         match u {
-            1 => Version::UUIDv1,
-            3 => Version::UUIDv3,
-            4 => Version::UUIDv4,
-            5 => Version::UUIDv5,
-            6 => Version::UUIDv6,
-            7 => Version::UUIDv7,
-            8 => Version::UUIDv8,
-            _ => unreachable!(),
+            1 => Ok(Version::UUIDv1),
+            3 => Ok(Version::UUIDv3),
+            4 => Ok(Version::UUIDv4),
+            5 => Ok(Version::UUIDv5),
+            6 => Ok(Version::UUIDv6),
+            7 => Ok(Version::UUIDv7),
+            8 => Ok(Version::UUIDv8),
+            _ => py_schema_err!("Invalid `version` {}, expected 1, 3, 4, 5, 6, 7 or 8", u),
+        }
+    }
+}
+
+/// A `timestamp_ge` or `timestamp_lt` bound on the time embedded in a UUID.
+#[derive(Debug, Clone)]
+struct TimestampBound {
+    // microseconds since the Unix epoch
+    micros: i64,
+    // the bound in ISO 8601 format, for errors
+    display: String,
+}
+
+impl TimestampBound {
+    fn from_schema(schema: &Bound<'_, PyDict>, key: &Bound<'_, PyString>) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let Some(bound) = schema.get_as::<Bound<'_, PyDateTime>>(key)? else {
+            return Ok(None);
+        };
+        // `timestamp()` of a naive datetime would depend on the local timezone
+        if bound.get_tzinfo().is_none() {
+            return py_schema_err!("`{}` should be a timezone-aware datetime", key);
         }
+        let seconds: f64 = bound.call_method0(intern!(py, "timestamp"))?.extract()?;
+        Ok(Some(Self {
+            micros: (seconds * 1_000_000.0).round() as i64,
+            display: bound.call_method0(intern!(py, "isoformat"))?.extract()?,
+        }))
     }
 }
 
@@ -73,6 +111,10 @@ impl From<u8> for Version {
 pub struct UuidValidator {
     strict: bool,
     version: Option<usize>,
+    timestamp_ge: Option<TimestampBound>,
+    timestamp_lt: Option<TimestampBound>,
+    // whether to return a `TimestampedUUID` with the time embedded in the UUID
+    with_timestamp: bool,
 }
 
 impl BuildValidator for UuidValidator {
@@ -85,10 +127,25 @@ impl BuildValidator for UuidValidator {
     ) -> PyResult<Arc<CombinedValidator>> {
         let py = schema.py();
         // Note(lig): let's keep this conversion through the Version enum just for the sake of validation
-        let version = schema.get_as::<u8>(intern!(py, "version"))?.map(Version::from);
+        let version = schema
+            .get_as::<u8>(intern!(py, "version"))?
+            .map(Version::try_from)
+            .transpose()?;
+        let timestamp_ge = TimestampBound::from_schema(schema, intern!(py, "timestamp_ge"))?;
+        let timestamp_lt = TimestampBound::from_schema(schema, intern!(py, "timestamp_lt"))?;
+        let with_timestamp = schema.get_as(intern!(py, "with_timestamp"))?.unwrap_or(false);
+        let uses_timestamp = timestamp_ge.is_some() || timestamp_lt.is_some() || with_timestamp;
+        if uses_timestamp && !matches!(version, Some(Version::UUIDv1 | Version::UUIDv6 | Version::UUIDv7)) {
+            return py_schema_err!(
+                "`timestamp_ge`, `timestamp_lt` and `with_timestamp` require a time-based `version`, 1, 6 or 7"
+            );
+        }
         Ok(CombinedValidator::Uuid(Self {
             strict: is_strict(schema, config)?,
             version: version.map(usize::from),
+            timestamp_ge,
+            timestamp_lt,
+            with_timestamp,
         })
         .into())
     }
@@ -120,6 +177,13 @@ impl Validator for UuidValidator {
                     ));
                 }
             }
+            if self.uses_timestamp() {
+                let uuid = Uuid::from_u128(py_input.getattr(intern!(py, UUID_INT))?.extract()?);
+                let timestamp = self.check_timestamp(&uuid, input)?;
+                if self.with_timestamp && !py_input.is_instance(get_timestamped_uuid_type(py)?)? {
+                    return self.create_py_timestamped_uuid(py, &uuid, timestamp);
+                }
+            }
             Ok(py_input.clone().unbind())
         } else if state.strict_or(self.strict) && state.extra().input_type == InputType::Python {
             Err(ValError::new(
@@ -154,10 +218,23 @@ impl Validator for UuidValidator {
                     ));
                 }
             }
-            self.create_py_uuid(class, &uuid)
+            let timestamp = self.check_timestamp(&uuid, input)?;
+            if self.with_timestamp {
+                self.create_py_timestamped_uuid(py, &uuid, timestamp)
+            } else {
+                self.create_py_uuid(class, &uuid)
+            }
         }
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        // a random UUID of the required version, version 4 if there isn't one
+        let version = self.version.unwrap_or(4) as u128;
+        let random = u128::from(generator.next_u64()) << 64 | u128::from(generator.next_u64());
+        let int = (random & !(0xF << 76) & !(0b11 << 62)) | version << 76 | 0b10 << 62;
+        get_uuid_type(py)?.call1((Uuid::from_u128(int).to_string(),))
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -220,6 +297,54 @@ impl UuidValidator {
         Ok(uuid)
     }
 
+    fn uses_timestamp(&self) -> bool {
+        self.timestamp_ge.is_some() || self.timestamp_lt.is_some() || self.with_timestamp
+    }
+
+    /// Check the time embedded in a UUID of the expected version against `timestamp_ge` and `timestamp_lt`,
+    /// returning it in microseconds since the Unix epoch.
+    fn check_timestamp<'py>(&self, uuid: &Uuid, input: &(impl Input<'py> + ?Sized)) -> ValResult<i64> {
+        let Some(timestamp) = uuid.get_timestamp() else {
+            return Ok(0);
+        };
+        let (seconds, nanos) = timestamp.to_unix();
+        let micros = seconds as i64 * 1_000_000 + i64::from(nanos / 1000);
+        if let Some(ge) = &self.timestamp_ge {
+            if micros < ge.micros {
+                return Err(ValError::new(
+                    ErrorType::UuidTimestampGreaterThanEqual {
+                        ge: ge.display.clone(),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(lt) = &self.timestamp_lt {
+            if micros >= lt.micros {
+                return Err(ValError::new(
+                    ErrorType::UuidTimestampLessThan {
+                        lt: lt.display.clone(),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        Ok(micros)
+    }
+
+    /// Like `create_py_uuid`, but creates a `TimestampedUUID` with its `timestamp` set to an aware UTC datetime.
+    fn create_py_timestamped_uuid(&self, py: Python<'_>, uuid: &Uuid, micros: i64) -> ValResult<Py<PyAny>> {
+        let py_uuid = self
+            .create_py_uuid(get_timestamped_uuid_type(py)?, uuid)?
+            .into_bound(py);
+        let timestamp =
+            PyDateTime::from_timestamp(py, micros as f64 / 1_000_000.0, Some(&PyTzInfo::utc(py)?.to_owned()))?;
+        force_setattr(py, &py_uuid, intern!(py, "timestamp"), timestamp)?;
+        Ok(py_uuid.unbind())
+    }
+
     /// Sets the attributes in a Python type object (`py_type`) to represent a UUID class.
     /// The function creates the python class and converts the UUID to a u128 integer and
     /// sets the corresponding attributes in the dictionary object to the converted value
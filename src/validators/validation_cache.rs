@@ -0,0 +1,152 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyFrozenSet, PyInt, PyString, PyTuple};
+use pyo3::{intern, PyTraverseError, PyVisit};
+
+use crate::build_tools::py_schema_err;
+use crate::py_gc::PyGcTraverse;
+
+use super::CombinedValidator;
+
+/// Validated instances of a frozen model, keyed by the input they were validated from, see the
+/// `validation_cache_size` config.
+#[derive(Debug)]
+pub(super) struct ValidationCache {
+    // keyed by `(input kind, options, typed input)`, see `typed_key`, in the order the entries were added
+    entries: Py<PyDict>,
+    max_size: usize,
+}
+
+impl ValidationCache {
+    pub fn new(py: Python<'_>, validator: &CombinedValidator, max_size: usize) -> PyResult<Self> {
+        if !is_frozen_model(py, validator)? {
+            return py_schema_err!("`validation_cache_size` can only be used with frozen models");
+        }
+        Ok(Self {
+            entries: PyDict::new(py).unbind(),
+            max_size,
+        })
+    }
+
+    /// Look up the output of validating `input` with the options in `options_key`, only immutable inputs are
+    /// cached, see `typed_key`.
+    pub fn lookup<'a, 'py>(
+        &'a self,
+        input_kind: &Bound<'py, PyString>,
+        options_key: &str,
+        input: &Bound<'py, PyAny>,
+    ) -> PyResult<CacheLookup<'a, 'py>> {
+        let py = input.py();
+        let Ok(typed_key) = typed_key(input) else {
+            return Ok(CacheLookup::Skip);
+        };
+        let key = PyTuple::new(
+            py,
+            [input_kind.as_any(), PyString::new(py, options_key).as_any(), &typed_key],
+        )?;
+        match self.entries.bind(input.py()).get_item(&key)? {
+            Some(output) => Ok(CacheLookup::Hit(output.unbind())),
+            None => Ok(CacheLookup::Miss(self, key)),
+        }
+    }
+
+    pub fn json_kind(py: Python<'_>) -> &Bound<'_, PyString> {
+        intern!(py, "json")
+    }
+
+    pub fn python_kind(py: Python<'_>) -> &Bound<'_, PyString> {
+        intern!(py, "python")
+    }
+
+    /// Add an entry, dropping the oldest entry if the cache is full.
+    fn insert(&self, key: &Bound<'_, PyTuple>, value: &Py<PyAny>) -> PyResult<()> {
+        let entries = self.entries.bind(key.py());
+        if entries.len() >= self.max_size {
+            if let Some((oldest, _)) = entries.iter().next() {
+                entries.del_item(oldest)?;
+            }
+        }
+        entries.set_item(key, value)
+    }
+
+    pub fn clear(&self, py: Python<'_>) {
+        self.entries.bind(py).clear();
+    }
+}
+
+/// The result of looking up an input in a `ValidationCache`.
+pub(super) enum CacheLookup<'a, 'py> {
+    Hit(Py<PyAny>),
+    Miss(&'a ValidationCache, Bound<'py, PyTuple>),
+    // the input isn't cached, e.g. because it's mutable or validation options were passed
+    Skip,
+}
+
+impl CacheLookup<'_, '_> {
+    /// Cache `output` if the input was missing from the cache, returning the output.
+    pub fn insert(self, output: Py<PyAny>) -> PyResult<Py<PyAny>> {
+        if let Self::Miss(cache, key) = self {
+            cache.insert(&key, &output)?;
+        }
+        Ok(output)
+    }
+}
+
+impl PyGcTraverse for ValidationCache {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.entries)
+    }
+}
+
+/// `input` with the type of every value it's made of, so equal inputs of different types, e.g. `1` and `True` or
+/// `(1,)` and `(True,)`, aren't confused; the items of tuples and frozensets are keyed the same way, and the sign of
+/// floats is kept as `0.0 == -0.0`.
+///
+/// Only `str`, `bytes`, `int`, `float`, `bool` and `None`, and tuples and frozensets of them, can be keys, other
+/// objects may change after they're cached, e.g. the attributes read with `from_attributes`, so are an error.
+fn typed_key<'py>(input: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let py = input.py();
+    let value = if let Ok(tuple) = input.downcast_exact::<PyTuple>() {
+        PyTuple::new(
+            py,
+            tuple
+                .iter()
+                .map(|item| typed_key(&item))
+                .collect::<PyResult<Vec<_>>>()?,
+        )?
+        .into_any()
+    } else if let Ok(frozenset) = input.downcast_exact::<PyFrozenSet>() {
+        PyFrozenSet::new(
+            py,
+            frozenset
+                .iter()
+                .map(|item| typed_key(&item))
+                .collect::<PyResult<Vec<_>>>()?,
+        )?
+        .into_any()
+    } else if let Ok(float) = input.downcast_exact::<PyFloat>() {
+        (float.value(), float.value().is_sign_negative())
+            .into_pyobject(py)?
+            .into_any()
+    } else if input.is_exact_instance_of::<PyString>()
+        || input.is_exact_instance_of::<PyBytes>()
+        || input.is_exact_instance_of::<PyInt>()
+        || input.is_exact_instance_of::<PyBool>()
+        || input.is_none()
+    {
+        input.clone()
+    } else {
+        return Err(PyTypeError::new_err("only immutable values can be cached"));
+    };
+    Ok(PyTuple::new(py, [input.get_type().into_any(), value])?.into_any())
+}
+
+fn is_frozen_model(py: Python<'_>, validator: &CombinedValidator) -> PyResult<bool> {
+    match validator {
+        CombinedValidator::Model(model) => Ok(model.is_frozen()),
+        CombinedValidator::DefinitionRef(definition_ref) => {
+            definition_ref.read_definition(py, |validator| is_frozen_model(py, validator))?
+        }
+        _ => Ok(false),
+    }
+}
@@ -0,0 +1,97 @@
+use std::num::NonZeroUsize;
+
+use jiter::PartialMode;
+use pyo3::prelude::*;
+
+use crate::build_tools::ExtraBehavior;
+
+use super::{StrictScope, UnionMode};
+
+/// The options of one validation, set by the keyword arguments of `validate_python`, `validate_json` and
+/// `validate_strings`, the defaults are those of the keyword arguments.
+#[derive(Debug, Clone)]
+pub struct ValidationOptions<'a, 'py> {
+    pub strict: Option<bool>,
+    pub strict_scope: StrictScope,
+    pub extra_behavior: Option<ExtraBehavior>,
+    pub from_attributes: Option<bool>,
+    pub context: Option<&'a Bound<'py, PyAny>>,
+    pub self_instance: Option<&'a Bound<'py, PyAny>>,
+    pub allow_partial: PartialMode,
+    pub by_alias: Option<bool>,
+    pub by_name: Option<bool>,
+    // `None` uses `max_errors` of the config
+    pub max_errors: Option<NonZeroUsize>,
+    pub fail_fast: bool,
+    pub trace: bool,
+    pub exactness: bool,
+    pub partial_report: bool,
+    pub partial_update: bool,
+    pub on_extra_ignored: Option<&'a Bound<'py, PyAny>>,
+    pub provenance: bool,
+    pub union_mode: Option<UnionMode>,
+    pub reference_time: Option<&'a Bound<'py, PyAny>>,
+    pub identity_cache: bool,
+    pub timeout_ms: Option<u64>,
+}
+
+impl Default for ValidationOptions<'_, '_> {
+    fn default() -> Self {
+        Self {
+            strict: None,
+            strict_scope: StrictScope::Recursive,
+            extra_behavior: None,
+            from_attributes: None,
+            context: None,
+            self_instance: None,
+            allow_partial: PartialMode::Off,
+            by_alias: None,
+            by_name: None,
+            max_errors: None,
+            fail_fast: false,
+            trace: false,
+            exactness: false,
+            partial_report: false,
+            partial_update: false,
+            on_extra_ignored: None,
+            provenance: false,
+            union_mode: None,
+            reference_time: None,
+            identity_cache: false,
+            timeout_ms: None,
+        }
+    }
+}
+
+impl ValidationOptions<'_, '_> {
+    /// The options which change the output, as part of the key of cached outputs, `None` if the output can't be
+    /// cached with these options, e.g. because of a `context` or a `trace`. Options only limiting how errors are
+    /// collected, or how long validation takes, don't count.
+    pub fn cache_key(&self) -> Option<String> {
+        let cacheable = self.context.is_none()
+            && self.self_instance.is_none()
+            && matches!(self.allow_partial, PartialMode::Off)
+            && !self.trace
+            && !self.exactness
+            && !self.partial_report
+            && !self.partial_update
+            && self.on_extra_ignored.is_none()
+            && !self.provenance
+            && self.reference_time.is_none()
+            && !self.identity_cache;
+        cacheable.then(|| {
+            format!(
+                "{:?}",
+                (
+                    self.strict,
+                    self.strict.map(|_| self.strict_scope),
+                    self.extra_behavior,
+                    self.from_attributes,
+                    self.by_alias,
+                    self.by_name,
+                    self.union_mode,
+                )
+            )
+        })
+    }
+}
@@ -1,15 +1,27 @@
+use std::num::NonZeroUsize;
 use std::ops::{Deref, DerefMut};
+use std::time::Instant;
 
+use pyo3::exceptions::PyTimeoutError;
 use pyo3::prelude::*;
-use pyo3::types::PyString;
+use pyo3::types::{PyDict, PyString};
 
 use jiter::{PartialMode, StringCacheMode};
+use speedate::DateTime;
 
 use crate::build_tools::ExtraBehavior;
+use crate::errors::{LocItem, Location, ValError, ValLineError, ValResult};
 use crate::recursion_guard::{ContainsRecursionState, RecursionState};
 use crate::tools::new_py_string;
 
-use super::Extra;
+use super::explain::Explanation;
+use super::ignored_extras::IgnoredExtras;
+use super::incremental::ResumeNode;
+use super::instance_cache::InstanceCache;
+use super::partial_report::PartialOmission;
+use super::provenance::Provenance;
+use super::union::{UnionMode, UnionTrace};
+use super::{Extra, StrictScope};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
 pub enum Exactness {
@@ -35,6 +47,43 @@ pub struct ValidationState<'a, 'py> {
     // TODO: this should probably be moved directly into the structured types which need it, but that
     // requires some refactoring to make them have knowledge of default (factories).
     pub has_field_error: bool,
+    // The maximum number of errors to collect, once reached collections stop validating further items.
+    pub max_errors: Option<NonZeroUsize>,
+    // Whether all collections should stop validating after the first error, set by `fail_fast` at validation time.
+    pub fail_fast: bool,
+    // Whether missing fields of models are left out instead of being errors or getting defaults, set by
+    // `partial_update` at validation time. Cleared within a model's fields, so nested models are validated in full.
+    pub partial_update: bool,
+    // Traces of the unions validated so far, only collected when validating with `trace=True`.
+    pub union_trace: Option<Vec<UnionTrace>>,
+    // Coercions and defaults found so far, only collected by `SchemaValidator.explain`.
+    pub explanation: Option<Explanation>,
+    // Outputs of complete items to reuse, only set by `SchemaValidator.incremental_json`, taken by the
+    // array or object validator it's for.
+    pub resume: Option<ResumeNode>,
+    // Items left out of the output by `allow_partial` so far, only collected when validating with
+    // `partial_report=True`.
+    pub partial_omissions: Option<Vec<PartialOmission>>,
+    // Reports extra items dropped by `extra_behavior='ignore'`, only set when validating with `on_extra_ignored`.
+    pub ignored_extras: Option<IgnoredExtras<'py>>,
+    // Where the values of fields came from, only collected when validating with `provenance=True`.
+    pub provenance: Option<Provenance>,
+    // Overrides the `mode` of every `union` schema, set by `union_mode` at validation time.
+    pub union_mode: Option<UnionMode>,
+    // The time `now_op` constraints compare with and its UTC offset, set by `reference_time` at validation time.
+    pub reference_time: Option<(DateTime, Option<i32>)>,
+    // Outputs of model instances revalidated so far, only collected when validating with `identity_cache=True`.
+    pub instance_cache: Option<InstanceCache>,
+    // The location being validated, from the outermost, for `ValidationInfo.path`, only tracked with the
+    // `validation_info_path` config.
+    pub path: Option<Vec<LocItem>>,
+    // The scratch context validator functions can write to as `ValidationInfo.scratch`, created when the first
+    // `ValidationInfo` is.
+    pub scratch: Option<Py<PyDict>>,
+    // When validation is abandoned, set by `timeout_ms` at validation time, see `check_deadline`.
+    pub deadline: Option<Instant>,
+    // Whether validation was abandoned because the deadline passed.
+    pub timed_out: bool,
     // deliberately make Extra readonly
     extra: Extra<'a, 'py>,
 }
@@ -47,10 +96,114 @@ impl<'a, 'py> ValidationState<'a, 'py> {
             fields_set_count: None,
             allow_partial,
             has_field_error: false,
+            max_errors: None,
+            fail_fast: false,
+            partial_update: false,
+            union_trace: None,
+            explanation: None,
+            resume: None,
+            partial_omissions: None,
+            ignored_extras: None,
+            provenance: None,
+            union_mode: None,
+            reference_time: None,
+            instance_cache: None,
+            path: None,
+            scratch: None,
+            deadline: None,
+            timed_out: false,
             extra,
         }
     }
 
+    /// Abandon validation once the deadline has passed, checked for each item of a container, each field and each
+    /// union choice, and before recursing into a definition.
+    ///
+    /// The error is internal so it isn't caught by unions or collected with other errors, it's replaced by a
+    /// `validation_timeout` error once it reaches `SchemaValidator`, whatever the validation returns. Validator
+    /// functions can still catch it, e.g. from their handler, so once the deadline has passed every later check fails
+    /// too.
+    pub fn check_deadline(&mut self) -> ValResult<()> {
+        match self.deadline {
+            Some(deadline) if self.timed_out || Instant::now() >= deadline => {
+                self.timed_out = true;
+                Err(ValError::InternalErr(PyTimeoutError::new_err(
+                    "Validation took longer than `timeout_ms`",
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Validate the item at `loc` within the location being validated with `validate`, `loc` is only called when
+    /// the path is tracked.
+    pub fn with_path_item<I: Into<LocItem>, R>(
+        &mut self,
+        loc: impl FnOnce() -> I,
+        validate: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        match self.path.as_mut() {
+            Some(path) => {
+                let depth = path.len();
+                path.push(loc().into());
+                self.with_path_depth(depth, validate)
+            }
+            None => validate(self),
+        }
+    }
+
+    /// Like `with_path_item`, for the location of a field found by a lookup path, which can have several items.
+    pub fn with_path_location<R>(
+        &mut self,
+        loc: impl FnOnce() -> Location,
+        validate: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        match self.path.as_mut() {
+            Some(path) => {
+                let depth = path.len();
+                if let Location::List(items) = loc() {
+                    // locations are reversed
+                    path.extend(items.into_iter().rev());
+                }
+                self.with_path_depth(depth, validate)
+            }
+            None => validate(self),
+        }
+    }
+
+    /// The scratch context, created if this is the first time it's used.
+    pub fn scratch<'s>(&'s mut self, py: Python<'py>) -> &'s Py<PyDict> {
+        self.scratch.get_or_insert_with(|| PyDict::new(py).unbind())
+    }
+
+    /// A copy of the scratch context, to roll back the writes made by a union choice which isn't used.
+    pub fn scratch_mark(&self, py: Python<'py>) -> PyResult<Option<Py<PyDict>>> {
+        self.scratch
+            .as_ref()
+            .map(|scratch| scratch.bind(py).copy().map(Bound::unbind))
+            .transpose()
+    }
+
+    /// Restore the scratch context to a copy from `scratch_mark`, in place, as validator functions may keep it.
+    pub fn restore_scratch(&mut self, py: Python<'py>, mark: Option<&Py<PyDict>>) -> PyResult<()> {
+        if let Some(scratch) = &self.scratch {
+            let scratch = scratch.bind(py);
+            scratch.clear();
+            if let Some(mark) = mark {
+                scratch.update(mark.bind(py).as_mapping())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn with_path_depth<R>(&mut self, depth: usize, validate: impl FnOnce(&mut Self) -> R) -> R {
+        let result = validate(self);
+        if let Some(path) = self.path.as_mut() {
+            path.truncate(depth);
+        }
+        result
+    }
+
     /// Temporarily rebinds the extra field by calling `f` to modify extra.
     ///
     /// When `ValidationStateWithReboundExtra` drops, the extra field is restored to its original value.
@@ -91,6 +244,31 @@ impl<'a, 'py> ValidationState<'a, 'py> {
         EnumerateLastPartial::new(iter, self.allow_partial)
     }
 
+    /// Call `f` on entering a model or dataclass, the validation time `strict` setting is cleared for
+    /// models nested inside the outermost one if `strict_scope='top-level'` was used.
+    pub fn with_model_strict_scope<R>(&mut self, f: impl FnOnce(&mut ValidationState<'a, 'py>) -> R) -> R {
+        match self.extra.strict_scope {
+            StrictScope::TopLevel => f(&mut self.rebind_extra(|extra| extra.strict_scope = StrictScope::Nested)),
+            StrictScope::Nested if self.extra.strict.is_some() => {
+                f(&mut self.rebind_extra(|extra| extra.strict = None))
+            }
+            StrictScope::Recursive | StrictScope::Nested => f(self),
+        }
+    }
+
+    /// Whether a collection should stop validating further items, either because `fail_fast` is set (on the
+    /// collection's schema or for the whole validation) and an error has occurred, or because more than
+    /// `max_errors` errors have been collected. One error beyond the limit is collected so it's known that
+    /// errors were left out when the errors are truncated to `max_errors`.
+    pub fn stop_collecting(&self, fail_fast: bool, errors: &[ValLineError]) -> bool {
+        !errors.is_empty()
+            && (fail_fast
+                || self.fail_fast
+                || self
+                    .max_errors
+                    .is_some_and(|max_errors| errors.len() > max_errors.get()))
+    }
+
     pub fn strict_or(&self, default: bool) -> bool {
         self.extra.strict.unwrap_or(default)
     }
@@ -1,16 +1,23 @@
-use std::sync::Arc;
+use std::ffi::CString;
+use std::sync::{Arc, Mutex, PoisonError};
 
+use pyo3::exceptions::PyUserWarning;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::sync::PyOnceLock;
 use pyo3::types::PyDict;
 use pyo3::types::PyString;
 use pyo3::PyTraverseError;
+use pyo3::PyTypeInfo;
 use pyo3::PyVisit;
 
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ExampleGenerator, ValidationState,
+    Validator,
+};
 use crate::build_tools::py_schema_err;
 use crate::build_tools::schema_or_config_same;
+use crate::definitions::CompositeName;
 use crate::errors::{ErrorTypeDefaults, LocItem, ValError, ValResult};
 use crate::input::Input;
 use crate::py_gc::PyGcTraverse;
@@ -86,6 +93,78 @@ enum OnError {
     Default,
 }
 
+/// How a default which is the same mutable object as a default returned before is reported, from the
+/// `shared_defaults_check` config, e.g. a `default_factory` which returns a module-level list every time.
+#[derive(Debug)]
+struct SharedDefaultsCheck {
+    error: bool,
+    // the last unhashable default returned, kept so the next one can be compared with it by identity
+    last: Mutex<Option<Py<PyAny>>>,
+}
+
+impl SharedDefaultsCheck {
+    fn from_config(config: Option<&Bound<'_, PyDict>>) -> PyResult<Option<Self>> {
+        let Some(config) = config else {
+            return Ok(None);
+        };
+        let error = match config
+            .get_as::<String>(intern!(config.py(), "shared_defaults_check"))?
+            .as_deref()
+        {
+            None => return Ok(None),
+            Some("warn") => false,
+            Some("error") => true,
+            Some(other) => {
+                return py_schema_err!("Invalid shared_defaults_check: `{}`, expected 'warn' or 'error'", other);
+            }
+        };
+        Ok(Some(Self {
+            error,
+            last: Mutex::new(None),
+        }))
+    }
+
+    fn check(&self, default: &Bound<'_, PyAny>) -> ValResult<()> {
+        // hashable defaults, e.g. `0` or `None`, are assumed to be immutable and can be shared safely
+        if default.hash().is_ok() {
+            return Ok(());
+        }
+        let previous = self
+            .last
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .replace(default.clone().unbind());
+        // the previous default is dropped after the lock is released, since dropping it could run Python code
+        if !previous.is_some_and(|previous| previous.is(default)) {
+            Ok(())
+        } else if self.error {
+            Err(ValError::new(ErrorTypeDefaults::DefaultShared, default))
+        } else {
+            Ok(warn_shared(default)?)
+        }
+    }
+}
+
+fn warn_shared(default: &Bound<'_, PyAny>) -> PyResult<()> {
+    let py = default.py();
+    let message = format!(
+        "Default value of type `{}` is the same object as a default returned before, results share it",
+        default.get_type().qualname()?
+    );
+    PyErr::warn(py, &PyUserWarning::type_object(py), &CString::new(message)?, 0)
+}
+
+impl PyGcTraverse for SharedDefaultsCheck {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        if let Ok(last) = self.last.try_lock() {
+            if let Some(last) = last.as_ref() {
+                visit.call(last)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub struct WithDefaultValidator {
     default: DefaultType,
@@ -93,7 +172,8 @@ pub struct WithDefaultValidator {
     validator: Arc<CombinedValidator>,
     validate_default: bool,
     copy_default: bool,
-    name: String,
+    shared_defaults_check: Option<SharedDefaultsCheck>,
+    name: CompositeName,
     undefined: Py<PyAny>,
 }
 
@@ -134,22 +214,25 @@ impl BuildValidator for WithDefaultValidator {
             false
         };
 
-        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
-
         Ok(CombinedValidator::WithDefault(Self {
             default,
             on_error,
             validator,
             validate_default: schema_or_config_same(schema, config, intern!(py, "validate_default"))?.unwrap_or(false),
             copy_default,
-            name,
+            shared_defaults_check: SharedDefaultsCheck::from_config(config)?,
+            name: CompositeName::new(),
             undefined: PydanticUndefinedType::get(py).clone_ref(schema.py()).into_any(),
         })
         .into())
     }
 }
 
-impl_py_gc_traverse!(WithDefaultValidator { default, validator });
+impl_py_gc_traverse!(WithDefaultValidator {
+    default,
+    validator,
+    shared_defaults_check
+});
 
 impl Validator for WithDefaultValidator {
     fn validate<'py>(
@@ -201,31 +284,61 @@ impl Validator for WithDefaultValidator {
                 } else {
                     stored_dft
                 };
-                if self.validate_default {
-                    match self.validate(py, dft.bind(py), state) {
-                        Ok(v) => Ok(Some(v)),
-                        Err(e) => {
-                            if let Some(outer_loc) = outer_loc {
-                                Err(e.with_outer_location(outer_loc))
-                            } else {
-                                Err(e)
-                            }
+                let result = if self.validate_default {
+                    self.validate(py, dft.bind(py), state)
+                } else {
+                    Ok(dft)
+                };
+                // the result is checked rather than the default, since validating it may return a new object
+                let result = result.and_then(|v| match &self.shared_defaults_check {
+                    Some(check) => check.check(v.bind(py)).map(|()| v),
+                    None => Ok(v),
+                });
+                match result {
+                    Ok(v) => Ok(Some(v)),
+                    Err(e) => {
+                        if let Some(outer_loc) = outer_loc {
+                            Err(e.with_outer_location(outer_loc))
+                        } else {
+                            Err(e)
                         }
                     }
-                } else {
-                    Ok(Some(dft))
                 }
             }
             None => Ok(None),
         }
     }
 
+    fn example<'py>(&self, py: Python<'py>, generator: &mut ExampleGenerator) -> PyResult<Bound<'py, PyAny>> {
+        self.validator.example(py, generator)
+    }
+
     fn get_name(&self) -> &str {
-        &self.name
+        self.name
+            .get_or_init(|| format!("{}[{}]", Self::EXPECTED_TYPE, self.validator.get_name()))
     }
 }
 
 impl WithDefaultValidator {
+    /// Wrap the validator of a `const` schema, which defaults to its `value`.
+    pub(super) fn with_const_default(
+        value: &Bound<'_, PyAny>,
+        validator: Arc<CombinedValidator>,
+    ) -> Arc<CombinedValidator> {
+        let py = value.py();
+        CombinedValidator::WithDefault(Self {
+            default: DefaultType::Default(value.clone().unbind()),
+            on_error: OnError::Raise,
+            copy_default: value.hash().is_err(),
+            shared_defaults_check: None,
+            validate_default: false,
+            name: CompositeName::known(validator.get_name().to_string()),
+            validator,
+            undefined: PydanticUndefinedType::get(py).clone_ref(py).into_any(),
+        })
+        .into()
+    }
+
     pub fn has_default(&self) -> bool {
         !matches!(self.default, DefaultType::None)
     }
@@ -233,4 +346,23 @@ impl WithDefaultValidator {
     pub fn omit_on_error(&self) -> bool {
         matches!(self.on_error, OnError::Omit)
     }
+
+    pub(super) fn validator(&self) -> &CombinedValidator {
+        &self.validator
+    }
+
+    pub(super) fn default(&self) -> &DefaultType {
+        &self.default
+    }
+
+    /// The default, without validating it, for constructing an instance from `data` without validation.
+    pub(super) fn construct_default(&self, py: Python<'_>, data: &Bound<'_, PyDict>) -> PyResult<Option<Py<PyAny>>> {
+        match self.default.default_value(py, Some(data))? {
+            Some(default) if self.copy_default => {
+                let deepcopy_func = COPY_DEEPCOPY.get_or_init(py, || get_deepcopy(py).unwrap());
+                deepcopy_func.call1(py, (&default,)).map(Some)
+            }
+            default => Ok(default),
+        }
+    }
 }
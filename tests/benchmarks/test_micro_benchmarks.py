@@ -6,6 +6,7 @@ import decimal
 import json
 import platform
 import sys
+from collections import namedtuple
 from datetime import date, datetime, timedelta, timezone
 from decimal import Decimal
 from enum import Enum
@@ -1489,3 +1490,20 @@ def test_multi_host_url_core(benchmark):
     v = SchemaValidator(core_schema.multi_host_url_schema())
 
     benchmark(v.validate_python, 'https://example.com,b:2@example.org:777/some/path?query=string#fragment')
+
+
+@pytest.mark.benchmark(group='namedtuple')
+def test_namedtuple_core(benchmark):
+    Point = namedtuple('Point', ['x', 'y', 'z'])
+    v = SchemaValidator(
+        core_schema.call_schema(
+            core_schema.arguments_schema(
+                [core_schema.arguments_parameter(name, core_schema.float_schema()) for name in Point._fields]
+            ),
+            Point,
+        )
+    )
+
+    assert v.validate_python((1, 2, 3)) == Point(1.0, 2.0, 3.0)
+
+    benchmark(v.validate_python, (1, 2, 3))
@@ -22,6 +22,7 @@ from pydantic_core import (
     SchemaValidator,
     core_schema,
     to_json,
+    to_jsonable_python,
 )
 
 from ..conftest import plain_repr
@@ -335,6 +336,58 @@ def test_unknown_type_fallback(any_serializer: SchemaSerializer):
     assert any_serializer.to_json(f, fallback=fallback_func) == b'"fallback:<Foobar repr>"'
 
 
+class Spam:
+    pass
+
+
+def test_fallback_chain(any_serializer: SchemaSerializer):
+    calls = []
+
+    def foobar_fallback(obj):
+        calls.append('foobar')
+        if not isinstance(obj, Foobar):
+            raise NotImplementedError
+        return 'foobar'
+
+    def spam_fallback(obj):
+        calls.append('spam')
+        if not isinstance(obj, Spam):
+            raise NotImplementedError
+        return 'spam'
+
+    fallbacks = [foobar_fallback, spam_fallback]
+    value = {'f': Foobar(), 's': Spam()}
+    assert any_serializer.to_python(value, mode='json', fallback=fallbacks) == {'f': 'foobar', 's': 'spam'}
+    assert calls == ['foobar', 'foobar', 'spam']
+    assert any_serializer.to_python(value, fallback=fallbacks) == {'f': 'foobar', 's': 'spam'}
+    assert any_serializer.to_json(value, fallback=tuple(fallbacks)) == b'{"f":"foobar","s":"spam"}'
+    assert to_jsonable_python(value, fallback=fallbacks) == {'f': 'foobar', 's': 'spam'}
+    assert to_json({Spam(): 1}, fallback=fallbacks) == b'{"spam":1}'
+
+
+def test_fallback_chain_declined(any_serializer: SchemaSerializer):
+    def decline(obj):
+        raise NotImplementedError
+
+    f = Foobar()
+    # when every fallback declines, the value is treated as if there were no fallback
+    assert any_serializer.to_python(f, fallback=[decline]) is f
+    with pytest.raises(PydanticSerializationError, match="Unable to serialize unknown type: <class '.+Foobar'>"):
+        any_serializer.to_json(f, fallback=[decline, decline])
+    assert to_jsonable_python(f, fallback=[decline], serialize_unknown=True) == '<Foobar repr>'
+
+    # other errors aren't caught
+    def error(obj):
+        raise ValueError('fallback error')
+
+    with pytest.raises(ValueError, match='fallback error'):
+        any_serializer.to_python(f, mode='json', fallback=[error, decline])
+
+    # a single function raising `NotImplementedError` isn't a chain
+    with pytest.raises(NotImplementedError):
+        any_serializer.to_python(f, mode='json', fallback=decline)
+
+
 def test_fallback_cycle_same(any_serializer: SchemaSerializer):
     def fallback_func(obj):
         return obj
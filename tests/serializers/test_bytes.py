@@ -4,7 +4,7 @@ from enum import Enum
 
 import pytest
 
-from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema, to_json
+from pydantic_core import PydanticSerializationError, SchemaError, SchemaSerializer, core_schema, to_json
 
 
 def test_bytes():
@@ -172,3 +172,55 @@ def test_bytes_mode_set_via_model_config_not_serializer_config():
 
     assert to_json({'foo': b'some bytes'}, bytes_mode='base64') == b'{"foo":"c29tZSBieXRlcw=="}'
     assert to_json({'bar': bm}, bytes_mode='base64') == b'{"bar":{"foo":"Zm9vYmFy"}}'
+
+
+@pytest.mark.parametrize(
+    'encoding,input_value,expected',
+    [
+        ('base64', b'\xfb\xff\xbf', '+/+/'),
+        ('base64', b'ab', 'YWI='),
+        ('urlsafe-base64', b'\xfb\xff\xbf', '-_-_'),
+        ('urlsafe-base64', b'ab', 'YWI='),
+        ('base32', b'', ''),
+        ('base32', b'f', 'MY======'),
+        ('base32', b'foob', 'MZXW6YQ='),
+        ('base32', b'foobar', 'MZXW6YTBOI======'),
+        ('hex', b'\xab\xcd', 'abcd'),
+    ],
+)
+def test_bytes_format(encoding, input_value, expected):
+    s = SchemaSerializer(core_schema.bytes_schema(serialization=core_schema.bytes_format_ser_schema(encoding)))
+    assert s.to_python(input_value) == input_value
+    assert s.to_python(input_value, mode='json') == expected
+    assert s.to_json(input_value) == json.dumps(expected).encode()
+
+
+def test_bytes_format_per_field():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'digest': core_schema.typed_dict_field(
+                    core_schema.bytes_schema(serialization=core_schema.bytes_format_ser_schema('hex'))
+                ),
+                'blob': core_schema.typed_dict_field(
+                    core_schema.bytes_schema(serialization=core_schema.bytes_format_ser_schema('base64'))
+                ),
+                'other': core_schema.typed_dict_field(core_schema.bytes_schema()),
+            }
+        ),
+        {'ser_json_bytes': 'utf8'},
+    )
+    value = {'digest': b'\x01\xff', 'blob': b'\xfb\xff', 'other': b'plain'}
+    assert s.to_json(value) == b'{"digest":"01ff","blob":"+/8=","other":"plain"}'
+    assert s.to_python(value, mode='json') == {'digest': '01ff', 'blob': '+/8=', 'other': 'plain'}
+
+
+def test_bytes_format_dict_key():
+    bytes_schema = core_schema.bytes_schema(serialization=core_schema.bytes_format_ser_schema('base32'))
+    s = SchemaSerializer(core_schema.dict_schema(bytes_schema, core_schema.int_schema()))
+    assert s.to_json({b'foob': 1}) == b'{"MZXW6YQ=":1}'
+
+
+def test_bytes_format_invalid_encoding():
+    with pytest.raises(SchemaError, match="Invalid bytes encoding: `base85`, expected 'base64', 'urlsafe-base64'"):
+        SchemaSerializer(core_schema.bytes_schema(serialization={'type': 'bytes-format', 'encoding': 'base85'}))
@@ -2,7 +2,7 @@ from datetime import date, datetime, time, timedelta, timezone
 
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import PydanticSerializationError, SchemaError, SchemaSerializer, core_schema
 
 
 def test_datetime():
@@ -115,6 +115,47 @@ def test_date_datetime_union():
     assert v.to_json(datetime(2022, 12, 2, 1)) == b'"2022-12-02T01:00:00"'
 
 
+@pytest.mark.parametrize('schema', [core_schema.datetime_schema(), core_schema.any_schema()])
+def test_naive_datetime_assume_utc(schema):
+    s = SchemaSerializer(schema, config={'ser_naive_datetime': 'assume-utc'})
+    naive = datetime(2022, 12, 2, 12, 13, 14)
+    assert s.to_python(naive) == naive
+    assert s.to_python(naive, mode='json') == '2022-12-02T12:13:14Z'
+    assert s.to_json(naive) == b'"2022-12-02T12:13:14Z"'
+
+    aware = datetime(2022, 12, 2, 12, 13, 14, tzinfo=timezone(timedelta(hours=2)))
+    assert s.to_json(aware) == b'"2022-12-02T12:13:14+02:00"'
+
+
+def test_naive_datetime_assume_utc_key():
+    s = SchemaSerializer(
+        core_schema.dict_schema(core_schema.datetime_schema()), config={'ser_naive_datetime': 'assume-utc'}
+    )
+    assert s.to_json({datetime(2022, 12, 2, 12, 13, 14): 1}) == b'{"2022-12-02T12:13:14Z":1}'
+
+
+@pytest.mark.parametrize('schema', [core_schema.datetime_schema(), core_schema.any_schema()])
+def test_naive_datetime_error(schema):
+    s = SchemaSerializer(schema, config={'ser_naive_datetime': 'error'})
+    naive = datetime(2022, 12, 2, 12, 13, 14)
+    # python mode is unaffected
+    assert s.to_python(naive) == naive
+
+    msg = "Cannot serialize naive datetime `2022-12-02T12:13:14` when `ser_naive_datetime` is 'error'"
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_python(naive, mode='json')
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_json(naive)
+
+    aware = datetime(2022, 12, 2, 12, 13, 14, tzinfo=timezone.utc)
+    assert s.to_json(aware) == b'"2022-12-02T12:13:14Z"'
+
+
+def test_naive_datetime_invalid_mode():
+    with pytest.raises(SchemaError, match='Invalid NaiveDatetimeMode serialization mode: `foo`'):
+        SchemaSerializer(core_schema.datetime_schema(), config={'ser_naive_datetime': 'foo'})
+
+
 @pytest.mark.parametrize(
     'dt,expected_to_python,expected_to_json,expected_to_python_dict,expected_to_json_dict,mode',
     [
@@ -2,7 +2,7 @@ from decimal import Decimal
 
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import SchemaError, SchemaSerializer, core_schema
 
 
 def test_decimal():
@@ -61,3 +61,82 @@ def test_any_decimal_key():
 
     assert v.to_python(input_value, mode='json') == {'123.456': 1}
     assert v.to_json(input_value) == b'{"123.456":1}'
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (Decimal('1.50'), b'1.50'),
+        (Decimal('-0'), b'-0'),
+        (Decimal('1E+2'), b'1E+2'),
+        (Decimal('123456789123456789.123456789123456789'), b'123456789123456789.123456789123456789'),
+    ],
+)
+def test_decimal_number(value, expected):
+    s = SchemaSerializer(core_schema.decimal_schema(ser_mode='number'))
+    assert s.to_json(value) == expected
+    assert s.to_python(value) == value
+    assert s.to_python(value, mode='json') == float(value)
+
+
+@pytest.mark.parametrize(
+    'inf_nan_mode,expected',
+    [
+        ('null', b'[null,null,null]'),
+        ('constants', b'[Infinity,-Infinity,NaN]'),
+        ('strings', b'["Infinity","-Infinity","NaN"]'),
+    ],
+)
+def test_decimal_number_inf_nan(inf_nan_mode, expected):
+    s = SchemaSerializer(
+        core_schema.list_schema(core_schema.decimal_schema(ser_mode='number')), {'ser_json_inf_nan': inf_nan_mode}
+    )
+    assert s.to_json([Decimal('Infinity'), Decimal('-Infinity'), Decimal('NaN')]) == expected
+
+
+def test_decimal_number_allow_inf_nan():
+    s = SchemaSerializer(core_schema.decimal_schema(ser_mode='number', allow_inf_nan=True))
+    assert s.to_json(Decimal('NaN')) == b'NaN'
+    s = SchemaSerializer(core_schema.decimal_schema(ser_mode='number', allow_inf_nan=False))
+    assert s.to_json(Decimal('NaN')) == b'null'
+
+
+def test_decimal_ser_mode_per_field():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'price': core_schema.typed_dict_field(core_schema.decimal_schema(ser_mode='string')),
+                'ratio': core_schema.typed_dict_field(core_schema.decimal_schema(ser_mode='number')),
+            }
+        )
+    )
+    value = {'price': Decimal('9.99'), 'ratio': Decimal('0.125')}
+    assert s.to_json(value) == b'{"price":"9.99","ratio":0.125}'
+    assert s.to_python(value, mode='json') == {'price': '9.99', 'ratio': 0.125}
+
+
+def test_decimal_number_key():
+    s = SchemaSerializer(
+        core_schema.dict_schema(core_schema.decimal_schema(ser_mode='number'), core_schema.decimal_schema())
+    )
+    assert s.to_json({Decimal('1.5'): Decimal('2.5')}) == b'{"1.5":"2.5"}'
+
+
+def test_decimal_invalid_ser_mode():
+    with pytest.raises(SchemaError, match='Invalid decimal ser_mode: `float`, expected `string` or `number`'):
+        SchemaSerializer(core_schema.decimal_schema(ser_mode='float'))
+
+
+def test_decimal_rounding():
+    s = SchemaSerializer(core_schema.decimal_schema(decimal_places=2, rounding='ROUND_HALF_UP'))
+    assert s.to_python(Decimal('1.005')) == Decimal('1.01')
+    assert s.to_python(Decimal('1.005'), mode='json') == '1.01'
+    assert s.to_json(Decimal('1.005')) == b'"1.01"'
+    assert s.to_json(Decimal('1.5')) == b'"1.5"'
+
+
+def test_decimal_quantize_to():
+    s = SchemaSerializer(core_schema.decimal_schema(quantize_to=Decimal('0.01'), ser_mode='number'))
+    assert s.to_json(Decimal('1.5')) == b'1.50'
+    assert s.to_json(Decimal('2.345')) == b'2.34'
+    assert s.to_python(Decimal('1.5')) == Decimal('1.50')
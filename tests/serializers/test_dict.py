@@ -1,9 +1,11 @@
 import json
+from datetime import datetime, timezone
+from enum import Enum
 
 import pytest
 from dirty_equals import IsStrictDict
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
 
 
 def test_dict_str_int():
@@ -143,3 +145,66 @@ def test_filter_runtime_int():
         core_schema.dict_schema(core_schema.any_schema(), serialization=core_schema.filter_dict_schema(exclude={0, 1}))
     )
     assert s.to_python({0: 0, 1: 1, 2: 2, 3: 3}, include={1, 2}) == {1: 1, 2: 2}
+
+
+class Color(Enum):
+    RED = 'red'
+    BLUE = 'blue'
+
+
+def test_keys_ser_schema_enum():
+    s = SchemaSerializer(
+        core_schema.dict_schema(
+            core_schema.any_schema(),
+            core_schema.int_schema(),
+            keys_ser_schema=core_schema.enum_schema(Color, list(Color.__members__.values())),
+        )
+    )
+    assert s.to_json({Color.RED: 1, Color.BLUE: 2}) == b'{"red":1,"blue":2}'
+    assert s.to_python({Color.RED: 1}, mode='json') == {'red': 1}
+    assert s.to_python({Color.RED: 1}) == {Color.RED: 1}
+
+
+def test_keys_ser_schema_datetime():
+    s = SchemaSerializer(core_schema.dict_schema(keys_ser_schema=core_schema.datetime_schema()))
+    assert s.to_json({datetime(2024, 1, 1, tzinfo=timezone.utc): 1}) == b'{"2024-01-01T00:00:00Z":1}'
+
+    s = SchemaSerializer(
+        core_schema.dict_schema(keys_ser_schema=core_schema.datetime_schema()),
+        core_schema.CoreConfig(ser_json_temporal='seconds'),
+    )
+    assert s.to_json({datetime(2024, 1, 1, tzinfo=timezone.utc): 1}) == b'{"1704067200":1}'
+
+
+def test_keys_separator():
+    s = SchemaSerializer(
+        core_schema.dict_schema(
+            core_schema.tuple_schema([core_schema.str_schema(), core_schema.int_schema()]), keys_separator='|'
+        )
+    )
+    assert s.to_json({('a', 1): {('b', 2): 3}}) == b'{"a|1":{"b,2":3}}'
+    assert s.to_python({('a', 1): 1}, mode='json') == {'a|1': 1}
+    assert s.to_python({('a', 1): 1}) == {('a', 1): 1}
+
+    # the separator applies to inferred tuple keys too
+    s = SchemaSerializer(core_schema.dict_schema(keys_separator='/'))
+    assert s.to_json({(1, 'x', (2, 3)): 1}) == b'{"1/x/2/3":1}'
+
+
+def test_key_fallback():
+    schema = core_schema.dict_schema(keys_ser_schema=core_schema.int_schema())
+
+    s = SchemaSerializer(schema)
+    with pytest.warns(UserWarning, match='Expected `int`'):
+        assert s.to_json({'a': 1}) == b'{"a":1}'
+
+    s = SchemaSerializer(schema, core_schema.CoreConfig(ser_json_key_fallback='str'))
+    assert s.to_json({1: 1, 'a': 2, (1, 2): 3}) == b'{"1":1,"a":2,"(1, 2)":3}'
+    assert s.to_python({'a': 1}, mode='json') == {'a': 1}
+
+    s = SchemaSerializer(schema, core_schema.CoreConfig(ser_json_key_fallback='error'))
+    assert s.to_json({1: 1}) == b'{"1":1}'
+    with pytest.raises(PydanticSerializationError, match="Dict key 'a' doesn't match `int`"):
+        s.to_json({'a': 1})
+    with pytest.raises(PydanticSerializationError, match="Dict key 'a' doesn't match `int`"):
+        s.to_python({'a': 1}, mode='json')
@@ -0,0 +1,78 @@
+import hashlib
+import json
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
+
+
+def canonical_sha256(value) -> str:
+    data = json.dumps(value, sort_keys=True, separators=(',', ':'), ensure_ascii=False)
+    return hashlib.sha256(data.encode()).hexdigest()
+
+
+def test_hash_canonical():
+    s = SchemaSerializer(core_schema.any_schema())
+    value = {'b': [1, {'z': None, 'y': 'é'}], 'a': {'d': True, 'c': 1.5}}
+    assert s.hash(value) == canonical_sha256(value)
+    assert s.hash(value) == s.hash({'a': {'c': 1.5, 'd': True}, 'b': [1, {'y': 'é', 'z': None}]})
+    assert s.hash(value) != s.hash({**value, 'e': 1})
+
+
+@pytest.mark.parametrize('value,expected', [(1.0, 1), (-0.0, 0), ([3.0], [3]), ({'a': 2.0}, {'a': 2})])
+def test_hash_normalized_numbers(value, expected):
+    s = SchemaSerializer(core_schema.any_schema())
+    assert s.hash(value) == s.hash(expected)
+
+
+def test_hash_integral_float():
+    s = SchemaSerializer(core_schema.float_schema())
+    assert s.hash(3.0) == hashlib.sha256(b'3').hexdigest()
+    assert s.hash(1e20) == hashlib.sha256(b'1e20').hexdigest()
+
+
+def test_hash_algorithm():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.int_schema()))
+    assert s.hash([1, 2], algorithm='md5') == hashlib.md5(b'[1,2]').hexdigest()
+    assert s.hash([1, 2], algorithm='blake2b') == hashlib.blake2b(b'[1,2]').hexdigest()
+
+    with pytest.raises(ValueError, match='unsupported hash type'):
+        s.hash([1, 2], algorithm='not-an-algorithm')
+
+
+def test_hash_model():
+    class Model:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+        def __init__(self, **kwargs):
+            self.__dict__.update(kwargs)
+            self.__pydantic_extra__ = None
+            self.__pydantic_fields_set__ = set(kwargs)
+
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {
+                    'x': core_schema.model_field(core_schema.float_schema(), serialization_alias='X'),
+                    'y': core_schema.model_field(core_schema.str_schema()),
+                }
+            ),
+        )
+    )
+    m = Model(y='hello', x=1.0)
+    assert s.hash(m) == canonical_sha256({'x': 1, 'y': 'hello'})
+    assert s.hash(m, by_alias=True) == canonical_sha256({'X': 1, 'y': 'hello'})
+    assert s.hash(m, exclude={'y'}) == canonical_sha256({'x': 1})
+
+
+def test_hash_large():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.str_schema()))
+    value = [str(i) * 10 for i in range(20_000)]
+    assert s.hash(value) == canonical_sha256(value)
+
+
+def test_hash_unknown_value():
+    s = SchemaSerializer(core_schema.any_schema())
+    with pytest.raises(PydanticSerializationError, match='Unable to serialize unknown type'):
+        s.hash({'a': object()})
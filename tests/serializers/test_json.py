@@ -1,4 +1,19 @@
-from pydantic_core import SchemaSerializer, core_schema
+import gzip
+import re
+from io import BytesIO
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, capabilities, core_schema, to_json
+
+try:
+    import zstandard
+except ImportError:
+    zstandard = None
+
+# codecs can be left out of a build with cargo features
+requires_gzip = pytest.mark.skipif('gzip' not in capabilities()['compression'], reason='built without gzip')
+requires_zstd = pytest.mark.skipif('zstd' not in capabilities()['compression'], reason='built without zstd')
 
 
 def test_json_int():
@@ -45,3 +60,140 @@ def test_custom_serializer():
     assert s.to_python({1: 2}, mode='json', round_trip=True) == '{"1":2}'
     assert s.to_json({1: 2}) == b'{"1":2}'
     assert s.to_json({1: 2}, round_trip=True) == b'"{\\"1\\":2}"'
+
+
+def test_to_jsonl():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.str_schema()),
+            }
+        )
+    )
+    rows = [{'a': 1, 'b': 'x'}, {'a': 2, 'b': 'é'}]
+    assert s.to_jsonl(rows) == '{"a":1,"b":"x"}\n{"a":2,"b":"é"}\n'.encode()
+    assert s.to_jsonl(rows) == b''.join(s.to_json(row) + b'\n' for row in rows)
+    assert s.to_jsonl(rows, ensure_ascii=True) == b'{"a":1,"b":"x"}\n{"a":2,"b":"\\u00e9"}\n'
+    assert s.to_jsonl(rows, exclude={'b'}) == b'{"a":1}\n{"a":2}\n'
+    assert s.to_jsonl(iter(rows)) == s.to_jsonl(rows)
+    assert s.to_jsonl([]) == b''
+
+
+def test_to_jsonl_error():
+    s = SchemaSerializer(core_schema.int_schema())
+    with pytest.warns(UserWarning, match='Expected `int`'):
+        assert s.to_jsonl([1, 'a', 3]) == b'1\n"a"\n3\n'
+    with pytest.raises(PydanticSerializationError):
+        s.to_jsonl([1, 'a'], warnings='error')
+    with pytest.raises(TypeError, match='not iterable'):
+        s.to_jsonl(1)
+
+
+def test_to_json_writer():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.str_schema()))
+    value = ['é', 'x' * 100_000, 'y' * 100_000]
+
+    file = BytesIO()
+    assert s.to_json(value, writer=file) is None
+    assert file.getvalue() == s.to_json(value)
+
+    file = BytesIO()
+    s.to_json(value, writer=file, indent=2, ensure_ascii=True)
+    assert file.getvalue() == s.to_json(value, indent=2, ensure_ascii=True)
+
+
+def test_to_json_writer_chunks():
+    class Writer:
+        def __init__(self):
+            self.chunks = []
+
+        def write(self, data):
+            assert isinstance(data, bytes)
+            self.chunks.append(data)
+
+    s = SchemaSerializer(core_schema.list_schema(core_schema.int_schema()))
+    value = list(range(100_000))
+    writer = Writer()
+    s.to_json(value, writer=writer)
+    assert len(writer.chunks) > 1
+    assert b''.join(writer.chunks) == s.to_json(value)
+
+    writer = Writer()
+    s.to_json([], writer=writer)
+    assert writer.chunks == [b'[]']
+
+
+def test_to_json_writer_errors():
+    class BrokenWriter:
+        def write(self, data):
+            raise OSError('disk full')
+
+    s = SchemaSerializer(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(OSError, match='disk full'):
+        s.to_json([1, 2, 3], writer=BrokenWriter())
+    with pytest.raises(AttributeError, match="has no attribute 'write'"):
+        s.to_json([1, 2, 3], writer=object())
+
+    file = BytesIO()
+    with pytest.raises(PydanticSerializationError):
+        s.to_json([1, 'a'], writer=file, warnings='error')
+
+
+@requires_gzip
+def test_to_json_compress():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.int_schema()))
+    value = list(range(100_000))
+    compressed = s.to_json(value, compress='gzip')
+    assert len(compressed) < len(s.to_json(value)) // 2
+    assert gzip.decompress(compressed) == s.to_json(value)
+    assert gzip.decompress(s.to_json(value, compress='gzip', level=0, indent=2)) == s.to_json(value, indent=2)
+    assert len(s.to_json(value, compress='gzip', level=9)) < len(s.to_json(value, compress='gzip', level=0))
+    assert gzip.decompress(to_json({'a': [1, 2]}, compress='gzip')) == b'{"a":[1,2]}'
+
+    file = BytesIO()
+    s.to_json(value, compress='gzip', writer=file)
+    assert gzip.decompress(file.getvalue()) == s.to_json(value)
+
+
+@requires_zstd
+def test_to_json_compress_zstd():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.int_schema()))
+    value = list(range(100_000))
+    compressed = s.to_json(value, compress='zstd')
+    # the zstd frame magic number
+    assert compressed[:4] == b'\x28\xb5\x2f\xfd'
+    assert len(compressed) < len(s.to_json(value)) // 2
+    assert s.to_json(value, compress='zstd', level=-5)[:4] == b'\x28\xb5\x2f\xfd'
+    if zstandard is not None:
+        assert zstandard.ZstdDecompressor().decompressobj().decompress(compressed) == s.to_json(value)
+
+
+@pytest.mark.parametrize(
+    'kwargs,message',
+    [
+        ({'compress': 'brotli'}, "Invalid compress: `brotli`, expected 'gzip' or 'zstd'"),
+        pytest.param(
+            {'compress': 'gzip', 'level': 10}, 'Invalid gzip level: 10, expected 0 to 9', marks=requires_gzip
+        ),
+        pytest.param(
+            {'compress': 'zstd', 'level': 23}, 'Invalid zstd level: 23, expected -[0-9]+ to 22', marks=requires_zstd
+        ),
+        ({'level': 1}, '`level` requires `compress`'),
+    ],
+)
+def test_to_json_compress_errors(kwargs, message):
+    s = SchemaSerializer(core_schema.int_schema())
+    with pytest.raises(ValueError, match=message):
+        s.to_json(1, **kwargs)
+    with pytest.raises(ValueError, match=message):
+        to_json(1, **kwargs)
+
+
+@pytest.mark.parametrize('codec', ['gzip', 'zstd'])
+def test_to_json_compress_not_built(codec):
+    if codec in capabilities()['compression']:
+        pytest.skip(f'built with {codec}')
+    message = f"`compress='{codec}'` isn't available, pydantic-core was built without the `{codec}` feature"
+    with pytest.raises(ValueError, match=re.escape(message)):
+        to_json(1, compress=codec)
@@ -1,4 +1,6 @@
 import json
+import warnings
+from collections import deque
 from functools import partial
 
 import pytest
@@ -262,6 +264,26 @@ def test_tuple_fallback():
         assert v.to_python([1, 2, 3], mode='json') == [1, 2, 3]
 
 
+def test_list_preserve_type():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.int_schema(), preserve_type=True))
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        assert s.to_python((1, 2)) == (1, 2)
+        assert s.to_python((1, 2), mode='json') == [1, 2]
+        assert s.to_json((1, 2)) == b'[1,2]'
+
+        output = s.to_python(deque([1, 2], maxlen=3))
+        assert isinstance(output, deque) and output == deque([1, 2]) and output.maxlen == 3
+        assert s.to_python(deque([1, 2]), mode='json') == [1, 2]
+        assert s.to_json(deque([1, 2])) == b'[1,2]'
+        assert s.to_python((1, 2, 3), exclude={1}) == (1, 3)
+
+    # without `preserve_type`, tuples are still unexpected
+    s = SchemaSerializer(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.warns(UserWarning, match=r'Expected `list\[int\]` - serialized value may not be as expected'):
+        s.to_json((1, 2))
+
+
 @pytest.mark.parametrize(
     'params',
     [
@@ -11,6 +11,7 @@ from dirty_equals import IsJson
 
 from pydantic_core import (
     PydanticSerializationError,
+    SchemaError,
     SchemaSerializer,
     SchemaValidator,
     core_schema,
@@ -776,6 +777,54 @@ def test_computed_field_without_fields() -> None:
     assert serializer.to_json(a) == b'{"b":"b"}'
 
 
+@pytest.mark.parametrize('cache,expected_calls', [(None, 3), (True, 1)])
+def test_computed_field_cache(cache, expected_calls):
+    calls = []
+
+    class Inner:
+        def __init__(self, x):
+            self.x = x
+
+        @property
+        def doubled(self) -> int:
+            calls.append(self)
+            return self.x * 2
+
+    class Outer:
+        def __init__(self, items):
+            self.items = items
+
+    inner_schema = core_schema.model_schema(
+        Inner,
+        core_schema.model_fields_schema(
+            {'x': core_schema.model_field(core_schema.int_schema())},
+            computed_fields=[core_schema.computed_field('doubled', core_schema.int_schema(), cache=cache)],
+        ),
+    )
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            Outer,
+            core_schema.model_fields_schema(
+                {'items': core_schema.model_field(core_schema.list_schema(inner_schema))},
+            ),
+        )
+    )
+    inner = Inner(1)
+    outer = Outer([inner, inner, inner])
+    expected = {'items': [{'x': 1, 'doubled': 2}] * 3}
+
+    assert s.to_python(outer) == expected
+    assert len(calls) == expected_calls
+    # the cache only lasts for a single call
+    assert s.to_json(outer) == b'{"items":[{"x":1,"doubled":2},{"x":1,"doubled":2},{"x":1,"doubled":2}]}'
+    assert len(calls) == expected_calls * 2
+
+    # values are cached per instance, not per equal value
+    calls.clear()
+    assert s.to_python(Outer([Inner(1), Inner(1), Inner(2)]))['items'][2] == {'x': 2, 'doubled': 4}
+    assert len(calls) == 3
+
+
 def test_computed_field_exclude_none():
     @dataclasses.dataclass
     class Model:
@@ -1284,3 +1333,236 @@ def test_by_alias_and_name_config_interaction(config, runtime, expected) -> None
     )
     s = SchemaSerializer(schema)
     assert s.to_python(Model(1), by_alias=runtime) == expected
+
+
+class Point:
+    def __init__(self, x: int, y: int) -> None:
+        self.x = x
+        self.y = y
+
+
+def point_encoder(value):
+    if isinstance(value, Point):
+        return [value.x, value.y]
+    raise NotImplementedError
+
+
+def test_unknown_type_encoder():
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.any_schema())}),
+            unknown_type_encoder=point_encoder,
+        )
+    )
+    assert s.to_python(BasicModel(a=Point(1, 2)), mode='json') == {'a': [1, 2]}
+    assert s.to_json(BasicModel(a={'p': Point(1, 2)})) == b'{"a":{"p":[1,2]}}'
+    # in python mode the encoder isn't used, and unknown types are returned as is
+    assert s.to_python(BasicModel(a={'p': Point(1, 2)}))['a']['p'].x == 1
+
+    # the encoder is tried before the fallback, and passes values it doesn't handle on to the fallback
+    assert s.to_json(BasicModel(a=Point(1, 2)), fallback=lambda v: 'fallback') == b'{"a":[1,2]}'
+    assert s.to_json(BasicModel(a=object()), fallback=lambda v: 'fallback') == b'{"a":"fallback"}'
+    with pytest.raises(PydanticSerializationError, match='Unable to serialize unknown type'):
+        s.to_json(BasicModel(a=object()))
+
+
+def test_unknown_type_encoder_scoped():
+    class Inner(BasicModel):
+        pass
+
+    inner_schema = core_schema.model_schema(
+        Inner,
+        core_schema.model_fields_schema({'b': core_schema.model_field(core_schema.any_schema())}),
+        unknown_type_encoder=lambda v: 'inner',
+    )
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.any_schema()),
+                    'inner': core_schema.model_field(inner_schema),
+                    'other': core_schema.model_field(
+                        core_schema.model_schema(
+                            BasicSubModel,
+                            core_schema.model_fields_schema({'c': core_schema.model_field(core_schema.any_schema())}),
+                        )
+                    ),
+                }
+            ),
+            unknown_type_encoder=point_encoder,
+        )
+    )
+    value = BasicModel(a=Point(1, 2), inner=Inner(b=Point(3, 4)), other=BasicSubModel(c=Point(5, 6)))
+    # the innermost model with an encoder wins
+    assert s.to_python(value, mode='json') == {'a': [1, 2], 'inner': {'b': 'inner'}, 'other': {'c': [5, 6]}}
+
+    # the encoder only applies to values within the model
+    with pytest.raises(PydanticSerializationError, match='Unable to serialize unknown type'):
+        SchemaSerializer(core_schema.any_schema()).to_json(Point(1, 2))
+
+
+def test_dataclass_unknown_type_encoder():
+    @dataclasses.dataclass
+    class Foo:
+        a: Any
+
+    s = SchemaSerializer(
+        core_schema.dataclass_schema(
+            Foo,
+            core_schema.dataclass_args_schema(
+                'Foo', [core_schema.dataclass_field(name='a', schema=core_schema.any_schema())]
+            ),
+            ['a'],
+            unknown_type_encoder=point_encoder,
+        )
+    )
+    assert s.to_python(Foo(a=Point(1, 2)), mode='json') == {'a': [1, 2]}
+    assert s.to_json(Foo(a=[Point(1, 2)])) == b'{"a":[[1,2]]}'
+
+
+def test_collect_warnings():
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'foo': core_schema.model_field(core_schema.int_schema()),
+                    'bar': core_schema.model_field(core_schema.bytes_schema()),
+                }
+            ),
+        )
+    )
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        value, collected = s.to_python(BasicModel(foo='lorem', bar=b'more'), warnings='collect')
+    assert value == {'foo': 'lorem', 'bar': b'more'}
+    assert collected == [
+        {
+            'loc': ('foo',),
+            'msg': (
+                "Expected `int` - serialized value may not be as expected [field_name='foo', input_value='lorem', "
+                'input_type=str]'
+            ),
+            'expected': 'int',
+            'actual': 'str',
+            'input': 'lorem',
+        }
+    ]
+
+    json, collected = s.to_json(BasicModel(foo='lorem', bar=1), warnings='collect')
+    assert json == b'{"foo":"lorem","bar":1}'
+    assert [(w['loc'], w['expected'], w['actual']) for w in collected] == [
+        (('foo',), 'int', 'str'),
+        (('bar',), 'bytes', 'int'),
+    ]
+
+    # no warnings gives an empty list
+    assert s.to_python(BasicModel(foo=1, bar=b'more'), warnings='collect') == ({'foo': 1, 'bar': b'more'}, [])
+    assert s.to_json(BasicModel(foo=1, bar=b'more'), warnings='collect') == (b'{"foo":1,"bar":"more"}', [])
+
+    # values outside a field have an empty loc
+    _, collected = s.to_python(123, warnings='collect')
+    assert [(w['loc'], w['expected'], w['input']) for w in collected] == [((), 'BasicModel', 123)]
+
+
+def test_collect_warnings_unsupported():
+    s = SchemaSerializer(core_schema.int_schema())
+    with pytest.raises(ValueError, match="`warnings='collect'` is not supported by `to_jsonl`"):
+        s.to_jsonl([1], warnings='collect')
+    with pytest.raises(TypeError, match=r"should be `'none'`, `'warn'`, `'error'`, `'collect'` or a `bool`"):
+        s.to_python(1, warnings='gather')
+
+
+class Plugin(BasicModel):
+    pass
+
+
+class RenamePlugin(Plugin):
+    pass
+
+
+class OtherPlugin(Plugin):
+    pass
+
+
+def plugin_schema(cls, **fields):
+    return core_schema.model_schema(
+        cls,
+        core_schema.model_fields_schema({name: core_schema.model_field(schema) for name, schema in fields.items()}),
+    )
+
+
+def test_subclass_serializers():
+    schema = core_schema.list_schema(plugin_schema(Plugin, name=core_schema.str_schema()))
+    subclass_serializers = {
+        RenamePlugin: plugin_schema(RenamePlugin, name=core_schema.str_schema(), to=core_schema.str_schema())
+    }
+    s = SchemaSerializer(schema, subclass_serializers=subclass_serializers)
+
+    plugins = [
+        Plugin(name='a'),
+        RenamePlugin(name='b', to='c'),
+        # subclasses which aren't registered use the declared schema
+        OtherPlugin(name='d', extra='e'),
+    ]
+    expected = [{'name': 'a'}, {'name': 'b', 'to': 'c'}, {'name': 'd'}]
+    assert s.to_python(plugins) == expected
+    assert s.to_python(plugins, mode='json') == expected
+    assert json.loads(s.to_json(plugins)) == expected
+
+    # without the registry, the declared schema is used for every subclass
+    assert SchemaSerializer(schema).to_python(plugins) == [{'name': 'a'}, {'name': 'b'}, {'name': 'd'}]
+
+
+def test_subclass_serializers_exact_class():
+    class SubRenamePlugin(RenamePlugin):
+        pass
+
+    s = SchemaSerializer(
+        plugin_schema(Plugin, name=core_schema.str_schema()),
+        subclass_serializers={
+            RenamePlugin: plugin_schema(RenamePlugin, name=core_schema.str_schema(), to=core_schema.str_schema())
+        },
+    )
+    # only the exact class of a value is looked up, not its bases
+    assert s.to_python(SubRenamePlugin(name='a', to='b')) == {'name': 'a'}
+
+
+def test_subclass_serializers_dataclass():
+    @dataclasses.dataclass
+    class Base:
+        a: int
+
+    @dataclasses.dataclass
+    class Sub(Base):
+        b: int
+
+    def dataclass_schema(cls, fields):
+        return core_schema.dataclass_schema(
+            cls,
+            core_schema.dataclass_args_schema(
+                cls.__name__, [core_schema.dataclass_field(name=f, schema=core_schema.int_schema()) for f in fields]
+            ),
+            fields,
+        )
+
+    s = SchemaSerializer(dataclass_schema(Base, ['a']), subclass_serializers={Sub: dataclass_schema(Sub, ['a', 'b'])})
+    assert s.to_python(Base(a=1)) == {'a': 1}
+    assert s.to_python(Sub(a=1, b=2)) == {'a': 1, 'b': 2}
+    assert s.to_json(Sub(a=1, b=2)) == b'{"a":1,"b":2}'
+
+
+def test_subclass_serializers_invalid():
+    with pytest.raises(SchemaError, match='`subclass_serializers` keys must be types'):
+        SchemaSerializer(core_schema.any_schema(), subclass_serializers={'Plugin': core_schema.any_schema()})
+
+    with pytest.raises(
+        SchemaError,
+        match='The serializer for `RenamePlugin` in `subclass_serializers` is for a different class, `Plugin`',
+    ):
+        SchemaSerializer(
+            core_schema.any_schema(),
+            subclass_serializers={RenamePlugin: plugin_schema(Plugin, name=core_schema.str_schema())},
+        )
@@ -0,0 +1,127 @@
+from datetime import date, datetime, timedelta, timezone
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (None, 'c0'),
+        (True, 'c3'),
+        (False, 'c2'),
+        (1, '01'),
+        (-1, 'ff'),
+        (-33, 'd0df'),
+        (200, 'ccc8'),
+        (-200, 'd1ff38'),
+        (2**40, 'cf0000010000000000'),
+        (2**64 - 1, 'cfffffffffffffffff'),
+        (1.5, 'cb3ff8000000000000'),
+        ('abc', 'a3616263'),
+        ('x' * 40, 'd928' + '78' * 40),
+        (b'ab', 'c4026162'),
+        ([1, 'x'], '9201a178'),
+        ((1, 2), '920102'),
+        (list(range(16)), 'dc0010' + ''.join(f'{i:02x}' for i in range(16))),
+        ({'a': 1}, '81a16101'),
+        # keys are serialized as in JSON
+        ({1: 2}, '81a13102'),
+        (date(2024, 1, 1), 'aa323032342d30312d3031'),
+        (Decimal('1.5'), 'a3312e35'),
+    ],
+)
+def test_any(value, expected):
+    s = SchemaSerializer(core_schema.any_schema())
+    assert s.to_msgpack(value).hex() == expected
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (datetime(2024, 1, 1, tzinfo=timezone.utc), 'd6ff65920080'),
+        (datetime(2024, 1, 1, 1, tzinfo=timezone(timedelta(hours=1))), 'd6ff65920080'),
+        # naive datetimes are taken to be UTC
+        (datetime(2024, 1, 1), 'd6ff65920080'),
+        (datetime(2024, 1, 1, microsecond=5), 'd7ff00004e2065920080'),
+        (datetime(1969, 12, 31, 23, 59, 59, tzinfo=timezone.utc), 'c70cff00000000ffffffffffffffff'),
+    ],
+)
+def test_datetime_timestamp(value, expected):
+    assert SchemaSerializer(core_schema.datetime_schema()).to_msgpack(value).hex() == expected
+    assert SchemaSerializer(core_schema.any_schema()).to_msgpack(value).hex() == expected
+
+
+def test_datetime_modes():
+    s = SchemaSerializer(core_schema.datetime_schema())
+    dt = datetime(2024, 1, 1, tzinfo=timezone.utc)
+    assert s.to_msgpack(dt, datetime_mode='iso8601') == b'\xb42024-01-01T00:00:00Z'
+    assert s.to_msgpack(dt, datetime_mode='seconds').hex() == 'cb41d9648020000000'
+
+    s = SchemaSerializer(core_schema.datetime_schema(), config={'ser_naive_datetime': 'error'})
+    with pytest.raises(PydanticSerializationError, match='Cannot serialize naive datetime'):
+        s.to_msgpack(datetime(2024, 1, 1))
+
+
+def test_bytes_modes():
+    s = SchemaSerializer(core_schema.bytes_schema(), config={'ser_json_bytes': 'hex'})
+    assert s.to_msgpack(b'ab') == b'\xc4\x02ab'
+    assert s.to_msgpack(b'ab', bytes_mode='utf8') == b'\xa2ab'
+    assert s.to_msgpack(b'ab', bytes_mode='base64') == b'\xa4YWI='
+    assert s.to_msgpack(b'ab', bytes_mode='hex') == b'\xa46162'
+    assert SchemaSerializer(core_schema.any_schema()).to_msgpack(bytearray(b'ab')) == b'\xc4\x02ab'
+
+
+def test_decimal_modes():
+    s = SchemaSerializer(core_schema.decimal_schema())
+    assert s.to_msgpack(Decimal('1.5')) == b'\xa31.5'
+    assert s.to_msgpack(Decimal('1.5'), decimal_mode='float').hex() == 'cb3ff8000000000000'
+
+
+def test_typed_dict_include_exclude_alias():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.bytes_schema(), serialization_alias='B'),
+                'c': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.int_schema())),
+            }
+        )
+    )
+    value = {'a': 1, 'b': b'x', 'c': None}
+    assert s.to_msgpack(value).hex() == '83a16101a162c40178a163c0'
+    # the map's length is written once the excluded items are known
+    assert s.to_msgpack(value, exclude={'a'}, by_alias=True).hex() == '82a142c40178a163c0'
+    assert s.to_msgpack(value, include={'a'}).hex() == '81a16101'
+    assert s.to_msgpack(value, exclude_none=True).hex() == '82a16101a162c40178'
+
+
+def test_nested_json_and_functions():
+    s = SchemaSerializer(
+        core_schema.list_schema(
+            core_schema.str_schema(serialization=core_schema.plain_serializer_function_ser_schema(str.encode))
+        )
+    )
+    assert s.to_msgpack(['x']).hex() == '91c40178'
+
+    # with round_trip, JSON values are JSON strings in msgpack, with bytes serialized as in JSON
+    s = SchemaSerializer(core_schema.json_schema(core_schema.bytes_schema()))
+    assert s.to_msgpack(b'x') == b'\xc4\x01x'
+    assert s.to_msgpack(b'x', round_trip=True) == b'\xa3"x"'
+
+
+def test_generator():
+    s = SchemaSerializer(core_schema.generator_schema(core_schema.int_schema()))
+    assert s.to_msgpack(iter(range(3))).hex() == '93000102'
+
+
+def test_errors():
+    s = SchemaSerializer(core_schema.any_schema())
+    with pytest.raises(PydanticSerializationError, match='Integer `1180591620717411303424` is too large for msgpack'):
+        s.to_msgpack(2**70)
+    with pytest.raises(ValueError, match="Invalid msgpack bytes mode: `x`, expected 'bin', 'utf8', 'base64' or 'hex'"):
+        s.to_msgpack(b'', bytes_mode='x')
+    with pytest.raises(ValueError, match="Invalid msgpack decimal mode: `int`, expected 'str' or 'float'"):
+        s.to_msgpack(Decimal(1), decimal_mode='int')
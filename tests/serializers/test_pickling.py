@@ -71,3 +71,24 @@ def test_schema_serializer_not_reused_when_unpickling() -> None:
 
     reconstructed = pickle.loads(pickle.dumps(Model.__pydantic_serializer__))
     assert 'Prebuilt' not in str(reconstructed)
+
+
+class Animal:
+    def __init__(self, **kwargs):
+        self.__dict__.update(kwargs)
+
+
+class Dog(Animal):
+    pass
+
+
+def animal_schema(cls, *fields):
+    return core_schema.model_schema(
+        cls, core_schema.model_fields_schema({f: core_schema.model_field(core_schema.str_schema()) for f in fields})
+    )
+
+
+def test_subclass_serializers():
+    s = SchemaSerializer(animal_schema(Animal, 'name'), subclass_serializers={Dog: animal_schema(Dog, 'name', 'breed')})
+    s = pickle.loads(pickle.dumps(s))
+    assert s.to_python(Dog(name='Rex', breed='collie')) == {'name': 'Rex', 'breed': 'collie'}
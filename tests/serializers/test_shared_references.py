@@ -0,0 +1,103 @@
+import json
+import re
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
+
+
+class BasicModel:
+    def __init__(self, **kwargs):
+        for key, value in kwargs.items():
+            setattr(self, key, value)
+
+
+item_schema = core_schema.model_schema(
+    BasicModel,
+    core_schema.model_fields_schema(
+        {
+            'name': core_schema.model_field(core_schema.str_schema()),
+            'tags': core_schema.model_field(core_schema.list_schema(core_schema.str_schema())),
+        }
+    ),
+)
+s = SchemaSerializer(
+    core_schema.model_schema(
+        BasicModel,
+        core_schema.model_fields_schema(
+            {
+                'items': core_schema.model_field(core_schema.list_schema(item_schema)),
+                'main': core_schema.model_field(item_schema),
+            }
+        ),
+    )
+)
+
+
+def test_default():
+    item = BasicModel(name='a', tags=[])
+    assert s.to_python(BasicModel(items=[item, item], main=item)) == {
+        'items': [{'name': 'a', 'tags': []}, {'name': 'a', 'tags': []}],
+        'main': {'name': 'a', 'tags': []},
+    }
+
+
+def test_ref():
+    a = BasicModel(name='a', tags=['x'])
+    b = BasicModel(name='b', tags=[])
+    value = BasicModel(items=[a, b, a], main=b)
+    expected = {
+        'items': [{'name': 'a', 'tags': ['x']}, {'name': 'b', 'tags': []}, {'$ref': '#/items/0'}],
+        'main': {'$ref': '#/items/1'},
+    }
+    assert s.to_python(value, shared_references='ref') == expected
+    assert s.to_python(value, mode='json', shared_references='ref') == expected
+    assert json.loads(s.to_json(value, shared_references='ref')) == expected
+
+
+def test_ref_writer():
+    a = BasicModel(name='a', tags=[])
+    chunks = []
+
+    class Writer:
+        def write(self, chunk: bytes) -> None:
+            chunks.append(chunk)
+
+    s.to_json(BasicModel(items=[a], main=a), writer=Writer(), shared_references='ref')
+    assert json.loads(b''.join(chunks)) == {'items': [{'name': 'a', 'tags': []}], 'main': {'$ref': '#/items/0'}}
+
+
+def test_equal_values_not_shared():
+    value = BasicModel(items=[BasicModel(name='a', tags=[])], main=BasicModel(name='a', tags=[]))
+    assert s.to_python(value, shared_references='ref') == {
+        'items': [{'name': 'a', 'tags': []}],
+        'main': {'name': 'a', 'tags': []},
+    }
+
+
+def test_dict_keys_escaped():
+    d = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.any_schema()))
+    shared = {'x': 1}
+    assert d.to_python({'a/b~c': shared, 'other': shared}, shared_references='ref') == {
+        'a/b~c': {'x': 1},
+        'other': {'$ref': '#/a~1b~0c'},
+    }
+
+
+def test_error():
+    a = BasicModel(name='a', tags=[])
+    msg = 'The value at `#/main` is the same object as the value at `#/items/0`, shared references aren\'t allowed'
+    with pytest.raises(PydanticSerializationError, match=re.escape(msg)):
+        s.to_python(BasicModel(items=[a], main=a), shared_references='error')
+    with pytest.raises(PydanticSerializationError, match=re.escape(msg)):
+        s.to_json(BasicModel(items=[a], main=a), shared_references='error')
+    # no error without shared references
+    assert s.to_python(BasicModel(items=[], main=a), shared_references='error') == {
+        'items': [],
+        'main': {'name': 'a', 'tags': []},
+    }
+
+
+def test_invalid():
+    with pytest.raises(ValueError, match="Invalid shared_references: `copy`, expected 'ref' or 'error'"):
+        s.to_python(BasicModel(items=[], main=BasicModel(name='a', tags=[])), shared_references='copy')
@@ -3,7 +3,7 @@ from enum import IntEnum
 
 import pytest
 
-from pydantic_core import CoreConfig, SchemaSerializer, core_schema
+from pydantic_core import CoreConfig, SchemaError, SchemaSerializer, SchemaValidator, core_schema
 
 try:
     import numpy
@@ -169,3 +169,145 @@ def test_float_inf_and_nan_serializers(value, expected_json, config):
 
     # Serialized JSON value respects the ser_json_inf_nan setting
     assert s.to_json(value).decode() == expected_json
+
+
+def test_float_allow_inf_nan_per_field():
+    schema = core_schema.typed_dict_schema(
+        {
+            'measured': core_schema.typed_dict_field(core_schema.float_schema(allow_inf_nan=True)),
+            'price': core_schema.typed_dict_field(core_schema.float_schema(allow_inf_nan=False)),
+            'other': core_schema.typed_dict_field(core_schema.float_schema()),
+        }
+    )
+    value = {'measured': float('inf'), 'price': float('nan'), 'other': float('-inf')}
+    s = SchemaSerializer(schema, CoreConfig(allow_inf_nan=False))
+    assert s.to_json(value) == b'{"measured":Infinity,"price":null,"other":null}'
+    # non-finite values of fields allowing them validate again
+    v = SchemaValidator(schema, CoreConfig(allow_inf_nan=False))
+    assert v.validate_json(b'{"measured":Infinity,"price":1,"other":2}') == {
+        'measured': float('inf'),
+        'price': 1,
+        'other': 2,
+    }
+
+    # the config takes precedence when it's set
+    s = SchemaSerializer(schema, CoreConfig(ser_json_inf_nan='strings'))
+    assert s.to_json(value) == b'{"measured":"Infinity","price":"NaN","other":"-Infinity"}'
+
+
+@pytest.mark.parametrize(
+    'kwargs,value,expected_json',
+    [
+        ({}, 1.0, '1.0'),
+        ({}, 1, '1.0'),
+        ({}, 0.1, '0.1'),
+        ({}, 1e20, '100000000000000000000.0'),
+        ({'decimal_point': False}, 1.0, '1'),
+        ({'decimal_point': False}, 1.5, '1.5'),
+        ({'precision': 2}, 1.005, '1.01'),
+        ({'precision': 2}, -3.14159, '-3.14'),
+        ({'precision': 2}, 12.1, '12.10'),
+        ({'precision': 0}, 2.5, '3.0'),
+        ({'precision': 0}, 12.5, '13.0'),
+        ({'precision': 0}, -12.5, '-13.0'),
+        ({'precision': 0}, 12.4, '12.0'),
+        ({'precision': 0, 'decimal_point': False}, 2.5, '3'),
+        ({'precision': 0, 'decimal_point': False}, 0.4, '0'),
+        ({'precision': 1}, 9.96, '10.0'),
+        ({'precision': 1}, 0.05, '0.1'),
+        ({'precision': 1}, 0.04, '0.0'),
+        ({'precision': 3}, 0.0001, '0.000'),
+        ({'precision': 2}, 1e20, '100000000000000000000.00'),
+        ({'precision': 2}, 0.0, '0.00'),
+        ({'scientific_threshold': 6}, 1234567.0, '1.234567e6'),
+        ({'scientific_threshold': 6}, 123456.0, '123456.0'),
+        ({'scientific_threshold': 6}, 0.0000012, '1.2e-6'),
+        ({'scientific_threshold': 6}, 0.000012, '0.000012'),
+        ({'scientific_threshold': 6}, 1e6, '1.0e6'),
+        ({'scientific_threshold': 6}, 0.0, '0.0'),
+        ({'scientific_threshold': 6, 'decimal_point': False}, 1e6, '1e6'),
+        ({'scientific_threshold': 3, 'precision': 2}, 12345.678, '1.23e4'),
+        ({'scientific_threshold': 3, 'precision': 2}, 99999.0, '1.00e5'),
+        ({'scientific_threshold': 3, 'precision': 0}, 0.00025, '3.0e-4'),
+    ],
+)
+def test_float_format(kwargs, value, expected_json):
+    s = SchemaSerializer(core_schema.float_schema(serialization=core_schema.float_format_ser_schema(**kwargs)))
+    assert s.to_json(value).decode() == expected_json
+
+    # the format only applies to JSON
+    assert s.to_python(value) == value
+    assert s.to_python(value, mode='json') == value
+
+
+def test_float_format_field():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(
+                    core_schema.float_schema(serialization=core_schema.float_format_ser_schema(precision=3))
+                ),
+                'b': core_schema.typed_dict_field(core_schema.float_schema()),
+            }
+        )
+    )
+    assert s.to_json({'a': 1.5, 'b': 1.5}) == b'{"a":1.500,"b":1.5}'
+    assert s.to_json({'a': 1.5, 'b': 1.5}, indent=2) == b'{\n  "a": 1.500,\n  "b": 1.5\n}'
+
+
+@pytest.mark.parametrize(
+    'value,expected_json,config',
+    [
+        (float('inf'), 'null', {}),
+        (float('nan'), 'NaN', CoreConfig(ser_json_inf_nan='constants')),
+        (float('-inf'), '"-Infinity"', CoreConfig(ser_json_inf_nan='strings')),
+    ],
+)
+def test_float_format_inf_and_nan(value, expected_json, config):
+    s = SchemaSerializer(
+        core_schema.float_schema(serialization=core_schema.float_format_ser_schema(precision=2)), config
+    )
+    assert s.to_json(value).decode() == expected_json
+
+
+def test_ser_json_float_config():
+    config = CoreConfig(ser_json_float={'precision': 2})
+    s = SchemaSerializer(core_schema.float_schema(), config)
+    assert s.to_json(12.1) == b'12.10'
+    assert s.to_json(1e20) == b'100000000000000000000.00'
+    assert s.to_python(12.1, mode='json') == 12.1
+
+    # floats serialized by inference use the config too
+    s = SchemaSerializer(core_schema.any_schema(), config)
+    assert s.to_json({'a': [12.1, 3]}) == b'{"a":[12.10,3]}'
+
+    # without a format, large floats are written in scientific notation
+    s = SchemaSerializer(core_schema.float_schema())
+    assert s.to_json(1e20) == b'1e20'
+    s = SchemaSerializer(core_schema.float_schema(), CoreConfig(ser_json_float={}))
+    assert s.to_json(1e20) == b'100000000000000000000.0'
+
+
+def test_ser_json_float_config_field_format():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(
+                    core_schema.float_schema(serialization=core_schema.float_format_ser_schema(precision=3))
+                ),
+                'b': core_schema.typed_dict_field(core_schema.float_schema()),
+            }
+        ),
+        CoreConfig(ser_json_float={'precision': 1, 'scientific_threshold': 3}),
+    )
+    assert s.to_json({'a': 1.5, 'b': 1234.5}) == b'{"a":1.500,"b":1.2e3}'
+
+    with pytest.raises(SchemaError, match='`scientific_threshold` must be greater than 0'):
+        SchemaSerializer(core_schema.float_schema(), CoreConfig(ser_json_float={'scientific_threshold': 0}))
+
+
+def test_float_format_invalid_threshold():
+    with pytest.raises(SchemaError, match='`scientific_threshold` must be greater than 0'):
+        SchemaSerializer(
+            core_schema.float_schema(serialization=core_schema.float_format_ser_schema(scientific_threshold=0))
+        )
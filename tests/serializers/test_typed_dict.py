@@ -408,3 +408,32 @@ def test_nested_typed_dict_field_serializers():
     # if the inner field serializer incorrectly receives OuterModel as self, the keys
     # will be ['model'] instead of ['x']
     assert outer_s.to_python(OuterModel(model=Model(x=None))) == {'model': {'x': "['x']"}}
+
+
+def test_alias_generator():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'first_name': core_schema.typed_dict_field(core_schema.str_schema()),
+                'last_name': core_schema.typed_dict_field(core_schema.str_schema(), serialization_alias='surname'),
+            },
+            alias_generator=lambda name: name.replace('_', '-'),
+        )
+    )
+    value = {'first_name': 'a', 'last_name': 'b'}
+    assert s.to_python(value, by_alias=True) == {'first-name': 'a', 'surname': 'b'}
+    assert json.loads(s.to_json(value, by_alias=True)) == {'first-name': 'a', 'surname': 'b'}
+    assert s.to_python(value) == value
+
+
+def test_derive_aliases():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='A'),
+                'b': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias=['x', 'b']),
+            },
+            derive_aliases=True,
+        )
+    )
+    assert s.to_python({'a': 1, 'b': 2}, by_alias=True) == {'A': 1, 'b': 2}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use _pydantic_core::{SchemaSerializer, SchemaValidator, WarningsArg};
+    use _pydantic_core::{SchemaSerializer, SchemaValidator, StrictScope, WarningsArg};
     use pyo3::ffi::c_str; // can switch to c"" literals on MSRV >= 1.77
     use pyo3::prelude::*;
     use pyo3::types::PyDict;
@@ -49,7 +49,7 @@ mod tests {
             }"
             );
             let schema: Bound<'_, PyDict> = py.eval(code, None, None).unwrap().extract().unwrap();
-            SchemaSerializer::py_new(schema, None).unwrap();
+            SchemaSerializer::py_new(schema, None, None).unwrap();
         });
     }
 
@@ -78,7 +78,29 @@ json_input = '{"a": "something"}'
             let json_input = locals.get_item("json_input").unwrap().unwrap();
             let binding = SchemaValidator::py_new(py, &schema, None)
                 .unwrap()
-                .validate_json(py, &json_input, None, None, None, None, false.into(), None, None)
+                .validate_json(
+                    py,
+                    &json_input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    None,
+                    None,
+                    StrictScope::Recursive,
+                    None,
+                    false,
+                    false,
+                    false,
+                    false,
+                    false,
+                    None,
+                    false,
+                    None,
+                    None,
+                    None,
+                )
                 .unwrap();
             let validation_result: Bound<'_, PyAny> = binding.extract(py).unwrap();
             let repr = format!("{}", validation_result.repr().unwrap());
@@ -137,13 +159,14 @@ dump_json_input_2 = {'a': 'something'}
                 .unwrap();
             let dump_json_input_1 = locals.get_item("dump_json_input_1").unwrap().unwrap();
             let dump_json_input_2 = locals.get_item("dump_json_input_2").unwrap().unwrap();
-            let serializer = SchemaSerializer::py_new(schema, None).unwrap();
+            let serializer = SchemaSerializer::py_new(schema, None, None).unwrap();
             let serialization_result = serializer
                 .to_json(
                     py,
                     &dump_json_input_1,
                     None,
                     Some(false),
+                    false,
                     None,
                     None,
                     Some(false),
@@ -156,6 +179,10 @@ dump_json_input_2 = {'a': 'something'}
                     None,
                     false,
                     None,
+                    None,
+                    None,
+                    None,
+                    None,
                 )
                 .unwrap();
             let repr = format!("{}", serialization_result.bind(py).repr().unwrap());
@@ -167,6 +194,7 @@ dump_json_input_2 = {'a': 'something'}
                     &dump_json_input_2,
                     None,
                     Some(false),
+                    false,
                     None,
                     None,
                     Some(false),
@@ -179,6 +207,10 @@ dump_json_input_2 = {'a': 'something'}
                     None,
                     false,
                     None,
+                    None,
+                    None,
+                    None,
+                    None,
                 )
                 .unwrap();
             let repr = format!("{}", serialization_result.bind(py).repr().unwrap());
@@ -0,0 +1,120 @@
+import pytest
+
+from pydantic_core import SchemaValidator, core_schema
+
+
+def test_valid_examples():
+    schema = core_schema.int_schema(gt=0, examples=[1, '2', 3.0])
+    assert SchemaValidator(schema).check_examples() == []
+
+
+def test_no_examples():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.check_examples() == []
+
+
+def test_failing_example():
+    schema = core_schema.int_schema(gt=0, examples=[1, -1])
+    failures = SchemaValidator(schema).check_examples()
+    assert len(failures) == 1
+    failure = failures[0]
+    assert failure['path'] == ()
+    assert failure['index'] == 1
+    assert failure['example'] == -1
+    assert 'Input should be greater than 0' in failure['message']
+    assert [(e['type'], e['loc']) for e in failure['errors']] == [('greater_than', ())]
+
+
+def test_nested_examples():
+    schema = core_schema.typed_dict_schema(
+        {
+            'name': core_schema.typed_dict_field(
+                core_schema.str_schema(max_length=5, examples=['alice', 'bartholomew'])
+            ),
+            'age': core_schema.typed_dict_field(core_schema.int_schema(examples=[30, 'old'])),
+        },
+        examples=[{'name': 'bob', 'age': 1}, {'name': 'bob'}],
+    )
+    failures = SchemaValidator(schema).check_examples()
+    assert [(f['path'], f['index'], f['errors'][0]['type']) for f in failures] == [
+        ((), 1, 'missing'),
+        (('fields', 'name', 'schema'), 1, 'string_too_long'),
+        (('fields', 'age', 'schema'), 1, 'int_parsing'),
+    ]
+
+
+@pytest.mark.parametrize(
+    'schema,expected_failures',
+    [
+        (core_schema.any_schema(examples=[1]), []),
+        (core_schema.list_schema(core_schema.int_schema(), examples=[[1], ['x']]), [1]),
+        (core_schema.nullable_schema(core_schema.int_schema(), examples=[None, 'x']), [1]),
+        (core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()], examples=['a', []]), [1]),
+    ],
+)
+def test_factory_examples(schema, expected_failures):
+    assert [f['index'] for f in SchemaValidator(schema).check_examples()] == expected_failures
+
+
+def test_examples_use_config():
+    schema = core_schema.int_schema(examples=['1'])
+    assert SchemaValidator(schema).check_examples() == []
+    failures = SchemaValidator(schema, config=core_schema.CoreConfig(strict=True)).check_examples()
+    assert [f['errors'][0]['type'] for f in failures] == ['int_type']
+
+
+def test_definitions():
+    node_schema = core_schema.typed_dict_schema(
+        {
+            'value': core_schema.typed_dict_field(core_schema.int_schema()),
+            'children': core_schema.typed_dict_field(
+                core_schema.list_schema(core_schema.definition_reference_schema('node'))
+            ),
+        },
+        ref='node',
+    )
+    node_schema['examples'] = [{'value': 1, 'children': [{'value': 2, 'children': []}]}, {'value': 1, 'children': [1]}]
+    schema = core_schema.definitions_schema(core_schema.definition_reference_schema('node'), [node_schema])
+    failures = SchemaValidator(schema).check_examples()
+    assert [(f['path'], f['index'], f['errors'][0]['loc']) for f in failures] == [
+        (('definitions', 0), 1, ('children', 0)),
+    ]
+
+
+def test_field_schema_examples():
+    field_schema = core_schema.int_schema(examples=[1, 2])
+    schema = core_schema.model_fields_schema({'a': core_schema.model_field(field_schema)})
+    assert SchemaValidator(schema).check_examples() == []
+
+    function_schema = core_schema.with_info_plain_validator_function(lambda v, info: v, examples=[1])
+    schema = core_schema.model_fields_schema({'a': core_schema.model_field(function_schema)})
+    assert SchemaValidator(schema).check_examples() == []
+
+
+def test_examples_not_a_list():
+    schema = core_schema.int_schema()
+    schema['examples'] = 1
+    with pytest.raises(TypeError, match='`examples` should be a list, got int'):
+        SchemaValidator(schema).check_examples()
+
+
+def test_validator_error_raised():
+    def f(input_value):
+        raise RuntimeError('boom')
+
+    schema = core_schema.no_info_plain_validator_function(f, examples=[1])
+    with pytest.raises(RuntimeError, match='boom'):
+        SchemaValidator(schema).check_examples()
+
+
+def test_schema_cant_be_built_on_its_own():
+    field = core_schema.typed_dict_field(core_schema.int_schema())
+    # fields aren't schemas, the examples should be on the field's schema
+    field['examples'] = [1, 2]
+    schema = core_schema.typed_dict_schema({'a': field})
+    failures = SchemaValidator(schema).check_examples()
+    assert [(f['path'], f['index'], f['example'], f['errors']) for f in failures] == [
+        (('fields', 'a'), 0, 1, []),
+        (('fields', 'a'), 1, 2, []),
+    ]
+    assert failures[0]['message'].startswith("Schema can't be built on its own to check examples:")
@@ -0,0 +1,174 @@
+import dataclasses
+import sys
+
+import pytest
+
+from pydantic_core import SchemaValidator, core_schema
+
+
+class Model:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+def model_validator(**kwargs) -> SchemaValidator:
+    def factory(data):
+        # the number of fields before `b` with a value
+        return len(data)
+
+    return SchemaValidator(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema(), validation_alias='A'),
+                    'b': core_schema.model_field(
+                        core_schema.with_default_schema(
+                            core_schema.int_schema(), default_factory=factory, default_factory_takes_data=True
+                        )
+                    ),
+                    'c': core_schema.model_field(
+                        core_schema.with_default_schema(core_schema.int_schema(), default='x', validate_default=True)
+                    ),
+                },
+                **kwargs,
+            ),
+        )
+    )
+
+
+def test_model():
+    v = model_validator()
+    m = v.construct({'A': 'not an int', 'other': 1})
+    assert isinstance(m, Model)
+    # values aren't validated, nor are defaults
+    assert m.__dict__ == {'a': 'not an int', 'b': 1, 'c': 'x'}
+    assert m.__pydantic_fields_set__ == {'a'}
+    assert m.__pydantic_extra__ is None
+    assert m.__pydantic_private__ is None
+
+
+def test_model_missing_fields():
+    v = model_validator()
+    m = v.construct({'b': 2})
+    assert m.__dict__ == {'b': 2, 'c': 'x'}
+    assert m.__pydantic_fields_set__ == {'b'}
+
+
+def test_model_fields_set():
+    v = model_validator()
+    m = v.construct({'A': 1}, fields_set={'a', 'c'})
+    assert m.__dict__ == {'a': 1, 'b': 1, 'c': 'x'}
+    assert m.__pydantic_fields_set__ == {'a', 'c'}
+
+
+def test_model_by_name():
+    v = model_validator()
+    assert v.construct({'a': 1}).__dict__ == {'b': 0, 'c': 'x'}
+    assert v.construct({'a': 1}, by_name=True).__dict__ == {'a': 1, 'b': 1, 'c': 'x'}
+    assert v.construct({'a': 1, 'A': 3}, by_alias=False, by_name=True).__dict__ == {'a': 1, 'b': 1, 'c': 'x'}
+
+
+def test_model_extra_allow():
+    v = model_validator(extra_behavior='allow')
+    m = v.construct({'A': 1, 'other': 2})
+    assert m.__dict__ == {'a': 1, 'b': 1, 'c': 'x'}
+    assert m.__pydantic_extra__ == {'other': 2}
+
+
+def test_model_post_init():
+    class PostInitModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+        def call_me_maybe(self, context):
+            self.post_init_context = context
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            PostInitModel,
+            core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())}),
+            post_init='call_me_maybe',
+        )
+    )
+    m = v.construct({'a': 1})
+    assert m.__dict__ == {'a': 1, 'post_init_context': None}
+
+
+def test_root_model():
+    class RootModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(RootModel, core_schema.list_schema(core_schema.int_schema()), root_model=True)
+    )
+    m = v.construct(['a'])
+    assert m.root == ['a']
+    assert m.__pydantic_fields_set__ == {'root'}
+
+
+def test_definition_ref():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('model'),
+            [
+                core_schema.model_schema(
+                    Model,
+                    core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())}),
+                    ref='model',
+                )
+            ],
+        )
+    )
+    assert v.construct({'a': 'x'}).__dict__ == {'a': 'x'}
+
+
+@pytest.mark.parametrize(
+    'slots',
+    [
+        False,
+        pytest.param(
+            True,
+            marks=pytest.mark.skipif(
+                sys.version_info < (3, 10), reason='slots are only supported for dataclasses in Python >= 3.10'
+            ),
+        ),
+    ],
+)
+def test_dataclass(slots):
+    @dataclasses.dataclass(**({'slots': True} if slots else {}))
+    class Foo:
+        a: int
+        b: str = 'b'
+        c: int = dataclasses.field(init=False, default=3)
+
+        def __post_init__(self):
+            raise AssertionError('not called')
+
+    v = SchemaValidator(
+        core_schema.dataclass_schema(
+            Foo,
+            core_schema.dataclass_args_schema(
+                'Foo',
+                [
+                    core_schema.dataclass_field(name='a', schema=core_schema.int_schema()),
+                    core_schema.dataclass_field(
+                        name='b', schema=core_schema.with_default_schema(core_schema.str_schema(), default='b')
+                    ),
+                    core_schema.dataclass_field(
+                        name='c', schema=core_schema.with_default_schema(core_schema.int_schema(), default=3), init=False
+                    ),
+                ],
+            ),
+            ['a', 'b', 'c'],
+            slots=slots,
+            post_init=True,
+        )
+    )
+    foo = v.construct({'a': 'x', 'c': 4})
+    assert isinstance(foo, Foo)
+    assert dataclasses.asdict(foo) == {'a': 'x', 'b': 'b', 'c': 3}
+
+
+def test_not_model():
+    v = SchemaValidator(core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}))
+    with pytest.raises(TypeError, match='`construct` is only supported for model and dataclass schemas'):
+        v.construct({'a': 1})
@@ -1,4 +1,5 @@
 import enum
+import json
 import os
 import pickle
 import re
@@ -16,9 +17,11 @@ from pydantic_core import (
     PydanticCustomError,
     PydanticKnownError,
     PydanticOmit,
+    SchemaError,
     SchemaValidator,
     ValidationError,
     core_schema,
+    register_error_type,
 )
 from pydantic_core._pydantic_core import list_all_errors
 
@@ -263,8 +266,20 @@ all_errors = [
         {'method_name': 'isinstance'},
     ),
     ('recursion_loop', 'Recursion error - cyclic reference detected', None),
+    ('input_limit_exceeded', 'Input exceeds the depth limit of 3', {'limit': 'depth', 'max': 3}),
+    ('validation_timeout', "Validation didn't finish within the timeout of 100ms", {'timeout_ms': 100}),
     ('model_type', 'Input should be a valid dictionary or instance of Foobar', {'class_name': 'Foobar'}),
     ('model_attributes_type', 'Input should be a valid dictionary or object to extract fields from', None),
+    (
+        'consistency_mismatch',
+        'Value should be 15, the sum of items.*.price',
+        {'check': 'sum of items.*.price', 'expected': '15'},
+    ),
+    (
+        'consistency_uncomputable',
+        "The sum of items.*.price can't be computed: unsupported operand type(s)",
+        {'check': 'sum of items.*.price', 'error': 'unsupported operand type(s)'},
+    ),
     ('dataclass_exact_type', 'Input should be an instance of Foobar', {'class_name': 'Foobar'}),
     ('dataclass_type', 'Input should be a dictionary or an instance of Foobar', {'class_name': 'Foobar'}),
     (
@@ -272,8 +287,14 @@ all_errors = [
         'The default factory uses validated data, but at least one validation error occurred',
         None,
     ),
+    (
+        'default_shared',
+        'Default value is the same object as a default returned before, it should be a new object each time',
+        None,
+    ),
     ('missing', 'Field required', None),
     ('frozen_field', 'Field is frozen', None),
+    ('read_only_field', 'Field is read-only', None),
     ('frozen_instance', 'Instance is frozen', None),
     ('extra_forbidden', 'Extra inputs are not permitted', None),
     ('invalid_key', 'Keys should be strings', None),
@@ -301,15 +322,27 @@ all_errors = [
     ('string_sub_type', 'Input should be a string, not an instance of a subclass of str', None),
     ('string_unicode', 'Input should be a valid string, unable to parse raw data as a unicode string', None),
     ('string_pattern_mismatch', "String should match pattern 'foo'", {'pattern': 'foo'}),
+    ('string_pattern_forbidden', "String should not match pattern 'foo'", {'pattern': 'foo'}),
     ('string_too_short', 'String should have at least 42 characters', {'min_length': 42}),
     ('string_too_short', 'String should have at least 1 character', {'min_length': 1}),
     ('string_too_long', 'String should have at most 42 characters', {'max_length': 42}),
     ('string_too_long', 'String should have at most 1 character', {'max_length': 1}),
+    ('string_bytes_too_short', 'String should have at least 42 bytes in UTF-8', {'min_length': 42}),
+    ('string_bytes_too_long', 'String should have at most 1 byte in UTF-8', {'max_length': 1}),
     ('dict_type', 'Input should be a valid dictionary', None),
     ('mapping_type', 'Input should be a valid mapping, error: foobar', {'error': 'foobar'}),
     ('iterable_type', 'Input should be iterable', None),
     ('iteration_error', 'Error iterating over object, error: foobar', {'error': 'foobar'}),
     ('list_type', 'Input should be a valid list', None),
+    ('list_not_monotonic', 'List items should be increasing', {'monotonic': 'increasing'}),
+    (
+        'list_adjacent_duplicates',
+        'List should have at most 2 adjacent duplicates',
+        {'max_adjacent_duplicates': 2},
+    ),
+    ('list_adjacent_duplicates', 'List should have at most 1 adjacent duplicate', {'max_adjacent_duplicates': 1}),
+    ('keyed_list_duplicate', "Duplicate key, each item should have a unique 'name'", {'key_field': 'name'}),
+    ('duplicate_item', 'Items should be unique, this item is a duplicate of the item at index 2', {'first_index': 2}),
     ('tuple_type', 'Input should be a valid tuple', None),
     ('set_item_not_hashable', 'Set items should be hashable', None),
     ('set_type', 'Input should be a valid set', None),
@@ -319,13 +352,22 @@ all_errors = [
     ('int_parsing', 'Input should be a valid integer, unable to parse string as an integer', None),
     ('int_parsing_size', 'Unable to parse input string as an integer, exceeded maximum size', None),
     ('int_from_float', 'Input should be a valid integer, got a number with a fractional part', None),
+    (
+        'int_from_float_tolerance',
+        'Input should be a valid integer, got a number more than 0.000000001 from an integer',
+        {'tolerance': 1e-9},
+    ),
+    ('int_max_digits', 'Integer input should have no more than 42 digits', {'max_digits': 42}),
+    ('int_max_digits', 'Integer input should have no more than 1 digit', {'max_digits': 1}),
     ('multiple_of', 'Input should be a multiple of 42.1', {'multiple_of': 42.1}),
+    ('out_of_range', 'Input should be in the interval [0, 100)', {'interval': '[0, 100)'}),
     ('greater_than', 'Input should be greater than 42.1', {'gt': 42.1}),
     ('greater_than_equal', 'Input should be greater than or equal to 42.1', {'ge': 42.1}),
     ('less_than', 'Input should be less than 42.1', {'lt': 42.1}),
     ('less_than_equal', 'Input should be less than or equal to 42.1', {'le': 42.1}),
     ('float_type', 'Input should be a valid number', None),
     ('float_parsing', 'Input should be a valid number, unable to parse string as a number', None),
+    ('float_inexact', 'Input should be a number exactly representable as a float', None),
     ('bytes_type', 'Input should be a valid bytes', None),
     ('bytes_too_short', 'Data should have at least 42 bytes', {'min_length': 42}),
     ('bytes_too_short', 'Data should have at least 1 byte', {'min_length': 1}),
@@ -345,26 +387,48 @@ all_errors = [
     ('assertion_error', 'Assertion failed, foobar', {'error': AssertionError('foobar')}),
     ('literal_error', 'Input should be foo', {'expected': 'foo'}),
     ('literal_error', 'Input should be foo or bar', {'expected': 'foo or bar'}),
+    ('const_mismatch', 'Input should be the constant 2', {'expected': '2'}),
     ('missing_sentinel_error', "Input should be the 'MISSING' sentinel", None),
     ('date_type', 'Input should be a valid date', None),
     ('date_parsing', 'Input should be a valid date in the format YYYY-MM-DD, foobar', {'error': 'foobar'}),
     ('date_from_datetime_parsing', 'Input should be a valid date or datetime, foobar', {'error': 'foobar'}),
     ('date_from_datetime_inexact', 'Datetimes provided to dates should have zero time - e.g. be exact dates', None),
+    (
+        'date_format',
+        "Input should be a valid date in the format '%d/%m/%Y', foobar",
+        {'format': '%d/%m/%Y', 'error': 'foobar'},
+    ),
     ('date_past', 'Date should be in the past', None),
     ('date_future', 'Date should be in the future', None),
     ('time_type', 'Input should be a valid time', None),
     ('time_parsing', 'Input should be in a valid time format, foobar', {'error': 'foobar'}),
+    (
+        'time_format',
+        "Input should be a valid time in the format '%H:%M', foobar",
+        {'format': '%H:%M', 'error': 'foobar'},
+    ),
     ('datetime_type', 'Input should be a valid datetime', None),
     ('datetime_parsing', 'Input should be a valid datetime, foobar', {'error': 'foobar'}),
     ('datetime_from_date_parsing', 'Input should be a valid datetime or date, foobar', {'error': 'foobar'}),
     ('datetime_object_invalid', 'Invalid datetime object, got foobar', {'error': 'foobar'}),
+    (
+        'datetime_format',
+        "Input should be a valid datetime in the format '%d/%m/%Y %H:%M', foobar",
+        {'format': '%d/%m/%Y %H:%M', 'error': 'foobar'},
+    ),
     ('datetime_past', 'Input should be in the past', None),
     ('datetime_future', 'Input should be in the future', None),
     ('timezone_naive', 'Input should not have timezone info', None),
     ('timezone_aware', 'Input should have timezone info', None),
     ('timezone_offset', 'Timezone offset of 0 required, got 60', {'tz_expected': 0, 'tz_actual': 60}),
+    (
+        'timezone_name',
+        "Timezone offset of 3600 required for 'Europe/Paris', got 0",
+        {'tz_name': 'Europe/Paris', 'tz_expected': 3600, 'tz_actual': 0},
+    ),
     ('time_delta_type', 'Input should be a valid timedelta', None),
     ('time_delta_parsing', 'Input should be a valid timedelta, foobar', {'error': 'foobar'}),
+    ('time_delta_negative', 'Input should be a non-negative timedelta', None),
     ('frozen_set_type', 'Input should be a valid frozenset', None),
     ('is_instance_of', 'Input should be an instance of Foo', {'class': 'Foo'}),
     ('is_subclass_of', 'Input should be a subclass of Foo', {'class': 'Foo'}),
@@ -391,6 +455,30 @@ all_errors = [
     ('uuid_type', 'UUID input should be a string, bytes or UUID object', None),
     ('uuid_parsing', 'Input should be a valid UUID, Foobar', {'error': 'Foobar'}),
     ('uuid_version', 'UUID version 42 expected', {'expected_version': 42}),
+    (
+        'uuid_timestamp_greater_than_equal',
+        'UUID timestamp should be greater than or equal to 2024-01-01T00:00:00+00:00',
+        {'ge': '2024-01-01T00:00:00+00:00'},
+    ),
+    ('uuid_timestamp_less_than', 'UUID timestamp should be less than 2025-01-01', {'lt': '2025-01-01'}),
+    ('semver_type', 'Version input should be a string or Version', None),
+    ('semver_parsing', 'Input should be a valid semantic version, Foobar', {'error': 'Foobar'}),
+    ('semver_greater_than_equal', 'Version should be greater than or equal to 1.0.0', {'ge': '1.0.0'}),
+    ('semver_less_than', 'Version should be less than 2.0.0', {'lt': '2.0.0'}),
+    ('ulid_type', 'ULID input should be a string, bytes, UUID or Ulid', None),
+    ('ulid_parsing', 'Input should be a valid ULID, Foobar', {'error': 'Foobar'}),
+    ('email_parsing', 'Input should be a valid email address, Foobar', {'error': 'Foobar'}),
+    ('email_too_long', 'Email address should have at most 42 characters', {'max_length': 42}),
+    ('email_too_long', 'Email address should have at most 1 character', {'max_length': 1}),
+    ('email_domain', "Email address domain should be 'example.com'", {'expected_domains': "'example.com'"}),
+    ('path_type', 'Input should be a valid path', None),
+    ('path_not_found', 'Path does not exist', None),
+    ('path_not_file', 'Path does not point to a file', None),
+    ('path_not_directory', 'Path does not point to a directory', None),
+    ('path_suffix', "Path suffix should be '.toml'", {'expected_suffixes': "'.toml'"}),
+    ('ip_address', 'Input is not a valid IPv4 address', {'version': 'IPv4'}),
+    ('ip_network', 'Input is not a valid IPv4 or IPv6 network', {'version': 'IPv4 or IPv6'}),
+    ('ip_interface', 'Input is not a valid IPv6 interface', {'version': 'IPv6'}),
     ('decimal_type', 'Decimal input should be an integer, float, string or Decimal object', None),
     ('decimal_parsing', 'Input should be a valid decimal', None),
     ('decimal_max_digits', 'Decimal input should have no more than 42 digits in total', {'max_digits': 42}),
@@ -417,6 +505,7 @@ all_errors = [
         'Input should be a valid complex string following the rules at https://docs.python.org/3/library/functions.html#complex',
         None,
     ),
+    ('plugin_type', 'Input should be a valid numpy-int64', {'plugin': 'numpy-int64'}),
 ]
 
 
@@ -493,6 +582,57 @@ def test_custom_context_for_simple_error():
     assert err.context == {'foo': 'bar'}
 
 
+def test_registered_error_type():
+    register_error_type('test_errors.invalid_sku', 'Invalid SKU {sku}', required_context=['sku'])
+
+    e = PydanticKnownError('test_errors.invalid_sku', {'sku': 'AB-1'})
+    assert e.message() == 'Invalid SKU AB-1'
+    assert e.message_template == 'Invalid SKU {sku}'
+    assert e.type == 'test_errors.invalid_sku'
+    assert e.context == {'sku': 'AB-1'}
+    with pytest.raises(TypeError, match="^test_errors.invalid_sku: 'sku' required in context$"):
+        PydanticKnownError('test_errors.invalid_sku')
+
+    def f(input_value):
+        raise PydanticKnownError('test_errors.invalid_sku', {'sku': input_value})
+
+    v = SchemaValidator(core_schema.no_info_plain_validator_function(f))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    assert exc_info.value.errors() == [
+        {'type': 'test_errors.invalid_sku', 'loc': (), 'msg': 'Invalid SKU x', 'input': 'x', 'ctx': {'sku': 'x'}}
+    ]
+
+    error = ValidationError.from_exception_data(
+        'Test', [{'type': 'test_errors.invalid_sku', 'input': 'y', 'ctx': {'sku': 'y'}}]
+    )
+    assert error.errors()[0]['msg'] == 'Invalid SKU y'
+
+    # registered types can be used like built-in ones in schemas and config
+    v = SchemaValidator(
+        core_schema.custom_error_schema(
+            core_schema.int_schema(), 'test_errors.invalid_sku', custom_error_context={'sku': '?'}
+        )
+    )
+    with pytest.raises(ValidationError, match=r'Invalid SKU \? \[type=test_errors.invalid_sku'):
+        v.validate_python('z')
+
+    v = SchemaValidator(
+        core_schema.no_info_plain_validator_function(f),
+        config=CoreConfig(error_message_templates={'test_errors.invalid_sku': 'SKU {sku} is unknown'}),
+    )
+    with pytest.raises(ValidationError, match='SKU x is unknown'):
+        v.validate_python('x')
+
+    # registered types aren't built-in errors
+    assert 'test_errors.invalid_sku' not in {e['type'] for e in list_all_errors()}
+
+
+def test_register_builtin_error_type():
+    with pytest.raises(ValueError, match="Can't register 'int_parsing', it's a built-in error type"):
+        register_error_type('int_parsing', 'Not an int')
+
+
 def test_all_errors():
     errors = list_all_errors()
     # print(f'{len(errors)=}')
@@ -780,6 +920,70 @@ def test_error_on_repr(pydantic_version):
     )
 
 
+def truncated_repr(value: Any) -> str:
+    r = repr(value)
+    return f'{r[:25]}...{r[-24:]}' if len(r) > 50 else r
+
+
+@pytest.mark.parametrize(
+    'input_value',
+    [
+        list(range(1000)),
+        tuple(range(1000)),
+        ('x' * 100,),
+        {i: [str(i)] * 3 for i in range(1000)},
+        [[[[1, 2, 3]]]] * 100,
+        [{'a': (1, [2, {3: ()}])}] * 100,
+        [],
+        (),
+        {},
+    ],
+)
+def test_container_input_repr(input_value):
+    s = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python(input_value)
+
+    assert f'input_value={truncated_repr(input_value)}, ' in str(exc_info.value)
+
+
+def test_recursive_container_input_repr():
+    s = SchemaValidator(core_schema.int_schema())
+    input_value = [1, {'a': 2}]
+    input_value.append(input_value)
+    input_value[1]['b'] = input_value[1]
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python(input_value)
+
+    assert f'input_value={truncated_repr(input_value)}, ' in str(exc_info.value)
+
+
+def test_large_container_input_repr():
+    class CountRepr:
+        calls = 0
+
+        def __repr__(self):
+            CountRepr.calls += 1
+            return 'CountRepr()'
+
+    s = SchemaValidator(core_schema.int_schema())
+    input_value = [1] * 100 + [CountRepr()] * 100_000 + [2] * 100
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python(input_value)
+
+    assert 'input_value=[1, 1, 1, 1, 1, 1, 1, 1, ... 2, 2, 2, 2, 2, 2, 2, 2], ' in str(exc_info.value)
+    # only the items shown in the truncated repr are rendered
+    assert CountRepr.calls == 0
+
+
+def test_error_on_repr_in_container():
+    s = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python([1, BadRepr()])
+
+    assert 'input_value=<unprintable list object>, ' in str(exc_info.value)
+
+
 def test_error_json(pydantic_version):
     s = SchemaValidator(core_schema.str_schema(min_length=3))
     with pytest.raises(ValidationError) as exc_info:
@@ -1126,6 +1330,377 @@ def test_validation_error_pickle() -> None:
     assert original.errors() == roundtripped.errors()
 
 
+class Unserializable:
+    def __init__(self, value):
+        self.value = value
+
+    def __eq__(self, other):
+        return isinstance(other, Unserializable) and other.value == self.value
+
+    def __repr__(self):
+        return f'Unserializable({self.value})'
+
+
+def test_validation_error_pickle_full_fidelity() -> None:
+    def f(input_value):
+        if input_value == 1:
+            raise ValueError('one')
+        if input_value == 2:
+            raise PydanticCustomError('my_error', 'custom {thing}', {'thing': Unserializable(1)})
+        return input_value
+
+    s = SchemaValidator(
+        core_schema.list_schema(core_schema.no_info_after_validator_function(f, core_schema.int_schema())),
+        config=CoreConfig(title='Things', validation_error_cause=True),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python([1, 2, Unserializable(3)])
+
+    original = exc_info.value
+    roundtripped = pickle.loads(pickle.dumps(original))
+    assert roundtripped.title == 'Things'
+    # exceptions don't compare equal to their copies
+    assert repr(roundtripped.errors()[0].pop('ctx')) == repr(original.errors()[0].pop('ctx'))
+    assert roundtripped.errors()[1:] == original.errors()[1:]
+    assert roundtripped.errors()[1]['ctx'] == {'thing': Unserializable(1)}
+    assert roundtripped.errors()[2]['input'] == Unserializable(3)
+    assert str(roundtripped) == str(original)
+    assert isinstance(roundtripped.__cause__, type(original.__cause__))
+    assert [repr(e) for e in roundtripped.__cause__.exceptions] == ["ValueError('one')"]
+
+    # JSON inputs keep their messages, as do messages replaced with `error_message_templates`
+    s = SchemaValidator(
+        core_schema.dict_schema(), config=CoreConfig(error_message_templates={'dict_type': 'Not a mapping'})
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_json('1')
+    roundtripped = pickle.loads(pickle.dumps(exc_info.value))
+    assert roundtripped.errors() == exc_info.value.errors()
+    assert roundtripped.errors()[0]['msg'] == 'Not a mapping'
+    assert str(roundtripped) == str(exc_info.value)
+
+
+def test_from_exception_data_full_representation() -> None:
+    cause = ValueError('cause')
+    error = ValidationError.from_exception_data(
+        'Foobar',
+        [
+            {'type': 'dict_type', 'input': 1, 'msg_template': 'Not a mapping: {input}'},
+            {'type': PydanticCustomError('my_error', 'custom {a}', {'a': 1}), 'input': 2},
+        ],
+        input_type='json',
+        cause=cause,
+    )
+    assert [e['msg'] for e in error.errors()] == ['Not a mapping: {input}', 'custom 1']
+    assert error.__cause__ is cause
+
+
+def test_loc_as_pointer():
+    s = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a/b': core_schema.typed_dict_field(core_schema.int_schema()),
+                'c~d': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python({'a/b': 'x', 'c~d': [1, 'y']})
+
+    assert [e['loc'] for e in exc_info.value.errors(loc_as_pointer=True)] == ['/a~1b', '/c~0d/1']
+    assert [e['loc'] for e in exc_info.value.errors()] == [('a/b',), ('c~d', 1)]
+    assert [e['loc'] for e in json.loads(exc_info.value.json(loc_as_pointer=True))] == ['/a~1b', '/c~0d/1']
+
+
+def test_loc_as_pointer_root():
+    s = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python('x')
+
+    assert exc_info.value.errors(loc_as_pointer=True)[0]['loc'] == ''
+
+
+def test_max_errors():
+    s = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), config=CoreConfig(max_errors=3))
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python(['a'] * 1000)
+
+    assert exc_info.value.error_count() == 3
+    assert exc_info.value.truncated
+    assert [e['loc'] for e in exc_info.value.errors()] == [(0,), (1,), (2,)]
+    assert str(exc_info.value).startswith('3 validation errors for list[int] (truncated, max_errors reached)\n')
+
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_json('["a", "b"]')
+    assert exc_info.value.error_count() == 2
+    assert not exc_info.value.truncated
+
+    # exactly `max_errors` errors, none were left out
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python(['a', 'b', 'c'])
+    assert exc_info.value.error_count() == 3
+    assert not exc_info.value.truncated
+
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python(['a', 'b', 'c', 'd'])
+    assert exc_info.value.error_count() == 3
+    assert exc_info.value.truncated
+
+
+def test_max_errors_per_call():
+    s = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), config=CoreConfig(max_errors=3))
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python(['a'] * 10, max_errors=5)
+    assert exc_info.value.error_count() == 5
+
+    s = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python(['a'] * 10)
+    assert exc_info.value.error_count() == 10
+    assert not exc_info.value.truncated
+
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_json('["a", "b", "c"]', max_errors=1)
+    assert exc_info.value.error_count() == 1
+
+    s = SchemaValidator(core_schema.dict_schema(values_schema=core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_strings({'a': 'x', 'b': 'y', 'c': 'z'}, max_errors=2)
+    assert exc_info.value.error_count() == 2
+
+    with pytest.raises(ValueError, match='^`max_errors` must be at least 1$'):
+        s.validate_python([], max_errors=0)
+    with pytest.raises(ValueError, match='^`max_errors` must be at least 1$'):
+        s.validate_json('[]', max_errors=0)
+
+
+def test_max_errors_config_zero():
+    with pytest.raises(SchemaError, match='`max_errors` must be at least 1'):
+        SchemaValidator(core_schema.int_schema(), config=CoreConfig(max_errors=0))
+
+
+def test_max_errors_nested():
+    s = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.dict_schema(values_schema=core_schema.int_schema()))},
+            extra_behavior='forbid',
+        ),
+        config=CoreConfig(max_errors=4),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python({'a': {str(i): 'x' for i in range(100)}, **{f'extra{i}': 1 for i in range(100)}})
+
+    assert exc_info.value.error_count() == 4
+    assert exc_info.value.truncated
+    assert {e['type'] for e in exc_info.value.errors()} == {'int_parsing'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python({'a': {}, **{f'extra{i}': 1 for i in range(100)}})
+    assert [e['type'] for e in exc_info.value.errors()] == ['extra_forbidden'] * 4
+
+
+def test_max_errors_pickle():
+    s = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), config=CoreConfig(max_errors=1))
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python(['a', 'b'])
+
+    roundtripped = pickle.loads(pickle.dumps(exc_info.value))
+    assert roundtripped.truncated
+    assert roundtripped.errors() == exc_info.value.errors()
+
+
+def test_fail_fast_per_call():
+    calls = []
+
+    def f(input_value: int) -> int:
+        calls.append(input_value)
+        return input_value
+
+    item_schema = core_schema.no_info_after_validator_function(f, core_schema.int_schema())
+    s = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.list_schema(item_schema)),
+                'b': core_schema.typed_dict_field(core_schema.dict_schema(values_schema=item_schema)),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python({'a': [1, 'x', 2, 'y'], 'b': {'c': 'z'}}, fail_fast=True)
+
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a', 1),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+    assert not exc_info.value.truncated
+    assert calls == [1]
+
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_json('{"a": ["x", "y"], "b": {"c": "z"}}', fail_fast=True)
+    assert exc_info.value.error_count() == 1
+
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_strings({'a': ['x', 'y'], 'b': {'c': 'z'}}, fail_fast=True)
+    assert exc_info.value.error_count() == 1
+
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python({'a': ['x', 'y'], 'b': {'c': 'z'}})
+    assert exc_info.value.error_count() == 3
+
+
+def _positions_validator() -> SchemaValidator:
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+            }
+        ),
+        config=CoreConfig(json_error_positions=True),
+    )
+
+
+def test_json_error_positions() -> None:
+    with pytest.raises(ValidationError) as exc_info:
+        _positions_validator().validate_json('{"a": "x",\n "b": [1, "y"]}')
+
+    assert [(e['loc'], e['position']) for e in exc_info.value.errors()] == [
+        (('a',), {'offset': 6, 'line': 1, 'column': 7}),
+        (('b', 1), {'offset': 21, 'line': 2, 'column': 11}),
+    ]
+    assert exc_info.value.json(include_url=False) == IsJson(
+        [
+            {
+                'type': 'int_parsing',
+                'loc': ['a'],
+                'msg': 'Input should be a valid integer, unable to parse string as an integer',
+                'input': 'x',
+                'position': {'offset': 6, 'line': 1, 'column': 7},
+            },
+            {
+                'type': 'int_parsing',
+                'loc': ['b', 1],
+                'msg': 'Input should be a valid integer, unable to parse string as an integer',
+                'input': 'y',
+                'position': {'offset': 21, 'line': 2, 'column': 11},
+            },
+        ]
+    )
+
+
+def test_json_error_positions_missing_field() -> None:
+    with pytest.raises(ValidationError) as exc_info:
+        _positions_validator().validate_json('  {"a": 1}')
+
+    # the missing field points at the enclosing object
+    assert exc_info.value.errors()[0]['position'] == {'offset': 2, 'line': 1, 'column': 3}
+
+
+def test_json_error_positions_duplicate_key() -> None:
+    with pytest.raises(ValidationError) as exc_info:
+        _positions_validator().validate_json('{"a": "é", "a": "x", "b": []}')
+
+    # the last value of a duplicated key is kept, so that's the value the position refers to
+    error = exc_info.value.errors()[0]
+    assert (error['loc'], error['input']) == (('a',), 'x')
+    assert error['position'] == {'offset': 17, 'line': 1, 'column': 18}
+
+
+def test_json_error_positions_not_following_loc() -> None:
+    # the position is where the input was found, even where the location doesn't follow the JSON, e.g. with the tag
+    # of a tagged union in the location, or a field's name rather than its alias
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'p': core_schema.typed_dict_field(
+                    core_schema.tagged_union_schema(
+                        {
+                            'x': core_schema.typed_dict_schema(
+                                {
+                                    'kind': core_schema.typed_dict_field(core_schema.str_schema()),
+                                    'x': core_schema.typed_dict_field(core_schema.int_schema()),
+                                }
+                            )
+                        },
+                        discriminator='kind',
+                    )
+                ),
+                'field': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='f'),
+            },
+            config=CoreConfig(loc_by_alias=False),
+        ),
+        config=CoreConfig(json_error_positions=True),
+    )
+    json_data = '{"p": {"kind": "x", "x": {"x": 1}}, "field": 3, "f": "bad"}'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(json_data)
+
+    errors = exc_info.value.errors()
+    assert [(e['loc'], e['position']['offset']) for e in errors] == [(('p', 'x', 'x'), 25), (('field',), 53)]
+    assert [json_data[e['position']['offset'] :][:8] for e in errors] == ['{"x": 1}', '"bad"}']
+
+
+def test_json_error_positions_keys() -> None:
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.str_schema(max_length=2), core_schema.int_schema()),
+        config=CoreConfig(json_error_positions=True),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"ab": 1,\n "a\\u0062c": 2}')
+
+    error = exc_info.value.errors()[0]
+    assert error['loc'] == ('abc', '[key]')
+    assert error['position'] == {'offset': 11, 'line': 2, 'column': 2}
+
+
+def test_json_error_positions_wrap_validator() -> None:
+    # the handler of a wrap validator validates a Python value, its errors are placed by following their location
+    v = SchemaValidator(
+        core_schema.no_info_wrap_validator_function(
+            lambda value, handler: handler(value), core_schema.list_schema(core_schema.int_schema())
+        ),
+        config=CoreConfig(json_error_positions=True),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, "x"]')
+    assert exc_info.value.errors()[0]['position'] == {'offset': 4, 'line': 1, 'column': 5}
+
+
+def test_json_error_positions_invalid_json() -> None:
+    with pytest.raises(ValidationError) as exc_info:
+        _positions_validator().validate_json('{"a": 1,\n "b": [1, }')
+
+    error = exc_info.value.errors()[0]
+    assert error['type'] == 'json_invalid'
+    assert error['position'] == {'offset': 19, 'line': 2, 'column': 11}
+
+
+def test_json_error_positions_pickle() -> None:
+    with pytest.raises(ValidationError) as exc_info:
+        _positions_validator().validate_json('{"a": "x", "b": []}')
+
+    original = exc_info.value
+    roundtripped = pickle.loads(pickle.dumps(original))
+    assert roundtripped.errors()[0]['position'] == {'offset': 6, 'line': 1, 'column': 7}
+    assert original.errors() == roundtripped.errors()
+
+
+def test_json_error_positions_default_off() -> None:
+    s = SchemaValidator(core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}))
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_json('{"a": "x"}')
+    assert 'position' not in exc_info.value.errors()[0]
+
+    with pytest.raises(ValidationError) as exc_info:
+        _positions_validator().validate_python({'a': 'x', 'b': []})
+    assert 'position' not in exc_info.value.errors()[0]
+
+
 @pytest.mark.skipif('PYDANTIC_ERRORS_INCLUDE_URL' in os.environ, reason="can't test when envvar is set")
 def test_errors_include_url() -> None:
     s = SchemaValidator(core_schema.int_schema())
@@ -1134,6 +1709,128 @@ def test_errors_include_url() -> None:
     assert 'https://errors.pydantic.dev' in repr(exc_info.value)
 
 
+def _loc_mapping_schema() -> core_schema.CoreSchema:
+    return core_schema.typed_dict_schema(
+        {
+            'user_id': core_schema.typed_dict_field(core_schema.int_schema()),
+            'tags': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+        }
+    )
+
+
+def test_error_loc_mapping_dict() -> None:
+    v = SchemaValidator(_loc_mapping_schema(), config=CoreConfig(error_loc_mapping={'user_id': 'userId', 1: 'second'}))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'user_id': 'x', 'tags': [1, 'y']})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('userId',), ('tags', 'second')]
+    assert 'userId\n  Input should be a valid integer' in str(exc_info.value)
+
+
+def test_error_loc_mapping_callable() -> None:
+    def to_pointer(loc: tuple[Any, ...]) -> tuple[str]:
+        return ('/' + '/'.join(map(str, loc)),)
+
+    v = SchemaValidator(_loc_mapping_schema(), config=CoreConfig(error_loc_mapping=to_pointer))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'tags': [1, 'y']})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('/user_id',), ('/tags/1',)]
+
+    v = SchemaValidator(
+        _loc_mapping_schema(), config=CoreConfig(error_loc_mapping=to_pointer, json_error_positions=True)
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"user_id": 1, "tags": [1, "y"]}')
+    # positions are of the inputs of the errors, whatever their location
+    assert [(e['loc'], e['position']) for e in exc_info.value.errors()] == [
+        (('/tags/1',), {'offset': 27, 'line': 1, 'column': 28})
+    ]
+
+
+def test_error_loc_mapping_callable_error() -> None:
+    def bad_mapping(loc):
+        raise RuntimeError('mapping failed')
+
+    v = SchemaValidator(_loc_mapping_schema(), config=CoreConfig(error_loc_mapping=bad_mapping))
+    with pytest.raises(RuntimeError, match='mapping failed'):
+        v.validate_python({})
+
+    v = SchemaValidator(_loc_mapping_schema(), config=CoreConfig(error_loc_mapping=lambda loc: 'not a loc'))
+    with pytest.raises(TypeError, match='Location must be a list or tuple of strings and ints'):
+        v.validate_python({})
+
+
+def test_error_loc_mapping_invalid() -> None:
+    with pytest.raises(SchemaError, match='`error_loc_mapping` must be a dict or a callable'):
+        SchemaValidator(core_schema.int_schema(), config=CoreConfig(error_loc_mapping=['a']))
+
+
+def test_error_message_templates_dict() -> None:
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.int_schema(gt=0), max_length=2),
+        config=CoreConfig(error_message_templates={'greater_than': 'Doit être supérieur à {gt}'}),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([0, 'x'])
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'greater_than', 'loc': (0,), 'msg': 'Doit être supérieur à 0', 'input': 0, 'ctx': {'gt': 0}},
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+    ]
+    assert exc_info.value.errors()[0]['url'].endswith('/v/greater_than')
+
+
+def test_error_message_templates_computed_placeholders() -> None:
+    v = SchemaValidator(
+        core_schema.str_schema(min_length=1, max_length=3),
+        config=CoreConfig(
+            error_message_templates={
+                'string_too_short': 'Au moins {min_length} caractère{expected_plural}',
+                'string_too_long': 'Au plus {max_length} caractère{expected_plural}',
+            }
+        ),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('')
+    assert exc_info.value.errors()[0]['msg'] == 'Au moins 1 caractère'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('abcd')
+    assert exc_info.value.errors()[0]['msg'] == 'Au plus 3 caractères'
+    assert 'Au plus 3 caractères [type=string_too_long' in str(exc_info.value)
+
+
+def test_error_message_templates_callable() -> None:
+    catalog = {'Input should be greater than {gt}': 'Eingabe muss größer als {gt} sein'}
+    calls = []
+
+    def translate(error_type: str, message_template: str, context: Any) -> Any:
+        calls.append((error_type, message_template, context))
+        return catalog.get(message_template)
+
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.int_schema(gt=0)), config=CoreConfig(error_message_templates=translate)
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([0, 'x'])
+    assert [e['msg'] for e in exc_info.value.errors()] == [
+        'Eingabe muss größer als 0 sein',
+        'Input should be a valid integer, unable to parse string as an integer',
+    ]
+    assert calls == [
+        ('greater_than', 'Input should be greater than {gt}', {'gt': 0}),
+        ('int_parsing', 'Input should be a valid integer, unable to parse string as an integer', None),
+    ]
+
+
+def test_error_message_templates_invalid() -> None:
+    with pytest.raises(SchemaError, match='`error_message_templates` must be a dict or a callable'):
+        SchemaValidator(core_schema.int_schema(), config=CoreConfig(error_message_templates='fr'))
+
+
 @pytest.mark.skipif(sys.platform == 'emscripten', reason='no subprocesses on emscripten')
 @pytest.mark.parametrize(
     ('env_var', 'env_var_value', 'expected_to_have_url'),
@@ -0,0 +1,91 @@
+from dataclasses import dataclass
+
+from pydantic_core import SchemaValidator, core_schema
+
+
+class Model:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+def test_explain_typed_dict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.float_schema()),
+                'c': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.str_schema(), default='x')
+                ),
+                'inner': core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema({'d': core_schema.typed_dict_field(core_schema.bool_schema())})
+                ),
+            }
+        )
+    )
+    assert v.explain({'a': '1', 'b': 2, 'inner': {'d': 'yes'}}) == {
+        'valid': True,
+        'coercions': [
+            {'loc': ('a',), 'from_type': 'str', 'to_type': 'int'},
+            {'loc': ('inner', 'd'), 'from_type': 'str', 'to_type': 'bool'},
+        ],
+        'defaults': [{'loc': ('c',), 'value': 'x'}],
+        'errors': [],
+    }
+    assert v.explain({'a': 1, 'b': 2.0, 'c': 'y', 'inner': {'d': True}}) == {
+        'valid': True,
+        'coercions': [],
+        'defaults': [],
+        'errors': [],
+    }
+
+
+def test_explain_errors():
+    v = SchemaValidator(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(core_schema.list_schema(core_schema.int_schema())),
+                }
+            ),
+        )
+    )
+    explanation = v.explain({'a': 'x', 'b': ['1', 2]})
+    assert explanation['valid'] is False
+    assert explanation['coercions'] == [{'loc': ('b',), 'from_type': 'list', 'to_type': 'list'}]
+    assert [(e['type'], e['loc']) for e in explanation['errors']] == [('int_parsing', ('a',))]
+
+    # in strict mode the coercion is an error instead
+    explanation = v.explain({'a': 1, 'b': ['1', 2]}, strict=True)
+    assert explanation['coercions'] == []
+    assert [(e['type'], e['loc']) for e in explanation['errors']] == [('int_type', ('b', 0))]
+
+
+def test_explain_dataclass():
+    @dataclass
+    class Foo:
+        a: int
+        b: str = 'default'
+
+    v = SchemaValidator(
+        core_schema.dataclass_schema(
+            Foo,
+            core_schema.dataclass_args_schema(
+                'Foo',
+                [
+                    core_schema.dataclass_field('a', core_schema.int_schema()),
+                    core_schema.dataclass_field(
+                        'b', core_schema.with_default_schema(core_schema.str_schema(), default='default')
+                    ),
+                ],
+            ),
+            ['a', 'b'],
+        )
+    )
+    assert v.explain({'a': 1.0}) == {
+        'valid': True,
+        'coercions': [{'loc': ('a',), 'from_type': 'float', 'to_type': 'int'}],
+        'defaults': [{'loc': ('b',), 'value': 'default'}],
+        'errors': [],
+    }
@@ -0,0 +1,315 @@
+import dataclasses
+import re
+from datetime import date, datetime, time, timedelta
+from decimal import Decimal
+from enum import Enum
+from uuid import UUID
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def examples(schema, count=20):
+    v = SchemaValidator(schema)
+    return [v.generate_example(seed=seed) for seed in range(count)]
+
+
+def test_seed():
+    schema = core_schema.list_schema(core_schema.int_schema())
+    v = SchemaValidator(schema)
+    assert v.generate_example(seed=1) == v.generate_example(seed=1)
+    assert len({repr(v.generate_example(seed=seed)) for seed in range(20)}) > 1
+    assert isinstance(v.generate_example(), list)
+
+
+@pytest.mark.parametrize(
+    'schema,check',
+    [
+        (core_schema.int_schema(ge=10, lt=13), lambda v: v in (10, 11, 12)),
+        (core_schema.int_schema(gt=-5, le=-3), lambda v: v in (-4, -3)),
+        (core_schema.int_schema(ge=0, multiple_of=7), lambda v: v >= 0 and v % 7 == 0),
+        (core_schema.int_schema(lt=-1000), lambda v: v < -1000),
+        (core_schema.float_schema(gt=0, lt=1), lambda v: 0 < v < 1),
+        (core_schema.float_schema(ge=1, le=1), lambda v: v == 1),
+        (core_schema.float_schema(multiple_of=0.5, ge=2, le=4), lambda v: v in (2, 2.5, 3, 3.5, 4)),
+        (core_schema.decimal_schema(gt=1, le=2), lambda v: isinstance(v, Decimal) and 1 < v <= 2),
+        (core_schema.decimal_schema(multiple_of=Decimal('0.1'), ge=0, le=1), lambda v: v * 10 == int(v * 10)),
+        (core_schema.str_schema(min_length=5, max_length=6), lambda v: 5 <= len(v) <= 6),
+        (core_schema.str_schema(max_length=0), lambda v: v == ''),
+        (core_schema.bytes_schema(min_length=2, max_length=2), lambda v: isinstance(v, bytes) and len(v) == 2),
+        (core_schema.bool_schema(), lambda v: isinstance(v, bool)),
+        (core_schema.none_schema(), lambda v: v is None),
+        (core_schema.date_schema(gt=date(2020, 1, 1), lt=date(2020, 1, 3)), lambda v: v == date(2020, 1, 2)),
+        (core_schema.date_schema(), lambda v: date(2000, 1, 1) <= v <= date(2030, 12, 31)),
+        (core_schema.datetime_schema(tz_constraint='aware'), lambda v: v.tzinfo is not None),
+        (
+            core_schema.datetime_schema(ge=datetime(2010, 1, 1), le=datetime(2010, 1, 2)),
+            lambda v: datetime(2010, 1, 1) <= v <= datetime(2010, 1, 2) and v.tzinfo is None,
+        ),
+        (core_schema.time_schema(ge=time(12), lt=time(13)), lambda v: v.hour == 12),
+        (core_schema.timedelta_schema(ge=timedelta(hours=1), le=timedelta(hours=2)), lambda v: 3600 <= v.seconds),
+        (core_schema.uuid_schema(version=4), lambda v: isinstance(v, UUID) and v.version == 4),
+        (core_schema.uuid_schema(version=7), lambda v: v.version == 7),
+    ],
+)
+def test_constraints(schema, check):
+    for example in examples(schema):
+        assert check(example), example
+
+
+@pytest.mark.parametrize(
+    'pattern', [r'^\d{3}-\d{4}$', r'[A-Z][a-z]+', r'(cat|dog)s?', r'^[\w.]+@example\.com$', r'x{2,}']
+)
+def test_pattern(pattern):
+    for example in examples(core_schema.str_schema(pattern=pattern)):
+        assert re.search(pattern, example), example
+
+
+def test_pattern_and_length():
+    for example in examples(core_schema.str_schema(pattern=r'^a+$', min_length=2, max_length=3)):
+        assert example in ('aa', 'aaa')
+
+
+def test_literal_and_enum():
+    assert set(examples(core_schema.literal_schema(['a', 1, None]), 50)) == {'a', 1, None}
+
+    class Color(Enum):
+        RED = 1
+        GREEN = 2
+
+    assert set(examples(core_schema.enum_schema(Color, list(Color.__members__.values())), 50)) == set(Color)
+
+
+def test_containers():
+    schema = core_schema.dict_schema(
+        core_schema.str_schema(),
+        core_schema.list_schema(core_schema.int_schema(ge=0, le=9), min_length=1, max_length=2),
+        min_length=2,
+    )
+    for example in examples(schema):
+        assert len(example) >= 2
+        assert all(isinstance(k, str) for k in example)
+        assert all(1 <= len(v) <= 2 and all(0 <= i <= 9 for i in v) for v in example.values())
+
+    for example in examples(core_schema.set_schema(core_schema.bool_schema(), min_length=2)):
+        assert example == {True, False}
+    for example in examples(core_schema.frozenset_schema(core_schema.int_schema(), max_length=1)):
+        assert isinstance(example, frozenset) and len(example) <= 1
+
+
+def test_tuple():
+    schema = core_schema.tuple_schema(
+        [core_schema.str_schema(), core_schema.int_schema(), core_schema.bool_schema()],
+        variadic_item_index=1,
+        min_length=4,
+    )
+    for example in examples(schema):
+        assert len(example) >= 4
+        assert isinstance(example[0], str)
+        assert all(isinstance(i, int) for i in example[1:-1])
+        assert isinstance(example[-1], bool)
+
+
+def test_typed_dict():
+    schema = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='A'),
+            'b': core_schema.typed_dict_field(core_schema.str_schema(), required=False),
+            'c': core_schema.typed_dict_field(core_schema.with_default_schema(core_schema.int_schema(), default=0)),
+        }
+    )
+    results = examples(schema, 50)
+    assert all(isinstance(e['a'], int) for e in results)
+    assert {'b' in e for e in results} == {True, False}
+    assert all(isinstance(e['c'], int) for e in results)
+
+
+def test_model_and_dataclass():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    schema = core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema(
+            {
+                'x': core_schema.model_field(core_schema.int_schema(gt=0)),
+                'y': core_schema.model_field(core_schema.literal_schema(['on', 'off'])),
+            }
+        ),
+    )
+    for example in examples(schema):
+        assert isinstance(example, MyModel)
+        assert example.x > 0 and example.y in ('on', 'off')
+
+    @dataclasses.dataclass
+    class Point:
+        x: float
+        y: float
+
+    schema = core_schema.dataclass_schema(
+        Point,
+        core_schema.dataclass_args_schema(
+            'Point',
+            [
+                core_schema.dataclass_field('x', core_schema.float_schema(ge=0, le=1)),
+                core_schema.dataclass_field('y', core_schema.float_schema(ge=0, le=1)),
+            ],
+        ),
+        ['x', 'y'],
+    )
+    for example in examples(schema):
+        assert isinstance(example, Point)
+        assert 0 <= example.x <= 1 and 0 <= example.y <= 1
+
+
+def test_tagged_union():
+    schema = core_schema.tagged_union_schema(
+        {
+            'cat': core_schema.typed_dict_schema(
+                {
+                    'pet': core_schema.typed_dict_field(core_schema.literal_schema(['cat'])),
+                    'meows': core_schema.typed_dict_field(core_schema.int_schema()),
+                }
+            ),
+            'dog': core_schema.typed_dict_schema(
+                {
+                    'pet': core_schema.typed_dict_field(core_schema.literal_schema(['dog'])),
+                    'barks': core_schema.typed_dict_field(core_schema.float_schema()),
+                }
+            ),
+        },
+        discriminator='pet',
+    )
+    results = examples(schema, 30)
+    assert {e['pet'] for e in results} == {'cat', 'dog'}
+    assert all(('meows' in e) == (e['pet'] == 'cat') for e in results)
+
+
+def test_recursive():
+    schema = core_schema.definitions_schema(
+        core_schema.definition_reference_schema('node'),
+        [
+            core_schema.typed_dict_schema(
+                {
+                    'value': core_schema.typed_dict_field(core_schema.int_schema()),
+                    'children': core_schema.typed_dict_field(
+                        core_schema.list_schema(core_schema.definition_reference_schema('node'))
+                    ),
+                    'parent': core_schema.typed_dict_field(
+                        core_schema.nullable_schema(core_schema.definition_reference_schema('node'))
+                    ),
+                },
+                ref='node',
+            )
+        ],
+    )
+    for example in examples(schema):
+        assert isinstance(example['value'], int)
+
+
+def test_recursion_never_ends():
+    schema = core_schema.definitions_schema(
+        core_schema.definition_reference_schema('node'),
+        [
+            core_schema.typed_dict_schema(
+                {'child': core_schema.typed_dict_field(core_schema.definition_reference_schema('node'))},
+                ref='node',
+            )
+        ],
+    )
+    with pytest.raises(ValueError, match="Can't generate an example for `typed-dict`, its recursion doesn't end"):
+        SchemaValidator(schema).generate_example(seed=0)
+
+
+def test_functions():
+    calls = []
+
+    def double(value):
+        calls.append(value)
+        return value * 2
+
+    schema = core_schema.no_info_after_validator_function(double, core_schema.int_schema(ge=1, le=3))
+    assert SchemaValidator(schema).generate_example(seed=0) in (2, 4, 6)
+    assert len(calls) == 1
+
+    schema = core_schema.no_info_plain_validator_function(lambda v: v)
+    with pytest.raises(TypeError, match="Can't generate an example for `function-plain\\[<lambda>\\(\\)\\]`"):
+        SchemaValidator(schema).generate_example()
+
+    def broken(value):
+        raise RuntimeError('broken')
+
+    schema = core_schema.no_info_after_validator_function(broken, core_schema.int_schema())
+    with pytest.raises(RuntimeError, match='broken'):
+        SchemaValidator(schema).generate_example()
+
+
+def test_invalid_after_attempts():
+    def odd(value):
+        if value % 2 == 0:
+            raise ValueError('even')
+        return value
+
+    schema = core_schema.no_info_after_validator_function(odd, core_schema.int_schema(multiple_of=2))
+    with pytest.raises(ValueError, match="Couldn't generate a valid example in 20 attempts") as exc_info:
+        SchemaValidator(schema).generate_example(seed=0)
+    assert isinstance(exc_info.value.__cause__, ValidationError)
+
+
+def test_context():
+    def info_func(value, info):
+        return (value, info.context)
+
+    schema = core_schema.with_info_after_validator_function(info_func, core_schema.literal_schema([1]))
+    assert SchemaValidator(schema).generate_example(seed=0, context={'a': 1}) == (1, {'a': 1})
+
+
+def test_aware_datetime_offset():
+    schema = core_schema.datetime_schema(tz_constraint=3600)
+    for example in examples(schema):
+        assert example.utcoffset() == timedelta(hours=1)
+    assert examples(core_schema.datetime_schema(tz_constraint='aware'))[0].utcoffset() == timedelta(0)
+
+
+@pytest.mark.parametrize(
+    'schema',
+    [
+        core_schema.decimal_schema(max_digits=3),
+        core_schema.decimal_schema(max_digits=4, decimal_places=1),
+        core_schema.decimal_schema(max_digits=2, ge=0),
+        core_schema.decimal_schema(max_digits=5, decimal_places=5),
+        core_schema.decimal_schema(max_digits=3, multiple_of=Decimal('0.5')),
+    ],
+)
+def test_decimal_max_digits(schema):
+    v = SchemaValidator(schema)
+    for seed in range(20):
+        example = v.generate_example(seed=seed)
+        assert v.validate_python(example) == example
+        digits = example.as_tuple().digits
+        assert len(digits) <= schema['max_digits'], example
+
+
+@pytest.mark.parametrize('now_op', ['past', 'future'])
+def test_now_op(now_op):
+    # the current time is outside the default range of examples, from 2000 to 2030
+    now = datetime(2040, 6, 1) if now_op == 'future' else datetime(1990, 6, 1)
+    config = core_schema.CoreConfig(clock=now)
+    for schema, now in (
+        (core_schema.date_schema(now_op=now_op), now.date()),
+        (core_schema.datetime_schema(now_op=now_op), now),
+    ):
+        v = SchemaValidator(schema, config)
+        for seed in range(10):
+            example = v.generate_example(seed=seed)
+            assert v.validate_python(example) == example
+            assert (example > now) if now_op == 'future' else (example < now)
+
+
+def test_now_op_context():
+    v = SchemaValidator(core_schema.date_schema(now_op='future'), core_schema.CoreConfig(clock_context_key='now'))
+    context = {'now': datetime(2050, 1, 1)}
+    example = v.generate_example(seed=0, context=context)
+    assert example > date(2050, 1, 1)
+    assert v.validate_python(example, context=context) == example
@@ -0,0 +1,159 @@
+import pytest
+
+from pydantic_core import IncrementalValidation, SchemaValidator, ValidationError, core_schema
+
+
+class MyModel:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+def counting_schema(calls: list, schema: core_schema.CoreSchema) -> core_schema.CoreSchema:
+    def record(value):
+        calls.append(value)
+        return value
+
+    return core_schema.no_info_after_validator_function(record, schema)
+
+
+def test_incremental_list():
+    calls = []
+    v = SchemaValidator(core_schema.list_schema(counting_schema(calls, core_schema.int_schema())))
+    incremental = v.incremental_json()
+    assert isinstance(incremental, IncrementalValidation)
+    assert incremental.value is None
+    assert repr(incremental) == 'IncrementalValidation(length=0)'
+
+    assert incremental.resume('[1, 2') == [1, 2]
+    assert incremental.resume('3, 4') == [1, 23, 4]
+    assert incremental.resume(b', 5]') == [1, 23, 4, 5]
+    assert incremental.finish() == [1, 23, 4, 5]
+    assert incremental.value == [1, 23, 4, 5]
+    assert repr(incremental) == 'IncrementalValidation(length=13)'
+
+    # each item is validated when it's the last item, and once more when it's complete
+    assert calls == [1, 2, 23, 4, 4, 5, 5]
+
+
+def test_incremental_model():
+    calls = []
+    item_schema = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.int_schema()),
+            'b': core_schema.typed_dict_field(core_schema.str_schema(), required=False),
+        }
+    )
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'name': core_schema.model_field(core_schema.str_schema()),
+                    'items': core_schema.model_field(core_schema.list_schema(counting_schema(calls, item_schema))),
+                }
+            ),
+        )
+    )
+    doc = '{"name": "x", "items": [{"a": 1, "b": "one"}, {"a": 2, "b": "two"}, {"a": 3}]}'
+    incremental = v.incremental_json(allow_partial='trailing-strings')
+
+    # until the required fields have arrived the document is invalid, validation can still be resumed
+    with pytest.raises(ValidationError, match=r'items\s+Field required'):
+        incremental.resume(doc[:14])
+    assert incremental.value is None
+
+    m = incremental.resume(doc[14:40])
+    assert m.__dict__ == {'name': 'x', 'items': [{'a': 1, 'b': 'o'}]}
+    m = incremental.resume(doc[40:60])
+    assert m.__dict__ == {'name': 'x', 'items': [{'a': 1, 'b': 'one'}, {'a': 2}]}
+    m = incremental.finish(doc[60:])
+    assert m.__dict__ == {'name': 'x', 'items': [{'a': 1, 'b': 'one'}, {'a': 2, 'b': 'two'}, {'a': 3}]}
+    assert incremental.value is m
+
+    assert calls == [{'a': 1, 'b': 'o'}, {'a': 1, 'b': 'one'}, {'a': 2}, {'a': 2, 'b': 'two'}, {'a': 3}]
+
+
+def test_incremental_dict():
+    calls = []
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.str_schema(), counting_schema(calls, core_schema.str_schema()))
+    )
+    incremental = v.incremental_json(allow_partial='trailing-strings')
+    assert incremental.resume('{"a": "x", "b": "y') == {'a': 'x', 'b': 'y'}
+    assert incremental.resume('z", "c": "') == {'a': 'x', 'b': 'yz', 'c': ''}
+    assert incremental.finish('w"}') == {'a': 'x', 'b': 'yz', 'c': 'w'}
+    assert calls == ['x', 'y', 'yz', '', 'w']
+
+
+def test_incremental_not_reused():
+    """Outputs aren't reused by validators which may validate the input differently, e.g. function validators."""
+    calls = []
+    v = SchemaValidator(
+        core_schema.no_info_before_validator_function(
+            lambda x: x, core_schema.list_schema(counting_schema(calls, core_schema.int_schema()))
+        )
+    )
+    incremental = v.incremental_json()
+    assert incremental.resume('[1, 2') == [1, 2]
+    assert incremental.finish(']') == [1, 2]
+    assert calls == [1, 2, 1, 2]
+
+
+def test_incremental_errors():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    incremental = v.incremental_json()
+    assert incremental.resume('[1, 2') == [1, 2]
+    with pytest.raises(ValidationError, match=r'2\s+Input should be a valid integer'):
+        incremental.resume(', "x", 3')
+    assert incremental.value == [1, 2]
+
+    with pytest.raises(ValidationError, match=r'Invalid JSON: EOF while parsing a list'):
+        v.incremental_json().finish('[1, 2')
+    with pytest.raises(TypeError, match='JSON input should be a str, bytes or bytearray'):
+        v.incremental_json().resume(1)
+    with pytest.raises(ValueError, match="`allow_partial` must be `True` or 'trailing-strings'"):
+        v.incremental_json(allow_partial=False)
+
+
+@pytest.mark.parametrize(
+    'doc',
+    [
+        '[1, 2.5, "a, b]", [3, [4]], {"c": "d}"}, "e\\"f", null, true]',
+        ' {"a": 1 , "b": [1, 2], "a": {"c": "}"}, "d": "e"}\n',
+        '[\n  1,\n  2\n]\n',
+        '"abc"',
+    ],
+)
+def test_incremental_parts(doc):
+    v = SchemaValidator(core_schema.any_schema())
+    for size in range(1, len(doc)):
+        incremental = v.incremental_json(allow_partial='trailing-strings')
+        parts = [doc[i : i + size] for i in range(0, len(doc), size)]
+        for index, part in enumerate(parts[:-1]):
+            received = ''.join(parts[: index + 1])
+            try:
+                expected = v.validate_json(received, allow_partial='trailing-strings')
+            except ValidationError:
+                with pytest.raises(ValidationError):
+                    incremental.resume(part)
+            else:
+                assert incremental.resume(part) == expected
+        assert incremental.finish(parts[-1]) == v.validate_json(doc)
+
+
+@pytest.mark.parametrize(
+    'parts,error',
+    [
+        (['[1, 2,', ']'], 'trailing comma at line 1 column 7'),
+        (['{"a": 1,', ' }'], 'trailing comma at line 1 column 10'),
+        (['[1, 2, 3', ',\n', 'x]'], 'expected value at line 2 column 1'),
+        (['[1, 2]', ' 3'], 'trailing characters at line 1 column 8'),
+    ],
+)
+def test_incremental_invalid_json(parts, error):
+    v = SchemaValidator(core_schema.any_schema())
+    incremental = v.incremental_json()
+    for part in parts[:-1]:
+        incremental.resume(part)
+    # the error is at its position in the whole document, not in the JSON parsed since the last part
+    with pytest.raises(ValidationError, match=f'Invalid JSON: {error}'):
+        incremental.finish(parts[-1])
@@ -0,0 +1,153 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def limit_error(exc_info):
+    (error,) = exc_info.value.errors(include_url=False)
+    return error['type'], error['loc'], error['ctx']
+
+
+def test_max_input_length():
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.str_schema(), core_schema.list_schema(core_schema.any_schema())),
+        config={'max_input_length': 3},
+    )
+    assert v.validate_python({'abc': ['def', b'ghi', 1234]}) == {'abc': ['def', b'ghi', 1234]}
+    assert v.validate_json('{"abc": ["def"]}') == {'abc': ['def']}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'abc': ['def', b'ghij']})
+    assert limit_error(exc_info) == ('input_limit_exceeded', ('abc', 1), {'limit': 'length', 'max': 3})
+    assert exc_info.value.errors(include_url=False)[0]['msg'] == 'Input exceeds the length limit of 3'
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"abcd": []}')
+    assert limit_error(exc_info) == ('input_limit_exceeded', ('abcd', '[key]'), {'limit': 'length', 'max': 3})
+
+    # the input is checked before it's validated, even where the schema would ignore it
+    v = SchemaValidator(core_schema.any_schema(), config={'max_input_length': 3})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('["abcd"]')
+    assert limit_error(exc_info) == ('input_limit_exceeded', (0,), {'limit': 'length', 'max': 3})
+
+
+def test_max_input_length_json_numbers():
+    v = SchemaValidator(core_schema.any_schema(), config={'max_input_length': 5})
+    assert v.validate_json('[12345, 1.5e3]') == [12345, 1500.0]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": [1, ' + '9' * 10_000 + ']}')
+    assert limit_error(exc_info) == ('input_limit_exceeded', ('a', 1), {'limit': 'length', 'max': 5})
+    assert exc_info.value.errors()[0]['input'] == '9' * 10_000
+
+
+def test_json_checked_before_parsing():
+    v = SchemaValidator(core_schema.any_schema(), config={'max_input_items': 2})
+    # the input of JSON errors is the text of the value exceeding the limit
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": [1, 2, 3], "b": 1}')
+    assert limit_error(exc_info) == ('input_limit_exceeded', ('a',), {'limit': 'items', 'max': 2})
+    assert exc_info.value.errors()[0]['input'] == '[1, 2, 3]'
+
+    # the data is checked up to the limit, the invalid JSON after it isn't reached
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, 2, 3, oops')
+    assert limit_error(exc_info) == ('input_limit_exceeded', (), {'limit': 'items', 'max': 2})
+
+    # invalid JSON before the limit is reported by the parser
+    with pytest.raises(ValidationError, match=r'Invalid JSON'):
+        v.validate_json('[1, oops, 2, 3]')
+
+
+def test_partial_json():
+    v = SchemaValidator(core_schema.list_schema(core_schema.str_schema()), config={'max_input_length': 3})
+    assert v.validate_json('["abc", "de', allow_partial=True) == ['abc']
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('["abc", "defg', allow_partial='trailing-strings')
+    assert limit_error(exc_info) == ('input_limit_exceeded', (1,), {'limit': 'length', 'max': 3})
+
+
+def test_max_input_items():
+    v = SchemaValidator(core_schema.any_schema(), config={'max_input_items': 2})
+    assert v.validate_python([(1, 2), {1: frozenset({2, 3})}]) == [(1, 2), {1: frozenset({2, 3})}]
+
+    for input_value in [[1, 2, 3], (1, 2, 3), {1, 2, 3}, frozenset({1, 2, 3}), {'a': 1, 'b': 2, 'c': 3}]:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert limit_error(exc_info) == ('input_limit_exceeded', (), {'limit': 'items', 'max': 2})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": [1, [1, 2, 3]]}')
+    assert limit_error(exc_info) == ('input_limit_exceeded', ('a', 1), {'limit': 'items', 'max': 2})
+
+
+def test_max_input_depth():
+    v = SchemaValidator(core_schema.any_schema(), config={'max_input_depth': 2})
+    # only the builtin containers are looked into
+    values = {'a': [(2,)]}.values()
+    assert v.validate_python([[1], values]) == [[1], values]
+    assert v.validate_json('[[1], {"a": 2}]') == [[1], {'a': 2}]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': [[1]]})
+    assert limit_error(exc_info) == ('input_limit_exceeded', ('a', 0), {'limit': 'depth', 'max': 2})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[{"a": [1]}]')
+    assert limit_error(exc_info) == ('input_limit_exceeded', (0, 'a'), {'limit': 'depth', 'max': 2})
+
+
+def test_max_input_depth_deeper_than_recursion_limit():
+    deep_json = '[' * 3000 + ']' * 3000
+    deep_list = []
+    for _ in range(100_000):
+        deep_list = [deep_list]
+
+    v = SchemaValidator(core_schema.any_schema(), config={'max_input_depth': 10**6})
+    # JSON deeper than the parser's recursion limit is left to the parser
+    with pytest.raises(ValidationError, match=r'recursion limit exceeded'):
+        v.validate_json(deep_json)
+    assert v.validate_python(deep_list) is deep_list
+
+    v = SchemaValidator(core_schema.any_schema(), config={'max_input_depth': 50_000})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(deep_list)
+    assert limit_error(exc_info) == ('input_limit_exceeded', (0,) * 50_000, {'limit': 'depth', 'max': 50_000})
+
+
+def test_cyclic_input():
+    v = SchemaValidator(core_schema.list_schema(core_schema.any_schema()), config={'max_input_depth': 10})
+    cyclic = []
+    cyclic.append(cyclic)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(cyclic)
+    assert limit_error(exc_info) == ('input_limit_exceeded', (0,) * 10, {'limit': 'depth', 'max': 10})
+
+    # without a depth limit, the cycle is left to validation
+    v = SchemaValidator(core_schema.list_schema(core_schema.any_schema()), config={'max_input_items': 10})
+    assert v.validate_python(cyclic) == cyclic
+
+
+def test_shared_items():
+    v = SchemaValidator(core_schema.any_schema(), config={'max_input_depth': 40})
+    shared = []
+    for _ in range(30):
+        shared = [shared, shared]
+    assert v.validate_python(shared) is shared
+
+
+def test_validate_strings():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}),
+        config={'max_input_length': 3},
+    )
+    assert v.validate_strings({'a': '123'}) == {'a': 123}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings({'a': '1234'})
+    assert limit_error(exc_info) == ('input_limit_exceeded', ('a',), {'limit': 'length', 'max': 3})
+
+
+def test_no_limits():
+    v = SchemaValidator(core_schema.any_schema())
+    assert v.validate_python(['a' * 10_000] * 10_000) == ['a' * 10_000] * 10_000
@@ -0,0 +1,190 @@
+import dataclasses
+
+import pytest
+
+from pydantic_core import SchemaValidator, core_schema
+
+
+class MyModel:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+@dataclasses.dataclass
+class Point:
+    x: int
+    y: int = 0
+
+
+point_schema = core_schema.dataclass_schema(
+    Point,
+    core_schema.dataclass_args_schema(
+        'Point',
+        [
+            core_schema.dataclass_field('x', core_schema.int_schema()),
+            core_schema.dataclass_field('y', core_schema.with_default_schema(core_schema.int_schema(), default=0)),
+            core_schema.dataclass_field('z', core_schema.int_schema(), init=False),
+        ],
+    ),
+    ['x', 'y'],
+)
+
+
+def model_schema():
+    return core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(
+                    core_schema.int_schema(), validation_alias='A', serialization_alias='a_out'
+                ),
+                'b': core_schema.model_field(
+                    core_schema.with_default_schema(core_schema.nullable_schema(core_schema.str_schema()), default=None)
+                ),
+                'c': core_schema.model_field(
+                    core_schema.with_default_schema(core_schema.list_schema(), default_factory=list),
+                    validation_alias=[['c1', 0], ['c2']],
+                ),
+                'point': core_schema.model_field(core_schema.nullable_schema(point_schema)),
+            }
+        ),
+    )
+
+
+def test_model():
+    v = SchemaValidator(model_schema())
+    assert v.fields() == {
+        'a': {
+            'required': True,
+            'has_default': False,
+            'validation_alias': 'A',
+            'serialization_alias': 'a_out',
+            'type': 'int',
+            'model': None,
+        },
+        'b': {
+            'required': False,
+            'has_default': True,
+            'validation_alias': None,
+            'serialization_alias': None,
+            'type': 'nullable[str]',
+            'model': None,
+        },
+        'c': {
+            'required': False,
+            'has_default': True,
+            'validation_alias': [['c1', 0], ['c2']],
+            'serialization_alias': None,
+            'type': 'list[any]',
+            'model': None,
+        },
+        'point': {
+            'required': True,
+            'has_default': False,
+            'validation_alias': None,
+            'serialization_alias': None,
+            'type': 'nullable[Point]',
+            'model': Point,
+        },
+    }
+    assert v.required_fields() == ['a', 'point']
+    assert v.aliases() == {'a': 'A', 'c': [['c1', 0], ['c2']]}
+    assert v.aliases(mode='serialization') == {'a': 'a_out'}
+    # default factories aren't called
+    assert v.defaults() == {'b': None}
+
+
+def test_dataclass():
+    v = SchemaValidator(point_schema)
+    # fields with `init=False` can't be given
+    assert list(v.fields()) == ['x', 'y']
+    assert v.required_fields() == ['x']
+    assert v.aliases() == {}
+    assert v.defaults() == {'y': 0}
+
+
+def test_typed_dict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.str_schema(), required=False),
+                'c': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.str_schema(), default='x'), validation_alias=['C', 1]
+                ),
+            }
+        )
+    )
+    assert v.fields()['b'] == {
+        'required': False,
+        'has_default': False,
+        'validation_alias': None,
+        'serialization_alias': None,
+        'type': 'str',
+        'model': None,
+    }
+    assert v.required_fields() == ['a']
+    assert v.aliases() == {'c': [['C', 1]]}
+    assert v.aliases(mode='serialization') == {}
+    assert v.defaults() == {'c': 'x'}
+
+
+def test_serialization_aliases():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a_b': core_schema.typed_dict_field(core_schema.int_schema()),
+                'c': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='C'),
+            },
+            alias_generator=lambda name: name.replace('_', '-'),
+            derive_aliases=True,
+        )
+    )
+    # aliases are resolved like the serializer does, with `alias_generator` and `derive_aliases`
+    assert v.aliases(mode='serialization') == {'a_b': 'a-b', 'c': 'C'}
+    assert v.fields()['c']['serialization_alias'] == 'C'
+    with pytest.raises(ValueError, match="Invalid mode: `json`, expected 'validation' or 'serialization'"):
+        v.aliases(mode='json')
+
+
+def test_wrapped_and_recursive():
+    schema = core_schema.definitions_schema(
+        core_schema.definition_reference_schema('model'),
+        [
+            core_schema.no_info_before_validator_function(
+                lambda v: v,
+                core_schema.model_schema(
+                    MyModel,
+                    core_schema.model_fields_schema(
+                        {
+                            'parent': core_schema.model_field(
+                                core_schema.with_default_schema(
+                                    core_schema.nullable_schema(core_schema.definition_reference_schema('model')),
+                                    default=None,
+                                )
+                            )
+                        }
+                    ),
+                ),
+                ref='model',
+            )
+        ],
+    )
+    v = SchemaValidator(schema)
+    assert v.fields()['parent']['model'] is MyModel
+    assert v.defaults() == {'parent': None}
+
+
+@pytest.mark.parametrize(
+    'schema',
+    [
+        core_schema.int_schema(),
+        core_schema.list_schema(model_schema()),
+        core_schema.model_schema(MyModel, core_schema.int_schema(), root_model=True),
+    ],
+)
+def test_unsupported(schema):
+    v = SchemaValidator(schema)
+    with pytest.raises(TypeError, match='Field introspection is only supported for model, dataclass and typed-dict'):
+        v.fields()
+    with pytest.raises(TypeError):
+        v.required_fields()
@@ -1,3 +1,4 @@
+import io
 import json
 import platform
 import re
@@ -227,6 +228,36 @@ def test_to_json():
         to_json([1, 2], 2)
 
 
+def test_to_json_sort_keys():
+    value = {'b': {'d': [{'f': 1, 'e': 2}], 'c': {}}, 'a': 'à', 'é': None, '\n': 0}
+    expected = json.dumps(value, sort_keys=True, separators=(',', ':'))
+    assert to_json(value, sort_keys=True, ensure_ascii=True).decode() == expected
+    expected = json.dumps(value, sort_keys=True, indent=2)
+    assert to_json(value, sort_keys=True, indent=2, ensure_ascii=True).decode() == expected
+    assert to_json(value, sort_keys=True, indent=2).decode() == json.dumps(
+        value, sort_keys=True, indent=2, ensure_ascii=False
+    )
+    # keys are sorted by their text, not how they're escaped
+    assert to_json({'é': 1, 'z': 2}, sort_keys=True, ensure_ascii=True) == b'{"z":2,"\\u00e9":1}'
+
+
+def test_to_json_sort_keys_schema_serializer():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                'a': core_schema.typed_dict_field(core_schema.dict_schema()),
+            }
+        )
+    )
+    value = {'b': 1, 'a': {'y': 1, 'x': 2}}
+    assert s.to_json(value) == b'{"b":1,"a":{"y":1,"x":2}}'
+    assert s.to_json(value, sort_keys=True) == b'{"a":{"x":2,"y":1},"b":1}'
+    f = io.BytesIO()
+    s.to_json(value, sort_keys=True, writer=f)
+    assert f.getvalue() == b'{"a":{"x":2,"y":1},"b":1}'
+
+
 def test_to_json_fallback():
     with pytest.raises(PydanticSerializationError, match=r'Unable to serialize unknown type: <.+\.Foobar'):
         to_json(Foobar())
@@ -243,6 +274,16 @@ def test_to_jsonable_python():
     assert to_jsonable_python([0, 1, 2, 3, 4], exclude={1, 3}) == [0, 2, 4]
 
 
+def test_to_jsonable_python_sort_keys():
+    value = {'b': [{'d': 1, 'c': 2}], 'a': (1, {'f': 1, 'e': 2})}
+    output = to_jsonable_python(value, sort_keys=True)
+    assert output == {'a': [1, {'e': 2, 'f': 1}], 'b': [{'c': 2, 'd': 1}]}
+    assert list(output) == ['a', 'b']
+    assert list(output['a'][1]) == ['e', 'f']
+    assert list(output['b'][0]) == ['c', 'd']
+    assert list(to_jsonable_python(value)) == ['b', 'a']
+
+
 def test_to_jsonable_python_fallback():
     with pytest.raises(PydanticSerializationError, match=r'Unable to serialize unknown type: <.+\.Foobar'):
         to_jsonable_python(Foobar())
@@ -469,3 +510,64 @@ def test_json_bytes_hex_invalid():
             'input': wrong_input,
         }
     ]
+
+
+@pytest.mark.parametrize('chunk_size', [1, 3, 65536])
+def test_validate_json_items(chunk_size):
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'id': core_schema.typed_dict_field(core_schema.int_schema()),
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+            }
+        )
+    )
+    items = [{'id': i, 'name': 'é' * i} for i in range(20)]
+    data = json.dumps(items, ensure_ascii=False)
+    assert list(v.validate_json_items(io.BytesIO(data.encode()), chunk_size=chunk_size)) == items
+    assert list(v.validate_json_items(io.StringIO(data), chunk_size=chunk_size)) == items
+    assert list(v.validate_json_items(data)) == items
+    assert list(v.validate_json_items(bytearray(data.encode()))) == items
+
+
+def test_validate_json_items_errors():
+    v = SchemaValidator(core_schema.int_schema())
+    items = v.validate_json_items(io.BytesIO(b' [1, "x", 12345 ] '), chunk_size=2)
+    assert next(items) == 1
+    with pytest.raises(ValidationError) as exc_info:
+        next(items)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+    # iteration continues after an item fails validation
+    assert list(items) == [12345]
+    assert items.index == 3
+
+
+@pytest.mark.parametrize(
+    'input_value,error',
+    [
+        ('{}', 'expected a JSON array at byte 0'),
+        ('[1 2]', 'expected `,` or `]` at byte 3'),
+        ('[1,', 'EOF while parsing a value at byte 3'),
+        ('[1, 2] 3', 'trailing characters at byte 7'),
+    ],
+)
+def test_validate_json_items_invalid(input_value, error):
+    items = SchemaValidator(core_schema.int_schema()).validate_json_items(io.StringIO(input_value), chunk_size=1)
+    with pytest.raises(ValidationError, match=re.escape(f'Invalid JSON: {error}')):
+        list(items)
+    assert list(items) == []
+
+
+def test_validate_json_items_input_type():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(TypeError, match='JSON input should be a str, bytes, bytearray or a file-like object'):
+        v.validate_json_items(1)
+    with pytest.raises(ValueError, match='`chunk_size` must be greater than 0'):
+        v.validate_json_items('[]', chunk_size=0)
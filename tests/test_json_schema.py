@@ -0,0 +1,274 @@
+import pickle
+import re
+from enum import Enum
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaJsonifier, core_schema
+
+
+@pytest.mark.parametrize(
+    'schema,expected',
+    [
+        (core_schema.any_schema(), {}),
+        (core_schema.none_schema(), {'type': 'null'}),
+        (core_schema.bool_schema(), {'type': 'boolean'}),
+        (core_schema.int_schema(ge=1, lt=10), {'type': 'integer', 'minimum': 1, 'exclusiveMaximum': 10}),
+        (core_schema.int_schema(interval='[0, 100)'), {'type': 'integer', 'minimum': 0, 'exclusiveMaximum': 100}),
+        (core_schema.float_schema(multiple_of=0.5), {'type': 'number', 'multipleOf': 0.5}),
+        (core_schema.decimal_schema(), {'anyOf': [{'type': 'number'}, {'type': 'string'}]}),
+        (
+            core_schema.str_schema(min_length=1, max_length=5, pattern=r'^\w+$'),
+            {'type': 'string', 'minLength': 1, 'maxLength': 5, 'pattern': r'^\w+$'},
+        ),
+        (core_schema.str_schema(pattern=re.compile('^a')), {'type': 'string', 'pattern': '^a'}),
+        (core_schema.str_schema(pattern_not='^admin$'), {'type': 'string', 'not': {'pattern': '^admin$'}}),
+        (core_schema.str_schema(max_length=20, length_unit='graphemes'), {'type': 'string'}),
+        (core_schema.bytes_schema(), {'type': 'string', 'format': 'binary'}),
+        (core_schema.datetime_schema(), {'type': 'string', 'format': 'date-time'}),
+        (core_schema.timedelta_schema(), {'type': 'string', 'format': 'duration'}),
+        (core_schema.uuid_schema(), {'type': 'string', 'format': 'uuid'}),
+        (core_schema.semver_schema(), {'type': 'string', 'format': 'semver'}),
+        (core_schema.ulid_schema(), {'type': 'string', 'format': 'ulid'}),
+        (core_schema.email_schema(), {'type': 'string', 'format': 'email'}),
+        (core_schema.name_email_schema(), {'type': 'string', 'format': 'name-email'}),
+        (core_schema.path_schema(), {'type': 'string', 'format': 'path'}),
+        (core_schema.ip_v4_address_schema(), {'type': 'string', 'format': 'ipv4'}),
+        (core_schema.ip_network_schema(), {'type': 'string', 'format': 'ipvanynetwork'}),
+        (core_schema.ip_interface_schema(version=6), {'type': 'string', 'format': 'ipv6interface'}),
+        (core_schema.url_schema(), {'type': 'string', 'format': 'uri', 'minLength': 1}),
+        (core_schema.literal_schema(['a']), {'const': 'a', 'type': 'string'}),
+        (core_schema.literal_schema([1, 'a']), {'enum': [1, 'a']}),
+        (core_schema.const_schema(2), {'const': 2, 'type': 'integer', 'default': 2}),
+        (
+            core_schema.list_schema(core_schema.int_schema(), max_length=3),
+            {'type': 'array', 'items': {'type': 'integer'}, 'maxItems': 3},
+        ),
+        (core_schema.set_schema(), {'type': 'array', 'items': {}, 'uniqueItems': True}),
+        (
+            core_schema.keyed_list_schema(core_schema.dict_schema(), 'name', min_length=1),
+            {'type': 'array', 'items': {'type': 'object', 'additionalProperties': {}}, 'minItems': 1},
+        ),
+        (
+            core_schema.tuple_schema([core_schema.int_schema(), core_schema.str_schema()]),
+            {
+                'type': 'array',
+                'prefixItems': [{'type': 'integer'}, {'type': 'string'}],
+                'items': False,
+                'minItems': 2,
+                'maxItems': 2,
+            },
+        ),
+        (
+            core_schema.tuple_schema([core_schema.int_schema()], variadic_item_index=0),
+            {'type': 'array', 'items': {'type': 'integer'}, 'minItems': 0},
+        ),
+        (
+            core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()),
+            {'type': 'object', 'additionalProperties': {'type': 'integer'}},
+        ),
+        (
+            core_schema.nullable_schema(core_schema.int_schema()),
+            {'anyOf': [{'type': 'integer'}, {'type': 'null'}]},
+        ),
+        (
+            core_schema.union_schema([core_schema.int_schema(), (core_schema.str_schema(), 'label')]),
+            {'anyOf': [{'type': 'integer'}, {'type': 'string'}]},
+        ),
+        (core_schema.with_default_schema(core_schema.int_schema(), default=1), {'type': 'integer', 'default': 1}),
+        (core_schema.with_default_schema(core_schema.int_schema(), default=object()), {'type': 'integer'}),
+        (
+            core_schema.chain_schema([core_schema.str_schema(), core_schema.int_schema()]),
+            {'type': 'string'},
+        ),
+        (
+            core_schema.json_schema(core_schema.int_schema()),
+            {'type': 'string', 'contentMediaType': 'application/json', 'contentSchema': {'type': 'integer'}},
+        ),
+    ],
+)
+def test_validation(schema, expected):
+    assert SchemaJsonifier(schema).json_schema() == expected
+
+
+def test_enum():
+    class Color(Enum):
+        RED = 'red'
+        BLUE = 'blue'
+
+    schema = core_schema.enum_schema(Color, list(Color.__members__.values()), sub_type='str')
+    assert SchemaJsonifier(schema).json_schema() == {'enum': ['red', 'blue'], 'type': 'string', 'title': 'Color'}
+    assert SchemaJsonifier(core_schema.literal_schema([Color.RED])).json_schema() == {'const': 'red', 'type': 'string'}
+
+
+def test_typed_dict_aliases():
+    schema = core_schema.typed_dict_schema(
+        {
+            'first_name': core_schema.typed_dict_field(
+                core_schema.str_schema(), validation_alias='firstName', serialization_alias='FirstName'
+            ),
+            'age': core_schema.typed_dict_field(
+                core_schema.with_default_schema(core_schema.int_schema(), default=0), validation_alias=[['a', 0]]
+            ),
+            'secret': core_schema.typed_dict_field(core_schema.str_schema(), serialization_exclude=True),
+        },
+        extra_behavior='forbid',
+    )
+    jsonifier = SchemaJsonifier(schema)
+    assert jsonifier.json_schema() == {
+        'type': 'object',
+        'properties': {
+            'firstName': {'type': 'string', 'title': 'First Name'},
+            'a': {'type': 'integer', 'default': 0, 'title': 'Age'},
+            'secret': {'type': 'string', 'title': 'Secret'},
+        },
+        'required': ['firstName', 'secret'],
+        'additionalProperties': False,
+    }
+    assert jsonifier.json_schema(mode='serialization') == {
+        'type': 'object',
+        'properties': {
+            'FirstName': {'type': 'string', 'title': 'First Name'},
+            'age': {'type': 'integer', 'default': 0, 'title': 'Age'},
+        },
+        'required': ['FirstName'],
+        'additionalProperties': False,
+    }
+    assert list(jsonifier.json_schema(by_alias=False)['properties']) == ['first_name', 'age', 'secret']
+
+
+def test_alias_generator():
+    schema = core_schema.typed_dict_schema(
+        {
+            'first_name': core_schema.typed_dict_field(core_schema.str_schema()),
+            'age': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias='years'),
+        },
+        alias_generator=lambda name: name.upper(),
+    )
+    jsonifier = SchemaJsonifier(schema)
+    assert list(jsonifier.json_schema()['properties']) == ['FIRST_NAME', 'AGE']
+    assert list(jsonifier.json_schema(mode='serialization')['properties']) == ['FIRST_NAME', 'years']
+
+
+def test_model_refs():
+    class Node:
+        pass
+
+    schema = core_schema.definitions_schema(
+        core_schema.definition_reference_schema('Node'),
+        [
+            core_schema.model_schema(
+                Node,
+                core_schema.model_fields_schema(
+                    {
+                        'value': core_schema.model_field(core_schema.int_schema()),
+                        'children': core_schema.model_field(
+                            core_schema.list_schema(core_schema.definition_reference_schema('Node'))
+                        ),
+                        'parent': core_schema.model_field(
+                            core_schema.nullable_schema(core_schema.definition_reference_schema('Node'))
+                        ),
+                    },
+                    computed_fields=[core_schema.computed_field('depth', core_schema.int_schema())],
+                ),
+                ref='Node',
+            )
+        ],
+    )
+    node = {
+        'type': 'object',
+        'properties': {
+            'value': {'type': 'integer', 'title': 'Value'},
+            'children': {'type': 'array', 'items': {'$ref': '#/$defs/Node'}, 'title': 'Children'},
+            'parent': {'anyOf': [{'$ref': '#/$defs/Node'}, {'type': 'null'}], 'title': 'Parent'},
+        },
+        'required': ['value', 'children', 'parent'],
+        'title': 'Node',
+    }
+    jsonifier = SchemaJsonifier(schema)
+    assert jsonifier.json_schema() == {'$ref': '#/$defs/Node', '$defs': {'Node': node}}
+
+    serialization = jsonifier.json_schema(mode='serialization', ref_template='#/components/schemas/{ref}')
+    assert serialization['$ref'] == '#/components/schemas/Node'
+    depth = serialization['$defs']['Node']['properties']['depth']
+    assert depth == {'type': 'integer', 'readOnly': True, 'title': 'Depth'}
+    assert serialization['$defs']['Node']['required'] == ['value', 'children', 'parent', 'depth']
+
+
+def test_tagged_union():
+    cat = core_schema.typed_dict_schema(
+        {'pet_type': core_schema.typed_dict_field(core_schema.literal_schema(['cat']))}, ref='Cat'
+    )
+    dog = core_schema.typed_dict_schema(
+        {'pet_type': core_schema.typed_dict_field(core_schema.literal_schema(['dog']))}, ref='Dog'
+    )
+    json_schema = SchemaJsonifier(
+        core_schema.tagged_union_schema({'cat': cat, 'dog': dog}, discriminator='pet_type')
+    ).json_schema()
+    assert json_schema['oneOf'] == [{'$ref': '#/$defs/Cat'}, {'$ref': '#/$defs/Dog'}]
+    assert json_schema['discriminator'] == {
+        'propertyName': 'pet_type',
+        'mapping': {'cat': '#/$defs/Cat', 'dog': '#/$defs/Dog'},
+    }
+    assert list(json_schema['$defs']) == ['Cat', 'Dog']
+
+
+def test_functions():
+    def f(value):
+        return value
+
+    before = core_schema.no_info_before_validator_function(
+        f, core_schema.int_schema(), json_schema_input_schema=core_schema.str_schema()
+    )
+    assert SchemaJsonifier(before).json_schema() == {'type': 'string'}
+    assert SchemaJsonifier(before).json_schema(mode='serialization') == {'type': 'integer'}
+    assert SchemaJsonifier(core_schema.no_info_plain_validator_function(f)).json_schema() == {}
+
+    serialized = core_schema.int_schema(
+        serialization=core_schema.plain_serializer_function_ser_schema(f, return_schema=core_schema.str_schema())
+    )
+    assert SchemaJsonifier(serialized).json_schema() == {'type': 'integer'}
+    assert SchemaJsonifier(serialized).json_schema(mode='serialization') == {'type': 'string'}
+
+
+def test_str_truncate():
+    jsonifier = SchemaJsonifier(core_schema.str_schema(min_length=1, max_length=5, truncate=True))
+    assert jsonifier.json_schema() == {'type': 'string', 'minLength': 1}
+    assert jsonifier.json_schema(mode='serialization') == {'type': 'string', 'minLength': 1, 'maxLength': 5}
+
+
+def test_datetime_format():
+    jsonifier = SchemaJsonifier(core_schema.datetime_schema(format='%d/%m/%Y %H:%M'))
+    assert jsonifier.json_schema() == {'type': 'string'}
+    assert jsonifier.json_schema(mode='serialization') == {'type': 'string', 'format': 'date-time'}
+
+
+def test_timedelta_output():
+    jsonifier = SchemaJsonifier(core_schema.timedelta_schema(output='period'))
+    assert jsonifier.json_schema() == {'type': 'string', 'format': 'duration'}
+    assert jsonifier.json_schema(mode='serialization') == {'type': 'object'}
+
+
+def test_config():
+    jsonifier = SchemaJsonifier(core_schema.timedelta_schema(), config={'ser_json_timedelta': 'float'})
+    assert jsonifier.json_schema() == {'type': 'string', 'format': 'duration'}
+    assert jsonifier.json_schema(mode='serialization') == {'type': 'number'}
+
+
+def test_unsupported():
+    jsonifier = SchemaJsonifier(core_schema.is_instance_schema(int))
+    with pytest.raises(SchemaError, match='Cannot generate a JSON Schema for `is-instance` schemas'):
+        jsonifier.json_schema()
+
+
+def test_invalid_args():
+    jsonifier = SchemaJsonifier(core_schema.int_schema())
+    with pytest.raises(ValueError, match="Invalid mode: `python`, expected 'validation' or 'serialization'"):
+        jsonifier.json_schema(mode='python')
+    with pytest.raises(ValueError, match='`ref_template` must contain `{ref}`'):
+        jsonifier.json_schema(ref_template='#/$defs/')
+
+
+def test_pickle():
+    jsonifier = SchemaJsonifier(core_schema.int_schema(gt=0))
+    assert pickle.loads(pickle.dumps(jsonifier)).json_schema() == {'type': 'integer', 'exclusiveMinimum': 0}
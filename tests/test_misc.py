@@ -10,14 +10,16 @@ from typing_extensions import (  # noqa: UP035 (https://github.com/astral-sh/ruf
 from typing_inspection import typing_objects
 from typing_inspection.introspection import UNKNOWN, AnnotationSource, inspect_annotation
 
-from pydantic_core import CoreConfig, CoreSchema, CoreSchemaType, PydanticUndefined, core_schema
+from pydantic_core import CoreConfig, CoreSchema, CoreSchemaType, PydanticUndefined, compare_secrets, core_schema
 from pydantic_core._pydantic_core import (
     SchemaError,
     SchemaValidator,
     ValidationError,
     __version__,
+    _recursion_limit,
     build_info,
     build_profile,
+    capabilities,
 )
 
 
@@ -228,3 +230,40 @@ def test_core_schema_import_field_validation_info():
 def test_core_schema_import_missing():
     with pytest.raises(AttributeError, match="module 'pydantic_core' has no attribute 'foobar'"):
         core_schema.foobar
+
+
+@pytest.mark.parametrize(
+    'a,b,expected',
+    [
+        ('password', 'password', True),
+        ('password', 'passw0rd', False),
+        ('password', 'pass', False),
+        ('', '', True),
+        ('pässword', 'pässword', True),
+        (b'token', b'token', True),
+        (b'token', b'tokeN', False),
+    ],
+)
+def test_compare_secrets(a, b, expected):
+    assert compare_secrets(a, b) is expected
+
+
+def test_compare_secrets_types():
+    with pytest.raises(TypeError, match=r"unsupported operand types for compare_secrets: 'str' and 'bytes'"):
+        compare_secrets('token', b'token')
+    with pytest.raises(TypeError, match=r"unsupported operand types for compare_secrets: 'int' and 'int'"):
+        compare_secrets(1, 1)
+
+
+def test_capabilities():
+    caps = capabilities()
+    assert caps['version'] == __version__
+    assert caps['build_profile'] == build_profile
+    assert isinstance(caps['pgo'], bool)
+    assert isinstance(caps['free_threading'], bool)
+    assert caps['input_formats'] == ['python', 'json', 'strings']
+    assert caps['output_formats'] == ['python', 'json', 'jsonl', 'msgpack']
+    assert set(caps['compression']) <= {'gzip', 'zstd'}
+    assert caps['regex_engines'] == ['rust-regex', 'python-re']
+    assert set(caps['simd']) <= {'json'}
+    assert caps['limits'] == {'recursion_limit': _recursion_limit, 'json_max_depth': 200}
@@ -0,0 +1,150 @@
+import ctypes
+import re
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema, register_plugin
+
+# plugins are usually implemented by other compiled extensions, these are built with ctypes instead
+VALIDATE = ctypes.CFUNCTYPE(
+    ctypes.c_int, ctypes.py_object, ctypes.py_object, ctypes.c_int, ctypes.POINTER(ctypes.c_void_p)
+)
+SERIALIZE = ctypes.CFUNCTYPE(ctypes.c_void_p, ctypes.py_object, ctypes.py_object, ctypes.c_int)
+
+
+class PydanticCorePlugin(ctypes.Structure):
+    _fields_ = [('abi_version', ctypes.c_uint32), ('validate', VALIDATE), ('serialize', SERIALIZE)]
+
+
+CAPSULE_NAME = b'pydantic_core.plugin'
+
+py_capsule_new = ctypes.pythonapi.PyCapsule_New
+py_capsule_new.restype = ctypes.py_object
+py_capsule_new.argtypes = [ctypes.c_void_p, ctypes.c_char_p, ctypes.c_void_p]
+
+py_incref = ctypes.pythonapi.Py_IncRef
+py_incref.argtypes = [ctypes.py_object]
+
+
+def new_reference(value):
+    py_incref(value)
+    return id(value)
+
+
+@VALIDATE
+def validate_hex_int(options, input_value, strict, output):
+    """Validates ints, and in lax mode hex strings like `'0x1f'`, up to the `max` option."""
+    if isinstance(input_value, int) and not isinstance(input_value, bool):
+        value = input_value
+    elif not strict and isinstance(input_value, str) and re.fullmatch('0x[0-9a-f]+', input_value):
+        value = int(input_value, 16)
+    else:
+        return 1
+    if value > options.get('max', value):
+        return 1
+    output[0] = new_reference(value)
+    return 0
+
+
+@SERIALIZE
+def serialize_hex_int(options, value, json):
+    return new_reference(hex(value) if json else value)
+
+
+hex_int = PydanticCorePlugin(1, validate_hex_int, serialize_hex_int)
+register_plugin('hex-int', py_capsule_new(ctypes.addressof(hex_int), CAPSULE_NAME, None))
+
+
+def test_validate():
+    v = SchemaValidator(core_schema.plugin_schema('hex-int', options={'max': 255}))
+    assert v.validate_python(12) == 12
+    assert v.validate_python('0x1f') == 31
+    assert v.validate_json('"0xff"') == 255
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('0x1f', strict=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'plugin_type',
+            'loc': (),
+            'msg': 'Input should be a valid hex-int',
+            'input': '0x1f',
+            'ctx': {'plugin': 'hex-int'},
+        }
+    ]
+    with pytest.raises(ValidationError, match=r'Input should be a valid hex-int \[type=plugin_type'):
+        v.validate_python(256)
+
+
+def test_strict_schema():
+    v = SchemaValidator(core_schema.plugin_schema('hex-int', strict=True))
+    assert v.validate_python(12) == 12
+    with pytest.raises(ValidationError, match='Input should be a valid hex-int'):
+        v.validate_python('0x1f')
+
+
+def test_union():
+    v = SchemaValidator(core_schema.union_schema([core_schema.plugin_schema('hex-int'), core_schema.str_schema()]))
+    assert v.validate_python('0x1f') == 31
+    assert v.validate_python('foo') == 'foo'
+
+
+def test_serialize():
+    hex_int_schema = core_schema.plugin_schema('hex-int')
+    s = SchemaSerializer(core_schema.dict_schema(hex_int_schema, hex_int_schema))
+    assert s.to_python({1: 255}) == {1: 255}
+    assert s.to_python({1: 255}, mode='json') == {'0x1': '0xff'}
+    assert s.to_json({1: 255}) == b'{"0x1":"0xff"}'
+
+
+@VALIDATE
+def validate_any(options, input_value, strict, output):
+    output[0] = new_reference(input_value)
+    return 0
+
+
+def test_without_serializer():
+    plugin = PydanticCorePlugin(1, validate_any)
+    register_plugin('any', py_capsule_new(ctypes.addressof(plugin), CAPSULE_NAME, None))
+    schema = core_schema.plugin_schema('any')
+    assert SchemaValidator(schema).validate_python([1, 'a']) == [1, 'a']
+    # values are serialized like `any` schemas
+    assert SchemaSerializer(schema).to_json([1, 'a']) == b'[1,"a"]'
+
+
+@VALIDATE
+def validate_unknown_status(options, input_value, strict, output):
+    return 2
+
+
+def test_unknown_status():
+    plugin = PydanticCorePlugin(1, validate_unknown_status)
+    register_plugin('unknown-status', py_capsule_new(ctypes.addressof(plugin), CAPSULE_NAME, None))
+    v = SchemaValidator(core_schema.plugin_schema('unknown-status'))
+    with pytest.raises(SystemError, match="Plugin 'unknown-status' returned the unknown status 2"):
+        v.validate_python(1)
+
+
+def test_register_errors():
+    with pytest.raises(ValueError, match="Plugins must be registered with a 'pydantic_core.plugin' capsule"):
+        register_plugin('wrong-name', py_capsule_new(ctypes.addressof(hex_int), b'other', None))
+
+    future = PydanticCorePlugin(2, validate_hex_int)
+    with pytest.raises(ValueError, match="Plugin 'future' has ABI version 2, this version of pydantic-core supports"):
+        register_plugin('future', py_capsule_new(ctypes.addressof(future), CAPSULE_NAME, None))
+
+    without_validate = PydanticCorePlugin(1)
+    with pytest.raises(ValueError, match="Plugin 'without-validate' doesn't have a validate function"):
+        register_plugin('without-validate', py_capsule_new(ctypes.addressof(without_validate), CAPSULE_NAME, None))
+    with pytest.raises(SchemaError, match="Plugin 'without-validate' isn't registered"):
+        SchemaValidator(core_schema.plugin_schema('without-validate'))
+
+    with pytest.raises(TypeError):
+        register_plugin('not-a-capsule', object())
+
+
+def test_not_registered():
+    with pytest.raises(SchemaError, match="Plugin 'missing' isn't registered, see `register_plugin`"):
+        SchemaValidator(core_schema.plugin_schema('missing'))
+    with pytest.raises(SchemaError, match="Plugin 'missing' isn't registered"):
+        SchemaSerializer(core_schema.plugin_schema('missing'))
@@ -0,0 +1,133 @@
+import time
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+class Model:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+def slow(value):
+    time.sleep(0.01)
+    return value
+
+
+def make_schema():
+    class Inner(Model):
+        pass
+
+    class Outer(Model):
+        pass
+
+    inner_schema = core_schema.model_schema(
+        Inner,
+        core_schema.model_fields_schema(
+            {'x': core_schema.model_field(core_schema.no_info_after_validator_function(slow, core_schema.int_schema()))}
+        ),
+    )
+    return core_schema.model_schema(
+        Outer,
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(core_schema.str_schema()),
+                'inner': core_schema.model_field(core_schema.list_schema(inner_schema)),
+            }
+        ),
+    )
+
+
+def test_profile():
+    v = SchemaValidator(make_schema(), config={'profile_validation': True})
+    v.validate_python({'a': 'foo', 'inner': [{'x': 1}, {'x': '2'}]})
+
+    profile = v.get_profile()
+    assert list(profile) == [
+        'model[Outer]',
+        'model[Outer].model-fields',
+        'model[Outer].model-fields.a.str',
+        'model[Outer].model-fields.inner.list',
+        'model[Outer].model-fields.inner.list.model[Inner]',
+        'model[Outer].model-fields.inner.list.model[Inner].model-fields',
+        'model[Outer].model-fields.inner.list.model[Inner].model-fields.x.function-after[slow]',
+        'model[Outer].model-fields.inner.list.model[Inner].model-fields.x.function-after[slow].int',
+    ]
+    assert {path: entry['calls'] for path, entry in profile.items()} == {
+        'model[Outer]': 1,
+        'model[Outer].model-fields': 1,
+        'model[Outer].model-fields.a.str': 1,
+        'model[Outer].model-fields.inner.list': 1,
+        'model[Outer].model-fields.inner.list.model[Inner]': 2,
+        'model[Outer].model-fields.inner.list.model[Inner].model-fields': 2,
+        'model[Outer].model-fields.inner.list.model[Inner].model-fields.x.function-after[slow]': 2,
+        'model[Outer].model-fields.inner.list.model[Inner].model-fields.x.function-after[slow].int': 2,
+    }
+    slow_time = profile['model[Outer].model-fields.inner.list.model[Inner].model-fields.x.function-after[slow]'][
+        'total_time'
+    ]
+    assert slow_time >= 0.02
+    assert profile['model[Outer]']['total_time'] >= slow_time
+    assert profile['model[Outer].model-fields.a.str']['total_time'] < 0.01
+
+
+def test_profile_reset():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), config={'profile_validation': True})
+    v.validate_python([1, 2, 3])
+    with pytest.raises(ValidationError):
+        v.validate_python([1, 'x'])
+
+    assert v.get_profile(reset=True) == {
+        'list': {'calls': 2, 'total_time': pytest.approx(0, abs=0.01)},
+        'list.int': {'calls': 5, 'total_time': pytest.approx(0, abs=0.01)},
+    }
+    assert v.get_profile() == {'list': {'calls': 0, 'total_time': 0}, 'list.int': {'calls': 0, 'total_time': 0}}
+
+
+def test_profile_definitions():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('node'),
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'children': core_schema.typed_dict_field(
+                            core_schema.list_schema(core_schema.definition_reference_schema('node'))
+                        )
+                    },
+                    ref='node',
+                )
+            ],
+        ),
+        config={'profile_validation': True},
+    )
+    v.validate_python({'children': [{'children': []}, {'children': [{'children': []}]}]})
+    assert {path: entry['calls'] for path, entry in v.get_profile().items()} == {
+        'definitions': 1,
+        'definitions.typed-dict': 4,
+        'definitions.typed-dict.children.list': 4,
+        'definitions.typed-dict.children.list.definition-ref[node]': 3,
+        'definitions.definition-ref[node]': 1,
+    }
+
+
+def test_profile_prebuilt():
+    class Inner(Model):
+        pass
+
+    inner_schema = core_schema.model_schema(
+        Inner, core_schema.model_fields_schema({'x': core_schema.model_field(core_schema.int_schema())})
+    )
+    Inner.__pydantic_validator__ = SchemaValidator(inner_schema)
+    Inner.__pydantic_complete__ = True
+
+    v = SchemaValidator(core_schema.list_schema(inner_schema), config={'profile_validation': True})
+    v.validate_python([{'x': 1}])
+    # nested models with their own validator are built again so they're profiled
+    assert 'list.model[Inner].model-fields.x.int' in v.get_profile()
+
+
+def test_profile_not_enabled():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(RuntimeError, match='Validation profiling is not enabled'):
+        v.get_profile()
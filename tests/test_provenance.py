@@ -0,0 +1,177 @@
+from dataclasses import dataclass
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+class Model:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+@pytest.fixture
+def typed_dict_validator():
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.float_schema()),
+                'c': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.str_schema(), default='x')
+                ),
+                'inner': core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema({'d': core_schema.typed_dict_field(core_schema.bool_schema())})
+                ),
+            }
+        )
+    )
+
+
+def test_provenance(typed_dict_validator):
+    result = typed_dict_validator.validate_python({'a': '1', 'b': 2.5, 'inner': {'d': 'yes'}}, provenance=True)
+    assert result.output == {'a': 1, 'b': 2.5, 'c': 'x', 'inner': {'d': True}}
+    assert result.provenance == [
+        {'loc': ('a',), 'source': 'coerced', 'input_type': 'str'},
+        {'loc': ('b',), 'source': 'supplied', 'input_type': 'float'},
+        {'loc': ('c',), 'source': 'default', 'input_type': None},
+        {'loc': ('inner', 'd'), 'source': 'coerced', 'input_type': 'str'},
+    ]
+    assert typed_dict_validator.validate_python({'a': 1, 'b': 2.5, 'inner': {'d': True}}) == {
+        'a': 1,
+        'b': 2.5,
+        'c': 'x',
+        'inner': {'d': True},
+    }
+
+
+def test_provenance_json(typed_dict_validator):
+    result = typed_dict_validator.validate_json('{"a": 1, "b": 2, "c": "y", "inner": {"d": true}}', provenance=True)
+    assert result.provenance == [
+        {'loc': ('a',), 'source': 'supplied', 'input_type': 'int'},
+        # ints are valid floats in JSON without coercion
+        {'loc': ('b',), 'source': 'supplied', 'input_type': 'int'},
+        {'loc': ('c',), 'source': 'supplied', 'input_type': 'str'},
+        {'loc': ('inner', 'd'), 'source': 'supplied', 'input_type': 'bool'},
+    ]
+
+
+def test_provenance_strings(typed_dict_validator):
+    result = typed_dict_validator.validate_strings({'a': '1', 'b': '2.5', 'inner': {'d': 'true'}}, provenance=True)
+    assert [(p['loc'], p['source']) for p in result.provenance] == [
+        (('a',), 'supplied'),
+        (('b',), 'supplied'),
+        (('c',), 'default'),
+        (('inner', 'd'), 'supplied'),
+    ]
+
+
+def test_provenance_with_other_results(typed_dict_validator):
+    result = typed_dict_validator.validate_python(
+        {'a': 1, 'b': 2.5, 'inner': {'d': True}}, exactness=True, provenance=True
+    )
+    assert result.output['a'] == 1
+    assert result.exactness == 'exact'
+    assert [p['source'] for p in result.provenance] == ['supplied', 'supplied', 'default', 'supplied']
+    assert (result.trace, result.partial_report) == (None, None)
+
+
+def test_provenance_model_and_dataclass():
+    @dataclass
+    class Foo:
+        a: int
+        b: str = 'default'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {
+                    'x': core_schema.model_field(core_schema.list_schema(core_schema.int_schema())),
+                    'foo': core_schema.model_field(
+                        core_schema.dataclass_schema(
+                            Foo,
+                            core_schema.dataclass_args_schema(
+                                'Foo',
+                                [
+                                    core_schema.dataclass_field('a', core_schema.int_schema()),
+                                    core_schema.dataclass_field(
+                                        'b',
+                                        core_schema.with_default_schema(core_schema.str_schema(), default='default'),
+                                    ),
+                                ],
+                            ),
+                            ['a', 'b'],
+                        )
+                    ),
+                }
+            ),
+        )
+    )
+    result = v.validate_python({'x': ('1', 2), 'foo': {'a': 1}}, provenance=True)
+    assert result.output.x == [1, 2]
+    assert result.output.foo == Foo(1)
+    assert result.provenance == [
+        # a list field is a leaf, coerced here as the input is a tuple of a str and an int
+        {'loc': ('x',), 'source': 'coerced', 'input_type': 'tuple'},
+        {'loc': ('foo', 'a'), 'source': 'supplied', 'input_type': 'int'},
+        {'loc': ('foo', 'b'), 'source': 'default', 'input_type': None},
+    ]
+
+
+def test_provenance_union():
+    # only the fields of the union member which is used are recorded
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'value': core_schema.typed_dict_field(
+                    core_schema.union_schema(
+                        [
+                            core_schema.typed_dict_schema(
+                                {'a': core_schema.typed_dict_field(core_schema.int_schema())}
+                            ),
+                            core_schema.typed_dict_schema(
+                                {
+                                    'a': core_schema.typed_dict_field(core_schema.str_schema()),
+                                    'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                                }
+                            ),
+                        ]
+                    )
+                )
+            }
+        )
+    )
+    result = v.validate_python({'value': {'a': 'x', 'b': 1}}, provenance=True)
+    assert result.output == {'value': {'a': 'x', 'b': 1}}
+    assert result.provenance == [
+        {'loc': ('value', 'a'), 'source': 'supplied', 'input_type': 'str'},
+        {'loc': ('value', 'b'), 'source': 'supplied', 'input_type': 'int'},
+    ]
+
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'a': core_schema.typed_dict_field(core_schema.str_schema()),
+                        'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                    }
+                ),
+                core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.str_schema())}),
+            ],
+            mode='left_to_right',
+        )
+    )
+    result = v.validate_python({'a': 'x', 'b': 'y'}, provenance=True)
+    assert result.provenance == [{'loc': ('a',), 'source': 'supplied', 'input_type': 'str'}]
+
+
+def test_provenance_errors(typed_dict_validator):
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        typed_dict_validator.validate_python({'a': 'x', 'b': 1, 'inner': {'d': True}}, provenance=True)
+
+
+def test_provenance_no_fields():
+    v = SchemaValidator(core_schema.int_schema())
+    result = v.validate_python('1', provenance=True)
+    assert (result.output, result.provenance) == (1, [])
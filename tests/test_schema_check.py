@@ -0,0 +1,211 @@
+from enum import Enum
+
+from pydantic_core import check_schema, core_schema
+
+
+def test_valid_schema():
+    schema = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.int_schema(ge=0, le=10)),
+            'b': core_schema.typed_dict_field(
+                core_schema.union_schema([core_schema.str_schema(max_length=5), core_schema.none_schema()])
+            ),
+        }
+    )
+    assert check_schema(schema) == []
+
+
+def test_incompatible_constraint():
+    schema = core_schema.list_schema({'type': 'int', 'max_length': 5, 'pattern': '^a'})
+    assert check_schema(schema) == [
+        {
+            'path': ('items_schema', 'max_length'),
+            'kind': 'incompatible_constraint',
+            'message': '`max_length` has no effect on `int` schemas',
+            'suggestion': (
+                'remove `max_length`, it only applies to `str`, `bytes`, `list`, `keyed-list`, `tuple`, `set`, '
+                '`frozenset`, `dict`, `generator`, `url`, `multi-host-url` schemas'
+            ),
+        },
+        {
+            'path': ('items_schema', 'pattern'),
+            'kind': 'incompatible_constraint',
+            'message': '`pattern` has no effect on `int` schemas',
+            'suggestion': 'remove `pattern`, it only applies to `str` schemas',
+        },
+    ]
+
+
+def test_unsatisfiable_constraint():
+    diagnostics = check_schema(
+        core_schema.tuple_schema(
+            [
+                core_schema.str_schema(min_length=5, max_length=2),
+                core_schema.float_schema(gt=1, le=1),
+                core_schema.int_schema(ge=1, le=1),
+            ]
+        )
+    )
+    assert [(d['path'], d['kind'], d['message']) for d in diagnostics] == [
+        (
+            ('items_schema', 0, 'min_length'),
+            'unsatisfiable_constraint',
+            "`min_length=5` and `max_length=2` can't both be satisfied, no input is valid",
+        ),
+        (
+            ('items_schema', 1, 'gt'),
+            'unsatisfiable_constraint',
+            "`gt=1` and `le=1` can't both be satisfied, no input is valid",
+        ),
+    ]
+
+
+def test_semver_bounds():
+    # versions are compared by precedence, not as strings
+    assert check_schema(core_schema.semver_schema(ge='2.0.0', lt='10.0.0')) == []
+    diagnostics = check_schema(core_schema.semver_schema(ge='2.0.0', lt='1.0.0'))
+    assert [(d['path'], d['kind'], d['message']) for d in diagnostics] == [
+        (('ge',), 'unsatisfiable_constraint', "`ge=2.0.0` and `lt=1.0.0` can't both be satisfied, no input is valid")
+    ]
+
+
+def test_union_choices():
+    schema = core_schema.union_schema(
+        [
+            core_schema.int_schema(),
+            (core_schema.str_schema(), 'label'),
+            core_schema.int_schema(),
+            core_schema.any_schema(),
+            core_schema.float_schema(),
+        ],
+        mode='left_to_right',
+    )
+    assert [(d['path'], d['kind']) for d in check_schema(schema)] == [
+        (('choices', 2), 'duplicate_union_choice'),
+        (('choices', 4), 'unreachable_union_choice'),
+    ]
+    # in smart mode later choices can still be a better match than `any`
+    schema['mode'] = 'smart'
+    assert [(d['path'], d['kind']) for d in check_schema(schema)] == [(('choices', 2), 'duplicate_union_choice')]
+
+
+def test_tagged_union():
+    class Pet(Enum):
+        CAT = 'cat'
+
+    def pet(tag: str):
+        field = core_schema.typed_dict_field(core_schema.literal_schema([tag]))
+        return core_schema.typed_dict_schema({'pet_type': field})
+
+    schema = core_schema.tagged_union_schema(
+        {Pet.CAT: pet('cat'), 'cat': pet('cat'), 'dog': pet('dog'), 'lion': pet('cat')}, discriminator='pet_type'
+    )
+    assert check_schema(schema) == [
+        {
+            'path': ('choices', 'cat'),
+            'kind': 'duplicate_discriminator_tag',
+            'message': "Tag 'cat' is the same as another tag in the tagged union",
+            'suggestion': 'use a distinct tag for each choice',
+        },
+        {
+            'path': ('choices', 'lion'),
+            'kind': 'unreachable_union_choice',
+            'message': "Choice 'lion' is never valid, its `pet_type` field only allows ['cat']",
+            'suggestion': "change the tag or allow it in the choice's `pet_type` field",
+        },
+    ]
+
+
+def test_tagged_union_const():
+    def version(value: int):
+        return core_schema.typed_dict_schema({'version': core_schema.typed_dict_field(core_schema.const_schema(value))})
+
+    schema = core_schema.tagged_union_schema({1: version(1), 2: version(1)}, discriminator='version')
+    assert [(d['path'], d['kind'], d['message']) for d in check_schema(schema)] == [
+        (('choices', '2'), 'unreachable_union_choice', 'Choice 2 is never valid, its `version` field only allows [1]'),
+    ]
+
+
+def test_invalid_schema():
+    diagnostics = check_schema({'type': 'int', 'gt': 1, 'interval': '[0, 1]', 'max_length': 1})
+    assert [(d['path'], d['kind']) for d in diagnostics] == [
+        ((), 'invalid_schema'),
+        (('max_length',), 'incompatible_constraint'),
+    ]
+    assert '`interval` cannot be combined with `gt`' in diagnostics[0]['message']
+
+
+def test_alias_mismatch():
+    schema = core_schema.model_fields_schema(
+        {
+            'a': core_schema.model_field(core_schema.int_schema(), validation_alias='A'),
+            'b': core_schema.model_field(core_schema.int_schema(), serialization_alias='B'),
+            'c': core_schema.model_field(
+                core_schema.int_schema(), validation_alias=['x', 'c'], serialization_alias='C'
+            ),
+            'same': core_schema.model_field(core_schema.int_schema(), validation_alias='S', serialization_alias='S'),
+            'choices': core_schema.model_field(
+                core_schema.int_schema(), validation_alias=[['ch'], ['Choices']], serialization_alias='Choices'
+            ),
+            'excluded': core_schema.model_field(
+                core_schema.int_schema(), validation_alias='E', serialization_exclude=True
+            ),
+        }
+    )
+    assert check_schema(schema) == [
+        {
+            'path': ('fields', 'a'),
+            'kind': 'alias_mismatch',
+            'message': "Field `a` is serialized as `a` but validated from `A`, so serialized data can't be validated "
+            'again',
+            'suggestion': "set `serialization_alias` to 'A', or `derive_aliases=True` on the schema",
+        },
+        {
+            'path': ('fields', 'b'),
+            'kind': 'alias_mismatch',
+            'message': "Field `b` is serialized as `B` but validated from `b`, so serialized data can't be validated "
+            'again',
+            'suggestion': "set `validation_alias` to 'B', or `derive_aliases=True` on the schema",
+        },
+        {
+            'path': ('fields', 'c'),
+            'kind': 'alias_mismatch',
+            'message': (
+                "Field `c` is serialized as `C` but only validated from nested data, so serialized data can't be "
+                'validated again'
+            ),
+            'suggestion': 'use the same alias for validation and serialization',
+        },
+    ]
+
+    schema['derive_aliases'] = True
+    assert [d['path'] for d in check_schema(schema)] == [('fields', 'c')]
+
+
+def test_alias_mismatch_keys_listed_once():
+    schema = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='a', serialization_alias='B'),
+            'c': core_schema.typed_dict_field(
+                core_schema.int_schema(), validation_alias=[['x'], ['x', 'y'], ['x']], serialization_alias='C'
+            ),
+        }
+    )
+    assert [d['message'] for d in check_schema(schema, {'validate_by_name': True})] == [
+        "Field `a` is serialized as `B` but validated from `a`, so serialized data can't be validated again",
+        "Field `c` is serialized as `C` but validated from `x` or `c`, so serialized data can't be validated again",
+    ]
+
+
+def test_alias_mismatch_by_name():
+    schema = core_schema.dataclass_args_schema(
+        'Foo', [core_schema.dataclass_field('a', core_schema.int_schema(), validation_alias='A')]
+    )
+    assert [d['path'] for d in check_schema(schema)] == [('fields', 0)]
+    assert check_schema(schema, {'validate_by_name': True}) == []
+    # a schema's own config applies to the schemas nested in it
+    nested = core_schema.typed_dict_schema(
+        {'a': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='A')},
+        config={'validate_by_name': True},
+    )
+    assert check_schema(core_schema.list_schema(nested)) == []
@@ -63,9 +63,15 @@ all_schema_functions = [
     (core_schema.bytes_schema, args(min_length=5, ref='xx'), {'type': 'bytes', 'min_length': 5, 'ref': 'xx'}),
     (core_schema.date_schema, args(), {'type': 'date'}),
     (core_schema.date_schema, args(gt=date(2020, 1, 1)), {'type': 'date', 'gt': date(2020, 1, 1)}),
+    (core_schema.date_schema, args(format='%d/%m/%Y'), {'type': 'date', 'format': '%d/%m/%Y'}),
     (core_schema.time_schema, args(), {'type': 'time', 'microseconds_precision': 'truncate'}),
     (core_schema.datetime_schema, args(), {'type': 'datetime', 'microseconds_precision': 'truncate'}),
     (core_schema.timedelta_schema, args(), {'type': 'timedelta', 'microseconds_precision': 'truncate'}),
+    (
+        core_schema.timedelta_schema,
+        args(allow_negative=False, sign_scope='days'),
+        {'type': 'timedelta', 'microseconds_precision': 'truncate', 'allow_negative': False, 'sign_scope': 'days'},
+    ),
     (
         core_schema.time_schema,
         args(microseconds_precision='error'),
@@ -81,7 +87,13 @@ all_schema_functions = [
         args(microseconds_precision='error'),
         {'type': 'timedelta', 'microseconds_precision': 'error'},
     ),
+    (
+        core_schema.timedelta_schema,
+        args(output='period'),
+        {'type': 'timedelta', 'microseconds_precision': 'truncate', 'output': 'period'},
+    ),
     (core_schema.literal_schema, args(['a', 'b']), {'type': 'literal', 'expected': ['a', 'b']}),
+    (core_schema.const_schema, args(2), {'type': 'const', 'value': 2}),
     (core_schema.missing_sentinel_schema, args(), {'type': 'missing-sentinel'}),
     (
         core_schema.enum_schema,
@@ -92,6 +104,16 @@ all_schema_functions = [
     (core_schema.callable_schema, args(), {'type': 'callable'}),
     (core_schema.list_schema, args(), {'type': 'list'}),
     (core_schema.list_schema, args({'type': 'int'}), {'type': 'list', 'items_schema': {'type': 'int'}}),
+    (
+        core_schema.keyed_list_schema,
+        args({'type': 'typed-dict', 'fields': {}}, 'name', duplicates='last'),
+        {
+            'type': 'keyed-list',
+            'items_schema': {'type': 'typed-dict', 'fields': {}},
+            'key': 'name',
+            'duplicates': 'last',
+        },
+    ),
     (core_schema.tuple_schema, args([]), {'type': 'tuple', 'items_schema': []}),
     (
         core_schema.set_schema,
@@ -308,9 +330,33 @@ all_schema_functions = [
         {'type': 'dataclass', 'schema': {'type': 'int'}, 'fields': ['foobar'], 'cls': MyDataclass, 'slots': True},
     ),
     (core_schema.uuid_schema, args(), {'type': 'uuid'}),
+    (core_schema.semver_schema, args(ge='1.0.0', lt='2.0.0'), {'type': 'semver', 'ge': '1.0.0', 'lt': '2.0.0'}),
+    (core_schema.ulid_schema, args(), {'type': 'ulid'}),
+    (core_schema.ip_v4_address_schema, args(), {'type': 'ip-v4-address'}),
+    (core_schema.ip_v6_address_schema, args(strict=True), {'type': 'ip-v6-address', 'strict': True}),
+    (core_schema.ip_network_schema, args(version=4), {'type': 'ip-network', 'version': 4}),
+    (core_schema.ip_interface_schema, args(), {'type': 'ip-interface'}),
+    (core_schema.email_schema, args(), {'type': 'email'}),
+    (
+        core_schema.email_schema,
+        args(max_length=100, allowed_domains=['example.com']),
+        {'type': 'email', 'max_length': 100, 'allowed_domains': ['example.com']},
+    ),
+    (core_schema.name_email_schema, args(), {'type': 'name-email'}),
+    (core_schema.path_schema, args(), {'type': 'path'}),
+    (
+        core_schema.path_schema,
+        args(is_file=True, suffix_in=['.toml']),
+        {'type': 'path', 'is_file': True, 'suffix_in': ['.toml']},
+    ),
     (core_schema.decimal_schema, args(), {'type': 'decimal'}),
     (core_schema.decimal_schema, args(multiple_of=5, gt=1.2), {'type': 'decimal', 'multiple_of': 5, 'gt': 1.2}),
     (core_schema.complex_schema, args(), {'type': 'complex'}),
+    (
+        core_schema.plugin_schema,
+        args('numpy-int64', options={'overflow': 'error'}),
+        {'type': 'plugin', 'plugin': 'numpy-int64', 'options': {'overflow': 'error'}},
+    ),
     (core_schema.invalid_schema, args(), {'type': 'invalid'}),
 ]
 
@@ -320,7 +366,8 @@ def test_schema_functions(function, args_kwargs, expected_schema):
     args, kwargs = args_kwargs
     schema = function(*args, **kwargs)
     assert schema == expected_schema
-    if schema.get('type') in {None, 'definition-ref', 'typed-dict-field', 'model-field', 'invalid'}:
+    # plugins are registered by other extensions
+    if schema.get('type') in {None, 'definition-ref', 'typed-dict-field', 'model-field', 'invalid', 'plugin'}:
         return
 
     v = SchemaValidator(schema)
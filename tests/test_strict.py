@@ -1,11 +1,12 @@
 from __future__ import annotations
 
 import re
+from datetime import date
 from typing import Any
 
 import pytest
 
-from pydantic_core import ValidationError
+from pydantic_core import SchemaValidator, ValidationError, core_schema
 
 from .conftest import Err, PyAndJson
 
@@ -47,3 +48,47 @@ def test_int_strict_argument(
     else:
         assert v.isinstance_test(input_value, strict_to_validator) is True
         assert v.validate_test(input_value, strict_to_validator) == expected
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        (core_schema.int_schema(), 1, 'exact'),
+        (core_schema.int_schema(), '1', 'lax'),
+        (core_schema.float_schema(), 1, 'strict'),
+        (core_schema.list_schema(core_schema.int_schema()), [1, 2], 'exact'),
+        (core_schema.list_schema(core_schema.int_schema()), [1, '2'], 'lax'),
+        (core_schema.list_schema(core_schema.int_schema()), (1, 2), 'lax'),
+        (core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]), '1', 'exact'),
+        (core_schema.union_schema([core_schema.int_schema(), core_schema.float_schema()]), '1.5', 'lax'),
+    ],
+)
+def test_exactness(schema, input_value, expected):
+    v = SchemaValidator(schema)
+    assert v.validate_python(input_value, exactness=True).exactness == expected
+
+
+def test_exactness_json():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.date_schema()))
+    result = v.validate_json('{"a": "2020-01-01"}', exactness=True)
+    assert (result.output, result.exactness) == ({'a': date(2020, 1, 1)}, 'strict')
+    result = v.validate_strings({'a': '2020-01-01'}, exactness=True)
+    assert (result.output, result.exactness) == ({'a': date(2020, 1, 1)}, 'strict')
+
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    result = v.validate_json('{"a": 1}', exactness=True)
+    assert (result.output, result.exactness) == ({'a': 1}, 'strict')
+    assert repr(result) == (
+        "ValidationResult(output={'a': 1}, trace=None, exactness='strict', partial_report=None, provenance=None)"
+    )
+    result = v.validate_json('{"a": 1.0}', exactness=True)
+    assert (result.output, result.exactness) == ({'a': 1}, 'lax')
+
+
+def test_exactness_with_trace():
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    result = v.validate_python(b'1', trace=True, exactness=True)
+    assert (result.output, result.exactness) == (1, 'lax')
+    assert result.trace[0]['winner'] == 'int'
+    # exactness isn't returned unless requested
+    assert v.validate_python('1') == '1'
@@ -0,0 +1,46 @@
+import pytest
+
+from pydantic_core import SchemaValidator, clear_string_cache, core_schema, set_string_cache_size, string_cache_info
+
+
+def test_string_cache_info():
+    clear_string_cache()
+    assert string_cache_info() == {'size': 16_384, 'usage': 0}
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.str_schema()))
+    # strings shorter than 2 characters aren't cached
+    assert v.validate_json('["a", "bb", "cc", "bb"]') == ['a', 'bb', 'cc', 'bb']
+    assert string_cache_info() == {'size': 16_384, 'usage': 2}
+
+    clear_string_cache()
+    assert string_cache_info()['usage'] == 0
+
+
+def test_set_string_cache_size():
+    v = SchemaValidator(core_schema.list_schema(core_schema.str_schema()))
+    try:
+        set_string_cache_size(8)
+        assert string_cache_info() == {'size': 8, 'usage': 0}
+        v.validate_json('[' + ', '.join(f'"value{i}"' for i in range(100)) + ']')
+        assert 0 < string_cache_info()['usage'] <= 8
+
+        values = v.validate_json('["active", "active"]')
+        assert values[0] is values[1]
+
+        # a size of 0 stops strings being cached
+        set_string_cache_size(0)
+        values = v.validate_json('["active", "active"]')
+        assert values[0] is not values[1]
+        assert string_cache_info() == {'size': 0, 'usage': 0}
+    finally:
+        set_string_cache_size(16_384)
+
+
+def test_set_string_cache_size_too_large():
+    with pytest.raises(ValueError, match=r'String cache size 1099511627776 is too large, the maximum is 16777216'):
+        set_string_cache_size(2**40)
+    # the cache is unchanged
+    assert string_cache_info()['size'] == 16_384
+
+    with pytest.raises(OverflowError):
+        set_string_cache_size(-1)
@@ -0,0 +1,127 @@
+import time
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def slow_int_schema(calls):
+    def slow(value):
+        calls.append(value)
+        time.sleep(0.01)
+        return value
+
+    return core_schema.no_info_after_validator_function(slow, core_schema.int_schema())
+
+
+def timeout_error(exc_info):
+    (error,) = exc_info.value.errors(include_url=False)
+    return error['type'], error['loc'], error['ctx']
+
+
+def test_timeout():
+    calls = []
+    v = SchemaValidator(core_schema.list_schema(slow_int_schema(calls)))
+    assert v.validate_python([1, 2], timeout_ms=10_000) == [1, 2]
+
+    calls.clear()
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(list(range(100)), timeout_ms=50)
+    assert timeout_error(exc_info) == ('validation_timeout', (), {'timeout_ms': 50})
+    assert exc_info.value.errors(include_url=False)[0]['msg'] == "Validation didn't finish within the timeout of 50ms"
+    # validation is abandoned, rather than validating every item
+    assert 0 < len(calls) < 100
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]', timeout_ms=0)
+    assert timeout_error(exc_info) == ('validation_timeout', (), {'timeout_ms': 0})
+
+
+def test_timeout_validate_strings():
+    calls = []
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), slow_int_schema(calls)))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings({'a': '1', 'b': '2'}, timeout_ms=0)
+    assert timeout_error(exc_info) == ('validation_timeout', (), {'timeout_ms': 0})
+    assert calls == []
+
+
+def test_timeout_other_errors():
+    calls = []
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.str_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(slow_int_schema(calls))),
+            }
+        )
+    )
+    # errors found before the timeout aren't reported with it
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': list(range(100))}, timeout_ms=30)
+    assert timeout_error(exc_info) == ('validation_timeout', (), {'timeout_ms': 30})
+
+
+def test_timeout_union():
+    calls = []
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.list_schema(slow_int_schema(calls)), core_schema.list_schema()])
+    )
+    # unions don't try their other choices once the timeout is exceeded
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(list(range(100)), timeout_ms=30)
+    assert timeout_error(exc_info) == ('validation_timeout', (), {'timeout_ms': 30})
+
+
+def test_timeout_wrap_validator():
+    calls = []
+
+    def wrap(value, handler):
+        try:
+            return handler(value)
+        except Exception:
+            return []
+
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.no_info_wrap_validator_function(wrap, core_schema.list_schema(slow_int_schema(calls)))
+        )
+    )
+    # validator functions catching the timeout within their handlers don't end it
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([list(range(100))], timeout_ms=30)
+    assert timeout_error(exc_info) == ('validation_timeout', (), {'timeout_ms': 30})
+
+
+def test_timeout_caught_by_wrap_validator():
+    calls = []
+    caught = []
+
+    def wrap(value, handler):
+        try:
+            return handler(value)
+        except Exception as e:
+            caught.append(type(e))
+            return []
+
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.no_info_wrap_validator_function(wrap, core_schema.list_schema(slow_int_schema(calls)))
+        )
+    )
+    # once the timeout is exceeded it keeps failing, the lists after the one whose timeout was caught aren't
+    # validated
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([list(range(100)), list(range(100)), list(range(100))], timeout_ms=30)
+    assert timeout_error(exc_info) == ('validation_timeout', (), {'timeout_ms': 30})
+    assert caught == [TimeoutError]
+    assert 0 < len(calls) < 100
+
+
+def test_timeout_generator():
+    calls = []
+    v = SchemaValidator(core_schema.generator_schema(slow_int_schema(calls)))
+    gen = v.validate_python(iter([1, 2, 3]), timeout_ms=10)
+    time.sleep(0.02)
+    # items are validated as the generator is consumed, after validation
+    assert list(gen) == [1, 2, 3]
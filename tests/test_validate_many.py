@@ -0,0 +1,83 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+@pytest.fixture(scope='module')
+def validator():
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+            }
+        )
+    )
+
+
+def test_validate_many_python(validator: SchemaValidator):
+    results = validator.validate_many([{'a': 1, 'b': ['x']}, {'a': 'x', 'b': []}, {'a': '2', 'b': ('y',)}])
+    assert len(results) == 3
+    assert results[0] == {'a': 1, 'b': ['x']}
+    assert isinstance(results[1], ValidationError)
+    assert [(e['type'], e['loc']) for e in results[1].errors()] == [('int_parsing', ('a',))]
+    assert results[2] == {'a': 2, 'b': ['y']}
+
+    # strict applies to every input
+    results = validator.validate_many([{'a': 1, 'b': ['x']}, {'a': '2', 'b': ['y']}], strict=True)
+    assert results[0] == {'a': 1, 'b': ['x']}
+    assert [(e['type'], e['loc']) for e in results[1].errors()] == [('int_type', ('a',))]
+
+
+def test_validate_many_json(validator: SchemaValidator):
+    results = validator.validate_many(
+        ['{"a": 1, "b": ["x"]}', b'{"a": "x", "b": []}', '{"a": 1', bytearray(b'{"a": 3, "b": []}'), 42],
+        input_type='json',
+    )
+    assert len(results) == 5
+    assert results[0] == {'a': 1, 'b': ['x']}
+    assert [(e['type'], e['loc']) for e in results[1].errors()] == [('int_parsing', ('a',))]
+    assert [e['type'] for e in results[2].errors()] == ['json_invalid']
+    assert results[3] == {'a': 3, 'b': []}
+    assert [e['type'] for e in results[4].errors()] == ['json_type']
+    assert results[1].errors(include_url=False)[0]['input'] == 'x'
+
+    # the same as validating each input with validate_json
+    inputs = ['{"a": 1, "b": ["x"]}', '{"b": 1}']
+    for input, result in zip(inputs, validator.validate_many(inputs, input_type='json')):
+        try:
+            expected = validator.validate_json(input)
+        except ValidationError as e:
+            assert result.errors() == e.errors()
+        else:
+            assert result == expected
+
+
+def test_validate_many_strings():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    results = v.validate_many([{'a': '1'}, {'a': 'x'}], input_type='string')
+    assert results[0] == {'a': 1}
+    assert [(e['type'], e['loc']) for e in results[1].errors()] == [('int_parsing', ('a',))]
+
+
+def test_validate_many_iterable():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_many(str(i) for i in range(3)) == [0, 1, 2]
+    assert v.validate_many([]) == []
+
+
+def test_validate_many_internal_error():
+    def f(input_value):
+        if input_value == 2:
+            raise RuntimeError('boom')
+        return input_value
+
+    v = SchemaValidator(core_schema.no_info_after_validator_function(f, core_schema.int_schema()))
+    with pytest.raises(RuntimeError, match='boom'):
+        v.validate_many([1, 2, 3])
+
+
+def test_validate_many_invalid_input_type():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValueError, match='xml'):
+        v.validate_many([1], input_type='xml')
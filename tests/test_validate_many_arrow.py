@@ -0,0 +1,287 @@
+import ctypes
+import dataclasses
+import gc
+from datetime import date, datetime, timedelta, timezone
+
+import pytest
+
+from pydantic_core import ArrowBatch, SchemaValidator, ValidationError, core_schema
+
+try:
+    import pyarrow
+except ImportError:
+    pyarrow = None
+
+
+class ArrowSchema(ctypes.Structure):
+    pass
+
+
+ArrowSchema._fields_ = [
+    ('format', ctypes.c_char_p),
+    ('name', ctypes.c_char_p),
+    ('metadata', ctypes.c_char_p),
+    ('flags', ctypes.c_int64),
+    ('n_children', ctypes.c_int64),
+    ('children', ctypes.POINTER(ctypes.POINTER(ArrowSchema))),
+    ('dictionary', ctypes.POINTER(ArrowSchema)),
+    ('release', ctypes.c_void_p),
+    ('private_data', ctypes.c_void_p),
+]
+
+
+class ArrowArray(ctypes.Structure):
+    pass
+
+
+ArrowArray._fields_ = [
+    ('length', ctypes.c_int64),
+    ('null_count', ctypes.c_int64),
+    ('offset', ctypes.c_int64),
+    ('n_buffers', ctypes.c_int64),
+    ('n_children', ctypes.c_int64),
+    ('buffers', ctypes.POINTER(ctypes.c_void_p)),
+    ('children', ctypes.POINTER(ctypes.POINTER(ArrowArray))),
+    ('dictionary', ctypes.POINTER(ArrowArray)),
+    ('release', ctypes.c_void_p),
+    ('private_data', ctypes.c_void_p),
+]
+
+PyCapsule_GetPointer = ctypes.pythonapi.PyCapsule_GetPointer
+PyCapsule_GetPointer.restype = ctypes.c_void_p
+PyCapsule_GetPointer.argtypes = [ctypes.py_object, ctypes.c_char_p]
+
+
+def capsule_struct(capsule, name, struct):
+    return struct.from_address(PyCapsule_GetPointer(capsule, name))
+
+
+def read_column(schema: ArrowSchema, array: ArrowArray):
+    """Read the values of a column which is a child of the exported struct array, a simple C data interface consumer."""
+    fmt = schema.format.decode()
+    length = array.length
+    if fmt == 'n':
+        assert array.n_buffers == 0
+        return [None] * length
+
+    def valid(i):
+        if not array.buffers[0]:
+            return True
+        return bool(ctypes.string_at(array.buffers[0], (length + 7) // 8)[i // 8] & (1 << (i % 8)))
+
+    if fmt == 'b':
+        bits = ctypes.string_at(array.buffers[1], (length + 7) // 8)
+        values = [bool(bits[i // 8] & (1 << (i % 8))) for i in range(length)]
+    elif fmt in ('l', 'tsu:', 'tsu:UTC'):
+        values = list((ctypes.c_int64 * length).from_address(array.buffers[1])) if length else []
+    elif fmt == 'g':
+        values = list((ctypes.c_double * length).from_address(array.buffers[1])) if length else []
+    elif fmt == 'tdD':
+        values = list((ctypes.c_int32 * length).from_address(array.buffers[1])) if length else []
+    elif fmt in ('U', 'Z'):
+        offsets = list((ctypes.c_int64 * (length + 1)).from_address(array.buffers[1]))
+        data = ctypes.string_at(array.buffers[2], offsets[-1]) if offsets[-1] else b''
+        values = [data[offsets[i] : offsets[i + 1]] for i in range(length)]
+        if fmt == 'U':
+            values = [v.decode() for v in values]
+    else:
+        raise AssertionError(f'unexpected format {fmt}')
+    return [v if valid(i) else None for i, v in enumerate(values)]
+
+
+def read_batch(batch: ArrowBatch):
+    """The formats and the values of each column of the batch, by name."""
+    schema_capsule, array_capsule = batch.__arrow_c_array__()
+    schema = capsule_struct(schema_capsule, b'arrow_schema', ArrowSchema)
+    array = capsule_struct(array_capsule, b'arrow_array', ArrowArray)
+    assert schema.format == b'+s'
+    assert schema.n_children == array.n_children
+    assert array.length == batch.num_rows
+    columns = {}
+    for i in range(schema.n_children):
+        child_schema, child_array = schema.children[i].contents, array.children[i].contents
+        assert child_array.length == array.length
+        columns[child_schema.name.decode()] = (child_schema.format.decode(), read_column(child_schema, child_array))
+    return columns
+
+
+user_schema = core_schema.typed_dict_schema(
+    {
+        'id': core_schema.typed_dict_field(core_schema.int_schema()),
+        'name': core_schema.typed_dict_field(core_schema.str_schema()),
+        'score': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.float_schema())),
+        'active': core_schema.typed_dict_field(core_schema.bool_schema()),
+    }
+)
+
+
+def test_validate_many_arrow():
+    v = SchemaValidator(user_schema)
+    batch = v.validate_many_arrow(
+        [
+            {'id': 1, 'name': 'alice', 'score': 1.5, 'active': True},
+            {'id': 'x', 'name': 'bob', 'score': None, 'active': False},
+            {'id': '3', 'name': 'carol', 'score': None, 'active': 'yes'},
+            {'id': 4, 'name': '', 'score': 2, 'active': False},
+        ]
+    )
+    assert batch.num_rows == len(batch) == 3
+    assert batch.column_names == ['id', 'name', 'score', 'active']
+    assert read_batch(batch) == {
+        'id': ('l', [1, 3, 4]),
+        'name': ('U', ['alice', 'carol', '']),
+        'score': ('g', [1.5, None, 2.0]),
+        'active': ('b', [True, True, False]),
+    }
+    [(index, error)] = batch.errors
+    assert index == 1
+    assert isinstance(error, ValidationError)
+    assert [(e['type'], e['loc']) for e in error.errors()] == [('int_parsing', ('id',))]
+    assert repr(batch) == 'ArrowBatch(num_rows=3, num_columns=4, errors=1)'
+
+
+def test_validate_many_arrow_json():
+    v = SchemaValidator(user_schema)
+    batch = v.validate_many_arrow(
+        ['{"id": 1, "name": "a", "score": null, "active": true}', '{"id": 2', b'{"id": 2, "name": "b", "active": 0}'],
+        input_type='json',
+    )
+    assert [(index, [e['type'] for e in error.errors()]) for index, error in batch.errors] == [
+        (1, ['json_invalid']),
+        (2, ['missing']),
+    ]
+    assert read_batch(batch)['name'] == ('U', ['a'])
+
+
+def test_schema_capsule():
+    batch = SchemaValidator(user_schema).validate_many_arrow([{'id': 1, 'name': 'a', 'score': None, 'active': True}])
+    schema_capsule = batch.__arrow_c_schema__()
+    schema = capsule_struct(schema_capsule, b'arrow_schema', ArrowSchema)
+    assert schema.format == b'+s'
+    fields = [(schema.children[i].contents.name, schema.children[i].contents.format) for i in range(schema.n_children)]
+    assert fields == [(b'id', b'l'), (b'name', b'U'), (b'score', b'n'), (b'active', b'b')]
+    # every column is nullable
+    assert {schema.children[i].contents.flags for i in range(schema.n_children)} == {2}
+
+
+def test_nulls_before_first_value():
+    v = SchemaValidator(core_schema.dict_schema())
+    batch = v.validate_many_arrow([{'a': None, 'b': b'x'}, {'a': None}, {'a': 'x', 'b': None}, {'a': 'y', 'b': b''}])
+    assert read_batch(batch) == {'a': ('U', [None, None, 'x', 'y']), 'b': ('Z', [b'x', None, None, b''])}
+
+
+def test_dates():
+    v = SchemaValidator(core_schema.dict_schema())
+    utc = timezone.utc
+    batch = v.validate_many_arrow(
+        [
+            {
+                'd': date(1970, 1, 2),
+                'naive': datetime(1970, 1, 1, 0, 0, 1, 5),
+                'aware': datetime(2000, 1, 1, tzinfo=utc),
+            },
+            {
+                'd': date(1969, 12, 31),
+                'naive': None,
+                'aware': datetime(2000, 1, 1, 1, tzinfo=timezone(timedelta(hours=1))),
+            },
+        ]
+    )
+    assert read_batch(batch) == {
+        'd': ('tdD', [1, -1]),
+        'naive': ('tsu:', [1_000_005, None]),
+        'aware': ('tsu:UTC', [946_684_800_000_000, 946_684_800_000_000]),
+    }
+
+
+def test_models_and_dataclasses():
+    class Model:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    model_schema = core_schema.model_schema(
+        Model,
+        core_schema.model_fields_schema(
+            {
+                'x': core_schema.model_field(core_schema.int_schema()),
+                'y': core_schema.model_field(core_schema.str_schema()),
+            }
+        ),
+    )
+    batch = SchemaValidator(model_schema).validate_many_arrow([{'x': 1, 'y': 'a'}, {'x': '2', 'y': 'b'}])
+    assert read_batch(batch) == {'x': ('l', [1, 2]), 'y': ('U', ['a', 'b'])}
+
+    @dataclasses.dataclass
+    class Point:
+        x: int
+        y: int
+
+    dataclass_schema = core_schema.dataclass_schema(
+        Point,
+        core_schema.dataclass_args_schema(
+            'Point',
+            [
+                core_schema.dataclass_field('x', core_schema.int_schema()),
+                core_schema.dataclass_field('y', core_schema.int_schema()),
+            ],
+        ),
+        ['x', 'y'],
+    )
+    batch = SchemaValidator(dataclass_schema).validate_many_arrow([{'x': 1, 'y': 2}])
+    assert read_batch(batch) == {'x': ('l', [1]), 'y': ('l', [2])}
+
+
+def test_empty():
+    batch = SchemaValidator(user_schema).validate_many_arrow([{'id': 'x'}])
+    assert batch.num_rows == 0
+    assert batch.column_names == []
+    assert len(batch.errors) == 1
+    assert read_batch(batch) == {}
+
+
+def test_capsules_outlive_batch():
+    batch = SchemaValidator(core_schema.dict_schema()).validate_many_arrow([{'a': 'x' * 100}, {'a': 'y'}])
+    schema_capsule, array_capsule = batch.__arrow_c_array__()
+    del batch
+    gc.collect()
+    schema = capsule_struct(schema_capsule, b'arrow_schema', ArrowSchema)
+    array = capsule_struct(array_capsule, b'arrow_array', ArrowArray)
+    assert read_column(schema.children[0].contents, array.children[0].contents) == ['x' * 100, 'y']
+
+
+@pytest.mark.parametrize(
+    'inputs,error,message',
+    [
+        ([{'a': 1}, {'a': 'x'}], TypeError, r'Field `a` has values of different types, `str` isn\'t `l`'),
+        ([{'a': True}, {'a': 1}], TypeError, r'Field `a` has values of different types, `int` isn\'t `b`'),
+        ([{'a': 1}, {'a': 1, 'b': 2}], TypeError, 'Results have different fields'),
+        ([{'a': [1]}], TypeError, "Field `a` has a value of type `list`, which can't be exported to Arrow"),
+        ([{'a': 2**63}], OverflowError, "Field `a` has an int which doesn't fit in an int64"),
+        (
+            [{'a': datetime(2000, 1, 1)}, {'a': datetime(2000, 1, 1, tzinfo=timezone.utc)}],
+            TypeError,
+            'Field `a` mixes naive and aware datetimes',
+        ),
+    ],
+)
+def test_export_errors(inputs, error, message):
+    v = SchemaValidator(core_schema.dict_schema())
+    with pytest.raises(error, match=message):
+        v.validate_many_arrow(inputs)
+
+
+def test_not_a_record():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(TypeError, match='Only dicts, models and dataclasses can be exported to Arrow, not `int`'):
+        v.validate_many_arrow([1])
+
+
+@pytest.mark.skipif(not pyarrow, reason='pyarrow not installed')
+def test_pyarrow():
+    batch = SchemaValidator(user_schema).validate_many_arrow(
+        [{'id': 1, 'name': 'a', 'score': 0.5, 'active': True}, {'id': 2, 'name': 'b', 'score': None, 'active': False}]
+    )
+    record_batch = pyarrow.record_batch(batch)
+    assert record_batch.to_pylist() == [
+        {'id': 1, 'name': 'a', 'score': 0.5, 'active': True},
+        {'id': 2, 'name': 'b', 'score': None, 'active': False},
+    ]
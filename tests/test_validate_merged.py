@@ -0,0 +1,186 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+
+class Model:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+def config_validator() -> SchemaValidator:
+    db_schema = core_schema.typed_dict_schema(
+        {
+            'host': core_schema.typed_dict_field(core_schema.str_schema()),
+            'port': core_schema.typed_dict_field(core_schema.int_schema()),
+            'options': core_schema.typed_dict_field(
+                core_schema.dict_schema(core_schema.str_schema(), core_schema.str_schema()),
+                required=False,
+                merge_policy='merge',
+            ),
+        }
+    )
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                'db': core_schema.typed_dict_field(db_schema, merge_policy='merge'),
+                'plugins': core_schema.typed_dict_field(
+                    core_schema.list_schema(core_schema.str_schema()), merge_policy='append'
+                ),
+                'tags': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+            }
+        )
+    )
+
+
+def test_merge_policies():
+    v = config_validator()
+    base = {
+        'name': 'base',
+        'db': {'host': 'localhost', 'port': 5432, 'options': {'sslmode': 'off', 'timeout': '10'}},
+        'plugins': ['auth'],
+        'tags': ['a', 'b'],
+    }
+    override = {'db': {'port': '6543', 'options': {'sslmode': 'on'}}, 'plugins': ('metrics',), 'tags': ['c']}
+    value, sources = v.validate_merged([base, override])
+    assert value == {
+        'name': 'base',
+        'db': {'host': 'localhost', 'port': 6543, 'options': {'sslmode': 'on', 'timeout': '10'}},
+        'plugins': ['auth', 'metrics'],
+        'tags': ['c'],
+    }
+    assert sources == {
+        ('name',): 0,
+        ('db', 'host'): 0,
+        ('db', 'port'): 1,
+        ('db', 'options', 'sslmode'): 1,
+        ('db', 'options', 'timeout'): 0,
+        ('plugins', 0): 0,
+        ('plugins', 1): 1,
+        ('tags',): 1,
+    }
+    # the layers aren't changed
+    assert base['db'] == {'host': 'localhost', 'port': 5432, 'options': {'sslmode': 'off', 'timeout': '10'}}
+
+
+def test_value_replaces_earlier_layers():
+    v = config_validator()
+    layers = [
+        {'name': 'a', 'db': {'host': 'x', 'port': 1}, 'plugins': ['auth'], 'tags': []},
+        {'db': None, 'plugins': None},
+        {'db': {'host': 'y', 'port': 2}, 'plugins': ['metrics']},
+    ]
+    value, sources = v.validate_merged(layers)
+    assert value == {'name': 'a', 'db': {'host': 'y', 'port': 2}, 'plugins': ['metrics'], 'tags': []}
+    assert sources == {('name',): 0, ('db', 'host'): 2, ('db', 'port'): 2, ('plugins', 0): 2, ('tags',): 0}
+
+
+def test_merged_result_invalid():
+    v = config_validator()
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_merged([{'name': 'a', 'db': {'host': 'x'}, 'plugins': [], 'tags': []}, {'db': {'port': 'x'}}])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', ('db', 'port'))]
+
+
+def test_model():
+    inner_schema = core_schema.model_schema(
+        Model,
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(core_schema.int_schema()),
+                'b': core_schema.model_field(core_schema.list_schema(core_schema.int_schema()), merge_policy='append'),
+            }
+        ),
+    )
+    v = SchemaValidator(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {
+                    'inner': core_schema.model_field(
+                        core_schema.with_default_schema(core_schema.nullable_schema(inner_schema), default=None),
+                        validation_alias='Inner',
+                        merge_policy='merge',
+                    ),
+                }
+            ),
+        )
+    )
+    model, sources = v.validate_merged([{'Inner': {'a': 1, 'b': [1]}}, {'Inner': {'b': [2]}}, {'Inner': {'a': 3}}])
+    assert model.inner.a == 3
+    assert model.inner.b == [1, 2]
+    assert sources == {('Inner', 'a'): 2, ('Inner', 'b', 0): 0, ('Inner', 'b', 1): 1}
+
+
+def test_recursive():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('node'),
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'values': core_schema.typed_dict_field(
+                            core_schema.list_schema(core_schema.int_schema()), merge_policy='append'
+                        ),
+                        'child': core_schema.typed_dict_field(
+                            core_schema.nullable_schema(core_schema.definition_reference_schema('node')),
+                            required=False,
+                            merge_policy='merge',
+                        ),
+                    },
+                    ref='node',
+                )
+            ],
+        )
+    )
+    value, sources = v.validate_merged(
+        [{'values': [1], 'child': {'values': [2]}}, {'values': [3], 'child': {'values': [4]}}]
+    )
+    assert value == {'values': [1, 3], 'child': {'values': [2, 4]}}
+    assert sources == {('values', 0): 0, ('values', 1): 1, ('child', 'values', 0): 0, ('child', 'values', 1): 1}
+
+
+def test_no_layers():
+    v = config_validator()
+    with pytest.raises(ValidationError, match='4 validation errors'):
+        v.validate_merged([])
+
+
+def test_layer_not_dict():
+    v = config_validator()
+    with pytest.raises(TypeError, match="Each layer must be a dict, layer 1 isn't"):
+        v.validate_merged([{}, [('name', 'a')]])
+
+
+def test_invalid_merge_policy():
+    with pytest.raises(SchemaError, match=re.escape("Invalid merge_policy: `deep`, expected 'replace', 'merge' or")):
+        SchemaValidator(
+            core_schema.typed_dict_schema(
+                {'a': core_schema.typed_dict_field(core_schema.int_schema(), merge_policy='deep')}  # type: ignore
+            )
+        )
+
+
+def test_alias_and_name():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'tags': core_schema.typed_dict_field(
+                    core_schema.list_schema(core_schema.str_schema()), validation_alias='Tags', merge_policy='append'
+                ),
+                'name': core_schema.typed_dict_field(core_schema.str_schema(), validation_alias='Name'),
+            },
+            config=core_schema.CoreConfig(validate_by_name=True),
+        )
+    )
+    # the values of a field are merged whether layers have its alias or its name
+    value, sources = v.validate_merged([{'Tags': ['a'], 'name': 'x'}, {'tags': ['b'], 'Name': 'y'}])
+    assert value == {'tags': ['a', 'b'], 'name': 'y'}
+    assert sources == {('Tags', 0): 0, ('Tags', 1): 1, ('Name',): 1}
+
+    # without `by_name` the name isn't the field
+    value, sources = v.validate_merged([{'Tags': ['a'], 'Name': 'x'}, {'tags': ['b']}], by_name=False)
+    assert value == {'tags': ['a'], 'name': 'x'}
+    assert sources == {('Tags', 0): 0, ('Name',): 0, ('tags',): 1}
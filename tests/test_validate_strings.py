@@ -132,3 +132,42 @@ def test_validate_strings_forbid_extra_fn_override():
 
     with pytest.raises(ValidationError, match='Extra inputs are not permitted'):
         v.validate_strings({'f': '1', 'extra_field': '123'}, extra='forbid')
+
+
+def test_env_style():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'debug': core_schema.typed_dict_field(core_schema.bool_schema()),
+                'hosts': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+                'ports': core_schema.typed_dict_field(core_schema.tuple_schema([core_schema.int_schema()] * 2)),
+                'tags': core_schema.typed_dict_field(core_schema.frozenset_schema(core_schema.str_schema())),
+                'timeout': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.int_schema())),
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+            }
+        )
+    )
+    env = {'debug': 'on', 'hosts': 'a.com, b.com', 'ports': '80,443', 'tags': '', 'timeout': 'null', 'name': 'null'}
+    assert v.validate_strings(env, env_style=True) == {
+        'debug': True,
+        'hosts': ['a.com', 'b.com'],
+        'ports': (80, 443),
+        'tags': frozenset(),
+        'timeout': None,
+        # `null` is only `None` where `None` is allowed
+        'name': 'null',
+    }
+    assert v.validate_strings({**env, 'timeout': ''}, env_style=True)['timeout'] is None
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings(env)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('list_type', ('hosts',)),
+        ('tuple_type', ('ports',)),
+        ('frozen_set_type', ('tags',)),
+        ('int_parsing', ('timeout',)),
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings({**env, 'ports': '80, x'}, env_style=True)
+    assert [(e['type'], e['loc'], e['input']) for e in exc_info.value.errors()] == [('int_parsing', ('ports', 1), 'x')]
@@ -0,0 +1,143 @@
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+
+class MyModel:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+def model_schema(**kwargs) -> core_schema.ModelSchema:
+    return core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema({'sub': core_schema.model_field(core_schema.str_schema())}),
+        **kwargs,
+    )
+
+
+def test_validate_json_cached():
+    v = SchemaValidator(model_schema(frozen=True), config={'validation_cache_size': 10})
+    m = v.validate_json('{"sub": "alice"}')
+    assert m.__dict__ == {'sub': 'alice'}
+    assert v.validate_json('{"sub": "alice"}') is m
+    # str and bytes inputs are cached separately
+    assert v.validate_json(b'{"sub": "alice"}') is not m
+    assert v.validate_json('{"sub": "bob"}').__dict__ == {'sub': 'bob'}
+
+    # inputs validated with options which change the output are cached separately
+    strict = v.validate_json('{"sub": "alice"}', strict=True)
+    assert strict is not m
+    assert v.validate_json('{"sub": "alice"}', strict=True) is strict
+    # and not at all with some, e.g. `context`
+    assert v.validate_json('{"sub": "alice"}', context={}) is not m
+    assert v.validate_json('{"sub": "alice"}') is m
+
+    v.clear_validation_cache()
+    assert v.validate_json('{"sub": "alice"}') is not m
+
+
+def test_validate_python_cached():
+    v = SchemaValidator(model_schema(frozen=True, revalidate_instances='always'), config={'validation_cache_size': 10})
+    m = v.validate_python({'sub': 'alice'})
+    # only immutable inputs are cached, not dicts or instances
+    assert v.validate_python({'sub': 'alice'}) is not m
+    revalidated = v.validate_python(m)
+    assert revalidated is not m
+    assert v.validate_python(m) is not revalidated
+
+
+def test_from_attributes_not_cached():
+    class Attributes:
+        sub = 'alice'
+
+    v = SchemaValidator(model_schema(frozen=True), config={'validation_cache_size': 10})
+    attributes = Attributes()
+    m = v.validate_python(attributes, from_attributes=True)
+    assert m.__dict__ == {'sub': 'alice'}
+    # the object can change between calls, so it's validated again
+    attributes.sub = 'bob'
+    assert v.validate_python(attributes, from_attributes=True).__dict__ == {'sub': 'bob'}
+
+
+def test_equal_inputs_of_different_types():
+    class MyRootModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyRootModel,
+            core_schema.tuple_schema([core_schema.any_schema()], variadic_item_index=0),
+            root_model=True,
+            frozen=True,
+        ),
+        config={'validation_cache_size': 10},
+    )
+    inputs = [(1,), (True,), (1.0,), (frozenset([1]),), (frozenset([True]),), (0.0,), (-0.0,)]
+    outputs = [v.validate_python(i) for i in inputs]
+    for input_value, output in zip(inputs, outputs):
+        assert v.validate_python(input_value) is output
+        assert repr(output.root) == repr(input_value)
+
+    # tuples of mutable values aren't cached
+    mutable = ([1],)
+    assert v.validate_python(mutable) is not v.validate_python(mutable)
+
+
+def test_unhashable_input_not_cached():
+    v = SchemaValidator(model_schema(frozen=True), config={'validation_cache_size': 10})
+    data = {'sub': 'alice'}
+    m = v.validate_python(data)
+    # dicts can't be cache keys, so they're validated again every time
+    assert v.validate_python(data) is not m
+    assert v.validate_python(data).__dict__ == m.__dict__
+
+
+def test_mutable_values_shared():
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema({'tags': core_schema.model_field(core_schema.list_schema())}),
+            frozen=True,
+        ),
+        config={'validation_cache_size': 10},
+    )
+    m = v.validate_json('{"tags": ["a"]}')
+    m.tags.append('b')
+    # the cached instance is returned, with the list changed
+    assert v.validate_json('{"tags": ["a"]}').tags == ['a', 'b']
+
+
+def test_errors_not_cached():
+    v = SchemaValidator(model_schema(frozen=True), config={'validation_cache_size': 10})
+    for _ in range(2):
+        with pytest.raises(ValidationError, match=r'sub\s+Field required'):
+            v.validate_json('{}')
+
+
+def test_oldest_dropped():
+    v = SchemaValidator(model_schema(frozen=True), config={'validation_cache_size': 2})
+    inputs = [f'{{"sub": "{name}"}}' for name in 'abc']
+    a, b, c = [v.validate_json(i) for i in inputs]
+    assert v.validate_json(inputs[1]) is b
+    assert v.validate_json(inputs[2]) is c
+    assert v.validate_json(inputs[0]) is not a
+
+
+def test_definitions():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('m'), [model_schema(frozen=True, ref='m')]
+        ),
+        config={'validation_cache_size': 10},
+    )
+    m = v.validate_json('{"sub": "alice"}')
+    assert v.validate_json('{"sub": "alice"}') is m
+
+
+@pytest.mark.parametrize('schema', [model_schema(), core_schema.dict_schema()])
+def test_not_frozen_model(schema):
+    with pytest.raises(SchemaError, match='`validation_cache_size` can only be used with frozen models'):
+        SchemaValidator(schema, config={'validation_cache_size': 10})
+
+    # without a cache, clearing it does nothing
+    SchemaValidator(schema).clear_validation_cache()
@@ -129,3 +129,35 @@ def test_full(py_and_json: PyAndJson, input_value, expected) -> None:
     )
 
     assert v.validate_test(input_value) == expected
+
+
+def test_fail_fast() -> None:
+    v = SchemaValidator(
+        cs.arguments_v3_schema(
+            [
+                cs.arguments_v3_parameter(name='a', schema=cs.int_schema()),
+                cs.arguments_v3_parameter(name='args', schema=cs.int_schema(), mode='var_args'),
+                cs.arguments_v3_parameter(name='kwargs', schema=cs.int_schema(), mode='var_kwargs_uniform'),
+            ]
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs(('x', 'y', 'z'), {'b': 'x', 'c': 'x'}), fail_fast=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [(0,)]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'args': ('y', 'z'), 'kwargs': {'b': 'x'}}, fail_fast=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [('a',)]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'args': ('y', 'z'), 'kwargs': {'b': 'x'}}, fail_fast=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [('args', 0)]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs(('x', 'y', 'z'), {'b': 'x', 'c': 'x'}))
+    assert exc_info.value.error_count() == 5
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs(('x', 'y', 'z'), {'b': 'x', 'c': 'x'}), max_errors=2)
+    assert exc_info.value.error_count() == 2
+    assert exc_info.value.truncated
@@ -1,6 +1,6 @@
 import pytest
 
-from pydantic_core import ArgsKwargs, ValidationError
+from pydantic_core import ArgsKwargs, SchemaValidator, ValidationError
 from pydantic_core import core_schema as cs
 
 from ...conftest import Err, PyAndJson
@@ -118,3 +118,22 @@ def test_var_kwargs_invalid_dict(py_and_json: PyAndJson) -> None:
 
     assert error['type'] == 'dict_type'
     assert error['loc'] == ('kwargs',)
+
+
+def test_var_kwargs_not_dict() -> None:
+    """Test a var-kwargs parameter (unpacked typed dict) whose validator doesn't return a dict."""
+    v = SchemaValidator(
+        cs.arguments_v3_schema(
+            [
+                cs.arguments_v3_parameter(
+                    name='kwargs',
+                    schema=cs.no_info_after_validator_function(list, cs.typed_dict_schema({})),
+                    mode='var_kwargs_unpacked_typed_dict',
+                ),
+            ]
+        )
+    )
+
+    for input_value in (ArgsKwargs((), {}), {'kwargs': {}}, {}):
+        with pytest.raises(TypeError, match='Unpacked typed dict validator should return a dict of keyword arguments'):
+            v.validate_python(input_value)
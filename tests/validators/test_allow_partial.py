@@ -336,3 +336,254 @@ def test_json_trailing_strings():
     assert v.validate_json(
         '["{\\"a\\": 1}", "{\\"b\\": 2, \\"c\\": \\"x', allow_partial='trailing-strings'
     ) == snapshot([{'a': 1}, {'b': 2, 'c': 'x'}])
+
+
+class MyModel:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+def test_model_json_field_trailing_strings():
+    payload_schema = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+            'b': core_schema.typed_dict_field(core_schema.str_schema(), required=False),
+        }
+    )
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'x': core_schema.model_field(core_schema.int_schema()),
+                    'payload': core_schema.model_field(
+                        core_schema.json_or_python_schema(
+                            json_schema=core_schema.json_schema(payload_schema),
+                            python_schema=core_schema.json_schema(payload_schema),
+                        )
+                    ),
+                }
+            ),
+        )
+    )
+
+    m = v.validate_json('{"x": 1, "payload": "{\\"a\\": [1, 2], \\"b\\": \\"hel', allow_partial='trailing-strings')
+    assert m.__dict__ == snapshot({'x': 1, 'payload': {'a': [1, 2], 'b': 'hel'}})
+    m = v.validate_json('{"x": 1, "payload": "{\\"a\\": [1, 2], \\"b\\": \\"hel"', allow_partial=True)
+    assert m.__dict__ == snapshot({'x': 1, 'payload': {'a': [1, 2]}})
+    m = v.validate_python({'x': 1, 'payload': '{"a": [1, 2], "b": "hel'}, allow_partial='trailing-strings')
+    assert m.__dict__ == snapshot({'x': 1, 'payload': {'a': [1, 2], 'b': 'hel'}})
+
+    # only the last field is validated as partial
+    with pytest.raises(ValidationError, match=r'payload\s+Invalid JSON: EOF while parsing a string'):
+        v.validate_python({'payload': '{"a": [1, 2], "b": "hel', 'x': 1}, allow_partial='trailing-strings')
+    with pytest.raises(ValidationError, match=r'Invalid JSON: EOF while parsing a string'):
+        v.validate_json('{"x": 1, "payload": "{\\"a\\": [1, 2], \\"b\\": \\"hel')
+
+
+def test_model_partial_last_field_default():
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(
+                        core_schema.with_default_schema(core_schema.list_schema(core_schema.int_schema()), default=[])
+                    ),
+                    'c': core_schema.model_field(core_schema.str_schema(min_length=3)),
+                }
+            ),
+        )
+    )
+    m = v.validate_json('{"a": 1, "c": "abc", "b": [1, 2', allow_partial=True)
+    assert m.__dict__ == snapshot({'a': 1, 'b': [1, 2], 'c': 'abc'})
+    m = v.validate_json('{"a": 1, "c": "abc", "b": "x', allow_partial='trailing-strings')
+    assert m.__dict__ == snapshot({'a': 1, 'b': [], 'c': 'abc'})
+    assert m.__pydantic_fields_set__ == {'a', 'c'}
+
+    # without a default, errors in the last field are still raised
+    with pytest.raises(ValidationError, match=r'c\s+String should have at least 3 characters'):
+        v.validate_json('{"a": 1, "b": [1], "c": "a', allow_partial='trailing-strings')
+
+
+def test_partial_report():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(
+                    core_schema.list_schema(
+                        core_schema.dict_schema(core_schema.str_schema(), core_schema.str_schema(min_length=3))
+                    ),
+                    required=False,
+                ),
+            }
+        )
+    )
+    value = v.validate_json('{"a": 1, "b": [{"x": "abc"}, {"y": "abc", "z": "a', allow_partial=True)
+    assert value == {'a': 1, 'b': [{'x': 'abc'}, {'y': 'abc'}]}
+    # the incomplete string is left out by parsing, not validation
+    result = v.validate_json('{"a": 1, "b": [{"y": "abc", "z": "a', allow_partial=True, partial_report=True)
+    assert result.output == {'a': 1, 'b': [{'y': 'abc'}]}
+    assert result.partial_report == snapshot(
+        [
+            {
+                'loc': ('b', 0, 'z'),
+                'errors': [
+                    {
+                        'type': 'json_invalid',
+                        'loc': ('b', 0, 'z'),
+                        'msg': 'Invalid JSON: EOF while parsing a string',
+                        'input': '"a',
+                        'ctx': {'error': 'EOF while parsing a string'},
+                    }
+                ],
+            }
+        ]
+    )
+
+    result = v.validate_json(
+        '{"a": 1, "b": [{"x": "abc"}, {"y": "abc", "z": "a', allow_partial='trailing-strings', partial_report=True
+    )
+    assert result.output == {'a': 1, 'b': [{'x': 'abc'}, {'y': 'abc'}]}
+    assert result.partial_report == snapshot(
+        [
+            {
+                'loc': ('b', 1, 'z'),
+                'errors': [
+                    {
+                        'type': 'string_too_short',
+                        'loc': ('b', 1, 'z'),
+                        'msg': 'String should have at least 3 characters',
+                        'input': 'a',
+                        'ctx': {'min_length': 3},
+                    }
+                ],
+            }
+        ]
+    )
+
+    result = v.validate_python({'a': 1, 'b': [{'x': 'abc'}, 'wrong']}, allow_partial=True, partial_report=True)
+    assert result.output == {'a': 1, 'b': [{'x': 'abc'}]}
+    assert result.partial_report == snapshot(
+        [
+            {
+                'loc': ('b', 1),
+                'errors': [
+                    {
+                        'type': 'dict_type',
+                        'loc': ('b', 1),
+                        'msg': 'Input should be a valid dictionary',
+                        'input': 'wrong',
+                    }
+                ],
+            }
+        ]
+    )
+
+    # the last field of the typed dict is left out
+    result = v.validate_python({'a': 1, 'b': 'wrong'}, allow_partial=True, partial_report=True)
+    assert result.output == {'a': 1}
+    assert [omission['loc'] for omission in result.partial_report] == [('b',)]
+
+
+def test_partial_report_complete():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    result = v.validate_json('[1, 2, 3', allow_partial=True, partial_report=True)
+    assert (result.output, result.partial_report) == ([1, 2, 3], [])
+    # without `allow_partial` nothing is left out
+    result = v.validate_python([1, 2], partial_report=True)
+    assert (result.output, result.partial_report) == ([1, 2], [])
+
+
+@pytest.mark.parametrize(
+    'json,output,loc,input_value',
+    [
+        ('{"a": 1, "b": [{"x": "abc"}, tr', {'a': 1, 'b': [{'x': 'abc'}]}, ('b', 1), 'tr'),
+        ('{"a": 1, "b": ', {'a': 1}, ('b',), ''),
+        ('{"a": 1, "b"', {'a': 1}, ('b',), '"b"'),
+        ('{"a": 1, "b": [{"x": "abc"}, {"y', {'a': 1, 'b': [{'x': 'abc'}, {}]}, ('b', 1, 'y'), '"y'),
+    ],
+)
+def test_partial_report_json_truncated(json, output, loc, input_value):
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(
+                    core_schema.list_schema(
+                        core_schema.dict_schema(core_schema.str_schema(), core_schema.str_schema())
+                    ),
+                    required=False,
+                ),
+            }
+        )
+    )
+    result = v.validate_json(json, allow_partial=True, partial_report=True)
+    assert result.output == output
+    (omission,) = result.partial_report
+    assert omission['loc'] == loc
+    assert [(error['type'], error['input']) for error in omission['errors']] == [('json_invalid', input_value)]
+
+
+def test_partial_report_json_nothing_truncated():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.list_schema()))
+    # nothing of the next item has arrived, or the parser keeps the end
+    for json, allow_partial in ('{"a": [1],', True), ('{"a": [1, ', True), ('{"a": ["b', 'trailing-strings'):
+        assert v.validate_json(json, allow_partial=allow_partial, partial_report=True).partial_report == []
+    # a truncated item within an item which validation left out is only reported once
+    v = SchemaValidator(core_schema.list_schema(core_schema.list_schema(core_schema.int_schema(), min_length=2)))
+    result = v.validate_json('[[1, 2], [3, tr', allow_partial=True, partial_report=True)
+    assert result.output == [[1, 2]]
+    assert [omission['loc'] for omission in result.partial_report] == [(1,)]
+
+
+def test_partial_report_union():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.union_schema(
+                [
+                    core_schema.list_schema(core_schema.int_schema(), min_length=3),
+                    core_schema.list_schema(core_schema.str_schema()),
+                ]
+            )
+        )
+    )
+    # only the list of ints is valid, items left out by the list of strs aren't reported
+    result = v.validate_python([[1, 2, 3, 'x']], allow_partial=True, partial_report=True)
+    assert result.output == [[1, 2, 3]]
+    assert [(omission['loc'], omission['errors'][0]['type']) for omission in result.partial_report] == [
+        ((0, 3), 'int_parsing')
+    ]
+
+
+def test_partial_report_with_trace():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    result = v.validate_python([1, 'x'], allow_partial=True, trace=True, exactness=True, partial_report=True)
+    assert result.output == [1]
+    assert result.trace == []
+    assert result.exactness == 'exact'
+    assert [omission['loc'] for omission in result.partial_report] == [(1,)]
+    assert result.provenance is None
+
+
+def test_partial_report_model_default():
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(
+                        core_schema.with_default_schema(core_schema.list_schema(core_schema.int_schema()), default=[]),
+                        validation_alias='B',
+                    ),
+                }
+            ),
+        )
+    )
+    result = v.validate_json('{"a": 1, "B": "x', allow_partial='trailing-strings', partial_report=True)
+    assert result.output.__dict__ == {'a': 1, 'b': []}
+    assert [(omission['loc'], omission['errors'][0]['loc']) for omission in result.partial_report] == [
+        (('B',), ('B',))
+    ]
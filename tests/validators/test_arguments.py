@@ -845,6 +845,47 @@ def test_kwargs_typed_dict(py_and_json: PyAndJson, input_value, expected):
         assert v.validate_test(input_value) == expected
 
 
+@pytest.mark.parametrize(
+    'extra_behavior,expected',
+    [
+        ('ignore', ((1,), {'y': 2})),
+        ('allow', ((1,), {'y': 2, 'z': '3'})),
+        ('forbid', [('extra_forbidden', ('z',))]),
+    ],
+)
+def test_kwargs_typed_dict_extra(extra_behavior, expected):
+    v = SchemaValidator(
+        cs.arguments_schema(
+            [cs.arguments_parameter('x', cs.int_schema())],
+            var_kwargs_mode='unpacked-typed-dict',
+            var_kwargs_schema=cs.typed_dict_schema(
+                {'y': cs.typed_dict_field(cs.int_schema())}, extra_behavior=extra_behavior
+            ),
+        )
+    )
+    # unknown keyword arguments are the typed dict's extra keys
+    if isinstance(expected, list):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(ArgsKwargs((1,), {'y': '2', 'z': '3'}))
+        assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == expected
+    else:
+        assert v.validate_python(ArgsKwargs((1,), {'y': '2', 'z': '3'})) == expected
+
+
+def test_kwargs_typed_dict_not_dict():
+    v = SchemaValidator(
+        cs.arguments_schema(
+            [],
+            var_kwargs_mode='unpacked-typed-dict',
+            var_kwargs_schema=cs.no_info_after_validator_function(
+                list, cs.typed_dict_schema({'y': cs.typed_dict_field(cs.int_schema())})
+            ),
+        )
+    )
+    with pytest.raises(TypeError, match='should return a dict of keyword arguments, not list'):
+        v.validate_python(ArgsKwargs((), {'y': 1}))
+
+
 @pytest.mark.parametrize(
     'input_value,expected',
     [
@@ -1224,3 +1265,143 @@ def test_by_alias_and_name_config_interaction(
             (),
             {'my_field': 1},
         )
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (ArgsKwargs((1,)), ((1,), {'b': 2, 'c': 3})),
+        (ArgsKwargs((1, 4)), ((1, 4), {'c': 3})),
+        (ArgsKwargs((1,), {}), ((1,), {'b': 2, 'c': 3})),
+        (ArgsKwargs((1,), {'c': 5}), ((1,), {'b': 2, 'c': 5})),
+        (
+            ArgsKwargs(()),
+            Err(
+                '',
+                [
+                    {
+                        'type': 'missing_positional_only_argument',
+                        'loc': (0,),
+                        'msg': 'Missing required positional only argument',
+                    }
+                ],
+            ),
+        ),
+        (
+            ArgsKwargs((1, 2, 3)),
+            Err('', [{'type': 'unexpected_positional_argument', 'loc': (2,), 'msg': 'Unexpected positional argument'}]),
+        ),
+    ],
+)
+def test_positional_only_call_defaults(input_value, expected):
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter('a', core_schema.int_schema(), mode='positional_only'),
+                core_schema.arguments_parameter(
+                    'b', core_schema.with_default_schema(core_schema.int_schema(), default=2)
+                ),
+                core_schema.arguments_parameter(
+                    'c', core_schema.with_default_schema(core_schema.int_schema(), default=3), mode='keyword_only'
+                ),
+            ]
+        )
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        errors = exc_info.value.errors(include_url=False, include_input=False)
+        assert [{k: e[k] for k in ('type', 'loc', 'msg')} for e in errors] == expected.errors
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_positional_only_call_required_keyword_only():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter('a', core_schema.int_schema()),
+                core_schema.arguments_parameter('b', core_schema.int_schema(), mode='keyword_only'),
+            ]
+        )
+    )
+    assert v.validate_python(ArgsKwargs((1,), {'b': 2})) == ((1,), {'b': 2})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs((1,)))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'missing_keyword_only_argument',
+            'loc': ('b',),
+            'msg': 'Missing required keyword only argument',
+            'input': ArgsKwargs((1,)),
+        }
+    ]
+
+
+def test_positional_only_call_field_name():
+    def f(input_value, info):
+        return f'{info.field_name}={input_value}'
+
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter(
+                    'a', core_schema.with_info_plain_validator_function(f), mode='positional_only'
+                ),
+                core_schema.arguments_parameter(
+                    'b',
+                    core_schema.with_default_schema(
+                        core_schema.with_info_plain_validator_function(f), default=2, validate_default=True
+                    ),
+                ),
+            ]
+        )
+    )
+    assert v.validate_python(ArgsKwargs((1,))) == (('a=1',), {'b': 'b=2'})
+    assert v.validate_python(ArgsKwargs((1, 3))) == (('a=1', 'b=3'), {})
+
+
+def test_max_errors():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter('a', core_schema.int_schema()),
+                core_schema.arguments_parameter('b', core_schema.int_schema()),
+            ],
+            var_kwargs_schema=core_schema.int_schema(),
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs(('x', 'y', 'z', 'w')), max_errors=2)
+    assert exc_info.value.error_count() == 2
+    assert exc_info.value.truncated
+    assert [e['loc'] for e in exc_info.value.errors()] == [(0,), (1,)]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs((1, 2), {f'k{i}': 'x' for i in range(10)}), max_errors=3)
+    assert exc_info.value.error_count() == 3
+    assert exc_info.value.truncated
+    assert {e['type'] for e in exc_info.value.errors()} == {'int_parsing'}
+
+
+def test_fail_fast():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter('a', core_schema.int_schema()),
+                core_schema.arguments_parameter('b', core_schema.int_schema()),
+            ],
+            var_kwargs_schema=core_schema.int_schema(),
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs(('x', 'y', 'z', 'w')), fail_fast=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [(0,)]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs((1, 2), {f'k{i}': 'x' for i in range(10)}), fail_fast=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [('k0',)]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs(('x', 'y', 'z', 'w')))
+    assert exc_info.value.error_count() == 4
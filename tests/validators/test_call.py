@@ -2,6 +2,7 @@ import dataclasses
 import re
 from collections import namedtuple
 from functools import partial
+from typing import NamedTuple
 
 import pytest
 
@@ -173,6 +174,72 @@ def test_named_tuple():
     assert d.y == 2.2
 
 
+def test_named_tuple_defaults():
+    class Point(NamedTuple):
+        x: float
+        y: float = 0.0
+        label: str = 'origin'
+
+    v = SchemaValidator(
+        cs.call_schema(
+            function=Point,
+            arguments=cs.arguments_schema(
+                arguments=[
+                    cs.arguments_parameter('x', cs.float_schema()),
+                    cs.arguments_parameter('y', cs.with_default_schema(cs.float_schema(), default=1.0)),
+                ]
+            ),
+        )
+    )
+    # `label` isn't an argument, so its default comes from the namedtuple
+    assert v.validate_python(('1.5',)) == Point(1.5, 1.0, 'origin')
+    assert type(v.validate_python({'x': 1, 'y': 2})) is Point
+    assert v.validate_python({'x': 1, 'y': 2}) == Point(1.0, 2.0, 'origin')
+
+
+def test_named_tuple_bad_arguments():
+    Point = namedtuple('Point', ['x', 'y'])
+
+    v = SchemaValidator(
+        cs.call_schema(
+            function=Point,
+            arguments=cs.arguments_schema(
+                arguments=[cs.arguments_parameter('x', cs.int_schema())], var_kwargs_schema=cs.int_schema()
+            ),
+        )
+    )
+    assert v.validate_python({'x': 1, 'y': 2}) == Point(1, 2)
+    # arguments which don't match the fields raise the same errors as calling the namedtuple
+    with pytest.raises(TypeError, match="missing 1 required positional argument: 'y'"):
+        v.validate_python({'x': 1})
+    with pytest.raises(TypeError, match="got an unexpected keyword argument 'z'"):
+        v.validate_python({'x': 1, 'y': 2, 'z': 3})
+
+
+def test_named_tuple_subclass():
+    calls = []
+
+    class Point(namedtuple('Point', ['x', 'y'])):
+        def __new__(cls, x, y):
+            calls.append((x, y))
+            return super().__new__(cls, x, y)
+
+    class PlainPoint(namedtuple('PlainPoint', ['x', 'y'])):
+        def norm(self):
+            return (self.x**2 + self.y**2) ** 0.5
+
+    arguments = cs.arguments_schema(
+        arguments=[cs.arguments_parameter('x', cs.int_schema()), cs.arguments_parameter('y', cs.int_schema())]
+    )
+    # an overridden `__new__` is still called
+    assert SchemaValidator(cs.call_schema(arguments, Point)).validate_python(('3', 4)) == (3, 4)
+    assert calls == [(3, 4)]
+
+    point = SchemaValidator(cs.call_schema(arguments, PlainPoint)).validate_python(('3', 4))
+    assert type(point) is PlainPoint
+    assert point.norm() == 5
+
+
 def test_function_call_partial():
     def my_function(a, b, c):
         return a + b + c
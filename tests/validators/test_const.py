@@ -0,0 +1,121 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaJsonifier, SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'value,input_value,expected',
+    [
+        (2, 2, 2),
+        (2, 3, Err('Input should be the constant 2 [type=const_mismatch, input_value=3, input_type=int]')),
+        (2, '2', Err('Input should be the constant 2 [type=const_mismatch,')),
+        (1, 1.5, Err('Input should be the constant 1 [type=const_mismatch,')),
+        (1, True, Err('Input should be the constant 1 [type=const_mismatch,')),
+        (1, 1.0, Err('Input should be the constant 1 [type=const_mismatch,')),
+        (1.0, 1, Err('Input should be the constant 1.0 [type=const_mismatch,')),
+        (True, 1, Err('Input should be the constant True [type=const_mismatch,')),
+        (True, True, True),
+        (1.5, 1.5, 1.5),
+        ('v1', 'v1', 'v1'),
+        ('v1', 'v2', Err("Input should be the constant 'v1' [type=const_mismatch,")),
+        (None, None, None),
+    ],
+)
+def test_const(py_and_json: PyAndJson, value, input_value, expected):
+    v = py_and_json(core_schema.const_schema(value))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_const_error_details():
+    v = SchemaValidator(core_schema.const_schema('v1'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('v2')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'const_mismatch',
+            'loc': (),
+            'msg': "Input should be the constant 'v1'",
+            'input': 'v2',
+            'ctx': {'expected': "'v1'"},
+        }
+    ]
+
+
+def test_typed_dict_default():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'version': core_schema.typed_dict_field(core_schema.const_schema(2)),
+                'data': core_schema.typed_dict_field(core_schema.str_schema()),
+            }
+        )
+    )
+    assert v.validate_python({'data': 'x'}) == {'version': 2, 'data': 'x'}
+    assert v.validate_json('{"version": 2, "data": "x"}') == {'version': 2, 'data': 'x'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'version': 1, 'data': 'x'})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('const_mismatch', ('version',))]
+
+
+def test_model_fields_set():
+    class Envelope:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            Envelope,
+            core_schema.model_fields_schema(
+                {
+                    'version': core_schema.model_field(core_schema.const_schema(2)),
+                    'data': core_schema.model_field(core_schema.int_schema()),
+                }
+            ),
+        )
+    )
+    m = v.validate_python({'data': 1})
+    assert m.__dict__ == {'version': 2, 'data': 1}
+    # like other defaults, the constant doesn't count as a field that was set
+    assert m.__pydantic_fields_set__ == {'data'}
+
+
+def test_unhashable_value_copied():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema({'tags': core_schema.typed_dict_field(core_schema.const_schema(['a']))})
+    )
+    first = v.validate_python({})
+    first['tags'].append('b')
+    assert v.validate_python({}) == {'tags': ['a']}
+    assert v.validate_python({'tags': ['a']}) == {'tags': ['a']}
+
+
+def test_serialization():
+    schema = core_schema.typed_dict_schema(
+        {
+            'version': core_schema.typed_dict_field(core_schema.const_schema(2)),
+            'data': core_schema.typed_dict_field(core_schema.int_schema()),
+        }
+    )
+    s = SchemaSerializer(schema)
+    assert s.to_python({'version': 2, 'data': 1}) == {'version': 2, 'data': 1}
+    assert s.to_json({'version': 2, 'data': 1}) == b'{"version":2,"data":1}'
+    assert s.to_python({'version': 2, 'data': 1}, exclude_defaults=True) == {'data': 1}
+
+
+def test_json_schema_not_required():
+    schema = core_schema.typed_dict_schema(
+        {
+            'version': core_schema.typed_dict_field(core_schema.const_schema(2)),
+            'data': core_schema.typed_dict_field(core_schema.int_schema()),
+        }
+    )
+    json_schema = SchemaJsonifier(schema).json_schema()
+    assert json_schema['properties']['version'] == {'const': 2, 'type': 'integer', 'default': 2, 'title': 'Version'}
+    assert json_schema['required'] == ['data']
@@ -39,3 +39,48 @@ def test_custom_error_invalid():
                 core_schema.int_schema(), 'recursion_loop', custom_error_message='xxx'
             )
         )
+
+
+def test_error_messages(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.int_schema(gt=0, error_messages={'greater_than': 'must be positive, > {gt}'}))
+    assert v.validate_test(1) == 1
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(0)
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'greater_than', 'loc': (), 'msg': 'must be positive, > 0', 'input': 0, 'ctx': {'gt': 0}}
+    ]
+
+    # errors of other types keep their default message
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer, unable to parse string'):
+        v.validate_test('x')
+
+
+def test_error_messages_nested():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.str_schema(max_length=3, error_messages={'string_too_long': 'too long'}),
+            min_length=1,
+            error_messages={'too_short': 'at least one item is required', 'string_too_long': 'not used'},
+        )
+    )
+    assert v.validate_python(['abc']) == ['abc']
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([])
+    assert [(e['type'], e['msg']) for e in exc_info.value.errors()] == [
+        ('too_short', 'at least one item is required')
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['abcd', 1])
+    assert [(e['type'], e['loc'], e['msg']) for e in exc_info.value.errors()] == [
+        ('string_too_long', (0,), 'too long'),
+        ('string_type', (1,), 'Input should be a valid string'),
+    ]
+
+
+def test_error_messages_invalid_type():
+    with pytest.raises(SchemaError, match="Invalid error type in `error_messages`: 'not_an_error'"):
+        SchemaValidator(core_schema.int_schema(error_messages={'not_an_error': 'foobar'}))
@@ -189,6 +189,21 @@ def test_aliases(py_and_json: PyAndJson):
     )
 
 
+def test_alias_generator(py_and_json: PyAndJson):
+    schema = core_schema.dataclass_args_schema(
+        'MyDataclass',
+        [
+            core_schema.dataclass_field(name='a', schema=core_schema.str_schema()),
+            core_schema.dataclass_field(name='b', schema=core_schema.int_schema(), validation_alias='B'),
+        ],
+        alias_generator=lambda name: f'{name}_alias',
+    )
+    v = py_and_json(schema)
+    assert v.validate_test({'a_alias': 'x', 'B': 1}) == ({'a': 'x', 'b': 1}, None)
+    with pytest.raises(ValidationError, match=r'a_alias\n  Field required'):
+        v.validate_test({'a': 'x', 'B': 1})
+
+
 @dataclasses.dataclass
 class FooDataclass:
     a: str
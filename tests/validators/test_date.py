@@ -305,6 +305,30 @@ def test_date_past_future_today():
     assert v.isinstance_python(today + timedelta(days=1)) is True
 
 
+def test_date_clock():
+    clock = datetime(2020, 1, 1, 23, tzinfo=timezone.utc)
+    v = SchemaValidator(core_schema.date_schema(now_op='past', now_utc_offset=0), {'clock': clock})
+    assert v.isinstance_python(date(2019, 12, 31)) is True
+    assert v.isinstance_python(date(2020, 1, 1)) is False
+
+    # today is in the UTC offset of the clock
+    clock = datetime(2020, 1, 2, 1, tzinfo=timezone(timedelta(hours=2)))
+    v = SchemaValidator(core_schema.date_schema(now_op='future'), {'clock': clock})
+    assert v.validate_python('2020-01-03') == date(2020, 1, 3)
+    with pytest.raises(ValidationError, match=r'Date should be in the future \[type=date_future,'):
+        v.validate_python('2020-01-02')
+
+
+def test_date_reference_time():
+    v = SchemaValidator(core_schema.date_schema(now_op='past', now_utc_offset=0), {'clock_context_key': 'now'})
+    reference_time = datetime(2020, 1, 1, 23, tzinfo=timezone.utc)
+    assert v.validate_python(date(2019, 12, 31), reference_time=reference_time) == date(2019, 12, 31)
+    with pytest.raises(ValidationError, match=r'Date should be in the past \[type=date_past,'):
+        v.validate_python(date(2020, 1, 1), reference_time=reference_time)
+    assert v.isinstance_python(date(2019, 12, 31), context={'now': reference_time}) is True
+    assert v.isinstance_python(date(2020, 1, 1), context={'now': reference_time}) is False
+
+
 @pytest.mark.parametrize(
     'val_temporal_unit, input_value, expected',
     [
@@ -334,3 +358,72 @@ def test_val_temporal_unit_date(val_temporal_unit, input_value, expected):
     )
     output = v.validate_python(input_value)
     assert output == expected
+
+
+@pytest.mark.parametrize(
+    'format,input_value,expected',
+    [
+        ('%d/%m/%Y', '31/12/2024', date(2024, 12, 31)),
+        ('%d/%m/%Y', '1/2/2024', date(2024, 2, 1)),
+        ('%d/%m/%Y', '29/02/2024', date(2024, 2, 29)),
+        ('%d %B %Y', '4 july 1976', date(1976, 7, 4)),
+        ('%a %d %b %y', 'Mon 01 Jan 24', date(2024, 1, 1)),
+        ('%y-%m-%d', '69-01-01', date(1969, 1, 1)),
+        ('%Y%m%d', '20240101', date(2024, 1, 1)),
+        ('%d  %m %Y', '01 \t02   2024', date(2024, 2, 1)),
+        ('%d/%m/%Y', date(2024, 1, 1), date(2024, 1, 1)),
+        ('%d/%m/%Y', 1704067200, date(2024, 1, 1)),
+        (
+            '%d/%m/%Y',
+            '2024-12-31',
+            Err("Input should be a valid date in the format '%d/%m/%Y', expected '/' at position 2 [type=date_format"),
+        ),
+        ('%d/%m/%Y', '29/02/2023', Err("format '%d/%m/%Y', day value is outside expected range")),
+        ('%d/%m/%Y', '01/13/2024', Err("format '%d/%m/%Y', month value is outside expected range")),
+        ('%d/%m/%Y', '01/01/24', Err("format '%d/%m/%Y', expected a 4 digit year at position 6")),
+        ('%d/%m/%Y', '01/01/2024 ', Err("format '%d/%m/%Y', unexpected extra characters at position 10")),
+        ('%d %B %Y', '4 jul 1976', Err("format '%d %B %Y', expected a month name at position 2")),
+        ('%Y-%m-%d', '0000-01-01', Err("format '%Y-%m-%d', year 0 is out of range")),
+    ],
+)
+def test_date_format(format: str, input_value, expected):
+    v = SchemaValidator(cs.date_schema(format=format))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_date_format_json_strict():
+    v = SchemaValidator(cs.date_schema(format='%d/%m/%Y', strict=True, gt=date(2024, 1, 1)))
+    assert v.validate_json('"31/12/2024"') == date(2024, 12, 31)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"01/01/2024"')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'greater_than',
+            'loc': (),
+            'msg': 'Input should be greater than 2024-01-01',
+            'input': '01/01/2024',
+            'ctx': {'gt': '2024-01-01'},
+        }
+    ]
+    # like ISO 8601 strings, Python strings aren't accepted in strict mode
+    with pytest.raises(ValidationError, match=r'Input should be a valid date \[type=date_type'):
+        v.validate_python('31/12/2024')
+
+
+@pytest.mark.parametrize(
+    'format,message',
+    [
+        ('%d/%m', '`format` should contain a directive for the year'),
+        ('%d/%m/%Y %H', "`format` can't contain '%H' for a date"),
+        ('%d/%m/%Y/%y', '`format` has more than one directive for the year'),
+        ('%d/%m/%Y %', "`format` can't end with a single '%'"),
+        ('%d/%m/%Y %j', "Invalid directive '%j' in `format`, expected one of %Y, %y, %m, %b, %B, %d, %a, %A, %H"),
+    ],
+)
+def test_date_format_invalid(format: str, message: str):
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator(cs.date_schema(format=format))
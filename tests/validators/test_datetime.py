@@ -406,6 +406,107 @@ def test_datetime_future_timezone():
     assert not v.isinstance_python(past_utc.astimezone(zoneinfo.ZoneInfo('America/Los_Angeles')))
 
 
+def test_clock_fixed():
+    clock = datetime(2020, 1, 1, 12, tzinfo=timezone.utc)
+    v = SchemaValidator(core_schema.datetime_schema(now_op='past'), {'clock': clock})
+    assert v.isinstance_python(datetime(2020, 1, 1, 11, 59, tzinfo=timezone.utc))
+    assert not v.isinstance_python(datetime(2020, 1, 1, 12, 1, tzinfo=timezone.utc))
+    # naive inputs are compared in the UTC offset of the clock
+    assert v.isinstance_python(datetime(2020, 1, 1, 11, 59))
+    assert not v.isinstance_python(datetime(2020, 1, 1, 12, 1))
+    with pytest.raises(ValidationError, match=r'Input should be in the past \[type=datetime_past,'):
+        v.validate_json('"2020-01-01T13:00:00+00:00"')
+
+    # a timestamp
+    v = SchemaValidator(core_schema.datetime_schema(now_op='future', now_utc_offset=0), {'clock': clock.timestamp()})
+    assert v.isinstance_python(datetime(2020, 1, 1, 12, 0, 1))
+    assert not v.isinstance_python(datetime(2020, 1, 1, 11, 59, 59))
+
+
+def test_clock_offset():
+    clock = datetime(2020, 1, 1, 12, tzinfo=timezone(timedelta(hours=2)))
+    v = SchemaValidator(core_schema.datetime_schema(now_op='past'), {'clock': clock})
+    assert v.isinstance_python(datetime(2020, 1, 1, 11, 59))
+    assert not v.isinstance_python(datetime(2020, 1, 1, 12, 1))
+    assert v.isinstance_python(datetime(2020, 1, 1, 9, 59, tzinfo=timezone.utc))
+
+    # `now_utc_offset` takes precedence
+    v = SchemaValidator(core_schema.datetime_schema(now_op='past', now_utc_offset=0), {'clock': clock})
+    assert v.isinstance_python(datetime(2020, 1, 1, 9, 59))
+    assert not v.isinstance_python(datetime(2020, 1, 1, 11, 59))
+
+
+def test_clock_callable():
+    times = [datetime(2020, 1, 1, tzinfo=timezone.utc), datetime(2021, 1, 1, tzinfo=timezone.utc)]
+    v = SchemaValidator(core_schema.datetime_schema(now_op='past'), {'clock': times.pop})
+    value = datetime(2020, 6, 1, tzinfo=timezone.utc)
+    assert v.isinstance_python(value)
+    assert not v.isinstance_python(value)
+
+    v = SchemaValidator(core_schema.datetime_schema(now_op='past'), {'clock': lambda: 'now'})
+    with pytest.raises(TypeError, match='the clock should be a datetime or a timestamp, got str'):
+        v.validate_python(value)
+
+
+def test_clock_invalid():
+    msg = 'Invalid `clock` config: TypeError: the clock should be a datetime or a timestamp, got str'
+    with pytest.raises(SchemaError, match=msg):
+        SchemaValidator(core_schema.datetime_schema(now_op='past'), {'clock': 'now'})
+
+
+def test_clock_model_config():
+    class Model:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {'created': core_schema.model_field(core_schema.datetime_schema(now_op='past'))}
+            ),
+            config={'clock': datetime(2000, 1, 1, tzinfo=timezone.utc)},
+        )
+    )
+    with pytest.raises(ValidationError, match=r'Input should be in the past \[type=datetime_past,'):
+        v.validate_python({'created': '2010-01-01T00:00:00Z'})
+    assert v.validate_python({'created': '1990-01-01T00:00:00Z'}).created.year == 1990
+
+
+def test_reference_time():
+    clock = datetime(2000, 1, 1, tzinfo=timezone.utc)
+    v = SchemaValidator(core_schema.datetime_schema(now_op='past'), {'clock': clock})
+    value = datetime(2010, 1, 1, tzinfo=timezone.utc)
+    assert not v.isinstance_python(value)
+    reference_time = datetime(2020, 1, 1, tzinfo=timezone.utc)
+    assert v.validate_python(value, reference_time=reference_time) == value
+    assert v.validate_json('"2010-01-01T00:00:00Z"', reference_time=reference_time.timestamp()) == value
+    assert v.validate_strings('2010-01-01T00:00:00Z', reference_time=reference_time) == value
+    with pytest.raises(ValidationError, match=r'Input should be in the past \[type=datetime_past,'):
+        v.validate_python(value, reference_time=datetime(2005, 1, 1, tzinfo=timezone.utc))
+
+    with pytest.raises(TypeError, match='Invalid `reference_time`: the clock should be a datetime or a timestamp'):
+        v.validate_python(value, reference_time='now')
+
+
+def test_reference_time_context():
+    v = SchemaValidator(core_schema.datetime_schema(now_op='future'), {'clock_context_key': 'now'})
+    value = datetime(2010, 1, 1, tzinfo=timezone.utc)
+    assert v.validate_python(value, context={'now': datetime(2005, 1, 1, tzinfo=timezone.utc)}) == value
+    with pytest.raises(ValidationError, match=r'Input should be in the future \[type=datetime_future,'):
+        v.validate_python(value, context={'now': datetime(2015, 1, 1, tzinfo=timezone.utc)})
+    # the argument takes precedence over the context
+    reference_time = datetime(2005, 1, 1, tzinfo=timezone.utc)
+    context = {'now': datetime(2015, 1, 1, tzinfo=timezone.utc)}
+    assert v.validate_python(value, context=context, reference_time=reference_time) == value
+
+    # the system clock is used without the key
+    assert not v.isinstance_python(value, context={'other': 1})
+    assert not v.isinstance_python(value, context=None)
+
+    with pytest.raises(TypeError, match="Invalid time in the validation context at 'now': the clock should be"):
+        v.validate_python(value, context={'now': 'soon'})
+
+
 def test_mock_utc_offset_8_hours(mocker):
     """
     Test that mocking time.localtime() is working, note that due to caching in datetime_etc,
@@ -492,6 +593,73 @@ def test_neg_7200():
         v.validate_python('2022-06-08T12:13:14Z')
 
 
+def test_tz_zone_name():
+    paris = zoneinfo.ZoneInfo('Europe/Paris')
+    v = SchemaValidator(core_schema.datetime_schema(tz_constraint='Europe/Paris'))
+    # the offset depends on the time of year
+    assert v.validate_python('2022-01-08T12:13:14+01:00') == datetime(2022, 1, 8, 12, 13, 14, tzinfo=paris)
+    assert v.validate_python('2022-06-08T12:13:14+02:00') == datetime(2022, 6, 8, 12, 13, 14, tzinfo=paris)
+    value = datetime(2022, 6, 8, 12, 13, 14, tzinfo=paris)
+    assert v.validate_python(value) is value
+    # other zones are accepted when they have the same offset
+    assert v.validate_python(datetime(2022, 6, 8, 12, 13, 14, tzinfo=zoneinfo.ZoneInfo('Europe/Berlin')))
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('2022-06-08T12:13:14+01:00')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'timezone_name',
+            'loc': (),
+            'msg': "Timezone offset of 7200 required for 'Europe/Paris', got 3600",
+            'input': '2022-06-08T12:13:14+01:00',
+            'ctx': {'tz_name': 'Europe/Paris', 'tz_expected': 7200, 'tz_actual': 3600},
+        }
+    ]
+    with pytest.raises(ValidationError, match='Input should have timezone info'):
+        v.validate_python('2022-06-08T12:13:14')
+
+
+def test_tz_zone_name_dst_transition():
+    v = SchemaValidator(core_schema.datetime_schema(tz_constraint='Europe/Paris'))
+    # clocks went back from 03:00+02:00 to 02:00+01:00 on 2022-10-30, so 02:30 happened with both offsets
+    assert v.validate_python('2022-10-30T02:30:00+02:00')
+    assert v.validate_python('2022-10-30T02:30:00+01:00')
+    with pytest.raises(ValidationError, match="Timezone offset of 3600 required for 'Europe/Paris', got 7200"):
+        v.validate_python('2022-10-30T03:30:00+02:00')
+
+
+def test_zoneinfo_output():
+    paris = zoneinfo.ZoneInfo('Europe/Paris')
+    v = SchemaValidator(core_schema.datetime_schema(tz_constraint='Europe/Paris', zoneinfo=True))
+    output = v.validate_python('2022-03-26T12:00:00+01:00')
+    assert output == datetime(2022, 3, 26, 12, tzinfo=paris)
+    assert output.tzinfo is paris
+    # arithmetic uses the offset after the DST transition
+    assert (output + timedelta(days=1)).utcoffset() == timedelta(hours=2)
+
+    assert v.validate_json('"2022-06-08T12:13:14+02:00"').tzinfo is paris
+    output = v.validate_python(datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone(timedelta(hours=2))))
+    assert output == datetime(2022, 6, 8, 12, 13, 14, tzinfo=paris)
+    assert output.tzinfo is paris
+
+
+@pytest.mark.parametrize(
+    'kwargs,message',
+    [
+        (
+            {'tz_constraint': 'Mars/Olympus_Mons'},
+            "Invalid tz_constraint \"Mars/Olympus_Mons\", expected 'aware', 'naive', an offset or an IANA time zone "
+            'name: ZoneInfoNotFoundError:',
+        ),
+        ({'zoneinfo': True}, '`zoneinfo` requires `tz_constraint` to be an IANA time zone name'),
+        ({'tz_constraint': 'aware', 'zoneinfo': True}, '`zoneinfo` requires `tz_constraint` to be an IANA time zone'),
+    ],
+)
+def test_tz_zone_name_invalid(kwargs, message):
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator(core_schema.datetime_schema(**kwargs))
+
+
 def test_tz_constraint_too_high():
     with pytest.raises(SchemaError, match='OverflowError: Python int too large.*'):
         SchemaValidator(core_schema.datetime_schema(tz_constraint=2**64))
@@ -560,3 +728,98 @@ def test_val_temporal_unit_datetime(val_temporal_unit, input_value, expected):
     )
     output = v.validate_python(input_value)
     assert output == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('31/12/2024 23:59', datetime(2024, 12, 31, 23, 59)),
+        ('1/1/2024 0:00', datetime(2024, 1, 1)),
+        (
+            '2024-12-31T23:59',
+            Err(
+                "Input should be a valid datetime in the format '%d/%m/%Y %H:%M', expected '/' at position 2 "
+                '[type=datetime_format'
+            ),
+        ),
+        ('31/12/2024', Err("format '%d/%m/%Y %H:%M', expected whitespace at position 10")),
+        ('31/12/2024 23:60', Err("format '%d/%m/%Y %H:%M', minute value is outside expected range")),
+    ],
+)
+def test_datetime_format(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(cs.datetime_schema(format='%d/%m/%Y %H:%M'))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_datetime_format_constraints():
+    lt = datetime(2025, 1, 1, tzinfo=timezone.utc)
+    v = SchemaValidator(cs.datetime_schema(format='%Y-%m-%d %H:%M:%S %z', tz_constraint='aware', lt=lt))
+    assert v.validate_python('2024-06-01 12:00:00 -02:00') == datetime(
+        2024, 6, 1, 12, tzinfo=timezone(timedelta(hours=-2))
+    )
+    with pytest.raises(ValidationError, match=r'Input should be less than 2025-01-01T00:00:00Z \[type=less_than'):
+        v.validate_python('2025-01-01 00:00:00 Z')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('2024-06-01 12:00:00')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'datetime_format',
+            'loc': (),
+            'msg': (
+                "Input should be a valid datetime in the format '%Y-%m-%d %H:%M:%S %z', "
+                'expected whitespace at position 19'
+            ),
+            'input': '2024-06-01 12:00:00',
+            'ctx': {'format': '%Y-%m-%d %H:%M:%S %z', 'error': 'expected whitespace at position 19'},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('Sun, 06 Nov 1994 08:49:37 GMT', datetime(1994, 11, 6, 8, 49, 37, tzinfo=timezone.utc)),
+        (
+            'Tue, 1 Jul 2003 10:52:37 +0200',
+            datetime(2003, 7, 1, 10, 52, 37, tzinfo=timezone(timedelta(hours=2))),
+        ),
+        ('1 jul 2003 10:52 -0330', datetime(2003, 7, 1, 10, 52, tzinfo=timezone(timedelta(hours=-3, minutes=-30)))),
+        ('Fri, 21 Nov 97 09:55:06 EST', datetime(1997, 11, 21, 9, 55, 6, tzinfo=timezone(timedelta(hours=-5)))),
+        ('21 Nov 03 09:55:06 UT', datetime(2003, 11, 21, 9, 55, 6, tzinfo=timezone.utc)),
+        ('2024-01-01T00:00:00Z', datetime(2024, 1, 1, tzinfo=timezone.utc)),
+        ('2024-01-01', datetime(2024, 1, 1)),
+        ('Mon, 06 Nov 1994 08:49:37 GMT', Err('Input should be a valid datetime or date, invalid character in year')),
+        ('Sun, 06 Nov 1994 08:49:37', Err('Input should be a valid datetime or date, invalid character in year')),
+        ('Sun, 06 Nov 1994 08:49:37 GMT (comment)', Err('Input should be a valid datetime')),
+        ('31 Feb 2024 08:49:37 GMT', Err('Input should be a valid datetime')),
+    ],
+)
+def test_datetime_rfc2822(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(cs.datetime_schema(rfc2822=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_datetime_rfc2822_config():
+    http_date = 'Sun, 06 Nov 1994 08:49:37 GMT'
+    expected = datetime(1994, 11, 6, 8, 49, 37, tzinfo=timezone.utc)
+    v = SchemaValidator(cs.datetime_schema(), config={'datetime_rfc2822': True})
+    assert v.validate_python(http_date) == expected
+    # the schema setting takes precedence
+    v = SchemaValidator(cs.datetime_schema(rfc2822=False), config={'datetime_rfc2822': True})
+    with pytest.raises(ValidationError, match='type=datetime_from_date_parsing'):
+        v.validate_python(http_date)
+
+
+def test_datetime_rfc2822_strict():
+    v = SchemaValidator(cs.datetime_schema(rfc2822=True, strict=True))
+    with pytest.raises(ValidationError, match='Input should be a valid datetime'):
+        v.validate_json('"Sun, 06 Nov 1994 08:49:37 GMT"')
+    assert v.validate_json('"1994-11-06T08:49:37Z"') == datetime(1994, 11, 6, 8, 49, 37, tzinfo=timezone.utc)
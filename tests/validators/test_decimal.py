@@ -521,3 +521,157 @@ def test_union_with_str_prefers_str() -> None:
 
     assert s.validate_python('1.23') == '1.23'
     assert s.validate_python(1.23) == Decimal('1.23')
+
+
+@pytest.mark.parametrize(
+    'rounding,input_value,expected',
+    [
+        ('ROUND_HALF_EVEN', '1.005', Decimal('1.00')),
+        ('ROUND_HALF_EVEN', '1.015', Decimal('1.02')),
+        ('ROUND_HALF_UP', '1.005', Decimal('1.01')),
+        ('ROUND_DOWN', '1.009', Decimal('1.00')),
+        ('ROUND_UP', '1.001', Decimal('1.01')),
+        ('ROUND_FLOOR', '-1.001', Decimal('-1.01')),
+        ('ROUND_CEILING', '-1.009', Decimal('-1.00')),
+        # values with at most `decimal_places` aren't changed
+        ('ROUND_HALF_UP', '1.5', Decimal('1.5')),
+        ('ROUND_HALF_UP', '7', Decimal('7')),
+    ],
+)
+def test_decimal_rounding(py_and_json: PyAndJson, rounding, input_value, expected):
+    v = py_and_json(cs.decimal_schema(decimal_places=2, rounding=rounding))
+    output = v.validate_test(input_value)
+    assert output == expected
+    assert str(output) == str(expected)
+
+
+def test_decimal_rounding_max_digits() -> None:
+    # the rounded value is checked against `max_digits`
+    v = SchemaValidator(cs.decimal_schema(max_digits=3, decimal_places=2, rounding='ROUND_HALF_UP'))
+    assert v.validate_python('9.994') == Decimal('9.99')
+    with pytest.raises(ValidationError, match='should have no more than 1 digit before the decimal point'):
+        v.validate_python('9.995')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('1.5', '1.50'),
+        ('1.005', '1.00'),
+        ('1.015', '1.02'),
+        (3, '3.00'),
+        (Decimal('2.50000'), '2.50'),
+    ],
+)
+def test_decimal_quantize_to(input_value, expected):
+    v = SchemaValidator(cs.decimal_schema(quantize_to=Decimal('0.01')))
+    assert str(v.validate_python(input_value)) == expected
+
+
+def test_decimal_quantize_to_rounding():
+    v = SchemaValidator(cs.decimal_schema(quantize_to=Decimal('1'), rounding='ROUND_HALF_UP'))
+    assert str(v.validate_python('2.5')) == '3'
+    assert str(v.validate_json('2.49')) == '2'
+
+
+def test_decimal_quantize_to_inf_nan():
+    v = SchemaValidator(cs.decimal_schema(quantize_to=Decimal('0.01'), allow_inf_nan=True))
+    assert v.validate_python('Infinity') == Decimal('Infinity')
+
+
+def test_decimal_quantize_more_digits_than_context():
+    # rounding isn't limited by the precision of the current context
+    v = SchemaValidator(cs.decimal_schema(decimal_places=2, rounding='ROUND_HALF_UP'))
+    assert v.validate_python('123456789012345678901234567890.129') == Decimal('123456789012345678901234567890.13')
+
+    v = SchemaValidator(cs.decimal_schema(quantize_to=Decimal('0.01')))
+    assert str(v.validate_python('1e30')) == '1' + '0' * 30 + '.00'
+    assert str(v.validate_python('1' * 30)) == '1' * 30 + '.00'
+
+
+def test_decimal_quantize_too_many_digits():
+    v = SchemaValidator(cs.decimal_schema(quantize_to=Decimal('0.01')))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('1e999999999')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'decimal_max_digits',
+            'loc': (),
+            'msg': 'Decimal input should have no more than 4300 digits in total',
+            'input': '1e999999999',
+            'ctx': {'max_digits': 4300},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'kwargs,message',
+    [
+        ({'rounding': 'ROUND_HALF_UP'}, '`rounding` requires `decimal_places` or `quantize_to`'),
+        (
+            {'decimal_places': 2, 'rounding': 'HALF_UP'},
+            'Invalid rounding: `HALF_UP`, expected one of ROUND_HALF_EVEN, ROUND_HALF_UP, ROUND_HALF_DOWN, ',
+        ),
+        ({'quantize_to': Decimal('NaN')}, "'quantize_to' must be a finite Decimal"),
+        ({'quantize_to': 'x'}, "'quantize_to' must be coercible to a Decimal instance"),
+    ],
+)
+def test_decimal_rounding_invalid_schema(kwargs, message):
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator(cs.decimal_schema(**kwargs))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('1,000', Decimal('1000')),
+        ('-1,234,567.25', Decimal('-1234567.25')),
+        ('1,00.5', Err('Input should be a valid decimal [type=decimal_parsing,')),
+        ('1.000,5', Err('Input should be a valid decimal [type=decimal_parsing,')),
+    ],
+)
+def test_decimal_digit_separators(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(cs.decimal_schema(digit_separators=','))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_decimal_digit_separators_strict():
+    v = SchemaValidator(cs.decimal_schema(digit_separators=',', strict=True))
+    with pytest.raises(ValidationError, match=r'\[type=decimal_parsing,'):
+        v.validate_json('"1,000"')
+
+
+def test_decimal_digit_separators_places():
+    v = SchemaValidator(cs.decimal_schema(digit_separators=',', decimal_places=2))
+    assert v.validate_python('1,000.25') == Decimal('1000.25')
+    with pytest.raises(ValidationError, match=r'\[type=decimal_max_places,'):
+        v.validate_python('1,000.125')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('1500ms', Decimal('1.500')),
+        ('1.5m', Decimal('90.0')),
+        ('0.25 s', Decimal('0.25')),
+        ('5d', Err('Input should be a valid decimal [type=decimal_parsing,')),
+    ],
+)
+def test_decimal_unit(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(cs.decimal_schema(unit='s', unit_conversions={'ms': 0.001, 'm': Decimal(60)}))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_decimal_unit_places():
+    v = SchemaValidator(cs.decimal_schema(unit='s', unit_conversions={'ms': 0.001}, decimal_places=2))
+    assert v.validate_python('10ms') == Decimal('0.01')
+    with pytest.raises(ValidationError, match=r'\[type=decimal_max_places,'):
+        v.validate_python('1ms')
@@ -1,6 +1,6 @@
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import plain_repr
 
@@ -150,3 +150,112 @@ def test_forwards_get_default_value():
 
     assert default is not None
     assert default.value == 1
+
+
+def test_lazy_definitions():
+    schema = core_schema.definitions_schema(
+        core_schema.definition_reference_schema('Node'),
+        [
+            core_schema.typed_dict_schema(
+                {
+                    'value': core_schema.typed_dict_field(core_schema.definition_reference_schema('Value')),
+                    'children': core_schema.typed_dict_field(
+                        core_schema.list_schema(core_schema.definition_reference_schema('Node'))
+                    ),
+                },
+                ref='Node',
+            ),
+            core_schema.int_schema(ref='Value'),
+            # never used, so never built
+            {'type': 'int', 'ref': 'Unused', 'gt': 1, 'interval': '[0, 1]'},
+        ],
+    )
+    with pytest.raises(SchemaError, match='`interval` cannot be combined with `gt`'):
+        SchemaValidator(schema)
+
+    v = SchemaValidator(schema, config=core_schema.CoreConfig(lazy_definitions=True))
+    assert v.title == 'typed-dict'
+    data = {'value': '1', 'children': [{'value': 2, 'children': []}]}
+    assert v.validate_python(data) == {'value': 1, 'children': [{'value': 2, 'children': []}]}
+    assert v.validate_json('{"value": 3, "children": []}') == {'value': 3, 'children': []}
+
+
+def test_lazy_definitions_names():
+    schema = core_schema.definitions_schema(
+        core_schema.union_schema(
+            [
+                core_schema.list_schema(core_schema.definition_reference_schema('a')),
+                core_schema.definition_reference_schema('b'),
+            ]
+        ),
+        [
+            core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.int_schema())}, ref='a'),
+            core_schema.nullable_schema(core_schema.definition_reference_schema('a'), ref='b'),
+        ],
+    )
+    eager = SchemaValidator(schema)
+    lazy = SchemaValidator(schema, config=core_schema.CoreConfig(lazy_definitions=True))
+    assert eager.title == 'union[list[typed-dict],nullable[typed-dict]]'
+    assert lazy.title == eager.title
+
+    for input_value in ([{'x': 'a'}], {'x': 'a'}, 'x'):
+        with pytest.raises(ValidationError) as eager_exc_info:
+            eager.validate_python(input_value)
+        with pytest.raises(ValidationError) as lazy_exc_info:
+            lazy.validate_python(input_value)
+        assert lazy_exc_info.value.title == eager_exc_info.value.title
+        assert lazy_exc_info.value.errors(include_url=False) == eager_exc_info.value.errors(include_url=False)
+
+
+def test_lazy_definitions_title_before_use():
+    # the list's item definition isn't used when the input isn't a list, it's still named in the title
+    schema = core_schema.definitions_schema(
+        core_schema.list_schema(core_schema.definition_reference_schema('a')),
+        [core_schema.nullable_schema(core_schema.int_schema(), ref='a')],
+    )
+    v = SchemaValidator(schema, config=core_schema.CoreConfig(lazy_definitions=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    assert exc_info.value.title == 'list[nullable[int]]'
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'list_type', 'loc': (), 'msg': 'Input should be a valid list', 'input': 'x'}
+    ]
+
+
+def test_lazy_definitions_error_on_first_use():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.union_schema(
+                [core_schema.int_schema(), core_schema.definition_reference_schema('invalid')], mode='left_to_right'
+            ),
+            [{'type': 'int', 'ref': 'invalid', 'gt': 1, 'interval': '[0, 1]'}],
+        ),
+        config=core_schema.CoreConfig(lazy_definitions=True),
+    )
+    assert v.validate_python(1) == 1
+    for _ in range(2):
+        with pytest.raises(SchemaError, match='`interval` cannot be combined with `gt`'):
+            v.validate_python('x')
+
+
+def test_lazy_definitions_nested():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('outer'),
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'items': core_schema.typed_dict_field(
+                            core_schema.definitions_schema(
+                                core_schema.list_schema(core_schema.definition_reference_schema('inner')),
+                                [core_schema.int_schema(ref='inner')],
+                            )
+                        )
+                    },
+                    ref='outer',
+                )
+            ],
+        ),
+        config=core_schema.CoreConfig(lazy_definitions=True),
+    )
+    assert v.validate_python({'items': ['1', 2]}) == {'items': [1, 2]}
@@ -1,13 +1,13 @@
 import re
 import sys
-from collections import OrderedDict
+from collections import Counter, OrderedDict, defaultdict
 from collections.abc import Mapping
 from typing import Any
 
 import pytest
 from dirty_equals import HasRepr, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 from pydantic_core import core_schema as cs
 
 from ..conftest import Err, PyAndJson
@@ -340,3 +340,150 @@ def test_ordered_dict_key_order_preservation(strict):
     result2 = v.validate_python(foo2, strict=strict)
     assert list(result2.keys()) == list(foo2.keys()) == ['y', 'z', 'x']
     assert result2 == {'y': 2, 'z': 3, 'x': 1}
+
+
+def test_parallel():
+    v = SchemaValidator(cs.dict_schema(cs.int_schema(), cs.str_schema(), parallel=True, max_length=9_999))
+    assert v.validate_python({str(i): str(i) for i in range(5_000)}) == {i: str(i) for i in range(5_000)}
+    input_value = {i: str(i) for i in range(5_000)}
+    input_value['x'] = 1
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_parsing', ('x', '[key]')),
+        ('string_type', ('x',)),
+    ]
+    input_value[0] = 0
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value, fail_fast=True)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('string_type', (0,))]
+
+
+def test_from_items():
+    v = SchemaValidator(cs.dict_schema(cs.int_schema(), cs.str_schema(), from_items=True))
+
+    def rows():
+        for i in range(3):
+            yield str(i), f'row {i}'
+
+    assert v.validate_python(rows()) == {0: 'row 0', 1: 'row 1', 2: 'row 2'}
+    assert v.validate_python([('1', 'a'), (2, 'b')]) == {1: 'a', 2: 'b'}
+    assert v.validate_python({'1': 'a'}.items()) == {1: 'a'}
+    assert v.validate_python({'1': 'a'}) == {1: 'a'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(iter([('x', 'a'), ('2', 3)]))
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_parsing', ('x', '[key]')),
+        ('string_type', ('2',)),
+    ]
+
+    # strings aren't iterables of pairs
+    with pytest.raises(ValidationError, match=r'Input should be a valid dictionary \[type=dict_type'):
+        v.validate_python('ab')
+
+    v = SchemaValidator(cs.dict_schema(cs.int_schema(), cs.str_schema()))
+    with pytest.raises(ValidationError, match=r'Input should be a valid dictionary \[type=dict_type'):
+        v.validate_python([('1', 'a')])
+
+
+def test_from_items_fail_fast():
+    v = SchemaValidator(cs.dict_schema(cs.int_schema(), cs.str_schema(), from_items=True, fail_fast=True))
+    consumed = []
+
+    def rows():
+        for i in ['x', '1', '2']:
+            consumed.append(i)
+            yield i, 'a'
+
+    with pytest.raises(ValidationError, match=r'x\.\[key\]\n +Input should be a valid integer'):
+        v.validate_python(rows())
+    # the rest of the items aren't consumed once validation has failed
+    assert consumed == ['x', '1']
+
+
+@pytest.mark.parametrize(
+    'items,error',
+    [
+        ([('1', 'a'), (1,)], 'Input should be a valid mapping, error: Items must be tuples of (key, value) pairs'),
+        ([('1', 'a'), 'ab'], 'Input should be a valid mapping, error: Items must be tuples of (key, value) pairs'),
+    ],
+)
+def test_from_items_error(items, error):
+    v = SchemaValidator(cs.dict_schema(cs.int_schema(), cs.str_schema(), from_items=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(iter(items))
+    assert [(e['type'], e['loc'], e['msg']) for e in exc_info.value.errors()] == [('mapping_type', (1,), error)]
+
+
+def test_from_items_iteration_error():
+    def rows():
+        yield '1', 'a'
+        raise RuntimeError('cursor closed')
+
+    v = SchemaValidator(cs.dict_schema(cs.int_schema(), cs.str_schema(), from_items=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(rows())
+    assert [(e['type'], e['loc'], e['msg']) for e in exc_info.value.errors()] == [
+        ('iteration_error', (1,), 'Error iterating over object, error: RuntimeError: cursor closed')
+    ]
+
+
+@pytest.mark.parametrize('dict_type,expected_type', [('ordered_dict', OrderedDict), ('counter', Counter)])
+def test_dict_type(dict_type, expected_type):
+    v = SchemaValidator(cs.dict_schema(cs.str_schema(), cs.int_schema(), dict_type=dict_type))
+    for output in (v.validate_python({'a': '1', 'b': 2}), v.validate_json('{"a": "1", "b": 2}')):
+        assert type(output) is expected_type
+        assert output == {'a': 1, 'b': 2}
+        assert list(output) == ['a', 'b']
+
+    output = v.validate_python(Counter({'x': 3}))
+    assert type(output) is expected_type
+    assert output == {'x': 3}
+
+
+def test_dict_type_counter():
+    v = SchemaValidator(cs.dict_schema(cs.str_schema(), cs.int_schema(), dict_type='counter'))
+    output = v.validate_python({'a': 1, 'b': 3})
+    assert output['missing'] == 0
+    assert output.most_common(1) == [('b', 3)]
+
+
+def test_dict_type_default_dict():
+    schema = cs.dict_schema(cs.str_schema(), cs.int_schema(), dict_type='default_dict', default_factory=list)
+    v = SchemaValidator(schema)
+    for output in (v.validate_python({'a': '1'}), v.validate_json('{"a": "1"}')):
+        assert type(output) is defaultdict
+        assert output.default_factory is list
+        assert output == {'a': 1}
+        assert output['missing'] == []
+
+    # each output has its own default_factory defaults
+    first, second = v.validate_python({}), v.validate_python({})
+    first['x'].append(1)
+    assert second['x'] == []
+
+
+def test_dict_type_errors():
+    v = SchemaValidator(cs.dict_schema(cs.str_schema(), cs.int_schema(), dict_type='counter'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', ('a',))]
+
+
+@pytest.mark.parametrize(
+    'kwargs,error',
+    [
+        (
+            {'dict_type': 'set'},
+            "Invalid dict_type: `set`, expected 'dict', 'ordered_dict', 'counter' or 'default_dict'",
+        ),
+        ({'dict_type': 'default_dict'}, "`dict_type='default_dict'` requires `default_factory`"),
+        ({'dict_type': 'default_dict', 'default_factory': 1}, '`default_factory` must be callable'),
+        ({'default_factory': list}, "`default_factory` requires `dict_type='default_dict'`"),
+        ({'dict_type': 'counter', 'default_factory': list}, "`default_factory` requires `dict_type='default_dict'`"),
+    ],
+)
+def test_dict_type_schema_errors(kwargs, error):
+    with pytest.raises(SchemaError, match=re.escape(error)):
+        SchemaValidator(cs.dict_schema(cs.str_schema(), cs.int_schema(), **kwargs))
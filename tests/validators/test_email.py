@@ -0,0 +1,147 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('foo@example.com', 'foo@example.com'),
+        ('Foo.Bar+tag@Example.COM', 'Foo.Bar+tag@example.com'),
+        ("!#$%&'*+-/=?^_`{|}~@example.com", "!#$%&'*+-/=?^_`{|}~@example.com"),
+        ('Samuel Colvin <s@example.com>', 's@example.com'),
+        ('"Colvin, Samuel" <s@example.com> ', 's@example.com'),
+        ('<s@example.com>', 's@example.com'),
+        ('用户@例子.广告', '用户@例子.广告'),
+        ('user@xn--fsqu00a.xn--4rr70v', 'user@例子.广告'),
+        ('user@Bücher.example', 'user@bücher.example'),
+        ('foo', Err('Input should be a valid email address, an email address must have an @-sign')),
+        ('@example.com', Err('Input should be a valid email address, there must be something before the @-sign')),
+        ('foo@', Err('Input should be a valid email address, there must be something after the @-sign')),
+        ('foo bar@example.com', Err('the part before the @-sign contains invalid characters')),
+        ('"foo"@example.com', Err('the part before the @-sign contains invalid characters')),
+        ('a@b@example.com', Err('the part before the @-sign contains invalid characters')),
+        ('.foo@example.com', Err("the part before the @-sign can't start or end with a period")),
+        ('foo..bar@example.com', Err("the part before the @-sign can't start or end with a period")),
+        ('a' * 65 + '@example.com', Err('the part before the @-sign is too long')),
+        ('foo@localhost', Err('the part after the @-sign must contain a period')),
+        ('foo@example..com', Err('the part after the @-sign is not a valid domain')),
+        ('foo@-example.com', Err('the part after the @-sign is not a valid domain')),
+        ('foo@exa_mple.com', Err('the part after the @-sign is not a valid domain')),
+        ('foo@127.0.0.1', Err('the part after the @-sign is not a valid domain')),
+        ('foo@[127.0.0.1]', Err('the part after the @-sign is not a valid domain')),
+        ('foo@' + 'a' * 64 + '.com', Err('the part after the @-sign is not a valid domain')),
+        ('a' * 64 + '@' + ('b' * 63 + '.') * 3 + 'com', Err('the email address is too long')),
+        ('x' * 2049, Err('Input should be a valid email address, the input is too long')),
+        ('Foo s@example.com>', Err("the display name has an unmatched '>'")),
+        (1, Err('Input should be a valid string [type=string_type,')),
+    ],
+)
+def test_email(input_value, expected):
+    v = SchemaValidator(core_schema.email_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_email_error_details():
+    v = SchemaValidator(core_schema.email_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('foo')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'email_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid email address, an email address must have an @-sign',
+            'input': 'foo',
+            'ctx': {'error': 'an email address must have an @-sign'},
+        }
+    ]
+
+
+def test_email_json(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.email_schema())
+    assert v.validate_test('Foo@EXAMPLE.com') == 'Foo@example.com'
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_test(123)
+
+
+def test_max_length():
+    v = SchemaValidator(core_schema.email_schema(max_length=15))
+    assert v.validate_python('Name <foo@example.com>') == 'foo@example.com'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('fooo@example.com')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'email_too_long',
+            'loc': (),
+            'msg': 'Email address should have at most 15 characters',
+            'input': 'fooo@example.com',
+            'ctx': {'max_length': 15},
+        }
+    ]
+
+
+def test_allowed_domains():
+    v = SchemaValidator(core_schema.email_schema(allowed_domains=['example.com', 'Bücher.example']))
+    assert v.validate_python('foo@EXAMPLE.com') == 'foo@example.com'
+    assert v.validate_python('foo@xn--bcher-kva.example') == 'foo@bücher.example'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('foo@sub.example.com')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'email_domain',
+            'loc': (),
+            'msg': "Email address domain should be 'example.com' or 'Bücher.example'",
+            'input': 'foo@sub.example.com',
+            'ctx': {'expected_domains': "'example.com' or 'Bücher.example'"},
+        }
+    ]
+
+
+def test_allowed_domains_invalid():
+    with pytest.raises(SchemaError, match='`allowed_domains` should have length > 0'):
+        SchemaValidator(core_schema.email_schema(allowed_domains=[]))
+    with pytest.raises(SchemaError, match="Invalid domain in `allowed_domains`: 'exa_mple.com'"):
+        SchemaValidator(core_schema.email_schema(allowed_domains=['exa_mple.com']))
+
+
+def test_strict():
+    assert SchemaValidator(core_schema.email_schema()).validate_python(b'foo@example.com') == 'foo@example.com'
+    v = SchemaValidator(core_schema.email_schema(strict=True))
+    assert v.validate_python('foo@example.com') == 'foo@example.com'
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_python(b'foo@example.com')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('Samuel Colvin <S@Example.com>', ('Samuel Colvin', 'S@example.com')),
+        ('"Colvin, Samuel" <s@example.com>', ('Colvin, Samuel', 's@example.com')),
+        ('s.colvin@example.com', ('s.colvin', 's.colvin@example.com')),
+        ('<s@example.com>', ('s', 's@example.com')),
+        ('Samuel <bad>', Err('Input should be a valid email address, an email address must have an @-sign')),
+        ('Sam<uel <s@example.com>', Err('the display name contains invalid characters')),
+    ],
+)
+def test_name_email(input_value, expected):
+    v = SchemaValidator(core_schema.name_email_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_name_email_allowed_domains():
+    v = SchemaValidator(core_schema.name_email_schema(allowed_domains=['example.com']))
+    assert repr(v).count('name-email[\'example.com\']')
+    with pytest.raises(ValidationError, match=re.escape("Email address domain should be 'example.com'")):
+        v.validate_python('Foo <foo@example.org>')
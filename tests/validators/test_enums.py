@@ -504,3 +504,53 @@ def test_strict_enum_wrap_json() -> None:
 
     assert v.validate_python(Animal.CAT) == Animal.CAT
     assert v.validate_json('"dog"') == Animal.DOG
+
+
+def test_accept_names():
+    class Color(Enum):
+        RED = 1
+        GREEN = 2
+
+    v = SchemaValidator(core_schema.enum_schema(Color, list(Color.__members__.values()), accept_names=True))
+    assert v.validate_python(1) is Color.RED
+    assert v.validate_python('GREEN') is Color.GREEN
+    assert v.validate_json('"RED"') is Color.RED
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('red')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'enum',
+            'loc': (),
+            'msg': "Input should be 1, 2, 'RED' or 'GREEN'",
+            'input': 'red',
+            'ctx': {'expected': "1, 2, 'RED' or 'GREEN'"},
+        }
+    ]
+
+    v = SchemaValidator(core_schema.enum_schema(Color, list(Color.__members__.values())))
+    with pytest.raises(ValidationError, match=r'Input should be 1 or 2 \[type=enum'):
+        v.validate_python('RED')
+
+    v = SchemaValidator(
+        core_schema.enum_schema(Color, list(Color.__members__.values()), accept_names=True, strict=True)
+    )
+    with pytest.raises(ValidationError):
+        v.validate_json('"RED"')
+
+
+def test_accept_names_str_enum():
+    class Size(str, Enum):
+        SMALL = 'S'
+        LARGE = 'L'
+        # a name which is also a value is the member with that value
+        L = 'XL'
+
+    v = SchemaValidator(
+        core_schema.enum_schema(Size, list(Size.__members__.values()), sub_type='str', accept_names=True)
+    )
+    assert v.validate_python('SMALL') is Size.SMALL
+    assert v.validate_python('L') is Size.LARGE
+    assert v.validate_python('XL') is Size.L
+    assert v.validate_json('"LARGE"') is Size.LARGE
+    with pytest.raises(ValidationError, match=r"Input should be 'S', 'L', 'XL', 'SMALL' or 'LARGE' \[type=enum"):
+        v.validate_python('M')
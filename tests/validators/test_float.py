@@ -2,12 +2,13 @@ import math
 import re
 import sys
 from decimal import Decimal
+from fractions import Fraction
 from typing import Any
 
 import pytest
 from dirty_equals import FunctionCheck, IsFloatNan, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 from pydantic_core import core_schema as cs
 
 from ..conftest import Err, PyAndJson, plain_repr
@@ -208,12 +209,12 @@ def test_float_repr():
     v = SchemaValidator(cs.float_schema())
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:false,allow_inf_nan:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:false,allow_inf_nan:true,exact_numeric_conversion:false,digit_separators:None,units:None}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator(cs.float_schema(strict=True))
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:true,exact_numeric_conversion:false,digit_separators:None,units:None}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator(cs.float_schema(multiple_of=7))
     assert plain_repr(v).startswith('SchemaValidator(title="constrained-float",validator=ConstrainedFloat(')
@@ -437,3 +438,207 @@ def test_allow_inf_nan_false_json() -> None:
     assert exc_info3.value.errors(include_url=False) == [
         {'type': 'finite_number', 'loc': (), 'msg': 'Input should be a finite number', 'input': float('-inf')}
     ]
+
+
+@pytest.mark.parametrize(
+    'interval,input_value,expected',
+    [
+        ('[0, 1)', 0, 0),
+        ('[0, 1)', '0.5', 0.5),
+        ('[0, 1)', 1.0, Err('Input should be in the interval [0, 1) [type=out_of_range,')),
+        ('[0, 1)', -0.1, Err('Input should be in the interval [0, 1) [type=out_of_range,')),
+        ('(-0.5, 0.5]', 0.5, 0.5),
+        ('(-0.5, 0.5]', -0.5, Err('Input should be in the interval (-0.5, 0.5] [type=out_of_range,')),
+        ('(-inf, 0]', -1e300, -1e300),
+        ('[0, inf)', 1e300, 1e300),
+        ('[0, inf)', -1e-9, Err('Input should be in the interval [0, inf) [type=out_of_range,')),
+    ],
+)
+def test_float_interval(py_and_json: PyAndJson, interval: str, input_value, expected):
+    v = py_and_json(cs.float_schema(interval=interval))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_test(input_value)
+        assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'interval': interval}
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+@pytest.mark.parametrize('interval', ['[1, 0.5]', '(0.5, 0.5]', '[0.5, 0.5)'])
+def test_float_interval_empty(interval: str):
+    with pytest.raises(SchemaError, match=re.escape(f'Empty interval `{interval}`')):
+        SchemaValidator(cs.float_schema(interval=interval))
+
+
+@pytest.mark.parametrize('interval', ['[nan, 1]', '[0, nan)', '(nan, inf)', '[-inf, nan]'])
+def test_float_interval_nan(interval: str):
+    with pytest.raises(SchemaError, match=re.escape(f"Invalid interval `{interval}`, bounds can't be NaN")):
+        SchemaValidator(cs.float_schema(interval=interval))
+
+
+@pytest.mark.parametrize('key', ['le', 'lt', 'ge', 'gt'])
+def test_float_bound_nan(key: str):
+    with pytest.raises(SchemaError, match=f"`{key}` can't be NaN"):
+        SchemaValidator(cs.float_schema(**{key: float('nan')}))
+
+
+def test_float_interval_multiple_of():
+    v = SchemaValidator(cs.float_schema(interval='[0, 10]', multiple_of=0.5))
+    assert v.validate_python(2.5) == 2.5
+    with pytest.raises(ValidationError, match=r'Input should be a multiple of 0.5 \[type=multiple_of,'):
+        v.validate_python(2.2)
+    with pytest.raises(ValidationError, match=r'Input should be in the interval \[0, 10\] \[type=out_of_range,'):
+        v.validate_python(10.5)
+
+
+def test_float_interval_invalid():
+    with pytest.raises(SchemaError, match=re.escape('Invalid interval `[0, 1`')):
+        SchemaValidator(cs.float_schema(interval='[0, 1'))
+    with pytest.raises(SchemaError, match='`interval` cannot be combined with `ge`'):
+        SchemaValidator(cs.float_schema(interval='[0, 1)', ge=0))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (Decimal('1.5'), 1.5),
+        (Decimal('1'), 1.0),
+        (Decimal('-0.25'), -0.25),
+        (Decimal('Infinity'), math.inf),
+        (Fraction(3, 4), 0.75),
+        (Fraction(2**60, 1), float(2**60)),
+        (Decimal('0.1'), Err('Input should be a number exactly representable as a float [type=float_inexact')),
+        (Fraction(1, 3), Err('Input should be a number exactly representable as a float [type=float_inexact')),
+        (Decimal('1e400'), Err('Input should be a number exactly representable as a float [type=float_inexact')),
+        (Decimal('sNaN'), Err('Input should be a valid number [type=float_type')),
+    ],
+)
+@pytest.mark.parametrize('constrained', [False, True])
+def test_exact_numeric_conversion(input_value, expected, constrained):
+    schema = cs.float_schema(ge=-10.0) if constrained else cs.float_schema()
+    v = SchemaValidator(schema, config=cs.CoreConfig(exact_numeric_conversion=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_exact_numeric_conversion_strict():
+    v = SchemaValidator(cs.float_schema(strict=True), config=cs.CoreConfig(exact_numeric_conversion=True))
+    with pytest.raises(ValidationError, match=r'Input should be a valid number \[type=float_type'):
+        v.validate_python(Decimal('1.5'))
+    with pytest.raises(ValidationError, match=r'Input should be a valid number \[type=float_type'):
+        v.validate_python(Fraction(1, 2))
+    assert v.validate_python(1.5) == 1.5
+
+
+def test_inexact_numeric_conversion_default():
+    # without `exact_numeric_conversion`, decimals and fractions are rounded to the nearest float, even in strict mode
+    v = SchemaValidator(cs.float_schema(strict=True))
+    assert v.validate_python(Decimal('0.1')) == 0.1
+    assert v.validate_python(Fraction(1, 3)) == 1 / 3
+
+
+def test_exact_numeric_conversion_nan():
+    v = SchemaValidator(cs.float_schema(), config=cs.CoreConfig(exact_numeric_conversion=True))
+    assert math.isnan(v.validate_python(Decimal('NaN')))
+
+
+def test_exact_numeric_conversion_inf_nan():
+    v = SchemaValidator(cs.float_schema(allow_inf_nan=False), config=cs.CoreConfig(exact_numeric_conversion=True))
+    with pytest.raises(ValidationError, match=r'Input should be a finite number \[type=finite_number'):
+        v.validate_python(Decimal('Infinity'))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('1,000', 1000.0),
+        ('-1,234,567.25', -1234567.25),
+        ('1,000e3', 1e6),
+        ('1,00.5', Err('Input should be a valid number, unable to parse string as a number [type=float_parsing,')),
+        ('1.000,5', Err('Input should be a valid number, unable to parse string as a number [type=float_parsing,')),
+    ],
+)
+def test_float_digit_separators(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(cs.float_schema(digit_separators=','))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_float_digit_separators_constrained():
+    v = SchemaValidator(cs.float_schema(digit_separators=',', le=1000))
+    assert v.validate_python('1,000.0') == 1000.0
+    with pytest.raises(ValidationError, match=r'Input should be less than or equal to 1000 \[type=less_than_equal,'):
+        v.validate_python('1,000.5')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('2.5km', 2500.0),
+        ('10 cm', 0.1),
+        ('3m', 3.0),
+        ('1e3mm', 1.0),
+        ('3', 3.0),
+        ('3 miles', Err('Input should be a valid number, unable to parse string as a number [type=float_parsing,')),
+    ],
+)
+def test_float_unit(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(cs.float_schema(unit='m', unit_conversions={'km': 1000, 'cm': 0.01, 'mm': 0.001}))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == pytest.approx(expected)
+
+
+def test_float_unit_constrained():
+    v = SchemaValidator(cs.float_schema(unit='m', unit_conversions={'km': 1000}, lt=1000))
+    assert v.validate_python('0.5km') == 500.0
+    with pytest.raises(ValidationError, match=r'Input should be less than 1000 \[type=less_than,'):
+        v.validate_python('1km')
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,valid',
+    [
+        ({}, 0.30000000000000004, True),
+        ({}, 123456789.1, False),
+        ({'multiple_of_rel_tolerance': 1e-12}, 123456789.1, True),
+        ({'multiple_of_rel_tolerance': 1e-12}, 123456789.15, False),
+        ({'multiple_of_tolerance': 0.01}, 0.305, True),
+        ({'multiple_of_tolerance': 0.01}, 0.32, False),
+        ({'multiple_of_tolerance': 0}, 0.5, True),
+    ],
+)
+def test_float_multiple_of_tolerance(kwargs, input_value, valid):
+    v = SchemaValidator(cs.float_schema(multiple_of=0.1, **kwargs))
+    if valid:
+        assert v.validate_python(input_value) == input_value
+    else:
+        with pytest.raises(ValidationError, match=r'Input should be a multiple of 0.1 \[type=multiple_of,'):
+            v.validate_python(input_value)
+
+
+@pytest.mark.parametrize(
+    'kwargs,message',
+    [
+        ({'multiple_of': 0.1, 'multiple_of_tolerance': -1}, "'multiple_of_tolerance' must be a non-negative number"),
+        (
+            {'multiple_of': 0.1, 'multiple_of_rel_tolerance': float('nan')},
+            "'multiple_of_rel_tolerance' must be a non-negative number",
+        ),
+        (
+            {'multiple_of_tolerance': 0.1},
+            '`multiple_of_tolerance` and `multiple_of_rel_tolerance` require `multiple_of`',
+        ),
+    ],
+)
+def test_float_multiple_of_tolerance_invalid(kwargs, message):
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator(cs.float_schema(**kwargs))
@@ -251,7 +251,7 @@ def test_repr():
         'validator=FrozenSet(FrozenSetValidator{'
         'strict:true,item_validator:Any(AnyValidator),min_length:Some(42),max_length:None,'
         'name:"frozenset[any]",'
-        'fail_fast:false'
+        'fail_fast:false,parallel:false,lax_sequence_types:LaxSequenceTypes(None)'
         '}),'
         'definitions=[],'
         'cache_strings=True)'
@@ -8,7 +8,15 @@ from typing import Any
 import pytest
 from dirty_equals import HasRepr
 
-from pydantic_core import CoreConfig, SchemaValidator, ValidationError, core_schema
+from pydantic_core import (
+    CoreConfig,
+    PydanticCustomError,
+    PydanticKnownError,
+    PydanticMultipleErrors,
+    SchemaValidator,
+    ValidationError,
+    core_schema,
+)
 from pydantic_core import core_schema as cs
 
 from ..conftest import plain_repr
@@ -1047,3 +1055,191 @@ def test_field_name_preserved_wrap_validator() -> None:
     v.validate_python({'f': 1})
 
     assert field_names == [('val1', 'f'), ('val2', 'f')]
+
+
+def test_multiple_errors() -> None:
+    def f(input_value: dict[str, Any]) -> dict[str, Any]:
+        raise PydanticMultipleErrors(
+            [
+                ('a', ValueError('a is wrong')),
+                (('b', 0), PydanticKnownError('greater_than', {'gt': 10})),
+                ((), PydanticCustomError('my_error', 'whole input is {thing}', {'thing': 'bad'})),
+                (['c'], AssertionError('c is wrong')),
+            ]
+        )
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'outer': core_schema.typed_dict_field(
+                    core_schema.no_info_after_validator_function(f, core_schema.dict_schema())
+                )
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'outer': {'a': 1}})
+
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'value_error',
+            'loc': ('outer', 'a'),
+            'msg': 'Value error, a is wrong',
+            'input': {'a': 1},
+            'ctx': {'error': HasRepr(repr(ValueError('a is wrong')))},
+        },
+        {
+            'type': 'greater_than',
+            'loc': ('outer', 'b', 0),
+            'msg': 'Input should be greater than 10',
+            'input': {'a': 1},
+            'ctx': {'gt': 10},
+        },
+        {
+            'type': 'my_error',
+            'loc': ('outer',),
+            'msg': 'whole input is bad',
+            'input': {'a': 1},
+            'ctx': {'thing': 'bad'},
+        },
+        {
+            'type': 'assertion_error',
+            'loc': ('outer', 'c'),
+            'msg': 'Assertion failed, c is wrong',
+            'input': {'a': 1},
+            'ctx': {'error': HasRepr(repr(AssertionError('c is wrong')))},
+        },
+    ]
+
+
+def test_multiple_errors_nested() -> None:
+    def f(input_value: Any) -> Any:
+        raise PydanticMultipleErrors([('a', PydanticMultipleErrors([('b', ValueError('x')), ('c', ValueError('y'))]))])
+
+    v = SchemaValidator(core_schema.no_info_plain_validator_function(f))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1)
+    assert [e['loc'] for e in exc_info.value.errors()] == [('a', 'b'), ('a', 'c')]
+
+
+def test_multiple_errors_internal_error() -> None:
+    def f(input_value: Any) -> Any:
+        raise PydanticMultipleErrors([('a', ValueError('x')), ('b', TypeError('internal'))])
+
+    v = SchemaValidator(core_schema.no_info_plain_validator_function(f))
+    with pytest.raises(TypeError, match='internal'):
+        v.validate_python(1)
+
+
+def test_multiple_errors_init() -> None:
+    errors = PydanticMultipleErrors([('a', ValueError('x')), ((1, 'b'), ValueError('y'))])
+    assert isinstance(errors, ValueError)
+    assert [(loc, str(error)) for loc, error in errors.errors] == [(('a',), 'x'), ((1, 'b'), 'y')]
+    assert str(errors) == '2 errors\na\n  x\n1.b\n  y'
+    assert repr(errors) == "PydanticMultipleErrors([(('a',), ValueError('x')), ((1, 'b'), ValueError('y'))])"
+
+    with pytest.raises(TypeError, match=r'expects an iterable of `\(loc, error\)` tuples'):
+        PydanticMultipleErrors([ValueError('x')])
+    with pytest.raises(TypeError, match='`loc` must be a string, int, or tuple of strings and ints'):
+        PydanticMultipleErrors([(None, ValueError('x'))])
+    with pytest.raises(TypeError, match='`error` must be an exception instance'):
+        PydanticMultipleErrors([('a', 'x')])
+    with pytest.raises(ValueError, match='^PydanticMultipleErrors requires at least one error$'):
+        PydanticMultipleErrors([])
+    with pytest.raises(ValueError, match='^PydanticMultipleErrors requires at least one error$'):
+        PydanticMultipleErrors(iter(()))
+
+
+def test_validation_info_path() -> None:
+    paths = []
+
+    def f(input_value: Any, info: core_schema.ValidationInfo) -> Any:
+        paths.append(info.path)
+        return input_value
+
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    item_schema = core_schema.with_info_after_validator_function(f, core_schema.int_schema())
+    model_schema = core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema(
+            {
+                'items': core_schema.model_field(core_schema.list_schema(item_schema), validation_alias='Items'),
+                'pair': core_schema.model_field(core_schema.tuple_schema([item_schema, item_schema])),
+            }
+        ),
+    )
+    schema = core_schema.dict_schema(core_schema.str_schema(), model_schema)
+    v = SchemaValidator(schema, config=CoreConfig(validation_info_path=True))
+    v.validate_python({'a': {'Items': [1, 2], 'pair': (3, 4)}})
+    assert paths == [('a', 'Items', 0), ('a', 'Items', 1), ('a', 'pair', 0), ('a', 'pair', 1)]
+
+    paths.clear()
+    SchemaValidator(item_schema, config=CoreConfig(validation_info_path=True)).validate_python(1)
+    assert paths == [()]
+
+    paths.clear()
+    SchemaValidator(schema).validate_python({'a': {'Items': [1], 'pair': (3, 4)}})
+    assert paths == [None, None, None]
+
+
+def test_validation_info_path_wrap_and_assignment() -> None:
+    paths = []
+
+    def f(input_value: Any, handler: core_schema.ValidatorFunctionWrapHandler, info: core_schema.ValidationInfo):
+        paths.append(info.path)
+        return handler(input_value)
+
+    schema = core_schema.typed_dict_schema(
+        {
+            'x': core_schema.typed_dict_field(
+                core_schema.list_schema(core_schema.with_info_wrap_validator_function(f, core_schema.int_schema()))
+            )
+        },
+        extra_behavior='allow',
+        extras_schema=core_schema.with_info_wrap_validator_function(f, core_schema.int_schema()),
+    )
+    v = SchemaValidator(schema, config=CoreConfig(validation_info_path=True))
+    assert v.validate_python({'x': [1, 2], 'y': 3}) == {'x': [1, 2], 'y': 3}
+    assert paths == [('x', 0), ('x', 1), ('y',)]
+
+    paths.clear()
+    assert v.validate_assignment({'x': [1]}, 'x', [5]) == {'x': [5]}
+    assert paths == [('x', 0)]
+
+
+def test_validation_info_parent_data() -> None:
+    seen = []
+
+    def f(input_value: Any, info: core_schema.ValidationInfo) -> Any:
+        seen.append((info.field_name, dict(info.data), info.parent_data and dict(info.parent_data)))
+        return input_value
+
+    int_schema = core_schema.with_info_after_validator_function(f, core_schema.int_schema())
+    inner_schema = core_schema.typed_dict_schema({'b': core_schema.typed_dict_field(int_schema)})
+    schema = core_schema.typed_dict_schema(
+        {'a': core_schema.typed_dict_field(int_schema), 'inner': core_schema.typed_dict_field(inner_schema)}
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python({'a': 1, 'inner': {'b': 2}}) == {'a': 1, 'inner': {'b': 2}}
+    assert seen == [('a', {}, None), ('b', {}, {'a': 1})]
+
+
+def test_validation_info_scratch_wrap() -> None:
+    def inner(input_value: Any, info: core_schema.ValidationInfo) -> Any:
+        info.scratch['inner'] = input_value
+        return input_value
+
+    def wrap(input_value: Any, handler: core_schema.ValidatorFunctionWrapHandler, info: core_schema.ValidationInfo):
+        info.scratch['outer'] = input_value
+        output = handler(input_value)
+        return output, dict(info.scratch)
+
+    schema = core_schema.with_info_wrap_validator_function(
+        wrap, core_schema.with_info_after_validator_function(inner, core_schema.int_schema())
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python(1) == (1, {'outer': 1, 'inner': 1})
+    assert v.validate_python(2) == (2, {'outer': 2, 'inner': 2})
@@ -1,3 +1,4 @@
+import asyncio
 import re
 
 import pytest
@@ -197,3 +198,108 @@ def test_generator_too_short():
             'ctx': {'field_type': 'Generator', 'min_length': 4, 'actual_length': 3},
         }
     ]
+
+
+async def agen(*items):
+    for item in items:
+        await asyncio.sleep(0)
+        yield item
+
+
+async def consume(aiterator, out):
+    async for item in aiterator:
+        out.append(item)
+    return out
+
+
+def test_async_generator():
+    v = SchemaValidator(cs.generator_schema(items_schema=cs.int_schema()))
+    validating_iterator = v.validate_python(agen(1, '2', 3.0))
+    assert repr(validating_iterator).startswith('AsyncValidatorIterator(index=0, schema=Some(Int(')
+    assert asyncio.run(consume(validating_iterator, [])) == [1, 2, 3]
+    assert validating_iterator.index == 3
+
+    v = SchemaValidator(cs.generator_schema())
+    assert asyncio.run(consume(v.validate_python(agen(1, 'a')), [])) == [1, 'a']
+
+
+def test_async_generator_error():
+    v = SchemaValidator(cs.generator_schema(items_schema=cs.int_schema()))
+    out = []
+    with pytest.raises(ValidationError) as exc_info:
+        asyncio.run(consume(v.validate_python(agen(1, 2, 'wrong', 4)), out))
+    # items are validated lazily, as they're consumed
+    assert out == [1, 2]
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (2,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'wrong',
+        }
+    ]
+
+
+def test_async_generator_length():
+    v = SchemaValidator(cs.generator_schema(items_schema=cs.int_schema(), min_length=2, max_length=3))
+    assert asyncio.run(consume(v.validate_python(agen(1, 2)), [])) == [1, 2]
+
+    out = []
+    with pytest.raises(ValidationError) as exc_info:
+        asyncio.run(consume(v.validate_python(agen(1, 2, 3, 4)), out))
+    assert out == [1, 2, 3]
+    assert [(e['type'], e['msg']) for e in exc_info.value.errors()] == [
+        ('too_long', 'Generator should have at most 3 items after validation, not more')
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        asyncio.run(consume(v.validate_python(agen(1)), []))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'too_short',
+            'loc': (),
+            'msg': 'Generator should have at least 2 items after validation, not 1',
+            'input': HasRepr(IsStr(regex='<async_generator object agen at .+>')),
+            'ctx': {'field_type': 'Generator', 'min_length': 2, 'actual_length': 1},
+        }
+    ]
+
+
+def test_async_iterator():
+    class Countdown:
+        def __init__(self, start):
+            self.current = start
+
+        def __aiter__(self):
+            return self
+
+        async def __anext__(self):
+            await asyncio.sleep(0)
+            if self.current == 0:
+                raise StopAsyncIteration
+            self.current -= 1
+            return str(self.current)
+
+    v = SchemaValidator(cs.generator_schema(items_schema=cs.int_schema()))
+    assert asyncio.run(consume(v.validate_python(Countdown(3)), [])) == [2, 1, 0]
+
+
+def test_async_generator_exceptions():
+    async def broken():
+        yield 1
+        raise RuntimeError('broken')
+
+    v = SchemaValidator(cs.generator_schema(items_schema=cs.int_schema()))
+    out = []
+    with pytest.raises(RuntimeError, match='broken'):
+        asyncio.run(consume(v.validate_python(broken()), out))
+    assert out == [1]
+
+    async def cancelled():
+        task = asyncio.ensure_future(consume(v.validate_python(agen(*range(100))), out))
+        await asyncio.sleep(0)
+        task.cancel()
+        with pytest.raises(asyncio.CancelledError):
+            await task
+
+    asyncio.run(cancelled())
@@ -412,12 +412,18 @@ def test_int_repr():
     v = SchemaValidator(cs.int_schema())
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:false}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="int",'
+        'validator=Int(IntValidator{strict:false,float_tolerance:None,allow_radix_prefix:false,'
+        'digit_separators:None,units:None}),'
+        'definitions=[],cache_strings=True)'
     )
     v = SchemaValidator(cs.int_schema(strict=True))
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="int",'
+        'validator=Int(IntValidator{strict:true,float_tolerance:None,allow_radix_prefix:false,'
+        'digit_separators:None,units:None}),'
+        'definitions=[],cache_strings=True)'
     )
     v = SchemaValidator(cs.int_schema(multiple_of=7))
     assert plain_repr(v).startswith('SchemaValidator(title="constrained-int",validator=ConstrainedInt(')
@@ -586,3 +592,328 @@ def test_json_big_int_key():
     assert v.validate_python({big_integer: 'x'}) == {big_integer: 'x'}
     assert v.validate_json('{"' + str(big_integer) + '": "x"}') == {big_integer: 'x'}
     assert v.validate_strings({str(big_integer): 'x'}) == {big_integer: 'x'}
+
+
+@pytest.mark.parametrize(
+    'interval,input_value,expected',
+    [
+        ('[0, 100)', 0, 0),
+        ('[0, 100)', '99', 99),
+        ('[0, 100)', 100, Err('Input should be in the interval [0, 100) [type=out_of_range,')),
+        ('[0, 100)', -1, Err('Input should be in the interval [0, 100) [type=out_of_range,')),
+        ('(0, 100]', 100, 100),
+        ('(0, 100]', 0, Err('Input should be in the interval (0, 100] [type=out_of_range,')),
+        ('[-inf, 10]', -(2**100), -(2**100)),
+        ('[-inf, 10]', 11, Err('Input should be in the interval [-inf, 10] [type=out_of_range,')),
+        ('(10, +inf)', 2**100, 2**100),
+        (f'[0, {2**70}]', 2**70 + 1, Err(f'Input should be in the interval [0, {2**70}] [type=out_of_range,')),
+    ],
+)
+def test_int_interval(py_and_json: PyAndJson, interval: str, input_value, expected):
+    v = py_and_json(cs.int_schema(interval=interval))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_test(input_value)
+        assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'interval': interval}
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+@pytest.mark.parametrize('interval', ['0, 100', '[0; 100)', '[0, x)', '(0, 100'])
+def test_int_interval_invalid(interval: str):
+    with pytest.raises(SchemaError, match=re.escape(f'Invalid interval `{interval}`')):
+        SchemaValidator(cs.int_schema(interval=interval))
+
+
+@pytest.mark.parametrize('interval', ['[5, 1]', '(1, 1]', '[1, 1)', '(1, 1)'])
+def test_int_interval_empty(interval: str):
+    with pytest.raises(SchemaError, match=re.escape(f'Empty interval `{interval}`, the lower bound must be less than')):
+        SchemaValidator(cs.int_schema(interval=interval))
+
+
+def test_int_interval_single_value():
+    v = SchemaValidator(cs.int_schema(interval='[1, 1]'))
+    assert v.validate_python(1) == 1
+    with pytest.raises(ValidationError, match=re.escape('Input should be in the interval [1, 1] [type=out_of_range,')):
+        v.validate_python(2)
+
+
+def test_int_interval_with_bounds():
+    with pytest.raises(SchemaError, match='`interval` cannot be combined with `lt`'):
+        SchemaValidator(cs.int_schema(interval='[0, 100)', lt=10))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (123, 123),
+        (-999, -999),
+        ('999', 999),
+        ('-000999', -999),
+        ('1_000', Err('Integer input should have no more than 3 digits [type=int_max_digits,')),
+        (1000, Err('Integer input should have no more than 3 digits [type=int_max_digits,')),
+        (-1000, Err('Integer input should have no more than 3 digits [type=int_max_digits,')),
+        ('1' * 1_000_000, Err('Integer input should have no more than 3 digits [type=int_max_digits,')),
+        (2**100, Err('Integer input should have no more than 3 digits [type=int_max_digits,')),
+    ],
+)
+def test_int_max_digits(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(cs.int_schema(max_digits=3))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_test(input_value)
+        assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'max_digits': 3}
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_int_max_digits_large():
+    # the limit isn't computed as `10 ** max_digits`, so huge limits are fine
+    v = SchemaValidator(cs.int_schema(max_digits=2**40))
+    assert v.validate_python(10**100) == 10**100
+    assert isinstance(v.generate_example(seed=0), int)
+
+    v = SchemaValidator(cs.int_schema(max_digits=30))
+    assert v.validate_python(10**30 - 1) == 10**30 - 1
+    with pytest.raises(ValidationError, match=r'no more than 30 digits \[type=int_max_digits,'):
+        v.validate_python(10**30)
+
+
+def test_int_max_digits_float():
+    v = SchemaValidator(cs.int_schema(max_digits=2))
+    assert v.validate_python(99.0) == 99
+    assert v.validate_python('99.00') == 99
+    with pytest.raises(ValidationError, match=r'no more than 2 digits \[type=int_max_digits,'):
+        v.validate_python(100.0)
+
+
+@pytest.mark.parametrize('schema', [cs.int_schema(), cs.int_schema(ge=-10)])
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (2.0, 2),
+        (2.0000000001, 2),
+        (2.9999999999, 3),
+        (-1.0000000001, -1),
+        (2.1, Err('Input should be a valid integer, got a number more than 0.000000001 from an integer')),
+        (2.5, Err('Input should be a valid integer, got a number more than 0.000000001 from an integer')),
+    ],
+)
+def test_int_float_tolerance(py_and_json: PyAndJson, schema, input_value, expected):
+    v = py_and_json(schema, cs.CoreConfig(int_float_tolerance=1e-9))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_test(input_value)
+        assert exc_info.value.errors(include_url=False) == [
+            {
+                'type': 'int_from_float_tolerance',
+                'loc': (),
+                'msg': expected.message,
+                'input': input_value,
+                'ctx': {'tolerance': 1e-9},
+            }
+        ]
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_int_float_tolerance_strict():
+    v = SchemaValidator(cs.int_schema(strict=True), cs.CoreConfig(int_float_tolerance=1e-9))
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type,'):
+        v.validate_python(2.0000000001)
+
+
+def test_int_float_tolerance_decimal():
+    v = SchemaValidator(cs.int_schema(), cs.CoreConfig(int_float_tolerance=0.01))
+    assert v.validate_python(Decimal('2.001')) == 2
+    assert v.validate_python(Fraction(299, 100)) == 3
+
+
+@pytest.mark.parametrize('tolerance', [-1, float('inf'), float('nan')])
+def test_int_float_tolerance_invalid(tolerance):
+    with pytest.raises(SchemaError, match="'int_float_tolerance' must be a non-negative number"):
+        SchemaValidator(cs.int_schema(), cs.CoreConfig(int_float_tolerance=tolerance))
+
+
+@pytest.mark.parametrize(
+    'schema', [cs.int_schema(allow_radix_prefix=True), cs.int_schema(allow_radix_prefix=True, ge=-100)]
+)
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('0x1F', 31),
+        ('0X1f', 31),
+        ('0o17', 15),
+        ('0b1010', 10),
+        ('-0x1F', -31),
+        (' +0b_1010 ', 10),
+        ('0x_ff_ff', 65535),
+        ('42', 42),
+        (31, 31),
+        ('0x', Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing,')),
+        ('0x1G', Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing,')),
+        ('0b102', Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing,')),
+        ('0x-1', Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing,')),
+        ('0x__1', Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing,')),
+        ('0x1_', Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing,')),
+    ],
+)
+def test_int_radix_prefix(py_and_json: PyAndJson, schema, input_value, expected):
+    v = py_and_json(schema)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_int_radix_prefix_big():
+    v = SchemaValidator(cs.int_schema(allow_radix_prefix=True))
+    assert v.validate_python('0x' + 'f' * 32) == 2**128 - 1
+
+
+def test_int_radix_prefix_default():
+    v = SchemaValidator(cs.int_schema())
+    with pytest.raises(ValidationError, match=r'unable to parse string as an integer \[type=int_parsing,'):
+        v.validate_python('0x1F')
+
+
+def test_int_radix_prefix_strict():
+    v = SchemaValidator(cs.int_schema(allow_radix_prefix=True, strict=True))
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type,'):
+        v.validate_python('0x1F')
+
+
+def test_int_radix_prefix_constrained():
+    v = SchemaValidator(cs.int_schema(allow_radix_prefix=True, le=0xFF))
+    assert v.validate_python('0xFF') == 255
+    with pytest.raises(ValidationError, match=r'Input should be less than or equal to 255 \[type=less_than_equal,'):
+        v.validate_python('0x100')
+
+
+@pytest.mark.parametrize(
+    'schema', [cs.int_schema(digit_separators=',\u2009'), cs.int_schema(digit_separators=',', ge=0)]
+)
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('1,000', 1000),
+        ('1,000,000', 1_000_000),
+        ('12,345', 12345),
+        ('+123,456', 123456),
+        (' 1,000 ', 1000),
+        ('1_000', 1000),
+        ('1000', 1000),
+        ('1,00', Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing,')),
+        ('1,0000', Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing,')),
+        ('1234,567', Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing,')),
+        (',100', Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing,')),
+        ('100,', Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing,')),
+        ('1,,000', Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing,')),
+    ],
+)
+def test_int_digit_separators(py_and_json: PyAndJson, schema, input_value, expected):
+    v = py_and_json(schema)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_int_digit_separators_thin_space():
+    v = SchemaValidator(cs.int_schema(digit_separators=',\u2009'))
+    assert v.validate_python('-1\u2009000\u2009000') == -1_000_000
+    # only one kind of separator can be used in a value
+    with pytest.raises(ValidationError, match=r'\[type=int_parsing,'):
+        v.validate_python('1,000\u2009000')
+
+
+def test_int_digit_separators_strict():
+    v = SchemaValidator(cs.int_schema(digit_separators=',', strict=True))
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type,'):
+        v.validate_python('1,000')
+
+
+def test_int_digit_separators_max_digits():
+    v = SchemaValidator(cs.int_schema(digit_separators=',', max_digits=3))
+    assert v.validate_python('999') == 999
+    with pytest.raises(ValidationError, match=r'no more than 3 digits \[type=int_max_digits,'):
+        v.validate_python('1,000')
+
+
+@pytest.mark.parametrize('separators', ['', '.', '-', 'x', '1,'])
+def test_int_digit_separators_invalid(separators):
+    with pytest.raises(SchemaError, match="separators can't be letters, digits, signs or '.'"):
+        SchemaValidator(cs.int_schema(digit_separators=separators))
+
+
+seconds = {'unit': 's', 'unit_conversions': {'ms': 0.001, 'm': 60, 'h': 3600}}
+
+
+@pytest.mark.parametrize('schema', [cs.int_schema(**seconds), cs.int_schema(**seconds, ge=0)])
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('5m', 300),
+        ('5 m', 300),
+        ('1.5h', 5400),
+        ('300s', 300),
+        ('2000ms', 2),
+        ('300', 300),
+        (300, 300),
+        ('1ms', Err('Input should be a valid integer, got a number with a fractional part [type=int_from_float,')),
+        ('5d', Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing,')),
+        ('m', Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing,')),
+        ('xm', Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing,')),
+    ],
+)
+def test_int_unit(py_and_json: PyAndJson, schema, input_value, expected):
+    v = py_and_json(schema)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_test(input_value)
+        assert exc_info.value.errors(include_url=False)[0]['input'] == input_value
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_int_unit_in_conversions():
+    v = SchemaValidator(cs.int_schema(unit='s', unit_conversions={'m': 60, 's': 1}))
+    assert v.validate_python('5m') == 300
+    assert v.validate_python('5s') == 5
+
+
+def test_int_unit_big():
+    v = SchemaValidator(cs.int_schema(unit='B', unit_conversions={'KiB': 1024, 'EiB': 2**60}))
+    assert v.validate_python('4KiB') == 4096
+    assert v.validate_python('100EiB') == 100 * 2**60
+
+
+def test_int_unit_constrained():
+    v = SchemaValidator(cs.int_schema(**seconds, le=3600))
+    assert v.validate_python('1h') == 3600
+    with pytest.raises(ValidationError, match=r'Input should be less than or equal to 3600 \[type=less_than_equal,'):
+        v.validate_python('61m')
+
+
+def test_int_unit_strict():
+    v = SchemaValidator(cs.int_schema(**seconds, strict=True))
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type,'):
+        v.validate_python('5m')
+
+
+@pytest.mark.parametrize(
+    'schema,message',
+    [
+        ({'unit_conversions': {'m': 60}}, '`unit_conversions` requires `unit`'),
+        ({'unit': 's', 'unit_conversions': {'m': 0}}, 'Invalid unit conversion `m: 0`'),
+        ({'unit': 's', 'unit_conversions': {'m': '60'}}, 'Invalid unit conversion `m: 60`'),
+        ({'unit': 's', 'unit_conversions': {'m': True}}, 'Invalid unit conversion `m: True`'),
+        ({'unit': 's', 'unit_conversions': {'s': 2}}, 'Invalid unit conversion `s: 2`'),
+        ({'unit': 's', 'unit_conversions': {'': 1}}, 'Invalid unit conversion `: 1`'),
+    ],
+)
+def test_int_unit_invalid(schema, message):
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator(cs.int_schema(**schema))
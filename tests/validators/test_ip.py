@@ -0,0 +1,218 @@
+import re
+from ipaddress import IPv4Address, IPv4Interface, IPv4Network, IPv6Address, IPv6Interface, IPv6Network
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('192.168.0.1', IPv4Address('192.168.0.1')),
+        ('0.0.0.0', IPv4Address('0.0.0.0')),
+        (IPv4Address('10.0.0.1'), IPv4Address('10.0.0.1')),
+        (3232235521, IPv4Address('192.168.0.1')),
+        (b'\xc0\xa8\x00\x01', IPv4Address('192.168.0.1')),
+        ('192.168.0.256', Err('Input is not a valid IPv4 address [type=ip_address,')),
+        ('192.168.00.1', Err('Input is not a valid IPv4 address [type=ip_address,')),
+        ('192.168.0.1/32', Err('Input is not a valid IPv4 address [type=ip_address,')),
+        ('192.168.0.1%eth0', Err('Input is not a valid IPv4 address [type=ip_address,')),
+        ('::1', Err('Input is not a valid IPv4 address [type=ip_address,')),
+        (2**32, Err('Input is not a valid IPv4 address [type=ip_address,')),
+        (-1, Err('Input is not a valid IPv4 address [type=ip_address,')),
+        (b'\x00\x00\x00', Err('Input is not a valid IPv4 address [type=ip_address,')),
+        (1.5, Err('Input is not a valid IPv4 address [type=ip_address,')),
+        (None, Err('Input is not a valid IPv4 address [type=ip_address,')),
+    ],
+)
+def test_ip_v4_address(input_value, expected):
+    v = SchemaValidator(core_schema.ip_v4_address_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is IPv4Address
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('::1', IPv6Address('::1')),
+        ('2001:DB8:0:0:0:0:0:1', IPv6Address('2001:db8::1')),
+        ('::ffff:192.168.0.1', IPv6Address('::ffff:192.168.0.1')),
+        (IPv6Address('fe80::1'), IPv6Address('fe80::1')),
+        (1, IPv6Address('::1')),
+        (b'\x00' * 15 + b'\x01', IPv6Address('::1')),
+        ('fe80::1%eth0', IPv6Address('fe80::1%eth0')),
+        ('fe80::1%1', IPv6Address('fe80::1%1')),
+        ('fe80::1%', Err('Input is not a valid IPv6 address [type=ip_address,')),
+        ('fe80::1%a%b', Err('Input is not a valid IPv6 address [type=ip_address,')),
+        ('192.168.0.1', Err('Input is not a valid IPv6 address [type=ip_address,')),
+        ('2001:db8::g', Err('Input is not a valid IPv6 address [type=ip_address,')),
+        (2**128, Err('Input is not a valid IPv6 address [type=ip_address,')),
+    ],
+)
+def test_ip_v6_address(input_value, expected):
+    v = SchemaValidator(core_schema.ip_v6_address_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is IPv6Address
+
+
+@pytest.mark.parametrize(
+    'version,input_value,expected',
+    [
+        (None, '10.0.0.0/8', IPv4Network('10.0.0.0/8')),
+        (None, '10.0.0.1', IPv4Network('10.0.0.1/32')),
+        (None, '10.0.0.0/255.0.0.0', IPv4Network('10.0.0.0/8')),
+        (None, '10.0.0.0/0.255.255.255', IPv4Network('10.0.0.0/8')),
+        (None, '0.0.0.0/0', IPv4Network('0.0.0.0/0')),
+        (None, '2001:db8::/32', IPv6Network('2001:db8::/32')),
+        (None, IPv6Network('2001:db8::/32'), IPv6Network('2001:db8::/32')),
+        (None, 167772160, IPv4Network('10.0.0.0/32')),
+        (None, 2**32, IPv6Network('::1:0:0/128')),
+        (None, '10.0.0.1/8', Err('Input is not a valid IPv4 or IPv6 network [type=ip_network,')),
+        (None, '10.0.0.0/33', Err('Input is not a valid IPv4 or IPv6 network [type=ip_network,')),
+        (None, '10.0.0.0/255.0.255.0', Err('Input is not a valid IPv4 or IPv6 network [type=ip_network,')),
+        (None, '10.0.0.0/8/8', Err('Input is not a valid IPv4 or IPv6 network [type=ip_network,')),
+        (None, '10.0.0.0/', Err('Input is not a valid IPv4 or IPv6 network [type=ip_network,')),
+        (None, '2001:db8::/ffff::', Err('Input is not a valid IPv4 or IPv6 network [type=ip_network,')),
+        (None, 'fe80::%1/64', IPv6Network('fe80::%1/64')),
+        (None, 'fe80::1%1/64', Err('Input is not a valid IPv4 or IPv6 network [type=ip_network,')),
+        (4, 'fe80::%1/64', Err('Input is not a valid IPv4 network [type=ip_network,')),
+        (4, '10.0.0.0/8', IPv4Network('10.0.0.0/8')),
+        (4, '2001:db8::/32', Err('Input is not a valid IPv4 network [type=ip_network,')),
+        (4, IPv6Network('2001:db8::/32'), Err('Input is not a valid IPv4 network [type=ip_network,')),
+        (6, '2001:db8::/32', IPv6Network('2001:db8::/32')),
+        (6, 1, IPv6Network('::1/128')),
+        (6, '10.0.0.0/8', Err('Input is not a valid IPv6 network [type=ip_network,')),
+    ],
+)
+def test_ip_network(version, input_value, expected):
+    v = SchemaValidator(core_schema.ip_network_schema(version=version))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is type(expected)
+
+
+@pytest.mark.parametrize(
+    'version,input_value,expected',
+    [
+        (None, '192.168.0.1/24', IPv4Interface('192.168.0.1/24')),
+        (None, '192.168.0.1', IPv4Interface('192.168.0.1/32')),
+        (None, '192.168.0.1/255.255.255.0', IPv4Interface('192.168.0.1/24')),
+        (None, 'fe80::1/64', IPv6Interface('fe80::1/64')),
+        (None, 'fe80::1%1/64', IPv6Interface('fe80::1%1/64')),
+        (None, 'fe80::1%eth0', IPv6Interface('fe80::1%eth0/128')),
+        (None, '10.0.0.1%1/8', Err('Input is not a valid IPv4 or IPv6 interface [type=ip_interface,')),
+        (None, IPv4Interface('192.168.0.1/24'), IPv4Interface('192.168.0.1/24')),
+        (None, '192.168.0.1/33', Err('Input is not a valid IPv4 or IPv6 interface [type=ip_interface,')),
+        (6, 'fe80::1/64', IPv6Interface('fe80::1/64')),
+        (6, '192.168.0.1/24', Err('Input is not a valid IPv6 interface [type=ip_interface,')),
+    ],
+)
+def test_ip_interface(version, input_value, expected):
+    v = SchemaValidator(core_schema.ip_interface_schema(version=version))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is type(expected)
+
+
+def test_scope_id():
+    v = SchemaValidator(core_schema.ip_v6_address_schema())
+    assert v.validate_python('fe80::1%eth0').scope_id == 'eth0'
+    assert v.validate_json('"fe80::1%1"').scope_id == '1'
+    assert v.validate_python('fe80::1').scope_id is None
+
+    v = SchemaValidator(core_schema.ip_interface_schema())
+    interface = v.validate_python('fe80::1%1/64')
+    assert (interface.scope_id, interface.network.prefixlen) == ('1', 64)
+
+
+def test_interface_is_address():
+    # like `isinstance`, interfaces are addresses
+    v = SchemaValidator(core_schema.ip_v4_address_schema())
+    interface = IPv4Interface('192.168.0.1/24')
+    assert v.validate_python(interface) is interface
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,class_name',
+    [
+        (core_schema.ip_v4_address_schema(strict=True), '192.168.0.1', 'IPv4Address'),
+        (core_schema.ip_v6_address_schema(strict=True), 1, 'IPv6Address'),
+        (core_schema.ip_network_schema(strict=True), '10.0.0.0/8', 'IPv4Network | IPv6Network'),
+        (core_schema.ip_interface_schema(version=4, strict=True), '192.168.0.1/24', 'IPv4Interface'),
+    ],
+)
+def test_strict(schema, input_value, class_name):
+    v = SchemaValidator(schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'is_instance_of',
+            'loc': (),
+            'msg': f'Input should be an instance of {class_name}',
+            'input': input_value,
+            'ctx': {'class': class_name},
+        }
+    ]
+    # strings are still allowed in JSON
+    assert v.validate_json('"::1"' if schema['type'] == 'ip-v6-address' else f'"{input_value}"')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('10.0.0.0/8', IPv4Network('10.0.0.0/8')),
+        ('2001:db8::/32', IPv6Network('2001:db8::/32')),
+        (167772160, IPv4Network('10.0.0.0/32')),
+        ('10.0.0.1/8', Err('Input is not a valid IPv4 or IPv6 network [type=ip_network,')),
+        ([], Err('Input is not a valid IPv4 or IPv6 network [type=ip_network,')),
+    ],
+)
+def test_ip_network_json(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.ip_network_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_strict_json_int():
+    v = SchemaValidator(core_schema.ip_v4_address_schema(strict=True))
+    with pytest.raises(ValidationError, match=re.escape('Input is not a valid IPv4 address [type=ip_address,')):
+        v.validate_json('3232235521')
+    assert v.validate_json('3232235521', strict=False) == IPv4Address('192.168.0.1')
+
+
+def test_invalid_version():
+    with pytest.raises(SchemaError, match='IP version should be 4 or 6, got 5'):
+        SchemaValidator({'type': 'ip-network', 'version': 5})
+
+
+def test_serialization():
+    s = SchemaSerializer(core_schema.ip_interface_schema())
+    interface = IPv4Interface('192.168.0.1/24')
+    assert s.to_python(interface) is interface
+    assert s.to_python(interface, mode='json') == '192.168.0.1/24'
+    assert s.to_json(IPv6Interface('fe80::1/64')) == b'"fe80::1/64"'
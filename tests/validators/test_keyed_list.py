@@ -0,0 +1,125 @@
+from dataclasses import dataclass
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import plain_repr
+
+port_schema = core_schema.typed_dict_schema(
+    {
+        'name': core_schema.typed_dict_field(core_schema.str_schema()),
+        'port': core_schema.typed_dict_field(core_schema.int_schema()),
+    }
+)
+
+
+def test_keyed_list():
+    v = SchemaValidator(core_schema.keyed_list_schema(port_schema, 'name'))
+    assert v.validate_python([{'name': 'http', 'port': '80'}, {'name': 'https', 'port': 443}]) == {
+        'http': {'name': 'http', 'port': 80},
+        'https': {'name': 'https', 'port': 443},
+    }
+    assert v.validate_python([]) == {}
+    assert v.validate_json('[{"name": "http", "port": 80}]') == {'http': {'name': 'http', 'port': 80}}
+
+
+def test_item_errors_keep_index():
+    v = SchemaValidator(core_schema.keyed_list_schema(port_schema, 'name'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[{"name": "http", "port": 80}, {"name": "https", "port": "x"}]')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (1, 'port'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_not_a_list():
+    v = SchemaValidator(core_schema.keyed_list_schema(port_schema, 'name'))
+    with pytest.raises(ValidationError, match=r'Input should be a valid list \[type=list_type'):
+        v.validate_python({'http': {'name': 'http', 'port': 80}})
+
+
+def test_missing_key():
+    v = SchemaValidator(core_schema.keyed_list_schema(core_schema.dict_schema(), 'name'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([{'name': 'a'}, {'port': 80}])
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': (1, 'name'), 'msg': 'Field required', 'input': {'port': 80}}
+    ]
+
+
+def test_duplicates_error():
+    v = SchemaValidator(core_schema.keyed_list_schema(port_schema, 'name'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(
+            [{'name': 'http', 'port': 80}, {'name': 'https', 'port': 443}, {'name': 'http', 'port': 8080}]
+        )
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'keyed_list_duplicate',
+            'loc': (2, 'name'),
+            'msg': "Duplicate key, each item should have a unique 'name'",
+            'input': 'http',
+            'ctx': {'key_field': 'name'},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'duplicates,expected',
+    [
+        ('first', {'http': {'name': 'http', 'port': 80}, 'https': {'name': 'https', 'port': 443}}),
+        ('last', {'http': {'name': 'http', 'port': 8080}, 'https': {'name': 'https', 'port': 443}}),
+    ],
+)
+def test_duplicates(duplicates, expected):
+    v = SchemaValidator(core_schema.keyed_list_schema(port_schema, 'name', duplicates=duplicates))
+    output = v.validate_python(
+        [{'name': 'http', 'port': 80}, {'name': 'https', 'port': 443}, {'name': 'http', 'port': 8080}]
+    )
+    assert output == expected
+    # the position of the first item with the key is kept
+    assert list(output) == ['http', 'https']
+
+
+def test_attribute_key():
+    @dataclass
+    class Port:
+        name: str
+        port: int
+
+    items_schema = core_schema.no_info_plain_validator_function(lambda value: Port(**value))
+    v = SchemaValidator(core_schema.keyed_list_schema(items_schema, 'name'))
+    assert v.validate_python([{'name': 'http', 'port': 80}]) == {'http': Port('http', 80)}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([{'name': 'http', 'port': 80}, {'name': 'http', 'port': 8080}])
+    assert exc_info.value.errors(include_url=False)[0]['loc'] == (1, 'name')
+
+
+def test_length_constraints():
+    v = SchemaValidator(core_schema.keyed_list_schema(port_schema, 'name', max_length=1))
+    with pytest.raises(ValidationError, match=r'List should have at most 1 item after validation, not 2'):
+        v.validate_python([{'name': 'http', 'port': 80}, {'name': 'https', 'port': 443}])
+
+
+def test_invalid_duplicates():
+    with pytest.raises(SchemaError, match="Invalid duplicates: `other`, expected 'error', 'first' or 'last'"):
+        SchemaValidator(core_schema.keyed_list_schema(port_schema, 'name', duplicates='other'))
+
+
+def test_repr():
+    v = SchemaValidator(core_schema.keyed_list_schema(core_schema.dict_schema(), 'name'))
+    assert 'title="keyed-list[dict[any,any]]"' in plain_repr(v)
+
+
+def test_serialization():
+    s = SchemaSerializer(core_schema.keyed_list_schema(port_schema, 'name'))
+    value = {'http': {'name': 'http', 'port': 80}, 'https': {'name': 'https', 'port': 443}}
+    assert s.to_python(value) == [{'name': 'http', 'port': 80}, {'name': 'https', 'port': 443}]
+    assert s.to_json(value) == b'[{"name":"http","port":80},{"name":"https","port":443}]'
+    assert s.to_python(value, include={0}) == [{'name': 'http', 'port': 80}]
@@ -8,7 +8,7 @@ from typing import Any, Union
 import pytest
 from dirty_equals import Contains, HasRepr, IsInstance, IsList, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 from pydantic_core import core_schema as cs
 
 from ..conftest import Err, PyAndJson, infinite_generator
@@ -548,3 +548,305 @@ def test_list_dict_items_input(testcase: ListInputTestCase) -> None:
         output = v.validate_python(testcase.input)
         assert output == testcase.output
         assert output is not testcase.input
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        (cs.list_schema(cs.int_schema(), parallel=True), [str(i) for i in range(10_000)], list(range(10_000))),
+        (cs.set_schema(cs.int_schema(), parallel=True), [i % 100 for i in range(10_000)], set(range(100))),
+        (
+            cs.frozenset_schema(cs.str_schema(), parallel=True),
+            {str(i) for i in range(10_000)},
+            frozenset(str(i) for i in range(10_000)),
+        ),
+        (
+            cs.tuple_schema([cs.nullable_schema(cs.float_schema())], variadic_item_index=0, parallel=True),
+            [None, 1] * 5_000,
+            (None, 1.0) * 5_000,
+        ),
+    ],
+)
+def test_parallel(schema, input_value, expected):
+    # items are only validated in parallel on free-threaded Python, the result is the same either way
+    result = SchemaValidator(schema).validate_python(input_value, exactness=True)
+    assert result.output == expected
+    assert result.exactness == 'lax'
+
+
+def test_parallel_errors():
+    v = SchemaValidator(cs.list_schema(cs.int_schema(), parallel=True))
+    input_value = list(range(10_000))
+    input_value[10] = 'x'
+    input_value[9_999] = 'y'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_parsing', (10,)),
+        ('int_parsing', (9_999,)),
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value, fail_fast=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [(10,)]
+    input_value[5_000] = 'z'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value, max_errors=2)
+    assert [e['loc'] for e in exc_info.value.errors()] == [(10,), (5_000,)]
+    v = SchemaValidator(cs.list_schema(cs.int_schema(), parallel=True, fail_fast=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert [e['loc'] for e in exc_info.value.errors()] == [(10,)]
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (range(3), [0, 1, 2]),
+        ({'a': 1, 'b': 2}.keys(), ['a', 'b']),
+        ({'a': 1, 'b': 2}.values(), [1, 2]),
+        ({'a': 1, 'b': 2}.items(), [('a', 1), ('b', 2)]),
+    ],
+)
+def test_dict_view_and_range_input(input_value, expected):
+    assert SchemaValidator(cs.list_schema()).validate_python(input_value) == expected
+    assert SchemaValidator(cs.tuple_schema([cs.any_schema()], variadic_item_index=0)).validate_python(
+        input_value
+    ) == tuple(expected)
+    with pytest.raises(ValidationError, match='Input should be a valid list'):
+        SchemaValidator(cs.list_schema(strict=True)).validate_python(input_value)
+
+
+def test_dict_view_too_long():
+    v = SchemaValidator(cs.list_schema(cs.int_schema(), max_length=2))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({1: 'a', 2: 'b', 3: 'c'}.keys())
+    # the length of views is known up front, unlike other iterables
+    assert exc_info.value.errors(include_url=False)[0]['ctx'] == {
+        'field_type': 'List',
+        'max_length': 2,
+        'actual_length': 3,
+    }
+
+
+@pytest.mark.parametrize(
+    'schema,expected',
+    [
+        (cs.list_schema(cs.int_schema()), [1, 2]),
+        (cs.tuple_schema([cs.int_schema()], variadic_item_index=0), (1, 2)),
+        (cs.set_schema(cs.int_schema()), {1, 2}),
+        (cs.frozenset_schema(cs.int_schema()), frozenset({1, 2})),
+    ],
+)
+def test_lax_sequence_types(schema, expected):
+    input_value = MyMapping({1: 'a', 2: 'b'})
+    with pytest.raises(ValidationError, match=r'type=\w+_type'):
+        SchemaValidator(schema).validate_python(input_value)
+
+    v = SchemaValidator(schema, config=core_schema.CoreConfig(lax_sequence_types=[MyMapping]))
+    assert v.validate_python(input_value) == expected
+    with pytest.raises(ValidationError, match=r'type=\w+_type'):
+        v.validate_python(input_value, strict=True)
+
+
+def test_lax_sequence_types_invalid():
+    with pytest.raises(SchemaError, match='`lax_sequence_types` must be a list of types'):
+        SchemaValidator(cs.list_schema(), config=core_schema.CoreConfig(lax_sequence_types=['foo']))
+
+
+@pytest.mark.parametrize(
+    'monotonic,input_value,expected',
+    [
+        ('increasing', [1, '2', 2, 5], [1, 2, 2, 5]),
+        ('increasing', [], []),
+        ('increasing', [1, 3, 2, 4, 0], Err('', [(2, 2), (4, 0)])),
+        ('decreasing', [5, 5, 1], [5, 5, 1]),
+        ('decreasing', [5, 6], Err('', [(1, 6)])),
+    ],
+)
+def test_list_monotonic(py_and_json: PyAndJson, monotonic, input_value, expected):
+    v = py_and_json(cs.list_schema(cs.int_schema(), monotonic=monotonic))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_test(input_value)
+        assert exc_info.value.errors(include_url=False) == [
+            {
+                'type': 'list_not_monotonic',
+                'loc': (index,),
+                'msg': f'List items should be {monotonic}',
+                'input': item,
+                'ctx': {'monotonic': monotonic},
+            }
+            for index, item in expected.errors
+        ]
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+@pytest.mark.parametrize('monotonic', ['increasing', 'decreasing'])
+@pytest.mark.parametrize(
+    'input_value,error_indexes',
+    [
+        ([float('nan')], [0]),
+        ([1.0, float('nan'), 1.0], [1]),
+        ([float('nan'), float('nan')], [0, 1]),
+    ],
+)
+def test_list_monotonic_nan(monotonic, input_value, error_indexes):
+    v = SchemaValidator(cs.list_schema(cs.float_schema(), monotonic=monotonic))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('list_not_monotonic', (index,)) for index in error_indexes
+    ]
+
+
+def test_list_monotonic_nan_skipped():
+    v = SchemaValidator(cs.list_schema(cs.float_schema(), monotonic='increasing'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([2.0, float('nan'), 1.0, 3.0])
+    # items after a NaN are compared to the item before it
+    assert [e['loc'] for e in exc_info.value.errors()] == [(1,), (2,)]
+
+
+def test_list_monotonic_no_items_schema():
+    v = SchemaValidator(cs.list_schema(monotonic='increasing'))
+    assert v.validate_python(['a', 'b']) == ['a', 'b']
+    # items which can't be compared aren't in order
+    with pytest.raises(ValidationError, match=r'List items should be increasing \[type=list_not_monotonic'):
+        v.validate_python([1, 'a'])
+
+
+@pytest.mark.parametrize(
+    'max_adjacent_duplicates,input_value,error_indexes',
+    [
+        (0, [1, 2, 1], []),
+        (0, [1, 1, 2, 2], [1, 3]),
+        (1, [1, 1, 2, 2], []),
+        (1, [1, 1, 1, 1, 2], [2, 3]),
+        (2, [3, 3, 3, 4, 4, 4, 4], [6]),
+    ],
+)
+def test_list_max_adjacent_duplicates(max_adjacent_duplicates, input_value, error_indexes):
+    v = SchemaValidator(cs.list_schema(cs.int_schema(), max_adjacent_duplicates=max_adjacent_duplicates))
+    if error_indexes:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        errors = exc_info.value.errors(include_url=False)
+        assert [error['loc'] for error in errors] == [(index,) for index in error_indexes]
+        assert errors[0]['type'] == 'list_adjacent_duplicates'
+        assert errors[0]['ctx'] == {'max_adjacent_duplicates': max_adjacent_duplicates}
+    else:
+        assert v.validate_python(input_value) == input_value
+
+
+def test_list_strictly_increasing():
+    v = SchemaValidator(cs.list_schema(cs.float_schema(), monotonic='increasing', max_adjacent_duplicates=0))
+    assert v.validate_json('[1.5, 2, 3.25]') == [1.5, 2, 3.25]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1.5, 1.5, 1]')
+    assert [(error['type'], error['loc']) for error in exc_info.value.errors()] == [
+        ('list_adjacent_duplicates', (1,)),
+        ('list_not_monotonic', (2,)),
+    ]
+
+
+@pytest.mark.parametrize(
+    'input_value,duplicates',
+    [
+        ([1, 2, 3], []),
+        ([1, 2, 1, 1], [(2, 0), (3, 0)]),
+        (['a', 'b', 'a'], [(2, 0)]),
+        ([[1], [2], [1]], [(2, 0)]),
+        ([{'a': 1}, 1, {'a': 1}, 1], [(2, 0), (3, 1)]),
+    ],
+)
+def test_list_unique_items(input_value, duplicates):
+    v = SchemaValidator(cs.list_schema(cs.any_schema(), unique_items=True))
+    if not duplicates:
+        assert v.validate_python(input_value) == input_value
+        return
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    errors = exc_info.value.errors(include_url=False)
+    assert [(e['loc'][0], e['ctx']['first_index']) for e in errors] == duplicates
+    assert errors[0]['type'] == 'duplicate_item'
+    first_index = duplicates[0][1]
+    assert errors[0]['msg'] == f'Items should be unique, this item is a duplicate of the item at index {first_index}'
+
+
+def test_list_unique_items_validated():
+    # uniqueness is checked on the validated items
+    v = SchemaValidator(cs.list_schema(cs.int_schema(), unique_items=True))
+    assert v.validate_python(['1', 2]) == [1, 2]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, '1'])
+    assert [(e['type'], e['loc'], e['input']) for e in exc_info.value.errors()] == [('duplicate_item', (1,), 1)]
+
+
+def test_list_unique_items_fail_fast():
+    v = SchemaValidator(cs.list_schema(cs.int_schema(), unique_items=True, fail_fast=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 1, 2, 2])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('duplicate_item', (1,))]
+
+    v = SchemaValidator(cs.list_schema(cs.int_schema(), unique_items=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 1, 2, 2, 3, 3], max_errors=2)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('duplicate_item', (1,)),
+        ('duplicate_item', (3,)),
+    ]
+
+
+def test_list_unique_items_eq_error():
+    class BadEq:
+        __hash__ = None
+
+        def __eq__(self, other):
+            raise RuntimeError('eq failed')
+
+    v = SchemaValidator(cs.list_schema(cs.any_schema(), unique_items=True))
+    with pytest.raises(RuntimeError, match='eq failed'):
+        v.validate_python([BadEq(), BadEq()])
+
+
+def test_list_monotonic_invalid():
+    with pytest.raises(SchemaError, match="Invalid monotonic: `up`, expected 'increasing' or 'decreasing'"):
+        SchemaValidator(cs.list_schema(monotonic='up'))
+
+
+def test_list_preserve_type():
+    v = SchemaValidator(cs.list_schema(cs.int_schema(), preserve_type=True))
+    assert v.validate_python(['1', 2]) == [1, 2]
+    assert v.validate_python(('1', 2)) == (1, 2)
+
+    output = v.validate_python(deque(['1', 2], maxlen=3))
+    assert isinstance(output, deque)
+    assert output == deque([1, 2]) and output.maxlen == 3
+
+    output = v.validate_python(x for x in ['1', 2])
+    assert isinstance(output, Iterator)
+    assert list(output) == [1, 2]
+
+    # other types, and JSON, are still lists
+    assert v.validate_python({'1': None}.keys()) == [1]
+    assert v.validate_json('[1, "2"]') == [1, 2]
+
+
+def test_list_preserve_type_subclasses():
+    class MyDeque(deque):
+        pass
+
+    Point = collections.namedtuple('Point', 'x y')
+
+    v = SchemaValidator(cs.list_schema(cs.int_schema(), preserve_type=True))
+    output = v.validate_python(MyDeque(['1']))
+    assert type(output) is MyDeque and output == deque([1])
+    assert type(v.validate_python(Point('1', '2'))) is tuple
+
+
+def test_list_preserve_type_strict():
+    v = SchemaValidator(cs.list_schema(cs.int_schema(), preserve_type=True, strict=True))
+    with pytest.raises(ValidationError, match='Input should be a valid list'):
+        v.validate_python((1, 2))
+    assert SchemaValidator(cs.list_schema(cs.int_schema())).validate_python((1, 2)) == [1, 2]
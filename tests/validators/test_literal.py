@@ -402,3 +402,23 @@ def test_enum_for_str() -> None:
     for val in [val_enum, val_str]:
         assert val.validate_python('a') == 'a'
         assert val.validate_python(S.a) == 'a'
+
+
+def test_case_insensitive():
+    v = SchemaValidator(core_schema.literal_schema(['GET', 'Post', 'straße', 1], case_insensitive=True))
+    assert v.validate_python('get') == 'GET'
+    assert v.validate_python('POST') == 'Post'
+    assert v.validate_python('STRASSE') == 'straße'
+    assert v.validate_python(1) == 1
+    assert v.validate_json('"gEt"') == 'GET'
+    with pytest.raises(ValidationError, match=r"Input should be 'GET', 'Post', 'straße' or 1 \[type=literal_error"):
+        v.validate_python('put')
+
+    v = SchemaValidator(core_schema.literal_schema(['GET']))
+    with pytest.raises(ValidationError, match=r'\[type=literal_error'):
+        v.validate_python('get')
+
+
+def test_case_insensitive_ambiguous():
+    with pytest.raises(SchemaError, match="Literal members `a` and `A` only differ by case"):
+        SchemaValidator(core_schema.literal_schema(['a', 'b', 'A'], case_insensitive=True))
@@ -1,3 +1,4 @@
+import json
 import re
 import sys
 from collections import defaultdict
@@ -920,6 +921,59 @@ def test_revalidate_post_init():
     assert call_count == 2
 
 
+def test_revalidate_identity_cache():
+    calls = []
+
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    def check_a(value):
+        calls.append(value)
+        return value
+
+    a_schema = core_schema.no_info_after_validator_function(check_a, core_schema.int_schema())
+    model_schema = core_schema.model_schema(
+        cls=MyModel,
+        schema=core_schema.model_fields_schema(fields={'a': core_schema.model_field(a_schema)}),
+        config=CoreConfig(revalidate_instances='always'),
+    )
+    v = SchemaValidator(core_schema.list_schema(model_schema))
+
+    shared = MyModel()
+    shared.__dict__ = {'a': 1}
+    shared.__pydantic_extra__ = None
+    shared.__pydantic_fields_set__ = {'a'}
+    other = MyModel()
+    other.__dict__ = {'a': 2}
+    other.__pydantic_extra__ = None
+    other.__pydantic_fields_set__ = {'a'}
+
+    output = v.validate_python([shared, other, shared, shared])
+    assert calls == [1, 2, 1, 1]
+    assert output[0] is not output[2]
+
+    calls.clear()
+    output = v.validate_python([shared, other, shared, shared], identity_cache=True)
+    assert calls == [1, 2]
+    assert [m.a for m in output] == [1, 2, 1, 1]
+    assert output[0] is not shared
+    assert output[0] is output[2] is output[3]
+
+    # the cache only lasts for one call
+    calls.clear()
+    v.validate_python([shared], identity_cache=True)
+    assert calls == [1]
+
+    # invalid instances aren't cached, each occurrence is reported
+    invalid = MyModel()
+    invalid.__dict__ = {'a': 'x'}
+    invalid.__pydantic_extra__ = None
+    invalid.__pydantic_fields_set__ = {'a'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([invalid, invalid], identity_cache=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [(0, 'a'), (1, 'a')]
+
+
 def test_post_init_validation_error():
     class MyModel:
         __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
@@ -1155,6 +1209,191 @@ def test_frozen():
     ]
 
 
+def test_validate_into():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.str_schema()),
+                    'b': core_schema.model_field(
+                        core_schema.no_info_after_validator_function(lambda x: x * 2, core_schema.int_schema())
+                    ),
+                    'c': core_schema.model_field(core_schema.with_default_schema(core_schema.int_schema(), default=0)),
+                }
+            ),
+        )
+    )
+
+    m = v.validate_python({'a': 'x', 'b': 1})
+    assert m.__pydantic_fields_set__ == {'a', 'b'}
+
+    assert v.validate_into(m, {'a': 'x', 'b': '2', 'c': 3}) == {'b', 'c'}
+    assert m.__dict__ == {'a': 'x', 'b': 4, 'c': 3}
+    assert m.__pydantic_fields_set__ == {'a', 'b', 'c'}
+
+    assert v.validate_into(m, {}) == set()
+
+
+def test_validate_into_rollback():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.str_schema()),
+                    'b': core_schema.model_field(core_schema.int_schema()),
+                    'c': core_schema.model_field(core_schema.int_schema(), frozen=True),
+                }
+            ),
+        )
+    )
+
+    m = v.validate_python({'a': 'x', 'b': 1, 'c': 2})
+    m.__pydantic_fields_set__ = {'a'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_into(m, {'a': 'y', 'b': 'wrong', 'c': 3})
+
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('b',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'wrong',
+        },
+        {'type': 'frozen_field', 'loc': ('c',), 'msg': 'Field is frozen', 'input': 3},
+    ]
+    assert m.__dict__ == {'a': 'x', 'b': 1, 'c': 2}
+    assert m.__pydantic_fields_set__ == {'a'}
+
+
+def test_deep_copy():
+    class Inner:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    class Outer:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    class Other:
+        def __init__(self, value):
+            self.value = value
+
+    inner_schema = core_schema.model_schema(
+        Inner, core_schema.model_fields_schema({'x': core_schema.model_field(core_schema.list_schema())})
+    )
+    v = SchemaValidator(
+        core_schema.model_schema(
+            Outer,
+            core_schema.model_fields_schema(
+                {
+                    'inner': core_schema.model_field(inner_schema),
+                    'items': core_schema.model_field(core_schema.list_schema(inner_schema)),
+                    'mapping': core_schema.model_field(core_schema.dict_schema()),
+                    'frozen': core_schema.model_field(
+                        core_schema.tuple_schema([core_schema.any_schema()], variadic_item_index=0)
+                    ),
+                    'other': core_schema.model_field(core_schema.any_schema()),
+                },
+                extra_behavior='allow',
+            ),
+            extra_behavior='allow',
+        )
+    )
+
+    m = v.validate_python(
+        {
+            'inner': {'x': [1, 2]},
+            'items': [{'x': []}],
+            'mapping': {'a': 'b', 'c': [1]},
+            'frozen': ('a', 1),
+            'other': Other([1]),
+            'extra_field': [1],
+        }
+    )
+    m.__pydantic_private__ = {'_private': [1]}
+    m.mapping['self'] = m.mapping
+    m.items.append(m.inner)
+
+    c = v.deep_copy(m)
+    assert type(c) is Outer
+    assert c is not m
+    assert c.__dict__.keys() == m.__dict__.keys()
+    assert c.__pydantic_fields_set__ == m.__pydantic_fields_set__
+    assert c.__pydantic_fields_set__ is not m.__pydantic_fields_set__
+    assert c.__pydantic_extra__ == {'extra_field': [1]}
+    assert c.__pydantic_extra__['extra_field'] is not m.__pydantic_extra__['extra_field']
+    assert c.__pydantic_private__ == {'_private': [1]}
+    assert c.__pydantic_private__['_private'] is not m.__pydantic_private__['_private']
+
+    assert type(c.inner) is Inner
+    assert c.inner is not m.inner
+    assert c.inner.x == [1, 2]
+    assert c.inner.x is not m.inner.x
+    # shared references stay shared, and cycles are preserved
+    assert c.items[1] is c.inner
+    assert c.mapping['self'] is c.mapping
+    assert c.mapping['c'] is not m.mapping['c']
+    # immutable values are reused
+    assert c.frozen is m.frozen
+    # other objects are copied with `copy.deepcopy`
+    assert c.other is not m.other
+    assert c.other.value == [1]
+    assert c.other.value is not m.other.value
+
+    c.inner.x.append(3)
+    assert m.inner.x == [1, 2]
+
+
+def test_deep_copy_memo():
+    v = SchemaValidator(core_schema.list_schema())
+    shared = [1]
+    memo = {}
+    c = v.deep_copy([shared, (shared,)], memo)
+    assert c[0] is not shared
+    assert c[1][0] is c[0]
+    assert memo[id(shared)] is c[0]
+    assert deepcopy(shared, memo) is c[0]
+
+
+def test_deep_copy_calls_dunder_deepcopy():
+    calls = []
+
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+        def __deepcopy__(self, memo):
+            calls.append(self)
+            return v.deep_copy(self, memo)
+
+    inner_schema = core_schema.model_schema(
+        MyModel, core_schema.model_fields_schema({'x': core_schema.model_field(core_schema.list_schema())})
+    )
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel, core_schema.model_fields_schema({'x': core_schema.model_field(inner_schema)})
+        )
+    )
+    m = v.validate_python({'x': {'x': [1]}})
+    c = v.deep_copy(m)
+    # `__deepcopy__` is called for the nested instance, the outer one is copied directly
+    assert calls == [m.x]
+    assert c.x is not m.x
+    assert c.x.x == [1]
+    assert c.x.x is not m.x.x
+    calls.clear()
+    c = deepcopy(m)
+    assert calls == [m, m.x]
+    assert c.x is not m.x
+
+
 @pytest.mark.parametrize(
     'function_schema,call1, call2',
     [
@@ -1386,3 +1625,216 @@ def test_model_from_defaultdict():
             'input': defaultdict(int),
         }
     ]
+
+
+def _nested_strict_scope_validator() -> SchemaValidator:
+    class Inner:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    class Outer:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    inner_schema = core_schema.model_schema(
+        Inner, core_schema.model_fields_schema({'x': core_schema.model_field(core_schema.int_schema())})
+    )
+    return SchemaValidator(
+        core_schema.model_schema(
+            Outer,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'inner': core_schema.model_field(inner_schema),
+                    'inners': core_schema.model_field(core_schema.list_schema(inner_schema)),
+                }
+            ),
+        )
+    )
+
+
+def test_strict_scope_recursive():
+    v = _nested_strict_scope_validator()
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'inner': {'x': '2'}, 'inners': [{'x': '3'}]}, strict=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [('inner', 'x'), ('inners', 0, 'x')]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(
+            {'a': 1, 'inner': {'x': '2'}, 'inners': [{'x': '3'}]}, strict=True, strict_scope='recursive'
+        )
+    assert [e['loc'] for e in exc_info.value.errors()] == [('inner', 'x'), ('inners', 0, 'x')]
+
+
+def test_strict_scope_top_level():
+    v = _nested_strict_scope_validator()
+    # nested models are validated in lax mode, using their own config
+    m = v.validate_json('{"a": 1, "inner": {"x": "2"}, "inners": [{"x": "3"}]}', strict=True, strict_scope='top-level')
+    assert m.inner.x == 2
+    assert m.inners[0].x == 3
+
+    # but fields of the outermost model are still strict
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": "1", "inner": {"x": "2"}, "inners": []}', strict=True, strict_scope='top-level')
+    assert [e['loc'] for e in exc_info.value.errors()] == [('a',)]
+
+
+def test_strict_scope_top_level_assignment():
+    v = _nested_strict_scope_validator()
+    m = v.validate_python({'a': 1, 'inner': {'x': 2}, 'inners': []})
+
+    v.validate_assignment(m, 'inner', {'x': '5'}, strict=True, strict_scope='top-level')
+    assert m.inner.x == 5
+
+    with pytest.raises(ValidationError):
+        v.validate_assignment(m, 'a', '5', strict=True, strict_scope='top-level')
+
+
+def test_strict_scope_invalid():
+    v = _nested_strict_scope_validator()
+    with pytest.raises(ValueError, match="Invalid strict_scope: `foo`, expected 'recursive' or 'top-level'"):
+        v.validate_python({}, strict=True, strict_scope='foo')
+
+
+def _partial_update_schema() -> core_schema.ModelSchema:
+    class Inner:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    class Outer:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    inner_schema = core_schema.model_schema(
+        Inner,
+        core_schema.model_fields_schema(
+            {
+                'x': core_schema.model_field(core_schema.int_schema()),
+                'y': core_schema.model_field(core_schema.with_default_schema(core_schema.int_schema(), default=0)),
+            }
+        ),
+    )
+    return core_schema.model_schema(
+        Outer,
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(core_schema.int_schema()),
+                'b': core_schema.model_field(core_schema.with_default_schema(core_schema.str_schema(), default='b')),
+                'inner': core_schema.model_field(inner_schema),
+            }
+        ),
+    )
+
+
+def test_partial_update():
+    v = SchemaValidator(_partial_update_schema())
+    m = v.validate_python({'a': '1'}, partial_update=True)
+    assert m.__dict__ == {'a': 1}
+    assert m.__pydantic_fields_set__ == {'a'}
+
+    m = v.validate_json('{}', partial_update=True)
+    assert m.__dict__ == {}
+    assert m.__pydantic_fields_set__ == set()
+
+    # present fields are still validated
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'}, partial_update=True)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', ('a',))]
+
+    # without `partial_update`, missing fields are errors
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('missing', ('inner',))]
+
+
+def test_partial_update_nested():
+    v = SchemaValidator(_partial_update_schema())
+    # nested models are validated in full
+    m = v.validate_python({'inner': {'x': 1}}, partial_update=True)
+    assert m.__dict__.keys() == {'inner'}
+    assert m.inner.__dict__ == {'x': 1, 'y': 0}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'inner': {'y': 1}}, partial_update=True)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('missing', ('inner', 'x'))]
+
+
+def test_partial_update_list():
+    # each model in a list is a partial update
+    v = SchemaValidator(core_schema.list_schema(_partial_update_schema()))
+    models = v.validate_python([{'a': 1}, {'b': 'x'}], partial_update=True)
+    assert [m.__dict__ for m in models] == [{'a': 1}, {'b': 'x'}]
+
+
+def test_on_extra_ignored():
+    class MyModel:
+        pass
+
+    inner_schema = core_schema.typed_dict_schema({'b': core_schema.typed_dict_field(core_schema.int_schema())})
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'inner': core_schema.model_field(inner_schema, validation_alias='Inner'),
+                    'allowed': core_schema.model_field(
+                        core_schema.typed_dict_schema({}, extra_behavior='allow'),
+                    ),
+                }
+            ),
+        )
+    )
+    ignored = []
+    data = {'a': 1, 'x': 2, 'Inner': {'b': 3, 'y': 4}, 'allowed': {'z': 5}}
+    m = v.validate_python(data, on_extra_ignored=ignored.append)
+    assert (m.a, m.inner, m.allowed) == (1, {'b': 3}, {'z': 5})
+    assert ignored == [('inner', 'y'), ('x',)]
+
+    ignored.clear()
+    v.validate_json(json.dumps(data), on_extra_ignored=ignored.append)
+    assert ignored == [('inner', 'y'), ('x',)]
+
+    ignored.clear()
+    v.validate_python(data, extra='allow', on_extra_ignored=ignored.append)
+    assert ignored == []
+
+
+def test_on_extra_ignored_dataclass():
+    v = SchemaValidator(
+        core_schema.dataclass_args_schema('Foo', [core_schema.dataclass_field('a', core_schema.int_schema())])
+    )
+    ignored = []
+    assert v.validate_python({'a': 1, 'b': 2}, on_extra_ignored=ignored.append) == ({'a': 1}, None)
+    assert ignored == [('b',)]
+
+
+def test_on_extra_ignored_union():
+    class A:
+        pass
+
+    class B:
+        pass
+
+    int_field = core_schema.model_field(core_schema.int_schema())
+    a_schema = core_schema.model_schema(A, core_schema.model_fields_schema({'a': int_field}))
+    b_schema = core_schema.model_schema(B, core_schema.model_fields_schema({'a': int_field, 'b': int_field}))
+    ignored = []
+
+    # `B` is used as more of its fields are set, so `A` ignoring `b` isn't reported
+    v = SchemaValidator(core_schema.union_schema([a_schema, b_schema]))
+    assert isinstance(v.validate_python({'a': 1, 'b': 2}, on_extra_ignored=ignored.append), B)
+    assert ignored == []
+    assert isinstance(v.validate_python({'a': 1, 'c': 2}, on_extra_ignored=ignored.append), A)
+    assert ignored == [('c',)]
+
+    ignored.clear()
+    v = SchemaValidator(core_schema.union_schema([b_schema, a_schema], mode='left_to_right'))
+    assert isinstance(v.validate_python({'a': 1, 'c': 2}, on_extra_ignored=ignored.append), A)
+    assert ignored == [('c',)]
+
+
+def test_on_extra_ignored_error():
+    v = SchemaValidator(core_schema.typed_dict_schema({}))
+
+    def on_extra_ignored(loc):
+        raise RuntimeError(f'unexpected {loc}')
+
+    with pytest.raises(RuntimeError, match=re.escape("unexpected ('a',)")):
+        v.validate_python({'a': 1}, on_extra_ignored=on_extra_ignored)
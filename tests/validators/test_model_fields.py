@@ -4,6 +4,7 @@ import sys
 from collections.abc import Mapping
 from dataclasses import dataclass
 from datetime import datetime
+from decimal import Decimal
 from typing import Any, Union
 
 import pytest
@@ -222,6 +223,66 @@ def test_allow_extra_invalid():
         )
 
 
+def test_allow_extra_key_pattern():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            fields={'name': core_schema.model_field(schema=core_schema.str_schema())},
+            extras_key_pattern='^label_',
+            extra_behavior='allow',
+        )
+    )
+    assert v.validate_python({'name': 'x', 'label_env': 'prod', 'label_n': 1}) == (
+        {'name': 'x'},
+        {'label_env': 'prod', 'label_n': 1},
+        {'name', 'label_env', 'label_n'},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'name': 'x', 'env': 'prod'})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'extra_forbidden', 'loc': ('env',), 'msg': 'Extra inputs are not permitted', 'input': 'prod'}
+    ]
+
+
+def test_allow_extra_pattern_schemas():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            fields={},
+            extras_pattern_schemas={'^label_': core_schema.str_schema(), '^count_': core_schema.int_schema()},
+            extra_behavior='allow',
+        )
+    )
+    assert v.validate_json('{"label_env": "prod", "count_x": "3"}')[1] == {'label_env': 'prod', 'count_x': 3}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'label_env': 1, 'count_x': 'x', 'other': 2})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('string_type', ('label_env',)),
+        ('int_parsing', ('count_x',)),
+        ('extra_forbidden', ('other',)),
+    ]
+
+    assert v.validate_assignment({}, 'count_y', '4') == ({}, {'count_y': 4}, {'count_y'})
+    with pytest.raises(ValidationError, match=r'Object has no attribute \'other\''):
+        v.validate_assignment({}, 'other', 1)
+
+    # keys matching none of the patterns fall back to `extras_schema`
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            fields={},
+            extras_schema=core_schema.float_schema(),
+            extras_pattern_schemas={'^label_': core_schema.str_schema()},
+            extra_behavior='allow',
+        )
+    )
+    assert v.validate_python({'label_env': 'prod', 'other': '1.5'})[1] == {'label_env': 'prod', 'other': 1.5}
+
+
+def test_allow_extra_pattern_invalid():
+    with pytest.raises(
+        SchemaError, match='extras_key_pattern and extras_pattern_schemas can only be used if extra_behavior=allow'
+    ):
+        SchemaValidator(core_schema.model_fields_schema(fields={}, extras_key_pattern='^a'))
+
+
 def test_allow_extra_wrong():
     with pytest.raises(SchemaError, match='Invalid extra_behavior: `wrong`'):
         SchemaValidator(
@@ -676,6 +737,83 @@ def test_aliases_path_negative_json(py_and_json: PyAndJson, input_value, expecte
         assert output == expected
 
 
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ({'items': [{'id': 1}, {'id': '2'}]}, [1, 2]),
+        ({'items': [{'id': 1}, {'other': 2}, {'id': 3}]}, [1, 3]),
+        ({'items': {'a': {'id': 1}, 'b': {'id': 2}}}, [1, 2]),
+        ({'items': []}, []),
+        ({'items': 'abc'}, Err(r'items.\*.id\n +Field required \[type=missing,')),
+        ({'items': [{'id': 'x'}]}, Err(r'items.0.id\n +Input should be a valid integer')),
+        # errors are reported at the position of the item in the input, not in the collected list
+        ({'items': [{'other': 1}, {'id': 'x'}]}, Err(r'items.1.id\n +Input should be a valid integer')),
+        ({'items': {'a': {'other': 1}, 'b': {'id': 'x'}}}, Err(r'items.b.id\n +Input should be a valid integer')),
+        ({'items': [{'id': 1}, {'id': None}]}, Err(r'items.1.id\n +Input should be a valid integer')),
+    ],
+    ids=repr,
+)
+def test_alias_path_wildcard(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(
+        core_schema.model_fields_schema(
+            fields={
+                'ids': core_schema.model_field(
+                    validation_alias=['items', '*', 'id'], schema=core_schema.list_schema(core_schema.int_schema())
+                )
+            }
+        )
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=expected.message):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == ({'ids': expected}, None, {'ids'})
+
+
+def test_alias_path_wildcard_nested(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.model_fields_schema(
+            fields={
+                'tags': core_schema.model_field(
+                    validation_alias=['groups', '*', 'items', '*', -1], schema=core_schema.any_schema()
+                )
+            }
+        )
+    )
+    input_value = {'groups': [{'items': [[1, 2], [3]]}, {'items': []}, {'other': []}]}
+    assert v.validate_test(input_value) == ({'tags': [[2, 3], []]}, None, {'tags'})
+
+    v = py_and_json(
+        core_schema.model_fields_schema(
+            fields={
+                'tags': core_schema.model_field(
+                    validation_alias=['groups', '*', 'items', '*', -1],
+                    schema=core_schema.list_schema(core_schema.list_schema(core_schema.int_schema())),
+                )
+            }
+        )
+    )
+    input_value = {'groups': [{'other': []}, {'items': [[1], 2, [3, 'x']]}]}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(input_value)
+    assert [e['loc'] for e in exc_info.value.errors()] == [('groups', 1, 'items', 2, -1)]
+
+
+def test_alias_path_wildcard_from_attributes():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            fields={
+                'ids': core_schema.model_field(
+                    validation_alias=['items', '*', 'id'], schema=core_schema.list_schema(core_schema.int_schema())
+                )
+            },
+            from_attributes=True,
+        )
+    )
+    items = (Cls(id=1), Cls(id=2), Cls(other=3))
+    assert v.validate_python(Cls(items=items)) == ({'ids': [1, 2]}, None, {'ids'})
+
+
 def test_aliases_debug():
     v = SchemaValidator(
         core_schema.model_fields_schema(
@@ -1358,6 +1496,105 @@ def test_from_attributes_path_error():
     ]
 
 
+def test_from_attributes_dotted_alias():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            fields={
+                'city': core_schema.model_field(validation_alias='owner.address.city', schema=core_schema.str_schema()),
+                'zip': core_schema.model_field(
+                    validation_alias=[['owner.address', 'zip', 0]], schema=core_schema.int_schema()
+                ),
+            },
+            from_attributes=True,
+        )
+    )
+    owner = Cls(address=Cls(city='London', zip=[123]))
+    assert v.validate_python(Cls(owner=owner))[0] == {'city': 'London', 'zip': 123}
+    # dicts reached through attributes are looked up by key
+    assert v.validate_python(Cls(owner={'address': {'city': 'Paris', 'zip': (1,)}}))[0] == {'city': 'Paris', 'zip': 1}
+    # an attribute named by the whole alias is used first
+    obj = Cls(owner=owner)
+    setattr(obj, 'owner.address.city', 'Rome')
+    assert v.validate_python(obj)[0] == {'city': 'Rome', 'zip': 123}
+
+    # dicts aren't read as paths
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'owner': {'address': {'city': 'London', 'zip': [1]}}})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('owner.address.city',), ('owner.address', 'zip', 0)]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Cls(owner=Cls(address=None)))
+    assert [e['type'] for e in exc_info.value.errors()] == ['missing', 'missing']
+
+
+def test_from_attributes_slots():
+    class Row:
+        __slots__ = ('a', 'b', 'c')
+
+        def __init__(self, a, b=None):
+            self.a = a
+            if b is not None:
+                self.b = b
+
+    class SubRow(Row):
+        __slots__ = ('d',)
+
+        @property
+        def c(self):
+            return 'property'
+
+    class DictRow(Row):
+        pass
+
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            fields={
+                'a': core_schema.model_field(schema=core_schema.int_schema()),
+                'b': core_schema.model_field(
+                    schema=core_schema.with_default_schema(schema=core_schema.int_schema(), default=0)
+                ),
+                'c': core_schema.model_field(
+                    schema=core_schema.with_default_schema(schema=core_schema.str_schema(), default='x')
+                ),
+            },
+            from_attributes=True,
+        )
+    )
+
+    # repeated to check the slots looked up on the first validation are read correctly
+    for _ in range(3):
+        assert v.validate_python(Row(1)) == ({'a': 1, 'b': 0, 'c': 'x'}, None, {'a'})
+        assert v.validate_python(Row(1, 2)) == ({'a': 1, 'b': 2, 'c': 'x'}, None, {'a', 'b'})
+        assert v.validate_python(SubRow(3)) == ({'a': 3, 'b': 0, 'c': 'property'}, None, {'a', 'c'})
+        dict_row = DictRow(4)
+        dict_row.__dict__['b'] = 5
+        # slots take precedence over the instance dict
+        assert v.validate_python(dict_row) == ({'a': 4, 'b': 0, 'c': 'x'}, None, {'a'})
+
+    with pytest.raises(ValidationError, match=r'a\n +Field required \[type=missing,'):
+        v.validate_python(Row.__new__(Row))
+
+    # modifying the class is seen by later validations
+    Row.b = property(lambda self: 6)
+    assert v.validate_python(Row(1)) == ({'a': 1, 'b': 6, 'c': 'x'}, None, {'a', 'b'})
+
+
+def test_from_attributes_slots_getattr():
+    class Row:
+        __slots__ = ('a',)
+
+        def __getattr__(self, name):
+            return 42
+
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            fields={'a': core_schema.model_field(schema=core_schema.int_schema())}, from_attributes=True
+        )
+    )
+    for _ in range(2):
+        assert v.validate_python(Row()) == ({'a': 42}, None, {'a'})
+
+
 def test_alias_extra(py_and_json: PyAndJson):
     v = py_and_json(
         {
@@ -1616,6 +1853,24 @@ def test_frozen_field():
     ]
 
 
+def test_read_only_field():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            fields={
+                'id': core_schema.model_field(schema=core_schema.int_schema(), read_only=True),
+                'name': core_schema.model_field(schema=core_schema.str_schema()),
+            }
+        )
+    )
+    data, _, _ = v.validate_python({'id': '1', 'name': 'x'})
+    assert data == {'id': 1, 'name': 'x'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(data, 'id', 2)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'read_only_field', 'loc': ('id',), 'msg': 'Field is read-only', 'input': 2}
+    ]
+
+
 @pytest.mark.parametrize(
     'config,schema_extra_behavior_kw',
     [
@@ -1874,3 +2129,283 @@ def test_by_alias_and_name_config_interaction(
         assert s.validate_python({'my_alias': 1}, by_alias=runtime_by_alias, by_name=runtime_by_name).my_field == 1
     if name_allowed:
         assert s.validate_python({'my_field': 1}, by_alias=runtime_by_alias, by_name=runtime_by_name).my_field == 1
+
+
+def test_alias_generator():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'first_name': core_schema.model_field(core_schema.str_schema()),
+                'last_name': core_schema.model_field(core_schema.str_schema(), validation_alias='surname'),
+            },
+            alias_generator=lambda name: name.upper(),
+        )
+    )
+    assert v.validate_python({'FIRST_NAME': 'a', 'surname': 'b'})[0] == {'first_name': 'a', 'last_name': 'b'}
+    assert v.validate_json('{"FIRST_NAME": "a", "surname": "b"}')[0] == {'first_name': 'a', 'last_name': 'b'}
+    assert v.validate_python({'first_name': 'a', 'surname': 'b'}, by_name=True)[0] == {
+        'first_name': 'a',
+        'last_name': 'b',
+    }
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'first_name': 'a', 'LAST_NAME': 'b'})
+    assert [e['loc'] for e in exc_info.value.errors(include_url=False)] == [('FIRST_NAME',), ('surname',)]
+
+
+def test_alias_generator_not_str():
+    with pytest.raises(SchemaError, match='`alias_generator` should return a string, got `int` for field `a`'):
+        SchemaValidator(
+            core_schema.model_fields_schema(
+                {'a': core_schema.model_field(core_schema.str_schema())}, alias_generator=lambda name: 1
+            )
+        )
+
+
+def test_derive_aliases():
+    schema = core_schema.model_fields_schema(
+        {
+            'a': core_schema.model_field(core_schema.int_schema(), validation_alias='A'),
+            'b': core_schema.model_field(core_schema.int_schema(), serialization_alias='B'),
+            'c': core_schema.model_field(core_schema.int_schema(), validation_alias=['x', 'c']),
+        },
+        derive_aliases=True,
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python({'A': 1, 'B': 2, 'x': {'c': 3}})[0] == {'a': 1, 'b': 2, 'c': 3}
+    with pytest.raises(ValidationError, match=r'B\n  Field required'):
+        v.validate_python({'A': 1, 'b': 2, 'x': {'c': 3}})
+
+
+def order_validator(price_schema: core_schema.CoreSchema, **check_kwargs: Any) -> SchemaValidator:
+    item_schema = core_schema.typed_dict_schema({'price': core_schema.typed_dict_field(price_schema)})
+    return SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'items': core_schema.model_field(core_schema.list_schema(item_schema)),
+                'shipping': core_schema.model_field(price_schema),
+                'total': core_schema.model_field(price_schema),
+            },
+            consistency_checks=[
+                core_schema.sum_consistency_check('total', [['items', '*', 'price'], ['shipping']], **check_kwargs)
+            ],
+        )
+    )
+
+
+@pytest.mark.parametrize(
+    'price_schema,prices,shipping,total,expected',
+    [
+        (core_schema.int_schema(), ['10', 5], 2, '17', 17),
+        # the sum overflows an i64
+        (core_schema.int_schema(), [2**62, 2**62], 2**62, 3 * 2**62, 3 * 2**62),
+        (core_schema.float_schema(), [0.1, 0.2], 0, 0.3, 0.3),
+        (core_schema.float_schema(), [1, 0.5], 0, 1.5, 1.5),
+        (core_schema.decimal_schema(), ['0.10', '0.20'], '1', '1.3', Decimal('1.3')),
+        (core_schema.int_schema(), [], 3, 3, 3),
+    ],
+)
+def test_sum_consistency_check(price_schema, prices, shipping, total, expected):
+    v = order_validator(price_schema)
+    data, _, _ = v.validate_python({'items': [{'price': p} for p in prices], 'shipping': shipping, 'total': total})
+    assert data['total'] == expected
+
+
+def test_sum_consistency_check_error():
+    v = order_validator(core_schema.int_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"items": [{"price": 10}, {"price": 5}], "shipping": 2, "total": 15}')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'consistency_mismatch',
+            'loc': ('total',),
+            'msg': 'Value should be 17, the sum of items.*.price, shipping',
+            'input': 15,
+            'ctx': {'check': 'sum of items.*.price, shipping', 'expected': '17'},
+        }
+    ]
+
+    # the check only runs once the fields are valid
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'items': [{'price': 'x'}], 'shipping': 2, 'total': 15})
+    assert [error['type'] for error in exc_info.value.errors()] == ['int_parsing']
+
+
+def test_sum_consistency_check_not_numbers():
+    v = order_validator(core_schema.any_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'items': [{'price': 10}, {'price': 'x'}], 'shipping': 2, 'total': 12})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'consistency_uncomputable',
+            'loc': ('total',),
+            'msg': (
+                "The sum of items.*.price, shipping can't be computed: "
+                "unsupported operand type(s) for +: 'int' and 'str'"
+            ),
+            'input': 12,
+            'ctx': {
+                'check': 'sum of items.*.price, shipping',
+                'error': "unsupported operand type(s) for +: 'int' and 'str'",
+            },
+        }
+    ]
+
+
+def test_sum_consistency_check_tolerance():
+    v = order_validator(core_schema.float_schema(), tolerance=0.01)
+    assert v.validate_python({'items': [{'price': 9.999}], 'shipping': 0, 'total': 10})[0]['total'] == 10
+    with pytest.raises(ValidationError, match=r'Value should be 9.9, the sum of items.\*.price, shipping'):
+        v.validate_python({'items': [{'price': 9.9}], 'shipping': 0, 'total': 10})
+
+
+def test_sum_consistency_check_assignment():
+    v = order_validator(core_schema.int_schema())
+    data, _, _ = v.validate_python({'items': [{'price': 10}], 'shipping': 2, 'total': 12})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(data, 'shipping', 3)
+    assert exc_info.value.errors(include_url=False)[0]['loc'] == ('total',)
+    new_data, _, _ = v.validate_assignment({**data, 'shipping': 3}, 'total', 13)
+    assert new_data['total'] == 13
+
+
+def test_sum_consistency_check_partial_update():
+    v = order_validator(core_schema.int_schema())
+    data, _, _ = v.validate_python({'items': [{'price': 10}]}, partial_update=True)
+    assert data == {'items': [{'price': 10}]}
+
+
+@pytest.mark.parametrize(
+    'payload,checksum,valid',
+    [
+        ('123456789', 0xCBF43926, True),
+        ('123456789', 'CBF43926', True),
+        ('123456789', 'cbf43926', True),
+        (b'123456789', 0xCBF43926, True),
+        ('123456789', 1, False),
+    ],
+)
+def test_checksum_consistency_check(payload, checksum, valid):
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'payload': core_schema.model_field(
+                    core_schema.union_schema([core_schema.str_schema(), core_schema.bytes_schema()])
+                ),
+                'crc': core_schema.model_field(
+                    core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()])
+                ),
+            },
+            consistency_checks=[core_schema.checksum_consistency_check('crc', ['payload'], 'crc32')],
+        )
+    )
+    if valid:
+        assert v.validate_python({'payload': payload, 'crc': checksum})[0]['crc'] == checksum
+    else:
+        with pytest.raises(ValidationError, match=r'Value should be 3421780262, the crc32 of payload'):
+            v.validate_python({'payload': payload, 'crc': checksum})
+
+
+def test_checksum_adler32():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'payload': core_schema.model_field(core_schema.str_schema()),
+                'checksum': core_schema.model_field(core_schema.int_schema()),
+            },
+            consistency_checks=[core_schema.checksum_consistency_check('checksum', ['payload'], 'adler32')],
+        )
+    )
+    # `zlib.adler32(b'Wikipedia')`
+    assert v.validate_python({'payload': 'Wikipedia', 'checksum': 300286872})[0]['checksum'] == 300286872
+
+
+@pytest.mark.parametrize(
+    'check,message',
+    [
+        (core_schema.sum_consistency_check('missing', [['a']]), 'Consistency check field `missing` is not a field'),
+        (core_schema.sum_consistency_check('a', [['missing']]), 'Consistency check field `missing` is not a field'),
+        (
+            core_schema.checksum_consistency_check('a', ['b', '*'], 'crc32'),
+            "A checksum can't be computed over a path with '*'",
+        ),
+        (
+            core_schema.checksum_consistency_check('a', ['b'], 'md5'),
+            "Invalid checksum algorithm: `md5`, expected 'crc32' or 'adler32'",
+        ),
+        ({'type': 'product', 'field': 'a', 'of': []}, "Invalid consistency check type: `product`"),
+    ],
+)
+def test_consistency_check_invalid(check, message):
+    fields = {
+        'a': core_schema.model_field(core_schema.int_schema()),
+        'b': core_schema.model_field(core_schema.str_schema()),
+    }
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator(core_schema.model_fields_schema(fields, consistency_checks=[check]))
+
+
+def form_schema(**kwargs):
+    return core_schema.model_fields_schema(
+        {
+            'name': core_schema.model_field(core_schema.str_schema()),
+            'age': core_schema.model_field(
+                core_schema.with_default_schema(core_schema.nullable_schema(core_schema.int_schema()), default=None)
+            ),
+            'note': core_schema.model_field(
+                core_schema.with_default_schema(core_schema.str_schema(), default='-'), empty_str_as_missing=False
+            ),
+        },
+        **kwargs,
+    )
+
+
+def test_empty_str_as_missing():
+    v = SchemaValidator(form_schema(empty_str_as_missing=True, extra_behavior='forbid'))
+    model_dict, _, fields_set = v.validate_python({'name': 'Alice', 'age': '', 'note': ''})
+    assert model_dict == {'name': 'Alice', 'age': None, 'note': ''}
+    assert fields_set == {'name', 'note'}
+    model_dict, _, _ = v.validate_json('{"name": "Alice", "age": "42", "note": "hi"}')
+    assert model_dict == {'name': 'Alice', 'age': 42, 'note': 'hi'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'name': '', 'age': ''})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('name',), 'msg': 'Field required', 'input': {'name': '', 'age': ''}}
+    ]
+
+
+def test_empty_str_as_missing_default():
+    v = SchemaValidator(form_schema())
+    with pytest.raises(ValidationError, match=r'unable to parse string as an integer \[type=int_parsing,'):
+        v.validate_python({'name': 'Alice', 'age': ''})
+
+
+def test_empty_str_as_missing_config():
+    v = SchemaValidator(form_schema(), config=CoreConfig(empty_str_as_missing=True))
+    assert v.validate_python({'name': 'Alice', 'age': ''})[0] == {'name': 'Alice', 'age': None, 'note': '-'}
+    # the schema setting overrides the config
+    v = SchemaValidator(form_schema(empty_str_as_missing=False), config=CoreConfig(empty_str_as_missing=True))
+    with pytest.raises(ValidationError, match=r'\[type=int_parsing,'):
+        v.validate_python({'name': 'Alice', 'age': ''})
+
+
+def test_empty_str_as_missing_field():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(
+                    core_schema.with_default_schema(core_schema.str_schema(), default='x'), empty_str_as_missing=True
+                ),
+                'b': core_schema.model_field(core_schema.with_default_schema(core_schema.str_schema(), default='y')),
+            }
+        )
+    )
+    assert v.validate_python({'a': '', 'b': ''})[0] == {'a': 'x', 'b': ''}
+    # strings of whitespace aren't empty
+    assert v.validate_python({'a': ' ', 'b': ''})[0] == {'a': ' ', 'b': ''}
+
+
+def test_empty_str_as_missing_strings():
+    v = SchemaValidator(form_schema(empty_str_as_missing=True))
+    assert v.validate_strings({'name': 'Alice', 'age': '', 'note': ''})[0] == {'name': 'Alice', 'age': None, 'note': ''}
+    assert v.validate_strings({'name': 'Alice', 'age': '42'})[0] == {'name': 'Alice', 'age': 42, 'note': '-'}
@@ -0,0 +1,156 @@
+import os
+import re
+from pathlib import Path, PurePosixPath
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, plain_repr
+
+
+class PathLike:
+    def __init__(self, path):
+        self.path = path
+
+    def __fspath__(self):
+        return self.path
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('foo/bar.txt', Path('foo/bar.txt')),
+        ('', Path('')),
+        (Path('foo'), Path('foo')),
+        (b'foo/bar', Path('foo/bar')),
+        (PathLike('foo'), Path('foo')),
+        (PathLike(b'foo'), Path('foo')),
+        (PurePosixPath('foo/bar'), Path('foo/bar')),
+        (PathLike(1), Err('Input should be a valid path [type=path_type, input_value=<tests.validators')),
+        (1, Err('Input should be a valid path [type=path_type, input_value=1, input_type=int]')),
+        (None, Err('Input should be a valid path [type=path_type')),
+    ],
+)
+def test_path(input_value, expected):
+    v = SchemaValidator(core_schema.path_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert isinstance(output, Path)
+
+
+def test_path_instance_returned():
+    v = SchemaValidator(core_schema.path_schema())
+    path = Path('foo')
+    assert v.validate_python(path) is path
+
+
+def test_path_json():
+    v = SchemaValidator(core_schema.path_schema(strict=True))
+    assert v.validate_json('"foo/bar"') == Path('foo/bar')
+    with pytest.raises(ValidationError, match='Input should be a valid path'):
+        v.validate_json('1')
+
+
+def test_path_strict():
+    v = SchemaValidator(core_schema.path_schema(strict=True))
+    assert v.validate_python(Path('foo')) == Path('foo')
+    for input_value in ('foo', b'foo', PathLike('foo')):
+        with pytest.raises(ValidationError, match='Input should be a valid path'):
+            v.validate_python(input_value)
+
+
+def test_path_filesystem(tmp_path: Path):
+    file = tmp_path / 'file.txt'
+    file.write_text('hello')
+    missing = tmp_path / 'missing'
+
+    v = SchemaValidator(core_schema.path_schema(must_exist=True))
+    assert v.validate_python(str(file)) == file
+    assert v.validate_python(tmp_path) == tmp_path
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(str(missing))
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'path_not_found', 'loc': (), 'msg': 'Path does not exist', 'input': str(missing)}
+    ]
+
+    v = SchemaValidator(core_schema.path_schema(is_file=True))
+    assert v.validate_python(file) == file
+    with pytest.raises(ValidationError, match=r'Path does not point to a file \[type=path_not_file'):
+        v.validate_python(tmp_path)
+    with pytest.raises(ValidationError, match=r'Path does not exist \[type=path_not_found'):
+        v.validate_python(missing)
+
+    v = SchemaValidator(core_schema.path_schema(is_dir=True))
+    assert v.validate_python(tmp_path) == tmp_path
+    with pytest.raises(ValidationError, match=r'Path does not point to a directory \[type=path_not_directory'):
+        v.validate_python(file)
+
+
+@pytest.mark.skipif(not hasattr(os, 'symlink'), reason='symlinks are needed')
+def test_path_resolve(tmp_path: Path):
+    file = tmp_path / 'file.txt'
+    file.write_text('hello')
+    link = tmp_path / 'link.txt'
+    link.symlink_to(file)
+
+    v = SchemaValidator(core_schema.path_schema(resolve=True, is_file=True))
+    assert v.validate_python(str(link)) == file.resolve()
+    assert v.validate_python(str(tmp_path / 'sub' / '..' / 'file.txt')) == file.resolve()
+
+    # the constraints are checked on the resolved path
+    v = SchemaValidator(core_schema.path_schema(resolve=True, suffix_in=['.txt']))
+    assert v.validate_python(str(tmp_path / 'dir' / '..' / 'other.txt')) == (tmp_path / 'other.txt').resolve()
+    with pytest.raises(ValidationError, match="Path suffix should be '.txt'"):
+        v.validate_python(str(tmp_path / 'other.txt' / '..'))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('config.toml', Path('config.toml')),
+        ('dir/config.yaml', Path('dir/config.yaml')),
+        ('archive.tar.gz', Path('archive.tar.gz')),
+        ('config.TOML', Err("Path suffix should be '.toml', '.yaml' or '.tar.gz' [type=path_suffix")),
+        ('.toml', Err("Path suffix should be '.toml', '.yaml' or '.tar.gz'")),
+        ('archive.gz', Err("Path suffix should be '.toml', '.yaml' or '.tar.gz'")),
+        ('config', Err("Path suffix should be '.toml', '.yaml' or '.tar.gz'")),
+        ('config.toml/', Path('config.toml')),
+    ],
+)
+def test_path_suffix_in(input_value, expected):
+    v = SchemaValidator(core_schema.path_schema(suffix_in=['.toml', '.yaml', '.tar.gz']))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_path_repr():
+    v = SchemaValidator(core_schema.path_schema(suffix_in=['.toml', '.yaml']))
+    assert "title=\"path['.toml','.yaml']\"" in plain_repr(v)
+
+
+@pytest.mark.parametrize(
+    'kwargs,message',
+    [
+        ({'is_file': True, 'is_dir': True}, "`is_file` and `is_dir` can't both be set"),
+        ({'suffix_in': []}, '`suffix_in` should have length > 0'),
+        ({'suffix_in': ['toml']}, "Invalid suffix in `suffix_in`: 'toml', expected a suffix like '.txt'"),
+        ({'suffix_in': ['.']}, "Invalid suffix in `suffix_in`: '.', expected a suffix like '.txt'"),
+    ],
+)
+def test_path_invalid_schema(kwargs, message):
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator(core_schema.path_schema(**kwargs))
+
+
+def test_path_serialization():
+    s = SchemaSerializer(core_schema.path_schema())
+    assert s.to_python(Path('foo/bar')) == Path('foo/bar')
+    assert s.to_json(Path('foo/bar')) == b'"foo/bar"'
@@ -0,0 +1,184 @@
+import copy
+import pickle
+import re
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, Version, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('1.2.3', '1.2.3'),
+        ('0.0.0', '0.0.0'),
+        ('1.0.0-alpha', '1.0.0-alpha'),
+        ('1.0.0-alpha.1', '1.0.0-alpha.1'),
+        ('1.0.0-0.3.7', '1.0.0-0.3.7'),
+        ('1.0.0-x-y-z.--', '1.0.0-x-y-z.--'),
+        ('1.0.0+20130313144700', '1.0.0+20130313144700'),
+        ('1.0.0-beta+exp.sha.5114f85', '1.0.0-beta+exp.sha.5114f85'),
+        ('1.0.0+001', '1.0.0+001'),
+        (' v1.2.3 ', '1.2.3'),
+        ('V1.2.3', '1.2.3'),
+        ('1.2', Err('it should have major, minor and patch versions separated by periods')),
+        ('1.2.3.4', Err('it should have major, minor and patch versions separated by periods')),
+        ('1.2.x', Err('the major, minor and patch versions should be numbers')),
+        ('1..3', Err('the major, minor and patch versions should be numbers')),
+        ('01.2.3', Err('the major version has a leading zero')),
+        ('1.02.3', Err('the minor version has a leading zero')),
+        ('1.2.03', Err('the patch version has a leading zero')),
+        ('1.2.3-', Err('the pre-release should be dot-separated alphanumeric identifiers')),
+        ('1.2.3-a..b', Err('the pre-release should be dot-separated alphanumeric identifiers')),
+        ('1.2.3-a_b', Err('the pre-release should be dot-separated alphanumeric identifiers')),
+        ('1.2.3-01', Err('a numeric pre-release identifier has a leading zero')),
+        ('1.2.3+', Err('the build metadata should be dot-separated alphanumeric identifiers')),
+        ('1.2.3+a+b', Err('the build metadata should be dot-separated alphanumeric identifiers')),
+        ('18446744073709551616.0.0', Err('a version number is too large')),
+        (123, Err('Version input should be a string or Version [type=semver_type,')),
+    ],
+)
+def test_semver(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.semver_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert isinstance(output, Version)
+        assert str(output) == expected
+
+
+def test_error_details():
+    v = SchemaValidator(core_schema.semver_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('1.2')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'semver_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid semantic version, '
+            'it should have major, minor and patch versions separated by periods',
+            'input': '1.2',
+            'ctx': {'error': 'it should have major, minor and patch versions separated by periods'},
+        }
+    ]
+
+
+def test_strict():
+    v = SchemaValidator(core_schema.semver_schema(strict=True))
+    assert str(v.validate_python('1.2.3')) == '1.2.3'
+    with pytest.raises(ValidationError, match='the major, minor and patch versions should be numbers'):
+        v.validate_python('v1.2.3')
+    with pytest.raises(ValidationError, match='the major, minor and patch versions should be numbers'):
+        v.validate_json('" 1.2.3"')
+
+
+def test_components():
+    version = Version('1.2.3-rc.1+build.5')
+    assert (version.major, version.minor, version.patch) == (1, 2, 3)
+    assert version.prerelease == 'rc.1'
+    assert version.build == 'build.5'
+    assert repr(version) == "Version('1.2.3-rc.1+build.5')"
+
+    version = Version('v4.5.6')
+    assert str(version) == '4.5.6'
+    assert (version.prerelease, version.build) == (None, None)
+
+    with pytest.raises(ValidationError, match='Input should be a valid semantic version'):
+        Version('4.5')
+
+
+def test_ordering():
+    # the example from the SemVer spec, in order of precedence
+    versions = [
+        '1.0.0-alpha',
+        '1.0.0-alpha.1',
+        '1.0.0-alpha.beta',
+        '1.0.0-beta',
+        '1.0.0-beta.2',
+        '1.0.0-beta.11',
+        '1.0.0-rc.1',
+        '1.0.0',
+        '2.0.0',
+        '2.1.0',
+        '2.1.1',
+        '10.0.0',
+    ]
+    assert sorted(Version(v) for v in reversed(versions)) == [Version(v) for v in versions]
+    assert Version('1.0.0') == Version('1.0.0')
+    # build metadata is compared last, so equality and hashing are consistent with ordering
+    assert Version('1.0.0+a') != Version('1.0.0+b')
+    assert Version('1.0.0+a') < Version('1.0.0+b')
+    assert hash(Version('1.0.0+a')) == hash(Version('1.0.0+a'))
+    assert len({Version('1.0.0'), Version('v1.0.0'), Version('1.0.0+a')}) == 2
+
+
+def test_instance_input():
+    v = SchemaValidator(core_schema.semver_schema(strict=True))
+    version = Version('1.2.3')
+    assert v.validate_python(version) is version
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('1.0.0', '1.0.0'),
+        ('1.5.0+build', '1.5.0+build'),
+        ('1.99.99', '1.99.99'),
+        ('1.0.0-rc.1', Err('Version should be greater than or equal to 1.0.0 [type=semver_greater_than_equal,')),
+        ('0.9.0', Err('Version should be greater than or equal to 1.0.0 [type=semver_greater_than_equal,')),
+        ('2.0.0-alpha', '2.0.0-alpha'),
+        ('2.0.0', Err('Version should be less than 2.0.0 [type=semver_less_than,')),
+        ('2.0.0+build', Err('Version should be less than 2.0.0 [type=semver_less_than,')),
+    ],
+)
+def test_range(input_value, expected):
+    v = SchemaValidator(core_schema.semver_schema(ge='1.0.0', lt=Version('2.0.0')))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert str(v.validate_python(input_value)) == expected
+
+
+def test_range_error_details():
+    v = SchemaValidator(core_schema.semver_schema(ge='1.0.0'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Version('0.1.0'))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'semver_greater_than_equal',
+            'loc': (),
+            'msg': 'Version should be greater than or equal to 1.0.0',
+            'input': Version('0.1.0'),
+            'ctx': {'ge': '1.0.0'},
+        }
+    ]
+
+
+def test_invalid_constraint():
+    with pytest.raises(SchemaError, match="Invalid `ge` version '1.0': it should have major, minor and patch versions"):
+        SchemaValidator(core_schema.semver_schema(ge='1.0'))
+
+
+def test_serialization():
+    s = SchemaSerializer(core_schema.semver_schema())
+    version = Version('v1.2.3-rc.1')
+    assert s.to_python(version) is version
+    assert s.to_python(version, mode='json') == '1.2.3-rc.1'
+    assert s.to_json(version) == b'"1.2.3-rc.1"'
+
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.semver_schema(), core_schema.any_schema()))
+    assert s.to_json({version: version}) == b'{"1.2.3-rc.1":"1.2.3-rc.1"}'
+
+    s = SchemaSerializer(core_schema.any_schema())
+    assert s.to_json([version]) == b'["1.2.3-rc.1"]'
+
+
+def test_pickle_and_copy():
+    version = Version('1.2.3+build')
+    assert pickle.loads(pickle.dumps(version)) == version
+    assert copy.deepcopy(version) == version
@@ -422,8 +422,396 @@ def test_coerce_numbers_to_str_schema_with_strict_mode(number: int):
         v.validate_json(str(number))
 
 
+@pytest.mark.parametrize('engine', [None, 'rust-regex', 'python-re'])
+def test_pattern_not(engine):
+    v = SchemaValidator(core_schema.str_schema(pattern_not='^(admin|root)$', regex_engine=engine))
+    assert v.validate_python('alice') == 'alice'
+    assert v.validate_python('administrator') == 'administrator'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('root')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_pattern_forbidden',
+            'loc': (),
+            'msg': "String should not match pattern '^(admin|root)$'",
+            'input': 'root',
+            'ctx': {'pattern': '^(admin|root)$'},
+        }
+    ]
+
+
+def test_pattern_not_with_pattern():
+    v = SchemaValidator(core_schema.str_schema(pattern='^[a-z]+$', pattern_not='^test', to_upper=True))
+    assert v.validate_python('alice') == 'ALICE'
+    with pytest.raises(ValidationError, match=r"String should match pattern '\^\[a-z\]\+\$'"):
+        v.validate_python('Alice')
+    with pytest.raises(ValidationError, match=r"should not match pattern '\^test' \[type=string_pattern_forbidden,"):
+        v.validate_python('testing')
+
+
+def test_pattern_not_compiled():
+    v = SchemaValidator(core_schema.str_schema(pattern_not=re.compile('secret', re.IGNORECASE)))
+    assert v.validate_python('public') == 'public'
+    with pytest.raises(ValidationError, match=r'\[type=string_pattern_forbidden,'):
+        v.validate_json('"TOP SECRET"')
+
+
+def test_pattern_not_invalid():
+    with pytest.raises(SchemaError, match='error: unclosed group'):
+        SchemaValidator(core_schema.str_schema(pattern_not='(abc'))
+
+
 @pytest.mark.parametrize('engine', [None, 'rust-regex', 'python-re'])
 def test_compiled_regex(engine) -> None:
     v = SchemaValidator(core_schema.str_schema(pattern=re.compile('abc', re.IGNORECASE), regex_engine=engine))
     assert v.validate_python('abc') == 'abc'
     assert v.validate_python('ABC') == 'ABC'
+
+
+@pytest.mark.parametrize('engine', ['rust-regex', 'python-re'])
+def test_shared_patterns(engine):
+    """Fields with the same pattern share a compiled regex, which mustn't mix up engines or compiled patterns."""
+    slug = core_schema.str_schema(pattern='^[a-z]+$', regex_engine=engine)
+    v = SchemaValidator(
+        core_schema.typed_dict_schema({f'f{i}': core_schema.typed_dict_field(slug) for i in range(100)})
+    )
+    assert v.validate_python({f'f{i}': 'abc' for i in range(100)})['f99'] == 'abc'
+    with pytest.raises(ValidationError, match=r'f50\n  String should match pattern'):
+        v.validate_python({**{f'f{i}': 'abc' for i in range(100)}, 'f50': 'ABC'})
+
+    ignore_case = SchemaValidator(core_schema.str_schema(pattern=re.compile('^[a-z]+$', re.IGNORECASE)))
+    assert ignore_case.validate_python('ABC') == 'ABC'
+    with pytest.raises(ValidationError, match='string_pattern_mismatch'):
+        SchemaValidator(slug).validate_python('ABC')
+    with pytest.raises(SchemaError, match='unclosed group|unterminated subpattern'):
+        SchemaValidator(core_schema.str_schema(pattern='(abc', regex_engine=engine))
+
+
+def test_intern():
+    v = SchemaValidator(core_schema.list_schema(core_schema.str_schema(intern=True)))
+    # build equal strings at runtime so they're different objects
+    values = v.validate_python([''.join(['act', 'ive']), ''.join(['ac', 'tive']), b'active'])
+    assert values == ['active', 'active', 'active']
+    assert values[0] is values[1] is values[2]
+
+    json_values = v.validate_json('["active", "active"]')
+    assert json_values[0] is json_values[1] is values[0]
+
+    # interning is applied to the final value
+    v = SchemaValidator(core_schema.str_schema(intern=True, strip_whitespace=True, to_lower=True))
+    assert v.validate_python(' Active ') is v.validate_python(''.join(['ACT', 'IVE']))
+
+
+def test_intern_disabled():
+    v = SchemaValidator(core_schema.str_schema(min_length=1))
+    a, b = ''.join(['act', 'ive']), ''.join(['ac', 'tive'])
+    assert v.validate_python(a) is a
+    assert v.validate_python(a) is not v.validate_python(b)
+
+
+def test_cache_strings_disabled():
+    v = SchemaValidator(core_schema.list_schema(core_schema.str_schema(cache_strings=False)))
+    values = v.validate_json('["active", "active"]')
+    assert values == ['active', 'active']
+    assert values[0] is not values[1]
+
+    # strings are still cached for the schemas which don't opt out
+    v = SchemaValidator(core_schema.list_schema(core_schema.str_schema()))
+    values = v.validate_json('["active", "active"]')
+    assert values[0] is values[1]
+
+    with pytest.raises(SchemaError, match="`intern` can't be used with `cache_strings=False`"):
+        SchemaValidator(core_schema.str_schema(intern=True, cache_strings=False))
+
+
+def test_bytes_encoding():
+    v = SchemaValidator(core_schema.str_schema(encoding='latin-1'))
+    assert v.validate_python(b'caf\xe9') == 'café'
+    assert v.validate_python(bytearray(b'caf\xe9')) == 'café'
+    assert v.validate_python('café') == 'café'
+
+    with pytest.raises(ValidationError, match=r'Input should be a valid string \[type=string_type'):
+        v.validate_python(b'caf\xe9', strict=True)
+
+    # constraints apply to the decoded string
+    v = SchemaValidator(core_schema.str_schema(encoding='latin-1', max_length=4))
+    assert v.validate_python(b'caf\xe9') == 'café'
+
+
+def test_bytes_errors():
+    v = SchemaValidator(core_schema.str_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(b'caf\xe9')
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'string_unicode'
+
+    v = SchemaValidator(core_schema.str_schema(errors='replace'))
+    assert v.validate_python(b'caf\xe9') == 'caf\ufffd'
+    assert v.validate_python(b'cafe') == 'cafe'
+
+    v = SchemaValidator(core_schema.str_schema(errors='reject'))
+    assert v.validate_python('cafe') == 'cafe'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(b'cafe')
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'string_type', 'loc': (), 'msg': 'Input should be a valid string', 'input': b'cafe'}
+    ]
+
+    v = SchemaValidator(core_schema.str_schema(encoding='ascii'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(b'caf\xe9')
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'string_unicode'
+
+
+def test_bytes_decoding_invalid_schema():
+    with pytest.raises(SchemaError, match='Unknown encoding: `not-a-codec`'):
+        SchemaValidator(core_schema.str_schema(encoding='not-a-codec'))
+
+    with pytest.raises(SchemaError, match="Invalid errors: `ignore`, expected 'strict', 'replace' or 'reject'"):
+        SchemaValidator(core_schema.str_schema(errors='ignore'))
+
+
+def test_strip_bom():
+    v = SchemaValidator(core_schema.str_schema(strip_bom=True))
+    assert v.validate_python('\ufeffhello') == 'hello'
+    assert v.validate_python('hello') == 'hello'
+    # only a leading byte order mark is removed
+    assert v.validate_python('he\ufeffllo') == 'he\ufeffllo'
+    assert v.validate_json('"\\ufeffhello"') == 'hello'
+    assert v.validate_python(b'\xef\xbb\xbfhello') == 'hello'
+
+    # length constraints apply after the byte order mark is removed
+    v = SchemaValidator(core_schema.str_schema(strip_bom=True, max_length=5))
+    assert v.validate_python('\ufeffhello') == 'hello'
+
+
+@pytest.mark.parametrize(
+    'newline,input_value,expected',
+    [
+        ('\n', 'a\r\nb\rc\nd', 'a\nb\nc\nd'),
+        ('\n', 'a\nb', 'a\nb'),
+        ('\n', 'a\r\r\nb', 'a\n\nb'),
+        ('\r\n', 'a\r\nb\rc\nd', 'a\r\nb\r\nc\r\nd'),
+        ('\r\n', 'abc', 'abc'),
+        ('\n', 'line\r\n', 'line\n'),
+    ],
+)
+def test_normalize_newlines(newline, input_value, expected):
+    v = SchemaValidator(core_schema.str_schema(normalize_newlines=newline))
+    assert v.validate_python(input_value) == expected
+
+
+def test_normalize_newlines_with_strip_bom():
+    v = SchemaValidator(core_schema.str_schema(strip_bom=True, normalize_newlines='\n', strip_whitespace=True))
+    assert v.validate_python('\ufeff a\r\nb \r\n') == 'a\nb'
+
+
+def test_normalize_newlines_invalid():
+    with pytest.raises(SchemaError, match="Invalid normalize_newlines: \"\\\\r\", expected '\\\\n' or '\\\\r\\\\n'"):
+        SchemaValidator(core_schema.str_schema(normalize_newlines='\r'))
+
+
+@pytest.mark.parametrize(
+    'form,input_value,expected',
+    [
+        ('NFC', 'e\u0301', '\u00e9'),
+        ('NFC', '\u00e9', '\u00e9'),
+        ('NFD', '\u00e9', 'e\u0301'),
+        ('NFKC', '\ufb01le \u2460', 'file 1'),
+        ('NFKD', '\u00bd', '1\u20442'),
+        ('NFKC', 'plain ascii', 'plain ascii'),
+    ],
+)
+def test_unicode_normalization(form, input_value, expected):
+    v = SchemaValidator(core_schema.str_schema(unicode_normalization=form))
+    assert v.validate_python(input_value) == expected
+    assert v.validate_json(f'"{input_value}"') == expected
+
+
+def test_unicode_normalization_before_constraints():
+    v = SchemaValidator(core_schema.str_schema(unicode_normalization='NFC', max_length=4, pattern='^caf\u00e9$'))
+    assert v.validate_python('cafe\u0301') == 'caf\u00e9'
+
+    v = SchemaValidator(core_schema.str_schema(unicode_normalization='NFKC', to_lower=True, strip_whitespace=True))
+    assert v.validate_python(' \uff21\uff22 ') == 'ab'
+
+
+def test_unicode_normalization_invalid():
+    with pytest.raises(SchemaError, match="Invalid unicode_normalization: `nfc`, expected 'NFC', 'NFKC', 'NFD' or"):
+        SchemaValidator(core_schema.str_schema(unicode_normalization='nfc'))
+
+
+def test_truncate():
+    v = SchemaValidator(core_schema.str_schema(max_length=5, truncate=True))
+    assert v.validate_python('hello world') == 'hello'
+    assert v.validate_python('hello') == 'hello'
+    assert v.validate_python('hi') == 'hi'
+    assert v.validate_json('"hello world"') == 'hello'
+
+
+def test_truncate_suffix():
+    v = SchemaValidator(core_schema.str_schema(max_length=6, truncate=True, truncate_suffix='…'))
+    assert v.validate_python('hello world') == 'hello…'
+    assert v.validate_python('hello!') == 'hello!'
+
+    # the suffix can fill the whole length
+    v = SchemaValidator(core_schema.str_schema(max_length=3, truncate=True, truncate_suffix='...'))
+    assert v.validate_python('hello') == '...'
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        # `e` followed by a combining acute accent
+        ('abcde\u0301f', 'abcd'),
+        ('abcd\u0301\u0301', 'abc'),
+        # a family, joined by zero width joiners
+        ('ab\U0001f468\u200d\U0001f469\u200d\U0001f467', 'ab'),
+        # a thumbs up with a skin tone modifier
+        ('abcd\U0001f44d\U0001f3fd', 'abcd'),
+        # flags are pairs of regional indicators
+        ('\U0001f1eb\U0001f1f7\U0001f1e9\U0001f1ea\U0001f1ec\U0001f1e7', '\U0001f1eb\U0001f1f7\U0001f1e9\U0001f1ea'),
+        ('ab\U0001f1eb\U0001f1f7\U0001f1e9\U0001f1ea', 'ab\U0001f1eb\U0001f1f7'),
+        ('abcd\r\n', 'abcd'),
+        # a Hangul syllable written as conjoining jamo
+        ('abc\u1100\u1161\u11a8', 'abc'),
+        ('abcd\u00e9f', 'abcd\u00e9'),
+        ('abcd\U0001f600\U0001f600', 'abcd\U0001f600'),
+    ],
+)
+def test_truncate_grapheme_clusters(input_value, expected):
+    v = SchemaValidator(core_schema.str_schema(max_length=5, truncate=True))
+    assert v.validate_python(input_value) == expected
+
+
+def test_truncate_grapheme_clusters_suffix():
+    v = SchemaValidator(core_schema.str_schema(max_length=4, truncate=True, truncate_suffix='…'))
+    assert v.validate_python('cafe\u0301 au lait') == 'caf…'
+
+
+def test_truncate_constraints():
+    # other constraints apply to the truncated value
+    v = SchemaValidator(core_schema.str_schema(max_length=3, truncate=True, pattern='^[a-z]+$'))
+    assert v.validate_python('abc1') == 'abc'
+
+    v = SchemaValidator(core_schema.str_schema(min_length=3, max_length=4, truncate=True, truncate_suffix='…'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('a\u0301\u0301\u0301\u0301')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_too_short',
+            'loc': (),
+            'msg': 'String should have at least 3 characters',
+            'input': 'a\u0301\u0301\u0301\u0301',
+            'ctx': {'min_length': 3},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'kwargs,message',
+    [
+        ({'truncate': True}, '`truncate` requires `max_length`'),
+        ({'max_length': 5, 'truncate_suffix': '…'}, '`truncate_suffix` requires `truncate=True`'),
+        (
+            {'max_length': 2, 'truncate': True, 'truncate_suffix': '...'},
+            '`truncate_suffix` should be at most `max_length` characters',
+        ),
+    ],
+)
+def test_truncate_invalid(kwargs, message):
+    with pytest.raises(SchemaError, match=message):
+        SchemaValidator(core_schema.str_schema(**kwargs))
+
+
+@pytest.mark.parametrize(
+    'length_unit,input_value,length',
+    [
+        ('codepoints', 'e\u0301', 2),
+        ('graphemes', 'e\u0301', 1),
+        ('utf8_bytes', 'e\u0301', 3),
+        ('graphemes', '\U0001f1ec\U0001f1e7\U0001f1eb\U0001f1f7\U0001f1ec', 3),
+        ('graphemes', '\U0001f468\u200d\U0001f469\u200d\U0001f467 ok', 4),
+        ('graphemes', 'a\r\nb', 3),
+        ('utf8_bytes', '\U0001f600', 4),
+        ('graphemes', '', 0),
+    ],
+)
+def test_length_unit(length_unit, input_value, length):
+    v = SchemaValidator(core_schema.str_schema(min_length=length, max_length=length, length_unit=length_unit))
+    assert v.validate_python(input_value) == input_value
+
+    v = SchemaValidator(core_schema.str_schema(min_length=length + 1, length_unit=length_unit))
+    with pytest.raises(ValidationError):
+        v.validate_python(input_value)
+
+
+def test_length_unit_graphemes_errors():
+    v = SchemaValidator(core_schema.str_schema(max_length=2, length_unit='graphemes'))
+    assert v.validate_python('\U0001f44d\U0001f3fd\U0001f1fa\U0001f1f8') == '\U0001f44d\U0001f3fd\U0001f1fa\U0001f1f8'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('abc')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_too_long',
+            'loc': (),
+            'msg': 'String should have at most 2 characters',
+            'input': 'abc',
+            'ctx': {'max_length': 2},
+        }
+    ]
+
+
+def test_length_unit_utf8_bytes_errors():
+    v = SchemaValidator(core_schema.str_schema(min_length=2, max_length=4, length_unit='utf8_bytes'))
+    assert v.validate_python('\u00e9\u00e9') == '\u00e9\u00e9'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('\u00e9\u00e9a')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_bytes_too_long',
+            'loc': (),
+            'msg': 'String should have at most 4 bytes in UTF-8',
+            'input': '\u00e9\u00e9a',
+            'ctx': {'max_length': 4},
+        }
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('a')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_bytes_too_short',
+            'loc': (),
+            'msg': 'String should have at least 2 bytes in UTF-8',
+            'input': 'a',
+            'ctx': {'min_length': 2},
+        }
+    ]
+
+
+def test_length_unit_config():
+    v = SchemaValidator(core_schema.str_schema(length_unit='utf8_bytes'), config=CoreConfig(str_max_length=2))
+    with pytest.raises(ValidationError, match='String should have at most 2 bytes in UTF-8'):
+        v.validate_python('\u00e9a')
+
+
+@pytest.mark.parametrize(
+    'length_unit,max_length,input_value,expected',
+    [
+        ('graphemes', 3, 'e\u0301e\u0301e\u0301e\u0301', 'e\u0301e\u0301\u2026'),
+        ('graphemes', 3, '\U0001f1ec\U0001f1e7' * 4, '\U0001f1ec\U0001f1e7' * 2 + '\u2026'),
+        # the suffix is 3 bytes, and `'é'` isn't split to fit more
+        ('utf8_bytes', 6, 'abc\u00e9\u00e9', 'abc\u2026'),
+        ('utf8_bytes', 6, 'ae\u0301e\u0301', 'a\u2026'),
+    ],
+)
+def test_length_unit_truncate(length_unit, max_length, input_value, expected):
+    schema = core_schema.str_schema(
+        max_length=max_length, length_unit=length_unit, truncate=True, truncate_suffix='\u2026'
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python(input_value) == expected
+
+
+def test_length_unit_invalid():
+    with pytest.raises(SchemaError, match="Invalid length_unit: `bytes`, expected 'codepoints', 'graphemes' or"):
+        SchemaValidator(core_schema.str_schema(max_length=5, length_unit='bytes'))
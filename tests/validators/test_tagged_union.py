@@ -3,7 +3,7 @@ from enum import Enum
 import pytest
 from dirty_equals import IsAnyStr
 
-from pydantic_core import CoreConfig, SchemaValidator, ValidationError, core_schema
+from pydantic_core import CoreConfig, SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 from .test_typed_dict import Cls
@@ -297,6 +297,48 @@ def test_discriminator_path(py_and_json: PyAndJson):
     ]
 
 
+def test_discriminator_nested_tuple_path(py_and_json: PyAndJson):
+    meta = core_schema.typed_dict_field(core_schema.any_schema())
+    v = py_and_json(
+        core_schema.tagged_union_schema(
+            discriminator=('meta', 'kind'),
+            choices={
+                'cat': core_schema.typed_dict_schema(
+                    {'meta': meta, 'meows': core_schema.typed_dict_field(core_schema.int_schema())}
+                ),
+                'dog': core_schema.typed_dict_schema(
+                    {'meta': meta, 'barks': core_schema.typed_dict_field(core_schema.float_schema())}
+                ),
+            },
+        )
+    )
+    assert v.validate_test({'meta': {'kind': 'cat'}, 'meows': '3'}) == {'meta': {'kind': 'cat'}, 'meows': 3}
+    assert v.validate_test({'meta': {'kind': 'dog'}, 'barks': 1}) == {'meta': {'kind': 'dog'}, 'barks': 1.0}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'meta': {'kind': 'dog'}, 'barks': 'x'})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('dog', 'barks')]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'kind': 'cat'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'union_tag_not_found',
+            'loc': (),
+            'msg': "Unable to extract tag using discriminator 'meta'.'kind'",
+            'input': {'kind': 'cat'},
+            'ctx': {'discriminator': "'meta'.'kind'"},
+        }
+    ]
+
+
+def test_discriminator_tuple_path_invalid():
+    with pytest.raises(SchemaError, match='The first item in an alias path should be a string'):
+        SchemaValidator(
+            core_schema.tagged_union_schema(discriminator=(0, 'kind'), choices={'a': core_schema.int_schema()})
+        )
+
+
 @pytest.mark.parametrize(
     'input_value,expected',
     [
@@ -450,6 +492,71 @@ def test_int_discriminator_function(py_and_json: PyAndJson, input_value, expecte
         assert v.validate_test(input_value) == expected
 
 
+def test_cache_discriminator():
+    calls = []
+
+    def discriminator_function(obj):
+        calls.append(obj)
+        return None if obj is None else type(obj).__name__
+
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.tagged_union_schema(
+                discriminator=discriminator_function,
+                choices={'str': core_schema.str_schema(), 'int': core_schema.int_schema()},
+                cache_discriminator=True,
+            )
+        )
+    )
+    assert v.validate_python(['a', 1, 'b', 2, 3]) == ['a', 1, 'b', 2, 3]
+    # the discriminator is only called once for each input type
+    assert calls == ['a', 1]
+
+    assert v.validate_python(['c']) == ['c']
+    assert calls == ['a', 1]
+
+    # `None` tags are cached too
+    for _ in range(2):
+        with pytest.raises(ValidationError, match='Unable to extract tag using discriminator'):
+            v.validate_python([None])
+    assert calls == ['a', 1, None]
+
+
+def test_cache_discriminator_mappings():
+    calls = []
+
+    def discriminator_function(obj):
+        calls.append(obj)
+        return obj['kind']
+
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.tagged_union_schema(
+                discriminator=discriminator_function,
+                choices={
+                    'a': core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.int_schema())}),
+                    'b': core_schema.typed_dict_schema({'y': core_schema.typed_dict_field(core_schema.str_schema())}),
+                },
+                cache_discriminator=True,
+            )
+        )
+    )
+    input_value = [{'kind': 'a', 'x': 1}, {'kind': 'b', 'y': 'z'}]
+    assert v.validate_python(input_value) == [{'x': 1}, {'y': 'z'}]
+    assert v.validate_json('[{"kind": "a", "x": 1}, {"kind": "b", "y": "z"}]') == [{'x': 1}, {'y': 'z'}]
+    # the tag of each dict depends on its contents, so it's never cached
+    assert len(calls) == 4
+
+
+def test_cache_discriminator_not_callable():
+    with pytest.raises(SchemaError, match='`cache_discriminator` can only be used with a callable discriminator'):
+        SchemaValidator(
+            core_schema.tagged_union_schema(
+                discriminator='kind', choices={'a': core_schema.int_schema()}, cache_discriminator=True
+            )
+        )
+
+
 def test_from_attributes():
     v = SchemaValidator(
         core_schema.tagged_union_schema(
@@ -295,3 +295,47 @@ def test_neg_7200():
 def test_tz_constraint_too_high():
     with pytest.raises(SchemaError, match='OverflowError: Python int too large.*'):
         SchemaValidator(core_schema.time_schema(tz_constraint=2**64))
+
+
+@pytest.mark.parametrize(
+    'format,input_value,expected',
+    [
+        ('%H:%M', '23:59', time(23, 59)),
+        ('%I:%M %p', '12:30 am', time(0, 30)),
+        ('%I:%M %p', '12:30 PM', time(12, 30)),
+        ('%I:%M%p', '1:05PM', time(13, 5)),
+        ('%H.%M.%S.%f', '09.05.01.25', time(9, 5, 1, 250000)),
+        ('%H:%M %z', '10:00 +0530', time(10, tzinfo=timezone(timedelta(hours=5, minutes=30)))),
+        ('%H:%M%z', '10:00Z', time(10, tzinfo=timezone.utc)),
+        ('%H:%M', time(1, 2), time(1, 2)),
+        (
+            '%H:%M',
+            '24:00',
+            Err("Input should be a valid time in the format '%H:%M', hour value is outside expected range"),
+        ),
+        ('%I:%M %p', '13:00 PM', Err("format '%I:%M %p', hour value is outside expected range")),
+        ('%I:%M %p', '10:00 noon', Err("format '%I:%M %p', expected AM or PM at position 6 [type=time_format")),
+        ('%H:%M %z', '10:00 +2400', Err("format '%H:%M %z', expected a timezone offset at position 6")),
+    ],
+)
+def test_time_format(format: str, input_value, expected):
+    v = SchemaValidator(core_schema.time_schema(format=format))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'format,message',
+    [
+        ('%M:%S', '`format` should contain a directive for the hour'),
+        ('%d %H:%M', "`format` can't contain '%d' for a time"),
+        ('%I:%M', "`format` can't contain '%I' without '%p'"),
+        ('%H:%M %p', "`format` can't contain '%p' without '%I'"),
+    ],
+)
+def test_time_format_invalid(format: str, message: str):
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator(core_schema.time_schema(format=format))
@@ -5,7 +5,7 @@ from typing import Any
 
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -298,3 +298,142 @@ def test_pandas():
         v.validate_python(one_55)
     with pytest.raises(ValidationError, match=msg):
         v.validate_python(one_55.to_pytimedelta())
+
+
+def components(days=0, hours=0, minutes=0, seconds=0, microseconds=0, negative=False):
+    return {
+        'days': days,
+        'hours': hours,
+        'minutes': minutes,
+        'seconds': seconds,
+        'microseconds': microseconds,
+        'negative': negative,
+    }
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (timedelta(days=1, hours=2, minutes=3, seconds=4, microseconds=5), components(1, 2, 3, 4, 5)),
+        (timedelta(hours=-1), components(hours=1, negative=True)),
+        ('PT36H', components(days=1, hours=12)),
+        ('P1Y', components(days=365)),
+        ('-1 day, 12:00:00', components(days=1, hours=12, negative=True)),
+        (90.5, components(minutes=1, seconds=30, microseconds=500_000)),
+    ],
+)
+def test_timedelta_components(input_value, expected):
+    v = SchemaValidator(core_schema.timedelta_schema(output='components'))
+    assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('P1Y2M3DT4H5M6.5S', {'years': 1, 'months': 2, **components(3, 4, 5, 6, 500_000)}),
+        ('PT36H', {'years': 0, 'months': 0, **components(hours=36)}),
+        ('P2W1D', {'years': 0, 'months': 0, **components(days=15)}),
+        ('-P1M', {'years': 0, 'months': 1, **components(negative=True)}),
+        # only seconds keep a fraction, other durations are normalized like `components`
+        ('P1.5Y', {'years': 0, 'months': 0, **components(days=547, hours=12)}),
+        ('12:30', {'years': 0, 'months': 0, **components(hours=12, minutes=30)}),
+        (timedelta(hours=36), {'years': 0, 'months': 0, **components(days=1, hours=12)}),
+    ],
+)
+def test_timedelta_period(input_value, expected):
+    v = SchemaValidator(core_schema.timedelta_schema(output='period'))
+    assert v.validate_python(input_value) == expected
+
+
+def test_timedelta_period_json():
+    v = SchemaValidator(core_schema.timedelta_schema(output='period'))
+    assert v.validate_json('"P1Y6M"') == {'years': 1, 'months': 6, **components()}
+
+
+def test_timedelta_output_constraints():
+    # constraints are checked on the duration as a timedelta, a year is 365 days
+    v = SchemaValidator(core_schema.timedelta_schema(output='period', le=timedelta(days=365)))
+    assert v.validate_python('P1Y') == {'years': 1, 'months': 0, **components()}
+    with pytest.raises(ValidationError, match='Input should be less than or equal to 365 days'):
+        v.validate_python('P1Y1D')
+
+
+def test_timedelta_output_serialization():
+    schema = core_schema.timedelta_schema(output='period')
+    output = SchemaValidator(schema).validate_python('P1Y')
+    assert SchemaSerializer(schema).to_json(output) == (
+        b'{"years":1,"months":0,"days":0,"hours":0,"minutes":0,"seconds":0,"microseconds":0,"negative":false}'
+    )
+
+
+def test_timedelta_output_invalid():
+    with pytest.raises(SchemaError, match="Invalid output: \"weeks\", expected 'timedelta', 'components' or 'period'"):
+        SchemaValidator(core_schema.timedelta_schema(output='weeks'))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('PT1H', timedelta(hours=1)),
+        ('-PT0S', timedelta(0)),
+        (0, timedelta(0)),
+        ('-PT1H', Err("Input should be a non-negative timedelta [type=time_delta_negative, input_value='-PT1H'")),
+        (-1, Err('Input should be a non-negative timedelta [type=time_delta_negative, input_value=-1')),
+    ],
+)
+def test_timedelta_allow_negative(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.timedelta_schema(allow_negative=False))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_timedelta_allow_negative_python():
+    v = SchemaValidator(core_schema.timedelta_schema(allow_negative=False))
+    assert v.validate_python(timedelta(0)) == timedelta(0)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(timedelta(seconds=-1))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'time_delta_negative',
+            'loc': (),
+            'msg': 'Input should be a non-negative timedelta',
+            'input': timedelta(seconds=-1),
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('-1 day, 23:00:00', timedelta(hours=-1)),
+        ('-1 day, 23:59:59.500000', timedelta(microseconds=-500_000)),
+        ('-2 days, 0:00:00', timedelta(days=-2)),
+        ('-3d 12:00:00', timedelta(days=-2, hours=-12)),
+        ('-1 day', timedelta(days=-1)),
+        ('-23:00:00', timedelta(hours=-23)),
+        ('-P1DT23H', timedelta(days=-1, hours=-23)),
+        ('1 day, 1:00:00', timedelta(days=1, hours=1)),
+    ],
+)
+def test_timedelta_sign_scope_days(input_value, expected):
+    v = SchemaValidator(core_schema.timedelta_schema(sign_scope='days'))
+    assert v.validate_python(input_value) == expected
+    # `str(timedelta)` round-trips
+    assert v.validate_python(str(expected)) == expected
+
+
+def test_timedelta_sign_scope_allow_negative():
+    v = SchemaValidator(core_schema.timedelta_schema(sign_scope='days', allow_negative=False))
+    # the whole duration is -47 hours, but the days are only negated once the sign scope is applied
+    with pytest.raises(ValidationError, match='Input should be a non-negative timedelta'):
+        v.validate_python('-1 day, 23:00:00')
+    v = SchemaValidator(core_schema.timedelta_schema(sign_scope='duration'))
+    assert v.validate_python('-1 day, 23:00:00') == timedelta(days=-1, hours=-23)
+
+
+def test_timedelta_sign_scope_invalid():
+    with pytest.raises(SchemaError, match="Invalid sign_scope: \"hours\", expected 'duration' or 'days'"):
+        SchemaValidator(core_schema.timedelta_schema(sign_scope='hours'))
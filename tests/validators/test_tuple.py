@@ -558,3 +558,18 @@ def test_tuple_fail_fast(fail_fast, expected):
         v.validate_python(['str', 'not-num', 'again'])
 
     assert exc_info.value.errors(include_url=False) == expected
+
+
+def test_tuple_unique_items():
+    v = SchemaValidator(core_schema.tuple_schema([core_schema.int_schema()], variadic_item_index=0, unique_items=True))
+    assert v.validate_python([1, '2', 3]) == (1, 2, 3)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python((1, 2, '1', 2))
+    assert [(e['type'], e['loc'], e['ctx']) for e in exc_info.value.errors()] == [
+        ('duplicate_item', (2,), {'first_index': 0}),
+        ('duplicate_item', (3,), {'first_index': 1}),
+    ]
+    # items aren't checked for duplicates while other items are invalid
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python((1, 1, 'x'))
+    assert [e['type'] for e in exc_info.value.errors()] == ['int_parsing']
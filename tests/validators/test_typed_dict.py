@@ -1271,3 +1271,117 @@ def test_by_alias_and_name_config_interaction(
         assert s.validate_python({'my_alias': 1}, by_alias=runtime_by_alias, by_name=runtime_by_name) == {'my_field': 1}
     if name_allowed:
         assert s.validate_python({'my_field': 1}, by_alias=runtime_by_alias, by_name=runtime_by_name) == {'my_field': 1}
+
+
+def test_validate_into_dict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                'tags': core_schema.typed_dict_field(
+                    core_schema.list_schema(
+                        core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.int_schema())})
+                    ),
+                    required=False,
+                ),
+            }
+        )
+    )
+    output = {'stale': 1}
+    result = v.validate_python({'name': 'a', 'tags': [{'x': '1'}]}, self_instance=output)
+    assert result is output
+    assert output == {'name': 'a', 'tags': [{'x': 1}]}
+    # nested typed dicts get their own dicts
+    assert output['tags'][0] is not output
+
+    assert v.validate_json('{"name": "b"}', self_instance=output) is output
+    assert output == {'name': 'b'}
+
+    # the dict is left empty when validation fails
+    with pytest.raises(ValidationError):
+        v.validate_python({'name': 'c', 'tags': 'x'}, self_instance=output)
+    assert output == {}
+    assert v.validate_json('{"name": "d"}', self_instance=output) is output
+    with pytest.raises(ValidationError):
+        v.validate_json('{"tags": []}', self_instance=output)
+    assert output == {}
+
+
+def test_validate_into_input_dict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.str_schema(), required=False),
+            }
+        )
+    )
+    data = {'a': '1', 'b': 'x', 'c': 3}
+    assert v.validate_python(data, self_instance=data) is data
+    assert data == {'a': 1, 'b': 'x'}
+
+    data = {'a': 'wrong', 'b': 'x'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(data, self_instance=data)
+    assert exc_info.value.errors(include_url=False)[0]['input'] == 'wrong'
+    assert data == {'a': 'wrong', 'b': 'x'}
+
+
+def test_extras_pattern_schemas():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'name': core_schema.typed_dict_field(core_schema.str_schema())},
+            extras_key_pattern='^label_',
+            extras_pattern_schemas={'_count$': core_schema.int_schema()},
+            extras_schema=core_schema.str_schema(),
+            extra_behavior='allow',
+        )
+    )
+    assert v.validate_python({'name': 'x', 'label_env': 'prod', 'label_count': '2'}) == {
+        'name': 'x',
+        'label_env': 'prod',
+        'label_count': 2,
+    }
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'name': 'x', 'env_count': 2})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'extra_forbidden', 'loc': ('env_count',), 'msg': 'Extra inputs are not permitted', 'input': 2}
+    ]
+
+
+def test_validate_assignment():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'id': core_schema.typed_dict_field(core_schema.int_schema(), read_only=True),
+                'count': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        )
+    )
+    data = v.validate_python({'id': 1, 'count': '2'})
+    assert v.validate_assignment(data, 'count', '3') is data
+    assert data == {'id': 1, 'count': 3}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(data, 'id', 2)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'read_only_field', 'loc': ('id',), 'msg': 'Field is read-only', 'input': 2}
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(data, 'count', 'x')
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', ('count',))]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(data, 'other', 1)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('no_such_attribute', ('other',))]
+    assert data == {'id': 1, 'count': 3}
+
+
+def test_validate_assignment_extra_allow():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.int_schema())},
+            extra_behavior='allow',
+            extras_schema=core_schema.int_schema(),
+        )
+    )
+    assert v.validate_assignment({'a': 1}, 'b', '2') == {'a': 1, 'b': 2}
@@ -0,0 +1,140 @@
+import copy
+import pickle
+import re
+from datetime import datetime, timezone
+from uuid import UUID
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, Ulid, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+ULID = '01ARZ3NDEKTSV4RRFFQ69G5FAV'
+ULID_INT = 1777027686520646174104517696511196507
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (ULID, ULID),
+        (ULID.lower(), ULID),
+        ('00000000000000000000000000', '00000000000000000000000000'),
+        ('7ZZZZZZZZZZZZZZZZZZZZZZZZZ', '7ZZZZZZZZZZZZZZZZZZZZZZZZZ'),
+        ('01ARZ3NDEKTSV4RRFFQ69G5FA', Err('Input should be a valid ULID, it should have 26 characters')),
+        ('01ARZ3NDEKTSV4RRFFQ69G5FAVX', Err('it should have 26 characters')),
+        ('01ARZ3NDEKTSV4RRFFQ69G5FAU', Err('it should only contain Crockford base32 characters')),
+        ('01ARZ3NDEKTSV4RRFFQ69G5FA-', Err('it should only contain Crockford base32 characters')),
+        ('80000000000000000000000000', Err('it is too large, the first character should be at most 7')),
+        (123, Err('ULID input should be a string, bytes, UUID or Ulid [type=ulid_type,')),
+    ],
+)
+def test_ulid(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.ulid_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert isinstance(output, Ulid)
+        assert str(output) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (UUID(int=ULID_INT), ULID),
+        (ULID_INT.to_bytes(16, 'big'), ULID),
+        (ULID.encode(), ULID),
+        (b'01ARZ3', Err('Input should be a valid ULID, it should have 26 characters')),
+        (b'\xff' * 26, Err('it should have 26 characters')),
+        (ULID_INT, Err('ULID input should be a string, bytes, UUID or Ulid')),
+    ],
+)
+def test_lax(input_value, expected):
+    v = SchemaValidator(core_schema.ulid_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert str(v.validate_python(input_value)) == expected
+
+
+def test_strict():
+    v = SchemaValidator(core_schema.ulid_schema(strict=True))
+    assert str(v.validate_python(ULID.lower())) == ULID
+    ulid = Ulid(ULID)
+    assert v.validate_python(ulid) is ulid
+    for input_value in (UUID(int=ULID_INT), ULID.encode()):
+        with pytest.raises(ValidationError, match=r'\[type=ulid_type,'):
+            v.validate_python(input_value)
+
+
+def test_error_details():
+    v = SchemaValidator(core_schema.ulid_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('foobar')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'ulid_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid ULID, it should have 26 characters',
+            'input': 'foobar',
+            'ctx': {'error': 'it should have 26 characters'},
+        }
+    ]
+
+
+def test_attributes():
+    ulid = Ulid(ULID)
+    assert ulid.timestamp == datetime(2016, 7, 30, 23, 54, 10, 259000, tzinfo=timezone.utc)
+    assert ulid.timestamp_ms == 1469922850259
+    assert ulid.int == ULID_INT
+    assert ulid.bytes == ULID_INT.to_bytes(16, 'big')
+    assert repr(ulid) == f"Ulid('{ULID}')"
+    assert Ulid(ulid.bytes) == ulid
+
+    with pytest.raises(ValidationError, match='Input should be a valid ULID'):
+        Ulid('foobar')
+
+
+def test_timestamp_out_of_range():
+    # the largest ULID timestamp is after the maximum year of `datetime`
+    ulid = Ulid('7ZZZZZZZZZZZZZZZZZZZZZZZZZ')
+    assert ulid.timestamp_ms == 2**48 - 1
+    with pytest.raises(ValueError, match='year 10889 is out of range'):
+        ulid.timestamp
+
+
+def test_ordering():
+    ulids = [Ulid('00000000000000000000000000'), Ulid('01ARZ3NDEKTSV4RRFFQ69G5FAV'), Ulid('01BX5ZZKBKACTAV9WEVGEMMVRZ')]
+    assert sorted(reversed(ulids)) == ulids
+    assert Ulid(ULID) == Ulid(ULID.lower())
+    assert len({Ulid(ULID), Ulid(ULID.lower()), ulids[0]}) == 2
+
+
+def test_serialization():
+    s = SchemaSerializer(core_schema.ulid_schema())
+    ulid = Ulid(ULID.lower())
+    assert s.to_python(ulid) is ulid
+    assert s.to_python(ulid, mode='json') == ULID
+    assert s.to_json(ulid) == f'"{ULID}"'.encode()
+
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.ulid_schema(), core_schema.any_schema()))
+    assert s.to_json({ulid: ulid}) == f'{{"{ULID}":"{ULID}"}}'.encode()
+
+    s = SchemaSerializer(core_schema.any_schema())
+    assert s.to_json([ulid]) == f'["{ULID}"]'.encode()
+
+
+def test_union():
+    # a string is a lax match for `ulid`, so `str` wins in smart unions
+    v = SchemaValidator(core_schema.union_schema([core_schema.ulid_schema(), core_schema.str_schema()]))
+    assert v.validate_python(ULID) == ULID
+    assert isinstance(v.validate_python(Ulid(ULID)), Ulid)
+
+
+def test_pickle_and_copy():
+    ulid = Ulid(ULID)
+    assert pickle.loads(pickle.dumps(ulid)) == ulid
+    assert copy.deepcopy(ulid) == ulid
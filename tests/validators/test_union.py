@@ -450,6 +450,25 @@ def test_case_labels():
     ]
 
 
+def test_case_labels_nested_loc():
+    card = core_schema.no_info_after_validator_function(
+        lambda v: v, core_schema.typed_dict_schema({'number': core_schema.typed_dict_field(core_schema.str_schema())})
+    )
+    bank = core_schema.typed_dict_schema({'iban': core_schema.typed_dict_field(core_schema.str_schema())})
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'payment': core_schema.typed_dict_field(core_schema.union_schema([(card, 'card'), (bank, 'bank')]))}
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'payment': {}})
+    # labels replace the names of the choices in error locations
+    assert [e['loc'] for e in exc_info.value.errors()] == [
+        ('payment', 'card', 'number'),
+        ('payment', 'bank', 'iban'),
+    ]
+
+
 def test_left_to_right_doesnt_care_about_strict_check():
     v = SchemaValidator(
         core_schema.union_schema([core_schema.int_schema(), core_schema.json_schema()], mode='left_to_right')
@@ -517,6 +536,31 @@ def test_left_to_right_union_strict():
     assert isinstance(out, float)
 
 
+def test_union_mode_override():
+    choices = [core_schema.int_schema(), core_schema.float_schema()]
+    v = SchemaValidator(core_schema.list_schema(core_schema.union_schema(choices)))
+    assert v.validate_python([1.0, '2']) == [IsFloat(exactly=1.0), 2]
+    out = v.validate_python([1.0, '2'], union_mode='left_to_right')
+    assert out == [IsInt(exactly=1), 2]
+    assert v.validate_json('[1.0, 2]', union_mode='left_to_right') == [IsInt(exactly=1), IsInt(exactly=2)]
+    # the schema's mode is used again without an override
+    assert v.validate_python([1.0]) == [IsFloat(exactly=1.0)]
+
+    v = SchemaValidator(core_schema.union_schema(choices, mode='left_to_right'))
+    assert v.validate_python(1.0) == IsInt(exactly=1)
+    assert v.validate_python(1.0, union_mode='smart') == IsFloat(exactly=1.0)
+
+
+def test_union_mode_override_errors():
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.bool_schema()]))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x', union_mode='left_to_right')
+    assert [e['type'] for e in exc_info.value.errors()] == ['int_parsing', 'bool_parsing']
+
+    with pytest.raises(ValueError, match="Invalid union_mode: `first`, expected 'smart' or 'left_to_right'"):
+        v.validate_python(1, union_mode='first')
+
+
 def test_union_function_before_called_once():
     # See https://github.com/pydantic/pydantic/issues/6830 - in particular the
     # smart union validator used to call `remove_prefix` twice, which is not
@@ -1427,3 +1471,339 @@ def test_smart_union_wrap_validator_should_not_change_nested_model_field_counts(
     assert isinstance(m, RootModel)
     assert isinstance(m.ab, ModelA)
     assert m.ab.sub.x == 'y'
+
+
+def test_union_trace_smart() -> None:
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]))
+
+    result = v.validate_python('1', trace=True)
+    assert result.output == '1'
+    assert result.trace == [
+        {
+            'union': 'union[int,str]',
+            'mode': 'smart',
+            'attempts': [
+                {'choice': 'int', 'valid': True, 'exactness': 'lax', 'fields_set_count': None, 'nested': []},
+                {'choice': 'str', 'valid': True, 'exactness': 'exact', 'fields_set_count': None, 'nested': []},
+            ],
+            'winner': 'str',
+            'reason': 'exact',
+        }
+    ]
+    assert (result.exactness, result.partial_report, result.provenance) == (None, None, None)
+
+    result = v.validate_python(1.0, trace=True)
+    assert result.output == 1
+    trace = result.trace
+    assert trace[0]['attempts'] == [
+        {'choice': 'int', 'valid': True, 'exactness': 'lax', 'fields_set_count': None, 'nested': []},
+        {'choice': 'str', 'valid': False, 'exactness': None, 'fields_set_count': None, 'nested': []},
+    ]
+    assert (trace[0]['winner'], trace[0]['reason']) == ('int', 'only_valid_choice')
+
+    result = v.validate_json('1', trace=True)
+    assert result.output == 1
+    assert (result.trace[0]['winner'], result.trace[0]['reason']) == ('int', 'exact')
+
+    # without `trace` just the output is returned
+    assert v.validate_python('1') == '1'
+
+
+def test_union_trace_reasons() -> None:
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                (core_schema.str_schema(), 'first'),
+                (core_schema.str_schema(), 'second'),
+            ]
+        )
+    )
+    trace = v.validate_python(b'x', trace=True).trace
+    assert (trace[0]['winner'], trace[0]['reason']) == ('first', 'first_of_equal_choices')
+
+    class MyStr(str):
+        pass
+
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    trace = v.validate_python(MyStr('1'), trace=True).trace
+    assert [a['exactness'] for a in trace[0]['attempts']] == ['lax', 'strict']
+    assert (trace[0]['winner'], trace[0]['reason']) == ('str', 'most_exact')
+
+    # a later choice that doesn't beat the winner doesn't change the reason
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [core_schema.int_schema(), core_schema.str_schema(), (core_schema.str_schema(), 'other_str')]
+        )
+    )
+    trace = v.validate_python(MyStr('1'), trace=True).trace
+    assert [a['exactness'] for a in trace[0]['attempts']] == ['lax', 'strict', 'strict']
+    assert (trace[0]['winner'], trace[0]['reason']) == ('str', 'most_exact')
+
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                (core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}), 'a'),
+                (
+                    core_schema.typed_dict_schema(
+                        {
+                            'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                            'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                        }
+                    ),
+                    'ab',
+                ),
+            ]
+        )
+    )
+    trace = v.validate_python({'a': 1, 'b': 2}, trace=True).trace
+    assert [a['fields_set_count'] for a in trace[0]['attempts']] == [1, 2]
+    assert (trace[0]['winner'], trace[0]['reason']) == ('ab', 'most_fields_set')
+
+    with pytest.raises(ValidationError):
+        v.validate_python({'b': 2}, trace=True)
+
+
+def test_union_trace_left_to_right_and_nested() -> None:
+    inner = core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()], mode='left_to_right')
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.list_schema(inner), core_schema.none_schema()]),
+    )
+
+    result = v.validate_python(['a', 1], trace=True)
+    assert result.output == ['a', 1]
+    trace = result.trace
+    assert len(trace) == 1
+    assert trace[0]['union'] == 'union[list[union[int,str]],none]'
+    assert (trace[0]['winner'], trace[0]['reason']) == ('list[union[int,str]]', 'exact')
+    nested = trace[0]['attempts'][0]['nested']
+    assert [(t['mode'], t['winner'], t['reason']) for t in nested] == [
+        ('left_to_right', 'str', 'first_valid_choice'),
+        ('left_to_right', 'int', 'first_valid_choice'),
+    ]
+    assert [a['choice'] for a in nested[0]['attempts']] == ['int', 'str']
+    assert [a['choice'] for a in nested[1]['attempts']] == ['int']
+
+    # unions that aren't nested in another union are listed in order
+    v = SchemaValidator(core_schema.dict_schema(values_schema=inner))
+    trace = v.validate_strings({'a': '1', 'b': 'x'}, trace=True).trace
+    assert [t['winner'] for t in trace] == ['int', 'str']
+
+
+def test_union_scorer() -> None:
+    calls = []
+
+    def prefer_float(choice, value, exactness, fields_set_count):
+        calls.append((choice, value, exactness, fields_set_count))
+        return 1 if choice == 'float' else 0
+
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.int_schema(), core_schema.float_schema()], scorer=prefer_float)
+    )
+    # the exact match on `int` doesn't return early, since every choice is scored
+    assert v.validate_python(1) == IsFloat(exactly=1.0)
+    assert calls == [('int', 1, 'exact', None), ('float', 1.0, 'strict', None)]
+
+    trace = v.validate_python(2, trace=True).trace
+    assert (trace[0]['winner'], trace[0]['reason']) == ('float', 'highest_score')
+
+    # invalid choices aren't scored
+    calls.clear()
+    assert v.validate_python(1.5) == 1.5
+    assert calls == [('float', 1.5, 'exact', None)]
+
+
+def test_union_scorer_overrides_fields_set() -> None:
+    a_schema = core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())})
+    ab_schema = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.int_schema()),
+            'b': core_schema.typed_dict_field(core_schema.int_schema(), required=False),
+        }
+    )
+    priority = {'a': 1, 'ab': 0}
+    v = SchemaValidator(
+        core_schema.union_schema([(a_schema, 'a'), (ab_schema, 'ab')], scorer=lambda choice, *_: priority[choice])
+    )
+    trace = v.validate_python({'a': 1, 'b': 2}, trace=True).trace
+    assert [a['fields_set_count'] for a in trace[0]['attempts']] == [1, 2]
+    assert (trace[0]['winner'], trace[0]['reason']) == ('a', 'highest_score')
+
+    # equal scores fall back to the default heuristics
+    v = SchemaValidator(core_schema.union_schema([(a_schema, 'a'), (ab_schema, 'ab')], scorer=lambda *_: 0))
+    trace = v.validate_python({'a': 1, 'b': 2}, trace=True).trace
+    assert (trace[0]['winner'], trace[0]['reason']) == ('ab', 'most_fields_set')
+
+
+@pytest.mark.parametrize('int_first', [True, False])
+def test_union_scorer_nan(int_first: bool) -> None:
+    choices = [core_schema.int_schema(), core_schema.float_schema()]
+    if not int_first:
+        choices.reverse()
+    v = SchemaValidator(
+        core_schema.union_schema(choices, scorer=lambda choice, *_: float('nan') if choice == 'int' else -1e308)
+    )
+    # NaN is the lowest score, whichever choice it's compared with
+    assert v.validate_python(1) == IsFloat(exactly=1.0)
+
+
+def test_union_scorer_errors() -> None:
+    def bad_scorer(*args):
+        raise RuntimeError('scoring failed')
+
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()], scorer=bad_scorer)
+    )
+    with pytest.raises(RuntimeError, match='scoring failed'):
+        v.validate_python(1)
+
+    # if the error is handled, the exactness of the choice which was being scored is discarded
+    def fallback(value, handler):
+        try:
+            return handler(value)
+        except RuntimeError:
+            return 'fallback'
+
+    v = SchemaValidator(
+        core_schema.no_info_wrap_validator_function(
+            fallback, core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()], scorer=bad_scorer)
+        )
+    )
+    result = v.validate_python('1', exactness=True)
+    assert (result.output, result.exactness) == ('fallback', 'exact')
+
+    # as are the items left out and the fields validated by the choice
+    choice = core_schema.typed_dict_schema(
+        {'a': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema()))}
+    )
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.with_default_schema(
+                core_schema.union_schema([choice, core_schema.str_schema()], scorer=bad_scorer),
+                default=None,
+                on_error='default',
+            )
+        )
+    )
+    result = v.validate_python([{'a': [1, 'x']}], allow_partial=True, partial_report=True, provenance=True)
+    assert (result.output, result.partial_report, result.provenance) == ([None], [], [])
+
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()], scorer=lambda *_: 'high')
+    )
+    with pytest.raises(TypeError):
+        v.validate_python(1)
+
+    with pytest.raises(SchemaError, match='`scorer` can only be used with `smart` mode unions'):
+        SchemaValidator(
+            core_schema.union_schema(
+                [core_schema.int_schema(), core_schema.str_schema()], mode='left_to_right', scorer=lambda *_: 0
+            )
+        )
+
+
+def test_union_choices_strict() -> None:
+    choices = [core_schema.int_schema(), core_schema.float_schema()]
+    v = SchemaValidator(core_schema.union_schema(choices))
+    assert v.validate_python('1') == IsInt(exactly=1)
+
+    v = SchemaValidator(core_schema.union_schema(choices, choices_strict=[True, False]))
+    assert v.validate_python(1) == IsInt(exactly=1)
+    # `int` only accepts ints, so the string is coerced by `float`
+    assert v.validate_python('1') == IsFloat(exactly=1.0)
+    # the override applies regardless of the validation time setting
+    assert v.validate_python('1.5', strict=True) == 1.5
+    assert v.validate_json('"2"', strict=True) == IsFloat(exactly=2.0)
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_type', ('int',)),
+        ('float_parsing', ('float',)),
+    ]
+
+
+def test_union_choices_strict_overrides_schema() -> None:
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [core_schema.int_schema(strict=True), core_schema.str_schema()],
+            choices_strict=[False, None],
+            mode='left_to_right',
+        )
+    )
+    assert v.validate_python('1') == 1
+    # `None` leaves the choice to follow the validation time setting
+    assert v.validate_python('1', strict=True) == 1
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_python(b'x', strict=True)
+
+
+def test_union_choices_strict_nested_model() -> None:
+    class Model:
+        pass
+
+    model_schema = core_schema.model_schema(
+        Model, core_schema.model_fields_schema({'x': core_schema.model_field(core_schema.int_schema())})
+    )
+    v = SchemaValidator(core_schema.union_schema([model_schema], choices_strict=[True]))
+    assert v.validate_python({'x': 1}).x == 1
+    # a single choice with its own strictness isn't collapsed, and the strictness applies within the model
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python({'x': '1'})
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python({'x': '1'}, strict=False, strict_scope='top-level')
+
+
+def test_union_choices_strict_length() -> None:
+    with pytest.raises(SchemaError, match='`choices_strict` should have the same length as `choices`, got 1 and 2'):
+        SchemaValidator(
+            core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()], choices_strict=[True])
+        )
+
+
+def record_scratch(name: str, schema: core_schema.CoreSchema) -> core_schema.CoreSchema:
+    def f(input_value: Any, info: core_schema.ValidationInfo) -> Any:
+        info.scratch['choices'] = info.scratch.get('choices', ()) + (name,)
+        return input_value
+
+    return core_schema.with_info_after_validator_function(f, schema)
+
+
+@pytest.mark.parametrize('mode', ['smart', 'left_to_right'])
+def test_union_scratch_rollback(mode) -> None:
+    scratches = []
+
+    def outer(input_value: Any, info: core_schema.ValidationInfo) -> Any:
+        scratches.append(dict(info.scratch))
+        return input_value
+
+    choices = [
+        core_schema.chain_schema([record_scratch('int', core_schema.int_schema()), core_schema.int_schema(gt=10)]),
+        record_scratch('str', core_schema.str_schema()),
+        record_scratch('float', core_schema.float_schema()),
+    ]
+    schema = core_schema.tuple_schema(
+        [record_scratch('first', core_schema.int_schema()), core_schema.union_schema(choices, mode=mode)]
+    )
+    v = SchemaValidator(core_schema.with_info_after_validator_function(outer, schema))
+    # the `int` choice writes to the scratch context before failing the `gt` check, `str` fails before writing
+    assert v.validate_python((1, 1)) == (1, 1.0)
+    assert scratches == [{'choices': ('first', 'float')}]
+
+    # each validation gets its own scratch context
+    scratches.clear()
+    assert v.validate_python((1, 'x')) == (1, 'x')
+    assert scratches == [{'choices': ('first', 'str')}]
+
+
+def test_union_scratch_smart_best_match() -> None:
+    scratches = []
+
+    def outer(input_value: Any, info: core_schema.ValidationInfo) -> Any:
+        scratches.append(dict(info.scratch))
+        return input_value
+
+    # both choices succeed, `float` is strict for a float input so it wins
+    choices = [record_scratch('int', core_schema.int_schema()), record_scratch('float', core_schema.float_schema())]
+    v = SchemaValidator(core_schema.with_info_after_validator_function(outer, core_schema.union_schema(choices)))
+    assert v.validate_python(1.0) == 1.0
+    assert scratches == [{'choices': ('float',)}]
@@ -1,10 +1,12 @@
 import copy
+import pickle
 import re
+from datetime import datetime, timedelta, timezone
 from uuid import UUID
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, TimestampedUUID, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -234,3 +236,95 @@ def test_uuid_wrap_json():
     assert v.validate_json('"a6cc5730-2261-11ee-9c43-2eb5a363657c"', strict=True) == UUID(
         'a6cc5730-2261-11ee-9c43-2eb5a363657c'
     )
+
+
+# the examples from RFC 9562, all generated at 2022-02-22T19:22:22Z
+UUID_V1 = 'c232ab00-9414-11ec-b3c8-9f6bdeced846'
+UUID_V6 = '1ec9414c-232a-6b00-b3c8-9f6bdeced846'
+UUID_V7 = '017f22e2-79b0-7cc3-98c4-dc0c0c07398f'
+GENERATED_AT = datetime(2022, 2, 22, 19, 22, 22, tzinfo=timezone.utc)
+
+
+@pytest.mark.parametrize('version,input_value', [(1, UUID_V1), (6, UUID_V6), (7, UUID_V7)])
+def test_uuid_with_timestamp(version, input_value):
+    v = SchemaValidator(core_schema.uuid_schema(version=version, with_timestamp=True))
+    output = v.validate_python(input_value)
+    assert isinstance(output, TimestampedUUID)
+    assert output == UUID(input_value)
+    assert output.timestamp == GENERATED_AT
+    assert v.validate_json(f'"{input_value}"').timestamp == GENERATED_AT
+
+    # a `uuid.UUID` is returned as a `TimestampedUUID`, which is returned as is
+    from_uuid = v.validate_python(UUID(input_value))
+    assert isinstance(from_uuid, TimestampedUUID)
+    assert from_uuid.timestamp == GENERATED_AT
+    assert v.validate_python(from_uuid) is from_uuid
+
+
+def test_timestamped_uuid_copy():
+    v = SchemaValidator(core_schema.uuid_schema(version=7, with_timestamp=True))
+    output = v.validate_python(UUID_V7)
+    for c in (copy.copy(output), copy.deepcopy(output), pickle.loads(pickle.dumps(output))):
+        assert isinstance(c, TimestampedUUID)
+        assert c == output
+        assert c.timestamp == GENERATED_AT
+
+
+@pytest.mark.parametrize('version,input_value', [(1, UUID_V1), (6, UUID_V6), (7, UUID_V7)])
+def test_uuid_timestamp_range(version, input_value):
+    v = SchemaValidator(
+        core_schema.uuid_schema(
+            version=version, timestamp_ge=GENERATED_AT, timestamp_lt=GENERATED_AT + timedelta(milliseconds=1)
+        )
+    )
+    assert v.validate_python(input_value) == UUID(input_value)
+    assert v.validate_python(UUID(input_value)) == UUID(input_value)
+
+    v = SchemaValidator(
+        core_schema.uuid_schema(version=version, timestamp_ge=GENERATED_AT + timedelta(milliseconds=1))
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'uuid_timestamp_greater_than_equal',
+            'loc': (),
+            'msg': 'UUID timestamp should be greater than or equal to 2022-02-22T19:22:22.001000+00:00',
+            'input': input_value,
+            'ctx': {'ge': '2022-02-22T19:22:22.001000+00:00'},
+        }
+    ]
+
+    v = SchemaValidator(core_schema.uuid_schema(version=version, timestamp_lt=GENERATED_AT))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(UUID(input_value))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'uuid_timestamp_less_than',
+            'loc': (),
+            'msg': 'UUID timestamp should be less than 2022-02-22T19:22:22+00:00',
+            'input': UUID(input_value),
+            'ctx': {'lt': '2022-02-22T19:22:22+00:00'},
+        }
+    ]
+
+
+def test_uuid_timestamp_range_other_timezone():
+    tz = timezone(timedelta(hours=2))
+    v = SchemaValidator(core_schema.uuid_schema(version=7, timestamp_lt=datetime(2022, 2, 22, 21, 22, 22, tzinfo=tz)))
+    with pytest.raises(ValidationError, match=r'UUID timestamp should be less than 2022-02-22T21:22:22\+02:00'):
+        v.validate_python(UUID_V7)
+
+
+@pytest.mark.parametrize(
+    'kwargs,message',
+    [
+        ({'version': 4, 'with_timestamp': True}, 'require a time-based `version`, 1, 6 or 7'),
+        ({'timestamp_ge': GENERATED_AT}, 'require a time-based `version`, 1, 6 or 7'),
+        ({'version': 7, 'timestamp_lt': datetime(2022, 1, 1)}, '`timestamp_lt` should be a timezone-aware datetime'),
+        ({'version': 2}, 'Invalid `version` 2, expected 1, 3, 4, 5, 6, 7 or 8'),
+    ],
+)
+def test_uuid_invalid_schema(kwargs, message):
+    with pytest.raises(SchemaError, match=message):
+        SchemaValidator(core_schema.uuid_schema(**kwargs))
@@ -1002,3 +1002,74 @@ def test_default_validate_default_after_validator_field_name() -> None:
     val.validate_python({})
 
     assert field_name == 'a'
+
+
+shared_tags = []
+
+
+def shared_defaults_schema(factory, **config):
+    return core_schema.typed_dict_schema(
+        {
+            'tags': core_schema.typed_dict_field(
+                core_schema.with_default_schema(core_schema.list_schema(), default_factory=factory)
+            )
+        },
+        config=config,
+    )
+
+
+def test_shared_defaults_check_error():
+    v = SchemaValidator(shared_defaults_schema(lambda: shared_tags, shared_defaults_check='error'))
+    assert v.validate_python({})['tags'] is shared_tags
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'default_shared',
+            'loc': ('tags',),
+            'msg': 'Default value is the same object as a default returned before, it should be a new object each time',
+            'input': [],
+        }
+    ]
+    # values from the input aren't checked
+    assert v.validate_python({'tags': shared_tags}) == {'tags': []}
+
+
+def test_shared_defaults_check_warn():
+    v = SchemaValidator(shared_defaults_schema(lambda: shared_tags, shared_defaults_check='warn'))
+    v.validate_python({})
+    with pytest.warns(UserWarning, match='Default value of type `list` is the same object as a default returned'):
+        assert v.validate_python({})['tags'] is shared_tags
+
+
+def test_shared_defaults_check_new_objects():
+    v = SchemaValidator(shared_defaults_schema(list, shared_defaults_check='error'))
+    assert v.validate_python({}) == {'tags': []}
+    assert v.validate_python({}) == {'tags': []}
+
+    # unhashable defaults are copied, and hashable ones are assumed to be immutable
+    schema = core_schema.typed_dict_schema(
+        {
+            'tags': core_schema.typed_dict_field(
+                core_schema.with_default_schema(core_schema.list_schema(), default=[])
+            ),
+            'n': core_schema.typed_dict_field(core_schema.with_default_schema(core_schema.int_schema(), default=1)),
+        },
+        config={'shared_defaults_check': 'error'},
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python({}) == {'tags': [], 'n': 1}
+    assert v.validate_python({}) == {'tags': [], 'n': 1}
+
+
+def test_shared_defaults_check_validate_default():
+    # validating the default returns a new list, so results don't share it
+    schema = shared_defaults_schema(lambda: shared_tags, shared_defaults_check='error', validate_default=True)
+    v = SchemaValidator(schema)
+    assert v.validate_python({}) == {'tags': []}
+    assert v.validate_python({}) == {'tags': []}
+
+
+def test_shared_defaults_check_invalid():
+    with pytest.raises(SchemaError, match="Invalid shared_defaults_check: `raise`, expected 'warn' or 'error'"):
+        SchemaValidator(shared_defaults_schema(list, shared_defaults_check='raise'))